@@ -0,0 +1,59 @@
+// Size regression check for the wasm-pack bundles (run after `bun run build:wasm`).
+//
+// The core bundle is fetched on every app start, so it has the tighter budget
+// and must not export the LinkedIn parsers, which live in the lazily loaded
+// bundle. Budgets are gzipped bytes; override with WASM_CORE_BUDGET_KB and
+// WASM_LINKEDIN_BUDGET_KB when a deliberate change needs more room.
+import { existsSync, readFileSync } from "node:fs";
+import { resolve, dirname } from "node:path";
+import { fileURLToPath } from "node:url";
+import { gzipSync } from "node:zlib";
+
+const __dirname = dirname(fileURLToPath(import.meta.url));
+const wasmDir = resolve(__dirname, "..", "wasm");
+
+const bundles = [
+  {
+    name: "core",
+    js: resolve(wasmDir, "rustume_wasm.js"),
+    wasm: resolve(wasmDir, "rustume_wasm_bg.wasm"),
+    budgetKb: Number(process.env.WASM_CORE_BUDGET_KB ?? 600),
+    forbiddenExports: ["parse_linkedin_export", "parse_linkedin_jobs"],
+  },
+  {
+    name: "linkedin",
+    js: resolve(wasmDir, "linkedin", "rustume_wasm_linkedin.js"),
+    wasm: resolve(wasmDir, "linkedin", "rustume_wasm_linkedin_bg.wasm"),
+    budgetKb: Number(process.env.WASM_LINKEDIN_BUDGET_KB ?? 500),
+    forbiddenExports: ["Storage"],
+  },
+];
+
+let failed = false;
+for (const bundle of bundles) {
+  if (!existsSync(bundle.wasm) || !existsSync(bundle.js)) {
+    console.error(`error: ${bundle.name} bundle not found at ${bundle.wasm} (run build:wasm first)`);
+    failed = true;
+    continue;
+  }
+
+  const raw = readFileSync(bundle.wasm);
+  const gzipKb = gzipSync(raw, { level: 9 }).length / 1024;
+  const rawKb = raw.length / 1024;
+  const status = gzipKb <= bundle.budgetKb ? "ok" : "OVER BUDGET";
+  console.log(
+    `${bundle.name}: ${rawKb.toFixed(1)} KiB raw, ${gzipKb.toFixed(1)} KiB gzip ` +
+      `(budget ${bundle.budgetKb} KiB) ${status}`,
+  );
+  if (gzipKb > bundle.budgetKb) failed = true;
+
+  const glue = readFileSync(bundle.js, "utf8");
+  for (const name of bundle.forbiddenExports) {
+    if (new RegExp(`export (function|class) ${name}\\b`).test(glue)) {
+      console.error(`error: ${bundle.name} bundle exports ${name}; check its cargo features`);
+      failed = true;
+    }
+  }
+}
+
+process.exit(failed ? 1 : 0);
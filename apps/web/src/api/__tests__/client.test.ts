@@ -104,6 +104,24 @@ describe("get", () => {
     });
   });
 
+  it("keeps the error code from error responses", async () => {
+    const body = JSON.stringify({
+      error: "Failed to parse LinkedIn export",
+      code: "PARSE_LINKEDIN_BAD_ZIP",
+    });
+    globalThis.fetch = vi.fn().mockResolvedValue({
+      ok: false,
+      status: 400,
+      statusText: "Bad Request",
+      text: () => Promise.resolve(body),
+    });
+
+    await expect(post("/parse", {})).rejects.toMatchObject({
+      status: 400,
+      code: "PARSE_LINKEDIN_BAD_ZIP",
+    });
+  });
+
   it("throws ApiValidationError when schema validation fails", async () => {
     globalThis.fetch = vi.fn().mockResolvedValue({
       ok: true,
@@ -179,7 +179,19 @@ const mockResume: ResumeData = {
     template: "rhyhorn",
     layout: [],
     css: { value: "", visible: false },
-    page: { margin: 18, format: "a4", breakLine: false, pageNumbers: false },
+    page: {
+      margin: 18,
+      format: "a4",
+      options: {
+        breakLine: false,
+        pageNumbers: false,
+        pageNumberStyle: "plain",
+        runningHeader: "hidden",
+        continuedMarkers: false,
+        contactQr: "hidden",
+        pdfMetadata: false,
+      },
+    },
     theme: { background: "#ffffff", text: "#000000", primary: "#65a30d" },
     typography: {
       font: {
@@ -189,12 +201,28 @@ const mockResume: ResumeData = {
         size: 14,
       },
       lineHeight: 1.5,
+      letterSpacing: 0,
+      headingScale: 1,
+      smallCaps: false,
       hideIcons: false,
       underlineLinks: false,
     },
     notes: "",
     levelDisplay: "template-default",
+    sectionDisplay: {},
+    showDurations: false,
+    showCefr: false,
+    locale: "en",
+    emoji: "preserve",
+    templateOptions: {
+      photo: "template-default",
+      headerAlign: "template-default",
+      divider: "template-default",
+      nameOrder: "auto",
+      familyNameCaps: false,
+    },
   },
+  attachments: [],
 };
 
 describe("renderPreview", () => {
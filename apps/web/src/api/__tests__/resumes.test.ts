@@ -6,7 +6,9 @@ import {
   getCloudResume,
   importResumes,
   listCloudResumes,
+  listCloudTrash,
   parseApiErrorBody,
+  restoreCloudResume,
   ResumeVersionConflictError,
   updateCloudResume,
   upsertCloudResume,
@@ -239,6 +241,54 @@ describe("resume API helpers", () => {
     );
   });
 
+  it("deleteCloudResume appends permanent=true when erasing", async () => {
+    const mockFetch = vi.fn().mockResolvedValue({
+      ok: true,
+      status: 204,
+      headers: new Headers(),
+      text: () => Promise.resolve(""),
+    });
+    globalThis.fetch = mockFetch;
+
+    await deleteCloudResume("abc", true);
+
+    expect(mockFetch).toHaveBeenCalledWith(
+      "/api/resumes/abc?permanent=true",
+      expect.objectContaining({ method: "DELETE" }),
+    );
+  });
+
+  it("listCloudTrash calls GET /api/resumes/trash", async () => {
+    const trashed = [
+      {
+        id: "abc",
+        title: "Old",
+        trashed_at: "2026-01-01T00:00:00Z",
+        purge_at: "2026-01-31T00:00:00Z",
+      },
+    ];
+    const mockFetch = vi.fn().mockResolvedValue(jsonFetch(trashed));
+    globalThis.fetch = mockFetch;
+
+    await expect(listCloudTrash()).resolves.toEqual(trashed);
+    expect(mockFetch).toHaveBeenCalledWith(
+      "/api/resumes/trash",
+      expect.objectContaining({ method: "GET" }),
+    );
+  });
+
+  it("restoreCloudResume posts to /api/resumes/:id/restore", async () => {
+    const row = mockRow({ id: "abc" });
+    const mockFetch = vi.fn().mockResolvedValue(jsonFetch(row));
+    globalThis.fetch = mockFetch;
+
+    await expect(restoreCloudResume("abc")).resolves.toEqual(row);
+    expect(mockFetch).toHaveBeenCalledWith(
+      "/api/resumes/abc/restore",
+      expect.objectContaining({ method: "POST" }),
+    );
+  });
+
   it("createCloudResume posts JSON body", async () => {
     const body = { title: "Mine", data: testResume("A") };
     const row = mockRow({ id: "1", title: "Mine" });
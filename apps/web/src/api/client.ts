@@ -1,4 +1,5 @@
 import type { z } from "zod";
+import type { ErrorCode } from "../wasm/types";
 
 const API_BASE = "/api";
 
@@ -108,8 +109,30 @@ export function extractApiErrorMessage(text: string, fallback: string): string {
   return text || fallback;
 }
 
+/** The stable `code` of an error response body, when it has one. */
+export function extractApiErrorCode(text: string): ErrorCode | undefined {
+  try {
+    const parsed: unknown = JSON.parse(text);
+    if (typeof parsed === "object" && parsed !== null) {
+      const body = parsed as Record<string, unknown>;
+      if (typeof body.code === "string") {
+        return body.code as ErrorCode;
+      }
+    }
+  } catch {
+    // Not JSON, so no code.
+  }
+
+  return undefined;
+}
+
 function createApiError(status: number, text: string, fallback: string): ApiError {
-  return new ApiError(status, extractApiErrorMessage(text, fallback), text);
+  return new ApiError(
+    status,
+    extractApiErrorMessage(text, fallback),
+    text,
+    extractApiErrorCode(text),
+  );
 }
 
 async function request<T>(
@@ -180,6 +203,8 @@ export class ApiError extends Error {
     public status: number,
     message: string,
     public readonly body?: string,
+    /** Stable error code from the server; branch on this, not on `message`. */
+    public readonly code?: ErrorCode,
   ) {
     super(message);
     this.name = "ApiError";
@@ -1,4 +1,4 @@
-import { ApiError, extractApiErrorMessage, get } from "./client";
+import { ApiError, extractApiErrorCode, extractApiErrorMessage, get } from "./client";
 import { resumeBulkExportSchema } from "./schemas";
 
 export interface ResumeExportItem {
@@ -22,7 +22,12 @@ export async function exportResumesPdf(): Promise<Blob> {
   const response = await fetch("/api/resumes/export/pdf", { credentials: "include" });
   if (!response.ok) {
     const text = await response.text();
-    throw new ApiError(response.status, extractApiErrorMessage(text, response.statusText), text);
+    throw new ApiError(
+      response.status,
+      extractApiErrorMessage(text, response.statusText),
+      text,
+      extractApiErrorCode(text),
+    );
   }
   return response.blob();
 }
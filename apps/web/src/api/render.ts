@@ -17,9 +17,12 @@ export interface PreviewRequest extends RenderRequest {
 }
 
 export interface ParseRequest {
-  format: "json-resume" | "linkedin" | "rrv3" | "rustume";
+  format: "auto" | "json-resume" | "linkedin" | "rrv3" | "rustume" | "stack-overflow" | "text";
   data: string;
   base64?: boolean;
+  locale?: string;
+  ids?: "random" | "stable";
+  lenient?: boolean;
 }
 
 export interface PreviewResult {
@@ -4,6 +4,8 @@ import {
   cloudResumeRowSchema,
   importBatchResponseSchema,
   paginatedCloudResumeSummariesSchema,
+  resumeHistorySchema,
+  trashedResumesSchema,
 } from "./schemas";
 
 export interface CloudResumeSummary {
@@ -28,6 +30,21 @@ export interface CloudResumeRow extends CloudResumeSummary {
   created_at: string;
 }
 
+export interface CloudResumeHistoryEntry {
+  resume_id: string;
+  author: string | null;
+  action: "created" | "updated" | "deleted" | "restored";
+  summary: string;
+  at: string;
+}
+
+export interface CloudTrashedResume {
+  id: string;
+  title: string;
+  trashed_at: string;
+  purge_at: string;
+}
+
 export interface ImportResumeItem {
   id?: string;
   title?: string;
@@ -135,6 +152,11 @@ export async function getCloudResume(id: string): Promise<CloudResumeRow> {
   return get(`/resumes/${id}`, cloudResumeRowSchema) as unknown as Promise<CloudResumeRow>;
 }
 
+/** Change log of a resume, oldest first. */
+export async function getCloudResumeHistory(id: string): Promise<CloudResumeHistoryEntry[]> {
+  return get(`/resumes/${id}/history`, resumeHistorySchema);
+}
+
 export async function createCloudResume(payload: CreateResumePayload): Promise<CloudResumeRow> {
   return post("/resumes", payload, cloudResumeRowSchema) as unknown as Promise<CloudResumeRow>;
 }
@@ -157,8 +179,22 @@ export async function updateCloudResume(
   }
 }
 
-export async function deleteCloudResume(id: string): Promise<void> {
-  await del(`/resumes/${id}`);
+/** Moves a resume to the trash, or erases it when `permanent` is set. */
+export async function deleteCloudResume(id: string, permanent = false): Promise<void> {
+  await del(permanent ? `/resumes/${id}?permanent=true` : `/resumes/${id}`);
+}
+
+/** Trashed resumes, most recently deleted first. */
+export async function listCloudTrash(): Promise<CloudTrashedResume[]> {
+  return get("/resumes/trash", trashedResumesSchema);
+}
+
+export async function restoreCloudResume(id: string): Promise<CloudResumeRow> {
+  return post(
+    `/resumes/${id}/restore`,
+    undefined,
+    cloudResumeRowSchema,
+  ) as unknown as Promise<CloudResumeRow>;
 }
 
 export const MAX_IMPORT_BATCH = 100;
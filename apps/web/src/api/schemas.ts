@@ -31,6 +31,25 @@ export const cloudResumeRowSchema = cloudResumeSummarySchema.extend({
   created_at: z.string(),
 });
 
+export const resumeHistorySchema = z.array(
+  z.object({
+    resume_id: z.string(),
+    author: z.string().nullable(),
+    action: z.enum(["created", "updated", "deleted", "restored"]),
+    summary: z.string(),
+    at: z.string(),
+  }),
+);
+
+export const trashedResumesSchema = z.array(
+  z.object({
+    id: z.string(),
+    title: z.string(),
+    trashed_at: z.string(),
+    purge_at: z.string(),
+  }),
+);
+
 export const importBatchResponseSchema = z.object({
   imported: z.array(cloudResumeSummarySchema),
   failed: z.array(
@@ -51,6 +70,13 @@ export const templateInfoSchema = z.object({
   id: z.string(),
   name: z.string(),
   theme: themeSchema,
+  options: z
+    .object({
+      photo: z.array(z.enum(["top", "left", "right", "hidden"])),
+      headerAlign: z.boolean(),
+      divider: z.boolean(),
+    })
+    .optional(),
 });
 
 export const templateListSchema = z.array(templateInfoSchema);
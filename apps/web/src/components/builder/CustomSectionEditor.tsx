@@ -2,7 +2,8 @@ import { For, Show, createSignal } from "solid-js";
 import { Button, Input } from "../ui";
 import { LazyRichTextEditor as RichTextEditor } from "../ui/LazyRichTextEditor";
 import { resumeStore } from "../../stores/resume";
-import { createEmptyUrl, generateId, type CustomItem } from "../../wasm/types";
+import { createSectionItem } from "../../wasm";
+import type { CustomItem } from "../../wasm/types";
 
 interface CustomSectionEditorProps {
   sectionId: string;
@@ -13,20 +14,6 @@ interface CustomSectionsIndexProps {
   onSelectSection: (sectionId: string) => void;
 }
 
-function createCustomItem(): CustomItem {
-  return {
-    id: generateId(),
-    visible: true,
-    name: "",
-    description: "",
-    date: "",
-    location: "",
-    summary: "",
-    keywords: [],
-    url: createEmptyUrl(),
-  };
-}
-
 export function CustomSectionEditor(props: CustomSectionEditorProps) {
   const {
     store,
@@ -50,7 +37,7 @@ export function CustomSectionEditor(props: CustomSectionEditorProps) {
 
   const handleAdd = () => {
     const nextIndex = items().length;
-    addCustomSectionItem(props.sectionId, createCustomItem());
+    addCustomSectionItem(props.sectionId, createSectionItem("custom"));
     touch();
     setExpandedIndex(nextIndex);
   };
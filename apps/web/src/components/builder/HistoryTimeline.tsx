@@ -0,0 +1,83 @@
+import { createResource, For, Match, Show, Switch } from "solid-js";
+import { Spinner } from "../ui";
+import { resumeStore } from "../../stores/resume";
+import { getResumeActivity, type ResumeActivity } from "../../stores/persistence";
+
+const ACTION_LABELS: Record<ResumeActivity["action"], string> = {
+  created: "Created",
+  updated: "Edited",
+  deleted: "Deleted",
+  restored: "Restored from trash",
+};
+
+function formatTime(at: string): string {
+  const date = new Date(at);
+  return Number.isNaN(date.getTime()) ? at : date.toLocaleString();
+}
+
+export function HistoryTimeline() {
+  const { store } = resumeStore;
+
+  // Refetch after every save so the newest change shows up
+  const [activity] = createResource(
+    () => (store.id ? { id: store.id, savedAt: store.lastSaved?.getTime() ?? 0 } : null),
+    ({ id }) => getResumeActivity(id),
+  );
+
+  return (
+    <div class="space-y-4">
+      {/* Header */}
+      <div class="flex items-center gap-3 pb-4 border-b border-border">
+        <div class="w-10 h-10 bg-accent/10 rounded-lg flex items-center justify-center">
+          <svg class="w-5 h-5 text-accent" fill="none" stroke="currentColor" viewBox="0 0 24 24">
+            <path
+              stroke-linecap="round"
+              stroke-linejoin="round"
+              stroke-width="2"
+              d="M12 8v4l3 3m6-3a9 9 0 11-18 0 9 9 0 0118 0z"
+            />
+          </svg>
+        </div>
+        <div>
+          <h2 class="font-display text-lg font-semibold text-ink">History</h2>
+          <p class="text-sm text-stone">Every saved change to this resume</p>
+        </div>
+      </div>
+
+      <Switch>
+        <Match when={activity.error}>
+          <p class="text-sm text-red-600">Could not load history.</p>
+        </Match>
+        <Match when={activity.loading && !activity.latest}>
+          <div class="flex justify-center py-8">
+            <Spinner />
+          </div>
+        </Match>
+        <Match when={activity.latest?.length === 0}>
+          <p class="text-sm text-stone">No changes recorded yet.</p>
+        </Match>
+        <Match when={activity.latest}>
+          {(entries) => (
+            <ol class="relative border-l border-border ml-2 space-y-4">
+              <For each={entries()}>
+                {(entry) => (
+                  <li class="ml-4">
+                    <span class="absolute -left-1.5 mt-1.5 h-3 w-3 rounded-full border border-surface bg-accent" />
+                    <p class="text-sm font-medium text-ink">{ACTION_LABELS[entry.action]}</p>
+                    <Show when={entry.action === "updated"}>
+                      <p class="text-sm text-ink">{entry.summary}</p>
+                    </Show>
+                    <p class="text-xs text-stone">
+                      <time datetime={entry.at}>{formatTime(entry.at)}</time>
+                      <Show when={entry.author}>{(author) => <> · {author()}</>}</Show>
+                    </p>
+                  </li>
+                )}
+              </For>
+            </ol>
+          )}
+        </Match>
+      </Switch>
+    </div>
+  );
+}
@@ -1,9 +1,10 @@
 import { For, Show, createSignal, type JSX } from "solid-js";
-import { Button, Input } from "../ui";
+import { Button, Input, TextArea } from "../ui";
 import { LazyRichTextEditor as RichTextEditor } from "../ui/LazyRichTextEditor";
 import { resumeStore, type SectionKey } from "../../stores/resume";
-import { generateId, createEmptyUrl } from "../../wasm/types";
+import { createSectionItem } from "../../wasm";
 import type {
+  CefrLevel,
   Experience,
   Education,
   Skill,
@@ -18,6 +19,16 @@ import type {
   Reference,
 } from "../../wasm/types";
 
+// 0–5 level of each CEFR level, matching `CefrLevel::level` in the schema
+const CEFR_LEVELS: Record<CefrLevel, number> = {
+  A1: 1,
+  A2: 1,
+  B1: 2,
+  B2: 3,
+  C1: 4,
+  C2: 5,
+};
+
 // Singularize section titles for better UX
 const SINGULAR_MAP: Record<string, string> = {
   Experience: "experience",
@@ -34,6 +45,20 @@ const SINGULAR_MAP: Record<string, string> = {
   References: "reference",
 };
 
+/** One highlight per line, printed as a list after the summary. */
+function HighlightsField(props: { value: string[]; onInput: (value: string[]) => void }) {
+  return (
+    <TextArea
+      label="Highlights"
+      description="One achievement per line, shown as bullets after the summary"
+      placeholder={"Cut build times by 40%\nMentored three engineers"}
+      rows={3}
+      value={props.value.join("\n")}
+      onInput={(v) => props.onInput(v ? v.split("\n") : [])}
+    />
+  );
+}
+
 function singularize(title: string): string {
   return SINGULAR_MAP[title] || title.toLowerCase().replace(/s$/, "");
 }
@@ -271,16 +296,7 @@ export function ExperienceEditor() {
       sectionKey="experience"
       title="Experience"
       icon="M21 13.255A23.931 23.931 0 0112 15c-3.183 0-6.22-.62-9-1.745M16 6V4a2 2 0 00-2-2h-4a2 2 0 00-2 2v2m4 6h.01M5 20h14a2 2 0 002-2V8a2 2 0 00-2-2H5a2 2 0 00-2 2v10a2 2 0 002 2z"
-      createItem={() => ({
-        id: generateId(),
-        visible: true,
-        company: "",
-        position: "",
-        location: "",
-        date: "",
-        summary: "",
-        url: createEmptyUrl(),
-      })}
+      createItem={() => createSectionItem("experience")}
       getItemTitle={(item) => item.position || item.company}
       getItemSubtitle={(item) => (item.company && item.position ? item.company : "")}
       renderItem={(item, _index, update) => (
@@ -319,6 +335,10 @@ export function ExperienceEditor() {
             value={item.summary}
             onInput={(v) => update({ summary: v })}
           />
+          <HighlightsField
+            value={item.highlights ?? []}
+            onInput={(highlights) => update({ highlights })}
+          />
           <div class="grid grid-cols-2 gap-4">
             <Input
               label="Link Label"
@@ -346,17 +366,7 @@ export function EducationEditor() {
       sectionKey="education"
       title="Education"
       icon="M12 14l9-5-9-5-9 5 9 5z M12 14l6.16-3.422a12.083 12.083 0 01.665 6.479A11.952 11.952 0 0012 20.055a11.952 11.952 0 00-6.824-2.998 12.078 12.078 0 01.665-6.479L12 14z"
-      createItem={() => ({
-        id: generateId(),
-        visible: true,
-        institution: "",
-        area: "",
-        studyType: "",
-        date: "",
-        score: "",
-        summary: "",
-        url: createEmptyUrl(),
-      })}
+      createItem={() => createSectionItem("education")}
       getItemTitle={(item) => item.institution}
       getItemSubtitle={(item) => item.area || item.studyType}
       renderItem={(item, _index, update) => (
@@ -413,14 +423,7 @@ export function SkillsEditor() {
       sectionKey="skills"
       title="Skills"
       icon="M9.663 17h4.673M12 3v1m6.364 1.636l-.707.707M21 12h-1M4 12H3m3.343-5.657l-.707-.707m2.828 9.9a5 5 0 117.072 0l-.548.547A3.374 3.374 0 0014 18.469V19a2 2 0 11-4 0v-.531c0-.895-.356-1.754-.988-2.386l-.548-.547z"
-      createItem={() => ({
-        id: generateId(),
-        visible: true,
-        name: "",
-        description: "",
-        level: 0,
-        keywords: [],
-      })}
+      createItem={() => createSectionItem("skills")}
       getItemTitle={(item) => item.name}
       getItemSubtitle={(item) => item.keywords.slice(0, 3).join(", ")}
       renderItem={(item, _index, update) => (
@@ -475,16 +478,7 @@ export function ProjectsEditor() {
       sectionKey="projects"
       title="Projects"
       icon="M19 11H5m14 0a2 2 0 012 2v6a2 2 0 01-2 2H5a2 2 0 01-2-2v-6a2 2 0 012-2m14 0V9a2 2 0 00-2-2M5 11V9a2 2 0 012-2m0 0V5a2 2 0 012-2h6a2 2 0 012 2v2M7 7h10"
-      createItem={() => ({
-        id: generateId(),
-        visible: true,
-        name: "",
-        description: "",
-        date: "",
-        summary: "",
-        keywords: [],
-        url: createEmptyUrl(),
-      })}
+      createItem={() => createSectionItem("projects")}
       getItemTitle={(item) => item.name}
       getItemSubtitle={(item) => item.description}
       renderItem={(item, _index, update) => (
@@ -513,6 +507,10 @@ export function ProjectsEditor() {
             value={item.summary}
             onInput={(v) => update({ summary: v })}
           />
+          <HighlightsField
+            value={item.highlights ?? []}
+            onInput={(highlights) => update({ highlights })}
+          />
           <Input
             label="Technologies"
             placeholder="React, Rust, PostgreSQL (comma separated)"
@@ -553,14 +551,7 @@ export function ProfilesEditor() {
       sectionKey="profiles"
       title="Profiles"
       icon="M13.828 10.172a4 4 0 00-5.656 0l-4 4a4 4 0 105.656 5.656l1.102-1.101m-.758-4.899a4 4 0 005.656 0l4-4a4 4 0 00-5.656-5.656l-1.1 1.1"
-      createItem={() => ({
-        id: generateId(),
-        visible: true,
-        network: "",
-        username: "",
-        icon: "",
-        url: createEmptyUrl(),
-      })}
+      createItem={() => createSectionItem("profiles")}
       getItemTitle={(item) => item.network || item.username}
       getItemSubtitle={(item) => (item.network && item.username ? `@${item.username}` : "")}
       renderItem={(item, _index, update) => (
@@ -612,15 +603,7 @@ export function AwardsEditor() {
       sectionKey="awards"
       title="Awards"
       icon="M5 3v4M3 5h4M6 17v4m-2-2h4m5-16l2.286 6.857L21 12l-5.714 2.143L13 21l-2.286-6.857L5 12l5.714-2.143L13 3z"
-      createItem={() => ({
-        id: generateId(),
-        visible: true,
-        title: "",
-        awarder: "",
-        date: "",
-        summary: "",
-        url: createEmptyUrl(),
-      })}
+      createItem={() => createSectionItem("awards")}
       getItemTitle={(item) => item.title}
       getItemSubtitle={(item) => item.awarder}
       renderItem={(item, _index, update) => (
@@ -678,15 +661,7 @@ export function CertificationsEditor() {
       sectionKey="certifications"
       title="Certifications"
       icon="M9 12l2 2 4-4M7.835 4.697a3.42 3.42 0 001.946-.806 3.42 3.42 0 014.438 0 3.42 3.42 0 001.946.806 3.42 3.42 0 013.138 3.138 3.42 3.42 0 00.806 1.946 3.42 3.42 0 010 4.438 3.42 3.42 0 00-.806 1.946 3.42 3.42 0 01-3.138 3.138 3.42 3.42 0 00-1.946.806 3.42 3.42 0 01-4.438 0 3.42 3.42 0 00-1.946-.806 3.42 3.42 0 01-3.138-3.138 3.42 3.42 0 00-.806-1.946 3.42 3.42 0 010-4.438 3.42 3.42 0 00.806-1.946 3.42 3.42 0 013.138-3.138z"
-      createItem={() => ({
-        id: generateId(),
-        visible: true,
-        name: "",
-        issuer: "",
-        date: "",
-        summary: "",
-        url: createEmptyUrl(),
-      })}
+      createItem={() => createSectionItem("certifications")}
       getItemTitle={(item) => item.name}
       getItemSubtitle={(item) => item.issuer}
       renderItem={(item, _index, update) => (
@@ -744,15 +719,7 @@ export function PublicationsEditor() {
       sectionKey="publications"
       title="Publications"
       icon="M12 6.253v13m0-13C10.832 5.477 9.246 5 7.5 5S4.168 5.477 3 6.253v13C4.168 18.477 5.754 18 7.5 18s3.332.477 4.5 1.253m0-13C13.168 5.477 14.754 5 16.5 5c1.747 0 3.332.477 4.5 1.253v13C19.832 18.477 18.247 18 16.5 18c-1.746 0-3.332.477-4.5 1.253"
-      createItem={() => ({
-        id: generateId(),
-        visible: true,
-        name: "",
-        publisher: "",
-        date: "",
-        summary: "",
-        url: createEmptyUrl(),
-      })}
+      createItem={() => createSectionItem("publications")}
       getItemTitle={(item) => item.name}
       getItemSubtitle={(item) => item.publisher}
       renderItem={(item, _index, update) => (
@@ -810,13 +777,7 @@ export function LanguagesEditor() {
       sectionKey="languages"
       title="Languages"
       icon="M3 5h12M9 3v2m1.048 9.5A18.022 18.022 0 016.412 9m6.088 9h7M11 21l5-10 5 10M12.751 5C11.783 10.77 8.07 15.61 3 18.129"
-      createItem={() => ({
-        id: generateId(),
-        visible: true,
-        name: "",
-        description: "",
-        level: 0,
-      })}
+      createItem={() => createSectionItem("languages")}
       getItemTitle={(item) => item.name}
       getItemSubtitle={(item) => item.description}
       renderItem={(item, _index, update) => (
@@ -833,6 +794,29 @@ export function LanguagesEditor() {
             value={item.description}
             onInput={(v) => update({ description: v })}
           />
+          <div>
+            <label
+              for={`cefr-${item.id}`}
+              class="font-mono text-xs uppercase tracking-wider text-stone block mb-2"
+            >
+              CEFR level
+            </label>
+            <select
+              id={`cefr-${item.id}`}
+              value={item.cefr ?? ""}
+              onChange={(e) => {
+                const cefr = (e.currentTarget.value || undefined) as CefrLevel | undefined;
+                update(cefr ? { cefr, level: CEFR_LEVELS[cefr] } : { cefr });
+              }}
+              class="w-full px-3 py-2 text-sm bg-surface border border-border rounded-lg
+                focus:outline-none focus:border-accent"
+            >
+              <option value="">None</option>
+              <For each={Object.keys(CEFR_LEVELS) as CefrLevel[]}>
+                {(cefr) => <option value={cefr}>{cefr}</option>}
+              </For>
+            </select>
+          </div>
           <div>
             <label class="font-mono text-xs uppercase tracking-wider text-stone block mb-2">
               Level ({item.level}/5)
@@ -842,7 +826,13 @@ export function LanguagesEditor() {
               min="0"
               max="5"
               value={item.level}
-              onInput={(e) => update({ level: parseInt(e.currentTarget.value) })}
+              onInput={(e) => {
+                const level = parseInt(e.currentTarget.value);
+                // A level more than a step from the CEFR code's clears the code
+                const cefr =
+                  item.cefr && Math.abs(CEFR_LEVELS[item.cefr] - level) <= 1 ? item.cefr : undefined;
+                update({ level, cefr });
+              }}
               class="w-full accent-accent"
             />
           </div>
@@ -858,12 +848,7 @@ export function InterestsEditor() {
       sectionKey="interests"
       title="Interests"
       icon="M4.318 6.318a4.5 4.5 0 000 6.364L12 20.364l7.682-7.682a4.5 4.5 0 00-6.364-6.364L12 7.636l-1.318-1.318a4.5 4.5 0 00-6.364 0z"
-      createItem={() => ({
-        id: generateId(),
-        visible: true,
-        name: "",
-        keywords: [],
-      })}
+      createItem={() => createSectionItem("interests")}
       getItemTitle={(item) => item.name}
       getItemSubtitle={(item) => item.keywords.slice(0, 3).join(", ")}
       renderItem={(item, _index, update) => (
@@ -899,16 +884,7 @@ export function VolunteerEditor() {
       sectionKey="volunteer"
       title="Volunteer"
       icon="M17 20h5v-2a3 3 0 00-5.356-1.857M17 20H7m10 0v-2c0-.656-.126-1.283-.356-1.857M7 20H2v-2a3 3 0 015.356-1.857M7 20v-2c0-.656.126-1.283.356-1.857m0 0a5.002 5.002 0 019.288 0M15 7a3 3 0 11-6 0 3 3 0 016 0zm6 3a2 2 0 11-4 0 2 2 0 014 0zM7 10a2 2 0 11-4 0 2 2 0 014 0z"
-      createItem={() => ({
-        id: generateId(),
-        visible: true,
-        organization: "",
-        position: "",
-        location: "",
-        date: "",
-        summary: "",
-        url: createEmptyUrl(),
-      })}
+      createItem={() => createSectionItem("volunteer")}
       getItemTitle={(item) => item.position || item.organization}
       getItemSubtitle={(item) => (item.organization && item.position ? item.organization : "")}
       renderItem={(item, _index, update) => (
@@ -974,14 +950,7 @@ export function ReferencesEditor() {
       sectionKey="references"
       title="References"
       icon="M8 12h.01M12 12h.01M16 12h.01M21 12c0 4.418-4.03 8-9 8a9.863 9.863 0 01-4.255-.949L3 20l1.395-3.72C3.512 15.042 3 13.574 3 12c0-4.418 4.03-8 9-8s9 3.582 9 8z"
-      createItem={() => ({
-        id: generateId(),
-        visible: true,
-        name: "",
-        description: "",
-        summary: "",
-        url: createEmptyUrl(),
-      })}
+      createItem={() => createSectionItem("references")}
       getItemTitle={(item) => item.name}
       getItemSubtitle={(item) => item.description}
       renderItem={(item, _index, update) => (
@@ -6,12 +6,20 @@ import {
   parseJsonResume,
   parseReactiveResumeV3,
   parseLinkedInExport,
+  parseStackOverflow,
+  parseTextResume,
   isWasmReady,
 } from "../../wasm";
+import type { LinkedInImportProgress } from "../../wasm";
 import type { ResumeData } from "../../wasm/types";
 import { parseResume } from "../../api/render";
 
-type ImportFormat = "json-resume" | "rrv3" | "linkedin" | "rustume";
+type ImportFormat = "json-resume" | "rrv3" | "linkedin" | "rustume" | "stack-overflow" | "text";
+
+/** Stack Overflow JSON exports have a `user` and `top_tags` but no `basics`. */
+function isStackOverflowJson(json: Record<string, unknown>): boolean {
+  return "top_tags" in json || ("user" in json && !("basics" in json));
+}
 
 /** Native Rustume resume JSON has `sections.summary`; JSON Resume does not. */
 function isNativeRustumeJson(json: Record<string, unknown>): boolean {
@@ -78,11 +86,14 @@ export function ImportModal() {
   const [isDragging, setIsDragging] = createSignal(false);
   const [isLoading, setIsLoading] = createSignal(false);
   const [error, setError] = createSignal<string | null>(null);
+  const [progress, setProgress] = createSignal<LinkedInImportProgress | null>(null);
 
   const isOpen = () => ui.modal === "import";
 
   const detectFormat = (filename: string): ImportFormat => {
     if (filename.endsWith(".zip")) return "linkedin";
+    if (filename.endsWith(".xml")) return "stack-overflow";
+    if (filename.endsWith(".txt")) return "text";
     return "json-resume"; // Default, will try to detect from content
   };
 
@@ -99,7 +110,7 @@ export function ImportModal() {
         const data = new Uint8Array(buffer);
 
         if (isWasmReady()) {
-          const resume = parseLinkedInExport(data);
+          const resume = await parseLinkedInExport(data, setProgress);
           importResume(normalizeImportedResume(resume));
         } else {
           // Use server API with safe chunked base64 encoding
@@ -111,6 +122,26 @@ export function ImportModal() {
           });
           importResume(normalizeImportedResume(resume));
         }
+      } else if (format === "stack-overflow") {
+        const text = await file.text();
+        const resume = isWasmReady()
+          ? parseStackOverflow(text)
+          : await parseResume({ format: "stack-overflow", data: text });
+        importResume(normalizeImportedResume(resume));
+      } else if (format === "text") {
+        const text = await file.text();
+        if (isWasmReady()) {
+          // Plain text is sectionized heuristically; flag the shaky guesses
+          const draft = parseTextResume(text);
+          importResume(normalizeImportedResume(draft.resume));
+          const guesses = draft.confidence.filter((field) => field.confidence === "low").length;
+          if (guesses > 0) {
+            toast.warning(`${guesses} imported field(s) were guessed; check them before exporting`);
+          }
+        } else {
+          const resume = await parseResume({ format: "text", data: text });
+          importResume(normalizeImportedResume(resume));
+        }
       } else {
         // JSON formats
         const text = await file.text();
@@ -123,6 +154,8 @@ export function ImportModal() {
           if (json.basics && json.meta) {
             // Looks like Reactive Resume V3
             parsed = parseReactiveResumeV3(text);
+          } else if (isStackOverflowJson(json)) {
+            parsed = parseStackOverflow(text);
           } else if (isNativeRustumeJson(json)) {
             // Native Rustume must be detected before JSON Resume (both have `basics`)
             parsed = json as ResumeData;
@@ -137,6 +170,8 @@ export function ImportModal() {
           let serverFormat: ImportFormat = "json-resume";
           if (json.basics && json.meta) {
             serverFormat = "rrv3";
+          } else if (isStackOverflowJson(json)) {
+            serverFormat = "stack-overflow";
           } else if (isNativeRustumeJson(json)) {
             serverFormat = "rustume";
           }
@@ -158,6 +193,7 @@ export function ImportModal() {
       setError(e instanceof Error ? e.message : "Failed to import file");
     } finally {
       setIsLoading(false);
+      setProgress(null);
     }
   };
 
@@ -193,7 +229,7 @@ export function ImportModal() {
       open={isOpen()}
       onOpenChange={(open) => !open && closeModal()}
       title="Import Resume"
-      description="Import from JSON Resume, Reactive Resume, or LinkedIn export"
+      description="Import from JSON Resume, Reactive Resume, LinkedIn, Stack Overflow, or plain text"
     >
       <div class="space-y-4">
         {/* Drop Zone */}
@@ -208,7 +244,7 @@ export function ImportModal() {
         >
           <input
             type="file"
-            accept=".json,.zip"
+            accept=".json,.zip,.xml,.txt"
             onChange={handleFileInput}
             class="absolute inset-0 opacity-0 cursor-pointer"
           />
@@ -230,7 +266,7 @@ export function ImportModal() {
                 Drop your file here, or <span class="text-accent font-medium">browse</span>
               </p>
               <p class="text-sm text-stone mt-1">
-                Supports JSON Resume, Reactive Resume V3, LinkedIn ZIP
+                Supports JSON Resume, Reactive Resume V3, LinkedIn ZIP, Stack Overflow, plain text
               </p>
             </div>
           </div>
@@ -255,7 +291,11 @@ export function ImportModal() {
                 d="M4 12a8 8 0 018-8V0C5.373 0 0 5.373 0 12h4z"
               />
             </svg>
-            <span class="text-sm">Importing...</span>
+            <span class="text-sm">
+              <Show when={progress()} fallback="Importing...">
+                {(p) => `Importing... ${p().percent}%`}
+              </Show>
+            </span>
           </div>
         </Show>
 
@@ -271,7 +311,7 @@ export function ImportModal() {
           <h4 class="font-mono text-xs uppercase tracking-wider text-stone mb-3">
             Supported Formats
           </h4>
-          <div class="grid grid-cols-3 gap-3">
+          <div class="grid grid-cols-2 gap-3">
             <div class="p-3 bg-surface rounded-lg text-center">
               <div class="font-mono text-xs text-accent mb-1">JSON Resume</div>
               <div class="text-xs text-stone">.json</div>
@@ -284,6 +324,14 @@ export function ImportModal() {
               <div class="font-mono text-xs text-accent mb-1">LinkedIn</div>
               <div class="text-xs text-stone">.zip export</div>
             </div>
+            <div class="p-3 bg-surface rounded-lg text-center">
+              <div class="font-mono text-xs text-accent mb-1">Stack Overflow</div>
+              <div class="text-xs text-stone">.xml or .json export</div>
+            </div>
+            <div class="p-3 bg-surface rounded-lg text-center">
+              <div class="font-mono text-xs text-accent mb-1">Plain Text</div>
+              <div class="text-xs text-stone">.txt copied from a PDF</div>
+            </div>
           </div>
         </div>
       </div>
@@ -1,7 +1,14 @@
 import { Show, For, createSignal } from "solid-js";
 import { resumeStore } from "../../stores/resume";
 import { getThemePresets } from "../../stores/themePresets";
-import type { Metadata, PageConfig, ThemePresetInfo } from "../../wasm/types";
+import { Switch } from "../ui";
+import type {
+  Metadata,
+  PageConfig,
+  PageOptions,
+  SectionDisplay,
+  ThemePresetInfo,
+} from "../../wasm/types";
 
 // Must match the templates wired to sidebar-ratio helpers in
 // crates/render/src/typst_engine/templates/<template>.typ.
@@ -29,10 +36,69 @@ const LEVEL_DISPLAY_OPTIONS: { value: Metadata["levelDisplay"]; label: string }[
   { value: "text", label: "Text label" },
 ];
 
+const SECTION_DISPLAY_OPTIONS: { value: SectionDisplay; label: string }[] = [
+  { value: "list", label: "Template default" },
+  { value: "bar-chart", label: "Bar chart" },
+  { value: "dots", label: "Dot ratings" },
+  { value: "tag-cloud", label: "Tag cloud" },
+];
+
+// Must match SECTION_DISPLAY_KEYS in crates/schema/src/metadata.rs
+const SECTION_DISPLAY_SECTIONS = [
+  { key: "skills", label: "Skills display" },
+  { key: "languages", label: "Languages display" },
+] as const;
+
+// Languages with translations for generated text (see rustume-utils `Locale`)
+const LOCALE_OPTIONS: { value: string; label: string }[] = [
+  { value: "en", label: "English" },
+  { value: "de", label: "Deutsch" },
+  { value: "es", label: "Español" },
+  { value: "fr", label: "Français" },
+  { value: "it", label: "Italiano" },
+  { value: "nl", label: "Nederlands" },
+  { value: "pt", label: "Português" },
+  { value: "ar", label: "العربية" },
+  { value: "he", label: "עברית" },
+];
+
+const EMOJI_OPTIONS: { value: NonNullable<Metadata["emoji"]>; label: string }[] = [
+  { value: "preserve", label: "Keep" },
+  { value: "strip", label: "Remove" },
+  { value: "replace", label: "Replace with text" },
+];
+
+type PageNumberOption = "off" | NonNullable<PageOptions["pageNumberStyle"]>;
+
+const PAGE_NUMBER_OPTIONS: { value: PageNumberOption; label: string }[] = [
+  { value: "off", label: "Off" },
+  { value: "plain", label: "2" },
+  { value: "of-total", label: "2 / 3" },
+  { value: "labeled", label: "Page 2 of 3" },
+];
+
+const RUNNING_HEADER_OPTIONS: { value: PageOptions["runningHeader"]; label: string }[] = [
+  { value: "header", label: "Header" },
+  { value: "footer", label: "Footer" },
+  { value: "hidden", label: "Hidden" },
+];
+
+const CONTACT_QR_OPTIONS: { value: PageOptions["contactQr"]; label: string }[] = [
+  { value: "hidden", label: "Hidden" },
+  { value: "header", label: "Header" },
+  { value: "footer", label: "Footer" },
+];
+
 export function ThemeEditor() {
   const { store, updateTheme, updateMetadata } = resumeStore;
   const [activeTab, setActiveTab] = createSignal<"presets" | "custom" | "css">("presets");
 
+  const updatePageOptions = (options: Partial<PageOptions>) => {
+    const page = store.resume?.metadata.page;
+    if (!page) return;
+    updateMetadata("page", { ...page, options: { ...page.options, ...options } });
+  };
+
   // Theme presets are embedded client-side -- no network dependency.
   const presets = getThemePresets();
 
@@ -205,6 +271,190 @@ export function ThemeEditor() {
               </select>
             </div>
 
+            <For each={SECTION_DISPLAY_SECTIONS}>
+              {(section) => (
+                <div class="space-y-2">
+                  <label
+                    for={`section-display-${section.key}`}
+                    class="font-mono text-xs uppercase tracking-wider text-stone block"
+                  >
+                    {section.label}
+                  </label>
+                  <select
+                    id={`section-display-${section.key}`}
+                    value={resume().metadata.sectionDisplay?.[section.key] ?? "list"}
+                    onChange={(e) =>
+                      updateMetadata("sectionDisplay", {
+                        ...resume().metadata.sectionDisplay,
+                        [section.key]: e.currentTarget.value as SectionDisplay,
+                      })
+                    }
+                    class="w-full px-3 py-2 text-sm bg-surface border border-border rounded-lg
+                      focus:outline-none focus:border-accent"
+                  >
+                    <For each={SECTION_DISPLAY_OPTIONS}>
+                      {(option) => <option value={option.value}>{option.label}</option>}
+                    </For>
+                  </select>
+                </div>
+              )}
+            </For>
+
+            <div class="space-y-2">
+              <label
+                for="resume-locale"
+                class="font-mono text-xs uppercase tracking-wider text-stone block"
+              >
+                Resume language
+              </label>
+              <select
+                id="resume-locale"
+                value={(resume().metadata.locale ?? "en").split(/[-_]/)[0].toLowerCase()}
+                onChange={(e) => updateMetadata("locale", e.currentTarget.value)}
+                class="w-full px-3 py-2 text-sm bg-surface border border-border rounded-lg
+                  focus:outline-none focus:border-accent"
+              >
+                <For each={LOCALE_OPTIONS}>
+                  {(option) => <option value={option.value}>{option.label}</option>}
+                </For>
+              </select>
+              <p class="text-xs text-stone">
+                Language of text Rustume adds, such as "Present", durations, and page numbers
+              </p>
+            </div>
+
+            <Switch
+              label="Show durations"
+              description="Add each position's length (2 yrs 3 mos) after its dates"
+              checked={resume().metadata.showDurations ?? false}
+              onChange={(checked) => updateMetadata("showDurations", checked)}
+            />
+
+            <Switch
+              label="Show CEFR levels"
+              description="Add each language's CEFR code (C1) after its proficiency"
+              checked={resume().metadata.showCefr ?? false}
+              onChange={(checked) => updateMetadata("showCefr", checked)}
+            />
+
+            <div class="space-y-2">
+              <label
+                for="emoji-policy"
+                class="font-mono text-xs uppercase tracking-wider text-stone block"
+              >
+                Emoji
+              </label>
+              <select
+                id="emoji-policy"
+                value={resume().metadata.emoji ?? "preserve"}
+                onChange={(e) =>
+                  updateMetadata("emoji", e.currentTarget.value as NonNullable<Metadata["emoji"]>)
+                }
+                class="w-full px-3 py-2 text-sm bg-surface border border-border rounded-lg
+                  focus:outline-none focus:border-accent"
+              >
+                <For each={EMOJI_OPTIONS}>
+                  {(option) => <option value={option.value}>{option.label}</option>}
+                </For>
+              </select>
+              <p class="text-xs text-stone">
+                Many applicant tracking systems misread emoji in the headline and descriptions
+              </p>
+            </div>
+
+            <div class="space-y-2">
+              <label
+                for="page-numbers"
+                class="font-mono text-xs uppercase tracking-wider text-stone block"
+              >
+                Page numbers
+              </label>
+              <select
+                id="page-numbers"
+                value={
+                  resume().metadata.page.options.pageNumbers
+                    ? (resume().metadata.page.options.pageNumberStyle ?? "of-total")
+                    : "off"
+                }
+                onChange={(e) => {
+                  const value = e.currentTarget.value as PageNumberOption;
+                  updatePageOptions(
+                    value === "off"
+                      ? { pageNumbers: false }
+                      : { pageNumbers: true, pageNumberStyle: value },
+                  );
+                }}
+                class="w-full px-3 py-2 text-sm bg-surface border border-border rounded-lg
+                  focus:outline-none focus:border-accent"
+              >
+                <For each={PAGE_NUMBER_OPTIONS}>
+                  {(option) => <option value={option.value}>{option.label}</option>}
+                </For>
+              </select>
+            </div>
+
+            <div class="space-y-2">
+              <label
+                for="running-header"
+                class="font-mono text-xs uppercase tracking-wider text-stone block"
+              >
+                Name on later pages
+              </label>
+              <select
+                id="running-header"
+                value={resume().metadata.page.options.runningHeader ?? "header"}
+                onChange={(e) =>
+                  updatePageOptions({
+                    runningHeader: e.currentTarget.value as PageOptions["runningHeader"],
+                  })
+                }
+                class="w-full px-3 py-2 text-sm bg-surface border border-border rounded-lg
+                  focus:outline-none focus:border-accent"
+              >
+                <For each={RUNNING_HEADER_OPTIONS}>
+                  {(option) => <option value={option.value}>{option.label}</option>}
+                </For>
+              </select>
+            </div>
+
+            <div class="space-y-2">
+              <label
+                for="contact-qr"
+                class="font-mono text-xs uppercase tracking-wider text-stone block"
+              >
+                Contact QR code
+              </label>
+              <select
+                id="contact-qr"
+                value={resume().metadata.page.options.contactQr ?? "hidden"}
+                onChange={(e) =>
+                  updatePageOptions({
+                    contactQr: e.currentTarget.value as PageOptions["contactQr"],
+                  })
+                }
+                class="w-full px-3 py-2 text-sm bg-surface border border-border rounded-lg
+                  focus:outline-none focus:border-accent"
+              >
+                <For each={CONTACT_QR_OPTIONS}>
+                  {(option) => <option value={option.value}>{option.label}</option>}
+                </For>
+              </select>
+            </div>
+
+            <Switch
+              label="Continued headings"
+              description="Repeat a section's heading when it carries over to the next page"
+              checked={resume().metadata.page.options.continuedMarkers ?? true}
+              onChange={(checked) => updatePageOptions({ continuedMarkers: checked })}
+            />
+
+            <Switch
+              label="PDF document info"
+              description="Put your name, headline, and skills in the PDF's title, author, and keywords"
+              checked={resume().metadata.page.options.pdfMetadata ?? true}
+              onChange={(checked) => updatePageOptions({ pdfMetadata: checked })}
+            />
+
             {/* Preview */}
             <div class="p-4 rounded-lg border border-border">
               <div
@@ -70,6 +70,23 @@ describe("ThemeEditor custom CSS tab", () => {
     expect(resumeStore.store.resume?.metadata.levelDisplay).toBe("progress-bar");
     expect(select).toHaveValue("progress-bar");
   });
+
+  it("sets a chart style per section", async () => {
+    render(() => <ThemeEditor />);
+
+    const skills = screen.getByLabelText("Skills display");
+    expect(skills).toHaveValue("list");
+
+    fireEvent.change(skills, { target: { value: "bar-chart" } });
+    fireEvent.change(screen.getByLabelText("Languages display"), {
+      target: { value: "tag-cloud" },
+    });
+
+    expect(resumeStore.store.resume?.metadata.sectionDisplay).toEqual({
+      skills: "bar-chart",
+      languages: "tag-cloud",
+    });
+  });
 });
 
 describe("ThemeEditor sidebar width control", () => {
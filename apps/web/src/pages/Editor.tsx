@@ -67,6 +67,11 @@ const TemplatePicker = lazy(() =>
     default: module.TemplatePicker,
   })),
 );
+const HistoryTimeline = lazy(() =>
+  import("../components/builder/HistoryTimeline").then((module) => ({
+    default: module.HistoryTimeline,
+  })),
+);
 const ImportModal = lazy(() =>
   import("../components/import/ImportModal").then((module) => ({ default: module.ImportModal })),
 );
@@ -100,7 +105,8 @@ type EditorTab =
   | "references"
   | "custom"
   | `custom:${string}`
-  | "theme";
+  | "theme"
+  | "history";
 
 const CUSTOM_ICON = "M12 4v16m8-8H4";
 
@@ -190,6 +196,11 @@ const TABS: SidebarItem[] = [
     label: "Theme",
     icon: "M7 21a4 4 0 01-4-4V5a2 2 0 012-2h4a2 2 0 012 2v12a4 4 0 01-4 4zm0 0h12a2 2 0 002-2v-4a2 2 0 00-2-2h-2.343M11 7.343l1.657-1.657a2 2 0 012.828 0l2.829 2.829a2 2 0 010 2.828l-8.486 8.485M7 17h.01",
   },
+  {
+    id: "history",
+    label: "History",
+    icon: "M12 8v4l3 3m6-3a9 9 0 11-18 0 9 9 0 0118 0z",
+  },
 ];
 
 const CONTENT_TAB_IDS = new Set([
@@ -208,7 +219,7 @@ const CONTENT_TAB_IDS = new Set([
   "volunteer",
   "references",
 ]);
-const SETTINGS_TAB_IDS = new Set(["layout", "theme"]);
+const SETTINGS_TAB_IDS = new Set(["layout", "theme", "history"]);
 const SIDEBAR_GROUP_ORDER = new Map([
   ["Content", 0],
   ["Custom", 1],
@@ -505,6 +516,12 @@ export default function Editor() {
             <ThemeEditor />
           </Suspense>
         );
+      case "history":
+        return (
+          <Suspense fallback={<TabFallback />}>
+            <HistoryTimeline />
+          </Suspense>
+        );
       default:
         if (activeTab().startsWith("custom:")) {
           const sectionId = activeTab().slice("custom:".length);
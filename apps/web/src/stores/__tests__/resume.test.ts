@@ -634,6 +634,13 @@ describe("isNotFoundError", () => {
     expect(isNotFoundError(new ResumeCorruptedError("id"))).toBe(false);
   });
 
+  it("uses the code of WASM storage errors", () => {
+    const coded = (message: string, code: string) => Object.assign(new Error(message), { code });
+    expect(isNotFoundError(coded("Not found: some-resume-id", "STORAGE_NOT_FOUND"))).toBe(true);
+    // The code decides, whatever the message says
+    expect(isNotFoundError(coded("Storage error: key not found", "STORAGE_INTERNAL"))).toBe(false);
+  });
+
   it("returns false for generic errors", () => {
     expect(isNotFoundError(new Error("Storage read failed"))).toBe(false);
     expect(isNotFoundError(new Error("Network timeout"))).toBe(false);
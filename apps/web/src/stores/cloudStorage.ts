@@ -2,10 +2,12 @@ import {
   createCloudResume,
   deleteCloudResume,
   getCloudResume,
+  getCloudResumeHistory,
   listCloudResumes,
   ResumeVersionConflictError,
   updateCloudResume,
   upsertCloudResume,
+  type CloudResumeHistoryEntry,
   type CloudResumeSummary,
 } from "../api/resumes";
 import { ApiError } from "../api/client";
@@ -131,6 +133,18 @@ export async function loadCloudResume(id: string): Promise<ResumeData> {
   }
 }
 
+/** Change log of a cloud resume, oldest first. */
+export async function cloudResumeHistory(id: string): Promise<CloudResumeHistoryEntry[]> {
+  try {
+    return await getCloudResumeHistory(id);
+  } catch (error: unknown) {
+    if (error instanceof ApiError && error.status === 404) {
+      throw new ResumeNotFoundError(id);
+    }
+    throw error;
+  }
+}
+
 export async function saveCloudResume(id: string, data: ResumeData, title?: string): Promise<void> {
   assertCloudWriteAllowed(id);
   const resolvedTitle = title ?? deriveTitleFromResume(data);
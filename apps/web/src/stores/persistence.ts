@@ -6,6 +6,7 @@ import {
   getResume as getFromWasmStorage,
   saveResume as saveToWasmStorage,
   resumeExists as wasmResumeExists,
+  getResumeHistory as getWasmResumeHistory,
   isWasmReady,
 } from "../wasm";
 import { generateId } from "../wasm/types";
@@ -22,6 +23,7 @@ import {
   renameCloudResume,
   duplicateCloudResume,
   cloudResumeExists,
+  cloudResumeHistory,
   saveCloudResume,
   showResumeVersionConflictToast,
 } from "./cloudStorage";
@@ -548,3 +550,32 @@ export async function getStoredResume(id: string): Promise<ResumeData> {
   }
   return getLocalResume(id);
 }
+
+/** One change in a resume's activity timeline. */
+export interface ResumeActivity {
+  action: "created" | "updated" | "deleted" | "restored";
+  summary: string;
+  /** RFC 3339 timestamp. */
+  at: string;
+  /** Who made the change; only known for cloud resumes. */
+  author?: string;
+}
+
+/**
+ * Change log of a resume, newest first. Resumes kept in the localStorage
+ * fallback have no history.
+ */
+export async function getResumeActivity(id: string): Promise<ResumeActivity[]> {
+  let entries: ResumeActivity[] = [];
+  if (isCloudAuthenticated()) {
+    entries = (await cloudResumeHistory(id)).map((entry) => ({
+      action: entry.action,
+      summary: entry.summary,
+      at: entry.at,
+      author: entry.author ?? undefined,
+    }));
+  } else if (isWasmReady()) {
+    entries = await getWasmResumeHistory(id);
+  }
+  return entries.slice().reverse();
+}
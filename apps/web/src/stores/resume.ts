@@ -17,6 +17,7 @@ import {
   getResume as getFromWasmStorage,
   isWasmReady,
 } from "../wasm";
+import { isRustumeError } from "../wasm/types";
 import {
   isCloudAuthenticated,
   isCloudWriteBlockedError,
@@ -69,11 +70,12 @@ export function validateResumeData(parsed: unknown, id: string): ResumeData {
 export function isNotFoundError(error: unknown): boolean {
   if (error instanceof ResumeNotFoundError) return true;
   if (error instanceof ResumeCorruptedError) return false;
+  if (isRustumeError(error)) return error.code === "STORAGE_NOT_FOUND";
   if (error instanceof Error) {
     const msg = error.message.toLowerCase();
     return msg.includes("not found") || msg.includes("notfound") || msg.includes("404");
   }
-  // WASM rejects with plain strings (JsValue::from_str), not Error objects.
+  // Older WASM builds rejected with plain strings rather than coded errors.
   if (typeof error === "string") {
     const msg = error.toLowerCase();
     return msg.includes("not found") || msg.includes("notfound") || msg.includes("404");
@@ -142,6 +142,21 @@ describe("WASM wrapper – fallback behaviour (WASM not loaded)", () => {
     expect(resume.metadata.template).toBe("rhyhorn");
   });
 
+  // -------------------------------------------------------------------
+  // createSectionItem
+  // -------------------------------------------------------------------
+
+  it("createSectionItem returns blank items with the Rust defaults in fallback mode", async () => {
+    const { createSectionItem } = await loadModule();
+    const skill = createSectionItem("skills");
+    const other = createSectionItem("skills");
+
+    expect(skill.id).not.toBe("");
+    expect(skill.id).not.toBe(other.id);
+    expect(skill).toMatchObject({ visible: true, name: "", level: 1, keywords: [] });
+    expect(createSectionItem("custom").url).toEqual({ label: "", href: "" });
+  });
+
   // -------------------------------------------------------------------
   // resumeToJson
   // -------------------------------------------------------------------
@@ -169,12 +184,12 @@ describe("WASM wrapper – fallback behaviour (WASM not loaded)", () => {
   });
 
   // -------------------------------------------------------------------
-  // parseLinkedInExport – throws when WASM not loaded
+  // parseLinkedInExport – rejects when WASM not loaded
   // -------------------------------------------------------------------
 
-  it('parseLinkedInExport throws "WASM not initialized" when not loaded', async () => {
+  it('parseLinkedInExport rejects with "WASM not initialized" when not loaded', async () => {
     const { parseLinkedInExport } = await loadModule();
-    expect(() => parseLinkedInExport(new Uint8Array())).toThrow("WASM not initialized");
+    await expect(parseLinkedInExport(new Uint8Array())).rejects.toThrow("WASM not initialized");
   });
 
   // -------------------------------------------------------------------
@@ -185,4 +200,13 @@ describe("WASM wrapper – fallback behaviour (WASM not loaded)", () => {
     const { parseReactiveResumeV3 } = await loadModule();
     expect(() => parseReactiveResumeV3("{}")).toThrow("WASM not initialized");
   });
+
+  // -------------------------------------------------------------------
+  // parseStackOverflow – throws when WASM not loaded
+  // -------------------------------------------------------------------
+
+  it('parseStackOverflow throws "WASM not initialized" when not loaded', async () => {
+    const { parseStackOverflow } = await loadModule();
+    expect(() => parseStackOverflow("<users />")).toThrow("WASM not initialized");
+  });
 });
@@ -1,5 +1,5 @@
-import type { ResumeData } from "./types";
-import { generateId } from "./types";
+import type { ResumeData, SectionItems } from "./types";
+import { createEmptyUrl, generateId } from "./types";
 
 /**
  * Creates a sample resume with realistic placeholder data.
@@ -236,8 +236,15 @@ export function createSampleResume(): ResumeData {
       page: {
         margin: 18,
         format: "a4",
-        breakLine: true,
-        pageNumbers: true,
+        options: {
+          breakLine: true,
+          pageNumbers: true,
+          pageNumberStyle: "of-total",
+          runningHeader: "header",
+          continuedMarkers: true,
+          contactQr: "hidden",
+          pdfMetadata: true,
+        },
       },
       theme: {
         background: "#ffffff",
@@ -252,12 +259,28 @@ export function createSampleResume(): ResumeData {
           size: 14,
         },
         lineHeight: 1.5,
+        letterSpacing: 0,
+        headingScale: 1,
+        smallCaps: false,
         hideIcons: false,
         underlineLinks: false,
       },
       notes: "",
       levelDisplay: "template-default",
+      sectionDisplay: {},
+      showDurations: false,
+      showCefr: false,
+      locale: "en",
+      emoji: "preserve",
+      templateOptions: {
+        photo: "template-default",
+        headerAlign: "template-default",
+        divider: "template-default",
+        nameOrder: "auto",
+        familyNameCaps: false,
+      },
     },
+    attachments: [],
   };
 }
 
@@ -496,8 +519,15 @@ export function createDefaultResume(): ResumeData {
       page: {
         margin: 18,
         format: "a4",
-        breakLine: true,
-        pageNumbers: true,
+        options: {
+          breakLine: true,
+          pageNumbers: true,
+          pageNumberStyle: "of-total",
+          runningHeader: "header",
+          continuedMarkers: true,
+          contactQr: "hidden",
+          pdfMetadata: true,
+        },
       },
       theme: {
         background: "#ffffff",
@@ -512,11 +542,120 @@ export function createDefaultResume(): ResumeData {
           size: 14,
         },
         lineHeight: 1.5,
+        letterSpacing: 0,
+        headingScale: 1,
+        smallCaps: false,
         hideIcons: false,
         underlineLinks: false,
       },
       notes: "",
       levelDisplay: "template-default",
+      sectionDisplay: {},
+      showDurations: false,
+      showCefr: false,
+      locale: "en",
+      emoji: "preserve",
+      templateOptions: {
+        photo: "template-default",
+        headerAlign: "template-default",
+        divider: "template-default",
+        nameOrder: "auto",
+        familyNameCaps: false,
+      },
     },
+    attachments: [],
   };
 }
+
+const SECTION_ITEM_DEFAULTS: { [K in keyof SectionItems]: () => Omit<SectionItems[K], "id"> } = {
+  experience: () => ({
+    visible: true,
+    company: "",
+    position: "",
+    location: "",
+    date: "",
+    summary: "",
+    url: createEmptyUrl(),
+  }),
+  education: () => ({
+    visible: true,
+    institution: "",
+    area: "",
+    studyType: "",
+    date: "",
+    score: "",
+    summary: "",
+    url: createEmptyUrl(),
+  }),
+  skills: () => ({ visible: true, name: "", description: "", level: 1, keywords: [] }),
+  projects: () => ({
+    visible: true,
+    name: "",
+    description: "",
+    date: "",
+    summary: "",
+    keywords: [],
+    url: createEmptyUrl(),
+  }),
+  profiles: () => ({ visible: true, network: "", username: "", icon: "", url: createEmptyUrl() }),
+  awards: () => ({
+    visible: true,
+    title: "",
+    awarder: "",
+    date: "",
+    summary: "",
+    url: createEmptyUrl(),
+  }),
+  certifications: () => ({
+    visible: true,
+    name: "",
+    issuer: "",
+    date: "",
+    summary: "",
+    url: createEmptyUrl(),
+  }),
+  publications: () => ({
+    visible: true,
+    name: "",
+    publisher: "",
+    date: "",
+    summary: "",
+    url: createEmptyUrl(),
+  }),
+  languages: () => ({ visible: true, name: "", description: "", level: 1 }),
+  interests: () => ({ visible: true, name: "", keywords: [] }),
+  volunteer: () => ({
+    visible: true,
+    organization: "",
+    position: "",
+    location: "",
+    date: "",
+    summary: "",
+    url: createEmptyUrl(),
+  }),
+  references: () => ({
+    visible: true,
+    name: "",
+    description: "",
+    summary: "",
+    url: createEmptyUrl(),
+  }),
+  custom: () => ({
+    visible: true,
+    name: "",
+    description: "",
+    date: "",
+    location: "",
+    summary: "",
+    keywords: [],
+    url: createEmptyUrl(),
+  }),
+};
+
+/**
+ * Creates a blank section item mirroring the Rust defaults.
+ * Used when WASM is not available; `createSectionItem` prefers the WASM constructors.
+ */
+export function createDefaultSectionItem<K extends keyof SectionItems>(key: K): SectionItems[K] {
+  return { id: generateId(), ...SECTION_ITEM_DEFAULTS[key]() } as SectionItems[K];
+}
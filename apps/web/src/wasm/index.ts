@@ -1,5 +1,19 @@
-import type { ResumeData, ValidationResult } from "./types";
-import { createDefaultResume } from "./defaults";
+import type {
+  HistoryEntry,
+  ImportOptions,
+  ImportResult,
+  LayoutPreset,
+  ResumeData,
+  TextDraft,
+  SectionItems,
+  TrashedResume,
+  ValidationIssue,
+  ValidationResult,
+} from "./types";
+import { createDefaultResume, createDefaultSectionItem } from "./defaults";
+import type { LinkedInImportProgress, LinkedInWorkerMessage } from "./linkedin.worker";
+
+export type { LinkedInImportProgress } from "./linkedin.worker";
 
 // Type definitions for WASM module
 interface WasmModule {
@@ -7,22 +21,63 @@ interface WasmModule {
   Storage: new (dbName: string) => WasmStorage;
   parse_json_resume: (input: string) => ResumeData;
   parse_reactive_resume_v3: (input: string) => ResumeData;
-  parse_linkedin_export: (data: Uint8Array) => ResumeData;
+  parse_stack_overflow: (input: string) => ResumeData;
+  parse_text_resume: (input: string) => TextDraft;
+  parse_auto: (data: Uint8Array) => ResumeData;
+  import_resume: (data: Uint8Array, options?: ImportOptions) => ImportResult;
   validate_resume: (input: string) => ValidationResult;
+  validate_resume_detailed: (input: string) => ValidationIssue[];
   create_empty_resume: () => ResumeData;
   resume_to_json: (resume: ResumeData) => string;
+  new_experience: (company: string, position: string) => SectionItems["experience"];
+  new_education: (institution: string, area: string) => SectionItems["education"];
+  new_skill: (name: string) => SectionItems["skills"];
+  new_project: (name: string) => SectionItems["projects"];
+  new_profile: (network: string, username: string) => SectionItems["profiles"];
+  new_award: (title: string) => SectionItems["awards"];
+  new_certification: (name: string, issuer: string) => SectionItems["certifications"];
+  new_publication: (name: string) => SectionItems["publications"];
+  new_language: (name: string) => SectionItems["languages"];
+  new_interest: (name: string) => SectionItems["interests"];
+  new_volunteer: (organization: string, position: string) => SectionItems["volunteer"];
+  new_reference: (name: string) => SectionItems["references"];
+  new_custom_item: (name: string) => SectionItems["custom"];
+  move_layout_section: (
+    resume: ResumeData,
+    section: string,
+    page: number,
+    column: number,
+    index: number,
+  ) => string[][][];
+  normalize_layout: (resume: ResumeData) => string[][][];
+  apply_layout_preset: (resume: ResumeData, preset: LayoutPreset) => ResumeData;
+  check_layout: (resume: ResumeData) => string[];
   list_templates: () => string[];
   get_template_theme_js: (
     template: string,
   ) => { background: string; text: string; primary: string } | null;
 }
 
+// LinkedIn parsers ship in a separate bundle (zip + csv) loaded on first use
+interface LinkedInWasmModule {
+  default: () => Promise<void>;
+  parse_linkedin_export: (
+    data: Uint8Array,
+    onProgress?: (progress: LinkedInImportProgress) => void,
+  ) => ResumeData;
+}
+
 interface WasmStorage {
   list: () => Promise<string[]>;
   get: (id: string) => Promise<ResumeData>;
   save: (id: string, data: ResumeData) => Promise<void>;
   delete: (id: string) => Promise<void>;
   exists: (id: string) => Promise<boolean>;
+  history: (id: string) => Promise<HistoryEntry[]>;
+  list_trashed: () => Promise<TrashedResume[]>;
+  restore: (id: string) => Promise<void>;
+  purge: (id: string) => Promise<void>;
+  purge_expired: (retentionDays?: number) => Promise<number>;
 }
 
 // WASM module - lazily initialized
@@ -30,6 +85,7 @@ let wasmModule: WasmModule | null = null;
 let storageInstance: WasmStorage | null = null;
 let wasmLoadError: Error | null = null;
 let wasmInitPromise: Promise<void> | null = null;
+let linkedInModule: Promise<LinkedInWasmModule> | null = null;
 
 export async function initWasm(): Promise<void> {
   if (wasmModule) return;
@@ -72,6 +128,10 @@ export async function getStorage(): Promise<WasmStorage> {
   }
   if (!storageInstance) {
     storageInstance = new wasmModule.Storage("rustume");
+    // Erase resumes past the trash retention window once per session
+    storageInstance.purge_expired().catch((e: unknown) => {
+      console.warn("Failed to purge expired trash:", e);
+    });
   }
   return storageInstance;
 }
@@ -101,6 +161,26 @@ export async function resumeExists(id: string): Promise<boolean> {
   return storage.exists(id);
 }
 
+export async function getResumeHistory(id: string): Promise<HistoryEntry[]> {
+  const storage = await getStorage();
+  return storage.history(id);
+}
+
+export async function listTrashedResumes(): Promise<TrashedResume[]> {
+  const storage = await getStorage();
+  return storage.list_trashed();
+}
+
+export async function restoreResume(id: string): Promise<void> {
+  const storage = await getStorage();
+  return storage.restore(id);
+}
+
+export async function purgeResume(id: string): Promise<void> {
+  const storage = await getStorage();
+  return storage.purge(id);
+}
+
 // Parser operations
 export function parseJsonResume(input: string): ResumeData {
   if (!wasmModule) {
@@ -116,11 +196,85 @@ export function parseReactiveResumeV3(input: string): ResumeData {
   return wasmModule.parse_reactive_resume_v3(input);
 }
 
-export function parseLinkedInExport(data: Uint8Array): ResumeData {
+export function parseStackOverflow(input: string): ResumeData {
   if (!wasmModule) {
     throw new Error("WASM not initialized");
   }
-  return wasmModule.parse_linkedin_export(data);
+  return wasmModule.parse_stack_overflow(input);
+}
+
+export function parseTextResume(input: string): TextDraft {
+  if (!wasmModule) {
+    throw new Error("WASM not initialized");
+  }
+  return wasmModule.parse_text_resume(input);
+}
+
+export function parseAuto(data: Uint8Array): ResumeData {
+  if (!wasmModule) {
+    throw new Error("WASM not initialized");
+  }
+  return wasmModule.parse_auto(data);
+}
+
+export function importResume(data: Uint8Array, options?: ImportOptions): ImportResult {
+  if (!wasmModule) {
+    throw new Error("WASM not initialized");
+  }
+  return wasmModule.import_resume(data, options);
+}
+
+async function loadLinkedInModule(): Promise<LinkedInWasmModule> {
+  if (!linkedInModule) {
+    const path = "/wasm/linkedin/rustume_wasm_linkedin.js";
+    linkedInModule = (async () => {
+      const wasm = (await import(/* @vite-ignore */ path)) as unknown as LinkedInWasmModule;
+      await wasm.default();
+      return wasm;
+    })().catch((e) => {
+      // Allow a retry, e.g. after a network blip
+      linkedInModule = null;
+      throw e;
+    });
+  }
+  return linkedInModule;
+}
+
+// Parse in a worker when the browser has them, so large exports don't freeze
+// the page. The bytes are transferred, so `data` is detached afterwards.
+export async function parseLinkedInExport(
+  data: Uint8Array,
+  onProgress?: (progress: LinkedInImportProgress) => void,
+): Promise<ResumeData> {
+  if (!wasmModule) {
+    throw new Error("WASM not initialized");
+  }
+  if (typeof Worker === "undefined") {
+    const linkedIn = await loadLinkedInModule();
+    return linkedIn.parse_linkedin_export(data, onProgress);
+  }
+
+  const worker = new Worker(new URL("./linkedin.worker.ts", import.meta.url), {
+    type: "module",
+  });
+  try {
+    return await new Promise<ResumeData>((resolve, reject) => {
+      worker.onmessage = (event: MessageEvent<LinkedInWorkerMessage>) => {
+        const message = event.data;
+        if (message.type === "progress") {
+          onProgress?.(message);
+        } else if (message.type === "done") {
+          resolve(message.resume);
+        } else {
+          reject(new Error(message.message));
+        }
+      };
+      worker.onerror = (event) => reject(new Error(event.message || "LinkedIn import failed"));
+      worker.postMessage(data, [data.buffer as ArrayBuffer]);
+    });
+  } finally {
+    worker.terminate();
+  }
 }
 
 // Utility operations
@@ -131,6 +285,14 @@ export function validateResume(input: string): ValidationResult {
   return wasmModule.validate_resume(input);
 }
 
+/** Every problem with the resume, with the field path to attach it to. */
+export function validateResumeDetailed(input: string): ValidationIssue[] {
+  if (!wasmModule) {
+    throw new Error("WASM not initialized");
+  }
+  return wasmModule.validate_resume_detailed(input);
+}
+
 export function createEmptyResume(): ResumeData {
   if (wasmModule) {
     return wasmModule.create_empty_resume();
@@ -139,6 +301,33 @@ export function createEmptyResume(): ResumeData {
   return createDefaultResume();
 }
 
+// Blank items come from the Rust constructors so defaults and IDs match the core
+const SECTION_ITEM_CONSTRUCTORS: {
+  [K in keyof SectionItems]: (wasm: WasmModule) => SectionItems[K];
+} = {
+  experience: (wasm) => wasm.new_experience("", ""),
+  education: (wasm) => wasm.new_education("", ""),
+  skills: (wasm) => wasm.new_skill(""),
+  projects: (wasm) => wasm.new_project(""),
+  profiles: (wasm) => wasm.new_profile("", ""),
+  awards: (wasm) => wasm.new_award(""),
+  certifications: (wasm) => wasm.new_certification("", ""),
+  publications: (wasm) => wasm.new_publication(""),
+  languages: (wasm) => wasm.new_language(""),
+  interests: (wasm) => wasm.new_interest(""),
+  volunteer: (wasm) => wasm.new_volunteer("", ""),
+  references: (wasm) => wasm.new_reference(""),
+  custom: (wasm) => wasm.new_custom_item(""),
+};
+
+export function createSectionItem<K extends keyof SectionItems>(key: K): SectionItems[K] {
+  if (wasmModule) {
+    return SECTION_ITEM_CONSTRUCTORS[key](wasmModule);
+  }
+  // Fallback when WASM not available
+  return createDefaultSectionItem(key);
+}
+
 export function resumeToJson(resume: ResumeData): string {
   if (wasmModule) {
     return wasmModule.resume_to_json(resume);
@@ -147,6 +336,41 @@ export function resumeToJson(resume: ResumeData): string {
   return JSON.stringify(resume, null, 2);
 }
 
+// Layout operations
+export function moveLayoutSection(
+  resume: ResumeData,
+  section: string,
+  page: number,
+  column: number,
+  index: number,
+): string[][][] {
+  if (!wasmModule) {
+    throw new Error("WASM not initialized");
+  }
+  return wasmModule.move_layout_section(resume, section, page, column, index);
+}
+
+export function normalizeLayout(resume: ResumeData): string[][][] {
+  if (!wasmModule) {
+    throw new Error("WASM not initialized");
+  }
+  return wasmModule.normalize_layout(resume);
+}
+
+export function applyLayoutPreset(resume: ResumeData, preset: LayoutPreset): ResumeData {
+  if (!wasmModule) {
+    throw new Error("WASM not initialized");
+  }
+  return wasmModule.apply_layout_preset(resume, preset);
+}
+
+export function checkLayout(resume: ResumeData): string[] {
+  if (!wasmModule) {
+    throw new Error("WASM not initialized");
+  }
+  return wasmModule.check_layout(resume);
+}
+
 // Template operations
 //
 // Fallback defaults below mirror the WASM module (crates/render).
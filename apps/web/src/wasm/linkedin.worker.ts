@@ -0,0 +1,55 @@
+// Runs LinkedIn imports off the main thread. The page transfers the ZIP bytes
+// in; the worker loads the LinkedIn bundle, reports progress while it reads the
+// archive, and posts back the parsed resume (or an error message).
+import type { ResumeData } from "./types";
+
+export interface LinkedInImportProgress {
+  phase: "read" | "validate" | "convert";
+  percent: number;
+  /** CSV file being read, during the validate phase */
+  detail?: string;
+}
+
+export type LinkedInWorkerMessage =
+  | ({ type: "progress" } & LinkedInImportProgress)
+  | { type: "done"; resume: ResumeData }
+  | { type: "error"; message: string };
+
+interface LinkedInWorkerModule {
+  default: () => Promise<void>;
+  parse_linkedin_export_async: (
+    data: Uint8Array,
+    onProgress?: (progress: LinkedInImportProgress) => void,
+  ) => Promise<ResumeData>;
+}
+
+let linkedIn: Promise<LinkedInWorkerModule> | null = null;
+
+function load(): Promise<LinkedInWorkerModule> {
+  if (!linkedIn) {
+    const path = "/wasm/linkedin/rustume_wasm_linkedin.js";
+    linkedIn = (async () => {
+      const wasm = (await import(/* @vite-ignore */ path)) as unknown as LinkedInWorkerModule;
+      await wasm.default();
+      return wasm;
+    })().catch((e) => {
+      linkedIn = null;
+      throw e;
+    });
+  }
+  return linkedIn;
+}
+
+const post = (message: LinkedInWorkerMessage) => self.postMessage(message);
+
+self.onmessage = async (event: MessageEvent<Uint8Array>) => {
+  try {
+    const wasm = await load();
+    const resume = await wasm.parse_linkedin_export_async(event.data, (progress) =>
+      post({ type: "progress", ...progress }),
+    );
+    post({ type: "done", resume });
+  } catch (e) {
+    post({ type: "error", message: e instanceof Error ? e.message : String(e) });
+  }
+};
@@ -1,244 +1,136 @@
-// TypeScript types matching the Rust ResumeData schema
-
-export interface Url {
-  label: string;
-  href: string;
-}
-
-export interface CustomField {
-  id: string;
-  icon: string;
-  name: string;
-  value: string;
-}
-
-export interface Picture {
-  url: string;
-  size: number;
-  aspectRatio: number;
-  borderRadius: number;
-  effects: {
-    hidden: boolean;
-    border: boolean;
-    grayscale: boolean;
-    rotation: number;
-    borderColor: string;
-    borderWidth: number;
-    shadowColor: string;
-    shadowSize: number;
-  };
-}
-
-export interface Basics {
-  name: string;
-  headline: string;
-  email: string;
-  phone: string;
-  location: string;
-  url: Url;
-  customFields: CustomField[];
-  picture: Picture;
-}
-
-export interface SummarySection {
-  id: string;
-  name: string;
-  columns: number;
-  separateLinks: boolean;
-  visible: boolean;
-  content: string;
-}
-
-export interface CoverLetterRecipient {
-  name: string;
-  title: string;
-  company: string;
-  address: string;
-  email: string;
-}
-
-export interface CoverLetterSection {
-  id: string;
-  name: string;
-  visible: boolean;
-  recipient: CoverLetterRecipient;
-  content: string;
-}
-
-export interface Section<T> {
-  id: string;
-  name: string;
-  columns: number;
-  separateLinks: boolean;
-  visible: boolean;
-  items: T[];
-}
-
-export interface Experience {
-  id: string;
-  visible: boolean;
-  company: string;
-  position: string;
-  location: string;
-  date: string;
-  summary: string;
-  url: Url;
-}
-
-export interface Education {
-  id: string;
-  visible: boolean;
-  institution: string;
-  area: string;
-  studyType: string;
-  date: string;
-  score: string;
-  summary: string;
-  url: Url;
-}
-
-export interface Skill {
-  id: string;
-  visible: boolean;
-  name: string;
-  description: string;
-  level: number;
-  keywords: string[];
-}
-
-export interface Project {
-  id: string;
-  visible: boolean;
-  name: string;
-  description: string;
-  date: string;
-  summary: string;
-  keywords: string[];
-  url: Url;
-}
-
-export interface Profile {
-  id: string;
-  visible: boolean;
-  network: string;
-  username: string;
-  icon: string;
-  url: Url;
-}
-
-export interface Award {
-  id: string;
-  visible: boolean;
-  title: string;
-  awarder: string;
-  date: string;
-  summary: string;
-  url: Url;
-}
-
-export interface Certification {
-  id: string;
-  visible: boolean;
-  name: string;
-  issuer: string;
-  date: string;
+// Schema types are generated from the Rust types into bindings/wasm/schema.d.ts,
+// which also ships in the WASM package; regenerate it with
+// `RUSTUME_UPDATE_TS=1 cargo test -p rustume-schema typescript`.
+// This file adds the types that only exist on the JS side.
+
+export type {
+  ApplicationStatus,
+  Attachment,
+  AttachmentMode,
+  Award,
+  Basics,
+  CefrLevel,
+  Certification,
+  ContentSuggestion,
+  CoverLetterRecipient,
+  CoverLetterSection,
+  CustomCss,
+  CustomField,
+  CustomFieldKind,
+  CustomItem,
+  DividerStyle,
+  DuplicateCandidate,
+  Education,
+  EmojiPolicy,
+  EmploymentGap,
+  ErrorCode,
+  Experience,
+  FontConfig,
+  HeaderAlign,
+  Interest,
+  JobApplication,
+  Language,
+  Layout,
+  LayoutPreset,
+  LevelDisplay,
+  MergedImport,
+  MergeEntry,
+  MergeReport,
+  MergeStrategy,
+  Metadata,
+  NameOrder,
+  PageConfig,
+  PageFormat,
+  PageNumberStyle,
+  PageOptions,
+  PersonName,
+  PhotoPosition,
+  Picture,
+  PictureEffects,
+  PositionDuration,
+  Profile,
+  Project,
+  Publication,
+  Reference,
+  RepairFix,
+  ResumeData,
+  ResumeStats,
+  RuleReport,
+  RunningHeader,
+  RustumeError,
+  Section,
+  SectionDisplay,
+  Sections,
+  Severity,
+  Skill,
+  SummarySection,
+  TemplateOptions,
+  Theme,
+  Typography,
+  Url,
+  ValidationIssue,
+  Volunteer,
+} from "../../../../bindings/wasm/schema";
+
+import type {
+  Award,
+  Certification,
+  CustomItem,
+  DuplicateCandidate,
+  Education,
+  ErrorCode,
+  Experience,
+  Interest,
+  Language,
+  PhotoPosition,
+  Picture,
+  Profile,
+  Project,
+  Publication,
+  Reference,
+  RepairFix,
+  ResumeData,
+  RustumeError,
+  Skill,
+  Theme,
+  Url,
+  ValidationIssue,
+  Volunteer,
+} from "../../../../bindings/wasm/schema";
+
+/** The item type of each section, with `custom` for custom sections. */
+export interface SectionItems {
+  experience: Experience;
+  education: Education;
+  skills: Skill;
+  projects: Project;
+  profiles: Profile;
+  awards: Award;
+  certifications: Certification;
+  publications: Publication;
+  languages: Language;
+  interests: Interest;
+  volunteer: Volunteer;
+  references: Reference;
+  custom: CustomItem;
+}
+
+/** One entry of a locally stored resume's change log. */
+export interface HistoryEntry {
+  resumeId: string;
+  /** RFC 3339 timestamp in UTC. */
+  at: string;
+  author?: string;
+  action: "created" | "updated" | "deleted" | "restored";
   summary: string;
-  url: Url;
 }
 
-export interface Publication {
+/** A deleted resume kept in local storage until restored or purged. */
+export interface TrashedResume {
   id: string;
-  visible: boolean;
-  name: string;
-  publisher: string;
-  date: string;
-  summary: string;
-  url: Url;
-}
-
-export interface Language {
-  id: string;
-  visible: boolean;
-  name: string;
-  description: string;
-  level: number;
-}
-
-export interface Interest {
-  id: string;
-  visible: boolean;
-  name: string;
-  keywords: string[];
-}
-
-export interface Volunteer {
-  id: string;
-  visible: boolean;
-  organization: string;
-  position: string;
-  location: string;
-  date: string;
-  summary: string;
-  url: Url;
-}
-
-export interface Reference {
-  id: string;
-  visible: boolean;
-  name: string;
-  description: string;
-  summary: string;
-  url: Url;
-}
-
-export interface CustomItem {
-  id: string;
-  visible: boolean;
-  name: string;
-  description: string;
-  date: string;
-  location: string;
-  summary: string;
-  keywords: string[];
-  url: Url;
-}
-
-export interface Sections {
-  summary: SummarySection;
-  coverLetter: CoverLetterSection;
-  experience: Section<Experience>;
-  education: Section<Education>;
-  skills: Section<Skill>;
-  projects: Section<Project>;
-  profiles: Section<Profile>;
-  awards: Section<Award>;
-  certifications: Section<Certification>;
-  publications: Section<Publication>;
-  languages: Section<Language>;
-  interests: Section<Interest>;
-  volunteer: Section<Volunteer>;
-  references: Section<Reference>;
-  custom: Record<string, Section<CustomItem>>;
-}
-
-export interface CustomCss {
-  value: string;
-  visible: boolean;
-}
-
-export interface PageConfig {
-  margin: number;
-  format: "a4" | "letter";
-  sidebarRatio?: number;
-  breakLine: boolean;
-  pageNumbers: boolean;
-}
-
-export interface Theme {
-  preset?: string;
-  background: string;
-  text: string;
-  primary: string;
+  /** RFC 3339 timestamp in UTC. */
+  trashedAt: string;
+  resume: ResumeData;
 }
 
 export interface ThemePresetInfo {
@@ -252,53 +144,74 @@ export interface ThemePresetInfo {
   };
 }
 
-export interface Typography {
-  font: {
-    family: string;
-    subset: string;
-    variants: string[];
-    size: number;
+export interface TemplateInfo {
+  id: string;
+  name: string;
+  theme: Theme;
+  /** Template options this template honors; the rest are ignored with a warning. */
+  options?: {
+    photo: PhotoPosition[];
+    headerAlign: boolean;
+    divider: boolean;
   };
-  lineHeight: number;
-  hideIcons: boolean;
-  underlineLinks: boolean;
 }
 
-export type LevelDisplay =
-  | "template-default"
-  | "hidden"
-  | "circle"
-  | "square"
-  | "progress-bar"
-  | "text";
+export interface ValidationResult {
+  valid: boolean;
+  errors?: string[];
+}
 
-export interface Metadata {
-  template: string;
-  layout: string[][][];
-  css: CustomCss;
-  page: PageConfig;
-  theme: Theme;
-  typography: Typography;
-  notes: string;
-  /** Optional: resumes stored before this field existed lack it. */
-  levelDisplay?: LevelDisplay;
+/** How sure the plain-text importer is about a guessed field. */
+export type Confidence = "low" | "medium" | "high";
+
+export interface FieldConfidence {
+  /** Field path, e.g. `sections.experience.items[0].company` */
+  path: string;
+  confidence: Confidence;
+  reason: string;
 }
 
-export interface ResumeData {
-  basics: Basics;
-  sections: Sections;
-  metadata: Metadata;
+/** A resume recovered from plain text, with the confidence of each guess. */
+export interface TextDraft {
+  resume: ResumeData;
+  confidence: FieldConfidence[];
 }
 
-export interface TemplateInfo {
-  id: string;
-  name: string;
-  theme: Theme;
+/** A format the import pipeline reads. `linked-in` needs the LinkedIn build. */
+export type ImportFormat =
+  | "json-resume"
+  | "linked-in"
+  | "rrv3"
+  | "stack-overflow"
+  | "html"
+  | "vcard"
+  | "text"
+  | "rustume";
+
+/** Options of `importResume`; every field is optional. */
+export interface ImportOptions {
+  /** Parse as this format instead of detecting it; required for `text`. */
+  format?: ImportFormat;
+  /** Locale to write normalized dates in, e.g. `de`. */
+  locale?: string;
+  /** `stable` derives item IDs from their content, so re-imports match. */
+  ids?: "random" | "stable";
+  /** Keep a resume that fails validation and report its errors instead. */
+  lenient?: boolean;
 }
 
-export interface ValidationResult {
-  valid: boolean;
-  errors?: string[];
+/** What an import changed on the way from the input to the resume. */
+export interface ImportReport {
+  format: ImportFormat;
+  confidence: number;
+  changes: RepairFix[];
+  duplicates: DuplicateCandidate[];
+  issues: ValidationIssue[];
+}
+
+export interface ImportResult {
+  resume: ResumeData;
+  report: ImportReport;
 }
 
 // Helper to create default empty values
@@ -325,6 +238,15 @@ export function createEmptyPicture(): Picture {
   };
 }
 
+/** Whether `error` was thrown by the WASM module, with `code` when given. */
+export function isRustumeError(error: unknown, code?: ErrorCode): error is Error & RustumeError {
+  return (
+    error instanceof Error &&
+    typeof (error as Partial<RustumeError>).code === "string" &&
+    (code === undefined || (error as Partial<RustumeError>).code === code)
+  );
+}
+
 export function generateId(): string {
   return crypto.randomUUID();
 }
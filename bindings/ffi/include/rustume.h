@@ -0,0 +1,147 @@
+/* Rustume C API. Generated by cbindgen from bindings/ffi; do not edit by hand. */
+
+#ifndef RUSTUME_H
+#define RUSTUME_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// Input format: JSON Resume (jsonresume.org).
+#define RUSTUME_FORMAT_JSON_RESUME 0
+
+// Input format: LinkedIn data export ZIP.
+#define RUSTUME_FORMAT_LINKEDIN 1
+
+// Input format: Reactive Resume v3 JSON export.
+#define RUSTUME_FORMAT_RRV3 2
+
+// Input format: native Rustume JSON.
+#define RUSTUME_FORMAT_RUSTUME 3
+
+// Input format: Stack Overflow data export (XML or JSON).
+#define RUSTUME_FORMAT_STACK_OVERFLOW 4
+
+// Result code returned by every `rustume_*` operation.
+typedef enum RustumeStatus {
+  // The call succeeded.
+  RUSTUME_STATUS_OK = 0,
+  // A required pointer argument was null.
+  RUSTUME_STATUS_NULL_POINTER = 1,
+  // An argument was out of range (e.g. unknown format) or not UTF-8 JSON.
+  RUSTUME_STATUS_INVALID_ARGUMENT = 2,
+  // The input could not be parsed.
+  RUSTUME_STATUS_PARSE_ERROR = 3,
+  // The resume failed schema validation.
+  RUSTUME_STATUS_VALIDATION_FAILED = 4,
+  // Rendering failed.
+  RUSTUME_STATUS_RENDER_ERROR = 5,
+  // The input exceeded a safety limit (archive size, entry count, ...).
+  RUSTUME_STATUS_LIMIT_EXCEEDED = 6,
+  // Rustume panicked; the error message has details.
+  RUSTUME_STATUS_PANIC = 7,
+} RustumeStatus;
+
+// A byte buffer allocated by Rustume.
+//
+// Release with [`rustume_buffer_free`]. An empty buffer has a null `data`
+// pointer and a zero `len`.
+typedef struct RustumeBuffer {
+  // Pointer to the first byte, or null when empty.
+  uint8_t *data;
+  // Number of bytes at `data`.
+  size_t len;
+} RustumeBuffer;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Return the library version as a static NUL-terminated string.
+const char *rustume_version(void);
+
+// Return the last error message for the calling thread, or null if none.
+//
+// The pointer stays valid until the next `rustume_*` call on the same thread.
+// Do not free it.
+const char *rustume_last_error_message(void);
+
+// Release a buffer returned by Rustume. Passing an empty buffer is a no-op.
+//
+// # Safety
+// `buffer` must have been produced by this library and not freed before.
+void rustume_buffer_free(struct RustumeBuffer buffer);
+
+// Parse `input` in the given `RUSTUME_FORMAT_*` into Rustume JSON.
+//
+// On success `out_json` receives UTF-8 JSON in the Rustume schema.
+//
+// # Safety
+// `input` must point to `input_len` readable bytes (or be null with length 0)
+// and `out_json` must point to a writable `RustumeBuffer`.
+enum RustumeStatus rustume_parse(uint32_t format,
+                                 const uint8_t *input_ptr,
+                                 size_t input_len,
+                                 struct RustumeBuffer *out_json);
+
+// Validate Rustume JSON against the schema.
+//
+// `out_report` always receives a JSON report of the form
+// `{"valid": bool, "errors": [..], "warnings": [..]}` (empty arrays omitted),
+// including when the status is `RUSTUME_STATUS_VALIDATION_FAILED`.
+//
+// # Safety
+// `json` must point to `json_len` readable bytes and `out_report` must point
+// to a writable `RustumeBuffer`.
+enum RustumeStatus rustume_validate(const uint8_t *json,
+                                    size_t json_len,
+                                    struct RustumeBuffer *out_report);
+
+#if defined(RUSTUME_FEATURE_RENDER)
+// Render Rustume JSON to a PDF document.
+//
+// # Safety
+// `json` must point to `json_len` readable bytes and `out_pdf` must point
+// to a writable `RustumeBuffer`.
+enum RustumeStatus rustume_render_pdf(const uint8_t *json,
+                                      size_t json_len,
+                                      struct RustumeBuffer *out_pdf);
+#endif
+
+#if defined(RUSTUME_FEATURE_RENDER)
+// Render one page (zero-based) of Rustume JSON to a PNG image.
+//
+// `out_total_pages` may be null; otherwise it receives the page count.
+//
+// # Safety
+// `json` must point to `json_len` readable bytes, `out_png` must point to
+// a writable `RustumeBuffer`, and `out_total_pages` must be null or writable.
+enum RustumeStatus rustume_render_png(const uint8_t *json,
+                                      size_t json_len,
+                                      uint32_t page,
+                                      struct RustumeBuffer *out_png,
+                                      uint32_t *out_total_pages);
+#endif
+
+#if defined(RUSTUME_FEATURE_RENDER)
+// Render one page (zero-based) of Rustume JSON to a UTF-8 SVG document.
+//
+// `out_total_pages` may be null; otherwise it receives the page count.
+//
+// # Safety
+// `json` must point to `json_len` readable bytes, `out_svg` must point to
+// a writable `RustumeBuffer`, and `out_total_pages` must be null or writable.
+enum RustumeStatus rustume_render_svg(const uint8_t *json,
+                                      size_t json_len,
+                                      uint32_t page,
+                                      struct RustumeBuffer *out_svg,
+                                      uint32_t *out_total_pages);
+#endif
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* RUSTUME_H */
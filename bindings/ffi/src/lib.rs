@@ -0,0 +1,582 @@
+//! C ABI bindings for Rustume.
+//!
+//! This crate exposes parsing, validation, and rendering to C-compatible
+//! callers (ctypes, cffi, P/Invoke, JNI, ...). The generated header lives at
+//! `bindings/ffi/include/rustume.h`.
+//!
+//! # Conventions
+//!
+//! - Every operation returns a [`RustumeStatus`]; `RUSTUME_STATUS_OK` is `0`.
+//! - Inputs are `(pointer, length)` byte buffers owned by the caller. Resume
+//!   data is always UTF-8 JSON in the Rustume schema.
+//! - Outputs are written to a caller-provided [`RustumeBuffer`]. The bytes are
+//!   owned by Rustume and must be released with [`rustume_buffer_free`].
+//! - On failure, [`rustume_last_error_message`] returns a description of the
+//!   most recent error on the calling thread.
+//! - Panics never cross the FFI boundary; they surface as
+//!   `RUSTUME_STATUS_PANIC`.
+
+use std::cell::RefCell;
+use std::ffi::{c_char, CString};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::{ptr, slice};
+
+use rustume_parser::{parse_resume, ParseError, ResumeFormat};
+use rustume_schema::{validation_messages, ResumeData};
+use serde::Serialize;
+use validator::Validate;
+
+/// Input format: JSON Resume (jsonresume.org).
+pub const RUSTUME_FORMAT_JSON_RESUME: u32 = 0;
+/// Input format: LinkedIn data export ZIP.
+pub const RUSTUME_FORMAT_LINKEDIN: u32 = 1;
+/// Input format: Reactive Resume v3 JSON export.
+pub const RUSTUME_FORMAT_RRV3: u32 = 2;
+/// Input format: native Rustume JSON.
+pub const RUSTUME_FORMAT_RUSTUME: u32 = 3;
+/// Input format: Stack Overflow data export (XML or JSON).
+pub const RUSTUME_FORMAT_STACK_OVERFLOW: u32 = 4;
+
+/// Result code returned by every `rustume_*` operation.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RustumeStatus {
+    /// The call succeeded.
+    Ok = 0,
+    /// A required pointer argument was null.
+    NullPointer = 1,
+    /// An argument was out of range (e.g. unknown format) or not UTF-8 JSON.
+    InvalidArgument = 2,
+    /// The input could not be parsed.
+    ParseError = 3,
+    /// The resume failed schema validation.
+    ValidationFailed = 4,
+    /// Rendering failed.
+    RenderError = 5,
+    /// The input exceeded a safety limit (archive size, entry count, ...).
+    LimitExceeded = 6,
+    /// Rustume panicked; the error message has details.
+    Panic = 7,
+}
+
+/// A byte buffer allocated by Rustume.
+///
+/// Release with [`rustume_buffer_free`]. An empty buffer has a null `data`
+/// pointer and a zero `len`.
+#[repr(C)]
+#[derive(Debug)]
+pub struct RustumeBuffer {
+    /// Pointer to the first byte, or null when empty.
+    pub data: *mut u8,
+    /// Number of bytes at `data`.
+    pub len: usize,
+}
+
+impl RustumeBuffer {
+    fn empty() -> Self {
+        Self {
+            data: ptr::null_mut(),
+            len: 0,
+        }
+    }
+
+    fn from_vec(bytes: Vec<u8>) -> Self {
+        if bytes.is_empty() {
+            return Self::empty();
+        }
+        let boxed = bytes.into_boxed_slice();
+        let len = boxed.len();
+        Self {
+            data: Box::into_raw(boxed).cast::<u8>(),
+            len,
+        }
+    }
+}
+
+/// Error raised inside a binding before it is converted to a status code.
+struct FfiError {
+    status: RustumeStatus,
+    message: String,
+}
+
+impl FfiError {
+    fn new(status: RustumeStatus, message: impl Into<String>) -> Self {
+        Self {
+            status,
+            message: message.into(),
+        }
+    }
+}
+
+impl From<ParseError> for FfiError {
+    fn from(err: ParseError) -> Self {
+        let status = match err {
+            ParseError::LimitExceeded(_) => RustumeStatus::LimitExceeded,
+            _ => RustumeStatus::ParseError,
+        };
+        Self::new(status, err.to_string())
+    }
+}
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+fn set_last_error(message: &str) {
+    // Interior NULs would truncate the C string; replace them instead.
+    let message = CString::new(message.replace('\0', " ")).unwrap_or_default();
+    LAST_ERROR.with(|slot| *slot.borrow_mut() = Some(message));
+}
+
+fn clear_last_error() {
+    LAST_ERROR.with(|slot| *slot.borrow_mut() = None);
+}
+
+/// Borrow a caller-provided input buffer.
+///
+/// # Safety
+/// `data` must be null or point to `len` readable bytes that outlive the call.
+unsafe fn input<'a>(data: *const u8, len: usize) -> Result<&'a [u8], FfiError> {
+    if data.is_null() {
+        if len == 0 {
+            return Ok(&[]);
+        }
+        return Err(FfiError::new(
+            RustumeStatus::NullPointer,
+            "input pointer is null",
+        ));
+    }
+    Ok(slice::from_raw_parts(data, len))
+}
+
+/// Run `body`, write its output to `out`, and translate errors and panics.
+///
+/// `body` returns the status to report alongside the output so that
+/// operations such as validation can return a report with a non-OK status.
+///
+/// # Safety
+/// `out` must be null or point to writable memory for one `RustumeBuffer`.
+unsafe fn run(
+    out: *mut RustumeBuffer,
+    body: impl FnOnce() -> Result<(RustumeStatus, Vec<u8>), FfiError>,
+) -> RustumeStatus {
+    if out.is_null() {
+        set_last_error("output buffer pointer is null");
+        return RustumeStatus::NullPointer;
+    }
+    out.write(RustumeBuffer::empty());
+
+    let result = catch_unwind(AssertUnwindSafe(body)).unwrap_or_else(|panic| {
+        let message = panic
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| panic.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown panic".to_string());
+        Err(FfiError::new(
+            RustumeStatus::Panic,
+            format!("panic: {message}"),
+        ))
+    });
+
+    match result {
+        Ok((status, bytes)) => {
+            if status == RustumeStatus::Ok {
+                clear_last_error();
+            }
+            out.write(RustumeBuffer::from_vec(bytes));
+            status
+        }
+        Err(err) => {
+            set_last_error(&err.message);
+            err.status
+        }
+    }
+}
+
+fn format_from_code(format: u32) -> Result<ResumeFormat, FfiError> {
+    match format {
+        RUSTUME_FORMAT_JSON_RESUME => Ok(ResumeFormat::JsonResume),
+        RUSTUME_FORMAT_LINKEDIN => Ok(ResumeFormat::LinkedIn),
+        RUSTUME_FORMAT_RRV3 => Ok(ResumeFormat::Rrv3),
+        RUSTUME_FORMAT_RUSTUME => Ok(ResumeFormat::Rustume),
+        RUSTUME_FORMAT_STACK_OVERFLOW => Ok(ResumeFormat::StackOverflow),
+        other => Err(FfiError::new(
+            RustumeStatus::InvalidArgument,
+            format!("unknown format code {other}"),
+        )),
+    }
+}
+
+fn resume_from_json(json: &[u8]) -> Result<ResumeData, FfiError> {
+    serde_json::from_slice(json).map_err(|e| {
+        FfiError::new(
+            RustumeStatus::InvalidArgument,
+            format!("invalid resume JSON: {e}"),
+        )
+    })
+}
+
+fn to_json(value: &impl Serialize) -> Result<Vec<u8>, FfiError> {
+    serde_json::to_vec(value).map_err(|e| {
+        FfiError::new(
+            RustumeStatus::ParseError,
+            format!("failed to serialize: {e}"),
+        )
+    })
+}
+
+/// JSON report written by [`rustume_validate`].
+#[derive(Serialize)]
+struct ValidationReport {
+    valid: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    errors: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    warnings: Vec<String>,
+}
+
+/// Return the library version as a static NUL-terminated string.
+#[no_mangle]
+pub extern "C" fn rustume_version() -> *const c_char {
+    concat!(env!("CARGO_PKG_VERSION"), "\0")
+        .as_ptr()
+        .cast::<c_char>()
+}
+
+/// Return the last error message for the calling thread, or null if none.
+///
+/// The pointer stays valid until the next `rustume_*` call on the same thread.
+/// Do not free it.
+#[no_mangle]
+pub extern "C" fn rustume_last_error_message() -> *const c_char {
+    LAST_ERROR.with(|slot| {
+        slot.borrow()
+            .as_ref()
+            .map_or(ptr::null(), |message| message.as_ptr())
+    })
+}
+
+/// Release a buffer returned by Rustume. Passing an empty buffer is a no-op.
+///
+/// # Safety
+/// `buffer` must have been produced by this library and not freed before.
+#[no_mangle]
+pub unsafe extern "C" fn rustume_buffer_free(buffer: RustumeBuffer) {
+    if buffer.data.is_null() {
+        return;
+    }
+    drop(Box::from_raw(ptr::slice_from_raw_parts_mut(
+        buffer.data,
+        buffer.len,
+    )));
+}
+
+/// Parse `input` in the given `RUSTUME_FORMAT_*` into Rustume JSON.
+///
+/// On success `out_json` receives UTF-8 JSON in the Rustume schema.
+///
+/// # Safety
+/// `input` must point to `input_len` readable bytes (or be null with length 0)
+/// and `out_json` must point to a writable `RustumeBuffer`.
+#[no_mangle]
+pub unsafe extern "C" fn rustume_parse(
+    format: u32,
+    input_ptr: *const u8,
+    input_len: usize,
+    out_json: *mut RustumeBuffer,
+) -> RustumeStatus {
+    run(out_json, || {
+        let format = format_from_code(format)?;
+        let data = input(input_ptr, input_len)?;
+        let resume = parse_resume(format, data)?;
+        Ok((RustumeStatus::Ok, to_json(&resume)?))
+    })
+}
+
+/// Validate Rustume JSON against the schema and the semantic rules.
+///
+/// `out_report` always receives a JSON report of the form
+/// `{"valid": bool, "errors": [..], "warnings": [..]}` (empty arrays omitted),
+/// including when the status is `RUSTUME_STATUS_VALIDATION_FAILED`.
+///
+/// # Safety
+/// `json` must point to `json_len` readable bytes and `out_report` must point
+/// to a writable `RustumeBuffer`.
+#[no_mangle]
+pub unsafe extern "C" fn rustume_validate(
+    json: *const u8,
+    json_len: usize,
+    out_report: *mut RustumeBuffer,
+) -> RustumeStatus {
+    run(out_report, || {
+        let resume = resume_from_json(input(json, json_len)?)?;
+        let rules = resume.check_rules();
+        let mut errors = match resume.validate() {
+            Ok(()) => Vec::new(),
+            Err(e) => validation_messages(&e),
+        };
+        errors.extend(rules.errors.iter().map(ToString::to_string));
+        let report = ValidationReport {
+            valid: errors.is_empty(),
+            errors,
+            warnings: rules.warnings.iter().map(ToString::to_string).collect(),
+        };
+        let status = if report.valid {
+            RustumeStatus::Ok
+        } else {
+            set_last_error(&report.errors.join("; "));
+            RustumeStatus::ValidationFailed
+        };
+        Ok((status, to_json(&report)?))
+    })
+}
+
+#[cfg(feature = "render")]
+mod render {
+    use std::sync::OnceLock;
+
+    use rustume_render::{Renderer, TypstRenderer};
+
+    use super::*;
+
+    /// Shared renderer so font discovery happens once per process.
+    fn renderer() -> &'static TypstRenderer {
+        static RENDERER: OnceLock<TypstRenderer> = OnceLock::new();
+        RENDERER.get_or_init(TypstRenderer::new)
+    }
+
+    /// Deserialize and validate resume JSON before rendering.
+    fn valid_resume(json: &[u8]) -> Result<ResumeData, FfiError> {
+        let resume = resume_from_json(json)?;
+        resume.validate().map_err(|e| {
+            FfiError::new(
+                RustumeStatus::ValidationFailed,
+                validation_messages(&e).join("; "),
+            )
+        })?;
+        Ok(resume)
+    }
+
+    fn render_error(err: impl std::fmt::Display) -> FfiError {
+        FfiError::new(RustumeStatus::RenderError, err.to_string())
+    }
+
+    /// Render Rustume JSON to a PDF document.
+    ///
+    /// # Safety
+    /// `json` must point to `json_len` readable bytes and `out_pdf` must point
+    /// to a writable `RustumeBuffer`.
+    #[no_mangle]
+    pub unsafe extern "C" fn rustume_render_pdf(
+        json: *const u8,
+        json_len: usize,
+        out_pdf: *mut RustumeBuffer,
+    ) -> RustumeStatus {
+        run(out_pdf, || {
+            let resume = valid_resume(input(json, json_len)?)?;
+            let pdf = renderer().render_pdf(&resume).map_err(render_error)?;
+            Ok((RustumeStatus::Ok, pdf))
+        })
+    }
+
+    /// Render one page (zero-based) of Rustume JSON to a PNG image.
+    ///
+    /// `out_total_pages` may be null; otherwise it receives the page count.
+    ///
+    /// # Safety
+    /// `json` must point to `json_len` readable bytes, `out_png` must point to
+    /// a writable `RustumeBuffer`, and `out_total_pages` must be null or writable.
+    #[no_mangle]
+    pub unsafe extern "C" fn rustume_render_png(
+        json: *const u8,
+        json_len: usize,
+        page: u32,
+        out_png: *mut RustumeBuffer,
+        out_total_pages: *mut u32,
+    ) -> RustumeStatus {
+        run(out_png, || {
+            let resume = valid_resume(input(json, json_len)?)?;
+            let (png, total_pages) = renderer()
+                .render_preview(&resume, page as usize)
+                .map_err(render_error)?;
+            if !out_total_pages.is_null() {
+                out_total_pages.write(u32::try_from(total_pages).unwrap_or(u32::MAX));
+            }
+            Ok((RustumeStatus::Ok, png))
+        })
+    }
+
+    /// Render one page (zero-based) of Rustume JSON to a UTF-8 SVG document.
+    ///
+    /// `out_total_pages` may be null; otherwise it receives the page count.
+    ///
+    /// # Safety
+    /// `json` must point to `json_len` readable bytes, `out_svg` must point to
+    /// a writable `RustumeBuffer`, and `out_total_pages` must be null or writable.
+    #[no_mangle]
+    pub unsafe extern "C" fn rustume_render_svg(
+        json: *const u8,
+        json_len: usize,
+        page: u32,
+        out_svg: *mut RustumeBuffer,
+        out_total_pages: *mut u32,
+    ) -> RustumeStatus {
+        run(out_svg, || {
+            let resume = valid_resume(input(json, json_len)?)?;
+            let (svg, total_pages) = renderer()
+                .render_svg(&resume, page as usize)
+                .map_err(render_error)?;
+            if !out_total_pages.is_null() {
+                out_total_pages.write(u32::try_from(total_pages).unwrap_or(u32::MAX));
+            }
+            Ok((RustumeStatus::Ok, svg.into_bytes()))
+        })
+    }
+}
+
+#[cfg(feature = "render")]
+pub use render::{rustume_render_pdf, rustume_render_png, rustume_render_svg};
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::CStr;
+
+    use super::*;
+
+    fn last_error() -> Option<String> {
+        let message = rustume_last_error_message();
+        if message.is_null() {
+            return None;
+        }
+        Some(
+            unsafe { CStr::from_ptr(message) }
+                .to_string_lossy()
+                .into_owned(),
+        )
+    }
+
+    /// Copy a buffer into a Vec and free it.
+    fn take(buffer: RustumeBuffer) -> Vec<u8> {
+        let bytes = if buffer.data.is_null() {
+            Vec::new()
+        } else {
+            unsafe { slice::from_raw_parts(buffer.data, buffer.len) }.to_vec()
+        };
+        unsafe { rustume_buffer_free(buffer) };
+        bytes
+    }
+
+    fn validate(json: &str) -> (RustumeStatus, serde_json::Value) {
+        let mut out = RustumeBuffer::empty();
+        let status = unsafe { rustume_validate(json.as_ptr(), json.len(), &mut out) };
+        (status, serde_json::from_slice(&take(out)).unwrap())
+    }
+
+    #[test]
+    fn version_matches_crate() {
+        let version = unsafe { CStr::from_ptr(rustume_version()) };
+        assert_eq!(version.to_str().unwrap(), env!("CARGO_PKG_VERSION"));
+    }
+
+    #[test]
+    fn parse_json_resume_to_rustume_json() {
+        let input = br#"{"basics":{"name":"Ada Lovelace","email":"ada@example.com"}}"#;
+        let mut out = RustumeBuffer::empty();
+        let status = unsafe {
+            rustume_parse(
+                RUSTUME_FORMAT_JSON_RESUME,
+                input.as_ptr(),
+                input.len(),
+                &mut out,
+            )
+        };
+
+        assert_eq!(status, RustumeStatus::Ok);
+        assert!(last_error().is_none());
+        let resume: ResumeData = serde_json::from_slice(&take(out)).unwrap();
+        assert_eq!(resume.basics.name, "Ada Lovelace");
+    }
+
+    #[test]
+    fn parse_reports_errors_with_message() {
+        let input = b"not json";
+        let mut out = RustumeBuffer::empty();
+        let status = unsafe {
+            rustume_parse(
+                RUSTUME_FORMAT_JSON_RESUME,
+                input.as_ptr(),
+                input.len(),
+                &mut out,
+            )
+        };
+
+        assert_eq!(status, RustumeStatus::ParseError);
+        assert!(out.data.is_null());
+        assert!(last_error().is_some());
+    }
+
+    #[test]
+    fn parse_rejects_unknown_format_and_null_pointers() {
+        let mut out = RustumeBuffer::empty();
+        let status = unsafe { rustume_parse(42, b"{}".as_ptr(), 2, &mut out) };
+        assert_eq!(status, RustumeStatus::InvalidArgument);
+        assert!(last_error().unwrap().contains("42"));
+
+        let status = unsafe { rustume_parse(RUSTUME_FORMAT_RUSTUME, ptr::null(), 10, &mut out) };
+        assert_eq!(status, RustumeStatus::NullPointer);
+
+        let status =
+            unsafe { rustume_parse(RUSTUME_FORMAT_RUSTUME, b"{}".as_ptr(), 2, ptr::null_mut()) };
+        assert_eq!(status, RustumeStatus::NullPointer);
+    }
+
+    #[test]
+    fn parse_linkedin_limit_maps_to_limit_exceeded() {
+        let oversized = vec![0u8; 50 * 1024 * 1024 + 1];
+        let mut out = RustumeBuffer::empty();
+        let status = unsafe {
+            rustume_parse(
+                RUSTUME_FORMAT_LINKEDIN,
+                oversized.as_ptr(),
+                oversized.len(),
+                &mut out,
+            )
+        };
+        assert_eq!(status, RustumeStatus::LimitExceeded);
+    }
+
+    #[test]
+    fn validate_valid_resume() {
+        let (status, report) = validate(r#"{"basics":{"name":"Ada"}}"#);
+        assert_eq!(status, RustumeStatus::Ok);
+        assert_eq!(report["valid"], true);
+        assert!(report.get("errors").is_none());
+    }
+
+    #[test]
+    fn validate_invalid_resume_returns_report() {
+        let (status, report) = validate(r#"{"basics":{"email":"nope"}}"#);
+        assert_eq!(status, RustumeStatus::ValidationFailed);
+        assert_eq!(report["valid"], false);
+        assert_eq!(
+            report["errors"][0],
+            "basics.email: Must be a valid email address"
+        );
+        assert!(last_error().unwrap().contains("basics.email"));
+    }
+
+    #[test]
+    fn validate_reports_contrast_warnings() {
+        let (status, report) = validate(r##"{"metadata":{"theme":{"text":"#dddddd"}}}"##);
+        assert_eq!(status, RustumeStatus::Ok);
+        assert!(report["warnings"][0]
+            .as_str()
+            .unwrap()
+            .starts_with("metadata.theme.text"));
+    }
+
+    #[test]
+    fn buffer_free_accepts_empty_buffer() {
+        unsafe { rustume_buffer_free(RustumeBuffer::empty()) };
+    }
+}
@@ -0,0 +1,56 @@
+//! Keep the checked-in C header in sync with the exported Rust API.
+//!
+//! cbindgen is not a build dependency, so a forgotten regeneration would ship
+//! a header that lies about the ABI. These checks catch missing symbols.
+
+use std::fs;
+use std::path::PathBuf;
+
+fn read(relative: &str) -> String {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(relative);
+    fs::read_to_string(&path).unwrap_or_else(|e| panic!("Failed to read {}: {e}", path.display()))
+}
+
+/// Names following `marker` up to the next non-identifier character.
+fn exported_names(source: &str, marker: &str) -> Vec<String> {
+    source
+        .match_indices(marker)
+        .map(|(idx, _)| {
+            source[idx + marker.len()..]
+                .chars()
+                .take_while(|c| c.is_ascii_alphanumeric() || *c == '_')
+                .collect()
+        })
+        .collect()
+}
+
+#[test]
+fn header_declares_every_exported_function() {
+    let source = read("src/lib.rs");
+    let header = read("include/rustume.h");
+
+    let functions = exported_names(&source, "extern \"C\" fn ");
+    assert!(
+        functions.len() >= 7,
+        "expected exported functions, got {functions:?}"
+    );
+    for function in functions {
+        assert!(
+            header.contains(&format!("{function}(")),
+            "`{function}` is exported but missing from include/rustume.h; regenerate with cbindgen"
+        );
+    }
+}
+
+#[test]
+fn header_declares_every_format_constant() {
+    let source = read("src/lib.rs");
+    let header = read("include/rustume.h");
+
+    for constant in exported_names(&source, "pub const ") {
+        assert!(
+            header.contains(&format!("#define {constant} ")),
+            "`{constant}` is missing from include/rustume.h; regenerate with cbindgen"
+        );
+    }
+}
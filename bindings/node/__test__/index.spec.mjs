@@ -0,0 +1,57 @@
+import assert from "node:assert/strict";
+import { createRequire } from "node:module";
+import { test } from "node:test";
+
+const require = createRequire(import.meta.url);
+const rustume = require("../index.js");
+
+const jsonResume = JSON.stringify({
+  basics: {
+    name: "Ada Lovelace",
+    label: "Analyst",
+    email: "ada@example.com",
+  },
+  work: [{ name: "Analytical Engine", position: "Programmer", startDate: "1842-01" }],
+});
+
+test("lists templates", () => {
+  const templates = rustume.listTemplates();
+  assert.ok(templates.includes("rhyhorn"));
+});
+
+test("parses JSON Resume", () => {
+  const resume = rustume.parseJsonResume(jsonResume);
+  assert.equal(resume.basics.name, "Ada Lovelace");
+});
+
+test("rejects malformed input", () => {
+  assert.throws(() => rustume.parseJsonResume("{not json"), /Failed to parse/);
+});
+
+test("validates without throwing", () => {
+  const resume = rustume.parseJsonResume(jsonResume);
+  const result = rustume.validateResume(resume);
+  assert.equal(result.valid, true);
+  assert.deepEqual(result.errors, []);
+});
+
+test("renders a PDF", async () => {
+  const resume = rustume.parseJsonResume(jsonResume);
+  const pdf = await rustume.renderPdf(resume, "pikachu");
+  assert.ok(Buffer.isBuffer(pdf));
+  assert.equal(pdf.subarray(0, 4).toString(), "%PDF");
+});
+
+test("renders a PNG preview", async () => {
+  const resume = rustume.parseJsonResume(jsonResume);
+  const preview = await rustume.renderPreview(resume, 0);
+  assert.ok(preview.totalPages >= 1);
+  assert.deepEqual([...preview.png.subarray(1, 4)], [0x50, 0x4e, 0x47]);
+});
+
+test("renders an SVG page", async () => {
+  const resume = rustume.parseJsonResume(jsonResume);
+  const page = await rustume.renderSvg(resume, 0);
+  assert.ok(page.totalPages >= 1);
+  assert.ok(page.svg.startsWith("<svg"));
+});
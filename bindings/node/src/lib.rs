@@ -0,0 +1,286 @@
+//! Node.js native addon for Rustume.
+//!
+//! Unlike the WASM bindings, this addon links the Typst renderer natively, so
+//! Node backends can generate PDFs and previews in-process instead of calling
+//! the HTTP server. Rendering runs on the libuv thread pool and returns a
+//! `Promise`, keeping the event loop free.
+//!
+//! # Example (JavaScript)
+//! ```js
+//! import { parseJsonResume, renderPdf } from "@rustume/node";
+//!
+//! const resume = parseJsonResume(jsonResumeString);
+//! const pdf = await renderPdf(resume, "pikachu");
+//! await fs.writeFile("resume.pdf", pdf);
+//! ```
+
+use std::sync::OnceLock;
+
+use napi::bindgen_prelude::*;
+use napi_derive::napi;
+use rustume_parser::{parse_resume, ResumeFormat};
+use rustume_render::{get_template_theme, Renderer, TypstRenderer, TEMPLATES};
+use rustume_schema::{validation_messages, ResumeData};
+use serde_json::Value;
+use validator::Validate;
+
+/// Shared renderer so fonts are discovered once per process.
+fn renderer() -> &'static TypstRenderer {
+    static RENDERER: OnceLock<TypstRenderer> = OnceLock::new();
+    RENDERER.get_or_init(TypstRenderer::new)
+}
+
+fn invalid_arg(message: impl Into<String>) -> Error {
+    Error::new(Status::InvalidArg, message.into())
+}
+
+fn to_js(resume: &ResumeData) -> Result<Value> {
+    serde_json::to_value(resume).map_err(|e| Error::from_reason(e.to_string()))
+}
+
+/// Deserialize resume JSON, apply an optional template override, and validate.
+fn prepare_resume(resume: Value, template: Option<String>) -> Result<ResumeData> {
+    let mut resume: ResumeData = serde_json::from_value(resume)
+        .map_err(|e| invalid_arg(format!("Invalid resume data: {e}")))?;
+
+    if let Some(template) = template {
+        resume.metadata.template = template;
+    }
+
+    resume.validate().map_err(|e| {
+        invalid_arg(format!(
+            "Validation failed: {}",
+            validation_messages(&e).join("; ")
+        ))
+    })?;
+
+    Ok(resume)
+}
+
+// ============================================================================
+// Parser Functions
+// ============================================================================
+
+fn parse(format: ResumeFormat, data: &[u8]) -> Result<Value> {
+    let resume = parse_resume(format, data)
+        .map_err(|e| invalid_arg(format!("Failed to parse {}: {e}", format.label())))?;
+    to_js(&resume)
+}
+
+/// Parse a JSON Resume document into Rustume format.
+#[napi]
+pub fn parse_json_resume(input: String) -> Result<Value> {
+    parse(ResumeFormat::JsonResume, input.as_bytes())
+}
+
+/// Parse a Reactive Resume v3 JSON export into Rustume format.
+#[napi]
+pub fn parse_reactive_resume_v3(input: String) -> Result<Value> {
+    parse(ResumeFormat::Rrv3, input.as_bytes())
+}
+
+/// Parse a Stack Overflow data export (XML or JSON) into Rustume format.
+#[napi]
+pub fn parse_stack_overflow(input: String) -> Result<Value> {
+    parse(ResumeFormat::StackOverflow, input.as_bytes())
+}
+
+/// Parse a LinkedIn data export ZIP into Rustume format.
+#[napi]
+pub fn parse_linkedin_export(data: Buffer) -> Result<Value> {
+    parse(ResumeFormat::LinkedIn, &data)
+}
+
+// ============================================================================
+// Validation
+// ============================================================================
+
+/// Result of `validateResume`.
+#[napi(object)]
+pub struct ValidationResult {
+    /// Whether the resume passed schema validation and the semantic rules.
+    pub valid: bool,
+    /// `path: message` strings for each validation error.
+    pub errors: Vec<String>,
+    /// Non-fatal warnings (e.g. low theme contrast, `http://` links).
+    pub warnings: Vec<String>,
+}
+
+/// Validate resume data without throwing on schema errors.
+///
+/// Throws only when `resume` cannot be deserialized at all.
+#[napi]
+pub fn validate_resume(resume: Value) -> Result<ValidationResult> {
+    let resume: ResumeData = serde_json::from_value(resume)
+        .map_err(|e| invalid_arg(format!("Invalid resume data: {e}")))?;
+
+    let rules = resume.check_rules();
+    let mut errors = match resume.validate() {
+        Ok(()) => Vec::new(),
+        Err(e) => validation_messages(&e),
+    };
+    errors.extend(rules.errors.iter().map(ToString::to_string));
+
+    Ok(ValidationResult {
+        valid: errors.is_empty(),
+        errors,
+        warnings: rules.warnings.iter().map(ToString::to_string).collect(),
+    })
+}
+
+// ============================================================================
+// Templates
+// ============================================================================
+
+/// Default theme colors for a template.
+#[napi(object)]
+pub struct TemplateTheme {
+    pub background: String,
+    pub text: String,
+    pub primary: String,
+}
+
+/// List available template names.
+#[napi]
+pub fn list_templates() -> Vec<String> {
+    TEMPLATES.iter().map(|name| name.to_string()).collect()
+}
+
+/// Get the default theme colors for a template.
+#[napi]
+pub fn get_template_theme_colors(template: String) -> TemplateTheme {
+    let theme = get_template_theme(&template);
+    TemplateTheme {
+        background: theme.background,
+        text: theme.text,
+        primary: theme.primary,
+    }
+}
+
+// ============================================================================
+// Render Functions
+// ============================================================================
+
+/// Background task that renders a PDF on the libuv thread pool.
+pub struct RenderPdfTask {
+    resume: ResumeData,
+}
+
+impl Task for RenderPdfTask {
+    type Output = Vec<u8>;
+    type JsValue = Buffer;
+
+    fn compute(&mut self) -> Result<Self::Output> {
+        renderer()
+            .render_pdf(&self.resume)
+            .map_err(|e| Error::from_reason(format!("Failed to render PDF: {e}")))
+    }
+
+    fn resolve(&mut self, _env: Env, output: Self::Output) -> Result<Self::JsValue> {
+        Ok(output.into())
+    }
+}
+
+/// Render resume data to a PDF.
+///
+/// `template` overrides `metadata.template` when provided.
+#[napi(ts_return_type = "Promise<Buffer>")]
+pub fn render_pdf(resume: Value, template: Option<String>) -> Result<AsyncTask<RenderPdfTask>> {
+    let resume = prepare_resume(resume, template)?;
+    Ok(AsyncTask::new(RenderPdfTask { resume }))
+}
+
+/// A rendered preview page.
+#[napi(object)]
+pub struct PreviewImage {
+    /// PNG image bytes.
+    pub png: Buffer,
+    /// Total number of pages in the document.
+    pub total_pages: u32,
+}
+
+/// Background task that renders a PNG preview on the libuv thread pool.
+pub struct RenderPreviewTask {
+    resume: ResumeData,
+    page: usize,
+}
+
+impl Task for RenderPreviewTask {
+    type Output = (Vec<u8>, usize);
+    type JsValue = PreviewImage;
+
+    fn compute(&mut self) -> Result<Self::Output> {
+        renderer()
+            .render_preview(&self.resume, self.page)
+            .map_err(|e| Error::from_reason(format!("Failed to render preview: {e}")))
+    }
+
+    fn resolve(&mut self, _env: Env, (png, total_pages): Self::Output) -> Result<Self::JsValue> {
+        Ok(PreviewImage {
+            png: png.into(),
+            total_pages: u32::try_from(total_pages).unwrap_or(u32::MAX),
+        })
+    }
+}
+
+/// Render one page (zero-based) of resume data to a PNG preview.
+#[napi(ts_return_type = "Promise<PreviewImage>")]
+pub fn render_preview(
+    resume: Value,
+    page: Option<u32>,
+    template: Option<String>,
+) -> Result<AsyncTask<RenderPreviewTask>> {
+    let resume = prepare_resume(resume, template)?;
+    Ok(AsyncTask::new(RenderPreviewTask {
+        resume,
+        page: page.unwrap_or(0) as usize,
+    }))
+}
+
+/// A page rendered as SVG.
+#[napi(object)]
+pub struct SvgPage {
+    /// SVG document.
+    pub svg: String,
+    /// Total number of pages in the document.
+    pub total_pages: u32,
+}
+
+/// Background task that renders an SVG page on the libuv thread pool.
+pub struct RenderSvgTask {
+    resume: ResumeData,
+    page: usize,
+}
+
+impl Task for RenderSvgTask {
+    type Output = (String, usize);
+    type JsValue = SvgPage;
+
+    fn compute(&mut self) -> Result<Self::Output> {
+        renderer()
+            .render_svg(&self.resume, self.page)
+            .map_err(|e| Error::from_reason(format!("Failed to render SVG: {e}")))
+    }
+
+    fn resolve(&mut self, _env: Env, (svg, total_pages): Self::Output) -> Result<Self::JsValue> {
+        Ok(SvgPage {
+            svg,
+            total_pages: u32::try_from(total_pages).unwrap_or(u32::MAX),
+        })
+    }
+}
+
+/// Render one page (zero-based) of resume data to SVG, for previews that
+/// stay sharp at any zoom.
+#[napi(ts_return_type = "Promise<SvgPage>")]
+pub fn render_svg(
+    resume: Value,
+    page: Option<u32>,
+    template: Option<String>,
+) -> Result<AsyncTask<RenderSvgTask>> {
+    let resume = prepare_resume(resume, template)?;
+    Ok(AsyncTask::new(RenderSvgTask {
+        resume,
+        page: page.unwrap_or(0) as usize,
+    }))
+}
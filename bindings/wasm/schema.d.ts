@@ -0,0 +1,973 @@
+// Generated from the Rust schema by rustume-schema; do not edit.
+// Regenerate with: RUSTUME_UPDATE_TS=1 cargo test -p rustume-schema typescript
+
+/**
+ * Root resume data structure.
+ *
+ * This is the main type that represents a complete resume.
+ * It contains three main sections:
+ * - `basics`: Personal information (name, email, etc.)
+ * - `sections`: All resume sections (experience, education, skills, etc.)
+ * - `metadata`: Display settings (template, theme, layout, etc.)
+ *
+ * plus `attachments`, files such as reference letters that go with it.
+ */
+export interface ResumeData {
+  /** Basic personal information. */
+  basics: Basics;
+  /** All resume sections. */
+  sections: Sections;
+  /** Display metadata (template, theme, layout). */
+  metadata: Metadata;
+  /** Files such as reference letters and certificates. */
+  attachments: Attachment[];
+}
+
+/** Basic personal information. */
+export interface Basics {
+  /** Full name, as the person writes it. */
+  name: string;
+  /**
+   * `name` split into given and family name, for sorting and for
+   * printing in the locale's name order. Omitted while unknown.
+   */
+  nameParts?: PersonName;
+  /** Professional headline/title. */
+  headline: string;
+  /** Email address (must be empty or valid email). */
+  email: string;
+  /** Phone number. */
+  phone: string;
+  /** Location (city, country, etc.). */
+  location: string;
+  /**
+   * Postal address in parts, for addresses a single `location` line
+   * would make ambiguous. Omitted while unknown.
+   */
+  address?: Address;
+  /** Personal website or portfolio URL. */
+  url: Url;
+  /** Custom fields for additional info. */
+  customFields: CustomField[];
+  /** Profile picture configuration. */
+  picture: Picture;
+}
+
+/** A personal name in parts. */
+export interface PersonName {
+  /** Honorific before the name, such as `Dr.`. */
+  prefix: string;
+  /** Given names, including middle names. */
+  given: string;
+  /** Family name, including particles such as `van` or `de la`. */
+  family: string;
+  /** Generational or academic suffix, such as `Jr.` or `PhD`. */
+  suffix: string;
+}
+
+/** A postal address in parts. */
+export interface Address {
+  /** Street and house number, with any flat or building. */
+  street: string;
+  /** City, town, or locality. */
+  city: string;
+  /** State, province, prefecture, or county. */
+  region: string;
+  /** Postal or ZIP code. */
+  postalCode: string;
+  /** Country, as an ISO 3166 code such as `DE` or a name. */
+  country: string;
+}
+
+/** URL with label. */
+export interface Url {
+  /** Display label for the URL. */
+  label: string;
+  /** The URL href (must be empty or valid HTTP(S) URL). */
+  href: string;
+}
+
+/**
+ * What a custom field's value is, which decides how it is validated and
+ * rendered.
+ */
+export type CustomFieldKind = "text" | "link" | "email" | "phone" | "date";
+
+/** Custom field for basics section. */
+export interface CustomField {
+  /** CUID2 format identifier. */
+  id: string;
+  /**
+   * Icon identifier (e.g., from a icon library). Templates draw it when it
+   * is an emoji or pictographic glyph.
+   */
+  icon: string;
+  /** Field name/label. */
+  name: string;
+  /** Field value. */
+  value: string;
+  /** What the value is. */
+  kind: CustomFieldKind;
+}
+
+/** Profile picture configuration. */
+export interface Picture {
+  /** URL to the picture (can be data URL or remote URL). */
+  url: string;
+  /** Display size in pixels. */
+  size: number;
+  /** Aspect ratio (width/height). */
+  aspectRatio: number;
+  /** Border radius in pixels (0 = square, size/2 = circle). */
+  borderRadius: number;
+  /** Picture display effects. */
+  effects: PictureEffects;
+}
+
+/** Picture display effects. */
+export interface PictureEffects {
+  /** Hide the picture. */
+  hidden: boolean;
+  /** Show a border around the picture. */
+  border: boolean;
+  /** Apply grayscale filter. */
+  grayscale: boolean;
+  /** Rotation in degrees, constrained to 0..=360. */
+  rotation: number;
+  /** Border color as hex. Empty means templates should use the theme primary color. */
+  borderColor: string;
+  /** Border width in pixels/points. */
+  borderWidth: number;
+  /** Shadow color as hex with optional alpha. */
+  shadowColor: string;
+  /** Shadow offset/spread size in pixels/points. */
+  shadowSize: number;
+}
+
+/** All resume sections. */
+export interface Sections {
+  summary: SummarySection;
+  coverLetter: CoverLetterSection;
+  experience: Section<Experience>;
+  education: Section<Education>;
+  skills: Section<Skill>;
+  projects: Section<Project>;
+  profiles: Section<Profile>;
+  awards: Section<Award>;
+  certifications: Section<Certification>;
+  publications: Section<Publication>;
+  languages: Section<Language>;
+  interests: Section<Interest>;
+  volunteer: Section<Volunteer>;
+  references: Section<Reference>;
+  /** Custom sections (dynamic keys). */
+  custom: Record<string, Section<CustomItem>>;
+}
+
+/** Generic section wrapper. */
+export interface Section<T> {
+  /** Section identifier. */
+  id: string;
+  /** Display name. */
+  name: string;
+  /** Number of columns (1-5). */
+  columns: number;
+  /** Show links separately. */
+  separateLinks: boolean;
+  /** Section visibility. */
+  visible: boolean;
+  /** Section items. */
+  items: T[];
+}
+
+/** Summary section (special - no items, just content). */
+export interface SummarySection {
+  id: string;
+  name: string;
+  columns: number;
+  separateLinks: boolean;
+  visible: boolean;
+  /** Summary content (HTML/Markdown). */
+  content: string;
+}
+
+/** Cover letter recipient block. */
+export interface CoverLetterRecipient {
+  name: string;
+  title: string;
+  company: string;
+  address: string;
+  email: string;
+}
+
+/** Cover letter section (special - recipient block plus rich-text body). */
+export interface CoverLetterSection {
+  id: string;
+  name: string;
+  visible: boolean;
+  recipient: CoverLetterRecipient;
+  /** Cover letter body (HTML/Markdown). */
+  content: string;
+}
+
+/** Work experience item. */
+export interface Experience {
+  id: string;
+  visible: boolean;
+  company: string;
+  position: string;
+  location: string;
+  date: string;
+  /**
+   * Structured start of `date` as an ISO partial date (`2020`, `2020-03`,
+   * `2020-03-15`).
+   */
+  startDate?: string;
+  /** Structured end of `date`; `None` with a `start_date` means ongoing. */
+  endDate?: string;
+  summary: string;
+  /** Achievements as separate bullets, printed as a list after `summary`. */
+  highlights?: string[];
+  url: Url;
+}
+
+/** Education item. */
+export interface Education {
+  id: string;
+  visible: boolean;
+  institution: string;
+  area: string;
+  studyType: string;
+  date: string;
+  /**
+   * Structured start of `date` as an ISO partial date (`2020`, `2020-03`,
+   * `2020-03-15`).
+   */
+  startDate?: string;
+  /** Structured end of `date`; `None` with a `start_date` means ongoing. */
+  endDate?: string;
+  score: string;
+  summary: string;
+  url: Url;
+}
+
+/** Skill item. */
+export interface Skill {
+  id: string;
+  visible: boolean;
+  name: string;
+  description: string;
+  level: number;
+  keywords: string[];
+}
+
+/** Project item. */
+export interface Project {
+  id: string;
+  visible: boolean;
+  name: string;
+  description: string;
+  date: string;
+  /**
+   * Structured start of `date` as an ISO partial date (`2020`, `2020-03`,
+   * `2020-03-15`).
+   */
+  startDate?: string;
+  /** Structured end of `date`; `None` with a `start_date` means ongoing. */
+  endDate?: string;
+  summary: string;
+  /** Achievements as separate bullets, printed as a list after `summary`. */
+  highlights?: string[];
+  keywords: string[];
+  url: Url;
+}
+
+/** Social/professional profile. */
+export interface Profile {
+  id: string;
+  visible: boolean;
+  network: string;
+  username: string;
+  icon: string;
+  url: Url;
+}
+
+/** Award item. */
+export interface Award {
+  id: string;
+  visible: boolean;
+  title: string;
+  awarder: string;
+  date: string;
+  summary: string;
+  url: Url;
+}
+
+/** Certification item. */
+export interface Certification {
+  id: string;
+  visible: boolean;
+  name: string;
+  issuer: string;
+  date: string;
+  summary: string;
+  url: Url;
+}
+
+/** Publication item. */
+export interface Publication {
+  id: string;
+  visible: boolean;
+  name: string;
+  publisher: string;
+  date: string;
+  summary: string;
+  url: Url;
+}
+
+/** Language item. */
+export interface Language {
+  id: string;
+  visible: boolean;
+  name: string;
+  description: string;
+  level: number;
+  /** CEFR level, which should agree with `level`. */
+  cefr?: CefrLevel;
+}
+
+/** A CEFR level, from A1 (beginner) to C2 (mastery). */
+export type CefrLevel = "A1" | "A2" | "B1" | "B2" | "C1" | "C2";
+
+/** Interest item. */
+export interface Interest {
+  id: string;
+  visible: boolean;
+  name: string;
+  keywords: string[];
+}
+
+/** Volunteer experience item. */
+export interface Volunteer {
+  id: string;
+  visible: boolean;
+  organization: string;
+  position: string;
+  location: string;
+  date: string;
+  summary: string;
+  url: Url;
+}
+
+/** Reference item. */
+export interface Reference {
+  id: string;
+  visible: boolean;
+  name: string;
+  description: string;
+  summary: string;
+  url: Url;
+}
+
+/** Custom section item. */
+export interface CustomItem {
+  id: string;
+  visible: boolean;
+  name: string;
+  description: string;
+  date: string;
+  location: string;
+  summary: string;
+  keywords: string[];
+  url: Url;
+}
+
+/** Resume metadata. */
+export interface Metadata {
+  template: string;
+  /** Layout: pages -> columns -> section IDs. */
+  layout: Layout;
+  css: CustomCss;
+  page: PageConfig;
+  theme: Theme;
+  typography: Typography;
+  notes: string;
+  levelDisplay: LevelDisplay;
+  /**
+   * Chart style per section key (see [`SECTION_DISPLAY_KEYS`]). Sections
+   * left out keep the template's layout.
+   */
+  sectionDisplay: Record<string, SectionDisplay>;
+  /** Show each position's duration ("2 yrs 3 mos") after its dates. */
+  showDurations: boolean;
+  /** Show each language's CEFR code ("C1") with its description. */
+  showCefr: boolean;
+  /**
+   * Language of generated text such as durations and the end of ongoing
+   * date ranges, as a BCP 47 tag (`de`, `pt-BR`). Unsupported languages
+   * fall back to English.
+   */
+  locale: string;
+  /** Emoji handling in headlines and rich text when rendering. */
+  emoji: EmojiPolicy;
+  /** Photo, header, divider, and name options for the selected template. */
+  templateOptions: TemplateOptions;
+}
+
+/**
+ * Section order as pages → columns → section keys.
+ *
+ * Keys are built-in section keys, custom section IDs, or the
+ * [`CUSTOM_SECTIONS_SLOT`]. Serializes as the nested arrays templates read
+ * from `metadata.layout`. An empty layout leaves the order to the template.
+ */
+export type Layout = string[][][];
+
+/** A curated section order and visibility set. */
+export type LayoutPreset = "chronological" | "functional" | "hybrid" | "academic";
+
+/** A starting resume for a profession. */
+export type ContentPreset = "software-engineer" | "designer" | "academic" | "nurse" | "sales";
+
+/** Custom CSS configuration. */
+export interface CustomCss {
+  value: string;
+  visible: boolean;
+}
+
+/** Page configuration. */
+export interface PageConfig {
+  margin: number;
+  format: PageFormat;
+  sidebarRatio?: number;
+  options: PageOptions;
+  /**
+   * Page and sidebar colors for some pages of the resume, in place of
+   * the theme background and the template's sidebar color.
+   */
+  backgrounds?: PageBackground[];
+}
+
+/** Page format. */
+export type PageFormat = "a4" | "letter";
+
+/**
+ * Page display options.
+ *
+ * Page numbers and the running header only appear when the resume runs
+ * past one page.
+ */
+export interface PageOptions {
+  breakLine: boolean;
+  pageNumbers: boolean;
+  pageNumberStyle: PageNumberStyle;
+  /** Repeat the name and contact details on pages 2+. */
+  runningHeader: RunningHeader;
+  /**
+   * Repeat a section's heading, marked "continued", when its items
+   * carry over to a new page.
+   */
+  continuedMarkers: boolean;
+  /**
+   * Print the name, headline, email, phone, location, and website as a
+   * vCard QR code in the first page's header or footer, so a phone camera
+   * can save them as a contact.
+   */
+  contactQr: ContactQr;
+  /**
+   * Write the title ("Name — Headline"), author, subject, keywords
+   * (the skills), and creation date into exported PDFs.
+   */
+  pdfMetadata: boolean;
+}
+
+/** How page numbers are written in the footer. */
+export type PageNumberStyle = "plain" | "of-total" | "labeled";
+
+/** Where the name and contact line repeats on pages after the first. */
+export type RunningHeader = "hidden" | "header" | "footer";
+
+/** Where a QR code of the contact details prints on the first page. */
+export type ContactQr = "hidden" | "header" | "footer";
+
+/**
+ * Pages of the resume a [`PageBackground`] applies to. The first resume
+ * page is page 1; a cover letter before it and attachments after it keep
+ * the theme background.
+ */
+export type PageSelector = "all" | "first" | "rest" | "last" | "odd" | "even";
+
+/**
+ * Colors of the pages `pages` selects. Where several backgrounds select
+ * the same page, the later one wins for each color it sets.
+ */
+export interface PageBackground {
+  pages: PageSelector;
+  /** Page color; empty keeps the theme background. */
+  background: string;
+  /**
+   * Sidebar color on templates with a sidebar; empty keeps the
+   * template's.
+   */
+  sidebar: string;
+  /** Leave the sidebar unfilled, so the page color shows through. */
+  hideSidebar: boolean;
+}
+
+/** Color theme. */
+export interface Theme {
+  background: string;
+  text: string;
+  primary: string;
+  /** Id of the theme preset the colors were picked from, if any. */
+  preset?: string;
+}
+
+/** Typography configuration. */
+export interface Typography {
+  font: FontConfig;
+  /**
+   * Line height as a multiple of the font size. In PDFs it scales each
+   * template's line spacing, which the default of 1.5 keeps as designed.
+   */
+  lineHeight: number;
+  /** Extra space between letters, in em. */
+  letterSpacing: number;
+  /** Space between paragraphs, in em; unset keeps the template's. */
+  paragraphSpacing?: number;
+  /** Size of section headings relative to the template's. */
+  headingScale: number;
+  /** Set section headings in small capitals. */
+  smallCaps: boolean;
+  /** Justify paragraphs to both edges; unset keeps the template's. */
+  justify?: boolean;
+  /** Hyphenate words at line ends; unset hyphenates justified text only. */
+  hyphenate?: boolean;
+  hideIcons: boolean;
+  underlineLinks: boolean;
+}
+
+/** Font configuration. */
+export interface FontConfig {
+  family: string;
+  subset: string;
+  variants: string[];
+  size: number;
+}
+
+/** Controls how skill and language proficiency levels are rendered. */
+export type LevelDisplay =
+  | "template-default"
+  | "hidden"
+  | "circle"
+  | "square"
+  | "progress-bar"
+  | "text";
+
+/**
+ * How a section with proficiency levels is drawn, set per section in
+ * [`Metadata::section_display`].
+ */
+export type SectionDisplay = "list" | "bar-chart" | "dots" | "tag-cloud";
+
+/**
+ * What rendering does with emoji and pictographic icons in headlines and
+ * rich text. Many applicant tracking systems garble them when parsing a PDF.
+ */
+export type EmojiPolicy = "preserve" | "strip" | "replace";
+
+/**
+ * Per-template presentation options, so moving the photo or centering the
+ * header doesn't take a new template. Fields left at `template-default`
+ * keep the template's own look; which options a template honors is listed
+ * in its capability descriptor, and renders warn about the rest. The name
+ * options apply to every template.
+ */
+export interface TemplateOptions {
+  /** Placement of `basics.picture`. Its size stays in `basics.picture.size`. */
+  photo: PhotoPosition;
+  headerAlign: HeaderAlign;
+  divider: DividerStyle;
+  /**
+   * Order of the given and family name in the header, when
+   * `basics.nameParts` is set.
+   */
+  nameOrder: NameOrder;
+  /**
+   * Print the family name in capitals (`Jean DUPONT`), when
+   * `basics.nameParts` is set.
+   */
+  familyNameCaps: boolean;
+}
+
+/** Where a template draws the profile picture in its header. */
+export type PhotoPosition = "template-default" | "top" | "left" | "right" | "hidden";
+
+/** Alignment of the name and contact details in a template's header. */
+export type HeaderAlign = "template-default" | "left" | "center" | "right";
+
+/** The rule drawn under section headings. */
+export type DividerStyle = "template-default" | "line" | "thick" | "dotted" | "none";
+
+/** Which part of a name is printed first. */
+export type NameOrder = "auto" | "given-first" | "family-first";
+
+/** A named file kept with the resume. */
+export interface Attachment {
+  /** CUID2 format identifier. */
+  id: string;
+  /** Whether the attachment is included in exports. */
+  visible: boolean;
+  /** Display name, e.g. "Reference letter – Acme". */
+  name: string;
+  /**
+   * The file as a base64 `data:` URL with one of the
+   * [`ATTACHMENT_MEDIA_TYPES`], or empty.
+   */
+  file: string;
+  /** Link to a hosted copy (must be empty or a valid HTTP(S) URL). */
+  url: string;
+  /** How the attachment appears in the PDF. */
+  mode: AttachmentMode;
+}
+
+/** How an attachment is presented in the exported PDF. */
+export type AttachmentMode = "append" | "qr-code";
+
+/** How serious a [`ValidationIssue`] is. */
+export type Severity = "error" | "warning";
+
+/**
+ * A problem found by a semantic [`ValidationRule`].
+ *
+ * Unlike the field validators above, rules look across fields and items
+ * (date ranges, duplicate IDs, theme contrast). Only [`Severity::Error`]
+ * issues make a resume invalid.
+ */
+export interface ValidationIssue {
+  /** Dotted path of the field the issue applies to. */
+  path: string;
+  /** Machine-readable issue code. */
+  code: string;
+  /** Whether the issue is an error or a warning. */
+  severity: Severity;
+  /** Human-readable description of the problem. */
+  message: string;
+  /** Suggested replacement value, when one can be computed. */
+  suggestion?: string;
+}
+
+/** Issues found by a [`RuleSet`], split by severity. */
+export interface RuleReport {
+  errors: ValidationIssue[];
+  warnings: ValidationIssue[];
+}
+
+/** A rule-based improvement hint for one bullet point. */
+export interface ContentSuggestion {
+  /** Dotted path of the field containing the bullet. */
+  path: string;
+  /** Machine-readable rule code. */
+  code: string;
+  /** Human-readable explanation of the hint. */
+  message: string;
+  /** Plain text of the bullet the hint applies to. */
+  excerpt: string;
+  /** Suggested replacement wording, when the rule has one. */
+  suggestion?: string;
+}
+
+/** Two items in the same section that look like the same entry. */
+export interface DuplicateCandidate {
+  /** Section key: `experience`, `skills`, ... or `custom.<id>`. */
+  section: string;
+  /** ID of the earlier item, kept by default when merging. */
+  keepId: string;
+  /** ID of the later item, folded into the kept one when merging. */
+  duplicateId: string;
+  /** Identifying fields of the kept item, for display. */
+  keepLabel: string;
+  /** Identifying fields of the duplicate item, for display. */
+  duplicateLabel: string;
+  /** Lowest similarity across the identifying fields both items fill in (0–1). */
+  similarity: number;
+}
+
+/** How imported data is combined with the existing resume. */
+export type MergeStrategy = "append_new" | "fill_blanks";
+
+/** One imported item and what happened to it. */
+export interface MergeEntry {
+  /** Section key: `experience`, `skills`, ... or `custom.<id>`. */
+  section: string;
+  /** Identifying fields of the imported item. */
+  label: string;
+  /**
+   * ID of the item in the merged resume: the appended item for `added`,
+   * the existing match for `skipped` and `updated`.
+   */
+  id: string;
+}
+
+/** Outcome of [`merge_import`] for each imported item. */
+export interface MergeReport {
+  /** Imported items with no match, appended to their section. */
+  added: MergeEntry[];
+  /** Imported items that matched an existing item and were left out. */
+  skipped: MergeEntry[];
+  /**
+   * Matched items whose blank fields were filled from the import
+   * ([`MergeStrategy::FillBlanks`] only).
+   */
+  updated: MergeEntry[];
+}
+
+/** A merged resume and its report. */
+export interface MergedImport {
+  resume: ResumeData;
+  report: MergeReport;
+}
+
+/** One change made by a repair. */
+export interface RepairFix {
+  /** Dotted path of the changed field. */
+  path: string;
+  /**
+   * Machine-readable kind of problem: `empty_id`, `duplicate_id`,
+   * `unknown_layout_section`, `duplicate_layout_section`,
+   * `invalid_section_display`, or `invalid_value`.
+   */
+  code: string;
+  /** What was wrong and what changed. */
+  message: string;
+}
+
+/** Everything a repair changed, and what it couldn't. */
+export interface RepairReport {
+  /** Changes made, in the order they were applied. */
+  fixes: RepairFix[];
+  /** Rule errors left after repairing, which need a person to decide. */
+  unresolved: ValidationIssue[];
+}
+
+/** A repaired resume and its report. */
+export interface RepairedResume {
+  resume: ResumeData;
+  report: RepairReport;
+}
+
+/** What a [`FitStep`] changes. */
+export type FitStepKind = "tighten_margin" | "shrink_font" | "hide_item";
+
+/** One change to a resume: set the field at `path` to `value`. */
+export interface FitStep {
+  kind: FitStepKind;
+  /** Dotted path of the changed field. */
+  path: string;
+  /** The field's new value. */
+  value: unknown;
+  /** The change for people. */
+  description: string;
+}
+
+/** Result of fitting a resume to a page budget. */
+export interface FitReport {
+  /** Page budget asked for. */
+  targetPages: number;
+  /** Pages before any change. */
+  pagesBefore: number;
+  /** Pages with every step applied. */
+  pagesAfter: number;
+  /**
+   * Whether `pagesAfter` is within the budget. When even every candidate
+   * cut isn't enough, all of them are applied and this is `false`.
+   */
+  fits: boolean;
+  /** Changes made, in order. */
+  steps: FitStep[];
+}
+
+/** A resume fitted to a page budget and what changed. */
+export interface FittedResume {
+  resume: ResumeData;
+  report: FitReport;
+}
+
+/** What [`keyword_report`] flags and compares against. */
+export interface KeywordOptions {
+  /** Keywords the target role expects; words or phrases. */
+  target: string[];
+  /** Words and phrases to flag as empty buzzwords. */
+  buzzwords: string[];
+  /** Terms used more than this many times are reported as overused. */
+  maxRepeats: number;
+}
+
+/** How often a term appears. */
+export interface KeywordCount {
+  /** The term, lowercased; phrases as given. */
+  term: string;
+  count: number;
+  /** Share of all counted words, in percent to one decimal place. */
+  density: number;
+}
+
+/** Target role keywords found and missing. */
+export interface TargetCoverage {
+  /** Target keywords the resume uses, with counts. */
+  matched: KeywordCount[];
+  /** Target keywords the resume never uses. */
+  missing: string[];
+  /** Share of target keywords matched, from 0 to 1. */
+  coverage: number;
+}
+
+/** Keyword counts for one section. */
+export interface SectionKeywords {
+  /** Section key: `basics`, `summary`, `experience`, `custom.<id>`, ... */
+  section: string;
+  /** Words counted in the section. */
+  totalWords: number;
+  /** Most used terms, most frequent first. */
+  keywords: KeywordCount[];
+  /** Buzzwords used in the section. */
+  buzzwords: KeywordCount[];
+  /** Target keywords used in the section. */
+  targetMatches: KeywordCount[];
+}
+
+/** Keyword frequency, overuse, buzzwords, and target coverage. */
+export interface KeywordReport {
+  /** Words counted across the resume, stop words excluded. */
+  totalWords: number;
+  /** Most used terms, most frequent first. */
+  keywords: KeywordCount[];
+  /** Terms used more than [`KeywordOptions::max_repeats`] times. */
+  overused: KeywordCount[];
+  /** Buzzwords used, most frequent first. */
+  buzzwords: KeywordCount[];
+  /** Coverage of [`KeywordOptions::target`]; absent when no target was given. */
+  target?: TargetCoverage;
+  /**
+   * The same counts per section, in resume order; sections without text
+   * are left out.
+   */
+  sections: SectionKeywords[];
+}
+
+/** How long one position lasted. */
+export interface PositionDuration {
+  /** ID of the experience item. */
+  id: string;
+  company: string;
+  position: string;
+  /** Whole months, counted inclusively. */
+  months: number;
+  /** The same duration for display. */
+  duration: string;
+}
+
+/** Aggregate statistics for a resume. */
+export interface ResumeStats {
+  /** Visible positions whose dates could be read, in resume order. */
+  positions: PositionDuration[];
+  /** Months covered by at least one position; overlapping roles count once. */
+  totalExperienceMonths: number;
+  /** Total experience in years, to one decimal place. */
+  totalExperienceYears: number;
+  /** Total experience for display. */
+  totalExperience: string;
+  /** Gaps of at least [`DEFAULT_GAP_MONTHS`] between entries, oldest first. */
+  gaps: EmploymentGap[];
+}
+
+/** Options for [`employment_gaps`]. */
+export interface GapOptions {
+  /** Shortest gap to report, in whole months. Defaults to 6. */
+  minMonths: number;
+}
+
+/** A stretch of time no entry on the resume accounts for. */
+export interface EmploymentGap {
+  /** First month of the gap, `YYYY-MM`. */
+  start: string;
+  /** Last month of the gap, `YYYY-MM`. */
+  end: string;
+  /** Length of the gap in whole months. */
+  months: number;
+  /** The same length for display. */
+  duration: string;
+  /** Path of the entry that ended last before the gap. */
+  after: string;
+  /** Path of the first entry after the gap. */
+  before: string;
+  /** What could be added to account for the gap. */
+  suggestion: string;
+}
+
+/** A job application and the resume version sent with it. */
+export interface JobApplication {
+  id: string;
+  company: string;
+  role: string;
+  status: ApplicationStatus;
+  /** ID of the tailored resume sent with this application. */
+  resumeId?: string;
+  /** Job posting link. */
+  url: Url;
+  location: string;
+  /** Date the application was sent (`YYYY-MM-DD`). */
+  appliedDate: string;
+  /** Date to follow up or of the next interview (`YYYY-MM-DD`). */
+  followUpDate: string;
+  notes: string;
+}
+
+/** Pipeline stage of a job application. */
+export type ApplicationStatus =
+  | "saved"
+  | "applied"
+  | "interviewing"
+  | "offer"
+  | "accepted"
+  | "rejected"
+  | "withdrawn";
+
+/** Machine-readable kind of failure, serialized as `SCREAMING_SNAKE_CASE`. */
+export type ErrorCode =
+  | "INVALID_INPUT"
+  | "INTERNAL"
+  | "VALIDATION_FAILED"
+  | "LAYOUT_SECTION_NOT_FOUND"
+  | "LAYOUT_INVALID"
+  | "MERGE_ITEM_NOT_FOUND"
+  | "MERGE_INVALID"
+  | "PARSE_READ_FAILED"
+  | "PARSE_INVALID_FORMAT"
+  | "PARSE_INVALID_JSON"
+  | "PARSE_CONVERSION_FAILED"
+  | "PARSE_LIMIT_EXCEEDED"
+  | "PARSE_LINKEDIN_BAD_ZIP"
+  | "RENDER_TEMPLATE_NOT_FOUND"
+  | "RENDER_TEMPLATE_NOT_ALLOWED"
+  | "RENDER_FAILED"
+  | "RENDER_INVALID_CONFIG"
+  | "RENDER_TOO_LARGE"
+  | "RENDER_ENGINE_UNAVAILABLE"
+  | "RENDER_UNSUPPORTED"
+  | "STORAGE_NOT_FOUND"
+  | "STORAGE_ALREADY_EXISTS"
+  | "STORAGE_INTERNAL";
+
+/**
+ * An error from any Rustume crate: its [`ErrorCode`] and message.
+ *
+ * Crate errors convert into it with `From`, so a caller that only needs the
+ * code can write `RustumeError::from(err).code`.
+ */
+export interface RustumeError {
+  /** Machine-readable kind of failure. */
+  code: ErrorCode;
+  /** Description for people, in English; may change between releases. */
+  message: string;
+}
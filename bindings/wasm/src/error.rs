@@ -0,0 +1,29 @@
+//! Errors thrown to JavaScript.
+//!
+//! Every failure is a JS `Error` with a `code` property holding its
+//! [`ErrorCode`], e.g. `"PARSE_LINKEDIN_BAD_ZIP"`, matching the `RustumeError`
+//! interface in `schema.d.ts`.
+
+use rustume_schema::{ErrorCode, RustumeError};
+use std::fmt::Display;
+use wasm_bindgen::JsValue;
+
+/// A JS `Error` carrying the message and code of `err`.
+pub(crate) fn js_error(err: impl Into<RustumeError>) -> JsValue {
+    let err = err.into();
+    let error = js_sys::Error::new(&err.message);
+    error.set_name("RustumeError");
+    // Defining a property on a fresh Error can't throw
+    let _ = js_sys::Reflect::set(&error, &"code".into(), &err.code.as_str().into());
+    error.into()
+}
+
+/// An argument that isn't the expected type, such as a malformed resume.
+pub(crate) fn invalid_input(err: &impl Display) -> JsValue {
+    js_error(RustumeError::new(ErrorCode::InvalidInput, err.to_string()))
+}
+
+/// A result that couldn't be converted back to a JS value.
+pub(crate) fn internal(err: &impl Display) -> JsValue {
+    js_error(RustumeError::new(ErrorCode::Internal, err.to_string()))
+}
@@ -6,14 +6,65 @@
 //! # Supported Import Formats
 //!
 //! - **JSON Resume**: Standard JSON Resume format (`parse_json_resume`)
-//! - **LinkedIn Export**: ZIP file from LinkedIn data export (`parse_linkedin_export`)
+//! - **LinkedIn Export**: ZIP file from LinkedIn data export (`parse_linkedin_export`,
+//!   or `parse_linkedin_export_async` to keep a worker or tab responsive)
+//! - **LinkedIn Jobs**: Job applications and saved jobs from the same ZIP (`parse_linkedin_jobs`)
 //! - **Reactive Resume V3**: JSON export from Reactive Resume V3 (`parse_reactive_resume_v3`)
+//! - **Stack Overflow**: XML or JSON data export of a Stack Overflow profile (`parse_stack_overflow`)
+//! - **Plain Text**: Resume text pasted from a PDF, with a confidence for each guess (`parse_text_resume`)
+//! - **Any of the above but plain text**, detected from the bytes (`parse_auto`)
+//!
+//! `import_resume` runs the import pipeline shared with the CLI and server:
+//! it also normalizes dates, folds duplicates, validates, and returns a report
+//! of what it changed.
+//!
+//! # Features
+//!
+//! Parsing, validation, and resume utilities are always built. The rest is
+//! opt-in so each bundle only ships what it uses:
+//!
+//! - `linkedin`: the LinkedIn parsers (adds zip and csv)
+//! - `storage`: the IndexedDB-backed `Storage` class
+//!
+//! Both are on by default. The web app builds a core bundle with `storage`
+//! and a separate `linkedin` bundle it loads on demand; see `make wasm`.
+//!
+//! # TypeScript
+//!
+//! Functions return schema types as plain objects. Their interfaces are
+//! generated from the Rust types into `schema.d.ts`, which every bundle's
+//! `.d.ts` includes; regenerate it with
+//! `RUSTUME_UPDATE_TS=1 cargo test -p rustume-schema typescript`.
+//!
+//! Thrown errors are `Error` objects with a stable `code` (see `ErrorCode`
+//! in `schema.d.ts`); branch on `error.code` rather than the message.
 
-use rustume_parser::{JsonResumeParser, LinkedInParser, Parser, ReactiveResumeV3Parser};
-use rustume_schema::ResumeData;
+use rustume_parser::{
+    ImportOptions, ImportPipeline, JsonResumeParser, Parser, ReactiveResumeV3Parser,
+    StackOverflowParser, TextResumeParser,
+};
+use rustume_schema::{
+    apply_preset, canonicalize, Award, Certification, CustomItem, Education, Experience, Interest,
+    Language, LayoutLocator, LayoutPreset, MergeStrategy, Profile, Project, Publication, Reference,
+    ResumeData, Skill, Volunteer,
+};
 use validator::Validate;
 use wasm_bindgen::prelude::*;
 
+use crate::error::{internal, invalid_input, js_error};
+
+mod error;
+#[cfg(feature = "linkedin")]
+mod linkedin;
+#[cfg(all(feature = "storage", target_arch = "wasm32"))]
+mod storage;
+
+#[cfg(feature = "linkedin")]
+pub use linkedin::{parse_linkedin_export, parse_linkedin_export_async, parse_linkedin_jobs};
+
+#[wasm_bindgen(typescript_custom_section)]
+const SCHEMA_TYPES: &str = include_str!("../schema.d.ts");
+
 /// Initialize the WASM module.
 #[wasm_bindgen(start)]
 pub fn init() {
@@ -38,13 +89,11 @@ pub fn init() {
 /// console.log(resume.basics.name);
 /// ```
 #[wasm_bindgen]
-pub fn parse_json_resume(input: &str) -> Result<JsValue, JsError> {
+pub fn parse_json_resume(input: &str) -> Result<JsValue, JsValue> {
     let parser = JsonResumeParser;
-    let resume = parser
-        .parse(input.as_bytes())
-        .map_err(|e| JsError::new(&e.to_string()))?;
+    let resume = parser.parse(input.as_bytes()).map_err(js_error)?;
 
-    serde_wasm_bindgen::to_value(&resume).map_err(|e| JsError::new(&e.to_string()))
+    serde_wasm_bindgen::to_value(&resume).map_err(|e| internal(&e))
 }
 
 /// Parse a Reactive Resume V3 JSON export into Rustume format.
@@ -64,43 +113,120 @@ pub fn parse_json_resume(input: &str) -> Result<JsValue, JsError> {
 /// console.log(resume.basics.name);
 /// ```
 #[wasm_bindgen]
-pub fn parse_reactive_resume_v3(input: &str) -> Result<JsValue, JsError> {
+pub fn parse_reactive_resume_v3(input: &str) -> Result<JsValue, JsValue> {
     let parser = ReactiveResumeV3Parser;
-    let resume = parser
-        .parse(input.as_bytes())
-        .map_err(|e| JsError::new(&e.to_string()))?;
+    let resume = parser.parse(input.as_bytes()).map_err(js_error)?;
 
-    serde_wasm_bindgen::to_value(&resume).map_err(|e| JsError::new(&e.to_string()))
+    serde_wasm_bindgen::to_value(&resume).map_err(|e| internal(&e))
 }
 
-/// Parse a LinkedIn data export ZIP file into Rustume format.
+/// Parse a Stack Overflow data export into Rustume format.
+///
+/// # Arguments
+/// * `input` - The export as XML (`<row>` elements) or JSON
 ///
-/// LinkedIn allows users to download their data as a ZIP file containing
-/// CSV files. This function parses that ZIP file and extracts resume data.
+/// # Returns
+/// A JavaScript object representing the parsed resume data.
+///
+/// # Example (JavaScript)
+/// ```js
+/// const resume = parse_stack_overflow(exportXml);
+/// console.log(resume.sections.skills.items);
+/// ```
+#[wasm_bindgen]
+pub fn parse_stack_overflow(input: &str) -> Result<JsValue, JsValue> {
+    let parser = StackOverflowParser;
+    let resume = parser.parse(input.as_bytes()).map_err(js_error)?;
+
+    serde_wasm_bindgen::to_value(&resume).map_err(|e| internal(&e))
+}
+
+/// Parse a resume pasted as plain text.
+///
+/// Sections, dates, and contact details are found heuristically, so the
+/// result is a draft: `confidence` lists each guess as `{ path, confidence,
+/// reason }`, with `confidence` one of `low`, `medium`, or `high`.
 ///
 /// # Arguments
-/// * `data` - Raw bytes of the LinkedIn ZIP export file (Uint8Array in JS)
+/// * `input` - Resume text, such as the contents of a PDF copied to the clipboard
+///
+/// # Returns
+/// A JavaScript object `{ resume, confidence }`.
+///
+/// # Example (JavaScript)
+/// ```js
+/// const { resume, confidence } = parse_text_resume(pastedText);
+/// const toCheck = confidence.filter((field) => field.confidence === "low");
+/// ```
+#[wasm_bindgen]
+pub fn parse_text_resume(input: &str) -> Result<JsValue, JsValue> {
+    let draft = TextResumeParser.draft(input.as_bytes()).map_err(js_error)?;
+
+    serde_wasm_bindgen::to_value(&draft).map_err(|e| internal(&e))
+}
+
+/// Parse a resume in whatever format it turns out to be.
+///
+/// The format is detected from the bytes: magic bytes, markup, and JSON
+/// shape. Plain text is never detected; use `parse_text_resume` for it.
+/// LinkedIn exports are only read by a build with the `linkedin` feature.
+/// The result goes through the same import pipeline as `import_resume` with
+/// its default options, so an invalid resume throws.
+///
+/// # Arguments
+/// * `data` - The file contents
 ///
 /// # Returns
 /// A JavaScript object representing the parsed resume data.
 ///
 /// # Example (JavaScript)
 /// ```js
-/// // From file input
-/// const file = event.target.files[0];
-/// const arrayBuffer = await file.arrayBuffer();
-/// const data = new Uint8Array(arrayBuffer);
-/// const resume = parse_linkedin_export(data);
+/// const resume = parse_auto(new Uint8Array(await file.arrayBuffer()));
 /// console.log(resume.basics.name);
 /// ```
 #[wasm_bindgen]
-pub fn parse_linkedin_export(data: &[u8]) -> Result<JsValue, JsError> {
-    let parser = LinkedInParser;
-    let resume = parser
-        .parse(data)
-        .map_err(|e| JsError::new(&e.to_string()))?;
+pub fn parse_auto(data: &[u8]) -> Result<JsValue, JsValue> {
+    let imported = ImportPipeline::default().run(data).map_err(js_error)?;
+
+    serde_wasm_bindgen::to_value(&imported.resume).map_err(|e| internal(&e))
+}
+
+/// Import a resume and report what the import changed.
+///
+/// Runs the import pipeline the CLI and server use: detect the format (or
+/// use `options.format`), parse, normalize dates, fold duplicate items,
+/// assign IDs, and validate.
+///
+/// # Arguments
+/// * `data` - The file contents
+/// * `options` - Optional `{ format, locale, ids, lenient }`; `ids` is
+///   `"random"` or `"stable"`, and `lenient` keeps a resume that fails
+///   validation instead of throwing
+///
+/// # Returns
+/// `{ resume, report }`, where the report lists the detected format, the
+/// changes made, possible duplicates, the remaining issues, and the old keys
+/// of renamed fields the input used.
+///
+/// # Example (JavaScript)
+/// ```js
+/// const { resume, report } = import_resume(data, { locale: "de", lenient: true });
+/// report.issues.forEach((issue) => console.warn(issue.path, issue.message));
+/// ```
+#[wasm_bindgen]
+pub fn import_resume(data: &[u8], options: JsValue) -> Result<JsValue, JsValue> {
+    let options: ImportOptions = if options.is_undefined() || options.is_null() {
+        ImportOptions::default()
+    } else {
+        serde_wasm_bindgen::from_value(options).map_err(|e| invalid_input(&e))?
+    };
+    let imported = ImportPipeline::new(options).run(data).map_err(js_error)?;
 
-    serde_wasm_bindgen::to_value(&resume).map_err(|e| JsError::new(&e.to_string()))
+    serde_wasm_bindgen::to_value(&serde_json::json!({
+        "resume": imported.resume,
+        "report": imported.report,
+    }))
+    .map_err(|e| internal(&e))
 }
 
 // ============================================================================
@@ -109,31 +235,297 @@ pub fn parse_linkedin_export(data: &[u8]) -> Result<JsValue, JsError> {
 
 /// Validate resume data.
 #[wasm_bindgen]
-pub fn validate_resume(input: &str) -> Result<bool, JsError> {
-    let resume: ResumeData =
-        serde_json::from_str(input).map_err(|e| JsError::new(&e.to_string()))?;
+pub fn validate_resume(input: &str) -> Result<bool, JsValue> {
+    let resume: ResumeData = serde_json::from_str(input).map_err(|e| invalid_input(&e))?;
 
-    resume
-        .validate()
-        .map_err(|e| JsError::new(&e.to_string()))?;
+    resume.validate().map_err(js_error)?;
 
     Ok(true)
 }
 
+/// Validate resume data and list every problem found.
+///
+/// Runs the field checks and the semantic rules (date order, duplicate IDs,
+/// contact details, theme contrast). Unlike `validate_resume`, problems are
+/// returned rather than thrown, so a form can mark the input at each `path`.
+///
+/// # Returns
+/// An array of `ValidationIssue` objects `{ path, code, message, severity }`,
+/// with `suggestion` when a fix can be computed; empty when the resume is
+/// clean. The resume is valid when no issue has severity `"error"`.
+///
+/// # Example (JavaScript)
+/// ```js
+/// const issues = validate_resume_detailed(JSON.stringify(resume));
+/// // [{ path: "basics.email", code: "invalid_email", severity: "error",
+/// //    message: "Must be a valid email address" }]
+/// const valid = issues.every((issue) => issue.severity !== "error");
+/// ```
+#[wasm_bindgen]
+pub fn validate_resume_detailed(input: &str) -> Result<JsValue, JsValue> {
+    let resume: ResumeData = serde_json::from_str(input).map_err(|e| invalid_input(&e))?;
+
+    serde_wasm_bindgen::to_value(&resume.issues()).map_err(|e| internal(&e))
+}
+
 /// Create a new empty resume with defaults.
 #[wasm_bindgen]
-pub fn create_empty_resume() -> Result<JsValue, JsError> {
+pub fn create_empty_resume() -> Result<JsValue, JsValue> {
     let resume = ResumeData::default();
-    serde_wasm_bindgen::to_value(&resume).map_err(|e| JsError::new(&e.to_string()))
+    serde_wasm_bindgen::to_value(&resume).map_err(|e| internal(&e))
 }
 
 /// Serialize resume to JSON string.
 #[wasm_bindgen]
-pub fn resume_to_json(resume: JsValue) -> Result<String, JsError> {
+pub fn resume_to_json(resume: JsValue) -> Result<String, JsValue> {
+    let resume: ResumeData =
+        serde_wasm_bindgen::from_value(resume).map_err(|e| invalid_input(&e))?;
+
+    serde_json::to_string_pretty(&resume).map_err(|e| internal(&e))
+}
+
+/// Serialize resume to canonical JSON: sorted keys, no whitespace, and
+/// numbers written one way, so equal content gives equal strings.
+///
+/// # Example (JavaScript)
+/// ```js
+/// const unchanged = canonicalize_resume(saved) === canonicalize_resume(draft);
+/// ```
+#[wasm_bindgen]
+pub fn canonicalize_resume(resume: JsValue) -> Result<String, JsValue> {
+    let resume: ResumeData =
+        serde_wasm_bindgen::from_value(resume).map_err(|e| invalid_input(&e))?;
+
+    Ok(canonicalize(&resume))
+}
+
+/// Compute per-position durations and total years of experience.
+///
+/// # Returns
+/// An object `{ positions, totalExperienceMonths, totalExperienceYears, totalExperience }`.
+///
+/// # Example (JavaScript)
+/// ```js
+/// const { totalExperience } = resume_stats(resume);
+/// console.log(`${totalExperience} of experience`); // "6 yrs 2 mos of experience"
+/// ```
+#[wasm_bindgen]
+pub fn resume_stats(resume: JsValue) -> Result<JsValue, JsValue> {
     let resume: ResumeData =
-        serde_wasm_bindgen::from_value(resume).map_err(|e| JsError::new(&e.to_string()))?;
+        serde_wasm_bindgen::from_value(resume).map_err(|e| invalid_input(&e))?;
 
-    serde_json::to_string_pretty(&resume).map_err(|e| JsError::new(&e.to_string()))
+    serde_wasm_bindgen::to_value(&resume.stats()).map_err(|e| internal(&e))
+}
+
+/// Draw the career timeline as an SVG: one bar per visible position,
+/// labeled with the company, with education shaded behind.
+///
+/// # Returns
+/// The SVG markup, sized 800 wide and scaling with its `viewBox`.
+///
+/// # Example (JavaScript)
+/// ```js
+/// document.querySelector("#timeline").innerHTML = render_timeline(resume);
+/// ```
+#[wasm_bindgen]
+pub fn render_timeline(resume: JsValue) -> Result<String, JsValue> {
+    let resume: ResumeData =
+        serde_wasm_bindgen::from_value(resume).map_err(|e| invalid_input(&e))?;
+
+    Ok(rustume_parser::render_timeline(&resume))
+}
+
+/// Merge a newly imported resume into an existing one.
+///
+/// Appends only imported items without a near-duplicate in `existing`; existing
+/// items keep their IDs, visibility, and edits. `strategy` is `"append_new"`
+/// (default) or `"fill_blanks"`, which also fills empty fields of matched
+/// items, basics, and the summary.
+///
+/// # Returns
+/// An object `{ resume, report: { added, skipped, updated } }`.
+///
+/// # Example (JavaScript)
+/// ```js
+/// const imported = parse_linkedin_export(data);
+/// const { resume, report } = merge_import(current, imported);
+/// console.log(`${report.added.length} new, ${report.skipped.length} already present`);
+/// ```
+#[wasm_bindgen]
+pub fn merge_import(
+    existing: JsValue,
+    imported: JsValue,
+    strategy: Option<String>,
+) -> Result<JsValue, JsValue> {
+    let existing: ResumeData =
+        serde_wasm_bindgen::from_value(existing).map_err(|e| invalid_input(&e))?;
+    let imported: ResumeData =
+        serde_wasm_bindgen::from_value(imported).map_err(|e| invalid_input(&e))?;
+    let strategy: MergeStrategy = match strategy {
+        Some(strategy) => serde_json::from_value(serde_json::Value::String(strategy))
+            .map_err(|e| invalid_input(&e))?,
+        None => MergeStrategy::default(),
+    };
+
+    let merged = rustume_schema::merge_import(existing, imported, strategy).map_err(js_error)?;
+    serde_wasm_bindgen::to_value(&merged).map_err(|e| internal(&e))
+}
+
+// ============================================================================
+// Layout Functions
+// ============================================================================
+
+/// Move a section to a new spot in the resume's layout.
+///
+/// `index` is where the section ends up in the target column; the page and
+/// column are created when they don't exist yet.
+///
+/// # Returns
+/// The updated layout (pages → columns → section keys).
+///
+/// # Example (JavaScript)
+/// ```js
+/// // Drop "skills" at the top of the sidebar
+/// resume.metadata.layout = move_layout_section(resume, "skills", 0, 1, 0);
+/// ```
+#[wasm_bindgen]
+pub fn move_layout_section(
+    resume: JsValue,
+    section: &str,
+    page: usize,
+    column: usize,
+    index: usize,
+) -> Result<JsValue, JsValue> {
+    let resume: ResumeData =
+        serde_wasm_bindgen::from_value(resume).map_err(|e| invalid_input(&e))?;
+
+    let mut layout = resume.metadata.layout;
+    let target = LayoutLocator {
+        page,
+        column,
+        section: index,
+    };
+    layout.move_section(section, target).map_err(js_error)?;
+    serde_wasm_bindgen::to_value(&layout).map_err(|e| internal(&e))
+}
+
+/// Clean up the resume's layout: drop unknown and repeated section keys and
+/// append sections that aren't placed to the last column of the first page.
+///
+/// # Returns
+/// The normalized layout.
+///
+/// # Example (JavaScript)
+/// ```js
+/// resume.metadata.layout = normalize_layout(resume);
+/// ```
+#[wasm_bindgen]
+pub fn normalize_layout(resume: JsValue) -> Result<JsValue, JsValue> {
+    let resume: ResumeData =
+        serde_wasm_bindgen::from_value(resume).map_err(|e| invalid_input(&e))?;
+
+    let mut layout = resume.metadata.layout;
+    layout.normalize(&resume.sections);
+    serde_wasm_bindgen::to_value(&layout).map_err(|e| internal(&e))
+}
+
+/// Reorder and show or hide sections with a named preset: `chronological`,
+/// `functional`, `hybrid`, or `academic`.
+///
+/// # Returns
+/// The resume with the preset's layout and section visibility.
+///
+/// # Example (JavaScript)
+/// ```js
+/// resume = apply_layout_preset(resume, "functional");
+/// ```
+#[wasm_bindgen]
+pub fn apply_layout_preset(resume: JsValue, preset: &str) -> Result<JsValue, JsValue> {
+    let mut resume: ResumeData =
+        serde_wasm_bindgen::from_value(resume).map_err(|e| invalid_input(&e))?;
+    let preset: LayoutPreset = preset.parse().map_err(|e: String| invalid_input(&e))?;
+
+    apply_preset(&mut resume, preset);
+    serde_wasm_bindgen::to_value(&resume).map_err(|e| internal(&e))
+}
+
+/// List problems with the resume's layout (unknown or repeated section keys).
+///
+/// # Returns
+/// An array of messages; empty when the layout is valid.
+///
+/// # Example (JavaScript)
+/// ```js
+/// check_layout(resume);
+/// // ["Unknown section in layout: hobbies"]
+/// ```
+#[wasm_bindgen]
+pub fn check_layout(resume: JsValue) -> Result<JsValue, JsValue> {
+    let resume: ResumeData =
+        serde_wasm_bindgen::from_value(resume).map_err(|e| invalid_input(&e))?;
+
+    let messages: Vec<String> = resume
+        .metadata
+        .layout
+        .check(&resume.sections)
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+    serde_wasm_bindgen::to_value(&messages).map_err(|e| internal(&e))
+}
+
+// ============================================================================
+// Section Item Functions
+// ============================================================================
+
+/// Define a WASM constructor per section item type, each returning the item
+/// from the type's `new` with every other field at its Rust default and a
+/// generated ID.
+macro_rules! item_constructors {
+    ($($(#[doc = $doc:literal])* $name:ident => $item:ident($($arg:ident),*);)*) => {
+        $(
+            $(#[doc = $doc])*
+            #[wasm_bindgen]
+            pub fn $name($($arg: &str),*) -> Result<JsValue, JsValue> {
+                serde_wasm_bindgen::to_value(&$item::new($($arg),*)).map_err(|e| internal(&e))
+            }
+        )*
+    };
+}
+
+item_constructors! {
+    /// Create an experience item with a generated ID.
+    ///
+    /// # Example (JavaScript)
+    /// ```js
+    /// const job = new_experience("Acme", "Engineer");
+    /// // { id: "tz4a98xxat96iws9zmbrgj3a", visible: true, company: "Acme", ... }
+    /// ```
+    new_experience => Experience(company, position);
+    /// Create an education item with a generated ID.
+    new_education => Education(institution, area);
+    /// Create a skill item with a generated ID and level 1.
+    new_skill => Skill(name);
+    /// Create a project item with a generated ID.
+    new_project => Project(name);
+    /// Create a profile item with a generated ID and the network's icon.
+    new_profile => Profile(network, username);
+    /// Create an award item with a generated ID.
+    new_award => Award(title);
+    /// Create a certification item with a generated ID.
+    new_certification => Certification(name, issuer);
+    /// Create a publication item with a generated ID.
+    new_publication => Publication(name);
+    /// Create a language item with a generated ID and level 1.
+    new_language => Language(name);
+    /// Create an interest item with a generated ID.
+    new_interest => Interest(name);
+    /// Create a volunteer item with a generated ID.
+    new_volunteer => Volunteer(organization, position);
+    /// Create a reference item with a generated ID.
+    new_reference => Reference(name);
+    /// Create a custom section item with a generated ID.
+    new_custom_item => CustomItem(name);
 }
 
 // ============================================================================
@@ -156,7 +548,7 @@ pub fn resume_to_json(resume: JsValue) -> Result<String, JsError> {
 ///
 /// **Keep in sync with:** `crates/render/src/typst_engine/engine.rs::TEMPLATES`
 #[wasm_bindgen]
-pub fn list_templates() -> Result<JsValue, JsError> {
+pub fn list_templates() -> Result<JsValue, JsValue> {
     // Hardcoded list since we can't import rustume_render in WASM
     let templates = vec![
         "rhyhorn",
@@ -172,7 +564,7 @@ pub fn list_templates() -> Result<JsValue, JsError> {
         "leafish",
         "onyx",
     ];
-    serde_wasm_bindgen::to_value(&templates).map_err(|e| JsError::new(&e.to_string()))
+    serde_wasm_bindgen::to_value(&templates).map_err(|e| internal(&e))
 }
 
 /// Get the default theme colors for a template.
@@ -189,7 +581,7 @@ pub fn list_templates() -> Result<JsValue, JsError> {
 /// // { background: "#ffffff", text: "#000000", primary: "#65a30d" }
 /// ```
 #[wasm_bindgen]
-pub fn get_template_theme_js(template: &str) -> Result<JsValue, JsError> {
+pub fn get_template_theme_js(template: &str) -> Result<JsValue, JsValue> {
     // Hardcoded themes since we can't import rustume_render in WASM
     let (background, text, primary) = match template {
         "rhyhorn" => ("#ffffff", "#000000", "#65a30d"),
@@ -212,158 +604,5 @@ pub fn get_template_theme_js(template: &str) -> Result<JsValue, JsError> {
         "text": text,
         "primary": primary,
     }))
-    .map_err(|e| JsError::new(&e.to_string()))
-}
-
-// ============================================================================
-// Storage Functions (WASM only - IndexedDB)
-// ============================================================================
-
-#[cfg(target_arch = "wasm32")]
-mod storage_wasm {
-    use super::*;
-    use rustume_storage::{IndexedDbStorage, StorageBackend, StorageError};
-    use wasm_bindgen_futures::future_to_promise;
-
-    /// Storage wrapper for WASM bindings.
-    #[wasm_bindgen]
-    pub struct Storage {
-        db_name: String,
-    }
-
-    #[wasm_bindgen]
-    impl Storage {
-        /// Create a new storage instance.
-        ///
-        /// # Arguments
-        /// * `db_name` - Name of the IndexedDB database (default: "rustume")
-        ///
-        /// # Example (JavaScript)
-        /// ```js
-        /// const storage = new Storage("my-resumes");
-        /// ```
-        #[wasm_bindgen(constructor)]
-        pub fn new(db_name: Option<String>) -> Self {
-            Self {
-                db_name: db_name.unwrap_or_else(|| "rustume".to_string()),
-            }
-        }
-
-        /// List all resume IDs.
-        ///
-        /// # Returns
-        /// A Promise resolving to an array of resume IDs.
-        ///
-        /// # Example (JavaScript)
-        /// ```js
-        /// const ids = await storage.list();
-        /// // ["resume-1", "resume-2"]
-        /// ```
-        pub fn list(&self) -> js_sys::Promise {
-            let storage = IndexedDbStorage::new(self.db_name.clone());
-            future_to_promise(async move {
-                let ids: Vec<String> = storage
-                    .list()
-                    .await
-                    .map_err(|e: StorageError| JsValue::from_str(&e.to_string()))?;
-                serde_wasm_bindgen::to_value(&ids).map_err(|e| JsValue::from_str(&e.to_string()))
-            })
-        }
-
-        /// Get a resume by ID.
-        ///
-        /// # Arguments
-        /// * `id` - Resume ID
-        ///
-        /// # Returns
-        /// A Promise resolving to the resume data, or rejecting if not found.
-        ///
-        /// # Example (JavaScript)
-        /// ```js
-        /// const resume = await storage.get("my-resume-id");
-        /// console.log(resume.basics.name);
-        /// ```
-        pub fn get(&self, id: String) -> js_sys::Promise {
-            let storage = IndexedDbStorage::new(self.db_name.clone());
-            future_to_promise(async move {
-                let resume: ResumeData = storage
-                    .get(&id)
-                    .await
-                    .map_err(|e: StorageError| JsValue::from_str(&e.to_string()))?;
-                serde_wasm_bindgen::to_value(&resume).map_err(|e| JsValue::from_str(&e.to_string()))
-            })
-        }
-
-        /// Save a resume.
-        ///
-        /// # Arguments
-        /// * `id` - Resume ID
-        /// * `resume` - Resume data to save
-        ///
-        /// # Returns
-        /// A Promise resolving when save is complete.
-        ///
-        /// # Example (JavaScript)
-        /// ```js
-        /// await storage.save("my-resume-id", resume);
-        /// ```
-        pub fn save(&self, id: String, resume: JsValue) -> js_sys::Promise {
-            let storage = IndexedDbStorage::new(self.db_name.clone());
-            future_to_promise(async move {
-                let resume: ResumeData = serde_wasm_bindgen::from_value(resume)
-                    .map_err(|e| JsValue::from_str(&e.to_string()))?;
-                storage
-                    .save(&id, &resume)
-                    .await
-                    .map_err(|e: StorageError| JsValue::from_str(&e.to_string()))?;
-                Ok(JsValue::UNDEFINED)
-            })
-        }
-
-        /// Delete a resume.
-        ///
-        /// # Arguments
-        /// * `id` - Resume ID
-        ///
-        /// # Returns
-        /// A Promise resolving when delete is complete, or rejecting if not found.
-        ///
-        /// # Example (JavaScript)
-        /// ```js
-        /// await storage.delete("my-resume-id");
-        /// ```
-        pub fn delete(&self, id: String) -> js_sys::Promise {
-            let storage = IndexedDbStorage::new(self.db_name.clone());
-            future_to_promise(async move {
-                storage
-                    .delete(&id)
-                    .await
-                    .map_err(|e: StorageError| JsValue::from_str(&e.to_string()))?;
-                Ok(JsValue::UNDEFINED)
-            })
-        }
-
-        /// Check if a resume exists.
-        ///
-        /// # Arguments
-        /// * `id` - Resume ID
-        ///
-        /// # Returns
-        /// A Promise resolving to a boolean.
-        ///
-        /// # Example (JavaScript)
-        /// ```js
-        /// const exists = await storage.exists("my-resume-id");
-        /// ```
-        pub fn exists(&self, id: String) -> js_sys::Promise {
-            let storage = IndexedDbStorage::new(self.db_name.clone());
-            future_to_promise(async move {
-                let exists: bool = storage
-                    .exists(&id)
-                    .await
-                    .map_err(|e: StorageError| JsValue::from_str(&e.to_string()))?;
-                Ok(JsValue::from_bool(exists))
-            })
-        }
-    }
+    .map_err(|e| internal(&e))
 }
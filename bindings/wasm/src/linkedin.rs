@@ -0,0 +1,158 @@
+//! LinkedIn export parsers (`linkedin` feature).
+//!
+//! Kept out of the core bundle because they pull in zip and csv. The web app
+//! loads a build with this feature only when a LinkedIn import starts.
+
+use js_sys::{Function, Object, Promise, Reflect, Uint8Array};
+use rustume_parser::{
+    LinkedInData, LinkedInJobsParser, LinkedInParser, ParsePhase, ParseProgress, Parser,
+};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::{future_to_promise, JsFuture};
+
+use crate::error::{internal, js_error};
+
+/// Parse a LinkedIn data export ZIP file into Rustume format.
+///
+/// LinkedIn allows users to download their data as a ZIP file containing
+/// CSV files. This function parses that ZIP file and extracts resume data.
+///
+/// # Arguments
+/// * `data` - Raw bytes of the LinkedIn ZIP export file (Uint8Array in JS)
+/// * `on_progress` - Optional callback, called with `{ phase, percent, detail }`
+///   as the archive is read (`detail` is the CSV filename, when there is one)
+///
+/// # Returns
+/// A JavaScript object representing the parsed resume data.
+///
+/// # Example (JavaScript)
+/// ```js
+/// // From file input
+/// const file = event.target.files[0];
+/// const arrayBuffer = await file.arrayBuffer();
+/// const data = new Uint8Array(arrayBuffer);
+/// const resume = parse_linkedin_export(data, ({ percent }) => console.log(percent));
+/// console.log(resume.basics.name);
+/// ```
+#[wasm_bindgen]
+pub fn parse_linkedin_export(
+    data: &[u8],
+    on_progress: Option<Function>,
+) -> Result<JsValue, JsValue> {
+    let parser = LinkedInParser::default();
+    let resume = match on_progress {
+        Some(callback) => parser.parse_with_progress(data, &mut |progress| {
+            // A throwing callback shouldn't abort the import
+            let _ = report_progress(&callback, &progress);
+        }),
+        None => parser.parse(data),
+    }
+    .map_err(js_error)?;
+
+    serde_wasm_bindgen::to_value(&resume).map_err(|e| internal(&e))
+}
+
+/// Parse a LinkedIn data export ZIP file without blocking the event loop.
+///
+/// Same result as `parse_linkedin_export`, but the archive is processed one
+/// CSV file at a time, yielding to the event loop in between. Meant to run in
+/// a Web Worker: transfer the buffer to the worker instead of copying it, and
+/// forward progress to the page.
+///
+/// # Arguments
+/// * `data` - Raw bytes of the LinkedIn ZIP export file
+/// * `on_progress` - Optional callback, called with `{ phase, percent, detail }`
+///   after each archive entry, as in `parse_linkedin_export`
+///
+/// # Returns
+/// A Promise that resolves to the parsed resume data.
+///
+/// # Example (JavaScript, in a worker)
+/// ```js
+/// self.onmessage = async ({ data: bytes }) => {
+///   const resume = await parse_linkedin_export_async(bytes, (progress) =>
+///     self.postMessage({ type: "progress", ...progress }),
+///   );
+///   self.postMessage({ type: "done", resume });
+/// };
+/// // On the page: worker.postMessage(bytes, [bytes.buffer]);
+/// ```
+#[wasm_bindgen]
+pub fn parse_linkedin_export_async(data: &Uint8Array, on_progress: Option<Function>) -> Promise {
+    // Copy out of JS memory up front; the caller may detach the buffer once
+    // this returns.
+    let bytes = data.to_vec();
+    future_to_promise(async move {
+        let parser = LinkedInParser::default();
+        let report = |progress: ParseProgress| {
+            if let Some(callback) = &on_progress {
+                let _ = report_progress(callback, &progress);
+            }
+        };
+
+        report(ParseProgress::new(ParsePhase::Read, 0));
+        let mut entries = parser.entries(&bytes).map_err(js_error)?;
+        let mut linkedin = LinkedInData::default();
+        while let Some(entry) = entries.next() {
+            let entry = entry.map_err(js_error)?;
+            parser
+                .collect_entry(&mut linkedin, &entry)
+                .map_err(js_error)?;
+            report(LinkedInParser::entry_progress(&entries, &entry));
+            yield_now().await?;
+        }
+
+        report(ParseProgress::new(ParsePhase::Convert, 90));
+        let resume = parser.convert(linkedin).map_err(js_error)?;
+        report(ParseProgress::new(ParsePhase::Convert, 100));
+        serde_wasm_bindgen::to_value(&resume).map_err(|e| internal(&e))
+    })
+}
+
+/// Call `callback` with `{ phase, percent, detail }`.
+fn report_progress(callback: &Function, progress: &ParseProgress) -> Result<(), JsValue> {
+    let object = Object::new();
+    Reflect::set(&object, &"phase".into(), &progress.phase.as_str().into())?;
+    Reflect::set(&object, &"percent".into(), &progress.percent.into())?;
+    let detail = progress
+        .detail
+        .as_deref()
+        .map_or(JsValue::UNDEFINED, JsValue::from);
+    Reflect::set(&object, &"detail".into(), &detail)?;
+    callback.call1(&JsValue::NULL, &object)?;
+    Ok(())
+}
+
+/// Resolve on the next macrotask, so pending messages and rendering get a
+/// turn. Uses the global `setTimeout`, which windows and workers both have.
+async fn yield_now() -> Result<(), JsValue> {
+    let global = js_sys::global();
+    let set_timeout: Function = Reflect::get(&global, &"setTimeout".into())?.dyn_into()?;
+    let tick = Promise::new(&mut |resolve, _reject| {
+        let _ = set_timeout.call2(&global, &resolve, &JsValue::from(0));
+    });
+    JsFuture::from(tick).await?;
+    Ok(())
+}
+
+/// Parse job applications and saved jobs from a LinkedIn data export ZIP file.
+///
+/// Returns an array of job application objects for the tracker: submitted
+/// applications first, then saved jobs that were not applied to. Save them
+/// with `Storage.save_application`.
+///
+/// # Example (JavaScript)
+/// ```js
+/// const data = new Uint8Array(await file.arrayBuffer());
+/// for (const application of parse_linkedin_jobs(data)) {
+///   await storage.save_application(application);
+/// }
+/// ```
+#[wasm_bindgen]
+pub fn parse_linkedin_jobs(data: &[u8]) -> Result<JsValue, JsValue> {
+    let applications = LinkedInJobsParser::default()
+        .parse(data)
+        .map_err(js_error)?;
+
+    serde_wasm_bindgen::to_value(&applications).map_err(|e| internal(&e))
+}
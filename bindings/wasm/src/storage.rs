@@ -0,0 +1,360 @@
+//! IndexedDB storage bindings (`storage` feature, wasm32 only).
+
+use rustume_schema::{validation_messages, ErrorCode, JobApplication, ResumeData, RustumeError};
+use rustume_storage::{
+    ApplicationStore, HistoryEntry, IndexedDbStorage, ResumeHistory, ResumeSearch, ResumeTrash,
+    SearchResult, StorageBackend, TrashedResume, TRASH_RETENTION_DAYS,
+};
+use validator::Validate;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::future_to_promise;
+
+use crate::error::{internal, invalid_input, js_error};
+
+/// Storage wrapper for WASM bindings.
+#[wasm_bindgen]
+pub struct Storage {
+    db_name: String,
+}
+
+#[wasm_bindgen]
+impl Storage {
+    /// Create a new storage instance.
+    ///
+    /// # Arguments
+    /// * `db_name` - Name of the IndexedDB database (default: "rustume")
+    ///
+    /// # Example (JavaScript)
+    /// ```js
+    /// const storage = new Storage("my-resumes");
+    /// ```
+    #[wasm_bindgen(constructor)]
+    pub fn new(db_name: Option<String>) -> Self {
+        Self {
+            db_name: db_name.unwrap_or_else(|| "rustume".to_string()),
+        }
+    }
+
+    /// List all resume IDs.
+    ///
+    /// # Returns
+    /// A Promise resolving to an array of resume IDs.
+    ///
+    /// # Example (JavaScript)
+    /// ```js
+    /// const ids = await storage.list();
+    /// // ["resume-1", "resume-2"]
+    /// ```
+    pub fn list(&self) -> js_sys::Promise {
+        let storage = IndexedDbStorage::new(self.db_name.clone());
+        future_to_promise(async move {
+            let ids: Vec<String> = storage.list().await.map_err(js_error)?;
+            serde_wasm_bindgen::to_value(&ids).map_err(|e| internal(&e))
+        })
+    }
+
+    /// Get a resume by ID.
+    ///
+    /// # Arguments
+    /// * `id` - Resume ID
+    ///
+    /// # Returns
+    /// A Promise resolving to the resume data, or rejecting if not found.
+    ///
+    /// # Example (JavaScript)
+    /// ```js
+    /// const resume = await storage.get("my-resume-id");
+    /// console.log(resume.basics.name);
+    /// ```
+    pub fn get(&self, id: String) -> js_sys::Promise {
+        let storage = IndexedDbStorage::new(self.db_name.clone());
+        future_to_promise(async move {
+            let resume: ResumeData = storage.get(&id).await.map_err(js_error)?;
+            serde_wasm_bindgen::to_value(&resume).map_err(|e| internal(&e))
+        })
+    }
+
+    /// Save a resume.
+    ///
+    /// # Arguments
+    /// * `id` - Resume ID
+    /// * `resume` - Resume data to save
+    ///
+    /// # Returns
+    /// A Promise resolving when save is complete.
+    ///
+    /// # Example (JavaScript)
+    /// ```js
+    /// await storage.save("my-resume-id", resume);
+    /// ```
+    pub fn save(&self, id: String, resume: JsValue) -> js_sys::Promise {
+        let storage = IndexedDbStorage::new(self.db_name.clone());
+        future_to_promise(async move {
+            let resume: ResumeData =
+                serde_wasm_bindgen::from_value(resume).map_err(|e| invalid_input(&e))?;
+            storage.save(&id, &resume).await.map_err(js_error)?;
+            Ok(JsValue::UNDEFINED)
+        })
+    }
+
+    /// Delete a resume, moving it to the trash.
+    ///
+    /// # Arguments
+    /// * `id` - Resume ID
+    ///
+    /// # Returns
+    /// A Promise resolving when delete is complete, or rejecting if not found.
+    ///
+    /// # Example (JavaScript)
+    /// ```js
+    /// await storage.delete("my-resume-id");
+    /// ```
+    pub fn delete(&self, id: String) -> js_sys::Promise {
+        let storage = IndexedDbStorage::new(self.db_name.clone());
+        future_to_promise(async move {
+            storage.delete(&id).await.map_err(js_error)?;
+            Ok(JsValue::UNDEFINED)
+        })
+    }
+
+    /// List resumes in the trash, most recently deleted first.
+    ///
+    /// # Returns
+    /// A Promise resolving to an array of `{ id, trashedAt, resume }`.
+    ///
+    /// # Example (JavaScript)
+    /// ```js
+    /// const trashed = await storage.list_trashed();
+    /// console.log(trashed[0]?.resume.basics.name);
+    /// ```
+    pub fn list_trashed(&self) -> js_sys::Promise {
+        let storage = IndexedDbStorage::new(self.db_name.clone());
+        future_to_promise(async move {
+            let trashed: Vec<TrashedResume> = storage.list_trashed().await.map_err(js_error)?;
+            serde_wasm_bindgen::to_value(&trashed).map_err(|e| internal(&e))
+        })
+    }
+
+    /// Restore a resume from the trash.
+    ///
+    /// # Arguments
+    /// * `id` - Resume ID
+    ///
+    /// # Returns
+    /// A Promise resolving when the resume is back, or rejecting if it is not
+    /// in the trash or a resume with its ID was saved since.
+    ///
+    /// # Example (JavaScript)
+    /// ```js
+    /// await storage.restore("my-resume-id");
+    /// ```
+    pub fn restore(&self, id: String) -> js_sys::Promise {
+        let storage = IndexedDbStorage::new(self.db_name.clone());
+        future_to_promise(async move {
+            storage.restore(&id).await.map_err(js_error)?;
+            Ok(JsValue::UNDEFINED)
+        })
+    }
+
+    /// Permanently delete a resume from the trash.
+    ///
+    /// # Arguments
+    /// * `id` - Resume ID
+    ///
+    /// # Returns
+    /// A Promise resolving when the resume is erased, or rejecting if it is
+    /// not in the trash.
+    ///
+    /// # Example (JavaScript)
+    /// ```js
+    /// await storage.purge("my-resume-id");
+    /// ```
+    pub fn purge(&self, id: String) -> js_sys::Promise {
+        let storage = IndexedDbStorage::new(self.db_name.clone());
+        future_to_promise(async move {
+            storage.purge(&id).await.map_err(js_error)?;
+            Ok(JsValue::UNDEFINED)
+        })
+    }
+
+    /// Permanently delete resumes that have been in the trash too long.
+    ///
+    /// # Arguments
+    /// * `retention_days` - Days to keep trashed resumes (default: 30)
+    ///
+    /// # Returns
+    /// A Promise resolving to the number of resumes erased.
+    ///
+    /// # Example (JavaScript)
+    /// ```js
+    /// const purged = await storage.purge_expired();
+    /// ```
+    pub fn purge_expired(&self, retention_days: Option<u32>) -> js_sys::Promise {
+        let storage = IndexedDbStorage::new(self.db_name.clone());
+        future_to_promise(async move {
+            let purged = storage
+                .purge_expired(retention_days.unwrap_or(TRASH_RETENTION_DAYS))
+                .await
+                .map_err(js_error)?;
+            Ok(JsValue::from(purged as u32))
+        })
+    }
+
+    /// Check if a resume exists.
+    ///
+    /// # Arguments
+    /// * `id` - Resume ID
+    ///
+    /// # Returns
+    /// A Promise resolving to a boolean.
+    ///
+    /// # Example (JavaScript)
+    /// ```js
+    /// const exists = await storage.exists("my-resume-id");
+    /// ```
+    pub fn exists(&self, id: String) -> js_sys::Promise {
+        let storage = IndexedDbStorage::new(self.db_name.clone());
+        future_to_promise(async move {
+            let exists: bool = storage.exists(&id).await.map_err(js_error)?;
+            Ok(JsValue::from_bool(exists))
+        })
+    }
+
+    /// Search stored resumes.
+    ///
+    /// Every word of the query must appear somewhere in a resume for it
+    /// to match. Results are ranked best first, and each lists the
+    /// fields that matched with a short snippet.
+    ///
+    /// # Returns
+    /// A Promise resolving to an array of `{ resumeId, score, hits }`.
+    ///
+    /// # Example (JavaScript)
+    /// ```js
+    /// const results = await storage.search("rust kubernetes");
+    /// for (const hit of results[0]?.hits ?? []) console.log(hit.path, hit.snippet);
+    /// ```
+    pub fn search(&self, query: String) -> js_sys::Promise {
+        let storage = IndexedDbStorage::new(self.db_name.clone());
+        future_to_promise(async move {
+            let results: Vec<SearchResult> = storage.search(&query).await.map_err(js_error)?;
+            serde_wasm_bindgen::to_value(&results).map_err(|e| internal(&e))
+        })
+    }
+
+    /// Change log of a resume, oldest first.
+    ///
+    /// Every save that changed the resume, and its deletion, adds an entry
+    /// with a one-line summary of what changed. The log is kept after the
+    /// resume is deleted.
+    ///
+    /// # Returns
+    /// A Promise resolving to an array of `{ resumeId, at, action, summary }`,
+    /// where `action` is `"created"`, `"updated"`, or `"deleted"`.
+    ///
+    /// # Example (JavaScript)
+    /// ```js
+    /// const history = await storage.history("my-resume-id");
+    /// // [{ at: "2026-10-16T09:12:03.512Z", action: "updated", summary: "Edited basics (email)", ... }]
+    /// ```
+    pub fn history(&self, id: String) -> js_sys::Promise {
+        let storage = IndexedDbStorage::new(self.db_name.clone());
+        future_to_promise(async move {
+            let history: Vec<HistoryEntry> = storage.history(&id).await.map_err(js_error)?;
+            serde_wasm_bindgen::to_value(&history).map_err(|e| internal(&e))
+        })
+    }
+
+    /// List all job applications.
+    ///
+    /// # Returns
+    /// A Promise resolving to an array of job applications.
+    ///
+    /// # Example (JavaScript)
+    /// ```js
+    /// const applications = await storage.list_applications();
+    /// ```
+    pub fn list_applications(&self) -> js_sys::Promise {
+        let storage = IndexedDbStorage::new(self.db_name.clone());
+        future_to_promise(async move {
+            let applications: Vec<JobApplication> =
+                storage.list_applications().await.map_err(js_error)?;
+            serde_wasm_bindgen::to_value(&applications).map_err(|e| internal(&e))
+        })
+    }
+
+    /// Get a job application by ID.
+    ///
+    /// # Returns
+    /// A Promise resolving to the application, or rejecting if not found.
+    pub fn get_application(&self, id: String) -> js_sys::Promise {
+        let storage = IndexedDbStorage::new(self.db_name.clone());
+        future_to_promise(async move {
+            let application: JobApplication =
+                storage.get_application(&id).await.map_err(js_error)?;
+            serde_wasm_bindgen::to_value(&application).map_err(|e| internal(&e))
+        })
+    }
+
+    /// Validate and save a job application under its `id`.
+    ///
+    /// # Returns
+    /// A Promise resolving when save is complete, or rejecting with
+    /// validation errors.
+    ///
+    /// # Example (JavaScript)
+    /// ```js
+    /// await storage.save_application({
+    ///   id: "app-1",
+    ///   company: "Acme",
+    ///   role: "Engineer",
+    ///   status: "applied",
+    ///   resumeId: "my-resume-id",
+    /// });
+    /// ```
+    pub fn save_application(&self, application: JsValue) -> js_sys::Promise {
+        let storage = IndexedDbStorage::new(self.db_name.clone());
+        future_to_promise(async move {
+            let application: JobApplication =
+                serde_wasm_bindgen::from_value(application).map_err(|e| invalid_input(&e))?;
+            application.validate().map_err(|e| {
+                js_error(RustumeError::new(
+                    ErrorCode::ValidationFailed,
+                    format!("Validation failed: {}", validation_messages(&e).join("; ")),
+                ))
+            })?;
+            storage
+                .save_application(&application)
+                .await
+                .map_err(js_error)?;
+            Ok(JsValue::UNDEFINED)
+        })
+    }
+
+    /// Delete a job application.
+    ///
+    /// # Returns
+    /// A Promise resolving when delete is complete, or rejecting if not found.
+    pub fn delete_application(&self, id: String) -> js_sys::Promise {
+        let storage = IndexedDbStorage::new(self.db_name.clone());
+        future_to_promise(async move {
+            storage.delete_application(&id).await.map_err(js_error)?;
+            Ok(JsValue::UNDEFINED)
+        })
+    }
+
+    /// List job applications sent with a resume.
+    ///
+    /// # Returns
+    /// A Promise resolving to an array of job applications.
+    pub fn applications_for_resume(&self, resume_id: String) -> js_sys::Promise {
+        let storage = IndexedDbStorage::new(self.db_name.clone());
+        future_to_promise(async move {
+            let applications: Vec<JobApplication> = storage
+                .applications_for_resume(&resume_id)
+                .await
+                .map_err(js_error)?;
+            serde_wasm_bindgen::to_value(&applications).map_err(|e| internal(&e))
+        })
+    }
+}
@@ -7,30 +7,100 @@
 //! rustume parse resume.json --format json-resume
 //! rustume parse linkedin-export.zip --format linkedin
 //!
+//! # Import a saved personal web page with hResume or schema.org markup
+//! rustume import url about.html -o resume.json
+//!
+//! # Append skills from a spreadsheet export to an existing resume
+//! rustume import csv --section skills skills.csv --resume resume.json -o resume.json
+//!
 //! # Render resume to PDF
 //! rustume render resume.json -o resume.pdf
 //!
+//! # Export the employment timeline for a calendar app
+//! rustume export resume.json --format ics -o timeline.ics
+//!
+//! # Export a section for editing in a spreadsheet
+//! rustume export resume.json --section experience --format csv -o experience.csv
+//!
+//! # Render a public copy without the phone number
+//! rustume render resume.json --exclude-section basics.phone -o public.pdf
+//!
+//! # Print the HTML page with headless Chromium (`chromium` feature)
+//! rustume render resume.json --engine chromium -o resume.pdf
+//!
+//! # Build a static website with a PDF download
+//! rustume export-site resume.json -o ./site --url https://jane.example.com/
+//!
 //! # Preview resume as PNG
 //! rustume preview resume.json -o preview.png
 //!
+//! # Or as SVG, which stays sharp when zoomed
+//! rustume preview resume.json -o preview.svg
+//!
+//! # Compare the resume in every template
+//! rustume compare resume.json -o sheet.pdf
+//!
 //! # List available templates
 //! rustume templates
 //!
 //! # Validate resume data
 //! rustume validate resume.json
 //!
+//! # Review what changed between two versions, word by word
+//! rustume diff old.json new.json --format html -o changes.html
+//!
+//! # Suggest improvements to experience bullet points
+//! rustume suggest resume.json
+//!
+//! # Find and merge duplicate items, asking before each merge
+//! rustume dedupe resume.json -o resume.json
+//!
+//! # Fix duplicate IDs and broken layout entries in an old file
+//! rustume doctor old.json -o resume.json
+//!
+//! # Put skills and projects before work history
+//! rustume preset functional resume.json -o resume.json
+//!
 //! # Create new empty resume
 //! rustume init -o my-resume.json
+//!
+//! # Start from a profession's pre-filled skeleton
+//! rustume init --preset software-engineer -o my-resume.json
+//!
+//! # Keep a local count of renders and imports, then look at it
+//! rustume stats --usage --enable
+//! rustume stats --usage
+//!
+//! # Re-render whenever a template in ./templates is saved (`dev` feature)
+//! rustume preview resume.json --dev-templates ./templates -o preview.svg
 //! ```
 
 use anyhow::{anyhow, Context, Result};
-use clap::{Parser, Subcommand, ValueEnum};
-use rustume_parser::{parse_resume, ResumeFormat};
-use rustume_render::{get_template_theme, Renderer, TypstRenderer, TEMPLATES};
-use rustume_schema::ResumeData;
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use rustume_parser::{
+    detect_resume_format, import_section_csv, section_table, to_ics, to_vcard, CsvSection,
+    IdStrategy, ImportOptions, ImportPipeline, ParseError, ParseProgress, ResumeFormat,
+};
+#[cfg(feature = "chromium")]
+use rustume_render::ChromiumRenderer;
+use rustume_render::{
+    get_template_theme, layout_warnings, page_option_warnings, print_stylesheet, render_html_page,
+    resume_stylesheet, template_option_warnings, template_rules, HtmlPageOptions,
+    InstalledTemplates, Redaction, RenderError, Renderer, SheetFormat, TemplatePackage,
+    TemplatePackageError, TemplatePolicy, TemplateTheme, TypstRenderer, PRINT_STYLESHEET_FILE,
+    STYLESHEET_FILE, TEMPLATES,
+};
+use rustume_schema::{
+    apply_preset, diff_report, find_duplicates, merge_items, repair_json, validation_messages,
+    ContentPreset, DiffReportFormat, ErrorCode, FittedResume, LayoutError, LayoutPreset,
+    MergeError, RepairedResume, ResumeData, RustumeError, DEFAULT_SIMILARITY,
+};
+use rustume_storage::{StorageError, UsageLog, UsageStats};
+use rustume_utils::PartialDate;
+use std::collections::{BTreeMap, HashSet};
 use std::fs;
-use std::io::{self, Read, Write};
-use std::path::PathBuf;
+use std::io::{self, BufRead, IsTerminal, Read, Write};
+use std::path::{Path, PathBuf};
 use validator::Validate;
 
 /// Rustume - A modern resume builder
@@ -42,11 +112,17 @@ struct Cli {
     #[arg(short, long, global = true)]
     debug: bool,
 
+    /// Use the Typst templates in DIR instead of the built-in ones; `render`
+    /// and `preview` run again each time a template in DIR changes
+    #[cfg(feature = "dev")]
+    #[arg(long, global = true, value_name = "DIR")]
+    dev_templates: Option<PathBuf>,
+
     #[command(subcommand)]
     command: Commands,
 }
 
-#[derive(Subcommand)]
+#[derive(Clone, Subcommand)]
 enum Commands {
     /// Parse a resume file into Rustume format
     Parse {
@@ -64,6 +140,15 @@ enum Commands {
         /// Pretty print JSON output
         #[arg(long, default_value = "true")]
         pretty: bool,
+
+        #[command(flatten)]
+        import: ImportArgs,
+    },
+
+    /// Import a resume from another source
+    Import {
+        #[command(subcommand)]
+        source: ImportSource,
     },
 
     /// Render a resume to PDF
@@ -78,9 +163,66 @@ enum Commands {
         /// Output PDF file path
         #[arg(short, long)]
         output: Option<PathBuf>,
+
+        /// Leave a section or contact field (e.g. basics.phone) out of the PDF; repeatable
+        #[arg(long = "exclude-section", value_name = "KEY")]
+        exclude_sections: Vec<String>,
+
+        /// Leave the item with this ID out of the PDF; repeatable
+        #[arg(long = "exclude-item", value_name = "ID")]
+        exclude_item_ids: Vec<String>,
+
+        /// Rendering backend
+        #[arg(long, value_enum, default_value = "typst")]
+        engine: EngineArg,
     },
 
-    /// Generate a PNG preview of a resume page
+    /// Export resume data to another format
+    Export {
+        /// Input resume JSON file (use '-' for stdin)
+        input: String,
+
+        /// Output format
+        #[arg(short, long)]
+        format: ExportFormat,
+
+        /// Section to export as a table (`experience`, `skills`, `custom.<id>`, ...);
+        /// required for csv and xlsx
+        #[arg(short, long)]
+        section: Option<String>,
+
+        /// Output file path (defaults to stdout)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+
+    /// Build a static website from a resume, with a PDF download
+    ExportSite {
+        /// Input resume JSON file (use '-' for stdin)
+        input: String,
+
+        /// Output directory, created if missing
+        #[arg(short, long, default_value = "site")]
+        output: PathBuf,
+
+        /// Template for the PDF and site colors (overrides metadata.template if specified)
+        #[arg(short, long)]
+        template: Option<String>,
+
+        /// Public URL the site will be served from, for link previews
+        #[arg(long)]
+        url: Option<String>,
+
+        /// Leave a section or contact field (e.g. basics.phone) off the site; repeatable
+        #[arg(long = "exclude-section", value_name = "KEY")]
+        exclude_sections: Vec<String>,
+
+        /// Leave the item with this ID off the site; repeatable
+        #[arg(long = "exclude-item", value_name = "ID")]
+        exclude_item_ids: Vec<String>,
+    },
+
+    /// Generate a PNG or SVG preview of a resume page
     Preview {
         /// Input resume JSON file (use '-' for stdin)
         input: String,
@@ -93,9 +235,50 @@ enum Commands {
         #[arg(short, long)]
         template: Option<String>,
 
-        /// Output PNG file path
+        /// Output file path; a `.svg` extension writes an SVG, anything else a PNG
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+
+    /// Render the first page in every template side by side, to pick one
+    Compare {
+        /// Input resume JSON file (use '-' for stdin)
+        input: String,
+
+        /// Output file path; a `.png` extension writes an image, anything else a PDF
+        #[arg(short, long, default_value = "templates.pdf")]
+        output: PathBuf,
+
+        /// Only compare this template; repeatable (defaults to all templates)
+        #[arg(short, long = "template", value_name = "NAME")]
+        templates: Vec<String>,
+    },
+
+    /// Suggest cuts that bring a resume down to a number of pages: narrower
+    /// margins, a smaller font, then hiding the oldest, least important items
+    Fit {
+        /// Input resume JSON file (use '-' for stdin)
+        input: String,
+
+        /// Page budget
+        #[arg(short, long, default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+        pages: u32,
+
+        /// Template to use (overrides metadata.template if specified)
+        #[arg(short, long)]
+        template: Option<String>,
+
+        /// Never hide items with this keyword; repeatable
+        #[arg(long, value_name = "KEYWORD")]
+        keep: Vec<String>,
+
+        /// Write the fitted resume here (without it, only suggest)
         #[arg(short, long)]
         output: Option<PathBuf>,
+
+        /// Print the report as JSON
+        #[arg(long)]
+        json: bool,
     },
 
     /// List available templates
@@ -105,12 +288,123 @@ enum Commands {
         verbose: bool,
     },
 
+    /// Check a new Typst template against fixture resumes (empty, minimal,
+    /// huge, RTL, CJK) for compile errors, sections it renders nothing for,
+    /// and text that runs off the page
+    TemplateCheck {
+        /// Typst template file
+        file: PathBuf,
+    },
+
+    /// Install or uninstall community template packages
+    Template {
+        #[command(subcommand)]
+        action: TemplateAction,
+    },
+
     /// Validate a resume file
     Validate {
         /// Input resume JSON file (use '-' for stdin)
         input: String,
     },
 
+    /// Report what changed between two versions of a resume: added and
+    /// removed items, and edited fields word by word
+    Diff {
+        /// Old version of the resume JSON (use '-' for stdin)
+        old: String,
+
+        /// New version of the resume JSON (use '-' for stdin)
+        new: String,
+
+        /// Report format
+        #[arg(short, long, value_enum, default_value = "md")]
+        format: DiffFormatArg,
+
+        /// Output file path (defaults to stdout)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+
+    /// Suggest improvements to experience bullet points
+    Suggest {
+        /// Input resume JSON file (use '-' for stdin)
+        input: String,
+
+        /// Print suggestions as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Find near-duplicate items and merge them
+    Dedupe {
+        /// Input resume JSON file (use '-' for stdin together with --yes)
+        input: String,
+
+        /// Output file path for the merged resume (defaults to stdout)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// Merge every candidate without asking
+        #[arg(short, long)]
+        yes: bool,
+
+        /// Print duplicate candidates as JSON without merging
+        #[arg(long)]
+        json: bool,
+
+        /// Minimum similarity (0-1) for two items to count as duplicates
+        #[arg(long, default_value_t = DEFAULT_SIMILARITY)]
+        threshold: f64,
+    },
+
+    /// Show your own usage counts, kept locally if you opt in
+    Stats {
+        /// Show the local usage log: renders, templates, and import sources
+        #[arg(long, required = true)]
+        usage: bool,
+
+        /// Start keeping the usage log; nothing is ever sent anywhere
+        #[arg(long, conflicts_with = "disable")]
+        enable: bool,
+
+        /// Stop keeping the usage log and delete it
+        #[arg(long)]
+        disable: bool,
+
+        /// Print the counts as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Find and fix structural problems: blank or repeated item IDs,
+    /// layout entries naming no section, and unknown option values
+    Doctor {
+        /// Input resume JSON file (use '-' for stdin)
+        input: String,
+
+        /// Write the repaired resume here (without it, only report)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// Print the report as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Reorder sections with a curated layout preset
+    Preset {
+        /// Preset to apply
+        preset: PresetArg,
+
+        /// Input resume JSON file (use '-' for stdin)
+        input: String,
+
+        /// Output file path (defaults to stdout)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+
     /// Create a new empty resume
     Init {
         /// Output file path
@@ -118,11 +412,206 @@ enum Commands {
         output: Option<PathBuf>,
 
         /// Pre-fill with sample data
-        #[arg(long)]
+        #[arg(long, conflicts_with = "preset")]
         sample: bool,
+
+        /// Start from a profession's content preset
+        #[arg(long, value_name = "PRESET")]
+        preset: Option<ContentPresetArg>,
     },
 }
 
+#[derive(Clone, Subcommand)]
+enum ImportSource {
+    /// Read hResume or schema.org Person markup from a saved web page
+    Url {
+        /// Saved HTML page (use '-' for stdin); nothing is downloaded
+        input: String,
+
+        /// Output file path (defaults to stdout)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// Pretty print JSON output
+        #[arg(long, default_value = "true")]
+        pretty: bool,
+
+        #[command(flatten)]
+        import: ImportArgs,
+    },
+    /// Append skills, languages, or certifications from a CSV file with a header row
+    Csv {
+        /// CSV file (use '-' for stdin); comma, semicolon, and tab separators work
+        input: String,
+
+        /// Section to append the rows to
+        #[arg(short, long)]
+        section: SectionArg,
+
+        /// Rustume JSON resume to append to (defaults to an empty resume)
+        #[arg(short, long)]
+        resume: Option<PathBuf>,
+
+        /// Output file path (defaults to stdout)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// Pretty print JSON output
+        #[arg(long, default_value = "true")]
+        pretty: bool,
+    },
+}
+
+#[derive(Clone, Subcommand)]
+enum TemplateAction {
+    /// Install a template package (.rtpl archive or directory), replacing an
+    /// installed version of it
+    Install {
+        /// Template package
+        package: PathBuf,
+    },
+    /// Remove an installed template package
+    Uninstall {
+        /// Template name
+        name: String,
+    },
+}
+
+/// Post-processing options shared by the commands that import a resume.
+#[derive(Clone, Args)]
+struct ImportArgs {
+    /// Language of the resume (BCP 47 tag such as `de`), used for dates
+    #[arg(long)]
+    locale: Option<String>,
+
+    /// How imported items get their IDs
+    #[arg(long, value_enum, default_value = "random")]
+    ids: IdsArg,
+
+    /// Keep a resume that fails validation, printing its errors as warnings
+    #[arg(long)]
+    lenient: bool,
+}
+
+impl ImportArgs {
+    fn options(self, format: InputFormat) -> ImportOptions {
+        ImportOptions {
+            format: Some(format.into()),
+            locale: self.locale,
+            ids: self.ids.into(),
+            lenient: self.lenient,
+        }
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum IdsArg {
+    /// Fresh random IDs
+    Random,
+    /// IDs derived from each item's content, the same on every import
+    Stable,
+}
+
+impl From<IdsArg> for IdStrategy {
+    fn from(ids: IdsArg) -> Self {
+        match ids {
+            IdsArg::Random => Self::Random,
+            IdsArg::Stable => Self::Stable,
+        }
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum SectionArg {
+    Skills,
+    Languages,
+    Certifications,
+}
+
+impl From<SectionArg> for CsvSection {
+    fn from(section: SectionArg) -> Self {
+        match section {
+            SectionArg::Skills => Self::Skills,
+            SectionArg::Languages => Self::Languages,
+            SectionArg::Certifications => Self::Certifications,
+        }
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum PresetArg {
+    /// Work history first, newest role on top
+    Chronological,
+    /// Skills and projects first, work history after
+    Functional,
+    /// A skills summary, then work history
+    Hybrid,
+    /// Education and publications first, with references
+    Academic,
+}
+
+impl From<PresetArg> for LayoutPreset {
+    fn from(preset: PresetArg) -> Self {
+        match preset {
+            PresetArg::Chronological => Self::Chronological,
+            PresetArg::Functional => Self::Functional,
+            PresetArg::Hybrid => Self::Hybrid,
+            PresetArg::Academic => Self::Academic,
+        }
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum ContentPresetArg {
+    /// Engineering roles, skills grouped by area, and a side project
+    SoftwareEngineer,
+    /// Case-study projects and design skills ahead of work history
+    Designer,
+    /// Degrees, publications, teaching, grants, and references
+    Academic,
+    /// Licenses, certifications, clinical roles, and languages
+    Nurse,
+    /// Quota-carrying roles with results, sales tools, and awards
+    Sales,
+}
+
+impl From<ContentPresetArg> for ContentPreset {
+    fn from(preset: ContentPresetArg) -> Self {
+        match preset {
+            ContentPresetArg::SoftwareEngineer => Self::SoftwareEngineer,
+            ContentPresetArg::Designer => Self::Designer,
+            ContentPresetArg::Academic => Self::Academic,
+            ContentPresetArg::Nurse => Self::Nurse,
+            ContentPresetArg::Sales => Self::Sales,
+        }
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum DiffFormatArg {
+    /// Markdown, with removed words struck through and added words in bold
+    Md,
+    /// A standalone HTML page
+    Html,
+}
+
+impl From<DiffFormatArg> for DiffReportFormat {
+    fn from(format: DiffFormatArg) -> Self {
+        match format {
+            DiffFormatArg::Md => Self::Markdown,
+            DiffFormatArg::Html => Self::Html,
+        }
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum EngineArg {
+    /// Typst templates
+    Typst,
+    /// The HTML page printed by headless Chromium (`chromium` feature)
+    Chromium,
+}
+
 #[derive(Clone, ValueEnum)]
 enum InputFormat {
     /// JSON Resume format
@@ -131,25 +620,123 @@ enum InputFormat {
     LinkedIn,
     /// Reactive Resume v3 format
     Rrv3,
+    /// Stack Overflow user data (API JSON or data-dump XML)
+    StackOverflow,
+    /// HTML page with hResume or schema.org Person markup
+    Html,
+    /// vCard contact (.vcf), imported into basics
+    Vcard,
+    /// Plain text copied out of a PDF or document, sectionized heuristically
+    Text,
     /// Native Rustume format
     Rustume,
 }
 
+#[derive(Clone, Copy, ValueEnum)]
+enum ExportFormat {
+    /// iCalendar timeline with one event per position and degree
+    Ics,
+    /// vCard 4.0 contact card from basics and profiles
+    Vcard,
+    /// One section's items as comma-separated values
+    Csv,
+    /// One section's items as an Excel workbook
+    Xlsx,
+}
+
 impl From<InputFormat> for ResumeFormat {
     fn from(format: InputFormat) -> Self {
         match format {
             InputFormat::JsonResume => Self::JsonResume,
             InputFormat::LinkedIn => Self::LinkedIn,
             InputFormat::Rrv3 => Self::Rrv3,
+            InputFormat::StackOverflow => Self::StackOverflow,
+            InputFormat::Html => Self::Html,
+            InputFormat::Vcard => Self::Vcard,
+            InputFormat::Text => Self::Text,
             InputFormat::Rustume => Self::Rustume,
         }
     }
 }
 
+impl From<ResumeFormat> for InputFormat {
+    fn from(format: ResumeFormat) -> Self {
+        match format {
+            ResumeFormat::JsonResume => Self::JsonResume,
+            ResumeFormat::LinkedIn => Self::LinkedIn,
+            ResumeFormat::Rrv3 => Self::Rrv3,
+            ResumeFormat::StackOverflow => Self::StackOverflow,
+            ResumeFormat::Html => Self::Html,
+            ResumeFormat::Vcard => Self::Vcard,
+            ResumeFormat::Text => Self::Text,
+            ResumeFormat::Rustume => Self::Rustume,
+        }
+    }
+}
+
 fn main() {
     if let Err(e) = run() {
-        eprintln!("Error: {e:#}");
-        std::process::exit(1);
+        let code = error_code(&e);
+        match code {
+            Some(code) => eprintln!("Error [{code}]: {e:#}"),
+            None => eprintln!("Error: {e:#}"),
+        }
+        std::process::exit(code.map_or(1, exit_status));
+    }
+}
+
+/// Code of the first core error in the chain of `err`.
+fn error_code(err: &anyhow::Error) -> Option<ErrorCode> {
+    err.chain().find_map(|cause| {
+        if let Some(err) = cause.downcast_ref::<RustumeError>() {
+            Some(err.code)
+        } else if let Some(err) = cause.downcast_ref::<ParseError>() {
+            Some(err.code())
+        } else if let Some(err) = cause.downcast_ref::<RenderError>() {
+            Some(err.code())
+        } else if let Some(err) = cause.downcast_ref::<MergeError>() {
+            Some(err.code())
+        } else if let Some(err) = cause.downcast_ref::<LayoutError>() {
+            Some(err.code())
+        } else if let Some(err) = cause.downcast_ref::<StorageError>() {
+            Some(err.code())
+        } else if let Some(err) = cause.downcast_ref::<TemplatePackageError>() {
+            Some(err.code())
+        } else if cause.is::<validator::ValidationErrors>() {
+            Some(ErrorCode::ValidationFailed)
+        } else {
+            None
+        }
+    })
+}
+
+/// Exit status for an error with `code`: one per area, so scripts can tell
+/// unreadable input from an invalid resume or a failed render.
+fn exit_status(code: ErrorCode) -> i32 {
+    match code {
+        ErrorCode::InvalidInput
+        | ErrorCode::ParseReadFailed
+        | ErrorCode::ParseInvalidFormat
+        | ErrorCode::ParseInvalidJson
+        | ErrorCode::ParseConversionFailed
+        | ErrorCode::ParseLimitExceeded
+        | ErrorCode::ParseLinkedinBadZip => 3,
+        ErrorCode::ValidationFailed
+        | ErrorCode::LayoutSectionNotFound
+        | ErrorCode::LayoutInvalid
+        | ErrorCode::MergeItemNotFound
+        | ErrorCode::MergeInvalid => 4,
+        ErrorCode::RenderTemplateNotFound
+        | ErrorCode::RenderTemplateNotAllowed
+        | ErrorCode::RenderFailed
+        | ErrorCode::RenderInvalidConfig
+        | ErrorCode::RenderTooLarge
+        | ErrorCode::RenderEngineUnavailable
+        | ErrorCode::RenderUnsupported => 5,
+        ErrorCode::StorageNotFound
+        | ErrorCode::StorageAlreadyExists
+        | ErrorCode::StorageInternal => 6,
+        ErrorCode::Internal => 1,
     }
 }
 
@@ -167,27 +754,175 @@ fn run() -> Result<()> {
         tracing::debug!("Debug logging enabled");
     }
 
-    match cli.command {
+    #[cfg(feature = "dev")]
+    if let Some(dir) = cli.dev_templates {
+        return run_with_dev_templates(dir, cli.command);
+    }
+    run_command(cli.command)
+}
+
+/// Run `command` with the templates in `dir`. `render` and `preview` keep
+/// running, and render again after every template change until interrupted.
+#[cfg(feature = "dev")]
+fn run_with_dev_templates(dir: PathBuf, command: Commands) -> Result<()> {
+    rustume_render::set_dev_templates_dir(Some(dir.clone()))?;
+    let (Commands::Render { input, .. } | Commands::Preview { input, .. }) = &command else {
+        return run_command(command);
+    };
+    if input == "-" {
+        return Err(anyhow!(
+            "--dev-templates re-reads the resume on each change; pass a file, not stdin"
+        ));
+    }
+
+    let mut watcher = rustume_render::TemplateWatcher::new(&dir)
+        .with_context(|| format!("Failed to watch {}", dir.display()))?;
+    loop {
+        if let Err(err) = run_command(command.clone()) {
+            eprintln!("Error: {err:#}");
+        }
+        eprintln!(
+            "Watching {} for template changes (Ctrl-C to stop)",
+            dir.display()
+        );
+        let changed = watcher
+            .wait()
+            .with_context(|| format!("Failed to watch {}", dir.display()))?;
+        eprintln!("Changed: {}", changed.join(", "));
+    }
+}
+
+fn run_command(command: Commands) -> Result<()> {
+    match command {
         Commands::Parse {
             input,
             format,
             output,
             pretty,
-        } => cmd_parse(&input, format, output, pretty),
+            import,
+        } => cmd_parse(&input, format, import, output, pretty),
+        Commands::Import {
+            source:
+                ImportSource::Url {
+                    input,
+                    output,
+                    pretty,
+                    import,
+                },
+        } => cmd_parse(&input, Some(InputFormat::Html), import, output, pretty),
+        Commands::Import {
+            source:
+                ImportSource::Csv {
+                    input,
+                    section,
+                    resume,
+                    output,
+                    pretty,
+                },
+        } => cmd_import_csv(&input, section.into(), resume, output, pretty),
         Commands::Render {
             input,
             template,
             output,
-        } => cmd_render(&input, template.as_deref(), output),
+            exclude_sections,
+            exclude_item_ids,
+            engine,
+        } => cmd_render(
+            &input,
+            template.as_deref(),
+            output,
+            Redaction {
+                exclude_sections,
+                exclude_item_ids,
+            },
+            engine,
+        ),
+        Commands::Export {
+            input,
+            format,
+            section,
+            output,
+        } => cmd_export(&input, format, section.as_deref(), output),
+        Commands::ExportSite {
+            input,
+            output,
+            template,
+            url,
+            exclude_sections,
+            exclude_item_ids,
+        } => cmd_export_site(
+            &input,
+            &output,
+            template.as_deref(),
+            url,
+            &Redaction {
+                exclude_sections,
+                exclude_item_ids,
+            },
+        ),
         Commands::Preview {
             input,
             page,
             template,
             output,
         } => cmd_preview(&input, page, template.as_deref(), output),
+        Commands::Compare {
+            input,
+            output,
+            templates,
+        } => cmd_compare(&input, &output, &templates),
+        Commands::Fit {
+            input,
+            pages,
+            template,
+            keep,
+            output,
+            json,
+        } => cmd_fit(&input, pages, template.as_deref(), &keep, output, json),
         Commands::Templates { verbose } => cmd_templates(verbose),
+        Commands::TemplateCheck { file } => cmd_template_check(&file),
+        Commands::Template {
+            action: TemplateAction::Install { package },
+        } => cmd_template_install(&package),
+        Commands::Template {
+            action: TemplateAction::Uninstall { name },
+        } => cmd_template_uninstall(&name),
         Commands::Validate { input } => cmd_validate(&input),
-        Commands::Init { output, sample } => cmd_init(output, sample),
+        Commands::Diff {
+            old,
+            new,
+            format,
+            output,
+        } => cmd_diff(&old, &new, format.into(), output),
+        Commands::Suggest { input, json } => cmd_suggest(&input, json),
+        Commands::Dedupe {
+            input,
+            output,
+            yes,
+            json,
+            threshold,
+        } => cmd_dedupe(&input, output, yes, json, threshold),
+        Commands::Stats {
+            usage: _,
+            enable,
+            disable,
+            json,
+        } => cmd_stats_usage(enable, disable, json),
+        Commands::Doctor {
+            input,
+            output,
+            json,
+        } => cmd_doctor(&input, output, json),
+        Commands::Preset {
+            preset,
+            input,
+            output,
+        } => cmd_preset(preset.into(), &input, output),
+        Commands::Init {
+            output,
+            sample,
+            preset,
+        } => cmd_init(output, sample, preset.map(ContentPreset::from)),
     }
 }
 
@@ -204,6 +939,13 @@ fn read_input(path: &str) -> Result<Vec<u8>> {
     }
 }
 
+/// Read `data` as Rustume resume JSON.
+fn read_resume_json(data: &[u8]) -> Result<ResumeData> {
+    serde_json::from_slice(data)
+        .map_err(|err| ParseError::DeserializeError(err.to_string()))
+        .context("Failed to parse resume JSON")
+}
+
 /// Write output to file or stdout
 fn write_output(data: &[u8], path: Option<PathBuf>) -> Result<()> {
     match path {
@@ -220,62 +962,39 @@ fn write_output(data: &[u8], path: Option<PathBuf>) -> Result<()> {
     Ok(())
 }
 
+/// Count an event in the local usage log, if the user opted in.
+fn record_usage(update: impl FnOnce(&mut UsageStats)) {
+    if let Some(log) = UsageLog::open_default() {
+        log.record(update);
+    }
+}
+
 /// Detect input format from file extension or content
 fn detect_format(path: &str, data: &[u8]) -> Result<InputFormat> {
     // Check file extension first
     if path.ends_with(".zip") {
         return Ok(InputFormat::LinkedIn);
     }
-
-    // Check for ZIP magic bytes (handles stdin ZIP input)
-    // ZIP signatures: PK\x03\x04 (local file), PK\x05\x06 (empty), PK\x07\x08 (spanned)
-    if data.len() >= 4 && data[0] == b'P' && data[1] == b'K' {
-        let sig = data[2..4].try_into().unwrap_or([0, 0]);
-        if sig == [0x03, 0x04] || sig == [0x05, 0x06] || sig == [0x07, 0x08] {
-            return Ok(InputFormat::LinkedIn);
-        }
+    if path.ends_with(".html") || path.ends_with(".htm") {
+        return Ok(InputFormat::Html);
     }
-
-    // Try to parse as JSON and detect format
-    if let Ok(text) = std::str::from_utf8(data) {
-        if let Ok(json) = serde_json::from_str::<serde_json::Value>(text) {
-            // Check basics first to distinguish between formats
-            if let Some(basics) = json.get("basics") {
-                // Native Rustume has "headline" instead of "label"
-                if basics.get("headline").is_some() {
-                    return Ok(InputFormat::Rustume);
-                }
-                // JSON Resume has "basics" with "label" (not "headline")
-                if basics.get("label").is_some() {
-                    return Ok(InputFormat::JsonResume);
-                }
-            }
-            // Reactive Resume v3 has sections, metadata, AND a "public" field
-            // (Rustume also has sections/metadata, so we need a stricter check)
-            if json.get("sections").is_some()
-                && json.get("metadata").is_some()
-                && json.get("public").is_some()
-            {
-                return Ok(InputFormat::Rrv3);
-            }
-            // Rustume has sections+metadata but no "public" field
-            if json.get("sections").is_some() && json.get("metadata").is_some() {
-                return Ok(InputFormat::Rustume);
-            }
-            // Default to JSON Resume for other JSON
-            return Ok(InputFormat::JsonResume);
-        }
+    if path.ends_with(".vcf") || path.ends_with(".vcard") {
+        return Ok(InputFormat::Vcard);
+    }
+    if path.ends_with(".txt") {
+        return Ok(InputFormat::Text);
     }
 
-    Err(anyhow!(
-        "Could not detect input format. Please specify --format"
-    ))
+    // Content detection handles stdin input
+    let format = detect_resume_format(data).context("Please specify --format")?;
+    Ok(InputFormat::from(format))
 }
 
 /// Parse command
 fn cmd_parse(
     input: &str,
     format: Option<InputFormat>,
+    import: ImportArgs,
     output: Option<PathBuf>,
     pretty: bool,
 ) -> Result<()> {
@@ -286,39 +1005,176 @@ fn cmd_parse(
         None => detect_format(input, &data)?,
     };
 
+    // Counted under the name `--format` takes
+    let source = format
+        .to_possible_value()
+        .map(|value| value.get_name().to_string());
+
     let context_msg = match format {
         InputFormat::JsonResume => "Failed to parse JSON Resume",
         InputFormat::LinkedIn => "Failed to parse LinkedIn export",
         InputFormat::Rrv3 => "Failed to parse Reactive Resume v3",
+        InputFormat::StackOverflow => "Failed to parse Stack Overflow export",
+        InputFormat::Html => "Failed to import HTML page",
+        InputFormat::Vcard => "Failed to parse vCard",
+        InputFormat::Text => "Failed to import plain text",
         InputFormat::Rustume => "Failed to parse Rustume JSON",
     };
 
-    let resume = parse_resume(format.into(), &data).context(context_msg)?;
+    let pipeline = ImportPipeline::new(import.options(format));
+    // Big LinkedIn exports take a while; show how far along we are, but
+    // keep redirected stderr free of control codes.
+    let result = if io::stderr().is_terminal() {
+        let result = pipeline.run_with_progress(&data, &mut draw_progress);
+        eprint!("\r\x1b[2K");
+        result
+    } else {
+        pipeline.run(&data)
+    };
+    let imported = result.map_err(|err| {
+        let context = match err {
+            ParseError::InvalidResume(_) => {
+                "Import failed validation; pass --lenient to keep the resume anyway"
+            }
+            _ => context_msg,
+        };
+        anyhow::Error::new(err).context(context)
+    })?;
+
+    // Plain text is guesswork; point out the guesses worth checking, and
+    // whatever else the import couldn't settle
+    for issue in &imported.report.issues {
+        eprintln!("Warning: check {} ({})", issue.path, issue.message);
+    }
+    for duplicate in &imported.report.duplicates {
+        eprintln!(
+            "Warning: possible duplicate in {}: {} / {}",
+            duplicate.section, duplicate.keep_label, duplicate.duplicate_label
+        );
+    }
 
     let json = if pretty {
-        serde_json::to_string_pretty(&resume)?
+        serde_json::to_string_pretty(&imported.resume)?
     } else {
-        serde_json::to_string(&resume)?
+        serde_json::to_string(&imported.resume)?
     };
 
     write_output(json.as_bytes(), output)?;
+    if let Some(source) = source {
+        record_usage(|stats| stats.record_import(&source));
+    }
     Ok(())
 }
 
+fn cmd_import_csv(
+    input: &str,
+    section: CsvSection,
+    resume: Option<PathBuf>,
+    output: Option<PathBuf>,
+    pretty: bool,
+) -> Result<()> {
+    let data = read_input(input)?;
+    let mut resume: ResumeData = match resume {
+        Some(path) => {
+            let json = fs::read(&path)
+                .with_context(|| format!("Failed to read file: {}", path.display()))?;
+            read_resume_json(&json)?
+        }
+        None => ResumeData::default(),
+    };
+
+    let added = import_section_csv(&mut resume, section, &data)
+        .with_context(|| format!("Failed to import {section} CSV"))?;
+    eprintln!("Added {added} item(s) to {section}");
+    record_usage(|stats| stats.record_import("csv"));
+
+    let json = if pretty {
+        serde_json::to_string_pretty(&resume)?
+    } else {
+        serde_json::to_string(&resume)?
+    };
+    write_output(json.as_bytes(), output)
+}
+
+/// Redraw the parse progress bar in place on stderr.
+fn draw_progress(progress: ParseProgress) {
+    const WIDTH: usize = 30;
+    let filled = WIDTH * usize::from(progress.percent) / 100;
+    eprint!(
+        "\r\x1b[2K[{}{}] {:>3}% {}",
+        "#".repeat(filled),
+        "-".repeat(WIDTH - filled),
+        progress.percent,
+        progress
+            .detail
+            .as_deref()
+            .unwrap_or(progress.phase.as_str())
+    );
+    let _ = io::stderr().flush();
+}
+
 /// Apply template ID and matching theme colors (mirrors server thumbnail rendering).
 fn apply_template(resume: &mut ResumeData, template: &str) {
     resume.metadata.template = template.to_string();
-    let theme = get_template_theme(template);
+    let theme = template_theme(template);
     resume.metadata.theme.primary = theme.primary;
     resume.metadata.theme.text = theme.text;
     resume.metadata.theme.background = theme.background;
 }
 
+/// Templates offered by `RUSTUME_TEMPLATES_ALLOW` and `RUSTUME_TEMPLATES_DENY`.
+fn template_policy() -> Result<TemplatePolicy> {
+    TemplatePolicy::from_env().context("Invalid RUSTUME_TEMPLATES_ALLOW or RUSTUME_TEMPLATES_DENY")
+}
+
+/// The Typst renderer, limited to the templates of [`template_policy`] and
+/// the installed template packages.
+fn typst_renderer() -> Result<TypstRenderer> {
+    Ok(TypstRenderer::new()
+        .with_template_policy(template_policy()?)
+        .with_template_packages(template_packages()?))
+}
+
+/// Template packages installed under the data directory.
+fn installed_templates() -> Option<InstalledTemplates> {
+    rustume_storage::data_dir().map(|dir| InstalledTemplates::new(dir.join("templates")))
+}
+
+/// The installed template packages; none without a data directory.
+fn template_packages() -> Result<Vec<TemplatePackage>> {
+    let Some(installed) = installed_templates() else {
+        return Ok(Vec::new());
+    };
+    installed.load().with_context(|| {
+        format!(
+            "Failed to load templates from {}",
+            installed.dir().display()
+        )
+    })
+}
+
+/// Theme colors of a built-in template, or of an installed package.
+fn template_theme(template: &str) -> TemplateTheme {
+    if !TEMPLATES.contains(&template) {
+        if let Some(package) =
+            installed_templates().and_then(|installed| installed.get(template).ok())
+        {
+            return package.manifest.theme;
+        }
+    }
+    get_template_theme(template)
+}
+
 /// Render command
-fn cmd_render(input: &str, template: Option<&str>, output: Option<PathBuf>) -> Result<()> {
+fn cmd_render(
+    input: &str,
+    template: Option<&str>,
+    output: Option<PathBuf>,
+    redaction: Redaction,
+    engine: EngineArg,
+) -> Result<()> {
     let data = read_input(input)?;
-    let mut resume: ResumeData =
-        serde_json::from_slice(&data).context("Failed to parse resume JSON")?;
+    let mut resume: ResumeData = read_resume_json(&data)?;
 
     if let Some(t) = template {
         apply_template(&mut resume, t);
@@ -327,17 +1183,134 @@ fn cmd_render(input: &str, template: Option<&str>, output: Option<PathBuf>) -> R
     // Validate before rendering
     resume.validate().context("Resume validation failed")?;
 
-    let renderer = TypstRenderer::new();
-    let pdf = renderer
+    let unknown = redaction.unknown_sections(&resume);
+    if !unknown.is_empty() {
+        return Err(anyhow!(
+            "Unknown sections to exclude: {}",
+            unknown.join(", ")
+        ));
+    }
+
+    print_template_warnings(&resume);
+    // Typst checks this itself; Chromium renders any template
+    template_policy()?.check(&resume.metadata.template)?;
+
+    let pdf = pdf_renderer(engine, redaction)?
         .render_pdf(&resume)
         .context("Failed to render PDF")?;
 
     let output = output.unwrap_or_else(|| PathBuf::from("resume.pdf"));
     write_output(&pdf, Some(output))?;
+    record_usage(|stats| stats.record_render(&resume.metadata.template));
 
     Ok(())
 }
 
+/// The PDF backend for `engine`, applying `redaction`.
+fn pdf_renderer(engine: EngineArg, redaction: Redaction) -> Result<Box<dyn Renderer>> {
+    match engine {
+        EngineArg::Typst => Ok(Box::new(typst_renderer()?.with_redaction(redaction))),
+        #[cfg(feature = "chromium")]
+        EngineArg::Chromium => {
+            let renderer = ChromiumRenderer::discover().ok_or_else(|| {
+                anyhow!("No Chromium binary found; install Chromium or set RUSTUME_CHROMIUM_PATH")
+            })?;
+            Ok(Box::new(renderer.with_redaction(redaction)))
+        }
+        #[cfg(not(feature = "chromium"))]
+        EngineArg::Chromium => Err(anyhow!(
+            "The chromium engine is not available; rebuild rustume with the `chromium` feature"
+        )),
+    }
+}
+
+/// File name of the PDF in an exported site.
+const SITE_PDF_FILE: &str = "resume.pdf";
+
+/// Export site command
+fn cmd_export_site(
+    input: &str,
+    output: &Path,
+    template: Option<&str>,
+    url: Option<String>,
+    redaction: &Redaction,
+) -> Result<()> {
+    let data = read_input(input)?;
+    let mut resume: ResumeData = read_resume_json(&data)?;
+
+    if let Some(t) = template {
+        apply_template(&mut resume, t);
+    }
+
+    resume.validate().context("Resume validation failed")?;
+
+    let unknown = redaction.unknown_sections(&resume);
+    if !unknown.is_empty() {
+        return Err(anyhow!(
+            "Unknown sections to exclude: {}",
+            unknown.join(", ")
+        ));
+    }
+    if let Some(url) = &url {
+        if !url.starts_with("https://") && !url.starts_with("http://") {
+            return Err(anyhow!("Site URL must start with http:// or https://"));
+        }
+    }
+
+    print_template_warnings(&resume);
+
+    // Render the PDF first so a failure leaves no half-written site behind
+    redaction.apply(&mut resume);
+    let pdf = typst_renderer()?
+        .render_pdf(&resume)
+        .context("Failed to render PDF")?;
+    let page = render_html_page(
+        &resume,
+        &HtmlPageOptions {
+            linked_styles: true,
+            pdf_href: Some(SITE_PDF_FILE.to_string()),
+            url,
+        },
+    );
+
+    fs::create_dir_all(output)
+        .with_context(|| format!("Failed to create directory: {}", output.display()))?;
+    let files = [
+        ("index.html", page.into_bytes()),
+        (STYLESHEET_FILE, resume_stylesheet(&resume).into_bytes()),
+        (
+            PRINT_STYLESHEET_FILE,
+            print_stylesheet(&resume).into_bytes(),
+        ),
+        (SITE_PDF_FILE, pdf),
+    ];
+    for (name, contents) in files {
+        let path = output.join(name);
+        fs::write(&path, contents)
+            .with_context(|| format!("Failed to write to: {}", path.display()))?;
+    }
+    eprintln!("Wrote site: {}", output.display());
+    record_usage(|stats| stats.record_render(&resume.metadata.template));
+
+    Ok(())
+}
+
+/// Report parts of the layout, template options, and page options the template won't
+/// render as written.
+fn print_template_warnings(resume: &ResumeData) {
+    let metadata = &resume.metadata;
+    let warnings = layout_warnings(&metadata.template, &metadata.layout)
+        .into_iter()
+        .chain(template_option_warnings(
+            &metadata.template,
+            &metadata.template_options,
+        ))
+        .chain(page_option_warnings(&metadata.template, &metadata.page));
+    for warning in warnings {
+        eprintln!("Warning: {}", warning);
+    }
+}
+
 /// Preview command
 fn cmd_preview(
     input: &str,
@@ -346,8 +1319,7 @@ fn cmd_preview(
     output: Option<PathBuf>,
 ) -> Result<()> {
     let data = read_input(input)?;
-    let mut resume: ResumeData =
-        serde_json::from_slice(&data).context("Failed to parse resume JSON")?;
+    let mut resume: ResumeData = read_resume_json(&data)?;
 
     // Override template and theme when explicitly specified
     if let Some(t) = template {
@@ -357,33 +1329,221 @@ fn cmd_preview(
     // Validate before rendering
     resume.validate().context("Resume validation failed")?;
 
-    let renderer = TypstRenderer::new();
-    let (png, _total_pages) = renderer
-        .render_preview(&resume, page)
-        .context("Failed to render preview")?;
+    print_template_warnings(&resume);
 
+    let renderer = typst_renderer()?;
     let output = output.unwrap_or_else(|| PathBuf::from("preview.png"));
-    write_output(&png, Some(output))?;
+    let image = if output
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("svg"))
+    {
+        let (svg, _total_pages) = renderer
+            .render_svg(&resume, page)
+            .context("Failed to render preview")?;
+        svg.into_bytes()
+    } else {
+        let (png, _total_pages) = renderer
+            .render_preview(&resume, page)
+            .context("Failed to render preview")?;
+        png
+    };
+    write_output(&image, Some(output))?;
+    record_usage(|stats| stats.record_render(&resume.metadata.template));
+
+    Ok(())
+}
+
+/// Fit command
+fn cmd_fit(
+    input: &str,
+    pages: u32,
+    template: Option<&str>,
+    keep: &[String],
+    output: Option<PathBuf>,
+    json: bool,
+) -> Result<()> {
+    let data = read_input(input)?;
+    let mut resume: ResumeData = read_resume_json(&data)?;
+    if let Some(t) = template {
+        apply_template(&mut resume, t);
+    }
+    resume.validate().context("Resume validation failed")?;
+
+    let FittedResume { resume, report } = typst_renderer()?
+        .fit_to_pages(&resume, pages, keep)
+        .context("Failed to fit resume")?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else if report.steps.is_empty() {
+        println!(
+            "{} already fits in {}",
+            page_count(report.pages_before),
+            page_count(pages)
+        );
+    } else {
+        println!(
+            "{} -> {}:",
+            page_count(report.pages_before),
+            page_count(report.pages_after)
+        );
+        for step in &report.steps {
+            println!("  {}", step.description);
+        }
+    }
+
+    match output {
+        Some(path) => write_output(resume.to_json()?.as_bytes(), Some(path))?,
+        None if !report.steps.is_empty() => {
+            eprintln!("Nothing was saved; run again with -o <OUTPUT> to apply these changes")
+        }
+        None => {}
+    }
+    if !report.fits {
+        return Err(anyhow!(
+            "Still {} after every cut; shorten the content to fit {}",
+            page_count(report.pages_after),
+            page_count(pages)
+        ));
+    }
+    Ok(())
+}
+
+/// `1 page`, `2 pages`
+fn page_count(pages: u32) -> String {
+    if pages == 1 {
+        "1 page".to_string()
+    } else {
+        format!("{pages} pages")
+    }
+}
+
+/// Compare command
+fn cmd_compare(input: &str, output: &Path, templates: &[String]) -> Result<()> {
+    let data = read_input(input)?;
+    let resume: ResumeData = read_resume_json(&data)?;
+
+    resume.validate().context("Resume validation failed")?;
+
+    if let Some(unknown) = templates.iter().find(|t| !TEMPLATES.contains(&t.as_str())) {
+        return Err(RustumeError::new(
+            ErrorCode::RenderTemplateNotFound,
+            format!("Unknown template: {unknown} (run `rustume templates` to list them)"),
+        )
+        .into());
+    }
+    let renderer = typst_renderer()?;
+    let templates: Vec<&str> = if templates.is_empty() {
+        renderer.template_policy().templates()
+    } else {
+        templates.iter().map(String::as_str).collect()
+    };
+    let format = match output.extension().and_then(|ext| ext.to_str()) {
+        Some(ext) if ext.eq_ignore_ascii_case("png") => SheetFormat::Png,
+        _ => SheetFormat::Pdf,
+    };
+
+    eprintln!("Rendering {} templates...", templates.len());
+    let sheet = renderer
+        .render_contact_sheet(&resume, &templates, format)
+        .context("Failed to render comparison sheet")?;
+
+    write_output(&sheet, Some(output.to_path_buf()))?;
 
     Ok(())
 }
 
 /// Templates command
 fn cmd_templates(verbose: bool) -> Result<()> {
+    let templates = template_policy()?.templates();
+    let packages = template_packages()?;
     if verbose {
         println!("Available templates:\n");
-        for name in TEMPLATES {
-            let theme = get_template_theme(name);
-            println!("  {}", name);
-            println!("    Background: {}", theme.background);
-            println!("    Text:       {}", theme.text);
-            println!("    Primary:    {}", theme.primary);
+        for name in &templates {
+            print_template_theme(name, &get_template_theme(name));
+            println!();
+        }
+        for package in &packages {
+            let manifest = &package.manifest;
+            print_template_theme(&manifest.name, &manifest.theme);
+            println!("    Version:    {} (installed)", manifest.version);
+            if !manifest.description.is_empty() {
+                println!("    About:      {}", manifest.description);
+            }
             println!();
         }
     } else {
-        for name in TEMPLATES {
+        for name in &templates {
             println!("{}", name);
         }
+        for package in &packages {
+            println!("{}", package.name());
+        }
+    }
+    Ok(())
+}
+
+fn print_template_theme(name: &str, theme: &TemplateTheme) {
+    println!("  {}", name);
+    println!("    Background: {}", theme.background);
+    println!("    Text:       {}", theme.text);
+    println!("    Primary:    {}", theme.primary);
+}
+
+/// Template install command
+fn cmd_template_install(path: &Path) -> Result<()> {
+    let installed = installed_templates().ok_or_else(|| {
+        anyhow!("No data directory for installed templates; set RUSTUME_DATA_DIR or HOME")
+    })?;
+    let package = TemplatePackage::read(path)
+        .with_context(|| format!("Failed to read template package: {}", path.display()))?;
+    let manifest = &package.manifest;
+    match installed.install(&package)? {
+        Some(previous) => eprintln!(
+            "Updated template {} from {} to {}",
+            manifest.name, previous, manifest.version
+        ),
+        None => eprintln!("Installed template {} {}", manifest.name, manifest.version),
+    }
+    Ok(())
+}
+
+/// Template uninstall command
+fn cmd_template_uninstall(name: &str) -> Result<()> {
+    let installed = installed_templates().ok_or_else(|| {
+        anyhow!("No data directory for installed templates; set RUSTUME_DATA_DIR or HOME")
+    })?;
+    installed.uninstall(name)?;
+    eprintln!("Uninstalled template {name}");
+    Ok(())
+}
+
+/// Template check command
+fn cmd_template_check(file: &Path) -> Result<()> {
+    let source = fs::read_to_string(file)
+        .with_context(|| format!("Failed to read file: {}", file.display()))?;
+
+    // The candidate stands in for the template the fixtures name, so the
+    // template policy doesn't apply
+    let report = TypstRenderer::new().check_template(&source);
+    for check in &report.fixtures {
+        if check.issues.is_empty() {
+            let pages = u32::try_from(check.pages).unwrap_or(u32::MAX);
+            println!("{}: ok ({})", check.fixture, page_count(pages));
+            continue;
+        }
+        println!("{}:", check.fixture);
+        for issue in &check.issues {
+            println!("  {}", issue.to_string().replace('\n', "\n    "));
+        }
+    }
+
+    if !report.is_ok() {
+        return Err(RustumeError::new(
+            ErrorCode::ValidationFailed,
+            format!("{} has problems", file.display()),
+        )
+        .into());
     }
     Ok(())
 }
@@ -391,37 +1551,294 @@ fn cmd_templates(verbose: bool) -> Result<()> {
 /// Validate command
 fn cmd_validate(input: &str) -> Result<()> {
     let data = read_input(input)?;
-    let resume: ResumeData =
-        serde_json::from_slice(&data).context("Failed to parse resume JSON")?;
-
-    match resume.validate() {
-        Ok(_) => {
-            println!("Valid resume");
-            Ok(())
-        }
-        Err(errors) => {
-            eprintln!("Validation errors:");
-            for (field, errs) in errors.field_errors() {
-                for err in errs {
-                    let message = err
-                        .message
-                        .as_ref()
-                        .map(|s| s.as_ref())
-                        .unwrap_or("validation failed");
-                    eprintln!("  {}: {}", field, message);
+    let resume: ResumeData = read_resume_json(&data)?;
+
+    let report = template_rules().check(&resume);
+    if !report.warnings.is_empty() {
+        eprintln!("Warnings:");
+        for warning in &report.warnings {
+            eprintln!("  {}", warning);
+        }
+    }
+
+    let mut errors = match resume.validate() {
+        Ok(_) => Vec::new(),
+        Err(errors) => validation_messages(&errors),
+    };
+    errors.extend(report.errors.iter().map(ToString::to_string));
+
+    if errors.is_empty() {
+        println!("Valid resume");
+        return Ok(());
+    }
+    eprintln!("Validation errors:");
+    for error in &errors {
+        eprintln!("  {}", error);
+    }
+    Err(RustumeError::new(ErrorCode::ValidationFailed, "Resume validation failed").into())
+}
+
+/// Diff command: write a change report from `old` to `new`
+fn cmd_diff(old: &str, new: &str, format: DiffReportFormat, output: Option<PathBuf>) -> Result<()> {
+    if old == "-" && new == "-" {
+        return Err(anyhow!(
+            "Only one of the two resumes can be read from stdin"
+        ));
+    }
+    let before = read_resume_json(&read_input(old)?)
+        .with_context(|| format!("Failed to read the old resume: {old}"))?;
+    let after = read_resume_json(&read_input(new)?)
+        .with_context(|| format!("Failed to read the new resume: {new}"))?;
+
+    let report = diff_report(&before, &after);
+    write_output(report.render(format).as_bytes(), output)
+}
+
+/// Export command
+fn cmd_export(
+    input: &str,
+    format: ExportFormat,
+    section: Option<&str>,
+    output: Option<PathBuf>,
+) -> Result<()> {
+    let data = read_input(input)?;
+    let resume: ResumeData = read_resume_json(&data)?;
+
+    let table = |section: Option<&str>| {
+        let section =
+            section.ok_or_else(|| anyhow!("--section is required for csv and xlsx export"))?;
+        section_table(&resume, section).context("Failed to export section")
+    };
+    let exported = match (format, section) {
+        (ExportFormat::Ics | ExportFormat::Vcard, Some(_)) => {
+            return Err(anyhow!("--section only applies to csv and xlsx export"));
+        }
+        (ExportFormat::Ics, None) => to_ics(&resume, &PartialDate::today()).into_bytes(),
+        (ExportFormat::Vcard, None) => to_vcard(&resume).into_bytes(),
+        (ExportFormat::Csv, section) => table(section)?.to_csv()?.into_bytes(),
+        (ExportFormat::Xlsx, section) => table(section)?.to_xlsx()?,
+    };
+    write_output(&exported, output)
+}
+
+/// Suggest command
+fn cmd_suggest(input: &str, json: bool) -> Result<()> {
+    let data = read_input(input)?;
+    let resume: ResumeData = read_resume_json(&data)?;
+
+    let suggestions = resume.suggestions();
+    if json {
+        println!("{}", serde_json::to_string_pretty(&suggestions)?);
+        return Ok(());
+    }
+
+    if suggestions.is_empty() {
+        println!("No suggestions");
+        return Ok(());
+    }
+
+    let mut last_excerpt = None;
+    for suggestion in &suggestions {
+        let excerpt = (&suggestion.path, &suggestion.excerpt);
+        if last_excerpt != Some(excerpt) {
+            println!("{}: \"{}\"", suggestion.path, suggestion.excerpt);
+            last_excerpt = Some(excerpt);
+        }
+        print!("  [{}] {}", suggestion.code, suggestion.message);
+        match &suggestion.suggestion {
+            Some(alternative) => println!(" (try: {})", alternative),
+            None => println!(),
+        }
+    }
+    Ok(())
+}
+
+/// Dedupe command
+fn cmd_dedupe(
+    input: &str,
+    output: Option<PathBuf>,
+    yes: bool,
+    json: bool,
+    threshold: f64,
+) -> Result<()> {
+    let data = read_input(input)?;
+    let mut resume: ResumeData = read_resume_json(&data)?;
+
+    if json {
+        let candidates = find_duplicates(&resume, threshold);
+        println!("{}", serde_json::to_string_pretty(&candidates)?);
+        return Ok(());
+    }
+    if input == "-" && !yes {
+        return Err(anyhow!(
+            "Reading the resume from stdin leaves no way to answer prompts; use --yes or --json"
+        ));
+    }
+
+    let mut stdin = io::stdin().lock();
+    let mut declined = HashSet::new();
+    let mut merged = 0;
+    // Look again after every merge: the merged item may match a third copy.
+    while let Some(candidate) = find_duplicates(&resume, threshold)
+        .into_iter()
+        .find(|c| !declined.contains(&(c.keep_id.clone(), c.duplicate_id.clone())))
+    {
+        let (keep_id, duplicate_id) = if yes {
+            (&candidate.keep_id, &candidate.duplicate_id)
+        } else {
+            eprintln!(
+                "{}: {:.0}% similar",
+                candidate.section,
+                candidate.similarity * 100.0
+            );
+            eprintln!("  1: {}", candidate.keep_label);
+            eprintln!("  2: {}", candidate.duplicate_label);
+            eprint!("Merge 2 into 1? [y]es / [s]wap (keep 2) / [N]o / [q]uit: ");
+            io::stderr().flush()?;
+
+            let mut answer = String::new();
+            if stdin.read_line(&mut answer)? == 0 {
+                eprintln!();
+                break;
+            }
+            match answer.trim().to_lowercase().as_str() {
+                "y" | "yes" => (&candidate.keep_id, &candidate.duplicate_id),
+                "s" | "swap" => (&candidate.duplicate_id, &candidate.keep_id),
+                "q" | "quit" => break,
+                _ => {
+                    declined.insert((candidate.keep_id.clone(), candidate.duplicate_id.clone()));
+                    continue;
                 }
             }
-            Err(anyhow!("Resume validation failed"))
+        };
+        merge_items(&mut resume, &candidate.section, keep_id, duplicate_id)?;
+        merged += 1;
+    }
+
+    match merged {
+        0 => eprintln!("No duplicates merged"),
+        1 => eprintln!("Merged 1 duplicate"),
+        n => eprintln!("Merged {n} duplicates"),
+    }
+    let json = serde_json::to_string_pretty(&resume)?;
+    write_output(json.as_bytes(), output)
+}
+
+/// Doctor command
+fn cmd_doctor(input: &str, output: Option<PathBuf>, json: bool) -> Result<()> {
+    let data = read_input(input)?;
+    let value: serde_json::Value = serde_json::from_slice(&data)
+        .map_err(|err| ParseError::DeserializeError(err.to_string()))
+        .context("Failed to parse resume JSON")?;
+    let RepairedResume { resume, report } = repair_json(value)
+        .map_err(|err| ParseError::DeserializeError(err.to_string()))
+        .context("Failed to repair resume")?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else if report.is_clean() {
+        println!("No problems found");
+    } else {
+        if !report.fixes.is_empty() {
+            println!("Fixed:");
+            for fix in &report.fixes {
+                println!("  {}: {}", fix.path, fix.message);
+            }
         }
+        if !report.unresolved.is_empty() {
+            println!("Needs your attention:");
+            for issue in &report.unresolved {
+                println!("  {}", issue);
+            }
+        }
+    }
+
+    match output {
+        Some(path) => write_output(resume.to_json()?.as_bytes(), Some(path))?,
+        None if !report.fixes.is_empty() => {
+            eprintln!("Nothing was saved; run again with -o <OUTPUT> to write the fixes")
+        }
+        None => {}
+    }
+    if !report.unresolved.is_empty() {
+        return Err(RustumeError::new(
+            ErrorCode::ValidationFailed,
+            "Some problems can't be fixed automatically",
+        )
+        .into());
+    }
+    Ok(())
+}
+
+/// Preset command: apply a layout preset and write the resume
+fn cmd_preset(preset: LayoutPreset, input: &str, output: Option<PathBuf>) -> Result<()> {
+    let data = read_input(input)?;
+    let mut resume = read_resume_json(&data)?;
+
+    apply_preset(&mut resume, preset);
+    write_output(resume.to_json()?.as_bytes(), output)
+}
+
+/// Stats command: show, enable, or delete the local usage log
+fn cmd_stats_usage(enable: bool, disable: bool, json: bool) -> Result<()> {
+    let log = UsageLog::open_default().ok_or_else(|| {
+        anyhow!("No data directory for the usage log; set RUSTUME_DATA_DIR or HOME")
+    })?;
+
+    if disable {
+        log.disable().context("Failed to delete the usage log")?;
+        eprintln!("Usage log disabled and deleted: {}", log.path().display());
+        return Ok(());
+    }
+    if enable {
+        log.enable(&PartialDate::today().to_string())
+            .context("Failed to create the usage log")?;
+        eprintln!("Usage log enabled: {}", log.path().display());
+    }
+    if !log.is_enabled() {
+        eprintln!(
+            "The usage log is off. Run `rustume stats --usage --enable` to count renders and \
+             imports on this machine; nothing is sent anywhere."
+        );
+        return Ok(());
+    }
+
+    let stats = log.load().context("Failed to read the usage log")?;
+    if json {
+        println!("{}", serde_json::to_string_pretty(&stats)?);
+        return Ok(());
+    }
+
+    match &stats.since {
+        Some(since) => println!("Usage since {since}"),
+        None => println!("Usage"),
+    }
+    println!("  Log: {}\n", log.path().display());
+    print_counts("Renders", stats.renders, &stats.templates);
+    println!();
+    print_counts("Imports", stats.total_imports(), &stats.imports);
+    Ok(())
+}
+
+/// Print a total and its breakdown, largest first.
+fn print_counts(label: &str, total: u64, counts: &BTreeMap<String, u64>) {
+    println!("{label}: {total}");
+    let mut counts: Vec<_> = counts.iter().collect();
+    counts.sort_by(|a, b| b.1.cmp(a.1).then_with(|| a.0.cmp(b.0)));
+    let width = counts.iter().map(|(name, _)| name.len()).max().unwrap_or(0);
+    for (name, count) in counts {
+        println!("  {name:<width$}  {count}");
     }
 }
 
 /// Init command
 #[allow(clippy::field_reassign_with_default)]
-fn cmd_init(output: Option<PathBuf>, sample: bool) -> Result<()> {
+fn cmd_init(output: Option<PathBuf>, sample: bool, preset: Option<ContentPreset>) -> Result<()> {
     use rustume_schema::{Basics, Education, Experience, Section, Skill};
 
-    let resume = if sample {
+    let resume = if let Some(preset) = preset {
+        preset.resume()
+    } else if sample {
         let mut resume = ResumeData::default();
         resume.basics = Basics::new("Jane Doe")
             .with_headline("Software Engineer")
@@ -18,6 +18,11 @@ fn workspace_root() -> PathBuf {
 fn rustume_cmd() -> Command {
     let mut cmd = assert_cmd::cargo_bin_cmd!("rustume");
     cmd.current_dir(workspace_root());
+    // Keep test runs out of the developer's own usage log
+    cmd.env(
+        "RUSTUME_DATA_DIR",
+        PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("no-usage-log"),
+    );
     cmd
 }
 
@@ -52,6 +57,99 @@ fn test_templates_verbose() {
         .stdout(predicate::str::contains("Primary:"));
 }
 
+#[test]
+fn test_template_check_reports_missing_section() {
+    let dir = tempdir().unwrap();
+    let template = dir.path().join("candidate.typ");
+    let onyx = fs::read_to_string(
+        workspace_root().join("crates/render/src/typst_engine/templates/onyx.typ"),
+    )
+    .unwrap();
+    fs::write(
+        &template,
+        onyx.replace("projects: render-project,", "projects: item => none,"),
+    )
+    .unwrap();
+
+    rustume_cmd()
+        .arg("template-check")
+        .arg(&template)
+        .assert()
+        .code(4)
+        .stdout(predicate::str::contains("minimal: ok"))
+        .stdout(predicate::str::contains(
+            "renders nothing for the `projects` section",
+        ));
+}
+
+#[test]
+fn test_template_install_and_uninstall() {
+    let dir = tempdir().unwrap();
+    let data_dir = dir.path().join("data");
+    let package = dir.path().join("midnight.rtpl");
+    fs::create_dir(&package).unwrap();
+    fs::write(
+        package.join("manifest.json"),
+        r##"{"name": "midnight", "version": "1.0.0",
+            "theme": {"background": "#0f172a", "text": "#e2e8f0", "primary": "#38bdf8"}}"##,
+    )
+    .unwrap();
+    fs::copy(
+        workspace_root().join("crates/render/src/typst_engine/templates/onyx.typ"),
+        package.join("template.typ"),
+    )
+    .unwrap();
+    let resume = dir.path().join("resume.json");
+    let pdf = dir.path().join("resume.pdf");
+    let templates = || {
+        let mut cmd = rustume_cmd();
+        cmd.env("RUSTUME_DATA_DIR", &data_dir);
+        cmd
+    };
+
+    templates()
+        .args(["template", "install"])
+        .arg(&package)
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(
+            "Installed template midnight 1.0.0",
+        ));
+    templates()
+        .args(["templates", "--verbose"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("1.0.0 (installed)"));
+
+    templates()
+        .args(["parse", "tests/fixtures/json_resume/full.json", "-o"])
+        .arg(&resume)
+        .assert()
+        .success();
+    templates()
+        .args(["render", "--template", "midnight"])
+        .arg(&resume)
+        .arg("-o")
+        .arg(&pdf)
+        .assert()
+        .success();
+    assert!(fs::read(&pdf).unwrap().starts_with(b"%PDF"));
+
+    templates()
+        .args(["template", "uninstall", "midnight"])
+        .assert()
+        .success();
+    templates()
+        .arg("templates")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("midnight").not());
+    templates()
+        .args(["template", "uninstall", "midnight"])
+        .assert()
+        .code(5);
+}
+
 #[test]
 fn test_init_default() {
     let dir = tempdir().unwrap();
@@ -84,6 +182,26 @@ fn test_init_sample() {
     assert!(content.contains("Software Engineer"));
 }
 
+#[test]
+fn test_init_content_preset() {
+    let dir = tempdir().unwrap();
+    let output = dir.path().join("nurse.json");
+
+    rustume_cmd()
+        .args(["init", "--preset", "nurse", "-o"])
+        .arg(&output)
+        .assert()
+        .success();
+
+    let content = fs::read_to_string(&output).unwrap();
+    assert!(content.contains("Registered Nurse"));
+
+    rustume_cmd()
+        .args(["init", "--preset", "nurse", "--sample"])
+        .assert()
+        .failure();
+}
+
 #[test]
 fn test_validate_valid() {
     let dir = tempdir().unwrap();
@@ -105,6 +223,393 @@ fn test_validate_valid() {
         .stdout(predicate::str::contains("Valid resume"));
 }
 
+#[test]
+fn test_validate_low_contrast_theme_warns() {
+    let dir = tempdir().unwrap();
+    let resume_path = dir.path().join("low-contrast.json");
+    fs::write(
+        &resume_path,
+        r##"{"metadata":{"theme":{"background":"#ffffff","text":"#bbbbbb","primary":"#000000"}}}"##,
+    )
+    .unwrap();
+
+    rustume_cmd()
+        .arg("validate")
+        .arg(&resume_path)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Valid resume"))
+        .stderr(predicate::str::contains("metadata.theme.text"))
+        .stderr(predicate::str::contains("suggested: #"));
+}
+
+#[test]
+fn test_validate_rule_errors_fail() {
+    let dir = tempdir().unwrap();
+    let resume_path = dir.path().join("reversed.json");
+    fs::write(
+        &resume_path,
+        r#"{"basics":{"url":{"href":"http://jane.dev"}},"sections":{"experience":{"id":"experience","name":"Experience","items":[
+            {"id":"exp1","company":"Acme","date":"2022 - 2019"}
+        ]}}}"#,
+    )
+    .unwrap();
+
+    rustume_cmd()
+        .arg("validate")
+        .arg(&resume_path)
+        .assert()
+        .code(4)
+        .stderr(predicate::str::contains("Error [VALIDATION_FAILED]"))
+        .stderr(predicate::str::contains("Warnings:"))
+        .stderr(predicate::str::contains("suggested: https://jane.dev"))
+        .stderr(predicate::str::contains("Validation errors:"))
+        .stderr(predicate::str::contains(
+            "sections.experience.items[0].date: \"2022 - 2019\" ends before it starts",
+        ));
+}
+
+#[test]
+fn test_suggest_flags_weak_bullets() {
+    let dir = tempdir().unwrap();
+    let resume_path = dir.path().join("weak.json");
+    fs::write(
+        &resume_path,
+        r#"{"sections":{"experience":{"id":"experience","name":"Experience","items":[
+            {"id":"exp1","company":"Acme","summary":"<ul><li>Helped with the onboarding flow</li></ul>"}
+        ]}}}"#,
+    )
+    .unwrap();
+
+    rustume_cmd()
+        .arg("suggest")
+        .arg(&resume_path)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "sections.experience.items[0].summary: \"Helped with the onboarding flow\"",
+        ))
+        .stdout(predicate::str::contains("[weak_verb]"))
+        .stdout(predicate::str::contains("[missing_metric]"));
+}
+
+#[test]
+fn test_suggest_json_output() {
+    let dir = tempdir().unwrap();
+    let resume_path = dir.path().join("resume.json");
+
+    rustume_cmd()
+        .args(["init", "--sample", "-o"])
+        .arg(&resume_path)
+        .assert()
+        .success();
+
+    let output = rustume_cmd()
+        .args(["suggest", "--json"])
+        .arg(&resume_path)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let suggestions: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert!(suggestions.is_array());
+}
+
+#[test]
+fn test_export_ics_timeline() {
+    let dir = tempdir().unwrap();
+    let resume_path = dir.path().join("resume.json");
+
+    rustume_cmd()
+        .args(["init", "--sample", "-o"])
+        .arg(&resume_path)
+        .assert()
+        .success();
+
+    rustume_cmd()
+        .args(["export", "--format", "ics"])
+        .arg(&resume_path)
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with("BEGIN:VCALENDAR\r\n"))
+        .stdout(predicate::str::contains(
+            "SUMMARY:Senior Software Engineer\\, Acme Corp\r\n",
+        ))
+        .stdout(predicate::str::contains("DTSTART;VALUE=DATE:20120101\r\n"))
+        .stdout(predicate::str::contains("DTEND;VALUE=DATE:20170101\r\n"));
+}
+
+#[test]
+fn test_export_vcard_from_stdin() {
+    rustume_cmd()
+        .args(["export", "-", "--format", "vcard"])
+        .write_stdin(r#"{"basics":{"name":"Grace Hopper","headline":"Rear Admiral"}}"#)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("FN:Grace Hopper\r\n"))
+        .stdout(predicate::str::contains("TITLE:Rear Admiral\r\n"));
+}
+
+#[test]
+fn test_export_section_csv() {
+    rustume_cmd()
+        .args(["export", "-", "--section", "skills", "--format", "csv"])
+        .write_stdin(DUPLICATE_SKILLS)
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with(
+            "name,level,keywords,description,visible,id\n",
+        ))
+        .stdout(predicate::str::contains(
+            "kubernetes,1,\"helm, Istio\",Advanced,true,s3\n",
+        ));
+}
+
+#[test]
+fn test_export_csv_requires_section() {
+    rustume_cmd()
+        .args(["export", "-", "--format", "csv"])
+        .write_stdin(DUPLICATE_SKILLS)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--section is required"));
+}
+
+const DUPLICATE_SKILLS: &str = r#"{"sections":{"skills":{"id":"skills","name":"Skills","items":[
+    {"id":"s1","name":"Kubernetes","keywords":["Helm"]},
+    {"id":"s2","name":"Rust"},
+    {"id":"s3","name":"kubernetes","description":"Advanced","keywords":["helm","Istio"]}
+]}}}"#;
+
+#[test]
+fn test_dedupe_json_lists_candidates() {
+    let dir = tempdir().unwrap();
+    let resume_path = dir.path().join("resume.json");
+    fs::write(&resume_path, DUPLICATE_SKILLS).unwrap();
+
+    let output = rustume_cmd()
+        .args(["dedupe", "--json"])
+        .arg(&resume_path)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let candidates: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(candidates.as_array().unwrap().len(), 1);
+    assert_eq!(candidates[0]["keepId"], "s1");
+    assert_eq!(candidates[0]["duplicateId"], "s3");
+}
+
+#[test]
+fn test_dedupe_prompts_before_merging() {
+    let dir = tempdir().unwrap();
+    let resume_path = dir.path().join("resume.json");
+    let output_path = dir.path().join("merged.json");
+    fs::write(&resume_path, DUPLICATE_SKILLS).unwrap();
+
+    rustume_cmd()
+        .arg("dedupe")
+        .arg(&resume_path)
+        .arg("-o")
+        .arg(&output_path)
+        .write_stdin("y\n")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("1: Kubernetes"))
+        .stderr(predicate::str::contains("Merged 1 duplicate"));
+
+    let merged: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&output_path).unwrap()).unwrap();
+    let items = merged["sections"]["skills"]["items"].as_array().unwrap();
+    assert_eq!(items.len(), 2);
+    assert_eq!(items[0]["id"], "s1");
+    assert_eq!(items[0]["description"], "Advanced");
+    assert_eq!(items[0]["keywords"], serde_json::json!(["Helm", "Istio"]));
+}
+
+#[test]
+fn test_dedupe_declined_leaves_resume_unchanged() {
+    let dir = tempdir().unwrap();
+    let resume_path = dir.path().join("resume.json");
+    fs::write(&resume_path, DUPLICATE_SKILLS).unwrap();
+
+    let output = rustume_cmd()
+        .arg("dedupe")
+        .arg(&resume_path)
+        .write_stdin("n\n")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let resume: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(
+        resume["sections"]["skills"]["items"]
+            .as_array()
+            .unwrap()
+            .len(),
+        3
+    );
+}
+
+#[test]
+fn test_diff_reports_changes_word_by_word() {
+    let dir = tempdir().unwrap();
+    let old_path = dir.path().join("old.json");
+    let new_path = dir.path().join("new.json");
+    fs::write(
+        &old_path,
+        r#"{"basics":{"headline":"Backend engineer"},"sections":{"skills":{"id":"skills","name":"Skills","items":[
+            {"id":"s1","name":"Rust"},{"id":"s2","name":"Perl"}
+        ]}}}"#,
+    )
+    .unwrap();
+    fs::write(
+        &new_path,
+        r#"{"basics":{"headline":"Senior backend engineer"},"sections":{"skills":{"id":"skills","name":"Skills","items":[
+            {"id":"s1","name":"Rust"},{"id":"s3","name":"Go"}
+        ]}}}"#,
+    )
+    .unwrap();
+
+    rustume_cmd()
+        .arg("diff")
+        .arg(&old_path)
+        .arg(&new_path)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "- Headline: ~~Backend~~ **Senior backend** engineer",
+        ))
+        .stdout(predicate::str::contains("- Removed: ~~Perl~~"))
+        .stdout(predicate::str::contains("- Added: **Go**"));
+
+    rustume_cmd()
+        .args(["diff", "--format", "html"])
+        .arg(&old_path)
+        .arg(&new_path)
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with("<!DOCTYPE html>"))
+        .stdout(predicate::str::contains("<li>Added: <ins>Go</ins></li>"));
+}
+
+#[test]
+fn test_dedupe_stdin_requires_yes() {
+    rustume_cmd()
+        .args(["dedupe", "-"])
+        .write_stdin(DUPLICATE_SKILLS)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--yes"));
+
+    rustume_cmd()
+        .args(["dedupe", "-", "--yes"])
+        .write_stdin(DUPLICATE_SKILLS)
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Merged 1 duplicate"));
+}
+
+const BROKEN_RESUME: &str = r#"{
+    "sections":{"skills":{"id":"skills","name":"Skills","items":[
+        {"id":"s1","name":"Rust"},
+        {"id":"s1","name":"Go"}
+    ]}},
+    "metadata":{"layout":[[["skills","hobbies"]]],"templateOptions":{"photo":"floating"}}
+}"#;
+
+#[test]
+fn test_doctor_repairs_and_reports() {
+    let dir = tempdir().unwrap();
+    let resume_path = dir.path().join("old.json");
+    let output_path = dir.path().join("repaired.json");
+    fs::write(&resume_path, BROKEN_RESUME).unwrap();
+
+    // The file doesn't load as written
+    rustume_cmd()
+        .arg("validate")
+        .arg(&resume_path)
+        .assert()
+        .code(3);
+
+    rustume_cmd()
+        .arg("doctor")
+        .arg(&resume_path)
+        .arg("-o")
+        .arg(&output_path)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("metadata.templateOptions.photo"))
+        .stdout(predicate::str::contains("sections.skills.items[1].id"))
+        .stdout(predicate::str::contains("\"hobbies\""));
+
+    let repaired: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&output_path).unwrap()).unwrap();
+    let items = &repaired["sections"]["skills"]["items"];
+    assert_eq!(items[0]["id"], "s1");
+    assert_ne!(items[1]["id"], "s1");
+
+    rustume_cmd()
+        .arg("doctor")
+        .arg(&output_path)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("No problems found"));
+}
+
+#[test]
+fn test_doctor_json_without_output_saves_nothing() {
+    let output = rustume_cmd()
+        .args(["doctor", "--json", "-"])
+        .write_stdin(BROKEN_RESUME)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let codes: Vec<&str> = report["fixes"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|fix| fix["code"].as_str().unwrap())
+        .collect();
+    assert_eq!(
+        codes,
+        ["invalid_value", "duplicate_id", "unknown_layout_section"]
+    );
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Nothing was saved"));
+}
+
+#[test]
+fn test_preset_reorders_sections() {
+    let dir = tempdir().unwrap();
+    let resume_path = dir.path().join("resume.json");
+
+    rustume_cmd()
+        .args(["init", "--sample", "-o"])
+        .arg(&resume_path)
+        .assert()
+        .success();
+
+    let output = rustume_cmd()
+        .args(["preset", "functional"])
+        .arg(&resume_path)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let resume: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let main = &resume["metadata"]["layout"][0][0];
+    assert_eq!(main[3], "skills");
+    assert_eq!(main[4], "projects");
+
+    rustume_cmd()
+        .args(["preset", "modern"])
+        .arg(&resume_path)
+        .assert()
+        .failure();
+}
+
 #[test]
 fn test_validate_invalid_json() {
     let dir = tempdir().unwrap();
@@ -115,7 +620,8 @@ fn test_validate_invalid_json() {
         .arg("validate")
         .arg(&invalid)
         .assert()
-        .failure()
+        .code(3)
+        .stderr(predicate::str::contains("Error [PARSE_INVALID_JSON]"))
         .stderr(predicate::str::contains("Failed to parse"));
 }
 
@@ -143,6 +649,197 @@ fn test_parse_rrv3() {
         .stdout(predicate::str::contains("\"name\""));
 }
 
+#[test]
+fn test_parse_stack_overflow_detects_xml() {
+    rustume_cmd()
+        .args(["parse", "tests/fixtures/stack_overflow/export.xml"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"Ada Byron\""))
+        .stdout(predicate::str::contains("\"Acme GmbH\""));
+}
+
+#[test]
+fn test_parse_detects_html() {
+    rustume_cmd()
+        .args(["parse", "tests/fixtures/html/hresume.html"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"Margaret Hamilton\""));
+}
+
+#[test]
+fn test_parse_detects_vcard() {
+    rustume_cmd()
+        .args(["parse", "-"])
+        .write_stdin("BEGIN:VCARD\r\nVERSION:4.0\r\nFN:Grace Hopper\r\nEND:VCARD\r\n")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"Grace Hopper\""));
+}
+
+#[test]
+fn test_parse_detects_text() {
+    rustume_cmd()
+        .args(["parse", "tests/fixtures/text/resume.txt"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"Katherine Johnson\""));
+}
+
+#[test]
+fn test_parse_text_warns_about_guesses() {
+    rustume_cmd()
+        .args(["parse", "-", "--format", "text"])
+        .write_stdin("jane doe\n\nExperience\nPlumbing Plus\nWebmaster\n")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"jane doe\""))
+        .stderr(predicate::str::contains("Warning: check basics.name"));
+}
+
+#[test]
+fn test_parse_validates_unless_lenient() {
+    let json = r#"{"basics":{"name":"Jane","label":"Dev"},"work":[
+        {"name":"Acme","position":"Dev","startDate":"2022-01","endDate":"2019-01"}
+    ]}"#;
+
+    rustume_cmd()
+        .args(["parse", "-", "--format", "json-resume"])
+        .write_stdin(json)
+        .assert()
+        .code(4)
+        .stderr(predicate::str::contains("Error [VALIDATION_FAILED]"))
+        .stderr(predicate::str::contains("--lenient"));
+
+    rustume_cmd()
+        .args(["parse", "-", "--format", "json-resume", "--lenient"])
+        .write_stdin(json)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"Jan 2022 - Jan 2019\""))
+        .stderr(predicate::str::contains(
+            "Warning: check sections.experience.items[0].endDate",
+        ));
+}
+
+#[test]
+fn test_import_url_reads_saved_page() {
+    rustume_cmd()
+        .args(["import", "url", "tests/fixtures/html/hresume.html"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"MIT Lincoln Laboratory\""));
+}
+
+#[test]
+fn test_import_url_without_markup_fails() {
+    rustume_cmd()
+        .args(["import", "url", "-"])
+        .write_stdin("<html><body><p>Hello</p></body></html>")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Failed to import HTML page"));
+}
+
+#[test]
+fn test_import_csv_appends_to_resume() {
+    let dir = tempdir().unwrap();
+    let resume = dir.path().join("resume.json");
+    rustume_cmd()
+        .args(["init", "-o"])
+        .arg(&resume)
+        .assert()
+        .success();
+
+    rustume_cmd()
+        .args(["import", "csv", "--section", "languages", "-", "--resume"])
+        .arg(&resume)
+        .write_stdin("Language;Proficiency\nGerman;Native\nSpanish;B2\n")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"German\""))
+        .stdout(predicate::str::contains("\"Spanish\""))
+        .stderr(predicate::str::contains("Added 2 item(s) to languages"));
+}
+
+#[test]
+fn test_import_csv_without_name_column_fails() {
+    rustume_cmd()
+        .args(["import", "csv", "--section", "skills", "-"])
+        .write_stdin("level,tags\n4,rust\n")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("no name column"));
+}
+
+#[test]
+fn test_stats_usage_is_opt_in() {
+    let dir = tempdir().unwrap();
+    let data_dir = dir.path().join("data");
+    let resume = dir.path().join("resume.json");
+    let pdf = dir.path().join("resume.pdf");
+    let stats = || {
+        let mut cmd = rustume_cmd();
+        cmd.env("RUSTUME_DATA_DIR", &data_dir);
+        cmd
+    };
+
+    // Off by default: nothing is written
+    stats()
+        .args(["parse", "tests/fixtures/json_resume/full.json", "-o"])
+        .arg(&resume)
+        .assert()
+        .success();
+    stats()
+        .args(["stats", "--usage"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("The usage log is off"));
+    assert!(!data_dir.exists());
+
+    stats()
+        .args(["stats", "--usage", "--enable"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Usage log enabled"));
+    stats()
+        .args(["parse", "tests/fixtures/json_resume/full.json", "-o"])
+        .arg(&resume)
+        .assert()
+        .success();
+    stats()
+        .args(["render", "--template", "onyx"])
+        .arg(&resume)
+        .arg("-o")
+        .arg(&pdf)
+        .assert()
+        .success();
+
+    let output = stats()
+        .args(["stats", "--usage", "--json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let counts: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(counts["renders"], 1);
+    assert_eq!(counts["templates"]["onyx"], 1);
+    assert_eq!(counts["imports"]["json-resume"], 1);
+
+    stats()
+        .args(["stats", "--usage"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Renders: 1"))
+        .stdout(predicate::str::contains("Imports: 1"));
+
+    stats()
+        .args(["stats", "--usage", "--disable"])
+        .assert()
+        .success();
+    assert!(!data_dir.join("usage.json").exists());
+}
+
 #[test]
 fn test_parse_to_file() {
     let dir = tempdir().unwrap();
@@ -187,6 +884,104 @@ fn test_render_pdf() {
     assert!(content.starts_with(b"%PDF"));
 }
 
+#[test]
+fn test_render_pdf_with_exclusions() {
+    let dir = tempdir().unwrap();
+    let resume = dir.path().join("resume.json");
+    let pdf = dir.path().join("public.pdf");
+
+    rustume_cmd()
+        .args(["init", "--sample", "-o"])
+        .arg(&resume)
+        .assert()
+        .success();
+
+    rustume_cmd()
+        .args(["render"])
+        .arg(&resume)
+        .args([
+            "--exclude-section",
+            "basics.phone",
+            "--exclude-section",
+            "skills",
+            "-o",
+        ])
+        .arg(&pdf)
+        .assert()
+        .success();
+    assert!(fs::read(&pdf).unwrap().starts_with(b"%PDF"));
+
+    rustume_cmd()
+        .args(["render"])
+        .arg(&resume)
+        .args(["--exclude-section", "skils", "-o"])
+        .arg(&pdf)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Unknown sections to exclude: skils",
+        ));
+}
+
+#[cfg(not(feature = "chromium"))]
+#[test]
+fn test_render_chromium_engine_needs_feature() {
+    let dir = tempdir().unwrap();
+    let resume = dir.path().join("resume.json");
+
+    rustume_cmd()
+        .args(["init", "-o"])
+        .arg(&resume)
+        .assert()
+        .success();
+
+    rustume_cmd()
+        .args(["render"])
+        .arg(&resume)
+        .args(["--engine", "chromium", "-o"])
+        .arg(dir.path().join("resume.pdf"))
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "rebuild rustume with the `chromium` feature",
+        ));
+}
+
+#[test]
+fn test_export_site() {
+    let dir = tempdir().unwrap();
+    let resume = dir.path().join("resume.json");
+    let site = dir.path().join("site");
+
+    rustume_cmd()
+        .args(["init", "--sample", "-o"])
+        .arg(&resume)
+        .assert()
+        .success();
+
+    rustume_cmd()
+        .args(["export-site"])
+        .arg(&resume)
+        .args(["--url", "https://jane.example.com/", "-o"])
+        .arg(&site)
+        .assert()
+        .success();
+
+    let page = fs::read_to_string(site.join("index.html")).unwrap();
+    assert!(page.contains("<h1>Jane Doe</h1>"));
+    assert!(page.contains("property=\"og:url\" content=\"https://jane.example.com/\""));
+    assert!(page.contains("href=\"resume.pdf\" download"));
+    assert!(fs::read_to_string(site.join("style.css"))
+        .unwrap()
+        .contains("--primary"));
+    assert!(fs::read_to_string(site.join("print.css"))
+        .unwrap()
+        .contains("@page"));
+    assert!(fs::read(site.join("resume.pdf"))
+        .unwrap()
+        .starts_with(b"%PDF"));
+}
+
 #[test]
 fn test_preview_png() {
     let dir = tempdir().unwrap();
@@ -215,6 +1010,88 @@ fn test_preview_png() {
     assert!(content.starts_with(&[0x89, 0x50, 0x4E, 0x47]));
 }
 
+#[test]
+fn test_fit_pages() {
+    let dir = tempdir().unwrap();
+    let resume = dir.path().join("resume.json");
+    let fitted = dir.path().join("fitted.json");
+
+    rustume_cmd()
+        .args(["init", "--sample", "-o"])
+        .arg(&resume)
+        .assert()
+        .success();
+
+    let output = rustume_cmd()
+        .args(["fit", "--json"])
+        .arg(&resume)
+        .output()
+        .unwrap();
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(report["targetPages"], 1);
+    assert_eq!(output.status.success(), report["fits"] == true);
+
+    rustume_cmd()
+        .arg("fit")
+        .arg(&resume)
+        .args(["--pages", "5", "-o"])
+        .arg(&fitted)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("already fits in 5 pages"));
+    assert!(fitted.exists());
+
+    rustume_cmd()
+        .arg("fit")
+        .arg(&resume)
+        .args(["--pages", "0"])
+        .assert()
+        .code(2);
+}
+
+#[test]
+fn test_compare_sheet() {
+    let dir = tempdir().unwrap();
+    let resume = dir.path().join("resume.json");
+    let pdf = dir.path().join("sheet.pdf");
+    let png = dir.path().join("sheet.png");
+
+    rustume_cmd()
+        .args(["init", "--sample", "-o"])
+        .arg(&resume)
+        .assert()
+        .success();
+
+    rustume_cmd()
+        .arg("compare")
+        .arg(&resume)
+        .arg("-o")
+        .arg(&pdf)
+        .assert()
+        .success();
+    assert!(fs::read(&pdf).unwrap().starts_with(b"%PDF"));
+
+    rustume_cmd()
+        .arg("compare")
+        .arg(&resume)
+        .args(["-t", "rhyhorn", "-t", "onyx", "-o"])
+        .arg(&png)
+        .assert()
+        .success();
+    assert!(fs::read(&png)
+        .unwrap()
+        .starts_with(&[0x89, 0x50, 0x4E, 0x47]));
+
+    rustume_cmd()
+        .arg("compare")
+        .arg(&resume)
+        .args(["-t", "missingno", "-o"])
+        .arg(&png)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Unknown template: missingno"));
+}
+
 #[test]
 fn test_stdin_parse() {
     let fixture_path = workspace_root().join("tests/fixtures/json_resume/minimal.json");
@@ -263,3 +1140,23 @@ fn test_parse_and_render_pipeline() {
     let content = fs::read(&pdf).unwrap();
     assert!(content.starts_with(b"%PDF"));
 }
+
+#[cfg(feature = "dev")]
+#[test]
+fn test_dev_templates_requires_a_directory_and_a_resume_file() {
+    let temp = tempdir().unwrap();
+    rustume_cmd()
+        .args(["templates", "--dev-templates"])
+        .arg(temp.path().join("missing"))
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("does not exist"));
+
+    rustume_cmd()
+        .args(["preview", "-", "--dev-templates"])
+        .arg(temp.path())
+        .write_stdin("{}")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("not stdin"));
+}
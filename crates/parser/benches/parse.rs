@@ -0,0 +1,87 @@
+//! Parse timings for the import formats.
+//!
+//! Run with `cargo bench -p rustume-parser --bench parse`. Criterion keeps
+//! results in `target/criterion/`; `-- --save-baseline <name>` records a
+//! baseline and `-- --baseline <name>` compares against it.
+
+use std::fs;
+use std::hint::black_box;
+use std::io::{Cursor, Write};
+use std::path::PathBuf;
+
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use rustume_parser::{JsonResumeParser, LinkedInParser, Parser};
+
+/// Copies of each list row in the large LinkedIn export.
+const LINKEDIN_ROW_COPIES: usize = 250;
+
+fn fixture_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../tests/fixtures")
+}
+
+/// A LinkedIn export built from the fixture CSVs, with the rows of every
+/// list file repeated so parsing does real work.
+fn large_linkedin_export() -> Vec<u8> {
+    let dir = fixture_dir().join("linkedin");
+    let mut entries: Vec<_> = fs::read_dir(&dir)
+        .expect("read linkedin fixtures")
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            (path.extension()? == "csv").then_some(path)
+        })
+        .collect();
+    entries.sort();
+
+    let mut buffer = Vec::new();
+    let mut zip = zip::ZipWriter::new(Cursor::new(&mut buffer));
+    let options = zip::write::SimpleFileOptions::default()
+        .compression_method(zip::CompressionMethod::Deflated);
+    for path in entries {
+        let name = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .expect("file name");
+        let contents = fs::read_to_string(&path).expect("read csv fixture");
+        zip.start_file(name, options).expect("start zip entry");
+
+        // Profile and email files hold one person; only lists grow
+        if matches!(name, "Profile.csv" | "Email Addresses.csv") {
+            zip.write_all(contents.as_bytes()).expect("write zip entry");
+            continue;
+        }
+        let (header, rows) = contents.split_once('\n').expect("csv header");
+        let rows = rows.trim_end_matches('\n');
+        zip.write_all(header.as_bytes()).expect("write zip entry");
+        for _ in 0..LINKEDIN_ROW_COPIES {
+            zip.write_all(b"\n").expect("write zip entry");
+            zip.write_all(rows.as_bytes()).expect("write zip entry");
+        }
+        zip.write_all(b"\n").expect("write zip entry");
+    }
+    zip.finish().expect("finish zip");
+    buffer
+}
+
+fn bench_json_resume(c: &mut Criterion) {
+    let data = fs::read(fixture_dir().join("json_resume/full.json")).expect("read full.json");
+    let mut group = c.benchmark_group("parse");
+    group.throughput(Throughput::Bytes(data.len() as u64));
+    group.bench_function("json_resume", |b| {
+        b.iter(|| JsonResumeParser.parse(black_box(&data)).expect("parse"));
+    });
+    group.finish();
+}
+
+fn bench_linkedin(c: &mut Criterion) {
+    let data = large_linkedin_export();
+    let parser = LinkedInParser::default();
+    let mut group = c.benchmark_group("parse");
+    group.throughput(Throughput::Bytes(data.len() as u64));
+    group.bench_function("linkedin_large_zip", |b| {
+        b.iter(|| parser.parse(black_box(&data)).expect("parse"));
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_json_resume, bench_linkedin);
+criterion_main!(benches);
@@ -0,0 +1,42 @@
+//! Content-line helpers shared by the vCard and iCalendar writers.
+//!
+//! Both formats use the same line syntax: `NAME;PARAM=VALUE:value`, CRLF
+//! endings, lines folded at 75 octets, and backslash-escaped text values
+//! (RFC 6350 §3.2–3.4, RFC 5545 §3.1 and §3.3.11).
+
+/// Longest content line in octets, excluding the line break.
+pub(crate) const MAX_LINE_OCTETS: usize = 75;
+
+/// Split a line longer than [`MAX_LINE_OCTETS`] into continuation lines,
+/// never inside a character.
+pub(crate) fn fold(line: &str) -> String {
+    let mut folded = String::with_capacity(line.len() + line.len() / MAX_LINE_OCTETS * 3);
+    let mut width = 0;
+    for c in line.chars() {
+        if width + c.len_utf8() > MAX_LINE_OCTETS {
+            folded.push_str("\r\n ");
+            // The leading space counts toward the continuation line
+            width = 1;
+        }
+        folded.push(c);
+        width += c.len_utf8();
+    }
+    folded
+}
+
+/// Escape a text value: backslash, comma, semicolon, and newline.
+pub(crate) fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' | ',' | ';' => {
+                out.push('\\');
+                out.push(c);
+            }
+            '\n' => out.push_str("\\n"),
+            '\r' => {}
+            _ => out.push(c),
+        }
+    }
+    out
+}
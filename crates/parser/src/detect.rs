@@ -0,0 +1,301 @@
+//! Input format detection from content.
+//!
+//! [`detect_format`] looks at the bytes of an upload, never at its name, and
+//! reports the most likely format with a confidence between 0 and 1:
+//!
+//! | Input | Detected as | Confidence |
+//! | --- | --- | --- |
+//! | `%PDF-` magic | [`DetectedKind::Pdf`] | 1.0 |
+//! | ZIP with `word/document.xml` | [`DetectedKind::Docx`] | 1.0 |
+//! | ZIP with `Profile.csv` or `Positions.csv` | LinkedIn export | 0.95 |
+//! | Other ZIP | LinkedIn export (`linkedin` feature), else [`DetectedKind::Zip`] | 0.5 |
+//! | `BEGIN:VCARD` | vCard | 1.0 |
+//! | `<!DOCTYPE html>` or `<html` near the start | HTML page | 0.95 |
+//! | Other markup | Stack Overflow data dump | 0.9 with `<row`, else 0.5 |
+//! | JSON `user` without `basics`, or `top_tags` | Stack Overflow API JSON | 0.9 |
+//! | JSON `sections`, `metadata`, and `public` | Reactive Resume v3 | 0.9 |
+//! | JSON `basics.headline` | Rustume JSON | 0.9 |
+//! | JSON `basics.label` | JSON Resume | 0.9 |
+//! | JSON `sections` and `metadata` | Rustume JSON | 0.8 |
+//! | Other JSON | JSON Resume | 0.6 with `basics`, else 0.3 |
+//!
+//! Plain text is never detected: any text could be a resume, so callers have
+//! to ask for [`ResumeFormat::Text`] by name.
+
+use serde_json::Value;
+
+use crate::{ParseError, ResumeFormat};
+
+/// ZIP signatures: local file header, empty archive, spanned archive.
+const ZIP_MAGIC: [&[u8]; 3] = [b"PK\x03\x04", b"PK\x05\x06", b"PK\x07\x08"];
+
+const PDF_MAGIC: &[u8] = b"%PDF-";
+
+/// What [`detect_format`] recognised an input as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DetectedKind {
+    /// A format [`parse_resume`](crate::parse_resume) reads.
+    Resume(ResumeFormat),
+    /// A PDF document, which has to be imported as its text.
+    Pdf,
+    /// A Word document, which has to be imported as its text.
+    Docx,
+    /// A ZIP archive other than a Word document, when the `linkedin` feature
+    /// is off.
+    Zip,
+}
+
+/// Result of [`detect_format`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DetectedFormat {
+    pub kind: DetectedKind,
+    /// How sure the detection is, from 0 to 1. Magic bytes and other
+    /// signatures score 1.
+    pub confidence: f32,
+}
+
+impl DetectedFormat {
+    fn new(kind: DetectedKind, confidence: f32) -> Self {
+        Self { kind, confidence }
+    }
+
+    fn resume(format: ResumeFormat, confidence: f32) -> Self {
+        Self::new(DetectedKind::Resume(format), confidence)
+    }
+
+    /// The resume format to parse the input as, if it is one.
+    pub fn resume_format(&self) -> Option<ResumeFormat> {
+        match self.kind {
+            DetectedKind::Resume(format) => Some(format),
+            DetectedKind::Pdf | DetectedKind::Docx | DetectedKind::Zip => None,
+        }
+    }
+}
+
+/// Detect the format of `data` from its magic bytes and structure.
+///
+/// Returns `None` for input that matches no format, including plain text.
+pub fn detect_format(data: &[u8]) -> Option<DetectedFormat> {
+    if data.starts_with(PDF_MAGIC) {
+        return Some(DetectedFormat::new(DetectedKind::Pdf, 1.0));
+    }
+    if ZIP_MAGIC.iter().any(|magic| data.starts_with(magic)) {
+        return Some(detect_zip(data));
+    }
+
+    let text = std::str::from_utf8(data).ok()?;
+    let text = text.trim_start_matches('\u{feff}').trim_start();
+    if text
+        .get(..11)
+        .is_some_and(|head| head.eq_ignore_ascii_case("BEGIN:VCARD"))
+    {
+        return Some(DetectedFormat::resume(ResumeFormat::Vcard, 1.0));
+    }
+    // Markup is either an HTML page or the Stack Overflow data dump
+    if text.starts_with('<') {
+        let head = text
+            .chars()
+            .take(256)
+            .collect::<String>()
+            .to_ascii_lowercase();
+        if head.contains("<!doctype html") || head.contains("<html") {
+            return Some(DetectedFormat::resume(ResumeFormat::Html, 0.95));
+        }
+        let confidence = if text.contains("<row") { 0.9 } else { 0.5 };
+        return Some(DetectedFormat::resume(
+            ResumeFormat::StackOverflow,
+            confidence,
+        ));
+    }
+
+    let json = serde_json::from_str::<Value>(text).ok()?;
+    Some(detect_json(&json))
+}
+
+/// [`detect_format`], narrowed to the formats [`parse_resume`](crate::parse_resume)
+/// reads. Inputs it can't place fail with a message naming what to do instead.
+pub fn detect_resume_format(data: &[u8]) -> Result<ResumeFormat, ParseError> {
+    detect_resume(data).map(|(format, _)| format)
+}
+
+/// [`detect_resume_format`] with the confidence of the detection.
+pub(crate) fn detect_resume(data: &[u8]) -> Result<(ResumeFormat, f32), ParseError> {
+    let Some(detected) = detect_format(data) else {
+        return Err(ParseError::ValidationError(
+            "could not detect the input format".to_string(),
+        ));
+    };
+    let confidence = detected.confidence;
+    detected
+        .resume_format()
+        .map(|format| (format, confidence))
+        .ok_or_else(|| {
+            let message = match detected.kind {
+                DetectedKind::Pdf => "PDF documents can't be imported; import their text instead",
+                DetectedKind::Docx => "Word documents can't be imported; import their text instead",
+                _ => "ZIP archives need the LinkedIn parser, which this build leaves out",
+            };
+            ParseError::ValidationError(message.to_string())
+        })
+}
+
+fn detect_zip(data: &[u8]) -> DetectedFormat {
+    // Entry names are stored uncompressed in the local file headers
+    if contains(data, b"word/document.xml") {
+        return DetectedFormat::new(DetectedKind::Docx, 1.0);
+    }
+    #[cfg(feature = "linkedin")]
+    {
+        let confidence = if contains(data, b"Profile.csv") || contains(data, b"Positions.csv") {
+            0.95
+        } else {
+            0.5
+        };
+        DetectedFormat::resume(ResumeFormat::LinkedIn, confidence)
+    }
+    #[cfg(not(feature = "linkedin"))]
+    {
+        DetectedFormat::new(DetectedKind::Zip, 0.5)
+    }
+}
+
+fn detect_json(json: &Value) -> DetectedFormat {
+    let has = |key: &str| json.get(key).is_some();
+
+    // Stack Exchange API shapes: a "user" object or per-tag totals
+    if has("top_tags") || (has("user") && !has("basics")) {
+        return DetectedFormat::resume(ResumeFormat::StackOverflow, 0.9);
+    }
+    // Rustume also has sections, metadata, and basics.headline; only v3
+    // has "public"
+    if has("sections") && has("metadata") && has("public") {
+        return DetectedFormat::resume(ResumeFormat::Rrv3, 0.9);
+    }
+    if let Some(basics) = json.get("basics") {
+        // Native Rustume has "headline" where JSON Resume has "label"
+        if basics.get("headline").is_some() {
+            return DetectedFormat::resume(ResumeFormat::Rustume, 0.9);
+        }
+        if basics.get("label").is_some() {
+            return DetectedFormat::resume(ResumeFormat::JsonResume, 0.9);
+        }
+    }
+    if has("sections") && has("metadata") {
+        return DetectedFormat::resume(ResumeFormat::Rustume, 0.8);
+    }
+    let confidence = if has("basics") { 0.6 } else { 0.3 };
+    DetectedFormat::resume(ResumeFormat::JsonResume, confidence)
+}
+
+fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+    haystack
+        .windows(needle.len())
+        .any(|window| window == needle)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::path::PathBuf;
+
+    fn fixture(path: &str) -> Vec<u8> {
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("../../tests/fixtures")
+            .join(path);
+        fs::read(&path).unwrap_or_else(|err| panic!("read {}: {err}", path.display()))
+    }
+
+    fn detected(data: &[u8]) -> Option<ResumeFormat> {
+        detect_format(data).and_then(|detected| detected.resume_format())
+    }
+
+    #[test]
+    fn detects_fixtures() {
+        let cases = [
+            ("json_resume/full.json", ResumeFormat::JsonResume),
+            ("json_resume/minimal.json", ResumeFormat::JsonResume),
+            ("v3/complete.json", ResumeFormat::Rrv3),
+            ("html/hresume.html", ResumeFormat::Html),
+            ("vcard/contact.vcf", ResumeFormat::Vcard),
+            ("stack_overflow/export.xml", ResumeFormat::StackOverflow),
+        ];
+        for (path, format) in cases {
+            assert_eq!(detected(&fixture(path)), Some(format), "{path}");
+        }
+    }
+
+    #[cfg(feature = "linkedin")]
+    #[test]
+    fn detects_linkedin_exports() {
+        let detected = detect_format(&fixture("linkedin/complete_export.zip")).unwrap();
+        assert_eq!(detected.resume_format(), Some(ResumeFormat::LinkedIn));
+        assert!(detected.confidence > 0.9);
+
+        // Any other archive is only a guess
+        let detected = detect_format(b"PK\x05\x06\0\0\0\0").unwrap();
+        assert_eq!(detected.resume_format(), Some(ResumeFormat::LinkedIn));
+        assert!(detected.confidence < 0.9);
+    }
+
+    #[test]
+    fn detects_documents_it_cannot_parse() {
+        let pdf = detect_format(b"%PDF-1.7\n%\xe2\xe3\xcf\xd3\n").unwrap();
+        assert_eq!(pdf.kind, DetectedKind::Pdf);
+        assert_eq!(pdf.confidence, 1.0);
+
+        let docx = b"PK\x03\x04\x14\0\x06\0\x08\0\0\0!\0\0\0\0\0\0\0\0\0\0\0\0\0\x11\0\0\0word/document.xml";
+        assert_eq!(detect_format(docx).unwrap().kind, DetectedKind::Docx);
+
+        let err = detect_resume_format(docx).unwrap_err();
+        assert!(err.to_string().contains("Word documents"), "{err}");
+    }
+
+    #[test]
+    fn detects_json_shapes() {
+        let cases = [
+            (
+                r#"{"basics":{"name":"A","headline":"Dev"}}"#,
+                ResumeFormat::Rustume,
+            ),
+            (
+                r#"{"basics":{"name":"A","label":"Dev"}}"#,
+                ResumeFormat::JsonResume,
+            ),
+            (
+                r#"{"sections":{},"metadata":{},"public":true}"#,
+                ResumeFormat::Rrv3,
+            ),
+            (r#"{"sections":{},"metadata":{}}"#, ResumeFormat::Rustume),
+            (
+                r#"{"user":{"display_name":"A"}}"#,
+                ResumeFormat::StackOverflow,
+            ),
+            (r#"{"top_tags":[]}"#, ResumeFormat::StackOverflow),
+            (r#"{"work":[]}"#, ResumeFormat::JsonResume),
+        ];
+        for (json, format) in cases {
+            assert_eq!(detected(json.as_bytes()), Some(format), "{json}");
+        }
+        // A bare object is only a guess
+        assert!(detect_format(b"{}").unwrap().confidence < 0.5);
+    }
+
+    #[test]
+    fn detects_markup_after_a_byte_order_mark() {
+        let html = "\u{feff}\n<!DOCTYPE html><html><body></body></html>";
+        assert_eq!(detected(html.as_bytes()), Some(ResumeFormat::Html));
+        let vcard = "\u{feff}begin:vcard\r\nFN:A\r\nEND:VCARD\r\n";
+        assert_eq!(detected(vcard.as_bytes()), Some(ResumeFormat::Vcard));
+    }
+
+    #[test]
+    fn plain_text_is_not_detected() {
+        assert_eq!(detect_format(&fixture("text/resume.txt")), None);
+        assert_eq!(detect_format(b"\xff\xfe\0binary"), None);
+        assert_eq!(detect_format(b""), None);
+
+        let err = detect_resume_format(b"Jane Doe\nEngineer").unwrap_err();
+        assert!(err.to_string().contains("could not detect"), "{err}");
+    }
+}
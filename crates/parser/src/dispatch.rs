@@ -1,18 +1,35 @@
 //! Shared resume format dispatch for server and CLI.
 
-use rustume_schema::ResumeData;
+use rustume_schema::{FieldDeprecation, LegacyFields, ResumeData};
+use serde::{Deserialize, Serialize};
 
-use crate::{JsonResumeParser, LinkedInParser, ParseError, Parser, ReactiveResumeV3Parser};
+#[cfg(feature = "linkedin")]
+use crate::LinkedInParser;
+use crate::{
+    HtmlParser, JsonResumeParser, ParseError, ParsePhase, ParseProgress, Parser,
+    ReactiveResumeV3Parser, StackOverflowParser, TextResumeParser, VcardParser,
+};
 
-/// Supported resume input formats.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Supported resume input formats, serialized by their kebab-case names
+/// (`json-resume`, `linked-in`, ...).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub enum ResumeFormat {
     /// JSON Resume standard format (https://jsonresume.org)
     JsonResume,
     /// LinkedIn data export ZIP file
+    #[cfg(feature = "linkedin")]
     LinkedIn,
     /// Reactive Resume v3 format
     Rrv3,
+    /// Stack Overflow user data (API JSON or data-dump XML)
+    StackOverflow,
+    /// HTML page with hResume or schema.org `Person` markup
+    Html,
+    /// vCard contact (.vcf), imported into basics
+    Vcard,
+    /// Unstructured plain text, sectionized heuristically
+    Text,
     /// Native Rustume format
     Rustume,
 }
@@ -22,8 +39,13 @@ impl ResumeFormat {
     pub fn label(self) -> &'static str {
         match self {
             Self::JsonResume => "JSON Resume",
+            #[cfg(feature = "linkedin")]
             Self::LinkedIn => "LinkedIn export",
             Self::Rrv3 => "Reactive Resume v3",
+            Self::StackOverflow => "Stack Overflow export",
+            Self::Html => "HTML page",
+            Self::Vcard => "vCard",
+            Self::Text => "plain text",
             Self::Rustume => "Rustume JSON",
         }
     }
@@ -33,10 +55,45 @@ impl ResumeFormat {
 pub fn parse_resume(format: ResumeFormat, data: &[u8]) -> Result<ResumeData, ParseError> {
     match format {
         ResumeFormat::JsonResume => JsonResumeParser.parse(data),
-        ResumeFormat::LinkedIn => LinkedInParser.parse(data),
+        #[cfg(feature = "linkedin")]
+        ResumeFormat::LinkedIn => LinkedInParser::default().parse(data),
         ResumeFormat::Rrv3 => ReactiveResumeV3Parser.parse(data),
-        ResumeFormat::Rustume => serde_json::from_slice(data)
-            .map_err(|err| ParseError::DeserializeError(err.to_string())),
+        ResumeFormat::StackOverflow => StackOverflowParser.parse(data),
+        ResumeFormat::Html => HtmlParser.parse(data),
+        ResumeFormat::Vcard => VcardParser.parse(data),
+        ResumeFormat::Text => TextResumeParser.parse(data),
+        ResumeFormat::Rustume => parse_rustume(data).map(|(resume, _)| resume),
+    }
+}
+
+/// Parse native Rustume JSON, listing the keys of renamed fields it still
+/// uses.
+pub fn parse_rustume(data: &[u8]) -> Result<(ResumeData, Vec<FieldDeprecation>), ParseError> {
+    serde_json::from_slice(data)
+        .and_then(ResumeData::from_json_value)
+        .map_err(|err| ParseError::DeserializeError(err.to_string()))
+}
+
+/// [`parse_resume`], calling `progress` as the parser works through `data`.
+pub fn parse_resume_with_progress(
+    format: ResumeFormat,
+    data: &[u8],
+    progress: &mut dyn FnMut(ParseProgress),
+) -> Result<ResumeData, ParseError> {
+    match format {
+        ResumeFormat::JsonResume => JsonResumeParser.parse_with_progress(data, progress),
+        #[cfg(feature = "linkedin")]
+        ResumeFormat::LinkedIn => LinkedInParser::default().parse_with_progress(data, progress),
+        ResumeFormat::Rrv3 => ReactiveResumeV3Parser.parse_with_progress(data, progress),
+        ResumeFormat::StackOverflow => StackOverflowParser.parse_with_progress(data, progress),
+        ResumeFormat::Html => HtmlParser.parse_with_progress(data, progress),
+        ResumeFormat::Vcard => VcardParser.parse_with_progress(data, progress),
+        ResumeFormat::Text => TextResumeParser.parse_with_progress(data, progress),
+        ResumeFormat::Rustume => {
+            let resume = parse_resume(format, data)?;
+            progress(ParseProgress::new(ParsePhase::Convert, 100));
+            Ok(resume)
+        }
     }
 }
 
@@ -72,6 +129,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "linkedin")]
     fn test_parse_linkedin_success() {
         let data = fs::read(fixtures_path().join("linkedin/complete_export.zip"))
             .expect("Failed to read LinkedIn ZIP fixture");
@@ -81,11 +139,34 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "linkedin")]
     fn test_parse_linkedin_failure() {
         let result = parse_resume(ResumeFormat::LinkedIn, b"not a zip file");
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_parse_with_progress_reports_phases() {
+        let data = fs::read(fixtures_path().join("json_resume/minimal.json"))
+            .expect("Failed to read minimal.json fixture");
+        let mut phases = Vec::new();
+
+        parse_resume_with_progress(ResumeFormat::JsonResume, &data, &mut |p| {
+            phases.push((p.phase, p.percent))
+        })
+        .expect("parse should succeed");
+
+        assert_eq!(
+            phases,
+            [
+                (ParsePhase::Read, 0),
+                (ParsePhase::Validate, 33),
+                (ParsePhase::Convert, 67),
+                (ParsePhase::Convert, 100)
+            ]
+        );
+    }
+
     #[test]
     fn test_parse_rrv3_success() {
         let data = fs::read(fixtures_path().join("v3/complete.json"))
@@ -101,6 +182,56 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_parse_stack_overflow_success() {
+        let data = fs::read(fixtures_path().join("stack_overflow/export.xml"))
+            .expect("Failed to read export.xml fixture");
+
+        let resume =
+            parse_resume(ResumeFormat::StackOverflow, &data).expect("parse should succeed");
+        assert_eq!(resume.basics.name, "Ada Byron");
+        assert_eq!(resume.sections.experience.len(), 2);
+        assert_eq!(resume.sections.skills.items[0].name, "rust");
+    }
+
+    #[test]
+    fn test_parse_html_success() {
+        let data = fs::read(fixtures_path().join("html/hresume.html"))
+            .expect("Failed to read hresume.html fixture");
+
+        let resume = parse_resume(ResumeFormat::Html, &data).expect("parse should succeed");
+        assert_eq!(resume.basics.name, "Margaret Hamilton");
+        assert_eq!(resume.sections.experience.len(), 2);
+        assert_eq!(resume.sections.education.len(), 1);
+        assert_eq!(
+            resume.sections.experience.items[0].url.href,
+            "https://www.draper.com"
+        );
+    }
+
+    #[test]
+    fn test_parse_vcard_success() {
+        let data = fs::read(fixtures_path().join("vcard/contact.vcf"))
+            .expect("Failed to read contact.vcf fixture");
+
+        let resume = parse_resume(ResumeFormat::Vcard, &data).expect("parse should succeed");
+        assert_eq!(resume.basics.name, "Grace Hopper");
+        assert_eq!(resume.basics.email, "grace@example.com");
+        assert_eq!(resume.basics.location, "Arlington, VA, USA");
+    }
+
+    #[test]
+    fn test_parse_text_success() {
+        let data = fs::read(fixtures_path().join("text/resume.txt"))
+            .expect("Failed to read resume.txt fixture");
+
+        let resume = parse_resume(ResumeFormat::Text, &data).expect("parse should succeed");
+        assert_eq!(resume.basics.name, "Katherine Johnson");
+        assert_eq!(resume.basics.email, "katherine@example.com");
+        assert_eq!(resume.sections.experience.len(), 2);
+        assert_eq!(resume.sections.education.len(), 1);
+    }
+
     #[test]
     fn test_parse_rustume_success() {
         let resume = ResumeData::default();
@@ -123,8 +254,13 @@ mod tests {
     #[test]
     fn test_resume_format_labels() {
         assert_eq!(ResumeFormat::JsonResume.label(), "JSON Resume");
+        #[cfg(feature = "linkedin")]
         assert_eq!(ResumeFormat::LinkedIn.label(), "LinkedIn export");
         assert_eq!(ResumeFormat::Rrv3.label(), "Reactive Resume v3");
+        assert_eq!(ResumeFormat::StackOverflow.label(), "Stack Overflow export");
+        assert_eq!(ResumeFormat::Html.label(), "HTML page");
+        assert_eq!(ResumeFormat::Vcard.label(), "vCard");
+        assert_eq!(ResumeFormat::Text.label(), "plain text");
         assert_eq!(ResumeFormat::Rustume.label(), "Rustume JSON");
     }
 }
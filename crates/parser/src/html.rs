@@ -0,0 +1,974 @@
+//! HTML page parser.
+//!
+//! Scrapes a saved web page, such as a personal site, for resume markup in
+//! either vocabulary:
+//!
+//! - **hResume**, as microformats2 (`h-resume` with `p-contact`,
+//!   `p-experience`, `p-education`) or with the classic class names
+//!   (`hresume`, `vcard`, `vevent`).
+//! - **schema.org `Person`**, as JSON-LD or microdata. `worksFor` becomes
+//!   experience and `alumniOf` education, including the `Role` form that
+//!   adds `roleName`, `startDate`, and `endDate`.
+//!
+//! Microformats are first read into the microformats2 JSON shape and
+//! microdata into the JSON-LD shape, so each vocabulary is interpreted in one
+//! place. Nothing is fetched; callers pass the page's bytes.
+
+use scraper::{ElementRef, Html};
+use serde_json::{Map, Value};
+
+use crate::traits::{ParseError, Parser};
+use rustume_schema::{Education, Experience, ResumeData, Section, Url};
+use rustume_utils::{format_date_range_in, sanitize_html, Locale};
+
+/// HTML page parser (hResume or schema.org `Person` markup).
+pub struct HtmlParser;
+
+// ============================================================================
+// Scraped Types
+// ============================================================================
+
+/// Resume data scraped from an HTML page.
+///
+/// A page may describe the same person in several vocabularies. Basics take
+/// the first value found, checking the `h-resume`, then JSON-LD, then
+/// microdata, then a lone `h-card`; experience and education come from the
+/// first of these that has any, so entries aren't imported twice.
+#[derive(Debug, Default)]
+pub struct HtmlResume {
+    /// `lang` of the `<html>` element
+    language: Option<String>,
+    basics: HtmlBasics,
+    experience: Vec<HtmlEntry>,
+    education: Vec<HtmlEntry>,
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct HtmlBasics {
+    name: Option<String>,
+    headline: Option<String>,
+    email: Option<String>,
+    phone: Option<String>,
+    location: Option<String>,
+    url: Option<String>,
+    /// HTML or plain text
+    summary: Option<String>,
+}
+
+/// A job or a school.
+#[derive(Debug, Default)]
+struct HtmlEntry {
+    /// Employer or school
+    organization: Option<String>,
+    /// Position, or degree for schools
+    title: Option<String>,
+    location: Option<String>,
+    start: Option<String>,
+    end: Option<String>,
+    /// HTML or plain text
+    summary: Option<String>,
+    url: Option<String>,
+}
+
+impl HtmlBasics {
+    /// Fill fields that are still empty from `other`.
+    fn fill(&mut self, other: Self) {
+        self.name = self.name.take().or(other.name);
+        self.headline = self.headline.take().or(other.headline);
+        self.email = self.email.take().or(other.email);
+        self.phone = self.phone.take().or(other.phone);
+        self.location = self.location.take().or(other.location);
+        self.url = self.url.take().or(other.url);
+        self.summary = self.summary.take().or(other.summary);
+    }
+}
+
+impl HtmlResume {
+    fn is_empty(&self) -> bool {
+        self.basics == HtmlBasics::default()
+            && self.experience.is_empty()
+            && self.education.is_empty()
+    }
+
+    fn merge(&mut self, other: Self) {
+        self.basics.fill(other.basics);
+        if self.experience.is_empty() {
+            self.experience = other.experience;
+        }
+        if self.education.is_empty() {
+            self.education = other.education;
+        }
+    }
+
+    fn from_html(html: &str) -> Self {
+        let document = Html::parse_document(html);
+        let root = document.root_element();
+        let mut resume = Self {
+            language: root.value().attr("lang").and_then(non_empty),
+            ..Self::default()
+        };
+
+        let items = microformats(root);
+        if let Some(h_resume) = items.iter().find(|item| has_type(item, "h-resume")) {
+            resume.merge(from_h_resume(h_resume));
+        }
+        if let Some(person) = json_ld_person(root) {
+            resume.merge(from_person(&person));
+        }
+        if let Some(person) = microdata_person(root) {
+            resume.merge(from_person(&person));
+        }
+        if let Some(card) = items.iter().find(|item| has_type(item, "h-card")) {
+            resume.basics.fill(card_basics(card));
+        }
+        resume
+    }
+}
+
+// ============================================================================
+// Parser Implementation
+// ============================================================================
+
+impl Parser for HtmlParser {
+    type RawData = HtmlResume;
+    type ValidatedData = HtmlResume;
+
+    fn read(&self, input: &[u8]) -> Result<Self::RawData, ParseError> {
+        // Older pages aren't always UTF-8; a stray byte shouldn't fail the import
+        Ok(HtmlResume::from_html(&String::from_utf8_lossy(input)))
+    }
+
+    fn validate(&self, data: Self::RawData) -> Result<Self::ValidatedData, ParseError> {
+        if data.is_empty() {
+            return Err(ParseError::ValidationError(
+                "No hResume or schema.org Person markup found".to_string(),
+            ));
+        }
+        Ok(data)
+    }
+
+    fn convert(&self, data: Self::ValidatedData) -> Result<ResumeData, ParseError> {
+        let mut resume = ResumeData::default();
+        if let Some(language) = data.language {
+            resume.metadata.locale = language;
+        }
+        let locale = Locale::for_tag(&resume.metadata.locale);
+        let titles = &locale.sections;
+
+        let basics = data.basics;
+        resume.basics.name = basics.name.unwrap_or_default();
+        resume.basics.guess_name_parts(&resume.metadata.locale);
+        resume.basics.headline = basics.headline.unwrap_or_default();
+        resume.basics.email = basics.email.unwrap_or_default();
+        resume.basics.phone = basics.phone.unwrap_or_default();
+        resume.basics.location = basics.location.unwrap_or_default();
+        resume.basics.url = Url::new(basics.url.and_then(absolute_url).unwrap_or_default());
+        if let Some(summary) = basics.summary {
+            resume.sections.summary.name = titles.summary.to_string();
+            resume.sections.summary.content = sanitize_html(&summary);
+        }
+
+        if !data.experience.is_empty() {
+            resume.sections.experience = Section::new("experience", titles.experience);
+            for e in data.experience {
+                let mut exp = Experience::new(
+                    e.organization.unwrap_or_default(),
+                    e.title.unwrap_or_default(),
+                );
+                if let Some(location) = e.location {
+                    exp = exp.with_location(location);
+                }
+                let date = format_date_range_in(e.start.as_deref(), e.end.as_deref(), locale);
+                if !date.is_empty() {
+                    exp = exp.with_date(date);
+                }
+                if let Some(summary) = e.summary {
+                    exp = exp.with_summary(sanitize_html(&summary));
+                }
+                if let Some(url) = e.url.and_then(absolute_url) {
+                    exp = exp.with_url(url);
+                }
+                resume.sections.experience.add_item(exp);
+            }
+        }
+
+        if !data.education.is_empty() {
+            resume.sections.education = Section::new("education", titles.education);
+            for e in data.education {
+                let mut edu = Education::new(
+                    e.organization.unwrap_or_default(),
+                    e.title.unwrap_or_default(),
+                );
+                let date = format_date_range_in(e.start.as_deref(), e.end.as_deref(), locale);
+                if !date.is_empty() {
+                    edu = edu.with_date(date);
+                }
+                if let Some(summary) = e.summary {
+                    edu = edu.with_summary(sanitize_html(&summary));
+                }
+                resume.sections.education.add_item(edu);
+            }
+        }
+
+        resume.sync_dates();
+        Ok(resume)
+    }
+}
+
+// ============================================================================
+// Microformats
+// ============================================================================
+
+/// How a property's value is read from its element.
+#[derive(Debug, Clone, Copy)]
+enum ValueKind {
+    /// `p-*`: text, or the `title` of an `<abbr>`
+    Text,
+    /// `u-*`: `href` or `src`
+    Link,
+    /// `dt-*`: `datetime`, or the `title` of an `<abbr>`
+    Date,
+    /// `e-*`: inner HTML
+    Markup,
+}
+
+/// Classic root class names and their microformats2 types.
+const CLASSIC_ROOTS: &[(&str, &str)] = &[
+    ("hresume", "h-resume"),
+    ("vcard", "h-card"),
+    ("vevent", "h-event"),
+    ("adr", "h-adr"),
+];
+
+/// Classic property class names as (root type, class, property, kind).
+const CLASSIC_PROPERTIES: &[(&str, &str, &str, ValueKind)] = &[
+    ("h-resume", "summary", "summary", ValueKind::Text),
+    ("h-resume", "contact", "contact", ValueKind::Text),
+    ("h-resume", "experience", "experience", ValueKind::Text),
+    ("h-resume", "education", "education", ValueKind::Text),
+    ("h-card", "fn", "name", ValueKind::Text),
+    ("h-card", "org", "org", ValueKind::Text),
+    ("h-card", "title", "job-title", ValueKind::Text),
+    ("h-card", "role", "role", ValueKind::Text),
+    ("h-card", "email", "email", ValueKind::Link),
+    ("h-card", "tel", "tel", ValueKind::Text),
+    ("h-card", "url", "url", ValueKind::Link),
+    ("h-card", "adr", "adr", ValueKind::Text),
+    ("h-card", "note", "note", ValueKind::Text),
+    ("h-card", "locality", "locality", ValueKind::Text),
+    ("h-card", "region", "region", ValueKind::Text),
+    ("h-card", "country-name", "country-name", ValueKind::Text),
+    ("h-adr", "locality", "locality", ValueKind::Text),
+    ("h-adr", "region", "region", ValueKind::Text),
+    ("h-adr", "country-name", "country-name", ValueKind::Text),
+    ("h-event", "summary", "name", ValueKind::Text),
+    ("h-event", "dtstart", "start", ValueKind::Date),
+    ("h-event", "dtend", "end", ValueKind::Date),
+    ("h-event", "location", "location", ValueKind::Text),
+    ("h-event", "description", "description", ValueKind::Markup),
+    ("h-event", "url", "url", ValueKind::Link),
+];
+
+/// Types of a microformat root element, and whether they come from classic
+/// class names.
+struct Root {
+    types: Vec<String>,
+    classic: bool,
+}
+
+impl Root {
+    fn of(element: ElementRef) -> Option<Self> {
+        let classes: Vec<&str> = element.value().classes().collect();
+        let types: Vec<String> = classes
+            .iter()
+            .filter(|class| {
+                class
+                    .strip_prefix("h-")
+                    .is_some_and(|name| !name.is_empty() && name.chars().all(is_name_char))
+            })
+            .map(|class| class.to_string())
+            .collect();
+        if !types.is_empty() {
+            return Some(Self {
+                types,
+                classic: false,
+            });
+        }
+
+        let types: Vec<String> = CLASSIC_ROOTS
+            .iter()
+            .filter(|(class, _)| classes.contains(class))
+            .map(|(_, ty)| ty.to_string())
+            .collect();
+        (!types.is_empty()).then_some(Self {
+            types,
+            classic: true,
+        })
+    }
+
+    /// Properties `element` sets on an item with this root.
+    fn properties(&self, element: ElementRef) -> Vec<(String, ValueKind)> {
+        let mut properties = Vec::new();
+        for class in element.value().classes() {
+            let modern = [
+                ("p-", ValueKind::Text),
+                ("u-", ValueKind::Link),
+                ("dt-", ValueKind::Date),
+                ("e-", ValueKind::Markup),
+            ]
+            .into_iter()
+            .find_map(|(prefix, kind)| {
+                class
+                    .strip_prefix(prefix)
+                    .filter(|name| !name.is_empty())
+                    .map(|name| (name, kind))
+            });
+            let classic = self
+                .classic
+                .then(|| {
+                    CLASSIC_PROPERTIES
+                        .iter()
+                        .find(|(ty, name, _, _)| {
+                            *name == class && self.types.iter().any(|t| t == ty)
+                        })
+                        .map(|(_, _, property, kind)| (*property, *kind))
+                })
+                .flatten();
+            if let Some((name, kind)) = modern.or(classic) {
+                properties.push((name.to_string(), kind));
+            }
+        }
+        properties
+    }
+}
+
+fn is_name_char(c: char) -> bool {
+    c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-'
+}
+
+/// Top-level microformat items under `element`, in the microformats2 JSON
+/// shape: `{"type": [...], "properties": {...}, "children": [...]}`.
+fn microformats(element: ElementRef) -> Vec<Value> {
+    let mut items = Vec::new();
+    for child in element.children().filter_map(ElementRef::wrap) {
+        match Root::of(child) {
+            Some(root) => items.push(parse_item(child, &root)),
+            None => items.extend(microformats(child)),
+        }
+    }
+    items
+}
+
+fn parse_item(element: ElementRef, root: &Root) -> Value {
+    let mut properties = Map::new();
+    let mut children = Vec::new();
+    parse_properties(element, root, &mut properties, &mut children);
+
+    let mut item = Map::new();
+    item.insert("type".to_string(), root.types.clone().into());
+    item.insert("properties".to_string(), Value::Object(properties));
+    item.insert("children".to_string(), Value::Array(children));
+    item.insert("value".to_string(), text(element).into());
+    Value::Object(item)
+}
+
+fn parse_properties(
+    element: ElementRef,
+    root: &Root,
+    properties: &mut Map<String, Value>,
+    children: &mut Vec<Value>,
+) {
+    for child in element.children().filter_map(ElementRef::wrap) {
+        let names = root.properties(child);
+        match Root::of(child) {
+            Some(nested) => {
+                let item = parse_item(child, &nested);
+                if names.is_empty() {
+                    children.push(item);
+                } else {
+                    for (name, _) in names {
+                        push_value(properties, &name, item.clone());
+                    }
+                }
+            }
+            None => {
+                for (name, kind) in names {
+                    push_value(properties, &name, property_value(child, kind).into());
+                }
+                parse_properties(child, root, properties, children);
+            }
+        }
+    }
+}
+
+fn property_value(element: ElementRef, kind: ValueKind) -> String {
+    let el = element.value();
+    let abbr_title = || el.attr("title").filter(|_| el.name() == "abbr");
+    let attribute = match kind {
+        ValueKind::Text => abbr_title(),
+        ValueKind::Link => el.attr("href").or_else(|| el.attr("src")),
+        ValueKind::Date => el.attr("datetime").or_else(abbr_title),
+        ValueKind::Markup => return element.inner_html().trim().to_string(),
+    };
+    attribute
+        .and_then(non_empty)
+        .unwrap_or_else(|| text(element))
+}
+
+fn has_type(item: &Value, ty: &str) -> bool {
+    item["type"]
+        .as_array()
+        .is_some_and(|types| types.iter().any(|t| t == ty))
+}
+
+fn mf_values<'a>(item: &'a Value, name: &str) -> impl Iterator<Item = &'a Value> {
+    item["properties"][name].as_array().into_iter().flatten()
+}
+
+/// First value of a property as text; nested items give their text.
+fn mf_text(item: &Value, name: &str) -> Option<String> {
+    mf_values(item, name).find_map(|value| json_text(value.get("value").unwrap_or(value)))
+}
+
+/// Name of an organization value: an `h-card`'s `org` or `name`, or text.
+fn mf_org_name(value: &Value) -> Option<String> {
+    if value.is_object() {
+        mf_text(value, "org").or_else(|| mf_text(value, "name"))
+    } else {
+        json_text(value)
+    }
+}
+
+/// "Locality, region, country" of an `h-adr` or `h-card`.
+fn mf_address_parts(item: &Value) -> Option<String> {
+    let parts: Vec<String> = ["locality", "region", "country-name"]
+        .iter()
+        .filter_map(|name| mf_text(item, name))
+        .collect();
+    (!parts.is_empty()).then(|| parts.join(", "))
+}
+
+/// Location of a `location`/`adr` value: a card's address, an `h-adr`, or
+/// plain text.
+fn mf_location(value: &Value) -> Option<String> {
+    if has_type(value, "h-card") {
+        mf_values(value, "adr")
+            .find_map(mf_location)
+            .or_else(|| mf_address_parts(value))
+    } else if value.is_object() {
+        mf_address_parts(value).or_else(|| value.get("value").and_then(json_text))
+    } else {
+        json_text(value)
+    }
+}
+
+fn card_basics(card: &Value) -> HtmlBasics {
+    HtmlBasics {
+        name: mf_text(card, "name"),
+        headline: mf_text(card, "job-title").or_else(|| mf_text(card, "role")),
+        email: mf_text(card, "email").map(|email| strip_scheme(&email, "mailto:")),
+        phone: mf_text(card, "tel").map(|tel| strip_scheme(&tel, "tel:")),
+        location: mf_location(card),
+        url: mf_text(card, "url"),
+        summary: mf_text(card, "note"),
+    }
+}
+
+fn from_h_resume(item: &Value) -> HtmlResume {
+    // Classic pages often leave `contact` off the resume's vcard
+    let card = mf_values(item, "contact")
+        .chain(item["children"].as_array().into_iter().flatten())
+        .find(|value| has_type(value, "h-card"));
+    let mut basics = card.map(card_basics).unwrap_or_default();
+    basics.name = basics.name.or_else(|| mf_text(item, "name"));
+    basics.summary = mf_text(item, "summary").or(basics.summary);
+
+    HtmlResume {
+        language: None,
+        basics,
+        experience: mf_values(item, "experience").map(mf_entry).collect(),
+        education: mf_values(item, "education").map(mf_entry).collect(),
+    }
+}
+
+/// An `experience` or `education` value: an `h-event` with an embedded
+/// organization card, an `h-card` for the organization itself, or text.
+fn mf_entry(value: &Value) -> HtmlEntry {
+    if !value.is_object() {
+        return HtmlEntry {
+            title: json_text(value),
+            ..HtmlEntry::default()
+        };
+    }
+
+    let is_card = has_type(value, "h-card");
+    let org_card = mf_values(value, "org")
+        .chain(mf_values(value, "location"))
+        .chain(value["children"].as_array().into_iter().flatten())
+        .find(|v| has_type(v, "h-card"));
+    let organization = if is_card {
+        mf_org_name(value)
+    } else {
+        mf_values(value, "org")
+            .find_map(mf_org_name)
+            .or_else(|| org_card.and_then(mf_org_name))
+    };
+    let title = mf_text(value, "job-title")
+        .or_else(|| mf_text(value, "role"))
+        .or_else(|| (!is_card).then(|| mf_text(value, "name")).flatten())
+        .or_else(|| org_card.and_then(|card| mf_text(card, "job-title")));
+    let location = mf_values(value, "location")
+        .chain(mf_values(value, "adr"))
+        .find_map(mf_location)
+        .or_else(|| mf_address_parts(value))
+        .or_else(|| org_card.and_then(mf_location));
+
+    HtmlEntry {
+        organization,
+        title,
+        location,
+        start: mf_text(value, "start"),
+        end: mf_text(value, "end"),
+        summary: mf_text(value, "description")
+            .or_else(|| mf_text(value, "summary"))
+            .or_else(|| mf_text(value, "note")),
+        url: mf_text(value, "url").or_else(|| org_card.and_then(|card| mf_text(card, "url"))),
+    }
+}
+
+// ============================================================================
+// schema.org
+// ============================================================================
+
+/// The first `Person` in the page's JSON-LD scripts, including one nested
+/// in an `@graph` or a `ProfilePage`.
+fn json_ld_person(root: ElementRef) -> Option<Value> {
+    root.descendants()
+        .filter_map(ElementRef::wrap)
+        .filter(|element| {
+            element.value().name() == "script"
+                && element
+                    .value()
+                    .attr("type")
+                    .is_some_and(|ty| ty.trim().eq_ignore_ascii_case("application/ld+json"))
+        })
+        // A broken script elsewhere on the page shouldn't fail the import
+        .filter_map(|script| serde_json::from_str(&script.text().collect::<String>()).ok())
+        .find_map(find_person)
+}
+
+fn find_person(value: Value) -> Option<Value> {
+    match value {
+        Value::Object(_) if is_schema_type(&value, "Person") => Some(value),
+        Value::Object(map) => map.into_iter().find_map(|(_, v)| find_person(v)),
+        Value::Array(items) => items.into_iter().find_map(find_person),
+        _ => None,
+    }
+}
+
+/// The first microdata `Person` item, as JSON-LD.
+fn microdata_person(root: ElementRef) -> Option<Value> {
+    root.descendants()
+        .filter_map(ElementRef::wrap)
+        .find(|element| {
+            let el = element.value();
+            el.attr("itemscope").is_some()
+                && el.attr("itemtype").is_some_and(|types| {
+                    types
+                        .split_whitespace()
+                        .any(|ty| schema_type_name(ty) == "Person")
+                })
+        })
+        .map(microdata_item)
+}
+
+fn microdata_item(element: ElementRef) -> Value {
+    let mut item = Map::new();
+    if let Some(types) = element.value().attr("itemtype") {
+        let types: Vec<Value> = types.split_whitespace().map(Value::from).collect();
+        item.insert("@type".to_string(), types.into());
+    }
+    microdata_properties(element, &mut item);
+    Value::Object(item)
+}
+
+fn microdata_properties(element: ElementRef, item: &mut Map<String, Value>) {
+    for child in element.children().filter_map(ElementRef::wrap) {
+        let scope = child.value().attr("itemscope").is_some();
+        if let Some(names) = child.value().attr("itemprop") {
+            let value = if scope {
+                microdata_item(child)
+            } else {
+                microdata_value(child).into()
+            };
+            for name in names.split_whitespace() {
+                push_value(item, name, value.clone());
+            }
+        }
+        if !scope {
+            microdata_properties(child, item);
+        }
+    }
+}
+
+fn microdata_value(element: ElementRef) -> String {
+    let el = element.value();
+    let attribute = match el.name() {
+        "meta" => el.attr("content"),
+        "a" | "area" | "link" => el.attr("href"),
+        "img" | "audio" | "video" | "source" | "iframe" | "embed" => el.attr("src"),
+        "time" => el.attr("datetime"),
+        "data" | "meter" => el.attr("value"),
+        _ => None,
+    };
+    attribute
+        .and_then(non_empty)
+        .unwrap_or_else(|| text(element))
+}
+
+/// `Person` from `Person`, `schema:Person`, or `https://schema.org/Person`.
+fn schema_type_name(ty: &str) -> &str {
+    ty.rsplit(['/', ':', '#']).next().unwrap_or(ty)
+}
+
+fn is_schema_type(value: &Value, ty: &str) -> bool {
+    ld_values(value, "@type")
+        .filter_map(Value::as_str)
+        .any(|t| schema_type_name(t) == ty)
+}
+
+/// Values of a JSON-LD property, which may be one value or an array.
+fn ld_values<'a>(value: &'a Value, key: &str) -> impl Iterator<Item = &'a Value> {
+    let (one, many) = match value.get(key) {
+        Some(Value::Array(items)) => (None, items.as_slice()),
+        other => (other, &[][..]),
+    };
+    one.into_iter().chain(many)
+}
+
+fn ld_text(value: &Value, key: &str) -> Option<String> {
+    ld_values(value, key).find_map(ld_name)
+}
+
+/// Text of a value, or the `name` of a thing.
+fn ld_name(value: &Value) -> Option<String> {
+    match value {
+        Value::Object(_) => ld_text(value, "name").or_else(|| ld_text(value, "@value")),
+        _ => json_text(value),
+    }
+}
+
+/// A text address, or "locality, region, country" of a `PostalAddress`.
+fn ld_address(value: &Value) -> Option<String> {
+    let parts: Vec<String> = ["addressLocality", "addressRegion", "addressCountry"]
+        .iter()
+        .filter_map(|key| ld_text(value, key))
+        .collect();
+    if parts.is_empty() {
+        ld_name(value)
+    } else {
+        Some(parts.join(", "))
+    }
+}
+
+fn from_person(person: &Value) -> HtmlResume {
+    let name = ld_text(person, "name").or_else(|| {
+        let parts: Vec<String> = ["givenName", "familyName"]
+            .iter()
+            .filter_map(|key| ld_text(person, key))
+            .collect();
+        (!parts.is_empty()).then(|| parts.join(" "))
+    });
+    let job_title = ld_text(person, "jobTitle");
+
+    HtmlResume {
+        language: None,
+        basics: HtmlBasics {
+            name,
+            headline: job_title.clone(),
+            email: ld_text(person, "email").map(|email| strip_scheme(&email, "mailto:")),
+            phone: ld_text(person, "telephone").map(|tel| strip_scheme(&tel, "tel:")),
+            location: ld_values(person, "address").find_map(ld_address),
+            url: ld_text(person, "url"),
+            summary: ld_text(person, "description"),
+        },
+        experience: ld_values(person, "worksFor")
+            .map(|value| ld_entry(value, "worksFor", job_title.as_deref()))
+            .collect(),
+        education: ld_values(person, "alumniOf")
+            .map(|value| ld_entry(value, "alumniOf", None))
+            .collect(),
+    }
+}
+
+/// A `worksFor` or `alumniOf` value: an organization, or a `Role` that
+/// repeats `property` around the organization and adds dates. A bare
+/// organization under `worksFor` is the current job, titled with the
+/// person's `jobTitle`.
+fn ld_entry(value: &Value, property: &str, job_title: Option<&str>) -> HtmlEntry {
+    let is_role = value.get(property).is_some();
+    let org = if is_role {
+        ld_values(value, property).next().unwrap_or(value)
+    } else {
+        value
+    };
+
+    HtmlEntry {
+        organization: ld_name(org),
+        title: if is_role {
+            ld_text(value, "roleName")
+        } else {
+            job_title.map(str::to_string)
+        },
+        location: ld_values(org, "address")
+            .chain(ld_values(org, "location"))
+            .find_map(ld_address),
+        start: ld_text(value, "startDate"),
+        end: ld_text(value, "endDate"),
+        summary: is_role.then(|| ld_text(value, "description")).flatten(),
+        url: ld_text(org, "url"),
+    }
+}
+
+// ============================================================================
+// Shared Helpers
+// ============================================================================
+
+/// Text content with whitespace collapsed.
+fn text(element: ElementRef) -> String {
+    let text: String = element.text().collect();
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn non_empty(value: &str) -> Option<String> {
+    let value = value.trim();
+    (!value.is_empty()).then(|| value.to_string())
+}
+
+fn json_text(value: &Value) -> Option<String> {
+    value.as_str().and_then(non_empty)
+}
+
+fn push_value(map: &mut Map<String, Value>, name: &str, value: Value) {
+    if let Value::Array(values) = map.entry(name).or_insert_with(|| Value::Array(Vec::new())) {
+        values.push(value);
+    }
+}
+
+/// `url` if it is absolute. Pages link to themselves with relative URLs,
+/// which mean nothing once imported.
+fn absolute_url(url: String) -> Option<String> {
+    (url.starts_with("http://") || url.starts_with("https://")).then_some(url)
+}
+
+fn strip_scheme(value: &str, scheme: &str) -> String {
+    value.strip_prefix(scheme).unwrap_or(value).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MF2_PAGE: &str = r#"<!doctype html>
+    <html lang="de">
+    <body>
+      <article class="h-resume">
+        <div class="p-contact h-card">
+          <h1 class="p-name">Grace Hopper</h1>
+          <p class="p-job-title">Rear Admiral</p>
+          <a class="u-email" href="mailto:grace@example.com">Email</a>
+          <a class="u-url" href="https://grace.example.com">Site</a>
+          <p class="p-adr h-adr"><span class="p-locality">Arlington</span>, <span class="p-region">VA</span></p>
+        </div>
+        <p class="p-summary">Pioneer of compilers.</p>
+        <section class="p-experience h-event">
+          <h3 class="p-name">Senior Programmer</h3>
+          <div class="p-location h-card"><span class="p-org">Remington Rand</span></div>
+          <time class="dt-start" datetime="1949-06">1949</time> –
+          <time class="dt-end" datetime="1967-12">1967</time>
+          <div class="e-description"><p>Built <strong>A-0</strong>.</p></div>
+        </section>
+        <section class="p-education h-event">
+          <span class="p-name">PhD Mathematics</span>
+          <span class="p-location h-card"><span class="p-name">Yale University</span></span>
+          <time class="dt-end" datetime="1934">1934</time>
+        </section>
+      </article>
+    </body>
+    </html>"#;
+
+    const CLASSIC_PAGE: &str = r#"<html><body>
+      <div class="hresume">
+        <div class="vcard">
+          <span class="fn">Alan Turing</span>
+          <span class="title">Mathematician</span>
+          <span class="tel">+44 20 0000</span>
+        </div>
+        <p class="summary">Computing machinery and intelligence.</p>
+        <div class="experience vevent vcard">
+          <span class="summary title">Reader</span> at
+          <span class="org">University of Manchester</span>
+          <abbr class="dtstart" title="1948-10">Oct 1948</abbr>
+        </div>
+      </div>
+    </body></html>"#;
+
+    const JSON_LD_PAGE: &str = r#"<html><head>
+      <script type="application/ld+json">{ broken</script>
+      <script type="application/ld+json">
+      {
+        "@context": "https://schema.org",
+        "@graph": [
+          {"@type": "WebSite", "name": "Ada's site"},
+          {
+            "@type": "ProfilePage",
+            "mainEntity": {
+              "@type": "Person",
+              "name": "Ada Lovelace",
+              "jobTitle": "Analyst",
+              "email": "mailto:ada@example.com",
+              "address": {"@type": "PostalAddress", "addressLocality": "London", "addressCountry": "UK"},
+              "worksFor": [
+                {
+                  "@type": "EmployeeRole",
+                  "roleName": "Translator",
+                  "startDate": "1842",
+                  "endDate": "1843",
+                  "worksFor": {"@type": "Organization", "name": "Babbage & Co"}
+                },
+                {"@type": "Organization", "name": "Analytical Society"}
+              ],
+              "alumniOf": "Home schooling"
+            }
+          }
+        ]
+      }
+      </script>
+    </head><body></body></html>"#;
+
+    const MICRODATA_PAGE: &str = r#"<html><body>
+      <div itemscope itemtype="https://schema.org/Person">
+        <span itemprop="givenName">Katherine</span> <span itemprop="familyName">Johnson</span>
+        <span itemprop="jobTitle">Mathematician</span>
+        <a itemprop="url" href="https://example.com/kj">Home</a>
+        <div itemprop="worksFor" itemscope itemtype="https://schema.org/Organization">
+          <span itemprop="name">NASA</span>
+        </div>
+        <div itemprop="alumniOf" itemscope itemtype="https://schema.org/CollegeOrUniversity">
+          <span itemprop="name">West Virginia State College</span>
+        </div>
+      </div>
+    </body></html>"#;
+
+    fn parse(html: &str) -> ResumeData {
+        HtmlParser.parse(html.as_bytes()).unwrap()
+    }
+
+    #[test]
+    fn test_parse_microformats2() {
+        let resume = parse(MF2_PAGE);
+        assert_eq!(resume.metadata.locale, "de");
+        assert_eq!(resume.basics.name, "Grace Hopper");
+        assert_eq!(resume.basics.headline, "Rear Admiral");
+        assert_eq!(resume.basics.email, "grace@example.com");
+        assert_eq!(resume.basics.url.href, "https://grace.example.com");
+        assert_eq!(resume.basics.location, "Arlington, VA");
+        assert_eq!(resume.sections.summary.content, "Pioneer of compilers.");
+
+        let job = &resume.sections.experience.items[0];
+        assert_eq!(job.company, "Remington Rand");
+        assert_eq!(job.position, "Senior Programmer");
+        assert_eq!(job.date, "1949-06 - 1967-12");
+        assert_eq!(job.summary, "<p>Built <strong>A-0</strong>.</p>");
+
+        let school = &resume.sections.education.items[0];
+        assert_eq!(school.institution, "Yale University");
+        assert_eq!(school.area, "PhD Mathematics");
+        assert_eq!(school.date, "1934");
+    }
+
+    #[test]
+    fn test_parse_classic_hresume() {
+        let resume = parse(CLASSIC_PAGE);
+        assert_eq!(resume.basics.name, "Alan Turing");
+        assert_eq!(resume.basics.headline, "Mathematician");
+        assert_eq!(resume.basics.phone, "+44 20 0000");
+        assert_eq!(
+            resume.sections.summary.content,
+            "Computing machinery and intelligence."
+        );
+
+        let job = &resume.sections.experience.items[0];
+        assert_eq!(job.company, "University of Manchester");
+        assert_eq!(job.position, "Reader");
+        assert_eq!(job.date, "1948-10 - Present");
+    }
+
+    #[test]
+    fn test_parse_json_ld_person() {
+        let resume = parse(JSON_LD_PAGE);
+        assert_eq!(resume.basics.name, "Ada Lovelace");
+        assert_eq!(resume.basics.email, "ada@example.com");
+        assert_eq!(resume.basics.location, "London, UK");
+
+        let jobs = &resume.sections.experience.items;
+        assert_eq!(jobs[0].company, "Babbage & Co");
+        assert_eq!(jobs[0].position, "Translator");
+        assert_eq!(jobs[0].date, "1842 - 1843");
+        // A bare organization is the current job
+        assert_eq!(jobs[1].company, "Analytical Society");
+        assert_eq!(jobs[1].position, "Analyst");
+
+        assert_eq!(
+            resume.sections.education.items[0].institution,
+            "Home schooling"
+        );
+    }
+
+    #[test]
+    fn test_parse_microdata_person() {
+        let resume = parse(MICRODATA_PAGE);
+        assert_eq!(resume.basics.name, "Katherine Johnson");
+        assert_eq!(resume.basics.url.href, "https://example.com/kj");
+        assert_eq!(resume.sections.experience.items[0].company, "NASA");
+        assert_eq!(
+            resume.sections.experience.items[0].position,
+            "Mathematician"
+        );
+        assert_eq!(
+            resume.sections.education.items[0].institution,
+            "West Virginia State College"
+        );
+    }
+
+    #[test]
+    fn test_sources_are_combined_without_duplicates() {
+        let page = MF2_PAGE.replace(
+            "</body>",
+            r#"<script type="application/ld+json">
+            {"@type": "Person", "name": "G. Hopper", "telephone": "tel:+1 555 0100",
+             "worksFor": {"@type": "Organization", "name": "US Navy"}}
+            </script></body>"#,
+        );
+        let resume = parse(&page);
+        assert_eq!(resume.basics.name, "Grace Hopper");
+        assert_eq!(resume.basics.phone, "+1 555 0100");
+        assert_eq!(resume.sections.experience.items.len(), 1);
+    }
+
+    #[test]
+    fn test_lone_h_card_gives_basics() {
+        let resume = parse(
+            r#"<body><div class="h-card"><a class="p-name u-url" href="/me">Jo</a></div></body>"#,
+        );
+        assert_eq!(resume.basics.name, "Jo");
+        assert!(resume.basics.url.href.is_empty());
+        assert!(resume.sections.experience.items.is_empty());
+    }
+
+    #[test]
+    fn test_page_without_markup_fails_validation() {
+        let result = HtmlParser.parse(b"<html><body><h1>Hello</h1></body></html>");
+        assert!(matches!(result, Err(ParseError::ValidationError(_))));
+    }
+}
@@ -0,0 +1,280 @@
+//! iCalendar (`.ics`) export of the employment and education timeline.
+//!
+//! [`to_ics`] writes one all-day event per visible experience and education
+//! item that has a date range (RFC 5545), so the timeline can be viewed in a
+//! calendar app. Items whose dates can't be read are left out.
+
+use chrono::{Months, NaiveDate};
+use rustume_schema::ResumeData;
+use rustume_utils::{html_text_blocks, PartialDate};
+
+use crate::content_line::{escape, fold};
+
+/// One timeline entry before it is written out.
+struct Event<'a> {
+    id: &'a str,
+    category: &'a str,
+    summary: String,
+    location: &'a str,
+    description: &'a str,
+    url: &'a str,
+    start: NaiveDate,
+    end: NaiveDate,
+}
+
+/// Write the visible experience and education items of `resume` as an
+/// iCalendar timeline, one event per item.
+///
+/// Dates are all-day and the end is exclusive, so a position ending in
+/// "2021-06" runs through June 30. Ongoing items end on `today`, which
+/// also stamps every event.
+pub fn to_ics(resume: &ResumeData, today: &PartialDate) -> String {
+    let mut events = Vec::new();
+
+    let experience = &resume.sections.experience;
+    if experience.visible {
+        for item in experience.items.iter().filter(|item| item.visible) {
+            let Some((start, end)) = item
+                .date_range()
+                .and_then(|range| bounds(&range.start, range.end.as_ref().unwrap_or(today)))
+            else {
+                continue;
+            };
+            events.push(Event {
+                id: &item.id,
+                category: "Experience",
+                summary: join([item.position.as_str(), item.company.as_str()]),
+                location: &item.location,
+                description: &item.summary,
+                url: &item.url.href,
+                start,
+                end,
+            });
+        }
+    }
+
+    let education = &resume.sections.education;
+    if education.visible {
+        for item in education.items.iter().filter(|item| item.visible) {
+            let Some((start, end)) = item
+                .date_range()
+                .and_then(|range| bounds(&range.start, range.end.as_ref().unwrap_or(today)))
+            else {
+                continue;
+            };
+            let degree = join([item.study_type.as_str(), item.area.as_str()]);
+            events.push(Event {
+                id: &item.id,
+                category: "Education",
+                summary: join([degree.as_str(), item.institution.as_str()]),
+                location: "",
+                description: &item.summary,
+                url: &item.url.href,
+                start,
+                end,
+            });
+        }
+    }
+
+    let stamp = format!(
+        "{:04}{:02}{:02}T000000Z",
+        today.year,
+        today.month.unwrap_or(1),
+        today.day.unwrap_or(1)
+    );
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//Rustume//Timeline//EN".to_string(),
+        "CALSCALE:GREGORIAN".to_string(),
+    ];
+    let name = resume.basics.name.trim();
+    if !name.is_empty() {
+        lines.push(format!("X-WR-CALNAME:{}", escape(name)));
+    }
+
+    for event in &events {
+        lines.push("BEGIN:VEVENT".to_string());
+        lines.push(format!("UID:{}@rustume", event.id));
+        lines.push(format!("DTSTAMP:{stamp}"));
+        lines.push(format!(
+            "DTSTART;VALUE=DATE:{}",
+            event.start.format("%Y%m%d")
+        ));
+        lines.push(format!("DTEND;VALUE=DATE:{}", event.end.format("%Y%m%d")));
+        lines.push(format!("SUMMARY:{}", escape(&event.summary)));
+        if !event.location.trim().is_empty() {
+            lines.push(format!("LOCATION:{}", escape(event.location.trim())));
+        }
+        let description = html_text_blocks(event.description).join("\n");
+        if !description.is_empty() {
+            lines.push(format!("DESCRIPTION:{}", escape(&description)));
+        }
+        if !event.url.is_empty() {
+            lines.push(format!("URL:{}", event.url));
+        }
+        lines.push(format!("CATEGORIES:{}", event.category));
+        // Years-long events shouldn't show as busy time
+        lines.push("TRANSP:TRANSPARENT".to_string());
+        lines.push("END:VEVENT".to_string());
+    }
+    lines.push("END:VCALENDAR".to_string());
+
+    lines.iter().map(|line| fold(line) + "\r\n").collect()
+}
+
+/// First day of `start` and the day after `end`, at least one day apart.
+fn bounds(start: &PartialDate, end: &PartialDate) -> Option<(NaiveDate, NaiveDate)> {
+    let first = NaiveDate::from_ymd_opt(
+        start.year,
+        start.month.unwrap_or(1),
+        start.month.and(start.day).unwrap_or(1),
+    )?;
+    let after = match (end.month, end.day) {
+        (Some(month), Some(day)) => NaiveDate::from_ymd_opt(end.year, month, day)?.succ_opt()?,
+        (Some(month), None) => {
+            NaiveDate::from_ymd_opt(end.year, month, 1)?.checked_add_months(Months::new(1))?
+        }
+        (None, _) => NaiveDate::from_ymd_opt(end.year + 1, 1, 1)?,
+    };
+    // An end before the start would be rejected by calendar apps
+    Some((first, after.max(first.succ_opt()?)))
+}
+
+/// Non-empty parts joined with a comma.
+pub(crate) fn join<const N: usize>(parts: [&str; N]) -> String {
+    parts
+        .iter()
+        .map(|part| part.trim())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::content_line::MAX_LINE_OCTETS;
+    use rustume_schema::{Education, Experience};
+
+    fn today() -> PartialDate {
+        PartialDate {
+            year: 2024,
+            month: Some(3),
+            day: Some(15),
+        }
+    }
+
+    fn events(ics: &str) -> Vec<&str> {
+        ics.split("BEGIN:VEVENT\r\n").skip(1).collect()
+    }
+
+    #[test]
+    fn test_one_event_per_dated_item() {
+        let mut resume = ResumeData::default();
+        resume.basics.name = "Ada Lovelace".to_string();
+        let mut engineer = Experience::new("Acme, Inc.", "Engineer")
+            .with_location("London")
+            .with_dates("2019-02", Some("2021-06".to_string()));
+        engineer.summary = "<ul><li>Built the engine</li><li>Wrote notes</li></ul>".to_string();
+        engineer.url.href = "https://acme.example".to_string();
+        resume.sections.experience.add_item(engineer);
+        resume
+            .sections
+            .experience
+            .add_item(Experience::new("Undated", "Intern"));
+        resume.sections.education.add_item(
+            Education::new("University of London", "Mathematics")
+                .with_study_type("BSc")
+                .with_dates("2014", Some("2017".to_string())),
+        );
+
+        let ics = to_ics(&resume, &today());
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n"));
+        assert!(ics.ends_with("END:VCALENDAR\r\n"));
+        assert!(ics.contains("X-WR-CALNAME:Ada Lovelace\r\n"));
+
+        let events = events(&ics);
+        assert_eq!(events.len(), 2);
+        assert!(events[0].contains("DTSTART;VALUE=DATE:20190201\r\n"));
+        assert!(events[0].contains("DTEND;VALUE=DATE:20210701\r\n"));
+        assert!(events[0].contains("SUMMARY:Engineer\\, Acme\\, Inc.\r\n"));
+        assert!(events[0].contains("LOCATION:London\r\n"));
+        assert!(events[0].contains("DESCRIPTION:Built the engine\\nWrote notes\r\n"));
+        assert!(events[0].contains("URL:https://acme.example\r\n"));
+        assert!(events[0].contains("CATEGORIES:Experience\r\n"));
+        assert!(events[0].contains("DTSTAMP:20240315T000000Z\r\n"));
+
+        assert!(events[1].contains("DTSTART;VALUE=DATE:20140101\r\n"));
+        assert!(events[1].contains("DTEND;VALUE=DATE:20180101\r\n"));
+        assert!(events[1].contains("SUMMARY:BSc\\, Mathematics\\, University of London\r\n"));
+        assert!(events[1].contains("CATEGORIES:Education\r\n"));
+    }
+
+    #[test]
+    fn test_ongoing_item_ends_today() {
+        let mut resume = ResumeData::default();
+        resume
+            .sections
+            .experience
+            .add_item(Experience::new("Acme", "Lead").with_dates("2022-09-05", None));
+        resume
+            .sections
+            .experience
+            .add_item(Experience::new("Acme", "Engineer").with_date("Jan 2020 - Present"));
+
+        let ics = to_ics(&resume, &today());
+        let events = events(&ics);
+        assert!(events[0].contains("DTSTART;VALUE=DATE:20220905\r\n"));
+        assert!(events[0].contains("DTEND;VALUE=DATE:20240316\r\n"));
+        assert!(events[1].contains("DTSTART;VALUE=DATE:20200101\r\n"));
+        assert!(events[1].contains("DTEND;VALUE=DATE:20240316\r\n"));
+    }
+
+    #[test]
+    fn test_hidden_items_and_sections_are_left_out() {
+        let mut resume = ResumeData::default();
+        let mut hidden = Experience::new("Secret", "Agent").with_dates("2010", None);
+        hidden.visible = false;
+        resume.sections.experience.add_item(hidden);
+        resume.sections.education.add_item(
+            Education::new("School", "History").with_dates("2005", Some("2009".to_string())),
+        );
+        resume.sections.education.visible = false;
+
+        let ics = to_ics(&resume, &today());
+        assert!(events(&ics).is_empty());
+        assert!(!ics.contains("X-WR-CALNAME"));
+    }
+
+    #[test]
+    fn test_end_before_start_still_spans_a_day() {
+        let (start, end) = bounds(
+            &PartialDate {
+                year: 2020,
+                month: Some(5),
+                day: None,
+            },
+            &PartialDate {
+                year: 2019,
+                month: None,
+                day: None,
+            },
+        )
+        .unwrap();
+        assert_eq!(start, NaiveDate::from_ymd_opt(2020, 5, 1).unwrap());
+        assert_eq!(end, NaiveDate::from_ymd_opt(2020, 5, 2).unwrap());
+    }
+
+    #[test]
+    fn test_long_lines_are_folded() {
+        let mut resume = ResumeData::default();
+        let mut item = Experience::new("Acme", "Engineer").with_dates("2020", None);
+        item.summary = "Shipped ".repeat(40);
+        resume.sections.experience.add_item(item);
+
+        let ics = to_ics(&resume, &today());
+        assert!(ics.split("\r\n").all(|line| line.len() <= MAX_LINE_OCTETS));
+        assert!(ics.contains("\r\n "));
+    }
+}
@@ -1,14 +1,18 @@
-//! JSON Resume format parser.
+//! JSON Resume format parser and exporter.
 //!
-//! Parses the standard JSON Resume schema (https://jsonresume.org/schema/).
+//! Parses the standard JSON Resume schema (https://jsonresume.org/schema/),
+//! and writes it back out with [`to_json_resume`].
 
 use crate::traits::{ParseError, Parser};
 use rustume_schema::{
-    Award, Certification, Education, Experience, Interest, Language, Profile, Project, Publication,
-    Reference, ResumeData, Section, Skill, SummarySection, Url, Volunteer,
+    Address, Award, Basics, Certification, Education, Experience, Interest, Language, Profile,
+    Project, Publication, Reference, ResumeData, Section, Skill, Url, Volunteer,
+};
+use rustume_utils::{
+    format_date_range_in, html_text_blocks, parse_date_range, parse_partial_date, DateRange, Locale,
 };
-use rustume_utils::format_date_range;
 use serde::Deserialize;
+use serde_json::{json, Value};
 
 /// JSON Resume parser.
 pub struct JsonResumeParser;
@@ -35,6 +39,14 @@ pub struct JsonResume {
     interests: Option<Vec<JsonResumeInterest>>,
     references: Option<Vec<JsonResumeReference>>,
     projects: Option<Vec<JsonResumeProject>>,
+    meta: Option<JsonResumeMeta>,
+}
+
+/// `meta` block. `language` is not part of the standard schema, but some
+/// tools write it; when present it sets the resume's locale.
+#[derive(Debug, Deserialize)]
+struct JsonResumeMeta {
+    language: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -53,7 +65,6 @@ struct JsonResumeBasics {
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
-#[allow(dead_code)]
 struct JsonResumeLocation {
     address: Option<String>,
     postal_code: Option<String>,
@@ -74,8 +85,29 @@ impl JsonResumeLocation {
         .filter(|s| !s.is_empty())
         .collect();
 
+        if parts.is_empty() {
+            // Free-form locations, as written by `to_json_resume`
+            return self.address.clone().unwrap_or_default();
+        }
         parts.join(", ")
     }
+
+    /// The location in parts, or `None` when it has only a free-form
+    /// `address`.
+    fn to_address(&self) -> Option<Address> {
+        let part = |value: &Option<String>| value.as_deref().unwrap_or_default().trim().to_string();
+        let address = Address {
+            street: String::new(),
+            city: part(&self.city),
+            region: part(&self.region),
+            postal_code: part(&self.postal_code),
+            country: part(&self.country_code),
+        };
+        (!address.is_empty()).then(|| Address {
+            street: part(&self.address),
+            ..address
+        })
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -222,10 +254,17 @@ impl Parser for JsonResumeParser {
     #[allow(clippy::field_reassign_with_default)]
     fn convert(&self, data: Self::ValidatedData) -> Result<ResumeData, ParseError> {
         let mut resume = ResumeData::default();
+        if let Some(language) = data.meta.and_then(|meta| meta.language) {
+            resume.metadata.locale = language;
+        }
+        let locale = Locale::for_tag(&resume.metadata.locale);
+        let titles = &locale.sections;
+        resume.sections.summary.name = titles.summary.to_string();
 
         // Convert basics
         if let Some(basics) = data.basics {
             resume.basics.name = basics.name.unwrap_or_default();
+            resume.basics.guess_name_parts(&resume.metadata.locale);
             resume.basics.headline = basics.label.unwrap_or_default();
             resume.basics.picture.url = basics.image.unwrap_or_default();
             resume.basics.email = basics.email.unwrap_or_default();
@@ -234,16 +273,19 @@ impl Parser for JsonResumeParser {
 
             if let Some(location) = basics.location {
                 resume.basics.location = location.format_location();
+                if let Some(address) = location.to_address() {
+                    resume.basics.address = address;
+                }
             }
 
             // Summary goes to summary section
             if let Some(summary) = basics.summary {
-                resume.sections.summary = SummarySection::new(summary);
+                resume.sections.summary.content = summary;
             }
 
             // Convert profiles
             if let Some(profiles) = basics.profiles {
-                resume.sections.profiles = Section::new("profiles", "Profiles");
+                resume.sections.profiles = Section::new("profiles", titles.profiles);
                 for p in profiles {
                     let network = p.network.clone().unwrap_or_default();
                     let mut profile = Profile::new(network.clone(), p.username.unwrap_or_default());
@@ -257,7 +299,7 @@ impl Parser for JsonResumeParser {
 
         // Convert work -> experience
         if let Some(work) = data.work {
-            resume.sections.experience = Section::new("experience", "Experience");
+            resume.sections.experience = Section::new("experience", titles.experience);
             for w in work {
                 let mut exp =
                     Experience::new(w.name.unwrap_or_default(), w.position.unwrap_or_default());
@@ -266,16 +308,18 @@ impl Parser for JsonResumeParser {
                     exp = exp.with_location(location);
                 }
 
-                let date = format_date_range(w.start_date.as_deref(), w.end_date.as_deref());
+                let date =
+                    format_date_range_in(w.start_date.as_deref(), w.end_date.as_deref(), locale);
                 if !date.is_empty() {
                     exp = exp.with_date(date);
                 }
 
-                // Combine summary and highlights
-                let summary = build_summary(w.summary.as_deref(), w.highlights.as_deref());
-                if !summary.is_empty() {
+                if let Some(summary) = w.summary.filter(|summary| !summary.is_empty()) {
                     exp = exp.with_summary(summary);
                 }
+                if let Some(highlights) = w.highlights {
+                    exp = exp.with_highlights(highlights);
+                }
 
                 if let Some(url) = w.url {
                     exp = exp.with_url(url);
@@ -287,7 +331,7 @@ impl Parser for JsonResumeParser {
 
         // Convert education
         if let Some(education) = data.education {
-            resume.sections.education = Section::new("education", "Education");
+            resume.sections.education = Section::new("education", titles.education);
             for e in education {
                 let mut edu = Education::new(
                     e.institution.unwrap_or_default(),
@@ -298,7 +342,8 @@ impl Parser for JsonResumeParser {
                     edu = edu.with_study_type(study_type);
                 }
 
-                let date = format_date_range(e.start_date.as_deref(), e.end_date.as_deref());
+                let date =
+                    format_date_range_in(e.start_date.as_deref(), e.end_date.as_deref(), locale);
                 if !date.is_empty() {
                     edu = edu.with_date(date);
                 }
@@ -310,7 +355,8 @@ impl Parser for JsonResumeParser {
                 // Courses become summary
                 if let Some(courses) = e.courses {
                     if !courses.is_empty() {
-                        edu = edu.with_summary(format!("Courses: {}", courses.join(", ")));
+                        edu =
+                            edu.with_summary(format!("{}: {}", locale.courses, courses.join(", ")));
                     }
                 }
 
@@ -320,7 +366,7 @@ impl Parser for JsonResumeParser {
 
         // Convert skills
         if let Some(skills) = data.skills {
-            resume.sections.skills = Section::new("skills", "Skills");
+            resume.sections.skills = Section::new("skills", titles.skills);
             for s in skills {
                 let mut skill = Skill::new(s.name.unwrap_or_default());
 
@@ -338,7 +384,7 @@ impl Parser for JsonResumeParser {
 
         // Convert projects
         if let Some(projects) = data.projects {
-            resume.sections.projects = Section::new("projects", "Projects");
+            resume.sections.projects = Section::new("projects", titles.projects);
             for p in projects {
                 let mut project = Project::new(p.name.unwrap_or_default());
 
@@ -346,9 +392,8 @@ impl Parser for JsonResumeParser {
                     project = project.with_description(desc);
                 }
 
-                let summary = build_summary(None, p.highlights.as_deref());
-                if !summary.is_empty() {
-                    project = project.with_summary(summary);
+                if let Some(highlights) = p.highlights {
+                    project = project.with_highlights(highlights);
                 }
 
                 if let Some(keywords) = p.keywords {
@@ -365,14 +410,15 @@ impl Parser for JsonResumeParser {
 
         // Convert volunteer
         if let Some(volunteer) = data.volunteer {
-            resume.sections.volunteer = Section::new("volunteer", "Volunteer");
+            resume.sections.volunteer = Section::new("volunteer", titles.volunteer);
             for v in volunteer {
                 let mut vol = Volunteer::new(
                     v.organization.unwrap_or_default(),
                     v.position.unwrap_or_default(),
                 );
 
-                let date = format_date_range(v.start_date.as_deref(), v.end_date.as_deref());
+                let date =
+                    format_date_range_in(v.start_date.as_deref(), v.end_date.as_deref(), locale);
                 if !date.is_empty() {
                     vol = vol.with_date(date);
                 }
@@ -392,7 +438,7 @@ impl Parser for JsonResumeParser {
 
         // Convert awards
         if let Some(awards) = data.awards {
-            resume.sections.awards = Section::new("awards", "Awards");
+            resume.sections.awards = Section::new("awards", titles.awards);
             for a in awards {
                 let mut award = Award::new(a.title.unwrap_or_default());
                 if let Some(awarder) = a.awarder {
@@ -407,7 +453,7 @@ impl Parser for JsonResumeParser {
 
         // Convert certificates -> certifications
         if let Some(certificates) = data.certificates {
-            resume.sections.certifications = Section::new("certifications", "Certifications");
+            resume.sections.certifications = Section::new("certifications", titles.certifications);
             for c in certificates {
                 let mut cert =
                     Certification::new(c.name.unwrap_or_default(), c.issuer.unwrap_or_default());
@@ -423,7 +469,7 @@ impl Parser for JsonResumeParser {
 
         // Convert publications
         if let Some(publications) = data.publications {
-            resume.sections.publications = Section::new("publications", "Publications");
+            resume.sections.publications = Section::new("publications", titles.publications);
             for p in publications {
                 let mut pub_item = Publication::new(p.name.unwrap_or_default());
 
@@ -449,7 +495,7 @@ impl Parser for JsonResumeParser {
 
         // Convert languages
         if let Some(languages) = data.languages {
-            resume.sections.languages = Section::new("languages", "Languages");
+            resume.sections.languages = Section::new("languages", titles.languages);
             for l in languages {
                 let mut lang = Language::new(l.language.unwrap_or_default());
                 if let Some(fluency) = l.fluency {
@@ -463,7 +509,7 @@ impl Parser for JsonResumeParser {
 
         // Convert interests
         if let Some(interests) = data.interests {
-            resume.sections.interests = Section::new("interests", "Interests");
+            resume.sections.interests = Section::new("interests", titles.interests);
             for i in interests {
                 let mut interest = Interest::new(i.name.unwrap_or_default());
                 if let Some(keywords) = i.keywords {
@@ -475,7 +521,7 @@ impl Parser for JsonResumeParser {
 
         // Convert references
         if let Some(references) = data.references {
-            resume.sections.references = Section::new("references", "References");
+            resume.sections.references = Section::new("references", titles.references);
             for r in references {
                 let mut ref_item = Reference::new(r.name.unwrap_or_default());
 
@@ -488,6 +534,7 @@ impl Parser for JsonResumeParser {
             }
         }
 
+        resume.sync_dates();
         Ok(resume)
     }
 }
@@ -517,7 +564,7 @@ fn build_summary(summary: Option<&str>, highlights: Option<&[String]>) -> String
 }
 
 /// Map fluency description to numeric level (0-5).
-fn fluency_to_level(fluency: &str) -> u8 {
+pub(crate) fn fluency_to_level(fluency: &str) -> u8 {
     let lower = fluency.to_lowercase();
     if lower.contains("native") || lower.contains("bilingual") {
         5
@@ -535,6 +582,223 @@ fn fluency_to_level(fluency: &str) -> u8 {
     }
 }
 
+// ============================================================================
+// Export
+// ============================================================================
+
+/// Export `resume` as a JSON Resume document.
+///
+/// Only what the resume shows is written: hidden sections and items are left
+/// out, rich text becomes plain text, and dates become ISO partial dates
+/// when they can be read. Empty fields are omitted. Custom sections, the
+/// cover letter, attachments, and display metadata have no JSON Resume
+/// equivalent and are dropped; the locale goes to `meta.language`.
+pub fn to_json_resume(resume: &ResumeData) -> Value {
+    // Visible items of a visible section
+    macro_rules! shown {
+        ($section:expr) => {
+            $section
+                .items
+                .iter()
+                .filter(|item| $section.visible && item.visible)
+        };
+    }
+
+    let basics = &resume.basics;
+    let sections = &resume.sections;
+    let summary = if sections.summary.visible {
+        plain_text(&sections.summary.content)
+    } else {
+        String::new()
+    };
+    let picture = if basics.picture.is_visible() {
+        basics.picture.url.as_str()
+    } else {
+        ""
+    };
+
+    let mut document = json!({
+        "basics": {
+            "name": basics.name,
+            "label": basics.headline,
+            "image": picture,
+            "email": basics.email,
+            "phone": basics.phone,
+            "url": basics.url.href,
+            "summary": summary,
+            "location": json_resume_location(basics),
+            "profiles": shown!(sections.profiles).map(|item| json!({
+                "network": item.network,
+                "username": item.username,
+                "url": item.url.href,
+            })).collect::<Vec<_>>(),
+        },
+        "work": shown!(sections.experience).map(|item| {
+            let (start, end) = iso_dates(item.date_range());
+            json!({
+                "name": item.company,
+                "position": item.position,
+                "location": item.location,
+                "url": item.url.href,
+                "startDate": start,
+                "endDate": end,
+                "summary": plain_text(&item.summary),
+                "highlights": item
+                    .highlights
+                    .iter()
+                    .filter(|line| !line.trim().is_empty())
+                    .collect::<Vec<_>>(),
+            })
+        }).collect::<Vec<_>>(),
+        "volunteer": shown!(sections.volunteer).map(|item| {
+            let (start, end) = iso_dates(parse_date_range(&item.date));
+            json!({
+                "organization": item.organization,
+                "position": item.position,
+                "url": item.url.href,
+                "startDate": start,
+                "endDate": end,
+                "summary": plain_text(&item.summary),
+            })
+        }).collect::<Vec<_>>(),
+        "education": shown!(sections.education).map(|item| {
+            let (start, end) = iso_dates(item.date_range());
+            json!({
+                "institution": item.institution,
+                "url": item.url.href,
+                "area": item.area,
+                "studyType": item.study_type,
+                "startDate": start,
+                "endDate": end,
+                "score": item.score,
+            })
+        }).collect::<Vec<_>>(),
+        "awards": shown!(sections.awards).map(|item| json!({
+            "title": item.title,
+            "date": iso_date(&item.date),
+            "awarder": item.awarder,
+            "summary": plain_text(&item.summary),
+        })).collect::<Vec<_>>(),
+        "certificates": shown!(sections.certifications).map(|item| json!({
+            "name": item.name,
+            "date": iso_date(&item.date),
+            "issuer": item.issuer,
+            "url": item.url.href,
+        })).collect::<Vec<_>>(),
+        "publications": shown!(sections.publications).map(|item| json!({
+            "name": item.name,
+            "publisher": item.publisher,
+            "releaseDate": iso_date(&item.date),
+            "url": item.url.href,
+            "summary": plain_text(&item.summary),
+        })).collect::<Vec<_>>(),
+        "skills": shown!(sections.skills).map(|item| json!({
+            "name": item.name,
+            "level": item.description,
+            "keywords": item.keywords,
+        })).collect::<Vec<_>>(),
+        "languages": shown!(sections.languages).map(|item| json!({
+            "language": item.name,
+            "fluency": item.description,
+        })).collect::<Vec<_>>(),
+        "interests": shown!(sections.interests).map(|item| json!({
+            "name": item.name,
+            "keywords": item.keywords,
+        })).collect::<Vec<_>>(),
+        "references": shown!(sections.references).map(|item| json!({
+            "name": item.name,
+            "reference": plain_text(&item.summary),
+        })).collect::<Vec<_>>(),
+        "projects": shown!(sections.projects).map(|item| {
+            let (start, end) = iso_dates(item.date_range());
+            json!({
+                "name": item.name,
+                "description": item.description,
+                // JSON Resume projects have no summary, so its lines lead the highlights
+                "highlights": html_text_blocks(&item.summary)
+                    .into_iter()
+                    .chain(
+                        item.highlights
+                            .iter()
+                            .filter(|line| !line.trim().is_empty())
+                            .cloned(),
+                    )
+                    .collect::<Vec<_>>(),
+                "keywords": item.keywords,
+                "startDate": start,
+                "endDate": end,
+                "url": item.url.href,
+            })
+        }).collect::<Vec<_>>(),
+        "meta": { "language": resume.metadata.locale },
+    });
+    prune(&mut document);
+    document
+}
+
+/// `basics.location` as a JSON Resume location: the address parts when
+/// known, otherwise the free-form line as `address`.
+fn json_resume_location(basics: &Basics) -> Value {
+    let address = &basics.address;
+    if address.is_empty() {
+        return json!({ "address": basics.location });
+    }
+    json!({
+        "address": address.street,
+        "postalCode": address.postal_code,
+        "city": address.city,
+        "region": address.region,
+        "countryCode": address.country_code().unwrap_or_else(|| address.country.clone()),
+    })
+}
+
+/// Rich text as plain text, one line per paragraph or list item.
+fn plain_text(html: &str) -> String {
+    html_text_blocks(html).join("\n")
+}
+
+/// ISO start and end of a range; an ongoing range has no end.
+fn iso_dates(range: Option<DateRange>) -> (String, String) {
+    range.map_or_else(Default::default, |range| {
+        (
+            range.start.to_string(),
+            range.end.map(|end| end.to_string()).unwrap_or_default(),
+        )
+    })
+}
+
+/// A display date as an ISO partial date, or empty when it can't be read.
+fn iso_date(date: &str) -> String {
+    parse_partial_date(date)
+        .map(|date| date.to_string())
+        .unwrap_or_default()
+}
+
+/// Drop empty strings, lists, and objects, recursively.
+fn prune(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            map.values_mut().for_each(prune);
+            map.retain(|_, value| !is_empty(value));
+        }
+        Value::Array(items) => {
+            items.iter_mut().for_each(prune);
+            items.retain(|value| !is_empty(value));
+        }
+        _ => {}
+    }
+}
+
+fn is_empty(value: &Value) -> bool {
+    match value {
+        Value::Null => true,
+        Value::String(text) => text.trim().is_empty(),
+        Value::Array(items) => items.is_empty(),
+        Value::Object(map) => map.is_empty(),
+        _ => false,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -607,6 +871,8 @@ mod tests {
         let result = parser.parse(MINIMAL_JSON.as_bytes()).unwrap();
 
         assert_eq!(result.basics.name, "John Doe");
+        assert_eq!(result.basics.name_parts.given, "John");
+        assert_eq!(result.basics.name_parts.family, "Doe");
         assert_eq!(result.basics.headline, "Software Engineer");
         assert_eq!(result.basics.email, "john@example.com");
     }
@@ -626,9 +892,22 @@ mod tests {
         // Check experience
         assert_eq!(result.sections.experience.len(), 1);
         assert_eq!(result.sections.experience.items[0].company, "Tech Corp");
-        assert!(result.sections.experience.items[0]
-            .summary
-            .contains("Reduced latency"));
+        assert_eq!(
+            result.sections.experience.items[0].summary,
+            "Led development team."
+        );
+        assert_eq!(
+            result.sections.experience.items[0].highlights,
+            ["Reduced latency by 40%", "Mentored junior devs"]
+        );
+        assert_eq!(
+            result.sections.experience.items[0].start_date.as_deref(),
+            Some("2020-01-01")
+        );
+        assert_eq!(
+            result.sections.experience.items[0].end_date.as_deref(),
+            Some("2024-01-01")
+        );
 
         // Check education
         assert_eq!(result.sections.education.len(), 1);
@@ -644,6 +923,27 @@ mod tests {
         assert_eq!(result.sections.languages.items[1].level, 2); // Intermediate -> 2
     }
 
+    #[test]
+    fn test_meta_language_localizes_generated_text() {
+        let json = r#"{
+            "meta": {"language": "fr"},
+            "work": [{"name": "Acme", "position": "Engineer", "startDate": "2020-01"}],
+            "education": [{"institution": "MIT", "area": "CS", "courses": ["Algorithms"]}]
+        }"#;
+        let result = JsonResumeParser.parse(json.as_bytes()).unwrap();
+
+        assert_eq!(result.metadata.locale, "fr");
+        assert_eq!(result.sections.experience.name, "Expérience");
+        assert_eq!(
+            result.sections.experience.items[0].date,
+            "2020-01 - Aujourd'hui"
+        );
+        assert_eq!(
+            result.sections.education.items[0].summary,
+            "Cours: Algorithms"
+        );
+    }
+
     #[test]
     fn test_fluency_to_level() {
         assert_eq!(fluency_to_level("Native speaker"), 5);
@@ -662,4 +962,134 @@ mod tests {
         assert!(summary.contains("Main summary"));
         assert!(summary.contains("• Point 1"));
     }
+
+    #[test]
+    fn test_export_writes_visible_content_as_json_resume() {
+        let mut resume = ResumeData::default();
+        resume.basics.name = "Ada Lovelace".to_string();
+        resume.basics.location = "London, UK".to_string();
+        resume.sections.summary.content = "<p>Analyst &amp; writer</p>".to_string();
+        let mut engineer = Experience::new("Acme", "Engineer")
+            .with_dates("2019-02", None)
+            .with_summary("<ul><li>Built the engine</li><li>Wrote notes</li></ul>");
+        engineer.url.href = "https://acme.example".to_string();
+        resume.sections.experience.add_item(engineer);
+        let mut hidden = Experience::new("Secret", "Agent");
+        hidden.visible = false;
+        resume.sections.experience.add_item(hidden);
+        resume
+            .sections
+            .awards
+            .add_item(Award::new("Prize").with_date("Mar 2021"));
+        resume.sections.awards.visible = true;
+        resume.sections.skills.add_item(Skill::new("Rust"));
+        resume.sections.skills.visible = false;
+
+        let json = to_json_resume(&resume);
+        assert_eq!(json["basics"]["name"], "Ada Lovelace");
+        assert_eq!(json["basics"]["summary"], "Analyst & writer");
+        assert_eq!(json["basics"]["location"]["address"], "London, UK");
+        assert!(json["basics"].get("email").is_none());
+
+        let work = json["work"].as_array().unwrap();
+        assert_eq!(work.len(), 1);
+        assert_eq!(work[0]["startDate"], "2019-02");
+        assert!(work[0].get("endDate").is_none());
+        assert_eq!(work[0]["summary"], "Built the engine\nWrote notes");
+        assert_eq!(work[0]["url"], "https://acme.example");
+        assert_eq!(json["awards"][0]["date"], "2021-03");
+        assert!(json.get("skills").is_none());
+        assert_eq!(json["meta"]["language"], "en");
+    }
+
+    #[test]
+    fn test_highlights_round_trip() {
+        let mut resume = JsonResumeParser.parse(FULL_JSON.as_bytes()).unwrap();
+        resume.sections.projects.add_item(
+            Project::new("Engine")
+                .with_summary("<p>Difference engine</p>")
+                .with_highlights(vec!["Printed tables".to_string()]),
+        );
+
+        let json = to_json_resume(&resume);
+        assert_eq!(
+            json["work"][0]["highlights"],
+            json!(["Reduced latency by 40%", "Mentored junior devs"])
+        );
+        assert_eq!(
+            json["projects"][0]["highlights"],
+            json!(["Difference engine", "Printed tables"])
+        );
+
+        let reimported = JsonResumeParser.parse(json.to_string().as_bytes()).unwrap();
+        let experience = &reimported.sections.experience.items[0];
+        assert_eq!(experience.summary, "Led development team.");
+        assert_eq!(
+            experience.highlights,
+            resume.sections.experience.items[0].highlights
+        );
+        assert_eq!(
+            reimported.sections.projects.items[0].highlights,
+            ["Difference engine", "Printed tables"]
+        );
+    }
+
+    #[test]
+    fn test_location_parts_round_trip() {
+        let json = r#"{
+            "basics": {
+                "name": "Marie Curie",
+                "location": {
+                    "address": "36 Quai de Béthune",
+                    "postalCode": "75004",
+                    "city": "Paris",
+                    "countryCode": "FR"
+                }
+            }
+        }"#;
+        let resume = JsonResumeParser.parse(json.as_bytes()).unwrap();
+        assert_eq!(resume.basics.location, "Paris, FR");
+        assert_eq!(resume.basics.address.street, "36 Quai de Béthune");
+        assert_eq!(resume.basics.address.postal_code, "75004");
+        assert_eq!(
+            resume.basics.address.format("en"),
+            "36 Quai de Béthune, 75004 Paris, FR"
+        );
+
+        let exported = to_json_resume(&resume);
+        assert_eq!(exported["basics"]["location"]["postalCode"], "75004");
+        assert_eq!(exported["basics"]["location"]["countryCode"], "FR");
+        let reparsed = JsonResumeParser
+            .parse(&serde_json::to_vec(&exported).unwrap())
+            .unwrap();
+        assert_eq!(reparsed.basics.address, resume.basics.address);
+
+        // A free-form location has no parts
+        let json = r#"{"basics": {"location": {"address": "London, UK"}}}"#;
+        let resume = JsonResumeParser.parse(json.as_bytes()).unwrap();
+        assert_eq!(resume.basics.location, "London, UK");
+        assert!(resume.basics.address.is_empty());
+    }
+
+    #[test]
+    fn test_export_round_trips_through_parser() {
+        let original = JsonResumeParser.parse(FULL_JSON.as_bytes()).unwrap();
+        let exported = serde_json::to_vec(&to_json_resume(&original)).unwrap();
+        let reparsed = JsonResumeParser.parse(&exported).unwrap();
+
+        assert_eq!(reparsed.basics.name, original.basics.name);
+        assert_eq!(reparsed.basics.location, original.basics.location);
+        assert_eq!(
+            reparsed.sections.experience.items[0].date_range(),
+            original.sections.experience.items[0].date_range()
+        );
+        assert_eq!(
+            reparsed.sections.experience.items.len(),
+            original.sections.experience.items.len()
+        );
+        assert_eq!(
+            reparsed.sections.skills.items[0].keywords,
+            original.sections.skills.items[0].keywords
+        );
+    }
 }
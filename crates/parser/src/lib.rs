@@ -1,18 +1,71 @@
 //! Import/export parsers for Rustume.
 //!
 //! Supports parsing from:
-//! - JSON Resume format
-//! - LinkedIn data export (ZIP)
+//! - JSON Resume format, which resumes can also be exported to
+//! - LinkedIn data export (ZIP, `linkedin` feature)
+//! - LinkedIn job applications and saved jobs (ZIP, for the application tracker, `linkedin` feature)
 //! - Reactive Resume V3 format (migration)
+//! - Stack Overflow user data (API JSON or data-dump XML)
+//! - HTML pages with hResume or schema.org `Person` markup
+//! - vCard contacts (`.vcf`), which can also be exported from `basics`
+//! - Plain text pasted from an old PDF, sectionized heuristically
+//! - CSV spreadsheets of skills, languages, or certifications, appended to
+//!   one section (`section-csv` feature)
+//!
+//! Also exports the experience and education timeline as iCalendar (`.ics`)
+//! or as an SVG chart, and any section's items as CSV or XLSX
+//! (`section-csv` and `xlsx` features).
+//!
+//! Parsers take their input as bytes; the LinkedIn parser can also read it
+//! from a reader ([`StreamingParser`]) or a tokio `AsyncRead` (`AsyncParser`,
+//! `async` feature).
+//!
+//! [`detect_format`] recognises an input's format from its bytes, for
+//! callers that let users upload without naming one. [`ImportPipeline`]
+//! detects, parses, and cleans up an import the same way for the CLI, the
+//! server, and the WASM bindings.
 
+mod content_line;
+mod detect;
 mod dispatch;
+mod html;
+mod ics;
 mod json_resume;
+#[cfg(feature = "linkedin")]
 mod linkedin;
+#[cfg(feature = "linkedin")]
+mod linkedin_jobs;
+mod pipeline;
 mod reactive_resume_v3;
+#[cfg(feature = "section-csv")]
+mod section_csv;
+#[cfg(feature = "section-csv")]
+mod section_export;
+mod stack_overflow;
+mod text;
+mod timeline;
 mod traits;
+mod vcard;
 
-pub use dispatch::{parse_resume, ResumeFormat};
-pub use json_resume::{JsonResume, JsonResumeParser};
-pub use linkedin::{LinkedInData, LinkedInParser};
+pub use detect::{detect_format, detect_resume_format, DetectedFormat, DetectedKind};
+pub use dispatch::{parse_resume, parse_resume_with_progress, parse_rustume, ResumeFormat};
+pub use html::{HtmlParser, HtmlResume};
+pub use ics::to_ics;
+pub use json_resume::{to_json_resume, JsonResume, JsonResumeParser};
+#[cfg(feature = "linkedin")]
+pub use linkedin::{CsvEntries, CsvEntry, LinkedInData, LinkedInParser, ZipLimits};
+#[cfg(feature = "linkedin")]
+pub use linkedin_jobs::LinkedInJobsParser;
+pub use pipeline::{IdStrategy, ImportOptions, ImportPipeline, ImportReport, Imported};
 pub use reactive_resume_v3::{ReactiveResumeV3Parser, V3Resume};
+#[cfg(feature = "section-csv")]
+pub use section_csv::{import_section_csv, CsvSection, MAX_SECTION_CSV_ROWS};
+#[cfg(feature = "section-csv")]
+pub use section_export::{section_table, SectionTable};
+pub use stack_overflow::{StackOverflowExport, StackOverflowParser};
+pub use text::{
+    Confidence, FieldConfidence, TextDraft, TextResume, TextResumeParser, MAX_TEXT_BYTES,
+};
+pub use timeline::render_timeline;
 pub use traits::*;
+pub use vcard::{to_vcard, Vcard, VcardParser};
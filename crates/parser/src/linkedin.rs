@@ -10,21 +10,54 @@
 //! - Projects.csv - Projects
 //! - Email Addresses.csv - Email addresses
 
-use crate::traits::{ParseError, Parser};
+#[cfg(feature = "async")]
+use crate::traits::AsyncParser;
+use crate::traits::{ParseError, ParsePhase, ParseProgress, Parser, StreamingParser};
 use csv::ReaderBuilder;
 use rustume_schema::{
-    Basics, Certification, Education, Experience, Language, Project, ResumeData, Section, Skill,
-    Url,
+    Basics, CefrLevel, Certification, Education, Experience, Language, PersonName, Project,
+    ResumeData, Section, Skill, Url,
 };
+use rustume_utils::{format_date_range_in, Locale, DEFAULT_LOCALE};
 use std::collections::HashMap;
 use std::io::{Cursor, Read};
+use zip::read::{read_zipfile_from_stream, ZipFile};
 use zip::ZipArchive;
 
 /// LinkedIn data export parser.
 ///
 /// Parses the ZIP file export that users can download from LinkedIn's
-/// "Get a copy of your data" feature.
-pub struct LinkedInParser;
+/// "Get a copy of your data" feature. Archive processing is bounded by
+/// [`ZipLimits`]; use [`LinkedInParser::with_limits`] to tighten or relax them.
+/// The export does not say what language the profile is in, so generated
+/// text is English unless [`LinkedInParser::with_locale`] says otherwise.
+#[derive(Debug, Clone, Default)]
+pub struct LinkedInParser {
+    limits: ZipLimits,
+    locale: Option<String>,
+}
+
+impl LinkedInParser {
+    /// Create a parser that enforces the given archive limits.
+    pub fn with_limits(limits: ZipLimits) -> Self {
+        Self {
+            limits,
+            locale: None,
+        }
+    }
+
+    /// Write generated text (section titles, `Present`) in the language of
+    /// `locale`, a BCP 47 tag, and record it in `metadata.locale`.
+    pub fn with_locale(mut self, locale: impl Into<String>) -> Self {
+        self.locale = Some(locale.into());
+        self
+    }
+
+    /// The archive limits enforced by this parser.
+    pub fn limits(&self) -> &ZipLimits {
+        &self.limits
+    }
+}
 
 // ============================================================================
 // LinkedIn CSV Data Structures
@@ -146,11 +179,39 @@ const MAX_TOTAL_UNCOMPRESSED: u64 = 100 * 1024 * 1024;
 /// expensive iteration over malicious archives with many tiny files.
 const MAX_LINKEDIN_ENTRIES: usize = 100;
 
+/// Resource limits applied while extracting a LinkedIn ZIP export.
+///
+/// Entry sizes are enforced on the bytes actually decompressed, not only on the
+/// sizes declared in the archive headers, so a forged header cannot smuggle a
+/// decompression bomb past the checks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ZipLimits {
+    /// Maximum size of the compressed archive in bytes.
+    pub max_archive_size: usize,
+    /// Maximum decompressed size of a single CSV entry in bytes.
+    pub max_entry_size: u64,
+    /// Maximum decompressed size across all CSV entries in bytes.
+    pub max_total_uncompressed: u64,
+    /// Maximum number of entries (files and directories) in the archive.
+    pub max_entries: usize,
+}
+
+impl Default for ZipLimits {
+    fn default() -> Self {
+        Self {
+            max_archive_size: MAX_ZIP_SIZE,
+            max_entry_size: MAX_UNCOMPRESSED_ENTRY_SIZE,
+            max_total_uncompressed: MAX_TOTAL_UNCOMPRESSED,
+            max_entries: MAX_LINKEDIN_ENTRIES,
+        }
+    }
+}
+
 /// Parse CSV records into an iterator of HashMaps.
 ///
 /// Creates a CSV reader with normalized headers (lowercase, underscores for spaces)
 /// and returns an iterator that yields each record as a HashMap.
-fn parse_csv_records(
+pub(crate) fn parse_csv_records(
     contents: &str,
 ) -> Result<(Vec<String>, csv::StringRecordsIntoIter<&[u8]>), ParseError> {
     let mut reader = ReaderBuilder::new()
@@ -178,7 +239,10 @@ fn parse_csv_records(
 }
 
 /// Convert a CSV record to a HashMap using the provided headers.
-fn record_to_map(headers: &[String], record: &csv::StringRecord) -> HashMap<String, String> {
+pub(crate) fn record_to_map(
+    headers: &[String],
+    record: &csv::StringRecord,
+) -> HashMap<String, String> {
     headers
         .iter()
         .zip(record.iter())
@@ -186,111 +250,307 @@ fn record_to_map(headers: &[String], record: &csv::StringRecord) -> HashMap<Stri
         .collect()
 }
 
-impl LinkedInParser {
-    /// Extract and parse CSV files from LinkedIn ZIP export.
-    fn parse_zip(&self, data: &[u8]) -> Result<LinkedInData, ParseError> {
+/// A CSV file read from a LinkedIn ZIP export.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsvEntry {
+    /// Lowercased base filename, directory stripped (`positions.csv`).
+    pub name: String,
+    /// UTF-8 contents of the file.
+    pub contents: String,
+}
+
+/// The CSV entries of a LinkedIn ZIP export, read one at a time.
+///
+/// Each call to [`Iterator::next`] inflates at most one entry, so callers that
+/// must stay responsive (a browser tab, say) can yield between entries. Limits
+/// are checked as entries are read; iteration stops after the first error.
+#[derive(Debug)]
+pub struct CsvEntries<'a> {
+    archive: ZipArchive<Cursor<&'a [u8]>>,
+    limits: ZipLimits,
+    index: usize,
+    cumulative_uncompressed: u64,
+    failed: bool,
+}
+
+impl<'a> CsvEntries<'a> {
+    /// Open `data` as a ZIP archive, checking the archive-level limits.
+    pub fn new(data: &'a [u8], limits: &ZipLimits) -> Result<Self, ParseError> {
         // Validate ZIP size to prevent DoS attacks
-        if data.len() > MAX_ZIP_SIZE {
-            return Err(ParseError::ReadError(format!(
+        if data.len() > limits.max_archive_size {
+            return Err(ParseError::LimitExceeded(format!(
                 "ZIP file too large: {} bytes exceeds {} byte limit",
                 data.len(),
-                MAX_ZIP_SIZE
+                limits.max_archive_size
             )));
         }
 
-        let cursor = Cursor::new(data);
-        let mut archive = ZipArchive::new(cursor)
-            .map_err(|e| ParseError::ReadError(format!("Failed to open ZIP archive: {}", e)))?;
+        let archive = ZipArchive::new(Cursor::new(data))
+            .map_err(|e| ParseError::InvalidArchive(e.to_string()))?;
 
         // Check entry count upfront to prevent expensive iteration over malicious archives
-        if archive.len() > MAX_LINKEDIN_ENTRIES {
-            return Err(ParseError::ReadError(format!(
+        if archive.len() > limits.max_entries {
+            return Err(ParseError::LimitExceeded(format!(
                 "ZIP archive has too many entries: {} exceeds {} entry limit",
                 archive.len(),
-                MAX_LINKEDIN_ENTRIES
+                limits.max_entries
             )));
         }
 
-        let mut linkedin_data = LinkedInData::default();
-        let mut cumulative_uncompressed: u64 = 0;
+        Ok(Self {
+            archive,
+            limits: *limits,
+            index: 0,
+            cumulative_uncompressed: 0,
+            failed: false,
+        })
+    }
 
-        // Iterate through files in the archive
-        for i in 0..archive.len() {
-            let mut file = archive.by_index(i).map_err(|e| {
-                ParseError::ReadError(format!("Failed to read ZIP entry {}: {}", i, e))
-            })?;
+    /// Number of archive entries, including directories and non-CSV files.
+    pub fn total(&self) -> usize {
+        self.archive.len()
+    }
 
-            let file_name = file.name().to_lowercase();
+    /// Number of archive entries examined so far.
+    pub fn position(&self) -> usize {
+        self.index
+    }
 
-            // Skip directories and non-CSV files
-            if file.is_dir() || !file_name.ends_with(".csv") {
-                continue;
-            }
+    /// Read entry `i`, or `None` if it is a directory or not a CSV file.
+    fn read_entry(&mut self, i: usize) -> Result<Option<CsvEntry>, ParseError> {
+        let file = self
+            .archive
+            .by_index(i)
+            .map_err(|e| ParseError::InvalidArchive(format!("entry {}: {}", i, e)))?;
+        read_csv_file(file, &self.limits, &mut self.cumulative_uncompressed)
+    }
+}
+
+/// Inflate a CSV entry within `limits`, or return `None` if it is a
+/// directory or not a CSV file. `cumulative_uncompressed` counts what the
+/// entries before it inflated to, and grows by this one.
+fn read_csv_file<R: Read + ?Sized>(
+    mut file: ZipFile<'_, R>,
+    limits: &ZipLimits,
+    cumulative_uncompressed: &mut u64,
+) -> Result<Option<CsvEntry>, ParseError> {
+    let file_name = file.name().to_lowercase();
+
+    // Skip directories and non-CSV files
+    if file.is_dir() || !file_name.ends_with(".csv") {
+        return Ok(None);
+    }
+
+    // ZIP bomb protection: reject entries whose declared size is already too large
+    let declared_size = file.size();
+    if declared_size > limits.max_entry_size {
+        return Err(ParseError::LimitExceeded(format!(
+            "ZIP entry '{}' uncompressed size ({} bytes) exceeds {} byte limit",
+            file_name, declared_size, limits.max_entry_size
+        )));
+    }
+
+    let remaining_total = limits
+        .max_total_uncompressed
+        .saturating_sub(*cumulative_uncompressed);
+    if declared_size > remaining_total {
+        return Err(ParseError::LimitExceeded(format!(
+            "ZIP total uncompressed size would exceed {} byte limit",
+            limits.max_total_uncompressed
+        )));
+    }
 
-            // ZIP bomb protection: check uncompressed size of this entry
-            let uncompressed_size = file.size();
-            if uncompressed_size > MAX_UNCOMPRESSED_ENTRY_SIZE {
-                return Err(ParseError::ReadError(format!(
-                    "ZIP entry '{}' uncompressed size ({} bytes) exceeds {} byte limit",
-                    file_name, uncompressed_size, MAX_UNCOMPRESSED_ENTRY_SIZE
-                )));
+    // Declared sizes can be forged, so cap the bytes actually inflated. Reading one
+    // byte past the budget tells us whether the entry overflowed it.
+    let budget = limits.max_entry_size.min(remaining_total);
+    let mut contents = Vec::new();
+    (&mut file)
+        .take(budget.saturating_add(1))
+        .read_to_end(&mut contents)
+        .map_err(|e| ParseError::InvalidArchive(format!("{}: {}", file_name, e)))?;
+
+    let actual_size = contents.len() as u64;
+    if actual_size > limits.max_entry_size {
+        return Err(ParseError::LimitExceeded(format!(
+            "ZIP entry '{}' decompressed past the {} byte limit",
+            file_name, limits.max_entry_size
+        )));
+    }
+    if actual_size > remaining_total {
+        return Err(ParseError::LimitExceeded(format!(
+            "ZIP total uncompressed size would exceed {} byte limit",
+            limits.max_total_uncompressed
+        )));
+    }
+    *cumulative_uncompressed += actual_size;
+
+    let contents = String::from_utf8(contents)
+        .map_err(|e| ParseError::ReadError(format!("Failed to read file {}: {}", file_name, e)))?;
+
+    // Extract base filename (strip directory path)
+    let name = file_name
+        .rsplit('/')
+        .next()
+        .unwrap_or(&file_name)
+        .to_lowercase();
+
+    Ok(Some(CsvEntry { name, contents }))
+}
+
+impl Iterator for CsvEntries<'_> {
+    type Item = Result<CsvEntry, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.failed && self.index < self.archive.len() {
+            let i = self.index;
+            self.index += 1;
+            match self.read_entry(i) {
+                Ok(Some(entry)) => return Some(Ok(entry)),
+                Ok(None) => {}
+                Err(e) => {
+                    self.failed = true;
+                    return Some(Err(e));
+                }
             }
+        }
+        None
+    }
+}
 
-            // ZIP bomb protection: check cumulative uncompressed size
-            if cumulative_uncompressed + uncompressed_size > MAX_TOTAL_UNCOMPRESSED {
-                return Err(ParseError::ReadError(format!(
-                    "ZIP total uncompressed size would exceed {} byte limit",
-                    MAX_TOTAL_UNCOMPRESSED
-                )));
+/// Read every CSV entry of a LinkedIn ZIP export from `input` as it
+/// arrives, within `limits`.
+///
+/// Entries are read one at a time from their local headers, so only the
+/// entry being inflated is held in memory, and reading stops as soon as the
+/// archive passes a limit. The central directory at the end is never read.
+/// Entries whose sizes follow their data (a "data descriptor") can't be
+/// found this way and fail with [`ParseError::InvalidArchive`].
+fn for_each_streamed_csv_entry(
+    input: &mut dyn Read,
+    limits: &ZipLimits,
+    mut visit: impl FnMut(CsvEntry) -> Result<(), ParseError>,
+) -> Result<(), ParseError> {
+    // Reading one byte past the size limit tells us the archive overflowed it
+    let mut input = input.take(limits.max_archive_size as u64 + 1);
+    let mut cumulative_uncompressed = 0;
+    let mut count = 0;
+
+    let result = loop {
+        let file = match read_zipfile_from_stream(&mut input) {
+            Ok(Some(file)) => file,
+            Ok(None) => break Ok(()),
+            Err(e) => {
+                break Err(ParseError::InvalidArchive(format!(
+                    "entry {}: {}",
+                    count, e
+                )))
             }
+        };
+        count += 1;
+        if count > limits.max_entries {
+            break Err(ParseError::LimitExceeded(format!(
+                "ZIP archive has too many entries: over the {} entry limit",
+                limits.max_entries
+            )));
+        }
+        let visited = read_csv_file(file, limits, &mut cumulative_uncompressed)
+            .and_then(|entry| entry.map_or(Ok(()), &mut visit));
+        if let Err(e) = visited {
+            break Err(e);
+        }
+    };
+
+    // A read cut short by the size limit fails however the ZIP reader saw it
+    if input.limit() == 0 {
+        return Err(ParseError::LimitExceeded(format!(
+            "ZIP file too large: exceeds {} byte limit",
+            limits.max_archive_size
+        )));
+    }
+    result
+}
 
-            // Read file contents
-            let mut contents = String::new();
-            file.read_to_string(&mut contents).map_err(|e| {
-                ParseError::ReadError(format!("Failed to read file {}: {}", file_name, e))
-            })?;
-
-            cumulative_uncompressed += uncompressed_size;
-
-            // Extract base filename (strip directory path)
-            let base_name = file_name
-                .rsplit('/')
-                .next()
-                .unwrap_or(&file_name)
-                .to_lowercase();
-
-            // Parse based on exact filename match for security
-            match base_name.as_str() {
-                "profile.csv" => {
-                    linkedin_data.profile = self.parse_profile_csv(&contents)?;
-                }
-                "positions.csv" => {
-                    linkedin_data.positions = self.parse_positions_csv(&contents)?;
-                }
-                "education.csv" => {
-                    linkedin_data.education = self.parse_education_csv(&contents)?;
-                }
-                "skills.csv" => {
-                    linkedin_data.skills = self.parse_skills_csv(&contents)?;
-                }
-                "languages.csv" => {
-                    linkedin_data.languages = self.parse_languages_csv(&contents)?;
-                }
-                "certifications.csv" => {
-                    linkedin_data.certifications = self.parse_certifications_csv(&contents)?;
-                }
-                "projects.csv" => {
-                    linkedin_data.projects = self.parse_projects_csv(&contents)?;
-                }
-                "email addresses.csv" => {
-                    linkedin_data.emails = self.parse_emails_csv(&contents)?;
-                }
-                _ => {
-                    // Skip unrecognized files
-                }
+/// Read every CSV entry of a LinkedIn ZIP export within `limits`.
+///
+/// Calls `visit` with the lowercased base filename (directory stripped) and the
+/// UTF-8 contents of each CSV entry, in archive order.
+pub(crate) fn for_each_csv_entry(
+    data: &[u8],
+    limits: &ZipLimits,
+    mut visit: impl FnMut(&str, &str) -> Result<(), ParseError>,
+) -> Result<(), ParseError> {
+    for entry in CsvEntries::new(data, limits)? {
+        let entry = entry?;
+        visit(&entry.name, &entry.contents)?;
+    }
+    Ok(())
+}
+
+impl LinkedInParser {
+    /// Open a LinkedIn ZIP export for incremental parsing.
+    ///
+    /// Feed each entry to [`LinkedInParser::collect_entry`], then pass the
+    /// collected data to [`Parser::convert`]. [`Parser::parse`] does the same
+    /// in one call.
+    pub fn entries<'a>(&self, data: &'a [u8]) -> Result<CsvEntries<'a>, ParseError> {
+        CsvEntries::new(data, &self.limits)
+    }
+
+    /// Parse one CSV entry of the export into `data`. Unrecognized files are
+    /// ignored.
+    pub fn collect_entry(
+        &self,
+        data: &mut LinkedInData,
+        entry: &CsvEntry,
+    ) -> Result<(), ParseError> {
+        let contents = entry.contents.as_str();
+        // Parse based on exact filename match for security
+        match entry.name.as_str() {
+            "profile.csv" => {
+                data.profile = self.parse_profile_csv(contents)?;
+            }
+            "positions.csv" => {
+                data.positions = self.parse_positions_csv(contents)?;
+            }
+            "education.csv" => {
+                data.education = self.parse_education_csv(contents)?;
+            }
+            "skills.csv" => {
+                data.skills = self.parse_skills_csv(contents)?;
+            }
+            "languages.csv" => {
+                data.languages = self.parse_languages_csv(contents)?;
+            }
+            "certifications.csv" => {
+                data.certifications = self.parse_certifications_csv(contents)?;
+            }
+            "projects.csv" => {
+                data.projects = self.parse_projects_csv(contents)?;
+            }
+            "email addresses.csv" => {
+                data.emails = self.parse_emails_csv(contents)?;
+            }
+            _ => {
+                // Skip unrecognized files
             }
         }
+        Ok(())
+    }
 
+    /// Progress after `entry`, the last entry read from `entries`. Reading the
+    /// archive is most of the work, so it spans 5% to 90%.
+    pub fn entry_progress(entries: &CsvEntries<'_>, entry: &CsvEntry) -> ParseProgress {
+        let total = entries.total().max(1);
+        let percent = 5 + 85 * entries.position().min(total) / total;
+        ParseProgress::new(ParsePhase::Validate, percent as u8).with_detail(&entry.name)
+    }
+
+    /// Extract and parse CSV files from LinkedIn ZIP export.
+    fn parse_zip(&self, data: &[u8]) -> Result<LinkedInData, ParseError> {
+        let mut linkedin_data = LinkedInData::default();
+        for entry in self.entries(data)? {
+            self.collect_entry(&mut linkedin_data, &entry?)?;
+        }
         Ok(linkedin_data)
     }
 
@@ -523,8 +783,34 @@ impl Parser for LinkedInParser {
         self.parse_zip(&data)
     }
 
+    fn parse_with_progress(
+        &self,
+        input: &[u8],
+        progress: &mut dyn FnMut(ParseProgress),
+    ) -> Result<ResumeData, ParseError> {
+        progress(ParseProgress::new(ParsePhase::Read, 0));
+        let mut entries = self.entries(input)?;
+        let mut data = LinkedInData::default();
+        while let Some(entry) = entries.next() {
+            let entry = entry?;
+            self.collect_entry(&mut data, &entry)?;
+            progress(Self::entry_progress(&entries, &entry));
+        }
+        progress(ParseProgress::new(ParsePhase::Convert, 90));
+        let resume = self.convert(data)?;
+        progress(ParseProgress::new(ParsePhase::Convert, 100));
+        Ok(resume)
+    }
+
     fn convert(&self, data: Self::ValidatedData) -> Result<ResumeData, ParseError> {
         let mut resume = ResumeData::default();
+        resume.metadata.locale = self
+            .locale
+            .clone()
+            .unwrap_or_else(|| DEFAULT_LOCALE.to_string());
+        let locale = Locale::for_tag(&resume.metadata.locale);
+        let titles = &locale.sections;
+        resume.sections.summary.name = titles.summary.to_string();
 
         // Convert profile/basics
         if let Some(profile) = data.profile {
@@ -532,7 +818,10 @@ impl Parser for LinkedInParser {
                 .trim()
                 .to_string();
 
-            resume.basics = Basics::new(&full_name);
+            resume.basics = Basics::new(&full_name).with_name_parts(PersonName::new(
+                profile.first_name.trim(),
+                profile.last_name.trim(),
+            ));
 
             if let Some(headline) = profile.headline {
                 resume.basics = resume.basics.with_headline(&headline);
@@ -559,18 +848,19 @@ impl Parser for LinkedInParser {
         }
 
         // Initialize profiles section (LinkedIn profile URL is not available in export data)
-        resume.sections.profiles = Section::new("profiles", "Profiles");
+        resume.sections.profiles = Section::new("profiles", titles.profiles);
 
         // Convert positions to experience
         if !data.positions.is_empty() {
-            resume.sections.experience = Section::new("experience", "Experience");
+            resume.sections.experience = Section::new("experience", titles.experience);
             for pos in data.positions {
                 let mut exp = Experience::new(&pos.company_name, &pos.title);
 
                 // Format date range
-                let date = format_linkedin_date_range(
+                let date = format_date_range_in(
                     pos.started_on.as_deref(),
                     pos.finished_on.as_deref(),
+                    locale,
                 );
                 if !date.is_empty() {
                     exp = exp.with_date(&date);
@@ -590,7 +880,7 @@ impl Parser for LinkedInParser {
 
         // Convert education
         if !data.education.is_empty() {
-            resume.sections.education = Section::new("education", "Education");
+            resume.sections.education = Section::new("education", titles.education);
             for edu in data.education {
                 // Education requires institution and area
                 let area = edu.field_of_study.clone().unwrap_or_default();
@@ -601,9 +891,10 @@ impl Parser for LinkedInParser {
                 }
 
                 // Format date range
-                let date = format_linkedin_date_range(
+                let date = format_date_range_in(
                     edu.started_on.as_deref(),
                     edu.finished_on.as_deref(),
+                    locale,
                 );
                 if !date.is_empty() {
                     education = education.with_date(&date);
@@ -615,32 +906,28 @@ impl Parser for LinkedInParser {
 
         // Convert skills - group them into a single skill entry with keywords
         if !data.skills.is_empty() {
-            resume.sections.skills = Section::new("skills", "Skills");
+            resume.sections.skills = Section::new("skills", titles.skills);
 
             // Group skills into categories of ~10 for better display
             let skill_names: Vec<String> = data.skills.into_iter().map(|s| s.name).collect();
             let chunks: Vec<&[String]> = skill_names.chunks(10).collect();
 
             for (i, chunk) in chunks.iter().enumerate() {
-                let label = match i {
-                    0 => "Skills",
-                    1 => "Additional Skills",
-                    _ => "More Skills",
-                };
-                let skill = Skill::new(label).with_keywords(chunk.to_vec());
+                let skill = Skill::new(locale.skill_group(i)).with_keywords(chunk.to_vec());
                 resume.sections.skills.add_item(skill);
             }
         }
 
         // Convert languages
         if !data.languages.is_empty() {
-            resume.sections.languages = Section::new("languages", "Languages");
+            resume.sections.languages = Section::new("languages", titles.languages);
             for lang in data.languages {
                 let mut language = Language::new(&lang.name);
 
                 if let Some(proficiency) = lang.proficiency {
                     let level = proficiency_to_level(&proficiency);
                     language = language.with_description(&proficiency).with_level(level);
+                    language.cefr = CefrLevel::from_linkedin(&proficiency);
                 }
 
                 resume.sections.languages.add_item(language);
@@ -649,7 +936,7 @@ impl Parser for LinkedInParser {
 
         // Convert certifications
         if !data.certifications.is_empty() {
-            resume.sections.certifications = Section::new("certifications", "Certifications");
+            resume.sections.certifications = Section::new("certifications", titles.certifications);
             for cert in data.certifications {
                 // Certification requires name and issuer
                 let issuer = cert.authority.clone().unwrap_or_default();
@@ -670,7 +957,7 @@ impl Parser for LinkedInParser {
 
         // Convert projects
         if !data.projects.is_empty() {
-            resume.sections.projects = Section::new("projects", "Projects");
+            resume.sections.projects = Section::new("projects", titles.projects);
             for proj in data.projects {
                 let mut project = Project::new(&proj.title);
 
@@ -683,9 +970,10 @@ impl Parser for LinkedInParser {
                 }
 
                 // Format date range
-                let date = format_linkedin_date_range(
+                let date = format_date_range_in(
                     proj.started_on.as_deref(),
                     proj.finished_on.as_deref(),
+                    locale,
                 );
                 if !date.is_empty() {
                     project = project.with_date(&date);
@@ -695,10 +983,41 @@ impl Parser for LinkedInParser {
             }
         }
 
+        resume.sync_dates();
         Ok(resume)
     }
 }
 
+impl StreamingParser for LinkedInParser {
+    fn read_from(&self, input: &mut dyn Read) -> Result<Self::ValidatedData, ParseError> {
+        let mut data = LinkedInData::default();
+        for_each_streamed_csv_entry(input, &self.limits, |entry| {
+            self.collect_entry(&mut data, &entry)
+        })?;
+        Ok(data)
+    }
+}
+
+#[cfg(feature = "async")]
+impl AsyncParser for LinkedInParser {
+    fn read_async<'a, R>(
+        &'a self,
+        input: R,
+    ) -> impl std::future::Future<Output = Result<Self::ValidatedData, ParseError>> + Send + 'a
+    where
+        R: tokio::io::AsyncRead + Unpin + Send + 'static,
+    {
+        let parser = self.clone();
+        async move {
+            // Inflating is blocking work; the bridge waits for each chunk on the runtime
+            let mut input = tokio_util::io::SyncIoBridge::new(input);
+            tokio::task::spawn_blocking(move || parser.read_from(&mut input))
+                .await
+                .map_err(|e| ParseError::ReadError(format!("Reading task failed: {}", e)))?
+        }
+    }
+}
+
 // ============================================================================
 // Helper Functions
 // ============================================================================
@@ -708,19 +1027,6 @@ fn format_linkedin_date(date: Option<&str>) -> String {
     date.map(|d| d.trim().to_string()).unwrap_or_default()
 }
 
-/// Format LinkedIn date range
-fn format_linkedin_date_range(start: Option<&str>, end: Option<&str>) -> String {
-    let start_str = start.map(|s| s.trim()).filter(|s| !s.is_empty());
-    let end_str = end.map(|s| s.trim()).filter(|s| !s.is_empty());
-
-    match (start_str, end_str) {
-        (Some(s), Some(e)) => format!("{} - {}", s, e),
-        (Some(s), None) => format!("{} - Present", s),
-        (None, Some(e)) => e.to_string(),
-        (None, None) => String::new(),
-    }
-}
-
 /// Convert LinkedIn proficiency to skill level (1-5)
 fn proficiency_to_level(proficiency: &str) -> u8 {
     let lower = proficiency.to_lowercase();
@@ -805,7 +1111,7 @@ mod tests {
     #[test]
     fn test_parse_linkedin_zip() {
         let zip_data = create_test_zip();
-        let parser = LinkedInParser;
+        let parser = LinkedInParser::default();
 
         let result = parser.parse(&zip_data);
         assert!(result.is_ok(), "Failed to parse: {:?}", result.err());
@@ -814,6 +1120,7 @@ mod tests {
 
         // Check basics
         assert_eq!(resume.basics.name, "John Doe");
+        assert_eq!(resume.basics.name_parts, PersonName::new("John", "Doe"));
         assert_eq!(resume.basics.headline, "Senior Software Engineer");
         assert_eq!(resume.basics.email, "john.doe@example.com");
         assert_eq!(resume.basics.location, "San Francisco Bay Area");
@@ -838,6 +1145,51 @@ mod tests {
         assert_eq!(resume.sections.languages.items.len(), 2);
     }
 
+    #[test]
+    fn test_incremental_entries_match_parse() {
+        let zip_data = create_test_zip();
+        let parser = LinkedInParser::default();
+
+        let mut entries = parser.entries(&zip_data).unwrap();
+        assert_eq!(entries.total(), 6);
+        let mut data = LinkedInData::default();
+        let mut names = Vec::new();
+        for entry in entries.by_ref() {
+            let entry = entry.unwrap();
+            parser.collect_entry(&mut data, &entry).unwrap();
+            names.push(entry.name);
+        }
+        assert_eq!(entries.position(), 6);
+        assert_eq!(names[0], "profile.csv");
+        assert_eq!(names[5], "email addresses.csv");
+
+        let incremental = parser.convert(data).unwrap();
+        let resume = parser.parse(&zip_data).unwrap();
+        assert_eq!(incremental.basics.name, resume.basics.name);
+        assert_eq!(
+            incremental.sections.experience.items.len(),
+            resume.sections.experience.items.len()
+        );
+    }
+
+    #[test]
+    fn test_parse_with_progress_reports_each_file() {
+        let zip_data = create_test_zip();
+        let mut updates = Vec::new();
+
+        let resume = LinkedInParser::default()
+            .parse_with_progress(&zip_data, &mut |p| updates.push(p))
+            .unwrap();
+
+        assert_eq!(resume.basics.name, "John Doe");
+        let files: Vec<_> = updates.iter().filter_map(|p| p.detail.as_deref()).collect();
+        assert_eq!(files.len(), 6);
+        assert_eq!(files[1], "positions.csv");
+        assert!(updates.windows(2).all(|w| w[0].percent <= w[1].percent));
+        assert_eq!(updates.first().unwrap().phase, ParsePhase::Read);
+        assert_eq!(updates.last().unwrap().percent, 100);
+    }
+
     #[test]
     fn test_proficiency_to_level() {
         assert_eq!(proficiency_to_level("Native or Bilingual Proficiency"), 5);
@@ -848,31 +1200,47 @@ mod tests {
     }
 
     #[test]
-    fn test_format_linkedin_date_range() {
-        assert_eq!(
-            format_linkedin_date_range(Some("Jan 2020"), Some("Dec 2022")),
-            "Jan 2020 - Dec 2022"
-        );
+    fn test_generated_text_follows_locale() {
+        let data = || LinkedInData {
+            positions: vec![LinkedInPosition {
+                company_name: "Acme".into(),
+                title: "Engineer".into(),
+                started_on: Some("Jan 2020".into()),
+                ..Default::default()
+            }],
+            skills: vec![LinkedInSkill {
+                name: "Rust".into(),
+            }],
+            ..Default::default()
+        };
+
+        let resume = LinkedInParser::default().convert(data()).unwrap();
+        assert_eq!(resume.metadata.locale, "en");
         assert_eq!(
-            format_linkedin_date_range(Some("Jan 2020"), None),
+            resume.sections.experience.items[0].date,
             "Jan 2020 - Present"
         );
-        assert_eq!(
-            format_linkedin_date_range(None, Some("Dec 2022")),
-            "Dec 2022"
-        );
-        assert_eq!(format_linkedin_date_range(None, None), "");
+
+        let resume = LinkedInParser::default()
+            .with_locale("de")
+            .convert(data())
+            .unwrap();
+        assert_eq!(resume.metadata.locale, "de");
+        assert_eq!(resume.sections.experience.name, "Berufserfahrung");
+        assert_eq!(resume.sections.experience.items[0].date, "Jan 2020 - Heute");
+        assert_eq!(resume.sections.skills.items[0].name, "Kenntnisse");
     }
 
     #[test]
     fn test_zip_size_limit_rejection() {
         // Create a ZIP that's too large (exceeds MAX_ZIP_SIZE)
-        let parser = LinkedInParser;
+        let parser = LinkedInParser::default();
         let oversized_data = vec![0u8; MAX_ZIP_SIZE + 1];
 
         let result = parser.parse(&oversized_data);
         assert!(result.is_err());
         let err = result.unwrap_err();
+        assert!(matches!(err, ParseError::LimitExceeded(_)), "got: {err:?}");
         assert!(
             err.to_string().contains("too large"),
             "Expected 'too large' error, got: {}",
@@ -880,6 +1248,148 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_reader_matches_parse() {
+        let zip_data = create_test_zip();
+        let parser = LinkedInParser::default();
+
+        let streamed = parser.parse_reader(&mut Cursor::new(&zip_data)).unwrap();
+        let resume = parser.parse(&zip_data).unwrap();
+        assert_eq!(streamed.basics.name, resume.basics.name);
+        assert_eq!(
+            streamed.sections.experience.items.len(),
+            resume.sections.experience.items.len()
+        );
+    }
+
+    /// A reader that counts the bytes taken from it.
+    struct Counted<R> {
+        inner: R,
+        read: usize,
+    }
+
+    impl<R: Read> Read for Counted<R> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            let n = self.inner.read(buf)?;
+            self.read += n;
+            Ok(n)
+        }
+    }
+
+    #[test]
+    fn test_streamed_archive_stops_at_size_limit() {
+        let parser = LinkedInParser::with_limits(ZipLimits {
+            max_archive_size: 256,
+            ..ZipLimits::default()
+        });
+
+        // An endless upload fails once it passes the limit instead of being read to its end
+        let mut endless = Cursor::new(create_test_zip()).chain(std::io::repeat(0));
+        let err = parser.read_from(&mut endless).unwrap_err();
+        assert!(matches!(err, ParseError::LimitExceeded(_)), "got: {err:?}");
+        assert!(err.to_string().contains("too large"), "got: {err}");
+
+        let err = parser.read_from(&mut std::io::repeat(0)).unwrap_err();
+        assert!(matches!(err, ParseError::InvalidArchive(_)), "got: {err:?}");
+    }
+
+    #[test]
+    fn test_streamed_entries_stop_at_the_first_over_the_limit() {
+        let mut buffer = Vec::new();
+        {
+            let mut zip = zip::ZipWriter::new(Cursor::new(&mut buffer));
+            let options = zip::write::SimpleFileOptions::default()
+                .compression_method(zip::CompressionMethod::Stored);
+            zip.start_file("Profile.csv", options).unwrap();
+            zip.write_all(b"First Name,Last Name\nJohn,Doe\n").unwrap();
+            zip.start_file("Positions.csv", options).unwrap();
+            zip.write_all(&[b'a'; 4096]).unwrap();
+            zip.start_file("padding.bin", options).unwrap();
+            zip.write_all(&vec![0; 1024 * 1024]).unwrap();
+            zip.finish().unwrap();
+        }
+        let parser = LinkedInParser::with_limits(ZipLimits {
+            max_entry_size: 1024,
+            ..ZipLimits::default()
+        });
+
+        let mut input = Counted {
+            inner: Cursor::new(&buffer),
+            read: 0,
+        };
+        let err = parser.read_from(&mut input).unwrap_err();
+        assert!(matches!(err, ParseError::LimitExceeded(_)), "got: {err:?}");
+        assert!(err.to_string().contains("positions.csv"), "got: {err}");
+        // The megabyte after the oversized entry was never read
+        assert!(input.read < 64 * 1024, "read {} bytes", input.read);
+
+        // Within the limits, entries before and after a skipped file are read
+        let data = LinkedInParser::default()
+            .read_from(&mut Cursor::new(&buffer))
+            .unwrap();
+        assert_eq!(data.profile.unwrap().first_name, "John");
+    }
+
+    #[test]
+    fn test_streamed_archive_checks_the_entry_count() {
+        let mut buffer = Vec::new();
+        {
+            let mut zip = zip::ZipWriter::new(Cursor::new(&mut buffer));
+            let options = zip::write::SimpleFileOptions::default();
+            for i in 0..4 {
+                zip.start_file(format!("file_{}.txt", i), options).unwrap();
+            }
+            zip.finish().unwrap();
+        }
+        let parser = LinkedInParser::with_limits(ZipLimits {
+            max_entries: 3,
+            ..ZipLimits::default()
+        });
+
+        let err = parser.read_from(&mut Cursor::new(&buffer)).unwrap_err();
+        assert!(err.to_string().contains("too many entries"), "got: {err}");
+    }
+
+    #[test]
+    fn test_streamed_archive_rejects_data_descriptors() {
+        let mut buffer = Vec::new();
+        {
+            // Writing to a stream puts each entry's sizes after its data
+            let mut zip = zip::ZipWriter::new_stream(&mut buffer);
+            zip.start_file("Profile.csv", zip::write::SimpleFileOptions::default())
+                .unwrap();
+            zip.write_all(b"First Name,Last Name\nJohn,Doe\n").unwrap();
+            zip.finish().unwrap();
+        }
+        let parser = LinkedInParser::default();
+
+        let err = parser.read_from(&mut Cursor::new(&buffer)).unwrap_err();
+        assert!(matches!(err, ParseError::InvalidArchive(_)), "got: {err:?}");
+        // The central directory still reads it when the archive is in memory
+        assert_eq!(parser.parse(&buffer).unwrap().basics.name, "John Doe");
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_read_async_matches_parse() {
+        let zip_data = create_test_zip();
+        let parser = LinkedInParser::default();
+
+        let data = parser
+            .read_async(Cursor::new(zip_data.clone()))
+            .await
+            .unwrap();
+        let streamed = parser.convert(data).unwrap();
+        assert_eq!(streamed.basics.name, "John Doe");
+
+        let tight = LinkedInParser::with_limits(ZipLimits {
+            max_archive_size: 16,
+            ..ZipLimits::default()
+        });
+        let err = tight.read_async(Cursor::new(zip_data)).await.unwrap_err();
+        assert!(matches!(err, ParseError::LimitExceeded(_)), "got: {err:?}");
+    }
+
     #[test]
     fn test_zip_entry_count_limit_rejection() {
         // Create a ZIP with too many entries (exceeds MAX_LINKEDIN_ENTRIES)
@@ -897,10 +1407,11 @@ mod tests {
             zip.finish().unwrap();
         }
 
-        let parser = LinkedInParser;
+        let parser = LinkedInParser::default();
         let result = parser.parse(&buffer);
         assert!(result.is_err());
         let err = result.unwrap_err();
+        assert!(matches!(err, ParseError::LimitExceeded(_)), "got: {err:?}");
         assert!(
             err.to_string().contains("too many entries"),
             "Expected 'too many entries' error, got: {}",
@@ -927,7 +1438,7 @@ mod tests {
             zip.finish().unwrap();
         }
 
-        let parser = LinkedInParser;
+        let parser = LinkedInParser::default();
         let result = parser.parse(&buffer);
         assert!(result.is_ok(), "Failed to parse: {:?}", result.err());
 
@@ -954,7 +1465,7 @@ mod tests {
             zip.finish().unwrap();
         }
 
-        let parser = LinkedInParser;
+        let parser = LinkedInParser::default();
         // The CSV parser is flexible, so it may still parse but with unexpected values
         // The important thing is that it doesn't panic
         let result = parser.parse(&buffer);
@@ -986,7 +1497,7 @@ mod tests {
             zip.finish().unwrap();
         }
 
-        let parser = LinkedInParser;
+        let parser = LinkedInParser::default();
         let result = parser.parse(&buffer);
         assert!(
             result.is_ok(),
@@ -1018,7 +1529,7 @@ mod tests {
             zip.finish().unwrap();
         }
 
-        let parser = LinkedInParser;
+        let parser = LinkedInParser::default();
         let result = parser.parse(&buffer);
         assert!(result.is_ok(), "Failed to parse: {:?}", result.err());
 
@@ -1033,7 +1544,7 @@ mod tests {
     #[test]
     fn test_invalid_zip_archive() {
         // Test with data that's not a valid ZIP archive
-        let parser = LinkedInParser;
+        let parser = LinkedInParser::default();
         let invalid_data = b"This is not a ZIP file";
 
         let result = parser.parse(invalid_data);
@@ -1044,5 +1555,128 @@ mod tests {
             "Expected ZIP-related error, got: {}",
             err
         );
+        assert_eq!(err.code(), rustume_schema::ErrorCode::ParseLinkedinBadZip);
+    }
+
+    /// Small limits so crafted archives stay cheap to build in tests.
+    fn tight_limits() -> ZipLimits {
+        ZipLimits {
+            max_archive_size: 64 * 1024,
+            max_entry_size: 4 * 1024,
+            max_total_uncompressed: 8 * 1024,
+            max_entries: 8,
+        }
+    }
+
+    /// Build a deflate-compressed archive from `(name, contents)` pairs.
+    fn deflated_zip(entries: &[(&str, Vec<u8>)]) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        {
+            let mut zip = zip::ZipWriter::new(Cursor::new(&mut buffer));
+            let options = zip::write::SimpleFileOptions::default()
+                .compression_method(zip::CompressionMethod::Deflated);
+            for (name, contents) in entries {
+                zip.start_file(*name, options).unwrap();
+                zip.write_all(contents).unwrap();
+            }
+            zip.finish().unwrap();
+        }
+        buffer
+    }
+
+    /// Overwrite the uncompressed size recorded in every local and central header,
+    /// mimicking a bomb that lies about how much it will inflate to.
+    fn forge_declared_sizes(archive: &mut [u8], declared: u32) {
+        const LOCAL_HEADER: [u8; 4] = [0x50, 0x4b, 0x03, 0x04];
+        const CENTRAL_HEADER: [u8; 4] = [0x50, 0x4b, 0x01, 0x02];
+        let bytes = declared.to_le_bytes();
+        let mut i = 0;
+        while i + 4 <= archive.len() {
+            let signature = &archive[i..i + 4];
+            let offset = if signature == LOCAL_HEADER {
+                Some(22)
+            } else if signature == CENTRAL_HEADER {
+                Some(24)
+            } else {
+                None
+            };
+            if let Some(offset) = offset {
+                archive[i + offset..i + offset + 4].copy_from_slice(&bytes);
+            }
+            i += 1;
+        }
+    }
+
+    fn bomb_csv(len: usize) -> Vec<u8> {
+        let mut csv = b"Name\n".to_vec();
+        csv.resize(len, b'a');
+        csv
+    }
+
+    #[test]
+    fn test_zip_bomb_declared_size_rejected() {
+        let archive = deflated_zip(&[("Skills.csv", bomb_csv(64 * 1024))]);
+        assert!(archive.len() < 4 * 1024, "payload should compress well");
+
+        let err = LinkedInParser::with_limits(tight_limits())
+            .parse(&archive)
+            .unwrap_err();
+        assert!(matches!(err, ParseError::LimitExceeded(_)), "got: {err:?}");
+        assert!(err.to_string().contains("skills.csv"), "got: {err}");
+    }
+
+    #[test]
+    fn test_zip_bomb_forged_header_rejected() {
+        let mut archive = deflated_zip(&[("Skills.csv", bomb_csv(64 * 1024))]);
+        forge_declared_sizes(&mut archive, 16);
+
+        let err = LinkedInParser::with_limits(tight_limits())
+            .parse(&archive)
+            .unwrap_err();
+        assert!(matches!(err, ParseError::LimitExceeded(_)), "got: {err:?}");
+        assert!(err.to_string().contains("decompressed past"), "got: {err}");
+    }
+
+    #[test]
+    fn test_zip_total_uncompressed_limit_rejected() {
+        // Each entry fits the per-file limit, but together they exceed the total.
+        let archive = deflated_zip(&[
+            ("Profile.csv", bomb_csv(3 * 1024)),
+            ("Skills.csv", bomb_csv(3 * 1024)),
+            ("Projects.csv", bomb_csv(3 * 1024)),
+        ]);
+
+        let err = LinkedInParser::with_limits(tight_limits())
+            .parse(&archive)
+            .unwrap_err();
+        assert!(matches!(err, ParseError::LimitExceeded(_)), "got: {err:?}");
+        assert!(err.to_string().contains("total uncompressed"), "got: {err}");
+    }
+
+    #[test]
+    fn test_zip_custom_entry_count_limit() {
+        let names: Vec<String> = (0..9).map(|i| format!("extra_{i}.txt")).collect();
+        let entries: Vec<_> = names
+            .iter()
+            .map(|name| (name.as_str(), Vec::new()))
+            .collect();
+        let archive = deflated_zip(&entries);
+
+        let err = LinkedInParser::with_limits(tight_limits())
+            .parse(&archive)
+            .unwrap_err();
+        assert!(matches!(err, ParseError::LimitExceeded(_)), "got: {err:?}");
+        assert!(LinkedInParser::default().parse(&archive).is_ok());
+    }
+
+    #[test]
+    fn test_zip_limits_are_configurable() {
+        let archive = deflated_zip(&[("Skills.csv", bomb_csv(16 * 1024))]);
+
+        assert!(LinkedInParser::with_limits(tight_limits())
+            .parse(&archive)
+            .is_err());
+        assert!(LinkedInParser::default().parse(&archive).is_ok());
+        assert_eq!(LinkedInParser::default().limits(), &ZipLimits::default());
     }
 }
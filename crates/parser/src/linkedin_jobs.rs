@@ -0,0 +1,369 @@
+//! LinkedIn job activity import.
+//!
+//! Reads `Job Applications.csv` and `Saved Jobs.csv` from a LinkedIn data
+//! export (LinkedIn puts them under `Jobs/`, and splits large histories into
+//! `Job Applications_1.csv`, `Job Applications_2.csv`, ...) and turns each row
+//! into a [`JobApplication`] for the tracker.
+
+use std::collections::HashSet;
+
+use rustume_schema::{validate_optional_date, ApplicationStatus, JobApplication};
+
+use crate::linkedin::{for_each_csv_entry, parse_csv_records, record_to_map};
+use crate::traits::ParseError;
+use crate::ZipLimits;
+
+/// Parser for the job application history in a LinkedIn data export.
+///
+/// Rows without both a company and a job title are skipped, since the tracker
+/// requires both.
+#[derive(Debug, Clone, Default)]
+pub struct LinkedInJobsParser {
+    limits: ZipLimits,
+}
+
+impl LinkedInJobsParser {
+    /// Create a parser that enforces the given archive limits.
+    pub fn with_limits(limits: ZipLimits) -> Self {
+        Self { limits }
+    }
+
+    /// The archive limits enforced by this parser.
+    pub fn limits(&self) -> &ZipLimits {
+        &self.limits
+    }
+
+    /// Parse a LinkedIn ZIP export into tracker entries.
+    ///
+    /// Submitted applications come first (status `applied`), followed by saved
+    /// jobs (status `saved`) that were not applied to.
+    pub fn parse(&self, data: &[u8]) -> Result<Vec<JobApplication>, ParseError> {
+        let mut applied = Vec::new();
+        let mut saved = Vec::new();
+
+        for_each_csv_entry(data, &self.limits, |base_name, contents| {
+            if is_export_csv(base_name, "job applications") {
+                applied.extend(self.parse_job_applications_csv(contents)?);
+            } else if is_export_csv(base_name, "saved jobs") {
+                saved.extend(self.parse_saved_jobs_csv(contents)?);
+            }
+            Ok(())
+        })?;
+
+        let applied_keys: HashSet<String> = applied.iter().map(job_key).collect();
+        saved.retain(|job| !applied_keys.contains(&job_key(job)));
+        applied.extend(saved);
+        Ok(applied)
+    }
+
+    /// Parse the contents of `Job Applications.csv`.
+    pub fn parse_job_applications_csv(
+        &self,
+        contents: &str,
+    ) -> Result<Vec<JobApplication>, ParseError> {
+        let mut applications = Vec::new();
+        let (headers, records) = parse_csv_records(contents)?;
+
+        for result in records {
+            let record = result
+                .map_err(|e| ParseError::ReadError(format!("Failed to read CSV record: {}", e)))?;
+
+            let row = record_to_map(&headers, &record);
+            let field = |name: &str| row.get(name).map(|s| s.trim()).unwrap_or_default();
+
+            let Some(application) = new_application(
+                field("company_name"),
+                field("job_title"),
+                field("job_url"),
+                ApplicationStatus::Applied,
+            ) else {
+                continue;
+            };
+
+            let notes: Vec<String> = [
+                ("Resume", field("resume_name")),
+                ("Contact email", field("contact_email")),
+                ("Contact phone", field("contact_phone_number")),
+            ]
+            .into_iter()
+            .filter(|(_, value)| !value.is_empty())
+            .map(|(label, value)| format!("{label}: {value}"))
+            .collect();
+
+            applications.push(
+                application
+                    .with_applied_date(linkedin_job_date(field("application_date")))
+                    .with_notes(notes.join("\n")),
+            );
+        }
+
+        Ok(applications)
+    }
+
+    /// Parse the contents of `Saved Jobs.csv`.
+    pub fn parse_saved_jobs_csv(&self, contents: &str) -> Result<Vec<JobApplication>, ParseError> {
+        let mut jobs = Vec::new();
+        let (headers, records) = parse_csv_records(contents)?;
+
+        for result in records {
+            let record = result
+                .map_err(|e| ParseError::ReadError(format!("Failed to read CSV record: {}", e)))?;
+
+            let row = record_to_map(&headers, &record);
+            let field = |name: &str| row.get(name).map(|s| s.trim()).unwrap_or_default();
+
+            let Some(job) = new_application(
+                field("company_name"),
+                field("job_title"),
+                field("job_url"),
+                ApplicationStatus::Saved,
+            ) else {
+                continue;
+            };
+
+            let saved_on = linkedin_job_date(field("saved_date"));
+            let notes = if saved_on.is_empty() {
+                "Saved on LinkedIn".to_string()
+            } else {
+                format!("Saved on LinkedIn on {saved_on}")
+            };
+            jobs.push(job.with_notes(notes));
+        }
+
+        Ok(jobs)
+    }
+}
+
+fn new_application(
+    company: &str,
+    role: &str,
+    url: &str,
+    status: ApplicationStatus,
+) -> Option<JobApplication> {
+    if company.is_empty() || role.is_empty() {
+        return None;
+    }
+
+    let mut application = JobApplication::new(company, role).with_status(status);
+    if url.starts_with("https://") || url.starts_with("http://") {
+        application = application.with_url(url);
+    }
+    Some(application)
+}
+
+/// Match `<stem>.csv` and LinkedIn's numbered splits (`<stem>_1.csv`).
+fn is_export_csv(base_name: &str, stem: &str) -> bool {
+    let Some(rest) = base_name
+        .strip_prefix(stem)
+        .and_then(|rest| rest.strip_suffix(".csv"))
+    else {
+        return false;
+    };
+    rest.is_empty()
+        || rest
+            .strip_prefix('_')
+            .is_some_and(|n| !n.is_empty() && n.bytes().all(|b| b.is_ascii_digit()))
+}
+
+/// Identity of a posting: the LinkedIn job ID when the URL has one, otherwise
+/// the company and title.
+fn job_key(job: &JobApplication) -> String {
+    let href = job.url.href.to_lowercase();
+    if let Some((_, rest)) = href.split_once("/jobs/view/") {
+        let id: String = rest.chars().take_while(char::is_ascii_digit).collect();
+        if !id.is_empty() {
+            return id;
+        }
+    }
+    format!(
+        "{}\u{1f}{}",
+        job.company.to_lowercase(),
+        job.role.to_lowercase()
+    )
+}
+
+/// Convert a LinkedIn export timestamp to `YYYY-MM-DD`.
+///
+/// LinkedIn writes US-style dates with an optional time (`11/8/23, 9:03 PM`);
+/// ISO dates are accepted as well. Anything else yields an empty string.
+fn linkedin_job_date(value: &str) -> String {
+    let date = value
+        .split([',', ' ', 'T'])
+        .next()
+        .unwrap_or_default()
+        .trim();
+
+    let iso = if date.contains('-') {
+        date.to_string()
+    } else {
+        let mut parts = date.split('/');
+        let (Some(month), Some(day), Some(year), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return String::new();
+        };
+        let (Ok(month), Ok(day), Ok(year)) = (
+            month.parse::<u32>(),
+            day.parse::<u32>(),
+            year.parse::<u32>(),
+        ) else {
+            return String::new();
+        };
+        let year = match year {
+            0..=99 => 2000 + year,
+            1000..=9999 => year,
+            _ => return String::new(),
+        };
+        format!("{year:04}-{month:02}-{day:02}")
+    };
+
+    if validate_optional_date(&iso).is_ok() {
+        iso
+    } else {
+        String::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Cursor, Write};
+    use validator::Validate;
+
+    const APPLICATIONS_CSV: &str = "\
+Application Date,Contact Email,Contact Phone Number,Company Name,Job Title,Job Url,Resume Name,Question And Answers
+\"11/8/23, 9:03 PM\",me@example.com,,Acme Corp,Staff Engineer,https://www.linkedin.com/jobs/view/3741234567,Resume-2023.pdf,
+\"1/15/24, 10:12 AM\",,,,Engineer,https://www.linkedin.com/jobs/view/3800000000,,
+";
+
+    const SAVED_JOBS_CSV: &str = "\
+Saved Date,Job Url,Job Title,Company Name
+\"11/1/23, 8:00 AM\",http://www.linkedin.com/jobs/view/3741234567/,Staff Engineer,Acme Corp
+\"12/24/23, 6:30 PM\",http://www.linkedin.com/jobs/view/3799999999/,Platform Lead,Globex
+";
+
+    fn zip_with(entries: &[(&str, &str)]) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        {
+            let mut zip = zip::ZipWriter::new(Cursor::new(&mut buffer));
+            let options = zip::write::SimpleFileOptions::default()
+                .compression_method(zip::CompressionMethod::Stored);
+            for (name, contents) in entries {
+                zip.start_file(*name, options).unwrap();
+                zip.write_all(contents.as_bytes()).unwrap();
+            }
+            zip.finish().unwrap();
+        }
+        buffer
+    }
+
+    #[test]
+    fn test_parse_job_applications_csv() {
+        let applications = LinkedInJobsParser::default()
+            .parse_job_applications_csv(APPLICATIONS_CSV)
+            .unwrap();
+
+        // The second row has no company and is skipped.
+        assert_eq!(applications.len(), 1);
+        let acme = &applications[0];
+        assert_eq!(acme.company, "Acme Corp");
+        assert_eq!(acme.role, "Staff Engineer");
+        assert_eq!(acme.status, ApplicationStatus::Applied);
+        assert_eq!(acme.applied_date, "2023-11-08");
+        assert_eq!(
+            acme.url.href,
+            "https://www.linkedin.com/jobs/view/3741234567"
+        );
+        assert_eq!(
+            acme.notes,
+            "Resume: Resume-2023.pdf\nContact email: me@example.com"
+        );
+        assert!(acme.validate().is_ok());
+    }
+
+    #[test]
+    fn test_parse_saved_jobs_csv() {
+        let jobs = LinkedInJobsParser::default()
+            .parse_saved_jobs_csv(SAVED_JOBS_CSV)
+            .unwrap();
+
+        assert_eq!(jobs.len(), 2);
+        assert!(jobs
+            .iter()
+            .all(|job| job.status == ApplicationStatus::Saved));
+        assert!(jobs.iter().all(|job| job.applied_date.is_empty()));
+        assert_eq!(jobs[1].notes, "Saved on LinkedIn on 2023-12-24");
+    }
+
+    #[test]
+    fn test_parse_export_skips_saved_jobs_already_applied_to() {
+        let zip = zip_with(&[
+            ("Profile.csv", "First Name,Last Name\nJane,Doe\n"),
+            ("Jobs/Saved Jobs.csv", SAVED_JOBS_CSV),
+            ("Jobs/Job Applications.csv", APPLICATIONS_CSV),
+            (
+                "Jobs/Job Applications_1.csv",
+                "Application Date,Company Name,Job Title\n2022-03-04,Initech,Analyst\n",
+            ),
+        ]);
+
+        let jobs = LinkedInJobsParser::default().parse(&zip).unwrap();
+        let summary: Vec<(&str, ApplicationStatus)> = jobs
+            .iter()
+            .map(|job| (job.company.as_str(), job.status))
+            .collect();
+
+        assert_eq!(
+            summary,
+            vec![
+                ("Acme Corp", ApplicationStatus::Applied),
+                ("Initech", ApplicationStatus::Applied),
+                ("Globex", ApplicationStatus::Saved),
+            ]
+        );
+        assert_eq!(jobs[1].applied_date, "2022-03-04");
+    }
+
+    #[test]
+    fn test_parse_export_without_job_files() {
+        let zip = zip_with(&[("Profile.csv", "First Name,Last Name\nJane,Doe\n")]);
+        assert!(LinkedInJobsParser::default()
+            .parse(&zip)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_parse_export_enforces_limits() {
+        let zip = zip_with(&[("Jobs/Saved Jobs.csv", SAVED_JOBS_CSV)]);
+        let parser = LinkedInJobsParser::with_limits(ZipLimits {
+            max_archive_size: 16,
+            ..ZipLimits::default()
+        });
+        assert!(matches!(
+            parser.parse(&zip),
+            Err(ParseError::LimitExceeded(_))
+        ));
+    }
+
+    #[test]
+    fn test_linkedin_job_date() {
+        assert_eq!(linkedin_job_date("11/8/23, 9:03 PM"), "2023-11-08");
+        assert_eq!(linkedin_job_date("2/29/2024"), "2024-02-29");
+        assert_eq!(linkedin_job_date("2024-05-01 10:00:00 UTC"), "2024-05-01");
+        assert_eq!(linkedin_job_date("2/30/23"), "");
+        assert_eq!(linkedin_job_date("last week"), "");
+        assert_eq!(linkedin_job_date(""), "");
+    }
+
+    #[test]
+    fn test_is_export_csv() {
+        assert!(is_export_csv("saved jobs.csv", "saved jobs"));
+        assert!(is_export_csv("job applications_12.csv", "job applications"));
+        assert!(!is_export_csv("job applications_.csv", "job applications"));
+        assert!(!is_export_csv(
+            "job applications backup.csv",
+            "job applications"
+        ));
+    }
+}
@@ -0,0 +1,717 @@
+//! One import path for every entry point.
+//!
+//! The CLI, the server, and the WASM bindings all import through
+//! [`ImportPipeline`], so a file becomes the same resume wherever it is
+//! uploaded. A run goes through these steps:
+//!
+//! 1. **Detect** the format from the data, unless the caller names one (see
+//!    [`detect_format`](crate::detect_format)).
+//! 2. **Parse** it into the Rustume schema. Guesses the plain text parser
+//!    makes with low confidence are reported as warnings, and keys of
+//!    renamed fields in Rustume JSON as deprecations.
+//! 3. **Normalize dates.** Display dates the importer can read are rewritten
+//!    in one style (`Mar 2020 - Present`, in the resume's locale), and
+//!    experience, education, and project items get structured
+//!    `startDate`/`endDate`. Dates it can't read are kept as written and
+//!    reported.
+//! 4. **Dedupe.** Items repeated verbatim within a section are folded into
+//!    the first copy; near-duplicates are only reported, for the user to
+//!    merge or keep.
+//! 5. **Assign IDs** by the chosen [`IdStrategy`].
+//! 6. **Validate.** Structural problems are fixed with
+//!    [`ResumeData::repair`], then the field checks and rule errors run. A
+//!    strict import fails on an error; a lenient one reports it.
+//!
+//! Every change and finding ends up in the [`ImportReport`]. Callers that
+//! parse on their own, like the streaming LinkedIn upload, hand the resume
+//! to [`ImportPipeline::finish`] for steps 3 to 6.
+
+use std::collections::HashSet;
+
+use rustume_schema::{
+    canonical_json, find_duplicates, merge_items, DuplicateCandidate, FieldDeprecation, RepairFix,
+    ResumeData, Severity, ValidationIssue, DEFAULT_SIMILARITY,
+};
+use rustume_utils::{display_date_range_in, iso_date_range, parse_iso_partial_date, Locale};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::detect::detect_resume;
+use crate::{
+    parse_resume_with_progress, parse_rustume, ParseError, ParsePhase, ParseProgress, ResumeFormat,
+    TextResumeParser,
+};
+
+/// Validation errors named in the message of a failed strict import.
+const MAX_LISTED_ERRORS: usize = 3;
+
+/// How imported items get their IDs.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum IdStrategy {
+    /// Fresh random IDs, as the parsers assign them.
+    #[default]
+    Random,
+    /// IDs derived from each item's section and content, so importing the
+    /// same file again gives every item the same ID.
+    Stable,
+}
+
+/// Options of an [`ImportPipeline`]. The defaults detect the format, keep
+/// the locale the parser set, assign random IDs, and fail on invalid
+/// resumes.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ImportOptions {
+    /// Format to parse the input as; detected from the data when `None`.
+    pub format: Option<ResumeFormat>,
+    /// Language of the resume as a BCP 47 tag (`de`, `pt-BR`), stored as
+    /// `metadata.locale` and used to write normalized dates.
+    pub locale: Option<String>,
+    /// How items get their IDs.
+    pub ids: IdStrategy,
+    /// Keep a resume that fails validation and report its errors, instead
+    /// of failing the import.
+    pub lenient: bool,
+}
+
+/// What an import did on the way from the input to the resume.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportReport {
+    /// Format the input was parsed as.
+    pub format: ResumeFormat,
+    /// How sure the format detection was, from 0 to 1; 1 for a named format.
+    pub confidence: f32,
+    /// Changes made after parsing: `normalized_date`, `duplicate_item`, and
+    /// the fixes of [`ResumeData::repair`].
+    pub changes: Vec<RepairFix>,
+    /// Items that look alike but differ, left for the user to merge.
+    pub duplicates: Vec<DuplicateCandidate>,
+    /// Warnings (`low_confidence`, `unrecognized_date`) and, for lenient
+    /// imports, the validation errors the resume still has.
+    pub issues: Vec<ValidationIssue>,
+    /// Keys of renamed fields the input still uses, read into their
+    /// current fields.
+    pub deprecations: Vec<FieldDeprecation>,
+}
+
+impl ImportReport {
+    fn new(format: ResumeFormat, confidence: f32) -> Self {
+        Self {
+            format,
+            confidence,
+            changes: Vec::new(),
+            duplicates: Vec::new(),
+            issues: Vec::new(),
+            deprecations: Vec::new(),
+        }
+    }
+}
+
+/// An imported resume and its report.
+#[derive(Debug, Clone, Serialize)]
+pub struct Imported {
+    pub resume: ResumeData,
+    pub report: ImportReport,
+}
+
+/// Detect, parse, and post-process an import the same way for every caller.
+/// See the [module docs](self) for the steps.
+#[derive(Debug, Clone, Default)]
+pub struct ImportPipeline {
+    options: ImportOptions,
+}
+
+impl ImportPipeline {
+    pub fn new(options: ImportOptions) -> Self {
+        Self { options }
+    }
+
+    pub fn options(&self) -> &ImportOptions {
+        &self.options
+    }
+
+    /// Import `data`.
+    pub fn run(&self, data: &[u8]) -> Result<Imported, ParseError> {
+        self.run_with_progress(data, &mut |_| {})
+    }
+
+    /// [`Self::run`], calling `progress` as the parser works through `data`.
+    pub fn run_with_progress(
+        &self,
+        data: &[u8],
+        progress: &mut dyn FnMut(ParseProgress),
+    ) -> Result<Imported, ParseError> {
+        let mut report = match self.options.format {
+            Some(format) => ImportReport::new(format, 1.0),
+            None => {
+                let (format, confidence) = detect_resume(data)?;
+                ImportReport::new(format, confidence)
+            }
+        };
+
+        let resume = match report.format {
+            ResumeFormat::Text => {
+                let draft = TextResumeParser.draft(data)?;
+                report.issues.extend(draft.uncertain().map(|field| {
+                    ValidationIssue::new(
+                        Severity::Warning,
+                        field.path.clone(),
+                        "low_confidence",
+                        field.reason.clone(),
+                    )
+                }));
+                draft.resume
+            }
+            ResumeFormat::Rustume => {
+                let (resume, deprecations) = parse_rustume(data)?;
+                progress(ParseProgress::new(ParsePhase::Convert, 100));
+                report.deprecations = deprecations;
+                resume
+            }
+            format => parse_resume_with_progress(format, data, progress)?,
+        };
+
+        self.process(resume, report)
+    }
+
+    /// Run the steps after parsing on a resume the caller parsed as
+    /// `format`.
+    pub fn finish(&self, format: ResumeFormat, resume: ResumeData) -> Result<Imported, ParseError> {
+        self.process(resume, ImportReport::new(format, 1.0))
+    }
+
+    fn process(
+        &self,
+        mut resume: ResumeData,
+        mut report: ImportReport,
+    ) -> Result<Imported, ParseError> {
+        if let Some(locale) = &self.options.locale {
+            resume.metadata.locale = locale.clone();
+        }
+        normalize_dates(&mut resume, &mut report);
+        dedupe(&mut resume, &mut report);
+        if self.options.ids == IdStrategy::Stable {
+            assign_stable_ids(&mut resume);
+        }
+
+        report.changes.extend(resume.repair().fixes);
+        let errors: Vec<_> = resume
+            .issues()
+            .into_iter()
+            .filter(|issue| issue.severity == Severity::Error)
+            .collect();
+        if !errors.is_empty() && !self.options.lenient {
+            return Err(ParseError::InvalidResume(describe(&errors)));
+        }
+        report.issues.extend(errors);
+
+        Ok(Imported { resume, report })
+    }
+}
+
+fn describe(errors: &[ValidationIssue]) -> String {
+    let mut message = errors
+        .iter()
+        .take(MAX_LISTED_ERRORS)
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("; ");
+    if errors.len() > MAX_LISTED_ERRORS {
+        message.push_str(&format!(" (and {} more)", errors.len() - MAX_LISTED_ERRORS));
+    }
+    message
+}
+
+// ============================================================================
+// Dates
+// ============================================================================
+
+fn normalize_dates(resume: &mut ResumeData, report: &mut ImportReport) {
+    let locale = Locale::for_tag(&resume.metadata.locale);
+    let sections = &mut resume.sections;
+
+    for (i, item) in sections.experience.items.iter_mut().enumerate() {
+        item.sync_dates_in(locale);
+        let path = date_path("experience", i);
+        normalize_range(
+            &mut item.date,
+            &item.start_date,
+            &item.end_date,
+            locale,
+            path,
+            report,
+        );
+    }
+    for (i, item) in sections.education.items.iter_mut().enumerate() {
+        item.sync_dates_in(locale);
+        let path = date_path("education", i);
+        normalize_range(
+            &mut item.date,
+            &item.start_date,
+            &item.end_date,
+            locale,
+            path,
+            report,
+        );
+    }
+    for (i, item) in sections.projects.items.iter_mut().enumerate() {
+        item.sync_dates_in(locale);
+        let path = date_path("projects", i);
+        normalize_range(
+            &mut item.date,
+            &item.start_date,
+            &item.end_date,
+            locale,
+            path,
+            report,
+        );
+    }
+
+    for (i, item) in sections.awards.items.iter_mut().enumerate() {
+        normalize_date(&mut item.date, locale, date_path("awards", i), report);
+    }
+    for (i, item) in sections.certifications.items.iter_mut().enumerate() {
+        normalize_date(
+            &mut item.date,
+            locale,
+            date_path("certifications", i),
+            report,
+        );
+    }
+    for (i, item) in sections.publications.items.iter_mut().enumerate() {
+        normalize_date(&mut item.date, locale, date_path("publications", i), report);
+    }
+    for (i, item) in sections.volunteer.items.iter_mut().enumerate() {
+        normalize_date(&mut item.date, locale, date_path("volunteer", i), report);
+    }
+
+    let mut custom: Vec<_> = sections.custom.iter_mut().collect();
+    custom.sort_by_key(|(id, _)| id.as_str());
+    for (id, section) in custom {
+        for (i, item) in section.items.iter_mut().enumerate() {
+            let path = date_path(&format!("custom.{id}"), i);
+            normalize_date(&mut item.date, locale, path, report);
+        }
+    }
+}
+
+fn date_path(section: &str, index: usize) -> String {
+    format!("sections.{section}.items[{index}].date")
+}
+
+/// Rewrite the display `date` of an item with structured dates from them.
+/// A display date the importer can't read next to structured dates is a
+/// wording the user chose ("Summer 2020") and is left alone.
+fn normalize_range(
+    date: &mut String,
+    start: &Option<String>,
+    end: &Option<String>,
+    locale: &Locale,
+    path: String,
+    report: &mut ImportReport,
+) {
+    let start = start
+        .as_deref()
+        .filter(|start| parse_iso_partial_date(start).is_some());
+    match start {
+        Some(start) if date.trim().is_empty() || iso_date_range(date).is_some() => {
+            let display = display_date_range_in(start, end.as_deref(), locale);
+            set_date(date, display, path, report);
+        }
+        Some(_) => {}
+        // Syncing found no structured dates in the display date
+        None => unrecognized_date(date, path, report),
+    }
+}
+
+/// Rewrite a display date that has no structured counterpart.
+fn normalize_date(date: &mut String, locale: &Locale, path: String, report: &mut ImportReport) {
+    match iso_date_range(date) {
+        Some((start, end)) => {
+            let display = display_date_range_in(&start, end.as_deref(), locale);
+            set_date(date, display, path, report);
+        }
+        None => unrecognized_date(date, path, report),
+    }
+}
+
+fn set_date(date: &mut String, display: String, path: String, report: &mut ImportReport) {
+    if *date == display {
+        return;
+    }
+    let message = if date.trim().is_empty() {
+        format!("Filled in \"{display}\" from the structured dates")
+    } else {
+        format!("Rewrote \"{date}\" as \"{display}\"")
+    };
+    report.changes.push(RepairFix {
+        path,
+        code: "normalized_date".to_string(),
+        message,
+    });
+    *date = display;
+}
+
+fn unrecognized_date(date: &str, path: String, report: &mut ImportReport) {
+    if date.trim().is_empty() {
+        return;
+    }
+    report.issues.push(ValidationIssue::new(
+        Severity::Warning,
+        path,
+        "unrecognized_date",
+        format!("\"{date}\" is not a date the importer can read; it was kept as written"),
+    ));
+}
+
+// ============================================================================
+// Duplicates and IDs
+// ============================================================================
+
+/// Fold items repeated verbatim into their first copy, and report the pairs
+/// that only look alike.
+fn dedupe(resume: &mut ResumeData, report: &mut ImportReport) {
+    let mut removed = HashSet::new();
+    for candidate in find_duplicates(resume, DEFAULT_SIMILARITY) {
+        if removed.contains(&candidate.keep_id) || removed.contains(&candidate.duplicate_id) {
+            continue;
+        }
+        if !is_verbatim_copy(resume, &candidate) {
+            report.duplicates.push(candidate);
+            continue;
+        }
+        let merged = merge_items(
+            resume,
+            &candidate.section,
+            &candidate.keep_id,
+            &candidate.duplicate_id,
+        );
+        if merged.is_ok() {
+            report.changes.push(RepairFix {
+                path: format!("sections.{}", candidate.section),
+                code: "duplicate_item".to_string(),
+                message: format!("Dropped a repeat of {}", candidate.keep_label),
+            });
+            removed.insert(candidate.duplicate_id);
+        }
+    }
+}
+
+/// Whether the two items of `candidate` are the same apart from their IDs.
+fn is_verbatim_copy(resume: &ResumeData, candidate: &DuplicateCandidate) -> bool {
+    let Ok(sections) = serde_json::to_value(&resume.sections) else {
+        return false;
+    };
+    let items = match candidate.section.strip_prefix("custom.") {
+        Some(id) => &sections["custom"][id]["items"],
+        None => &sections[candidate.section.as_str()]["items"],
+    };
+    let content = |id: &str| {
+        let mut item = items
+            .as_array()?
+            .iter()
+            .find(|item| item["id"] == id)?
+            .clone();
+        item.as_object_mut()?.remove("id");
+        Some(item)
+    };
+    match (
+        content(&candidate.keep_id),
+        content(&candidate.duplicate_id),
+    ) {
+        (Some(keep), Some(duplicate)) => keep == duplicate,
+        _ => false,
+    }
+}
+
+/// Replace every item ID with one derived from the item's section and
+/// content. Custom sections are told apart by name, as their keys are
+/// random too.
+fn assign_stable_ids(resume: &mut ResumeData) {
+    let Ok(mut sections) = serde_json::to_value(&resume.sections) else {
+        return;
+    };
+    let mut seen = HashSet::new();
+    if let Some(sections) = sections.as_object_mut() {
+        for (key, section) in sections.iter_mut() {
+            if key != "custom" {
+                stable_item_ids(key, section, &mut seen);
+                continue;
+            }
+            for section in section
+                .as_object_mut()
+                .into_iter()
+                .flat_map(|c| c.values_mut())
+            {
+                let scope = format!("custom:{}", section["name"].as_str().unwrap_or_default());
+                stable_item_ids(&scope, section, &mut seen);
+            }
+        }
+    }
+    if let Ok(sections) = serde_json::from_value(sections) {
+        resume.sections = sections;
+    }
+}
+
+fn stable_item_ids(scope: &str, section: &mut Value, seen: &mut HashSet<String>) {
+    let Some(items) = section.get_mut("items").and_then(Value::as_array_mut) else {
+        return;
+    };
+    for item in items {
+        let Some(object) = item.as_object_mut() else {
+            continue;
+        };
+        object.remove("id");
+        let content = canonical_json(item);
+        // Identical items in one section get the next free ID in order
+        let id = (0u32..)
+            .map(|n| format!("i{:016x}", fnv1a(&[scope, &content, &n.to_string()])))
+            .find(|id| seen.insert(id.clone()))
+            .expect("an unused ID");
+        item["id"] = Value::String(id);
+    }
+}
+
+/// 64-bit FNV-1a over `parts`, each followed by a zero byte. Unlike the
+/// std hashers, its output is fixed across Rust versions and platforms.
+fn fnv1a(parts: &[&str]) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for part in parts {
+        for byte in part.bytes().chain([0]) {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::path::PathBuf;
+
+    fn fixture(path: &str) -> Vec<u8> {
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("../../tests/fixtures")
+            .join(path);
+        fs::read(&path).unwrap_or_else(|err| panic!("read {}: {err}", path.display()))
+    }
+
+    fn lenient() -> ImportPipeline {
+        ImportPipeline::new(ImportOptions {
+            lenient: true,
+            ..ImportOptions::default()
+        })
+    }
+
+    #[test]
+    fn imports_fixtures_strictly() {
+        let fixtures = [
+            ("json_resume/full.json", ResumeFormat::JsonResume),
+            ("json_resume/minimal.json", ResumeFormat::JsonResume),
+            ("v3/complete.json", ResumeFormat::Rrv3),
+            #[cfg(feature = "linkedin")]
+            ("linkedin/complete_export.zip", ResumeFormat::LinkedIn),
+            ("html/hresume.html", ResumeFormat::Html),
+            ("vcard/contact.vcf", ResumeFormat::Vcard),
+            ("stack_overflow/export.xml", ResumeFormat::StackOverflow),
+        ];
+        for (path, format) in fixtures {
+            let imported = ImportPipeline::default()
+                .run(&fixture(path))
+                .unwrap_or_else(|err| panic!("{path}: {err}"));
+            assert_eq!(imported.report.format, format, "{path}");
+            assert!(imported.report.confidence > 0.5, "{path}");
+        }
+    }
+
+    #[test]
+    fn plain_text_has_to_be_named_and_reports_guesses() {
+        let text = fixture("text/resume.txt");
+        let err = ImportPipeline::default().run(&text).unwrap_err();
+        assert!(err.to_string().contains("could not detect"), "{err}");
+
+        let pipeline = ImportPipeline::new(ImportOptions {
+            format: Some(ResumeFormat::Text),
+            ..ImportOptions::default()
+        });
+        pipeline.run(&text).unwrap();
+        let imported = pipeline
+            .run(b"jane doe\n\nExperience\nPlumbing Plus\nWebmaster\n")
+            .unwrap();
+        assert_eq!(imported.report.confidence, 1.0);
+        assert!(imported
+            .report
+            .issues
+            .iter()
+            .any(|issue| issue.code == "low_confidence" && issue.path == "basics.name"));
+    }
+
+    #[test]
+    fn normalizes_dates_in_the_locale() {
+        let json = br#"{"basics":{"name":"A","label":"Dev"},"work":[
+            {"name":"Acme","position":"Dev","startDate":"2019-04"},
+            {"name":"Initech","position":"Dev","startDate":"2016-01","endDate":"2019-03"}
+        ],"awards":[{"title":"Prize","date":"2021-05-03"}]}"#;
+        let pipeline = ImportPipeline::new(ImportOptions {
+            locale: Some("de".to_string()),
+            ..ImportOptions::default()
+        });
+        let imported = pipeline.run(json).unwrap();
+        let resume = &imported.resume;
+
+        assert_eq!(resume.metadata.locale, "de");
+        let dates: Vec<_> = resume
+            .sections
+            .experience
+            .items
+            .iter()
+            .map(|item| item.date.as_str())
+            .collect();
+        assert_eq!(dates, ["Apr 2019 - Heute", "Jan 2016 - Mar 2019"]);
+        assert_eq!(resume.sections.awards.items[0].date, "May 2021");
+        assert!(imported
+            .report
+            .changes
+            .iter()
+            .any(|change| change.code == "normalized_date"
+                && change.path == "sections.awards.items[0].date"));
+    }
+
+    #[test]
+    fn keeps_and_reports_unreadable_dates() {
+        let json = br#"{"basics":{"name":"A","label":"Dev"},
+            "certificates":[{"name":"CKA","date":"next spring"}]}"#;
+        let imported = lenient().run(json).unwrap();
+
+        assert_eq!(
+            imported.resume.sections.certifications.items[0].date,
+            "next spring"
+        );
+        let issue = imported
+            .report
+            .issues
+            .iter()
+            .find(|issue| issue.code == "unrecognized_date")
+            .expect("a date warning");
+        assert_eq!(issue.path, "sections.certifications.items[0].date");
+        assert_eq!(issue.severity, Severity::Warning);
+    }
+
+    #[test]
+    fn folds_verbatim_copies_and_reports_near_duplicates() {
+        let json = br#"{"basics":{"name":"A","label":"Dev"},"skills":[
+            {"name":"Rust","level":"Expert"},
+            {"name":"Rust","level":"Expert"},
+            {"name":"rust","level":"Beginner"}
+        ]}"#;
+        let imported = ImportPipeline::default().run(json).unwrap();
+
+        let skills = &imported.resume.sections.skills.items;
+        assert_eq!(skills.len(), 2);
+        assert!(imported
+            .report
+            .changes
+            .iter()
+            .any(|change| change.code == "duplicate_item" && change.path == "sections.skills"));
+        assert_eq!(imported.report.duplicates.len(), 1);
+        assert_eq!(imported.report.duplicates[0].keep_id, skills[0].id);
+        assert_eq!(imported.report.duplicates[0].duplicate_id, skills[1].id);
+    }
+
+    #[test]
+    fn stable_ids_repeat_across_imports() {
+        let json = fixture("json_resume/full.json");
+        let pipeline = ImportPipeline::new(ImportOptions {
+            ids: IdStrategy::Stable,
+            ..ImportOptions::default()
+        });
+        let ids = |resume: &ResumeData| {
+            resume
+                .sections
+                .experience
+                .items
+                .iter()
+                .map(|item| item.id.clone())
+                .collect::<Vec<_>>()
+        };
+
+        let first = pipeline.run(&json).unwrap().resume;
+        let second = pipeline.run(&json).unwrap().resume;
+        assert!(!ids(&first).is_empty());
+        assert_eq!(ids(&first), ids(&second));
+        assert!(first.same_content(&second));
+
+        let random = ImportPipeline::default().run(&json).unwrap().resume;
+        assert_ne!(ids(&random), ids(&first));
+    }
+
+    #[test]
+    fn strict_imports_fail_on_invalid_resumes() {
+        let json = br#"{"basics":{"name":"A","label":"Dev"},"work":[
+            {"name":"Acme","position":"Dev","startDate":"2022-01","endDate":"2019-01"}
+        ]}"#;
+        let err = ImportPipeline::default().run(json).unwrap_err();
+        assert!(matches!(err, ParseError::InvalidResume(_)), "{err}");
+        assert!(
+            err.to_string().contains("sections.experience.items[0]"),
+            "{err}"
+        );
+
+        let imported = lenient().run(json).unwrap();
+        assert!(imported
+            .report
+            .issues
+            .iter()
+            .any(|issue| issue.severity == Severity::Error));
+    }
+
+    #[test]
+    fn reports_renamed_fields_in_rustume_json() {
+        let mut json =
+            serde_json::to_value(ResumeData::with_basics("Ada", "ada@example.com")).unwrap();
+        json["sections"]["languages"]["items"] = serde_json::json!([
+            { "id": "l1", "name": "German", "fluency": "Native", "level": 5 },
+        ]);
+        let data = serde_json::to_vec(&json).unwrap();
+
+        let imported = ImportPipeline::default().run(&data).unwrap();
+        assert_eq!(imported.report.format, ResumeFormat::Rustume);
+        assert_eq!(
+            imported.resume.sections.languages.items[0].description,
+            "Native"
+        );
+        assert_eq!(
+            imported.report.deprecations[0].to_string(),
+            "Language: `fluency` is deprecated, use `description`"
+        );
+
+        let report = serde_json::to_value(&imported.report).unwrap();
+        assert_eq!(report["deprecations"][0]["old"], "fluency");
+    }
+
+    #[test]
+    fn finish_post_processes_a_parsed_resume() {
+        let mut resume = ResumeData::default();
+        resume.metadata.locale = "en".to_string();
+        let imported = ImportPipeline::default()
+            .finish(ResumeFormat::Rustume, resume)
+            .unwrap();
+        assert_eq!(imported.report.format, ResumeFormat::Rustume);
+        assert_eq!(imported.report.confidence, 1.0);
+    }
+
+    #[test]
+    fn options_read_from_json() {
+        let options: ImportOptions =
+            serde_json::from_str(r#"{"format":"stack-overflow","ids":"stable","lenient":true}"#)
+                .unwrap();
+        assert_eq!(options.format, Some(ResumeFormat::StackOverflow));
+        assert_eq!(options.ids, IdStrategy::Stable);
+        assert!(options.lenient);
+        assert_eq!(options.locale, None);
+    }
+}
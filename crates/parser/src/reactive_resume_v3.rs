@@ -13,10 +13,12 @@
 use crate::traits::{ParseError, Parser};
 use rustume_schema::{
     validate_hex_color_with_optional_alpha, Award, Basics, Certification, CustomCss, CustomField,
-    CustomItem, Education, Experience, FontConfig, Interest, Language, LevelDisplay, Metadata,
-    PageConfig, PageFormat, PageOptions, Profile, Project, Publication, Reference, ResumeData,
-    Section, Skill, SummarySection, Theme, Typography, Url, Volunteer,
+    CustomFieldKind, CustomItem, Education, EmojiPolicy, Experience, FontConfig, Interest,
+    Language, Layout, LevelDisplay, Metadata, PageConfig, PageFormat, PageOptions, Profile,
+    Project, Publication, Reference, ResumeData, Section, Skill, SummarySection, Theme, Typography,
+    Url, Volunteer,
 };
+use rustume_utils::DEFAULT_LOCALE;
 use serde::Deserialize;
 use std::collections::HashMap;
 
@@ -501,6 +503,7 @@ impl Parser for ReactiveResumeV3Parser {
 
         // Convert basics
         resume.basics = convert_basics(&data.basics);
+        resume.basics.guess_name_parts(&resume.metadata.locale);
 
         // Convert summary
         resume.sections.summary = SummarySection {
@@ -536,6 +539,7 @@ impl Parser for ReactiveResumeV3Parser {
         // Convert metadata
         resume.metadata = convert_metadata(&data.metadata);
 
+        resume.sync_dates();
         Ok(resume)
     }
 }
@@ -622,6 +626,7 @@ fn convert_basics(v3: &V3Basics) -> Basics {
             icon: cf.icon.clone().unwrap_or_default(),
             name: cf.name.clone().unwrap_or_default(),
             value: cf.value.clone().unwrap_or_default(),
+            kind: CustomFieldKind::Text,
         })
         .collect();
 
@@ -1026,7 +1031,11 @@ fn convert_custom_sections(
 fn convert_metadata(v3: &V3Metadata) -> Metadata {
     Metadata {
         template: v3.template.clone().unwrap_or_else(|| "rhyhorn".to_string()),
-        layout: v3.layout.clone().unwrap_or_default(),
+        layout: v3
+            .layout
+            .clone()
+            .map(Layout::from)
+            .unwrap_or_else(Layout::empty),
         css: CustomCss {
             value: v3
                 .css
@@ -1061,7 +1070,9 @@ fn convert_metadata(v3: &V3Metadata) -> Metadata {
                     .as_ref()
                     .and_then(|o| o.page_numbers)
                     .unwrap_or(true),
+                ..PageOptions::default()
             },
+            backgrounds: Vec::new(),
         },
         theme: Theme {
             primary: v3
@@ -1079,6 +1090,7 @@ fn convert_metadata(v3: &V3Metadata) -> Metadata {
                 .text
                 .clone()
                 .unwrap_or_else(|| "#000000".to_string()),
+            preset: None,
         },
         typography: Typography {
             font: FontConfig {
@@ -1110,9 +1122,19 @@ fn convert_metadata(v3: &V3Metadata) -> Metadata {
             line_height: v3.typography.line_height.unwrap_or(1.5),
             hide_icons: v3.typography.hide_icons.unwrap_or(false),
             underline_links: v3.typography.underline_links.unwrap_or(true),
+            ..Typography::default()
         },
         notes: String::new(),
         level_display: LevelDisplay::TemplateDefault,
+        section_display: Default::default(),
+        show_durations: false,
+        show_cefr: false,
+        locale: v3
+            .locale
+            .clone()
+            .unwrap_or_else(|| DEFAULT_LOCALE.to_string()),
+        emoji: EmojiPolicy::Preserve,
+        template_options: Default::default(),
     }
 }
 
@@ -0,0 +1,505 @@
+//! Bulk import of simple sections from CSV.
+//!
+//! Spreadsheet exports of skills, languages, or certifications are mapped
+//! onto section items by their header row, so column order doesn't matter
+//! and common header names (`Skill`, `Rating`, `Issued By`, ...) are
+//! recognized. Comma-, semicolon-, and tab-separated files all work.
+
+use std::collections::HashSet;
+use std::fmt;
+use std::str::FromStr;
+
+use csv::{ReaderBuilder, StringRecord};
+use rustume_schema::{CefrLevel, Certification, Language, ResumeData, Skill};
+
+use crate::ParseError;
+
+/// Most data rows read from one file.
+pub const MAX_SECTION_CSV_ROWS: usize = 1_000;
+
+/// A section that can be filled from CSV.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CsvSection {
+    Skills,
+    Languages,
+    Certifications,
+}
+
+impl CsvSection {
+    /// Section key, as used in `sections` and layouts.
+    pub fn key(self) -> &'static str {
+        match self {
+            Self::Skills => "skills",
+            Self::Languages => "languages",
+            Self::Certifications => "certifications",
+        }
+    }
+}
+
+impl fmt::Display for CsvSection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.key())
+    }
+}
+
+impl FromStr for CsvSection {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "skills" => Ok(Self::Skills),
+            "languages" => Ok(Self::Languages),
+            "certifications" => Ok(Self::Certifications),
+            other => Err(ParseError::ValidationError(format!(
+                "Unknown CSV section '{other}' (expected skills, languages, or certifications)"
+            ))),
+        }
+    }
+}
+
+/// Item fields a column can map to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Field {
+    Name,
+    Level,
+    Description,
+    Keywords,
+    Issuer,
+    Date,
+    Url,
+}
+
+/// Header names per field, compared after [`normalize_header`].
+const HEADER_ALIASES: &[(Field, &[&str])] = &[
+    (
+        Field::Name,
+        &[
+            "name",
+            "skill",
+            "language",
+            "certification",
+            "certificate",
+            "title",
+        ],
+    ),
+    (
+        Field::Level,
+        &["level", "rating", "proficiency", "fluency", "score"],
+    ),
+    (
+        Field::Description,
+        &["description", "summary", "details", "notes"],
+    ),
+    (
+        Field::Keywords,
+        &["keywords", "tags", "tools", "technologies"],
+    ),
+    (
+        Field::Issuer,
+        &[
+            "issuer",
+            "issuedby",
+            "authority",
+            "organization",
+            "organisation",
+            "provider",
+        ],
+    ),
+    (
+        Field::Date,
+        &[
+            "date",
+            "issued",
+            "issuedate",
+            "issuedon",
+            "startedon",
+            "year",
+        ],
+    ),
+    (
+        Field::Url,
+        &["url", "link", "credentialurl", "verificationurl", "website"],
+    ),
+];
+
+/// Append the rows of a CSV file to `section` of `resume`.
+///
+/// The first row must be a header with a name column; other columns are
+/// optional and unknown ones are ignored. Rows without a name, and names the
+/// section already has (ignoring case), are skipped. Returns the number of
+/// items added.
+pub fn import_section_csv(
+    resume: &mut ResumeData,
+    section: CsvSection,
+    input: &[u8],
+) -> Result<usize, ParseError> {
+    let contents = std::str::from_utf8(input)
+        .map_err(|_| ParseError::ReadError("CSV input is not valid UTF-8".to_string()))?;
+    let contents = contents.trim_start_matches('\u{feff}');
+
+    let mut reader = ReaderBuilder::new()
+        .has_headers(true)
+        .flexible(true)
+        .trim(csv::Trim::All)
+        .delimiter(detect_delimiter(contents))
+        .from_reader(contents.as_bytes());
+    let headers = reader
+        .headers()
+        .map_err(|e| ParseError::ReadError(format!("Failed to read CSV headers: {}", e)))?;
+    let columns = map_columns(headers);
+    if !columns.contains(&Some(Field::Name)) {
+        let item = match section {
+            CsvSection::Skills => "skill",
+            CsvSection::Languages => "language",
+            CsvSection::Certifications => "certification",
+        };
+        return Err(ParseError::ValidationError(format!(
+            "CSV header has no name column (expected `name`, `title`, or `{item}`)"
+        )));
+    }
+
+    let mut seen: HashSet<String> = existing_names(resume, section);
+    let mut added = 0;
+    for (index, record) in reader.records().enumerate() {
+        if index >= MAX_SECTION_CSV_ROWS {
+            return Err(ParseError::LimitExceeded(format!(
+                "CSV has more than {MAX_SECTION_CSV_ROWS} rows"
+            )));
+        }
+        let record = record
+            .map_err(|e| ParseError::ReadError(format!("Failed to read CSV record: {}", e)))?;
+        let row = Row::new(&columns, &record);
+        let name = row.get(Field::Name);
+        if name.is_empty() || !seen.insert(name.to_lowercase()) {
+            continue;
+        }
+        push_item(resume, section, &row);
+        added += 1;
+    }
+    Ok(added)
+}
+
+/// The delimiter used most in the header line: comma, semicolon, or tab.
+fn detect_delimiter(contents: &str) -> u8 {
+    let header = contents.lines().next().unwrap_or_default();
+    [b',', b';', b'\t']
+        .into_iter()
+        .max_by_key(|&delimiter| header.bytes().filter(|&b| b == delimiter).count())
+        .filter(|&delimiter| header.as_bytes().contains(&delimiter))
+        .unwrap_or(b',')
+}
+
+/// Lowercase a header and drop everything but letters and digits, so
+/// `Issued By`, `issued_by`, and `IssuedBy` all match.
+fn normalize_header(header: &str) -> String {
+    header
+        .chars()
+        .filter(char::is_ascii_alphanumeric)
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+/// The field each column maps to. When two columns map to the same field,
+/// the first one wins.
+fn map_columns(headers: &StringRecord) -> Vec<Option<Field>> {
+    let mut taken = HashSet::new();
+    headers
+        .iter()
+        .map(|header| {
+            let header = normalize_header(header);
+            HEADER_ALIASES
+                .iter()
+                .find(|(_, aliases)| aliases.contains(&header.as_str()))
+                .map(|(field, _)| *field)
+                .filter(|field| taken.insert(*field))
+        })
+        .collect()
+}
+
+/// One data row, looked up by field.
+struct Row<'a> {
+    columns: &'a [Option<Field>],
+    record: &'a StringRecord,
+}
+
+impl<'a> Row<'a> {
+    fn new(columns: &'a [Option<Field>], record: &'a StringRecord) -> Self {
+        Self { columns, record }
+    }
+
+    /// The cell for `field`, or `""` when there is no such column. The `'`
+    /// that spreadsheet exports put before formula-like text is dropped.
+    fn get(&self, field: Field) -> &'a str {
+        let cell = self
+            .columns
+            .iter()
+            .position(|column| *column == Some(field))
+            .and_then(|index| self.record.get(index))
+            .unwrap_or_default();
+        cell.strip_prefix('\'')
+            .filter(|text| text.starts_with(['=', '+', '-', '@']))
+            .unwrap_or(cell)
+    }
+}
+
+fn existing_names(resume: &ResumeData, section: CsvSection) -> HashSet<String> {
+    let sections = &resume.sections;
+    let names: Vec<&str> = match section {
+        CsvSection::Skills => sections
+            .skills
+            .items
+            .iter()
+            .map(|i| i.name.as_str())
+            .collect(),
+        CsvSection::Languages => sections
+            .languages
+            .items
+            .iter()
+            .map(|i| i.name.as_str())
+            .collect(),
+        CsvSection::Certifications => sections
+            .certifications
+            .items
+            .iter()
+            .map(|i| i.name.as_str())
+            .collect(),
+    };
+    names.into_iter().map(str::to_lowercase).collect()
+}
+
+fn push_item(resume: &mut ResumeData, section: CsvSection, row: &Row) {
+    let name = row.get(Field::Name);
+    let description = row.get(Field::Description);
+    let level_cell = row.get(Field::Level);
+    let level = parse_level(level_cell);
+    match section {
+        CsvSection::Skills => {
+            let mut skill = Skill::new(name)
+                .with_description(description)
+                .with_keywords(split_keywords(row.get(Field::Keywords)));
+            if let Some(level) = level {
+                skill = skill.with_level(level);
+            }
+            resume.sections.skills.items.push(skill);
+        }
+        CsvSection::Languages => {
+            let cefr = CefrLevel::parse(level_cell);
+            // A worded level ("Fluent") doubles as the description
+            let description = if description.is_empty() && !level_cell.is_empty() && cefr.is_none()
+            {
+                level_cell
+            } else {
+                description
+            };
+            let mut language = Language::new(name).with_description(description);
+            if let Some(cefr) = cefr {
+                language = language.with_cefr_level(cefr);
+            } else if let Some(level) = level {
+                language = language.with_level(level);
+            }
+            resume.sections.languages.items.push(language);
+        }
+        CsvSection::Certifications => {
+            let mut certification = Certification::new(name, row.get(Field::Issuer))
+                .with_date(row.get(Field::Date))
+                .with_summary(description);
+            let url = row.get(Field::Url);
+            if !url.is_empty() {
+                certification = certification.with_url(url);
+            }
+            resume.sections.certifications.items.push(certification);
+        }
+    }
+}
+
+/// A 0–5 level from a number (`4`, `4/5`, `80%`), a CEFR code (`B2`), or a
+/// proficiency word (`Advanced`, `Native`).
+fn parse_level(cell: &str) -> Option<u8> {
+    let cell = cell.trim().to_lowercase();
+    if cell.is_empty() {
+        return None;
+    }
+    if let Some(percent) = cell.strip_suffix('%') {
+        let percent: f32 = percent.trim().parse().ok()?;
+        return Some((percent / 20.0).round().clamp(0.0, 5.0) as u8);
+    }
+    if let Some((value, scale)) = cell.split_once('/') {
+        let value: f32 = value.trim().parse().ok()?;
+        let scale: f32 = scale.trim().parse().ok().filter(|s: &f32| *s > 0.0)?;
+        return Some((value / scale * 5.0).round().clamp(0.0, 5.0) as u8);
+    }
+    if let Ok(value) = cell.parse::<f32>() {
+        return Some(value.round().clamp(0.0, 5.0) as u8);
+    }
+    if let Some(cefr) = CefrLevel::parse(&cell) {
+        return Some(cefr.level());
+    }
+    let level = match cell.as_str() {
+        _ if [
+            "native",
+            "bilingual",
+            "full professional",
+            "expert",
+            "master",
+        ]
+        .iter()
+        .any(|word| cell.contains(word)) =>
+        {
+            5
+        }
+        _ if ["professional working", "fluent", "advanced"]
+            .iter()
+            .any(|word| cell.contains(word)) =>
+        {
+            4
+        }
+        _ if [
+            "limited working",
+            "intermediate",
+            "proficient",
+            "conversational",
+        ]
+        .iter()
+        .any(|word| cell.contains(word)) =>
+        {
+            3
+        }
+        _ if ["elementary", "basic", "beginner", "novice"]
+            .iter()
+            .any(|word| cell.contains(word)) =>
+        {
+            2
+        }
+        _ => return None,
+    };
+    Some(level)
+}
+
+/// Split a keywords cell on commas, semicolons, or pipes.
+fn split_keywords(cell: &str) -> Vec<String> {
+    cell.split([',', ';', '|'])
+        .map(str::trim)
+        .filter(|keyword| !keyword.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn import(section: CsvSection, csv: &str) -> ResumeData {
+        let mut resume = ResumeData::default();
+        import_section_csv(&mut resume, section, csv.as_bytes()).unwrap();
+        resume
+    }
+
+    #[test]
+    fn test_imports_skills_by_header() {
+        let resume = import(
+            CsvSection::Skills,
+            "Level,Skill,Tags\n4,Rust,\"async, tokio\"\n,Go,\n",
+        );
+        let skills = &resume.sections.skills.items;
+        assert_eq!(skills.len(), 2);
+        assert_eq!(skills[0].name, "Rust");
+        assert_eq!(skills[0].level, 4);
+        assert_eq!(skills[0].keywords, vec!["async", "tokio"]);
+        assert_eq!(skills[1].name, "Go");
+        assert_eq!(skills[1].level, Skill::new("").level);
+    }
+
+    #[test]
+    fn test_imports_languages_with_worded_levels() {
+        let resume = import(
+            CsvSection::Languages,
+            "\u{feff}Language;Proficiency\nGerman;Native\nFrench;B1\n",
+        );
+        let languages = &resume.sections.languages.items;
+        assert_eq!(languages.len(), 2);
+        assert_eq!(languages[0].level, 5);
+        assert_eq!(languages[0].description, "Native");
+        assert_eq!(languages[1].level, 2);
+        assert_eq!(languages[1].cefr, Some(CefrLevel::B1));
+        assert_eq!(languages[1].description, "");
+    }
+
+    #[test]
+    fn test_imports_certifications() {
+        let resume = import(
+            CsvSection::Certifications,
+            "Name\tIssued By\tIssued On\tCredential URL\n\
+             AWS Solutions Architect\tAmazon\t2023-04\thttps://aws.example/cert/1\n",
+        );
+        let certification = &resume.sections.certifications.items[0];
+        assert_eq!(certification.name, "AWS Solutions Architect");
+        assert_eq!(certification.issuer, "Amazon");
+        assert_eq!(certification.date, "2023-04");
+        assert_eq!(certification.url.href, "https://aws.example/cert/1");
+    }
+
+    #[test]
+    fn test_skips_blank_and_duplicate_names() {
+        let mut resume = ResumeData::default();
+        resume.sections.skills.items.push(Skill::new("Rust"));
+        let added = import_section_csv(
+            &mut resume,
+            CsvSection::Skills,
+            b"name\nrust\n\nPython\n  \nPYTHON\n",
+        )
+        .unwrap();
+        assert_eq!(added, 1);
+        let names: Vec<_> = resume
+            .sections
+            .skills
+            .items
+            .iter()
+            .map(|s| &s.name)
+            .collect();
+        assert_eq!(names, ["Rust", "Python"]);
+    }
+
+    #[test]
+    fn test_requires_a_name_column() {
+        let mut resume = ResumeData::default();
+        let err =
+            import_section_csv(&mut resume, CsvSection::Languages, b"level\n5\n").unwrap_err();
+        assert!(
+            matches!(err, ParseError::ValidationError(_)),
+            "got: {err:?}"
+        );
+        assert!(err.to_string().contains("language"));
+    }
+
+    #[test]
+    fn test_rejects_too_many_rows() {
+        let csv = format!("name\n{}", "x\n".repeat(MAX_SECTION_CSV_ROWS + 1));
+        let mut resume = ResumeData::default();
+        let err = import_section_csv(&mut resume, CsvSection::Skills, csv.as_bytes()).unwrap_err();
+        assert!(matches!(err, ParseError::LimitExceeded(_)), "got: {err:?}");
+    }
+
+    #[rstest]
+    #[case("3", Some(3))]
+    #[case("4/5", Some(4))]
+    #[case("7 / 10", Some(4))]
+    #[case("80%", Some(4))]
+    #[case("9", Some(5))]
+    #[case("C1", Some(4))]
+    #[case("Professional working proficiency", Some(4))]
+    #[case("Limited working proficiency", Some(3))]
+    #[case("Expert", Some(5))]
+    #[case("", None)]
+    #[case("sometimes", None)]
+    fn test_parse_level(#[case] cell: &str, #[case] level: Option<u8>) {
+        assert_eq!(parse_level(cell), level);
+    }
+
+    #[test]
+    fn test_section_names() {
+        assert_eq!("Skills".parse::<CsvSection>().unwrap(), CsvSection::Skills);
+        assert_eq!(CsvSection::Certifications.to_string(), "certifications");
+        assert!("projects".parse::<CsvSection>().is_err());
+    }
+}
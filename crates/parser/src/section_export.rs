@@ -0,0 +1,459 @@
+//! Export of one section's items as a spreadsheet table.
+//!
+//! [`section_table`] flattens the items of any item section, built-in or
+//! custom, into one row per item with a column per field. The table can be
+//! written as CSV, which [`import_section_csv`](crate::import_section_csv)
+//! reads back for skills, languages, and certifications, or as an XLSX
+//! workbook (`xlsx` feature).
+
+use std::collections::BTreeSet;
+
+use rustume_schema::ResumeData;
+use rustume_utils::html_text_blocks;
+use serde_json::Value;
+
+use crate::ParseError;
+
+/// Fields shown first, in this order, when a section has them. Other fields
+/// follow alphabetically, then [`TRAILING_COLUMNS`].
+const LEADING_COLUMNS: &[&str] = &[
+    "name",
+    "title",
+    "company",
+    "institution",
+    "organization",
+    "network",
+    "position",
+    "studyType",
+    "area",
+    "username",
+    "issuer",
+    "awarder",
+    "publisher",
+    "level",
+    "score",
+    "date",
+    "location",
+    "keywords",
+    "url",
+    "description",
+    "summary",
+];
+
+/// Bookkeeping fields, kept at the end so edited sheets can be matched back
+/// to their items.
+const TRAILING_COLUMNS: &[&str] = &["visible", "id"];
+
+/// A section's items as rows of text cells.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SectionTable {
+    /// Section title, e.g. `Experience`.
+    pub title: String,
+    /// Field names in JSON (camelCase) form.
+    pub columns: Vec<String>,
+    /// One row per item, in section order, with a cell per column.
+    pub rows: Vec<Vec<String>>,
+    /// Columns whose values are all numbers, written as numeric cells.
+    numeric: Vec<bool>,
+}
+
+/// Flatten the items of `section` (`experience`, `skills`, ... or
+/// `custom.<id>`) into a table.
+///
+/// Links become their address, lists are joined with commas, and rich-text
+/// summaries become plain text with one line per paragraph.
+pub fn section_table(resume: &ResumeData, section: &str) -> Result<SectionTable, ParseError> {
+    let sections = serde_json::to_value(&resume.sections)
+        .map_err(|e| ParseError::ConversionError(e.to_string()))?;
+    let value = match section.strip_prefix("custom.") {
+        Some(id) => sections.get("custom").and_then(|custom| custom.get(id)),
+        None if section != "custom" => sections.get(section),
+        None => None,
+    }
+    .ok_or_else(|| ParseError::ValidationError(format!("Unknown section: {section}")))?;
+    let items = value
+        .get("items")
+        .and_then(Value::as_array)
+        .ok_or_else(|| ParseError::ValidationError(format!("Section {section} has no items")))?;
+
+    let columns = column_order(items);
+    let rows = items
+        .iter()
+        .map(|item| {
+            columns
+                .iter()
+                .map(|column| cell_text(column, item.get(column).unwrap_or(&Value::Null)))
+                .collect()
+        })
+        .collect();
+    let numeric = columns
+        .iter()
+        .map(|column| {
+            let mut values = items.iter().filter_map(|item| item.get(column));
+            values.clone().next().is_some() && values.all(Value::is_number)
+        })
+        .collect();
+
+    Ok(SectionTable {
+        title: value
+            .get("name")
+            .and_then(Value::as_str)
+            .unwrap_or(section)
+            .to_string(),
+        columns,
+        rows,
+        numeric,
+    })
+}
+
+impl SectionTable {
+    /// Write the table as comma-separated values with a header row.
+    ///
+    /// Cells starting with `=`, `+`, `-`, or `@` get a leading `'` so
+    /// spreadsheet apps show them as text instead of running them as
+    /// formulas; the CSV importer strips it again.
+    pub fn to_csv(&self) -> Result<String, ParseError> {
+        let mut writer = csv::Writer::from_writer(Vec::new());
+        writer
+            .write_record(&self.columns)
+            .map_err(|e| ParseError::ConversionError(e.to_string()))?;
+        for row in &self.rows {
+            writer
+                .write_record(row.iter().map(|cell| guard_formula(cell)))
+                .map_err(|e| ParseError::ConversionError(e.to_string()))?;
+        }
+        let bytes = writer
+            .into_inner()
+            .map_err(|e| ParseError::ConversionError(e.to_string()))?;
+        String::from_utf8(bytes).map_err(|e| ParseError::ConversionError(e.to_string()))
+    }
+
+    /// Write the table as a single-sheet XLSX workbook named after the
+    /// section, with the header row frozen. Text is stored inline, so no
+    /// cell is ever evaluated as a formula.
+    #[cfg(feature = "xlsx")]
+    pub fn to_xlsx(&self) -> Result<Vec<u8>, ParseError> {
+        use std::io::{Cursor, Write};
+
+        use zip::write::SimpleFileOptions;
+
+        let mut sheet = String::from(concat!(
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>"#,
+            r#"<worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main">"#,
+            r#"<sheetViews><sheetView workbookViewId="0">"#,
+            r#"<pane ySplit="1" topLeftCell="A2" activePane="bottomLeft" state="frozen"/>"#,
+            r#"</sheetView></sheetViews><sheetData>"#,
+        ));
+        let header = self.columns.iter().map(|column| (column.as_str(), false));
+        write_row(&mut sheet, 1, header);
+        for (index, row) in self.rows.iter().enumerate() {
+            let cells = row
+                .iter()
+                .zip(&self.numeric)
+                .map(|(cell, numeric)| (cell.as_str(), *numeric));
+            write_row(&mut sheet, index + 2, cells);
+        }
+        sheet.push_str("</sheetData></worksheet>");
+
+        let workbook = format!(
+            concat!(
+                r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>"#,
+                r#"<workbook xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" "#,
+                r#"xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">"#,
+                r#"<sheets><sheet name="{}" sheetId="1" r:id="rId1"/></sheets></workbook>"#,
+            ),
+            xml_escape(&sheet_name(&self.title))
+        );
+
+        let parts: [(&str, &str); 5] = [
+            ("[Content_Types].xml", XLSX_CONTENT_TYPES),
+            ("_rels/.rels", XLSX_ROOT_RELS),
+            ("xl/workbook.xml", &workbook),
+            ("xl/_rels/workbook.xml.rels", XLSX_WORKBOOK_RELS),
+            ("xl/worksheets/sheet1.xml", &sheet),
+        ];
+        let mut zip = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        let options =
+            SimpleFileOptions::default().compression_method(zip::CompressionMethod::Deflated);
+        for (name, contents) in parts {
+            zip.start_file(name, options)
+                .map_err(|e| ParseError::ConversionError(e.to_string()))?;
+            zip.write_all(contents.as_bytes())?;
+        }
+        let cursor = zip
+            .finish()
+            .map_err(|e| ParseError::ConversionError(e.to_string()))?;
+        Ok(cursor.into_inner())
+    }
+}
+
+/// Columns of every field any item has, in display order.
+fn column_order(items: &[Value]) -> Vec<String> {
+    let fields: BTreeSet<&str> = items
+        .iter()
+        .filter_map(Value::as_object)
+        .flat_map(|item| item.keys().map(String::as_str))
+        .collect();
+
+    let leading = LEADING_COLUMNS.iter().copied();
+    let middle = fields
+        .iter()
+        .copied()
+        .filter(|field| !LEADING_COLUMNS.contains(field) && !TRAILING_COLUMNS.contains(field));
+    let trailing = TRAILING_COLUMNS.iter().copied();
+    leading
+        .chain(middle)
+        .chain(trailing)
+        .filter(|column| fields.contains(column))
+        .map(str::to_string)
+        .collect()
+}
+
+/// One field value as cell text.
+fn cell_text(column: &str, value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(text) if column == "summary" => html_text_blocks(text).join("\n"),
+        Value::String(text) => text.clone(),
+        Value::Bool(flag) => flag.to_string(),
+        Value::Number(number) => number.to_string(),
+        Value::Array(values) if values.iter().all(Value::is_string) => values
+            .iter()
+            .filter_map(Value::as_str)
+            .collect::<Vec<_>>()
+            .join(", "),
+        Value::Object(link) if link.contains_key("href") => {
+            link["href"].as_str().unwrap_or_default().to_string()
+        }
+        other => other.to_string(),
+    }
+}
+
+/// Prefix `'` to text a spreadsheet would read as a formula.
+fn guard_formula(cell: &str) -> String {
+    if cell.starts_with(['=', '+', '-', '@']) {
+        format!("'{cell}")
+    } else {
+        cell.to_string()
+    }
+}
+
+#[cfg(feature = "xlsx")]
+const XLSX_CONTENT_TYPES: &str = concat!(
+    r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>"#,
+    r#"<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types">"#,
+    r#"<Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/>"#,
+    r#"<Default Extension="xml" ContentType="application/xml"/>"#,
+    r#"<Override PartName="/xl/workbook.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.sheet.main+xml"/>"#,
+    r#"<Override PartName="/xl/worksheets/sheet1.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.worksheet+xml"/>"#,
+    r#"</Types>"#,
+);
+
+#[cfg(feature = "xlsx")]
+const XLSX_ROOT_RELS: &str = concat!(
+    r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>"#,
+    r#"<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">"#,
+    r#"<Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument" Target="xl/workbook.xml"/>"#,
+    r#"</Relationships>"#,
+);
+
+#[cfg(feature = "xlsx")]
+const XLSX_WORKBOOK_RELS: &str = concat!(
+    r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>"#,
+    r#"<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">"#,
+    r#"<Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/worksheet" Target="worksheets/sheet1.xml"/>"#,
+    r#"</Relationships>"#,
+);
+
+/// Append a worksheet row; numeric cells that parse as numbers are stored
+/// as numbers, everything else as inline text.
+#[cfg(feature = "xlsx")]
+fn write_row<'a>(sheet: &mut String, row: usize, cells: impl Iterator<Item = (&'a str, bool)>) {
+    use std::fmt::Write;
+
+    let _ = write!(sheet, r#"<row r="{row}">"#);
+    for (index, (text, numeric)) in cells.enumerate() {
+        if text.is_empty() {
+            continue;
+        }
+        let reference = format!("{}{row}", column_letters(index));
+        if numeric && text.parse::<f64>().is_ok() {
+            let _ = write!(sheet, r#"<c r="{reference}"><v>{text}</v></c>"#);
+        } else {
+            let _ = write!(
+                sheet,
+                r#"<c r="{reference}" t="inlineStr"><is><t xml:space="preserve">{}</t></is></c>"#,
+                xml_escape(text)
+            );
+        }
+    }
+    sheet.push_str("</row>");
+}
+
+/// Spreadsheet column name for a zero-based index: `A`, ..., `Z`, `AA`, ...
+#[cfg(feature = "xlsx")]
+fn column_letters(mut index: usize) -> String {
+    let mut letters = Vec::new();
+    loop {
+        letters.push(b'A' + (index % 26) as u8);
+        if index < 26 {
+            break;
+        }
+        index = index / 26 - 1;
+    }
+    letters.iter().rev().map(|&b| b as char).collect()
+}
+
+/// A valid sheet name: at most 31 characters, none of `[]:*?/\`.
+#[cfg(feature = "xlsx")]
+fn sheet_name(title: &str) -> String {
+    let name: String = title
+        .chars()
+        .filter(|c| !matches!(c, '[' | ']' | ':' | '*' | '?' | '/' | '\\'))
+        .take(31)
+        .collect();
+    let name = name.trim();
+    if name.is_empty() {
+        "Sheet1".to_string()
+    } else {
+        name.to_string()
+    }
+}
+
+/// Escape XML text, dropping control characters XML 1.0 can't hold.
+#[cfg(feature = "xlsx")]
+fn xml_escape(text: &str) -> String {
+    let text: String = text
+        .chars()
+        .filter(|&c| matches!(c, '\t' | '\n' | '\r') || !c.is_control())
+        .collect();
+    rustume_utils::escape_html(&text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rustume_schema::{Certification, Experience, Section, Skill};
+
+    fn resume() -> ResumeData {
+        let mut resume = ResumeData::default();
+        resume.sections.skills.items = vec![
+            Skill::new("Rust")
+                .with_level(4)
+                .with_keywords(vec!["async".into(), "tokio".into()]),
+            Skill::new("=SUM(A1)"),
+        ];
+        resume
+    }
+
+    #[test]
+    fn test_skills_table_columns_and_rows() {
+        let resume = resume();
+        let table = section_table(&resume, "skills").unwrap();
+        assert_eq!(table.title, "Skills");
+        assert_eq!(
+            table.columns,
+            ["name", "level", "keywords", "description", "visible", "id"]
+        );
+        assert_eq!(table.rows[0][..4], ["Rust", "4", "async, tokio", ""]);
+        assert_eq!(table.rows[0][5], resume.sections.skills.items[0].id);
+    }
+
+    #[test]
+    fn test_links_and_rich_text_are_flattened() {
+        let mut resume = ResumeData::default();
+        resume.sections.experience.items = vec![Experience::new("Acme", "Engineer")
+            .with_url("https://acme.example")
+            .with_summary("<p>Built things</p><ul><li>Shipped</li></ul>")];
+        let table = section_table(&resume, "experience").unwrap();
+        let cell = |column: &str| {
+            let index = table.columns.iter().position(|c| c == column).unwrap();
+            table.rows[0][index].clone()
+        };
+        assert_eq!(table.columns[..2], ["company", "position"]);
+        assert_eq!(cell("url"), "https://acme.example");
+        assert_eq!(cell("summary"), "Built things\nShipped");
+    }
+
+    #[test]
+    fn test_custom_and_unknown_sections() {
+        let mut resume = ResumeData::default();
+        resume
+            .sections
+            .custom
+            .insert("talks".into(), Section::new("talks", "Talks"));
+        let table = section_table(&resume, "custom.talks").unwrap();
+        assert_eq!(table.title, "Talks");
+        assert!(table.rows.is_empty());
+
+        for section in ["nope", "custom", "custom.missing", "summary"] {
+            let err = section_table(&resume, section).unwrap_err();
+            assert!(matches!(err, ParseError::ValidationError(_)), "{section}");
+        }
+    }
+
+    #[test]
+    fn test_csv_guards_formulas_and_round_trips() {
+        let csv = section_table(&resume(), "skills")
+            .unwrap()
+            .to_csv()
+            .unwrap();
+        assert!(csv.starts_with("name,level,keywords,description,visible,id\n"));
+        assert!(csv.contains("Rust,4,\"async, tokio\","));
+        assert!(csv.contains("'=SUM(A1)"));
+
+        let mut imported = ResumeData::default();
+        let added =
+            crate::import_section_csv(&mut imported, crate::CsvSection::Skills, csv.as_bytes())
+                .unwrap();
+        assert_eq!(added, 2);
+        let skills = &imported.sections.skills.items;
+        assert_eq!(skills[0].keywords, ["async", "tokio"]);
+        assert_eq!(skills[0].level, 4);
+        assert_eq!(skills[1].name, "=SUM(A1)");
+    }
+
+    #[cfg(feature = "xlsx")]
+    #[test]
+    fn test_xlsx_workbook() {
+        use std::io::Read;
+
+        let mut resume = ResumeData::default();
+        resume.sections.certifications.items =
+            vec![Certification::new("AWS <Architect>", "Amazon").with_date("2023")];
+        resume.sections.certifications.name = "Certs: 2023/24".into();
+        let xlsx = section_table(&resume, "certifications")
+            .unwrap()
+            .to_xlsx()
+            .unwrap();
+
+        let mut archive = zip::ZipArchive::new(std::io::Cursor::new(xlsx)).unwrap();
+        let mut read = |name: &str| {
+            let mut contents = String::new();
+            archive
+                .by_name(name)
+                .unwrap()
+                .read_to_string(&mut contents)
+                .unwrap();
+            roxmltree::Document::parse(&contents).unwrap();
+            contents
+        };
+        assert!(read("xl/workbook.xml").contains(r#"name="Certs 202324""#));
+        let sheet = read("xl/worksheets/sheet1.xml");
+        assert!(sheet.contains(r#"<c r="A1" t="inlineStr"><is><t xml:space="preserve">name</t>"#));
+        assert!(sheet.contains("AWS &lt;Architect&gt;"));
+        read("[Content_Types].xml");
+        read("_rels/.rels");
+        read("xl/_rels/workbook.xml.rels");
+    }
+
+    #[cfg(feature = "xlsx")]
+    #[test]
+    fn test_column_letters() {
+        assert_eq!(column_letters(0), "A");
+        assert_eq!(column_letters(25), "Z");
+        assert_eq!(column_letters(26), "AA");
+        assert_eq!(column_letters(701), "ZZ");
+        assert_eq!(column_letters(702), "AAA");
+    }
+}
@@ -0,0 +1,514 @@
+//! Stack Overflow export parser.
+//!
+//! Accepts two shapes of the same data, told apart by the first byte:
+//!
+//! - **JSON**, as returned by the Stack Exchange API (`user`, `top_tags`,
+//!   `answers`) plus the Developer Story `positions` and `education` lists.
+//! - **XML**, in the data-dump style of `<row>` elements with PascalCase
+//!   attributes, inside `<users>`, `<posts>`, `<positions>`, and
+//!   `<education>`. These may share one root element or be a single dump
+//!   file on their own.
+//!
+//! The user becomes the basics and a Stack Overflow profile, positions
+//! become experience, and the tags of answers (and positions) become
+//! skills, ranked by answer score.
+
+use std::collections::HashMap;
+
+use crate::traits::{ParseError, Parser};
+use rustume_schema::{Education, Experience, Profile, ResumeData, Section, Skill, Url};
+use rustume_utils::{format_date_range_in, sanitize_html, Locale};
+use serde::Deserialize;
+
+/// Stack Overflow export parser.
+pub struct StackOverflowParser;
+
+/// Most skills taken from tags; answer histories often span hundreds.
+const MAX_SKILLS: usize = 15;
+
+// ============================================================================
+// Stack Overflow Export Types
+// ============================================================================
+
+/// A Stack Overflow export, from either JSON or XML.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct StackOverflowExport {
+    user: Option<SoUser>,
+    top_tags: Vec<SoTopTag>,
+    answers: Vec<SoPost>,
+    positions: Vec<SoPosition>,
+    education: Vec<SoEducation>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct SoUser {
+    user_id: Option<u64>,
+    display_name: Option<String>,
+    location: Option<String>,
+    website_url: Option<String>,
+    /// HTML
+    about_me: Option<String>,
+    /// Profile URL
+    link: Option<String>,
+}
+
+/// Per-tag totals, as in the API's `/users/{id}/top-tags`.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct SoTopTag {
+    tag_name: String,
+    answer_score: i64,
+    answer_count: u64,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct SoPost {
+    tags: Vec<String>,
+    score: i64,
+}
+
+/// A Developer Story position.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct SoPosition {
+    title: Option<String>,
+    company: Option<String>,
+    location: Option<String>,
+    start_date: Option<String>,
+    end_date: Option<String>,
+    description: Option<String>,
+    url: Option<String>,
+    tags: Vec<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct SoEducation {
+    school: Option<String>,
+    degree: Option<String>,
+    field_of_study: Option<String>,
+    start_date: Option<String>,
+    end_date: Option<String>,
+    description: Option<String>,
+}
+
+impl StackOverflowExport {
+    fn is_empty(&self) -> bool {
+        self.user.is_none()
+            && self.top_tags.is_empty()
+            && self.answers.is_empty()
+            && self.positions.is_empty()
+            && self.education.is_empty()
+    }
+
+    /// Read the data-dump style XML.
+    fn from_xml(xml: &str) -> Result<Self, ParseError> {
+        let doc = roxmltree::Document::parse(xml)
+            .map_err(|e| ParseError::ReadError(format!("Invalid XML: {e}")))?;
+        let mut export = Self::default();
+
+        for row in doc.descendants().filter(|node| node.has_tag_name("row")) {
+            let Some(parent) = row.parent_element() else {
+                continue;
+            };
+            let attr = |name: &str| {
+                row.attribute(name)
+                    .map(str::trim)
+                    .filter(|value| !value.is_empty())
+                    .map(str::to_string)
+            };
+            match parent.tag_name().name() {
+                // The first user is the account owner; later rows are other
+                // users from a full site dump.
+                "users" if export.user.is_none() => {
+                    let user_id = attr("Id").and_then(|id| id.parse().ok());
+                    export.user = Some(SoUser {
+                        user_id,
+                        display_name: attr("DisplayName"),
+                        location: attr("Location"),
+                        website_url: attr("WebsiteUrl"),
+                        about_me: attr("AboutMe"),
+                        link: None,
+                    });
+                }
+                "posts" => export.answers.push(SoPost {
+                    tags: attr("Tags")
+                        .map(|tags| split_tags(&tags))
+                        .unwrap_or_default(),
+                    score: attr("Score").and_then(|s| s.parse().ok()).unwrap_or(0),
+                }),
+                "positions" => export.positions.push(SoPosition {
+                    title: attr("Title"),
+                    company: attr("Company"),
+                    location: attr("Location"),
+                    start_date: attr("StartDate"),
+                    end_date: attr("EndDate"),
+                    description: attr("Description"),
+                    url: attr("Url"),
+                    tags: attr("Tags")
+                        .map(|tags| split_tags(&tags))
+                        .unwrap_or_default(),
+                }),
+                "education" => export.education.push(SoEducation {
+                    school: attr("School"),
+                    degree: attr("Degree"),
+                    field_of_study: attr("FieldOfStudy"),
+                    start_date: attr("StartDate"),
+                    end_date: attr("EndDate"),
+                    description: attr("Description"),
+                }),
+                _ => {}
+            }
+        }
+        Ok(export)
+    }
+}
+
+// ============================================================================
+// Parser Implementation
+// ============================================================================
+
+impl Parser for StackOverflowParser {
+    type RawData = StackOverflowExport;
+    type ValidatedData = StackOverflowExport;
+
+    fn read(&self, input: &[u8]) -> Result<Self::RawData, ParseError> {
+        let text = std::str::from_utf8(input).map_err(|e| ParseError::ReadError(e.to_string()))?;
+        let text = text.trim_start_matches('\u{feff}').trim_start();
+        if text.starts_with('<') {
+            StackOverflowExport::from_xml(text)
+        } else {
+            serde_json::from_str(text).map_err(|e| ParseError::ReadError(e.to_string()))
+        }
+    }
+
+    fn validate(&self, data: Self::RawData) -> Result<Self::ValidatedData, ParseError> {
+        if data.is_empty() {
+            return Err(ParseError::ValidationError(
+                "No Stack Overflow user, posts, or positions found".to_string(),
+            ));
+        }
+        Ok(data)
+    }
+
+    fn convert(&self, data: Self::ValidatedData) -> Result<ResumeData, ParseError> {
+        let mut resume = ResumeData::default();
+        let locale = Locale::for_tag(&resume.metadata.locale);
+        let titles = &locale.sections;
+
+        if let Some(user) = &data.user {
+            resume.basics.name = user.display_name.clone().unwrap_or_default();
+            resume.basics.location = user.location.clone().unwrap_or_default();
+            resume.basics.url = Url::new(user.website_url.clone().unwrap_or_default());
+            if let Some(about_me) = &user.about_me {
+                resume.sections.summary.name = titles.summary.to_string();
+                resume.sections.summary.content = sanitize_html(about_me);
+            }
+
+            let link = user.link.clone().or_else(|| {
+                user.user_id
+                    .map(|id| format!("https://stackoverflow.com/users/{id}"))
+            });
+            if let Some(link) = link {
+                resume.sections.profiles = Section::new("profiles", titles.profiles);
+                resume.sections.profiles.add_item(
+                    Profile::new("Stack Overflow", resume.basics.name.clone())
+                        .with_icon("stackoverflow")
+                        .with_url(link),
+                );
+            }
+        }
+
+        if !data.positions.is_empty() {
+            resume.sections.experience = Section::new("experience", titles.experience);
+            for p in &data.positions {
+                let mut exp = Experience::new(
+                    p.company.clone().unwrap_or_default(),
+                    p.title.clone().unwrap_or_default(),
+                );
+                if let Some(location) = &p.location {
+                    exp = exp.with_location(location);
+                }
+                let date = format_date_range_in(
+                    p.start_date.as_deref().map(date_part),
+                    p.end_date.as_deref().map(date_part),
+                    locale,
+                );
+                if !date.is_empty() {
+                    exp = exp.with_date(date);
+                }
+                if let Some(description) = &p.description {
+                    exp = exp.with_summary(description);
+                }
+                if let Some(url) = &p.url {
+                    exp = exp.with_url(url);
+                }
+                resume.sections.experience.add_item(exp);
+            }
+        }
+
+        if !data.education.is_empty() {
+            resume.sections.education = Section::new("education", titles.education);
+            for e in &data.education {
+                let mut edu = Education::new(
+                    e.school.clone().unwrap_or_default(),
+                    e.field_of_study.clone().unwrap_or_default(),
+                );
+                if let Some(degree) = &e.degree {
+                    edu = edu.with_study_type(degree);
+                }
+                let date = format_date_range_in(
+                    e.start_date.as_deref().map(date_part),
+                    e.end_date.as_deref().map(date_part),
+                    locale,
+                );
+                if !date.is_empty() {
+                    edu = edu.with_date(date);
+                }
+                if let Some(description) = &e.description {
+                    edu = edu.with_summary(description);
+                }
+                resume.sections.education.add_item(edu);
+            }
+        }
+
+        let skills = rank_tags(&data);
+        if !skills.is_empty() {
+            resume.sections.skills = Section::new("skills", titles.skills);
+            for skill in skills {
+                resume.sections.skills.add_item(skill);
+            }
+        }
+
+        resume.sync_dates();
+        Ok(resume)
+    }
+}
+
+/// Tags as skills: answer tags by total score, highest first, then tags
+/// that only appear on positions. Answer tags get a level relative to the
+/// best-scoring tag, so the top tag is 5 and every scored tag at least 1.
+fn rank_tags(data: &StackOverflowExport) -> Vec<Skill> {
+    let mut totals = TagTotals::default();
+    if data.top_tags.is_empty() {
+        for post in &data.answers {
+            for tag in &post.tags {
+                totals.add(tag, post.score, 1);
+            }
+        }
+    } else {
+        for tag in &data.top_tags {
+            totals.add(&tag.tag_name, tag.answer_score, tag.answer_count);
+        }
+    }
+    let answered = totals.tags.len();
+    for position in &data.positions {
+        for tag in &position.tags {
+            totals.add(tag, 0, 0);
+        }
+    }
+
+    let (answer_tags, position_tags) = totals.tags.split_at_mut(answered);
+    answer_tags.sort_by(|a, b| b.1.cmp(&a.1).then(b.2.cmp(&a.2)));
+    let best = answer_tags
+        .first()
+        .map_or(0, |tag| tag.1)
+        .max(1)
+        .unsigned_abs();
+
+    answer_tags
+        .iter()
+        .map(|(tag, score, _)| {
+            let score = (*score).max(0).unsigned_abs();
+            let level = (score * 5).div_ceil(best).clamp(1, 5);
+            Skill::new(tag).with_level(level as u8)
+        })
+        .chain(position_tags.iter().map(|(tag, _, _)| Skill::new(tag)))
+        .filter(|skill| !skill.name.is_empty())
+        .take(MAX_SKILLS)
+        .collect()
+}
+
+/// Score and answer count per tag, in first-seen order for stable ties.
+/// Tags differing only in case are one tag.
+#[derive(Default)]
+struct TagTotals {
+    tags: Vec<(String, i64, u64)>,
+    index: HashMap<String, usize>,
+}
+
+impl TagTotals {
+    fn add(&mut self, tag: &str, score: i64, answers: u64) {
+        let slot = match self.index.get(&tag.to_lowercase()) {
+            Some(&slot) => slot,
+            None => {
+                self.index.insert(tag.to_lowercase(), self.tags.len());
+                self.tags.push((tag.to_string(), 0, 0));
+                self.tags.len() - 1
+            }
+        };
+        self.tags[slot].1 += score;
+        self.tags[slot].2 += answers;
+    }
+}
+
+/// Split a tag list in any of the forms exports use: `<rust><serde>` in
+/// older dumps, `|rust|serde|` in newer ones, or space/comma/semicolon
+/// separated.
+fn split_tags(tags: &str) -> Vec<String> {
+    tags.split(['<', '>', '|', ';', ',', ' '])
+        .filter(|tag| !tag.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Date part of a dump timestamp (`2019-03-01T00:00:00.000` → `2019-03-01`).
+fn date_part(date: &str) -> &str {
+    date.split_once('T').map_or(date, |(day, _)| day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const API_JSON: &str = r#"{
+        "user": {
+            "user_id": 22656,
+            "display_name": "Jon Skeet",
+            "location": "Reading, United Kingdom",
+            "website_url": "https://codeblog.jonskeet.uk",
+            "about_me": "<p>Author of <em>C# in Depth</em>.</p><script>alert(1)</script>",
+            "link": "https://stackoverflow.com/users/22656/jon-skeet"
+        },
+        "top_tags": [
+            {"tag_name": "java", "answer_score": 40000, "answer_count": 9000},
+            {"tag_name": "c#", "answer_score": 200000, "answer_count": 20000},
+            {"tag_name": "linq", "answer_score": 0, "answer_count": 3}
+        ],
+        "positions": [
+            {
+                "title": "Staff Software Engineer",
+                "company": "Google",
+                "location": "London",
+                "start_date": "2008-04",
+                "description": "Cloud client libraries.",
+                "tags": ["c#", "grpc"]
+            }
+        ],
+        "education": [
+            {"school": "University of Cambridge", "degree": "BA", "field_of_study": "Computer Science", "start_date": "1995", "end_date": "1998"}
+        ]
+    }"#;
+
+    const DUMP_XML: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+    <stackoverflow>
+      <users>
+        <row Id="4242" DisplayName="Ada" Location="Berlin" AboutMe="&lt;p&gt;Rust and Postgres.&lt;/p&gt;" />
+        <row Id="7" DisplayName="Someone Else" />
+      </users>
+      <posts>
+        <row Id="1" PostTypeId="2" Score="12" Tags="&lt;rust&gt;&lt;serde&gt;" />
+        <row Id="2" PostTypeId="2" Score="30" Tags="|postgresql|" />
+        <row Id="3" PostTypeId="2" Score="3" Tags="|rust|" />
+      </posts>
+      <positions>
+        <row Title="Backend Engineer" Company="Acme" StartDate="2019-03-01T00:00:00.000" EndDate="2022-06-30T00:00:00.000" Tags="rust;kafka" />
+      </positions>
+    </stackoverflow>"#;
+
+    fn skill_names(resume: &ResumeData) -> Vec<&str> {
+        resume
+            .sections
+            .skills
+            .items
+            .iter()
+            .map(|skill| skill.name.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_api_json() {
+        let resume = StackOverflowParser.parse(API_JSON.as_bytes()).unwrap();
+
+        assert_eq!(resume.basics.name, "Jon Skeet");
+        assert_eq!(resume.basics.url.href, "https://codeblog.jonskeet.uk");
+        assert!(resume
+            .sections
+            .summary
+            .content
+            .contains("<em>C# in Depth</em>"));
+        assert!(!resume.sections.summary.content.contains("script"));
+
+        let profile = &resume.sections.profiles.items[0];
+        assert_eq!(profile.network, "Stack Overflow");
+        assert_eq!(profile.icon, "stackoverflow");
+        assert_eq!(
+            profile.url.href,
+            "https://stackoverflow.com/users/22656/jon-skeet"
+        );
+
+        let experience = &resume.sections.experience.items[0];
+        assert_eq!(experience.company, "Google");
+        assert_eq!(experience.position, "Staff Software Engineer");
+        assert_eq!(experience.start_date.as_deref(), Some("2008-04"));
+        assert_eq!(resume.sections.education.items[0].study_type, "BA");
+
+        assert_eq!(skill_names(&resume), ["c#", "java", "linq", "grpc"]);
+        let levels: Vec<u8> = resume
+            .sections
+            .skills
+            .items
+            .iter()
+            .map(|s| s.level)
+            .collect();
+        assert_eq!(levels[..3], [5, 1, 1]);
+    }
+
+    #[test]
+    fn test_parse_dump_xml() {
+        let resume = StackOverflowParser.parse(DUMP_XML.as_bytes()).unwrap();
+
+        assert_eq!(resume.basics.name, "Ada");
+        assert_eq!(resume.sections.summary.content, "<p>Rust and Postgres.</p>");
+        assert_eq!(
+            resume.sections.profiles.items[0].url.href,
+            "https://stackoverflow.com/users/4242"
+        );
+
+        let experience = &resume.sections.experience.items[0];
+        assert_eq!(experience.company, "Acme");
+        assert_eq!(experience.start_date.as_deref(), Some("2019-03-01"));
+        assert_eq!(experience.end_date.as_deref(), Some("2022-06-30"));
+
+        // postgresql 30, rust 12 + 3, serde 12, then position-only kafka
+        assert_eq!(
+            skill_names(&resume),
+            ["postgresql", "rust", "serde", "kafka"]
+        );
+        assert_eq!(resume.sections.skills.items[0].level, 5);
+        assert_eq!(resume.sections.skills.items[1].level, 3);
+    }
+
+    #[test]
+    fn test_rejects_empty_and_malformed_input() {
+        assert!(matches!(
+            StackOverflowParser.parse(b"{}"),
+            Err(ParseError::ValidationError(_))
+        ));
+        assert!(matches!(
+            StackOverflowParser.parse(b"<users><row"),
+            Err(ParseError::ReadError(_))
+        ));
+        assert!(StackOverflowParser.parse(b"not json").is_err());
+    }
+
+    #[test]
+    fn test_split_tags() {
+        assert_eq!(split_tags("<rust><serde>"), ["rust", "serde"]);
+        assert_eq!(split_tags("|c#|.net|"), ["c#", ".net"]);
+        assert_eq!(split_tags("rust; kafka"), ["rust", "kafka"]);
+    }
+}
@@ -0,0 +1,1684 @@
+//! Plain-text resume parser.
+//!
+//! Turns text pasted from an old PDF or a word processor into a draft
+//! resume. Plain text doesn't say which line is what, so
+//! [`TextResumeParser`] guesses:
+//!
+//! - A line such as `Experience` or `TECHNICAL SKILLS:` starts a section;
+//!   the lines above the first one are the header.
+//! - The email, phone number, and links are found by pattern in the header
+//!   (the email anywhere). The first header line left over is the name, the
+//!   next the headline, and one shaped like `Austin, TX` the location.
+//! - In entry sections (experience, education, ...), a line with a date
+//!   range, or after a blank line, starts a new entry. Bulleted lines and
+//!   sentences are its description; the short lines above them are split
+//!   into position, company, location, and dates.
+//! - List sections (skills, languages, interests) are split on commas and
+//!   bullets, with `Category: a, b` read as a skill with keywords.
+//!
+//! [`TextResumeParser::draft`] returns a [`FieldConfidence`] for each guess
+//! along with the resume, so an editor can ask the user to check the shaky
+//! ones. [`Parser::parse`] returns the resume alone.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::Serialize;
+
+use crate::json_resume::fluency_to_level;
+use crate::traits::{ParseError, Parser};
+use crate::vcard::{host, last_path_segment};
+use rustume_schema::{
+    Award, Certification, Education, Experience, Interest, Language, Profile, Project, Publication,
+    Reference, ResumeData, Section, Skill, Url, Volunteer,
+};
+use rustume_utils::{
+    canonicalize_url, escape_html, extract_emails, extract_phones, extract_urls, format_phone,
+    normalize_email, parse_date_range, Locale,
+};
+
+/// Plain-text resume parser.
+pub struct TextResumeParser;
+
+/// Largest input accepted. A long resume is well under 50 KB of text.
+pub const MAX_TEXT_BYTES: usize = 256 * 1024;
+
+/// Longest line still considered a heading or list entry, in characters.
+const MAX_SHORT_LINE: usize = 80;
+
+// ============================================================================
+// Confidence
+// ============================================================================
+
+/// How sure the parser is about a guessed value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Confidence {
+    /// A guess from position alone; worth checking
+    Low,
+    /// Backed by a cue such as a heading, keyword, or `at`
+    Medium,
+    /// Matched a precise pattern (email, date range, link)
+    High,
+}
+
+/// Confidence in one imported field or section.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FieldConfidence {
+    /// Path of the field, as in validation messages:
+    /// `basics.email`, `sections.experience.items[0].company`
+    pub path: String,
+    pub confidence: Confidence,
+    /// What the guess was based on
+    pub reason: String,
+}
+
+/// A resume recovered from plain text, with the confidence of each guess.
+#[derive(Debug, Clone, Serialize)]
+pub struct TextDraft {
+    pub resume: ResumeData,
+    /// In field order: basics first, then each section's items
+    pub confidence: Vec<FieldConfidence>,
+}
+
+impl TextDraft {
+    /// Guesses made with [`Confidence::Low`].
+    pub fn uncertain(&self) -> impl Iterator<Item = &FieldConfidence> {
+        self.confidence
+            .iter()
+            .filter(|field| field.confidence == Confidence::Low)
+    }
+}
+
+// ============================================================================
+// Sectionized Text
+// ============================================================================
+
+/// A built-in section a heading can start.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TextSection {
+    Summary,
+    Experience,
+    Education,
+    Skills,
+    Projects,
+    Certifications,
+    Languages,
+    Awards,
+    Publications,
+    Volunteer,
+    Interests,
+    References,
+}
+
+/// Headings, lowercased with `&` spelled `and`.
+const HEADINGS: &[(&str, TextSection)] = &[
+    ("summary", TextSection::Summary),
+    ("professional summary", TextSection::Summary),
+    ("career summary", TextSection::Summary),
+    ("profile", TextSection::Summary),
+    ("professional profile", TextSection::Summary),
+    ("about", TextSection::Summary),
+    ("about me", TextSection::Summary),
+    ("objective", TextSection::Summary),
+    ("career objective", TextSection::Summary),
+    ("overview", TextSection::Summary),
+    ("experience", TextSection::Experience),
+    ("work experience", TextSection::Experience),
+    ("professional experience", TextSection::Experience),
+    ("relevant experience", TextSection::Experience),
+    ("employment", TextSection::Experience),
+    ("employment history", TextSection::Experience),
+    ("work history", TextSection::Experience),
+    ("career history", TextSection::Experience),
+    ("education", TextSection::Education),
+    ("education and training", TextSection::Education),
+    ("academic background", TextSection::Education),
+    ("skills", TextSection::Skills),
+    ("technical skills", TextSection::Skills),
+    ("key skills", TextSection::Skills),
+    ("core skills", TextSection::Skills),
+    ("skills and tools", TextSection::Skills),
+    ("core competencies", TextSection::Skills),
+    ("competencies", TextSection::Skills),
+    ("technologies", TextSection::Skills),
+    ("projects", TextSection::Projects),
+    ("personal projects", TextSection::Projects),
+    ("selected projects", TextSection::Projects),
+    ("side projects", TextSection::Projects),
+    ("certifications", TextSection::Certifications),
+    ("certificates", TextSection::Certifications),
+    ("licenses and certifications", TextSection::Certifications),
+    ("certifications and licenses", TextSection::Certifications),
+    ("languages", TextSection::Languages),
+    ("awards", TextSection::Awards),
+    ("honors", TextSection::Awards),
+    ("honors and awards", TextSection::Awards),
+    ("awards and honors", TextSection::Awards),
+    ("achievements", TextSection::Awards),
+    ("publications", TextSection::Publications),
+    ("volunteer", TextSection::Volunteer),
+    ("volunteering", TextSection::Volunteer),
+    ("volunteer experience", TextSection::Volunteer),
+    ("volunteer work", TextSection::Volunteer),
+    ("interests", TextSection::Interests),
+    ("hobbies", TextSection::Interests),
+    ("hobbies and interests", TextSection::Interests),
+    ("references", TextSection::References),
+];
+
+/// Text split at its section headings.
+#[derive(Debug, Default)]
+pub struct TextResume {
+    /// Lines above the first heading, blank lines dropped
+    header: Vec<String>,
+    /// Lines under each heading, blank lines kept as `""`. A heading used
+    /// twice adds a second block.
+    sections: Vec<(TextSection, Vec<String>)>,
+    /// Every line, for patterns that may appear anywhere
+    all: Vec<String>,
+}
+
+impl TextResume {
+    fn from_text(text: &str) -> Self {
+        let mut resume = Self::default();
+        for line in text.lines() {
+            let line = line.replace('\t', "  ").trim().to_string();
+            if let Some(section) = heading(&line) {
+                resume.sections.push((section, Vec::new()));
+            } else if let Some((_, lines)) = resume.sections.last_mut() {
+                lines.push(line.clone());
+            } else if !line.is_empty() {
+                resume.header.push(line.clone());
+            }
+            if !line.is_empty() {
+                resume.all.push(line);
+            }
+        }
+        resume
+    }
+
+    fn blocks(&self, section: TextSection) -> impl Iterator<Item = &[String]> {
+        self.sections
+            .iter()
+            .filter(move |(s, _)| *s == section)
+            .map(|(_, lines)| lines.as_slice())
+    }
+
+    /// Lines of every block under `section`, separated by a blank line.
+    fn lines(&self, section: TextSection) -> Vec<&str> {
+        let mut lines = Vec::new();
+        for block in self.blocks(section) {
+            if !lines.is_empty() {
+                lines.push("");
+            }
+            lines.extend(block.iter().map(String::as_str));
+        }
+        lines
+    }
+}
+
+/// The section `line` is a heading for: a known name, alone on its line,
+/// optionally with a trailing colon or Markdown markers.
+fn heading(line: &str) -> Option<TextSection> {
+    let name =
+        line.trim_matches(|c: char| matches!(c, '#' | '=' | '*' | '_' | ':') || c.is_whitespace());
+    if name.is_empty() || name.chars().count() > 40 {
+        return None;
+    }
+    let key = name
+        .to_lowercase()
+        .replace('&', " and ")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ");
+    HEADINGS
+        .iter()
+        .find(|(heading, _)| *heading == key)
+        .map(|(_, section)| *section)
+}
+
+// ============================================================================
+// Patterns
+// ============================================================================
+
+/// A month-year, numeric month/year, or year from 1900 to 2099.
+const DATE: &str = r"(?:(?:jan(?:uary)?|feb(?:ruary)?|mar(?:ch)?|apr(?:il)?|may|june?|july?|aug(?:ust)?|sep(?:t(?:ember)?)?|oct(?:ober)?|nov(?:ember)?|dec(?:ember)?)\.?,?\s+(?:19|20)\d{2}|(?:0?[1-9]|1[0-2])/(?:19|20)\d{2}|(?:19|20)\d{2}(?:-(?:0[1-9]|1[0-2]))?)";
+
+/// A date, optionally followed by a separator and an end date or `Present`.
+static DATE_RANGE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(&format!(
+        r"(?i)\b(?P<start>{DATE})(?:\s*(?:-|–|—|to|until)\s*(?P<end>{DATE}|present|current|now|today|ongoing))?\b"
+    ))
+    .expect("Invalid date range regex")
+});
+
+/// `City, ST` or `City, Country`.
+static LOCATION: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^\p{Lu}[\p{L}.' -]*,\s*(?:[A-Z]{2,3}|\p{Lu}[\p{L} ]+)$")
+        .expect("Invalid location regex")
+});
+
+/// Profile hosts with their network name and icon slug.
+const NETWORKS: &[(&str, &str, &str)] = &[
+    ("linkedin.com", "LinkedIn", "linkedin"),
+    ("github.com", "GitHub", "github"),
+    ("gitlab.com", "GitLab", "gitlab"),
+    ("stackoverflow.com", "Stack Overflow", "stackoverflow"),
+    ("x.com", "X", "x"),
+    ("twitter.com", "Twitter", "twitter"),
+];
+
+/// Header labels dropped in front of a value (`Email: ...`).
+const LABELS: &[&str] = &[
+    "email",
+    "e-mail",
+    "phone",
+    "tel",
+    "mobile",
+    "cell",
+    "web",
+    "website",
+    "linkedin",
+    "github",
+    "portfolio",
+    "location",
+    "address",
+];
+
+/// Words that mark a job title.
+const TITLE_WORDS: &[&str] = &[
+    "engineer",
+    "developer",
+    "manager",
+    "designer",
+    "analyst",
+    "director",
+    "lead",
+    "intern",
+    "consultant",
+    "architect",
+    "scientist",
+    "specialist",
+    "officer",
+    "head",
+    "vp",
+    "president",
+    "coordinator",
+    "administrator",
+    "assistant",
+    "associate",
+    "teacher",
+    "researcher",
+    "founder",
+    "co-founder",
+    "cto",
+    "ceo",
+    "cfo",
+    "programmer",
+    "technician",
+    "writer",
+    "editor",
+    "nurse",
+    "accountant",
+    "technologist",
+    "mathematician",
+    "physicist",
+    "chemist",
+    "economist",
+    "representative",
+    "advisor",
+    "instructor",
+    "professor",
+    "owner",
+    "supervisor",
+    "executive",
+    "principal",
+    "staff",
+    "senior",
+    "junior",
+    "sr.",
+    "jr.",
+];
+
+/// Words that mark an organization.
+const COMPANY_WORDS: &[&str] = &[
+    "inc",
+    "inc.",
+    "llc",
+    "ltd",
+    "ltd.",
+    "gmbh",
+    "ag",
+    "corp",
+    "corp.",
+    "corporation",
+    "co.",
+    "company",
+    "group",
+    "technologies",
+    "labs",
+    "plc",
+    "s.a.",
+    "bv",
+    "foundation",
+    "agency",
+    "studio",
+    "studios",
+    "bank",
+    "university",
+    "hospital",
+    "center",
+    "centre",
+    "laboratory",
+];
+
+/// Words that mark a school.
+const INSTITUTION_WORDS: &[&str] = &[
+    "university",
+    "college",
+    "school",
+    "institute",
+    "academy",
+    "polytechnic",
+    "conservatory",
+    "universität",
+    "université",
+    "universidad",
+    "universidade",
+    "università",
+];
+
+/// Leading words of a spelled-out degree.
+const DEGREE_WORDS: &[&str] = &[
+    "bachelor",
+    "bachelor's",
+    "bachelors",
+    "master",
+    "master's",
+    "masters",
+    "doctor",
+    "doctorate",
+    "associate",
+    "associate's",
+    "diploma",
+    "certificate",
+    "high",
+];
+
+/// Degree abbreviations, lowercase without dots.
+const DEGREE_ABBREVIATIONS: &[&str] = &[
+    "ba", "bs", "bsc", "bfa", "beng", "bba", "ab", "ma", "ms", "msc", "mfa", "meng", "mba",
+    "mphil", "phd", "dphil", "llb", "llm", "jd", "md", "aa", "as",
+];
+
+/// Leading markers of bulleted and numbered lines.
+const BULLETS: &[char] = &[
+    '-', '*', '•', '·', '▪', '◦', '‣', '–', '—', '>', '○', '■', '●',
+];
+
+/// Separators between the parts of one heading line.
+const PART_SEPARATORS: &[&str] = &[" | ", " • ", " · ", " — ", " – ", " - ", "  "];
+
+// ============================================================================
+// Parser
+// ============================================================================
+
+impl TextResumeParser {
+    /// Parse `input` into a draft resume with the confidence of each guess.
+    pub fn draft(&self, input: &[u8]) -> Result<TextDraft, ParseError> {
+        let raw = self.read(input)?;
+        let text = self.validate(raw)?;
+        Ok(Draft::build(&text))
+    }
+}
+
+impl Parser for TextResumeParser {
+    type RawData = String;
+    type ValidatedData = TextResume;
+
+    fn read(&self, input: &[u8]) -> Result<Self::RawData, ParseError> {
+        if input.len() > MAX_TEXT_BYTES {
+            return Err(ParseError::LimitExceeded(format!(
+                "text is {} bytes; the limit is {MAX_TEXT_BYTES}",
+                input.len()
+            )));
+        }
+        let text = std::str::from_utf8(input).map_err(|e| ParseError::ReadError(e.to_string()))?;
+        Ok(text.trim_start_matches('\u{feff}').to_string())
+    }
+
+    fn validate(&self, data: Self::RawData) -> Result<Self::ValidatedData, ParseError> {
+        let text = TextResume::from_text(&data);
+        if text.all.is_empty() {
+            return Err(ParseError::ValidationError("No text to import".to_string()));
+        }
+        Ok(text)
+    }
+
+    fn convert(&self, data: Self::ValidatedData) -> Result<ResumeData, ParseError> {
+        Ok(Draft::build(&data).resume)
+    }
+}
+
+/// The resume being assembled and the confidence notes so far.
+struct Draft {
+    resume: ResumeData,
+    confidence: Vec<FieldConfidence>,
+}
+
+impl Draft {
+    fn build(text: &TextResume) -> TextDraft {
+        let mut draft = Self {
+            resume: ResumeData::default(),
+            confidence: Vec::new(),
+        };
+        draft.basics(text);
+        draft.summary(text);
+        draft.experience(text);
+        draft.education(text);
+        draft.skills(text);
+        draft.projects(text);
+        draft.certifications(text);
+        draft.languages(text);
+        draft.awards(text);
+        draft.publications(text);
+        draft.volunteer(text);
+        draft.interests(text);
+        draft.references(text);
+        draft.resume.sync_dates();
+        TextDraft {
+            resume: draft.resume,
+            confidence: draft.confidence,
+        }
+    }
+
+    fn note(&mut self, path: impl Into<String>, confidence: Confidence, reason: &str) {
+        self.confidence.push(FieldConfidence {
+            path: path.into(),
+            confidence,
+            reason: reason.to_string(),
+        });
+    }
+
+    fn titles(&self) -> &'static rustume_utils::locale::SectionTitles {
+        &Locale::for_tag(&self.resume.metadata.locale).sections
+    }
+
+    // ------------------------------------------------------------------------
+    // Header
+    // ------------------------------------------------------------------------
+
+    fn basics(&mut self, text: &TextResume) {
+        let email = text
+            .header
+            .iter()
+            .chain(&text.all)
+            .find_map(|line| extract_emails(line).into_iter().next());
+        if let Some(email) = email {
+            self.resume.basics.email = normalize_email(&email).unwrap_or(email);
+            self.note("basics.email", Confidence::High, "Email address pattern");
+        }
+
+        let mut links = Vec::new();
+        let mut parts = Vec::new();
+        for line in &text.header {
+            let rest = without(line, &extract_emails(line));
+            let urls = extract_urls(&rest);
+            links.extend(urls.iter().filter_map(|url| canonicalize_url(url)));
+            let mut rest = without(&rest, &urls);
+            if self.resume.basics.phone.is_empty() {
+                if let Some(phone) = extract_phones(&rest).into_iter().next() {
+                    rest = without(&rest, std::slice::from_ref(&phone));
+                    self.resume.basics.phone = format_phone(&phone, None).unwrap_or(phone);
+                    self.note("basics.phone", Confidence::High, "Phone number pattern");
+                }
+            }
+            let line_parts: Vec<String> = split_parts(&rest)
+                .into_iter()
+                .filter_map(strip_label)
+                .collect();
+            parts.push(line_parts);
+        }
+
+        for url in links {
+            match network(&url) {
+                Some((name, icon)) => {
+                    if self.resume.sections.profiles.items.is_empty() {
+                        self.resume.sections.profiles =
+                            Section::new("profiles", self.titles().profiles);
+                    }
+                    let username = last_path_segment(&url);
+                    self.resume
+                        .sections
+                        .profiles
+                        .add_item(Profile::new(name, username).with_icon(icon).with_url(url));
+                }
+                None if self.resume.basics.url.href.is_empty() => {
+                    self.resume.basics.url = Url::new(url);
+                    self.note("basics.url", Confidence::High, "Link in the header");
+                }
+                None => {}
+            }
+        }
+        if !self.resume.sections.profiles.items.is_empty() {
+            self.note(
+                "sections.profiles",
+                Confidence::High,
+                "Profile links in the header",
+            );
+        }
+
+        let mut remaining = Vec::new();
+        for (line, line_parts) in parts.into_iter().enumerate() {
+            for part in line_parts {
+                if self.resume.basics.location.is_empty() && is_location(&part) {
+                    self.resume.basics.location = part;
+                    self.note(
+                        "basics.location",
+                        Confidence::Medium,
+                        "Shaped like City, Region",
+                    );
+                } else {
+                    remaining.push((line, part));
+                }
+            }
+        }
+
+        let mut remaining = remaining.into_iter();
+        if let Some((first_line, name)) = remaining.next() {
+            let confidence = if looks_like_name(&name) {
+                Confidence::Medium
+            } else {
+                Confidence::Low
+            };
+            self.resume.basics.name = name;
+            self.resume
+                .basics
+                .guess_name_parts(&self.resume.metadata.locale);
+            self.note("basics.name", confidence, "First line of the header");
+
+            let mut prose = Vec::new();
+            for (line, part) in remaining {
+                if is_sentence(&part) {
+                    prose.push(part);
+                } else if self.resume.basics.headline.is_empty() {
+                    let confidence = if line > first_line {
+                        Confidence::Medium
+                    } else {
+                        Confidence::Low
+                    };
+                    self.resume.basics.headline = part;
+                    self.note("basics.headline", confidence, "Header line after the name");
+                }
+            }
+            // Some resumes open with a paragraph instead of a summary heading
+            if !prose.is_empty() && text.blocks(TextSection::Summary).next().is_none() {
+                let lines: Vec<&str> = prose.iter().map(String::as_str).collect();
+                self.resume.sections.summary.name = self.titles().summary.to_string();
+                self.resume.sections.summary.content = rich_text(&lines);
+                self.note(
+                    "sections.summary.content",
+                    Confidence::Low,
+                    "Paragraph in the header, without a summary heading",
+                );
+            }
+        }
+    }
+
+    // ------------------------------------------------------------------------
+    // Sections
+    // ------------------------------------------------------------------------
+
+    fn summary(&mut self, text: &TextResume) {
+        let lines = text.lines(TextSection::Summary);
+        let content = rich_text(&lines);
+        if content.is_empty() {
+            return;
+        }
+        self.resume.sections.summary.name = self.titles().summary.to_string();
+        self.resume.sections.summary.content = content;
+        self.note(
+            "sections.summary.content",
+            Confidence::High,
+            "Text under a summary heading",
+        );
+    }
+
+    fn experience(&mut self, text: &TextResume) {
+        let entries = entries(&text.lines(TextSection::Experience), false);
+        if entries.is_empty() {
+            return;
+        }
+        self.resume.sections.experience = Section::new("experience", self.titles().experience);
+        for (index, entry) in entries.into_iter().enumerate() {
+            let path = format!("sections.experience.items[{index}]");
+            let (mut heading, date) = self.heading(&entry, &path);
+            let location = take_location(&mut heading);
+            let (role, leftover) = self.role(heading, &path, "position", "company");
+
+            let mut item = Experience::new(role.organization, role.title);
+            if let Some(date) = date {
+                item = item.with_date(date);
+            }
+            if let Some(location) = location {
+                item = item.with_location(location);
+                self.note(
+                    format!("{path}.location"),
+                    Confidence::Medium,
+                    "Shaped like City, Region",
+                );
+            }
+            if let Some(url) = entry.link() {
+                item = item.with_url(url);
+            }
+            item = item.with_summary(entry.summary(&leftover));
+            self.resume.sections.experience.add_item(item);
+        }
+    }
+
+    fn education(&mut self, text: &TextResume) {
+        let entries = entries(&text.lines(TextSection::Education), false);
+        if entries.is_empty() {
+            return;
+        }
+        self.resume.sections.education = Section::new("education", self.titles().education);
+        for (index, entry) in entries.into_iter().enumerate() {
+            let path = format!("sections.education.items[{index}]");
+            let (heading, date) = self.heading(&entry, &path);
+
+            // "BSc Physics, Technical University of Munich" on one line
+            let mut heading: Vec<String> = heading
+                .into_iter()
+                .flat_map(|part| {
+                    if is_degree(&part) && has_word(&part, INSTITUTION_WORDS) {
+                        part.split(", ").map(str::to_string).collect()
+                    } else {
+                        vec![part]
+                    }
+                })
+                .collect();
+            let score = take(&mut heading, |part| part.to_lowercase().contains("gpa")).map(|gpa| {
+                gpa.find(|c: char| c.is_ascii_digit())
+                    .map_or(gpa.clone(), |start| gpa[start..].trim().to_string())
+            });
+            let institution = take(&mut heading, |part| has_word(part, INSTITUTION_WORDS));
+            let degree = take(&mut heading, is_degree);
+
+            let institution = match institution {
+                Some(institution) => {
+                    self.note(
+                        format!("{path}.institution"),
+                        Confidence::Medium,
+                        "Contains a school word",
+                    );
+                    institution
+                }
+                None if !heading.is_empty() => {
+                    self.note(
+                        format!("{path}.institution"),
+                        Confidence::Low,
+                        "First line of the entry",
+                    );
+                    heading.remove(0)
+                }
+                None => String::new(),
+            };
+            let (study_type, area) = match degree {
+                Some(degree) => {
+                    self.note(
+                        format!("{path}.studyType"),
+                        Confidence::Medium,
+                        "Starts with a degree",
+                    );
+                    split_degree(&degree)
+                }
+                None if !heading.is_empty() => {
+                    self.note(
+                        format!("{path}.area"),
+                        Confidence::Low,
+                        "Line after the school",
+                    );
+                    (String::new(), heading.remove(0))
+                }
+                None => (String::new(), String::new()),
+            };
+
+            let mut item = Education::new(institution, area).with_study_type(study_type);
+            if let Some(date) = date {
+                item = item.with_date(date);
+            }
+            if let Some(score) = score {
+                item = item.with_score(score);
+            }
+            item = item.with_summary(entry.summary(&heading));
+            self.resume.sections.education.add_item(item);
+        }
+    }
+
+    fn skills(&mut self, text: &TextResume) {
+        let mut skills: Vec<Skill> = Vec::new();
+        for line in text.lines(TextSection::Skills) {
+            let line = strip_bullet(line);
+            match labeled_list(line) {
+                Some((label, keywords)) => skills.push(Skill::new(label).with_keywords(keywords)),
+                None => skills.extend(list_items(line).into_iter().map(Skill::new)),
+            }
+        }
+        skills.dedup_by(|a, b| a.name.eq_ignore_ascii_case(&b.name));
+        if skills.is_empty() {
+            return;
+        }
+        self.resume.sections.skills = Section::new("skills", self.titles().skills);
+        for skill in skills {
+            self.resume.sections.skills.add_item(skill);
+        }
+        self.note(
+            "sections.skills",
+            Confidence::Medium,
+            "Split on commas and bullets",
+        );
+    }
+
+    fn projects(&mut self, text: &TextResume) {
+        let entries = entries(&text.lines(TextSection::Projects), false);
+        if entries.is_empty() {
+            return;
+        }
+        self.resume.sections.projects = Section::new("projects", self.titles().projects);
+        for (index, entry) in entries.into_iter().enumerate() {
+            let path = format!("sections.projects.items[{index}]");
+            let (heading, date) = self.heading(&entry, &path);
+            let mut heading = heading.into_iter();
+
+            let mut item = Project::new(heading.next().unwrap_or_default());
+            if let Some(description) = heading.next() {
+                item = item.with_description(description);
+            }
+            if let Some(date) = date {
+                item = item.with_date(date);
+            }
+            if let Some(url) = entry.link() {
+                item = item.with_url(url);
+            }
+            let leftover: Vec<String> = heading.collect();
+            item = item.with_summary(entry.summary(&leftover));
+            self.resume.sections.projects.add_item(item);
+        }
+        self.note(
+            "sections.projects",
+            Confidence::Medium,
+            "Name from the first line of each entry",
+        );
+    }
+
+    fn certifications(&mut self, text: &TextResume) {
+        let entries = entries(&text.lines(TextSection::Certifications), true);
+        if entries.is_empty() {
+            return;
+        }
+        self.resume.sections.certifications =
+            Section::new("certifications", self.titles().certifications);
+        for (index, entry) in entries.into_iter().enumerate() {
+            let path = format!("sections.certifications.items[{index}]");
+            let (heading, date) = self.heading(&entry, &path);
+            let mut heading = heading.into_iter();
+
+            let mut item = Certification::new(
+                heading.next().unwrap_or_default(),
+                heading.next().unwrap_or_default(),
+            );
+            if let Some(date) = date {
+                item = item.with_date(date);
+            }
+            if let Some(url) = entry.link() {
+                item = item.with_url(url);
+            }
+            let leftover: Vec<String> = heading.collect();
+            item = item.with_summary(entry.summary(&leftover));
+            self.resume.sections.certifications.add_item(item);
+        }
+        self.note(
+            "sections.certifications",
+            Confidence::Medium,
+            "One certification per line",
+        );
+    }
+
+    fn languages(&mut self, text: &TextResume) {
+        let languages: Vec<Language> = text
+            .lines(TextSection::Languages)
+            .into_iter()
+            .flat_map(|line| list_items(strip_bullet(line)))
+            .map(|item| {
+                let (name, fluency) = split_fluency(&item);
+                let language = Language::new(name);
+                match fluency {
+                    Some(fluency) => language
+                        .with_level(fluency_to_level(&fluency))
+                        .with_description(fluency),
+                    None => language,
+                }
+            })
+            .collect();
+        if languages.is_empty() {
+            return;
+        }
+        self.resume.sections.languages = Section::new("languages", self.titles().languages);
+        for language in languages {
+            self.resume.sections.languages.add_item(language);
+        }
+        self.note(
+            "sections.languages",
+            Confidence::Medium,
+            "Split on commas and bullets",
+        );
+    }
+
+    fn awards(&mut self, text: &TextResume) {
+        let entries = entries(&text.lines(TextSection::Awards), true);
+        if entries.is_empty() {
+            return;
+        }
+        self.resume.sections.awards = Section::new("awards", self.titles().awards);
+        for (index, entry) in entries.into_iter().enumerate() {
+            let path = format!("sections.awards.items[{index}]");
+            let (heading, date) = self.heading(&entry, &path);
+            let mut heading = heading.into_iter();
+
+            let mut item = Award::new(heading.next().unwrap_or_default());
+            if let Some(awarder) = heading.next() {
+                item = item.with_awarder(awarder);
+            }
+            if let Some(date) = date {
+                item = item.with_date(date);
+            }
+            let leftover: Vec<String> = heading.collect();
+            item = item.with_summary(entry.summary(&leftover));
+            self.resume.sections.awards.add_item(item);
+        }
+        self.note("sections.awards", Confidence::Medium, "One award per line");
+    }
+
+    fn publications(&mut self, text: &TextResume) {
+        let entries = entries(&text.lines(TextSection::Publications), true);
+        if entries.is_empty() {
+            return;
+        }
+        self.resume.sections.publications =
+            Section::new("publications", self.titles().publications);
+        for (index, entry) in entries.into_iter().enumerate() {
+            let path = format!("sections.publications.items[{index}]");
+            let (heading, date) = self.heading(&entry, &path);
+            let mut heading = heading.into_iter();
+
+            let mut item = Publication::new(heading.next().unwrap_or_default());
+            if let Some(publisher) = heading.next() {
+                item = item.with_publisher(publisher);
+            }
+            if let Some(date) = date {
+                item = item.with_date(date);
+            }
+            if let Some(url) = entry.link() {
+                item = item.with_url(url);
+            }
+            let leftover: Vec<String> = heading.collect();
+            item = item.with_summary(entry.summary(&leftover));
+            self.resume.sections.publications.add_item(item);
+        }
+        self.note(
+            "sections.publications",
+            Confidence::Medium,
+            "One publication per line",
+        );
+    }
+
+    fn volunteer(&mut self, text: &TextResume) {
+        let entries = entries(&text.lines(TextSection::Volunteer), false);
+        if entries.is_empty() {
+            return;
+        }
+        self.resume.sections.volunteer = Section::new("volunteer", self.titles().volunteer);
+        for (index, entry) in entries.into_iter().enumerate() {
+            let path = format!("sections.volunteer.items[{index}]");
+            let (mut heading, date) = self.heading(&entry, &path);
+            let location = take_location(&mut heading);
+            let (role, leftover) = self.role(heading, &path, "position", "organization");
+
+            let mut item = Volunteer::new(role.organization, role.title);
+            if let Some(date) = date {
+                item = item.with_date(date);
+            }
+            if let Some(location) = location {
+                item = item.with_location(location);
+            }
+            if let Some(url) = entry.link() {
+                item = item.with_url(url);
+            }
+            item = item.with_summary(entry.summary(&leftover));
+            self.resume.sections.volunteer.add_item(item);
+        }
+    }
+
+    fn interests(&mut self, text: &TextResume) {
+        let mut interests = Vec::new();
+        for line in text.lines(TextSection::Interests) {
+            let line = strip_bullet(line);
+            match labeled_list(line) {
+                Some((label, keywords)) => {
+                    interests.push(Interest::new(label).with_keywords(keywords));
+                }
+                None => interests.extend(list_items(line).into_iter().map(Interest::new)),
+            }
+        }
+        if interests.is_empty() {
+            return;
+        }
+        self.resume.sections.interests = Section::new("interests", self.titles().interests);
+        for interest in interests {
+            self.resume.sections.interests.add_item(interest);
+        }
+        self.note(
+            "sections.interests",
+            Confidence::Medium,
+            "Split on commas and bullets",
+        );
+    }
+
+    fn references(&mut self, text: &TextResume) {
+        let lines: Vec<&str> = text
+            .lines(TextSection::References)
+            .into_iter()
+            .filter(|line| !line.to_lowercase().contains("upon request"))
+            .collect();
+        let entries = entries(&lines, true);
+        if entries.is_empty() {
+            return;
+        }
+        self.resume.sections.references = Section::new("references", self.titles().references);
+        for entry in entries {
+            let mut heading = entry.heading().0.into_iter();
+            let mut item = Reference::new(heading.next().unwrap_or_default());
+            let description: Vec<String> = heading.collect();
+            if !description.is_empty() {
+                item = item.with_description(description.join(", "));
+            }
+            item = item.with_summary(entry.summary(&[]));
+            self.resume.sections.references.add_item(item);
+        }
+        self.note(
+            "sections.references",
+            Confidence::Low,
+            "Name from the first line of each entry",
+        );
+    }
+
+    // ------------------------------------------------------------------------
+    // Entry fields
+    // ------------------------------------------------------------------------
+
+    /// Split an entry's heading, noting how well its date range parsed.
+    fn heading(&mut self, entry: &Entry, path: &str) -> (Vec<String>, Option<String>) {
+        let (heading, date) = entry.heading();
+        if let Some(date) = &date {
+            let (confidence, reason) = if parse_date_range(date).is_some() {
+                (Confidence::High, "Date range pattern")
+            } else {
+                (Confidence::Low, "Looks like a date but did not parse")
+            };
+            self.note(format!("{path}.date"), confidence, reason);
+        }
+        (heading, date)
+    }
+
+    /// Split what's left of a job heading into a title and an organization.
+    /// Returns the parts that were neither.
+    fn role(
+        &mut self,
+        mut heading: Vec<String>,
+        path: &str,
+        title_field: &str,
+        organization_field: &str,
+    ) -> (Role, Vec<String>) {
+        let notes = |draft: &mut Self, confidence, reason| {
+            draft.note(format!("{path}.{title_field}"), confidence, reason);
+            draft.note(format!("{path}.{organization_field}"), confidence, reason);
+        };
+
+        // "Senior Engineer at Acme"
+        if let Some(index) = heading.iter().position(|part| part.contains(" at ")) {
+            let part = heading.remove(index);
+            let (title, organization) = part.split_once(" at ").unwrap_or_default();
+            notes(self, Confidence::Medium, "Written as title at organization");
+            let role = Role {
+                title: title.trim().to_string(),
+                organization: organization.trim().to_string(),
+            };
+            return (role, heading);
+        }
+
+        // "Senior Engineer, Acme" on one line
+        if heading.len() == 1 {
+            if let Some((a, b)) = heading[0].split_once(", ") {
+                let (a, b) = (a.trim().to_string(), b.trim().to_string());
+                heading = vec![a, b];
+            }
+        }
+
+        let title = heading
+            .iter()
+            .position(|part| has_word(part, TITLE_WORDS) && !has_word(part, COMPANY_WORDS));
+        let organization = heading
+            .iter()
+            .position(|part| has_word(part, COMPANY_WORDS) && !has_word(part, TITLE_WORDS));
+        let (title, organization, confidence, reason) = match (title, organization) {
+            (Some(t), Some(o)) => (
+                Some(t),
+                Some(o),
+                Confidence::Medium,
+                "Job title and company words",
+            ),
+            (Some(t), None) => {
+                let o = (0..heading.len()).find(|&i| i != t);
+                (Some(t), o, Confidence::Medium, "Job title words")
+            }
+            (None, Some(o)) => {
+                let t = (0..heading.len()).find(|&i| i != o);
+                (t, Some(o), Confidence::Medium, "Company words")
+            }
+            (None, None) => (
+                (!heading.is_empty()).then_some(0),
+                (heading.len() > 1).then_some(1),
+                Confidence::Low,
+                "Title first, then organization",
+            ),
+        };
+        if title.is_some() || organization.is_some() {
+            notes(self, confidence, reason);
+        }
+
+        let role = Role {
+            title: title.map(|i| heading[i].clone()).unwrap_or_default(),
+            organization: organization.map(|i| heading[i].clone()).unwrap_or_default(),
+        };
+        let leftover = heading
+            .into_iter()
+            .enumerate()
+            .filter(|(i, _)| Some(*i) != title && Some(*i) != organization)
+            .map(|(_, part)| part)
+            .collect();
+        (role, leftover)
+    }
+}
+
+/// Job title and employer (or volunteer organization).
+struct Role {
+    title: String,
+    organization: String,
+}
+
+// ============================================================================
+// Entries
+// ============================================================================
+
+/// Lines of one entry: short heading lines, then its description.
+#[derive(Debug, Default)]
+struct Entry {
+    heading: Vec<String>,
+    /// Paragraph lines and bullets (`Body::Bullet`)
+    body: Vec<Body>,
+}
+
+#[derive(Debug)]
+enum Body {
+    Text(String),
+    Bullet(String),
+}
+
+impl Entry {
+    fn has_date(&self) -> bool {
+        self.heading.iter().any(|line| DATE_RANGE.is_match(line))
+    }
+
+    /// Heading lines split into their parts, with links and the first date
+    /// range taken out. The range is returned as `start - end`.
+    fn heading(&self) -> (Vec<String>, Option<String>) {
+        let mut date = None;
+        let mut parts = Vec::new();
+        for line in &self.heading {
+            let mut line = without(line, &extract_urls(line));
+            if date.is_none() {
+                if let Some(caps) = DATE_RANGE.captures(&line) {
+                    date = Some(match caps.name("end") {
+                        Some(end) => format!("{} - {}", &caps["start"], end.as_str()),
+                        None => caps["start"].to_string(),
+                    });
+                    line = DATE_RANGE.replace(&line, "  ").into_owned();
+                }
+            }
+            parts.extend(split_parts(&line));
+        }
+        (parts, date)
+    }
+
+    /// The first link in the heading.
+    fn link(&self) -> Option<String> {
+        self.heading
+            .iter()
+            .flat_map(|line| extract_urls(line))
+            .find_map(|url| canonicalize_url(&url))
+    }
+
+    /// The description as HTML, after any heading parts nothing claimed.
+    fn summary(&self, leftover: &[String]) -> String {
+        let mut lines: Vec<&str> = leftover.iter().map(String::as_str).collect();
+        if !lines.is_empty() {
+            lines.push("");
+        }
+        let bullets: Vec<String> = self
+            .body
+            .iter()
+            .map(|body| match body {
+                Body::Text(text) => text.clone(),
+                Body::Bullet(text) => format!("- {text}"),
+            })
+            .collect();
+        lines.extend(bullets.iter().map(String::as_str));
+        rich_text(&lines)
+    }
+}
+
+/// Group section lines into entries. With `one_per_line`, every short line
+/// starts an entry (certifications, awards); otherwise up to three short
+/// lines make one heading, and a date, blank line, or description ends it.
+fn entries(lines: &[&str], one_per_line: bool) -> Vec<Entry> {
+    let mut entries: Vec<Entry> = Vec::new();
+    let mut after_blank = false;
+    for line in lines {
+        if line.is_empty() {
+            after_blank = true;
+            continue;
+        }
+        // A wrapped bullet continues in lowercase
+        if starts_lowercase(line) && !after_blank {
+            let last = entries.last_mut().and_then(|entry| entry.body.last_mut());
+            if let Some(Body::Bullet(text) | Body::Text(text)) = last {
+                text.push(' ');
+                text.push_str(line);
+                continue;
+            }
+        }
+        let current = entries.last_mut();
+        if let Some(text) = bullet_text(line) {
+            match current {
+                Some(entry) => entry.body.push(Body::Bullet(text.to_string())),
+                None => entries.push(Entry {
+                    heading: vec![text.to_string()],
+                    body: Vec::new(),
+                }),
+            }
+        } else if is_sentence(line) {
+            match current {
+                Some(entry) => entry.body.push(Body::Text(line.to_string())),
+                None => entries.push(Entry {
+                    heading: Vec::new(),
+                    body: vec![Body::Text(line.to_string())],
+                }),
+            }
+        } else {
+            let dated = DATE_RANGE.is_match(line);
+            let new_entry = match &current {
+                None => true,
+                Some(entry) => {
+                    one_per_line
+                        || !entry.body.is_empty()
+                        || after_blank
+                        || (dated && entry.has_date())
+                        || entry.heading.len() >= 3
+                }
+            };
+            if new_entry {
+                entries.push(Entry::default());
+            }
+            if let Some(entry) = entries.last_mut() {
+                entry.heading.push(line.to_string());
+            }
+        }
+        after_blank = false;
+    }
+    entries.retain(|entry| !entry.heading.is_empty() || !entry.body.is_empty());
+    entries
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+/// The text of a bulleted or numbered line.
+fn bullet_text(line: &str) -> Option<&str> {
+    if let Some(rest) = line.strip_prefix(BULLETS) {
+        // "-5% churn" is not a bullet, "- 5% churn" is
+        if rest.starts_with(char::is_whitespace) || !line.starts_with(['-', '>']) {
+            return Some(rest.trim());
+        }
+    }
+    let digits = line.find(|c: char| !c.is_ascii_digit())?;
+    let rest = line[digits..].strip_prefix(['.', ')'])?;
+    (digits > 0 && digits <= 2 && rest.starts_with(' ')).then(|| rest.trim())
+}
+
+fn strip_bullet(line: &str) -> &str {
+    bullet_text(line).unwrap_or(line)
+}
+
+/// Whether `line` reads as a sentence rather than a heading: long, or
+/// ending in a full stop.
+fn is_sentence(line: &str) -> bool {
+    let words: Vec<&str> = line.split_whitespace().collect();
+    if line.ends_with('.') {
+        // "Globex Inc." is a name, "Built billing services." a sentence
+        return words.len() > 2 && !has_word(words[words.len() - 1], COMPANY_WORDS);
+    }
+    // A heading line with dates can run long: "BSc Physics, TU Munich, 2010 - 2014"
+    (words.len() > 10 || line.chars().count() > MAX_SHORT_LINE) && !DATE_RANGE.is_match(line)
+}
+
+fn starts_lowercase(line: &str) -> bool {
+    line.chars().next().is_some_and(char::is_lowercase)
+}
+
+/// Split a heading line at `|`, bullets, spaced dashes, and runs of spaces.
+fn split_parts(line: &str) -> Vec<String> {
+    let mut parts = vec![line.to_string()];
+    for separator in PART_SEPARATORS {
+        parts = parts
+            .iter()
+            .flat_map(|part| part.split(separator))
+            .map(str::to_string)
+            .collect();
+    }
+    parts
+        .into_iter()
+        .map(|part| {
+            part.trim_matches(|c: char| {
+                c.is_whitespace() || matches!(c, ',' | '|' | '•' | '·' | '–' | '—' | '(' | ')')
+            })
+            .to_string()
+        })
+        .filter(|part| !part.is_empty() && part != "-")
+        .collect()
+}
+
+/// Drop a contact label (`Email:`), and the part if only the label is left.
+fn strip_label(part: String) -> Option<String> {
+    let value = match part.split_once(':') {
+        Some((label, value)) if LABELS.contains(&label.trim().to_lowercase().as_str()) => {
+            value.trim().to_string()
+        }
+        _ => part,
+    };
+    let bare = value.trim_end_matches(':').to_lowercase();
+    (!value.is_empty() && !LABELS.contains(&bare.as_str())).then_some(value)
+}
+
+/// `line` with each of `found` blanked out, so the rest splits cleanly.
+fn without(line: &str, found: &[String]) -> String {
+    found.iter().fold(line.to_string(), |line, found| {
+        line.replacen(found.as_str(), "  ", 1)
+    })
+}
+
+/// Network name and icon for a profile link.
+fn network(url: &str) -> Option<(&'static str, &'static str)> {
+    let host = host(url).to_lowercase();
+    NETWORKS
+        .iter()
+        .find(|(domain, _, _)| host == *domain || host.ends_with(&format!(".{domain}")))
+        .map(|(_, name, icon)| (*name, *icon))
+}
+
+fn is_location(part: &str) -> bool {
+    part.eq_ignore_ascii_case("remote")
+        || (LOCATION.is_match(part)
+            && part.split_whitespace().count() <= 5
+            && !has_word(part, TITLE_WORDS)
+            && !has_word(part, COMPANY_WORDS)
+            && !has_word(part, INSTITUTION_WORDS))
+}
+
+/// Take the location out of `heading`: a whole part shaped like one, or
+/// the end of a part such as `Acme GmbH, Berlin, Germany`.
+fn take_location(heading: &mut Vec<String>) -> Option<String> {
+    if let Some(location) = take(heading, is_location) {
+        return Some(location);
+    }
+    heading.iter_mut().find_map(|part| {
+        let (index, _) = part
+            .match_indices(", ")
+            .find(|(index, _)| is_location(&part[index + 2..]))?;
+        let location = part[index + 2..].to_string();
+        part.truncate(index);
+        Some(location)
+    })
+}
+
+/// Remove and return the first part matching `pred`.
+fn take(heading: &mut Vec<String>, pred: impl Fn(&str) -> bool) -> Option<String> {
+    let index = heading.iter().position(|part| pred(part))?;
+    Some(heading.remove(index))
+}
+
+/// Two to four capitalized words of letters.
+fn looks_like_name(part: &str) -> bool {
+    let words: Vec<&str> = part.split_whitespace().collect();
+    (2..=4).contains(&words.len())
+        && words.iter().all(|word| {
+            word.chars().next().is_some_and(char::is_uppercase)
+                && word
+                    .chars()
+                    .all(|c| c.is_alphabetic() || matches!(c, '.' | '\'' | '-'))
+        })
+}
+
+/// Whether any word of `part`, lowercased, is in `words`.
+fn has_word(part: &str, words: &[&str]) -> bool {
+    part.split(|c: char| c.is_whitespace() || matches!(c, ',' | '/' | '(' | ')'))
+        .map(str::to_lowercase)
+        .any(|word| words.contains(&word.as_str()))
+}
+
+fn is_degree(part: &str) -> bool {
+    let first = part
+        .split_whitespace()
+        .next()
+        .unwrap_or_default()
+        .trim_end_matches(',');
+    let lower = first.to_lowercase();
+    DEGREE_WORDS.contains(&lower.as_str())
+        || DEGREE_ABBREVIATIONS.contains(&lower.replace('.', "").as_str())
+}
+
+/// Split a degree into its type and field: `BSc Computer Science` or
+/// `Bachelor of Science in Physics`.
+fn split_degree(degree: &str) -> (String, String) {
+    if let Some((study_type, area)) = degree.split_once(" in ") {
+        return (study_type.trim().to_string(), area.trim().to_string());
+    }
+    let (first, rest) = degree.split_once(' ').unwrap_or((degree, ""));
+    let first = first.trim_end_matches(',');
+    if DEGREE_ABBREVIATIONS.contains(&first.to_lowercase().replace('.', "").as_str()) {
+        let rest = rest.trim().trim_start_matches(',').trim();
+        return (first.to_string(), rest.to_string());
+    }
+    (degree.to_string(), String::new())
+}
+
+/// `Languages: Rust, Go` as a label with its items.
+fn labeled_list(line: &str) -> Option<(String, Vec<String>)> {
+    let (label, items) = line.split_once(':')?;
+    let label = label.trim();
+    let items = list_items(items);
+    (!label.is_empty() && label.split_whitespace().count() <= 4 && !items.is_empty())
+        .then(|| (label.to_string(), items))
+}
+
+/// Items of a comma, semicolon, or bullet separated list.
+fn list_items(line: &str) -> Vec<String> {
+    line.split([',', ';', '|', '•', '·'])
+        .map(|item| item.trim().trim_end_matches('.').trim())
+        .filter(|item| !item.is_empty() && item.chars().count() <= 60)
+        .map(str::to_string)
+        .collect()
+}
+
+/// `English (native)`, `German - fluent`, or `French: basic` as a name and
+/// fluency.
+fn split_fluency(item: &str) -> (String, Option<String>) {
+    let split = item
+        .split_once(" (")
+        .map(|(name, rest)| (name, rest.trim_end_matches(')')))
+        .or_else(|| item.split_once(" - "))
+        .or_else(|| item.split_once(": "))
+        .or_else(|| item.split_once(" – "));
+    match split {
+        Some((name, fluency)) if !fluency.trim().is_empty() => {
+            (name.trim().to_string(), Some(fluency.trim().to_string()))
+        }
+        _ => (item.trim().to_string(), None),
+    }
+}
+
+/// Plain lines as HTML: paragraphs split at blank lines, bulleted lines as a
+/// list, and wrapped lines joined.
+fn rich_text(lines: &[&str]) -> String {
+    let mut html = String::new();
+    let mut paragraph: Vec<&str> = Vec::new();
+    let mut bullets: Vec<String> = Vec::new();
+
+    fn flush_paragraph(html: &mut String, paragraph: &mut Vec<&str>) {
+        if !paragraph.is_empty() {
+            html.push_str(&format!("<p>{}</p>", escape_html(&paragraph.join(" "))));
+            paragraph.clear();
+        }
+    }
+    fn flush_bullets(html: &mut String, bullets: &mut Vec<String>) {
+        if !bullets.is_empty() {
+            html.push_str("<ul>");
+            for bullet in bullets.drain(..) {
+                html.push_str(&format!("<li>{}</li>", escape_html(&bullet)));
+            }
+            html.push_str("</ul>");
+        }
+    }
+
+    for line in lines {
+        if line.is_empty() {
+            flush_paragraph(&mut html, &mut paragraph);
+            flush_bullets(&mut html, &mut bullets);
+        } else if let Some(text) = bullet_text(line) {
+            flush_paragraph(&mut html, &mut paragraph);
+            bullets.push(text.to_string());
+        } else if let (Some(last), true) = (bullets.last_mut(), starts_lowercase(line)) {
+            last.push(' ');
+            last.push_str(line);
+        } else {
+            flush_bullets(&mut html, &mut bullets);
+            paragraph.push(line);
+        }
+    }
+    flush_paragraph(&mut html, &mut paragraph);
+    flush_bullets(&mut html, &mut bullets);
+    html
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RESUME: &str = "\
+Jane Q. Public
+Senior Software Engineer • Berlin, Germany
+Email: jane@example.org • Phone: +49 30 1234567 • github.com/janeq
+
+## Work Experience
+Acme GmbH, Berlin, Germany
+Staff Engineer   03/2019 – Present
+* Led the move to Rust, cutting p99 latency
+  by 40% across the fleet
+* Mentored six engineers
+
+Globex Corporation
+Backend Developer
+2015 - 2019
+Built billing services.
+
+Education:
+Bachelor of Science in Physics, Technical University of Munich, 2010 - 2014, GPA 3.8
+
+Technical Skills
+Languages: Rust, Go
+PostgreSQL; Kubernetes | Terraform
+
+LANGUAGES
+German (Native), English - fluent
+";
+
+    fn draft(text: &str) -> TextDraft {
+        TextResumeParser.draft(text.as_bytes()).unwrap()
+    }
+
+    fn confidence(draft: &TextDraft, path: &str) -> Confidence {
+        draft
+            .confidence
+            .iter()
+            .find(|field| field.path == path)
+            .unwrap_or_else(|| panic!("no confidence for {path}"))
+            .confidence
+    }
+
+    #[test]
+    fn test_header_contact_details() {
+        let draft = draft(RESUME);
+        let basics = &draft.resume.basics;
+        assert_eq!(basics.name, "Jane Q. Public");
+        assert_eq!(basics.name_parts.family, "Public");
+        assert_eq!(basics.headline, "Senior Software Engineer");
+        assert_eq!(basics.location, "Berlin, Germany");
+        assert_eq!(basics.email, "jane@example.org");
+        assert_eq!(basics.phone, "+49 30 1234567");
+
+        let profile = &draft.resume.sections.profiles.items[0];
+        assert_eq!(profile.network, "GitHub");
+        assert_eq!(profile.username, "janeq");
+        assert_eq!(profile.url.href, "https://github.com/janeq");
+
+        assert_eq!(confidence(&draft, "basics.email"), Confidence::High);
+        assert_eq!(confidence(&draft, "basics.name"), Confidence::Medium);
+    }
+
+    #[test]
+    fn test_experience_entries() {
+        let draft = draft(RESUME);
+        let experience = &draft.resume.sections.experience;
+        assert_eq!(experience.name, "Experience");
+        assert_eq!(experience.len(), 2);
+
+        let acme = &experience.items[0];
+        assert_eq!(acme.company, "Acme GmbH");
+        assert_eq!(acme.position, "Staff Engineer");
+        assert_eq!(acme.location, "Berlin, Germany");
+        assert_eq!(acme.date, "03/2019 - Present");
+        assert_eq!(acme.start_date.as_deref(), Some("2019-03"));
+        assert_eq!(
+            acme.summary,
+            "<ul><li>Led the move to Rust, cutting p99 latency by 40% across the fleet</li>\
+             <li>Mentored six engineers</li></ul>"
+        );
+
+        let globex = &experience.items[1];
+        assert_eq!(globex.company, "Globex Corporation");
+        assert_eq!(globex.position, "Backend Developer");
+        assert_eq!(globex.date, "2015 - 2019");
+        assert_eq!(globex.summary, "<p>Built billing services.</p>");
+
+        let path = "sections.experience.items[0]";
+        assert_eq!(
+            confidence(&draft, &format!("{path}.date")),
+            Confidence::High
+        );
+        assert_eq!(
+            confidence(&draft, &format!("{path}.company")),
+            Confidence::Medium
+        );
+    }
+
+    #[test]
+    fn test_education_on_one_line() {
+        let draft = draft(RESUME);
+        let education = &draft.resume.sections.education.items[0];
+        assert_eq!(education.institution, "Technical University of Munich");
+        assert_eq!(education.study_type, "Bachelor of Science");
+        assert_eq!(education.area, "Physics");
+        assert_eq!(education.date, "2010 - 2014");
+        assert_eq!(education.score, "3.8");
+    }
+
+    #[test]
+    fn test_list_sections() {
+        let draft = draft(RESUME);
+        let skills = &draft.resume.sections.skills.items;
+        let names: Vec<&str> = skills.iter().map(|skill| skill.name.as_str()).collect();
+        assert_eq!(
+            names,
+            ["Languages", "PostgreSQL", "Kubernetes", "Terraform"]
+        );
+        assert_eq!(skills[0].keywords, ["Rust", "Go"]);
+
+        let languages = &draft.resume.sections.languages.items;
+        assert_eq!(languages[0].name, "German");
+        assert_eq!(languages[0].level, 5);
+        assert_eq!(languages[1].name, "English");
+        assert_eq!(languages[1].description, "fluent");
+        assert_eq!(languages[1].level, 4);
+    }
+
+    #[test]
+    fn test_guesses_without_cues_are_low_confidence() {
+        let draft = draft("jane doe\nI write software for small businesses and have done so for ten years.\n\nExperience\nPlumbing Plus\nWebmaster\n");
+        assert_eq!(draft.resume.basics.name, "jane doe");
+        assert!(draft
+            .resume
+            .sections
+            .summary
+            .content
+            .starts_with("<p>I write software"));
+
+        let uncertain: Vec<&str> = draft.uncertain().map(|field| field.path.as_str()).collect();
+        assert_eq!(
+            uncertain,
+            [
+                "basics.name",
+                "sections.summary.content",
+                "sections.experience.items[0].position",
+                "sections.experience.items[0].company",
+            ]
+        );
+        let job = &draft.resume.sections.experience.items[0];
+        assert_eq!(job.position, "Plumbing Plus");
+        assert_eq!(job.company, "Webmaster");
+    }
+
+    #[test]
+    fn test_headings() {
+        assert_eq!(heading("EXPERIENCE"), Some(TextSection::Experience));
+        assert_eq!(
+            heading("## Work  Experience:"),
+            Some(TextSection::Experience)
+        );
+        assert_eq!(heading("Honors & Awards"), Some(TextSection::Awards));
+        assert_eq!(heading("Experience with Rust"), None);
+        assert_eq!(heading(""), None);
+    }
+
+    #[test]
+    fn test_parse_returns_the_resume() {
+        let resume = TextResumeParser.parse(RESUME.as_bytes()).unwrap();
+        assert_eq!(resume.basics.name, "Jane Q. Public");
+    }
+
+    #[test]
+    fn test_rejects_empty_and_oversized_input() {
+        assert!(matches!(
+            TextResumeParser.parse(b" \n\t\n"),
+            Err(ParseError::ValidationError(_))
+        ));
+        assert!(matches!(
+            TextResumeParser.parse(&[0xff, 0xfe]),
+            Err(ParseError::ReadError(_))
+        ));
+        let huge = vec![b'a'; MAX_TEXT_BYTES + 1];
+        assert!(matches!(
+            TextResumeParser.parse(&huge),
+            Err(ParseError::LimitExceeded(_))
+        ));
+    }
+}
@@ -0,0 +1,342 @@
+//! SVG career timeline.
+//!
+//! [`render_timeline`] draws each visible position as a bar on a shared
+//! year axis, one row per position labeled with the company, and shades the
+//! years spent in education behind them. The SVG is self-contained, uses the
+//! resume's theme colors and font, and scales with its `viewBox`, so it can
+//! be embedded in a web page as is.
+
+use std::fmt::Write;
+
+use rustume_schema::ResumeData;
+use rustume_utils::{
+    escape_html, hex_to_rgb, linear_transform, rgb_to_hex, DateRange, PartialDate,
+};
+
+use crate::ics::join;
+
+const WIDTH: f64 = 800.0;
+const PADDING: f64 = 16.0;
+const ROW_HEIGHT: f64 = 28.0;
+const BAR_HEIGHT: f64 = 20.0;
+const AXIS_HEIGHT: f64 = 24.0;
+const FONT_SIZE: f64 = 12.0;
+
+/// Rough advance of one character at [`FONT_SIZE`], used to decide whether
+/// a label fits inside its bar.
+const CHAR_WIDTH: f64 = 6.5;
+
+/// Most year labels on the axis; longer careers label every few years.
+const MAX_TICKS: i64 = 12;
+
+/// A dated item as a half-open span of month indexes (`year * 12 + month - 1`).
+struct Bar {
+    label: String,
+    title: String,
+    start: i64,
+    end: i64,
+}
+
+/// Draw the visible experience and education items of `resume` as an SVG
+/// timeline. Ongoing items run to today; items whose dates can't be read
+/// are left out.
+pub fn render_timeline(resume: &ResumeData) -> String {
+    timeline_svg(resume, &PartialDate::today())
+}
+
+fn timeline_svg(resume: &ResumeData, today: &PartialDate) -> String {
+    let sections = &resume.sections;
+    let mut positions: Vec<Bar> = Vec::new();
+    if sections.experience.visible {
+        for item in sections.experience.items.iter().filter(|item| item.visible) {
+            let Some(range) = item.date_range() else {
+                continue;
+            };
+            let (start, end) = span(&range, today);
+            let label = if item.company.trim().is_empty() {
+                item.position.trim()
+            } else {
+                item.company.trim()
+            };
+            positions.push(Bar {
+                label: label.to_string(),
+                title: join([item.position.as_str(), item.company.as_str(), &item.date]),
+                start,
+                end,
+            });
+        }
+    }
+    // Earliest position on top; ties keep resume order
+    positions.sort_by_key(|bar| bar.start);
+
+    let mut schools: Vec<Bar> = Vec::new();
+    if sections.education.visible {
+        for item in sections.education.items.iter().filter(|item| item.visible) {
+            let Some(range) = item.date_range() else {
+                continue;
+            };
+            let (start, end) = span(&range, today);
+            schools.push(Bar {
+                label: item.institution.trim().to_string(),
+                title: join([
+                    item.study_type.as_str(),
+                    item.area.as_str(),
+                    item.institution.as_str(),
+                    &item.date,
+                ]),
+                start,
+                end,
+            });
+        }
+    }
+
+    let theme = &resume.metadata.theme;
+    let background = color(&theme.background, "#ffffff");
+    let text = color(&theme.text, "#000000");
+    let primary = color(&theme.primary, "#dc2626");
+    let family = resume
+        .metadata
+        .typography
+        .font
+        .family
+        .replace(['\'', '"'], "");
+
+    let label_row = if schools.is_empty() { 0.0 } else { ROW_HEIGHT };
+    let rows_top = PADDING + label_row;
+    let axis_top = rows_top + positions.len() as f64 * ROW_HEIGHT;
+    let dated = positions.iter().chain(&schools);
+    let (Some(first), Some(last)) = (
+        dated.clone().map(|bar| bar.start).min(),
+        dated.map(|bar| bar.end).max(),
+    ) else {
+        return format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 {WIDTH} {height}\" \
+             width=\"{WIDTH}\" height=\"{height}\"/>\n",
+            height = PADDING * 2.0
+        );
+    };
+    let height = axis_top + AXIS_HEIGHT + PADDING;
+
+    // Whole years on the axis
+    let first_year = first.div_euclid(12);
+    let last_year = (last + 11).div_euclid(12);
+    let x = |month: i64| {
+        linear_transform(
+            month as f64,
+            (first_year * 12) as f64,
+            (last_year * 12) as f64,
+            PADDING,
+            WIDTH - PADDING,
+        )
+    };
+
+    let mut svg = String::new();
+    let _ = writeln!(
+        svg,
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 {WIDTH} {height}\" \
+         width=\"{WIDTH}\" height=\"{height}\" font-family=\"{}\" font-size=\"{FONT_SIZE}\" \
+         role=\"img\">",
+        escape_html(&format!("'{family}', sans-serif"))
+    );
+    let name = resume.basics.name.trim();
+    if !name.is_empty() {
+        let _ = writeln!(svg, "<title>{}</title>", escape_html(name));
+    }
+    let _ = writeln!(
+        svg,
+        "<rect width=\"{WIDTH}\" height=\"{height}\" fill=\"{background}\"/>"
+    );
+
+    if !schools.is_empty() {
+        svg.push_str("<g class=\"education\">\n");
+        for bar in &schools {
+            let (left, right) = (x(bar.start), x(bar.end));
+            let _ = writeln!(
+                svg,
+                "<rect x=\"{left:.1}\" y=\"{PADDING}\" width=\"{:.1}\" height=\"{:.1}\" \
+                 fill=\"{primary}\" fill-opacity=\"0.12\"><title>{}</title></rect>",
+                right - left,
+                axis_top - PADDING,
+                escape_html(&bar.title)
+            );
+            let _ = writeln!(
+                svg,
+                "<text x=\"{:.1}\" y=\"{:.1}\" dominant-baseline=\"middle\" fill=\"{text}\" \
+                 fill-opacity=\"0.7\">{}</text>",
+                left + 4.0,
+                PADDING + ROW_HEIGHT / 2.0,
+                escape_html(&bar.label)
+            );
+        }
+        svg.push_str("</g>\n");
+    }
+
+    if !positions.is_empty() {
+        svg.push_str("<g class=\"experience\">\n");
+        for (row, bar) in positions.iter().enumerate() {
+            let (left, right) = (x(bar.start), x(bar.end));
+            let width = right - left;
+            let top = rows_top + row as f64 * ROW_HEIGHT + (ROW_HEIGHT - BAR_HEIGHT) / 2.0;
+            let _ = writeln!(
+                svg,
+                "<rect x=\"{left:.1}\" y=\"{top:.1}\" width=\"{width:.1}\" height=\"{BAR_HEIGHT}\" \
+                 rx=\"3\" fill=\"{primary}\"><title>{}</title></rect>",
+                escape_html(&bar.title)
+            );
+
+            // Inside the bar when it fits, else beside it on whichever side has room
+            let label_width = bar.label.chars().count() as f64 * CHAR_WIDTH + 8.0;
+            let (label_x, anchor, fill) = if label_width <= width {
+                (left + 4.0, "start", background.as_str())
+            } else if right + label_width <= WIDTH - PADDING {
+                (right + 4.0, "start", text.as_str())
+            } else {
+                (left - 4.0, "end", text.as_str())
+            };
+            let _ = writeln!(
+                svg,
+                "<text x=\"{label_x:.1}\" y=\"{:.1}\" text-anchor=\"{anchor}\" \
+                 dominant-baseline=\"middle\" fill=\"{fill}\">{}</text>",
+                top + BAR_HEIGHT / 2.0,
+                escape_html(&bar.label)
+            );
+        }
+        svg.push_str("</g>\n");
+    }
+
+    let step = ((last_year - first_year) + MAX_TICKS - 1) / MAX_TICKS;
+    let _ = writeln!(
+        svg,
+        "<g class=\"axis\" fill=\"{text}\" stroke=\"{text}\" text-anchor=\"middle\">"
+    );
+    let _ = writeln!(
+        svg,
+        "<line x1=\"{PADDING}\" y1=\"{axis_top}\" x2=\"{}\" y2=\"{axis_top}\" stroke-opacity=\"0.4\"/>",
+        WIDTH - PADDING
+    );
+    for year in (first_year..=last_year).step_by(step.max(1) as usize) {
+        let tick = x(year * 12);
+        let _ = writeln!(
+            svg,
+            "<line x1=\"{tick:.1}\" y1=\"{axis_top}\" x2=\"{tick:.1}\" y2=\"{}\" \
+             stroke-opacity=\"0.4\"/><text x=\"{tick:.1}\" y=\"{}\" stroke=\"none\">{year}</text>",
+            axis_top + 4.0,
+            axis_top + AXIS_HEIGHT - 4.0
+        );
+    }
+    svg.push_str("</g>\n</svg>\n");
+    svg
+}
+
+/// Half-open month span of `range`. A year-only end runs through December.
+fn span(range: &DateRange, today: &PartialDate) -> (i64, i64) {
+    let start = month_index(range.start.year, range.start.month.unwrap_or(1));
+    let end = range.end.unwrap_or(*today);
+    let end = month_index(end.year, end.month.unwrap_or(12)) + 1;
+    (start, end.max(start + 1))
+}
+
+fn month_index(year: i32, month: u32) -> i64 {
+    i64::from(year) * 12 + i64::from(month) - 1
+}
+
+/// `value` as `#rrggbb`, or `fallback` when it isn't a hex color.
+fn color(value: &str, fallback: &str) -> String {
+    hex_to_rgb(value).map_or_else(|| fallback.to_string(), rgb_to_hex)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rustume_schema::{Education, Experience};
+
+    fn today() -> PartialDate {
+        PartialDate {
+            year: 2024,
+            month: Some(3),
+            day: Some(15),
+        }
+    }
+
+    fn sample() -> ResumeData {
+        let mut resume = ResumeData::default();
+        resume.basics.name = "Ada Lovelace".to_string();
+        resume
+            .sections
+            .experience
+            .add_item(Experience::new("Babbage & Co", "Analyst").with_dates("2018-01", None));
+        resume.sections.experience.add_item(
+            Experience::new("Acme", "Engineer").with_dates("2014-06", Some("2017-12".to_string())),
+        );
+        resume
+            .sections
+            .experience
+            .add_item(Experience::new("Undated", "Intern"));
+        resume.sections.education.add_item(
+            Education::new("University of London", "Mathematics")
+                .with_dates("2010", Some("2014".to_string())),
+        );
+        resume
+    }
+
+    fn count(svg: &str, needle: &str) -> usize {
+        svg.matches(needle).count()
+    }
+
+    #[test]
+    fn test_one_bar_per_dated_position() {
+        let svg = timeline_svg(&sample(), &today());
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+        assert!(svg.ends_with("</svg>\n"));
+        assert!(svg.contains("<title>Ada Lovelace</title>"));
+        assert!(svg.contains(">Babbage &amp; Co</text>"));
+        assert!(!svg.contains("Undated"));
+
+        let experience = svg
+            .split("<g class=\"experience\">")
+            .nth(1)
+            .and_then(|rest| rest.split("</g>").next())
+            .unwrap();
+        assert_eq!(count(experience, "<rect"), 2);
+        // Earliest first
+        assert!(experience.find("Acme").unwrap() < experience.find("Babbage").unwrap());
+
+        let education = svg.split("<g class=\"experience\">").next().unwrap();
+        assert!(education.contains("fill-opacity=\"0.12\""));
+        assert!(education.contains(">University of London</text>"));
+    }
+
+    #[test]
+    fn test_axis_spans_whole_years() {
+        let svg = timeline_svg(&sample(), &today());
+        let axis = svg.split("<g class=\"axis\"").nth(1).unwrap();
+        // 2010 through the end of 2024, labeled every other year
+        assert!(axis.contains(">2010</text>"));
+        assert!(axis.contains(">2024</text>"));
+        assert!(!axis.contains(">2011</text>"));
+        assert!(axis.contains(&format!("x=\"{PADDING:.1}\"")));
+    }
+
+    #[test]
+    fn test_theme_colors_and_hidden_items() {
+        let mut resume = sample();
+        resume.metadata.theme.primary = "#1D4ED8".to_string();
+        resume.metadata.theme.text = "not a color".to_string();
+        resume.sections.education.visible = false;
+        resume.sections.experience.items[0].visible = false;
+
+        let svg = timeline_svg(&resume, &today());
+        assert!(svg.contains("fill=\"#1d4ed8\""));
+        assert!(svg.contains("fill=\"#000000\""));
+        assert!(!svg.contains("University of London"));
+        assert!(!svg.contains("Babbage"));
+        assert!(svg.contains(">2014</text>"));
+    }
+
+    #[test]
+    fn test_empty_timeline() {
+        let svg = timeline_svg(&ResumeData::default(), &today());
+        assert!(svg.starts_with("<svg"));
+        assert!(!svg.contains("<rect"));
+    }
+}
@@ -1,6 +1,8 @@
 //! Parser trait definitions.
 
-use rustume_schema::ResumeData;
+use std::io::Read;
+
+use rustume_schema::{ErrorCode, ResumeData, RustumeError};
 use thiserror::Error;
 
 /// Parser error types.
@@ -18,6 +20,40 @@ pub enum ParseError {
 
     #[error("Conversion failed: {0}")]
     ConversionError(String),
+
+    /// Input exceeded a configured safety limit (size, entry count, ...).
+    #[error("Limit exceeded: {0}")]
+    LimitExceeded(String),
+
+    /// The input is not a readable ZIP archive, or an entry in it is corrupt.
+    #[error("Invalid ZIP archive: {0}")]
+    InvalidArchive(String),
+
+    /// The input parsed, but the resume it holds fails validation and the
+    /// import wasn't lenient.
+    #[error("Imported resume is invalid: {0}")]
+    InvalidResume(String),
+}
+
+impl ParseError {
+    /// Stable code for this error.
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Self::ReadError(_) => ErrorCode::ParseReadFailed,
+            Self::ValidationError(_) => ErrorCode::ParseInvalidFormat,
+            Self::DeserializeError(_) => ErrorCode::ParseInvalidJson,
+            Self::ConversionError(_) => ErrorCode::ParseConversionFailed,
+            Self::LimitExceeded(_) => ErrorCode::ParseLimitExceeded,
+            Self::InvalidArchive(_) => ErrorCode::ParseLinkedinBadZip,
+            Self::InvalidResume(_) => ErrorCode::ValidationFailed,
+        }
+    }
+}
+
+impl From<ParseError> for RustumeError {
+    fn from(err: ParseError) -> Self {
+        Self::new(err.code(), err.to_string())
+    }
 }
 
 impl From<std::io::Error> for ParseError {
@@ -26,6 +62,51 @@ impl From<std::io::Error> for ParseError {
     }
 }
 
+/// Stage of the parser pipeline, for progress reporting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParsePhase {
+    Read,
+    Validate,
+    Convert,
+}
+
+impl ParsePhase {
+    /// Lowercase name, as shown to users and passed to JS callbacks.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Read => "read",
+            Self::Validate => "validate",
+            Self::Convert => "convert",
+        }
+    }
+}
+
+/// A progress update from [`Parser::parse_with_progress`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseProgress {
+    /// Pipeline stage in progress.
+    pub phase: ParsePhase,
+    /// Overall completion, 0 to 100. Never decreases during one parse.
+    pub percent: u8,
+    /// What is being worked on, e.g. the file inside an archive.
+    pub detail: Option<String>,
+}
+
+impl ParseProgress {
+    pub fn new(phase: ParsePhase, percent: u8) -> Self {
+        Self {
+            phase,
+            percent: percent.min(100),
+            detail: None,
+        }
+    }
+
+    pub fn with_detail(mut self, detail: impl Into<String>) -> Self {
+        self.detail = Some(detail.into());
+        self
+    }
+}
+
 /// Three-stage parser pipeline.
 pub trait Parser {
     type RawData;
@@ -46,4 +127,60 @@ pub trait Parser {
         let validated = self.validate(raw)?;
         self.convert(validated)
     }
+
+    /// Full pipeline, calling `progress` as it goes.
+    ///
+    /// The default reports the start of each stage and completion at 100.
+    /// Parsers with long stages (archives) override it to report finer steps.
+    /// Pass a closure that forwards to a channel to consume updates elsewhere.
+    fn parse_with_progress(
+        &self,
+        input: &[u8],
+        progress: &mut dyn FnMut(ParseProgress),
+    ) -> Result<ResumeData, ParseError> {
+        progress(ParseProgress::new(ParsePhase::Read, 0));
+        let raw = self.read(input)?;
+        progress(ParseProgress::new(ParsePhase::Validate, 33));
+        let validated = self.validate(raw)?;
+        progress(ParseProgress::new(ParsePhase::Convert, 67));
+        let resume = self.convert(validated)?;
+        progress(ParseProgress::new(ParsePhase::Convert, 100));
+        Ok(resume)
+    }
+}
+
+/// A [`Parser`] that can read its input from a reader.
+///
+/// Callers pass a reader, such as an upload's body, instead of the bytes.
+/// Reading and validating happen together as the input arrives, so the
+/// parser holds only what it is working on (the LinkedIn parser inflates one
+/// archive entry at a time) and stops as soon as the input passes its
+/// limits.
+pub trait StreamingParser: Parser {
+    /// Stages 1 and 2 over a reader: read and validate `input` to its end.
+    fn read_from(&self, input: &mut dyn Read) -> Result<Self::ValidatedData, ParseError>;
+
+    /// Full pipeline over a stream.
+    fn parse_reader(&self, input: &mut dyn Read) -> Result<ResumeData, ParseError> {
+        let validated = self.read_from(input)?;
+        self.convert(validated)
+    }
+}
+
+/// A [`StreamingParser`] that can read its input from an async reader
+/// (`async` feature), with the same limits.
+///
+/// Reading runs on tokio's blocking pool, waiting on `input` for each chunk.
+/// Conversion is left to the caller; it is CPU-bound too, so run it where
+/// blocking is fine, such as `tokio::task::spawn_blocking`.
+#[cfg(feature = "async")]
+pub trait AsyncParser: StreamingParser {
+    /// Stages 1 and 2 over an async reader: read and validate `input` to its
+    /// end. Must be awaited inside a tokio runtime.
+    fn read_async<'a, R>(
+        &'a self,
+        input: R,
+    ) -> impl std::future::Future<Output = Result<Self::ValidatedData, ParseError>> + Send + 'a
+    where
+        R: tokio::io::AsyncRead + Unpin + Send + 'static;
 }
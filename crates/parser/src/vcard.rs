@@ -0,0 +1,598 @@
+//! vCard (`.vcf`) import and export for contact details.
+//!
+//! [`VcardParser`] reads the first card of a vCard 3.0 or 4.0 file (RFC 6350)
+//! into `basics`: name, title, email, phone, address, photo, and URLs. The
+//! preferred value wins where a card has several (`PREF=1`, or `TYPE=pref`
+//! in 3.0). The first URL becomes the website and the rest, with any
+//! `X-SOCIALPROFILE`, become profiles.
+//!
+//! [`to_vcard`] goes the other way, writing `basics` and the visible
+//! profiles as a vCard 4.0 for a contact QR code or a PDF attachment.
+
+use crate::content_line::{escape, fold};
+use crate::traits::{ParseError, Parser};
+use rustume_schema::{PersonName, Profile, ResumeData, Section, Url};
+use rustume_utils::Locale;
+
+/// vCard contact parser.
+pub struct VcardParser;
+
+// ============================================================================
+// vCard Types
+// ============================================================================
+
+/// The properties of one card, in file order.
+#[derive(Debug, Default)]
+pub struct Vcard {
+    properties: Vec<VcardProperty>,
+}
+
+/// A content line: `NAME;PARAM=a,b:value`.
+#[derive(Debug)]
+struct VcardProperty {
+    /// Uppercase, without the group prefix
+    name: String,
+    /// Uppercase parameter names with their values
+    params: Vec<(String, String)>,
+    /// Still escaped
+    value: String,
+}
+
+impl VcardProperty {
+    /// Parse one unfolded content line.
+    fn parse(line: &str) -> Option<Self> {
+        let (head, value) = split_unquoted(line, ':')?;
+        let mut parts = split_all_unquoted(head, ';').into_iter();
+        let name = parts.next()?;
+        // Drop the group, as in `item1.URL`
+        let name = name.rsplit('.').next().unwrap_or(name).trim();
+        if name.is_empty() {
+            return None;
+        }
+
+        let params = parts
+            .flat_map(|param| {
+                // 3.0 allows bare types: `TEL;WORK;VOICE`
+                let (key, values) = split_unquoted(param, '=').unwrap_or(("TYPE", param));
+                let key = key.trim().to_ascii_uppercase();
+                split_all_unquoted(values, ',')
+                    .into_iter()
+                    .map(move |v| (key.clone(), v.trim().trim_matches('"').to_string()))
+            })
+            .collect();
+
+        Some(Self {
+            name: name.to_ascii_uppercase(),
+            params,
+            value: value.to_string(),
+        })
+    }
+
+    fn param<'a>(&'a self, key: &'a str) -> impl Iterator<Item = &'a str> {
+        self.params
+            .iter()
+            .filter(move |(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+
+    fn has_type(&self, ty: &str) -> bool {
+        self.param("TYPE").any(|t| t.eq_ignore_ascii_case(ty))
+    }
+
+    /// Lower is preferred: `PREF=1` is 1, a 3.0 `TYPE=pref` counts as 1,
+    /// and no preference sorts last.
+    fn preference(&self) -> u8 {
+        self.param("PREF")
+            .find_map(|pref| pref.parse().ok())
+            .or_else(|| self.has_type("pref").then_some(1))
+            .unwrap_or(u8::MAX)
+    }
+
+    fn text(&self) -> String {
+        unescape(self.value.trim())
+    }
+
+    /// Components of a structured value such as `N` or `ADR`.
+    fn components(&self) -> Vec<String> {
+        split_all_unescaped(&self.value, ';')
+            .iter()
+            .map(|part| unescape(part.trim()))
+            .collect()
+    }
+}
+
+impl Vcard {
+    /// Read the first card of a `.vcf` file.
+    fn from_text(text: &str) -> Self {
+        let mut card = Self::default();
+        let mut in_card = false;
+        for line in unfold(text) {
+            let Some(property) = VcardProperty::parse(&line) else {
+                continue;
+            };
+            match property.name.as_str() {
+                "BEGIN" if property.value.trim().eq_ignore_ascii_case("VCARD") => in_card = true,
+                "END" if in_card && property.value.trim().eq_ignore_ascii_case("VCARD") => break,
+                _ if in_card => card.properties.push(property),
+                _ => {}
+            }
+        }
+        card
+    }
+
+    fn all<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a VcardProperty> {
+        self.properties.iter().filter(move |p| p.name == name)
+    }
+
+    /// The preferred property called `name`, or the first one.
+    fn preferred<'a>(&'a self, name: &'a str) -> Option<&'a VcardProperty> {
+        self.all(name)
+            .filter(|p| !p.value.trim().is_empty())
+            .min_by_key(|p| p.preference())
+    }
+
+    fn preferred_text(&self, name: &str) -> Option<String> {
+        self.preferred(name).map(VcardProperty::text)
+    }
+
+    /// `FN`, or the `N` components in display order.
+    fn name(&self) -> Option<String> {
+        self.preferred_text("FN").or_else(|| {
+            let n = self.preferred("N")?.components();
+            // family; given; additional; prefixes; suffixes
+            let order = [3, 1, 2, 0, 4];
+            let parts: Vec<&str> = order
+                .iter()
+                .filter_map(|&i| n.get(i).map(String::as_str))
+                .filter(|part| !part.is_empty())
+                .collect();
+            (!parts.is_empty()).then(|| parts.join(" "))
+        })
+    }
+
+    /// The `N` components as name parts. Lists within a component, such as
+    /// several given names, are separated by commas.
+    fn name_parts(&self) -> Option<PersonName> {
+        let n = self.preferred("N")?.components();
+        let component = |i: usize, separator: &str| {
+            n.get(i)
+                .map(|part| {
+                    part.split(',')
+                        .map(str::trim)
+                        .filter(|word| !word.is_empty())
+                        .collect::<Vec<_>>()
+                        .join(separator)
+                })
+                .unwrap_or_default()
+        };
+        // family; given; additional; prefixes; suffixes
+        let given = [component(1, " "), component(2, " ")];
+        let parts = PersonName {
+            prefix: component(3, " "),
+            given: given.join(" ").trim().to_string(),
+            family: component(0, " "),
+            suffix: component(4, ", "),
+        };
+        (!parts.is_empty()).then_some(parts)
+    }
+
+    /// "Locality, region, country" of the preferred `ADR`.
+    fn location(&self) -> Option<String> {
+        let adr = self.preferred("ADR")?.components();
+        // PO box; extended; street; locality; region; postal code; country
+        let parts: Vec<&str> = [3, 4, 6]
+            .iter()
+            .filter_map(|&i| adr.get(i).map(String::as_str))
+            .filter(|part| !part.is_empty())
+            .collect();
+        (!parts.is_empty()).then(|| parts.join(", "))
+    }
+
+    /// The photo as a URL: a link, a 4.0 data URI, or 3.0 inline base64
+    /// turned into a data URI.
+    fn photo(&self) -> Option<String> {
+        let photo = self.preferred("PHOTO")?;
+        let value = photo.value.trim();
+        let inline = photo
+            .param("ENCODING")
+            .any(|e| e.eq_ignore_ascii_case("b") || e.eq_ignore_ascii_case("base64"));
+        if inline {
+            let subtype = photo
+                .param("TYPE")
+                .find(|t| !t.eq_ignore_ascii_case("pref"))
+                .unwrap_or("jpeg")
+                .to_ascii_lowercase();
+            let data: String = value.chars().filter(|c| !c.is_whitespace()).collect();
+            return Some(format!("data:image/{subtype};base64,{data}"));
+        }
+        let lower = value.to_ascii_lowercase();
+        ["data:image/", "https://", "http://"]
+            .iter()
+            .any(|scheme| lower.starts_with(scheme))
+            .then(|| value.to_string())
+    }
+}
+
+// ============================================================================
+// Parser Implementation
+// ============================================================================
+
+impl Parser for VcardParser {
+    type RawData = Vcard;
+    type ValidatedData = Vcard;
+
+    fn read(&self, input: &[u8]) -> Result<Self::RawData, ParseError> {
+        let text = std::str::from_utf8(input).map_err(|e| ParseError::ReadError(e.to_string()))?;
+        Ok(Vcard::from_text(text.trim_start_matches('\u{feff}')))
+    }
+
+    fn validate(&self, data: Self::RawData) -> Result<Self::ValidatedData, ParseError> {
+        if data.properties.is_empty() {
+            return Err(ParseError::ValidationError(
+                "No BEGIN:VCARD ... END:VCARD block found".to_string(),
+            ));
+        }
+        Ok(data)
+    }
+
+    fn convert(&self, data: Self::ValidatedData) -> Result<ResumeData, ParseError> {
+        let mut resume = ResumeData::default();
+        let titles = &Locale::for_tag(&resume.metadata.locale).sections;
+
+        resume.basics.name = data.name().unwrap_or_default();
+        match data.name_parts() {
+            Some(parts) => resume.basics.name_parts = parts,
+            None => resume.basics.guess_name_parts(&resume.metadata.locale),
+        }
+        resume.basics.headline = data
+            .preferred_text("TITLE")
+            .or_else(|| data.preferred_text("ROLE"))
+            .unwrap_or_default();
+        resume.basics.email = data
+            .preferred_text("EMAIL")
+            .map(|email| strip_scheme(&email, "mailto:"))
+            .unwrap_or_default();
+        resume.basics.phone = data
+            .preferred_text("TEL")
+            .map(|tel| strip_scheme(&tel, "tel:"))
+            .unwrap_or_default();
+        resume.basics.location = data.location().unwrap_or_default();
+        resume.basics.picture.url = data.photo().unwrap_or_default();
+
+        let mut urls: Vec<&VcardProperty> = data
+            .all("URL")
+            .filter(|url| !url.value.trim().is_empty())
+            .collect();
+        // Stable, so equally preferred URLs keep their order
+        urls.sort_by_key(|url| url.preference());
+        let mut urls = urls.into_iter().map(VcardProperty::text);
+        resume.basics.url = Url::new(urls.next().unwrap_or_default());
+
+        let profiles: Vec<Profile> = data
+            .all("X-SOCIALPROFILE")
+            .filter_map(social_profile)
+            .chain(urls.map(|url| url_profile(&url)))
+            .collect();
+        if !profiles.is_empty() {
+            resume.sections.profiles = Section::new("profiles", titles.profiles);
+            for profile in profiles {
+                resume.sections.profiles.add_item(profile);
+            }
+        }
+
+        Ok(resume)
+    }
+}
+
+/// `X-SOCIALPROFILE;TYPE=github;X-USER=jane:https://github.com/jane`
+fn social_profile(property: &VcardProperty) -> Option<Profile> {
+    let url = property.text();
+    let network = property
+        .param("TYPE")
+        .find(|t| !t.eq_ignore_ascii_case("pref"))
+        .map(str::to_string)
+        .unwrap_or_else(|| host(&url));
+    if url.is_empty() || network.is_empty() {
+        return None;
+    }
+    let username = property
+        .param("X-USER")
+        .next()
+        .map(str::to_string)
+        .unwrap_or_else(|| last_path_segment(&url));
+    let icon = network.to_lowercase();
+    Some(
+        Profile::new(network, username)
+            .with_icon(icon)
+            .with_url(url),
+    )
+}
+
+/// A further `URL`, as a profile named after its host.
+fn url_profile(url: &str) -> Profile {
+    Profile::new(host(url), last_path_segment(url)).with_url(url)
+}
+
+pub(crate) fn host(url: &str) -> String {
+    let rest = url.split_once("://").map_or(url, |(_, rest)| rest);
+    let host = rest.split(['/', '?', '#']).next().unwrap_or_default();
+    host.trim_start_matches("www.").to_string()
+}
+
+pub(crate) fn last_path_segment(url: &str) -> String {
+    let rest = url.split_once("://").map_or(url, |(_, rest)| rest);
+    let path = rest.split(['?', '#']).next().unwrap_or_default();
+    match path.split_once('/') {
+        Some((_, path)) => path.rsplit('/').find(|s| !s.is_empty()).unwrap_or_default(),
+        None => "",
+    }
+    .to_string()
+}
+
+fn strip_scheme(value: &str, scheme: &str) -> String {
+    value.strip_prefix(scheme).unwrap_or(value).to_string()
+}
+
+// ============================================================================
+// Export
+// ============================================================================
+
+/// Write `basics` and the visible profiles of `resume` as a vCard 4.0.
+///
+/// Lines end in CRLF and are folded at 75 octets. The photo is included
+/// when it is a link; inline data URIs are left out to keep the card small
+/// enough for a QR code.
+pub fn to_vcard(resume: &ResumeData) -> String {
+    let basics = &resume.basics;
+    let mut lines = vec!["BEGIN:VCARD".to_string(), "VERSION:4.0".to_string()];
+    let mut push = |name: &str, value: &str| {
+        if !value.trim().is_empty() {
+            lines.push(format!("{name}:{value}"));
+        }
+    };
+
+    let name = basics.name.trim();
+    push("FN", &escape(name));
+    if !name.is_empty() {
+        // Clients that predate 4.0 need N
+        let parts = basics
+            .current_name_parts()
+            .cloned()
+            .unwrap_or_else(|| PersonName::parse(name, &resume.metadata.locale));
+        push(
+            "N",
+            &format!(
+                "{};{};;{};{}",
+                escape(&parts.family),
+                escape(&parts.given),
+                escape(&parts.prefix),
+                escape(&parts.suffix)
+            ),
+        );
+    }
+    push("TITLE", &escape(&basics.headline));
+    push("EMAIL", &escape(&basics.email));
+    push("TEL", &escape(&basics.phone));
+    if !basics.location.trim().is_empty() {
+        push("ADR", &format!(";;;{};;;", escape(&basics.location)));
+    }
+    push("URL", &basics.url.href);
+    let photo = &basics.picture.url;
+    if photo.starts_with("https://") || photo.starts_with("http://") {
+        push("PHOTO", photo);
+    }
+    for profile in resume.sections.profiles.items.iter().filter(|p| p.visible) {
+        if profile.url.href.is_empty() {
+            continue;
+        }
+        let network: String = profile
+            .network
+            .to_lowercase()
+            .chars()
+            .filter(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '.'))
+            .collect();
+        let name = if network.is_empty() {
+            "X-SOCIALPROFILE".to_string()
+        } else {
+            format!("X-SOCIALPROFILE;TYPE={network}")
+        };
+        push(&name, &profile.url.href);
+    }
+    lines.push("END:VCARD".to_string());
+
+    lines.iter().map(|line| fold(line) + "\r\n").collect()
+}
+
+// ============================================================================
+// Lexing
+// ============================================================================
+
+/// Content lines with folding undone. Accepts CRLF or bare LF.
+fn unfold(text: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for raw in text.split('\n') {
+        let raw = raw.strip_suffix('\r').unwrap_or(raw);
+        if let (Some(continuation), Some(last)) = (raw.strip_prefix([' ', '\t']), lines.last_mut())
+        {
+            last.push_str(continuation);
+        } else if !raw.trim().is_empty() {
+            lines.push(raw.to_string());
+        }
+    }
+    lines
+}
+
+/// Split at the first `sep` outside double quotes.
+fn split_unquoted(text: &str, sep: char) -> Option<(&str, &str)> {
+    let mut quoted = false;
+    for (i, c) in text.char_indices() {
+        match c {
+            '"' => quoted = !quoted,
+            c if c == sep && !quoted => return Some((&text[..i], &text[i + 1..])),
+            _ => {}
+        }
+    }
+    None
+}
+
+fn split_all_unquoted(text: &str, sep: char) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut rest = text;
+    while let Some((part, tail)) = split_unquoted(rest, sep) {
+        parts.push(part);
+        rest = tail;
+    }
+    parts.push(rest);
+    parts
+}
+
+/// Split at every `sep` not escaped with a backslash.
+fn split_all_unescaped(text: &str, sep: char) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut start = 0;
+    let mut escaped = false;
+    for (i, c) in text.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            c if c == sep => {
+                parts.push(&text[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&text[start..]);
+    parts
+}
+
+fn unescape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n' | 'N') => out.push('\n'),
+            Some(other) => out.push(other),
+            None => out.push('\\'),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::content_line::MAX_LINE_OCTETS;
+
+    const VCARD_4: &str = "BEGIN:VCARD\r\n\
+        VERSION:4.0\r\n\
+        FN:Jane Q. Doe\r\n\
+        N:Doe;Jane;Q.;;\r\n\
+        TITLE:Staff Engineer\\, Platform\r\n\
+        EMAIL;TYPE=home:jane@home.example\r\n\
+        EMAIL;TYPE=work;PREF=1:jane@work.example\r\n\
+        TEL;VALUE=uri;TYPE=\"voice,cell\":tel:+49-30-1234567\r\n\
+        ADR;TYPE=home;LABEL=\"Street 1\\nBerlin\":;;Street 1;Berlin;;10115;Germany\r\n\
+        PHOTO:https://example.com/jane.jpg\r\n\
+        URL:https://jane.example\r\n\
+        item1.URL:https://gitlab.com/jane\r\n\
+        X-SOCIALPROFILE;TYPE=github;X-USER=janedoe:https://github.com/janedoe\r\n\
+        NOTE:Long note that is folded across two lines by the exporter and shou\r\n \
+        ld be joined again.\r\n\
+        END:VCARD\r\n\
+        BEGIN:VCARD\r\n\
+        VERSION:4.0\r\n\
+        FN:Someone Else\r\n\
+        END:VCARD\r\n";
+
+    const VCARD_3: &str = "BEGIN:VCARD
+VERSION:3.0
+N:Lovelace;Ada;;Countess;
+TEL;WORK;VOICE:+44 20 7946 0000
+TEL;HOME;PREF:+44 20 7946 0001
+PHOTO;ENCODING=b;TYPE=PNG:iVBORw0KGgo
+ AAAANSUhEUg==
+END:VCARD";
+
+    fn parse(vcf: &str) -> ResumeData {
+        VcardParser.parse(vcf.as_bytes()).unwrap()
+    }
+
+    #[test]
+    fn test_parse_vcard_4() {
+        let resume = parse(VCARD_4);
+        assert_eq!(resume.basics.name, "Jane Q. Doe");
+        assert_eq!(resume.basics.name_parts, PersonName::new("Jane Q.", "Doe"));
+        assert_eq!(resume.basics.headline, "Staff Engineer, Platform");
+        assert_eq!(resume.basics.email, "jane@work.example");
+        assert_eq!(resume.basics.phone, "+49-30-1234567");
+        assert_eq!(resume.basics.location, "Berlin, Germany");
+        assert_eq!(resume.basics.picture.url, "https://example.com/jane.jpg");
+        assert_eq!(resume.basics.url.href, "https://jane.example");
+
+        let profiles = &resume.sections.profiles.items;
+        assert_eq!(profiles.len(), 2);
+        assert_eq!(profiles[0].network, "github");
+        assert_eq!(profiles[0].username, "janedoe");
+        assert_eq!(profiles[1].network, "gitlab.com");
+        assert_eq!(profiles[1].username, "jane");
+        assert_eq!(profiles[1].url.href, "https://gitlab.com/jane");
+    }
+
+    #[test]
+    fn test_parse_vcard_3() {
+        let resume = parse(VCARD_3);
+        assert_eq!(resume.basics.name, "Countess Ada Lovelace");
+        assert_eq!(resume.basics.name_parts.prefix, "Countess");
+        assert_eq!(resume.basics.name_parts.family, "Lovelace");
+        assert_eq!(resume.basics.phone, "+44 20 7946 0001");
+        assert_eq!(
+            resume.basics.picture.url,
+            "data:image/png;base64,iVBORw0KGgoAAAANSUhEUg=="
+        );
+    }
+
+    #[test]
+    fn test_parse_rejects_input_without_card() {
+        let result = VcardParser.parse(b"FN:Loose line\nEMAIL:a@b.example\n");
+        assert!(matches!(result, Err(ParseError::ValidationError(_))));
+    }
+
+    #[test]
+    fn test_to_vcard_round_trips() {
+        let mut resume = parse(VCARD_4);
+        resume.basics.headline = "Engineer; Speaker".to_string();
+        let vcf = to_vcard(&resume);
+
+        assert!(vcf.starts_with("BEGIN:VCARD\r\nVERSION:4.0\r\n"));
+        assert!(vcf.ends_with("END:VCARD\r\n"));
+        assert!(vcf.contains("N:Doe;Jane Q.;;;\r\n"));
+        assert!(vcf.contains("TITLE:Engineer\\; Speaker\r\n"));
+        assert!(vcf.contains("X-SOCIALPROFILE;TYPE=github:https://github.com/janedoe\r\n"));
+
+        let again = parse(&vcf);
+        assert_eq!(again.basics.name, resume.basics.name);
+        assert_eq!(again.basics.name_parts, resume.basics.name_parts);
+        assert_eq!(again.basics.headline, resume.basics.headline);
+        assert_eq!(again.basics.email, resume.basics.email);
+        assert_eq!(again.basics.location, resume.basics.location);
+        assert_eq!(again.basics.picture.url, resume.basics.picture.url);
+        assert_eq!(again.sections.profiles.items.len(), 2);
+    }
+
+    #[test]
+    fn test_to_vcard_folds_long_lines_and_skips_inline_photos() {
+        let mut resume = ResumeData::default();
+        resume.basics.name = "Zoë".repeat(40);
+        resume.basics.picture.url = "data:image/png;base64,AAAA".to_string();
+        let vcf = to_vcard(&resume);
+
+        assert!(vcf.split("\r\n").all(|line| line.len() <= MAX_LINE_OCTETS));
+        assert!(!vcf.contains("PHOTO"));
+        assert_eq!(parse(&vcf).basics.name, resume.basics.name);
+    }
+}
@@ -213,13 +213,14 @@ mod json_resume {
 
 mod linkedin {
     use super::*;
+    use rustume_schema::CefrLevel;
 
     #[test]
     fn test_parse_linkedin_export() {
         let fixture_path = fixtures_path().join("linkedin").join("complete_export.zip");
         let data = fs::read(&fixture_path).expect("Failed to read LinkedIn ZIP fixture");
 
-        let parser = LinkedInParser;
+        let parser = LinkedInParser::default();
         let result = parser.parse(&data);
 
         assert!(
@@ -314,7 +315,7 @@ mod linkedin {
 
     #[test]
     fn test_linkedin_invalid_zip() {
-        let parser = LinkedInParser;
+        let parser = LinkedInParser::default();
         let result = parser.parse(b"not a zip file");
         assert!(result.is_err());
     }
@@ -329,7 +330,7 @@ mod linkedin {
             zip.finish().unwrap();
         }
 
-        let parser = LinkedInParser;
+        let parser = LinkedInParser::default();
         let result = parser.parse(&buffer);
 
         // Should succeed but with empty data
@@ -343,14 +344,14 @@ mod linkedin {
         // Test different proficiency level mappings
         // Note: LinkedIn's "Full professional proficiency" maps to 5 due to matching "full professional"
         let proficiencies = vec![
-            ("Native or bilingual proficiency", 5),
-            ("Full professional proficiency", 5), // matches "full professional" -> 5
-            ("Professional working proficiency", 4),
-            ("Limited working proficiency", 3),
-            ("Elementary proficiency", 2),
+            ("Native or bilingual proficiency", CefrLevel::C2, 5),
+            ("Full professional proficiency", CefrLevel::C1, 5), // matches "full professional" -> 5
+            ("Professional working proficiency", CefrLevel::B2, 4),
+            ("Limited working proficiency", CefrLevel::B1, 3),
+            ("Elementary proficiency", CefrLevel::A2, 2),
         ];
 
-        for (proficiency, expected_level) in proficiencies {
+        for (proficiency, expected_cefr, expected_level) in proficiencies {
             // Create a minimal ZIP with just languages
             let mut buffer = Vec::new();
             {
@@ -365,7 +366,7 @@ mod linkedin {
                 zip.finish().unwrap();
             }
 
-            let parser = LinkedInParser;
+            let parser = LinkedInParser::default();
             let result = parser.parse(&buffer);
             assert!(result.is_ok(), "Failed for proficiency: {}", proficiency);
 
@@ -376,6 +377,7 @@ mod linkedin {
                 "Wrong level for proficiency: {}",
                 proficiency
             );
+            assert_eq!(resume.sections.languages.items[0].cefr, Some(expected_cefr));
         }
     }
 }
@@ -693,7 +695,7 @@ mod cross_parser {
             ),
             (
                 "linkedin/complete_export.zip",
-                Box::new(|data| LinkedInParser.parse(data)),
+                Box::new(|data| LinkedInParser::default().parse(data)),
             ),
             (
                 "v3/complete.json",
@@ -0,0 +1,57 @@
+//! Render timings for the Typst pipeline.
+//!
+//! Run with `cargo bench -p rustume-render --bench render`. Criterion keeps
+//! results in `target/criterion/`; `-- --save-baseline <name>` records a
+//! baseline and `-- --baseline <name>` compares against it.
+//!
+//! Every iteration renders the same resume, so after the first one these
+//! measure the warm path the editor hits, with fonts and Typst's memoized
+//! layout reused. `repeat_render` covers the cold-versus-warm split.
+
+use std::hint::black_box;
+use std::path::PathBuf;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use rustume_parser::{JsonResumeParser, Parser};
+use rustume_render::{Renderer, TypstRenderer};
+use rustume_schema::ResumeData;
+
+fn sample_resume() -> ResumeData {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("../../tests/fixtures/json_resume/full.json");
+    let data = std::fs::read(&path).expect("read full.json fixture");
+    JsonResumeParser.parse(&data).expect("parse fixture")
+}
+
+fn bench_render(c: &mut Criterion) {
+    let renderer = TypstRenderer::new();
+    let resume = sample_resume();
+    let mut group = c.benchmark_group("render");
+
+    group.bench_function("typst_source", |b| {
+        b.iter(|| {
+            renderer
+                .generate_source(black_box(&resume))
+                .expect("source")
+        });
+    });
+    group.bench_function("pdf", |b| {
+        b.iter(|| renderer.render_pdf(black_box(&resume)).expect("render"));
+    });
+    group.bench_function("preview", |b| {
+        b.iter(|| {
+            renderer
+                .render_preview(black_box(&resume), 0)
+                .expect("render")
+        });
+    });
+    group.finish();
+}
+
+criterion_group! {
+    name = benches;
+    // Full renders take tens of milliseconds; fewer samples keep runs short
+    config = Criterion::default().sample_size(20);
+    targets = bench_render
+}
+criterion_main!(benches);
@@ -0,0 +1,97 @@
+//! Repeat-render timings for live preview workloads.
+//!
+//! Run with `cargo bench -p rustume-render --bench repeat_render`. The first
+//! render pays for fonts, the standard library, and parsing; later renders
+//! should reuse all of that plus Typst's memoized layout, so they ought to be
+//! several times faster. The concurrent cases render from several threads at
+//! once, as a server does for different users.
+
+use std::hint::black_box;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use rustume_parser::{JsonResumeParser, Parser};
+use rustume_render::{Renderer, TypstRenderer, TEMPLATES};
+use rustume_schema::ResumeData;
+
+const ITERATIONS: usize = 20;
+
+/// Threads rendering at once in the concurrent cases.
+const THREADS: usize = 4;
+
+fn sample_resume() -> ResumeData {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("../../tests/fixtures/json_resume/full.json");
+    let data = std::fs::read(&path).expect("read full.json fixture");
+    JsonResumeParser.parse(&data).expect("parse fixture")
+}
+
+/// Time `render` over `ITERATIONS` runs, passing the iteration index.
+fn measure(name: &str, mut render: impl FnMut(usize)) {
+    let mut times: Vec<Duration> = (0..ITERATIONS)
+        .map(|i| {
+            let start = Instant::now();
+            render(i);
+            start.elapsed()
+        })
+        .collect();
+    times.sort();
+    let mean = times.iter().sum::<Duration>() / ITERATIONS as u32;
+    println!(
+        "{name:<28} mean {:>8.1?}  median {:>8.1?}  min {:>8.1?}",
+        mean,
+        times[ITERATIONS / 2],
+        times[0]
+    );
+}
+
+fn main() {
+    let renderer = TypstRenderer::new();
+    let mut resume = sample_resume();
+
+    let start = Instant::now();
+    black_box(renderer.render_preview(&resume, 0).expect("cold render"));
+    println!("{:<28} {:>13.1?}", "cold preview", start.elapsed());
+
+    measure("unchanged preview", |_| {
+        black_box(renderer.render_preview(&resume, 0).expect("render"));
+    });
+
+    let summary = resume.sections.summary.content.clone();
+    measure("preview after summary edit", |i| {
+        resume.sections.summary.content = format!("{summary} Edit {i}.");
+        black_box(renderer.render_preview(&resume, 0).expect("render"));
+    });
+
+    measure("pdf after summary edit", |i| {
+        resume.sections.summary.content = format!("{summary} Revision {i}.");
+        black_box(renderer.render_pdf(&resume).expect("render"));
+    });
+
+    let templates = ["rhyhorn", "azurill", "onyx"];
+    measure("preview switching templates", |i| {
+        resume.metadata.template = templates[i % templates.len()].to_string();
+        black_box(renderer.render_preview(&resume, 0).expect("render"));
+    });
+
+    // Each thread edits its own resume, with its own template or all with one
+    let concurrent = |i: usize, template: &dyn Fn(usize) -> &'static str| {
+        std::thread::scope(|scope| {
+            for thread in 0..THREADS {
+                let mut resume = resume.clone();
+                resume.metadata.template = template(thread).to_string();
+                resume.sections.summary.content = format!("{summary} Thread {thread}, edit {i}.");
+                let renderer = &renderer;
+                scope.spawn(move || {
+                    black_box(renderer.render_preview(&resume, 0).expect("render"));
+                });
+            }
+        });
+    };
+    measure("concurrent, own templates", |i| {
+        concurrent(i, &|thread| TEMPLATES[thread]);
+    });
+    measure("concurrent, one template", |i| {
+        concurrent(i, &|_| templates[0]);
+    });
+}
@@ -0,0 +1,236 @@
+//! Pluggable rendering backends.
+//!
+//! Typst is the built-in backend and the default. Others implement
+//! [`Renderer`] and are registered in a [`RendererRegistry`] under a
+//! [`RenderEngine`], which callers pick per render or fall back from to the
+//! registry's default. The headless Chromium backend behind the `chromium`
+//! feature is one: it prints the HTML page, for layouts that need CSS Typst
+//! doesn't have.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{RenderError, Renderer, TypstRenderer};
+
+/// A rendering backend.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RenderEngine {
+    /// The Typst templates.
+    #[default]
+    Typst,
+    /// The HTML page printed by headless Chromium.
+    Chromium,
+}
+
+impl RenderEngine {
+    /// Every engine, in display order.
+    pub const ALL: &'static [Self] = &[Self::Typst, Self::Chromium];
+
+    /// Name used in config and requests.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Typst => "typst",
+            Self::Chromium => "chromium",
+        }
+    }
+}
+
+impl fmt::Display for RenderEngine {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for RenderEngine {
+    type Err = RenderError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|engine| engine.as_str().eq_ignore_ascii_case(s.trim()))
+            .ok_or_else(|| {
+                RenderError::InvalidConfig(format!(
+                    "Unknown render engine `{s}` (expected typst or chromium)"
+                ))
+            })
+    }
+}
+
+/// A renderer that can be shared across threads and registered as a backend.
+pub type SharedRenderer = Arc<dyn Renderer + Send + Sync>;
+
+/// Rendering backends by engine, plus the one used when a render names none.
+///
+/// # Example
+///
+/// ```ignore
+/// use std::sync::Arc;
+/// use rustume_render::{ChromiumRenderer, RenderEngine, RendererRegistry};
+///
+/// let registry = RendererRegistry::default()
+///     .register(RenderEngine::Chromium, Arc::new(ChromiumRenderer::new("chromium")));
+/// let renderer = registry.get(Some(RenderEngine::Chromium))?;
+/// ```
+#[derive(Clone)]
+pub struct RendererRegistry {
+    default: RenderEngine,
+    renderers: HashMap<RenderEngine, SharedRenderer>,
+}
+
+impl RendererRegistry {
+    /// A registry with `typst` as its only backend and the default.
+    pub fn new(typst: SharedRenderer) -> Self {
+        Self {
+            default: RenderEngine::Typst,
+            renderers: HashMap::from([(RenderEngine::Typst, typst)]),
+        }
+    }
+
+    /// Add or replace the backend for `engine`.
+    pub fn register(mut self, engine: RenderEngine, renderer: SharedRenderer) -> Self {
+        self.renderers.insert(engine, renderer);
+        self
+    }
+
+    /// Use `engine` when a render names none. It must be registered.
+    pub fn with_default(mut self, engine: RenderEngine) -> Result<Self, RenderError> {
+        if !self.renderers.contains_key(&engine) {
+            return Err(RenderError::EngineUnavailable(engine));
+        }
+        self.default = engine;
+        Ok(self)
+    }
+
+    /// Engine used when a render names none.
+    pub fn default_engine(&self) -> RenderEngine {
+        self.default
+    }
+
+    /// `engine`, or the default when it is `None`.
+    pub fn resolve(&self, engine: Option<RenderEngine>) -> RenderEngine {
+        engine.unwrap_or(self.default)
+    }
+
+    /// Registered engines, in [`RenderEngine::ALL`] order.
+    pub fn engines(&self) -> Vec<RenderEngine> {
+        RenderEngine::ALL
+            .iter()
+            .copied()
+            .filter(|engine| self.renderers.contains_key(engine))
+            .collect()
+    }
+
+    /// The backend for `engine`, or the default backend when it is `None`.
+    pub fn get(&self, engine: Option<RenderEngine>) -> Result<SharedRenderer, RenderError> {
+        let engine = self.resolve(engine);
+        self.renderers
+            .get(&engine)
+            .cloned()
+            .ok_or(RenderError::EngineUnavailable(engine))
+    }
+}
+
+impl Default for RendererRegistry {
+    fn default() -> Self {
+        Self::new(Arc::new(TypstRenderer::new()))
+    }
+}
+
+impl fmt::Debug for RendererRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RendererRegistry")
+            .field("default", &self.default)
+            .field("engines", &self.engines())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rustume_schema::ResumeData;
+
+    /// Backend that answers every call with a fixed marker.
+    struct Marker(&'static str);
+
+    impl Renderer for Marker {
+        fn render_pdf(&self, _resume: &ResumeData) -> Result<Vec<u8>, RenderError> {
+            Ok(self.0.as_bytes().to_vec())
+        }
+
+        fn render_html(&self, _resume: &ResumeData) -> Result<String, RenderError> {
+            Ok(self.0.to_string())
+        }
+
+        fn render_preview(
+            &self,
+            _resume: &ResumeData,
+            _page: usize,
+        ) -> Result<(Vec<u8>, usize), RenderError> {
+            Err(RenderError::Unsupported("previews"))
+        }
+    }
+
+    fn registry() -> RendererRegistry {
+        RendererRegistry::new(Arc::new(Marker("typst")))
+    }
+
+    #[test]
+    fn test_engine_names_round_trip() {
+        for engine in RenderEngine::ALL {
+            assert_eq!(engine.as_str().parse::<RenderEngine>().unwrap(), *engine);
+            assert_eq!(
+                serde_json::to_value(engine).unwrap(),
+                serde_json::json!(engine.as_str())
+            );
+        }
+        assert_eq!(
+            " Chromium ".parse::<RenderEngine>().unwrap(),
+            RenderEngine::Chromium
+        );
+        assert!(matches!(
+            "wkhtmltopdf".parse::<RenderEngine>(),
+            Err(RenderError::InvalidConfig(_))
+        ));
+    }
+
+    #[test]
+    fn test_registry_selects_backends() {
+        let resume = ResumeData::default();
+        let registry = registry().register(RenderEngine::Chromium, Arc::new(Marker("chromium")));
+        assert_eq!(
+            registry.engines(),
+            [RenderEngine::Typst, RenderEngine::Chromium]
+        );
+
+        let pdf = |engine| registry.get(engine).unwrap().render_pdf(&resume).unwrap();
+        assert_eq!(pdf(None), b"typst");
+        assert_eq!(pdf(Some(RenderEngine::Chromium)), b"chromium");
+
+        let registry = registry.with_default(RenderEngine::Chromium).unwrap();
+        assert_eq!(registry.default_engine(), RenderEngine::Chromium);
+        assert_eq!(registry.resolve(None), RenderEngine::Chromium);
+        let html = registry.get(None).unwrap().render_html(&resume).unwrap();
+        assert_eq!(html, "chromium");
+    }
+
+    #[test]
+    fn test_registry_rejects_unregistered_engines() {
+        let registry = registry();
+        assert_eq!(registry.engines(), [RenderEngine::Typst]);
+        assert!(matches!(
+            registry.get(Some(RenderEngine::Chromium)),
+            Err(RenderError::EngineUnavailable(RenderEngine::Chromium))
+        ));
+        assert!(matches!(
+            registry.with_default(RenderEngine::Chromium),
+            Err(RenderError::EngineUnavailable(RenderEngine::Chromium))
+        ));
+    }
+}
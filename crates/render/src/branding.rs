@@ -0,0 +1,196 @@
+//! Deployment-wide branding for white-labeled output.
+//!
+//! A [`Branding`] gives every render a deployment's colors, a line of footer
+//! text, and a logo, so a company embedding the API can match its own look
+//! without editing each resume. Colors only fill in where the resume keeps
+//! the default theme; the footer and logo have no resume counterpart and
+//! appear on every page.
+
+use std::sync::Arc;
+
+use rustume_schema::Theme;
+use rustume_utils::hex_to_rgba;
+
+use crate::RenderError;
+
+/// Longest footer text, in characters. The footer is one line of 8pt text.
+const MAX_FOOTER_CHARS: usize = 200;
+
+/// Colors, footer text, and logo added to every render. The default adds
+/// nothing.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Branding {
+    /// Page background, used when the resume's is the default.
+    pub background: Option<String>,
+    /// Text color, used when the resume's is the default.
+    pub text: Option<String>,
+    /// Accent color, used when the resume's is the default.
+    pub primary: Option<String>,
+    /// Text at the start of each page's footer.
+    pub footer: Option<String>,
+    /// Image drawn before the footer text.
+    pub logo: Option<Logo>,
+}
+
+/// A PNG, JPEG, GIF, WebP, or SVG logo.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Logo {
+    extension: &'static str,
+    bytes: Arc<[u8]>,
+}
+
+impl Logo {
+    /// Wrap image bytes, failing with [`RenderError::InvalidConfig`] when they
+    /// aren't an image format templates can draw.
+    pub fn new(bytes: impl Into<Arc<[u8]>>) -> Result<Self, RenderError> {
+        let bytes = bytes.into();
+        let extension = image_extension(&bytes).ok_or_else(|| {
+            RenderError::InvalidConfig(
+                "Logo must be a PNG, JPEG, GIF, WebP, or SVG image".to_string(),
+            )
+        })?;
+        Ok(Self { extension, bytes })
+    }
+
+    /// The image bytes.
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Virtual path the Typst world serves the logo from.
+    pub(crate) fn path(&self) -> String {
+        format!("/assets/brand-logo.{}", self.extension)
+    }
+}
+
+impl Branding {
+    /// Whether the branding adds nothing.
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+
+    /// Fail with [`RenderError::InvalidConfig`] on a color that isn't hex or
+    /// footer text over 200 characters.
+    pub fn validate(&self) -> Result<(), RenderError> {
+        for (name, color) in [
+            ("background", &self.background),
+            ("text", &self.text),
+            ("primary", &self.primary),
+        ] {
+            if let Some(color) = color.as_deref().filter(|c| hex_to_rgba(c).is_none()) {
+                return Err(RenderError::InvalidConfig(format!(
+                    "Brand {name} color `{color}` is not a hex color"
+                )));
+            }
+        }
+        if self
+            .footer
+            .as_ref()
+            .is_some_and(|footer| footer.chars().count() > MAX_FOOTER_CHARS)
+        {
+            return Err(RenderError::InvalidConfig(format!(
+                "Brand footer is over {MAX_FOOTER_CHARS} characters"
+            )));
+        }
+        Ok(())
+    }
+
+    /// Replace the colors of `theme` that are still the defaults with the
+    /// brand's. A theme picked from a preset is the resume's own choice and
+    /// is left alone.
+    pub fn apply_theme(&self, theme: &mut Theme) {
+        if theme.preset.is_some() {
+            return;
+        }
+        let defaults = Theme::default();
+        for (color, default, brand) in [
+            (
+                &mut theme.background,
+                &defaults.background,
+                &self.background,
+            ),
+            (&mut theme.text, &defaults.text, &self.text),
+            (&mut theme.primary, &defaults.primary, &self.primary),
+        ] {
+            if let Some(brand) = brand
+                .as_ref()
+                .filter(|_| color.eq_ignore_ascii_case(default))
+            {
+                *color = brand.clone();
+            }
+        }
+    }
+}
+
+/// File extension for an image format Typst can draw, from its leading bytes.
+fn image_extension(bytes: &[u8]) -> Option<&'static str> {
+    if bytes.starts_with(b"\x89PNG\r\n\x1a\n") {
+        Some("png")
+    } else if bytes.starts_with(&[0xff, 0xd8, 0xff]) {
+        Some("jpg")
+    } else if bytes.starts_with(b"GIF87a") || bytes.starts_with(b"GIF89a") {
+        Some("gif")
+    } else if bytes.len() >= 12 && &bytes[..4] == b"RIFF" && &bytes[8..12] == b"WEBP" {
+        Some("webp")
+    } else {
+        let head = String::from_utf8_lossy(&bytes[..bytes.len().min(1024)]);
+        head.contains("<svg").then_some("svg")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn brand_colors_fill_in_default_theme_colors_only() {
+        let branding = Branding {
+            background: Some("#f8fafc".to_string()),
+            primary: Some("#1d4ed8".to_string()),
+            ..Default::default()
+        };
+        assert!(branding.validate().is_ok());
+
+        let mut theme = Theme {
+            background: "#FFFFFF".to_string(),
+            text: "#111827".to_string(),
+            ..Theme::default()
+        };
+        branding.apply_theme(&mut theme);
+        assert_eq!(theme.background, "#f8fafc");
+        assert_eq!(theme.text, "#111827");
+        assert_eq!(theme.primary, "#1d4ed8");
+
+        let mut preset = Theme {
+            preset: Some("ocean".to_string()),
+            ..Theme::default()
+        };
+        branding.apply_theme(&mut preset);
+        assert_eq!(preset.primary, Theme::default().primary);
+    }
+
+    #[test]
+    fn rejects_bad_colors_long_footers_and_non_images() {
+        let branding = Branding {
+            primary: Some("blue".to_string()),
+            ..Default::default()
+        };
+        assert!(matches!(
+            branding.validate(),
+            Err(RenderError::InvalidConfig(message)) if message.contains("primary")
+        ));
+
+        let branding = Branding {
+            footer: Some("x".repeat(MAX_FOOTER_CHARS + 1)),
+            ..Default::default()
+        };
+        assert!(branding.validate().is_err());
+
+        assert!(Logo::new(b"not an image".to_vec()).is_err());
+        let svg = Logo::new(
+            br#"<?xml version="1.0"?><svg xmlns="http://www.w3.org/2000/svg"/>"#.to_vec(),
+        )
+        .unwrap();
+        assert_eq!(svg.path(), "/assets/brand-logo.svg");
+    }
+}
@@ -0,0 +1,370 @@
+//! PDF rendering through headless Chromium.
+//!
+//! [`ChromiumRenderer`] prints the standalone HTML page (see
+//! [`render_html_page`]) with Chrome's `--print-to-pdf`, so custom CSS that
+//! Typst can't express (grid layouts, web fonts embedded as data URLs, CSS
+//! filters) reaches the PDF. The print stylesheet sets the paper size and
+//! margins. A content security policy keeps the browser off the network:
+//! pictures must already be data URLs, as the server and web app make them.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use rustume_schema::ResumeData;
+
+use crate::{render_html_page, HtmlPageOptions, Redaction, RenderError, Renderer};
+
+/// Browser executables tried, in order, when `RUSTUME_CHROMIUM_PATH` is unset.
+const CANDIDATES: &[&str] = &[
+    "chromium",
+    "chromium-browser",
+    "google-chrome",
+    "google-chrome-stable",
+    "chrome",
+];
+
+/// Blocks every network request; inline styles and data URLs still load.
+const CONTENT_SECURITY_POLICY: &str =
+    "default-src 'none'; img-src data:; font-src data:; style-src 'unsafe-inline'";
+
+/// How long a print may take before the browser is killed.
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Interval between checks on the browser process.
+const POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+/// Bytes of browser stderr quoted in a failure.
+const MAX_LOG_BYTES: usize = 2048;
+
+/// Renders the HTML page to PDF with a headless Chromium or Chrome binary.
+#[derive(Debug, Clone)]
+pub struct ChromiumRenderer {
+    binary: PathBuf,
+    args: Vec<String>,
+    timeout: Duration,
+    redaction: Redaction,
+}
+
+impl ChromiumRenderer {
+    /// Use the browser at `binary`, a path or a name looked up on `PATH`.
+    pub fn new(binary: impl Into<PathBuf>) -> Self {
+        Self {
+            binary: binary.into(),
+            args: Vec::new(),
+            timeout: DEFAULT_TIMEOUT,
+            redaction: Redaction::default(),
+        }
+    }
+
+    /// Find a browser: `RUSTUME_CHROMIUM_PATH` if set, else the first of
+    /// `chromium`, `chromium-browser`, `google-chrome`, and `chrome` on
+    /// `PATH`. Whitespace-separated flags in `RUSTUME_CHROMIUM_ARGS` (such as
+    /// `--no-sandbox` in a container running as root) are passed to it.
+    pub fn discover() -> Option<Self> {
+        let binary = match std::env::var_os("RUSTUME_CHROMIUM_PATH") {
+            Some(path) if !path.is_empty() => Some(PathBuf::from(path)),
+            _ => CANDIDATES.iter().find_map(|name| find_on_path(name)),
+        }?;
+        let args = std::env::var("RUSTUME_CHROMIUM_ARGS").unwrap_or_default();
+        Some(Self::new(binary).with_args(args.split_whitespace()))
+    }
+
+    /// Pass extra command-line flags to the browser.
+    pub fn with_args<I, S>(mut self, args: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.args.extend(args.into_iter().map(Into::into));
+        self
+    }
+
+    /// Kill the browser and fail a render that takes longer than `timeout`.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Leave the given sections, contact fields, and items out of rendered
+    /// output. The resume passed to each render call is not modified.
+    pub fn with_redaction(mut self, redaction: Redaction) -> Self {
+        self.redaction = redaction;
+        self
+    }
+
+    /// Browser executable this renderer runs.
+    pub fn binary(&self) -> &Path {
+        &self.binary
+    }
+
+    /// The HTML page that gets printed, with the redaction applied and the
+    /// network blocked.
+    fn page(&self, resume: &ResumeData) -> Result<String, RenderError> {
+        let unknown = self.redaction.unknown_sections(resume);
+        if !unknown.is_empty() {
+            return Err(RenderError::InvalidConfig(format!(
+                "Unknown sections to exclude: {}",
+                unknown.join(", ")
+            )));
+        }
+        let options = HtmlPageOptions::default();
+        let page = if self.redaction.is_empty() {
+            render_html_page(resume, &options)
+        } else {
+            let mut redacted = resume.clone();
+            self.redaction.apply(&mut redacted);
+            render_html_page(&redacted, &options)
+        };
+        let csp = format!(
+            "<head>\n<meta http-equiv=\"Content-Security-Policy\" content=\"{CONTENT_SECURITY_POLICY}\">\n"
+        );
+        Ok(page.replacen("<head>\n", &csp, 1))
+    }
+
+    /// Run the browser on `html` in a scratch directory and read back the PDF.
+    fn print(&self, html: &str) -> Result<Vec<u8>, RenderError> {
+        let scratch = ScratchDir::create()
+            .map_err(|err| RenderError::RenderFailed(format!("Scratch directory: {err}")))?;
+        let page = scratch.path.join("resume.html");
+        let pdf = scratch.path.join("resume.pdf");
+        let log = scratch.path.join("chromium.log");
+        let write_failed = |err| RenderError::RenderFailed(format!("Writing the page: {err}"));
+        fs::write(&page, html).map_err(write_failed)?;
+        let stderr = fs::File::create(&log).map_err(write_failed)?;
+
+        let mut child = Command::new(&self.binary)
+            .args([
+                "--headless",
+                "--disable-gpu",
+                "--disable-extensions",
+                "--disable-background-networking",
+                "--no-first-run",
+                "--no-default-browser-check",
+                "--no-pdf-header-footer",
+                "--print-to-pdf-no-header",
+            ])
+            .arg(format!(
+                "--user-data-dir={}",
+                scratch.path.join("profile").display()
+            ))
+            .arg(format!("--print-to-pdf={}", pdf.display()))
+            .args(&self.args)
+            .arg(format!("file://{}", page.display()))
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(stderr)
+            .spawn()
+            .map_err(|err| {
+                RenderError::RenderFailed(format!(
+                    "Could not start {}: {err}",
+                    self.binary.display()
+                ))
+            })?;
+
+        let deadline = Instant::now() + self.timeout;
+        let status = loop {
+            match child.try_wait() {
+                Ok(Some(status)) => break status,
+                Ok(None) if Instant::now() < deadline => thread::sleep(POLL_INTERVAL),
+                Ok(None) => {
+                    let _ = child.kill();
+                    let _ = child.wait();
+                    return Err(RenderError::RenderFailed(format!(
+                        "Chromium did not finish within {}s",
+                        self.timeout.as_secs_f32()
+                    )));
+                }
+                Err(err) => {
+                    return Err(RenderError::RenderFailed(format!(
+                        "Waiting for Chromium: {err}"
+                    )))
+                }
+            }
+        };
+
+        match fs::read(&pdf) {
+            Ok(bytes) if status.success() && bytes.starts_with(b"%PDF-") => Ok(bytes),
+            _ => Err(RenderError::RenderFailed(format!(
+                "Chromium exited with {status} without writing a PDF: {}",
+                log_tail(&log)
+            ))),
+        }
+    }
+}
+
+impl Renderer for ChromiumRenderer {
+    fn render_pdf(&self, resume: &ResumeData) -> Result<Vec<u8>, RenderError> {
+        let html = self.page(resume)?;
+        self.print(&html)
+    }
+
+    fn render_html(&self, resume: &ResumeData) -> Result<String, RenderError> {
+        self.page(resume)
+    }
+
+    fn render_preview(
+        &self,
+        _resume: &ResumeData,
+        _page: usize,
+    ) -> Result<(Vec<u8>, usize), RenderError> {
+        Err(RenderError::Unsupported("page previews"))
+    }
+}
+
+/// First executable named `name` in a `PATH` directory.
+fn find_on_path(name: &str) -> Option<PathBuf> {
+    let path = std::env::var_os("PATH")?;
+    std::env::split_paths(&path)
+        .map(|dir| dir.join(name))
+        .find(|candidate| candidate.is_file())
+}
+
+/// The end of the browser's stderr, where the reason for a failure is.
+fn log_tail(log: &Path) -> String {
+    let text = fs::read_to_string(log).unwrap_or_default();
+    let text = text.trim();
+    if text.is_empty() {
+        return "no output".to_string();
+    }
+    let mut start = text.len().saturating_sub(MAX_LOG_BYTES);
+    while !text.is_char_boundary(start) {
+        start += 1;
+    }
+    text[start..].to_string()
+}
+
+/// A temporary directory removed with everything in it when dropped.
+struct ScratchDir {
+    path: PathBuf,
+}
+
+impl ScratchDir {
+    fn create() -> std::io::Result<Self> {
+        static NEXT: AtomicU64 = AtomicU64::new(0);
+        let path = std::env::temp_dir().join(format!(
+            "rustume-chromium-{}-{}",
+            std::process::id(),
+            NEXT.fetch_add(1, Ordering::Relaxed)
+        ));
+        fs::create_dir(&path)?;
+        Ok(Self { path })
+    }
+}
+
+impl Drop for ScratchDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.path);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    /// Held while a test starts a process, so no child inherits the write
+    /// handle of a fake browser another test is still writing ("text file
+    /// busy").
+    static SPAWN: Mutex<()> = Mutex::new(());
+
+    #[test]
+    fn test_page_blocks_the_network_and_applies_redaction() {
+        let mut resume = ResumeData::default();
+        resume.basics.name = "Ada Lovelace".to_string();
+        resume.basics.phone = "+44 20 7946 0958".to_string();
+
+        let renderer = ChromiumRenderer::new("chromium").with_redaction(Redaction {
+            exclude_sections: vec!["basics.phone".to_string()],
+            exclude_item_ids: Vec::new(),
+        });
+        let html = renderer.render_html(&resume).unwrap();
+        assert!(html.contains(
+            "<head>\n<meta http-equiv=\"Content-Security-Policy\" content=\"default-src 'none';"
+        ));
+        assert!(html.contains("Ada Lovelace"));
+        assert!(!html.contains("7946"));
+
+        let typo = ChromiumRenderer::new("chromium").with_redaction(Redaction {
+            exclude_sections: vec!["referencse".to_string()],
+            exclude_item_ids: Vec::new(),
+        });
+        assert!(matches!(
+            typo.render_pdf(&resume),
+            Err(RenderError::InvalidConfig(_))
+        ));
+    }
+
+    #[test]
+    fn test_missing_browser_fails_without_previews() {
+        let _spawn = SPAWN.lock().unwrap_or_else(|err| err.into_inner());
+        let renderer = ChromiumRenderer::new("/nonexistent/rustume-chromium");
+        let err = renderer.render_pdf(&ResumeData::default()).unwrap_err();
+        assert!(err.to_string().contains("Could not start"), "{err}");
+        assert!(matches!(
+            renderer.render_preview(&ResumeData::default(), 0),
+            Err(RenderError::Unsupported(_))
+        ));
+    }
+
+    /// Write an executable shell script standing in for the browser.
+    #[cfg(unix)]
+    fn fake_browser(dir: &Path, body: &str) -> PathBuf {
+        use std::os::unix::fs::PermissionsExt;
+
+        let path = dir.join("fake-chromium");
+        fs::write(&path, format!("#!/bin/sh\n{body}\n")).unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).unwrap();
+        path
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_prints_through_the_browser() {
+        let _spawn = SPAWN.lock().unwrap_or_else(|err| err.into_inner());
+        let dir = tempfile::tempdir().unwrap();
+        // Copies the page into the PDF path, so the output shows what was printed
+        let browser = fake_browser(
+            dir.path(),
+            r#"for arg in "$@"; do
+  case "$arg" in
+    --print-to-pdf=*) out="${arg#--print-to-pdf=}" ;;
+    file://*) page="${arg#file://}" ;;
+    --extra) extra=yes ;;
+  esac
+done
+[ "$extra" = yes ] || exit 3
+{ printf '%%PDF-'; cat "$page"; } > "$out""#,
+        );
+
+        let mut resume = ResumeData::default();
+        resume.basics.name = "Grace Hopper".to_string();
+        let pdf = ChromiumRenderer::new(&browser)
+            .with_args(["--extra"])
+            .render_pdf(&resume)
+            .unwrap();
+        assert!(pdf.starts_with(b"%PDF-<!DOCTYPE html>"));
+        assert!(String::from_utf8_lossy(&pdf).contains("Grace Hopper"));
+
+        let err = ChromiumRenderer::new(&browser)
+            .render_pdf(&resume)
+            .unwrap_err();
+        assert!(err.to_string().contains("without writing a PDF"), "{err}");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_kills_a_browser_that_hangs() {
+        let _spawn = SPAWN.lock().unwrap_or_else(|err| err.into_inner());
+        let dir = tempfile::tempdir().unwrap();
+        let browser = fake_browser(dir.path(), "exec sleep 10");
+        let err = ChromiumRenderer::new(browser)
+            .with_timeout(Duration::from_millis(100))
+            .render_pdf(&ResumeData::default())
+            .unwrap_err();
+        assert!(err.to_string().contains("did not finish"), "{err}");
+    }
+}
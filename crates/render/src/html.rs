@@ -0,0 +1,1133 @@
+//! HTML rendering for the web.
+//!
+//! Typst only produces paged output, so web pages get their own renderer:
+//! semantic markup for every visible section in layout order, styled by a
+//! stylesheet generated from the resume's theme and typography, plus a print
+//! stylesheet that makes the browser's printout approximate the PDF.
+
+use std::borrow::Cow;
+
+use rustume_schema::{
+    CustomFieldKind, Layout, PageConfig, PageFormat, PageNumberStyle, ResumeData, RunningHeader,
+    Sections, Url, CUSTOM_SECTIONS_SLOT,
+};
+use rustume_utils::{
+    escape_html, hex_to_rgb, html_text_blocks, is_rtl_language, rgb_to_hex, sanitize_html, Locale,
+};
+
+use crate::typst_engine::{template_layout, TemplateLayout};
+
+/// File name of the screen stylesheet when styles are linked.
+pub const STYLESHEET_FILE: &str = "style.css";
+
+/// File name of the print stylesheet when styles are linked.
+pub const PRINT_STYLESHEET_FILE: &str = "print.css";
+
+/// Longest page description, in characters, before it is cut at a word.
+const DESCRIPTION_LIMIT: usize = 200;
+
+/// Page-level options for [`render_html_page`].
+#[derive(Debug, Clone, Default)]
+pub struct HtmlPageOptions {
+    /// Link [`STYLESHEET_FILE`] and [`PRINT_STYLESHEET_FILE`] next to the
+    /// page instead of inlining the styles.
+    pub linked_styles: bool,
+    /// Show a download button linking to the PDF at this href.
+    pub pdf_href: Option<String>,
+    /// Public URL of the page, for the canonical link and `og:url`.
+    pub url: Option<String>,
+    /// Apply the print stylesheet on screen as well, laying the page out
+    /// as the first printed sheet.
+    pub paged: bool,
+}
+
+/// Render a complete HTML document with OpenGraph metadata.
+pub fn render_html_page(resume: &ResumeData, options: &HtmlPageOptions) -> String {
+    let basics = &resume.basics;
+    let locale = resume.metadata.locale.as_str();
+    let title = page_title(resume);
+    let description = page_description(resume);
+
+    let mut head = String::new();
+    head.push_str("<meta charset=\"utf-8\">\n");
+    head.push_str("<meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n");
+    head.push_str("<meta name=\"generator\" content=\"Rustume\">\n");
+    head.push_str(&format!("<title>{}</title>\n", escape_html(&title)));
+    if !description.is_empty() {
+        head.push_str(&meta("name", "description", &description));
+    }
+    head.push_str(&meta("property", "og:type", "profile"));
+    head.push_str(&meta("property", "og:title", &title));
+    if !description.is_empty() {
+        head.push_str(&meta("property", "og:description", &description));
+    }
+    if let Some(url) = options.url.as_deref().filter(|url| is_web_url(url)) {
+        head.push_str(&meta("property", "og:url", url));
+        head.push_str(&format!(
+            "<link rel=\"canonical\" href=\"{}\">\n",
+            escape_html(url)
+        ));
+    }
+    // Link previews fetch the image, so a data URL is of no use to them
+    if basics.picture.is_visible() && is_web_url(&basics.picture.url) {
+        head.push_str(&meta("property", "og:image", &basics.picture.url));
+    }
+    head.push_str(&meta("name", "twitter:card", "summary"));
+    if !locale.is_empty() {
+        head.push_str(&meta("property", "og:locale", &locale.replace('-', "_")));
+    }
+
+    let print_media = if options.paged {
+        ""
+    } else {
+        " media=\"print\""
+    };
+    if options.linked_styles {
+        head.push_str(&format!(
+            "<link rel=\"stylesheet\" href=\"{STYLESHEET_FILE}\">\n\
+             <link rel=\"stylesheet\" href=\"{PRINT_STYLESHEET_FILE}\"{print_media}>\n"
+        ));
+    } else {
+        head.push_str(&format!(
+            "<style>\n{}</style>\n<style{print_media}>\n{}</style>\n",
+            inline_css(&resume_stylesheet(resume)),
+            inline_css(&print_stylesheet(resume)),
+        ));
+    }
+    if options.paged {
+        head.push_str(&format!("<style>\n{}</style>\n", paged_screen_css(resume)));
+    }
+
+    let actions = options
+        .pdf_href
+        .as_deref()
+        .map(|href| {
+            format!(
+                "<nav class=\"site-actions\">\n<a class=\"download\" href=\"{}\" download>Download PDF</a>\n</nav>\n",
+                escape_html(href)
+            )
+        })
+        .unwrap_or_default();
+
+    let lang = if locale.is_empty() { "en" } else { locale };
+    let dir = if is_rtl_language(lang) { "rtl" } else { "ltr" };
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"{lang}\" dir=\"{dir}\">\n<head>\n{head}</head>\n<body>\n{actions}{body}</body>\n</html>\n",
+        lang = escape_html(lang),
+        body = render_html_body(resume),
+    )
+}
+
+/// Render the resume itself as a `<main>` element, without document chrome.
+///
+/// Each layout page becomes a `.page` block with the first column as the
+/// main column and the others as the sidebar. The print stylesheet starts
+/// every block after the first on a new sheet.
+pub fn render_html_body(resume: &ResumeData) -> String {
+    let default_layout;
+    let layout = if resume.metadata.layout.is_empty() {
+        default_layout = Layout::default();
+        &default_layout
+    } else {
+        &resume.metadata.layout
+    };
+
+    let mut html = String::from("<main class=\"resume\">\n");
+    for (index, columns) in layout.pages().iter().enumerate() {
+        let mut main = String::new();
+        let mut aside = String::new();
+        for (column, keys) in columns.iter().enumerate() {
+            let target = if column == 0 { &mut main } else { &mut aside };
+            for key in keys {
+                target.push_str(&render_slot(&resume.sections, key));
+            }
+        }
+        if index > 0 && main.is_empty() && aside.is_empty() {
+            continue;
+        }
+
+        html.push_str("<div class=\"page\">\n");
+        if index == 0 {
+            html.push_str(&render_header(resume));
+        }
+        html.push_str("<div class=\"columns\">\n");
+        html.push_str(&format!("<div class=\"main\">\n{main}</div>\n"));
+        if !aside.is_empty() {
+            html.push_str(&format!("<aside class=\"sidebar\">\n{aside}</aside>\n"));
+        }
+        html.push_str("</div>\n</div>\n");
+    }
+    html.push_str("</main>\n");
+    html
+}
+
+/// Screen stylesheet in the resume's theme colors and typography, followed
+/// by the resume's custom CSS when it is enabled.
+pub fn resume_stylesheet(resume: &ResumeData) -> String {
+    let metadata = &resume.metadata;
+    let theme = &metadata.theme;
+    let typography = &metadata.typography;
+    let picture = &resume.basics.picture;
+
+    let mut css = format!(
+        r#":root {{
+  --primary: {primary};
+  --text: {text};
+  --background: {background};
+  --font-family: {font}, system-ui, sans-serif;
+  --font-size: {size}pt;
+  --line-height: {line_height};
+  --letter-spacing: {letter_spacing}em;
+  --heading-scale: {heading_scale};
+}}
+*, *::before, *::after {{ box-sizing: border-box; }}
+body {{
+  margin: 0;
+  background: var(--background);
+  color: var(--text);
+  font-family: var(--font-family);
+  font-size: var(--font-size);
+  line-height: var(--line-height);
+  letter-spacing: var(--letter-spacing);
+}}
+a {{ color: var(--primary); text-decoration: {decoration}; }}
+.resume {{ max-width: 60rem; margin: 0 auto; padding: 2rem 1.5rem; }}
+.resume-header {{ display: flex; gap: 1.5rem; align-items: center; margin-bottom: 2rem; }}
+.resume-header h1 {{ margin: 0; color: var(--primary); font-size: 2em; line-height: 1.2; }}
+.headline {{ margin: 0.25rem 0 0; font-size: 1.15em; }}
+.contact {{ display: flex; flex-wrap: wrap; gap: 0.25rem 1rem; margin: 0.75rem 0 0; padding: 0; list-style: none; }}
+.picture {{ width: {picture_size}px; aspect-ratio: {aspect_ratio}; object-fit: cover; border-radius: {picture_radius}px;{grayscale} }}
+.columns {{ display: grid; grid-template-columns: minmax(0, 2fr) minmax(0, 1fr); gap: 2rem; }}
+.columns > :only-child {{ grid-column: 1 / -1; }}
+.section {{ margin-bottom: 1.75rem; }}
+.section > h2 {{ margin: 0 0 0.75rem; padding-bottom: 0.25rem; border-bottom: 2px solid var(--primary); color: var(--primary); font-size: calc(1.1em * var(--heading-scale)); text-transform: uppercase; letter-spacing: 0.05em;{small_caps} }}
+.items {{ display: grid; grid-template-columns: repeat(var(--columns, 1), minmax(0, 1fr)); gap: 1rem 1.5rem; }}
+.item h3 {{ margin: 0; font-size: 1em; }}
+.item p {{ margin: 0.25rem 0 0; }}
+.subtitle {{ font-style: italic; }}
+.meta {{ opacity: 0.75; font-size: 0.9em; }}
+.keywords {{ display: flex; flex-wrap: wrap; gap: 0.25rem; margin: 0.5rem 0 0; padding: 0; list-style: none; }}
+.keywords li {{ padding: 0 0.5rem; border: 1px solid var(--primary); border-radius: 999px; font-size: 0.85em; }}
+.level {{ display: block; width: 100%; max-width: 10rem; accent-color: var(--primary); }}
+.site-actions {{ max-width: 60rem; margin: 0 auto; padding: 1rem 1.5rem 0; text-align: end; }}
+.download {{ display: inline-block; padding: 0.5rem 1rem; border-radius: 0.375rem; background: var(--primary); color: var(--background); font-weight: 600; text-decoration: none; }}
+@media (max-width: 40rem) {{
+  .columns, .items {{ grid-template-columns: minmax(0, 1fr); }}
+  .resume-header {{ flex-direction: column; align-items: flex-start; }}
+}}
+"#,
+        primary = css_color(&theme.primary, "#dc2626"),
+        text = css_color(&theme.text, "#000000"),
+        background = css_color(&theme.background, "#ffffff"),
+        font = css_font_family(&typography.font.family),
+        size = typography.font.size.clamp(6, 72),
+        line_height = typography.line_height.clamp(1.0, 3.0),
+        letter_spacing = typography.letter_spacing.clamp(-0.05, 0.2),
+        heading_scale = typography.heading_scale.clamp(0.5, 2.0),
+        small_caps = if typography.small_caps {
+            " font-variant: small-caps;"
+        } else {
+            ""
+        },
+        decoration = if typography.underline_links {
+            "underline"
+        } else {
+            "none"
+        },
+        picture_size = picture.size.min(512),
+        aspect_ratio = picture.aspect_ratio.clamp(0.25, 4.0),
+        picture_radius = picture.border_radius.min(512),
+        grayscale = if picture.effects.grayscale {
+            " filter: grayscale(1);"
+        } else {
+            ""
+        },
+    );
+
+    if let Some(spacing) = typography.paragraph_spacing {
+        css.push_str(&format!(
+            ".rich-text p {{ margin: 0 0 {}em; }}\n",
+            spacing.clamp(0.0, 3.0)
+        ));
+    }
+
+    if typography.justify == Some(true) {
+        css.push_str(".rich-text, .item p { text-align: justify; }\n");
+    }
+    // Unset hyphenation follows justification, as it does in Typst
+    if typography
+        .hyphenate
+        .unwrap_or(typography.justify == Some(true))
+    {
+        css.push_str("body { hyphens: auto; }\n");
+    }
+
+    if metadata.css.visible && !metadata.css.value.trim().is_empty() {
+        css.push_str("\n/* Custom CSS */\n");
+        css.push_str(&metadata.css.value);
+        css.push('\n');
+    }
+    css
+}
+
+/// Print stylesheet approximating the PDF: the resume's paper size and
+/// margins, the template's column arrangement, page breaks that keep items
+/// and headings together, and page numbers and the running contact line in
+/// the page margins.
+///
+/// Unlike the PDF, which leaves them off single-page resumes, margin
+/// content only appears from the second sheet on.
+pub fn print_stylesheet(resume: &ResumeData) -> String {
+    let page = &resume.metadata.page;
+    let size = match page.format {
+        PageFormat::A4 => "A4",
+        PageFormat::Letter => "letter",
+    };
+    let mut css = format!(
+        r#"@page {{ size: {size}; margin: {margin}pt; }}
+html {{ -webkit-print-color-adjust: exact; print-color-adjust: exact; }}
+.site-actions {{ display: none; }}
+.resume {{ max-width: none; margin: 0; padding: 0; }}
+.page + .page {{ break-before: page; }}
+.item, .resume-header {{ break-inside: avoid; }}
+.section > h2 {{ break-after: avoid; }}
+p, li {{ orphans: 3; widows: 3; }}
+a {{ text-decoration: none; }}
+"#,
+        margin = page.margin.min(100),
+    );
+
+    match print_columns(&resume.metadata.template, page) {
+        Some(columns) => {
+            css.push_str(&format!(
+                ".columns {{ grid-template-columns: {}; column-gap: 20pt; }}\n",
+                columns.tracks
+            ));
+            if columns.sidebar_first {
+                css.push_str(".sidebar { order: -1; }\n");
+            }
+        }
+        None => css.push_str(".columns { display: block; }\n"),
+    }
+
+    css.push_str(&margin_boxes(resume));
+    css
+}
+
+/// How a two-column template places layout columns 1 and 2 in the PDF.
+struct PrintColumns {
+    /// CSS grid tracks, left to right.
+    tracks: String,
+    /// Whether the sidebar (column 2) is drawn on the left.
+    sidebar_first: bool,
+}
+
+/// The PDF's column arrangement for `template`, or `None` when the
+/// template stacks its columns.
+///
+/// **Keep in sync with:** the `columns:` or `sidebar-width:` entry of each
+/// two-column template's `render-resume` config.
+fn print_columns(template: &str, page: &PageConfig) -> Option<PrintColumns> {
+    if template_layout(template) != TemplateLayout::TwoColumn {
+        return None;
+    }
+    let ratio = page.sidebar_ratio.map(|ratio| ratio.clamp(0.1, 0.5));
+    // `content-width` in _common.typ: paper width less both margins
+    let paper_width = match page.format {
+        PageFormat::A4 => 595.28,
+        PageFormat::Letter => 612.0,
+    };
+    let content_width = paper_width - 2.0 * page.margin.min(100) as f32;
+    let sidebar = |default: u32| match ratio {
+        Some(ratio) => format!("{:.1}pt minmax(0, 1fr)", ratio * content_width),
+        None => format!("{default}pt minmax(0, 1fr)"),
+    };
+    let split = |first: f32| format!("{first:.2}fr {:.2}fr", 1.0 - first);
+
+    let (tracks, sidebar_first) = match template {
+        // Column 1 in the narrow left track
+        "azurill" => (split(ratio.unwrap_or(1.0 / 3.0)), false),
+        "chikorita" => (split(1.0 - ratio.unwrap_or(1.0 / 3.0)), false),
+        // Not adjustable by sidebarRatio
+        "leafish" => ("1fr 1fr".to_string(), false),
+        "ditto" => (sidebar(160), true),
+        "pikachu" => (sidebar(180), true),
+        _ => (sidebar(170), true),
+    };
+    Some(PrintColumns {
+        tracks,
+        sidebar_first,
+    })
+}
+
+/// Page numbers and the running contact line as page-margin boxes, in the
+/// PDF's page options. Margins under 16pt have no room for them.
+fn margin_boxes(resume: &ResumeData) -> String {
+    let page = &resume.metadata.page;
+    let options = &page.options;
+    if page.margin < 16 {
+        return String::new();
+    }
+    let style = format!(
+        "font-size: 8pt; color: {}; opacity: 0.6;",
+        css_color(&resume.metadata.theme.text, "#000000")
+    );
+
+    let mut boxes = Vec::new();
+    if options.page_numbers {
+        let label = match options.page_number_style {
+            PageNumberStyle::Plain => "counter(page)".to_string(),
+            PageNumberStyle::OfTotal => "counter(page) \" / \" counter(pages)".to_string(),
+            PageNumberStyle::Labeled => {
+                let label = Locale::for_tag(&resume.metadata.locale).page_label;
+                css_content(label)
+            }
+        };
+        boxes.push(("bottom-right", label));
+    }
+    let name = resume.display_name();
+    let location = resume.basics.display_location();
+    let running = [&name, &resume.basics.email, &resume.basics.phone, &location]
+        .into_iter()
+        .map(|part| part.trim())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("  ·  ");
+    if !running.is_empty() {
+        match options.running_header {
+            RunningHeader::Header => boxes.push(("top-left", css_string(&running))),
+            RunningHeader::Footer => boxes.push(("bottom-left", css_string(&running))),
+            RunningHeader::Hidden => {}
+        }
+    }
+    if boxes.is_empty() {
+        return String::new();
+    }
+
+    let mut css = String::from("@page {\n");
+    for (margin_box, content) in &boxes {
+        css.push_str(&format!(
+            "  @{margin_box} {{ content: {content}; {style} }}\n"
+        ));
+    }
+    css.push_str("}\n@page :first {\n");
+    for (margin_box, _) in &boxes {
+        css.push_str(&format!("  @{margin_box} {{ content: none; }}\n"));
+    }
+    css.push_str("}\n");
+    css
+}
+
+/// A page label with `{current}` and `{total}` placeholders as CSS
+/// `content`, counting pages.
+fn css_content(label: &str) -> String {
+    let mut parts = Vec::new();
+    let mut rest = label;
+    while let Some(start) = rest.find('{') {
+        let (text, tail) = rest.split_at(start);
+        let counter = if tail.starts_with("{current}") {
+            Some(("counter(page)", "{current}".len()))
+        } else if tail.starts_with("{total}") {
+            Some(("counter(pages)", "{total}".len()))
+        } else {
+            None
+        };
+        match counter {
+            Some((counter, len)) => {
+                if !text.is_empty() {
+                    parts.push(css_string(text));
+                }
+                parts.push(counter.to_string());
+                rest = &tail[len..];
+            }
+            None => {
+                parts.push(css_string(&rest[..=start]));
+                rest = &tail[1..];
+            }
+        }
+    }
+    if !rest.is_empty() {
+        parts.push(css_string(rest));
+    }
+    parts.join(" ")
+}
+
+/// Text as a double-quoted CSS string.
+fn css_string(text: &str) -> String {
+    let mut quoted = String::from("\"");
+    for c in text.chars() {
+        match c {
+            '"' | '\\' => {
+                quoted.push('\\');
+                quoted.push(c);
+            }
+            // Line breaks and other controls as hex escapes
+            c if c.is_control() => quoted.push_str(&format!("\\{:x} ", c as u32)),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// Screen rules that lay the page out as a printed sheet, for comparing
+/// the browser's rendering with the PDF.
+fn paged_screen_css(resume: &ResumeData) -> String {
+    let page = &resume.metadata.page;
+    let (width, height) = match page.format {
+        PageFormat::A4 => (595.28, 841.89),
+        PageFormat::Letter => (612.0, 792.0),
+    };
+    format!(
+        "@media screen {{\n  .resume {{ width: {width}pt; min-height: {height}pt; margin: 0; padding: {margin}pt; }}\n}}\n",
+        margin = page.margin.min(100),
+    )
+}
+
+/// Header with the picture, name, headline, and contact details.
+fn render_header(resume: &ResumeData) -> String {
+    let basics = &resume.basics;
+    let name = resume.display_name();
+    let mut html = String::from("<header class=\"resume-header\">\n");
+
+    if basics.picture.is_visible() && is_image_url(&basics.picture.url) {
+        html.push_str(&format!(
+            "<img class=\"picture\" src=\"{}\" alt=\"{}\">\n",
+            escape_html(&basics.picture.url),
+            escape_html(&name),
+        ));
+    }
+
+    html.push_str("<div>\n");
+    html.push_str(&format!("<h1>{}</h1>\n", escape_html(&name)));
+    if !basics.headline.trim().is_empty() {
+        html.push_str(&format!(
+            "<p class=\"headline\">{}</p>\n",
+            escape_html(&basics.headline)
+        ));
+    }
+
+    let mut contact = Vec::new();
+    if !basics.email.trim().is_empty() {
+        contact.push(format!(
+            "<a href=\"mailto:{0}\">{0}</a>",
+            escape_html(basics.email.trim())
+        ));
+    }
+    if !basics.phone.trim().is_empty() {
+        let dial: String = basics
+            .phone
+            .chars()
+            .filter(|c| c.is_ascii_digit() || *c == '+')
+            .collect();
+        contact.push(format!(
+            "<a href=\"tel:{}\">{}</a>",
+            dial,
+            escape_html(basics.phone.trim())
+        ));
+    }
+    let location = basics.display_location();
+    if !location.is_empty() {
+        contact.push(escape_html(&location));
+    }
+    if let Some(link) = render_link(&basics.url, "") {
+        contact.push(link);
+    }
+    for field in &basics.custom_fields {
+        let value = field.value.trim();
+        if value.is_empty() {
+            continue;
+        }
+        // Web links show their name, as the templates do
+        let href = field.href();
+        let label = match field.kind {
+            CustomFieldKind::Link | CustomFieldKind::Text
+                if !field.name.is_empty() && href.is_some() =>
+            {
+                escape_html(&field.name)
+            }
+            CustomFieldKind::Date if !field.name.is_empty() => {
+                format!("{}: {}", escape_html(&field.name), escape_html(value))
+            }
+            _ => escape_html(value),
+        };
+        contact.push(match href {
+            Some(href) => format!("<a href=\"{}\">{label}</a>", escape_html(&href)),
+            None => label,
+        });
+    }
+    if !contact.is_empty() {
+        html.push_str("<ul class=\"contact\">\n");
+        for entry in contact {
+            html.push_str(&format!("<li>{entry}</li>\n"));
+        }
+        html.push_str("</ul>\n");
+    }
+
+    html.push_str("</div>\n</header>\n");
+    html
+}
+
+/// One item of any section, reduced to the parts every section shares.
+#[derive(Default)]
+struct Entry<'a> {
+    title: &'a str,
+    subtitle: &'a str,
+    date: &'a str,
+    location: &'a str,
+    url: Option<&'a Url>,
+    /// Rich text, sanitized before output.
+    summary: Cow<'a, str>,
+    keywords: &'a [String],
+    level: Option<u8>,
+}
+
+/// Render the section (or sections, for the custom slot) a layout key names.
+fn render_slot(sections: &Sections, key: &str) -> String {
+    // A visible section's visible items as entries
+    macro_rules! entries {
+        ($section:expr, |$item:ident| $entry:expr) => {{
+            let section = &$section;
+            if section.visible {
+                let entries: Vec<Entry> = section
+                    .items
+                    .iter()
+                    .filter(|item| item.visible)
+                    .map(|$item| $entry)
+                    .collect();
+                render_section(&section.id, &section.name, section.columns, &entries)
+            } else {
+                String::new()
+            }
+        }};
+    }
+
+    match key {
+        "summary" => {
+            let summary = &sections.summary;
+            if !summary.visible || summary.is_empty() {
+                return String::new();
+            }
+            format!(
+                "<section class=\"section\" id=\"summary\">\n<h2>{}</h2>\n<div class=\"rich-text\">{}</div>\n</section>\n",
+                escape_html(&summary.name),
+                sanitize_html(&summary.content)
+            )
+        }
+        // A letter to one employer has no place on a public page
+        "coverLetter" => String::new(),
+        "experience" => entries!(sections.experience, |item| Entry {
+            title: &item.company,
+            subtitle: &item.position,
+            date: &item.date,
+            location: &item.location,
+            url: Some(&item.url),
+            summary: item.summary_html(),
+            ..Entry::default()
+        }),
+        "education" => entries!(sections.education, |item| Entry {
+            title: &item.institution,
+            subtitle: if item.study_type.is_empty() {
+                &item.area
+            } else {
+                &item.study_type
+            },
+            date: &item.date,
+            location: &item.score,
+            url: Some(&item.url),
+            summary: item.summary.as_str().into(),
+            ..Entry::default()
+        }),
+        "skills" => entries!(sections.skills, |item| Entry {
+            title: &item.name,
+            subtitle: &item.description,
+            keywords: &item.keywords,
+            level: Some(item.level),
+            ..Entry::default()
+        }),
+        "projects" => entries!(sections.projects, |item| Entry {
+            title: &item.name,
+            subtitle: &item.description,
+            date: &item.date,
+            url: Some(&item.url),
+            summary: item.summary_html(),
+            keywords: &item.keywords,
+            ..Entry::default()
+        }),
+        "profiles" => entries!(sections.profiles, |item| Entry {
+            title: &item.network,
+            subtitle: &item.username,
+            url: Some(&item.url),
+            ..Entry::default()
+        }),
+        "awards" => entries!(sections.awards, |item| Entry {
+            title: &item.title,
+            subtitle: &item.awarder,
+            date: &item.date,
+            url: Some(&item.url),
+            summary: item.summary.as_str().into(),
+            ..Entry::default()
+        }),
+        "certifications" => entries!(sections.certifications, |item| Entry {
+            title: &item.name,
+            subtitle: &item.issuer,
+            date: &item.date,
+            url: Some(&item.url),
+            summary: item.summary.as_str().into(),
+            ..Entry::default()
+        }),
+        "publications" => entries!(sections.publications, |item| Entry {
+            title: &item.name,
+            subtitle: &item.publisher,
+            date: &item.date,
+            url: Some(&item.url),
+            summary: item.summary.as_str().into(),
+            ..Entry::default()
+        }),
+        "languages" => entries!(sections.languages, |item| Entry {
+            title: &item.name,
+            subtitle: &item.description,
+            level: Some(item.level),
+            ..Entry::default()
+        }),
+        "interests" => entries!(sections.interests, |item| Entry {
+            title: &item.name,
+            keywords: &item.keywords,
+            ..Entry::default()
+        }),
+        "volunteer" => entries!(sections.volunteer, |item| Entry {
+            title: &item.organization,
+            subtitle: &item.position,
+            date: &item.date,
+            location: &item.location,
+            url: Some(&item.url),
+            summary: item.summary.as_str().into(),
+            ..Entry::default()
+        }),
+        "references" => entries!(sections.references, |item| Entry {
+            title: &item.name,
+            subtitle: &item.description,
+            url: Some(&item.url),
+            summary: item.summary.as_str().into(),
+            ..Entry::default()
+        }),
+        CUSTOM_SECTIONS_SLOT => {
+            let mut keys: Vec<&String> = sections.custom.keys().collect();
+            keys.sort_unstable();
+            keys.into_iter()
+                .map(|key| render_slot(sections, key))
+                .collect()
+        }
+        key => match sections.custom.get(key) {
+            Some(section) => entries!(*section, |item| Entry {
+                title: &item.name,
+                subtitle: &item.description,
+                date: &item.date,
+                location: &item.location,
+                url: Some(&item.url),
+                summary: item.summary.as_str().into(),
+                keywords: &item.keywords,
+                ..Entry::default()
+            }),
+            None => String::new(),
+        },
+    }
+}
+
+fn render_section(id: &str, name: &str, columns: u8, entries: &[Entry]) -> String {
+    if entries.is_empty() {
+        return String::new();
+    }
+    let mut html = format!(
+        "<section class=\"section\" id=\"{}\">\n<h2>{}</h2>\n<div class=\"items\" style=\"--columns: {}\">\n",
+        escape_html(id),
+        escape_html(name),
+        columns.clamp(1, 5)
+    );
+    for entry in entries {
+        html.push_str(&render_entry(entry));
+    }
+    html.push_str("</div>\n</section>\n");
+    html
+}
+
+fn render_entry(entry: &Entry) -> String {
+    let mut html = String::from("<article class=\"item\">\n");
+
+    let title = entry
+        .url
+        .and_then(|url| render_link(url, entry.title))
+        .unwrap_or_else(|| escape_html(entry.title));
+    html.push_str(&format!("<h3>{title}</h3>\n"));
+    if !entry.subtitle.trim().is_empty() {
+        html.push_str(&format!(
+            "<p class=\"subtitle\">{}</p>\n",
+            escape_html(entry.subtitle)
+        ));
+    }
+    let meta: Vec<String> = [entry.date, entry.location]
+        .into_iter()
+        .map(str::trim)
+        .filter(|part| !part.is_empty())
+        .map(escape_html)
+        .collect();
+    if !meta.is_empty() {
+        html.push_str(&format!("<p class=\"meta\">{}</p>\n", meta.join(" · ")));
+    }
+    if let Some(level) = entry.level.filter(|level| *level > 0) {
+        let level = level.min(5);
+        html.push_str(&format!(
+            "<meter class=\"level\" min=\"0\" max=\"5\" value=\"{level}\">{level}/5</meter>\n"
+        ));
+    }
+    if !entry.summary.trim().is_empty() {
+        html.push_str(&format!(
+            "<div class=\"rich-text\">{}</div>\n",
+            sanitize_html(&entry.summary)
+        ));
+    }
+    let keywords: Vec<&String> = entry
+        .keywords
+        .iter()
+        .filter(|keyword| !keyword.trim().is_empty())
+        .collect();
+    if !keywords.is_empty() {
+        html.push_str("<ul class=\"keywords\">\n");
+        for keyword in keywords {
+            html.push_str(&format!("<li>{}</li>\n", escape_html(keyword)));
+        }
+        html.push_str("</ul>\n");
+    }
+
+    html.push_str("</article>\n");
+    html
+}
+
+/// A link to a web URL, labelled by `text`, the URL's label, or the URL.
+fn render_link(url: &Url, text: &str) -> Option<String> {
+    if !is_web_url(&url.href) {
+        return None;
+    }
+    let label = [text, url.label.as_str(), url.href.as_str()]
+        .into_iter()
+        .find(|label| !label.trim().is_empty())
+        .unwrap_or_default();
+    Some(format!(
+        "<a href=\"{}\" rel=\"noopener noreferrer\">{}</a>",
+        escape_html(&url.href),
+        escape_html(label)
+    ))
+}
+
+/// "Name – Headline", or whichever part is set.
+fn page_title(resume: &ResumeData) -> String {
+    let name = resume.display_name();
+    let name = name.trim();
+    let headline = resume.basics.headline.trim();
+    match (name.is_empty(), headline.is_empty()) {
+        (false, false) => format!("{name} – {headline}"),
+        (false, true) => name.to_string(),
+        (true, false) => headline.to_string(),
+        (true, true) => "Resume".to_string(),
+    }
+}
+
+/// The summary as plain text, cut at a word boundary, or the headline.
+pub(crate) fn page_description(resume: &ResumeData) -> String {
+    let summary = &resume.sections.summary;
+    let text = if summary.visible {
+        html_text_blocks(&sanitize_html(&summary.content)).join(" ")
+    } else {
+        String::new()
+    };
+    if text.trim().is_empty() {
+        return resume.basics.headline.trim().to_string();
+    }
+    if text.chars().count() <= DESCRIPTION_LIMIT {
+        return text;
+    }
+    let cut: String = text.chars().take(DESCRIPTION_LIMIT).collect();
+    let cut = cut.rsplit_once(' ').map_or(cut.as_str(), |(head, _)| head);
+    format!("{}…", cut.trim_end_matches([',', ';', ':', '.']))
+}
+
+fn meta(attribute: &str, name: &str, content: &str) -> String {
+    format!(
+        "<meta {attribute}=\"{name}\" content=\"{}\">\n",
+        escape_html(content)
+    )
+}
+
+/// Escape text for HTML content and double-quoted attributes.
+/// Keep custom CSS from closing an inline `<style>` element.
+fn inline_css(css: &str) -> String {
+    css.replace("</", "<\\/")
+}
+
+fn is_web_url(url: &str) -> bool {
+    let url = url.trim();
+    let scheme = url.split_once(':').map(|(scheme, _)| scheme);
+    matches!(scheme, Some(scheme) if scheme.eq_ignore_ascii_case("https") || scheme.eq_ignore_ascii_case("http"))
+}
+
+/// Web URLs and inline raster or SVG images; other data URLs are dropped.
+fn is_image_url(url: &str) -> bool {
+    is_web_url(url) || url.trim_start().starts_with("data:image/")
+}
+
+/// A theme color as `#rrggbb`, or `fallback` when it isn't a hex color.
+fn css_color(color: &str, fallback: &str) -> String {
+    hex_to_rgb(color).map_or_else(|| fallback.to_string(), rgb_to_hex)
+}
+
+/// A font family as a quoted CSS string.
+fn css_font_family(family: &str) -> String {
+    let family: String = family
+        .chars()
+        .filter(|c| !matches!(c, '"' | '\\' | ';' | '{' | '}' | '<' | '>'))
+        .collect();
+    let family = family.trim();
+    if family.is_empty() {
+        "sans-serif".to_string()
+    } else {
+        format!("\"{family}\"")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use rustume_schema::{CustomField, Experience, Skill, SummarySection};
+
+    fn sample() -> ResumeData {
+        let mut resume = ResumeData::with_basics("Ada <Lovelace>", "ada@example.com");
+        resume.basics.headline = "Analyst & Programmer".into();
+        resume.sections.summary =
+            SummarySection::new("<p>Wrote the first program.</p><script>alert(1)</script>");
+        resume.sections.experience.add_item(
+            Experience::new("Analytical Engine", "Programmer")
+                .with_date("1842 - 1843")
+                .with_url("https://example.com/engine")
+                .with_summary("<ul><li>Notes on the engine</li></ul>"),
+        );
+        resume
+            .sections
+            .skills
+            .add_item(Skill::new("Mathematics").with_level(5));
+        resume
+    }
+
+    #[test]
+    fn test_page_escapes_text_and_sanitizes_rich_text() {
+        let html = render_html_page(&sample(), &HtmlPageOptions::default());
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<h1>Ada &lt;Lovelace&gt;</h1>"));
+        assert!(html.contains("Analyst &amp; Programmer"));
+        assert!(html.contains("<p>Wrote the first program.</p>"));
+        assert!(!html.contains("<script>"));
+        assert!(html.contains("<a href=\"https://example.com/engine\""));
+        assert!(html.contains("<meter class=\"level\""));
+    }
+
+    #[test]
+    fn test_custom_fields_link_by_kind() {
+        let mut resume = sample();
+        resume.basics = resume
+            .basics
+            .with_custom_field(CustomField::link("GitHub", "https://github.com/ada"))
+            .with_custom_field(CustomField::email("Work", "ada@engine.org"))
+            .with_custom_field(CustomField::date("Available", "1843-09"))
+            .with_custom_field(CustomField::new("Pronouns", "she/her"));
+
+        let html = render_html_page(&resume, &HtmlPageOptions::default());
+        assert!(html.contains("<li><a href=\"https://github.com/ada\">GitHub</a></li>"));
+        assert!(html.contains("<li><a href=\"mailto:ada@engine.org\">ada@engine.org</a></li>"));
+        assert!(html.contains("<li>Available: 1843-09</li>"));
+        assert!(html.contains("<li>she/her</li>"));
+    }
+
+    #[test]
+    fn test_page_has_opengraph_metadata() {
+        let mut resume = sample();
+        resume.basics.picture.url = "https://example.com/ada.png".into();
+        let options = HtmlPageOptions {
+            url: Some("https://ada.example.com/".into()),
+            ..HtmlPageOptions::default()
+        };
+
+        let html = render_html_page(&resume, &options);
+        assert!(html.contains(
+            "<meta property=\"og:title\" content=\"Ada &lt;Lovelace&gt; – Analyst &amp; Programmer\">"
+        ));
+        assert!(html
+            .contains("<meta property=\"og:description\" content=\"Wrote the first program.\">"));
+        assert!(html.contains("<meta property=\"og:url\" content=\"https://ada.example.com/\">"));
+        assert!(
+            html.contains("<meta property=\"og:image\" content=\"https://example.com/ada.png\">")
+        );
+    }
+
+    #[test]
+    fn test_hidden_sections_items_and_cover_letter_are_left_out() {
+        let mut resume = sample();
+        resume.sections.experience.items[0].visible = false;
+        resume.sections.skills.visible = false;
+        resume.sections.cover_letter.visible = true;
+
+        let html = render_html_body(&resume);
+        assert!(!html.contains("Analytical Engine"));
+        assert!(!html.contains("Mathematics"));
+        assert!(!html.contains("id=\"coverLetter\""));
+        assert!(html.contains("id=\"summary\""));
+    }
+
+    #[test]
+    fn test_linked_styles_and_download_button() {
+        let options = HtmlPageOptions {
+            linked_styles: true,
+            pdf_href: Some("resume.pdf".into()),
+            ..HtmlPageOptions::default()
+        };
+
+        let html = render_html_page(&sample(), &options);
+        assert!(html.contains("<link rel=\"stylesheet\" href=\"style.css\">"));
+        assert!(html.contains("href=\"print.css\" media=\"print\""));
+        assert!(html.contains("<a class=\"download\" href=\"resume.pdf\" download>"));
+        assert!(!html.contains("<style>"));
+        assert!(!html.contains("og:url"));
+    }
+
+    #[test]
+    fn test_stylesheet_uses_theme_and_guards_custom_css() {
+        let mut resume = sample();
+        resume.metadata.theme.primary = "#0EA5E9".into();
+        resume.metadata.theme.text = "red; } body { display: none".into();
+        resume.metadata.css.visible = true;
+        resume.metadata.css.value = "h1 { color: teal; } </style><script>".into();
+
+        let css = resume_stylesheet(&resume);
+        assert!(css.contains("--primary: #0ea5e9;"));
+        assert!(css.contains("--text: #000000;"));
+        assert!(css.contains("h1 { color: teal; }"));
+        assert!(!css.contains("small-caps"));
+
+        resume.metadata.typography.letter_spacing = 0.05;
+        resume.metadata.typography.paragraph_spacing = Some(0.5);
+        resume.metadata.typography.small_caps = true;
+        let css = resume_stylesheet(&resume);
+        assert!(css.contains("--letter-spacing: 0.05em;"));
+        assert!(css.contains(".rich-text p { margin: 0 0 0.5em; }"));
+        assert!(css.contains("font-variant: small-caps;"));
+        assert!(!css.contains("hyphens"));
+
+        resume.metadata.typography.justify = Some(true);
+        let css = resume_stylesheet(&resume);
+        assert!(css.contains("text-align: justify;"));
+        assert!(css.contains("hyphens: auto;"));
+
+        let html = render_html_page(&resume, &HtmlPageOptions::default());
+        assert!(!html.contains("</style><script>"));
+    }
+
+    #[test]
+    fn test_print_stylesheet_follows_page_settings() {
+        let mut resume = sample();
+        resume.metadata.page.format = PageFormat::Letter;
+        resume.metadata.page.margin = 24;
+
+        let css = print_stylesheet(&resume);
+        assert!(css.contains("@page { size: letter; margin: 24pt; }"));
+        assert!(css.contains(".site-actions { display: none; }"));
+        assert!(css.contains(".page + .page { break-before: page; }"));
+    }
+
+    #[rstest]
+    #[case::single_column("rhyhorn", None, ".columns { display: block; }")]
+    #[case::narrow_first_column("azurill", None, "grid-template-columns: 0.33fr 0.67fr;")]
+    #[case::wide_first_column("chikorita", Some(0.25), "grid-template-columns: 0.75fr 0.25fr;")]
+    #[case::fixed_sidebar("gengar", None, "grid-template-columns: 170pt minmax(0, 1fr);")]
+    #[case::sidebar_ratio("pikachu", Some(0.2), "grid-template-columns: 111.9pt minmax(0, 1fr);")]
+    fn test_print_columns_mirror_template(
+        #[case] template: &str,
+        #[case] sidebar_ratio: Option<f32>,
+        #[case] expected: &str,
+    ) {
+        let mut resume = sample();
+        resume.metadata.template = template.into();
+        resume.metadata.page.sidebar_ratio = sidebar_ratio;
+
+        let css = print_stylesheet(&resume);
+        assert!(css.contains(expected), "{css}");
+        assert_eq!(
+            css.contains(".sidebar { order: -1; }"),
+            matches!(template, "gengar" | "pikachu")
+        );
+    }
+
+    #[test]
+    fn test_margin_boxes_follow_page_options() {
+        let mut resume = sample();
+        resume.metadata.locale = "de-DE".into();
+        resume.metadata.page.options.page_number_style = PageNumberStyle::Labeled;
+        resume.metadata.page.options.running_header = RunningHeader::Footer;
+
+        let css = print_stylesheet(&resume);
+        assert!(css.contains(
+            "@bottom-right { content: \"Seite \" counter(page) \" von \" counter(pages);"
+        ));
+        assert!(css.contains("@bottom-left { content: \"Ada <Lovelace>  ·  ada@example.com\";"));
+        assert!(css.contains("@page :first {\n  @bottom-right { content: none; }"));
+
+        resume.metadata.page.margin = 12;
+        assert!(!print_stylesheet(&resume).contains("@bottom-right"));
+    }
+
+    #[test]
+    fn test_layout_pages_become_page_blocks() {
+        let mut resume = sample();
+        resume.metadata.layout = Layout::from(vec![
+            vec![vec!["summary".to_string()], vec!["skills".to_string()]],
+            vec![vec!["experience".to_string()]],
+            vec![vec!["awards".to_string()]],
+        ]);
+
+        let html = render_html_body(&resume);
+        // The third page only holds a hidden section
+        assert_eq!(html.matches("<div class=\"page\">").count(), 2);
+        assert_eq!(html.matches("<header class=\"resume-header\">").count(), 1);
+        assert!(html.find("id=\"skills\"").unwrap() < html.find("id=\"experience\"").unwrap());
+    }
+
+    #[test]
+    fn test_paged_page_applies_print_styles_on_screen() {
+        let options = HtmlPageOptions {
+            paged: true,
+            ..HtmlPageOptions::default()
+        };
+
+        let html = render_html_page(&sample(), &options);
+        assert!(!html.contains("media=\"print\""));
+        assert!(html.contains(".resume { width: 595.28pt; min-height: 841.89pt;"));
+    }
+
+    #[test]
+    fn test_css_strings_are_escaped() {
+        assert_eq!(css_string("a \"b\" \\ c"), r#""a \"b\" \\ c""#);
+        assert_eq!(css_string("line\nbreak"), r#""line\a break""#);
+        assert_eq!(
+            css_content("{current} of {total} {x}"),
+            r#"counter(page) " of " counter(pages) " {" "x}""#
+        );
+    }
+
+    #[test]
+    fn test_description_is_cut_at_a_word() {
+        let mut resume = sample();
+        resume.sections.summary = SummarySection::new(format!("<p>{}</p>", "word ".repeat(60)));
+
+        let description = page_description(&resume);
+        assert!(description.ends_with("word…"));
+        assert!(description.chars().count() <= DESCRIPTION_LIMIT + 1);
+    }
+}
@@ -1,6 +1,7 @@
 //! Template engine and PDF generation for Rustume.
 //!
-//! Uses Typst for high-quality PDF rendering without browser dependencies.
+//! Uses Typst for high-quality PDF rendering without browser dependencies, and
+//! renders standalone HTML pages for publishing a resume on the web.
 //!
 //! ## Template overrides
 //!
@@ -8,6 +9,35 @@
 //! directory of `<name>.typ` files to override embedded templates at render time without
 //! rebuilding. WASM builds use embedded templates only.
 //!
+//! The `dev` feature adds `set_dev_templates_dir`, for a `--dev-templates
+//! <dir>` flag, and a `TemplateWatcher` that reports edits to the
+//! directory, so a template author sees each change without a rebuild.
+//! [`TypstRenderer::check_template`] compiles a candidate template against
+//! fixture resumes and reports compile errors, missing section handlers,
+//! and overflowing text.
+//!
+//! Community templates ship as [`TemplatePackage`]s: a manifest, the Typst
+//! source, and a preview thumbnail. `InstalledTemplates` installs them into
+//! a local directory, and [`TypstRenderer::with_template_packages`] renders
+//! them by name next to the built-in [`TEMPLATES`].
+//!
+//! Typst code only sees the templates and files a render registers, and
+//! [`SandboxLimits`] bound each compilation's time, source size, and memory,
+//! and how many compilations run at once.
+//!
+//! A [`TemplatePolicy`] narrows the templates a deployment offers;
+//! `RUSTUME_TEMPLATES_ALLOW` and `RUSTUME_TEMPLATES_DENY` configure it for
+//! the server and CLI. A [`Branding`] adds a deployment's colors, footer
+//! text, and logo to every Typst render.
+//!
+//! ## Backends
+//!
+//! [`TypstRenderer`] is the default backend. Others implement [`Renderer`] and
+//! are picked by [`RenderEngine`] from a [`RendererRegistry`]. The `chromium`
+//! feature adds `ChromiumRenderer`, which prints the HTML page with headless
+//! Chromium; `RUSTUME_CHROMIUM_PATH` and `RUSTUME_CHROMIUM_ARGS` configure the
+//! browser it finds.
+//!
 //! # Example
 //!
 //! ```ignore
@@ -22,12 +52,50 @@
 //!
 //! // Generate preview image
 //! let (png_bytes, _total_pages) = renderer.render_preview(&resume, 0)?;
+//!
+//! // Or the same page as SVG, for previews that zoom without blurring
+//! let (svg, _total_pages) = renderer.render_svg(&resume, 0)?;
 //! ```
 
+mod backend;
+mod branding;
+#[cfg(feature = "chromium")]
+mod chromium;
+mod html;
+mod limits;
+mod redact;
+mod stats;
+mod template_package;
+mod template_policy;
 mod traits;
 mod typst_engine;
 
-pub use traits::{RenderError, Renderer};
+pub use backend::{RenderEngine, RendererRegistry, SharedRenderer};
+pub use branding::{Branding, Logo};
+#[cfg(feature = "chromium")]
+pub use chromium::ChromiumRenderer;
+pub use html::{
+    print_stylesheet, render_html_body, render_html_page, resume_stylesheet, HtmlPageOptions,
+    PRINT_STYLESHEET_FILE, STYLESHEET_FILE,
+};
+pub use limits::RenderLimits;
+pub use redact::Redaction;
+pub use stats::{RenderKind, RenderObserver, RenderStats};
+#[cfg(not(target_arch = "wasm32"))]
+pub use template_package::InstalledTemplates;
+pub use template_package::{
+    PackageOptions, TemplateManifest, TemplatePackage, TemplatePackageError, MANIFEST_FILE,
+    PREVIEW_FILE, TEMPLATE_FILE, TEMPLATE_PACKAGE_EXTENSION,
+};
+pub use template_policy::{TemplatePolicy, TEMPLATES_ALLOW_ENV, TEMPLATES_DENY_ENV};
+pub use traits::{RenderError, RenderProgress, Renderer};
+#[cfg(all(feature = "dev", not(target_arch = "wasm32")))]
+pub use typst_engine::{
+    dev_templates_dir, set_dev_templates_dir, TemplateWatcher, DEFAULT_POLL_INTERVAL,
+};
 pub use typst_engine::{
-    get_page_size, get_template_theme, TemplateTheme, TypstRenderer, TEMPLATES,
+    get_page_size, get_template_theme, layout_warnings, page_option_warnings, template_layout,
+    template_option_warnings, template_options, template_rules, FixtureCheck, SandboxLimits,
+    SheetFormat, TemplateCheckReport, TemplateFixture, TemplateIssue, TemplateLayout,
+    TemplateOptionSupport, TemplateTheme, TypstRenderer, TEMPLATES,
 };
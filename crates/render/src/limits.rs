@@ -0,0 +1,81 @@
+//! Size ceilings for rendering.
+//!
+//! Typst's memory use grows with the text it lays out and the images it
+//! decodes, and a pasted novel in a summary can take a server process down
+//! with it. [`RenderLimits`] rejects such resumes with
+//! [`RenderError::TooLarge`] before compilation starts.
+
+use crate::RenderError;
+
+/// Size ceilings checked before Typst compiles a resume. Both are off by
+/// default.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RenderLimits {
+    /// Largest resume data in bytes, measured as the JSON handed to Typst:
+    /// rich text converted, pictures and attachment files left out.
+    pub max_data_bytes: Option<usize>,
+    /// Largest combined size in bytes of the picture, attachment files, and
+    /// attachment QR codes.
+    pub max_asset_bytes: Option<usize>,
+}
+
+impl RenderLimits {
+    /// Fail with [`RenderError::TooLarge`] if either size is over its limit.
+    pub(crate) fn check(&self, data_bytes: usize, asset_bytes: usize) -> Result<(), RenderError> {
+        for (what, actual, limit) in [
+            ("resume data", data_bytes, self.max_data_bytes),
+            (
+                "pictures and attachments",
+                asset_bytes,
+                self.max_asset_bytes,
+            ),
+        ] {
+            if let Some(limit) = limit.filter(|limit| actual > *limit) {
+                return Err(RenderError::TooLarge {
+                    what,
+                    actual,
+                    limit,
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_limits_by_default() {
+        assert!(RenderLimits::default()
+            .check(usize::MAX, usize::MAX)
+            .is_ok());
+    }
+
+    #[test]
+    fn sizes_over_a_limit_are_rejected() {
+        let limits = RenderLimits {
+            max_data_bytes: Some(100),
+            max_asset_bytes: Some(1_000),
+        };
+        assert!(limits.check(100, 1_000).is_ok());
+
+        let err = limits.check(101, 0).unwrap_err();
+        assert!(matches!(
+            err,
+            RenderError::TooLarge {
+                what: "resume data",
+                actual: 101,
+                limit: 100
+            }
+        ));
+        assert!(matches!(
+            limits.check(0, 1_001),
+            Err(RenderError::TooLarge {
+                what: "pictures and attachments",
+                ..
+            })
+        ));
+    }
+}
@@ -0,0 +1,264 @@
+//! Render-time redaction.
+//!
+//! A [`Redaction`] drops sections, contact details, attachments, and single
+//! items from the copy of a resume that gets rendered, so a public version
+//! (say, without a phone number) can be exported without editing the stored
+//! resume.
+
+use std::collections::HashSet;
+
+use rustume_schema::{Address, ResumeData, Url};
+
+/// Section keys with items, as they appear in resume JSON.
+const ITEM_SECTIONS: &[&str] = &[
+    "profiles",
+    "experience",
+    "education",
+    "awards",
+    "certifications",
+    "skills",
+    "interests",
+    "publications",
+    "volunteer",
+    "languages",
+    "projects",
+    "references",
+];
+
+/// Contact fields that can be excluded like sections.
+const CONTACT_FIELDS: &[&str] = &[
+    "basics.email",
+    "basics.phone",
+    "basics.location",
+    "basics.url",
+    "basics.picture",
+    "basics.customFields",
+];
+
+/// Content to leave out of a render.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Redaction {
+    /// Section keys (`experience`, `summary`, `coverLetter`, a custom section
+    /// ID), contact fields (`basics.phone`, `basics.email`, ...), or
+    /// `attachments`.
+    pub exclude_sections: Vec<String>,
+
+    /// IDs of section items, `basics.customFields` entries, or attachments.
+    pub exclude_item_ids: Vec<String>,
+}
+
+impl Redaction {
+    /// Whether the redaction removes nothing.
+    pub fn is_empty(&self) -> bool {
+        self.exclude_sections.is_empty() && self.exclude_item_ids.is_empty()
+    }
+
+    /// Section keys that name nothing in `resume`. Callers should reject
+    /// these: a typo would otherwise leave in the content it meant to hide.
+    pub fn unknown_sections(&self, resume: &ResumeData) -> Vec<&str> {
+        self.exclude_sections
+            .iter()
+            .map(String::as_str)
+            .filter(|key| {
+                !matches!(*key, "summary" | "coverLetter" | "attachments")
+                    && !ITEM_SECTIONS.contains(key)
+                    && !CONTACT_FIELDS.contains(key)
+                    && !resume.sections.custom.contains_key(*key)
+            })
+            .collect()
+    }
+
+    /// Remove the excluded content from `resume`. Unknown section keys and
+    /// item IDs are ignored.
+    pub fn apply(&self, resume: &mut ResumeData) {
+        for key in &self.exclude_sections {
+            exclude(resume, key);
+        }
+
+        if self.exclude_item_ids.is_empty() {
+            return;
+        }
+        let ids: HashSet<&str> = self.exclude_item_ids.iter().map(String::as_str).collect();
+        let sections = &mut resume.sections;
+        macro_rules! retain_items {
+            ($($field:ident),*) => {
+                $(sections.$field.items.retain(|item| !ids.contains(item.id.as_str()));)*
+            };
+        }
+        retain_items!(
+            profiles,
+            experience,
+            education,
+            awards,
+            certifications,
+            skills,
+            interests,
+            publications,
+            volunteer,
+            languages,
+            projects,
+            references
+        );
+        for section in sections.custom.values_mut() {
+            section.items.retain(|item| !ids.contains(item.id.as_str()));
+        }
+        resume
+            .basics
+            .custom_fields
+            .retain(|field| !ids.contains(field.id.as_str()));
+        resume
+            .attachments
+            .retain(|attachment| !ids.contains(attachment.id.as_str()));
+    }
+}
+
+/// Hide one section or clear one contact field.
+fn exclude(resume: &mut ResumeData, key: &str) {
+    let basics = &mut resume.basics;
+    let sections = &mut resume.sections;
+    macro_rules! hide {
+        ($section:expr) => {{
+            $section.visible = false;
+            $section.items.clear();
+        }};
+    }
+
+    match key {
+        "basics.email" => basics.email.clear(),
+        "basics.phone" => basics.phone.clear(),
+        "basics.location" => {
+            basics.location.clear();
+            basics.address = Address::default();
+        }
+        "basics.url" => basics.url = Url::default(),
+        "basics.picture" => basics.picture.url.clear(),
+        "basics.customFields" => basics.custom_fields.clear(),
+        "attachments" => resume.attachments.clear(),
+        "summary" => {
+            sections.summary.visible = false;
+            sections.summary.content.clear();
+        }
+        "coverLetter" => {
+            sections.cover_letter.visible = false;
+            sections.cover_letter.content.clear();
+        }
+        "profiles" => hide!(sections.profiles),
+        "experience" => hide!(sections.experience),
+        "education" => hide!(sections.education),
+        "awards" => hide!(sections.awards),
+        "certifications" => hide!(sections.certifications),
+        "skills" => hide!(sections.skills),
+        "interests" => hide!(sections.interests),
+        "publications" => hide!(sections.publications),
+        "volunteer" => hide!(sections.volunteer),
+        "languages" => hide!(sections.languages),
+        "projects" => hide!(sections.projects),
+        "references" => hide!(sections.references),
+        custom => {
+            if let Some(section) = sections.custom.get_mut(custom) {
+                hide!(section);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rustume_schema::{Attachment, Basics, CustomItem, Experience, Section, Skill};
+
+    fn resume() -> ResumeData {
+        let mut resume = ResumeData::default();
+        resume.basics = Basics::new("Jane Doe")
+            .with_email("jane@example.com")
+            .with_phone("+1 555 0100");
+        resume.sections.summary.content = "Summary".into();
+        resume
+            .sections
+            .experience
+            .add_item(Experience::new("Acme", "Engineer"));
+        resume
+            .sections
+            .experience
+            .add_item(Experience::new("Globex", "Analyst"));
+        resume.sections.skills.add_item(Skill::new("Rust"));
+        let mut talks = Section::new("talks", "Talks");
+        talks.add_item(CustomItem::new("RustConf"));
+        resume.sections.custom.insert("talks".into(), talks);
+        resume
+    }
+
+    #[test]
+    fn test_excludes_sections_and_contact_fields() {
+        let mut resume = resume();
+        let redaction = Redaction {
+            exclude_sections: vec!["basics.phone".into(), "skills".into(), "talks".into()],
+            ..Redaction::default()
+        };
+        assert!(redaction.unknown_sections(&resume).is_empty());
+
+        redaction.apply(&mut resume);
+
+        assert!(resume.basics.phone.is_empty());
+        assert_eq!(resume.basics.email, "jane@example.com");
+        assert!(!resume.sections.skills.visible);
+        assert!(resume.sections.skills.items.is_empty());
+        assert!(resume.sections.custom["talks"].items.is_empty());
+        assert_eq!(resume.sections.experience.items.len(), 2);
+    }
+
+    #[test]
+    fn test_excludes_items_by_id() {
+        let mut resume = resume();
+        let id = resume.sections.experience.items[0].id.clone();
+        resume.basics.add_custom_field("WhatsApp", "+1 555 0100");
+        let field_id = resume.basics.custom_fields[0].id.clone();
+        let redaction = Redaction {
+            exclude_item_ids: vec![id, field_id, "missing".into()],
+            ..Redaction::default()
+        };
+
+        redaction.apply(&mut resume);
+
+        let experience = &resume.sections.experience.items;
+        assert_eq!(experience.len(), 1);
+        assert_eq!(experience[0].company, "Globex");
+        assert!(resume.basics.custom_fields.is_empty());
+    }
+
+    #[test]
+    fn test_excludes_attachments() {
+        let mut resume = resume();
+        resume.attachments = vec![
+            Attachment::link("Letter", "https://example.com/letter"),
+            Attachment::link("Certificate", "https://example.com/cert"),
+        ];
+        let by_id = Redaction {
+            exclude_item_ids: vec![resume.attachments[0].id.clone()],
+            ..Redaction::default()
+        };
+        by_id.apply(&mut resume);
+        assert_eq!(resume.attachments.len(), 1);
+        assert_eq!(resume.attachments[0].name, "Certificate");
+
+        let all = Redaction {
+            exclude_sections: vec!["attachments".into()],
+            ..Redaction::default()
+        };
+        assert!(all.unknown_sections(&resume).is_empty());
+        all.apply(&mut resume);
+        assert!(resume.attachments.is_empty());
+    }
+
+    #[test]
+    fn test_unknown_sections() {
+        let redaction = Redaction {
+            exclude_sections: vec!["experiance".into(), "summary".into(), "basics.fax".into()],
+            ..Redaction::default()
+        };
+        assert_eq!(
+            redaction.unknown_sections(&resume()),
+            ["experiance", "basics.fax"]
+        );
+    }
+}
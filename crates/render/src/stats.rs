@@ -0,0 +1,68 @@
+//! Measurements from each Typst render.
+//!
+//! After every PDF or preview render, [`TypstRenderer`](crate::TypstRenderer)
+//! logs a [`RenderStats`] as a structured `tracing` event and hands it to the
+//! [`RenderObserver`] it was built with, if any. The server's observer feeds
+//! Prometheus, so slow or failing templates show up per template.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use rustume_schema::ErrorCode;
+
+/// Callback given the [`RenderStats`] of every finished render.
+pub type RenderObserver = Arc<dyn Fn(&RenderStats) + Send + Sync>;
+
+/// What a render produced.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RenderKind {
+    /// A PDF document.
+    Pdf,
+    /// A PNG image of one page.
+    Preview,
+    /// An SVG image of one page.
+    Svg,
+}
+
+impl RenderKind {
+    /// Lowercase name, for logs and metric labels.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Pdf => "pdf",
+            Self::Preview => "preview",
+            Self::Svg => "svg",
+        }
+    }
+}
+
+/// Measurements from one render, successful or not.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenderStats {
+    /// Template rendered with, after falling back from an unknown name.
+    pub template: String,
+    pub kind: RenderKind,
+    /// Pages in the compiled document; 0 when compilation failed.
+    pub pages: usize,
+    /// Time spent generating the Typst source and compiling it.
+    pub compile_time: Duration,
+    /// Size of the PDF, PNG, or SVG; 0 when the render failed.
+    pub output_bytes: usize,
+    /// Fallback font families named for non-Latin scripts in the resume.
+    pub font_fallbacks: Vec<&'static str>,
+    /// Code of the error the render failed with.
+    pub error: Option<ErrorCode>,
+}
+
+impl RenderStats {
+    pub(crate) fn new(kind: RenderKind, template: &str) -> Self {
+        Self {
+            template: template.to_string(),
+            kind,
+            pages: 0,
+            compile_time: Duration::ZERO,
+            output_bytes: 0,
+            font_fallbacks: Vec::new(),
+            error: None,
+        }
+    }
+}
@@ -0,0 +1,555 @@
+//! Community template packages.
+//!
+//! A template package (`.rtpl`) is a ZIP archive, or a directory while it is
+//! being written, holding:
+//!
+//! - `manifest.json`: the [`TemplateManifest`] with the template's name,
+//!   version, theme defaults, and the template options it honors
+//! - `template.typ`: the Typst source, which may import `_common.typ` and
+//!   must export `template(data)` like the built-in templates
+//! - `preview.png`: an optional thumbnail
+//!
+//! [`InstalledTemplates`] installs packages into a local directory and loads
+//! them back for [`TypstRenderer::with_template_packages`], which renders an
+//! installed template by name next to the built-in [`TEMPLATES`].
+//!
+//! [`TypstRenderer::with_template_packages`]: crate::TypstRenderer::with_template_packages
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::io::{Cursor, Read};
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::{Path, PathBuf};
+
+use rustume_schema::{validate_hex_color, ErrorCode, PhotoPosition};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+#[cfg(not(target_arch = "wasm32"))]
+use tracing::warn;
+
+use crate::{TemplateTheme, TEMPLATES};
+
+/// File extension of a packed template package.
+pub const TEMPLATE_PACKAGE_EXTENSION: &str = "rtpl";
+
+/// The manifest inside a package.
+pub const MANIFEST_FILE: &str = "manifest.json";
+
+/// The Typst source inside a package.
+pub const TEMPLATE_FILE: &str = "template.typ";
+
+/// The optional thumbnail inside a package.
+pub const PREVIEW_FILE: &str = "preview.png";
+
+/// Largest file read from a package.
+#[cfg(not(target_arch = "wasm32"))]
+const MAX_FILE_BYTES: u64 = 8 * 1024 * 1024;
+
+/// Longest template name.
+const MAX_NAME_LEN: usize = 64;
+
+const PNG_SIGNATURE: &[u8] = b"\x89PNG\r\n\x1a\n";
+
+/// Why a template package can't be read, installed, or removed.
+#[derive(Debug, Error)]
+pub enum TemplatePackageError {
+    #[error("Invalid template package: {0}")]
+    Invalid(String),
+
+    #[error("Template package `{0}` is not installed")]
+    NotInstalled(String),
+
+    #[error("Template package I/O failed: {0}")]
+    Io(String),
+}
+
+impl TemplatePackageError {
+    /// Stable code for this error.
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Self::Invalid(_) => ErrorCode::InvalidInput,
+            Self::NotInstalled(_) => ErrorCode::RenderTemplateNotFound,
+            Self::Io(_) => ErrorCode::Internal,
+        }
+    }
+}
+
+/// Template options a packaged template honors, as in
+/// [`TemplateOptionSupport`](crate::TemplateOptionSupport). A manifest that
+/// leaves them out claims all of them.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PackageOptions {
+    /// Photo positions besides `template-default`.
+    #[serde(default = "all_photo_positions")]
+    pub photo: Vec<PhotoPosition>,
+    /// Whether `headerAlign` moves the name and contact details.
+    #[serde(default = "default_true")]
+    pub header_align: bool,
+    /// Whether `divider` restyles the rules under section headings.
+    #[serde(default = "default_true")]
+    pub divider: bool,
+}
+
+impl Default for PackageOptions {
+    fn default() -> Self {
+        Self {
+            photo: all_photo_positions(),
+            header_align: true,
+            divider: true,
+        }
+    }
+}
+
+fn all_photo_positions() -> Vec<PhotoPosition> {
+    vec![
+        PhotoPosition::Top,
+        PhotoPosition::Left,
+        PhotoPosition::Right,
+        PhotoPosition::Hidden,
+    ]
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// `manifest.json` of a template package.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TemplateManifest {
+    /// Template name resumes select it by: lowercase letters, digits, and
+    /// hyphens, starting with a letter, and not a built-in template's.
+    pub name: String,
+    pub version: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub author: String,
+    /// Colors a resume picks up when it switches to the template.
+    pub theme: TemplateTheme,
+    #[serde(default)]
+    pub options: PackageOptions,
+}
+
+impl TemplateManifest {
+    /// Check the name, version, and theme colors.
+    pub fn validate(&self) -> Result<(), TemplatePackageError> {
+        let invalid = |message: String| Err(TemplatePackageError::Invalid(message));
+        if !is_package_name(&self.name) {
+            return invalid(format!(
+                "`{}` is not a template name; use lowercase letters, digits, and hyphens",
+                self.name
+            ));
+        }
+        if TEMPLATES.contains(&self.name.as_str()) {
+            return invalid(format!("`{}` is a built-in template", self.name));
+        }
+        if self.version.trim().is_empty() {
+            return invalid("the manifest has no version".to_string());
+        }
+        for (field, color) in [
+            ("background", &self.theme.background),
+            ("text", &self.theme.text),
+            ("primary", &self.theme.primary),
+        ] {
+            if color.is_empty() || validate_hex_color(color).is_err() {
+                return invalid(format!("theme.{field} `{color}` is not a #RRGGBB color"));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Whether `name` can name a packaged template (and its install directory).
+fn is_package_name(name: &str) -> bool {
+    name.len() <= MAX_NAME_LEN
+        && name.starts_with(|c: char| c.is_ascii_lowercase())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+}
+
+/// A template package: manifest, Typst source, and optional thumbnail.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TemplatePackage {
+    pub manifest: TemplateManifest,
+    pub source: String,
+    /// PNG thumbnail.
+    pub preview: Option<Vec<u8>>,
+}
+
+impl TemplatePackage {
+    /// Build a package from the contents of its files, checking them.
+    pub fn from_files(
+        manifest: &[u8],
+        source: Vec<u8>,
+        preview: Option<Vec<u8>>,
+    ) -> Result<Self, TemplatePackageError> {
+        let manifest: TemplateManifest = serde_json::from_slice(manifest).map_err(|err| {
+            TemplatePackageError::Invalid(format!("{MANIFEST_FILE} is not valid: {err}"))
+        })?;
+        manifest.validate()?;
+        let source = String::from_utf8(source).map_err(|_| {
+            TemplatePackageError::Invalid(format!("{TEMPLATE_FILE} is not UTF-8 text"))
+        })?;
+        if source.trim().is_empty() {
+            return Err(TemplatePackageError::Invalid(format!(
+                "{TEMPLATE_FILE} is empty"
+            )));
+        }
+        if preview
+            .as_deref()
+            .is_some_and(|png| !png.starts_with(PNG_SIGNATURE))
+        {
+            return Err(TemplatePackageError::Invalid(format!(
+                "{PREVIEW_FILE} is not a PNG image"
+            )));
+        }
+        Ok(Self {
+            manifest,
+            source,
+            preview,
+        })
+    }
+
+    /// The template's name.
+    pub fn name(&self) -> &str {
+        &self.manifest.name
+    }
+
+    /// Read a package from a `.rtpl` archive or a directory of its files.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn read(path: &Path) -> Result<Self, TemplatePackageError> {
+        if path.is_dir() {
+            return Self::read_dir(path);
+        }
+        let data = std::fs::read(path).map_err(|err| io_error(path, &err))?;
+        Self::from_archive(&data)
+    }
+
+    /// Read a package from the bytes of a `.rtpl` archive.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn from_archive(data: &[u8]) -> Result<Self, TemplatePackageError> {
+        let invalid = |err: zip::result::ZipError| {
+            TemplatePackageError::Invalid(format!("not a template package archive: {err}"))
+        };
+        let mut archive = zip::ZipArchive::new(Cursor::new(data)).map_err(invalid)?;
+        let mut read = |name: &str| -> Result<Option<Vec<u8>>, TemplatePackageError> {
+            let file = match archive.by_name(name) {
+                Ok(file) => file,
+                Err(zip::result::ZipError::FileNotFound) => return Ok(None),
+                Err(err) => return Err(invalid(err)),
+            };
+            read_limited(file, name).map(Some)
+        };
+        let manifest = read(MANIFEST_FILE)?.ok_or_else(|| missing(MANIFEST_FILE))?;
+        let source = read(TEMPLATE_FILE)?.ok_or_else(|| missing(TEMPLATE_FILE))?;
+        let preview = read(PREVIEW_FILE)?;
+        Self::from_files(&manifest, source, preview)
+    }
+
+    /// Read a package from a directory of its files.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn read_dir(dir: &Path) -> Result<Self, TemplatePackageError> {
+        let read = |name: &str| -> Result<Option<Vec<u8>>, TemplatePackageError> {
+            let path = dir.join(name);
+            match std::fs::File::open(&path) {
+                Ok(file) => read_limited(file, name).map(Some),
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+                Err(err) => Err(io_error(&path, &err)),
+            }
+        };
+        let manifest = read(MANIFEST_FILE)?.ok_or_else(|| missing(MANIFEST_FILE))?;
+        let source = read(TEMPLATE_FILE)?.ok_or_else(|| missing(TEMPLATE_FILE))?;
+        let preview = read(PREVIEW_FILE)?;
+        Self::from_files(&manifest, source, preview)
+    }
+
+    /// Write the package's files into `dir`, which must exist.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn write_dir(&self, dir: &Path) -> Result<(), TemplatePackageError> {
+        let manifest = serde_json::to_vec_pretty(&self.manifest)
+            .map_err(|err| TemplatePackageError::Io(err.to_string()))?;
+        let mut files = vec![
+            (MANIFEST_FILE, manifest.as_slice()),
+            (TEMPLATE_FILE, self.source.as_bytes()),
+        ];
+        if let Some(preview) = &self.preview {
+            files.push((PREVIEW_FILE, preview.as_slice()));
+        }
+        for (name, contents) in files {
+            let path = dir.join(name);
+            std::fs::write(&path, contents).map_err(|err| io_error(&path, &err))?;
+        }
+        Ok(())
+    }
+}
+
+/// Read at most [`MAX_FILE_BYTES`] from the package file `name`.
+#[cfg(not(target_arch = "wasm32"))]
+fn read_limited(file: impl Read, name: &str) -> Result<Vec<u8>, TemplatePackageError> {
+    let mut data = Vec::new();
+    file.take(MAX_FILE_BYTES + 1)
+        .read_to_end(&mut data)
+        .map_err(|err| TemplatePackageError::Io(format!("{name}: {err}")))?;
+    if data.len() as u64 > MAX_FILE_BYTES {
+        return Err(TemplatePackageError::Invalid(format!(
+            "{name} is larger than {} MiB",
+            MAX_FILE_BYTES / (1024 * 1024)
+        )));
+    }
+    Ok(data)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn missing(name: &str) -> TemplatePackageError {
+    TemplatePackageError::Invalid(format!("{name} is missing"))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn io_error(path: &Path, err: &std::io::Error) -> TemplatePackageError {
+    TemplatePackageError::Io(format!("{}: {err}", path.display()))
+}
+
+/// Template packages installed in a local directory, one subdirectory of
+/// package files per template.
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Debug, Clone)]
+pub struct InstalledTemplates {
+    dir: PathBuf,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl InstalledTemplates {
+    /// Packages installed in `dir`, which is created on the first install.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// The install directory.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Install `package`, replacing an installed version of the same
+    /// template. Returns the version it replaced.
+    pub fn install(
+        &self,
+        package: &TemplatePackage,
+    ) -> Result<Option<String>, TemplatePackageError> {
+        package.manifest.validate()?;
+        let name = package.name();
+        let previous = match self.get(name) {
+            Ok(installed) => Some(installed.manifest.version),
+            // A broken install is replaced like a missing one
+            Err(_) => None,
+        };
+
+        // Write next to the target, then swap, so a failed write leaves the
+        // installed version in place
+        let staging = self.dir.join(format!(".{name}.installing"));
+        let target = self.dir.join(name);
+        let _ = std::fs::remove_dir_all(&staging);
+        std::fs::create_dir_all(&staging).map_err(|err| io_error(&staging, &err))?;
+        if let Err(err) = package.write_dir(&staging) {
+            let _ = std::fs::remove_dir_all(&staging);
+            return Err(err);
+        }
+        if target.exists() {
+            std::fs::remove_dir_all(&target).map_err(|err| io_error(&target, &err))?;
+        }
+        std::fs::rename(&staging, &target).map_err(|err| io_error(&target, &err))?;
+        Ok(previous)
+    }
+
+    /// Remove the installed template `name`.
+    pub fn uninstall(&self, name: &str) -> Result<(), TemplatePackageError> {
+        let target = self.package_dir(name)?;
+        std::fs::remove_dir_all(&target).map_err(|err| io_error(&target, &err))
+    }
+
+    /// The installed template `name`.
+    pub fn get(&self, name: &str) -> Result<TemplatePackage, TemplatePackageError> {
+        TemplatePackage::read_dir(&self.package_dir(name)?)
+    }
+
+    /// Every installed template, by name. Packages that no longer load are
+    /// logged and skipped, so one broken install can't block the others.
+    pub fn load(&self) -> Result<Vec<TemplatePackage>, TemplatePackageError> {
+        let entries = match std::fs::read_dir(&self.dir) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(err) => return Err(io_error(&self.dir, &err)),
+        };
+        let mut packages = Vec::new();
+        for entry in entries {
+            let path = entry.map_err(|err| io_error(&self.dir, &err))?.path();
+            let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+                continue;
+            };
+            if !path.is_dir() || !is_package_name(name) {
+                continue;
+            }
+            match TemplatePackage::read_dir(&path) {
+                Ok(package) if package.name() == name => packages.push(package),
+                Ok(package) => warn!(
+                    dir = %path.display(),
+                    name = package.name(),
+                    "Skipping template package installed under another name"
+                ),
+                Err(err) => warn!(dir = %path.display(), error = %err, "Skipping template package"),
+            }
+        }
+        packages.sort_by(|a, b| a.manifest.name.cmp(&b.manifest.name));
+        Ok(packages)
+    }
+
+    /// Directory of the installed template `name`.
+    fn package_dir(&self, name: &str) -> Result<PathBuf, TemplatePackageError> {
+        let dir = self.dir.join(name);
+        if is_package_name(name) && dir.is_dir() {
+            Ok(dir)
+        } else {
+            Err(TemplatePackageError::NotInstalled(name.to_string()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use super::*;
+
+    const SOURCE: &str = include_str!("typst_engine/templates/onyx.typ");
+
+    fn manifest(name: &str) -> String {
+        format!(
+            r##"{{
+                "name": "{name}",
+                "version": "1.0.0",
+                "description": "Onyx, repackaged",
+                "theme": {{"background": "#ffffff", "text": "#111827", "primary": "#2563eb"}},
+                "options": {{"photo": ["top", "hidden"], "headerAlign": false}}
+            }}"##
+        )
+    }
+
+    fn write_package_dir(dir: &Path, name: &str) {
+        std::fs::create_dir_all(dir).unwrap();
+        std::fs::write(dir.join(MANIFEST_FILE), manifest(name)).unwrap();
+        std::fs::write(dir.join(TEMPLATE_FILE), SOURCE).unwrap();
+    }
+
+    #[test]
+    fn test_reads_package_directory() {
+        let temp = tempfile::tempdir().unwrap();
+        write_package_dir(temp.path(), "midnight");
+
+        let package = TemplatePackage::read(temp.path()).unwrap();
+        assert_eq!(package.name(), "midnight");
+        assert_eq!(package.manifest.theme.primary, "#2563eb");
+        assert_eq!(
+            package.manifest.options.photo,
+            [PhotoPosition::Top, PhotoPosition::Hidden]
+        );
+        assert!(!package.manifest.options.header_align);
+        assert!(package.manifest.options.divider);
+        assert_eq!(package.preview, None);
+    }
+
+    #[test]
+    fn test_reads_package_archive() {
+        let mut archive = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        let options = zip::write::SimpleFileOptions::default();
+        for (name, contents) in [
+            (MANIFEST_FILE, manifest("midnight").into_bytes()),
+            (TEMPLATE_FILE, SOURCE.as_bytes().to_vec()),
+            (PREVIEW_FILE, PNG_SIGNATURE.to_vec()),
+        ] {
+            archive.start_file(name, options).unwrap();
+            archive.write_all(&contents).unwrap();
+        }
+        let data = archive.finish().unwrap().into_inner();
+
+        let package = TemplatePackage::from_archive(&data).unwrap();
+        assert_eq!(package.name(), "midnight");
+        assert_eq!(package.preview.as_deref(), Some(PNG_SIGNATURE));
+        assert!(matches!(
+            TemplatePackage::from_archive(b"not a zip"),
+            Err(TemplatePackageError::Invalid(_))
+        ));
+    }
+
+    #[test]
+    fn test_rejects_invalid_manifests() {
+        for (manifest, reason) in [
+            (manifest("onyx"), "built-in"),
+            (manifest("../escape"), "not a template name"),
+            (manifest("Midnight"), "not a template name"),
+            (manifest("midnight").replace("1.0.0", " "), "no version"),
+            (
+                manifest("midnight").replace("#2563eb", "blue"),
+                "theme.primary",
+            ),
+        ] {
+            let err =
+                TemplatePackage::from_files(manifest.as_bytes(), SOURCE.into(), None).unwrap_err();
+            assert!(err.to_string().contains(reason), "{err}");
+        }
+        let err = TemplatePackage::from_files(
+            manifest("midnight").as_bytes(),
+            SOURCE.into(),
+            Some(b"GIF89a".to_vec()),
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains(PREVIEW_FILE), "{err}");
+    }
+
+    #[test]
+    fn test_installs_and_uninstalls_packages() {
+        let temp = tempfile::tempdir().unwrap();
+        let source = temp.path().join("midnight.rtpl");
+        write_package_dir(&source, "midnight");
+        let installed = InstalledTemplates::new(temp.path().join("installed"));
+        assert!(installed.load().unwrap().is_empty());
+
+        let package = TemplatePackage::read(&source).unwrap();
+        assert_eq!(installed.install(&package).unwrap(), None);
+        let mut update = package.clone();
+        update.manifest.version = "1.1.0".to_string();
+        assert_eq!(
+            installed.install(&update).unwrap().as_deref(),
+            Some("1.0.0")
+        );
+        assert_eq!(installed.load().unwrap(), [update]);
+
+        installed.uninstall("midnight").unwrap();
+        assert!(installed.load().unwrap().is_empty());
+        assert!(matches!(
+            installed.uninstall("midnight"),
+            Err(TemplatePackageError::NotInstalled(_))
+        ));
+        assert!(matches!(
+            installed.uninstall(".."),
+            Err(TemplatePackageError::NotInstalled(_))
+        ));
+    }
+
+    #[test]
+    fn test_renders_package_by_name() {
+        use crate::{Renderer, TypstRenderer};
+
+        let package =
+            TemplatePackage::from_files(manifest("midnight").as_bytes(), SOURCE.into(), None)
+                .unwrap();
+        let renderer = TypstRenderer::new().with_template_packages(vec![package]);
+        assert_eq!(renderer.template_theme("midnight").primary, "#2563eb");
+
+        let mut resume = rustume_schema::ResumeData::default();
+        resume.basics.name = "Package Test".to_string();
+        resume.metadata.template = "midnight".to_string();
+        let pdf = renderer.render_pdf(&resume).unwrap();
+        assert!(pdf.starts_with(b"%PDF"));
+    }
+}
@@ -0,0 +1,147 @@
+//! Which built-in templates a deployment offers.
+//!
+//! Deployments that standardize on a corporate template narrow [`TEMPLATES`]
+//! with a [`TemplatePolicy`]: an allow list, a deny list, or both. A renderer
+//! with a policy refuses the templates it leaves out with
+//! [`RenderError::TemplateNotAllowed`], and falls back to an offered template
+//! for names it doesn't know.
+
+use crate::{RenderError, TEMPLATES};
+
+/// Environment variable with the comma-separated templates to offer.
+pub const TEMPLATES_ALLOW_ENV: &str = "RUSTUME_TEMPLATES_ALLOW";
+
+/// Environment variable with the comma-separated templates to withhold.
+pub const TEMPLATES_DENY_ENV: &str = "RUSTUME_TEMPLATES_DENY";
+
+/// Templates a deployment offers. The default offers all of them.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TemplatePolicy {
+    /// Templates offered; all of them when `None`.
+    allow: Option<Vec<String>>,
+    /// Templates withheld, even when allowed.
+    deny: Vec<String>,
+}
+
+impl TemplatePolicy {
+    /// Build a policy from comma-separated allow and deny lists. A missing or
+    /// blank list imposes nothing.
+    ///
+    /// Fails on a name that isn't a template and when the lists leave no
+    /// template at all, so a typo can't quietly offer every template or none.
+    pub fn parse(allow: Option<&str>, deny: Option<&str>) -> Result<Self, RenderError> {
+        let allow = allow
+            .map(names)
+            .transpose()?
+            .filter(|names| !names.is_empty());
+        let policy = Self {
+            allow,
+            deny: deny.map(names).transpose()?.unwrap_or_default(),
+        };
+        if policy.templates().is_empty() {
+            return Err(RenderError::InvalidConfig(
+                "The template allow and deny lists leave no template to render".to_string(),
+            ));
+        }
+        Ok(policy)
+    }
+
+    /// Read the policy from [`TEMPLATES_ALLOW_ENV`] and [`TEMPLATES_DENY_ENV`].
+    pub fn from_env() -> Result<Self, RenderError> {
+        let allow = std::env::var(TEMPLATES_ALLOW_ENV).ok();
+        let deny = std::env::var(TEMPLATES_DENY_ENV).ok();
+        Self::parse(allow.as_deref(), deny.as_deref())
+    }
+
+    /// Whether the policy leaves out any template.
+    pub fn is_restricted(&self) -> bool {
+        self.templates().len() < TEMPLATES.len()
+    }
+
+    /// Whether `name` is a template this policy offers.
+    pub fn allows(&self, name: &str) -> bool {
+        TEMPLATES.contains(&name)
+            && self
+                .allow
+                .as_ref()
+                .map_or(true, |allow| allow.iter().any(|allowed| allowed == name))
+            && !self.deny.iter().any(|denied| denied == name)
+    }
+
+    /// Offered templates, in [`TEMPLATES`] order.
+    pub fn templates(&self) -> Vec<&'static str> {
+        TEMPLATES
+            .iter()
+            .copied()
+            .filter(|name| self.allows(name))
+            .collect()
+    }
+
+    /// Fail with [`RenderError::TemplateNotAllowed`] for a template this
+    /// policy leaves out. Names that aren't templates pass; renderers fall
+    /// back for those.
+    pub fn check(&self, name: &str) -> Result<(), RenderError> {
+        if TEMPLATES.contains(&name) && !self.allows(name) {
+            return Err(RenderError::TemplateNotAllowed(name.to_string()));
+        }
+        Ok(())
+    }
+}
+
+/// Template names in a comma-separated list, lowercased.
+fn names(list: &str) -> Result<Vec<String>, RenderError> {
+    list.split(',')
+        .map(|name| name.trim().to_ascii_lowercase())
+        .filter(|name| !name.is_empty())
+        .map(|name| {
+            if TEMPLATES.contains(&name.as_str()) {
+                Ok(name)
+            } else {
+                Err(RenderError::TemplateNotFound(name))
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn offers_everything_by_default() {
+        let policy = TemplatePolicy::default();
+        assert!(!policy.is_restricted());
+        assert_eq!(policy.templates(), TEMPLATES);
+        assert!(policy.check("onyx").is_ok());
+        assert_eq!(TemplatePolicy::parse(Some(" "), None).unwrap(), policy);
+    }
+
+    #[test]
+    fn allow_and_deny_lists_narrow_templates() {
+        let policy = TemplatePolicy::parse(Some("Onyx, rhyhorn,kakuna"), Some("kakuna")).unwrap();
+        assert_eq!(policy.templates(), ["rhyhorn", "onyx"]);
+        assert!(policy.is_restricted());
+        assert!(matches!(
+            policy.check("azurill"),
+            Err(RenderError::TemplateNotAllowed(name)) if name == "azurill"
+        ));
+        // Unknown names are the renderer's fallback to handle
+        assert!(policy.check("missingno").is_ok());
+
+        let policy = TemplatePolicy::parse(None, Some("onyx")).unwrap();
+        assert_eq!(policy.templates().len(), TEMPLATES.len() - 1);
+        assert!(!policy.allows("onyx"));
+    }
+
+    #[test]
+    fn rejects_unknown_names_and_empty_results() {
+        assert!(matches!(
+            TemplatePolicy::parse(Some("rhyhorn,missingno"), None),
+            Err(RenderError::TemplateNotFound(name)) if name == "missingno"
+        ));
+        assert!(matches!(
+            TemplatePolicy::parse(Some("onyx"), Some("onyx")),
+            Err(RenderError::InvalidConfig(_))
+        ));
+    }
+}
@@ -1,26 +1,104 @@
 //! Renderer trait definitions.
 
-use rustume_schema::ResumeData;
+use rustume_schema::{ErrorCode, ResumeData, RustumeError};
+use serde::Serialize;
 use thiserror::Error;
 
+use crate::RenderEngine;
+
 /// Render error types.
 #[derive(Error, Debug)]
 pub enum RenderError {
     #[error("Template not found: {0}")]
     TemplateNotFound(String),
 
+    /// The template exists but the [`TemplatePolicy`](crate::TemplatePolicy)
+    /// doesn't offer it.
+    #[error("Template `{0}` is not available on this deployment")]
+    TemplateNotAllowed(String),
+
     #[error("Render failed: {0}")]
     RenderFailed(String),
 
     #[error("Invalid configuration: {0}")]
     InvalidConfig(String),
+
+    /// The resume is over a [`RenderLimits`](crate::RenderLimits) ceiling;
+    /// nothing was compiled.
+    #[error("Resume is too large to render: {what} is {actual} bytes, over the limit of {limit}")]
+    TooLarge {
+        what: &'static str,
+        actual: usize,
+        limit: usize,
+    },
+
+    /// Typst code went over a [`SandboxLimits`](crate::SandboxLimits)
+    /// ceiling while compiling.
+    #[error("Typst compilation stopped: {0}")]
+    LimitExceeded(String),
+
+    /// No backend is registered for the engine.
+    #[error("Render engine `{0}` is not available")]
+    EngineUnavailable(RenderEngine),
+
+    /// The backend can't produce this output.
+    #[error("This render engine does not support {0}")]
+    Unsupported(&'static str),
+}
+
+impl RenderError {
+    /// Stable code for this error.
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Self::TemplateNotFound(_) => ErrorCode::RenderTemplateNotFound,
+            Self::TemplateNotAllowed(_) => ErrorCode::RenderTemplateNotAllowed,
+            Self::RenderFailed(_) => ErrorCode::RenderFailed,
+            Self::InvalidConfig(_) => ErrorCode::RenderInvalidConfig,
+            Self::TooLarge { .. } | Self::LimitExceeded(_) => ErrorCode::RenderTooLarge,
+            Self::EngineUnavailable(_) => ErrorCode::RenderEngineUnavailable,
+            Self::Unsupported(_) => ErrorCode::RenderUnsupported,
+        }
+    }
 }
 
-/// Renderer trait.
+impl From<RenderError> for RustumeError {
+    fn from(err: RenderError) -> Self {
+        Self::new(err.code(), err.to_string())
+    }
+}
+
+/// Milestone reported while rendering a PDF, in the order they occur.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(tag = "stage", rename_all = "snake_case")]
+pub enum RenderProgress {
+    /// Typst source was generated from the resume data.
+    SourceGenerated,
+    /// Typst compilation and layout finished.
+    Compiled { total_pages: usize },
+    /// A laid-out page (zero-based) is ready for PDF export.
+    PageRendered { page: usize, total_pages: usize },
+    /// PDF export of the laid-out pages started.
+    Exporting,
+    /// The PDF is written, `size` bytes long.
+    Done { size: usize },
+}
+
+/// Renderer trait, implemented by each rendering backend.
 pub trait Renderer {
     /// Render resume to PDF bytes.
     fn render_pdf(&self, resume: &ResumeData) -> Result<Vec<u8>, RenderError>;
 
+    /// Render resume to PDF bytes, reporting each [`RenderProgress`]
+    /// milestone to `on_progress`. Backends without milestones only render.
+    fn render_pdf_with_progress(
+        &self,
+        resume: &ResumeData,
+        on_progress: &mut dyn FnMut(RenderProgress),
+    ) -> Result<Vec<u8>, RenderError> {
+        let _ = on_progress;
+        self.render_pdf(resume)
+    }
+
     /// Render resume to HTML string.
     fn render_html(&self, resume: &ResumeData) -> Result<String, RenderError>;
 
@@ -32,4 +110,11 @@ pub trait Renderer {
         resume: &ResumeData,
         page: usize,
     ) -> Result<(Vec<u8>, usize), RenderError>;
+
+    /// Render one page (zero-based) as an SVG document, which stays sharp at
+    /// any zoom. Returns `(svg, total_page_count)`.
+    fn render_svg(&self, resume: &ResumeData, page: usize) -> Result<(String, usize), RenderError> {
+        let _ = (resume, page);
+        Err(RenderError::Unsupported("SVG pages"))
+    }
 }
@@ -0,0 +1,141 @@
+//! Attachments in the PDF export.
+//!
+//! Attachment files arrive as base64 data URLs and Typst reads neither those
+//! nor the network, so [`prepare_attachments`] turns each visible attachment
+//! into a virtual asset: the decoded file for appended ones, an SVG QR code
+//! for linked ones. Templates get the result as `data.attachments`.
+
+use std::sync::Arc;
+
+use base64::Engine as _;
+use qrcode::render::svg;
+use qrcode::QrCode;
+use rustume_schema::{Attachment, AttachmentMode};
+use serde_json::{json, Value};
+use tracing::warn;
+
+/// Decoded attachment assets (virtual path, bytes).
+pub(crate) type Assets = Vec<(String, Vec<u8>)>;
+
+/// Describe the visible attachments for templates and collect their assets.
+///
+/// Attachments missing what their mode needs, or whose file can't be read,
+/// are left out; `attachment_warnings` reports the former when validating.
+pub(crate) fn prepare_attachments(attachments: &[Attachment]) -> (Value, Assets) {
+    let mut entries = Vec::new();
+    let mut assets = Vec::new();
+
+    for (index, attachment) in attachments.iter().enumerate() {
+        if !attachment.visible || !attachment.is_presentable() {
+            continue;
+        }
+        let prepared = match attachment.mode {
+            AttachmentMode::Append => appended(index, attachment),
+            AttachmentMode::QrCode => linked(index, attachment),
+        };
+        match prepared {
+            Some((entry, asset)) => {
+                entries.push(entry);
+                assets.push(asset);
+            }
+            None => warn!(name = %attachment.name, "Skipping unreadable attachment"),
+        }
+    }
+
+    (Value::Array(entries), assets)
+}
+
+/// The decoded file, with the page count for PDFs.
+fn appended(index: usize, attachment: &Attachment) -> Option<(Value, (String, Vec<u8>))> {
+    let (media_type, encoded) = attachment.file_parts()?;
+    let ext = match media_type {
+        "application/pdf" => "pdf",
+        "image/png" => "png",
+        "image/jpeg" => "jpg",
+        "image/gif" => "gif",
+        "image/webp" => "webp",
+        "image/svg+xml" => "svg",
+        _ => return None,
+    };
+    let data = base64::engine::general_purpose::STANDARD
+        .decode(encoded)
+        .ok()?;
+
+    let path = format!("/assets/attachment-{index}.{ext}");
+    let entry = if ext == "pdf" {
+        // Encrypted and malformed PDFs fail here as they would in `image()`
+        let pages = hayro_syntax::Pdf::new(Arc::new(data.clone()))
+            .ok()?
+            .pages()
+            .len();
+        json!({
+            "name": attachment.name,
+            "mode": "append",
+            "kind": "pdf",
+            "path": path,
+            "pages": pages,
+        })
+    } else {
+        json!({
+            "name": attachment.name,
+            "mode": "append",
+            "kind": "image",
+            "path": path,
+        })
+    };
+    Some((entry, (path, data)))
+}
+
+/// A QR code for the link, drawn as SVG.
+fn linked(index: usize, attachment: &Attachment) -> Option<(Value, (String, Vec<u8>))> {
+    let code = QrCode::new(attachment.url.as_bytes()).ok()?;
+    let image = code.render::<svg::Color>().quiet_zone(false).build();
+
+    let path = format!("/assets/attachment-{index}-qr.svg");
+    let entry = json!({
+        "name": attachment.name,
+        "mode": "qr-code",
+        "url": attachment.url,
+        "path": path,
+    });
+    Some((entry, (path, image.into_bytes())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_links_become_qr_code_assets() {
+        let attachments = [
+            Attachment::link("Reference letter", "https://example.com/letter"),
+            Attachment::link("Missing link", ""),
+        ];
+
+        let (entries, assets) = prepare_attachments(&attachments);
+        assert_eq!(entries.as_array().unwrap().len(), 1);
+        assert_eq!(entries[0]["mode"], "qr-code");
+        assert_eq!(entries[0]["path"], "/assets/attachment-0-qr.svg");
+        assert_eq!(assets[0].0, "/assets/attachment-0-qr.svg");
+        assert!(String::from_utf8_lossy(&assets[0].1).contains("<svg"));
+    }
+
+    #[test]
+    fn test_appended_files_are_decoded() {
+        let mut hidden = Attachment::new("Hidden", "data:image/png;base64,aGk=");
+        hidden.visible = false;
+        let attachments = [
+            hidden,
+            Attachment::new("Certificate", "data:image/png;base64,aGk="),
+            Attachment::new("Broken", "data:image/png;base64,***"),
+        ];
+
+        let (entries, assets) = prepare_attachments(&attachments);
+        assert_eq!(entries.as_array().unwrap().len(), 1);
+        assert_eq!(entries[0]["kind"], "image");
+        assert_eq!(
+            assets,
+            vec![("/assets/attachment-1.png".to_string(), b"hi".to_vec())]
+        );
+    }
+}
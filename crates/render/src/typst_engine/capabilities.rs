@@ -0,0 +1,465 @@
+//! What each template does with `metadata.layout` and
+//! `metadata.templateOptions`.
+//!
+//! Every template renders the first layout page only. Single-column
+//! templates read its columns one after another, so nothing is lost.
+//! Two-column templates read columns 1 and 2, ignore the rest, and fill an
+//! empty column with their default sections. [`layout_warnings`] reports
+//! the layouts a template would render differently from how they're written.
+//!
+//! Templates honor the photo, header, and divider options their header and
+//! headings have room for; [`template_option_warnings`] reports the rest.
+//! [`page_option_warnings`] reports page chrome a full-bleed template can't
+//! print, and sidebar colors for a template without a sidebar.
+
+use rustume_schema::{
+    ContactQr, DividerStyle, HeaderAlign, Layout, PageConfig, PhotoPosition, ResumeData, RuleSet,
+    Severity, TemplateOptions, ValidationIssue,
+};
+
+/// Layout key of the cover letter, which renders as its own page from any
+/// column of the first layout page.
+const COVER_LETTER: &str = "coverLetter";
+
+/// How a template arranges the columns of the first layout page.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TemplateLayout {
+    /// All columns in order, one after another.
+    SingleColumn,
+    /// Columns 1 and 2 side by side; further columns are not rendered.
+    TwoColumn,
+}
+
+impl TemplateLayout {
+    /// Number of layout columns the template renders separately.
+    pub fn columns(self) -> usize {
+        match self {
+            Self::SingleColumn => 1,
+            Self::TwoColumn => 2,
+        }
+    }
+}
+
+/// Column arrangement of `template`. Unknown templates render with the
+/// single-column fallback.
+///
+/// **Keep in sync with:** the `layout:` entry of each template's
+/// `render-resume` config.
+pub fn template_layout(template: &str) -> TemplateLayout {
+    match template {
+        "azurill" | "pikachu" | "chikorita" | "ditto" | "gengar" | "glalie" | "leafish" => {
+            TemplateLayout::TwoColumn
+        }
+        _ => TemplateLayout::SingleColumn,
+    }
+}
+
+/// Warn about parts of `layout` that `template` won't render as written:
+/// sections on later pages, sections in columns past the template's two,
+/// and empty columns the template fills with its defaults.
+///
+/// An empty layout uses the template's default order and gets no warnings.
+pub fn layout_warnings(template: &str, layout: &Layout) -> Vec<ValidationIssue> {
+    let mut issues = Vec::new();
+    if layout.is_empty() {
+        return issues;
+    }
+    let pages = layout.pages();
+
+    for (page, columns) in pages.iter().enumerate().skip(1) {
+        let keys: Vec<&str> = columns.iter().flatten().map(String::as_str).collect();
+        if !keys.is_empty() {
+            issues.push(ValidationIssue::new(
+                Severity::Warning,
+                "metadata.layout",
+                "layout_page_ignored",
+                format!(
+                    "Page {} of the layout is not rendered ({}); move these sections to page 1",
+                    page + 1,
+                    keys.join(", ")
+                ),
+            ));
+        }
+    }
+
+    if template_layout(template) == TemplateLayout::SingleColumn {
+        return issues;
+    }
+    let first_page = pages.first().map(Vec::as_slice).unwrap_or_default();
+
+    for (column, keys) in first_page.iter().enumerate().skip(2) {
+        let dropped: Vec<&str> = keys
+            .iter()
+            .map(String::as_str)
+            .filter(|key| *key != COVER_LETTER)
+            .collect();
+        if !dropped.is_empty() {
+            issues.push(ValidationIssue::new(
+                Severity::Warning,
+                "metadata.layout",
+                "layout_column_ignored",
+                format!(
+                    "{template} has two columns, so column {} is not rendered ({}); \
+                     move these sections to column 1 or 2",
+                    column + 1,
+                    dropped.join(", ")
+                ),
+            ));
+        }
+    }
+
+    // A cover-letter-only layout renders no resume body, so no column is used
+    if !first_page.iter().flatten().any(|key| key != COVER_LETTER) {
+        return issues;
+    }
+    for column in 0..2 {
+        let placed = first_page
+            .get(column)
+            .is_some_and(|keys| keys.iter().any(|key| key != COVER_LETTER));
+        if !placed {
+            issues.push(ValidationIssue::new(
+                Severity::Warning,
+                "metadata.layout",
+                "layout_column_empty",
+                format!(
+                    "Column {} is empty, so {template} fills it with its default sections, \
+                     which may repeat sections placed elsewhere; add a section to it",
+                    column + 1
+                ),
+            ));
+        }
+    }
+    issues
+}
+
+/// Photo positions a full-width header has room for.
+const ALL_PHOTO_POSITIONS: &[PhotoPosition] = &[
+    PhotoPosition::Top,
+    PhotoPosition::Left,
+    PhotoPosition::Right,
+    PhotoPosition::Hidden,
+];
+
+/// Photo positions in a header that sits in a narrow sidebar.
+const SIDEBAR_PHOTO_POSITIONS: &[PhotoPosition] = &[PhotoPosition::Top, PhotoPosition::Hidden];
+
+/// The [`TemplateOptions`] a template honors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TemplateOptionSupport {
+    /// Photo positions besides `template-default`.
+    pub photo: &'static [PhotoPosition],
+    /// Whether `headerAlign` moves the name and contact details.
+    pub header_align: bool,
+    /// Whether `divider` restyles the rules under section headings.
+    pub divider: bool,
+}
+
+/// Options `template` honors. Unknown templates claim all of them, so they
+/// get no option warnings.
+///
+/// **Keep in sync with:** the `header-with-photo`, `header-align`, and
+/// `divider-stroke` calls in each template.
+pub fn template_options(template: &str) -> TemplateOptionSupport {
+    match template {
+        // Name and contacts sit on opposite sides of a grid
+        "rhyhorn" | "onyx" | "leafish" => TemplateOptionSupport {
+            photo: ALL_PHOTO_POSITIONS,
+            header_align: false,
+            divider: true,
+        },
+        "gengar" | "glalie" => TemplateOptionSupport {
+            photo: SIDEBAR_PHOTO_POSITIONS,
+            header_align: true,
+            divider: true,
+        },
+        // Section headings are filled labels with no rule
+        "pikachu" => TemplateOptionSupport {
+            photo: SIDEBAR_PHOTO_POSITIONS,
+            header_align: true,
+            divider: false,
+        },
+        _ => TemplateOptionSupport {
+            photo: ALL_PHOTO_POSITIONS,
+            header_align: true,
+            divider: true,
+        },
+    }
+}
+
+fn photo_label(position: PhotoPosition) -> &'static str {
+    match position {
+        PhotoPosition::TemplateDefault => "template-default",
+        PhotoPosition::Top => "top",
+        PhotoPosition::Left => "left",
+        PhotoPosition::Right => "right",
+        PhotoPosition::Hidden => "hidden",
+    }
+}
+
+/// Warn about options in `options` that `template` ignores. Options left at
+/// `template-default` never warn.
+pub fn template_option_warnings(template: &str, options: &TemplateOptions) -> Vec<ValidationIssue> {
+    let support = template_options(template);
+    let mut issues = Vec::new();
+
+    if options.photo != PhotoPosition::TemplateDefault && !support.photo.contains(&options.photo) {
+        let supported: Vec<&str> = support.photo.iter().copied().map(photo_label).collect();
+        issues.push(ValidationIssue::new(
+            Severity::Warning,
+            "metadata.templateOptions.photo",
+            "template_option_unsupported",
+            format!(
+                "{template} can't place the photo {}, so it uses its default; choose one of: {}",
+                photo_label(options.photo),
+                supported.join(", ")
+            ),
+        ));
+    }
+    if options.header_align != HeaderAlign::TemplateDefault && !support.header_align {
+        issues.push(ValidationIssue::new(
+            Severity::Warning,
+            "metadata.templateOptions.headerAlign",
+            "template_option_unsupported",
+            format!("{template} splits its header across the page, so header alignment is ignored"),
+        ));
+    }
+    if options.divider != DividerStyle::TemplateDefault && !support.divider {
+        issues.push(ValidationIssue::new(
+            Severity::Warning,
+            "metadata.templateOptions.divider",
+            "template_option_unsupported",
+            format!(
+                "{template} has no rules under section headings, so the divider style is ignored"
+            ),
+        ));
+    }
+    issues
+}
+
+/// Templates that print to the page edge, leaving no margin for a header or
+/// footer.
+///
+/// **Keep in sync with:** the `margin: 0pt` page setting in each template.
+const FULL_BLEED_TEMPLATES: &[&str] = &["ditto", "gengar", "glalie", "pikachu"];
+
+/// Templates with a filled sidebar that page backgrounds can recolor.
+///
+/// **Keep in sync with:** the templates that pass `sidebar-bg` to
+/// `render-resume`.
+const SIDEBAR_TEMPLATES: &[&str] = &["ditto", "gengar", "glalie", "pikachu"];
+
+/// Warn about page settings in `page` that `template` has no room for.
+pub fn page_option_warnings(template: &str, page: &PageConfig) -> Vec<ValidationIssue> {
+    let mut issues = Vec::new();
+    if page.options.contact_qr != ContactQr::Hidden && FULL_BLEED_TEMPLATES.contains(&template) {
+        issues.push(ValidationIssue::new(
+            Severity::Warning,
+            "metadata.page.options.contactQr",
+            "template_option_unsupported",
+            format!("{template} has no page margin, so the contact QR code is not printed"),
+        ));
+    }
+    let recolors_sidebar = page
+        .backgrounds
+        .iter()
+        .any(|background| !background.sidebar.is_empty() || background.hide_sidebar);
+    if recolors_sidebar && !SIDEBAR_TEMPLATES.contains(&template) {
+        issues.push(ValidationIssue::new(
+            Severity::Warning,
+            "metadata.page.backgrounds",
+            "template_option_unsupported",
+            format!(
+                "{template} has no sidebar, so the sidebar colors of page backgrounds are ignored"
+            ),
+        ));
+    }
+    issues
+}
+
+/// The built-in [`RuleSet`] plus [`layout_warnings`],
+/// [`template_option_warnings`], and [`page_option_warnings`] for the
+/// resume's template.
+pub fn template_rules() -> RuleSet {
+    RuleSet::default().with(|resume: &ResumeData| {
+        let template = &resume.metadata.template;
+        let mut issues = layout_warnings(template, &resume.metadata.layout);
+        issues.extend(template_option_warnings(
+            template,
+            &resume.metadata.template_options,
+        ));
+        issues.extend(page_option_warnings(template, &resume.metadata.page));
+        issues
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rustume_schema::{PageBackground, PageSelector};
+
+    fn layout(pages: &[&[&[&str]]]) -> Layout {
+        pages
+            .iter()
+            .map(|columns| {
+                columns
+                    .iter()
+                    .map(|keys| keys.iter().map(|key| key.to_string()).collect())
+                    .collect()
+            })
+            .collect::<Vec<Vec<Vec<String>>>>()
+            .into()
+    }
+
+    fn layout_with_second_page() -> Layout {
+        layout(&[&[&["summary"], &["skills"]], &[&["awards", "languages"]]])
+    }
+
+    fn codes(issues: &[ValidationIssue]) -> Vec<&str> {
+        issues.iter().map(|issue| issue.code.as_str()).collect()
+    }
+
+    #[test]
+    fn test_default_layout_fits_every_template() {
+        for template in crate::TEMPLATES {
+            assert!(layout_warnings(template, &Layout::default()).is_empty());
+            assert!(layout_warnings(template, &Layout::empty()).is_empty());
+        }
+    }
+
+    #[test]
+    fn test_single_column_reads_every_column() {
+        let layout = layout(&[&[&["summary"], &["skills"], &["awards"]]]);
+        assert!(layout_warnings("rhyhorn", &layout).is_empty());
+        assert!(layout_warnings("unknown", &layout).is_empty());
+
+        let layout = layout_with_second_page();
+        assert_eq!(
+            codes(&layout_warnings("rhyhorn", &layout)),
+            ["layout_page_ignored"]
+        );
+    }
+
+    #[test]
+    fn test_two_column_drops_extra_columns() {
+        let extra = layout(&[&[&["summary"], &["skills"], &["awards", "coverLetter"]]]);
+        let issues = layout_warnings("pikachu", &extra);
+        assert_eq!(codes(&issues), ["layout_column_ignored"]);
+        assert!(issues[0]
+            .message
+            .contains("column 3 is not rendered (awards)"));
+
+        // The cover letter renders from any column
+        let cover = layout(&[&[&["summary"], &["skills"], &["coverLetter"]]]);
+        assert!(layout_warnings("pikachu", &cover).is_empty());
+
+        assert_eq!(
+            codes(&layout_warnings("azurill", &layout_with_second_page())),
+            ["layout_page_ignored"]
+        );
+    }
+
+    #[test]
+    fn test_two_column_warns_about_empty_columns() {
+        let main_only = layout(&[&[&["summary", "skills"]]]);
+        let issues = layout_warnings("chikorita", &main_only);
+        assert_eq!(codes(&issues), ["layout_column_empty"]);
+        assert!(issues[0].message.starts_with("Column 2 is empty"));
+
+        let no_main = layout(&[&[&["coverLetter"], &["skills"]]]);
+        assert_eq!(
+            codes(&layout_warnings("gengar", &no_main)),
+            ["layout_column_empty"]
+        );
+
+        let cover_letter_only = layout(&[&[&["coverLetter"]]]);
+        assert!(layout_warnings("gengar", &cover_letter_only).is_empty());
+    }
+
+    #[test]
+    fn test_template_rules_include_layout_warnings() {
+        let mut resume = ResumeData::default();
+        resume.metadata.template = "leafish".to_string();
+        assert!(template_rules().check(&resume).warnings.is_empty());
+
+        resume.metadata.layout = layout(&[&[&["summary", "skills"]]]);
+        let report = template_rules().check(&resume);
+        assert!(report.is_valid());
+        assert_eq!(codes(&report.warnings), ["layout_column_empty"]);
+    }
+
+    #[test]
+    fn test_default_options_fit_every_template() {
+        for template in crate::TEMPLATES {
+            assert!(template_option_warnings(template, &TemplateOptions::default()).is_empty());
+            assert!(template_options(template)
+                .photo
+                .contains(&PhotoPosition::Hidden));
+        }
+    }
+
+    #[test]
+    fn test_option_warnings_follow_the_descriptor() {
+        let options = TemplateOptions {
+            photo: PhotoPosition::Left,
+            header_align: HeaderAlign::Center,
+            divider: DividerStyle::Dotted,
+            ..Default::default()
+        };
+        assert!(template_option_warnings("bronzor", &options).is_empty());
+        assert!(template_option_warnings("unknown", &options).is_empty());
+
+        let issues = template_option_warnings("rhyhorn", &options);
+        assert_eq!(codes(&issues), ["template_option_unsupported"]);
+        assert_eq!(issues[0].path, "metadata.templateOptions.headerAlign");
+
+        let issues = template_option_warnings("pikachu", &options);
+        let paths: Vec<&str> = issues.iter().map(|issue| issue.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            [
+                "metadata.templateOptions.photo",
+                "metadata.templateOptions.divider"
+            ]
+        );
+        assert!(issues[0].message.ends_with("choose one of: top, hidden"));
+    }
+
+    #[test]
+    fn test_contact_qr_needs_a_page_margin() {
+        let mut page = PageConfig::default();
+        page.options.contact_qr = ContactQr::Footer;
+        assert!(page_option_warnings("rhyhorn", &page).is_empty());
+        assert!(page_option_warnings("gengar", &PageConfig::default()).is_empty());
+
+        let issues = page_option_warnings("gengar", &page);
+        assert_eq!(codes(&issues), ["template_option_unsupported"]);
+        assert_eq!(issues[0].path, "metadata.page.options.contactQr");
+    }
+
+    #[test]
+    fn test_sidebar_colors_need_a_sidebar() {
+        let mut page = PageConfig::default();
+        page.backgrounds = vec![PageBackground {
+            pages: PageSelector::Rest,
+            background: "#f1f5f9".to_string(),
+            ..Default::default()
+        }];
+        assert!(page_option_warnings("rhyhorn", &page).is_empty());
+
+        page.backgrounds[0].hide_sidebar = true;
+        assert!(page_option_warnings("pikachu", &page).is_empty());
+        let issues = page_option_warnings("rhyhorn", &page);
+        assert_eq!(codes(&issues), ["template_option_unsupported"]);
+        assert_eq!(issues[0].path, "metadata.page.backgrounds");
+    }
+
+    #[test]
+    fn test_template_rules_include_option_warnings() {
+        let mut resume = ResumeData::default();
+        resume.metadata.template = "glalie".to_string();
+        resume.metadata.template_options.photo = PhotoPosition::Right;
+        let report = template_rules().check(&resume);
+        assert!(report.is_valid());
+        assert_eq!(codes(&report.warnings), ["template_option_unsupported"]);
+    }
+}
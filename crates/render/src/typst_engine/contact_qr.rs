@@ -0,0 +1,64 @@
+//! vCard QR code of the contact details.
+//!
+//! With `metadata.page.options.contactQr` set to `header` or `footer`, the
+//! first resume page prints a QR code that phones read as a contact card.
+//! [`contact_qr_asset`] encodes the card as an SVG asset; `page-chrome` in
+//! `_common.typ` draws it.
+
+use qrcode::render::svg;
+use qrcode::{EcLevel, QrCode};
+use rustume_parser::to_vcard;
+use rustume_schema::{ContactQr, ResumeData};
+
+/// Virtual path of the contact QR code asset.
+pub(crate) const CONTACT_QR_PATH: &str = "/assets/contact-qr.svg";
+
+/// The contact QR code asset (virtual path, SVG bytes), or `None` when the
+/// option is off or the resume has no contact details.
+///
+/// The card leaves out the photo and profiles: every extra byte shrinks the
+/// modules of a code that has to fit in the page margin.
+pub(crate) fn contact_qr_asset(resume: &ResumeData) -> Option<(String, Vec<u8>)> {
+    if resume.metadata.page.options.contact_qr == ContactQr::Hidden {
+        return None;
+    }
+    let basics = &resume.basics;
+    if [&basics.name, &basics.email, &basics.phone]
+        .iter()
+        .all(|value| value.trim().is_empty())
+    {
+        return None;
+    }
+
+    let mut card = ResumeData {
+        basics: basics.clone(),
+        ..Default::default()
+    };
+    card.metadata.locale = resume.metadata.locale.clone();
+    card.basics.picture.url.clear();
+    let vcard = to_vcard(&card);
+
+    let code = QrCode::with_error_correction_level(vcard.as_bytes(), EcLevel::L).ok()?;
+    let image = code.render::<svg::Color>().quiet_zone(false).build();
+    Some((CONTACT_QR_PATH.to_string(), image.into_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_contact_qr_follows_the_option() {
+        let mut resume = ResumeData::with_basics("Ada Lovelace", "ada@example.com");
+        assert_eq!(contact_qr_asset(&resume), None);
+
+        resume.metadata.page.options.contact_qr = ContactQr::Footer;
+        resume.basics.picture.url = "https://example.com/ada.png".to_string();
+        let (path, svg) = contact_qr_asset(&resume).unwrap();
+        assert_eq!(path, CONTACT_QR_PATH);
+        assert!(String::from_utf8(svg).unwrap().contains("<svg"));
+
+        resume.basics = Default::default();
+        assert_eq!(contact_qr_asset(&resume), None);
+    }
+}
@@ -0,0 +1,148 @@
+//! Contact sheets: the first page of one resume in several templates, laid
+//! out side by side so a template can be picked by how it shows real content.
+
+use crate::traits::RenderError;
+use crate::typst_engine::engine::{
+    compile_document, encode_png, export_pdf, get_template_theme, TypstRenderer,
+    PREVIEW_PIXEL_PER_PT, TEMPLATES,
+};
+use rustume_schema::ResumeData;
+use tracing::{debug, instrument};
+
+/// Most pages per row; fewer templates make a single shorter row.
+const MAX_COLUMNS: usize = 4;
+
+/// Width of each page on the sheet, in points.
+const CELL_WIDTH_PT: u32 = 180;
+
+/// Resolution pages are rasterized at before being scaled into a cell: about
+/// five pixels per point of cell width, so the PDF stays readable when zoomed.
+const CELL_PIXEL_PER_PT: f64 = 1.5;
+
+/// Output format of [`TypstRenderer::render_contact_sheet`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SheetFormat {
+    #[default]
+    Pdf,
+    Png,
+}
+
+impl TypstRenderer {
+    /// Render the first page of `resume` in each of `templates`, with that
+    /// template's theme colors, as a labeled grid on a single page.
+    ///
+    /// Names outside [`TEMPLATES`] are rejected rather than rendered with the
+    /// fallback template, which would put duplicate pages on the sheet.
+    #[instrument(skip(self, resume), fields(count = templates.len()))]
+    pub fn render_contact_sheet(
+        &self,
+        resume: &ResumeData,
+        templates: &[&str],
+        format: SheetFormat,
+    ) -> Result<Vec<u8>, RenderError> {
+        if templates.is_empty() {
+            return Err(RenderError::InvalidConfig(
+                "A contact sheet needs at least one template".to_string(),
+            ));
+        }
+        if let Some(unknown) = templates.iter().find(|name| !TEMPLATES.contains(*name)) {
+            return Err(RenderError::TemplateNotFound(unknown.to_string()));
+        }
+
+        let mut assets = Vec::with_capacity(templates.len());
+        for &template in templates {
+            debug!("Rendering contact sheet page for {}", template);
+            let mut themed = resume.clone();
+            themed.metadata.template = template.to_string();
+            let theme = get_template_theme(template);
+            themed.metadata.theme.primary = theme.primary;
+            themed.metadata.theme.text = theme.text;
+            themed.metadata.theme.background = theme.background;
+
+            let document = self.compile(&themed, &mut |_| {})?;
+            let page = document.pages().first().ok_or_else(|| {
+                RenderError::RenderFailed(format!("{template} produced no pages"))
+            })?;
+            assets.push((cell_path(template), encode_png(page, CELL_PIXEL_PER_PT)?));
+        }
+
+        let document = compile_document(
+            sheet_source(templates),
+            None,
+            assets,
+            Vec::new(),
+            self.sandbox_limits(),
+        )?;
+        match format {
+            SheetFormat::Pdf => export_pdf(&document),
+            SheetFormat::Png => {
+                let page = document.pages().first().ok_or_else(|| {
+                    RenderError::RenderFailed("Contact sheet produced no pages".to_string())
+                })?;
+                encode_png(page, PREVIEW_PIXEL_PER_PT)
+            }
+        }
+    }
+}
+
+/// Virtual path of the rasterized page for `template`.
+fn cell_path(template: &str) -> String {
+    format!("/sheet/{template}.png")
+}
+
+/// Typst source placing each template's page above its name, on a page sized
+/// to fit the grid.
+fn sheet_source(templates: &[&str]) -> String {
+    let columns = templates.len().min(MAX_COLUMNS);
+    let cells: String = templates
+        .iter()
+        .map(|template| {
+            format!(
+                "  stack(spacing: 6pt, block(stroke: 0.5pt + luma(200), image({:?}, width: 100%)), \
+                 align(center, text(weight: \"bold\", {:?}))),\n",
+                cell_path(template),
+                template
+            )
+        })
+        .collect();
+
+    format!(
+        "#set page(width: auto, height: auto, margin: 24pt, fill: white)\n\
+         #set text(size: 10pt)\n\
+         #grid(\n  columns: ({CELL_WIDTH_PT}pt,) * {columns},\n  column-gutter: 16pt,\n  \
+         row-gutter: 20pt,\n{cells})\n"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sheet_source_lays_out_rows() {
+        let source = sheet_source(&TEMPLATES[..6]);
+        assert!(source.contains("columns: (180pt,) * 4"));
+        assert_eq!(source.matches("image(").count(), 6);
+        assert!(source.contains(r#"image("/sheet/azurill.png", width: 100%)"#));
+        assert!(source.contains(r#"text(weight: "bold", "azurill")"#));
+
+        let source = sheet_source(&["onyx", "ditto"]);
+        assert!(source.contains("columns: (180pt,) * 2"));
+    }
+
+    #[test]
+    fn test_contact_sheet_rejects_unknown_templates() {
+        let renderer = TypstRenderer::new();
+        let resume = ResumeData::default();
+
+        let err = renderer
+            .render_contact_sheet(&resume, &["rhyhorn", "missingno"], SheetFormat::Pdf)
+            .unwrap_err();
+        assert!(matches!(err, RenderError::TemplateNotFound(name) if name == "missingno"));
+
+        let err = renderer
+            .render_contact_sheet(&resume, &[], SheetFormat::Png)
+            .unwrap_err();
+        assert!(matches!(err, RenderError::InvalidConfig(_)));
+    }
+}
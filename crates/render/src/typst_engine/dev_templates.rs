@@ -0,0 +1,164 @@
+//! Template hot-reload for template authors (`dev` feature).
+//!
+//! [`set_dev_templates_dir`] points every render at a directory of `.typ`
+//! files, read from disk at render time like `RUSTUME_TEMPLATES_DIR` but set
+//! by a `--dev-templates <dir>` flag. A [`TemplateWatcher`] polls that
+//! directory so a CLI can re-render, or a server drop its cached
+//! thumbnails, as soon as a template is saved. Polling needs no platform
+//! file events, so it also sees edits on bind mounts in containers.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+use std::time::{Duration, SystemTime};
+
+use crate::traits::RenderError;
+
+/// Directory set by [`set_dev_templates_dir`].
+static DEV_TEMPLATES_DIR: RwLock<Option<PathBuf>> = RwLock::new(None);
+
+/// How often a [`TemplateWatcher`] looks at the directory by default.
+pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Render templates from `dir` instead of the embedded copies, or stop with
+/// `None`. Names missing from `dir` still use the embedded template, and the
+/// directory takes precedence over `RUSTUME_TEMPLATES_DIR`.
+pub fn set_dev_templates_dir(dir: Option<PathBuf>) -> Result<(), RenderError> {
+    if let Some(dir) = &dir {
+        if !dir.is_dir() {
+            return Err(RenderError::RenderFailed(format!(
+                "Template directory '{}' does not exist",
+                dir.display()
+            )));
+        }
+    }
+    *DEV_TEMPLATES_DIR
+        .write()
+        .unwrap_or_else(std::sync::PoisonError::into_inner) = dir;
+    Ok(())
+}
+
+/// The directory set by [`set_dev_templates_dir`].
+pub fn dev_templates_dir() -> Option<PathBuf> {
+    DEV_TEMPLATES_DIR
+        .read()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .clone()
+}
+
+/// Modification time and size of each `.typ` file, by template name.
+type Snapshot = BTreeMap<String, (Option<SystemTime>, u64)>;
+
+/// Watches a template directory for added, edited, and removed `.typ` files.
+#[derive(Debug)]
+pub struct TemplateWatcher {
+    dir: PathBuf,
+    interval: Duration,
+    snapshot: Snapshot,
+}
+
+impl TemplateWatcher {
+    /// Watch `dir`, taking its current templates as unchanged.
+    pub fn new(dir: impl Into<PathBuf>) -> io::Result<Self> {
+        let dir = dir.into();
+        let snapshot = snapshot(&dir)?;
+        Ok(Self {
+            dir,
+            interval: DEFAULT_POLL_INTERVAL,
+            snapshot,
+        })
+    }
+
+    /// Look at the directory every `interval` in [`Self::wait`].
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// The watched directory.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Names of the templates (`onyx`, `_common`) added, edited, or removed
+    /// since the last call, sorted; empty when nothing changed.
+    pub fn poll(&mut self) -> io::Result<Vec<String>> {
+        let current = snapshot(&self.dir)?;
+        let names: BTreeSet<&String> = self.snapshot.keys().chain(current.keys()).collect();
+        let changed = names
+            .into_iter()
+            .filter(|name| self.snapshot.get(*name) != current.get(*name))
+            .cloned()
+            .collect();
+        self.snapshot = current;
+        Ok(changed)
+    }
+
+    /// Block until a template changes and return the changed names.
+    ///
+    /// An editor that saves by writing a temporary file and renaming it can
+    /// leave the directory briefly without the template; such a gap shows
+    /// up as one change once the file is back.
+    pub fn wait(&mut self) -> io::Result<Vec<String>> {
+        loop {
+            std::thread::sleep(self.interval);
+            let changed = self.poll()?;
+            if !changed.is_empty() {
+                return Ok(changed);
+            }
+        }
+    }
+}
+
+/// Read the `.typ` files directly inside `dir`.
+fn snapshot(dir: &Path) -> io::Result<Snapshot> {
+    let mut files = Snapshot::new();
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.extension().and_then(|ext| ext.to_str()) != Some("typ") {
+            continue;
+        }
+        let Some(name) = path.file_stem().and_then(|stem| stem.to_str()) else {
+            continue;
+        };
+        // Follows symlinks, as template resolution does
+        let Ok(meta) = std::fs::metadata(&path) else {
+            continue;
+        };
+        if meta.is_file() {
+            files.insert(name.to_string(), (meta.modified().ok(), meta.len()));
+        }
+    }
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_watcher_reports_added_edited_and_removed_templates() {
+        let temp = tempfile::tempdir().unwrap();
+        std::fs::write(temp.path().join("onyx.typ"), "// onyx").unwrap();
+        std::fs::write(temp.path().join("notes.txt"), "not a template").unwrap();
+        let mut watcher = TemplateWatcher::new(temp.path()).unwrap();
+        assert!(watcher.poll().unwrap().is_empty());
+
+        std::fs::write(temp.path().join("_common.typ"), "// common").unwrap();
+        std::fs::write(temp.path().join("onyx.typ"), "// onyx, edited").unwrap();
+        std::fs::write(temp.path().join("notes.txt"), "still not a template").unwrap();
+        assert_eq!(watcher.poll().unwrap(), ["_common", "onyx"]);
+
+        std::fs::remove_file(temp.path().join("onyx.typ")).unwrap();
+        assert_eq!(watcher.poll().unwrap(), ["onyx"]);
+        assert!(watcher.poll().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_dev_templates_dir_must_exist() {
+        let temp = tempfile::tempdir().unwrap();
+        assert!(set_dev_templates_dir(Some(temp.path().join("missing"))).is_err());
+        assert_eq!(dev_templates_dir(), None);
+    }
+}
@@ -1,10 +1,39 @@
 //! Typst rendering engine.
 
-use crate::traits::{RenderError, Renderer};
+use crate::branding::Branding;
+use crate::html::{render_html_page, HtmlPageOptions};
+use crate::limits::RenderLimits;
+use crate::redact::Redaction;
+use crate::stats::{RenderKind, RenderObserver, RenderStats};
+use crate::template_package::TemplatePackage;
+use crate::template_policy::TemplatePolicy;
+use crate::traits::{RenderError, RenderProgress, Renderer};
+use crate::typst_engine::attachments::prepare_attachments;
+use crate::typst_engine::capabilities::{
+    layout_warnings, page_option_warnings, template_option_warnings,
+};
+use crate::typst_engine::contact_qr::contact_qr_asset;
+use crate::typst_engine::fonts::{detect_scripts, fallback_families};
+use crate::typst_engine::pdf_info::PdfInfo;
+use crate::typst_engine::sandbox::{self, SandboxLimits};
 use crate::typst_engine::world::RustumeWorld;
-use rustume_schema::{PageFormat, ResumeData};
-use rustume_utils::{html_to_typst, sanitize_html};
-use tracing::{debug, instrument, warn};
+use rustume_schema::{EmojiPolicy, PageFormat, ResumeData};
+use rustume_utils::{
+    contains_emoji, format_duration_in, hex_to_rgba, html_to_typst, is_rtl_char, is_rtl_language,
+    is_rtl_text, process_picture, sanitize_html, Locale, PartialDate, PictureBorder, PictureStyle,
+};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::time::Instant;
+use tracing::{debug, info, instrument, warn};
+
+/// Compilations a memoized Typst result may go unused before it is evicted.
+/// Long enough to span a preview session hopping between a few templates;
+/// without eviction the cache grows for the life of the process.
+const MEMO_MAX_AGE: usize = 30;
+
+/// Preview resolution: 2x, sharp on high-density screens.
+pub(super) const PREVIEW_PIXEL_PER_PT: f64 = 2.0;
 
 /// Available templates.
 pub const TEMPLATES: &[&str] = &[
@@ -22,9 +51,19 @@ pub const TEMPLATES: &[&str] = &[
     "onyx",      // Single-column linear, red accent (#dc2626)
 ];
 
-/// Generated Typst source plus an optional decoded picture asset
+/// Generated Typst source plus the decoded picture and attachment assets
 /// (virtual path, bytes) to expose to the Typst world.
-type PreparedSource = (String, Option<(String, Vec<u8>)>);
+type PreparedSource = (String, Vec<(String, Vec<u8>)>);
+
+/// A [`PreparedSource`] with the template it uses and the fallback font
+/// families it names, for [`RenderStats`].
+struct Prepared {
+    source: String,
+    assets: Vec<(String, Vec<u8>)>,
+    template: String,
+    font_fallbacks: Vec<&'static str>,
+    info: Option<PdfInfo>,
+}
 
 /// Decode a `data:image/<subtype>;base64,` picture URL into bytes and rewrite
 /// the picture URL to a virtual asset path so Typst's `image()` can load it.
@@ -52,118 +91,408 @@ fn extract_picture_asset(resume: &mut ResumeData) -> Option<(String, Vec<u8>)> {
     Some((path, data))
 }
 
-/// Convert an HTML string to Typst markup via sanitize → convert.
-fn convert_field(html: &str) -> String {
+/// Bake the picture's aspect-ratio crop, grayscale, rounded corners, and
+/// border into a PNG asset, so templates only place and rotate it. The
+/// picture's aspect ratio becomes the processed image's exact ratio.
+///
+/// Returns `None` for hidden pictures, and for images that fail to decode,
+/// which keep the template's own clipping and stroke.
+fn bake_picture_effects(resume: &mut ResumeData, data: &[u8]) -> Option<(String, Vec<u8>)> {
+    let picture = &mut resume.basics.picture;
+    let effects = &picture.effects;
+    if effects.hidden {
+        return None;
+    }
+
+    // Templates size the picture by its width, so lengths are relative to it
+    let size = picture.size.max(1) as f32;
+    let border = if effects.border && effects.border_width > 0 {
+        let color = if effects.border_color.is_empty() {
+            &resume.metadata.theme.primary
+        } else {
+            &effects.border_color
+        };
+        Some(PictureBorder {
+            width: effects.border_width as f32 / size,
+            color: hex_to_rgba(color)?,
+        })
+    } else {
+        None
+    };
+    let style = PictureStyle {
+        aspect_ratio: Some(picture.aspect_ratio.clamp(0.25, 4.0)),
+        grayscale: effects.grayscale,
+        corner_radius: picture.border_radius as f32 / size,
+        border,
+    };
+
+    let processed = process_picture(data, &style)
+        .inspect_err(|e| warn!("Picture effects not applied, image could not be processed: {e}"))
+        .ok()?;
+    picture.aspect_ratio = processed.width as f32 / processed.height as f32;
+    picture.url = "/assets/picture.png".to_string();
+    Some((picture.url.clone(), processed.png))
+}
+
+/// Convert an HTML string to Typst markup via emoji policy → sanitize → convert.
+fn convert_field(html: &str, emoji: EmojiPolicy) -> String {
     if html.is_empty() {
         return String::new();
     }
-    html_to_typst(&sanitize_html(html))
+    html_to_typst(&sanitize_html(&emoji.apply(html)))
 }
 
 /// Clone resume data and preprocess all rich-text fields (summary, description)
-/// from HTML to Typst markup so templates can `eval()` them.
+/// from HTML to Typst markup so templates can `eval()` them. The headline and
+/// rich text also go through the resume's emoji policy, and the name is put
+/// in display order.
 fn preprocess_rich_text(resume: &ResumeData) -> ResumeData {
     let mut r = resume.clone();
+    let emoji = r.metadata.emoji;
+    r.basics.name = resume.display_name();
+    r.basics.location = resume.basics.display_location();
+
+    // Headline
+    r.basics.headline = emoji.apply(&r.basics.headline);
+
+    // Custom field icons: templates can only draw glyphs, not icon-library
+    // names, and glyphs are kept only when the policy preserves emoji
+    for field in &mut r.basics.custom_fields {
+        if emoji != EmojiPolicy::Preserve || !contains_emoji(&field.icon) {
+            field.icon.clear();
+        }
+    }
 
     // Summary section content
-    r.sections.summary.content = convert_field(&r.sections.summary.content);
+    r.sections.summary.content = convert_field(&r.sections.summary.content, emoji);
 
     // Cover letter body
-    r.sections.cover_letter.content = convert_field(&r.sections.cover_letter.content);
+    r.sections.cover_letter.content = convert_field(&r.sections.cover_letter.content, emoji);
 
-    // Experience: summary
+    // Experience: summary, with highlights as a list
     for item in &mut r.sections.experience.items {
-        item.summary = convert_field(&item.summary);
+        item.summary = convert_field(&item.summary_html(), emoji);
+        item.highlights.clear();
     }
 
     // Education: summary
     for item in &mut r.sections.education.items {
-        item.summary = convert_field(&item.summary);
+        item.summary = convert_field(&item.summary, emoji);
     }
 
     // Skills: description
     for item in &mut r.sections.skills.items {
-        item.description = convert_field(&item.description);
+        item.description = convert_field(&item.description, emoji);
     }
 
-    // Projects: summary, description
+    // Projects: summary with highlights, description
     for item in &mut r.sections.projects.items {
-        item.summary = convert_field(&item.summary);
-        item.description = convert_field(&item.description);
+        item.summary = convert_field(&item.summary_html(), emoji);
+        item.highlights.clear();
+        item.description = convert_field(&item.description, emoji);
     }
 
     // Awards: summary
     for item in &mut r.sections.awards.items {
-        item.summary = convert_field(&item.summary);
+        item.summary = convert_field(&item.summary, emoji);
     }
 
     // Certifications: summary
     for item in &mut r.sections.certifications.items {
-        item.summary = convert_field(&item.summary);
+        item.summary = convert_field(&item.summary, emoji);
     }
 
     // Publications: summary
     for item in &mut r.sections.publications.items {
-        item.summary = convert_field(&item.summary);
+        item.summary = convert_field(&item.summary, emoji);
     }
 
     // Languages: description
     for item in &mut r.sections.languages.items {
-        item.description = convert_field(&item.description);
+        item.description = convert_field(&item.description, emoji);
     }
 
     // Volunteer: summary
     for item in &mut r.sections.volunteer.items {
-        item.summary = convert_field(&item.summary);
+        item.summary = convert_field(&item.summary, emoji);
     }
 
     // References: summary, description
     for item in &mut r.sections.references.items {
-        item.summary = convert_field(&item.summary);
-        item.description = convert_field(&item.description);
+        item.summary = convert_field(&item.summary, emoji);
+        item.description = convert_field(&item.description, emoji);
     }
 
     // Custom sections: summary, description
     for section in r.sections.custom.values_mut() {
         for item in &mut section.items {
-            item.summary = convert_field(&item.summary);
-            item.description = convert_field(&item.description);
+            item.summary = convert_field(&item.summary, emoji);
+            item.description = convert_field(&item.description, emoji);
         }
     }
 
     r
 }
 
+/// Whether the page should read right to left: the resume's locale is a
+/// right-to-left language, or its name, headline, and job titles are mostly
+/// Arabic, Hebrew, or similar script.
+fn is_rtl(resume: &ResumeData) -> bool {
+    if is_rtl_language(&resume.metadata.locale) {
+        return true;
+    }
+    let mut sample = vec![resume.basics.name.as_str(), resume.basics.headline.as_str()];
+    for item in &resume.sections.experience.items {
+        sample.extend([item.company.as_str(), item.position.as_str()]);
+    }
+    is_rtl_text(&sample.join(" "))
+}
+
+/// Wrap `text` in a left-to-right isolate (U+2066 ... U+2069) unless it
+/// already contains right-to-left letters. On a right-to-left page, a bare
+/// `2020 - 2024` or `+1 (555) 010-9999` would otherwise be reordered.
+fn isolate_ltr(text: &str) -> String {
+    if text.is_empty() || text.starts_with('\u{2066}') || text.chars().any(is_rtl_char) {
+        return text.to_string();
+    }
+    format!("\u{2066}{text}\u{2069}")
+}
+
+/// Isolate the phone number and item dates, the fields most often written in
+/// digits and Latin script inside an otherwise right-to-left resume.
+fn isolate_ltr_fields(resume: &mut ResumeData) {
+    resume.basics.phone = isolate_ltr(&resume.basics.phone);
+
+    let s = &mut resume.sections;
+    let dates = s
+        .experience
+        .items
+        .iter_mut()
+        .map(|item| &mut item.date)
+        .chain(s.education.items.iter_mut().map(|item| &mut item.date))
+        .chain(s.projects.items.iter_mut().map(|item| &mut item.date))
+        .chain(s.awards.items.iter_mut().map(|item| &mut item.date))
+        .chain(s.certifications.items.iter_mut().map(|item| &mut item.date))
+        .chain(s.publications.items.iter_mut().map(|item| &mut item.date))
+        .chain(s.volunteer.items.iter_mut().map(|item| &mut item.date))
+        .chain(
+            s.custom
+                .values_mut()
+                .flat_map(|section| section.items.iter_mut().map(|item| &mut item.date)),
+        );
+    for date in dates {
+        *date = isolate_ltr(date);
+    }
+}
+
+/// Append each experience item's duration to its display date
+/// ("2020 - Present · 3 yrs 2 mos"), so every template shows it without
+/// template changes. Items whose dates cannot be read are left alone. On a
+/// right-to-left page the range is isolated first, since the duration may be
+/// in the page's script while the range is not.
+fn append_durations(resume: &mut ResumeData, rtl: bool) {
+    let today = PartialDate::today();
+    let locale = Locale::for_tag(&resume.metadata.locale);
+    for item in &mut resume.sections.experience.items {
+        item.sync_dates_in(locale);
+        let Some(months) = item.date_range().map(|range| range.months(&today)) else {
+            continue;
+        };
+        if months > 0 {
+            let range = if rtl {
+                isolate_ltr(&item.date)
+            } else {
+                item.date.clone()
+            };
+            item.date = format!("{} · {}", range, format_duration_in(months, locale));
+        }
+    }
+}
+
+/// Add each language's CEFR code to its description ("Fluent · C1"), unless
+/// the description already names it.
+fn append_cefr(resume: &mut ResumeData) {
+    for item in &mut resume.sections.languages.items {
+        let Some(cefr) = item.cefr else {
+            continue;
+        };
+        let code = cefr.as_str();
+        if item.description.trim().is_empty() {
+            item.description = code.to_string();
+        } else if !item.description.contains(code) {
+            item.description = format!("{} · {code}", item.description);
+        }
+    }
+}
+
 /// Typst-based PDF renderer.
+#[derive(Clone)]
 pub struct TypstRenderer {
     /// Default template to use.
     default_template: String,
+    /// Content left out of every render.
+    redaction: Redaction,
+    /// Size ceilings checked before compiling.
+    limits: RenderLimits,
+    /// Time, source, and memory limits while compiling.
+    sandbox: SandboxLimits,
+    /// Templates this renderer may use.
+    templates: TemplatePolicy,
+    /// Installed template packages, rendered by name like [`TEMPLATES`].
+    packages: Arc<[TemplatePackage]>,
+    /// Deployment colors, footer, and logo added to every render.
+    branding: Branding,
+    /// Called with the stats of every PDF and preview render.
+    observer: Option<RenderObserver>,
 }
 
 impl TypstRenderer {
     /// Create a new Typst renderer.
     pub fn new() -> Self {
-        Self {
-            default_template: "rhyhorn".to_string(),
-        }
+        Self::with_template("rhyhorn")
     }
 
     /// Create a renderer with a specific default template.
     pub fn with_template(template: impl Into<String>) -> Self {
         Self {
             default_template: template.into(),
+            redaction: Redaction::default(),
+            limits: RenderLimits::default(),
+            sandbox: SandboxLimits::default(),
+            templates: TemplatePolicy::default(),
+            packages: Vec::new().into(),
+            branding: Branding::default(),
+            observer: None,
         }
     }
 
+    /// Leave the given sections, contact fields, and items out of rendered
+    /// output. The resume passed to each render call is not modified.
+    pub fn with_redaction(mut self, redaction: Redaction) -> Self {
+        self.redaction = redaction;
+        self
+    }
+
+    /// Reject resumes over `limits` with [`RenderError::TooLarge`] instead of
+    /// compiling them.
+    pub fn with_limits(mut self, limits: RenderLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Stop compilations that go over `limits` with
+    /// [`RenderError::LimitExceeded`].
+    pub fn with_sandbox_limits(mut self, limits: SandboxLimits) -> Self {
+        self.sandbox = limits;
+        self
+    }
+
+    /// The limits every compilation runs within.
+    pub fn sandbox_limits(&self) -> SandboxLimits {
+        self.sandbox
+    }
+
+    /// Render only the templates `policy` offers. When it leaves out the
+    /// default template, the first offered one becomes the default.
+    pub fn with_template_policy(mut self, policy: TemplatePolicy) -> Self {
+        if !policy.allows(&self.default_template) {
+            if let Some(first) = policy.templates().first() {
+                self.default_template = first.to_string();
+            }
+        }
+        self.templates = policy;
+        self
+    }
+
+    /// The templates this renderer may use.
+    pub fn template_policy(&self) -> &TemplatePolicy {
+        &self.templates
+    }
+
+    /// Also render the templates in `packages`, selected by name like the
+    /// built-in [`TEMPLATES`]. The template policy doesn't cover them.
+    pub fn with_template_packages(mut self, packages: Vec<TemplatePackage>) -> Self {
+        self.packages = packages.into();
+        self
+    }
+
+    /// The template packages this renderer can use.
+    pub fn template_packages(&self) -> &[TemplatePackage] {
+        &self.packages
+    }
+
+    /// Theme colors of `template`, from its package manifest when it is an
+    /// installed package.
+    pub fn template_theme(&self, template: &str) -> TemplateTheme {
+        match self.package(template) {
+            Some(package) => package.manifest.theme.clone(),
+            None => get_template_theme(template),
+        }
+    }
+
+    fn package(&self, template: &str) -> Option<&TemplatePackage> {
+        self.packages
+            .iter()
+            .find(|package| package.name() == template)
+    }
+
+    /// Sources (virtual path, text) to add to the Typst world for
+    /// `template`: the package's source when it is an installed package.
+    fn package_sources(&self, template: &str) -> Vec<(String, String)> {
+        self.package(template)
+            .map(|package| (format!("templates/{template}.typ"), package.source.clone()))
+            .into_iter()
+            .collect()
+    }
+
+    /// Add `branding` to every render: its colors where a resume keeps the
+    /// default theme, and its footer text and logo on every page.
+    pub fn with_branding(mut self, branding: Branding) -> Self {
+        self.branding = branding;
+        self
+    }
+
+    /// The branding added to every render.
+    pub fn branding(&self) -> &Branding {
+        &self.branding
+    }
+
+    /// Call `observer` with the [`RenderStats`] of every PDF and preview
+    /// render, failed ones included.
+    pub fn with_observer(mut self, observer: RenderObserver) -> Self {
+        self.observer = Some(observer);
+        self
+    }
+
     /// Generate the Typst source code for a resume.
     #[instrument(skip(self, resume), fields(template = %resume.metadata.template))]
     pub fn generate_source(&self, resume: &ResumeData) -> Result<String, RenderError> {
         Ok(self.prepare_source(resume)?.0)
     }
 
-    /// Generate the Typst source plus any binary picture asset extracted from
-    /// an inline data URL (the only URL form the web app produces on upload).
-    fn prepare_source(&self, resume: &ResumeData) -> Result<PreparedSource, RenderError> {
+    /// Generate the Typst source plus any binary assets extracted from inline
+    /// data URLs (the only URL form the web app produces on upload): the
+    /// picture and attachment files, and QR codes for attachment links.
+    pub(super) fn prepare_source(
+        &self,
+        resume: &ResumeData,
+    ) -> Result<PreparedSource, RenderError> {
+        self.prepare(resume)
+            .map(|prepared| (prepared.source, prepared.assets))
+    }
+
+    /// The template `resume` renders with: its own when that is a built-in
+    /// template or an installed package, the default otherwise.
+    pub(super) fn template_name<'a>(&'a self, resume: &'a ResumeData) -> &'a str {
+        let template = resume.metadata.template.as_str();
+        if TEMPLATES.contains(&template) || self.package(template).is_some() {
+            template
+        } else {
+            &self.default_template
+        }
+    }
+
+    fn prepare(&self, resume: &ResumeData) -> Result<Prepared, RenderError> {
         debug!("Generating Typst source");
 
         // Validate metadata bounds before embedding in Typst source
@@ -181,28 +510,128 @@ impl TypstRenderer {
                 font_size
             )));
         }
+        let typography = &resume.metadata.typography;
+        if !(-0.05..=0.2).contains(&typography.letter_spacing) {
+            return Err(RenderError::InvalidConfig(format!(
+                "Letter spacing {}em is outside the allowed range of -0.05–0.2em",
+                typography.letter_spacing
+            )));
+        }
+        if let Some(spacing) = typography.paragraph_spacing {
+            if !(0.0..=3.0).contains(&spacing) {
+                return Err(RenderError::InvalidConfig(format!(
+                    "Paragraph spacing {}em is outside the allowed range of 0–3em",
+                    spacing
+                )));
+            }
+        }
+        if !(0.5..=2.0).contains(&typography.heading_scale) {
+            return Err(RenderError::InvalidConfig(format!(
+                "Heading scale {} is outside the allowed range of 0.5–2",
+                typography.heading_scale
+            )));
+        }
 
-        let template = &resume.metadata.template;
-        let template_name = if TEMPLATES.contains(&template.as_str()) {
-            template.as_str()
-        } else {
+        let template_name = self.template_name(resume);
+        if template_name != resume.metadata.template {
             warn!(
-                requested = %template,
+                requested = %resume.metadata.template,
                 fallback = %self.default_template,
                 "Unknown template, using fallback"
             );
-            &self.default_template
-        };
+        }
+        self.templates.check(template_name)?;
+        let option_warnings =
+            template_option_warnings(template_name, &resume.metadata.template_options);
+        for issue in layout_warnings(template_name, &resume.metadata.layout)
+            .into_iter()
+            .chain(option_warnings)
+            .chain(page_option_warnings(template_name, &resume.metadata.page))
+        {
+            warn!(code = %issue.code, "{}", issue.message);
+        }
 
+        let unknown = self.redaction.unknown_sections(resume);
+        if !unknown.is_empty() {
+            return Err(RenderError::InvalidConfig(format!(
+                "Unknown sections to exclude: {}",
+                unknown.join(", ")
+            )));
+        }
+
+        let redacted = (!self.redaction.is_empty()).then(|| {
+            let mut redacted = resume.clone();
+            self.redaction.apply(&mut redacted);
+            redacted
+        });
+        let resume = redacted.as_ref().unwrap_or(resume);
+        // From the rich text as written, before it turns into Typst markup
+        let info = PdfInfo::of(resume);
         // Preprocess HTML fields → Typst markup before serialization
         let mut resume = preprocess_rich_text(resume);
+        self.branding.apply_theme(&mut resume.metadata.theme);
+        // Before the bidi isolates below, which don't belong in the vCard
+        let contact_qr = contact_qr_asset(&resume);
+        let rtl = is_rtl(&resume);
+        if resume.metadata.show_durations {
+            append_durations(&mut resume, rtl);
+        }
+        if resume.metadata.show_cefr {
+            append_cefr(&mut resume);
+        }
+        if rtl {
+            isolate_ltr_fields(&mut resume);
+        }
 
-        // Rewrite a data-URL picture to a virtual asset path served by the world.
+        // Rewrite a data-URL picture to a virtual asset path served by the world,
+        // with its effects baked in when the image can be processed.
         let picture_asset = extract_picture_asset(&mut resume);
-
-        // Serialize resume data to JSON for Typst
-        let resume_json = serde_json::to_string(&resume)
+        let baked_picture = picture_asset
+            .as_ref()
+            .and_then(|(_, bytes)| bake_picture_effects(&mut resume, bytes));
+        let picture_baked = baked_picture.is_some();
+        let picture_asset = baked_picture.or(picture_asset);
+        let (attachments, mut assets) = prepare_attachments(&resume.attachments);
+        assets.extend(picture_asset);
+        let contact_qr_path = contact_qr.as_ref().map(|(path, _)| path.clone());
+        assets.extend(contact_qr);
+
+        // Serialize resume data to JSON for Typst, adding the labels templates
+        // write themselves (page numbers, level names) in the resume's locale
+        // and the page direction for the few places that draw by side
+        let locale = Locale::for_tag(&resume.metadata.locale);
+        let mut data = serde_json::to_value(&resume)
             .map_err(|e| RenderError::RenderFailed(format!("JSON serialization failed: {}", e)))?;
+        data["strings"] = serde_json::json!({
+            "page": locale.page_label,
+            "levels": locale.level_labels,
+            "contact": locale.contact,
+            "attachments": locale.attachments,
+        });
+        data["dir"] = serde_json::json!(if rtl { "rtl" } else { "ltr" });
+        // Templates only need asset paths; the data URLs would bloat the source
+        data["attachments"] = attachments;
+        if let Some(fields) = data["basics"]["customFields"].as_array_mut() {
+            for (entry, field) in fields.iter_mut().zip(&resume.basics.custom_fields) {
+                entry["href"] = serde_json::json!(field.href());
+            }
+        }
+        data["basics"]["picture"]["baked"] = serde_json::json!(picture_baked);
+        data["contactQr"] = serde_json::json!(contact_qr_path);
+        let logo_path = self.branding.logo.as_ref().map(|logo| logo.path());
+        if !self.branding.is_empty() {
+            data["brand"] = serde_json::json!({
+                "footer": self.branding.footer,
+                "logo": logo_path,
+            });
+        }
+        let resume_json = data.to_string();
+        let asset_bytes = assets.iter().map(|(_, bytes)| bytes.len()).sum();
+        self.limits.check(resume_json.len(), asset_bytes)?;
+        // The deployment's logo doesn't count toward the resume's asset limit
+        if let (Some(path), Some(logo)) = (logo_path, &self.branding.logo) {
+            assets.push((path, logo.bytes().to_vec()));
+        }
 
         // Escape the JSON for embedding in Typst string
         // We need to escape backslashes first, then quotes
@@ -216,28 +645,51 @@ impl TypstRenderer {
             .family
             .replace('\\', "\\\\")
             .replace('"', "\\\"");
+        // Name a Noto family for each non-Latin script in the resume, so
+        // CJK, Arabic, and Hebrew text doesn't come out as tofu
+        let fallbacks = fallback_families(&detect_scripts(&resume_json), &resume.metadata.locale);
+        let font_list = if fallbacks.is_empty() {
+            format!(r#""{escaped_font_family}""#)
+        } else {
+            let families: Vec<String> = std::iter::once(escaped_font_family)
+                .chain(fallbacks.iter().map(|family| family.to_string()))
+                .map(|family| format!(r#""{family}""#))
+                .collect();
+            format!("({})", families.join(", "))
+        };
 
         // Generate the main Typst source that imports the template and passes data
         let source = format!(
             r#"#import "templates/{template}.typ": template
+#import "templates/_common.typ": page-background, page-chrome, render-appended-attachments
+
+// Parse the resume data
+#let data = json(bytes("{resume_json}"))
 
 // Page configuration
 #set page(
   paper: "{paper}",
   margin: {margin}pt,
+  header: page-chrome(data, "header"),
+  footer: page-chrome(data, "footer"),
+  background: page-background(data),
 )
 
 // Typography configuration
 #set text(
-  font: "{font_family}",
+  font: {font_list},
   size: {font_size}pt,
+  tracking: {tracking}em,
+  hyphenate: {hyphenate},
+  lang: "{lang}",
+  dir: {dir},
 )
-
-// Parse the resume data
-#let data = json(bytes("{resume_json}"))
-
+{paragraph_spacing}
 // Render the template
 #template(data)
+
+// Attachment files on pages after the resume
+#render-appended-attachments(data)
 "#,
             template = template_name,
             paper = match resume.metadata.page.format {
@@ -245,57 +697,222 @@ impl TypstRenderer {
                 PageFormat::Letter => "us-letter",
             },
             margin = resume.metadata.page.margin,
-            font_family = escaped_font_family,
+            lang = locale.language,
+            dir = if rtl { "rtl" } else { "ltr" },
             font_size = resume.metadata.typography.font.size,
+            tracking = resume.metadata.typography.letter_spacing,
+            hyphenate = match resume.metadata.typography.hyphenate {
+                Some(true) => "true",
+                Some(false) => "false",
+                None => "auto",
+            },
+            paragraph_spacing = match resume.metadata.typography.paragraph_spacing {
+                Some(spacing) => format!("#set par(spacing: {spacing}em)\n"),
+                None => String::new(),
+            },
             resume_json = escaped_json,
         );
 
-        Ok((source, picture_asset))
+        Ok(Prepared {
+            source,
+            assets,
+            template: template_name.to_string(),
+            font_fallbacks: fallbacks,
+            info,
+        })
+    }
+
+    /// Render a PDF, reporting each [`RenderProgress`] milestone to `on_progress`.
+    ///
+    /// Page events fire for each laid-out page once layout is done, since
+    /// Typst then exports the whole document in one pass.
+    #[instrument(skip(self, resume, on_progress))]
+    pub fn render_pdf_with_progress(
+        &self,
+        resume: &ResumeData,
+        mut on_progress: impl FnMut(RenderProgress),
+    ) -> Result<Vec<u8>, RenderError> {
+        debug!("Rendering PDF");
+        let mut stats = RenderStats::new(RenderKind::Pdf, self.template_name(resume));
+        let result = self
+            .compile_measured(resume, &mut on_progress, &mut stats)
+            .and_then(|document| {
+                let total_pages = document.pages().len();
+                on_progress(RenderProgress::Compiled { total_pages });
+                for page in 0..total_pages {
+                    on_progress(RenderProgress::PageRendered { page, total_pages });
+                }
+                on_progress(RenderProgress::Exporting);
+                let pdf = export_pdf(&document)?;
+                on_progress(RenderProgress::Done { size: pdf.len() });
+                Ok(pdf)
+            });
+        self.observe(stats, result.as_ref().map(Vec::len));
+        result
     }
 
     /// Compile the Typst source to a document.
-    #[instrument(skip(self, resume))]
-    fn compile(&self, resume: &ResumeData) -> Result<typst_layout::PagedDocument, RenderError> {
-        use typst::{World, WorldExt};
-
-        debug!("Starting Typst compilation");
-        let (source, picture_asset) = self.prepare_source(resume)?;
-        let mut world = RustumeWorld::new(source)?;
-        if let Some((path, data)) = picture_asset {
-            world.add_binary_file(&path, data)?;
+    pub(super) fn compile(
+        &self,
+        resume: &ResumeData,
+        on_progress: &mut dyn FnMut(RenderProgress),
+    ) -> Result<typst_layout::PagedDocument, RenderError> {
+        let mut stats = RenderStats::new(RenderKind::Pdf, self.template_name(resume));
+        self.compile_measured(resume, on_progress, &mut stats)
+    }
+
+    /// Compile the Typst source to a document, recording the template, font
+    /// fallbacks, compile time, and page count in `stats`.
+    #[instrument(skip_all)]
+    fn compile_measured(
+        &self,
+        resume: &ResumeData,
+        on_progress: &mut dyn FnMut(RenderProgress),
+        stats: &mut RenderStats,
+    ) -> Result<typst_layout::PagedDocument, RenderError> {
+        let started = Instant::now();
+        let prepared = self.prepare(resume)?;
+        on_progress(RenderProgress::SourceGenerated);
+        let sources = self.package_sources(&prepared.template);
+        let document = compile_document(
+            prepared.source,
+            Some(&prepared.template),
+            prepared.assets,
+            sources,
+            self.sandbox,
+        );
+        stats.template = prepared.template;
+        stats.font_fallbacks = prepared.font_fallbacks;
+        stats.compile_time = started.elapsed();
+        let mut document = document?;
+        stats.pages = document.pages().len();
+        if let Some(info) = prepared.info {
+            info.write_to(document.info_mut());
         }
+        Ok(document)
+    }
 
-        debug!("Compiling Typst document");
-        let result = typst::compile::<typst_layout::PagedDocument>(&world);
-        result.output.map_err(|errors| {
-            let messages: Vec<String> = errors
-                .iter()
-                .map(|e| {
-                    // Try to get source context for the error
-                    let file_id = e.span.id().unwrap_or_else(|| world.main());
-                    let location = if let Ok(src) = world.source(file_id) {
-                        if let Some(range) = world.range(e.span) {
-                            // Find line number by counting newlines before the error position
-                            let line = src.text()[..range.start].matches('\n').count();
-                            let text = src.text().lines().nth(line).unwrap_or("");
-                            format!("{:?}:{}: {}", src.id().vpath(), line + 1, text.trim())
-                        } else {
-                            format!("{:?}", src.id().vpath())
-                        }
-                    } else {
-                        format!("{:?}", e.span)
-                    };
-                    format!("{}: {}", location, e.message)
-                })
-                .collect();
-            RenderError::RenderFailed(format!(
-                "Typst compilation failed:\n{}",
-                messages.join("\n")
-            ))
-        })
+    /// Log a finished render and pass its stats to the observer. `output` is
+    /// the size of what it produced, or the error it failed with.
+    fn observe(&self, mut stats: RenderStats, output: Result<usize, &RenderError>) {
+        match output {
+            Ok(bytes) => stats.output_bytes = bytes,
+            Err(err) => stats.error = Some(err.code()),
+        }
+        info!(
+            template = %stats.template,
+            kind = stats.kind.as_str(),
+            pages = stats.pages,
+            compile_ms = stats.compile_time.as_millis() as u64,
+            output_bytes = stats.output_bytes,
+            font_fallbacks = %stats.font_fallbacks.join(","),
+            error = stats.error.map(|code| code.as_str()),
+            "Render finished"
+        );
+        if let Some(observer) = &self.observer {
+            observer(&stats);
+        }
     }
 }
 
+/// Compile Typst `source` with binary `assets` (virtual path, bytes) and
+/// extra Typst `sources` (virtual path, text) to a document, within
+/// `sandbox`. With a `template`, `source` is reparsed incrementally from the
+/// last source compiled for it.
+pub(super) fn compile_document(
+    source: String,
+    template: Option<&str>,
+    assets: Vec<(String, Vec<u8>)>,
+    sources: Vec<(String, String)>,
+    sandbox: SandboxLimits,
+) -> Result<typst_layout::PagedDocument, RenderError> {
+    debug!("Starting Typst compilation");
+    let world = match template {
+        Some(template) => RustumeWorld::for_template(template, source)?,
+        None => RustumeWorld::new(source)?,
+    };
+    let mut world = world.with_sandbox(sandbox);
+    for (path, data) in assets {
+        world.add_binary_file(&path, data)?;
+    }
+    for (path, text) in sources {
+        world.add_source(&path, text)?;
+    }
+    compile_world(world)
+}
+
+/// Compile a prepared `world` to a document on a watched thread, stopping
+/// it when it goes over the world's sandbox limits.
+pub(super) fn compile_world(
+    world: RustumeWorld,
+) -> Result<typst_layout::PagedDocument, RenderError> {
+    let budget = world.budget().clone();
+    sandbox::run(&budget, move || compile_in(&world))?
+}
+
+/// Compile `world` to a document on this thread.
+fn compile_in(world: &RustumeWorld) -> Result<typst_layout::PagedDocument, RenderError> {
+    use typst::{World, WorldExt};
+
+    debug!("Compiling Typst document");
+    let result = typst::compile::<typst_layout::PagedDocument>(world);
+    typst::comemo::evict(MEMO_MAX_AGE);
+    result.output.map_err(|errors| {
+        let messages: Vec<String> = errors
+            .iter()
+            .map(|e| {
+                // Try to get source context for the error
+                let file_id = e.span.id().unwrap_or_else(|| world.main());
+                let location = if let Ok(src) = world.source(file_id) {
+                    if let Some(range) = world.range(e.span) {
+                        // Find line number by counting newlines before the error position
+                        let line = src.text()[..range.start].matches('\n').count();
+                        let text = src.text().lines().nth(line).unwrap_or("");
+                        format!("{:?}:{}: {}", src.id().vpath(), line + 1, text.trim())
+                    } else {
+                        format!("{:?}", src.id().vpath())
+                    }
+                } else {
+                    format!("{:?}", e.span)
+                };
+                format!("{}: {}", location, e.message)
+            })
+            .collect();
+        RenderError::RenderFailed(format!(
+            "Typst compilation failed:\n{}",
+            messages.join("\n")
+        ))
+    })
+}
+
+/// Export a compiled document to PDF.
+pub(super) fn export_pdf(document: &typst_layout::PagedDocument) -> Result<Vec<u8>, RenderError> {
+    debug!("Converting to PDF format");
+    // Convert to PDF with default options
+    let options = typst_pdf::PdfOptions::default();
+    typst_pdf::pdf(document, &options).map_err(|errors| {
+        let messages: Vec<String> = errors
+            .iter()
+            .map(|e| format!("{:?}: {}", e.span, e.message))
+            .collect();
+        RenderError::RenderFailed(format!("PDF generation failed:\n{}", messages.join("\n")))
+    })
+}
+
+/// Rasterize a page to PNG at `pixel_per_pt`.
+pub(super) fn encode_png(
+    page: &typst_layout::Page,
+    pixel_per_pt: f64,
+) -> Result<Vec<u8>, RenderError> {
+    let options = typst_render::RenderOptions {
+        pixel_per_pt: typst::utils::Scalar::new(pixel_per_pt),
+        ..Default::default()
+    };
+    typst_render::render(page, &options)
+        .encode_png()
+        .map_err(|e| RenderError::RenderFailed(format!("PNG encoding failed: {}", e)))
+}
+
 impl Default for TypstRenderer {
     fn default() -> Self {
         Self::new()
@@ -303,31 +920,33 @@ impl Default for TypstRenderer {
 }
 
 impl Renderer for TypstRenderer {
-    #[instrument(skip(self, resume))]
     fn render_pdf(&self, resume: &ResumeData) -> Result<Vec<u8>, RenderError> {
-        debug!("Rendering PDF");
-        let document = self.compile(resume)?;
-
-        debug!("Converting to PDF format");
-        // Convert to PDF with default options
-        let options = typst_pdf::PdfOptions::default();
-        let pdf_result = typst_pdf::pdf(&document, &options);
+        TypstRenderer::render_pdf_with_progress(self, resume, |_| {})
+    }
 
-        pdf_result.map_err(|errors| {
-            let messages: Vec<String> = errors
-                .iter()
-                .map(|e| format!("{:?}: {}", e.span, e.message))
-                .collect();
-            RenderError::RenderFailed(format!("PDF generation failed:\n{}", messages.join("\n")))
-        })
+    fn render_pdf_with_progress(
+        &self,
+        resume: &ResumeData,
+        on_progress: &mut dyn FnMut(RenderProgress),
+    ) -> Result<Vec<u8>, RenderError> {
+        TypstRenderer::render_pdf_with_progress(self, resume, on_progress)
     }
 
-    fn render_html(&self, _resume: &ResumeData) -> Result<String, RenderError> {
-        // HTML rendering is not implemented via Typst
-        // This would be handled separately for web preview
-        Err(RenderError::RenderFailed(
-            "HTML rendering not supported via Typst. Use web-based preview.".to_string(),
-        ))
+    fn render_html(&self, resume: &ResumeData) -> Result<String, RenderError> {
+        let unknown = self.redaction.unknown_sections(resume);
+        if !unknown.is_empty() {
+            return Err(RenderError::InvalidConfig(format!(
+                "Unknown sections to exclude: {}",
+                unknown.join(", ")
+            )));
+        }
+        let options = HtmlPageOptions::default();
+        if self.redaction.is_empty() {
+            return Ok(render_html_page(resume, &options));
+        }
+        let mut redacted = resume.clone();
+        self.redaction.apply(&mut redacted);
+        Ok(render_html_page(&redacted, &options))
     }
 
     #[instrument(skip(self, resume), fields(page))]
@@ -337,26 +956,43 @@ impl Renderer for TypstRenderer {
         page: usize,
     ) -> Result<(Vec<u8>, usize), RenderError> {
         debug!("Rendering preview for page {}", page);
-        let document = self.compile(resume)?;
-        let total_pages = document.pages().len();
-
-        // Get the requested page
-        let page_content = document
-            .pages()
-            .get(page)
-            .ok_or_else(|| RenderError::RenderFailed(format!("Page {} not found", page)))?;
-
-        debug!("Rendering page to PNG");
-        // Render to PNG at 2x scale for high quality (RenderOptions default is 2.0 px/pt)
-        let pixmap = typst_render::render(page_content, &typst_render::RenderOptions::default());
-
-        debug!("Encoding PNG");
-        // Encode as PNG
-        let png_bytes = pixmap
-            .encode_png()
-            .map_err(|e| RenderError::RenderFailed(format!("PNG encoding failed: {}", e)))?;
+        let mut stats = RenderStats::new(RenderKind::Preview, self.template_name(resume));
+        let compiled = self.compile_measured(resume, &mut |_| {}, &mut stats);
+        let result = compiled.and_then(|document| {
+            let total_pages = document.pages().len();
+
+            // Get the requested page
+            let page_content = document
+                .pages()
+                .get(page)
+                .ok_or_else(|| RenderError::RenderFailed(format!("Page {} not found", page)))?;
+
+            debug!("Rendering page to PNG");
+            // Render at 2x scale for high quality
+            let png_bytes = encode_png(page_content, PREVIEW_PIXEL_PER_PT)?;
+
+            Ok((png_bytes, total_pages))
+        });
+        self.observe(stats, result.as_ref().map(|(png, _)| png.len()));
+        result
+    }
 
-        Ok((png_bytes, total_pages))
+    #[instrument(skip(self, resume), fields(page))]
+    fn render_svg(&self, resume: &ResumeData, page: usize) -> Result<(String, usize), RenderError> {
+        debug!("Rendering SVG for page {}", page);
+        let mut stats = RenderStats::new(RenderKind::Svg, self.template_name(resume));
+        let compiled = self.compile_measured(resume, &mut |_| {}, &mut stats);
+        let result = compiled.and_then(|document| {
+            let total_pages = document.pages().len();
+            let page_content = document
+                .pages()
+                .get(page)
+                .ok_or_else(|| RenderError::RenderFailed(format!("Page {} not found", page)))?;
+            let svg = typst_svg::svg(page_content, &typst_svg::SvgOptions::default());
+            Ok((svg, total_pages))
+        });
+        self.observe(stats, result.as_ref().map(|(svg, _)| svg.len()));
+        result
     }
 }
 
@@ -442,7 +1078,7 @@ pub fn get_template_theme(template: &str) -> TemplateTheme {
 }
 
 /// Template theme colors.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct TemplateTheme {
     pub background: String,
     pub text: String,
@@ -452,7 +1088,10 @@ pub struct TemplateTheme {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use rustume_schema::{Basics, Experience, Section};
+    use rustume_schema::{
+        Basics, CefrLevel, CustomField, Experience, Language, NameOrder, PersonName, Section,
+        Typography,
+    };
 
     #[allow(clippy::field_reassign_with_default)]
     fn sample_resume() -> ResumeData {
@@ -486,6 +1125,314 @@ mod tests {
         assert!(source.contains("rhyhorn"));
         assert!(source.contains("John Doe"));
         assert!(source.contains("Software Engineer"));
+        assert!(source.contains(r#"footer: page-chrome(data, "footer")"#));
+        assert!(source.contains("background: page-background(data)"));
+    }
+
+    #[test]
+    fn test_oversized_resume_is_rejected_before_compiling() {
+        let limits = RenderLimits {
+            max_data_bytes: Some(64 * 1024),
+            max_asset_bytes: None,
+        };
+        let renderer = TypstRenderer::new().with_limits(limits);
+        let mut resume = sample_resume();
+        assert!(renderer.generate_source(&resume).is_ok());
+
+        resume.sections.summary.content = "<p>word </p>".repeat(50_000);
+        let err = renderer.render_pdf(&resume).unwrap_err();
+        assert!(
+            matches!(err, RenderError::TooLarge { what: "resume data", limit, .. } if limit == 64 * 1024),
+            "unexpected error: {err}"
+        );
+    }
+
+    #[test]
+    fn test_renders_report_stats_to_observer() {
+        let seen = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = seen.clone();
+        let renderer =
+            TypstRenderer::new().with_observer(std::sync::Arc::new(move |stats: &RenderStats| {
+                sink.lock().unwrap().push(stats.clone());
+            }));
+        let mut resume = sample_resume();
+        resume.metadata.template = "missingno".to_string();
+        resume.basics.headline = "软件工程师".to_string();
+
+        let pdf = renderer.render_pdf(&resume).unwrap();
+        resume.metadata.page.margin = 500;
+        assert!(renderer.render_preview(&resume, 0).is_err());
+
+        let seen = seen.lock().unwrap();
+        assert_eq!(seen.len(), 2);
+        assert_eq!(seen[0].template, "rhyhorn");
+        assert_eq!(seen[0].kind, RenderKind::Pdf);
+        assert_eq!(seen[0].pages, 1);
+        assert_eq!(seen[0].output_bytes, pdf.len());
+        assert_eq!(seen[0].font_fallbacks, ["Noto Sans CJK SC"]);
+        assert_eq!(seen[0].error, None);
+
+        assert_eq!(seen[1].kind, RenderKind::Preview);
+        assert_eq!(seen[1].pages, 0);
+        assert_eq!(
+            seen[1].error,
+            Some(rustume_schema::ErrorCode::RenderInvalidConfig)
+        );
+    }
+
+    #[test]
+    fn test_generate_source_with_durations_and_locale() {
+        let renderer = TypstRenderer::new();
+        let mut resume = sample_resume();
+        resume.sections.experience.items[0].date = "Jan 2015 - Dec 2016".to_string();
+
+        let source = renderer.generate_source(&resume).unwrap();
+        assert!(!source.contains("2 yrs"));
+
+        resume.metadata.show_durations = true;
+        let source = renderer.generate_source(&resume).unwrap();
+        assert!(source.contains("Jan 2015 - Dec 2016 · 2 yrs"));
+
+        resume.metadata.locale = "de".to_string();
+        let source = renderer.generate_source(&resume).unwrap();
+        assert!(source.contains("Jan 2015 - Dec 2016 · 2 Jahre"));
+        assert!(source.contains(r#"lang: "de""#));
+        assert!(source.contains("Seite {current} von {total}"));
+    }
+
+    #[test]
+    fn test_generate_source_with_cefr_codes() {
+        let renderer = TypstRenderer::new();
+        let mut resume = sample_resume();
+        resume.sections.languages.items = vec![
+            Language::new("German")
+                .with_description("Fluent")
+                .with_cefr_level(CefrLevel::C1),
+            Language::new("French").with_cefr_level(CefrLevel::B1),
+            Language::new("Dutch").with_description("Fluent"),
+        ];
+
+        let source = renderer.generate_source(&resume).unwrap();
+        assert!(!source.contains("Fluent · C1"));
+
+        resume.metadata.show_cefr = true;
+        let source = renderer.generate_source(&resume).unwrap();
+        assert!(source.contains(r#"\"description\":\"Fluent · C1\""#));
+        assert!(source.contains(r#"\"description\":\"B1\""#));
+        assert!(source.contains(r#"\"description\":\"Fluent\""#));
+    }
+
+    #[test]
+    fn test_generate_source_right_to_left() {
+        let renderer = TypstRenderer::new();
+        let mut resume = sample_resume();
+        let source = renderer.generate_source(&resume).unwrap();
+        assert!(source.contains("dir: ltr"));
+        assert!(!source.contains('\u{2066}'));
+
+        // Arabic content switches direction even under the default locale
+        resume.basics.name = "ليلى حداد".to_string();
+        resume.basics.headline = "مهندسة برمجيات".to_string();
+        let source = renderer.generate_source(&resume).unwrap();
+        assert!(source.contains("dir: rtl"));
+        assert!(source.contains("Noto Sans Arabic"));
+        assert!(source.contains("\u{2066}+1-555-123-4567\u{2069}"));
+        assert!(source.contains("\u{2066}2020 - Present\u{2069}"));
+
+        resume.metadata.locale = "he".to_string();
+        resume.sections.experience.items[0].date = "2020 - היום".to_string();
+        let source = renderer.generate_source(&resume).unwrap();
+        assert!(source.contains(r#"lang: "he""#));
+        assert!(source.contains(r#"\"2020 - היום\""#));
+    }
+
+    #[test]
+    fn test_generate_source_adds_cjk_fallback_fonts() {
+        let renderer = TypstRenderer::new();
+        let mut resume = sample_resume();
+        let source = renderer.generate_source(&resume).unwrap();
+        assert!(!source.contains("Noto Sans"));
+
+        resume.basics.name = "山田 太郎".to_string();
+        resume.basics.headline = "ソフトウェアエンジニア".to_string();
+        let source = renderer.generate_source(&resume).unwrap();
+        assert!(source.contains(r#", "Noto Sans CJK JP")"#));
+        assert!(source.contains("dir: ltr"));
+
+        resume.basics.headline = "软件工程师".to_string();
+        resume.metadata.locale = "zh-TW".to_string();
+        let source = renderer.generate_source(&resume).unwrap();
+        assert!(source.contains(r#", "Noto Sans CJK TC")"#));
+    }
+
+    #[test]
+    fn test_generate_source_links_custom_fields() {
+        let mut resume = sample_resume();
+        resume.basics = resume
+            .basics
+            .with_custom_field(CustomField::link("GitHub", "https://github.com/jdoe"))
+            .with_custom_field(CustomField::phone("Mobile", "+1 (555) 010-0000"))
+            .with_custom_field(CustomField::with_icon("ph-globe", "Pronouns", "he/him"));
+
+        let source = TypstRenderer::new().generate_source(&resume).unwrap();
+        assert!(source.contains(r#"\"href\":\"https://github.com/jdoe\""#));
+        assert!(source.contains(r#"\"href\":\"tel:+15550100000\""#));
+        assert!(source.contains(r#"\"href\":null"#));
+        // Icon-library names can't be drawn, so templates don't get them
+        assert!(!source.contains("ph-globe"));
+    }
+
+    #[test]
+    fn test_preprocess_keeps_glyph_icons_by_emoji_policy() {
+        let mut resume = sample_resume();
+        resume.basics.custom_fields = vec![CustomField::with_icon("🐙", "GitHub", "jdoe")];
+
+        let processed = preprocess_rich_text(&resume);
+        assert_eq!(processed.basics.custom_fields[0].icon, "🐙");
+
+        resume.metadata.emoji = EmojiPolicy::Strip;
+        let processed = preprocess_rich_text(&resume);
+        assert_eq!(processed.basics.custom_fields[0].icon, "");
+    }
+
+    #[test]
+    fn test_preprocess_orders_name_parts() {
+        let mut resume = sample_resume();
+        resume.basics.name = "Taro Yamada".to_string();
+        resume.basics.name_parts = PersonName::new("Taro", "Yamada");
+        resume.metadata.locale = "ja".to_string();
+        assert_eq!(preprocess_rich_text(&resume).basics.name, "Yamada Taro");
+
+        resume.metadata.template_options.name_order = NameOrder::GivenFirst;
+        resume.metadata.template_options.family_name_caps = true;
+        assert_eq!(preprocess_rich_text(&resume).basics.name, "Taro YAMADA");
+
+        // Parts left from before the name was edited are ignored
+        resume.basics.name = "Hanako Suzuki".to_string();
+        assert_eq!(preprocess_rich_text(&resume).basics.name, "Hanako Suzuki");
+    }
+
+    #[test]
+    fn test_preprocess_applies_emoji_policy() {
+        let mut resume = sample_resume();
+        resume.basics.headline = "Software Engineer 🚀".to_string();
+        resume.sections.summary.content = "<p>✅ Shipped on time</p>".to_string();
+
+        let processed = preprocess_rich_text(&resume);
+        assert_eq!(processed.basics.headline, "Software Engineer 🚀");
+
+        resume.metadata.emoji = EmojiPolicy::Strip;
+        let processed = preprocess_rich_text(&resume);
+        assert_eq!(processed.basics.headline, "Software Engineer");
+        assert!(!processed.sections.summary.content.contains('✅'));
+
+        resume.metadata.emoji = EmojiPolicy::Replace;
+        let processed = preprocess_rich_text(&resume);
+        assert!(processed
+            .sections
+            .summary
+            .content
+            .contains("- Shipped on time"));
+    }
+
+    #[test]
+    fn test_generate_source_with_redaction() {
+        let mut resume = sample_resume();
+        resume.basics.phone = "+1 555 0100".to_string();
+
+        let renderer = TypstRenderer::new().with_redaction(Redaction {
+            exclude_sections: vec!["basics.phone".to_string(), "experience".to_string()],
+            ..Redaction::default()
+        });
+        let source = renderer.generate_source(&resume).unwrap();
+        assert!(!source.contains("555 0100"));
+        assert!(!source.contains("Acme Corp"));
+        assert_eq!(resume.basics.phone, "+1 555 0100");
+
+        let renderer = TypstRenderer::new().with_redaction(Redaction {
+            exclude_sections: vec!["experiance".to_string()],
+            ..Redaction::default()
+        });
+        let err = renderer.generate_source(&resume).unwrap_err().to_string();
+        assert!(err.contains("experiance"), "got: {err}");
+    }
+
+    #[test]
+    fn test_prepare_source_adds_branding() {
+        let logo =
+            crate::Logo::new(br#"<svg xmlns="http://www.w3.org/2000/svg"/>"#.to_vec()).unwrap();
+        let renderer = TypstRenderer::new().with_branding(Branding {
+            primary: Some("#1d4ed8".to_string()),
+            footer: Some("Prepared by Globex Talent".to_string()),
+            logo: Some(logo),
+            ..Branding::default()
+        });
+        let mut resume = sample_resume();
+        resume.metadata.theme.text = "#111827".to_string();
+
+        let (source, assets) = renderer.prepare_source(&resume).unwrap();
+        assert!(source.contains(r##"\"primary\":\"#1d4ed8\""##));
+        assert!(source.contains(r##"\"text\":\"#111827\""##));
+        assert!(source.contains("Prepared by Globex Talent"));
+        assert!(source.contains(r#"\"logo\":\"/assets/brand-logo.svg\""#));
+        assert_eq!(assets.last().unwrap().0, "/assets/brand-logo.svg");
+        assert!(!TypstRenderer::new()
+            .generate_source(&resume)
+            .unwrap()
+            .contains("brand"));
+    }
+
+    #[test]
+    fn test_prepare_source_moves_attachments_to_assets() {
+        let mut resume = sample_resume();
+        resume.attachments = vec![
+            rustume_schema::Attachment::new("Certificate", "data:image/png;base64,aGk="),
+            rustume_schema::Attachment::link("Portfolio", "https://example.com/portfolio"),
+        ];
+
+        let (source, assets) = TypstRenderer::new().prepare_source(&resume).unwrap();
+        assert!(!source.contains("base64"));
+        assert!(source.contains("/assets/attachment-0.png"));
+        assert!(source.contains("render-appended-attachments(data)"));
+        let paths: Vec<&str> = assets.iter().map(|(path, _)| path.as_str()).collect();
+        assert_eq!(
+            paths,
+            ["/assets/attachment-0.png", "/assets/attachment-1-qr.svg"]
+        );
+    }
+
+    #[test]
+    fn test_prepare_source_adds_contact_qr() {
+        let mut resume = sample_resume();
+        let source = TypstRenderer::new().generate_source(&resume).unwrap();
+        assert!(source.contains(r#"\"contactQr\":null"#));
+
+        resume.metadata.page.options.contact_qr = rustume_schema::ContactQr::Header;
+        let (source, assets) = TypstRenderer::new().prepare_source(&resume).unwrap();
+        assert!(source.contains(r#"\"contactQr\":\"/assets/contact-qr.svg\""#));
+        assert_eq!(assets.last().unwrap().0, "/assets/contact-qr.svg");
+    }
+
+    #[test]
+    fn test_prepare_source_bakes_picture_effects() {
+        // 1x1 opaque PNG
+        const PIXEL: &str = "iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAYAAAAfFcSJAAAADUlEQVR42mNkYPhfDwAChwGA60e6kgAAAABJRU5ErkJggg==";
+
+        let mut resume = sample_resume();
+        resume.basics.picture.url = format!("data:image/png;base64,{PIXEL}");
+        resume.basics.picture.effects.grayscale = true;
+        resume.basics.picture.effects.border = true;
+        let (source, assets) = TypstRenderer::new().prepare_source(&resume).unwrap();
+        assert!(source.contains(r#"\"baked\":true"#));
+        let (path, png) = assets.last().unwrap();
+        assert_eq!(path, "/assets/picture.png");
+        assert!(png.starts_with(b"\x89PNG"));
+
+        // Undecodable images keep the template's own effects
+        resume.basics.picture.url = "data:image/jpeg;base64,aGk=".to_string();
+        let (source, assets) = TypstRenderer::new().prepare_source(&resume).unwrap();
+        assert!(source.contains(r#"\"baked\":false"#));
+        assert_eq!(assets.last().unwrap().0, "/assets/picture.jpg");
     }
 
     #[test]
@@ -525,6 +1472,43 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_typography_is_passed_to_the_source() {
+        let mut resume = ResumeData::default();
+        let renderer = TypstRenderer::new();
+        let source = renderer.generate_source(&resume).unwrap();
+        assert!(source.contains("tracking: 0em"));
+        assert!(source.contains("hyphenate: auto"));
+        assert!(!source.contains("#set par("));
+
+        resume.metadata.typography.letter_spacing = 0.02;
+        resume.metadata.typography.paragraph_spacing = Some(0.8);
+        resume.metadata.typography.hyphenate = Some(false);
+        let source = renderer.generate_source(&resume).unwrap();
+        assert!(source.contains("tracking: 0.02em"));
+        assert!(source.contains("hyphenate: false"));
+        assert!(source.contains("#set par(spacing: 0.8em)"));
+    }
+
+    #[test]
+    fn test_rejects_typography_outside_bounds() {
+        let renderer = TypstRenderer::new();
+        let cases: [(fn(&mut Typography), &str); 3] = [
+            (|t| t.letter_spacing = f32::NAN, "Letter spacing"),
+            (|t| t.paragraph_spacing = Some(10.0), "Paragraph spacing"),
+            (|t| t.heading_scale = 0.1, "Heading scale"),
+        ];
+        for (set, expected) in cases {
+            let mut resume = ResumeData::default();
+            set(&mut resume.metadata.typography);
+            let err = renderer.generate_source(&resume).unwrap_err().to_string();
+            assert!(
+                err.contains(expected),
+                "Expected {expected} error, got: {err}"
+            );
+        }
+    }
+
     #[test]
     fn test_preprocess_rich_text_converts_html() {
         let mut resume = ResumeData::default();
@@ -551,6 +1535,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_preprocess_rich_text_lists_highlights() {
+        let mut resume = ResumeData::default();
+        resume.sections.experience.add_item(
+            Experience::new("Acme", "Dev")
+                .with_summary("Led the platform team.")
+                .with_highlights(vec!["Cut costs by 30%".to_string(), "Hired 4".to_string()]),
+        );
+
+        let processed = preprocess_rich_text(&resume);
+        let item = &processed.sections.experience.items[0];
+        assert_eq!(
+            item.summary,
+            "Led the platform team.\n\n- Cut costs by 30\\%\n- Hired 4"
+        );
+        assert!(item.highlights.is_empty());
+    }
+
     #[test]
     fn test_preprocess_rich_text_converts_cover_letter() {
         let mut resume = ResumeData::default();
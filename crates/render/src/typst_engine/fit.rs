@@ -0,0 +1,151 @@
+//! Page counting and fitting a resume to a page budget.
+
+use crate::traits::RenderError;
+use crate::typst_engine::engine::TypstRenderer;
+use rustume_schema::{
+    apply_fit_steps, fit_steps, Attachment, AttachmentMode, FitReport, FitStep, FittedResume,
+    ResumeData,
+};
+use tracing::{debug, instrument};
+
+impl TypstRenderer {
+    /// Pages `resume` renders to, not counting appended attachments.
+    pub fn page_count(&self, resume: &ResumeData) -> Result<u32, RenderError> {
+        let appended = |attachment: &Attachment| attachment.mode == AttachmentMode::Append;
+        let document = if resume.attachments.iter().any(appended) {
+            let mut own = resume.clone();
+            own.attachments.retain(|attachment| !appended(attachment));
+            self.compile(&own, &mut |_| {})?
+        } else {
+            self.compile(resume, &mut |_| {})?
+        };
+        Ok(u32::try_from(document.pages().len()).unwrap_or(u32::MAX))
+    }
+
+    /// Shorten `resume` to at most `pages` pages with the fewest of the
+    /// [`fit_steps`] candidates, never hiding items tagged with a keyword in
+    /// `keep`.
+    ///
+    /// A resume that already fits comes back unchanged. When every candidate
+    /// together still runs long, all of them are applied and the report says
+    /// it doesn't fit.
+    #[instrument(skip(self, resume, keep))]
+    pub fn fit_to_pages(
+        &self,
+        resume: &ResumeData,
+        pages: u32,
+        keep: &[String],
+    ) -> Result<FittedResume, RenderError> {
+        if pages == 0 {
+            return Err(RenderError::InvalidConfig(
+                "The page budget must be at least one page".to_string(),
+            ));
+        }
+
+        let pages_before = self.page_count(resume)?;
+        if pages_before <= pages {
+            return Ok(FittedResume {
+                resume: resume.clone(),
+                report: FitReport {
+                    target_pages: pages,
+                    pages_before,
+                    pages_after: pages_before,
+                    fits: true,
+                    steps: Vec::new(),
+                },
+            });
+        }
+
+        let mut steps = fit_steps(resume, keep);
+        let (mut fitted, mut pages_after) = self.apply_and_count(resume, &steps)?;
+
+        // Each step only removes space, so the page count never grows with
+        // more steps and the shortest prefix that fits can be bisected
+        if pages_after <= pages {
+            let (mut too_few, mut enough) = (0, steps.len());
+            while enough - too_few > 1 {
+                let mid = (too_few + enough) / 2;
+                let (candidate, count) = self.apply_and_count(resume, &steps[..mid])?;
+                debug!("{} fit steps give {} pages", mid, count);
+                if count <= pages {
+                    (fitted, pages_after, enough) = (candidate, count, mid);
+                } else {
+                    too_few = mid;
+                }
+            }
+            steps.truncate(enough);
+        }
+
+        Ok(FittedResume {
+            resume: fitted,
+            report: FitReport {
+                target_pages: pages,
+                pages_before,
+                pages_after,
+                fits: pages_after <= pages,
+                steps: last_per_path(steps),
+            },
+        })
+    }
+
+    fn apply_and_count(
+        &self,
+        resume: &ResumeData,
+        steps: &[FitStep],
+    ) -> Result<(ResumeData, u32), RenderError> {
+        let mut fitted = resume.clone();
+        apply_fit_steps(&mut fitted, steps)
+            .map_err(|err| RenderError::RenderFailed(format!("Could not apply fit step: {err}")))?;
+        let pages = self.page_count(&fitted)?;
+        Ok((fitted, pages))
+    }
+}
+
+/// Drop steps overwritten by a later step to the same field, so a margin
+/// narrowed four times is reported once.
+fn last_per_path(steps: Vec<FitStep>) -> Vec<FitStep> {
+    let mut kept: Vec<FitStep> = Vec::with_capacity(steps.len());
+    for step in steps.into_iter().rev() {
+        if !kept.iter().any(|later| later.path == step.path) {
+            kept.push(step);
+        }
+    }
+    kept.reverse();
+    kept
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rustume_schema::FitStepKind;
+
+    fn step(path: &str, value: u32) -> FitStep {
+        FitStep {
+            kind: FitStepKind::ShrinkFont,
+            path: path.to_string(),
+            value: value.into(),
+            description: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_last_per_path_keeps_final_value() {
+        let steps = vec![
+            step("metadata.page.margin", 16),
+            step("metadata.page.margin", 14),
+            step("metadata.typography.font.size", 13),
+        ];
+        let kept = last_per_path(steps);
+        assert_eq!(kept.len(), 2);
+        assert_eq!(kept[0].value, 14);
+        assert_eq!(kept[1].path, "metadata.typography.font.size");
+    }
+
+    #[test]
+    fn test_fit_to_pages_rejects_zero_pages() {
+        let err = TypstRenderer::new()
+            .fit_to_pages(&ResumeData::default(), 0, &[])
+            .unwrap_err();
+        assert!(matches!(err, RenderError::InvalidConfig(_)));
+    }
+}
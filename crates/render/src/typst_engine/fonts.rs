@@ -0,0 +1,142 @@
+//! Font fallbacks for scripts the template fonts don't cover.
+//!
+//! Template and user-picked fonts are Latin. When a resume contains Arabic,
+//! Hebrew, Chinese, Japanese, or Korean text, the generated source lists a
+//! Noto family for each of those scripts after the chosen font, so Typst
+//! shapes them with a font made for the script instead of its last-resort
+//! fallback (or tofu). The Docker image installs these fonts; elsewhere any
+//! that are missing are skipped.
+
+/// A script that needs a fallback font.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Script {
+    Arabic,
+    Hebrew,
+    Han,
+    Kana,
+    Hangul,
+}
+
+impl Script {
+    fn of(c: char) -> Option<Self> {
+        match c {
+            '\u{0590}'..='\u{05FF}' | '\u{FB1D}'..='\u{FB4F}' => Some(Self::Hebrew),
+            '\u{0600}'..='\u{06FF}'
+            | '\u{0750}'..='\u{077F}'
+            | '\u{08A0}'..='\u{08FF}'
+            | '\u{FB50}'..='\u{FDFF}'
+            | '\u{FE70}'..='\u{FEFF}' => Some(Self::Arabic),
+            '\u{3040}'..='\u{30FF}' | '\u{31F0}'..='\u{31FF}' | '\u{FF66}'..='\u{FF9F}' => {
+                Some(Self::Kana)
+            }
+            '\u{1100}'..='\u{11FF}' | '\u{3130}'..='\u{318F}' | '\u{AC00}'..='\u{D7AF}' => {
+                Some(Self::Hangul)
+            }
+            '\u{3400}'..='\u{4DBF}'
+            | '\u{4E00}'..='\u{9FFF}'
+            | '\u{F900}'..='\u{FAFF}'
+            | '\u{20000}'..='\u{2FA1F}' => Some(Self::Han),
+            _ => None,
+        }
+    }
+}
+
+/// Scripts needing a fallback that appear in `text`, in first-seen order.
+pub(crate) fn detect_scripts(text: &str) -> Vec<Script> {
+    let mut scripts = Vec::new();
+    for script in text.chars().filter_map(Script::of) {
+        if !scripts.contains(&script) {
+            scripts.push(script);
+        }
+    }
+    scripts
+}
+
+/// Noto families to try after the chosen font for a resume whose text uses
+/// `scripts`, written in the language of `locale`.
+///
+/// Han characters are drawn differently in Chinese, Japanese, and Korean, so
+/// the CJK family follows the text: kana means Japanese and hangul Korean.
+/// Otherwise the locale decides, with Simplified Chinese as the default.
+pub(crate) fn fallback_families(scripts: &[Script], locale: &str) -> Vec<&'static str> {
+    let mut families = Vec::new();
+    if scripts.contains(&Script::Arabic) {
+        families.push("Noto Sans Arabic");
+    }
+    if scripts.contains(&Script::Hebrew) {
+        families.push("Noto Sans Hebrew");
+    }
+
+    // Japanese quoting a Korean name (or the reverse) gets both families
+    if scripts.contains(&Script::Kana) {
+        families.push("Noto Sans CJK JP");
+    }
+    if scripts.contains(&Script::Hangul) {
+        families.push("Noto Sans CJK KR");
+    }
+    if scripts.contains(&Script::Han) && !families.iter().any(|f| f.contains("CJK")) {
+        families.push(han_family(locale));
+    }
+    families
+}
+
+/// CJK family for Han text without kana or hangul, by locale.
+fn han_family(locale: &str) -> &'static str {
+    let mut subtags = locale.split(['-', '_']).map(str::to_ascii_lowercase);
+    match subtags.next().as_deref() {
+        Some("ja") => "Noto Sans CJK JP",
+        Some("ko") => "Noto Sans CJK KR",
+        Some("zh") if subtags.any(|tag| matches!(tag.as_str(), "hant" | "tw" | "hk" | "mo")) => {
+            "Noto Sans CJK TC"
+        }
+        _ => "Noto Sans CJK SC",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_scripts() {
+        assert!(detect_scripts("Jane Doe, Software Engineer").is_empty());
+        assert_eq!(
+            detect_scripts("山田 太郎 — ソフトウェアエンジニア"),
+            vec![Script::Han, Script::Kana]
+        );
+        assert_eq!(detect_scripts("김민준"), vec![Script::Hangul]);
+        assert_eq!(
+            detect_scripts("ليلى / שלום"),
+            vec![Script::Arabic, Script::Hebrew]
+        );
+    }
+
+    #[test]
+    fn test_fallback_families() {
+        assert!(fallback_families(&[], "en").is_empty());
+        assert_eq!(
+            fallback_families(&[Script::Han], "en"),
+            vec!["Noto Sans CJK SC"]
+        );
+        assert_eq!(
+            fallback_families(&[Script::Han], "zh-Hant"),
+            vec!["Noto Sans CJK TC"]
+        );
+        assert_eq!(
+            fallback_families(&[Script::Han], "ja"),
+            vec!["Noto Sans CJK JP"]
+        );
+        assert_eq!(
+            fallback_families(&[Script::Han, Script::Kana], "en"),
+            vec!["Noto Sans CJK JP"]
+        );
+        assert_eq!(
+            fallback_families(&[Script::Hangul, Script::Han, Script::Kana], "ko"),
+            vec!["Noto Sans CJK JP", "Noto Sans CJK KR"]
+        );
+        assert_eq!(
+            fallback_families(&[Script::Arabic, Script::Han], "ar"),
+            vec!["Noto Sans Arabic", "Noto Sans CJK SC"]
+        );
+    }
+}
@@ -2,7 +2,29 @@
 //!
 //! This module provides PDF generation using the Typst typesetting system.
 
+mod attachments;
+mod capabilities;
+mod contact_qr;
+mod contact_sheet;
+#[cfg(all(feature = "dev", not(target_arch = "wasm32")))]
+mod dev_templates;
 mod engine;
+mod fit;
+mod fonts;
+mod pdf_info;
+mod sandbox;
+mod template_check;
 mod world;
 
+pub use capabilities::{
+    layout_warnings, page_option_warnings, template_layout, template_option_warnings,
+    template_options, template_rules, TemplateLayout, TemplateOptionSupport,
+};
+pub use contact_sheet::SheetFormat;
+#[cfg(all(feature = "dev", not(target_arch = "wasm32")))]
+pub use dev_templates::{
+    dev_templates_dir, set_dev_templates_dir, TemplateWatcher, DEFAULT_POLL_INTERVAL,
+};
 pub use engine::{get_page_size, get_template_theme, TemplateTheme, TypstRenderer, TEMPLATES};
+pub use sandbox::SandboxLimits;
+pub use template_check::{FixtureCheck, TemplateCheckReport, TemplateFixture, TemplateIssue};
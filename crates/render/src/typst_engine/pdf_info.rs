@@ -0,0 +1,145 @@
+//! Document information of exported PDFs.
+//!
+//! With `metadata.page.options.pdfMetadata` on, the default, [`PdfInfo`]
+//! fills the title, author, subject, keywords, and creation date that file
+//! browsers show and applicant tracking systems index. Typst writes them to
+//! both the document information dictionary and the XMP metadata.
+
+use chrono::{Datelike, Timelike, Utc};
+use rustume_schema::ResumeData;
+use typst::foundations::{Datetime, Smart};
+use typst::model::DocumentInfo;
+
+use crate::html::page_description;
+
+/// What a resume puts in its PDF's document information.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct PdfInfo {
+    /// "Name — Headline", or whichever of the two the resume has.
+    pub title: Option<String>,
+    pub author: Option<String>,
+    /// The summary as plain text, or the headline.
+    pub subject: Option<String>,
+    /// Skill names, then their keywords, without repeats.
+    pub keywords: Vec<String>,
+}
+
+impl PdfInfo {
+    /// Document information for `resume`, or `None` when the option is off.
+    ///
+    /// Takes the resume as written: the summary has to still be HTML, not
+    /// the Typst markup the renderer turns it into.
+    pub fn of(resume: &ResumeData) -> Option<Self> {
+        if !resume.metadata.page.options.pdf_metadata {
+            return None;
+        }
+        let name = resume.display_name().trim().to_string();
+        let headline = resume.basics.headline.trim();
+        let title = match (name.is_empty(), headline.is_empty()) {
+            (false, false) => Some(format!("{name} — {headline}")),
+            (false, true) => Some(name.clone()),
+            (true, false) => Some(headline.to_string()),
+            (true, true) => None,
+        };
+        let subject = Some(page_description(resume)).filter(|subject| !subject.is_empty());
+        Some(Self {
+            title,
+            author: Some(name).filter(|name| !name.is_empty()),
+            subject,
+            keywords: skill_keywords(resume),
+        })
+    }
+
+    /// Write into the `info` of a compiled document, dated now. The locale
+    /// Typst took from the page's `text` rule stays.
+    pub fn write_to(self, info: &mut DocumentInfo) {
+        let now = Utc::now();
+        info.title = self.title.map(Into::into);
+        info.author = self.author.into_iter().map(Into::into).collect();
+        info.description = self.subject.map(Into::into);
+        info.keywords = self.keywords.into_iter().map(Into::into).collect();
+        info.date = Smart::Custom(Datetime::from_ymd_hms(
+            now.year(),
+            now.month() as u8,
+            now.day() as u8,
+            now.hour() as u8,
+            now.minute() as u8,
+            now.second() as u8,
+        ));
+    }
+}
+
+/// Names and keywords of the visible skills, in order, each once.
+fn skill_keywords(resume: &ResumeData) -> Vec<String> {
+    let skills = &resume.sections.skills;
+    if !skills.visible {
+        return Vec::new();
+    }
+    let visible = || skills.items.iter().filter(|skill| skill.visible);
+    let mut keywords: Vec<String> = Vec::new();
+    let terms = visible()
+        .map(|skill| &skill.name)
+        .chain(visible().flat_map(|skill| &skill.keywords));
+    for term in terms {
+        let term = term.trim();
+        if !term.is_empty() && !keywords.iter().any(|seen| seen.eq_ignore_ascii_case(term)) {
+            keywords.push(term.to_string());
+        }
+    }
+    keywords
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rustume_schema::{Section, Skill};
+
+    fn resume() -> ResumeData {
+        let mut resume = ResumeData::with_basics("Ada Lovelace", "ada@example.com");
+        resume.basics.headline = "Analyst".to_string();
+        resume.sections.skills = Section::new("skills", "Skills");
+        resume.sections.skills.add_item(
+            Skill::new("Mathematics").with_keywords(vec!["Analysis".into(), "Rust".into()]),
+        );
+        resume
+            .sections
+            .skills
+            .add_item(Skill::new("rust").with_keywords(vec!["Engines".into()]));
+        resume
+    }
+
+    #[test]
+    fn test_pdf_info_describes_the_resume() {
+        let info = PdfInfo::of(&resume()).unwrap();
+        assert_eq!(info.title.as_deref(), Some("Ada Lovelace — Analyst"));
+        assert_eq!(info.author.as_deref(), Some("Ada Lovelace"));
+        assert_eq!(info.subject.as_deref(), Some("Analyst"));
+        assert_eq!(
+            info.keywords,
+            ["Mathematics", "rust", "Analysis", "Engines"]
+        );
+    }
+
+    #[test]
+    fn test_pdf_info_leaves_out_what_is_missing() {
+        let mut resume = resume();
+        resume.basics.headline.clear();
+        resume.sections.skills.visible = false;
+        let info = PdfInfo::of(&resume).unwrap();
+        assert_eq!(info.title.as_deref(), Some("Ada Lovelace"));
+        assert_eq!(info.subject, None);
+        assert!(info.keywords.is_empty());
+
+        resume.metadata.page.options.pdf_metadata = false;
+        assert_eq!(PdfInfo::of(&resume), None);
+    }
+
+    #[test]
+    fn test_pdf_info_is_written_with_a_date() {
+        let mut info = DocumentInfo::default();
+        PdfInfo::of(&resume()).unwrap().write_to(&mut info);
+        assert_eq!(info.title.as_deref(), Some("Ada Lovelace — Analyst"));
+        assert_eq!(info.keywords.len(), 4);
+        assert!(matches!(info.date, Smart::Custom(Some(_))));
+    }
+}
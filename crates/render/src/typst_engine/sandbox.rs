@@ -0,0 +1,319 @@
+//! Execution limits for Typst code.
+//!
+//! Templates are programs, and community template packages and template
+//! checks run ones Rustume didn't write. [`RustumeWorld`] only serves its
+//! virtual file system: the main source, templates, and the sources and
+//! files registered with it. Package imports and every other path fail.
+//! [`SandboxLimits`] bounds what a compilation can spend inside it:
+//!
+//! - **Time.** A watchdog stops waiting for a compilation after
+//!   `max_compile_time`, and the world fails every file access from then on,
+//!   so an abandoned compilation ends at its next import or file read.
+//!   Threads can't be killed, though: code that loops without touching a
+//!   file keeps its thread busy until the loop ends. Typst caps `while`
+//!   loops, call depth, and layout passes, but not `for` loops.
+//! - **Threads.** At most `max_compilations` compilation threads run at
+//!   once, counting abandoned ones until their threads really exit. Past
+//!   that, new compilations are refused, so runaway templates can't pile up
+//!   threads faster than they wind down.
+//! - **Source size.** Templates and registered sources over
+//!   `max_source_bytes` are refused before Typst parses them.
+//! - **Memory.** With `max_resident_bytes` set, the watchdog stops a
+//!   compilation once the process's resident memory is over it (Linux only),
+//!   so a runaway template fails its render instead of the process being
+//!   killed for running out of memory. The measure is the whole process's,
+//!   not the compilation's: while one heavy render holds the process over
+//!   the limit, every compilation running alongside it fails too.
+//!
+//! [`RustumeWorld`]: super::world::RustumeWorld
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, OnceLock};
+use std::time::{Duration, Instant};
+
+use crate::traits::RenderError;
+
+/// How often the watchdog checks a running compilation.
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Stack of the compilation thread, the size of a main thread's.
+const COMPILE_STACK_BYTES: usize = 8 * 1024 * 1024;
+
+/// Compilation threads alive in this process, abandoned ones included.
+static COMPILE_THREADS: AtomicUsize = AtomicUsize::new(0);
+
+/// Limits on a single Typst compilation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SandboxLimits {
+    /// Longest a compilation may run.
+    pub max_compile_time: Duration,
+    /// Largest template or registered Typst source, in bytes. The generated
+    /// main source is bounded by [`RenderLimits`](crate::RenderLimits)
+    /// instead.
+    pub max_source_bytes: usize,
+    /// Most compilation threads alive in the process at once, including
+    /// ones the watchdog gave up on that are still winding down. A
+    /// compilation started past it fails with
+    /// [`RenderError::LimitExceeded`].
+    pub max_compilations: usize,
+    /// Resident memory of the whole process above which compilations stop,
+    /// in bytes. Every compilation running while the process is over it
+    /// fails, not only the one that allocated the most. Off by default, and
+    /// ignored where the platform doesn't report it.
+    pub max_resident_bytes: Option<u64>,
+}
+
+impl Default for SandboxLimits {
+    fn default() -> Self {
+        Self {
+            max_compile_time: Duration::from_secs(60),
+            max_source_bytes: 1024 * 1024,
+            max_compilations: 32,
+            max_resident_bytes: None,
+        }
+    }
+}
+
+/// A compilation's use of its [`SandboxLimits`], shared between the world
+/// and the watchdog.
+#[derive(Debug, Clone)]
+pub(super) struct Budget {
+    limits: SandboxLimits,
+    started: Instant,
+    /// Why the watchdog stopped the compilation, once it has.
+    stopped: Arc<OnceLock<String>>,
+}
+
+impl Budget {
+    pub(super) fn new(limits: SandboxLimits) -> Self {
+        Self {
+            limits,
+            started: Instant::now(),
+            stopped: Arc::new(OnceLock::new()),
+        }
+    }
+
+    /// Fail once the compilation has been stopped or has run out of time.
+    pub(super) fn check(&self) -> Result<(), String> {
+        if let Some(reason) = self.stopped.get() {
+            return Err(reason.clone());
+        }
+        self.check_time()
+    }
+
+    /// Fail for a source of `len` bytes over the size limit.
+    pub(super) fn check_source(&self, path: &str, len: usize) -> Result<(), String> {
+        let limit = self.limits.max_source_bytes;
+        if len > limit {
+            return Err(format!(
+                "{path} is {len} bytes, over the source limit of {limit}"
+            ));
+        }
+        Ok(())
+    }
+
+    fn check_time(&self) -> Result<(), String> {
+        let limit = self.limits.max_compile_time;
+        if self.started.elapsed() > limit {
+            return Err(format!(
+                "compilation ran longer than {:.1}s",
+                limit.as_secs_f64()
+            ));
+        }
+        Ok(())
+    }
+
+    fn check_memory(&self) -> Result<(), String> {
+        let Some(limit) = self.limits.max_resident_bytes else {
+            return Ok(());
+        };
+        match resident_bytes() {
+            Some(resident) if resident > limit => Err(format!(
+                "the process uses {resident} bytes of memory, over the limit of {limit}"
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Mark the compilation stopped, so the world fails its file accesses.
+    fn stop(&self, reason: &str) {
+        let _ = self.stopped.set(reason.to_string());
+    }
+}
+
+/// A slot in a count of alive threads, given back when dropped.
+struct ThreadSlot(&'static AtomicUsize);
+
+impl ThreadSlot {
+    /// Take a slot in `alive`, unless `max` threads already hold one.
+    fn take(alive: &'static AtomicUsize, max: usize) -> Option<Self> {
+        alive
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |count| {
+                (count < max).then_some(count + 1)
+            })
+            .ok()
+            .map(|_| Self(alive))
+    }
+}
+
+impl Drop for ThreadSlot {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+/// Run `compile` on its own thread and wait for it within `budget`. Past a
+/// limit, the compilation is stopped and left to wind down on its own; its
+/// thread keeps its slot under `max_compilations` until it exits.
+pub(super) fn run<T: Send + 'static>(
+    budget: &Budget,
+    compile: impl FnOnce() -> T + Send + 'static,
+) -> Result<T, RenderError> {
+    run_counted(&COMPILE_THREADS, budget, compile)
+}
+
+/// [`run`], counting the compilation thread in `alive`.
+fn run_counted<T: Send + 'static>(
+    alive: &'static AtomicUsize,
+    budget: &Budget,
+    compile: impl FnOnce() -> T + Send + 'static,
+) -> Result<T, RenderError> {
+    let max = budget.limits.max_compilations;
+    let slot = ThreadSlot::take(alive, max).ok_or_else(|| {
+        RenderError::LimitExceeded(format!("{max} Typst compilations are already running"))
+    })?;
+    let (sender, receiver) = mpsc::sync_channel(1);
+    let span = tracing::Span::current();
+    std::thread::Builder::new()
+        .name("typst-compile".to_string())
+        .stack_size(COMPILE_STACK_BYTES)
+        .spawn(move || {
+            let _slot = slot;
+            let _entered = span.enter();
+            let _ = sender.send(compile());
+        })
+        .map_err(|err| {
+            RenderError::RenderFailed(format!("Failed to start Typst compilation: {err}"))
+        })?;
+
+    loop {
+        match receiver.recv_timeout(POLL_INTERVAL) {
+            Ok(output) => return Ok(output),
+            Err(RecvTimeoutError::Disconnected) => {
+                return Err(RenderError::RenderFailed(
+                    "Typst compilation panicked".to_string(),
+                ))
+            }
+            Err(RecvTimeoutError::Timeout) => {
+                if let Err(reason) = budget.check_time().and_then(|()| budget.check_memory()) {
+                    budget.stop(&reason);
+                    return Err(RenderError::LimitExceeded(reason));
+                }
+            }
+        }
+    }
+}
+
+/// Resident memory of this process, where the platform reports it.
+fn resident_bytes() -> Option<u64> {
+    #[cfg(target_os = "linux")]
+    {
+        let status = std::fs::read_to_string("/proc/self/status").ok()?;
+        let kib: u64 = status
+            .lines()
+            .find_map(|line| line.strip_prefix("VmRSS:"))?
+            .trim()
+            .strip_suffix("kB")?
+            .trim()
+            .parse()
+            .ok()?;
+        Some(kib * 1024)
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn budget_runs_out_of_time() {
+        let budget = Budget::new(SandboxLimits {
+            max_compile_time: Duration::ZERO,
+            ..SandboxLimits::default()
+        });
+        std::thread::sleep(Duration::from_millis(5));
+        assert!(budget.check().unwrap_err().contains("longer than"));
+    }
+
+    #[test]
+    fn stopped_budget_fails_every_check() {
+        let budget = Budget::new(SandboxLimits::default());
+        assert!(budget.check().is_ok());
+        budget.clone().stop("stopped for the test");
+        assert_eq!(budget.check().unwrap_err(), "stopped for the test");
+    }
+
+    #[test]
+    fn watchdog_stops_slow_compilations() {
+        let budget = Budget::new(SandboxLimits {
+            max_compile_time: Duration::from_millis(100),
+            ..SandboxLimits::default()
+        });
+        let err = run(&budget, || std::thread::sleep(Duration::from_secs(2))).unwrap_err();
+        assert!(matches!(err, RenderError::LimitExceeded(_)), "{err}");
+        assert!(budget.check().is_err());
+    }
+
+    #[test]
+    fn watchdog_reports_panics() {
+        let budget = Budget::new(SandboxLimits::default());
+        let err = run(&budget, || panic!("compile bug")).unwrap_err();
+        assert!(err.to_string().contains("panicked"), "{err}");
+        assert_eq!(run(&budget, || 7).unwrap(), 7);
+    }
+
+    #[test]
+    fn abandoned_compilations_hold_their_slot_until_they_exit() {
+        static ALIVE: AtomicUsize = AtomicUsize::new(0);
+        let budget = Budget::new(SandboxLimits {
+            max_compile_time: Duration::from_millis(50),
+            max_compilations: 1,
+            ..SandboxLimits::default()
+        });
+        let (release, wait) = mpsc::channel::<()>();
+        let err = run_counted(&ALIVE, &budget, move || {
+            let _ = wait.recv();
+        })
+        .unwrap_err();
+        assert!(matches!(err, RenderError::LimitExceeded(_)), "{err}");
+
+        let err = run_counted(&ALIVE, &Budget::new(budget.limits), || ()).unwrap_err();
+        assert!(err.to_string().contains("already running"), "{err}");
+
+        drop(release);
+        let budget = Budget::new(budget.limits);
+        let started = Instant::now();
+        while let Err(err) = run_counted(&ALIVE, &budget, || ()) {
+            assert!(started.elapsed() < Duration::from_secs(5), "{err}");
+            std::thread::sleep(Duration::from_millis(10));
+        }
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn watchdog_stops_compilations_over_the_memory_limit() {
+        assert!(resident_bytes().is_some_and(|bytes| bytes > 0));
+        let budget = Budget::new(SandboxLimits {
+            max_resident_bytes: Some(1),
+            ..SandboxLimits::default()
+        });
+        let err = run(&budget, || std::thread::sleep(Duration::from_secs(2))).unwrap_err();
+        assert!(err.to_string().contains("memory"), "{err}");
+    }
+}
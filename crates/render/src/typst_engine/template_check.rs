@@ -0,0 +1,604 @@
+//! Checking a candidate template against fixture resumes.
+//!
+//! [`TypstRenderer::check_template`] compiles a template's source, without
+//! adding it to the embedded set, against resumes that stress it in different
+//! ways: no content at all, a short resume, every section filled many times
+//! over, right-to-left Arabic, and Japanese. It reports what a reviewer would
+//! otherwise find by hand: compile errors, sections the template renders
+//! nothing for, and text that runs off the page.
+
+use std::fmt;
+
+use rustume_schema::{
+    Award, Certification, CustomItem, Education, Experience, Interest, Language, Profile, Project,
+    Publication, Reference, ResumeData, Section, Skill, SummarySection, Volunteer,
+};
+use typst::layout::{Frame, FrameItem, Point, Size, Transform};
+
+use crate::traits::RenderError;
+use crate::typst_engine::engine::{compile_world, TypstRenderer};
+use crate::typst_engine::world::RustumeWorld;
+
+/// Where the candidate lives in the Typst world, next to `_common.typ` so
+/// its `#import "_common.typ": *` resolves as in the embedded templates.
+const CANDIDATE_PATH: &str = "templates/candidate.typ";
+
+/// Items in each section of the [`TemplateFixture::Huge`] resume.
+const HUGE_ITEMS: usize = 12;
+
+/// How far text may reach past a page edge before it counts as overflow.
+const OVERFLOW_TOLERANCE_PT: f64 = 1.0;
+
+/// Characters of overflowing text quoted in an issue.
+const OVERFLOW_EXCERPT_CHARS: usize = 40;
+
+/// Section keys the huge fixture marks, in the order they are reported.
+const MARKED_SECTIONS: [&str; 14] = [
+    "summary",
+    "profiles",
+    "experience",
+    "education",
+    "awards",
+    "certifications",
+    "skills",
+    "interests",
+    "publications",
+    "volunteer",
+    "languages",
+    "projects",
+    "references",
+    "custom",
+];
+
+/// A resume a candidate template is compiled against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TemplateFixture {
+    /// A resume with nothing filled in.
+    Empty,
+    /// A name, a summary, one job, one degree, and a few skills.
+    Minimal,
+    /// Every section visible with many long items, checked for missing
+    /// section handlers.
+    Huge,
+    /// The minimal resume in Arabic, laid out right to left.
+    RightToLeft,
+    /// The minimal resume in Japanese.
+    Cjk,
+}
+
+impl TemplateFixture {
+    /// Every fixture, in the order they are checked.
+    pub const ALL: [Self; 5] = [
+        Self::Empty,
+        Self::Minimal,
+        Self::Huge,
+        Self::RightToLeft,
+        Self::Cjk,
+    ];
+
+    /// Short name for reports: `empty`, `minimal`, `huge`, `rtl`, `cjk`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Empty => "empty",
+            Self::Minimal => "minimal",
+            Self::Huge => "huge",
+            Self::RightToLeft => "rtl",
+            Self::Cjk => "cjk",
+        }
+    }
+
+    /// The fixture's resume data.
+    pub fn resume(self) -> ResumeData {
+        match self {
+            Self::Empty => ResumeData::default(),
+            Self::Minimal => minimal_resume(),
+            Self::Huge => huge_resume(),
+            Self::RightToLeft => right_to_left_resume(),
+            Self::Cjk => cjk_resume(),
+        }
+    }
+}
+
+impl fmt::Display for TemplateFixture {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// A problem [`TypstRenderer::check_template`] found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TemplateIssue {
+    /// The template failed to compile with the fixture.
+    Compile(String),
+    /// Nothing from the section's items made it onto the page, usually
+    /// because its entry in `renderers` is missing or ignores the item.
+    MissingSection(&'static str),
+    /// Text runs past the edge of a page (0-indexed).
+    Overflow { page: usize, text: String },
+}
+
+impl fmt::Display for TemplateIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Compile(message) => write!(f, "does not compile: {message}"),
+            Self::MissingSection(section) => {
+                write!(f, "renders nothing for the `{section}` section")
+            }
+            Self::Overflow { page, text } => {
+                write!(f, "text runs off page {}: \"{text}\"", page + 1)
+            }
+        }
+    }
+}
+
+/// The outcome of compiling a candidate template with one fixture.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FixtureCheck {
+    pub fixture: TemplateFixture,
+    /// Pages the fixture rendered to; zero when it didn't compile.
+    pub pages: usize,
+    pub issues: Vec<TemplateIssue>,
+}
+
+/// Results of [`TypstRenderer::check_template`], one per fixture.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TemplateCheckReport {
+    pub fixtures: Vec<FixtureCheck>,
+}
+
+impl TemplateCheckReport {
+    /// Whether no fixture turned up an issue.
+    pub fn is_ok(&self) -> bool {
+        self.fixtures.iter().all(|check| check.issues.is_empty())
+    }
+}
+
+impl TypstRenderer {
+    /// Compile the template `source` against every [`TemplateFixture`].
+    ///
+    /// The template is checked as it would be embedded: it can import
+    /// `_common.typ` and must export `template(data)`. Errors in it are
+    /// reported against `templates/candidate.typ`.
+    pub fn check_template(&self, source: &str) -> TemplateCheckReport {
+        TemplateCheckReport {
+            fixtures: TemplateFixture::ALL
+                .into_iter()
+                .map(|fixture| self.check_fixture(source, fixture))
+                .collect(),
+        }
+    }
+
+    fn check_fixture(&self, source: &str, fixture: TemplateFixture) -> FixtureCheck {
+        let document = match self.compile_candidate(source, &fixture.resume()) {
+            Ok(document) => document,
+            Err(err) => {
+                return FixtureCheck {
+                    fixture,
+                    pages: 0,
+                    issues: vec![TemplateIssue::Compile(err.to_string())],
+                }
+            }
+        };
+
+        let mut issues = Vec::new();
+        let mut text = String::new();
+        for (page, content) in document.pages().iter().enumerate() {
+            let mut runs = Vec::new();
+            collect_text(&content.frame, Transform::identity(), &mut runs);
+            if let Some(run) = runs.iter().find(|run| run.overflows(content.frame.size())) {
+                issues.push(TemplateIssue::Overflow {
+                    page,
+                    text: run.text.chars().take(OVERFLOW_EXCERPT_CHARS).collect(),
+                });
+            }
+            for run in runs {
+                text.push_str(&run.text);
+            }
+        }
+
+        if fixture == TemplateFixture::Huge {
+            // Line breaks and hyphenation can split a marker, and templates
+            // may change its case
+            let text: String = text
+                .chars()
+                .filter(|c| c.is_alphanumeric())
+                .flat_map(char::to_lowercase)
+                .collect();
+            issues.extend(
+                MARKED_SECTIONS
+                    .into_iter()
+                    .filter(|section| !text.contains(&marker(section)))
+                    .map(TemplateIssue::MissingSection),
+            );
+        }
+
+        FixtureCheck {
+            fixture,
+            pages: document.pages().len(),
+            issues,
+        }
+    }
+
+    /// Compile `resume` with the candidate in place of its template.
+    fn compile_candidate(
+        &self,
+        source: &str,
+        resume: &ResumeData,
+    ) -> Result<typst_layout::PagedDocument, RenderError> {
+        let (main, assets) = self.prepare_source(resume)?;
+        let main = main.replacen(
+            &format!("templates/{}.typ", self.template_name(resume)),
+            CANDIDATE_PATH,
+            1,
+        );
+        let mut world = RustumeWorld::new(main)?.with_sandbox(self.sandbox_limits());
+        world.add_source(CANDIDATE_PATH, source.to_string())?;
+        for (path, data) in assets {
+            world.add_binary_file(&path, data)?;
+        }
+        compile_world(world)
+    }
+}
+
+/// A run of text and where it sits on the page.
+struct TextRun {
+    text: String,
+    /// Baseline start and end, in page coordinates.
+    start: Point,
+    end: Point,
+}
+
+impl TextRun {
+    fn overflows(&self, page: Size) -> bool {
+        let tolerance = OVERFLOW_TOLERANCE_PT;
+        [self.start, self.end].iter().any(|point| {
+            let (x, y) = (point.x.to_pt(), point.y.to_pt());
+            x < -tolerance
+                || y < -tolerance
+                || x > page.x.to_pt() + tolerance
+                || y > page.y.to_pt() + tolerance
+        })
+    }
+}
+
+/// Collect the text runs in `frame`, whose origin `ts` maps to the page.
+fn collect_text(frame: &Frame, ts: Transform, runs: &mut Vec<TextRun>) {
+    for (pos, item) in frame.items() {
+        match item {
+            FrameItem::Group(group) => {
+                let ts = ts
+                    .pre_concat(Transform::translate(pos.x, pos.y))
+                    .pre_concat(group.transform);
+                collect_text(&group.frame, ts, runs);
+            }
+            FrameItem::Text(text) => runs.push(TextRun {
+                text: text.text.to_string(),
+                start: pos.transform(ts),
+                end: Point::new(pos.x + text.width(), pos.y).transform(ts),
+            }),
+            _ => {}
+        }
+    }
+}
+
+/// The word the huge fixture puts in the items of `section`.
+fn marker(section: &str) -> String {
+    format!("zz{section}zz")
+}
+
+fn minimal_resume() -> ResumeData {
+    let mut resume = ResumeData::with_basics("Jane Doe", "jane@example.com");
+    resume.basics.headline = "Software Engineer".to_string();
+    resume.sections.summary =
+        SummarySection::new("Engineer with five years of experience building web services.");
+    resume.sections.experience.add_item(
+        Experience::new("Acme Corp", "Software Engineer")
+            .with_location("Berlin")
+            .with_date("2021 - Present")
+            .with_summary("Built the billing platform."),
+    );
+    resume.sections.education.add_item(
+        Education::new("University of Example", "Computer Science")
+            .with_study_type("Bachelor of Science")
+            .with_date("2016 - 2020"),
+    );
+    for skill in ["Rust", "TypeScript", "PostgreSQL"] {
+        resume.sections.skills.add_item(Skill::new(skill));
+    }
+    resume
+}
+
+fn right_to_left_resume() -> ResumeData {
+    let mut resume = ResumeData::with_basics("ليلى حداد", "layla@example.com");
+    resume.metadata.locale = "ar".to_string();
+    resume.basics.headline = "مهندسة برمجيات".to_string();
+    resume.basics.location = "عمّان، الأردن".to_string();
+    resume.sections.summary =
+        SummarySection::new("مهندسة برمجيات بخبرة خمس سنوات في بناء خدمات الويب.");
+    resume.sections.experience.add_item(
+        Experience::new("شركة أكمي", "مهندسة برمجيات")
+            .with_location("عمّان")
+            .with_date("2021 - الآن")
+            .with_summary("بنت منصة الفوترة وقادت فريقًا من أربعة مهندسين."),
+    );
+    resume.sections.education.add_item(
+        Education::new("الجامعة الأردنية", "علوم الحاسوب")
+            .with_study_type("بكالوريوس")
+            .with_date("2016 - 2020"),
+    );
+    for skill in ["راست", "تايب سكريبت", "قواعد البيانات"] {
+        resume.sections.skills.add_item(Skill::new(skill));
+    }
+    resume
+}
+
+fn cjk_resume() -> ResumeData {
+    let mut resume = ResumeData::with_basics("山田 太郎", "taro@example.com");
+    resume.metadata.locale = "ja".to_string();
+    resume.basics.headline = "ソフトウェアエンジニア".to_string();
+    resume.basics.location = "東京都渋谷区".to_string();
+    resume.sections.summary = SummarySection::new(
+        "ウェブサービスの設計と開発に五年間携わってきたソフトウェアエンジニアです。",
+    );
+    resume.sections.experience.add_item(
+        Experience::new("株式会社アクメ", "ソフトウェアエンジニア")
+            .with_location("東京")
+            .with_date("2021 - 現在")
+            .with_summary("請求基盤を構築し、四人のチームを率いました。"),
+    );
+    resume.sections.education.add_item(
+        Education::new("東京大学", "情報科学")
+            .with_study_type("学士")
+            .with_date("2016 - 2020"),
+    );
+    for skill in ["Rust", "TypeScript", "データベース設計"] {
+        resume.sections.skills.add_item(Skill::new(skill));
+    }
+    resume
+}
+
+/// Every section visible with [`HUGE_ITEMS`] long items each; the first
+/// item of each section carries its [`marker`].
+fn huge_resume() -> ResumeData {
+    const SENTENCE: &str = "Led a cross-functional team through the redesign of a \
+        high-traffic platform, coordinating product, design, and operations \
+        while keeping every release on schedule.";
+    let paragraph = SENTENCE.repeat(3);
+
+    let mut resume = ResumeData::with_basics(
+        "Alexandra Montgomery-Vasquez",
+        "alexandra.montgomery-vasquez@example-consulting.com",
+    );
+    resume.basics.headline =
+        "Principal Software Engineer, Distributed Systems and Developer Platforms".to_string();
+    resume.basics.phone = "+1 (555) 123-4567".to_string();
+    resume.basics.location = "San Francisco, California, United States".to_string();
+    resume.sections.summary = SummarySection::new(format!("{} {paragraph}", marker("summary")));
+
+    let mut custom = Section::new("highlights", "Highlights");
+    let sections = &mut resume.sections;
+    for i in 1..=HUGE_ITEMS {
+        let date = format!("{} - {}", 2000 + i, 2001 + i);
+        let keywords = vec!["Leadership".to_string(), "Architecture".to_string()];
+        sections
+            .profiles
+            .add_item(Profile::new(format!("Network {i}"), "alexandra"));
+        sections.experience.add_item(
+            Experience::new(
+                format!("Globex International Holdings {i}"),
+                "Senior Staff Engineer",
+            )
+            .with_location("San Francisco, California")
+            .with_date(&date)
+            .with_summary(&paragraph),
+        );
+        sections.education.add_item(
+            Education::new(format!("University of Example {i}"), "Computer Science")
+                .with_study_type("Master of Science")
+                .with_date(&date)
+                .with_summary(SENTENCE),
+        );
+        sections.awards.add_item(
+            Award::new(format!("Engineering Excellence Award {i}"))
+                .with_awarder("Globex International")
+                .with_date(&date)
+                .with_summary(SENTENCE),
+        );
+        sections.certifications.add_item(
+            Certification::new(format!("Cloud Architect Professional {i}"), "Example Cloud")
+                .with_date(&date)
+                .with_summary(SENTENCE),
+        );
+        sections.skills.add_item(
+            Skill::new(format!("Distributed Systems {i}"))
+                .with_description("Expert")
+                .with_keywords(keywords.clone()),
+        );
+        sections
+            .interests
+            .add_item(Interest::new(format!("Mountaineering {i}")).with_keywords(keywords.clone()));
+        sections.publications.add_item(
+            Publication::new(format!("Scaling Consensus in Practice {i}"))
+                .with_publisher("Example Press")
+                .with_date(&date)
+                .with_summary(SENTENCE),
+        );
+        sections.volunteer.add_item(
+            Volunteer::new(format!("Code for Example {i}"), "Mentor")
+                .with_location("Oakland, California")
+                .with_date(&date)
+                .with_summary(SENTENCE),
+        );
+        sections
+            .languages
+            .add_item(Language::new(format!("Portuguese {i}")).with_description("Fluent"));
+        sections.projects.add_item(
+            Project::new(format!("Open Source Scheduler {i}"))
+                .with_description("Maintainer")
+                .with_date(&date)
+                .with_summary(&paragraph)
+                .with_keywords(keywords.clone()),
+        );
+        sections.references.add_item(
+            Reference::new(format!("Jordan Example {i}"))
+                .with_description("Former manager at Globex International")
+                .with_summary(SENTENCE),
+        );
+        custom.add_item(
+            CustomItem::new(format!("Conference Keynote {i}"))
+                .with_description("Speaker")
+                .with_date(&date)
+                .with_summary(SENTENCE)
+                .with_keywords(keywords),
+        );
+    }
+    sections.custom.insert(custom.id.clone(), custom);
+    mark_first_items(&mut resume);
+
+    for visible in [
+        &mut resume.sections.awards.visible,
+        &mut resume.sections.certifications.visible,
+        &mut resume.sections.publications.visible,
+        &mut resume.sections.languages.visible,
+        &mut resume.sections.interests.visible,
+        &mut resume.sections.volunteer.visible,
+        &mut resume.sections.references.visible,
+    ] {
+        *visible = true;
+    }
+    resume
+        .metadata
+        .layout
+        .ensure_all_sections_present(&resume.sections);
+    resume
+}
+
+/// Put each section's [`marker`] in the name fields of its first item, so
+/// the section shows up in the text whichever field a template prints.
+fn mark_first_items(resume: &mut ResumeData) {
+    let sections = &mut resume.sections;
+    if let Some(item) = sections.profiles.items.first_mut() {
+        item.network = marker("profiles");
+        item.username = marker("profiles");
+    }
+    if let Some(item) = sections.experience.items.first_mut() {
+        item.company = marker("experience");
+        item.position = marker("experience");
+    }
+    if let Some(item) = sections.education.items.first_mut() {
+        item.institution = marker("education");
+        item.area = marker("education");
+    }
+    if let Some(item) = sections.awards.items.first_mut() {
+        item.title = marker("awards");
+    }
+    if let Some(item) = sections.certifications.items.first_mut() {
+        item.name = marker("certifications");
+        item.issuer = marker("certifications");
+    }
+    if let Some(item) = sections.skills.items.first_mut() {
+        item.name = marker("skills");
+    }
+    if let Some(item) = sections.interests.items.first_mut() {
+        item.name = marker("interests");
+    }
+    if let Some(item) = sections.publications.items.first_mut() {
+        item.name = marker("publications");
+    }
+    if let Some(item) = sections.volunteer.items.first_mut() {
+        item.organization = marker("volunteer");
+        item.position = marker("volunteer");
+    }
+    if let Some(item) = sections.languages.items.first_mut() {
+        item.name = marker("languages");
+    }
+    if let Some(item) = sections.projects.items.first_mut() {
+        item.name = marker("projects");
+    }
+    if let Some(item) = sections.references.items.first_mut() {
+        item.name = marker("references");
+    }
+    for section in sections.custom.values_mut() {
+        if let Some(item) = section.items.first_mut() {
+            item.name = marker("custom");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ONYX: &str = include_str!("templates/onyx.typ");
+
+    #[test]
+    fn test_huge_fixture_shows_every_marked_section() {
+        let huge = TemplateFixture::Huge.resume();
+        assert!(huge.sections.awards.visible);
+        assert_eq!(huge.sections.references.items.len(), HUGE_ITEMS);
+        assert_eq!(huge.sections.experience.items[0].company, "zzexperiencezz");
+        assert!(huge.metadata.layout.find("highlights").is_some());
+    }
+
+    #[test]
+    fn test_embedded_template_renders_every_section() {
+        let report = TypstRenderer::new().check_template(ONYX);
+        for check in &report.fixtures {
+            assert!(check.pages > 0, "{} did not render", check.fixture);
+            assert!(
+                check
+                    .issues
+                    .iter()
+                    .all(|issue| matches!(issue, TemplateIssue::Overflow { .. })),
+                "{}: {:?}",
+                check.fixture,
+                check.issues
+            );
+        }
+    }
+
+    #[test]
+    fn test_reports_missing_section_handler() {
+        let source = ONYX.replace("projects: render-project,", "projects: item => none,");
+        assert_ne!(source, ONYX);
+        let report = TypstRenderer::new().check_template(&source);
+        assert!(!report.is_ok());
+        let huge = &report.fixtures[2];
+        assert_eq!(huge.fixture, TemplateFixture::Huge);
+        assert!(huge
+            .issues
+            .contains(&TemplateIssue::MissingSection("projects")));
+        assert!(!huge
+            .issues
+            .contains(&TemplateIssue::MissingSection("experience")));
+    }
+
+    #[test]
+    fn test_reports_compile_errors_against_the_candidate() {
+        let report = TypstRenderer::new().check_template("#let template(data) = {");
+        assert_eq!(report.fixtures.len(), TemplateFixture::ALL.len());
+        for check in &report.fixtures {
+            assert_eq!(check.pages, 0);
+            let [TemplateIssue::Compile(message)] = check.issues.as_slice() else {
+                panic!("{}: {:?}", check.fixture, check.issues);
+            };
+            assert!(message.contains("candidate.typ"), "{message}");
+        }
+    }
+
+    #[test]
+    fn test_overflowing_text_is_reported() {
+        use typst::layout::Abs;
+
+        let page = Size::new(Abs::pt(100.0), Abs::pt(100.0));
+        let run = |x: f64, width: f64| TextRun {
+            text: "text".to_string(),
+            start: Point::new(Abs::pt(x), Abs::pt(50.0)),
+            end: Point::new(Abs::pt(x + width), Abs::pt(50.0)),
+        };
+        assert!(!run(10.0, 80.0).overflows(page));
+        assert!(!run(10.0, 90.5).overflows(page));
+        assert!(run(10.0, 120.0).overflows(page));
+        assert!(run(-20.0, 10.0).overflows(page));
+    }
+}
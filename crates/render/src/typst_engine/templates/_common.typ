@@ -51,14 +51,26 @@
   } else {
     none
   }
-  let photo = box(
-    width: picture-size,
-    height: picture-size,
-    radius: border-radius,
-    clip: true,
-    stroke: stroke,
-    image(picture.url, width: picture-size, height: picture-size, fit: "cover")
-  )
+  // The renderer bakes the crop, grayscale, corners, and border into the
+  // image when it can decode it; otherwise the box clips and strokes a square.
+  let baked = picture.at("baked", default: false)
+  let picture-height = if baked {
+    picture-size / picture.at("aspectRatio", default: 1)
+  } else {
+    picture-size
+  }
+  let photo = if baked {
+    box(image(picture.url, width: picture-size, height: picture-height))
+  } else {
+    box(
+      width: picture-size,
+      height: picture-size,
+      radius: border-radius,
+      clip: true,
+      stroke: stroke,
+      image(picture.url, width: picture-size, height: picture-size, fit: "cover")
+    )
+  }
 
   let shadow-offset = shadow-size / 2
   let content = if shadow-size > 0pt {
@@ -66,12 +78,17 @@
     // layout anchor (e.g. inside align(center)). The shadow is place()d
     // with a diagonal offset and overflows the box (place does not clip),
     // appearing below-right of the photo.
-    box(width: picture-size, height: picture-size)[
+    box(width: picture-size, height: picture-height)[
       #place(
         top + left,
         dx: shadow-offset,
         dy: shadow-offset,
-        box(width: picture-size, height: picture-size, radius: border-radius, fill: shadow-color)
+        box(
+          width: picture-size,
+          height: picture-height,
+          radius: calc.min(border-radius, picture-height / 2),
+          fill: shadow-color,
+        )
       )
       #place(top + left, photo)
     ]
@@ -82,6 +99,93 @@
   rotate(rotation, reflow: true, content)
 }
 
+// ── Template options ──
+// **Keep in sync with:** `template_options` in capabilities.rs, which lists
+// the options each template honors.
+
+/// A value from `metadata.templateOptions`, or the template's `default`
+/// when it is unset or "template-default".
+#let template-option(data, key, default) = {
+  let options = data.metadata.at("templateOptions", default: none)
+  let value = if options == none { "template-default" } else {
+    options.at(key, default: "template-default")
+  }
+  if value == "template-default" { default } else { value }
+}
+
+/// Header alignment from `templateOptions.headerAlign`.
+#let header-align(data, default) = {
+  let value = template-option(data, "headerAlign", none)
+  if value == "left" {
+    left
+  } else if value == "center" {
+    center
+  } else if value == "right" {
+    right
+  } else {
+    default
+  }
+}
+
+/// Stroke of a section-heading rule under `templateOptions.divider`: the
+/// template's own `thickness` by default, 0.5pt for "line", 2pt for
+/// "thick", dotted at the template's weight, or none.
+#let divider-stroke(data, thickness, paint) = {
+  let style = template-option(data, "divider", "template-default")
+  if style == "none" {
+    none
+  } else if style == "line" {
+    (paint: paint, thickness: 0.5pt)
+  } else if style == "thick" {
+    (paint: paint, thickness: 2pt)
+  } else if style == "dotted" {
+    (paint: paint, thickness: calc.max(thickness, 1pt), dash: "dotted")
+  } else {
+    (paint: paint, thickness: thickness)
+  }
+}
+
+/// A full-width section-heading rule, or nothing when the divider is "none".
+#let divider-line(data, thickness, paint, start: (0pt, 0pt)) = {
+  let stroke = divider-stroke(data, thickness, paint)
+  if stroke != none {
+    line(start: start, length: 100%, stroke: stroke)
+  }
+}
+
+/// Set a header `body` with the profile picture where
+/// `templateOptions.photo` puts it: stacked above ("top", aligned with
+/// `alignment`), beside it ("left", "right"), or not at all ("hidden").
+/// Positions outside `positions` fall back to `default`. Without a visible
+/// picture the header is returned unchanged.
+#let header-with-photo(
+  data,
+  primary-color,
+  body,
+  default: "hidden",
+  positions: ("top", "left", "right", "hidden"),
+  alignment: left,
+  gutter: 16pt,
+) = {
+  let position = template-option(data, "photo", default)
+  if position not in positions {
+    position = default
+  }
+  if position == "hidden" or not has-visible-picture(data.basics) {
+    return body
+  }
+  let photo = render-picture(data.basics, primary-color)
+  if position == "top" {
+    align(alignment, photo)
+    v(8pt)
+    body
+  } else if position == "left" {
+    grid(columns: (auto, 1fr), column-gutter: gutter, align: horizon, photo, body)
+  } else {
+    grid(columns: (1fr, auto), column-gutter: gutter, align: horizon, body, photo)
+  }
+}
+
 /// Format a degree line from studyType and area.
 #let format-degree(studyType, area) = {
   if studyType != "" and area != "" {
@@ -93,6 +197,18 @@
   }
 }
 
+/// English fallbacks for the generated strings the renderer passes in
+/// `data.strings`, localized from `metadata.locale`.
+#let default-strings = (
+  page: "Page {current} of {total}",
+  levels: ("Novice", "Beginner", "Intermediate", "Advanced", "Expert"),
+  contact: "Contact",
+  attachments: "Attachments",
+)
+
+/// Generated strings (labels the template writes itself) in the resume's locale.
+#let strings(data) = data.at("strings", default: default-strings)
+
 /// Clamp a skill/language level to [0, 5] and convert to int.
 #let clamp-level(val) = {
   int(calc.min(calc.max(val, 0), 5))
@@ -137,6 +253,7 @@
   track-width: 48pt,
   track-height: 4pt,
   text-size: 8pt,
+  labels: default-strings.levels,
 ) = {
   let level = clamp-level(level)
 
@@ -152,7 +269,7 @@
       height: track-height,
       fill: empty-color,
       radius: track-height / 2,
-      place(top + left, box(
+      place(top + start, box(
         width: track-width * level / 5,
         height: track-height,
         fill: filled-color,
@@ -160,8 +277,7 @@
       )),
     )
   } else if display == "text" {
-    let labels = ("", "Novice", "Beginner", "Intermediate", "Advanced", "Expert")
-    let label = labels.at(level)
+    let label = (("",) + labels).at(level)
     if label != "" {
       text(size: text-size, fill: filled-color)[#label]
     }
@@ -201,17 +317,65 @@
   }
 }
 
-/// Build a filtered array of non-empty contact text items from basics.
-/// Returns an array of (email, phone, location) strings, excluding empties.
+/// Text for an item's link: `label` when there is one, else the URL itself,
+/// so the link never ends up empty and impossible to click.
+#let link-label(item, label) = {
+  if label != none and label != "" { label } else { item.url.href }
+}
+
+/// Build a filtered array of non-empty contact items from basics.
+/// Returns (email, phone, location), excluding empties; the email is a
+/// `mailto:` link.
 /// URL is intentionally omitted — templates style it differently.
 #let build-contact-items(basics) = {
   let items = ()
-  if basics.email != "" { items = items + (basics.email,) }
+  if basics.email != "" { items = items + (link("mailto:" + basics.email)[#basics.email],) }
   if basics.phone != "" { items = items + (basics.phone,) }
   if basics.location != "" { items = items + (basics.location,) }
   items
 }
 
+/// Glyphs for custom field kinds, for templates that mark contact details
+/// with icons.
+#let custom-field-glyphs = (link: "🔗", email: "✉", phone: "☎")
+
+/// Build contact items for basics.customFields, in order. Values the renderer
+/// gave an href (links, emails, phones, web addresses) are clickable in
+/// `color`; web links show their name and dates are labelled with it. The field's
+/// glyph icon leads the item; with `glyphs: true`, fields without one get the
+/// glyph for their kind.
+#let build-custom-field-items(basics, color: none, glyphs: false) = {
+  let items = ()
+  for field in basics.at("customFields", default: ()) {
+    if field.value.trim() == "" { continue }
+    let kind = field.at("kind", default: "text")
+    let href = field.at("href", default: none)
+    let label = if field.name != "" and href != none and kind in ("link", "text") {
+      field.name
+    } else if kind == "date" and field.name != "" {
+      field.name + ": " + field.value
+    } else {
+      field.value
+    }
+    let body = if href == none {
+      label
+    } else if color == none {
+      link(href, label)
+    } else {
+      link(href, text(fill: color, label))
+    }
+    let icon = if field.icon != "" {
+      field.icon
+    } else if glyphs {
+      custom-field-glyphs.at(kind, default: "")
+    } else {
+      ""
+    }
+    items.push(if icon == "" { body } else { [#icon #body] })
+  }
+  items
+}
+
 /// Optional sidebar width ratio from metadata.page.sidebarRatio.
 #let sidebar-ratio(data) = {
   data.metadata.page.at("sidebarRatio", default: none)
@@ -273,7 +437,9 @@
 /// Fixed-width sidebar plus flowing main content.
 ///
 /// The grid owns the sidebar background while each column receives breakable
-/// padding, so long content can continue onto later pages.
+/// padding, so long content can continue onto later pages. With
+/// `page-fills`, the grid stays clear and marks where it starts and ends,
+/// so `page-background` can color the sidebar page by page.
 #let sidebar-layout(
   sidebar-width: 170pt,
   sidebar-bg: none,
@@ -282,14 +448,21 @@
   main-inset: (x: 24pt, y: 24pt),
   sidebar-content: none,
   main-content: none,
+  page-fills: false,
 ) = {
+  if page-fills {
+    [#metadata((width: sidebar-width, fill: sidebar-bg)) <rustume-sidebar>]
+  }
   grid(
     columns: (sidebar-width, 1fr),
     column-gutter: 0pt,
-    fill: (x, _) => if x == 0 { sidebar-bg } else { body-bg },
+    fill: if page-fills { none } else { (x, _) => if x == 0 { sidebar-bg } else { body-bg } },
     pad(x: sidebar-inset.x, y: sidebar-inset.y, sidebar-content),
     pad(x: main-inset.x, y: main-inset.y, main-content),
   )
+  if page-fills {
+    [#metadata("sidebar-end") <rustume-sidebar-end>]
+  }
 }
 
 /// Proportional two-column content layout.
@@ -307,6 +480,16 @@
   )
 }
 
+/// Stroke and inset for an accent rule on the side where lines start: the
+/// left, or the right when the page reads right to left. Spread into `box`.
+#let start-rule(data, stroke, inset) = {
+  if data.at("dir", default: "ltr") == "rtl" {
+    (stroke: (right: stroke), inset: (right: inset, y: 2pt))
+  } else {
+    (stroke: (left: stroke), inset: (left: inset, y: 2pt))
+  }
+}
+
 
 #let default-main-sections = (
   "summary",
@@ -438,6 +621,55 @@
   }
 }
 
+/// Template line spacing `default` scaled by `metadata.typography.lineHeight`.
+/// The default line height of 1.5 keeps the template's spacing; stored
+/// values are clamped to the 1–3 the HTML export allows.
+#let line-leading(data, default) = {
+  let line-height = data.metadata.typography.at("lineHeight", default: 1.5)
+  if type(line-height) in (int, float) {
+    default * calc.clamp(line-height, 1, 3) / 1.5
+  } else {
+    default
+  }
+}
+
+/// Template justification `default`, unless `metadata.typography.justify`
+/// sets it.
+#let par-justify(data, default) = {
+  let justify = data.metadata.typography.at("justify", default: none)
+  if type(justify) == bool { justify } else { default }
+}
+
+/// Small capitals drawn from capital letters, since the bundled fonts have
+/// no small-caps glyphs: lowercase letters become capitals at 80% size.
+#let small-caps(title) = {
+  if type(title) != str or title == "" { return title }
+  for cluster in title.clusters() {
+    if cluster != upper(cluster) { text(size: 0.8em, upper(cluster)) } else { cluster }
+  }
+}
+
+/// The template's `heading` function with `metadata.typography` applied:
+/// small capitals, and the whole heading scaled by `headingScale` at the
+/// width the template drew it for.
+#let styled-heading(data, heading) = {
+  let typography = data.metadata.typography
+  let scale-by = typography.at("headingScale", default: 1)
+  let small = typography.at("smallCaps", default: false)
+  if heading == none or (scale-by == 1 and not small) {
+    heading
+  } else {
+    title => {
+      let body = heading(if small { small-caps(title) } else { title })
+      if scale-by == 1 {
+        body
+      } else {
+        layout(size => scale(scale-by * 100%, reflow: true, block(width: size.width / scale-by, body)))
+      }
+    }
+  }
+}
+
 /// Render the cover letter (heading, recipient block, rich-text body) using
 /// the template's heading style. Content arrives pre-converted to Typst
 /// markup by the engine's rich-text preprocessing.
@@ -452,6 +684,7 @@
 /// No-op unless the section is visible and placed in the layout.
 #let render-cover-letter-page(data, heading, size: 10pt, muted: none, inset: none) = {
   if not has-cover-letter(data) { return }
+  let heading = styled-heading(data, heading)
   if inset == none {
     render-cover-letter(data, heading, size: size, muted: muted)
   } else {
@@ -474,8 +707,15 @@
   }
 }
 
+/// Whether split sections repeat their heading on the next page.
+#let continued-markers(data) = {
+  data.metadata.page.options.at("continuedMarkers", default: true)
+}
+
 /// Render item sections while keeping headings with the first item.
-#let render-item-section(section, heading, render-item) = {
+/// With `continued`, an item that starts a new page repeats the heading
+/// marked "(continued)".
+#let render-item-section(section, heading, render-item, continued: false) = {
   if section.visible {
     let has-items = false
     let is-first = true
@@ -485,8 +725,17 @@
       block(breakable: false)[
         #if is-first {
           heading(section.name)
+        } else if continued {
+          context {
+            let previous = query(selector(<rustume-item>).before(here()))
+            let previous = previous.filter(marker => marker.value == section.id)
+            if previous.len() > 0 and previous.last().location().page() < here().page() {
+              heading(section.name + " (continued)")
+            }
+          }
         }
         #render-item(item)
+        #metadata(section.id) <rustume-item>
       ]
       is-first = false
     }
@@ -509,34 +758,102 @@
   }
 }
 
+/// Sections whose items have a `level` and can be drawn as a chart.
+/// **Keep in sync with:** `SECTION_DISPLAY_KEYS` in crates/schema/src/metadata.rs
+#let chart-sections = ("skills", "languages")
+
+/// Display mode for a section key from `metadata.sectionDisplay`; "list"
+/// keeps the template's own item renderer.
+#let section-display(data, key) = {
+  let modes = data.metadata.at("sectionDisplay", default: none)
+  if modes == none { "list" } else { modes.at(key, default: "list") }
+}
+
+/// Render a skills or languages section as a "bar-chart", "dots", or
+/// "tag-cloud" in the theme's primary color, in place of the template's
+/// item renderer. Levels are drawn whatever `levelDisplay` says.
+#let render-chart-section(data, section, heading, display) = {
+  if not section.visible { return }
+  let items = section.items.filter(item => item.visible)
+  let filled = resolve-color(data.metadata.theme.at("primary", default: ""), rgb("#dc2626"))
+  // Translucent so the track shows on white and tinted sidebars alike
+  let empty = filled.transparentize(75%)
+
+  let chart = if display == "bar-chart" {
+    grid(
+      columns: (auto, 1fr),
+      column-gutter: 8pt,
+      row-gutter: 6pt,
+      align: horizon,
+      ..items
+        .map(item => (
+          text(size: 9pt)[#item.name],
+          render-level(item.level, "progress-bar", filled, empty, track-width: 100%, track-height: 5pt),
+        ))
+        .flatten()
+    )
+  } else if display == "dots" {
+    grid(
+      columns: (1fr, auto),
+      column-gutter: 8pt,
+      row-gutter: 6pt,
+      align: horizon,
+      ..items
+        .map(item => (
+          text(size: 9pt)[#item.name],
+          rating-indicators(item.level, 6pt, 6pt, filled, empty, 50%, 2pt),
+        ))
+        .flatten()
+    )
+  } else if display == "tag-cloud" {
+    set par(justify: false, leading: 0.8em)
+    items
+      .map(item => box(
+        inset: (x: 4pt, y: 2pt),
+        radius: 3pt,
+        fill: empty,
+        text(size: 8pt + 1pt * clamp-level(item.level))[#item.name],
+      ))
+      .join([ ])
+  }
+
+  block(breakable: false)[
+    #heading(section.name)
+    #chart
+  ]
+}
+
 /// Render a semantic section key using template-provided presentation renderers.
 #let render-section(data, key, heading, renderers) = {
+  let continued = continued-markers(data)
   if key == "summary" {
     render-rich-text-section(data.sections.summary, heading)
+  } else if key in chart-sections and section-display(data, key) != "list" {
+    render-chart-section(data, data.sections.at(key), heading, section-display(data, key))
   } else if key == "profiles" {
-    render-item-section(data.sections.profiles, heading, renderers.profiles)
+    render-item-section(data.sections.profiles, heading, renderers.profiles, continued: continued)
   } else if key == "experience" {
-    render-item-section(data.sections.experience, heading, renderers.experience)
+    render-item-section(data.sections.experience, heading, renderers.experience, continued: continued)
   } else if key == "education" {
-    render-item-section(data.sections.education, heading, renderers.education)
+    render-item-section(data.sections.education, heading, renderers.education, continued: continued)
   } else if key == "awards" {
-    render-item-section(data.sections.awards, heading, renderers.awards)
+    render-item-section(data.sections.awards, heading, renderers.awards, continued: continued)
   } else if key == "certifications" {
-    render-item-section(data.sections.certifications, heading, renderers.certifications)
+    render-item-section(data.sections.certifications, heading, renderers.certifications, continued: continued)
   } else if key == "skills" {
-    render-item-section(data.sections.skills, heading, renderers.skills)
+    render-item-section(data.sections.skills, heading, renderers.skills, continued: continued)
   } else if key == "interests" {
-    render-item-section(data.sections.interests, heading, renderers.interests)
+    render-item-section(data.sections.interests, heading, renderers.interests, continued: continued)
   } else if key == "publications" {
-    render-item-section(data.sections.publications, heading, renderers.publications)
+    render-item-section(data.sections.publications, heading, renderers.publications, continued: continued)
   } else if key == "volunteer" {
-    render-item-section(data.sections.volunteer, heading, renderers.volunteer)
+    render-item-section(data.sections.volunteer, heading, renderers.volunteer, continued: continued)
   } else if key == "languages" {
-    render-item-section(data.sections.languages, heading, renderers.languages)
+    render-item-section(data.sections.languages, heading, renderers.languages, continued: continued)
   } else if key == "projects" {
-    render-item-section(data.sections.projects, heading, renderers.projects)
+    render-item-section(data.sections.projects, heading, renderers.projects, continued: continued)
   } else if key == "references" {
-    render-item-section(data.sections.references, heading, renderers.references)
+    render-item-section(data.sections.references, heading, renderers.references, continued: continued)
   } else if key == "coverLetter" {
     // Rendered as a dedicated page via render-cover-letter-page before the
     // resume body (pagebreaks are not allowed inside layout containers), so
@@ -544,13 +861,13 @@
   } else if key == "custom" and "custom" in data.sections {
     // Layout slot "custom" = render every custom section (order follows JSON object order).
     for (_, section) in data.sections.custom {
-      render-item-section(section, heading, renderers.custom)
+      render-item-section(section, heading, renderers.custom, continued: continued)
     }
   } else if "custom" in data.sections and key in data.sections.custom {
     // Layout may reference a single custom block by its id (e.g. imported React-Resume keys).
     let section = data.sections.custom.at(key)
     if section != none {
-      render-item-section(section, heading, renderers.custom)
+      render-item-section(section, heading, renderers.custom, continued: continued)
     }
   }
 }
@@ -572,12 +889,39 @@
   render-sections(data, layout-all-sections(data), heading, renderers)
 }
 
+/// QR codes for attachments shown as links, under the template's section
+/// heading. The renderer draws each code as an SVG asset at `path`.
+#let render-attachment-links(data, heading) = {
+  let links = data.at("attachments", default: ()).filter(item => item.mode == "qr-code")
+  if links.len() == 0 { return }
+  let title = strings(data).at("attachments", default: default-strings.attachments)
+
+  block(breakable: false, above: 16pt)[
+    #if heading == none { text(weight: "bold", title) } else { heading(title) }
+    #grid(
+      columns: (auto,) * calc.min(links.len(), 4),
+      column-gutter: 16pt,
+      row-gutter: 12pt,
+      ..links.map(item => box(width: 72pt)[
+        #align(center)[
+          #link(item.url, image(item.path, width: 56pt))
+          #v(2pt, weak: true)
+          #text(size: 8pt, item.name)
+        ]
+      ]),
+    )
+  ]
+}
+
 /// Render a resume from shared semantic rules and template-provided presentation.
 #let render-resume(data, config) = {
   let layout = config.layout
   let renderers = config.renderers
   let main-heading = config.at("main-heading", default: config.at("heading", default: none))
-  let sidebar-heading = config.at("sidebar-heading", default: main-heading)
+  let sidebar-heading = styled-heading(data, config.at("sidebar-heading", default: main-heading))
+  let main-heading = styled-heading(data, main-heading)
+  let left-heading = styled-heading(data, config.at("left-heading", default: none))
+  let right-heading = styled-heading(data, config.at("right-heading", default: none))
   let main-fallback = config.at("main-fallback", default: default-main-sections + ("custom",))
   let sidebar-fallback = config.at("sidebar-fallback", default: default-sidebar-sections)
   let before-layout = config.at("before-layout", default: none)
@@ -593,6 +937,7 @@
 
   render-slot(header)
   render-slot(before-layout)
+  [#metadata("body") <rustume-body>]
 
   if layout == "single" {
     render-all-sections(data, main-heading, renderers)
@@ -601,6 +946,7 @@
       sidebar-width: config.at("sidebar-width", default: 170pt),
       sidebar-bg: config.at("sidebar-bg", default: none),
       body-bg: config.at("body-bg", default: none),
+      page-fills: data.metadata.page.at("backgrounds", default: ()).len() > 0,
       sidebar-inset: config.at("sidebar-inset", default: (x: 16pt, y: 24pt)),
       main-inset: config.at("main-inset", default: (x: 24pt, y: 24pt)),
       sidebar-content: sidebar-wrapper([
@@ -622,7 +968,7 @@
           data,
           config.at("left-column", default: 0),
           config.at("left-fallback", default: main-fallback),
-          config.at("left-heading", default: main-heading),
+          if left-heading != none { left-heading } else { main-heading },
           renderers,
         )
       ]),
@@ -632,10 +978,234 @@
           data,
           config.at("right-column", default: 1),
           config.at("right-fallback", default: sidebar-fallback),
-          config.at("right-heading", default: sidebar-heading),
+          if right-heading != none { right-heading } else { sidebar-heading },
           renderers,
         )
       ]),
     )
   }
+
+  // Main-column heading style; two-column templates name it per side
+  let links-heading = if main-heading != none { main-heading } else {
+    if right-heading != none { right-heading } else { left-heading }
+  }
+  if layout == "sidebar-left" or layout == "full-header-sidebar" {
+    let inset = config.at("main-inset", default: (x: 24pt, y: 24pt))
+    pad(x: inset.x, bottom: inset.y, render-attachment-links(data, links-heading))
+  } else {
+    render-attachment-links(data, links-heading)
+  }
+  [#metadata("end") <rustume-end>]
+}
+
+// ── Page chrome ──
+
+/// Resolve one side of `page.margin` to an absolute length. The margin may be
+/// a length, a per-side dictionary, or auto (Typst's default of 2.5cm).
+#let margin-side(margin, side) = {
+  let value = margin
+  if type(margin) == dictionary {
+    let axis = if side == "top" or side == "bottom" { "y" } else { "x" }
+    value = margin.at(side, default: margin.at(axis, default: margin.at("rest", default: 0pt)))
+  }
+  if value == auto {
+    2.5cm
+  } else if type(value) == relative {
+    value.length
+  } else {
+    value
+  }
+}
+
+/// Name and contact line repeated on later pages.
+#let running-contact(data) = {
+  let basics = data.basics
+  let parts = (basics.name, basics.email, basics.phone, basics.location)
+  parts.filter(value => value != "").join("  ·  ")
+}
+
+/// Page number text in the configured `pageNumberStyle`. `label` is the
+/// "labeled" style's text, with `{current}` and `{total}` placeholders.
+#let page-number-label(style, current, total, label: default-strings.page) = {
+  if style == "plain" {
+    str(current)
+  } else if style == "labeled" {
+    label.replace("{current}", str(current)).replace("{total}", str(total))
+  } else {
+    str(current) + " / " + str(total)
+  }
+}
+
+/// Deployment branding for the footer: the logo, then the footer text.
+/// `none` when the deployment sets neither.
+#let brand-footer(data) = {
+  let brand = data.at("brand", default: (:))
+  let logo = brand.at("logo", default: none)
+  let footer = brand.at("footer", default: none)
+  if logo != none or footer != none {
+    box(height: 1.25em, baseline: 0.25em, {
+      if logo != none { image(logo, height: 100%) }
+      if logo != none and footer != none { h(0.5em) }
+      if footer != none { footer }
+    })
+  }
+}
+
+/// Page header or footer (`edge`) for `set page`: the running name and
+/// contact line after the first resume page, page numbers in the footer,
+/// deployment branding in every footer, and the contact QR code on the
+/// first resume page. Empty on single-page resumes without branding and on
+/// full-bleed templates whose margin leaves no room.
+#let page-chrome(data, edge) = context {
+  let options = data.metadata.page.options
+  // Appended attachment pages don't count toward the resume's pages
+  let end = query(<rustume-end>)
+  let total = if end.len() > 0 {
+    counter(page).at(end.first().location()).first()
+  } else {
+    counter(page).final().first()
+  }
+  let side = if edge == "header" { "top" } else { "bottom" }
+
+  if margin-side(page.margin, side) >= 16pt {
+    let body = query(<rustume-body>)
+    let first-page = if body.len() > 0 { body.first().location().page() } else { 1 }
+    let running = if total > 1 and options.at("runningHeader", default: "header") == edge and here().page() > first-page {
+      running-contact(data)
+    }
+    let brand = if edge == "footer" { brand-footer(data) }
+    let start-content = if running != none and brand != none {
+      [#brand#h(1em)#running]
+    } else if brand != none { brand } else { running }
+    let end-content = if total > 1 and edge == "footer" and options.at("pageNumbers", default: true) {
+      page-number-label(
+        options.at("pageNumberStyle", default: "of-total"),
+        counter(page).get().first(),
+        total,
+        label: strings(data).page,
+      )
+    }
+    // The header or footer area is the margin less its 30% ascent or
+    // descent; a code smaller than 24pt is too dense to scan
+    let qr-size = calc.min(margin-side(page.margin, side) * 0.7 - 4pt, 64pt)
+    let qr = if (
+      data.at("contactQr", default: none) != none
+        and options.at("contactQr", default: "hidden") == edge
+        and here().page() == first-page
+        and qr-size >= 24pt
+    ) {
+      image(data.contactQr, width: qr-size, height: qr-size)
+    }
+
+    if start-content != none or end-content != none or qr != none {
+      set text(size: 8pt, fill: resolve-color(data.metadata.theme.text, black).transparentize(40%))
+      grid(
+        columns: (1fr, auto, auto),
+        align: (start + horizon, end + horizon, end + horizon),
+        column-gutter: if end-content != none and qr != none { 1em } else { 0pt },
+        start-content, end-content, qr,
+      )
+    }
+  }
+}
+
+/// Whether a `metadata.page.backgrounds` selector picks resume page `n` of
+/// `total`.
+#let page-selected(pages, n, total) = (
+  pages == "all"
+    or (pages == "first" and n == 1)
+    or (pages == "rest" and n > 1)
+    or (pages == "last" and n == total)
+    or (pages == "odd" and calc.odd(n))
+    or (pages == "even" and calc.even(n))
+)
+
+/// Colors `metadata.page.backgrounds` gives resume page `n` of `total`.
+/// `background` and `sidebar` are `none` where no entry sets them; later
+/// entries win.
+#let page-colors(data, n, total) = {
+  let colors = (background: none, sidebar: none, hide-sidebar: false)
+  for entry in data.metadata.page.at("backgrounds", default: ()) {
+    if page-selected(entry.at("pages", default: "all"), n, total) {
+      if entry.at("background", default: "") != "" {
+        colors.background = rgb(entry.background)
+      }
+      if entry.at("sidebar", default: "") != "" {
+        colors.sidebar = rgb(entry.sidebar)
+        colors.hide-sidebar = false
+      }
+      if entry.at("hideSidebar", default: false) {
+        colors.hide-sidebar = true
+      }
+    }
+  }
+  colors
+}
+
+/// Page background for `set page`: the page and sidebar colors
+/// `metadata.page.backgrounds` gives each resume page. Pages before and
+/// after the resume keep the template's fill. Empty without backgrounds,
+/// when the template's page fill and sidebar grid show as they are.
+#let page-background(data) = context {
+  let backgrounds = data.metadata.page.at("backgrounds", default: ())
+  let body = if backgrounds.len() > 0 { query(<rustume-body>) } else { () }
+  let end = query(<rustume-end>)
+  let current = here().page()
+  if (
+    body.len() > 0
+      and end.len() > 0
+      and current >= body.first().location().page()
+      and current <= end.first().location().page()
+  ) {
+    let first-page = body.first().location().page()
+    let total = end.first().location().page() - first-page + 1
+    let colors = page-colors(data, current - first-page + 1, total)
+    if colors.background != none {
+      place(rect(width: 100%, height: 100%, fill: colors.background))
+    }
+
+    // The sidebar runs down each page the sidebar grid spans, from where
+    // the grid starts to where it ends
+    let start = query(<rustume-sidebar>)
+    let stop = query(<rustume-sidebar-end>)
+    if start.len() > 0 and stop.len() > 0 and not colors.hide-sidebar {
+      let sidebar = start.first().value
+      let fill = if colors.sidebar != none { colors.sidebar } else { sidebar.fill }
+      let from = start.first().location().position()
+      let to = stop.first().location().position()
+      if fill != none and current >= from.page and current <= to.page {
+        let top = if current == from.page { from.y } else { margin-side(page.margin, "top") }
+        let bottom = if current == to.page { to.y } else {
+          page.height - margin-side(page.margin, "bottom")
+        }
+        let x = if data.at("dir", default: "ltr") == "rtl" {
+          page.width - margin-side(page.margin, "right") - sidebar.width
+        } else { from.x }
+        if bottom > top {
+          place(dx: x, dy: top, rect(width: sidebar.width, height: bottom - top, fill: fill))
+        }
+      }
+    }
+  }
+}
+
+// ── Attachments ──
+
+/// Attachments appended after the resume, each page on a page of its own
+/// without header or footer. PDFs come with their page count, since
+/// `image()` draws one page at a time.
+#let render-appended-attachments(data) = {
+  for item in data.at("attachments", default: ()).filter(item => item.mode == "append") {
+    if item.kind == "pdf" {
+      for number in range(1, item.pages + 1) {
+        page(margin: 0pt, header: none, footer: none)[
+          #image(item.path, page: number, width: 100%, height: 100%, fit: "contain")
+        ]
+      }
+    } else {
+      page(header: none, footer: none)[
+        #align(center + horizon, image(item.path, width: 100%, height: 100%, fit: "contain"))
+      ]
+    }
+  }
 }
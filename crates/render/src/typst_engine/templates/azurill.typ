@@ -23,7 +23,7 @@
     v(14pt)
     text(weight: "bold", size: 11pt, fill: primary-color, tracking: 0.06em)[#upper(title)]
     v(2pt)
-    line(length: 100%, stroke: 1.5pt + primary-color)
+    divider-line(data, 1.5pt, primary-color)
     v(8pt)
   }
 
@@ -32,7 +32,7 @@
     v(12pt)
     text(weight: "semibold", size: 9pt, fill: primary-color, tracking: 0.1em)[#upper(title)]
     v(2pt)
-    line(length: 100%, stroke: 0.75pt + primary-color)
+    divider-line(data, 0.75pt, primary-color)
     v(6pt)
   }
 
@@ -42,9 +42,9 @@
     if level-display == "template-default" {
       rating-indicators(level, 14pt, 4pt, primary-color, bar-empty, 2pt, 2pt)
     } else if level-display == "progress-bar" {
-      render-level(level, level-display, primary-color, bar-empty, track-width: 70pt)
+      render-level(level, level-display, primary-color, bar-empty, track-width: 70pt, labels: strings(data).levels)
     } else {
-      render-level(level, level-display, primary-color, bar-empty)
+      render-level(level, level-display, primary-color, bar-empty, labels: strings(data).levels)
     }
   }
 
@@ -59,7 +59,7 @@
         #v(1pt)
         #text(size: 9pt, fill: muted-color)[#item.position]
       ],
-      align(right)[
+      align(end)[
         #text(size: 9pt, fill: muted-color)[#item.date]
         #if item.location != "" {
           v(1pt)
@@ -90,7 +90,7 @@
           text(size: 9pt, fill: muted-color)[#degree]
         }
       ],
-      align(right)[
+      align(end)[
         #text(size: 9pt, fill: muted-color)[#item.date]
       ]
     )
@@ -320,7 +320,7 @@
           text(size: 9pt, fill: muted-color)[#item.position]
         }
       ],
-      align(right)[
+      align(end)[
         #text(size: 9pt, fill: muted-color)[#item.date]
         #if item.location != "" {
           v(1pt)
@@ -419,8 +419,8 @@
   )
 
   set par(
-    leading: 0.65em,
-    justify: false,
+    leading: line-leading(data, 0.65em),
+    justify: par-justify(data, false),
   )
 
   // Cover letter — dedicated page before the resume content
@@ -428,7 +428,8 @@
 
   if has-resume-body(data) {
     // ── Header - centered, above columns ──
-    align(center)[
+    let header-alignment = header-align(data, center)
+    header-with-photo(data, primary-color, alignment: header-alignment, align(header-alignment)[
       #text(size: 26pt, weight: "bold", fill: text-color, tracking: 0.03em)[#data.basics.name]
 
       #if data.basics.headline != "" {
@@ -444,9 +445,10 @@
       #if data.basics.phone != "" { contact-items = contact-items + (data.basics.phone,) }
       #if data.basics.location != "" { contact-items = contact-items + (data.basics.location,) }
       #if has-url(data.basics) { contact-items = contact-items + (link(data.basics.url.href)[#data.basics.url.href],) }
+      #(contact-items = contact-items + build-custom-field-items(data.basics))
 
       #text(size: 9pt, fill: muted-color)[#contact-items.join("  ·  ")]
-    ]
+    ])
 
     v(16pt)
     line(length: 100%, stroke: 1pt + primary-color)
@@ -20,7 +20,7 @@
     v(12pt)
     text(weight: "bold", size: 11pt, fill: primary-color)[#upper(title)]
     v(2pt)
-    line(length: 100%, stroke: 0.5pt + primary-color)
+    divider-line(data, 0.5pt, primary-color)
     v(6pt)
   }
 
@@ -29,7 +29,7 @@
       columns: (1fr, auto),
       column-gutter: 8pt,
       left-content,
-      align(right)[
+      align(end)[
         #text(size: 9pt, fill: muted-color)[#right-content]
       ]
     )
@@ -42,7 +42,7 @@
       rating-indicators(level, 8pt, 8pt, primary-color, bg-color.darken(10%), 2pt, 2pt)
     } else if should-render-level(level, level-display) {
       h(4pt)
-      render-level(level, level-display, primary-color, bg-color.darken(10%), width: 8pt, height: 8pt)
+      render-level(level, level-display, primary-color, bg-color.darken(10%), width: 8pt, height: 8pt, labels: strings(data).levels)
     }
   }
 
@@ -141,8 +141,7 @@
     if item.visible == false { return }
 
     if has-url(item) {
-      let label = if item.username != "" { item.username } else { item.url.href }
-      link(item.url.href)[#text(fill: primary-color)[#label]]
+      link(item.url.href)[#text(fill: primary-color)[#link-label(item, item.username)]]
     } else {
       [#item.network: #item.username]
     }
@@ -353,22 +352,17 @@
   )
 
   set par(
-    leading: 0.65em,
-    justify: true,
+    leading: line-leading(data, 0.65em),
+    justify: par-justify(data, true),
   )
 
   // Cover letter — dedicated page before the resume content
   render-cover-letter-page(data, section-heading, muted: muted-color)
 
   if has-resume-body(data) {
-    // Header - centered with picture area
-    align(center)[
-      // Picture area
-      #if has-visible-picture(data.basics) {
-        render-picture(data.basics, primary-color)
-        v(8pt)
-      }
-
+    // Header - centered, picture above the name unless the options move it
+    let header-alignment = header-align(data, center)
+    header-with-photo(data, primary-color, default: "top", alignment: header-alignment, align(header-alignment)[
       // Name
       #text(size: 24pt, weight: "bold", fill: text-color)[#data.basics.name]
 
@@ -385,9 +379,10 @@
       #if has-url(data.basics) {
         contact-items = contact-items + (link(data.basics.url.href)[#text(fill: primary-color)[#data.basics.url.href]],)
       }
+      #(contact-items = contact-items + build-custom-field-items(data.basics, color: primary-color))
 
       #text(size: 9pt)[#contact-items.join([#h(10pt)#text(fill: muted-color)[|]#h(10pt)])]
-    ]
+    ])
 
     v(8pt)
     line(length: 100%, stroke: 0.5pt + primary-color)
@@ -23,7 +23,7 @@
     v(14pt)
     box(
       width: 100%,
-      stroke: (bottom: 2pt + primary-color),
+      stroke: (bottom: divider-stroke(data, 2pt, primary-color)),
       inset: (bottom: 4pt),
       text(weight: "bold", size: 10pt, fill: primary-color, tracking: 0.06em)[#upper(title)]
     )
@@ -34,7 +34,7 @@
     v(12pt)
     text(weight: "bold", size: 9pt, fill: primary-color, tracking: 0.08em)[#upper(title)]
     v(2pt)
-    line(length: 100%, stroke: 0.5pt + border-color)
+    divider-line(data, 0.5pt, border-color)
     v(6pt)
   }
 
@@ -43,7 +43,7 @@
     if level-display == "template-default" {
       rating-indicators(level, 6pt, 6pt, primary-color, border-color, 50%, 3pt)
     } else {
-      render-level(level, level-display, primary-color, border-color, spacing: 3pt)
+      render-level(level, level-display, primary-color, border-color, spacing: 3pt, labels: strings(data).levels)
     }
   }
 
@@ -58,7 +58,7 @@
         #v(2pt)
         #text(size: 10pt, fill: primary-color)[#item.position]
       ],
-      align(right)[
+      align(end)[
         #text(size: 9pt, fill: muted-color)[#item.date]
         #if item.location != "" {
           v(2pt)
@@ -295,7 +295,7 @@
         #v(2pt)
         #text(size: 10pt, fill: primary-color)[#item.position]
       ],
-      align(right)[
+      align(end)[
         #text(size: 9pt, fill: muted-color)[#item.date]
         #if item.location != "" {
           v(2pt)
@@ -325,8 +325,7 @@
     if item.summary != "" {
       v(4pt)
       box(
-        stroke: (left: 2pt + primary-color),
-        inset: (left: 10pt, y: 2pt),
+        ..start-rule(data, 2pt + primary-color, 10pt),
         render-rich-text(item.summary, size: 9pt, style: "italic", fill: muted-color)
       )
     }
@@ -407,8 +406,8 @@
   )
 
   set par(
-    leading: 0.65em,
-    justify: false,
+    leading: line-leading(data, 0.65em),
+    justify: par-justify(data, false),
   )
 
   // Cover letter — dedicated page before the resume content
@@ -416,22 +415,26 @@
 
   if has-resume-body(data) {
     // Header - above columns, left-aligned
-    text(size: 26pt, weight: "bold", fill: text-color)[#data.basics.name]
+    let header-alignment = header-align(data, left)
+    header-with-photo(data, primary-color, alignment: header-alignment, align(header-alignment, {
+      text(size: 26pt, weight: "bold", fill: text-color)[#data.basics.name]
 
-    if data.basics.headline != "" {
-      v(4pt)
-      text(size: 12pt, fill: primary-color)[#data.basics.headline]
-    }
+      if data.basics.headline != "" {
+        v(4pt)
+        text(size: 12pt, fill: primary-color)[#data.basics.headline]
+      }
 
-    v(10pt)
+      v(10pt)
 
-    // Contact info
-    let contact-items = build-contact-items(data.basics)
-    if has-url(data.basics) { contact-items = contact-items + (link(data.basics.url.href)[#data.basics.url.href],) }
+      // Contact info
+      let contact-items = build-contact-items(data.basics)
+      if has-url(data.basics) { contact-items = contact-items + (link(data.basics.url.href)[#data.basics.url.href],) }
+      contact-items = contact-items + build-custom-field-items(data.basics)
 
-    if contact-items.len() > 0 {
-      text(size: 9pt, fill: muted-color)[#contact-items.join("  |  ")]
-    }
+      if contact-items.len() > 0 {
+        text(size: 9pt, fill: muted-color)[#contact-items.join("  |  ")]
+      }
+    }))
 
     v(16pt)
     line(length: 100%, stroke: 1pt + border-color)
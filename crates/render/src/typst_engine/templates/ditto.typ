@@ -23,7 +23,7 @@
     v(10pt)
     text(weight: "bold", size: 8pt, fill: primary-color, tracking: 0.08em)[#upper(title)]
     v(2pt)
-    line(length: 100%, stroke: 0.5pt + primary-color)
+    divider-line(data, 0.5pt, primary-color)
     v(6pt)
   }
 
@@ -31,7 +31,7 @@
     v(10pt)
     box(
       width: 100%,
-      stroke: (bottom: 1.5pt + primary-color),
+      stroke: (bottom: divider-stroke(data, 1.5pt, primary-color)),
       inset: (bottom: 3pt),
       text(weight: "bold", size: 9pt, fill: primary-color, tracking: 0.06em)[#upper(title)]
     )
@@ -53,7 +53,7 @@
         #v(1pt)
         #text(size: 9pt)[#item.position]
       ],
-      align(right)[
+      align(end)[
         #text(size: 8pt, fill: muted-color)[#item.date]
         #if item.location != "" {
           v(1pt)
@@ -84,7 +84,7 @@
           text(size: 9pt)[#degree]
         }
       ],
-      align(right)[
+      align(end)[
         #text(size: 8pt, fill: muted-color)[#item.date]
       ]
     )
@@ -113,7 +113,7 @@
       rating-indicators(level, 6pt, 6pt, primary-color, bg-color.darken(10%), 50%, 2pt)
     } else if should-render-level(level, level-display) {
       v(2pt)
-      render-level(level, level-display, primary-color, bg-color.darken(10%))
+      render-level(level, level-display, primary-color, bg-color.darken(10%), labels: strings(data).levels)
     }
 
     if has-keywords(item) {
@@ -139,7 +139,7 @@
       rating-indicators(level, 6pt, 6pt, primary-color, bg-color.darken(10%), 50%, 2pt)
     } else if should-render-level(level, level-display) {
       v(2pt)
-      render-level(level, level-display, primary-color, bg-color.darken(10%))
+      render-level(level, level-display, primary-color, bg-color.darken(10%), labels: strings(data).levels)
     }
 
     v(6pt)
@@ -152,7 +152,7 @@
 
     if has-url(item) {
       v(1pt)
-      link(item.url.href)[#text(size: 7pt, fill: primary-color)[#item.username]]
+      link(item.url.href)[#text(size: 7pt, fill: primary-color)[#link-label(item, item.username)]]
     } else {
       v(1pt)
       text(size: 7pt, fill: muted-color)[#item.username]
@@ -405,8 +405,8 @@
   )
 
   set par(
-    leading: 0.6em,
-    justify: false,
+    leading: line-leading(data, 0.6em),
+    justify: par-justify(data, false),
   )
 
   // Cover letter — dedicated page before the resume content
@@ -414,11 +414,12 @@
 
   if has-resume-body(data) {
     // Header - full width teal background bar
+    let header-alignment = header-align(data, left)
     box(
       width: 100%,
       fill: primary-color,
       inset: (x: 24pt, y: 18pt),
-      [
+      header-with-photo(data, primary-color, alignment: header-alignment, align(header-alignment)[
         #text(size: 22pt, weight: "bold", fill: white)[#data.basics.name]
 
         #if data.basics.headline != "" {
@@ -430,9 +431,10 @@
 
         #let contact-items = build-contact-items(data.basics)
         #if has-url(data.basics) { contact-items = contact-items + (link(data.basics.url.href)[#text(fill: white)[#data.basics.url.href]],) }
+        #(contact-items = contact-items + build-custom-field-items(data.basics, color: white))
 
         #text(size: 8pt, fill: primary-color.lighten(85%))[#contact-items.join("  |  ")]
-      ]
+      ])
     )
 
     render-resume(data, (
@@ -23,7 +23,7 @@
     v(12pt)
     text(weight: "bold", size: 9pt, fill: primary-color, tracking: 0.05em)[#upper(title)]
     v(2pt)
-    line(length: 100%, stroke: 1pt + primary-color)
+    divider-line(data, 1pt, primary-color)
     v(8pt)
   }
 
@@ -31,7 +31,7 @@
     v(14pt)
     text(weight: "bold", size: 11pt, fill: text-color, tracking: 0.04em)[#upper(title)]
     v(3pt)
-    line(length: 100%, stroke: 1.5pt + primary-color)
+    divider-line(data, 1.5pt, primary-color)
     v(10pt)
   }
 
@@ -40,7 +40,7 @@
       columns: (1fr, auto),
       column-gutter: 8pt,
       left-content,
-      align(right)[
+      align(end)[
         #text(size: 9pt, fill: muted-color)[#right-content]
       ]
     )
@@ -51,7 +51,7 @@
     if level-display == "template-default" {
       rating-indicators(level, 8pt, 8pt, primary-color, bg-color.darken(10%), 1pt, 2pt)
     } else {
-      render-level(level, level-display, primary-color, bg-color.darken(10%), width: 8pt, height: 8pt)
+      render-level(level, level-display, primary-color, bg-color.darken(10%), width: 8pt, height: 8pt, labels: strings(data).levels)
     }
   }
 
@@ -163,7 +163,7 @@
     text(size: 9pt, weight: "medium", fill: sidebar-text)[#item.network]
     v(1pt)
     if has-url(item) {
-      link(item.url.href)[#text(size: 8pt, fill: primary-color)[#item.username]]
+      link(item.url.href)[#text(size: 8pt, fill: primary-color)[#link-label(item, item.username)]]
     } else {
       text(size: 8pt, fill: muted-color)[#item.username]
     }
@@ -393,8 +393,8 @@
   )
 
   set par(
-    leading: 0.65em,
-    justify: false,
+    leading: line-leading(data, 0.65em),
+    justify: par-justify(data, false),
   )
 
   // Cover letter — dedicated page before the resume content
@@ -406,35 +406,47 @@
       body
     }
 
-    let sidebar-before = () => [
-      // Header: Name, headline, contact info
-      #text(size: 18pt, weight: "bold", fill: sidebar-text)[#data.basics.name]
-
-      #if data.basics.headline != "" {
-        v(6pt)
-        text(size: 9pt, fill: muted-color)[#data.basics.headline]
-      }
+    // Narrow sidebar: the photo can only go above the name
+    let header-alignment = header-align(data, left)
+    let sidebar-before = () => header-with-photo(
+      data,
+      primary-color,
+      positions: ("top", "hidden"),
+      alignment: header-alignment,
+      align(header-alignment)[
+        // Header: Name, headline, contact info
+        #text(size: 18pt, weight: "bold", fill: sidebar-text)[#data.basics.name]
+
+        #if data.basics.headline != "" {
+          v(6pt)
+          text(size: 9pt, fill: muted-color)[#data.basics.headline]
+        }
 
-      #v(12pt)
+        #v(12pt)
 
-      // Contact info
-      #if data.basics.email != "" {
-        text(size: 8pt, fill: sidebar-text)[#data.basics.email]
-        v(4pt)
-      }
-      #if data.basics.phone != "" {
-        text(size: 8pt, fill: sidebar-text)[#data.basics.phone]
-        v(4pt)
-      }
-      #if data.basics.location != "" {
-        text(size: 8pt, fill: sidebar-text)[#data.basics.location]
-        v(4pt)
-      }
-      #if has-url(data.basics) {
-        link(data.basics.url.href)[#text(size: 8pt, fill: primary-color)[#data.basics.url.href]]
-        v(4pt)
-      }
-    ]
+        // Contact info
+        #if data.basics.email != "" {
+          link("mailto:" + data.basics.email)[#text(size: 8pt, fill: sidebar-text)[#data.basics.email]]
+          v(4pt)
+        }
+        #if data.basics.phone != "" {
+          text(size: 8pt, fill: sidebar-text)[#data.basics.phone]
+          v(4pt)
+        }
+        #if data.basics.location != "" {
+          text(size: 8pt, fill: sidebar-text)[#data.basics.location]
+          v(4pt)
+        }
+        #if has-url(data.basics) {
+          link(data.basics.url.href)[#text(size: 8pt, fill: primary-color)[#data.basics.url.href]]
+          v(4pt)
+        }
+        #for item in build-custom-field-items(data.basics, color: primary-color) {
+          text(size: 8pt, fill: sidebar-text)[#item]
+          v(4pt)
+        }
+      ]
+    )
 
     render-resume(data, (
       layout: "sidebar-left",
@@ -23,7 +23,7 @@
     v(14pt)
     text(weight: "bold", size: 11pt, fill: primary-color)[#title]
     v(3pt)
-    line(length: 100%, stroke: 1pt + primary-color)
+    divider-line(data, 1pt, primary-color)
     v(8pt)
   }
 
@@ -31,7 +31,7 @@
     v(12pt)
     text(weight: "bold", size: 10pt, fill: primary-color)[#title]
     v(2pt)
-    line(length: 100%, stroke: 0.75pt + primary-color)
+    divider-line(data, 0.75pt, primary-color)
     v(6pt)
   }
 
@@ -40,7 +40,7 @@
     if level-display == "template-default" {
       rating-indicators(level, 6pt, 6pt, primary-color, primary-color.lighten(70%), 50%, 2pt)
     } else {
-      render-level(level, level-display, primary-color, primary-color.lighten(70%))
+      render-level(level, level-display, primary-color, primary-color.lighten(70%), labels: strings(data).levels)
     }
   }
 
@@ -57,7 +57,7 @@
           text(size: 10pt)[#item.position]
         }
       ],
-      align(right)[
+      align(end)[
         #text(size: 9pt, fill: muted-color)[#item.date]
         #if item.location != "" {
           v(1pt)
@@ -88,7 +88,7 @@
           text(size: 10pt)[#degree]
         }
       ],
-      align(right)[
+      align(end)[
         #text(size: 9pt, fill: muted-color)[#item.date]
       ]
     )
@@ -157,7 +157,7 @@
 
     if has-url(item) {
       v(1pt)
-      link(item.url.href)[#text(size: 8pt, fill: primary-color)[#item.username]]
+      link(item.url.href)[#text(size: 8pt, fill: primary-color)[#link-label(item, item.username)]]
     } else {
       v(1pt)
       text(size: 8pt)[#item.username]
@@ -388,43 +388,55 @@
   )
 
   set par(
-    leading: 0.65em,
-    justify: true,
+    leading: line-leading(data, 0.65em),
+    justify: par-justify(data, true),
   )
 
   // Cover letter — dedicated page before the resume content
   render-cover-letter-page(data, section-heading, muted: muted-color, inset: (x: 24pt, y: 24pt))
 
   if has-resume-body(data) {
-    let sidebar-before = () => [
-      // Header: Name, headline, contact info
-      #text(size: 18pt, weight: "bold", fill: text-color)[#data.basics.name]
-
-      #if data.basics.headline != "" {
-        v(6pt)
-        text(size: 9pt, style: "italic", fill: muted-color)[#data.basics.headline]
-      }
-
-      #v(12pt)
-
-      // Contact info stacked vertically
-      #if data.basics.email != "" {
-        text(size: 8pt, fill: text-color)[#data.basics.email]
-        v(3pt)
-      }
-      #if data.basics.phone != "" {
-        text(size: 8pt, fill: text-color)[#data.basics.phone]
-        v(3pt)
-      }
-      #if data.basics.location != "" {
-        text(size: 8pt, fill: text-color)[#data.basics.location]
-        v(3pt)
-      }
-      #if has-url(data.basics) {
-        link(data.basics.url.href)[#text(size: 8pt, fill: primary-color)[#data.basics.url.href]]
-        v(3pt)
-      }
-    ]
+    // Narrow sidebar: the photo can only go above the name
+    let header-alignment = header-align(data, left)
+    let sidebar-before = () => header-with-photo(
+      data,
+      primary-color,
+      positions: ("top", "hidden"),
+      alignment: header-alignment,
+      align(header-alignment)[
+        // Header: Name, headline, contact info
+        #text(size: 18pt, weight: "bold", fill: text-color)[#data.basics.name]
+
+        #if data.basics.headline != "" {
+          v(6pt)
+          text(size: 9pt, style: "italic", fill: muted-color)[#data.basics.headline]
+        }
+
+        #v(12pt)
+
+        // Contact info stacked vertically
+        #if data.basics.email != "" {
+          link("mailto:" + data.basics.email)[#text(size: 8pt, fill: text-color)[#data.basics.email]]
+          v(3pt)
+        }
+        #if data.basics.phone != "" {
+          text(size: 8pt, fill: text-color)[#data.basics.phone]
+          v(3pt)
+        }
+        #if data.basics.location != "" {
+          text(size: 8pt, fill: text-color)[#data.basics.location]
+          v(3pt)
+        }
+        #if has-url(data.basics) {
+          link(data.basics.url.href)[#text(size: 8pt, fill: primary-color)[#data.basics.url.href]]
+          v(3pt)
+        }
+        #for item in build-custom-field-items(data.basics, color: primary-color) {
+          text(size: 8pt, fill: text-color)[#item]
+          v(3pt)
+        }
+      ]
+    )
 
     render-resume(data, (
       layout: "sidebar-left",
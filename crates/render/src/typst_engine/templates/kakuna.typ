@@ -25,7 +25,7 @@
       columns: (auto, 1fr),
       column-gutter: 10pt,
       text(weight: "semibold", size: 10pt, fill: primary-color, tracking: 0.06em)[#upper(title)],
-      line(start: (0pt, 5pt), length: 100%, stroke: 0.75pt + primary-color)
+      divider-line(data, 0.75pt, primary-color, start: (0pt, 5pt))
     )
     v(10pt)
   }
@@ -37,7 +37,7 @@
       rating-indicators(level, 8pt, 8pt, primary-color, bg-color.darken(10%), 50%, 2pt)
     } else if should-render-level(level, level-display) {
       h(4pt)
-      render-level(level, level-display, primary-color, bg-color.darken(10%), width: 8pt, height: 8pt)
+      render-level(level, level-display, primary-color, bg-color.darken(10%), width: 8pt, height: 8pt, labels: strings(data).levels)
     }
   }
 
@@ -46,7 +46,7 @@
       columns: (1fr, auto),
       column-gutter: 12pt,
       left-content,
-      align(right, text(size: 9pt, fill: muted-color)[#right-content])
+      align(end, text(size: 9pt, fill: muted-color)[#right-content])
     )
   }
 
@@ -377,8 +377,8 @@
   )
 
   set par(
-    leading: 0.7em,
-    justify: false,
+    leading: line-leading(data, 0.7em),
+    justify: par-justify(data, false),
   )
 
   // Cover letter — dedicated page before the resume content
@@ -386,13 +386,14 @@
 
   if has-resume-body(data) {
     // Centered header in bordered box
-    align(center)[
+    let header-alignment = header-align(data, center)
+    align(header-alignment)[
       #box(
         width: 100%,
         stroke: 1pt + border-color,
         radius: 4pt,
         inset: (x: 24pt, y: 20pt),
-        [
+        header-with-photo(data, primary-color, alignment: header-alignment)[
           #text(size: 24pt, weight: "light", fill: text-color, tracking: 0.03em)[#data.basics.name]
 
           #if data.basics.headline != "" {
@@ -404,6 +405,7 @@
 
           #let contact-items = build-contact-items(data.basics)
           #if has-url(data.basics) { contact-items = contact-items + (link(data.basics.url.href)[#data.basics.url.href],) }
+          #(contact-items = contact-items + build-custom-field-items(data.basics))
 
           #text(size: 9pt, fill: muted-color)[#contact-items.join("  ·  ")]
         ]
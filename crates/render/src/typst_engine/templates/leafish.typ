@@ -25,7 +25,7 @@
     v(10pt)
     box(
       width: 100%,
-      stroke: (bottom: 1.5pt + primary-color),
+      stroke: (bottom: divider-stroke(data, 1.5pt, primary-color)),
       inset: (bottom: 4pt),
       text(weight: "bold", size: 9pt, fill: primary-color, tracking: 0.06em)[#upper(title)]
     )
@@ -37,7 +37,7 @@
     if level-display == "template-default" {
       rating-indicators(level, 6pt, 6pt, primary-color, bg-color.darken(10%), 50%, 2pt)
     } else {
-      render-level(level, level-display, primary-color, bg-color.darken(10%))
+      render-level(level, level-display, primary-color, bg-color.darken(10%), labels: strings(data).levels)
     }
   }
 
@@ -58,7 +58,7 @@
           text(size: 9.5pt)[#item.position]
         }
       ],
-      align(right)[
+      align(end)[
         #text(size: 8.5pt, fill: muted-color)[#item.date]
         #if item.location != "" {
           v(1pt)
@@ -89,7 +89,7 @@
           text(size: 9.5pt)[#degree]
         }
       ],
-      align(right)[
+      align(end)[
         #text(size: 8.5pt, fill: muted-color)[#item.date]
       ]
     )
@@ -164,7 +164,7 @@
     text(size: 9pt, weight: "medium", fill: muted-color)[#item.network]
     h(4pt)
     if has-url(item) {
-      link(item.url.href)[#text(size: 9pt, fill: primary-color)[#item.username]]
+      link(item.url.href)[#text(size: 9pt, fill: primary-color)[#link-label(item, item.username)]]
     } else {
       text(size: 9pt)[#item.username]
     }
@@ -313,7 +313,7 @@
           text(size: 9.5pt)[#item.position]
         }
       ],
-      align(right)[
+      align(end)[
         #text(size: 8.5pt, fill: muted-color)[#item.date]
         #if item.location != "" {
           v(1pt)
@@ -416,8 +416,8 @@
   )
 
   set par(
-    leading: 0.65em,
-    justify: false,
+    leading: line-leading(data, 0.65em),
+    justify: par-justify(data, false),
   )
 
   // Cover letter — dedicated page before the resume content
@@ -432,7 +432,7 @@
       fill: header-bg,
       radius: (top: 6pt),
       inset: (x: 20pt, y: 14pt),
-      grid(
+      header-with-photo(data, primary-color, grid(
         columns: (1fr, auto),
         column-gutter: 16pt,
         [
@@ -442,12 +442,12 @@
             text(size: 11pt, fill: header-text-color)[#data.basics.headline]
           }
         ],
-        align(right + horizon)[
+        align(end + horizon)[
           #if has-url(data.basics) {
             link(data.basics.url.href)[#text(size: 9pt, fill: primary-color)[#data.basics.url.href]]
           }
         ]
-      )
+      ))
     )
 
     // Tier 2: Darker contact bar
@@ -457,7 +457,7 @@
       radius: (bottom: 6pt),
       inset: (x: 20pt, y: 8pt),
       {
-        let contact-items = build-contact-items(data.basics)
+        let contact-items = build-contact-items(data.basics) + build-custom-field-items(data.basics)
 
         text(size: 9pt, fill: white)[#contact-items.join([#h(8pt)#text(fill: separator-color)[|]#h(8pt)])]
       }
@@ -24,7 +24,7 @@
       columns: (auto, 1fr),
       column-gutter: 12pt,
       text(weight: "bold", size: 11pt, fill: primary-color)[#title],
-      line(start: (0pt, 6pt), length: 100%, stroke: 1pt + border-color)
+      divider-line(data, 1pt, border-color, start: (0pt, 6pt))
     )
     v(10pt)
   }
@@ -106,7 +106,7 @@
           }
         } else if should-render-level(level, level-display) {
           h(4pt)
-          render-level(level, level-display, primary-color, border-color)
+          render-level(level, level-display, primary-color, border-color, labels: strings(data).levels)
         }
       ]
     )
@@ -356,8 +356,8 @@
   )
 
   set par(
-    leading: 0.65em,
-    justify: true,
+    leading: line-leading(data, 0.65em),
+    justify: par-justify(data, true),
   )
 
   // Cover letter — dedicated page before the resume content
@@ -365,11 +365,12 @@
 
   if has-resume-body(data) {
     // Header with blue accent bar
+    let header-alignment = header-align(data, left)
     box(
       width: 100%,
       stroke: (bottom: 3pt + primary-color),
       inset: (bottom: 12pt),
-      [
+      header-with-photo(data, primary-color, alignment: header-alignment, align(header-alignment)[
         #text(size: 28pt, weight: "bold", fill: primary-color)[#data.basics.name]
 
         #if data.basics.headline != "" {
@@ -393,9 +394,10 @@
         #if has-url(data.basics) {
           contact-parts.push([🔗 #link(data.basics.url.href)[Portfolio]])
         }
+        #(contact-parts = contact-parts + build-custom-field-items(data.basics, glyphs: true))
 
         #text(size: 9pt)[#contact-parts.join("    ")]
-      ]
+      ])
     )
 
     v(8pt)
@@ -19,7 +19,7 @@
     v(14pt)
     box(
       width: 100%,
-      stroke: (bottom: 1.5pt + primary-color),
+      stroke: (bottom: divider-stroke(data, 1.5pt, primary-color)),
       inset: (bottom: 4pt),
       text(weight: "bold", size: 10pt, fill: primary-color, tracking: 0.06em)[#upper(title)]
     )
@@ -31,7 +31,7 @@
       columns: (1fr, auto),
       column-gutter: 8pt,
       left-content,
-      align(right)[
+      align(end)[
         #text(size: 9pt, fill: muted-color)[#right-content]
       ]
     )
@@ -42,7 +42,7 @@
     if level-display == "template-default" {
       rating-indicators(level, 8pt, 8pt, primary-color, bg-color.darken(10%), 0pt, 2pt)
     } else {
-      render-level(level, level-display, primary-color, bg-color.darken(10%), width: 8pt, height: 8pt)
+      render-level(level, level-display, primary-color, bg-color.darken(10%), width: 8pt, height: 8pt, labels: strings(data).levels)
     }
   }
 
@@ -152,7 +152,7 @@
     if item.visible == false { return }
 
     if has-url(item) {
-      link(item.url.href)[#text(fill: primary-color)[#item.network]]
+      link(item.url.href)[#text(fill: primary-color)[#link-label(item, item.network)]]
     } else {
       text(size: 10pt, fill: text-color)[#item.network: #item.username]
     }
@@ -382,8 +382,8 @@
   )
 
   set par(
-    leading: 0.65em,
-    justify: false,
+    leading: line-leading(data, 0.65em),
+    justify: par-justify(data, false),
   )
 
   // Cover letter — dedicated page before the resume content
@@ -391,7 +391,7 @@
 
   if has-resume-body(data) {
     // Header - horizontal flex: name/headline left, contact info stacked right
-    grid(
+    header-with-photo(data, primary-color, grid(
       columns: (1fr, auto),
       column-gutter: 16pt,
       [
@@ -402,16 +402,17 @@
           text(size: 12pt, fill: primary-color)[#data.basics.headline]
         }
       ],
-      align(right)[
+      align(end)[
         #let contact-items = build-contact-items(data.basics)
         #if has-url(data.basics) { contact-items = contact-items + (link(data.basics.url.href)[#text(fill: primary-color)[#data.basics.url.href]],) }
+        #(contact-items = contact-items + build-custom-field-items(data.basics, color: primary-color))
 
         #for item in contact-items {
           text(size: 9pt, fill: muted-color)[#item]
           v(2pt)
         }
       ]
-    )
+    ))
 
     v(8pt)
     line(length: 100%, stroke: 1.5pt + primary-color)
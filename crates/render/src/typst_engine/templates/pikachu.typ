@@ -41,7 +41,7 @@
     if level-display == "template-default" {
       rating-indicators(level, 6pt, 6pt, primary-color, sidebar-bg.darken(15%), 50%, 3pt)
     } else {
-      render-level(level, level-display, primary-color, sidebar-bg.darken(15%), spacing: 3pt)
+      render-level(level, level-display, primary-color, sidebar-bg.darken(15%), spacing: 3pt, labels: strings(data).levels)
     }
   }
 
@@ -121,7 +121,7 @@
 
     if has-url(item) {
       link(item.url.href)[
-        #text(size: 9pt, fill: sidebar-text-color)[#item.network]
+        #text(size: 9pt, fill: sidebar-text-color)[#link-label(item, item.network)]
       ]
     } else {
       text(size: 9pt)[#item.network]
@@ -231,8 +231,7 @@
     if item.summary != "" {
       v(6pt)
       box(
-        stroke: (left: 2pt + primary-color),
-        inset: (left: 10pt, y: 2pt),
+        ..start-rule(data, 2pt + primary-color, 10pt),
         render-rich-text(item.summary, size: 9pt, style: "italic", fill: muted-color)
       )
     }
@@ -306,8 +305,8 @@
   )
 
   set par(
-    leading: 0.65em,
-    justify: false,
+    leading: line-leading(data, 0.65em),
+    justify: par-justify(data, false),
   )
 
   // Cover letter — dedicated page before the resume content
@@ -319,33 +318,42 @@
       body
     }
 
+    // The photo replaces the initials when the options put it on top
+    let show-photo = (
+      template-option(data, "photo", "hidden") == "top" and has-visible-picture(data.basics)
+    )
+
     let sidebar-before = () => [
-      // Profile photo placeholder (initials)
-      #align(center)[
-        #box(
-          width: 80pt,
-          height: 80pt,
-          fill: primary-color,
-          radius: 50%,
-          [
-            #align(center + horizon)[
-              #text(size: 28pt, weight: "bold", fill: white)[
-                #let parts = data.basics.name.split(" ").filter(w => w.len() > 0)
-                #let initials = if parts.len() > 0 { parts.map(w => w.at(0, default: "")).join("") } else { "" }
-                #initials
+      #if show-photo {
+        align(center, render-picture(data.basics, primary-color, default-size: 80pt))
+      } else {
+        // Profile photo placeholder (initials)
+        align(center)[
+          #box(
+            width: 80pt,
+            height: 80pt,
+            fill: primary-color,
+            radius: 50%,
+            [
+              #align(center + horizon)[
+                #text(size: 28pt, weight: "bold", fill: white)[
+                  #let parts = data.basics.name.split(" ").filter(w => w.len() > 0)
+                  #let initials = if parts.len() > 0 { parts.map(w => w.at(0, default: "")).join("") } else { "" }
+                  #initials
+                ]
               ]
             ]
-          ]
-        )
-      ]
+          )
+        ]
+      }
 
       #v(16pt)
 
       // Contact
-      #sidebar-section("Contact")
+      #sidebar-section(strings(data).contact)
 
       #if data.basics.email != "" {
-        text(size: 9pt)[✉ #data.basics.email]
+        text(size: 9pt)[✉ #link("mailto:" + data.basics.email)[#data.basics.email]]
         v(4pt)
       }
 
@@ -363,9 +371,14 @@
         text(size: 9pt)[🔗 #link(data.basics.url.href)[Website]]
         v(4pt)
       }
+
+      #for item in build-custom-field-items(data.basics, glyphs: true) {
+        text(size: 9pt)[#item]
+        v(4pt)
+      }
     ]
 
-    let main-before = () => [
+    let main-before = () => align(header-align(data, left))[
       // Name and headline
       #text(size: 26pt, weight: "bold")[#data.basics.name]
 
@@ -20,7 +20,7 @@
     v(12pt)
     text(weight: "bold", size: 11pt, fill: primary-color)[#upper(title)]
     v(2pt)
-    line(length: 100%, stroke: 0.5pt + primary-color)
+    divider-line(data, 0.5pt, primary-color)
     v(6pt)
   }
 
@@ -29,7 +29,7 @@
       columns: (1fr, auto),
       column-gutter: 8pt,
       left-content,
-      align(right)[
+      align(end)[
         #text(size: 9pt, fill: muted-color)[#right-content]
       ]
     )
@@ -42,7 +42,7 @@
       rating-indicators(level, 8pt, 8pt, primary-color, bg-color.darken(10%), 2pt, 2pt)
     } else if should-render-level(level, level-display) {
       h(4pt)
-      render-level(level, level-display, primary-color, bg-color.darken(10%), width: 8pt, height: 8pt)
+      render-level(level, level-display, primary-color, bg-color.darken(10%), width: 8pt, height: 8pt, labels: strings(data).levels)
     }
   }
 
@@ -136,8 +136,7 @@
     if item.visible == false { return }
 
     if has-url(item) {
-      let label = if item.username != "" { item.username } else { item.url.href }
-      link(item.url.href)[#label]
+      link(item.url.href)[#link-label(item, item.username)]
     } else {
       [#item.network: #item.username]
     }
@@ -348,16 +347,16 @@
   )
 
   set par(
-    leading: 0.65em,
-    justify: true,
+    leading: line-leading(data, 0.65em),
+    justify: par-justify(data, true),
   )
 
   // Cover letter — dedicated page before the resume content
   render-cover-letter-page(data, section-heading, muted: muted-color)
 
   if has-resume-body(data) {
-    // Header - horizontal layout
-    grid(
+    // Header - horizontal layout, with the photo where the options put it
+    header-with-photo(data, primary-color, grid(
       columns: (1fr, auto),
       column-gutter: 16pt,
       [
@@ -368,19 +367,20 @@
           text(size: 12pt)[#data.basics.headline]
         }
       ],
-      align(right)[
+      align(end)[
         #let contact-items = ()
         #if data.basics.location != "" { contact-items = contact-items + (data.basics.location,) }
         #if data.basics.phone != "" { contact-items = contact-items + (data.basics.phone,) }
-        #if data.basics.email != "" { contact-items = contact-items + (data.basics.email,) }
+        #if data.basics.email != "" { contact-items = contact-items + (link("mailto:" + data.basics.email)[#data.basics.email],) }
         #if has-url(data.basics) { contact-items = contact-items + (link(data.basics.url.href)[#data.basics.url.href],) }
+        #(contact-items = contact-items + build-custom-field-items(data.basics))
 
         #for item in contact-items {
           text(size: 9pt)[#item]
           v(2pt)
         }
       ]
-    )
+    ))
 
     v(8pt)
     line(length: 100%, stroke: 0.5pt + primary-color)
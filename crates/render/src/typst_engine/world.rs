@@ -10,18 +10,47 @@
 //! containing `<name>.typ` files. Override files are resolved on each render
 //! (no server restart required); names not present in the override directory
 //! fall back to the embedded copy. WASM builds use embedded templates only.
+//! With the `dev` feature, a directory set by `set_dev_templates_dir` takes
+//! precedence.
+//!
+//! ## Sandbox
+//!
+//! Typst code sees nothing but this world: the main source, templates by
+//! name, and the sources and files registered with it. Package imports and
+//! other paths fail, and every access fails once the compilation's
+//! [`SandboxLimits`] are spent. See [`super::sandbox`].
+//!
+//! ## Caching across compilations
+//!
+//! A world is built per render, but the expensive parts are shared between
+//! them: the standard library and font book are process-wide, and parsed
+//! sources live in caches that are updated with [`Source::replace`], which
+//! reparses only the edited region. Templates are cached by path, and the
+//! generated main source by the template it renders, so concurrent renders
+//! with different templates don't keep replacing each other's source.
+//! Stable hashes for unchanged inputs let Typst's comemo cache reuse layout
+//! work from earlier renders, which is what keeps repeat renders of a live
+//! preview fast.
+//!
+//! ## Fonts
+//!
+//! Fonts bundled with Typst are always in memory. System fonts are indexed
+//! once at startup but only read when a document first uses them, so large
+//! fallback fonts such as Noto Sans CJK cost nothing until a resume needs
+//! them. See [`super::fonts`] for how those fallbacks are picked.
 
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::sync::{Mutex, OnceLock};
 
+use super::sandbox::{Budget, SandboxLimits};
 use crate::traits::RenderError;
 use chrono::Datelike;
 use include_dir::{include_dir, Dir};
-use typst::diag::{FileError, FileResult};
+use typst::diag::{FileError, FileResult, PackageError};
 use typst::foundations::{Bytes, Datetime, Duration};
 use typst::syntax::{FileId, RootedPath, Source, VirtualPath, VirtualRoot};
-use typst::text::{Font, FontBook};
+use typst::text::{Font, FontBook, FontInfo};
 use typst::utils::LazyHash;
 use typst::{Library, LibraryExt};
 
@@ -36,7 +65,103 @@ static EMBEDDED_TEMPLATES: OnceLock<HashMap<String, String>> = OnceLock::new();
 static TEST_TEMPLATES_OVERRIDE: Mutex<Option<PathBuf>> = Mutex::new(None);
 
 /// Shared font cache to avoid duplicate font loading
-static FONTS_CACHE: OnceLock<(FontBook, Vec<Font>)> = OnceLock::new();
+static FONTS_CACHE: OnceLock<(FontBook, Vec<FontSlot>)> = OnceLock::new();
+
+/// A font known to the book, loaded on first use when it lives on disk.
+struct FontSlot {
+    /// File and collection index to load from; `None` for bundled fonts.
+    source: Option<(PathBuf, u32)>,
+    font: OnceLock<Option<Font>>,
+}
+
+impl FontSlot {
+    fn loaded(font: Font) -> Self {
+        Self {
+            source: None,
+            font: OnceLock::from(Some(font)),
+        }
+    }
+
+    fn get(&self) -> Option<Font> {
+        self.font
+            .get_or_init(|| {
+                let (path, index) = self.source.as_ref()?;
+                let data = std::fs::read(path).ok()?;
+                Font::new(Bytes::new(data), *index)
+            })
+            .clone()
+    }
+}
+
+/// Standard library shared by every world.
+static LIBRARY: OnceLock<LazyHash<Library>> = OnceLock::new();
+
+/// Font book shared by every world.
+static BOOK: OnceLock<LazyHash<FontBook>> = OnceLock::new();
+
+/// Parsed template sources, reused across compilations.
+static TEMPLATE_SOURCES: OnceLock<Mutex<HashMap<FileId, Source>>> = OnceLock::new();
+
+/// Most templates [`MAIN_SOURCES`] keeps a main source for.
+const MAX_MAIN_SOURCES: usize = 32;
+
+/// The last main source compiled with each template, least recently used
+/// first. Generated sources for one template differ little between renders,
+/// so diffing against one beats a full parse.
+static MAIN_SOURCES: Mutex<Vec<(String, Source)>> = Mutex::new(Vec::new());
+
+/// Return a source for `id` with `text`, updating `cached` in place when it
+/// already holds a version of the file.
+fn reuse_source(cached: Option<&mut Source>, id: FileId, text: String) -> Source {
+    match cached {
+        Some(source) if source.id() == id => {
+            if source.text() != text {
+                source.replace(&text);
+            }
+            source.clone()
+        }
+        _ => Source::new(id, text),
+    }
+}
+
+/// Parsed main source for `text`, incrementally reparsed from the last one
+/// compiled with `template`.
+///
+/// The cached source is taken out while it is reparsed, so concurrent renders
+/// never wait on each other's parse; one that finds its template's source
+/// taken parses from scratch.
+fn main_source(template: &str, id: FileId, text: String) -> Source {
+    let lock = || {
+        MAIN_SOURCES
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    };
+    let mut cached = {
+        let mut cache = lock();
+        let index = cache.iter().position(|(key, _)| key == template);
+        index.map(|index| cache.remove(index).1)
+    };
+    let source = reuse_source(cached.as_mut(), id, text);
+
+    let mut cache = lock();
+    cache.retain(|(key, _)| key != template);
+    if cache.len() >= MAX_MAIN_SOURCES {
+        cache.remove(0);
+    }
+    cache.push((template.to_string(), source.clone()));
+    source
+}
+
+/// Parsed template source for `text`, from the cache when unchanged.
+fn template_source(id: FileId, text: String) -> Source {
+    let mut cache = TEMPLATE_SOURCES
+        .get_or_init(|| Mutex::new(HashMap::new()))
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    let source = reuse_source(cache.get_mut(&id), id, text);
+    cache.insert(id, source.clone());
+    source
+}
 
 /// Return the cached map of embedded template name → content.
 fn embedded_templates() -> &'static HashMap<String, String> {
@@ -68,6 +193,10 @@ fn templates_override_dir() -> Option<PathBuf> {
     #[cfg(not(test))]
     #[cfg(not(target_arch = "wasm32"))]
     {
+        #[cfg(feature = "dev")]
+        if let Some(dir) = super::dev_templates::dev_templates_dir() {
+            return Some(dir);
+        }
         std::env::var_os("RUSTUME_TEMPLATES_DIR").map(PathBuf::from)
     }
 
@@ -131,6 +260,15 @@ fn resolve_template_content(name: &str) -> Result<String, RenderError> {
         .ok_or_else(|| RenderError::TemplateNotFound(name.to_string()))
 }
 
+/// Whether `name` can name a template file: letters, digits, `_`, and `-`,
+/// so it can't reach outside the override directory on any platform.
+fn is_template_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
 /// Set an injectable templates override directory for unit tests.
 #[cfg(test)]
 pub(crate) fn set_test_templates_override(dir: Option<PathBuf>) {
@@ -141,15 +279,14 @@ pub(crate) fn set_test_templates_override(dir: Option<PathBuf>) {
 pub struct RustumeWorld {
     /// The main source file.
     main: Source,
-    /// The standard library.
-    library: OnceLock<LazyHash<Library>>,
-    /// The font book.
-    book: OnceLock<LazyHash<FontBook>>,
-    /// Lazily resolved template sources (override errors only fail when that
-    /// template is actually requested by Typst).
+    /// Template sources resolved during this compilation, so each file reads
+    /// the same for the whole compile. Resolved lazily: override errors only
+    /// fail when that template is actually requested by Typst.
     sources: Mutex<HashMap<FileId, Source>>,
     /// In-memory binary files (e.g. a decoded data-URL profile picture).
     binary_files: HashMap<FileId, Bytes>,
+    /// What this compilation may still spend.
+    budget: Budget,
 }
 
 /// Build a project-root [`FileId`] for a virtual path string.
@@ -166,15 +303,39 @@ impl RustumeWorld {
     /// broken override for an unused template cannot block rendering another.
     pub fn new(main_content: String) -> Result<Self, RenderError> {
         let main_id = project_file_id("main.typ")?;
-        let main = Source::new(main_id, main_content);
+        Ok(Self::with_main(Source::new(main_id, main_content)))
+    }
+
+    /// Create a world like [`RustumeWorld::new`], reparsing the main source
+    /// incrementally from the last one compiled with `template`.
+    pub fn for_template(template: &str, main_content: String) -> Result<Self, RenderError> {
+        let main_id = project_file_id("main.typ")?;
+        Ok(Self::with_main(main_source(
+            template,
+            main_id,
+            main_content,
+        )))
+    }
 
-        Ok(Self {
+    /// A world around an already parsed `main` source.
+    fn with_main(main: Source) -> Self {
+        Self {
             main,
-            library: OnceLock::new(),
-            book: OnceLock::new(),
             sources: Mutex::new(HashMap::new()),
             binary_files: HashMap::new(),
-        })
+            budget: Budget::new(SandboxLimits::default()),
+        }
+    }
+
+    /// Compile within `limits` instead of the default ones.
+    pub fn with_sandbox(mut self, limits: SandboxLimits) -> Self {
+        self.budget = Budget::new(limits);
+        self
+    }
+
+    /// What this world's compilation may still spend.
+    pub(super) fn budget(&self) -> &Budget {
+        &self.budget
     }
 
     /// Register an in-memory binary file at a project-root virtual path.
@@ -184,20 +345,50 @@ impl RustumeWorld {
         Ok(())
     }
 
+    /// Register an in-memory Typst source at a project-root virtual path,
+    /// read in place of a template of the same name.
+    pub fn add_source(&mut self, path: &str, text: String) -> Result<(), RenderError> {
+        self.budget
+            .check_source(path, text.len())
+            .map_err(RenderError::LimitExceeded)?;
+        let id = project_file_id(path)?;
+        self.sources
+            .get_mut()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .insert(id, Source::new(id, text));
+        Ok(())
+    }
+
     /// Resolve `templates/<name>.typ` from an override dir or embedded defaults.
-    fn load_template_source(id: FileId) -> FileResult<Source> {
+    fn load_template_source(&self, id: FileId) -> FileResult<Source> {
         let path_str = id.vpath().get_without_slash();
         let Some(name) = path_str
             .strip_prefix("templates/")
             .and_then(|rest| rest.strip_suffix(".typ"))
-            .filter(|name| !name.is_empty() && !name.contains('/'))
+            .filter(|name| is_template_name(name))
         else {
             return Err(FileError::NotFound(PathBuf::from(path_str)));
         };
 
         let content = resolve_template_content(name)
             .map_err(|err| FileError::Other(Some(err.to_string().into())))?;
-        Ok(Source::new(id, content))
+        self.budget
+            .check_source(path_str, content.len())
+            .map_err(|reason| FileError::Other(Some(reason.into())))?;
+        Ok(template_source(id, content))
+    }
+
+    /// Fail paths outside the project, such as package imports, and every
+    /// path once the sandbox budget is spent.
+    fn check_access(&self, id: FileId) -> FileResult<()> {
+        if let VirtualRoot::Package(package) = id.root() {
+            return Err(FileError::Package(PackageError::Other(Some(
+                format!("{package} is not available: Typst packages can't be imported").into(),
+            ))));
+        }
+        self.budget
+            .check()
+            .map_err(|reason| FileError::Other(Some(reason.into())))
     }
 
     /// Get the main source file ID.
@@ -205,8 +396,8 @@ impl RustumeWorld {
         self.main.id()
     }
 
-    /// Load system fonts.
-    fn load_fonts() -> (FontBook, Vec<Font>) {
+    /// Load bundled fonts and index system fonts.
+    fn load_fonts() -> (FontBook, Vec<FontSlot>) {
         let mut book = FontBook::new();
         let mut fonts = Vec::new();
 
@@ -215,7 +406,7 @@ impl RustumeWorld {
             let buffer = Bytes::new(entry.to_vec());
             for font in Font::iter(buffer) {
                 book.push(font.info().clone());
-                fonts.push(font);
+                fonts.push(FontSlot::loaded(font));
             }
         }
 
@@ -259,9 +450,10 @@ impl RustumeWorld {
     /// Supported font file extensions.
     const FONT_EXTENSIONS: &'static [&'static str] = &["ttf", "otf", "ttc", "woff", "woff2"];
 
-    /// Load fonts from a directory (recursively).
+    /// Index fonts in a directory (recursively). Only metadata is kept; the
+    /// font data is read again if a document uses the font.
     /// Skips symlinks to avoid potential cycles or unbounded traversal.
-    fn load_fonts_from_dir(path: &str, book: &mut FontBook, fonts: &mut Vec<Font>) {
+    fn load_fonts_from_dir(path: &str, book: &mut FontBook, fonts: &mut Vec<FontSlot>) {
         if let Ok(entries) = std::fs::read_dir(path) {
             for entry in entries.flatten() {
                 let entry_path = entry.path();
@@ -287,10 +479,17 @@ impl RustumeWorld {
 
                     if is_font {
                         if let Ok(data) = std::fs::read(&entry_path) {
-                            let buffer = Bytes::new(data);
-                            for font in Font::iter(buffer) {
-                                book.push(font.info().clone());
-                                fonts.push(font);
+                            // Collections hold faces at consecutive indices;
+                            // parsing past the last one fails
+                            let faces = (0..).map_while(|index| {
+                                FontInfo::new(&data, index).map(|info| (index, info))
+                            });
+                            for (index, info) in faces {
+                                book.push(info);
+                                fonts.push(FontSlot {
+                                    source: Some((entry_path.clone(), index)),
+                                    font: OnceLock::new(),
+                                });
                             }
                         }
                     }
@@ -301,19 +500,18 @@ impl RustumeWorld {
     }
 }
 
-/// Get the shared font cache, loading fonts only once.
-fn get_fonts_cache() -> &'static (FontBook, Vec<Font>) {
+/// Get the shared font cache, indexing fonts only once.
+fn get_fonts_cache() -> &'static (FontBook, Vec<FontSlot>) {
     FONTS_CACHE.get_or_init(RustumeWorld::load_fonts)
 }
 
 impl typst::World for RustumeWorld {
     fn library(&self) -> &LazyHash<Library> {
-        self.library
-            .get_or_init(|| LazyHash::new(Library::default()))
+        LIBRARY.get_or_init(|| LazyHash::new(Library::default()))
     }
 
     fn book(&self) -> &LazyHash<FontBook> {
-        self.book.get_or_init(|| {
+        BOOK.get_or_init(|| {
             let (book, _) = get_fonts_cache();
             LazyHash::new(book.clone())
         })
@@ -324,6 +522,7 @@ impl typst::World for RustumeWorld {
     }
 
     fn source(&self, id: FileId) -> FileResult<Source> {
+        self.check_access(id)?;
         if id == self.main.id() {
             return Ok(self.main.clone());
         }
@@ -338,7 +537,7 @@ impl typst::World for RustumeWorld {
             }
         }
 
-        let source = Self::load_template_source(id)?;
+        let source = self.load_template_source(id)?;
         let mut sources = self
             .sources
             .lock()
@@ -347,6 +546,7 @@ impl typst::World for RustumeWorld {
     }
 
     fn file(&self, id: FileId) -> FileResult<Bytes> {
+        self.check_access(id)?;
         // Only registered in-memory binary files are supported.
         self.binary_files
             .get(&id)
@@ -356,7 +556,7 @@ impl typst::World for RustumeWorld {
 
     fn font(&self, index: usize) -> Option<Font> {
         let (_, fonts) = get_fonts_cache();
-        fonts.get(index).cloned()
+        fonts.get(index)?.get()
     }
 
     fn today(&self, offset: Option<Duration>) -> Option<Datetime> {
@@ -526,6 +726,35 @@ mod tests {
         }
     }
 
+    #[test]
+    fn template_sources_are_reused_and_updated_in_place() {
+        let _lock = OVERRIDE_TEST_LOCK.lock().unwrap();
+        reset_test_override();
+        let id = project_file_id("templates/cache-test.typ").expect("path");
+
+        let first = template_source(id, "#let a = 1".into());
+        let again = template_source(id, "#let a = 1".into());
+        assert_eq!(first.root(), again.root());
+
+        let edited = template_source(id, "#let a = 2".into());
+        assert_eq!(edited.text(), "#let a = 2");
+        assert_eq!(
+            template_source(id, "#let a = 2".into()).text(),
+            edited.text()
+        );
+    }
+
+    #[test]
+    fn worlds_share_library_and_fonts() {
+        use typst::World;
+
+        let a = RustumeWorld::new("= A".into()).expect("world a");
+        let b = RustumeWorld::new("= B".into()).expect("world b");
+        assert!(std::ptr::eq(a.library(), b.library()));
+        assert!(std::ptr::eq(a.book(), b.book()));
+        assert_eq!(b.source(b.main()).expect("main").text(), "= B");
+    }
+
     #[test]
     fn unused_broken_override_does_not_block_other_template() {
         let _lock = OVERRIDE_TEST_LOCK.lock().unwrap();
@@ -548,4 +777,97 @@ mod tests {
         );
         reset_test_override();
     }
+
+    /// Compile `main` within `limits`, expecting it to fail.
+    fn compile_error(main: &str, limits: SandboxLimits) -> RenderError {
+        let world = RustumeWorld::new(main.into())
+            .expect("world")
+            .with_sandbox(limits);
+        match crate::typst_engine::engine::compile_world(world) {
+            Ok(_) => panic!("compiled: {main}"),
+            Err(err) => err,
+        }
+    }
+
+    #[test]
+    fn typst_code_cannot_reach_outside_the_virtual_fs() {
+        for main in [
+            r#"#read("/etc/passwd")"#,
+            r#"#read("../../../../../../etc/passwd")"#,
+            r#"#image("/etc/hostname")"#,
+            r#"#include "/Cargo.toml""#,
+            r#"#import "../../Cargo.toml": *"#,
+            r#"#read("templates/onyx.typ")"#,
+            r#"#json("/proc/self/environ")"#,
+        ] {
+            let err = compile_error(main, SandboxLimits::default());
+            assert!(
+                matches!(&err, RenderError::RenderFailed(message) if !message.contains("root:")),
+                "{main}: {err}"
+            );
+        }
+    }
+
+    #[test]
+    fn typst_packages_cannot_be_imported() {
+        let err = compile_error(
+            r#"#import "@preview/cetz:0.3.4": canvas"#,
+            SandboxLimits::default(),
+        );
+        assert!(err.to_string().contains("can't be imported"), "{err}");
+    }
+
+    #[test]
+    fn template_names_stay_inside_the_override_dir() {
+        for name in ["onyx", "_common", "my-template", "candidate"] {
+            assert!(is_template_name(name), "{name}");
+        }
+        for name in ["", "..", "../onyx", "a\\b", "C:onyx", "onyx.typ", "on yx"] {
+            assert!(!is_template_name(name), "{name}");
+        }
+    }
+
+    #[test]
+    fn long_running_typst_code_is_stopped() {
+        // The loop reads a file on every pass, so the abandoned compilation
+        // ends at its next read instead of spinning on after the test.
+        let main = r#"#for i in range(10000) { for j in range(10000) { read("data.txt") } }"#;
+        let mut world =
+            RustumeWorld::new(main.into())
+                .expect("world")
+                .with_sandbox(SandboxLimits {
+                    max_compile_time: std::time::Duration::from_millis(200),
+                    ..SandboxLimits::default()
+                });
+        world
+            .add_binary_file("data.txt", b"data".to_vec())
+            .expect("file");
+        let started = std::time::Instant::now();
+        let budget = world.budget().clone();
+        let err = match crate::typst_engine::engine::compile_world(world) {
+            Ok(_) => panic!("compiled: {main}"),
+            Err(err) => err,
+        };
+        assert!(matches!(err, RenderError::LimitExceeded(_)), "{err}");
+        assert!(started.elapsed() < std::time::Duration::from_secs(5));
+        assert!(budget.check().is_err());
+    }
+
+    #[test]
+    fn oversized_sources_are_refused() {
+        let limits = SandboxLimits {
+            max_source_bytes: 1024,
+            ..SandboxLimits::default()
+        };
+        let mut world = RustumeWorld::new(String::new())
+            .expect("world")
+            .with_sandbox(limits);
+        assert!(matches!(
+            world.add_source("templates/huge.typ", "x".repeat(1025)),
+            Err(RenderError::LimitExceeded(_))
+        ));
+
+        let err = compile_error(r#"#import "templates/onyx.typ": template"#, limits);
+        assert!(err.to_string().contains("over the source limit"), "{err}");
+    }
 }
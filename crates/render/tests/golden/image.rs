@@ -0,0 +1,138 @@
+//! PNG decoding and perceptual comparison for golden images.
+
+use std::io::Cursor;
+
+/// An 8-bit RGBA image.
+pub struct Image {
+    pub width: u32,
+    pub height: u32,
+    pub pixels: Vec<u8>,
+}
+
+/// Color difference, from 0 to 1, below which two pixels look the same.
+/// Absorbs anti-aliasing and rasterizer rounding, not changed content.
+const COLOR_THRESHOLD: f64 = 0.1;
+
+/// Largest YIQ distance between two colors (black and white).
+const MAX_YIQ_DELTA: f64 = 35215.0;
+
+/// How two images of the same size differ.
+pub struct Difference {
+    /// Pixels that look different.
+    pub changed: usize,
+    /// Pixels in each image.
+    pub total: usize,
+    /// The expected image faded, with changed pixels in red.
+    pub highlighted: Image,
+}
+
+impl Difference {
+    /// Share of pixels that look different.
+    pub fn ratio(&self) -> f64 {
+        self.changed as f64 / self.total as f64
+    }
+}
+
+pub fn decode(bytes: &[u8]) -> Image {
+    let mut decoder = png::Decoder::new(Cursor::new(bytes));
+    decoder.set_transformations(png::Transformations::EXPAND);
+    let mut reader = decoder.read_info().expect("Invalid PNG");
+    let mut buffer = vec![0; reader.output_buffer_size().expect("PNG too large")];
+    let frame = reader.next_frame(&mut buffer).expect("Invalid PNG frame");
+    assert_eq!(frame.bit_depth, png::BitDepth::Eight, "Expected 8-bit PNG");
+    buffer.truncate(frame.buffer_size());
+
+    let pixels = match frame.color_type {
+        png::ColorType::Rgba => buffer,
+        png::ColorType::Rgb => buffer
+            .chunks_exact(3)
+            .flat_map(|rgb| [rgb[0], rgb[1], rgb[2], 255])
+            .collect(),
+        png::ColorType::GrayscaleAlpha => buffer
+            .chunks_exact(2)
+            .flat_map(|ga| [ga[0], ga[0], ga[0], ga[1]])
+            .collect(),
+        png::ColorType::Grayscale => buffer.iter().flat_map(|&g| [g, g, g, 255]).collect(),
+        png::ColorType::Indexed => unreachable!("EXPAND turns palettes into RGB"),
+    };
+    Image {
+        width: frame.width,
+        height: frame.height,
+        pixels,
+    }
+}
+
+pub fn encode(image: &Image) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    let mut encoder = png::Encoder::new(&mut bytes, image.width, image.height);
+    encoder.set_color(png::ColorType::Rgba);
+    encoder.set_depth(png::BitDepth::Eight);
+    let mut writer = encoder.write_header().expect("Failed to write PNG header");
+    writer
+        .write_image_data(&image.pixels)
+        .expect("Failed to write PNG data");
+    writer.finish().expect("Failed to finish PNG");
+    bytes
+}
+
+/// Compare two images pixel by pixel in YIQ space, which weighs brightness
+/// over hue the way the eye does. `None` when the sizes differ.
+pub fn compare(expected: &Image, actual: &Image) -> Option<Difference> {
+    if (expected.width, expected.height) != (actual.width, actual.height) {
+        return None;
+    }
+    let max_delta = MAX_YIQ_DELTA * COLOR_THRESHOLD * COLOR_THRESHOLD;
+    let mut changed = 0;
+    let mut highlighted = Vec::with_capacity(expected.pixels.len());
+
+    for (a, b) in expected
+        .pixels
+        .chunks_exact(4)
+        .zip(actual.pixels.chunks_exact(4))
+    {
+        if yiq_delta(a, b) > max_delta {
+            changed += 1;
+            highlighted.extend_from_slice(&[255, 0, 0, 255]);
+        } else {
+            // Faded grayscale of the expected pixel, for context
+            let gray = (255.0 - (255.0 - y(&blend(a))) * 0.1) as u8;
+            highlighted.extend_from_slice(&[gray, gray, gray, 255]);
+        }
+    }
+
+    Some(Difference {
+        changed,
+        total: expected.pixels.len() / 4,
+        highlighted: Image {
+            width: expected.width,
+            height: expected.height,
+            pixels: highlighted,
+        },
+    })
+}
+
+/// A pixel composited over white, as RGB.
+fn blend(pixel: &[u8]) -> [f64; 3] {
+    let alpha = f64::from(pixel[3]) / 255.0;
+    [0, 1, 2].map(|channel| 255.0 + (f64::from(pixel[channel]) - 255.0) * alpha)
+}
+
+fn y([r, g, b]: &[f64; 3]) -> f64 {
+    r * 0.298_895_31 + g * 0.586_622_47 + b * 0.114_482_23
+}
+
+fn i([r, g, b]: &[f64; 3]) -> f64 {
+    r * 0.595_977_99 - g * 0.274_176_10 - b * 0.321_801_89
+}
+
+fn q([r, g, b]: &[f64; 3]) -> f64 {
+    r * 0.211_470_17 - g * 0.522_617_55 + b * 0.311_147_38
+}
+
+fn yiq_delta(a: &[u8], b: &[u8]) -> f64 {
+    let (a, b) = (blend(a), blend(b));
+    let dy = y(&a) - y(&b);
+    let di = i(&a) - i(&b);
+    let dq = q(&a) - q(&b);
+    0.5053 * dy * dy + 0.299 * di * di + 0.1957 * dq * dq
+}
@@ -0,0 +1,190 @@
+//! Golden-image regression tests for the templates.
+//!
+//! Every fixture in [`FIXTURES`] is rendered with every template, and each
+//! page is compared with `tests/golden/images/<fixture>-<template>-<page>.png`.
+//! A page fails when more than [`MAX_CHANGED_RATIO`] of its pixels look
+//! different; tiny rasterizer differences don't count. Failing pages are
+//! written to `target/golden-diff/` as the new rendering plus a diff with
+//! the changed pixels in red.
+//!
+//! After an intended template change, regenerate the images and commit them
+//! with the change:
+//!
+//! ```bash
+//! RUSTUME_UPDATE_GOLDEN=1 cargo test -p rustume-render --test golden
+//! ```
+//!
+//! Templates use the IBM Plex fonts, so generate images where those are
+//! installed, as in the Docker image. A page without a golden image fails
+//! the test like a changed one, so new templates and pages can't go
+//! unchecked.
+
+mod image;
+
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use rustume_parser::{parse_resume, ResumeFormat};
+use rustume_render::{Renderer, TypstRenderer, TEMPLATES};
+use rustume_schema::ResumeData;
+
+/// Canonical resumes: name, format, and path under `tests/fixtures`.
+const FIXTURES: &[(&str, ResumeFormat, &str)] = &[
+    ("full", ResumeFormat::JsonResume, "json_resume/full.json"),
+    (
+        "minimal",
+        ResumeFormat::JsonResume,
+        "json_resume/minimal.json",
+    ),
+    ("complete", ResumeFormat::Rrv3, "v3/complete.json"),
+];
+
+/// Largest share of pixels that may look different before a page fails.
+const MAX_CHANGED_RATIO: f64 = 0.001;
+
+/// Set to rewrite the golden images from the current output.
+const UPDATE_ENV: &str = "RUSTUME_UPDATE_GOLDEN";
+
+fn workspace_root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .and_then(Path::parent)
+        .expect("crates/render lives two levels below the workspace root")
+        .to_path_buf()
+}
+
+fn golden_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/golden/images")
+}
+
+fn fixture(format: ResumeFormat, path: &str) -> ResumeData {
+    let path = workspace_root().join("tests/fixtures").join(path);
+    let data = fs::read(&path).unwrap_or_else(|e| panic!("Failed to read {}: {e}", path.display()));
+    parse_resume(format, &data)
+        .unwrap_or_else(|e| panic!("Failed to parse {}: {e}", path.display()))
+}
+
+/// Every page of `resume` rendered with `template`, as PNGs.
+fn render_pages(resume: &ResumeData, template: &str) -> Vec<Vec<u8>> {
+    let mut resume = resume.clone();
+    resume.metadata.template = template.to_string();
+    let renderer = TypstRenderer::new();
+
+    let (first, total) = renderer
+        .render_preview(&resume, 0)
+        .unwrap_or_else(|e| panic!("{template}: rendering failed: {e}"));
+    let mut pages = vec![first];
+    for page in 1..total {
+        let (png, _) = renderer
+            .render_preview(&resume, page)
+            .unwrap_or_else(|e| panic!("{template}: rendering page {} failed: {e}", page + 1));
+        pages.push(png);
+    }
+    pages
+}
+
+/// How a rendered page compares with its golden image.
+enum Outcome {
+    Matches,
+    Missing,
+    Changed(String),
+}
+
+/// Compare one page with its golden image, or replace the image when
+/// `update` is set.
+fn check_page(name: &str, png: &[u8], update: bool) -> Outcome {
+    let golden_path = golden_dir().join(format!("{name}.png"));
+    if update {
+        fs::write(&golden_path, png).expect("Failed to write golden image");
+        return Outcome::Matches;
+    }
+    let Ok(golden) = fs::read(&golden_path) else {
+        return Outcome::Missing;
+    };
+
+    let expected = image::decode(&golden);
+    let actual = image::decode(png);
+    let diff_dir = workspace_root().join("target/golden-diff");
+    let mismatch = match image::compare(&expected, &actual) {
+        None => format!(
+            "{name}: size changed from {}x{} to {}x{}",
+            expected.width, expected.height, actual.width, actual.height
+        ),
+        Some(difference) if difference.ratio() > MAX_CHANGED_RATIO => {
+            fs::create_dir_all(&diff_dir).expect("Failed to create diff directory");
+            fs::write(
+                diff_dir.join(format!("{name}-diff.png")),
+                image::encode(&difference.highlighted),
+            )
+            .expect("Failed to write diff image");
+            format!(
+                "{name}: {} of {} pixels changed ({:.3}%)",
+                difference.changed,
+                difference.total,
+                difference.ratio() * 100.0
+            )
+        }
+        Some(_) => return Outcome::Matches,
+    };
+
+    fs::create_dir_all(&diff_dir).expect("Failed to create diff directory");
+    fs::write(diff_dir.join(format!("{name}-actual.png")), png)
+        .expect("Failed to write rendered image");
+    Outcome::Changed(mismatch)
+}
+
+#[test]
+fn test_templates_match_golden_images() {
+    let update = std::env::var_os(UPDATE_ENV).is_some();
+    if update {
+        fs::create_dir_all(golden_dir()).expect("Failed to create golden directory");
+    }
+
+    let mut expected_names = BTreeSet::new();
+    let mut mismatches = Vec::new();
+    for (fixture_name, format, path) in FIXTURES {
+        let resume = fixture(*format, path);
+        for template in TEMPLATES {
+            for (page, png) in render_pages(&resume, template).iter().enumerate() {
+                let name = format!("{fixture_name}-{template}-{}", page + 1);
+                match check_page(&name, png, update) {
+                    Outcome::Matches => {}
+                    Outcome::Missing => mismatches.push(format!("{name}: no golden image")),
+                    Outcome::Changed(mismatch) => mismatches.push(mismatch),
+                }
+                expected_names.insert(name);
+            }
+        }
+    }
+
+    // Images left over from renamed templates or pages that went away
+    let stale: Vec<String> = fs::read_dir(golden_dir())
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+                .filter_map(|file| file.strip_suffix(".png").map(str::to_string))
+                .filter(|name| !expected_names.contains(name))
+                .collect()
+        })
+        .unwrap_or_default();
+    if update {
+        for name in &stale {
+            fs::remove_file(golden_dir().join(format!("{name}.png")))
+                .expect("Failed to remove stale golden image");
+        }
+        return;
+    }
+
+    mismatches.extend(
+        stale
+            .iter()
+            .map(|name| format!("{name}: golden image without a matching page")),
+    );
+    assert!(
+        mismatches.is_empty(),
+        "Rendering differs from the golden images (new renderings in target/golden-diff/); \
+         if the change is intended, run with {UPDATE_ENV}=1 and commit the images:\n  {}",
+        mismatches.join("\n  ")
+    );
+}
@@ -5,10 +5,15 @@
 
 use rstest::rstest;
 use rustume_parser::{JsonResumeParser, Parser, ReactiveResumeV3Parser};
-use rustume_render::{get_page_size, get_template_theme, Renderer, TypstRenderer, TEMPLATES};
+use rustume_render::{
+    get_page_size, get_template_theme, Redaction, RenderProgress, Renderer, SheetFormat,
+    TypstRenderer, TEMPLATES,
+};
 use rustume_schema::{
-    Basics, CustomItem, Education, Experience, LevelDisplay, PageFormat, Picture, PictureEffects,
-    ResumeData, Section, Skill,
+    apply_fit_steps, Attachment, Basics, ContactQr, CustomItem, DividerStyle, Education,
+    Experience, HeaderAlign, Language, LevelDisplay, PageBackground, PageFormat, PageNumberStyle,
+    PageSelector, PhotoPosition, Picture, PictureEffects, ResumeData, RunningHeader, Section,
+    SectionDisplay, Skill, TemplateOptions,
 };
 use std::collections::HashMap;
 use std::fs;
@@ -284,6 +289,49 @@ fn test_render_pdf_with_content() {
     );
 }
 
+#[test]
+fn test_render_pdf_with_progress_reports_stages_in_order() {
+    let resume = sample_resume();
+    let renderer = TypstRenderer::new();
+
+    let mut events = Vec::new();
+    let pdf = renderer
+        .render_pdf_with_progress(&resume, |event| events.push(event))
+        .expect("PDF rendering failed");
+    assert!(pdf.starts_with(b"%PDF-"));
+
+    let RenderProgress::Compiled { total_pages } = events[1] else {
+        panic!("expected Compiled as second event, got {:?}", events);
+    };
+    assert!(total_pages > 0);
+    let mut expected = vec![
+        RenderProgress::SourceGenerated,
+        RenderProgress::Compiled { total_pages },
+    ];
+    expected
+        .extend((0..total_pages).map(|page| RenderProgress::PageRendered { page, total_pages }));
+    expected.extend([
+        RenderProgress::Exporting,
+        RenderProgress::Done { size: pdf.len() },
+    ]);
+    assert_eq!(events, expected);
+}
+
+#[test]
+fn test_render_pdf_with_progress_stops_on_invalid_config() {
+    let mut resume = ResumeData::default();
+    resume.metadata.page.margin = 500;
+
+    let mut events = Vec::new();
+    let result = TypstRenderer::new().render_pdf_with_progress(&resume, |event| events.push(event));
+
+    assert!(result.is_err());
+    assert!(
+        events.is_empty(),
+        "no progress expected before source generation"
+    );
+}
+
 #[test]
 fn test_render_pdf_from_json_resume() {
     let fixture_path = fixtures_path().join("json_resume").join("full.json");
@@ -343,6 +391,21 @@ fn test_render_preview_page_0() {
     assert!(total_pages >= 1, "Should have at least one page");
 }
 
+#[test]
+fn test_render_svg_page_0() {
+    let resume = sample_resume();
+    let renderer = TypstRenderer::new();
+
+    let (svg, total_pages) = renderer
+        .render_svg(&resume, 0)
+        .expect("SVG rendering failed");
+    assert!(svg.starts_with("<svg"), "Output is not an SVG document");
+    assert!(svg.contains("viewBox"));
+    assert!(total_pages >= 1, "Should have at least one page");
+
+    assert!(renderer.render_svg(&resume, 99).is_err());
+}
+
 #[test]
 fn test_render_preview_invalid_page() {
     let resume = ResumeData::default();
@@ -353,6 +416,60 @@ fn test_render_preview_invalid_page() {
     assert!(result.is_err(), "Should fail for invalid page");
 }
 
+#[test]
+fn test_render_contact_sheet() {
+    let resume = sample_resume();
+    let renderer = TypstRenderer::new();
+
+    let png = renderer
+        .render_contact_sheet(&resume, &["rhyhorn", "azurill"], SheetFormat::Png)
+        .expect("PNG contact sheet should render");
+    assert!(png.starts_with(&[0x89, 0x50, 0x4E, 0x47]));
+
+    let pdf = renderer
+        .render_contact_sheet(&resume, TEMPLATES, SheetFormat::Pdf)
+        .expect("PDF contact sheet should render");
+    assert!(pdf.starts_with(b"%PDF"));
+}
+
+#[test]
+#[allow(clippy::field_reassign_with_default)]
+fn test_fit_to_pages() {
+    let renderer = TypstRenderer::new();
+
+    let resume = sample_resume();
+    let fitted = renderer.fit_to_pages(&resume, 1, &[]).unwrap();
+    assert!(fitted.report.fits);
+    assert!(fitted.report.steps.is_empty());
+
+    let mut resume = ResumeData::default();
+    resume.basics = Basics::new("Fit Test");
+    resume.sections.experience = Section::new("experience", "Experience");
+    for year in 2000..2020 {
+        resume.sections.experience.add_item(
+            Experience::new(format!("Company {year}"), "Engineer")
+                .with_dates(year.to_string(), Some((year + 1).to_string()))
+                .with_summary("Lorem ipsum dolor sit amet, consectetur adipiscing elit. Sed do eiusmod tempor incididunt ut labore et dolore magna aliqua."),
+        );
+    }
+    assert!(renderer.page_count(&resume).unwrap() > 1);
+
+    let fitted = renderer.fit_to_pages(&resume, 1, &[]).unwrap();
+    let report = &fitted.report;
+    assert!(report.fits, "{report:?}");
+    assert_eq!(report.pages_after, 1);
+    assert_eq!(renderer.page_count(&fitted.resume).unwrap(), 1);
+    // The newest role is never cut
+    assert!(fitted.resume.sections.experience.items[19].visible);
+
+    let mut replayed = resume.clone();
+    apply_fit_steps(&mut replayed, &report.steps).unwrap();
+    assert_eq!(
+        serde_json::to_value(&replayed).unwrap(),
+        serde_json::to_value(&fitted.resume).unwrap()
+    );
+}
+
 // ============================================================================
 // Edge Cases
 // ============================================================================
@@ -404,12 +521,152 @@ fn test_render_long_content() {
 }
 
 #[test]
-fn test_render_html_not_supported() {
-    let resume = ResumeData::default();
+fn test_render_multi_page_chrome_all_templates() {
+    let mut resume = ResumeData::default();
+    resume.basics = Basics::new("Multi Page").with_email("multi@example.com");
+    resume.sections.experience = Section::new("experience", "Experience");
+    for i in 0..20 {
+        resume.sections.experience.add_item(
+            Experience::new(format!("Company {}", i), format!("Position {}", i))
+                .with_summary("Lorem ipsum dolor sit amet, consectetur adipiscing elit. Sed do eiusmod tempor incididunt ut labore et dolore magna aliqua."),
+        );
+    }
+
     let renderer = TypstRenderer::new();
+    let styles = [
+        (
+            PageNumberStyle::Plain,
+            RunningHeader::Header,
+            ContactQr::Footer,
+        ),
+        (
+            PageNumberStyle::OfTotal,
+            RunningHeader::Footer,
+            ContactQr::Header,
+        ),
+        (
+            PageNumberStyle::Labeled,
+            RunningHeader::Hidden,
+            ContactQr::Hidden,
+        ),
+    ];
+    for template in TEMPLATES {
+        for (style, running_header, contact_qr) in styles {
+            resume.metadata.template = template.to_string();
+            resume.metadata.page.options.page_number_style = style;
+            resume.metadata.page.options.running_header = running_header;
+            resume.metadata.page.options.contact_qr = contact_qr;
+
+            let (_, total_pages) = renderer
+                .render_preview(&resume, 1)
+                .unwrap_or_else(|e| panic!("{template} failed with {style:?}: {e}"));
+            assert!(
+                total_pages > 1,
+                "{template} should spill onto a second page"
+            );
+        }
+    }
+}
 
-    let result = renderer.render_html(&resume);
-    assert!(result.is_err(), "HTML rendering should not be supported");
+#[test]
+fn test_page_backgrounds_differ_between_pages() {
+    let mut resume = ResumeData::default();
+    resume.basics = Basics::new("Two Tone").with_email("two@example.com");
+    resume.sections.experience = Section::new("experience", "Experience");
+    for i in 0..20 {
+        resume.sections.experience.add_item(
+            Experience::new(format!("Company {}", i), format!("Position {}", i))
+                .with_summary("Lorem ipsum dolor sit amet, consectetur adipiscing elit. Sed do eiusmod tempor incididunt ut labore et dolore magna aliqua."),
+        );
+    }
+    // A colored sidebar on the first page only, and a tinted page after it
+    resume.metadata.page.backgrounds = vec![
+        PageBackground {
+            pages: PageSelector::First,
+            sidebar: "#123456".to_string(),
+            ..Default::default()
+        },
+        PageBackground {
+            pages: PageSelector::Rest,
+            background: "#232323".to_string(),
+            hide_sidebar: true,
+            ..Default::default()
+        },
+    ];
+
+    let renderer = TypstRenderer::new();
+    for template in ["ditto", "gengar", "glalie", "pikachu", "rhyhorn"] {
+        resume.metadata.template = template.to_string();
+        let (first, total_pages) = renderer
+            .render_svg(&resume, 0)
+            .unwrap_or_else(|e| panic!("{template} failed to render: {e}"));
+        assert!(
+            total_pages > 1,
+            "{template} should spill onto a second page"
+        );
+        let (second, _) = renderer.render_svg(&resume, 1).unwrap();
+
+        let has_sidebar = template != "rhyhorn";
+        assert_eq!(
+            first.contains("#123456"),
+            has_sidebar,
+            "{template}: sidebar color on page 1"
+        );
+        assert!(!first.contains("#232323"), "{template}: page 1 tinted");
+        assert!(second.contains("#232323"), "{template}: page 2 not tinted");
+        assert!(!second.contains("#123456"), "{template}: sidebar on page 2");
+    }
+}
+
+#[test]
+fn test_typography_controls_apply_in_every_template() {
+    let renderer = TypstRenderer::new();
+    let mut resume = sample_resume();
+    for template in TEMPLATES {
+        resume.metadata.template = template.to_string();
+        resume.metadata.typography = Default::default();
+        let (plain, _) = renderer.render_svg(&resume, 0).unwrap();
+
+        let typography = &mut resume.metadata.typography;
+        typography.line_height = 2.0;
+        typography.letter_spacing = 0.05;
+        typography.paragraph_spacing = Some(1.5);
+        typography.heading_scale = 1.5;
+        typography.small_caps = true;
+        typography.justify = Some(true);
+        typography.hyphenate = Some(true);
+        let (styled, _) = renderer
+            .render_svg(&resume, 0)
+            .unwrap_or_else(|e| panic!("{template} failed to render: {e}"));
+        assert_ne!(plain, styled, "{template} ignored the typography settings");
+    }
+}
+
+#[test]
+fn test_render_html_page() {
+    let resume = ResumeData::with_basics("Jane Doe", "jane@example.com");
+    let renderer = TypstRenderer::new();
+
+    let html = renderer
+        .render_html(&resume)
+        .expect("HTML rendering failed");
+    assert!(html.starts_with("<!DOCTYPE html>"));
+    assert!(html.contains("<h1>Jane Doe</h1>"));
+    assert!(html.contains("<style media=\"print\">"));
+}
+
+#[test]
+fn test_render_html_applies_redaction() {
+    let resume = ResumeData::with_basics("Jane Doe", "jane@example.com");
+    let renderer = TypstRenderer::new().with_redaction(Redaction {
+        exclude_sections: vec!["basics.email".into()],
+        exclude_item_ids: Vec::new(),
+    });
+
+    let html = renderer
+        .render_html(&resume)
+        .expect("HTML rendering failed");
+    assert!(!html.contains("jane@example.com"));
 }
 
 // ============================================================================
@@ -575,6 +832,46 @@ fn test_render_template_with_level_display_override(
     assert!(result.unwrap().starts_with(b"%PDF-"));
 }
 
+#[rstest]
+fn test_render_template_with_section_display(
+    // The chart replaces each template's item renderer, so one single-column
+    // and one sidebar template cover the shared code path.
+    #[values("rhyhorn", "azurill")] template_name: &str,
+    #[values(
+        SectionDisplay::BarChart,
+        SectionDisplay::Dots,
+        SectionDisplay::TagCloud
+    )]
+    display: SectionDisplay,
+) {
+    let renderer = TypstRenderer::new();
+    let mut resume = sample_resume();
+    resume.metadata.template = template_name.to_string();
+    resume.sections.languages = Section::new("languages", "Languages");
+    resume
+        .sections
+        .languages
+        .add_item(Language::new("German").with_level(3));
+    resume.sections.languages.add_item(Language::new("Latin"));
+    resume
+        .metadata
+        .section_display
+        .insert("skills".to_string(), display);
+    resume
+        .metadata
+        .section_display
+        .insert("languages".to_string(), display);
+
+    let result = renderer.render_pdf(&resume);
+    assert!(
+        result.is_ok(),
+        "PDF rendering failed for template '{template_name}' with section display \
+         '{display:?}': {:?}",
+        result.err()
+    );
+    assert!(result.unwrap().starts_with(b"%PDF-"));
+}
+
 /// Every template must compile with a non-default level display so a Typst
 /// syntax error in any template's override branch is caught.
 #[test]
@@ -624,7 +921,8 @@ fn test_templates_render_non_default_layout(#[case] template_name: &str) {
             "education".to_string(),
             "profiles".to_string(),
         ],
-    ]];
+    ]]
+    .into();
     resume.sections.profiles.visible = true;
 
     let result = renderer.render_pdf(&resume);
@@ -657,7 +955,8 @@ fn test_templates_render_custom_sections(#[case] template_name: &str) {
     resume.metadata.layout = vec![vec![
         vec!["summary".to_string(), "experience".to_string()],
         vec!["skills".to_string(), "custom".to_string()],
-    ]];
+    ]]
+    .into();
 
     let mut custom_section = Section::new("open-source", "Open Source");
     let mut custom_item = CustomItem::new("Rustume");
@@ -800,7 +1099,8 @@ fn test_cover_letter_not_placed_in_layout_is_skipped(#[case] template_name: &str
     resume.metadata.layout = vec![vec![
         vec!["summary".to_string(), "experience".to_string()],
         vec!["education".to_string(), "skills".to_string()],
-    ]];
+    ]]
+    .into();
     let (_, pages) = renderer
         .render_preview(&resume, 0)
         .unwrap_or_else(|e| panic!("Preview failed for '{template_name}': {e:?}"));
@@ -811,7 +1111,8 @@ fn test_cover_letter_not_placed_in_layout_is_skipped(#[case] template_name: &str
     baseline.metadata.layout = vec![vec![
         vec!["summary".to_string(), "experience".to_string()],
         vec!["education".to_string(), "skills".to_string()],
-    ]];
+    ]]
+    .into();
     let (_, base_pages) = renderer
         .render_preview(&baseline, 0)
         .unwrap_or_else(|e| panic!("Baseline preview failed for '{template_name}': {e:?}"));
@@ -839,7 +1140,8 @@ fn test_cover_letter_on_later_layout_page_is_skipped(#[case] template_name: &str
             vec!["education".to_string(), "skills".to_string()],
         ],
         vec![vec!["coverLetter".to_string()]],
-    ];
+    ]
+    .into();
     let (_, pages) = renderer
         .render_preview(&resume, 0)
         .unwrap_or_else(|e| panic!("Preview failed for '{template_name}': {e:?}"));
@@ -849,7 +1151,8 @@ fn test_cover_letter_on_later_layout_page_is_skipped(#[case] template_name: &str
     baseline.metadata.layout = vec![vec![
         vec!["summary".to_string(), "experience".to_string()],
         vec!["education".to_string(), "skills".to_string()],
-    ]];
+    ]]
+    .into();
     let (_, base_pages) = renderer
         .render_preview(&baseline, 0)
         .unwrap_or_else(|e| panic!("Baseline preview failed for '{template_name}': {e:?}"));
@@ -878,7 +1181,8 @@ fn test_cover_letter_placed_in_layout_column_renders_once(#[case] template_name:
             "experience".to_string(),
         ],
         vec!["education".to_string(), "skills".to_string()],
-    ]];
+    ]]
+    .into();
     let (_, pages) = renderer
         .render_preview(&resume, 0)
         .unwrap_or_else(|e| panic!("Preview failed for '{template_name}': {e:?}"));
@@ -888,7 +1192,8 @@ fn test_cover_letter_placed_in_layout_column_renders_once(#[case] template_name:
     baseline.metadata.layout = vec![vec![
         vec!["summary".to_string(), "experience".to_string()],
         vec!["education".to_string(), "skills".to_string()],
-    ]];
+    ]]
+    .into();
     let (_, base_pages) = renderer
         .render_preview(&baseline, 0)
         .unwrap_or_else(|e| panic!("Baseline preview failed for '{template_name}': {e:?}"));
@@ -918,7 +1223,7 @@ fn test_cover_letter_only_layout_renders_single_page(#[case] template_name: &str
     let mut resume = sample_resume();
     resume.metadata.template = template_name.to_string();
     fill_cover_letter(&mut resume, true);
-    resume.metadata.layout = vec![vec![vec!["coverLetter".to_string()]]];
+    resume.metadata.layout = vec![vec![vec!["coverLetter".to_string()]]].into();
 
     let (_, pages) = renderer.render_preview(&resume, 0).unwrap_or_else(|e| {
         panic!("Cover-letter-only preview failed for '{template_name}': {e:?}")
@@ -979,6 +1284,80 @@ fn test_render_picture_effects_smoke() {
     assert!(result.unwrap().starts_with(b"%PDF-"));
 }
 
+/// Every template must compile with each photo position, header alignment,
+/// and divider style, including the ones it ignores.
+#[rstest]
+#[case(PhotoPosition::Top, HeaderAlign::Center, DividerStyle::Thick)]
+#[case(PhotoPosition::Left, HeaderAlign::Right, DividerStyle::Dotted)]
+#[case(PhotoPosition::Right, HeaderAlign::Left, DividerStyle::None)]
+#[case(
+    PhotoPosition::Hidden,
+    HeaderAlign::TemplateDefault,
+    DividerStyle::Line
+)]
+fn test_render_all_templates_with_template_options(
+    #[case] photo: PhotoPosition,
+    #[case] header_align: HeaderAlign,
+    #[case] divider: DividerStyle,
+) {
+    let png_data_url = "data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAYAAAAfFcSJAAAADUlEQVR42mP8z8BQDwAEhQGAhKmMIQAAAABJRU5ErkJggg==";
+
+    let renderer = TypstRenderer::new();
+    for template_name in TEMPLATES {
+        let mut resume = sample_resume();
+        resume.metadata.template = (*template_name).to_string();
+        resume.basics.picture = Picture::new(png_data_url);
+        resume.metadata.template_options = TemplateOptions {
+            photo,
+            header_align,
+            divider,
+            ..Default::default()
+        };
+
+        let result = renderer.render_pdf(&resume);
+        assert!(
+            result.is_ok(),
+            "PDF rendering failed for template '{template_name}' with {:?}: {:?}",
+            resume.metadata.template_options,
+            result.err()
+        );
+    }
+}
+
+/// Appended attachments add their pages after the resume, which ends with
+/// the QR codes of linked ones.
+#[rstest]
+#[case::single_column("rhyhorn")]
+#[case::sidebar("pikachu")]
+fn test_render_template_with_attachments(#[case] template: &str) {
+    use base64::Engine as _;
+
+    let png_data_url = "data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAYAAAAfFcSJAAAADUlEQVR42mP8z8BQDwAEhQGAhKmMIQAAAABJRU5ErkJggg==";
+    let renderer = TypstRenderer::new();
+    let letter = sample_resume();
+    let (_, letter_pages) = renderer.render_preview(&letter, 0).unwrap();
+    let letter_url = format!(
+        "data:application/pdf;base64,{}",
+        base64::engine::general_purpose::STANDARD.encode(renderer.render_pdf(&letter).unwrap())
+    );
+
+    let mut resume = sample_resume();
+    resume.metadata.template = template.to_string();
+    resume.attachments = vec![Attachment::link(
+        "Portfolio",
+        "https://example.com/portfolio",
+    )];
+    let (_, resume_pages) = renderer.render_preview(&resume, 0).unwrap();
+
+    resume.attachments.extend([
+        Attachment::new("Reference letter", letter_url),
+        Attachment::new("Certificate", png_data_url),
+    ]);
+    let (_, total_pages) = renderer.render_preview(&resume, 0).unwrap();
+    assert_eq!(total_pages, resume_pages + letter_pages + 1);
+    assert!(renderer.render_pdf(&resume).unwrap().starts_with(b"%PDF-"));
+}
+
 #[test]
 fn test_templates_use_shared_render_contract() {
     let template_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
@@ -0,0 +1,181 @@
+//! Structure of exported PDFs: the text layer and the link annotations.
+//!
+//! Every template renders `json_resume/full.json`, and the PDF is read back
+//! with `pdf-extract` for its text and `lopdf` for its annotations. The text
+//! has to be selectable and come out in reading order, and every URL the
+//! resume shows has to be a clickable link: the website, each profile, each
+//! project, and the email address as a `mailto:` link. The document
+//! information has to describe the resume, unless it is turned off.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use lopdf::{decode_text_string, Document, Object};
+use rustume_parser::{JsonResumeParser, Parser};
+use rustume_render::{Renderer, TypstRenderer, TEMPLATES};
+use rustume_schema::ResumeData;
+
+/// Phrases of the fixture in the order a reader meets them.
+const READING_ORDER: &[&str] = &[
+    "Jane Smith",
+    "Tech Corp",
+    "Reduced API latency by 40%",
+    "Mentored team of 5 junior developers",
+    "Startup Inc",
+];
+
+/// Links every template has to make clickable.
+const LINKS: &[&str] = &[
+    "mailto:jane@example.com",
+    "https://janesmith.io",
+    "https://github.com/janesmith",
+    "https://linkedin.com/in/janesmith",
+    "https://github.com/janesmith/cli-tool",
+];
+
+/// Smallest side of a link's clickable area, in points.
+const MIN_LINK_SIZE: f32 = 1.0;
+
+fn workspace_root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .and_then(Path::parent)
+        .expect("crates/render lives two levels below the workspace root")
+        .to_path_buf()
+}
+
+fn fixture_resume(template: &str) -> ResumeData {
+    let path = workspace_root().join("tests/fixtures/json_resume/full.json");
+    let data = fs::read(&path).expect("Failed to read fixture");
+    let mut resume = JsonResumeParser
+        .parse(&data)
+        .expect("Failed to parse fixture");
+    // A profile that is only a URL still needs something to click
+    resume.sections.profiles.items[1].username.clear();
+    resume.metadata.template = template.to_string();
+    resume
+}
+
+fn render(template: &str) -> Vec<u8> {
+    TypstRenderer::new()
+        .render_pdf(&fixture_resume(template))
+        .unwrap_or_else(|e| panic!("{template} failed to render: {e}"))
+}
+
+/// Text layer of `pdf` without whitespace, so line breaks, letter spacing,
+/// and the spaces the extractor guesses at don't matter.
+fn text_layer(pdf: &[u8]) -> String {
+    pdf_extract::extract_text_from_mem(pdf)
+        .expect("PDF text should extract")
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect()
+}
+
+/// Targets of the URI link annotations in `pdf` with a clickable area.
+fn link_targets(pdf: &[u8]) -> BTreeSet<String> {
+    let doc = Document::load_mem(pdf).expect("PDF should load");
+    let resolve = |object| doc.dereference(object).expect("dangling reference").1;
+    let mut targets = BTreeSet::new();
+    for page in doc.get_pages().into_values() {
+        let page = doc.get_dictionary(page).expect("page dictionary");
+        let Ok(annots) = page.get(b"Annots") else {
+            continue;
+        };
+        for annot in resolve(annots).as_array().expect("Annots array") {
+            let annot = resolve(annot).as_dict().expect("annotation dictionary");
+            let subtype = annot.get(b"Subtype").and_then(Object::as_name);
+            if subtype.ok() != Some(b"Link".as_slice()) {
+                continue;
+            }
+            let rect: Vec<f32> = resolve(annot.get(b"Rect").expect("link Rect"))
+                .as_array()
+                .expect("Rect array")
+                .iter()
+                .map(|n| n.as_float().expect("Rect number"))
+                .collect();
+            let (width, height) = ((rect[2] - rect[0]).abs(), (rect[3] - rect[1]).abs());
+            if width < MIN_LINK_SIZE || height < MIN_LINK_SIZE {
+                continue;
+            }
+            let Ok(action) = annot.get(b"A") else {
+                continue;
+            };
+            let action = resolve(action).as_dict().expect("link action");
+            if let Ok(uri) = action.get(b"URI").and_then(Object::as_str) {
+                targets.insert(String::from_utf8_lossy(uri).into_owned());
+            }
+        }
+    }
+    targets
+}
+
+/// Entries of the document information dictionary of `pdf`, as text.
+fn document_info(pdf: &[u8]) -> BTreeMap<String, String> {
+    let doc = Document::load_mem(pdf).expect("PDF should load");
+    let Ok(info) = doc.trailer.get(b"Info") else {
+        return BTreeMap::new();
+    };
+    let info = doc.dereference(info).expect("dangling Info").1;
+    info.as_dict()
+        .expect("Info dictionary")
+        .iter()
+        .filter_map(|(key, value)| {
+            let value = doc.dereference(value).ok()?.1;
+            let text = decode_text_string(value).ok()?;
+            Some((String::from_utf8_lossy(key).into_owned(), text))
+        })
+        .collect()
+}
+
+#[test]
+fn test_text_layer_is_selectable_in_reading_order() {
+    for template in TEMPLATES {
+        let text = text_layer(&render(template));
+        let mut last = 0;
+        for phrase in READING_ORDER {
+            let phrase: String = phrase.chars().filter(|c| !c.is_whitespace()).collect();
+            let at = text[last..]
+                .find(&phrase)
+                .unwrap_or_else(|| panic!("{template}: {phrase:?} missing or out of order"));
+            last += at + phrase.len();
+        }
+    }
+}
+
+#[test]
+fn test_every_url_is_a_clickable_link() {
+    for template in TEMPLATES {
+        let targets = link_targets(&render(template));
+        for link in LINKS {
+            assert!(
+                targets.contains(*link),
+                "{template}: no clickable link to {link}, only {targets:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_document_info_describes_the_resume() {
+    let info = document_info(&render("rhyhorn"));
+    let entry = |key: &str| info.get(key).map(String::as_str).unwrap_or_default();
+    assert_eq!(entry("Title"), "Jane Smith — Senior Software Engineer");
+    assert_eq!(entry("Author"), "Jane Smith");
+    assert!(entry("Subject").contains("distributed systems"), "{info:?}");
+    assert!(
+        entry("Keywords").contains("Backend Development"),
+        "{info:?}"
+    );
+    assert!(entry("Keywords").contains("Kubernetes"), "{info:?}");
+    assert!(entry("CreationDate").starts_with("D:"), "{info:?}");
+
+    let mut resume = fixture_resume("rhyhorn");
+    resume.metadata.page.options.pdf_metadata = false;
+    let pdf = TypstRenderer::new().render_pdf(&resume).unwrap();
+    let info = document_info(&pdf);
+    for key in ["Title", "Author", "Subject", "Keywords"] {
+        assert!(!info.contains_key(key), "{key} written with the option off");
+    }
+}
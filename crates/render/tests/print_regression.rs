@@ -0,0 +1,205 @@
+//! Visual regression between the PDF and the browser's print of the HTML page.
+//!
+//! These tests drive a headless Chromium, so they are ignored by default. Run
+//! them with the browser's path in `RUSTUME_CHROME`:
+//!
+//! ```bash
+//! RUSTUME_CHROME=/usr/bin/chromium cargo test -p rustume-render --test print_regression -- --ignored
+//! ```
+//!
+//! The page is rendered with `HtmlPageOptions::paged`, which lays it out as the
+//! first printed sheet, and screenshotted at the paper size. Browser and Typst
+//! fonts differ, so instead of comparing pixels the tests compare where ink
+//! falls on a coarse grid over each image. Both images are kept in
+//! `target/print-regression/` for a look when a case fails.
+
+use std::fs;
+use std::io::Cursor;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use rstest::rstest;
+use rustume_parser::{JsonResumeParser, Parser};
+use rustume_render::{render_html_page, HtmlPageOptions, Renderer, TypstRenderer};
+use rustume_schema::ResumeData;
+
+/// Grid cells across and down each image.
+const GRID: (usize, usize) = (12, 16);
+
+/// Smallest correlation of ink coverage accepted as the same layout.
+const MIN_CORRELATION: f64 = 0.6;
+
+/// Luminance difference from the page background that counts as ink.
+const INK_THRESHOLD: f64 = 48.0;
+
+/// A decoded image as luminance values, row by row.
+struct Gray {
+    width: usize,
+    height: usize,
+    pixels: Vec<f64>,
+}
+
+fn workspace_root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .and_then(Path::parent)
+        .expect("crates/render lives two levels below the workspace root")
+        .to_path_buf()
+}
+
+fn fixture_resume(template: &str) -> ResumeData {
+    let path = workspace_root().join("tests/fixtures/json_resume/full.json");
+    let data = fs::read(&path).expect("Failed to read fixture");
+    let mut resume = JsonResumeParser
+        .parse(&data)
+        .expect("Failed to parse fixture");
+    resume.metadata.template = template.to_string();
+    resume
+}
+
+fn decode_png(bytes: &[u8]) -> Gray {
+    let decoder = png::Decoder::new(Cursor::new(bytes));
+    let mut reader = decoder.read_info().expect("Invalid PNG");
+    let mut buffer = vec![0; reader.output_buffer_size().expect("PNG too large")];
+    let frame = reader.next_frame(&mut buffer).expect("Invalid PNG frame");
+    let channels = frame.color_type.samples();
+    assert_eq!(frame.bit_depth, png::BitDepth::Eight, "Expected 8-bit PNG");
+
+    let pixels = buffer[..frame.buffer_size()]
+        .chunks_exact(channels)
+        .map(|pixel| match channels {
+            1 | 2 => f64::from(pixel[0]),
+            _ => {
+                0.2126 * f64::from(pixel[0])
+                    + 0.7152 * f64::from(pixel[1])
+                    + 0.0722 * f64::from(pixel[2])
+            }
+        })
+        .collect();
+    Gray {
+        width: frame.width as usize,
+        height: frame.height as usize,
+        pixels,
+    }
+}
+
+/// Share of ink in each grid cell, row by row. The top-left pixel is taken
+/// as the page background.
+fn ink_grid(image: &Gray) -> Vec<f64> {
+    let background = image.pixels[0];
+    let (columns, rows) = GRID;
+    let mut ink = vec![0.0; columns * rows];
+    let mut counts = vec![0.0; columns * rows];
+
+    for y in 0..image.height {
+        for x in 0..image.width {
+            let cell = (y * rows / image.height) * columns + x * columns / image.width;
+            counts[cell] += 1.0;
+            if (image.pixels[y * image.width + x] - background).abs() > INK_THRESHOLD {
+                ink[cell] += 1.0;
+            }
+        }
+    }
+    ink.iter()
+        .zip(&counts)
+        .map(|(ink, count)| if *count > 0.0 { ink / count } else { 0.0 })
+        .collect()
+}
+
+/// Pearson correlation of two equally long samples.
+fn correlation(a: &[f64], b: &[f64]) -> f64 {
+    let n = a.len() as f64;
+    let mean_a = a.iter().sum::<f64>() / n;
+    let mean_b = b.iter().sum::<f64>() / n;
+    let mut covariance = 0.0;
+    let mut variance_a = 0.0;
+    let mut variance_b = 0.0;
+    for (a, b) in a.iter().zip(b) {
+        covariance += (a - mean_a) * (b - mean_b);
+        variance_a += (a - mean_a).powi(2);
+        variance_b += (b - mean_b).powi(2);
+    }
+    if variance_a == 0.0 || variance_b == 0.0 {
+        return 0.0;
+    }
+    covariance / (variance_a.sqrt() * variance_b.sqrt())
+}
+
+/// Screenshot `html` in headless Chromium at the A4 sheet size (96 dpi).
+fn browser_screenshot(chrome: &str, html: &str, out_dir: &Path, name: &str) -> Vec<u8> {
+    let page = out_dir.join(format!("{name}.html"));
+    let screenshot = out_dir.join(format!("{name}-browser.png"));
+    fs::write(&page, html).expect("Failed to write page");
+
+    let status = Command::new(chrome)
+        .args([
+            "--headless",
+            "--disable-gpu",
+            "--hide-scrollbars",
+            "--force-device-scale-factor=1",
+            "--window-size=794,1123",
+        ])
+        .arg(format!("--screenshot={}", screenshot.display()))
+        .arg(format!("file://{}", page.display()))
+        .status()
+        .expect("Failed to start the browser");
+    assert!(status.success(), "Browser exited with {status}");
+    fs::read(&screenshot).expect("Browser wrote no screenshot")
+}
+
+#[rstest]
+#[case::single_column("rhyhorn")]
+#[case::narrow_first_column("azurill")]
+#[case::wide_first_column("chikorita")]
+#[ignore = "needs a headless Chromium in RUSTUME_CHROME"]
+fn test_browser_print_matches_pdf_preview(#[case] template: &str) {
+    let chrome = std::env::var("RUSTUME_CHROME").expect("Set RUSTUME_CHROME to a Chromium binary");
+    let out_dir = workspace_root().join("target/print-regression");
+    fs::create_dir_all(&out_dir).expect("Failed to create output directory");
+
+    let resume = fixture_resume(template);
+    let (preview, _) = TypstRenderer::new()
+        .render_preview(&resume, 0)
+        .expect("Preview rendering failed");
+    fs::write(out_dir.join(format!("{template}-typst.png")), &preview)
+        .expect("Failed to write preview");
+
+    let options = HtmlPageOptions {
+        paged: true,
+        ..HtmlPageOptions::default()
+    };
+    let html = render_html_page(&resume, &options);
+    let screenshot = browser_screenshot(&chrome, &html, &out_dir, template);
+
+    let typst = ink_grid(&decode_png(&preview));
+    let browser = ink_grid(&decode_png(&screenshot));
+    let score = correlation(&typst, &browser);
+    assert!(
+        score >= MIN_CORRELATION,
+        "{template}: print layout drifted from the PDF (correlation {score:.2}); compare the images in {}",
+        out_dir.display()
+    );
+}
+
+#[test]
+fn test_ink_grid_compares_layouts() {
+    // Ink in the left third versus the right third of a blank page
+    let page = |ink_from: usize, ink_to: usize| Gray {
+        width: 120,
+        height: 160,
+        pixels: (0..120 * 160)
+            .map(|i| {
+                let x = i % 120;
+                if (ink_from..ink_to).contains(&x) && i >= 120 {
+                    0.0
+                } else {
+                    255.0
+                }
+            })
+            .collect(),
+    };
+
+    let left = ink_grid(&page(0, 40));
+    assert!(correlation(&left, &ink_grid(&page(0, 40))) > 0.99);
+    assert!(correlation(&left, &ink_grid(&page(80, 120))) < MIN_CORRELATION);
+}
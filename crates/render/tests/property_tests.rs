@@ -0,0 +1,28 @@
+//! Property tests: the renderer must not panic on any valid resume.
+//!
+//! Rendering is slow, so the case count is kept low; failures still shrink to
+//! a minimal resume that reproduces the problem.
+
+use proptest::prelude::*;
+use rustume_render::{Renderer, TypstRenderer, TEMPLATES};
+use rustume_test_support::{arb_resume_data_with_templates, check_valid};
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(16))]
+
+    #[test]
+    fn render_pdf_never_panics(resume in arb_resume_data_with_templates(TEMPLATES)) {
+        check_valid(&resume)?;
+
+        // Errors are acceptable (e.g. unsupported glyphs); panics are not.
+        if let Ok(pdf) = TypstRenderer::new().render_pdf(&resume) {
+            prop_assert!(pdf.starts_with(b"%PDF"), "output is not a PDF");
+        }
+    }
+
+    #[test]
+    fn source_generation_never_panics(resume in arb_resume_data_with_templates(TEMPLATES)) {
+        let source = TypstRenderer::new().generate_source(&resume);
+        prop_assert!(source.is_ok(), "generate_source failed: {:?}", source.err());
+    }
+}
@@ -1,7 +1,8 @@
 //! Procedural macros for Rustume schema types.
 //!
 //! This crate provides the `SectionItem` derive macro that reduces boilerplate
-//! for resume section item types.
+//! for resume section item types, and the `TypeScript` derive that writes a
+//! type's TypeScript declaration for the WASM package.
 //!
 //! # Example
 //!
@@ -20,28 +21,94 @@
 //!
 //! This generates:
 //! - `id: String` and `visible: bool` fields (injected)
-//! - `Default` impl with `id: String::new()`, `visible: true`
+//! - `Default` impl with `id: String::new()`, `visible: true`, and the
+//!   `#[serde(default = "...")]` function of fields that name one
 //! - `new(company, position)` constructor with auto-generated ID
 //! - `with_location()` builder for optional fields
+//! - a `LegacyFields` impl listing renamed keys
+//!
+//! ## IDs
+//!
+//! `new()` gives each item a CUID2 ID. `#[section_item(id = "slug")]` derives
+//! the ID from the first `new()` argument instead (`Rust Conf` becomes
+//! `rust-conf`), and `id = "none"` leaves it empty for the caller to set.
+//!
+//! ## Validated fields
+//!
+//! A builder for a field with `#[validate(range(min = .., max = ..))]` clamps
+//! its argument into the range, so a built item always validates.
+//!
+//! ## Renamed fields
+//!
+//! `#[section_item(alias(employer = "company"))]` keeps JSON that still says
+//! `employer` loading into `company`. Serde only reads aliases from field
+//! attributes, which a derive cannot add, so the old keys are moved by the
+//! `LegacyFields` impl before deserializing: `Section` does it for its items,
+//! and `ResumeData::from_json_value` also reports each one found as a
+//! deprecation.
+
+mod serde_attrs;
+mod typescript;
 
 use proc_macro::TokenStream;
 use quote::{format_ident, quote};
+use serde_attrs::{serde_key, serde_str_arg};
 use syn::{
     parse::{Parse, ParseStream},
     parse_macro_input,
     punctuated::Punctuated,
-    Attribute, DeriveInput, Field, Fields, Ident, Result, Token,
+    Attribute, DeriveInput, Field, Fields, Ident, LitStr, Result, Token,
 };
 
+/// How `new()` fills in the item ID.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum IdStrategy {
+    /// A generated CUID2.
+    Cuid,
+    /// A slug of the first `new()` argument.
+    Slug,
+    /// Left empty.
+    None,
+}
+
 /// Arguments for the section_item attribute.
 struct SectionItemArgs {
     /// Fields required in the `new()` constructor.
     new_args: Vec<Ident>,
+    /// Old JSON keys and the fields they were renamed to.
+    aliases: Vec<(Ident, LitStr)>,
+    /// How `new()` fills in the ID.
+    id: IdStrategy,
+}
+
+impl Default for SectionItemArgs {
+    fn default() -> Self {
+        SectionItemArgs {
+            new_args: Vec::new(),
+            aliases: Vec::new(),
+            id: IdStrategy::Cuid,
+        }
+    }
+}
+
+/// One `old_key = "field"` pair inside `alias(...)`.
+struct AliasArg {
+    old: Ident,
+    field: LitStr,
+}
+
+impl Parse for AliasArg {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let old = input.parse()?;
+        let _: Token![=] = input.parse()?;
+        let field = input.parse()?;
+        Ok(AliasArg { old, field })
+    }
 }
 
 impl Parse for SectionItemArgs {
     fn parse(input: ParseStream) -> Result<Self> {
-        let mut new_args = Vec::new();
+        let mut args = SectionItemArgs::default();
 
         while !input.is_empty() {
             let ident: Ident = input.parse()?;
@@ -49,9 +116,37 @@ impl Parse for SectionItemArgs {
                 // Parse: new(field1, field2, ...)
                 let content;
                 syn::parenthesized!(content in input);
-                let args: Punctuated<Ident, Token![,]> =
+                let parsed: Punctuated<Ident, Token![,]> =
                     content.parse_terminated(Ident::parse, Token![,])?;
-                new_args = args.into_iter().collect();
+                args.new_args = parsed.into_iter().collect();
+            } else if ident == "alias" {
+                // Parse: alias(old_key = "field", ...)
+                let content;
+                syn::parenthesized!(content in input);
+                let parsed: Punctuated<AliasArg, Token![,]> =
+                    content.parse_terminated(AliasArg::parse, Token![,])?;
+                args.aliases
+                    .extend(parsed.into_iter().map(|arg| (arg.old, arg.field)));
+            } else if ident == "id" {
+                // Parse: id = "cuid" | "slug" | "none"
+                let _: Token![=] = input.parse()?;
+                let strategy: LitStr = input.parse()?;
+                args.id = match strategy.value().as_str() {
+                    "cuid" => IdStrategy::Cuid,
+                    "slug" => IdStrategy::Slug,
+                    "none" => IdStrategy::None,
+                    _ => {
+                        return Err(syn::Error::new(
+                            strategy.span(),
+                            "expected id = \"cuid\", \"slug\", or \"none\"",
+                        ))
+                    }
+                };
+            } else {
+                return Err(syn::Error::new(
+                    ident.span(),
+                    "expected `new(...)`, `alias(...)`, or `id = \"...\"`",
+                ));
             }
 
             // Handle trailing comma
@@ -60,18 +155,19 @@ impl Parse for SectionItemArgs {
             }
         }
 
-        Ok(SectionItemArgs { new_args })
+        Ok(args)
     }
 }
 
 /// Find the section_item attribute and parse its arguments.
-fn parse_section_item_attr(attrs: &[Attribute]) -> Option<SectionItemArgs> {
-    for attr in attrs {
-        if attr.path().is_ident("section_item") {
-            return attr.parse_args::<SectionItemArgs>().ok();
-        }
+fn parse_section_item_attr(attrs: &[Attribute]) -> Result<SectionItemArgs> {
+    match attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("section_item"))
+    {
+        Some(attr) => attr.parse_args(),
+        None => Ok(SectionItemArgs::default()),
     }
-    None
 }
 
 /// Check if a field has `#[serde(default)]` or `#[serde(default = "...")]`.
@@ -120,6 +216,74 @@ fn is_vec_type(field: &Field) -> bool {
     false
 }
 
+/// The type of the field named `name`.
+fn field_type<'a>(fields: impl IntoIterator<Item = &'a Field>, name: &Ident) -> &'a syn::Type {
+    fields
+        .into_iter()
+        .find(|f| f.ident.as_ref() == Some(name))
+        .map(|f| &f.ty)
+        .unwrap_or_else(|| panic!("Field '{}' not found in struct", name))
+}
+
+/// The bounds of `#[validate(range(min = .., max = ..))]` on `field`.
+fn validate_range(field: &Field) -> Option<(Option<syn::Expr>, Option<syn::Expr>)> {
+    field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("validate"))
+        .filter_map(|attr| {
+            attr.parse_args_with(Punctuated::<syn::Meta, Token![,]>::parse_terminated)
+                .ok()
+        })
+        .flatten()
+        .find_map(|meta| match meta {
+            syn::Meta::List(list) if list.path.is_ident("range") => {
+                let bounds = list
+                    .parse_args_with(Punctuated::<syn::MetaNameValue, Token![,]>::parse_terminated)
+                    .ok()?;
+                let bound = |key: &str| {
+                    bounds
+                        .iter()
+                        .find(|nv| nv.path.is_ident(key))
+                        .map(|nv| nv.value.clone())
+                };
+                Some((bound("min"), bound("max")))
+            }
+            _ => None,
+        })
+}
+
+/// What a field starts as in `Default` and `new()`: what serde fills in
+/// for a missing key.
+fn default_value(field: &Field) -> proc_macro2::TokenStream {
+    let ty = &field.ty;
+    if let Some(path) = serde_str_arg(&field.attrs, "default") {
+        let path: syn::ExprPath =
+            syn::parse_str(&path).unwrap_or_else(|_| panic!("Invalid serde default '{}'", path));
+        quote! { #path() }
+    } else if is_vec_type(field) {
+        quote! { Vec::new() }
+    } else if is_url_type(field) {
+        quote! { Url::default() }
+    } else {
+        quote! { <#ty>::default() }
+    }
+}
+
+/// How a builder's doc comment names `field`: `study_type` is "study type"
+/// and `url` is "URL".
+fn builder_subject(field: &Ident) -> String {
+    field
+        .to_string()
+        .split('_')
+        .map(|word| match word {
+            "url" => "URL".to_string(),
+            word => word.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
 /// Derive macro for section item types.
 ///
 /// Generates `Default`, `new()` constructor, and `with_*` builder methods.
@@ -129,9 +293,10 @@ pub fn derive_section_item(input: TokenStream) -> TokenStream {
     let name = &input.ident;
 
     // Parse section_item attribute
-    let args = parse_section_item_attr(&input.attrs).unwrap_or(SectionItemArgs {
-        new_args: Vec::new(),
-    });
+    let args = match parse_section_item_attr(&input.attrs) {
+        Ok(args) => args,
+        Err(err) => return err.to_compile_error().into(),
+    };
 
     // Get struct fields
     let fields = match &input.data {
@@ -160,15 +325,8 @@ pub fn derive_section_item(input: TokenStream) -> TokenStream {
             "id" => quote! { id: String::new() },
             "visible" => quote! { visible: true },
             _ => {
-                let ty = &f.ty;
-                // Check field type for appropriate default
-                if is_vec_type(f) {
-                    quote! { #field_name: Vec::new() }
-                } else if is_url_type(f) {
-                    quote! { #field_name: Url::default() }
-                } else {
-                    quote! { #field_name: <#ty>::default() }
-                }
+                let value = default_value(f);
+                quote! { #field_name: #value }
             }
         }
     });
@@ -188,50 +346,56 @@ pub fn derive_section_item(input: TokenStream) -> TokenStream {
         .new_args
         .iter()
         .map(|arg| {
-            // Find the field type
-            let field = fields
-                .iter()
-                .find(|f| f.ident.as_ref().unwrap() == arg)
-                .unwrap_or_else(|| panic!("Field '{}' not found in struct", arg));
-            let ty = &field.ty;
+            let ty = field_type(fields, arg);
             quote! { #arg: impl Into<#ty> }
         })
         .collect();
 
+    let id_value = match args.id {
+        IdStrategy::Cuid => quote! { cuid2::create_id() },
+        IdStrategy::Slug => {
+            let source = args
+                .new_args
+                .first()
+                .unwrap_or_else(|| panic!("id = \"slug\" needs a field in new(...)"));
+            quote! { rustume_utils::slugify(&#source) }
+        }
+        IdStrategy::None => quote! { String::new() },
+    };
+
     let new_field_inits = fields.iter().map(|f| {
         let field_name = f.ident.as_ref().unwrap();
         let field_name_str = field_name.to_string();
 
         if field_name_str == "id" {
-            quote! { id: cuid2::create_id() }
+            quote! { id }
         } else if field_name_str == "visible" {
             quote! { visible: true }
         } else if args.new_args.iter().any(|a| a == field_name) {
-            quote! { #field_name: #field_name.into() }
+            quote! { #field_name }
         } else {
-            quote! { #field_name: Default::default() }
+            let value = default_value(f);
+            quote! { #field_name: #value }
         }
     });
 
-    let new_impl = if args.new_args.is_empty() {
-        // No-arg constructor
-        quote! {
-            /// Create a new item with a generated ID.
-            pub fn new() -> Self {
-                Self {
-                    id: cuid2::create_id(),
-                    visible: true,
-                    ..Default::default()
-                }
-            }
-        }
+    // Convert the arguments first so a slug can read its source
+    let new_arg_lets = args.new_args.iter().map(|arg| {
+        let ty = field_type(fields, arg);
+        quote! { let #arg: #ty = #arg.into(); }
+    });
+    let new_doc = if args.new_args.is_empty() {
+        " Create a new item with a generated ID."
     } else {
-        quote! {
-            /// Create a new item with the required fields.
-            pub fn new(#(#new_params),*) -> Self {
-                Self {
-                    #(#new_field_inits),*
-                }
+        " Create a new item with the required fields."
+    };
+    let new_impl = quote! {
+        #[doc = #new_doc]
+        pub fn new(#(#new_params),*) -> Self {
+            #(#new_arg_lets)*
+            let id = #id_value;
+            Self {
+                #(#new_field_inits),*
             }
         }
     };
@@ -254,11 +418,31 @@ pub fn derive_section_item(input: TokenStream) -> TokenStream {
             let field_name = f.ident.as_ref().unwrap();
             let method_name = format_ident!("with_{}", field_name);
             let ty = &f.ty;
+            let doc = format!(" Builder method to set {}.", builder_subject(field_name));
 
             // Handle different types
-            if is_url_type(f) {
+            if let Some((min, max)) = validate_range(f) {
+                // Keep the value inside the range the field validates against
+                let clamp = match (min, max) {
+                    (Some(min), Some(max)) => quote! { #field_name.clamp(#min, #max) },
+                    (Some(min), None) => quote! { #field_name.max(#min) },
+                    (None, Some(max)) => quote! { #field_name.min(#max) },
+                    (None, None) => quote! { #field_name },
+                };
+                let doc = format!(
+                    " Builder method to set {}, clamped to its valid range.",
+                    builder_subject(field_name)
+                );
+                quote! {
+                    #[doc = #doc]
+                    pub fn #method_name(mut self, #field_name: #ty) -> Self {
+                        self.#field_name = #clamp;
+                        self
+                    }
+                }
+            } else if is_url_type(f) {
                 quote! {
-                    /// Builder method to set the URL.
+                    #[doc = #doc]
                     pub fn #method_name(mut self, url: impl Into<String>) -> Self {
                         self.#field_name = Url::new(url);
                         self
@@ -266,7 +450,7 @@ pub fn derive_section_item(input: TokenStream) -> TokenStream {
                 }
             } else if is_vec_type(f) {
                 quote! {
-                    /// Builder method to set this field.
+                    #[doc = #doc]
                     pub fn #method_name(mut self, #field_name: #ty) -> Self {
                         self.#field_name = #field_name;
                         self
@@ -274,7 +458,7 @@ pub fn derive_section_item(input: TokenStream) -> TokenStream {
                 }
             } else {
                 quote! {
-                    /// Builder method to set this field.
+                    #[doc = #doc]
                     pub fn #method_name(mut self, #field_name: impl Into<#ty>) -> Self {
                         self.#field_name = #field_name.into();
                         self
@@ -292,14 +476,59 @@ pub fn derive_section_item(input: TokenStream) -> TokenStream {
         }
     };
 
+    // Map each old key to the current key of the field it names
+    let rename_all = serde_str_arg(&input.attrs, "rename_all");
+    let field_keys: Vec<(String, String)> = fields
+        .iter()
+        .map(|f| {
+            (
+                f.ident.as_ref().unwrap().to_string(),
+                serde_key(f, rename_all.as_deref()),
+            )
+        })
+        .collect();
+    let aliases = args.aliases.iter().map(|(old, field)| {
+        let old = old.to_string();
+        let field = field.value();
+        let (_, key) = field_keys
+            .iter()
+            .find(|(name, _)| *name == field)
+            .unwrap_or_else(|| panic!("Alias target '{}' not found in struct", field));
+        if field_keys.iter().any(|(_, existing)| *existing == old) {
+            panic!("Alias '{}' is still the key of a field", old);
+        }
+        quote! { (#old, #key) }
+    });
+    let name_str = name.to_string();
+
+    let legacy_impl = quote! {
+        impl LegacyFields for #name {
+            const ITEM: &'static str = #name_str;
+            const FIELD_ALIASES: &'static [(&'static str, &'static str)] = &[#(#aliases),*];
+        }
+    };
+
     let expanded = quote! {
         #default_impl
         #impl_block
+        #legacy_impl
     };
 
     TokenStream::from(expanded)
 }
 
+/// Derive macro for the TypeScript declaration of a schema type.
+///
+/// Implements `TypeScript` with the declaration as the type serializes to
+/// JSON: keys follow `rename_all` and `rename`, fields with
+/// `skip_serializing_if` are optional, and doc comments become JSDoc.
+/// Supports structs with named fields, newtypes, and enums of unit variants.
+#[proc_macro_derive(TypeScript)]
+pub fn derive_typescript(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    TokenStream::from(typescript::expand(&input))
+}
+
 /// Helper macro attribute for documentation (no-op, parsed by SectionItem).
 #[proc_macro_attribute]
 pub fn section_item(_attr: TokenStream, item: TokenStream) -> TokenStream {
@@ -0,0 +1,93 @@
+//! Reading the `#[serde(...)]` attributes that decide JSON keys.
+
+use syn::{punctuated::Punctuated, Attribute, Field, Meta, Token};
+
+/// The nested metas of every `#[serde(...)]` among `attrs`.
+fn serde_metas(attrs: &[Attribute]) -> impl Iterator<Item = Meta> + '_ {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("serde"))
+        .filter_map(|attr| {
+            attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+                .ok()
+        })
+        .flatten()
+}
+
+/// The string value of `#[serde(<key> = "...")]` among `attrs`.
+pub(crate) fn serde_str_arg(attrs: &[Attribute], key: &str) -> Option<String> {
+    serde_metas(attrs).find_map(|meta| match meta {
+        Meta::NameValue(nv) if nv.path.is_ident(key) => match nv.value {
+            syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Str(value),
+                ..
+            }) => Some(value.value()),
+            _ => None,
+        },
+        _ => None,
+    })
+}
+
+/// Whether `attrs` hold `#[serde(<key>)]` or `#[serde(<key> = ...)]`.
+pub(crate) fn has_serde_arg(attrs: &[Attribute], key: &str) -> bool {
+    serde_metas(attrs).any(|meta| meta.path().is_ident(key))
+}
+
+/// The JSON key serde uses for `field` under the struct's `rename_all` rule.
+pub(crate) fn serde_key(field: &Field, rename_all: Option<&str>) -> String {
+    if let Some(renamed) = serde_str_arg(&field.attrs, "rename") {
+        return renamed;
+    }
+    let name = field.ident.as_ref().unwrap().to_string();
+    match rename_all {
+        None | Some("snake_case") | Some("lowercase") => name,
+        Some("camelCase") => {
+            let mut parts = name.split('_');
+            let mut key = parts.next().unwrap_or_default().to_string();
+            for part in parts {
+                let mut chars = part.chars();
+                if let Some(first) = chars.next() {
+                    key.extend(first.to_uppercase());
+                    key.push_str(chars.as_str());
+                }
+            }
+            key
+        }
+        Some("kebab-case") => name.replace('_', "-"),
+        Some(other) => panic!("rename_all = \"{}\" is not supported on structs", other),
+    }
+}
+
+/// The JSON string serde uses for a unit variant under the enum's
+/// `rename_all` rule.
+pub(crate) fn serde_variant(variant: &syn::Variant, rename_all: Option<&str>) -> String {
+    if let Some(renamed) = serde_str_arg(&variant.attrs, "rename") {
+        return renamed;
+    }
+    let name = variant.ident.to_string();
+    let words = |separator: char| {
+        let mut value = String::new();
+        for (i, c) in name.chars().enumerate() {
+            if c.is_uppercase() && i > 0 {
+                value.push(separator);
+            }
+            value.extend(c.to_lowercase());
+        }
+        value
+    };
+    match rename_all {
+        None => name.clone(),
+        Some("lowercase") => name.to_lowercase(),
+        Some("snake_case") => words('_'),
+        Some("SCREAMING_SNAKE_CASE") => words('_').to_uppercase(),
+        Some("kebab-case") => words('-'),
+        Some("camelCase") => {
+            let mut chars = name.chars();
+            chars
+                .next()
+                .map(|first| first.to_lowercase().chain(chars).collect())
+                .unwrap_or_default()
+        }
+        Some(other) => panic!("rename_all = \"{}\" is not supported on enums", other),
+    }
+}
@@ -0,0 +1,232 @@
+//! The `TypeScript` derive: a type's declaration as it serializes to JSON.
+
+use crate::serde_attrs::{has_serde_arg, serde_key, serde_str_arg, serde_variant};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Attribute, Data, DeriveInput, Fields, GenericArgument, PathArguments, Type};
+
+/// Line width past which a string union is written one member per line.
+const MAX_LINE: usize = 100;
+
+/// Expand `#[derive(TypeScript)]` into an impl carrying the declaration.
+pub(crate) fn expand(input: &DeriveInput) -> TokenStream {
+    let name = &input.ident;
+    let declaration = declaration(input);
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics TypeScript for #name #ty_generics #where_clause {
+            const DECLARATION: &'static str = #declaration;
+        }
+    }
+}
+
+fn declaration(input: &DeriveInput) -> String {
+    let name = input.ident.to_string();
+    let params: Vec<String> = input
+        .generics
+        .type_params()
+        .map(|param| param.ident.to_string())
+        .collect();
+    let name = if params.is_empty() {
+        name
+    } else {
+        format!("{}<{}>", name, params.join(", "))
+    };
+    let rename_all = serde_str_arg(&input.attrs, "rename_all");
+    let mut out = doc_comment(&input.attrs, "");
+
+    match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => {
+                out.push_str(&format!("export interface {} {{\n", name));
+                for field in &fields.named {
+                    if has_serde_arg(&field.attrs, "skip")
+                        || has_serde_arg(&field.attrs, "skip_serializing")
+                    {
+                        continue;
+                    }
+                    if has_serde_arg(&field.attrs, "flatten") {
+                        panic!("TypeScript does not support #[serde(flatten)]");
+                    }
+                    let key = serde_key(field, rename_all.as_deref());
+                    // Skipped values are absent, so `None` never reaches JSON as `null`
+                    let optional = has_serde_arg(&field.attrs, "skip_serializing_if");
+                    let ty = match (optional, option_inner(&field.ty)) {
+                        (true, Some(inner)) => ts_type(inner),
+                        _ => ts_type(&field.ty),
+                    };
+                    out.push_str(&doc_comment(&field.attrs, "  "));
+                    out.push_str(&format!(
+                        "  {}{}: {};\n",
+                        key,
+                        if optional { "?" } else { "" },
+                        ty
+                    ));
+                }
+                out.push_str("}\n");
+            }
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                // Newtypes serialize as their single field
+                let ty = ts_type(&fields.unnamed[0].ty);
+                out.push_str(&format!("export type {} = {};\n", name, ty));
+            }
+            _ => panic!("TypeScript supports structs with named fields and newtypes"),
+        },
+        Data::Enum(data) => {
+            let members: Vec<String> = data
+                .variants
+                .iter()
+                .map(|variant| {
+                    if !matches!(variant.fields, Fields::Unit) {
+                        panic!("TypeScript supports enums with unit variants only");
+                    }
+                    format!("\"{}\"", serde_variant(variant, rename_all.as_deref()))
+                })
+                .collect();
+            let line = format!("export type {} = {};", name, members.join(" | "));
+            if line.len() <= MAX_LINE {
+                out.push_str(&line);
+                out.push('\n');
+            } else {
+                out.push_str(&format!("export type {} =\n", name));
+                let last = members.len() - 1;
+                for (i, member) in members.iter().enumerate() {
+                    let end = if i == last { ";" } else { "" };
+                    out.push_str(&format!("  | {}{}\n", member, end));
+                }
+            }
+        }
+        Data::Union(_) => panic!("TypeScript does not support unions"),
+    }
+    out
+}
+
+/// `T` when `ty` is `Option<T>`.
+fn option_inner(ty: &Type) -> Option<&Type> {
+    match generic_args(ty) {
+        Some(("Option", args)) => args.first().copied(),
+        _ => None,
+    }
+}
+
+/// The last path segment of `ty` and its type arguments.
+fn generic_args(ty: &Type) -> Option<(&str, Vec<&Type>)> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    let args = match &segment.arguments {
+        PathArguments::AngleBracketed(args) => args
+            .args
+            .iter()
+            .filter_map(|arg| match arg {
+                GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            })
+            .collect(),
+        _ => Vec::new(),
+    };
+    let ident = segment.ident.to_string();
+    let name = match ident.as_str() {
+        "Option" => "Option",
+        "Vec" | "VecDeque" | "HashSet" | "BTreeSet" => "Vec",
+        "HashMap" | "BTreeMap" | "IndexMap" => "Map",
+        "Box" | "Arc" | "Rc" => "Box",
+        _ => return Some(("", args)),
+    };
+    Some((name, args))
+}
+
+/// The TypeScript type a Rust type serializes as.
+fn ts_type(ty: &Type) -> String {
+    match ty {
+        Type::Reference(reference) => ts_type(&reference.elem),
+        Type::Slice(slice) => array_of(&ts_type(&slice.elem)),
+        Type::Array(array) => array_of(&ts_type(&array.elem)),
+        Type::Tuple(tuple) if tuple.elems.is_empty() => "null".to_string(),
+        Type::Tuple(tuple) => {
+            let elems: Vec<String> = tuple.elems.iter().map(ts_type).collect();
+            format!("[{}]", elems.join(", "))
+        }
+        Type::Path(path) => {
+            let segment = path.path.segments.last().expect("type paths are not empty");
+            let ident = segment.ident.to_string();
+            let (kind, args) = generic_args(ty).unwrap_or(("", Vec::new()));
+            match (kind, args.as_slice()) {
+                ("Option", [inner]) => format!("{} | null", ts_type(inner)),
+                ("Vec", [inner]) => array_of(&ts_type(inner)),
+                ("Map", [_, value]) => format!("Record<string, {}>", ts_type(value)),
+                ("Box", [inner]) => ts_type(inner),
+                _ => match ident.as_str() {
+                    "String" | "str" | "char" => "string".to_string(),
+                    "bool" => "boolean".to_string(),
+                    "u8" | "u16" | "u32" | "u64" | "usize" | "i8" | "i16" | "i32" | "i64"
+                    | "isize" | "f32" | "f64" => "number".to_string(),
+                    "Value" => "unknown".to_string(),
+                    _ if args.is_empty() => ident,
+                    _ => {
+                        let args: Vec<String> = args.iter().map(|arg| ts_type(arg)).collect();
+                        format!("{}<{}>", ident, args.join(", "))
+                    }
+                },
+            }
+        }
+        _ => panic!("TypeScript does not support this field type"),
+    }
+}
+
+/// `T[]`, parenthesizing unions.
+fn array_of(elem: &str) -> String {
+    if elem.contains(" | ") {
+        format!("({})[]", elem)
+    } else {
+        format!("{}[]", elem)
+    }
+}
+
+/// The doc comment in `attrs` as JSDoc, indented by `indent`; empty when
+/// there is none.
+fn doc_comment(attrs: &[Attribute], indent: &str) -> String {
+    let lines: Vec<String> = attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc"))
+        .filter_map(|attr| match &attr.meta {
+            syn::Meta::NameValue(nv) => match &nv.value {
+                syn::Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Str(line),
+                    ..
+                }) => Some(line.value()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .map(|line| {
+            let line = line.strip_prefix(' ').unwrap_or(&line).trim_end();
+            line.replace("*/", "*\\/")
+        })
+        .collect();
+    let start = lines.iter().position(|line| !line.is_empty());
+    let end = lines.iter().rposition(|line| !line.is_empty());
+    let (Some(start), Some(end)) = (start, end) else {
+        return String::new();
+    };
+    let lines = &lines[start..=end];
+
+    if let [line] = lines {
+        let single = format!("{}/** {} */\n", indent, line);
+        if single.len() <= MAX_LINE + 1 {
+            return single;
+        }
+    }
+    let mut out = format!("{}/**\n", indent);
+    for line in lines {
+        if line.is_empty() {
+            out.push_str(&format!("{} *\n", indent));
+        } else {
+            out.push_str(&format!("{} * {}\n", indent, line));
+        }
+    }
+    out.push_str(&format!("{} */\n", indent));
+    out
+}
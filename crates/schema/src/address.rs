@@ -0,0 +1,221 @@
+//! Postal addresses split into parts.
+//!
+//! `basics.location` stays a free-form line such as `Berlin, Germany`. The
+//! parts in `basics.address` keep an international address unambiguous and
+//! let it print in the order its country expects: postal code before the
+//! city in most of Europe, after the region in North America, on its own
+//! line in the UK, and largest unit first in East Asia. Importers fill the
+//! parts from structured fields where a format has them.
+
+use rustume_utils::locale::region_subtag;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::TypeScript;
+
+/// A postal address in parts.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default, ToSchema, TypeScript)]
+#[serde(rename_all = "camelCase")]
+pub struct Address {
+    /// Street and house number, with any flat or building.
+    #[serde(default)]
+    pub street: String,
+
+    /// City, town, or locality.
+    #[serde(default)]
+    pub city: String,
+
+    /// State, province, prefecture, or county.
+    #[serde(default)]
+    pub region: String,
+
+    /// Postal or ZIP code.
+    #[serde(default)]
+    pub postal_code: String,
+
+    /// Country, as an ISO 3166 code such as `DE` or a name.
+    #[serde(default)]
+    pub country: String,
+}
+
+/// How a country orders the parts of an address.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum AddressStyle {
+    /// `City, Region 12345`, as in the United States.
+    CityRegionPostal,
+    /// `12345 City`, as in most of Europe and Latin America.
+    PostalCity,
+    /// City and postal code on lines of their own, as in the UK.
+    CityThenPostal,
+    /// Country, postal code, region, city, then street, as in Japan.
+    LargestFirst,
+}
+
+const POSTAL_CITY_COUNTRIES: &[&str] = &[
+    "AR", "AT", "BE", "BR", "CH", "CZ", "DE", "DK", "ES", "FI", "FR", "GR", "HU", "IL", "IS", "IT",
+    "LU", "MX", "NL", "NO", "PL", "PT", "SE", "SK", "TR",
+];
+
+const CITY_THEN_POSTAL_COUNTRIES: &[&str] = &["GB", "IE"];
+
+const LARGEST_FIRST_COUNTRIES: &[&str] = &["CN", "JP", "KR", "TW"];
+
+impl AddressStyle {
+    fn for_country(code: Option<&str>) -> Self {
+        let Some(code) = code else {
+            return Self::CityRegionPostal;
+        };
+        if POSTAL_CITY_COUNTRIES.contains(&code) {
+            Self::PostalCity
+        } else if CITY_THEN_POSTAL_COUNTRIES.contains(&code) {
+            Self::CityThenPostal
+        } else if LARGEST_FIRST_COUNTRIES.contains(&code) {
+            Self::LargestFirst
+        } else {
+            Self::CityRegionPostal
+        }
+    }
+}
+
+impl Address {
+    /// Whether no part is filled in.
+    pub fn is_empty(&self) -> bool {
+        [
+            &self.street,
+            &self.city,
+            &self.region,
+            &self.postal_code,
+            &self.country,
+        ]
+        .iter()
+        .all(|part| part.trim().is_empty())
+    }
+
+    /// `country` as an uppercase ISO 3166 alpha-2 code, when it is one.
+    pub fn country_code(&self) -> Option<String> {
+        let country = self.country.trim();
+        (country.len() == 2 && country.chars().all(|c| c.is_ascii_alphabetic()))
+            .then(|| country.to_ascii_uppercase())
+    }
+
+    /// Short form for a resume header: city, region, and country, such as
+    /// `Toronto, ON, CA`. Street and postal code are left out.
+    pub fn locality(&self) -> String {
+        join_parts([self.city.as_str(), &self.region, &self.country], ", ")
+    }
+
+    /// The address as postal lines in the order its country writes them.
+    ///
+    /// The country is read from `country`, or from the region of `locale`
+    /// (`de-AT` is Austria) when `country` isn't a code. The country line is
+    /// left out when it is the locale's own region, as for a domestic letter.
+    pub fn lines(&self, locale: &str) -> Vec<String> {
+        let home = region_subtag(locale);
+        let code = self.country_code();
+        let style = AddressStyle::for_country(code.as_deref().or(home.as_deref()));
+        let domestic = code.is_some() && code == home;
+        let country = if domestic { "" } else { self.country.trim() };
+
+        let street = self.street.trim();
+        let city = self.city.trim();
+        let region = self.region.trim();
+        let postal = self.postal_code.trim();
+
+        let lines = match style {
+            AddressStyle::CityRegionPostal => {
+                // `City, ST 12345`, or `City 12345` without a region
+                let locality = if region.is_empty() {
+                    join_parts([city, postal], " ")
+                } else {
+                    join_parts([city, &join_parts([region, postal], " ")], ", ")
+                };
+                vec![street.to_string(), locality, country.to_string()]
+            }
+            AddressStyle::PostalCity => vec![
+                street.to_string(),
+                join_parts([postal, city], " "),
+                region.to_string(),
+                country.to_string(),
+            ],
+            AddressStyle::CityThenPostal => vec![
+                street.to_string(),
+                city.to_string(),
+                region.to_string(),
+                postal.to_string(),
+                country.to_string(),
+            ],
+            AddressStyle::LargestFirst => vec![
+                country.to_string(),
+                postal.to_string(),
+                join_parts([region, city], " "),
+                street.to_string(),
+            ],
+        };
+        lines.into_iter().filter(|line| !line.is_empty()).collect()
+    }
+
+    /// [`lines`](Self::lines) on one line, separated by commas.
+    pub fn format(&self, locale: &str) -> String {
+        self.lines(locale).join(", ")
+    }
+}
+
+fn join_parts<'a>(parts: impl IntoIterator<Item = &'a str>, separator: &str) -> String {
+    parts
+        .into_iter()
+        .map(str::trim)
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn address(street: &str, city: &str, region: &str, postal: &str, country: &str) -> Address {
+        Address {
+            street: street.to_string(),
+            city: city.to_string(),
+            region: region.to_string(),
+            postal_code: postal.to_string(),
+            country: country.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_lines_follow_country_order() {
+        let us = address("1 Main St", "Springfield", "IL", "62701", "US");
+        assert_eq!(us.lines("en"), ["1 Main St", "Springfield, IL 62701", "US"]);
+        // A domestic address leaves the country out
+        assert_eq!(us.format("en-US"), "1 Main St, Springfield, IL 62701");
+
+        let de = address("Unter den Linden 1", "Berlin", "", "10117", "DE");
+        assert_eq!(de.format("en"), "Unter den Linden 1, 10117 Berlin, DE");
+
+        let gb = address("10 Downing St", "London", "", "SW1A 2AA", "GB");
+        assert_eq!(gb.lines("en-GB"), ["10 Downing St", "London", "SW1A 2AA"]);
+
+        let jp = address("1-1 Chiyoda", "Chiyoda", "Tokyo", "100-0001", "JP");
+        assert_eq!(
+            jp.lines("ja-JP"),
+            ["100-0001", "Tokyo Chiyoda", "1-1 Chiyoda"]
+        );
+    }
+
+    #[test]
+    fn test_locale_region_sets_style_without_country_code() {
+        let address = address("Hauptstraße 5", "Wien", "", "1010", "");
+        assert_eq!(address.format("de-AT"), "Hauptstraße 5, 1010 Wien");
+        assert_eq!(address.format("en"), "Hauptstraße 5, Wien 1010");
+    }
+
+    #[test]
+    fn test_locality_and_emptiness() {
+        let address = address("", "Toronto", "ON", "M5V 2T6", "Canada");
+        assert_eq!(address.locality(), "Toronto, ON, Canada");
+        assert_eq!(address.country_code(), None);
+        assert!(!address.is_empty());
+        assert!(Address::default().is_empty());
+    }
+}
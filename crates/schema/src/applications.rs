@@ -0,0 +1,247 @@
+//! Job application tracking.
+//!
+//! A [`JobApplication`] records where a resume was sent and how the
+//! application is progressing. Applications live next to resumes in storage
+//! rather than inside [`ResumeData`](crate::ResumeData), and link to the
+//! tailored resume by ID.
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+use crate::TypeScript;
+use crate::{validate_optional_date, Url};
+
+/// Pipeline stage of a job application.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema, TypeScript,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum ApplicationStatus {
+    /// Saved for later; not yet applied.
+    #[default]
+    Saved,
+    Applied,
+    Interviewing,
+    Offer,
+    Accepted,
+    Rejected,
+    Withdrawn,
+}
+
+impl ApplicationStatus {
+    /// All statuses in pipeline order.
+    pub const ALL: [ApplicationStatus; 7] = [
+        Self::Saved,
+        Self::Applied,
+        Self::Interviewing,
+        Self::Offer,
+        Self::Accepted,
+        Self::Rejected,
+        Self::Withdrawn,
+    ];
+
+    /// Wire name (`saved`, `applied`, ...).
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Saved => "saved",
+            Self::Applied => "applied",
+            Self::Interviewing => "interviewing",
+            Self::Offer => "offer",
+            Self::Accepted => "accepted",
+            Self::Rejected => "rejected",
+            Self::Withdrawn => "withdrawn",
+        }
+    }
+
+    /// Whether the application has reached a final outcome.
+    pub fn is_closed(self) -> bool {
+        matches!(self, Self::Accepted | Self::Rejected | Self::Withdrawn)
+    }
+}
+
+impl fmt::Display for ApplicationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for ApplicationStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|status| status.as_str().eq_ignore_ascii_case(s.trim()))
+            .ok_or_else(|| format!("Unknown application status: {s}"))
+    }
+}
+
+/// A job application and the resume version sent with it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate, ToSchema, TypeScript)]
+#[serde(rename_all = "camelCase")]
+pub struct JobApplication {
+    pub id: String,
+
+    #[validate(length(min = 1, max = 200, message = "Company is required"))]
+    pub company: String,
+
+    #[validate(length(min = 1, max = 200, message = "Role is required"))]
+    pub role: String,
+
+    #[serde(default)]
+    pub status: ApplicationStatus,
+
+    /// ID of the tailored resume sent with this application.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resume_id: Option<String>,
+
+    /// Job posting link.
+    #[validate(nested)]
+    #[serde(default)]
+    pub url: Url,
+
+    #[serde(default)]
+    pub location: String,
+
+    /// Date the application was sent (`YYYY-MM-DD`).
+    #[validate(custom(function = "validate_optional_date"))]
+    #[serde(default)]
+    pub applied_date: String,
+
+    /// Date to follow up or of the next interview (`YYYY-MM-DD`).
+    #[validate(custom(function = "validate_optional_date"))]
+    #[serde(default)]
+    pub follow_up_date: String,
+
+    #[validate(length(max = 10_000))]
+    #[serde(default)]
+    pub notes: String,
+}
+
+impl JobApplication {
+    /// Create a saved application with a fresh ID.
+    pub fn new(company: impl Into<String>, role: impl Into<String>) -> Self {
+        Self {
+            id: cuid2::create_id(),
+            company: company.into(),
+            role: role.into(),
+            status: ApplicationStatus::default(),
+            resume_id: None,
+            url: Url::default(),
+            location: String::new(),
+            applied_date: String::new(),
+            follow_up_date: String::new(),
+            notes: String::new(),
+        }
+    }
+
+    pub fn with_status(mut self, status: ApplicationStatus) -> Self {
+        self.status = status;
+        self
+    }
+
+    pub fn with_resume(mut self, resume_id: impl Into<String>) -> Self {
+        self.resume_id = Some(resume_id.into());
+        self
+    }
+
+    pub fn with_url(mut self, url: impl Into<String>) -> Self {
+        self.url = Url::new(url);
+        self
+    }
+
+    pub fn with_location(mut self, location: impl Into<String>) -> Self {
+        self.location = location.into();
+        self
+    }
+
+    pub fn with_applied_date(mut self, date: impl Into<String>) -> Self {
+        self.applied_date = date.into();
+        self
+    }
+
+    pub fn with_follow_up_date(mut self, date: impl Into<String>) -> Self {
+        self.follow_up_date = date.into();
+        self
+    }
+
+    pub fn with_notes(mut self, notes: impl Into<String>) -> Self {
+        self.notes = notes.into();
+        self
+    }
+
+    /// Serialize to JSON string.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(self)
+    }
+
+    /// Deserialize from JSON string.
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_application_is_saved_and_valid() {
+        let application = JobApplication::new("Acme", "Staff Engineer");
+        assert!(!application.id.is_empty());
+        assert_eq!(application.status, ApplicationStatus::Saved);
+        assert!(application.validate().is_ok());
+    }
+
+    #[test]
+    fn test_requires_company_and_role() {
+        assert!(JobApplication::new("", "Engineer").validate().is_err());
+        assert!(JobApplication::new("Acme", "").validate().is_err());
+    }
+
+    #[test]
+    fn test_rejects_invalid_dates_and_url() {
+        let application = JobApplication::new("Acme", "Engineer").with_applied_date("March 3rd");
+        assert!(application.validate().is_err());
+
+        let application = JobApplication::new("Acme", "Engineer").with_url("acme.com/jobs");
+        assert!(application.validate().is_err());
+    }
+
+    #[test]
+    fn test_json_roundtrip_uses_camel_case() {
+        let application = JobApplication::new("Acme", "Engineer")
+            .with_status(ApplicationStatus::Interviewing)
+            .with_resume("resume-1")
+            .with_applied_date("2024-05-01");
+
+        let json = application.to_json().unwrap();
+        assert!(json.contains(r#""status":"interviewing""#));
+        assert!(json.contains(r#""resumeId":"resume-1""#));
+        assert!(json.contains(r#""appliedDate":"2024-05-01""#));
+        assert_eq!(JobApplication::from_json(&json).unwrap(), application);
+    }
+
+    #[test]
+    fn test_minimal_json_uses_defaults() {
+        let application =
+            JobApplication::from_json(r#"{"id":"a1","company":"Acme","role":"Engineer"}"#).unwrap();
+        assert_eq!(application.status, ApplicationStatus::Saved);
+        assert!(application.resume_id.is_none());
+    }
+
+    #[test]
+    fn test_status_parse_and_display() {
+        for status in ApplicationStatus::ALL {
+            assert_eq!(status.to_string().parse::<ApplicationStatus>(), Ok(status));
+        }
+        assert_eq!(" Offer ".parse(), Ok(ApplicationStatus::Offer));
+        assert!("ghosted".parse::<ApplicationStatus>().is_err());
+        assert!(ApplicationStatus::Rejected.is_closed());
+        assert!(!ApplicationStatus::Interviewing.is_closed());
+    }
+}
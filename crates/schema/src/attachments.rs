@@ -0,0 +1,217 @@
+//! Files attached to a resume, such as reference letters and certificates.
+//!
+//! An [`Attachment`] holds a file inline as a base64 `data:` URL, a link to a
+//! hosted copy, or both. Its [`AttachmentMode`] picks how the PDF export
+//! presents it: the file's pages appended after the resume, or a QR code
+//! pointing at the link.
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::{Validate, ValidationError};
+
+use crate::validate_optional_url;
+use crate::TypeScript;
+
+/// Media types an attachment file may have. Appended pages are drawn by the
+/// renderer, so only formats it can place on a page are accepted.
+pub const ATTACHMENT_MEDIA_TYPES: &[&str] = &[
+    "application/pdf",
+    "image/png",
+    "image/jpeg",
+    "image/gif",
+    "image/webp",
+    "image/svg+xml",
+];
+
+/// How an attachment is presented in the exported PDF.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema, TypeScript,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum AttachmentMode {
+    /// Add the file's pages after the resume. Needs a `file`.
+    #[default]
+    Append,
+    /// Print a QR code for the link at the end of the resume. Needs a `url`.
+    QrCode,
+}
+
+/// A named file kept with the resume.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate, ToSchema, TypeScript)]
+#[serde(rename_all = "camelCase")]
+pub struct Attachment {
+    /// CUID2 format identifier.
+    #[serde(default = "cuid2::create_id")]
+    pub id: String,
+
+    /// Whether the attachment is included in exports.
+    #[serde(default = "default_true")]
+    pub visible: bool,
+
+    /// Display name, e.g. "Reference letter – Acme".
+    #[validate(length(min = 1, max = 200))]
+    #[serde(default)]
+    pub name: String,
+
+    /// The file as a base64 `data:` URL with one of the
+    /// [`ATTACHMENT_MEDIA_TYPES`], or empty.
+    #[validate(custom(function = "validate_attachment_file"))]
+    #[serde(default)]
+    pub file: String,
+
+    /// Link to a hosted copy (must be empty or a valid HTTP(S) URL).
+    #[validate(custom(function = "validate_optional_url"))]
+    #[serde(default)]
+    pub url: String,
+
+    /// How the attachment appears in the PDF.
+    #[serde(default)]
+    pub mode: AttachmentMode,
+}
+
+impl Attachment {
+    /// Create a visible attachment that appends `file` to the PDF.
+    pub fn new(name: impl Into<String>, file: impl Into<String>) -> Self {
+        Self {
+            id: cuid2::create_id(),
+            visible: true,
+            name: name.into(),
+            file: file.into(),
+            url: String::new(),
+            mode: AttachmentMode::Append,
+        }
+    }
+
+    /// Create a visible attachment shown as a QR code for `url`.
+    pub fn link(name: impl Into<String>, url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            mode: AttachmentMode::QrCode,
+            ..Self::new(name, "")
+        }
+    }
+
+    /// Set the link to a hosted copy.
+    pub fn with_url(mut self, url: impl Into<String>) -> Self {
+        self.url = url.into();
+        self
+    }
+
+    /// Set how the attachment appears in the PDF.
+    pub fn with_mode(mut self, mode: AttachmentMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Media type and base64 payload of the inline file, if there is one.
+    pub fn file_parts(&self) -> Option<(&str, &str)> {
+        parse_data_url(&self.file)
+    }
+
+    /// Whether the attachment has what its mode needs: a file to append or a
+    /// link to encode.
+    pub fn is_presentable(&self) -> bool {
+        match self.mode {
+            AttachmentMode::Append => self.file_parts().is_some(),
+            AttachmentMode::QrCode => !self.url.is_empty(),
+        }
+    }
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// Split a `data:<media type>;base64,<payload>` URL.
+fn parse_data_url(url: &str) -> Option<(&str, &str)> {
+    let (media_type, payload) = url.strip_prefix("data:")?.split_once(";base64,")?;
+    Some((media_type, payload))
+}
+
+/// Validate that a string is either empty or a base64 data URL of a
+/// supported attachment type.
+fn validate_attachment_file(file: &str) -> Result<(), ValidationError> {
+    if file.is_empty() {
+        return Ok(());
+    }
+
+    match parse_data_url(file) {
+        Some((media_type, _)) if ATTACHMENT_MEDIA_TYPES.contains(&media_type) => Ok(()),
+        Some(_) => {
+            let mut error = ValidationError::new("unsupported_attachment_type");
+            error.message = Some(
+                format!(
+                    "Attachment must be one of: {}",
+                    ATTACHMENT_MEDIA_TYPES.join(", ")
+                )
+                .into(),
+            );
+            Err(error)
+        }
+        None => {
+            let mut error = ValidationError::new("invalid_attachment_file");
+            error.message = Some("Attachment file must be a base64 data URL".into());
+            Err(error)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_attachment_file_validation() {
+        let letter = Attachment::new("Reference letter", "data:application/pdf;base64,JVBERi0=");
+        assert!(letter.validate().is_ok());
+        assert_eq!(letter.file_parts(), Some(("application/pdf", "JVBERi0=")));
+
+        let link = Attachment::link("Certificate", "https://example.com/cert.pdf");
+        assert!(link.validate().is_ok());
+        assert!(link.file_parts().is_none());
+
+        let remote = Attachment::new("Letter", "https://example.com/letter.pdf");
+        let errors = remote.validate().unwrap_err();
+        assert_eq!(
+            errors.field_errors()["file"][0].code,
+            "invalid_attachment_file"
+        );
+
+        let text = Attachment::new("Notes", "data:text/plain;base64,aGk=");
+        let errors = text.validate().unwrap_err();
+        assert_eq!(
+            errors.field_errors()["file"][0].code,
+            "unsupported_attachment_type"
+        );
+
+        assert!(Attachment::new("", "").validate().is_err());
+    }
+
+    #[test]
+    fn test_is_presentable_follows_mode() {
+        let letter = Attachment::new("Letter", "data:image/png;base64,iVBORw0=");
+        assert!(letter.is_presentable());
+        assert!(!letter
+            .clone()
+            .with_mode(AttachmentMode::QrCode)
+            .is_presentable());
+        assert!(letter
+            .with_url("https://example.com/letter")
+            .with_mode(AttachmentMode::QrCode)
+            .is_presentable());
+        assert!(!Attachment::new("Letter", "").is_presentable());
+    }
+
+    #[test]
+    fn test_attachment_json_defaults() {
+        let attachment: Attachment =
+            serde_json::from_str(r#"{"name": "Letter", "mode": "qr-code"}"#).unwrap();
+        assert!(attachment.visible);
+        assert!(!attachment.id.is_empty());
+        assert_eq!(attachment.mode, AttachmentMode::QrCode);
+        assert!(attachment.file.is_empty());
+
+        let json = serde_json::to_value(Attachment::link("Letter", "https://example.com")).unwrap();
+        assert_eq!(json["mode"], "qr-code");
+    }
+}
@@ -4,17 +4,28 @@ use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use validator::Validate;
 
+use rustume_utils::format_phone;
+use rustume_utils::locale::region_subtag;
+
+use crate::address::Address;
+use crate::name::{NameOrder, PersonName};
 use crate::shared::{CustomField, Url};
 use crate::validation::validate_optional_email;
+use crate::TypeScript;
 
 /// Basic personal information.
-#[derive(Debug, Clone, Serialize, Deserialize, Validate, Default, ToSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, Default, ToSchema, TypeScript)]
 #[serde(rename_all = "camelCase")]
 pub struct Basics {
-    /// Full name.
+    /// Full name, as the person writes it.
     #[serde(default)]
     pub name: String,
 
+    /// `name` split into given and family name, for sorting and for
+    /// printing in the locale's name order. Omitted while unknown.
+    #[serde(default, skip_serializing_if = "PersonName::is_empty")]
+    pub name_parts: PersonName,
+
     /// Professional headline/title.
     #[serde(default)]
     pub headline: String,
@@ -32,12 +43,18 @@ pub struct Basics {
     #[serde(default)]
     pub location: String,
 
+    /// Postal address in parts, for addresses a single `location` line
+    /// would make ambiguous. Omitted while unknown.
+    #[serde(default, skip_serializing_if = "Address::is_empty")]
+    pub address: Address,
+
     /// Personal website or portfolio URL.
     #[validate(nested)]
     #[serde(default)]
     pub url: Url,
 
     /// Custom fields for additional info.
+    #[validate(nested)]
     #[serde(default)]
     pub custom_fields: Vec<CustomField>,
 
@@ -56,6 +73,16 @@ impl Basics {
         }
     }
 
+    /// Builder method to set the name parts, filling `name` from them when
+    /// it is empty.
+    pub fn with_name_parts(mut self, parts: PersonName) -> Self {
+        if self.name.trim().is_empty() {
+            self.name = parts.display(NameOrder::GivenFirst, "");
+        }
+        self.name_parts = parts;
+        self
+    }
+
     /// Builder method to set headline.
     pub fn with_headline(mut self, headline: impl Into<String>) -> Self {
         self.headline = headline.into();
@@ -80,20 +107,75 @@ impl Basics {
         self
     }
 
+    /// Builder method to set the postal address.
+    pub fn with_address(mut self, address: Address) -> Self {
+        self.address = address;
+        self
+    }
+
     /// Builder method to set URL.
     pub fn with_url(mut self, url: impl Into<String>) -> Self {
         self.url = Url::new(url);
         self
     }
 
+    /// Builder method to add a custom field, e.g.
+    /// `.with_custom_field(CustomField::link("GitHub", "https://github.com/ada"))`.
+    pub fn with_custom_field(mut self, field: CustomField) -> Self {
+        self.custom_fields.push(field);
+        self
+    }
+
     /// Add a custom field.
     pub fn add_custom_field(&mut self, name: impl Into<String>, value: impl Into<String>) {
         self.custom_fields.push(CustomField::new(name, value));
     }
+
+    /// The name parts, when they still spell out `name`. Parts left over
+    /// from before `name` was edited don't count.
+    pub fn current_name_parts(&self) -> Option<&PersonName> {
+        Some(&self.name_parts).filter(|parts| parts.matches(&self.name))
+    }
+
+    /// Fill the name parts from `name` with [`PersonName::parse`], for
+    /// importers whose format has only a full name.
+    pub fn guess_name_parts(&mut self, locale: &str) {
+        self.name_parts = PersonName::parse(&self.name, locale);
+    }
+
+    /// The location as templates print it: `location` as written, or the
+    /// address's city, region, and country when `location` is empty.
+    pub fn display_location(&self) -> String {
+        match self.location.trim() {
+            "" => self.address.locality(),
+            location => location.to_string(),
+        }
+    }
+
+    /// `phone` in international form, read as a national number of the
+    /// address's country or else of `locale`'s region (`en-GB` is the UK).
+    /// Numbers that can't be read are returned as written.
+    pub fn display_phone(&self, locale: &str) -> String {
+        let region = self
+            .address
+            .country_code()
+            .or_else(|| region_subtag(locale));
+        format_phone(&self.phone, region.as_deref())
+            .unwrap_or_else(|| self.phone.trim().to_string())
+    }
+
+    /// Key for sorting people alphabetically: family name first when the
+    /// parts are known, otherwise `name` as written. Lowercased.
+    pub fn sort_name(&self) -> String {
+        match self.current_name_parts() {
+            Some(parts) => parts.sort_key(),
+            None => self.name.trim().to_lowercase(),
+        }
+    }
 }
 
 /// Profile picture configuration.
-#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema, TypeScript)]
 #[serde(rename_all = "camelCase")]
 pub struct Picture {
     /// URL to the picture (can be data URL or remote URL).
@@ -151,7 +233,7 @@ impl Picture {
 }
 
 /// Picture display effects.
-#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema, TypeScript)]
 #[serde(rename_all = "camelCase")]
 pub struct PictureEffects {
     /// Hide the picture.
@@ -246,6 +328,47 @@ mod tests {
         assert!(basics.validate().is_ok());
     }
 
+    #[test]
+    fn test_basics_name_parts() {
+        let basics = Basics::default().with_name_parts(PersonName::new("Ada", "Lovelace"));
+        assert_eq!(basics.name, "Ada Lovelace");
+        assert_eq!(basics.sort_name(), "lovelace, ada");
+        assert!(serde_json::to_value(Basics::new("Ada")).unwrap()["nameParts"].is_null());
+
+        let mut basics =
+            Basics::new("Ada King").with_name_parts(PersonName::new("Ada", "Lovelace"));
+        assert!(basics.current_name_parts().is_none());
+        assert_eq!(basics.sort_name(), "ada king");
+
+        basics.guess_name_parts("en");
+        assert_eq!(basics.name_parts, PersonName::new("Ada", "King"));
+        assert_eq!(basics.sort_name(), "king, ada");
+    }
+
+    #[test]
+    fn test_display_location_and_phone() {
+        let address = Address {
+            city: "Lyon".to_string(),
+            postal_code: "69002".to_string(),
+            country: "FR".to_string(),
+            ..Address::default()
+        };
+        let mut basics = Basics::new("Ada").with_address(address);
+        assert_eq!(basics.display_location(), "Lyon, FR");
+        basics.location = "Lyon, France".to_string();
+        assert_eq!(basics.display_location(), "Lyon, France");
+        assert_eq!(
+            serde_json::to_value(&basics).unwrap()["address"]["postalCode"],
+            "69002"
+        );
+        assert!(serde_json::to_value(Basics::new("Ada")).unwrap()["address"].is_null());
+
+        basics.phone = " +44 20  7946 0958 ".to_string();
+        assert_eq!(basics.display_phone("fr-FR"), "+44 20 7946 0958");
+        basics.phone = "ask by email".to_string();
+        assert_eq!(basics.display_phone("fr-FR"), "ask by email");
+    }
+
     #[test]
     fn test_basics_email_validation() {
         let valid = Basics::new("Test").with_email("test@example.com");
@@ -359,4 +482,19 @@ mod tests {
         assert_eq!(basics.custom_fields[0].name, "Pronouns");
         assert_eq!(basics.custom_fields[1].name, "Timezone");
     }
+
+    #[test]
+    fn test_with_custom_field_validates_nested() {
+        let basics = Basics::new("Test")
+            .with_custom_field(CustomField::link("GitHub", "https://github.com/test"))
+            .with_custom_field(CustomField::email("Work", "not-an-email"));
+        assert_eq!(basics.custom_fields.len(), 2);
+
+        let errors = basics.validate().unwrap_err();
+        let messages = crate::validation_messages(&errors);
+        assert_eq!(
+            messages,
+            ["custom_fields[1]: Must be a valid email address"]
+        );
+    }
 }
@@ -0,0 +1,166 @@
+//! Canonical JSON for hashing and comparing resumes.
+//!
+//! Two resumes with the same content serialize to the same canonical string,
+//! whatever order their JSON listed keys in, how it was indented, or how a
+//! number was written. That makes the string safe to hash for cache keys and
+//! ETags, and to compare for "did anything change".
+//!
+//! The form is written here rather than by `serde_json`, so it holds across
+//! `serde_json` versions and features such as `preserve_order`:
+//!
+//! - Object keys are sorted by code point, at every level.
+//! - There is no whitespace outside strings.
+//! - Strings escape only `"`, `\`, and control characters; `\b`, `\f`, `\n`,
+//!   `\r`, and `\t` use their short forms and the others `\u00xx`. Everything
+//!   else, including non-ASCII text, is written as is.
+//! - Integers are written in decimal. Other numbers are written as the
+//!   shortest decimal that reads back as the same `f64`, without an
+//!   exponent, so `1.0` and `1` are both `1` and `-0.0` is `0`.
+
+use std::fmt::Write;
+
+use serde_json::Value;
+
+use crate::ResumeData;
+
+/// Canonical JSON of `resume`.
+pub fn canonicalize(resume: &ResumeData) -> String {
+    // Every map in `ResumeData` is keyed by strings, so this can't fail
+    let value = serde_json::to_value(resume).expect("resume data serializes to JSON");
+    canonical_json(&value)
+}
+
+/// Canonical form of any JSON value. See the [module docs](self) for the
+/// rules.
+pub fn canonical_json(value: &Value) -> String {
+    let mut out = String::new();
+    write_value(&mut out, value);
+    out
+}
+
+fn write_value(out: &mut String, value: &Value) {
+    match value {
+        Value::Null => out.push_str("null"),
+        Value::Bool(b) => out.push_str(if *b { "true" } else { "false" }),
+        Value::Number(n) => write_number(out, n),
+        Value::String(s) => write_string(out, s),
+        Value::Array(items) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_value(out, item);
+            }
+            out.push(']');
+        }
+        Value::Object(map) => {
+            let mut entries: Vec<_> = map.iter().collect();
+            entries.sort_unstable_by(|a, b| a.0.cmp(b.0));
+            out.push('{');
+            for (i, (key, item)) in entries.into_iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_string(out, key);
+                out.push(':');
+                write_value(out, item);
+            }
+            out.push('}');
+        }
+    }
+}
+
+fn write_number(out: &mut String, n: &serde_json::Number) {
+    if let Some(i) = n.as_i64() {
+        let _ = write!(out, "{i}");
+    } else if let Some(u) = n.as_u64() {
+        let _ = write!(out, "{u}");
+    } else if let Some(f) = n.as_f64() {
+        // `Display` for floats is the shortest round-trip decimal, never in
+        // exponent form; `+ 0.0` turns -0 into 0
+        let _ = write!(out, "{}", f + 0.0);
+    }
+}
+
+fn write_string(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\u{08}' => out.push_str("\\b"),
+            '\u{0c}' => out.push_str("\\f"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c < ' ' => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn canonical_form_is_pinned() {
+        // Changing this output changes every content hash; only do it on purpose
+        let value: Value = serde_json::from_str(
+            r#"{
+                "z": [3, 1.0, -0.0, 2.5, 1e21, 0.000001, -12],
+                "a": {"y": null, "b": true, "a": false},
+                "é": "tab\there \"quoted\" back\\slash \u0001 ünïcödé 🚀\n"
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            canonical_json(&value),
+            r#"{"a":{"a":false,"b":true,"y":null},"z":[3,1,0,2.5,1000000000000000000000,0.000001,-12],"é":"tab\there \"quoted\" back\\slash \u0001 ünïcödé 🚀\n"}"#
+        );
+    }
+
+    #[test]
+    fn canonical_json_reads_back_as_the_same_value() {
+        let value = json!({"b": [1, "two", {"d": 4.25, "c": null}], "a": "\u{7f}"});
+        let canonical = canonical_json(&value);
+        assert_eq!(serde_json::from_str::<Value>(&canonical).unwrap(), value);
+        assert_eq!(
+            canonical_json(&serde_json::from_str(&canonical).unwrap()),
+            canonical
+        );
+    }
+
+    #[test]
+    fn resumes_with_the_same_content_canonicalize_alike() {
+        let mut resume = ResumeData::with_basics("Ada Lovelace", "ada@example.com");
+        for id in ["zeta", "alpha", "mid"] {
+            resume
+                .sections
+                .custom
+                .insert(id.to_string(), Default::default());
+        }
+        let canonical = canonicalize(&resume);
+
+        // Indentation and key order of the source JSON don't matter
+        let pretty = serde_json::to_string_pretty(&resume).unwrap();
+        let reparsed: ResumeData = serde_json::from_str(&pretty).unwrap();
+        assert_eq!(canonicalize(&reparsed), canonical);
+
+        let mut value = serde_json::to_value(&resume).unwrap();
+        let basics = value["basics"].as_object_mut().unwrap();
+        let name = basics.remove("name").unwrap();
+        basics.insert("name".to_string(), name);
+        let reordered: ResumeData = serde_json::from_value(value).unwrap();
+        assert_eq!(canonicalize(&reordered), canonical);
+
+        let mut changed = resume.clone();
+        changed.basics.name.push('!');
+        assert_ne!(canonicalize(&changed), canonical);
+    }
+}
@@ -0,0 +1,168 @@
+//! CEFR language levels.
+//!
+//! The Common European Framework of Reference grades language ability from
+//! A1 to C2, and European employers expect a resume to state it. A
+//! [`Language`](crate::Language) keeps its 0–5 `level` for the templates'
+//! indicators and may carry a [`CefrLevel`] as well; [`CefrLevel::level`] and
+//! [`CefrLevel::from_level`] convert between the two, and
+//! [`CefrLevel::from_linkedin`] reads LinkedIn's proficiency names.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::TypeScript;
+
+/// A CEFR level, from A1 (beginner) to C2 (mastery).
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, ToSchema, TypeScript,
+)]
+pub enum CefrLevel {
+    A1,
+    A2,
+    B1,
+    B2,
+    C1,
+    C2,
+}
+
+impl CefrLevel {
+    /// Every level, lowest first.
+    pub const ALL: [CefrLevel; 6] = [
+        CefrLevel::A1,
+        CefrLevel::A2,
+        CefrLevel::B1,
+        CefrLevel::B2,
+        CefrLevel::C1,
+        CefrLevel::C2,
+    ];
+
+    /// The code, such as `B2`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::A1 => "A1",
+            Self::A2 => "A2",
+            Self::B1 => "B1",
+            Self::B2 => "B2",
+            Self::C1 => "C1",
+            Self::C2 => "C2",
+        }
+    }
+
+    /// Read a code such as `b2` or ` C1 `, ignoring case and whitespace.
+    pub fn parse(code: &str) -> Option<Self> {
+        let code = code.trim();
+        Self::ALL
+            .into_iter()
+            .find(|level| level.as_str().eq_ignore_ascii_case(code))
+    }
+
+    /// The 0–5 `level` of a language at this CEFR level. Both A levels
+    /// are 1, so the scale keeps a step for each of B1 to C2.
+    pub fn level(self) -> u8 {
+        match self {
+            Self::A1 | Self::A2 => 1,
+            Self::B1 => 2,
+            Self::B2 => 3,
+            Self::C1 => 4,
+            Self::C2 => 5,
+        }
+    }
+
+    /// The CEFR level for a 0–5 `level`, reading 1 as A2. Level 0 means no
+    /// level and has none; levels above 5 are C2.
+    pub fn from_level(level: u8) -> Option<Self> {
+        match level {
+            0 => None,
+            1 => Some(Self::A2),
+            2 => Some(Self::B1),
+            3 => Some(Self::B2),
+            4 => Some(Self::C1),
+            _ => Some(Self::C2),
+        }
+    }
+
+    /// The CEFR level for a LinkedIn proficiency, such as `Professional
+    /// working proficiency`. Matching ignores case and the word
+    /// "proficiency", so `Native or Bilingual` works too.
+    pub fn from_linkedin(proficiency: &str) -> Option<Self> {
+        let lower = proficiency.to_lowercase();
+        if lower.contains("native") || lower.contains("bilingual") {
+            Some(Self::C2)
+        } else if lower.contains("full professional") {
+            Some(Self::C1)
+        } else if lower.contains("professional working") {
+            Some(Self::B2)
+        } else if lower.contains("limited working") {
+            Some(Self::B1)
+        } else if lower.contains("elementary") {
+            Some(Self::A2)
+        } else {
+            None
+        }
+    }
+
+    /// The LinkedIn proficiency closest to this level. LinkedIn has one
+    /// name for both A levels.
+    pub fn linkedin_proficiency(self) -> &'static str {
+        match self {
+            Self::A1 | Self::A2 => "Elementary proficiency",
+            Self::B1 => "Limited working proficiency",
+            Self::B2 => "Professional working proficiency",
+            Self::C1 => "Full professional proficiency",
+            Self::C2 => "Native or bilingual proficiency",
+        }
+    }
+}
+
+impl fmt::Display for CefrLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_codes_round_trip() {
+        for level in CefrLevel::ALL {
+            assert_eq!(CefrLevel::parse(level.as_str()), Some(level));
+            let json = serde_json::to_string(&level).unwrap();
+            assert_eq!(json, format!("\"{level}\""));
+        }
+        assert_eq!(CefrLevel::parse(" b2 "), Some(CefrLevel::B2));
+        assert_eq!(CefrLevel::parse("B3"), None);
+    }
+
+    #[test]
+    fn test_numeric_levels() {
+        for level in 1..=5 {
+            assert_eq!(CefrLevel::from_level(level).unwrap().level(), level);
+        }
+        assert_eq!(CefrLevel::from_level(0), None);
+        assert_eq!(CefrLevel::A1.level(), 1);
+        assert_eq!(CefrLevel::from_level(9), Some(CefrLevel::C2));
+    }
+
+    #[test]
+    fn test_linkedin_proficiencies() {
+        for level in CefrLevel::ALL.into_iter().skip(1) {
+            assert_eq!(
+                CefrLevel::from_linkedin(level.linkedin_proficiency()),
+                Some(level)
+            );
+        }
+        assert_eq!(
+            CefrLevel::from_linkedin("Native or Bilingual"),
+            Some(CefrLevel::C2)
+        );
+        assert_eq!(
+            CefrLevel::from_linkedin("Elementary proficiency"),
+            Some(CefrLevel::A2)
+        );
+        assert_eq!(CefrLevel::from_linkedin("Conversational"), None);
+    }
+}
@@ -0,0 +1,502 @@
+//! Pre-filled resume skeletons for common professions.
+//!
+//! Unlike a [`LayoutPreset`], which only reorders sections, a content preset
+//! is a whole starting resume: a headline, a summary, and a few example items
+//! in the sections that profession leans on, laid out with a fitting layout
+//! preset. Every text is a placeholder meant to be rewritten.
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::TypeScript;
+use crate::{
+    apply_preset, Award, Basics, Certification, Education, Experience, Interest, Language,
+    LayoutPreset, Profile, Project, Publication, Reference, ResumeData, Skill, SummarySection,
+};
+
+/// A starting resume for a profession.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema, TypeScript)]
+#[serde(rename_all = "kebab-case")]
+pub enum ContentPreset {
+    /// Experience, skills by area, and a side project.
+    SoftwareEngineer,
+    /// Portfolio projects and design skills ahead of work history.
+    Designer,
+    /// Degrees, publications, grants, and references.
+    Academic,
+    /// Licenses and certifications, clinical roles, and languages.
+    Nurse,
+    /// Quota-carrying roles with numbers, and sales awards.
+    Sales,
+}
+
+impl ContentPreset {
+    /// All content presets.
+    pub const ALL: [ContentPreset; 5] = [
+        Self::SoftwareEngineer,
+        Self::Designer,
+        Self::Academic,
+        Self::Nurse,
+        Self::Sales,
+    ];
+
+    /// Wire name (`software-engineer`, `designer`, ...).
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::SoftwareEngineer => "software-engineer",
+            Self::Designer => "designer",
+            Self::Academic => "academic",
+            Self::Nurse => "nurse",
+            Self::Sales => "sales",
+        }
+    }
+
+    /// Human-readable name.
+    pub fn title(self) -> &'static str {
+        match self {
+            Self::SoftwareEngineer => "Software Engineer",
+            Self::Designer => "Designer",
+            Self::Academic => "Academic",
+            Self::Nurse => "Nurse",
+            Self::Sales => "Sales",
+        }
+    }
+
+    /// One line on what the skeleton contains.
+    pub fn description(self) -> &'static str {
+        match self {
+            Self::SoftwareEngineer => {
+                "Engineering roles, skills grouped by area, education, and a side project"
+            }
+            Self::Designer => "Case-study projects and design skills ahead of work history",
+            Self::Academic => "Degrees, publications, teaching, grants, and references",
+            Self::Nurse => "Licenses, certifications, clinical roles, and languages",
+            Self::Sales => "Quota-carrying roles with results, sales tools, and awards",
+        }
+    }
+
+    /// The layout preset the skeleton is arranged with.
+    pub fn layout(self) -> LayoutPreset {
+        match self {
+            Self::SoftwareEngineer => LayoutPreset::Hybrid,
+            Self::Designer => LayoutPreset::Functional,
+            Self::Academic => LayoutPreset::Academic,
+            Self::Nurse | Self::Sales => LayoutPreset::Chronological,
+        }
+    }
+
+    /// A new resume pre-filled for this profession. Item IDs are fresh on
+    /// every call.
+    pub fn resume(self) -> ResumeData {
+        let mut resume = ResumeData::default();
+        match self {
+            Self::SoftwareEngineer => software_engineer(&mut resume),
+            Self::Designer => designer(&mut resume),
+            Self::Academic => academic(&mut resume),
+            Self::Nurse => nurse(&mut resume),
+            Self::Sales => sales(&mut resume),
+        }
+        apply_preset(&mut resume, self.layout());
+        resume
+    }
+}
+
+impl fmt::Display for ContentPreset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for ContentPreset {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = s.trim().replace('_', "-");
+        Self::ALL
+            .into_iter()
+            .find(|preset| preset.as_str().eq_ignore_ascii_case(&name))
+            .ok_or_else(|| format!("Unknown content preset: {s}"))
+    }
+}
+
+fn basics(headline: &str) -> Basics {
+    Basics::new("Your Name")
+        .with_headline(headline)
+        .with_email("you@example.com")
+        .with_phone("+1-555-000-0000")
+        .with_location("City, Country")
+}
+
+fn keywords(words: &[&str]) -> Vec<String> {
+    words.iter().map(|word| word.to_string()).collect()
+}
+
+fn software_engineer(resume: &mut ResumeData) {
+    resume.basics = basics("Software Engineer").with_url("https://example.com");
+    resume.sections.summary = SummarySection::new(
+        "Software engineer with N years of experience building and running production \
+         systems. Replace this with the kind of problems you solve and the scale you work at.",
+    );
+
+    let experience = &mut resume.sections.experience;
+    experience.add_item(
+        Experience::new("Company Name", "Senior Software Engineer")
+            .with_location("City, Country")
+            .with_date("2021 - Present")
+            .with_summary(
+                "Led the migration of a core service to a new architecture, cutting p99 \
+                 latency by 40%. Mentored three engineers. Replace with your own outcomes.",
+            ),
+    );
+    experience.add_item(
+        Experience::new("Previous Company", "Software Engineer")
+            .with_location("City, Country")
+            .with_date("2018 - 2021")
+            .with_summary(
+                "Built and shipped customer-facing features used by N users. Describe what \
+                 you built, for whom, and the measurable result.",
+            ),
+    );
+
+    resume.sections.education.add_item(
+        Education::new("University Name", "Computer Science")
+            .with_study_type("Bachelor of Science")
+            .with_date("2014 - 2018"),
+    );
+
+    let skills = &mut resume.sections.skills;
+    skills.add_item(Skill::new("Languages").with_keywords(keywords(&[
+        "Rust",
+        "TypeScript",
+        "Python",
+        "SQL",
+    ])));
+    skills.add_item(Skill::new("Infrastructure").with_keywords(keywords(&[
+        "AWS",
+        "Docker",
+        "Kubernetes",
+        "PostgreSQL",
+    ])));
+    skills.add_item(Skill::new("Practices").with_keywords(keywords(&[
+        "System design",
+        "Code review",
+        "CI/CD",
+        "Testing",
+    ])));
+
+    resume.sections.projects.add_item(
+        Project::new("Side Project")
+            .with_description("Open-source tool")
+            .with_summary("What it does, who uses it, and the technology behind it.")
+            .with_url("https://github.com/your-name/side-project"),
+    );
+    resume
+        .sections
+        .profiles
+        .add_item(Profile::new("GitHub", "your-name").with_url("https://github.com/your-name"));
+}
+
+fn designer(resume: &mut ResumeData) {
+    resume.basics = basics("Product Designer").with_url("https://your-portfolio.example.com");
+    resume.sections.summary = SummarySection::new(
+        "Product designer who turns research into shipped interfaces. Replace this with your \
+         focus (product, brand, interaction) and the kinds of teams you work best with.",
+    );
+
+    let projects = &mut resume.sections.projects;
+    projects.add_item(
+        Project::new("Case Study: Checkout Redesign")
+            .with_description("Research, interaction design, and prototyping")
+            .with_summary(
+                "Problem, your role, the process, and the result (for example, a 12% lift \
+                 in conversion).",
+            )
+            .with_url("https://your-portfolio.example.com/checkout"),
+    );
+    projects.add_item(
+        Project::new("Case Study: Design System")
+            .with_description("Component library and documentation")
+            .with_summary("What you standardized, who adopted it, and what it saved.")
+            .with_url("https://your-portfolio.example.com/design-system"),
+    );
+
+    let skills = &mut resume.sections.skills;
+    skills.add_item(Skill::new("Design").with_keywords(keywords(&[
+        "Interaction design",
+        "Visual design",
+        "Typography",
+    ])));
+    skills.add_item(Skill::new("Research").with_keywords(keywords(&[
+        "User interviews",
+        "Usability testing",
+        "Journey maps",
+    ])));
+    skills.add_item(Skill::new("Tools").with_keywords(keywords(&[
+        "Figma",
+        "Prototyping",
+        "HTML/CSS",
+    ])));
+
+    resume.sections.awards.visible = true;
+    resume.sections.awards.add_item(
+        Award::new("Design Award")
+            .with_awarder("Awarding Body")
+            .with_date("2023"),
+    );
+
+    resume.sections.experience.add_item(
+        Experience::new("Company Name", "Product Designer")
+            .with_location("City, Country")
+            .with_date("2020 - Present")
+            .with_summary("Owned the design of a product area end to end. Replace with your work."),
+    );
+    resume.sections.education.add_item(
+        Education::new("School of Design", "Interaction Design")
+            .with_study_type("Bachelor of Fine Arts")
+            .with_date("2016 - 2020"),
+    );
+    resume
+        .sections
+        .profiles
+        .add_item(Profile::new("Dribbble", "your-name").with_url("https://dribbble.com/your-name"));
+}
+
+fn academic(resume: &mut ResumeData) {
+    resume.basics = basics("Postdoctoral Researcher, Department of Field");
+    resume.sections.summary = SummarySection::new(
+        "Researcher in your field, working on your research questions. Replace this with your \
+         research agenda and teaching interests.",
+    );
+
+    let education = &mut resume.sections.education;
+    education.add_item(
+        Education::new("University Name", "Field of Study")
+            .with_study_type("PhD")
+            .with_date("2017 - 2022")
+            .with_summary("Dissertation: Title of Your Dissertation. Advisor: Prof. Name."),
+    );
+    education.add_item(
+        Education::new("University Name", "Field of Study")
+            .with_study_type("MSc")
+            .with_date("2015 - 2017"),
+    );
+
+    resume.sections.publications.visible = true;
+    let publications = &mut resume.sections.publications;
+    publications.add_item(
+        Publication::new("Title of Your Paper")
+            .with_publisher("Journal Name")
+            .with_date("2024")
+            .with_summary("Author, A., Your Name, and Author, C."),
+    );
+    publications.add_item(
+        Publication::new("Title of Your Conference Paper")
+            .with_publisher("Conference Proceedings")
+            .with_date("2022"),
+    );
+
+    let experience = &mut resume.sections.experience;
+    experience.add_item(
+        Experience::new("University Name", "Postdoctoral Researcher")
+            .with_date("2022 - Present")
+            .with_summary("Research focus, lab, and collaborations."),
+    );
+    experience.add_item(
+        Experience::new("University Name", "Teaching Assistant")
+            .with_date("2018 - 2021")
+            .with_summary("Course name and level, number of students, what you taught."),
+    );
+
+    resume.sections.awards.visible = true;
+    resume.sections.awards.add_item(
+        Award::new("Research Grant")
+            .with_awarder("Funding Agency")
+            .with_date("2023")
+            .with_summary("Amount and purpose of the grant."),
+    );
+
+    resume.sections.references.visible = true;
+    resume.sections.references.add_item(
+        Reference::new("Prof. Name")
+            .with_description("Advisor, University Name")
+            .with_summary("Available on request."),
+    );
+}
+
+fn nurse(resume: &mut ResumeData) {
+    resume.basics = basics("Registered Nurse");
+    resume.sections.summary = SummarySection::new(
+        "Registered nurse with N years of experience in your specialty. Replace this with your \
+         patient population, setting, and strengths.",
+    );
+
+    resume.sections.certifications.visible = true;
+    let certifications = &mut resume.sections.certifications;
+    certifications.add_item(
+        Certification::new("Registered Nurse (RN) License", "State Board of Nursing")
+            .with_date("2019")
+            .with_summary("License number and expiry."),
+    );
+    certifications.add_item(
+        Certification::new("Basic Life Support (BLS)", "American Heart Association")
+            .with_date("2024"),
+    );
+    certifications.add_item(
+        Certification::new(
+            "Advanced Cardiovascular Life Support (ACLS)",
+            "American Heart Association",
+        )
+        .with_date("2024"),
+    );
+
+    let experience = &mut resume.sections.experience;
+    experience.add_item(
+        Experience::new("Hospital Name", "Registered Nurse, Unit Name")
+            .with_location("City, Country")
+            .with_date("2021 - Present")
+            .with_summary(
+                "Unit type and bed count, patient ratio, and responsibilities such as charge \
+                 nurse shifts or precepting new graduates.",
+            ),
+    );
+    experience.add_item(
+        Experience::new("Clinic or Hospital Name", "Staff Nurse")
+            .with_location("City, Country")
+            .with_date("2019 - 2021")
+            .with_summary("Setting, patient population, and notable improvements you led."),
+    );
+
+    resume.sections.education.add_item(
+        Education::new("University Name", "Nursing")
+            .with_study_type("Bachelor of Science in Nursing")
+            .with_date("2015 - 2019"),
+    );
+
+    resume
+        .sections
+        .skills
+        .add_item(Skill::new("Clinical").with_keywords(keywords(&[
+            "Patient assessment",
+            "Medication administration",
+            "IV therapy",
+            "Wound care",
+        ])));
+    resume.sections.skills.add_item(
+        Skill::new("Systems").with_keywords(keywords(&["Electronic health records", "Epic"])),
+    );
+
+    resume.sections.languages.visible = true;
+    resume
+        .sections
+        .languages
+        .add_item(Language::new("English").with_description("Native"));
+    resume
+        .sections
+        .languages
+        .add_item(Language::new("Spanish").with_description("Conversational"));
+}
+
+fn sales(resume: &mut ResumeData) {
+    resume.basics = basics("Account Executive");
+    resume.sections.summary = SummarySection::new(
+        "Account executive with a record of exceeding quota in your market. Replace this with \
+         your segment, deal sizes, and sales cycle.",
+    );
+
+    let experience = &mut resume.sections.experience;
+    experience.add_item(
+        Experience::new("Company Name", "Senior Account Executive")
+            .with_location("City, Country")
+            .with_date("2021 - Present")
+            .with_summary(
+                "Closed $N in new business against a $N quota (N% attainment). Name your \
+                 segment, biggest deal, and how you built pipeline.",
+            ),
+    );
+    experience.add_item(
+        Experience::new("Previous Company", "Sales Development Representative")
+            .with_location("City, Country")
+            .with_date("2018 - 2021")
+            .with_summary("Meetings booked per month, pipeline sourced, and promotions earned."),
+    );
+
+    resume
+        .sections
+        .skills
+        .add_item(Skill::new("Selling").with_keywords(keywords(&[
+            "Prospecting",
+            "Discovery",
+            "Negotiation",
+            "Account management",
+        ])));
+    resume
+        .sections
+        .skills
+        .add_item(Skill::new("Tools").with_keywords(keywords(&["Salesforce", "HubSpot", "Gong"])));
+
+    resume.sections.awards.visible = true;
+    resume.sections.awards.add_item(
+        Award::new("President's Club")
+            .with_awarder("Company Name")
+            .with_date("2023")
+            .with_summary("Top N% of the sales organization."),
+    );
+
+    resume.sections.education.add_item(
+        Education::new("University Name", "Business Administration")
+            .with_study_type("Bachelor of Arts")
+            .with_date("2014 - 2018"),
+    );
+
+    resume.sections.interests.visible = true;
+    resume.sections.interests.add_item(
+        Interest::new("Interests").with_keywords(keywords(&["Running", "Public speaking"])),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use validator::Validate;
+
+    #[test]
+    fn test_every_content_preset_is_a_valid_resume() {
+        for preset in ContentPreset::ALL {
+            let resume = preset.resume();
+            assert!(resume.validate().is_ok(), "{preset}");
+            assert!(resume.metadata.layout.check(&resume.sections).is_empty());
+            assert!(!resume.basics.headline.is_empty(), "{preset}");
+            assert!(!resume.sections.experience.items.is_empty(), "{preset}");
+        }
+    }
+
+    #[test]
+    fn test_filled_sections_are_visible() {
+        let academic = ContentPreset::Academic.resume();
+        assert!(academic.sections.publications.visible);
+        assert!(academic.sections.references.visible);
+
+        let nurse = ContentPreset::Nurse.resume();
+        assert!(nurse.sections.certifications.visible);
+        assert!(nurse.sections.languages.visible);
+    }
+
+    #[test]
+    fn test_content_preset_names_round_trip() {
+        for preset in ContentPreset::ALL {
+            assert_eq!(preset.as_str().parse::<ContentPreset>(), Ok(preset));
+        }
+        assert_eq!(
+            "Software_Engineer".parse::<ContentPreset>(),
+            Ok(ContentPreset::SoftwareEngineer)
+        );
+        assert!("astronaut".parse::<ContentPreset>().is_err());
+        assert_eq!(
+            serde_json::to_value(ContentPreset::SoftwareEngineer).unwrap(),
+            "software-engineer"
+        );
+    }
+}
@@ -0,0 +1,472 @@
+//! Near-duplicate detection and merging for section items.
+//!
+//! Importing the same history from more than one source (a LinkedIn export
+//! and a JSON Resume file, say) leaves pairs of items that differ only in
+//! casing, punctuation, legal suffixes, or abbreviations. Items are compared
+//! within a section on their identifying fields (company and position for
+//! experience, name for skills, ...) with Jaro-Winkler similarity.
+//!
+//! Detection only proposes candidates; [`merge_items`] applies a merge the
+//! user has confirmed.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use thiserror::Error;
+use utoipa::ToSchema;
+
+use crate::ResumeData;
+use crate::TypeScript;
+
+/// Default minimum similarity (0–1) for two items to be reported as duplicates.
+pub const DEFAULT_SIMILARITY: f64 = 0.9;
+
+/// Identifying fields of each built-in item section, by JSON name.
+const IDENTITY_FIELDS: &[(&str, &[&str])] = &[
+    ("experience", &["company", "position"]),
+    ("education", &["institution", "area"]),
+    ("skills", &["name"]),
+    ("projects", &["name"]),
+    ("profiles", &["network", "username"]),
+    ("awards", &["title", "awarder"]),
+    ("certifications", &["name", "issuer"]),
+    ("publications", &["name"]),
+    ("languages", &["name"]),
+    ("interests", &["name"]),
+    ("volunteer", &["organization", "position"]),
+    ("references", &["name"]),
+];
+
+/// Identifying fields of custom section items.
+const CUSTOM_IDENTITY_FIELDS: &[&str] = &["name"];
+
+/// Common abbreviations expanded before comparing.
+const ABBREVIATIONS: &[(&str, &str)] = &[
+    ("asst", "assistant"),
+    ("assoc", "associate"),
+    ("dept", "department"),
+    ("dev", "developer"),
+    ("eng", "engineer"),
+    ("engr", "engineer"),
+    ("intl", "international"),
+    ("jr", "junior"),
+    ("mgr", "manager"),
+    ("sr", "senior"),
+    ("univ", "university"),
+];
+
+/// Articles and legal suffixes that do not distinguish organizations.
+const IGNORED_WORDS: &[&str] = &[
+    "co",
+    "company",
+    "corp",
+    "corporation",
+    "gmbh",
+    "inc",
+    "limited",
+    "llc",
+    "ltd",
+    "plc",
+    "the",
+];
+
+/// Two items in the same section that look like the same entry.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema, TypeScript)]
+#[serde(rename_all = "camelCase")]
+pub struct DuplicateCandidate {
+    /// Section key: `experience`, `skills`, ... or `custom.<id>`.
+    #[schema(example = "experience")]
+    pub section: String,
+
+    /// ID of the earlier item, kept by default when merging.
+    pub keep_id: String,
+
+    /// ID of the later item, folded into the kept one when merging.
+    pub duplicate_id: String,
+
+    /// Identifying fields of the kept item, for display.
+    #[schema(example = "Acme Inc., Sr. Engineer (2019 - 2022)")]
+    pub keep_label: String,
+
+    /// Identifying fields of the duplicate item, for display.
+    #[schema(example = "ACME, Senior Engineer (2019 - 2022)")]
+    pub duplicate_label: String,
+
+    /// Lowest similarity across the identifying fields both items fill in (0–1).
+    #[schema(example = 0.96)]
+    pub similarity: f64,
+}
+
+/// Error returned by [`merge_items`].
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum MergeError {
+    #[error("Unknown section: {0}")]
+    UnknownSection(String),
+
+    #[error("Item not found in {section}: {id}")]
+    ItemNotFound { section: String, id: String },
+
+    #[error("Cannot merge an item into itself")]
+    SameItem,
+
+    #[error("Failed to merge items: {0}")]
+    Invalid(String),
+}
+
+/// Find pairs of items within each section whose identifying fields are at
+/// least `min_similarity` alike.
+///
+/// Pairs are listed in section order, then item order. Three copies of the
+/// same entry yield three pairs; after merging one, look for duplicates again.
+pub fn find_duplicates(resume: &ResumeData, min_similarity: f64) -> Vec<DuplicateCandidate> {
+    let Ok(sections) = serde_json::to_value(&resume.sections) else {
+        return Vec::new();
+    };
+
+    let mut candidates = Vec::new();
+    for (section, items, fields) in item_sections(&sections) {
+        for (i, keep) in items.iter().enumerate() {
+            for duplicate in &items[i + 1..] {
+                let Some(similarity) = item_similarity(keep, duplicate, fields) else {
+                    continue;
+                };
+                if similarity >= min_similarity {
+                    candidates.push(DuplicateCandidate {
+                        section: section.clone(),
+                        keep_id: text(keep, "id").to_string(),
+                        duplicate_id: text(duplicate, "id").to_string(),
+                        keep_label: item_label(keep, fields),
+                        duplicate_label: item_label(duplicate, fields),
+                        similarity,
+                    });
+                }
+            }
+        }
+    }
+    candidates
+}
+
+/// Fold the item `duplicate_id` into `keep_id` and remove it from the section.
+///
+/// The kept item stays where it is and keeps its ID and any field it already
+/// has. Blank fields are filled from the duplicate, keyword lists are joined,
+/// and the result is visible if either item was.
+pub fn merge_items(
+    resume: &mut ResumeData,
+    section: &str,
+    keep_id: &str,
+    duplicate_id: &str,
+) -> Result<(), MergeError> {
+    if keep_id == duplicate_id {
+        return Err(MergeError::SameItem);
+    }
+
+    let mut sections =
+        serde_json::to_value(&resume.sections).map_err(|e| MergeError::Invalid(e.to_string()))?;
+    let items = section_items_mut(&mut sections, section)
+        .ok_or_else(|| MergeError::UnknownSection(section.to_string()))?;
+
+    let position = |id: &str| {
+        items
+            .iter()
+            .position(|item| text(item, "id") == id)
+            .ok_or_else(|| MergeError::ItemNotFound {
+                section: section.to_string(),
+                id: id.to_string(),
+            })
+    };
+    let keep = position(keep_id)?;
+    let duplicate = position(duplicate_id)?;
+
+    let removed = items.remove(duplicate);
+    let keep = if duplicate < keep { keep - 1 } else { keep };
+    merge_value(&mut items[keep], removed);
+
+    resume.sections =
+        serde_json::from_value(sections).map_err(|e| MergeError::Invalid(e.to_string()))?;
+    Ok(())
+}
+
+/// Item arrays of every item section, with their identifying fields.
+pub(crate) fn item_sections(sections: &Value) -> Vec<(String, &[Value], &'static [&'static str])> {
+    let mut result = Vec::new();
+    for (name, fields) in IDENTITY_FIELDS {
+        if let Some(items) = sections.get(*name).and_then(items_of) {
+            result.push((name.to_string(), items, *fields));
+        }
+    }
+    if let Some(custom) = sections.get("custom").and_then(Value::as_object) {
+        for (id, section) in custom {
+            if let Some(items) = items_of(section) {
+                result.push((format!("custom.{id}"), items, CUSTOM_IDENTITY_FIELDS));
+            }
+        }
+    }
+    result
+}
+
+/// Lowest similarity across the identifying fields both items fill in, or
+/// `None` when they have none in common.
+pub(crate) fn item_similarity(a: &Value, b: &Value, fields: &[&str]) -> Option<f64> {
+    let mut similarity: Option<f64> = None;
+    for field in fields {
+        let (a, b) = (normalize(text(a, field)), normalize(text(b, field)));
+        if a.is_empty() || b.is_empty() {
+            continue;
+        }
+        let score = strsim::jaro_winkler(&a, &b);
+        similarity = Some(similarity.map_or(score, |lowest| lowest.min(score)));
+    }
+    similarity
+}
+
+fn items_of(section: &Value) -> Option<&[Value]> {
+    section
+        .get("items")
+        .and_then(Value::as_array)
+        .map(Vec::as_slice)
+}
+
+pub(crate) fn section_items_mut<'a>(
+    sections: &'a mut Value,
+    section: &str,
+) -> Option<&'a mut Vec<Value>> {
+    let section = match section.strip_prefix("custom.") {
+        Some(id) => sections.get_mut("custom")?.get_mut(id)?,
+        None if IDENTITY_FIELDS.iter().any(|(name, _)| *name == section) => {
+            sections.get_mut(section)?
+        }
+        None => return None,
+    };
+    section.get_mut("items")?.as_array_mut()
+}
+
+/// Identifying fields of the items of `section` (`experience`, ... or
+/// `custom.<id>`).
+pub(crate) fn identity_fields(section: &str) -> &'static [&'static str] {
+    IDENTITY_FIELDS
+        .iter()
+        .find(|(name, _)| *name == section)
+        .map_or(CUSTOM_IDENTITY_FIELDS, |(_, fields)| *fields)
+}
+
+pub(crate) fn text<'a>(item: &'a Value, field: &str) -> &'a str {
+    item.get(field).and_then(Value::as_str).unwrap_or_default()
+}
+
+pub(crate) fn item_label(item: &Value, fields: &[&str]) -> String {
+    let label = fields
+        .iter()
+        .map(|field| text(item, field).trim())
+        .filter(|value| !value.is_empty())
+        .collect::<Vec<_>>()
+        .join(", ");
+    match text(item, "date").trim() {
+        "" => label,
+        date => format!("{label} ({date})"),
+    }
+}
+
+/// Lowercase words with abbreviations expanded and ignored words dropped.
+/// `+` and `#` are kept so "C++" and "C#" stay distinct.
+fn normalize(text: &str) -> String {
+    text.split(|c: char| !(c.is_alphanumeric() || c == '+' || c == '#'))
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .map(|word| {
+            ABBREVIATIONS
+                .iter()
+                .find(|(short, _)| *short == word)
+                .map_or(word, |(_, long)| long.to_string())
+        })
+        .filter(|word| !IGNORED_WORDS.contains(&word.as_str()))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn merge_value(keep: &mut Value, duplicate: Value) {
+    match (keep, duplicate) {
+        (Value::Object(keep), Value::Object(duplicate)) => {
+            for (key, value) in duplicate {
+                match keep.get_mut(&key) {
+                    Some(existing) => merge_value(existing, value),
+                    None => {
+                        keep.insert(key, value);
+                    }
+                }
+            }
+        }
+        (Value::Array(keep), Value::Array(duplicate)) => {
+            for value in duplicate {
+                let exists = keep.iter().any(|existing| match (existing, &value) {
+                    (Value::String(a), Value::String(b)) => normalize(a) == normalize(b),
+                    (a, b) => a == b,
+                });
+                if !exists {
+                    keep.push(value);
+                }
+            }
+        }
+        (Value::String(keep), Value::String(duplicate)) if keep.trim().is_empty() => {
+            *keep = duplicate;
+        }
+        (Value::Bool(keep), Value::Bool(duplicate)) => *keep |= duplicate,
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CustomItem, Experience, Section, Skill};
+
+    fn resume_with_experience(items: Vec<Experience>) -> ResumeData {
+        let mut resume = ResumeData::default();
+        resume.sections.experience = Section::new("experience", "Experience");
+        for item in items {
+            resume.sections.experience.add_item(item);
+        }
+        resume
+    }
+
+    #[test]
+    fn test_detects_same_role_from_two_sources() {
+        let resume = resume_with_experience(vec![
+            Experience::new("Acme Inc.", "Sr. Software Engineer").with_date("2019 - 2022"),
+            Experience::new("ACME", "Senior Software Engineer"),
+        ]);
+
+        let candidates = resume.duplicates();
+        assert_eq!(candidates.len(), 1);
+        let candidate = &candidates[0];
+        assert_eq!(candidate.section, "experience");
+        assert_eq!(candidate.keep_id, resume.sections.experience.items[0].id);
+        assert_eq!(
+            candidate.duplicate_id,
+            resume.sections.experience.items[1].id
+        );
+        assert_eq!(
+            candidate.keep_label,
+            "Acme Inc., Sr. Software Engineer (2019 - 2022)"
+        );
+        assert!((candidate.similarity - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_different_roles_at_same_company_are_not_duplicates() {
+        let resume = resume_with_experience(vec![
+            Experience::new("Acme", "Engineer"),
+            Experience::new("Acme", "Engineering Manager"),
+            Experience::new("Globex", "Engineer"),
+        ]);
+        assert!(resume.duplicates().is_empty());
+    }
+
+    #[test]
+    fn test_missing_field_compares_on_the_rest() {
+        let resume = resume_with_experience(vec![
+            Experience::new("Initech", ""),
+            Experience::new("Initech", "Analyst"),
+        ]);
+        assert_eq!(resume.duplicates().len(), 1);
+    }
+
+    #[test]
+    fn test_skills_keep_symbols_and_typos_match() {
+        let mut resume = ResumeData::default();
+        for name in ["C++", "C#", "Kubernetes", "Kubernets"] {
+            resume.sections.skills.add_item(Skill::new(name));
+        }
+
+        let candidates = resume.duplicates();
+        assert_eq!(candidates.len(), 1);
+        assert_eq!(candidates[0].keep_label, "Kubernetes");
+        assert_eq!(candidates[0].duplicate_label, "Kubernets");
+    }
+
+    #[test]
+    fn test_custom_sections_are_checked() {
+        let mut resume = ResumeData::default();
+        let mut section = Section::new("talks", "Talks");
+        section.add_item(CustomItem::new("RustConf Keynote"));
+        section.add_item(CustomItem::new("rustconf keynote"));
+        resume.sections.custom.insert("talks".to_string(), section);
+
+        let candidates = resume.duplicates();
+        assert_eq!(candidates.len(), 1);
+        assert_eq!(candidates[0].section, "custom.talks");
+    }
+
+    #[test]
+    fn test_merge_fills_blanks_and_joins_keywords() {
+        let mut resume = ResumeData::default();
+        let keep = Skill::new("Rust").with_keywords(vec!["Tokio".into()]);
+        let mut duplicate = Skill::new("rust")
+            .with_description("Advanced")
+            .with_keywords(vec!["tokio".into(), "Axum".into()]);
+        duplicate.visible = false;
+        let (keep_id, duplicate_id) = (keep.id.clone(), duplicate.id.clone());
+        resume.sections.skills.add_item(keep);
+        resume.sections.skills.add_item(duplicate);
+
+        merge_items(&mut resume, "skills", &keep_id, &duplicate_id).unwrap();
+
+        let items = &resume.sections.skills.items;
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].id, keep_id);
+        assert_eq!(items[0].name, "Rust");
+        assert_eq!(items[0].description, "Advanced");
+        assert_eq!(items[0].keywords, ["Tokio", "Axum"]);
+        assert!(items[0].visible);
+        assert!(resume.duplicates().is_empty());
+    }
+
+    #[test]
+    fn test_merge_keeps_later_item_in_place() {
+        let mut resume = resume_with_experience(vec![
+            Experience::new("Acme", "Engineer").with_summary("From LinkedIn"),
+            Experience::new("Globex", "Analyst"),
+            Experience::new("Acme", "Engineer")
+                .with_summary("From JSON Resume")
+                .with_location("Berlin"),
+        ]);
+        let ids: Vec<String> = resume
+            .sections
+            .experience
+            .items
+            .iter()
+            .map(|item| item.id.clone())
+            .collect();
+
+        merge_items(&mut resume, "experience", &ids[2], &ids[0]).unwrap();
+
+        let items = &resume.sections.experience.items;
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].id, ids[1]);
+        assert_eq!(items[1].id, ids[2]);
+        assert_eq!(items[1].summary, "From JSON Resume");
+        assert_eq!(items[1].location, "Berlin");
+    }
+
+    #[test]
+    fn test_merge_errors() {
+        let mut resume = resume_with_experience(vec![Experience::new("Acme", "Engineer")]);
+        let id = resume.sections.experience.items[0].id.clone();
+
+        assert_eq!(
+            merge_items(&mut resume, "experience", &id, &id),
+            Err(MergeError::SameItem)
+        );
+        assert_eq!(
+            merge_items(&mut resume, "summary", &id, "other"),
+            Err(MergeError::UnknownSection("summary".into()))
+        );
+        assert_eq!(
+            merge_items(&mut resume, "experience", &id, "missing"),
+            Err(MergeError::ItemNotFound {
+                section: "experience".into(),
+                id: "missing".into()
+            })
+        );
+        assert_eq!(resume.sections.experience.items.len(), 1);
+    }
+}
@@ -0,0 +1,359 @@
+//! Structural diff between two versions of a resume.
+//!
+//! [`diff_resumes`] walks both resumes as JSON and lists every field that was
+//! added, removed, or changed. Section items are matched by `id`, so moving or
+//! deleting an item doesn't show up as edits to every item after it; other
+//! arrays (keywords, the layout) are compared as a whole.
+//! [`ResumeDiff::summary`] condenses the changes into one line for history
+//! and activity views.
+
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::ResumeData;
+
+/// What happened to a field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeKind {
+    Added,
+    Removed,
+    Changed,
+    /// The same items in a different order; `before` and `after` list their IDs.
+    Reordered,
+}
+
+/// One difference between two resumes.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldChange {
+    /// JSON Pointer to the field, except that section items are addressed by
+    /// their ID instead of their index: `/sections/skills/items/<id>/name`.
+    pub path: String,
+    pub kind: ChangeKind,
+    /// Old value; `None` for added fields.
+    pub before: Option<Value>,
+    /// New value; `None` for removed fields.
+    pub after: Option<Value>,
+}
+
+/// Every difference between two resumes, in document order.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ResumeDiff {
+    pub changes: Vec<FieldChange>,
+}
+
+/// Differences from `before` to `after`.
+pub fn diff_resumes(before: &ResumeData, after: &ResumeData) -> ResumeDiff {
+    let (Ok(before), Ok(after)) = (serde_json::to_value(before), serde_json::to_value(after))
+    else {
+        return ResumeDiff::default();
+    };
+    let mut changes = Vec::new();
+    diff_values(&mut String::new(), &before, &after, &mut changes);
+    ResumeDiff { changes }
+}
+
+impl ResumeDiff {
+    /// Whether the resumes have the same content.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// One line describing the changes, such as `Edited basics (name,
+    /// email); added 1 experience item; changed template`.
+    pub fn summary(&self) -> String {
+        let mut basics = BTreeSet::new();
+        let mut metadata = BTreeSet::new();
+        let mut sections: Vec<(String, SectionTally)> = Vec::new();
+        let mut attachments = false;
+
+        for change in &self.changes {
+            let segments: Vec<&str> = change.path.split('/').skip(1).collect();
+            match segments.as_slice() {
+                ["basics", field, ..] => {
+                    basics.insert(*field);
+                }
+                ["basics"] => {
+                    basics.insert("basics");
+                }
+                ["metadata", field, ..] => {
+                    metadata.insert(*field);
+                }
+                ["sections", "custom", id, rest @ ..] => {
+                    tally(&mut sections, format!("custom.{id}"), rest, change.kind);
+                }
+                ["sections", name, rest @ ..] => {
+                    tally(&mut sections, (*name).to_string(), rest, change.kind);
+                }
+                ["attachments", ..] => attachments = true,
+                _ => {}
+            }
+        }
+
+        let mut parts = Vec::new();
+        if !basics.is_empty() {
+            let fields: Vec<&str> = basics.into_iter().collect();
+            parts.push(format!("edited basics ({})", fields.join(", ")));
+        }
+        for (name, tally) in sections {
+            for (count, verb) in [
+                (tally.added, "added"),
+                (tally.edited.len(), "edited"),
+                (tally.removed, "removed"),
+            ] {
+                if count > 0 {
+                    let noun = if count == 1 { "item" } else { "items" };
+                    parts.push(format!("{verb} {count} {name} {noun}"));
+                }
+            }
+            if tally.reordered {
+                parts.push(format!("reordered {name}"));
+            }
+            if tally.settings {
+                parts.push(format!("edited {name} section"));
+            }
+        }
+        if attachments {
+            parts.push("edited attachments".to_string());
+        }
+        if !metadata.is_empty() {
+            let fields: Vec<&str> = metadata.into_iter().collect();
+            parts.push(format!("changed {}", fields.join(", ")));
+        }
+
+        let summary = parts.join("; ");
+        let mut chars = summary.chars();
+        match chars.next() {
+            Some(first) => first.to_uppercase().chain(chars).collect(),
+            None => "No changes".to_string(),
+        }
+    }
+}
+
+/// Changes to one section, counted for [`ResumeDiff::summary`].
+#[derive(Default)]
+struct SectionTally {
+    added: usize,
+    removed: usize,
+    edited: HashSet<String>,
+    reordered: bool,
+    /// Title, visibility, columns, ... changed.
+    settings: bool,
+}
+
+fn tally(
+    sections: &mut Vec<(String, SectionTally)>,
+    name: String,
+    rest: &[&str],
+    kind: ChangeKind,
+) {
+    let index = match sections.iter().position(|(section, _)| *section == name) {
+        Some(index) => index,
+        None => {
+            sections.push((name, SectionTally::default()));
+            sections.len() - 1
+        }
+    };
+    let tally = &mut sections[index].1;
+    match (rest, kind) {
+        (["items", _], ChangeKind::Added) => tally.added += 1,
+        (["items", _], ChangeKind::Removed) => tally.removed += 1,
+        (["items"], ChangeKind::Reordered) => tally.reordered = true,
+        (["items", id, ..], _) => {
+            tally.edited.insert((*id).to_string());
+        }
+        _ => tally.settings = true,
+    }
+}
+
+fn diff_values(path: &mut String, before: &Value, after: &Value, out: &mut Vec<FieldChange>) {
+    if before == after {
+        return;
+    }
+    match (before, after) {
+        (Value::Object(old), Value::Object(new)) => {
+            let keys: BTreeSet<&String> = old.keys().chain(new.keys()).collect();
+            for key in keys {
+                with_segment(path, key, |path| match (old.get(key), new.get(key)) {
+                    (Some(old), Some(new)) => diff_values(path, old, new, out),
+                    (old, new) => out.push(FieldChange {
+                        path: path.clone(),
+                        kind: if old.is_some() {
+                            ChangeKind::Removed
+                        } else {
+                            ChangeKind::Added
+                        },
+                        before: old.cloned(),
+                        after: new.cloned(),
+                    }),
+                });
+            }
+        }
+        (Value::Array(old), Value::Array(new)) => match (item_ids(old), item_ids(new)) {
+            (Some(old_ids), Some(new_ids)) => diff_items(path, old, &old_ids, new, &new_ids, out),
+            _ => out.push(changed(path, before, after)),
+        },
+        _ => out.push(changed(path, before, after)),
+    }
+}
+
+/// Compare two lists of items with IDs, matching items by ID.
+fn diff_items(
+    path: &mut String,
+    old: &[Value],
+    old_ids: &[&str],
+    new: &[Value],
+    new_ids: &[&str],
+    out: &mut Vec<FieldChange>,
+) {
+    let old_by_id: HashMap<&str, &Value> = old_ids.iter().copied().zip(old).collect();
+    let new_by_id: HashMap<&str, &Value> = new_ids.iter().copied().zip(new).collect();
+
+    for (id, item) in old_ids.iter().zip(old) {
+        if !new_by_id.contains_key(id) {
+            with_segment(path, id, |path| {
+                out.push(FieldChange {
+                    path: path.clone(),
+                    kind: ChangeKind::Removed,
+                    before: Some(item.clone()),
+                    after: None,
+                })
+            });
+        }
+    }
+    for (id, item) in new_ids.iter().zip(new) {
+        with_segment(path, id, |path| match old_by_id.get(id) {
+            Some(old) => diff_values(path, old, item, out),
+            None => out.push(FieldChange {
+                path: path.clone(),
+                kind: ChangeKind::Added,
+                before: None,
+                after: Some(item.clone()),
+            }),
+        });
+    }
+
+    let kept_old: Vec<&str> = old_ids
+        .iter()
+        .copied()
+        .filter(|id| new_by_id.contains_key(id))
+        .collect();
+    let kept_new: Vec<&str> = new_ids
+        .iter()
+        .copied()
+        .filter(|id| old_by_id.contains_key(id))
+        .collect();
+    if kept_old != kept_new {
+        out.push(FieldChange {
+            path: path.clone(),
+            kind: ChangeKind::Reordered,
+            before: Some(kept_old.into()),
+            after: Some(kept_new.into()),
+        });
+    }
+}
+
+/// IDs of `items` when every one is an object with a distinct, non-empty
+/// string `id`.
+fn item_ids(items: &[Value]) -> Option<Vec<&str>> {
+    let ids: Vec<&str> = items
+        .iter()
+        .map(|item| item.get("id")?.as_str().filter(|id| !id.is_empty()))
+        .collect::<Option<_>>()?;
+    let distinct: HashSet<&str> = ids.iter().copied().collect();
+    (distinct.len() == ids.len()).then_some(ids)
+}
+
+fn changed(path: &str, before: &Value, after: &Value) -> FieldChange {
+    FieldChange {
+        path: path.to_string(),
+        kind: ChangeKind::Changed,
+        before: Some(before.clone()),
+        after: Some(after.clone()),
+    }
+}
+
+/// Run `f` with `segment` appended to `path` as a JSON Pointer segment.
+fn with_segment(path: &mut String, segment: &str, f: impl FnOnce(&mut String)) {
+    let len = path.len();
+    path.push('/');
+    path.push_str(&segment.replace('~', "~0").replace('/', "~1"));
+    f(path);
+    path.truncate(len);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Skill, Theme};
+
+    fn resume_with_skills(names: &[&str]) -> ResumeData {
+        let mut resume = ResumeData::with_basics("Ada Lovelace", "ada@example.com");
+        for name in names {
+            let mut skill = Skill::new(*name);
+            skill.id = format!("skill-{}", name.to_lowercase());
+            resume.sections.skills.items.push(skill);
+        }
+        resume
+    }
+
+    #[test]
+    fn identical_resumes_have_no_changes() {
+        let resume = resume_with_skills(&["Rust", "Go"]);
+        let diff = diff_resumes(&resume, &resume.clone());
+        assert!(diff.is_empty());
+        assert_eq!(diff.summary(), "No changes");
+    }
+
+    #[test]
+    fn items_are_matched_by_id() {
+        let before = resume_with_skills(&["Rust", "Go", "Python"]);
+        let mut after = resume_with_skills(&["Go", "Rust", "Zig"]);
+        after.sections.skills.items[1].level = 5;
+        after.basics.email = "ada@analytical.engine".to_string();
+        after.metadata.template = "onyx".to_string();
+
+        let diff = diff_resumes(&before, &after);
+        let paths: Vec<(&str, ChangeKind)> = diff
+            .changes
+            .iter()
+            .map(|change| (change.path.as_str(), change.kind))
+            .collect();
+        assert_eq!(
+            paths,
+            [
+                ("/basics/email", ChangeKind::Changed),
+                ("/metadata/template", ChangeKind::Changed),
+                ("/sections/skills/items/skill-python", ChangeKind::Removed),
+                (
+                    "/sections/skills/items/skill-rust/level",
+                    ChangeKind::Changed
+                ),
+                ("/sections/skills/items/skill-zig", ChangeKind::Added),
+                ("/sections/skills/items", ChangeKind::Reordered),
+            ]
+        );
+        assert_eq!(
+            diff.summary(),
+            "Edited basics (email); added 1 skills item; edited 1 skills item; \
+             removed 1 skills item; reordered skills; changed template"
+        );
+    }
+
+    #[test]
+    fn summary_groups_metadata_and_section_settings() {
+        let before = ResumeData::default();
+        let mut after = before.clone();
+        after.metadata.theme = Theme {
+            primary: "#1d4ed8".to_string(),
+            ..Theme::default()
+        };
+        after.sections.skills.name = "Tools".to_string();
+
+        let diff = diff_resumes(&before, &after);
+        assert_eq!(diff.summary(), "Edited skills section; changed theme");
+    }
+}
@@ -0,0 +1,624 @@
+//! Readable report of what changed between two versions of a resume.
+//!
+//! [`diff_report`] takes the changes [`diff_resumes`] finds and groups them
+//! the way a reviewer reads a resume: basics, each section, attachments, then
+//! settings. Items are named by their identifying fields (position and
+//! company, skill name, ...) instead of their IDs, and edited text is
+//! compared word by word with [`diff_words`], so an edit shows exactly which
+//! words were dropped and which were added. Rich text is compared as plain
+//! text. [`DiffReport::render`] writes the report as Markdown or as a
+//! standalone HTML page.
+
+use rustume_utils::{escape_html, html_text_blocks};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use utoipa::ToSchema;
+
+use crate::dedupe::{identity_fields, item_label};
+use crate::{diff_resumes, ChangeKind, FieldChange, ResumeData};
+
+/// Largest product of the two word counts compared word by word; longer
+/// texts show as the old text replaced by the new one.
+const MAX_WORD_DIFF_CELLS: usize = 1_000_000;
+
+/// Identifying fields of attachments.
+const ATTACHMENT_FIELDS: &[&str] = &["name"];
+
+/// How a [`DiffReport`] is written out.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub enum DiffReportFormat {
+    /// Markdown, with removed words struck through and added words in bold.
+    #[default]
+    #[serde(rename = "md", alias = "markdown")]
+    Markdown,
+    /// A standalone HTML page, with `<del>` and `<ins>` marking the words.
+    #[serde(rename = "html")]
+    Html,
+}
+
+/// A run of words in a word-level diff.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WordChange {
+    /// Words both versions have.
+    Same(String),
+    /// Words only the new version has.
+    Added(String),
+    /// Words only the old version has.
+    Removed(String),
+}
+
+/// One line of a [`DiffReport`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReportEntry {
+    /// An item was added; holds its label.
+    ItemAdded(String),
+    /// An item was removed; holds its label.
+    ItemRemoved(String),
+    /// The same items are in a different order; holds what was reordered.
+    Reordered(String),
+    /// A field was edited, filled in, or cleared.
+    Edited {
+        /// Label of the item the field belongs to, for fields of items.
+        item: Option<String>,
+        /// Name of the field, such as `Summary` or `Theme primary`.
+        field: String,
+        words: Vec<WordChange>,
+    },
+}
+
+/// Changes to one part of the resume.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReportGroup {
+    /// `Basics`, a section's name, `Attachments`, or `Settings`.
+    pub title: String,
+    pub entries: Vec<ReportEntry>,
+}
+
+/// Changes between two resumes, grouped and labeled for people to read.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiffReport {
+    /// The one-line [`ResumeDiff::summary`](crate::ResumeDiff::summary).
+    pub summary: String,
+    pub groups: Vec<ReportGroup>,
+}
+
+/// Report of the changes from `before` to `after`.
+pub fn diff_report(before: &ResumeData, after: &ResumeData) -> DiffReport {
+    let diff = diff_resumes(before, after);
+    let before = serde_json::to_value(before).unwrap_or_default();
+    let after = serde_json::to_value(after).unwrap_or_default();
+
+    let mut basics = Vec::new();
+    let mut sections: Vec<(String, ReportGroup)> = Vec::new();
+    let mut attachments = Vec::new();
+    let mut settings = Vec::new();
+
+    for change in &diff.changes {
+        let segments: Vec<String> = change
+            .path
+            .split('/')
+            .skip(1)
+            .map(|segment| segment.replace("~1", "/").replace("~0", "~"))
+            .collect();
+        let segments: Vec<&str> = segments.iter().map(String::as_str).collect();
+        match segments.as_slice() {
+            ["basics", field @ ..] => basics.push(edited(None, field, change)),
+            ["sections", "custom", id, rest @ ..] => {
+                let pointer = format!("/sections/custom/{}", change_segment(&change.path, 3));
+                let group = section_group(&mut sections, &pointer, id, &before, &after);
+                group.push(section_entry(
+                    &pointer, "custom", rest, change, &before, &after,
+                ));
+            }
+            ["sections", key, rest @ ..] => {
+                let pointer = format!("/sections/{}", change_segment(&change.path, 2));
+                let group = section_group(&mut sections, &pointer, key, &before, &after);
+                group.push(section_entry(&pointer, key, rest, change, &before, &after));
+            }
+            ["attachments", rest @ ..] => attachments.push(item_entry(
+                list(&before, "/attachments"),
+                list(&after, "/attachments"),
+                ATTACHMENT_FIELDS,
+                rest,
+                change,
+            )),
+            ["metadata", field @ ..] | field => settings.push(edited(None, field, change)),
+        }
+    }
+
+    let mut groups = Vec::new();
+    let mut add = |title: &str, entries: Vec<ReportEntry>| {
+        if !entries.is_empty() {
+            groups.push(ReportGroup {
+                title: title.to_string(),
+                entries,
+            });
+        }
+    };
+    add("Basics", basics);
+    for (_, group) in sections {
+        add(&group.title, group.entries);
+    }
+    add("Attachments", attachments);
+    add("Settings", settings);
+
+    DiffReport {
+        summary: diff.summary(),
+        groups,
+    }
+}
+
+/// Compare two texts word by word.
+///
+/// Words are split on whitespace, which is not compared: each run holds its
+/// words joined by single spaces. Where both removed and added words fall
+/// between the same unchanged words, the removed run comes first.
+pub fn diff_words(before: &str, after: &str) -> Vec<WordChange> {
+    #[derive(Clone, Copy, PartialEq)]
+    enum Side {
+        Same,
+        Added,
+        Removed,
+    }
+
+    let old: Vec<&str> = before.split_whitespace().collect();
+    let new: Vec<&str> = after.split_whitespace().collect();
+    let mut words: Vec<(Side, &str)> = Vec::with_capacity(old.len().max(new.len()));
+
+    if old.len().saturating_mul(new.len()) > MAX_WORD_DIFF_CELLS {
+        words.extend(old.iter().map(|word| (Side::Removed, *word)));
+        words.extend(new.iter().map(|word| (Side::Added, *word)));
+    } else {
+        // Length of the longest common subsequence of old[i..] and new[j..]
+        let width = new.len() + 1;
+        let mut common = vec![0u32; (old.len() + 1) * width];
+        for i in (0..old.len()).rev() {
+            for j in (0..new.len()).rev() {
+                common[i * width + j] = if old[i] == new[j] {
+                    common[(i + 1) * width + j + 1] + 1
+                } else {
+                    common[(i + 1) * width + j].max(common[i * width + j + 1])
+                };
+            }
+        }
+        let (mut i, mut j) = (0, 0);
+        while i < old.len() && j < new.len() {
+            if old[i] == new[j] {
+                words.push((Side::Same, old[i]));
+                i += 1;
+                j += 1;
+            } else if common[(i + 1) * width + j] >= common[i * width + j + 1] {
+                words.push((Side::Removed, old[i]));
+                i += 1;
+            } else {
+                words.push((Side::Added, new[j]));
+                j += 1;
+            }
+        }
+        words.extend(old[i..].iter().map(|word| (Side::Removed, *word)));
+        words.extend(new[j..].iter().map(|word| (Side::Added, *word)));
+    }
+
+    let mut runs: Vec<(Side, String)> = Vec::new();
+    for (side, word) in words {
+        match runs.last_mut() {
+            Some((last, text)) if *last == side => {
+                text.push(' ');
+                text.push_str(word);
+            }
+            _ => runs.push((side, word.to_string())),
+        }
+    }
+    runs.into_iter()
+        .map(|(side, text)| match side {
+            Side::Same => WordChange::Same(text),
+            Side::Added => WordChange::Added(text),
+            Side::Removed => WordChange::Removed(text),
+        })
+        .collect()
+}
+
+impl DiffReport {
+    /// Whether the resumes have the same content.
+    pub fn is_empty(&self) -> bool {
+        self.groups.is_empty()
+    }
+
+    /// The report in `format`.
+    pub fn render(&self, format: DiffReportFormat) -> String {
+        match format {
+            DiffReportFormat::Markdown => self.to_markdown(),
+            DiffReportFormat::Html => self.to_html(),
+        }
+    }
+
+    /// The report as Markdown: a heading per group and a list item per
+    /// change, with removed words ~~struck through~~ and added words in
+    /// **bold**.
+    pub fn to_markdown(&self) -> String {
+        let mut out = format!("# Resume changes\n\n{}\n", escape_markdown(&self.summary));
+        for group in &self.groups {
+            out.push_str(&format!("\n## {}\n\n", escape_markdown(&group.title)));
+            for entry in &group.entries {
+                let line = entry_line(
+                    entry,
+                    escape_markdown,
+                    |text| format!("**{text}**"),
+                    |text| format!("~~{text}~~"),
+                    |text| format!("_{text}_"),
+                );
+                out.push_str(&format!("- {line}\n"));
+            }
+        }
+        out
+    }
+
+    /// The report as a standalone HTML page, with removed words in `<del>`
+    /// and added words in `<ins>`.
+    pub fn to_html(&self) -> String {
+        let mut body = format!(
+            "<h1>Resume changes</h1>\n<p>{}</p>\n",
+            escape_html(&self.summary)
+        );
+        for group in &self.groups {
+            body.push_str(&format!("<h2>{}</h2>\n<ul>\n", escape_html(&group.title)));
+            for entry in &group.entries {
+                let line = entry_line(
+                    entry,
+                    escape_html,
+                    |text| format!("<ins>{text}</ins>"),
+                    |text| format!("<del>{text}</del>"),
+                    |text| format!("<em>{text}</em>"),
+                );
+                body.push_str(&format!("<li>{line}</li>\n"));
+            }
+            body.push_str("</ul>\n");
+        }
+        format!("{HTML_HEAD}{body}</body>\n</html>\n")
+    }
+}
+
+const HTML_HEAD: &str = r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<meta name="viewport" content="width=device-width, initial-scale=1">
+<title>Resume changes</title>
+<style>
+body { font: 15px/1.6 system-ui, sans-serif; color: #111827; max-width: 50rem; margin: 2rem auto; padding: 0 1rem; }
+h2 { font-size: 1.15rem; margin-top: 1.75rem; border-bottom: 1px solid #e5e7eb; }
+ins { background: #dcfce7; color: #166534; text-decoration: none; }
+del { background: #fee2e2; color: #991b1b; }
+</style>
+</head>
+<body>
+"#;
+
+/// One entry as a line of text. `escape` prepares plain text, and `added`,
+/// `removed`, and `note` mark up text that is already escaped.
+fn entry_line(
+    entry: &ReportEntry,
+    escape: fn(&str) -> String,
+    added: impl Fn(&str) -> String,
+    removed: impl Fn(&str) -> String,
+    note: impl Fn(&str) -> String,
+) -> String {
+    match entry {
+        ReportEntry::ItemAdded(label) => format!("Added: {}", added(&escape(label))),
+        ReportEntry::ItemRemoved(label) => format!("Removed: {}", removed(&escape(label))),
+        ReportEntry::Reordered(what) => format!("Reordered {}", escape(what)),
+        ReportEntry::Edited { item, field, words } => {
+            let field = match item {
+                Some(item) => format!("{item} › {field}"),
+                None => field.clone(),
+            };
+            let text: Vec<String> = words
+                .iter()
+                .map(|word| match word {
+                    WordChange::Same(text) => escape(text),
+                    WordChange::Added(text) => added(&escape(text)),
+                    WordChange::Removed(text) => removed(&escape(text)),
+                })
+                .collect();
+            let mut line = format!("{}: {}", escape(&field), text.join(" "));
+            if words.iter().all(|word| matches!(word, WordChange::Same(_))) {
+                line = format!("{} {}", line.trim_end(), note("(formatting only)"));
+            }
+            line
+        }
+    }
+}
+
+/// Entries of the section at `pointer`, adding its group on first use.
+fn section_group<'a>(
+    sections: &'a mut Vec<(String, ReportGroup)>,
+    pointer: &str,
+    key: &str,
+    before: &Value,
+    after: &Value,
+) -> &'a mut Vec<ReportEntry> {
+    let index = match sections.iter().position(|(seen, _)| seen == pointer) {
+        Some(index) => index,
+        None => {
+            let name = [after, before]
+                .iter()
+                .filter_map(|resume| resume.pointer(&format!("{pointer}/name"))?.as_str())
+                .map(str::trim)
+                .find(|name| !name.is_empty());
+            let title = match name {
+                Some(name) => name.to_string(),
+                None => field_name(&[key]),
+            };
+            sections.push((
+                pointer.to_string(),
+                ReportGroup {
+                    title,
+                    entries: Vec::new(),
+                },
+            ));
+            sections.len() - 1
+        }
+    };
+    &mut sections[index].1.entries
+}
+
+/// Entry for a change inside the section at `pointer`; `rest` is the path
+/// below the section.
+fn section_entry(
+    pointer: &str,
+    key: &str,
+    rest: &[&str],
+    change: &FieldChange,
+    before: &Value,
+    after: &Value,
+) -> ReportEntry {
+    match rest {
+        ["items", items @ ..] => {
+            let items_pointer = format!("{pointer}/items");
+            item_entry(
+                list(before, &items_pointer),
+                list(after, &items_pointer),
+                identity_fields(key),
+                items,
+                change,
+            )
+        }
+        setting => {
+            let mut entry = edited(None, setting, change);
+            if let ReportEntry::Edited { field, .. } = &mut entry {
+                *field = format!("Section {}", field.to_lowercase());
+            }
+            entry
+        }
+    }
+}
+
+/// Entry for a change to a list of items with IDs; `rest` is the path below
+/// the list.
+fn item_entry(
+    before: &[Value],
+    after: &[Value],
+    fields: &[&str],
+    rest: &[&str],
+    change: &FieldChange,
+) -> ReportEntry {
+    let label = |id: &str| {
+        let item = after
+            .iter()
+            .chain(before)
+            .find(|item| item.get("id").and_then(Value::as_str) == Some(id));
+        match item.map(|item| item_label(item, fields)) {
+            Some(label) if !label.is_empty() => label,
+            _ => id.to_string(),
+        }
+    };
+    match (rest, change.kind) {
+        ([], ChangeKind::Reordered) => ReportEntry::Reordered("items".to_string()),
+        ([id], ChangeKind::Added) => ReportEntry::ItemAdded(label(id)),
+        ([id], ChangeKind::Removed) => ReportEntry::ItemRemoved(label(id)),
+        ([id, field @ ..], _) => edited(Some(label(id)), field, change),
+        ([], _) => edited(None, &["items"], change),
+    }
+}
+
+fn edited(item: Option<String>, path: &[&str], change: &FieldChange) -> ReportEntry {
+    ReportEntry::Edited {
+        item,
+        field: field_name(path),
+        words: diff_words(
+            &value_text(change.before.as_ref()),
+            &value_text(change.after.as_ref()),
+        ),
+    }
+}
+
+/// Items of the list at `pointer`, or none.
+fn list<'a>(resume: &'a Value, pointer: &str) -> &'a [Value] {
+    resume
+        .pointer(pointer)
+        .and_then(Value::as_array)
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+}
+
+/// The `index`th segment of a JSON Pointer, still escaped.
+fn change_segment(path: &str, index: usize) -> &str {
+    path.split('/').nth(index).unwrap_or_default()
+}
+
+/// A field path as words: `studyType` reads `Study type`, `url/href` reads
+/// `URL`.
+fn field_name(path: &[&str]) -> String {
+    let mut words = Vec::new();
+    for segment in path {
+        match *segment {
+            "href" => {}
+            "url" => words.push("URL".to_string()),
+            segment => {
+                let mut word = String::new();
+                for c in segment.chars() {
+                    if c.is_uppercase() && !word.is_empty() {
+                        words.push(std::mem::take(&mut word));
+                    }
+                    word.extend(c.to_lowercase());
+                }
+                if !word.is_empty() {
+                    words.push(word);
+                }
+            }
+        }
+    }
+    let name = words.join(" ");
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => "Value".to_string(),
+    }
+}
+
+/// A field's value as the text a reader sees. Rich text is the editor's
+/// markup, so only strings wrapped in tags are read as HTML.
+fn value_text(value: Option<&Value>) -> String {
+    match value {
+        None | Some(Value::Null) => String::new(),
+        Some(Value::String(text)) if is_markup(text) => html_text_blocks(text).join(" "),
+        Some(Value::String(text)) => text.clone(),
+        Some(Value::Bool(true)) => "yes".to_string(),
+        Some(Value::Bool(false)) => "no".to_string(),
+        Some(Value::Number(number)) => number.to_string(),
+        Some(Value::Array(values)) => values
+            .iter()
+            .map(|value| value_text(Some(value)))
+            .filter(|text| !text.is_empty())
+            .collect::<Vec<_>>()
+            .join(", "),
+        Some(Value::Object(fields)) => match fields.get("href") {
+            Some(href) => value_text(Some(href)),
+            None => Value::Object(fields.clone()).to_string(),
+        },
+    }
+}
+
+fn is_markup(text: &str) -> bool {
+    let text = text.trim();
+    text.starts_with('<') && text.ends_with('>')
+}
+
+fn escape_markdown(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '\\' | '`' | '*' | '_' | '~' | '[' | ']' | '<' | '>') {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Experience, Skill};
+
+    fn resume() -> ResumeData {
+        let mut resume = ResumeData::with_basics("Ada Lovelace", "ada@example.com");
+        let mut job = Experience::new("Analytical Engines", "Engineer");
+        job.id = "job".to_string();
+        job.summary = "<p>Wrote the first published program.</p>".to_string();
+        resume.sections.experience.items.push(job);
+        for name in ["Rust", "Go"] {
+            let mut skill = Skill::new(name);
+            skill.id = format!("skill-{}", name.to_lowercase());
+            resume.sections.skills.items.push(skill);
+        }
+        resume
+    }
+
+    #[test]
+    fn words_are_compared_in_runs() {
+        assert_eq!(
+            diff_words("Led a team of  five", "Led the team of five engineers"),
+            [
+                WordChange::Same("Led".to_string()),
+                WordChange::Removed("a".to_string()),
+                WordChange::Added("the".to_string()),
+                WordChange::Same("team of five".to_string()),
+                WordChange::Added("engineers".to_string()),
+            ]
+        );
+        assert_eq!(
+            diff_words("", "new text"),
+            [WordChange::Added("new text".to_string())]
+        );
+        assert!(diff_words("", " ").is_empty());
+    }
+
+    #[test]
+    fn report_names_items_and_groups_changes() {
+        let before = resume();
+        let mut after = before.clone();
+        after.basics.email = "ada@analytical.engine".to_string();
+        after.sections.experience.items[0].summary =
+            "<p>Wrote the first published computer program.</p>".to_string();
+        after.sections.skills.items.remove(1);
+        let mut zig = Skill::new("Zig");
+        zig.id = "skill-zig".to_string();
+        after.sections.skills.items.push(zig);
+        after.metadata.template = "onyx".to_string();
+
+        let report = diff_report(&before, &after);
+        let titles: Vec<&str> = report.groups.iter().map(|g| g.title.as_str()).collect();
+        assert_eq!(titles, ["Basics", "Experience", "Skills", "Settings"]);
+        assert_eq!(
+            report.groups[1].entries,
+            [ReportEntry::Edited {
+                item: Some("Analytical Engines, Engineer".to_string()),
+                field: "Summary".to_string(),
+                words: vec![
+                    WordChange::Same("Wrote the first published".to_string()),
+                    WordChange::Added("computer".to_string()),
+                    WordChange::Same("program.".to_string()),
+                ],
+            }]
+        );
+        assert_eq!(
+            report.groups[2].entries,
+            [
+                ReportEntry::ItemRemoved("Go".to_string()),
+                ReportEntry::ItemAdded("Zig".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn markdown_marks_words_and_escapes_text() {
+        let before = resume();
+        let mut after = before.clone();
+        after.basics.headline = "C++ *and* Rust".to_string();
+        after.sections.skills.items.remove(0);
+
+        let markdown = diff_report(&before, &after).to_markdown();
+        assert!(markdown.starts_with("# Resume changes\n\nEdited basics (headline)"));
+        assert!(markdown.contains("## Basics\n\n- Headline: **C++ \\*and\\* Rust**\n"));
+        assert!(markdown.contains("## Skills\n\n- Removed: ~~Rust~~\n"));
+    }
+
+    #[test]
+    fn html_is_a_page_with_escaped_text() {
+        let before = resume();
+        let mut after = before.clone();
+        after.basics.name = "Ada <Byron>".to_string();
+
+        let html = diff_report(&before, &after).render(DiffReportFormat::Html);
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<li>Name: Ada <del>Lovelace</del> <ins>&lt;Byron&gt;</ins></li>"));
+        assert!(html.trim_end().ends_with("</html>"));
+    }
+
+    #[test]
+    fn identical_resumes_report_no_changes() {
+        let report = diff_report(&resume(), &resume());
+        assert!(report.is_empty());
+        assert_eq!(report.to_markdown(), "# Resume changes\n\nNo changes\n");
+    }
+}
@@ -0,0 +1,231 @@
+//! Error codes shared by every Rustume crate and client.
+//!
+//! Each crate keeps its own error enum, and each enum maps its variants to an
+//! [`ErrorCode`]. Codes are part of the public interface: the CLI derives its
+//! exit status from them, the server sends them as `ApiError.code`, and the
+//! WASM bindings set them as the `code` of thrown errors, so clients branch on
+//! the code instead of matching English messages. A code is never renamed or
+//! reused once released; a new kind of failure gets a new code.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use thiserror::Error;
+use utoipa::ToSchema;
+use validator::ValidationErrors;
+
+use crate::{LayoutError, MergeError, TypeScript};
+
+/// Machine-readable kind of failure, serialized as `SCREAMING_SNAKE_CASE`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema, TypeScript)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ErrorCode {
+    /// The request or argument is malformed (bad base64, not JSON, ...).
+    InvalidInput,
+    /// A bug or an unexpected failure; the message has the details.
+    Internal,
+
+    /// The resume failed validation.
+    ValidationFailed,
+    /// A section named in a layout operation is not in the layout.
+    LayoutSectionNotFound,
+    /// The layout lists an unknown section or one section twice.
+    LayoutInvalid,
+    /// A section or item named in a merge does not exist.
+    MergeItemNotFound,
+    /// The items can't be merged.
+    MergeInvalid,
+
+    /// The input could not be read.
+    ParseReadFailed,
+    /// The input is not in the expected format.
+    ParseInvalidFormat,
+    /// The input is not valid JSON for the expected shape.
+    ParseInvalidJson,
+    /// The input was read but could not be converted to a resume.
+    ParseConversionFailed,
+    /// The input is over a size or count limit.
+    ParseLimitExceeded,
+    /// The LinkedIn export is not a readable ZIP archive.
+    ParseLinkedinBadZip,
+
+    /// No template has the requested name.
+    RenderTemplateNotFound,
+    /// The template exists but this deployment doesn't offer it.
+    RenderTemplateNotAllowed,
+    /// Typst or the browser failed to produce the document.
+    RenderFailed,
+    /// The render options are invalid.
+    RenderInvalidConfig,
+    /// The resume is over a render limit.
+    RenderTooLarge,
+    /// The requested render engine is not available.
+    RenderEngineUnavailable,
+    /// The render engine can't produce the requested output.
+    RenderUnsupported,
+
+    /// The resume or application does not exist.
+    StorageNotFound,
+    /// A resource with the same id already exists.
+    StorageAlreadyExists,
+    /// The storage backend failed.
+    StorageInternal,
+}
+
+impl ErrorCode {
+    /// Every code, in declaration order.
+    pub const ALL: [Self; 23] = [
+        Self::InvalidInput,
+        Self::Internal,
+        Self::ValidationFailed,
+        Self::LayoutSectionNotFound,
+        Self::LayoutInvalid,
+        Self::MergeItemNotFound,
+        Self::MergeInvalid,
+        Self::ParseReadFailed,
+        Self::ParseInvalidFormat,
+        Self::ParseInvalidJson,
+        Self::ParseConversionFailed,
+        Self::ParseLimitExceeded,
+        Self::ParseLinkedinBadZip,
+        Self::RenderTemplateNotFound,
+        Self::RenderTemplateNotAllowed,
+        Self::RenderFailed,
+        Self::RenderInvalidConfig,
+        Self::RenderTooLarge,
+        Self::RenderEngineUnavailable,
+        Self::RenderUnsupported,
+        Self::StorageNotFound,
+        Self::StorageAlreadyExists,
+        Self::StorageInternal,
+    ];
+
+    /// The code as clients see it, e.g. `PARSE_LINKEDIN_BAD_ZIP`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::InvalidInput => "INVALID_INPUT",
+            Self::Internal => "INTERNAL",
+            Self::ValidationFailed => "VALIDATION_FAILED",
+            Self::LayoutSectionNotFound => "LAYOUT_SECTION_NOT_FOUND",
+            Self::LayoutInvalid => "LAYOUT_INVALID",
+            Self::MergeItemNotFound => "MERGE_ITEM_NOT_FOUND",
+            Self::MergeInvalid => "MERGE_INVALID",
+            Self::ParseReadFailed => "PARSE_READ_FAILED",
+            Self::ParseInvalidFormat => "PARSE_INVALID_FORMAT",
+            Self::ParseInvalidJson => "PARSE_INVALID_JSON",
+            Self::ParseConversionFailed => "PARSE_CONVERSION_FAILED",
+            Self::ParseLimitExceeded => "PARSE_LIMIT_EXCEEDED",
+            Self::ParseLinkedinBadZip => "PARSE_LINKEDIN_BAD_ZIP",
+            Self::RenderTemplateNotFound => "RENDER_TEMPLATE_NOT_FOUND",
+            Self::RenderTemplateNotAllowed => "RENDER_TEMPLATE_NOT_ALLOWED",
+            Self::RenderFailed => "RENDER_FAILED",
+            Self::RenderInvalidConfig => "RENDER_INVALID_CONFIG",
+            Self::RenderTooLarge => "RENDER_TOO_LARGE",
+            Self::RenderEngineUnavailable => "RENDER_ENGINE_UNAVAILABLE",
+            Self::RenderUnsupported => "RENDER_UNSUPPORTED",
+            Self::StorageNotFound => "STORAGE_NOT_FOUND",
+            Self::StorageAlreadyExists => "STORAGE_ALREADY_EXISTS",
+            Self::StorageInternal => "STORAGE_INTERNAL",
+        }
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// An error from any Rustume crate: its [`ErrorCode`] and message.
+///
+/// Crate errors convert into it with `From`, so a caller that only needs the
+/// code can write `RustumeError::from(err).code`.
+#[derive(Debug, Clone, PartialEq, Eq, Error, Serialize, Deserialize, ToSchema, TypeScript)]
+#[error("{message}")]
+pub struct RustumeError {
+    /// Machine-readable kind of failure.
+    pub code: ErrorCode,
+    /// Description for people, in English; may change between releases.
+    pub message: String,
+}
+
+impl RustumeError {
+    /// Create an error with `code`.
+    pub fn new(code: ErrorCode, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+}
+
+impl LayoutError {
+    /// Stable code for this error.
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Self::SectionNotFound(_) => ErrorCode::LayoutSectionNotFound,
+            Self::UnknownSection(_) | Self::DuplicateSection(_) => ErrorCode::LayoutInvalid,
+        }
+    }
+}
+
+impl MergeError {
+    /// Stable code for this error.
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Self::UnknownSection(_) | Self::ItemNotFound { .. } => ErrorCode::MergeItemNotFound,
+            Self::SameItem | Self::Invalid(_) => ErrorCode::MergeInvalid,
+        }
+    }
+}
+
+impl From<LayoutError> for RustumeError {
+    fn from(err: LayoutError) -> Self {
+        Self::new(err.code(), err.to_string())
+    }
+}
+
+impl From<MergeError> for RustumeError {
+    fn from(err: MergeError) -> Self {
+        Self::new(err.code(), err.to_string())
+    }
+}
+
+impl From<ValidationErrors> for RustumeError {
+    fn from(err: ValidationErrors) -> Self {
+        Self::new(ErrorCode::ValidationFailed, err.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_codes_serialize_as_their_name() {
+        for code in ErrorCode::ALL {
+            let json = serde_json::to_value(code).unwrap();
+            assert_eq!(json, code.as_str(), "{code:?}");
+            assert_eq!(serde_json::from_value::<ErrorCode>(json).unwrap(), code);
+        }
+        assert_eq!(
+            ErrorCode::ParseLinkedinBadZip.to_string(),
+            "PARSE_LINKEDIN_BAD_ZIP"
+        );
+    }
+
+    #[test]
+    fn test_crate_errors_convert_with_code() {
+        let err = RustumeError::from(LayoutError::SectionNotFound("hobbies".to_string()));
+        assert_eq!(err.code, ErrorCode::LayoutSectionNotFound);
+        assert_eq!(err.to_string(), "Section not in layout: hobbies");
+
+        let err = RustumeError::from(MergeError::SameItem);
+        assert_eq!(
+            serde_json::to_value(&err).unwrap(),
+            serde_json::json!({
+                "code": "MERGE_INVALID",
+                "message": "Cannot merge an item into itself",
+            })
+        );
+    }
+}
@@ -0,0 +1,382 @@
+//! Cuts that bring a resume down to a page budget.
+//!
+//! [`fit_steps`] lists every change worth trying, least damaging first:
+//! narrower margins, then a smaller font, then hiding items, oldest first in
+//! the sections that matter least. Counting pages needs a renderer, so the
+//! search for the shortest list of steps that fits lives in
+//! `rustume-render`; this module only proposes and applies them.
+//!
+//! Line height is left alone: PDF templates set their own leading, so it
+//! would change the HTML output without saving a page.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use utoipa::ToSchema;
+
+use crate::dedupe::{item_label, item_sections, text};
+use crate::sections::item_date_range;
+use crate::{ResumeData, TypeScript};
+
+/// Smallest page margin, in points, a fit will set.
+pub const MIN_FIT_MARGIN: u32 = 10;
+
+/// Smallest font size, in points, a fit will set.
+pub const MIN_FIT_FONT_SIZE: u32 = 10;
+
+/// Points the margin narrows by per step.
+const MARGIN_STEP: u32 = 2;
+
+/// Item sections in the order their items are hidden: interests go first,
+/// experience last. Custom sections come right after references; education,
+/// skills, languages and profiles are short and are never cut.
+const CUT_ORDER: &[&str] = &[
+    "interests",
+    "references",
+    "custom",
+    "volunteer",
+    "awards",
+    "publications",
+    "certifications",
+    "projects",
+    "experience",
+];
+
+/// What a [`FitStep`] changes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema, TypeScript)]
+#[serde(rename_all = "snake_case")]
+pub enum FitStepKind {
+    /// Narrow the page margin.
+    TightenMargin,
+    /// Make the font smaller.
+    ShrinkFont,
+    /// Hide one item.
+    HideItem,
+}
+
+/// One change to a resume: set the field at `path` to `value`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema, TypeScript)]
+pub struct FitStep {
+    pub kind: FitStepKind,
+
+    /// Dotted path of the changed field.
+    #[schema(example = "sections.experience.items[4].visible")]
+    pub path: String,
+
+    /// The field's new value.
+    pub value: Value,
+
+    /// The change for people.
+    #[schema(example = "Hide \"Initech, Intern\" from Experience")]
+    pub description: String,
+}
+
+impl FitStep {
+    /// Make this change to `resume`.
+    pub fn apply(&self, resume: &mut ResumeData) -> Result<(), serde_json::Error> {
+        apply_fit_steps(resume, std::slice::from_ref(self))
+    }
+}
+
+/// Result of fitting a resume to a page budget.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema, TypeScript)]
+#[serde(rename_all = "camelCase")]
+pub struct FitReport {
+    /// Page budget asked for.
+    #[schema(example = 1)]
+    pub target_pages: u32,
+
+    /// Pages before any change.
+    #[schema(example = 2)]
+    pub pages_before: u32,
+
+    /// Pages with every step applied.
+    #[schema(example = 1)]
+    pub pages_after: u32,
+
+    /// Whether `pagesAfter` is within the budget. When even every candidate
+    /// cut isn't enough, all of them are applied and this is `false`.
+    pub fits: bool,
+
+    /// Changes made, in order.
+    pub steps: Vec<FitStep>,
+}
+
+/// A resume fitted to a page budget and what changed.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, TypeScript)]
+pub struct FittedResume {
+    pub resume: ResumeData,
+    pub report: FitReport,
+}
+
+/// Every change worth trying to shorten `resume`, least damaging first.
+///
+/// Items whose keywords include one of `keep` (ignoring case) are never
+/// hidden, and each section keeps its newest visible item. Within a section,
+/// undated items go before dated ones, then the oldest by end date; ongoing
+/// items count as the newest.
+pub fn fit_steps(resume: &ResumeData, keep: &[String]) -> Vec<FitStep> {
+    let mut steps = Vec::new();
+
+    let mut margin = resume.metadata.page.margin;
+    while margin > MIN_FIT_MARGIN {
+        margin = margin.saturating_sub(MARGIN_STEP).max(MIN_FIT_MARGIN);
+        steps.push(FitStep {
+            kind: FitStepKind::TightenMargin,
+            path: "metadata.page.margin".to_string(),
+            value: margin.into(),
+            description: format!("Narrow the page margin to {margin}pt"),
+        });
+    }
+
+    let mut size = resume.metadata.typography.font.size;
+    while size > MIN_FIT_FONT_SIZE {
+        size -= 1;
+        steps.push(FitStep {
+            kind: FitStepKind::ShrinkFont,
+            path: "metadata.typography.font.size".to_string(),
+            value: size.into(),
+            description: format!("Reduce the font size to {size}pt"),
+        });
+    }
+
+    steps.extend(hide_item_steps(resume, keep));
+    steps
+}
+
+fn hide_item_steps(resume: &ResumeData, keep: &[String]) -> Vec<FitStep> {
+    let Ok(sections) = serde_json::to_value(&resume.sections) else {
+        return Vec::new();
+    };
+    let keep: Vec<String> = keep.iter().map(|tag| tag.trim().to_lowercase()).collect();
+
+    let mut listed = item_sections(&sections);
+    listed.retain(|(key, _, _)| cut_rank(key).is_some());
+    listed.sort_by_key(|(key, _, _)| cut_rank(key));
+
+    let mut steps = Vec::new();
+    for (key, items, fields) in listed {
+        let section = section_at(&sections, &key);
+        if section
+            .and_then(|s| s.get("visible"))
+            .and_then(Value::as_bool)
+            == Some(false)
+        {
+            continue;
+        }
+        let name = section.map(|s| text(s, "name")).unwrap_or_default();
+        let name = if name.is_empty() { key.as_str() } else { name };
+
+        let mut candidates: Vec<(usize, &Value)> = items
+            .iter()
+            .enumerate()
+            .filter(|(_, item)| item.get("visible").and_then(Value::as_bool) != Some(false))
+            .collect();
+        // Stable, so items of equal age keep resume order
+        candidates.sort_by_key(|(_, item)| age_key(item));
+        candidates.pop();
+
+        for (index, item) in candidates {
+            if is_kept(item, &keep) {
+                continue;
+            }
+            steps.push(FitStep {
+                kind: FitStepKind::HideItem,
+                path: format!("sections.{key}.items[{index}].visible"),
+                value: false.into(),
+                description: format!("Hide \"{}\" from {name}", item_label(item, fields)),
+            });
+        }
+    }
+    steps
+}
+
+/// Position of `key` in [`CUT_ORDER`], or `None` for sections never cut.
+fn cut_rank(key: &str) -> Option<usize> {
+    let base = if key.starts_with("custom.") {
+        "custom"
+    } else {
+        key
+    };
+    CUT_ORDER.iter().position(|name| *name == base)
+}
+
+/// The section object for an [`item_sections`] key.
+fn section_at<'a>(sections: &'a Value, key: &str) -> Option<&'a Value> {
+    match key.strip_prefix("custom.") {
+        Some(id) => sections.get("custom")?.get(id),
+        None => sections.get(key),
+    }
+}
+
+/// Sort key putting undated items first, then by end date with ongoing
+/// items last.
+fn age_key(item: &Value) -> (bool, bool, Option<(i32, u32)>) {
+    let optional = |field| item.get(field).and_then(Value::as_str);
+    let Some(range) = item_date_range(
+        text(item, "date"),
+        optional("startDate"),
+        optional("endDate"),
+    ) else {
+        return (false, false, None);
+    };
+    match range.end {
+        Some(end) => (true, false, Some((end.year, end.month.unwrap_or(12)))),
+        None => (true, true, None),
+    }
+}
+
+fn is_kept(item: &Value, keep: &[String]) -> bool {
+    let Some(keywords) = item.get("keywords").and_then(Value::as_array) else {
+        return false;
+    };
+    keywords
+        .iter()
+        .filter_map(Value::as_str)
+        .any(|keyword| keep.contains(&keyword.trim().to_lowercase()))
+}
+
+/// Make every change in `steps` to `resume`, in order.
+pub fn apply_fit_steps(
+    resume: &mut ResumeData,
+    steps: &[FitStep],
+) -> Result<(), serde_json::Error> {
+    if steps.is_empty() {
+        return Ok(());
+    }
+    let mut value = serde_json::to_value(&*resume)?;
+    for step in steps {
+        let field = value
+            .pointer_mut(&json_pointer(&step.path))
+            .ok_or_else(|| serde::de::Error::custom(format!("No field at {}", step.path)))?;
+        *field = step.value.clone();
+    }
+    *resume = serde_json::from_value(value)?;
+    Ok(())
+}
+
+/// `sections.experience.items[4].visible` as `/sections/experience/items/4/visible`.
+fn json_pointer(path: &str) -> String {
+    let mut pointer = String::with_capacity(path.len() + 1);
+    for part in path.split('.') {
+        for segment in part.split('[') {
+            pointer.push('/');
+            pointer.push_str(segment.trim_end_matches(']'));
+        }
+    }
+    pointer
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Award, Experience, Interest, Project};
+
+    fn paths(steps: &[FitStep], kind: FitStepKind) -> Vec<&str> {
+        steps
+            .iter()
+            .filter(|step| step.kind == kind)
+            .map(|step| step.path.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_fit_steps_shrink_layout_within_bounds() {
+        let mut resume = ResumeData::default();
+        resume.metadata.page.margin = 15;
+        resume.metadata.typography.font.size = 12;
+
+        let steps = fit_steps(&resume, &[]);
+        let margins: Vec<&Value> = steps
+            .iter()
+            .filter(|step| step.kind == FitStepKind::TightenMargin)
+            .map(|step| &step.value)
+            .collect();
+        assert_eq!(margins, [13, 11, 10]);
+        assert_eq!(paths(&steps, FitStepKind::ShrinkFont).len(), 2);
+        assert_eq!(steps.last().unwrap().value, MIN_FIT_FONT_SIZE);
+
+        resume.metadata.page.margin = 8;
+        resume.metadata.typography.font.size = 9;
+        assert!(fit_steps(&resume, &[]).is_empty());
+    }
+
+    #[test]
+    fn test_fit_steps_hide_oldest_items_in_least_important_sections() {
+        let mut resume = ResumeData::default();
+        let experience = &mut resume.sections.experience;
+        experience.add_item(Experience::new("Acme", "Lead").with_dates("2021-03", None));
+        experience.add_item(
+            Experience::new("Initech", "Intern").with_dates("2012-06", Some("2013-01".into())),
+        );
+        experience.add_item(
+            Experience::new("Globex", "Engineer").with_dates("2015-01", Some("2021-02".into())),
+        );
+        resume.sections.awards.add_item(Award::new("Best Talk"));
+        resume.sections.interests.visible = true;
+        resume.sections.interests.add_item(Interest::new("Chess"));
+        resume
+            .sections
+            .interests
+            .add_item(Interest::new("Climbing"));
+        let mut hidden = Interest::new("Go");
+        hidden.visible = false;
+        resume.sections.interests.add_item(hidden);
+
+        let steps = fit_steps(&resume, &[]);
+        assert_eq!(
+            paths(&steps, FitStepKind::HideItem),
+            [
+                "sections.interests.items[0].visible",
+                "sections.experience.items[1].visible",
+                "sections.experience.items[2].visible",
+            ]
+        );
+        let hide = steps
+            .iter()
+            .find(|s| s.kind == FitStepKind::HideItem)
+            .unwrap();
+        assert_eq!(hide.description, "Hide \"Chess\" from Interests");
+    }
+
+    #[test]
+    fn test_fit_steps_keep_tagged_items() {
+        let mut resume = ResumeData::default();
+        let mut flagship = Project::new("Compiler");
+        flagship.keywords = vec!["Core".to_string()];
+        resume.sections.projects.add_item(flagship);
+        resume.sections.projects.add_item(Project::new("Blog"));
+        resume.sections.projects.add_item(Project::new("Dotfiles"));
+
+        let steps = fit_steps(&resume, &["core".to_string()]);
+        assert_eq!(
+            paths(&steps, FitStepKind::HideItem),
+            ["sections.projects.items[1].visible"]
+        );
+    }
+
+    #[test]
+    fn test_apply_fit_steps() {
+        let mut resume = ResumeData::default();
+        resume.sections.interests.visible = true;
+        resume.sections.interests.add_item(Interest::new("Chess"));
+        resume
+            .sections
+            .interests
+            .add_item(Interest::new("Climbing"));
+        let steps = fit_steps(&resume, &[]);
+
+        apply_fit_steps(&mut resume, &steps).unwrap();
+        assert_eq!(resume.metadata.page.margin, MIN_FIT_MARGIN);
+        assert_eq!(resume.metadata.typography.font.size, MIN_FIT_FONT_SIZE);
+        assert!(!resume.sections.interests.items[0].visible);
+        assert!(resume.sections.interests.items[1].visible);
+
+        let missing = FitStep {
+            kind: FitStepKind::HideItem,
+            path: "sections.interests.items[9].visible".to_string(),
+            value: false.into(),
+            description: String::new(),
+        };
+        assert!(missing.apply(&mut resume).is_err());
+    }
+}
@@ -0,0 +1,319 @@
+//! Employment gap detection from item dates.
+//!
+//! Experience, education, and volunteer entries all count as time
+//! accounted for, so a year of study between two jobs is not a gap. Dates
+//! come from each item's structured dates when present and its display date
+//! otherwise; a year-only end runs to December of that year, so "2018 -
+//! 2020" followed by "2021 - Present" leaves no gap. Only gaps between
+//! entries are reported: the time since the last role ended may simply mean
+//! the resume is out of date.
+
+use rustume_utils::{format_duration_in, DateRange, Locale, PartialDate};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::sections::item_date_range;
+use crate::{ResumeData, Severity, TypeScript, ValidationIssue};
+
+/// Shortest gap, in months, reported by default.
+pub const DEFAULT_GAP_MONTHS: u32 = 6;
+
+/// Options for [`employment_gaps`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema, TypeScript)]
+#[serde(rename_all = "camelCase", default)]
+pub struct GapOptions {
+    /// Shortest gap to report, in whole months. Defaults to 6.
+    #[schema(example = 6)]
+    pub min_months: u32,
+}
+
+impl Default for GapOptions {
+    fn default() -> Self {
+        Self {
+            min_months: DEFAULT_GAP_MONTHS,
+        }
+    }
+}
+
+impl GapOptions {
+    /// Builder method to set the shortest gap reported.
+    pub fn with_min_months(mut self, min_months: u32) -> Self {
+        self.min_months = min_months;
+        self
+    }
+}
+
+/// A stretch of time no entry on the resume accounts for.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema, TypeScript)]
+#[serde(rename_all = "camelCase")]
+pub struct EmploymentGap {
+    /// First month of the gap, `YYYY-MM`.
+    #[schema(example = "2019-04")]
+    pub start: String,
+
+    /// Last month of the gap, `YYYY-MM`.
+    #[schema(example = "2019-12")]
+    pub end: String,
+
+    /// Length of the gap in whole months.
+    #[schema(example = 9)]
+    pub months: u32,
+
+    /// The same length for display.
+    #[schema(example = "9 mos")]
+    pub duration: String,
+
+    /// Path of the entry that ended last before the gap.
+    #[schema(example = "sections.experience.items[1]")]
+    pub after: String,
+
+    /// Path of the first entry after the gap.
+    #[schema(example = "sections.experience.items[0]")]
+    pub before: String,
+
+    /// What could be added to account for the gap.
+    pub suggestion: String,
+}
+
+/// A dated entry: its path, a name for messages, and its month span.
+struct Entry<'a> {
+    path: String,
+    label: &'a str,
+    start: i64,
+    end: i64,
+}
+
+/// Find gaps of at least `options.min_months` between the visible
+/// experience, education, and volunteer entries of `resume`, oldest first.
+///
+/// Ongoing entries run to `today`. Entries without a readable date are left
+/// out.
+pub fn employment_gaps(
+    resume: &ResumeData,
+    options: &GapOptions,
+    today: &PartialDate,
+) -> Vec<EmploymentGap> {
+    let locale = Locale::for_tag(&resume.metadata.locale);
+    let mut entries = dated_entries(resume, today);
+    entries.sort_by_key(|entry| (entry.start, entry.end));
+
+    let mut gaps = Vec::new();
+    let mut last: Option<&Entry> = None;
+    for entry in &entries {
+        let Some(previous) = last else {
+            last = Some(entry);
+            continue;
+        };
+        let months = entry.start - previous.end;
+        if months > 0 && months >= i64::from(options.min_months) {
+            let months = u32::try_from(months).unwrap_or(u32::MAX);
+            let (start, end) = (month(previous.end), month(entry.start - 1));
+            gaps.push(EmploymentGap {
+                suggestion: format!(
+                    "Add an entry covering {start} to {end}, such as study, volunteering, \
+                     freelance work, or a career break"
+                ),
+                start,
+                end,
+                months,
+                duration: format_duration_in(months, locale),
+                after: previous.path.clone(),
+                before: entry.path.clone(),
+            });
+        }
+        if entry.end > previous.end {
+            last = Some(entry);
+        }
+    }
+    gaps
+}
+
+/// Report gaps of at least [`DEFAULT_GAP_MONTHS`] between entries, on the
+/// date of the entry after each gap.
+///
+/// See [`employment_gaps`] for what counts as a gap. Gaps are often
+/// explained in an interview rather than on paper, hence a warning.
+pub fn employment_gap_warnings(resume: &ResumeData) -> Vec<ValidationIssue> {
+    let today = PartialDate::today();
+    let entries = dated_entries(resume, &today);
+    employment_gaps(resume, &GapOptions::default(), &today)
+        .into_iter()
+        .map(|gap| {
+            let name = |path: &str| {
+                entries
+                    .iter()
+                    .find(|entry| entry.path == path)
+                    .map(|entry| entry.label)
+                    .filter(|label| !label.is_empty())
+                    .unwrap_or("another entry")
+                    .to_string()
+            };
+            let mut issue = ValidationIssue::new(
+                Severity::Warning,
+                format!("{}.date", gap.before),
+                "employment_gap",
+                format!(
+                    "{} gap after {} ({} to {})",
+                    gap.duration,
+                    name(&gap.after),
+                    gap.start,
+                    gap.end
+                ),
+            );
+            issue.suggestion = Some(gap.suggestion);
+            issue
+        })
+        .collect()
+}
+
+fn dated_entries<'a>(resume: &'a ResumeData, today: &PartialDate) -> Vec<Entry<'a>> {
+    let sections = &resume.sections;
+    let experience = sections
+        .experience
+        .items
+        .iter()
+        .enumerate()
+        .map(|(i, item)| {
+            (
+                format!("sections.experience.items[{i}]"),
+                item.company.as_str(),
+                item.visible.then(|| item.date_range()).flatten(),
+            )
+        });
+    let education = sections
+        .education
+        .items
+        .iter()
+        .enumerate()
+        .map(|(i, item)| {
+            (
+                format!("sections.education.items[{i}]"),
+                item.institution.as_str(),
+                item.visible.then(|| item.date_range()).flatten(),
+            )
+        });
+    let volunteer = sections
+        .volunteer
+        .items
+        .iter()
+        .enumerate()
+        .map(|(i, item)| {
+            (
+                format!("sections.volunteer.items[{i}]"),
+                item.organization.as_str(),
+                item.visible
+                    .then(|| item_date_range(&item.date, None, None))
+                    .flatten(),
+            )
+        });
+
+    experience
+        .chain(education)
+        .chain(volunteer)
+        .filter_map(|(path, label, range): (String, &str, Option<DateRange>)| {
+            let range = range?;
+            let (start, mut end) = range.month_span(today);
+            // A year-only end may mean any month of that year
+            if range.end.is_some_and(|end| end.month.is_none()) {
+                end += 12;
+            }
+            (start < end).then_some(Entry {
+                path,
+                label,
+                start,
+                end,
+            })
+        })
+        .collect()
+}
+
+/// Format a month index (`year * 12 + month - 1`) as `YYYY-MM`.
+fn month(index: i64) -> String {
+    let date = PartialDate {
+        year: i32::try_from(index.div_euclid(12)).unwrap_or(i32::MAX),
+        month: u32::try_from(index.rem_euclid(12) + 1).ok(),
+        day: None,
+    };
+    date.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Education, Experience};
+
+    fn today() -> PartialDate {
+        PartialDate {
+            year: 2024,
+            month: Some(6),
+            day: Some(1),
+        }
+    }
+
+    #[test]
+    fn test_gaps_between_positions() {
+        let mut resume = ResumeData::default();
+        let experience = &mut resume.sections.experience;
+        experience.add_item(Experience::new("Globex", "Lead").with_dates("2020-01", None));
+        experience.add_item(Experience::new("Acme", "Engineer").with_date("Jan 2016 - Mar 2019"));
+        experience.add_item(Experience::new("Initech", "Intern").with_date("Jun 2015 - Aug 2015"));
+
+        let gaps = employment_gaps(&resume, &GapOptions::default(), &today());
+        assert_eq!(gaps.len(), 1);
+        let gap = &gaps[0];
+        assert_eq!(
+            (gap.start.as_str(), gap.end.as_str()),
+            ("2019-04", "2019-12")
+        );
+        assert_eq!(gap.months, 9);
+        assert_eq!(gap.duration, "9 mos");
+        assert_eq!(gap.after, "sections.experience.items[1]");
+        assert_eq!(gap.before, "sections.experience.items[0]");
+
+        let all = employment_gaps(&resume, &GapOptions::default().with_min_months(1), &today());
+        assert_eq!(all.len(), 2);
+        assert_eq!((all[0].start.as_str(), all[0].months), ("2015-09", 4));
+    }
+
+    #[test]
+    fn test_study_and_overlaps_close_gaps() {
+        let mut resume = ResumeData::default();
+        let experience = &mut resume.sections.experience;
+        experience.add_item(
+            Experience::new("Acme", "Engineer").with_dates("2015-01", Some("2017-12".into())),
+        );
+        experience.add_item(
+            Experience::new("Side gig", "Founder").with_dates("2016-01", Some("2016-06".into())),
+        );
+        experience.add_item(Experience::new("Globex", "Lead").with_dates("2020-01", None));
+        resume.sections.education.add_item(
+            Education::new("State University", "MSc").with_dates("2018-01", Some("2019-12".into())),
+        );
+
+        assert!(employment_gaps(&resume, &GapOptions::default(), &today()).is_empty());
+
+        resume.sections.education.items[0].visible = false;
+        let gaps = employment_gaps(&resume, &GapOptions::default(), &today());
+        assert_eq!(gaps.len(), 1);
+        assert_eq!(gaps[0].after, "sections.experience.items[0]");
+        assert_eq!(gaps[0].months, 24);
+    }
+
+    #[test]
+    fn test_gap_warning_names_previous_entry() {
+        let mut resume = ResumeData::default();
+        let experience = &mut resume.sections.experience;
+        experience.add_item(Experience::new("Globex", "Lead").with_date("2021 - 2022"));
+        experience.add_item(Experience::new("Acme", "Engineer").with_date("2018 - 2019"));
+
+        let warnings = employment_gap_warnings(&resume);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].path, "sections.experience.items[0].date");
+        assert_eq!(warnings[0].code, "employment_gap");
+        assert_eq!(
+            warnings[0].message,
+            "1 yr gap after Acme (2020-01 to 2020-12)"
+        );
+        assert!(warnings[0].suggestion.is_some());
+    }
+}
@@ -0,0 +1,513 @@
+//! Keyword density: which terms a resume leans on, which it overuses, and
+//! how well it covers the keywords a target role expects.
+//!
+//! Text is split into lowercase words, keeping `+` and `#` so `C++` and `C#`
+//! survive. Phrases such as "team player" or "machine learning" are matched
+//! as runs of words, so punctuation and case don't matter: `results-driven`
+//! matches "Results driven". Hidden sections and items are left out, as are
+//! IDs, dates, links, locations, and profiles.
+
+use std::collections::HashMap;
+
+use rustume_utils::html_text_blocks;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use utoipa::ToSchema;
+
+use crate::dedupe::item_sections;
+use crate::{ResumeData, TypeScript};
+
+/// Buzzwords flagged when no list is given: phrases that claim a quality
+/// instead of showing it.
+pub const DEFAULT_BUZZWORDS: &[&str] = &[
+    "best of breed",
+    "cutting-edge",
+    "detail-oriented",
+    "dynamic",
+    "game changer",
+    "go-getter",
+    "guru",
+    "hard worker",
+    "hardworking",
+    "innovative",
+    "motivated",
+    "ninja",
+    "passionate",
+    "proactive",
+    "results-driven",
+    "results-oriented",
+    "rockstar",
+    "seasoned",
+    "self-starter",
+    "strategic thinker",
+    "synergy",
+    "team player",
+    "think outside the box",
+    "thought leader",
+    "value add",
+    "win-win",
+    "world-class",
+];
+
+/// A term used more than this many times is reported as overused.
+pub const DEFAULT_MAX_REPEATS: u32 = 5;
+
+/// Terms listed in the overall ranking.
+const TOP_KEYWORDS: usize = 20;
+
+/// Terms listed per section.
+const TOP_SECTION_KEYWORDS: usize = 5;
+
+/// Sections whose text isn't prose worth counting.
+const SKIPPED_SECTIONS: &[&str] = &["profiles"];
+
+/// Item fields that never hold keywords.
+const SKIPPED_FIELDS: &[&str] = &["id", "date", "startDate", "endDate", "url", "location"];
+
+/// Common English words that are never keywords.
+const STOP_WORDS: &[&str] = &[
+    "a", "about", "across", "after", "all", "also", "an", "and", "any", "are", "as", "at", "be",
+    "been", "being", "between", "both", "but", "by", "can", "did", "do", "does", "during", "each",
+    "etc", "for", "from", "had", "has", "have", "he", "her", "his", "how", "i", "in", "into", "is",
+    "it", "its", "me", "more", "most", "my", "new", "no", "not", "of", "on", "one", "or", "other",
+    "our", "out", "over", "per", "she", "so", "such", "than", "that", "the", "their", "them",
+    "then", "there", "these", "they", "this", "those", "through", "to", "under", "up", "us",
+    "using", "via", "was", "we", "were", "what", "when", "where", "which", "while", "who", "will",
+    "with", "within", "would", "you", "your",
+];
+
+/// What [`keyword_report`] flags and compares against.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema, TypeScript)]
+#[serde(rename_all = "camelCase", default)]
+pub struct KeywordOptions {
+    /// Keywords the target role expects; words or phrases.
+    #[schema(example = json!(["rust", "kubernetes", "distributed systems"]))]
+    pub target: Vec<String>,
+
+    /// Words and phrases to flag as empty buzzwords.
+    pub buzzwords: Vec<String>,
+
+    /// Terms used more than this many times are reported as overused.
+    #[schema(example = 5)]
+    pub max_repeats: u32,
+}
+
+impl Default for KeywordOptions {
+    fn default() -> Self {
+        Self {
+            target: Vec::new(),
+            buzzwords: DEFAULT_BUZZWORDS.iter().map(|b| b.to_string()).collect(),
+            max_repeats: DEFAULT_MAX_REPEATS,
+        }
+    }
+}
+
+impl KeywordOptions {
+    /// Compare against these target role keywords.
+    pub fn with_target(mut self, target: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.target = target.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Flag these buzzwords instead of [`DEFAULT_BUZZWORDS`].
+    pub fn with_buzzwords(
+        mut self,
+        buzzwords: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        self.buzzwords = buzzwords.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Report terms used more than `max_repeats` times.
+    pub fn with_max_repeats(mut self, max_repeats: u32) -> Self {
+        self.max_repeats = max_repeats;
+        self
+    }
+}
+
+/// How often a term appears.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema, TypeScript)]
+pub struct KeywordCount {
+    /// The term, lowercased; phrases as given.
+    #[schema(example = "kubernetes")]
+    pub term: String,
+
+    #[schema(example = 4)]
+    pub count: u32,
+
+    /// Share of all counted words, in percent to one decimal place.
+    #[schema(example = 1.6)]
+    pub density: f64,
+}
+
+/// Target role keywords found and missing.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, ToSchema, TypeScript)]
+pub struct TargetCoverage {
+    /// Target keywords the resume uses, with counts.
+    pub matched: Vec<KeywordCount>,
+
+    /// Target keywords the resume never uses.
+    #[schema(example = json!(["distributed systems"]))]
+    pub missing: Vec<String>,
+
+    /// Share of target keywords matched, from 0 to 1.
+    #[schema(example = 0.67)]
+    pub coverage: f64,
+}
+
+/// Keyword counts for one section.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema, TypeScript)]
+#[serde(rename_all = "camelCase")]
+pub struct SectionKeywords {
+    /// Section key: `basics`, `summary`, `experience`, `custom.<id>`, ...
+    #[schema(example = "experience")]
+    pub section: String,
+
+    /// Words counted in the section.
+    pub total_words: u32,
+
+    /// Most used terms, most frequent first.
+    pub keywords: Vec<KeywordCount>,
+
+    /// Buzzwords used in the section.
+    pub buzzwords: Vec<KeywordCount>,
+
+    /// Target keywords used in the section.
+    pub target_matches: Vec<KeywordCount>,
+}
+
+/// Keyword frequency, overuse, buzzwords, and target coverage.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema, TypeScript)]
+#[serde(rename_all = "camelCase")]
+pub struct KeywordReport {
+    /// Words counted across the resume, stop words excluded.
+    #[schema(example = 412)]
+    pub total_words: u32,
+
+    /// Most used terms, most frequent first.
+    pub keywords: Vec<KeywordCount>,
+
+    /// Terms used more than [`KeywordOptions::max_repeats`] times.
+    pub overused: Vec<KeywordCount>,
+
+    /// Buzzwords used, most frequent first.
+    pub buzzwords: Vec<KeywordCount>,
+
+    /// Coverage of [`KeywordOptions::target`]; absent when no target was given.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target: Option<TargetCoverage>,
+
+    /// The same counts per section, in resume order; sections without text
+    /// are left out.
+    pub sections: Vec<SectionKeywords>,
+}
+
+/// Count keywords in `resume` and compare them with `options`.
+pub fn keyword_report(resume: &ResumeData, options: &KeywordOptions) -> KeywordReport {
+    let buzzwords = phrases(&options.buzzwords);
+    let target = phrases(&options.target);
+
+    let sections: Vec<(String, Vec<String>)> = section_texts(resume)
+        .into_iter()
+        .map(|(key, text)| (key, words(&text)))
+        .filter(|(_, words)| !words.is_empty())
+        .collect();
+    let all: Vec<String> = sections
+        .iter()
+        .flat_map(|(_, words)| words.iter().cloned())
+        .collect();
+
+    let counts = Counts::new(&all);
+    let mut overused: Vec<KeywordCount> = counts
+        .ranked()
+        .into_iter()
+        .filter(|keyword| keyword.count > options.max_repeats)
+        .collect();
+    overused.truncate(TOP_KEYWORDS);
+
+    let target_coverage = (!target.is_empty()).then(|| {
+        let matched = counts.phrases(&target);
+        let missing: Vec<String> = target
+            .iter()
+            .filter(|(phrase, _)| !matched.iter().any(|m| &m.term == phrase))
+            .map(|(phrase, _)| phrase.clone())
+            .collect();
+        TargetCoverage {
+            coverage: round_to(matched.len() as f64 / target.len() as f64, 2),
+            matched,
+            missing,
+        }
+    });
+
+    KeywordReport {
+        total_words: counts.total,
+        keywords: counts.top(TOP_KEYWORDS),
+        overused,
+        buzzwords: counts.phrases(&buzzwords),
+        target: target_coverage,
+        sections: sections
+            .iter()
+            .map(|(section, words)| {
+                let counts = Counts::new(words);
+                SectionKeywords {
+                    section: section.clone(),
+                    total_words: counts.total,
+                    keywords: counts.top(TOP_SECTION_KEYWORDS),
+                    buzzwords: counts.phrases(&buzzwords),
+                    target_matches: counts.phrases(&target),
+                }
+            })
+            .collect(),
+    }
+}
+
+/// Word counts for a run of text.
+struct Counts<'a> {
+    words: &'a [String],
+    /// Words that can be keywords: no stop words, at least one letter.
+    total: u32,
+    terms: HashMap<&'a str, u32>,
+}
+
+impl<'a> Counts<'a> {
+    fn new(words: &'a [String]) -> Self {
+        let mut terms: HashMap<&str, u32> = HashMap::new();
+        let mut total = 0;
+        for word in words {
+            if is_term(word) {
+                total += 1;
+                *terms.entry(word.as_str()).or_default() += 1;
+            }
+        }
+        Self {
+            words,
+            total,
+            terms,
+        }
+    }
+
+    fn count(&self, term: &str, count: u32) -> KeywordCount {
+        let density = if self.total == 0 {
+            0.0
+        } else {
+            round_to(f64::from(count) * 100.0 / f64::from(self.total), 1)
+        };
+        KeywordCount {
+            term: term.to_string(),
+            count,
+            density,
+        }
+    }
+
+    /// Every term, most frequent first, ties alphabetically.
+    fn ranked(&self) -> Vec<KeywordCount> {
+        let mut terms: Vec<(&str, u32)> = self.terms.iter().map(|(t, c)| (*t, *c)).collect();
+        terms.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+        terms
+            .into_iter()
+            .map(|(term, count)| self.count(term, count))
+            .collect()
+    }
+
+    fn top(&self, limit: usize) -> Vec<KeywordCount> {
+        let mut ranked = self.ranked();
+        ranked.truncate(limit);
+        ranked
+    }
+
+    /// Counts of the `phrases` that occur, most frequent first.
+    fn phrases(&self, phrases: &[(String, Vec<String>)]) -> Vec<KeywordCount> {
+        let mut found: Vec<KeywordCount> = phrases
+            .iter()
+            .filter_map(|(phrase, words)| {
+                let count = self
+                    .words
+                    .windows(words.len())
+                    .filter(|window| window == words)
+                    .count();
+                let count = u32::try_from(count).unwrap_or(u32::MAX);
+                (count > 0).then(|| self.count(phrase, count))
+            })
+            .collect();
+        found.sort_by_key(|keyword| std::cmp::Reverse(keyword.count));
+        found
+    }
+}
+
+/// Each phrase lowercased, with its words; blank and repeated phrases dropped.
+fn phrases(list: &[String]) -> Vec<(String, Vec<String>)> {
+    let mut result: Vec<(String, Vec<String>)> = Vec::new();
+    for phrase in list {
+        let words = words(phrase);
+        if !words.is_empty() && !result.iter().any(|(_, seen)| *seen == words) {
+            result.push((phrase.trim().to_lowercase(), words));
+        }
+    }
+    result
+}
+
+/// Lowercase words of `text`, keeping `+` and `#` (`c++`, `c#`).
+fn words(text: &str) -> Vec<String> {
+    text.split(|c: char| !(c.is_alphanumeric() || c == '+' || c == '#'))
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+fn is_term(word: &str) -> bool {
+    word.chars().any(char::is_alphabetic) && !STOP_WORDS.contains(&word)
+}
+
+fn round_to(value: f64, places: i32) -> f64 {
+    let scale = 10_f64.powi(places);
+    (value * scale).round() / scale
+}
+
+/// Plain text of each visible section, in resume order.
+fn section_texts(resume: &ResumeData) -> Vec<(String, String)> {
+    let mut texts = vec![("basics".to_string(), resume.basics.headline.clone())];
+    let summary = &resume.sections.summary;
+    if summary.visible {
+        texts.push((
+            "summary".to_string(),
+            html_text_blocks(&summary.content).join("\n"),
+        ));
+    }
+
+    let Ok(sections) = serde_json::to_value(&resume.sections) else {
+        return texts;
+    };
+    for (key, items, _) in item_sections(&sections) {
+        if SKIPPED_SECTIONS.contains(&key.as_str()) || !section_visible(&sections, &key) {
+            continue;
+        }
+        let mut text = Vec::new();
+        for item in items {
+            if item.get("visible").and_then(Value::as_bool) != Some(false) {
+                collect_text(item, &mut text);
+            }
+        }
+        texts.push((key, text.join("\n")));
+    }
+    texts
+}
+
+fn section_visible(sections: &Value, key: &str) -> bool {
+    let section = match key.strip_prefix("custom.") {
+        Some(id) => sections.get("custom").and_then(|custom| custom.get(id)),
+        None => sections.get(key),
+    };
+    section
+        .and_then(|s| s.get("visible"))
+        .and_then(Value::as_bool)
+        != Some(false)
+}
+
+/// Append the text of every string in `value`, skipping [`SKIPPED_FIELDS`].
+fn collect_text(value: &Value, out: &mut Vec<String>) {
+    match value {
+        Value::String(text) => out.extend(html_text_blocks(text)),
+        Value::Array(values) => values.iter().for_each(|value| collect_text(value, out)),
+        Value::Object(fields) => {
+            for (key, value) in fields {
+                if !SKIPPED_FIELDS.contains(&key.as_str()) {
+                    collect_text(value, out);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Experience, Profile, Skill};
+
+    fn resume() -> ResumeData {
+        let mut resume = ResumeData::default();
+        resume.basics.headline = "Passionate Rust engineer".to_string();
+        resume.sections.summary.content =
+            "<p>Results-driven team player building Rust services.</p>".to_string();
+        resume.sections.experience.add_item(
+            Experience::new("Acme", "Engineer")
+                .with_location("Rustville")
+                .with_summary(
+                    "<ul><li>Built Rust and C++ services on Kubernetes</li>\
+                     <li>Moved the billing service to Rust</li></ul>",
+                ),
+        );
+        let mut hidden = Experience::new("Initech", "Engineer");
+        hidden.summary = "Synergy synergy synergy".to_string();
+        hidden.visible = false;
+        resume.sections.experience.add_item(hidden);
+        resume.sections.skills.add_item(Skill::new("Rust"));
+        resume
+            .sections
+            .profiles
+            .add_item(Profile::new("GitHub", "rustacean"));
+        resume
+    }
+
+    fn terms(counts: &[KeywordCount]) -> Vec<(&str, u32)> {
+        counts.iter().map(|k| (k.term.as_str(), k.count)).collect()
+    }
+
+    #[test]
+    fn test_keyword_frequency_skips_hidden_items_and_stop_words() {
+        let report = keyword_report(&resume(), &KeywordOptions::default());
+        assert_eq!(report.keywords[0].term, "rust");
+        assert_eq!(report.keywords[0].count, 5);
+        assert_eq!(
+            report.keywords[0].density,
+            round_to(500.0 / f64::from(report.total_words), 1)
+        );
+        assert!(report.keywords.iter().any(|k| k.term == "c++"));
+        for skipped in ["the", "synergy", "rustville", "rustacean"] {
+            assert!(
+                report.keywords.iter().all(|k| k.term != skipped),
+                "{skipped} was counted"
+            );
+        }
+    }
+
+    #[test]
+    fn test_overused_terms_and_buzzwords() {
+        let report = keyword_report(&resume(), &KeywordOptions::default().with_max_repeats(3));
+        assert_eq!(terms(&report.overused), [("rust", 5)]);
+        assert_eq!(
+            terms(&report.buzzwords),
+            [("passionate", 1), ("results-driven", 1), ("team player", 1)]
+        );
+
+        let options = KeywordOptions::default().with_buzzwords(["Billing Service"]);
+        let report = keyword_report(&resume(), &options);
+        assert_eq!(terms(&report.buzzwords), [("billing service", 1)]);
+        assert!(report.overused.is_empty());
+    }
+
+    #[test]
+    fn test_target_coverage_and_sections() {
+        let options = KeywordOptions::default().with_target(["Kubernetes", "rust", "Terraform"]);
+        let report = keyword_report(&resume(), &options);
+
+        let target = report.target.unwrap();
+        assert_eq!(terms(&target.matched), [("rust", 5), ("kubernetes", 1)]);
+        assert_eq!(target.missing, ["terraform"]);
+        assert_eq!(target.coverage, 0.67);
+
+        let sections: Vec<&str> = report.sections.iter().map(|s| s.section.as_str()).collect();
+        assert_eq!(sections, ["basics", "summary", "experience", "skills"]);
+        let experience = &report.sections[2];
+        assert_eq!(
+            terms(&experience.target_matches),
+            [("rust", 2), ("kubernetes", 1)]
+        );
+        assert!(experience.buzzwords.is_empty());
+        assert_eq!(terms(&report.sections[0].buzzwords), [("passionate", 1)]);
+
+        assert!(keyword_report(&resume(), &KeywordOptions::default())
+            .target
+            .is_none());
+    }
+}
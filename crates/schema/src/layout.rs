@@ -0,0 +1,389 @@
+//! Section layout - which sections go on which page and column, in order.
+
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use utoipa::ToSchema;
+
+pub use rustume_utils::LayoutLocator;
+use rustume_utils::{find_item_in_layout, move_item_in_layout, remove_item_in_layout};
+
+use crate::Sections;
+use crate::TypeScript;
+
+/// Keys of the built-in sections, as they appear in resume JSON and layouts.
+pub const BUILT_IN_SECTIONS: &[&str] = &[
+    "summary",
+    "coverLetter",
+    "experience",
+    "education",
+    "skills",
+    "projects",
+    "profiles",
+    "awards",
+    "certifications",
+    "publications",
+    "languages",
+    "interests",
+    "volunteer",
+    "references",
+];
+
+/// Layout slot that renders every custom section, in stored order.
+pub const CUSTOM_SECTIONS_SLOT: &str = "custom";
+
+/// Section order as pages → columns → section keys.
+///
+/// Keys are built-in section keys, custom section IDs, or the
+/// [`CUSTOM_SECTIONS_SLOT`]. Serializes as the nested arrays templates read
+/// from `metadata.layout`. An empty layout leaves the order to the template.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema, TypeScript)]
+#[serde(transparent)]
+pub struct Layout(Vec<Vec<Vec<String>>>);
+
+/// A layout entry that can't be rendered as written, or a missing section.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum LayoutError {
+    #[error("Unknown section in layout: {0}")]
+    UnknownSection(String),
+
+    #[error("Section appears more than once in layout: {0}")]
+    DuplicateSection(String),
+
+    #[error("Section not in layout: {0}")]
+    SectionNotFound(String),
+}
+
+impl Default for Layout {
+    fn default() -> Self {
+        let column = |keys: &[&str]| keys.iter().map(|key| key.to_string()).collect();
+        Self(vec![vec![
+            column(&[
+                // Rendered as a dedicated page before the resume body; listed
+                // first because its column position does not affect placement.
+                "coverLetter",
+                "profiles",
+                "summary",
+                "experience",
+                "education",
+                "projects",
+                "volunteer",
+                "references",
+            ]),
+            column(&[
+                "skills",
+                "interests",
+                "certifications",
+                "awards",
+                "publications",
+                "languages",
+            ]),
+        ]])
+    }
+}
+
+impl From<Vec<Vec<Vec<String>>>> for Layout {
+    fn from(pages: Vec<Vec<Vec<String>>>) -> Self {
+        Self(pages)
+    }
+}
+
+impl From<Layout> for Vec<Vec<Vec<String>>> {
+    fn from(layout: Layout) -> Self {
+        layout.0
+    }
+}
+
+impl Sections {
+    /// Every key a layout can place: the built-in sections, then custom
+    /// section IDs in sorted order.
+    pub fn section_keys(&self) -> Vec<&str> {
+        let mut custom: Vec<&str> = self.custom.keys().map(String::as_str).collect();
+        custom.sort_unstable();
+        BUILT_IN_SECTIONS.iter().copied().chain(custom).collect()
+    }
+}
+
+impl Layout {
+    /// A layout with no pages, which leaves the order to the template.
+    pub fn empty() -> Self {
+        Self(Vec::new())
+    }
+
+    /// Pages, each a list of columns of section keys.
+    pub fn pages(&self) -> &[Vec<Vec<String>>] {
+        &self.0
+    }
+
+    /// Whether no section is placed.
+    pub fn is_empty(&self) -> bool {
+        self.sections().next().is_none()
+    }
+
+    /// Every placed key with its position, page by page, column by column.
+    pub fn sections(&self) -> impl Iterator<Item = (LayoutLocator, &str)> {
+        self.0.iter().enumerate().flat_map(|(page, columns)| {
+            columns.iter().enumerate().flat_map(move |(column, keys)| {
+                keys.iter().enumerate().map(move |(section, key)| {
+                    (
+                        LayoutLocator {
+                            page,
+                            column,
+                            section,
+                        },
+                        key.as_str(),
+                    )
+                })
+            })
+        })
+    }
+
+    /// Position of the first occurrence of `key`.
+    pub fn find(&self, key: &str) -> Option<LayoutLocator> {
+        find_item_in_layout(key, &self.0)
+    }
+
+    /// Move `key` to `target`, creating the page and column if needed.
+    ///
+    /// `target.section` is the index the section should end up at; indexes
+    /// past the end of the column append.
+    pub fn move_section(&mut self, key: &str, target: LayoutLocator) -> Result<(), LayoutError> {
+        let current = self
+            .find(key)
+            .ok_or_else(|| LayoutError::SectionNotFound(key.to_string()))?;
+        // The shared helper expects the index before removal when moving
+        // down within a column
+        let target = if current.page == target.page
+            && current.column == target.column
+            && current.section < target.section
+        {
+            LayoutLocator {
+                section: target.section + 1,
+                ..target
+            }
+        } else {
+            target
+        };
+        self.0 = move_item_in_layout(current, target, &self.0);
+        Ok(())
+    }
+
+    /// Remove the first occurrence of `key`, returning where it was.
+    pub fn remove_section(&mut self, key: &str) -> Option<LayoutLocator> {
+        remove_item_in_layout(key, &mut self.0)
+    }
+
+    /// Unknown and duplicate keys, in layout order. Each problem key is
+    /// reported once.
+    pub fn check(&self, sections: &Sections) -> Vec<LayoutError> {
+        let known: HashSet<&str> = sections.section_keys().into_iter().collect();
+        let mut seen = HashSet::new();
+        let mut reported = HashSet::new();
+        let mut errors = Vec::new();
+        for (_, key) in self.sections() {
+            let error = if !known.contains(key) && key != CUSTOM_SECTIONS_SLOT {
+                LayoutError::UnknownSection(key.to_string())
+            } else if !seen.insert(key) {
+                LayoutError::DuplicateSection(key.to_string())
+            } else {
+                continue;
+            };
+            if reported.insert(key) {
+                errors.push(error);
+            }
+        }
+        errors
+    }
+
+    /// Append every section of `sections` that isn't placed to the last
+    /// column of the first page, returning the keys added. An empty layout
+    /// starts from the default one.
+    ///
+    /// Custom sections count as placed when the layout has the
+    /// [`CUSTOM_SECTIONS_SLOT`].
+    pub fn ensure_all_sections_present(&mut self, sections: &Sections) -> Vec<String> {
+        if self.is_empty() {
+            *self = Self::default();
+        }
+        let placed: HashSet<&str> = self.sections().map(|(_, key)| key).collect();
+        let has_custom_slot = placed.contains(CUSTOM_SECTIONS_SLOT);
+        let missing: Vec<String> = sections
+            .section_keys()
+            .into_iter()
+            .filter(|key| !placed.contains(key))
+            .filter(|key| !(has_custom_slot && sections.custom.contains_key(*key)))
+            .map(str::to_string)
+            .collect();
+        if missing.is_empty() {
+            return missing;
+        }
+
+        let page = match self.0.first_mut() {
+            Some(page) => page,
+            None => {
+                self.0.push(Vec::new());
+                &mut self.0[0]
+            }
+        };
+        if page.is_empty() {
+            page.push(Vec::new());
+        }
+        if let Some(column) = page.last_mut() {
+            column.extend(missing.iter().cloned());
+        }
+        missing
+    }
+
+    /// Drop unknown keys and every repeat after a key's first occurrence,
+    /// returning what was wrong as [`Layout::check`] reports it.
+    pub fn remove_invalid(&mut self, sections: &Sections) -> Vec<LayoutError> {
+        let errors = self.check(sections);
+        if errors.is_empty() {
+            return errors;
+        }
+        let known: HashSet<&str> = sections.section_keys().into_iter().collect();
+        let mut seen = HashSet::new();
+        for column in self.0.iter_mut().flatten() {
+            column.retain(|key| {
+                (known.contains(key.as_str()) || key == CUSTOM_SECTIONS_SLOT)
+                    && seen.insert(key.clone())
+            });
+        }
+        errors
+    }
+
+    /// Drop unknown and repeated keys with [`Layout::remove_invalid`], then
+    /// add missing sections with [`Layout::ensure_all_sections_present`].
+    /// The result passes [`Layout::check`].
+    pub fn normalize(&mut self, sections: &Sections) {
+        self.remove_invalid(sections);
+        self.ensure_all_sections_present(sections);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CustomItem, Section};
+
+    fn layout(pages: &[&[&[&str]]]) -> Layout {
+        Layout(
+            pages
+                .iter()
+                .map(|columns| {
+                    columns
+                        .iter()
+                        .map(|keys| keys.iter().map(|key| key.to_string()).collect())
+                        .collect()
+                })
+                .collect(),
+        )
+    }
+
+    fn sections_with_custom(id: &str) -> Sections {
+        let mut sections = Sections::default();
+        sections
+            .custom
+            .insert(id.to_string(), Section::<CustomItem>::new(id, "Talks"));
+        sections
+    }
+
+    #[test]
+    fn test_serializes_as_nested_arrays() {
+        let layout = layout(&[&[&["summary"], &["skills"]]]);
+        let json = serde_json::to_value(&layout).unwrap();
+        assert_eq!(json, serde_json::json!([[["summary"], ["skills"]]]));
+        assert_eq!(serde_json::from_value::<Layout>(json).unwrap(), layout);
+    }
+
+    #[test]
+    fn test_default_places_every_built_in_section_once() {
+        let sections = Sections::default();
+        let mut layout = Layout::default();
+        assert!(layout.check(&sections).is_empty());
+        assert!(layout.ensure_all_sections_present(&sections).is_empty());
+    }
+
+    #[test]
+    fn test_check_reports_unknown_and_duplicate_keys() {
+        let sections = sections_with_custom("talks");
+        let layout = layout(&[&[
+            &["summary", "talks", "custom", "hobbies"],
+            &["skills", "summary", "summary"],
+        ]]);
+        assert_eq!(
+            layout.check(&sections),
+            vec![
+                LayoutError::UnknownSection("hobbies".to_string()),
+                LayoutError::DuplicateSection("summary".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_move_section_within_and_across_columns() {
+        let mut layout = layout(&[&[&["summary", "experience", "education"], &["skills"]]]);
+
+        let to_end = LayoutLocator {
+            page: 0,
+            column: 0,
+            section: 2,
+        };
+        layout.move_section("summary", to_end).unwrap();
+        assert_eq!(layout.pages()[0][0], ["experience", "education", "summary"]);
+
+        let to_sidebar_top = LayoutLocator {
+            page: 0,
+            column: 1,
+            section: 0,
+        };
+        layout.move_section("education", to_sidebar_top).unwrap();
+        assert_eq!(layout.pages()[0][0], ["experience", "summary"]);
+        assert_eq!(layout.pages()[0][1], ["education", "skills"]);
+
+        let new_page = LayoutLocator {
+            page: 1,
+            column: 0,
+            section: 0,
+        };
+        layout.move_section("skills", new_page).unwrap();
+        assert_eq!(layout.pages()[1][0], ["skills"]);
+
+        assert_eq!(
+            layout.move_section("awards", new_page),
+            Err(LayoutError::SectionNotFound("awards".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_ensure_all_sections_present_appends_to_last_column() {
+        let sections = sections_with_custom("talks");
+        let mut layout = layout(&[&[&["summary"], &["skills"]]]);
+        let added = layout.ensure_all_sections_present(&sections);
+
+        assert_eq!(added.len(), BUILT_IN_SECTIONS.len() - 2 + 1);
+        assert_eq!(added.last().map(String::as_str), Some("talks"));
+        assert_eq!(layout.pages()[0][0], ["summary"]);
+        assert!(layout.check(&sections).is_empty());
+
+        let mut with_slot = layout.clone();
+        with_slot.remove_section("talks");
+        with_slot.ensure_all_sections_present(&sections);
+        assert!(with_slot.find("talks").is_some());
+
+        let mut slotted = Layout::default();
+        slotted.0[0][0].push(CUSTOM_SECTIONS_SLOT.to_string());
+        assert!(slotted.ensure_all_sections_present(&sections).is_empty());
+    }
+
+    #[test]
+    fn test_normalize_drops_unknown_and_duplicate_keys() {
+        let sections = Sections::default();
+        let mut layout = layout(&[&[&["summary", "hobbies", "summary"], &[]]]);
+        layout.normalize(&sections);
+        assert_eq!(layout.pages()[0][0], ["summary"]);
+        assert_eq!(layout.pages()[0][1].len(), BUILT_IN_SECTIONS.len() - 1);
+        assert!(layout.check(&sections).is_empty());
+    }
+}
@@ -0,0 +1,111 @@
+//! Renamed fields: reading JSON exported before a schema field was renamed.
+//!
+//! Item types list their old keys with `#[section_item(alias(old = "new"))]`
+//! on the [`SectionItem`](rustume_schema_macros::SectionItem) derive, which
+//! implements [`LegacyFields`]. Reading through
+//! [`LegacyFields::from_json_value`] moves each old key to its current name
+//! before deserializing and reports a [`FieldDeprecation`] for it, so old
+//! files keep loading while their authors learn to re-export them.
+//!
+//! [`ResumeData`](crate::ResumeData) implements [`LegacyFields`] by
+//! upgrading the items of every section, so
+//! `ResumeData::from_json_value` reads a whole resume and lists the old keys
+//! it found. Plain deserializing accepts the old keys as well, through
+//! [`Section`](crate::Section), but has nowhere to report them.
+
+use serde::de::{DeserializeOwned, Error as _};
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+use std::fmt;
+
+/// An old key found while reading an item.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FieldDeprecation {
+    /// Rust name of the item type.
+    pub item: &'static str,
+    /// The key as it appeared in the JSON.
+    pub old: &'static str,
+    /// The key it was renamed to.
+    pub new: &'static str,
+}
+
+impl fmt::Display for FieldDeprecation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: `{}` is deprecated, use `{}`",
+            self.item, self.old, self.new
+        )
+    }
+}
+
+/// Types that still accept keys from before a field rename.
+pub trait LegacyFields: DeserializeOwned {
+    /// Rust name of the type, for [`FieldDeprecation::item`].
+    const ITEM: &'static str;
+
+    /// Old JSON keys and the current keys they were renamed to.
+    const FIELD_ALIASES: &'static [(&'static str, &'static str)];
+
+    /// Move old keys in a JSON object to their current names, appending a
+    /// deprecation per old key found. When both keys are present the
+    /// current one wins and the old one is dropped.
+    fn upgrade_legacy_fields(value: &mut Value, deprecations: &mut Vec<FieldDeprecation>) {
+        let Some(object) = value.as_object_mut() else {
+            return;
+        };
+        for &(old, new) in Self::FIELD_ALIASES {
+            let Some(legacy) = object.remove(old) else {
+                continue;
+            };
+            object.entry(new).or_insert(legacy);
+            deprecations.push(FieldDeprecation {
+                item: Self::ITEM,
+                old,
+                new,
+            });
+        }
+    }
+
+    /// Deserialize from a JSON value, accepting old keys.
+    fn from_json_value(
+        mut value: Value,
+    ) -> Result<(Self, Vec<FieldDeprecation>), serde_json::Error> {
+        let mut deprecations = Vec::new();
+        Self::upgrade_legacy_fields(&mut value, &mut deprecations);
+        Ok((serde_json::from_value(value)?, deprecations))
+    }
+}
+
+/// Deserialize the items of a section, moving old keys to their current
+/// names. The deprecations are dropped; see the [module docs](self).
+pub(crate) fn deserialize_items<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: LegacyFields,
+{
+    if T::FIELD_ALIASES.is_empty() {
+        return Vec::deserialize(deserializer);
+    }
+    let mut deprecations = Vec::new();
+    Vec::<Value>::deserialize(deserializer)?
+        .into_iter()
+        .map(|mut item| {
+            T::upgrade_legacy_fields(&mut item, &mut deprecations);
+            serde_json::from_value(item).map_err(D::Error::custom)
+        })
+        .collect()
+}
+
+/// Upgrade the items of a section object, if there is one.
+pub(crate) fn upgrade_section_items<T: LegacyFields>(
+    section: Option<&mut Value>,
+    deprecations: &mut Vec<FieldDeprecation>,
+) {
+    let Some(Value::Array(items)) = section.and_then(|section| section.get_mut("items")) else {
+        return;
+    };
+    for item in items {
+        T::upgrade_legacy_fields(item, deprecations);
+    }
+}
@@ -16,18 +16,63 @@
 //! assert!(resume.validate().is_ok());
 //! ```
 
+mod address;
+mod applications;
+mod attachments;
 mod basics;
+mod canonical;
+mod cefr;
+mod content_presets;
+mod dedupe;
+mod diff;
+mod diff_report;
+mod error;
+mod fit;
+mod gaps;
+mod keywords;
+mod layout;
+mod legacy;
+mod merge;
 mod metadata;
+mod name;
+mod presets;
+mod repair;
 mod sections;
 mod shared;
+mod stats;
+mod suggest;
+mod typescript;
 mod validation;
 
+pub use address::*;
+pub use applications::*;
+pub use attachments::*;
 pub use basics::*;
+pub use canonical::*;
+pub use cefr::*;
+pub use content_presets::*;
+pub use dedupe::*;
+pub use diff::*;
+pub use diff_report::*;
+pub use error::*;
+pub use fit::*;
+pub use gaps::*;
+pub use keywords::*;
+pub use layout::*;
+pub use legacy::*;
+pub use merge::*;
 pub use metadata::*;
+pub use name::*;
+pub use presets::*;
+pub use repair::*;
 pub use sections::*;
 pub use shared::*;
+pub use stats::*;
+pub use suggest::*;
+pub use typescript::*;
 pub use validation::*;
 
+use rustume_utils::Locale;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use validator::Validate;
@@ -39,7 +84,9 @@ use validator::Validate;
 /// - `basics`: Personal information (name, email, etc.)
 /// - `sections`: All resume sections (experience, education, skills, etc.)
 /// - `metadata`: Display settings (template, theme, layout, etc.)
-#[derive(Debug, Clone, Serialize, Deserialize, Validate, Default, ToSchema)]
+///
+/// plus `attachments`, files such as reference letters that go with it.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, Default, ToSchema, TypeScript)]
 #[serde(rename_all = "camelCase", default)]
 pub struct ResumeData {
     /// Basic personal information.
@@ -53,6 +100,10 @@ pub struct ResumeData {
     /// Display metadata (template, theme, layout).
     #[validate(nested)]
     pub metadata: Metadata,
+
+    /// Files such as reference letters and certificates.
+    #[validate(nested)]
+    pub attachments: Vec<Attachment>,
 }
 
 impl ResumeData {
@@ -69,6 +120,21 @@ impl ResumeData {
         resume
     }
 
+    /// The name as templates print it: the name parts in the order and case
+    /// set by `metadata.templateOptions`, or `basics.name` as written when
+    /// the parts are unknown or out of date.
+    pub fn display_name(&self) -> String {
+        let Some(parts) = self.basics.current_name_parts() else {
+            return self.basics.name.clone();
+        };
+        let options = &self.metadata.template_options;
+        let mut parts = parts.clone();
+        if options.family_name_caps {
+            parts.family = parts.family.to_uppercase();
+        }
+        parts.display(options.name_order, &self.metadata.locale)
+    }
+
     /// Serialize to JSON string.
     pub fn to_json(&self) -> Result<String, serde_json::Error> {
         serde_json::to_string_pretty(self)
@@ -88,6 +154,110 @@ impl ResumeData {
     pub fn from_json_bytes(bytes: &[u8]) -> Result<Self, serde_json::Error> {
         serde_json::from_slice(bytes)
     }
+
+    /// Whether `other` has the same content, compared by [`canonicalize`].
+    pub fn same_content(&self, other: &Self) -> bool {
+        canonicalize(self) == canonicalize(other)
+    }
+
+    /// Fill display dates from structured `startDate`/`endDate` fields, or
+    /// structured dates from display dates, on every experience, education,
+    /// and project item, in the resume's locale. See [`Experience::sync_dates`].
+    pub fn sync_dates(&mut self) {
+        let locale = Locale::for_tag(&self.metadata.locale);
+        let sections = &mut self.sections;
+        for item in &mut sections.experience.items {
+            item.sync_dates_in(locale);
+        }
+        for item in &mut sections.education.items {
+            item.sync_dates_in(locale);
+        }
+        for item in &mut sections.projects.items {
+            item.sync_dates_in(locale);
+        }
+    }
+
+    /// Normalize publications that carry a DOI or arXiv ID, offline: canonical
+    /// links, missing years, and citations for empty summaries. See
+    /// [`Publication::enrich`].
+    ///
+    /// Returns how many publications changed.
+    pub fn enrich_publications(&mut self) -> usize {
+        self.sections
+            .publications
+            .items
+            .iter_mut()
+            .map(Publication::enrich)
+            .filter(|changed| *changed)
+            .count()
+    }
+
+    /// Run the built-in semantic rules (date order, duplicate IDs, overlapping
+    /// positions, contact details, insecure links, theme contrast).
+    ///
+    /// These complement [`Validate::validate`]: a resume is valid when both
+    /// pass and the report has no errors.
+    pub fn check_rules(&self) -> RuleReport {
+        RuleSet::default().check(self)
+    }
+
+    /// Every problem with the resume as structured issues: failed field
+    /// checks, then rule errors, then rule warnings.
+    ///
+    /// The resume is valid when no issue has [`Severity::Error`].
+    pub fn issues(&self) -> Vec<ValidationIssue> {
+        let mut issues = match self.validate() {
+            Ok(()) => Vec::new(),
+            Err(errors) => validation_issues(&errors),
+        };
+        let report = self.check_rules();
+        issues.extend(report.errors);
+        issues.extend(report.warnings);
+        issues
+    }
+
+    /// Collect non-fatal warnings (e.g. low theme contrast).
+    ///
+    /// Unlike [`Validate::validate`], warnings do not make the resume invalid.
+    pub fn warnings(&self) -> Vec<ValidationIssue> {
+        self.check_rules().warnings
+    }
+
+    /// Collect rule-based writing hints for experience bullet points.
+    ///
+    /// See [`content_suggestions`] for the rules applied.
+    pub fn suggestions(&self) -> Vec<ContentSuggestion> {
+        content_suggestions(self)
+    }
+
+    /// Per-position durations and total experience as of today.
+    ///
+    /// See [`resume_stats`].
+    pub fn stats(&self) -> ResumeStats {
+        resume_stats(self, &rustume_utils::PartialDate::today())
+    }
+
+    /// Find likely duplicate items at [`DEFAULT_SIMILARITY`].
+    ///
+    /// See [`find_duplicates`] for how items are compared.
+    pub fn duplicates(&self) -> Vec<DuplicateCandidate> {
+        find_duplicates(self, DEFAULT_SIMILARITY)
+    }
+}
+
+/// Reads old keys in the items of every section; see [`LegacyFields`].
+impl LegacyFields for ResumeData {
+    const ITEM: &'static str = "ResumeData";
+    const FIELD_ALIASES: &'static [(&'static str, &'static str)] = &[];
+
+    fn upgrade_legacy_fields(
+        value: &mut serde_json::Value,
+        deprecations: &mut Vec<FieldDeprecation>,
+    ) {
+        if let Some(sections) = value.get_mut("sections") {
+            Sections::upgrade_legacy_fields(sections, deprecations);
+        }
+    }
 }
 
 #[cfg(test)]
@@ -133,4 +303,109 @@ mod tests {
 
         assert!(resume.validate().is_err());
     }
+
+    #[test]
+    fn test_sync_dates_fills_either_side() {
+        let mut resume = ResumeData::default();
+        resume
+            .sections
+            .experience
+            .add_item(Experience::new("Acme", "Engineer").with_date("Mar 2020 - Present"));
+        resume
+            .sections
+            .projects
+            .add_item(Project::new("Rustume").with_dates("2019-06", Some("2021".to_string())));
+        resume
+            .sections
+            .education
+            .add_item(Education::new("MIT", "CS").with_date("Fall semesters"));
+
+        resume.sync_dates();
+
+        let experience = &resume.sections.experience.items[0];
+        assert_eq!(experience.start_date.as_deref(), Some("2020-03"));
+        assert_eq!(experience.end_date, None);
+        assert_eq!(resume.sections.projects.items[0].date, "Jun 2019 - 2021");
+        assert_eq!(resume.sections.education.items[0].start_date, None);
+    }
+
+    #[test]
+    fn test_enrich_publications_counts_changed_entries() {
+        let mut resume = ResumeData::default();
+        resume.sections.publications.add_item(
+            Publication::new("Attention Is All You Need")
+                .with_url("https://arxiv.org/abs/1706.03762"),
+        );
+        resume
+            .sections
+            .publications
+            .add_item(Publication::new("Field notes").with_publisher("Self-published"));
+
+        assert_eq!(resume.enrich_publications(), 1);
+        assert_eq!(resume.sections.publications.items[0].date, "2017");
+        // Already enriched
+        assert_eq!(resume.enrich_publications(), 0);
+    }
+
+    #[test]
+    fn test_sync_dates_uses_resume_locale() {
+        let mut resume = ResumeData::default();
+        resume.metadata.locale = "de-AT".to_string();
+        resume
+            .sections
+            .experience
+            .add_item(Experience::new("Acme", "Engineer").with_dates("2020-03", None));
+
+        resume.sync_dates();
+
+        assert_eq!(resume.sections.experience.items[0].date, "Mar 2020 - Heute");
+        assert_eq!(resume.metadata.locale, "de-AT");
+        assert_eq!(ResumeData::default().metadata.locale, "en");
+    }
+
+    #[test]
+    fn test_structured_dates_are_validated_and_omitted_when_unset() {
+        let mut experience = Experience::new("Acme", "Engineer");
+        let json = serde_json::to_value(&experience).unwrap();
+        assert!(json.get("startDate").is_none());
+
+        experience.start_date = Some("March 2020".to_string());
+        assert!(experience.validate().is_err());
+        experience.start_date = Some("2020-03".to_string());
+        assert!(experience.validate().is_ok());
+    }
+
+    #[test]
+    fn test_resume_warnings_for_low_contrast_theme() {
+        let mut resume = ResumeData::default();
+        assert!(resume.warnings().is_empty());
+
+        resume.metadata.theme.text = "#eeeeee".to_string();
+        let warnings = resume.warnings();
+        assert_eq!(warnings.len(), 1);
+        assert!(
+            resume.validate().is_ok(),
+            "warnings must not fail validation"
+        );
+    }
+
+    #[test]
+    fn test_resume_issues_carry_path_code_and_severity() {
+        let mut resume = ResumeData::default();
+        assert!(resume.issues().is_empty());
+
+        resume.basics.url.href = "not-a-url".to_string();
+        resume.metadata.theme.text = "#eeeeee".to_string();
+        let issues = resume.issues();
+        assert_eq!(issues.len(), 2);
+        assert_eq!(issues[0].path, "basics.url.href");
+        assert_eq!(issues[0].code, "invalid_url");
+        assert_eq!(issues[0].severity, Severity::Error);
+        assert_eq!(
+            issues[0].to_string(),
+            "basics.url.href: Must be a valid HTTP(S) URL"
+        );
+        assert_eq!(issues[1].path, "metadata.theme.text");
+        assert_eq!(issues[1].severity, Severity::Warning);
+    }
 }
@@ -0,0 +1,335 @@
+//! Merging a fresh import into an existing resume.
+//!
+//! Re-importing a LinkedIn export should not throw away the edits made since
+//! the first import. [`merge_import`] keeps the existing resume as the base
+//! and appends only imported items that have no near-duplicate there, using
+//! the same matcher as [`find_duplicates`](crate::find_duplicates).
+
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use utoipa::ToSchema;
+
+use crate::dedupe::{item_label, item_sections, item_similarity, section_items_mut, text};
+use crate::TypeScript;
+use crate::{MergeError, ResumeData, DEFAULT_SIMILARITY};
+
+/// How imported data is combined with the existing resume.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema, TypeScript,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum MergeStrategy {
+    /// Append new items only; existing items, basics, and summary are untouched.
+    #[default]
+    AppendNew,
+    /// Also fill blank fields of matched items, basics, and the summary from
+    /// the import. Fields that already have a value are never overwritten.
+    FillBlanks,
+}
+
+/// One imported item and what happened to it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema, TypeScript)]
+#[serde(rename_all = "camelCase")]
+pub struct MergeEntry {
+    /// Section key: `experience`, `skills`, ... or `custom.<id>`.
+    #[schema(example = "experience")]
+    pub section: String,
+
+    /// Identifying fields of the imported item.
+    #[schema(example = "Acme, Senior Engineer (2019 - 2022)")]
+    pub label: String,
+
+    /// ID of the item in the merged resume: the appended item for `added`,
+    /// the existing match for `skipped` and `updated`.
+    pub id: String,
+}
+
+/// Outcome of [`merge_import`] for each imported item.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, ToSchema, TypeScript)]
+pub struct MergeReport {
+    /// Imported items with no match, appended to their section.
+    pub added: Vec<MergeEntry>,
+
+    /// Imported items that matched an existing item and were left out.
+    pub skipped: Vec<MergeEntry>,
+
+    /// Matched items whose blank fields were filled from the import
+    /// ([`MergeStrategy::FillBlanks`] only).
+    pub updated: Vec<MergeEntry>,
+}
+
+/// A merged resume and its report.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, TypeScript)]
+pub struct MergedImport {
+    pub resume: ResumeData,
+    pub report: MergeReport,
+}
+
+/// Merge `imported` into `existing`.
+///
+/// Existing items keep their IDs, order, visibility, and content. Imported
+/// items are compared with the items of the same section at
+/// [`DEFAULT_SIMILARITY`]; unmatched ones are appended (with a fresh ID if
+/// theirs is already taken), and repeats within the import collapse into one.
+/// Custom sections missing from `existing` are added. Metadata always comes
+/// from `existing`.
+pub fn merge_import(
+    existing: ResumeData,
+    imported: ResumeData,
+    strategy: MergeStrategy,
+) -> Result<MergedImport, MergeError> {
+    let invalid = |e: serde_json::Error| MergeError::Invalid(e.to_string());
+    let fill = strategy == MergeStrategy::FillBlanks;
+
+    let mut sections = serde_json::to_value(existing.sections).map_err(invalid)?;
+    let imported_sections = serde_json::to_value(imported.sections).map_err(invalid)?;
+
+    let mut ids: HashSet<String> = item_sections(&sections)
+        .into_iter()
+        .flat_map(|(_, items, _)| items.iter().map(|item| text(item, "id").to_string()))
+        .collect();
+
+    // Start custom sections the existing resume lacks with the imported
+    // settings and no items, so they fill up like any other section.
+    if let (Some(custom), Some(imported_custom)) = (
+        sections.get_mut("custom").and_then(Value::as_object_mut),
+        imported_sections.get("custom").and_then(Value::as_object),
+    ) {
+        for (id, section) in imported_custom {
+            if !custom.contains_key(id) {
+                let mut section = section.clone();
+                section["items"] = Value::Array(Vec::new());
+                custom.insert(id.clone(), section);
+            }
+        }
+    }
+
+    let mut report = MergeReport::default();
+    for (key, imported_items, fields) in item_sections(&imported_sections) {
+        let Some(items) = section_items_mut(&mut sections, &key) else {
+            continue;
+        };
+        for imported_item in imported_items {
+            let matched = items.iter().position(|item| {
+                item_similarity(item, imported_item, fields)
+                    .is_some_and(|similarity| similarity >= DEFAULT_SIMILARITY)
+            });
+            let label = item_label(imported_item, fields);
+
+            match matched {
+                Some(index) => {
+                    let entry = MergeEntry {
+                        section: key.clone(),
+                        label,
+                        id: text(&items[index], "id").to_string(),
+                    };
+                    if fill && fill_blanks(&mut items[index], imported_item) {
+                        report.updated.push(entry);
+                    } else {
+                        report.skipped.push(entry);
+                    }
+                }
+                None => {
+                    let mut item = imported_item.clone();
+                    let mut id = text(&item, "id").to_string();
+                    if id.is_empty() || ids.contains(&id) {
+                        id = cuid2::create_id();
+                        item["id"] = Value::String(id.clone());
+                    }
+                    ids.insert(id.clone());
+                    items.push(item);
+                    report.added.push(MergeEntry {
+                        section: key.clone(),
+                        label,
+                        id,
+                    });
+                }
+            }
+        }
+    }
+
+    let mut basics = existing.basics;
+    if fill {
+        fill_blanks(&mut sections["summary"], &imported_sections["summary"]);
+
+        let mut value = serde_json::to_value(&basics).map_err(invalid)?;
+        let imported_basics = serde_json::to_value(imported.basics).map_err(invalid)?;
+        if fill_blanks(&mut value, &imported_basics) {
+            basics = serde_json::from_value(value).map_err(invalid)?;
+        }
+    }
+
+    Ok(MergedImport {
+        resume: ResumeData {
+            basics,
+            sections: serde_json::from_value(sections).map_err(invalid)?,
+            metadata: existing.metadata,
+            attachments: existing.attachments,
+        },
+        report,
+    })
+}
+
+/// Copy `source` into blank strings and empty lists of `target`, recursing
+/// into objects. Returns whether anything changed.
+fn fill_blanks(target: &mut Value, source: &Value) -> bool {
+    match (target, source) {
+        (Value::Object(target), Value::Object(source)) => {
+            let mut changed = false;
+            for (key, value) in source {
+                match target.get_mut(key) {
+                    Some(existing) => changed |= fill_blanks(existing, value),
+                    None => {
+                        target.insert(key.clone(), value.clone());
+                        changed = true;
+                    }
+                }
+            }
+            changed
+        }
+        (Value::String(target), Value::String(source))
+            if target.trim().is_empty() && !source.trim().is_empty() =>
+        {
+            target.clone_from(source);
+            true
+        }
+        (Value::Array(target), Value::Array(source)) if target.is_empty() && !source.is_empty() => {
+            target.clone_from(source);
+            true
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CustomItem, Experience, Section, Skill};
+
+    fn resume(experience: Vec<Experience>, skills: Vec<Skill>) -> ResumeData {
+        let mut resume = ResumeData::default();
+        resume.sections.experience = Section::new("experience", "Experience");
+        for item in experience {
+            resume.sections.experience.add_item(item);
+        }
+        for item in skills {
+            resume.sections.skills.add_item(item);
+        }
+        resume
+    }
+
+    #[test]
+    fn test_appends_only_new_items() {
+        let mut edited = Experience::new("Acme Inc.", "Sr. Engineer")
+            .with_summary("<p>Hand-written summary</p>");
+        edited.visible = false;
+        let existing = resume(vec![edited.clone()], vec![Skill::new("Rust")]);
+        let imported = resume(
+            vec![
+                Experience::new("ACME", "Senior Engineer").with_summary("From LinkedIn"),
+                Experience::new("Globex", "Analyst"),
+            ],
+            vec![Skill::new("rust"), Skill::new("Go")],
+        );
+
+        let merged = merge_import(existing, imported, MergeStrategy::AppendNew).unwrap();
+
+        let experience = &merged.resume.sections.experience.items;
+        assert_eq!(experience.len(), 2);
+        assert_eq!(experience[0].id, edited.id);
+        assert_eq!(experience[0].summary, "<p>Hand-written summary</p>");
+        assert!(!experience[0].visible);
+        assert_eq!(experience[1].company, "Globex");
+
+        let report = &merged.report;
+        let added: Vec<&str> = report.added.iter().map(|e| e.label.as_str()).collect();
+        assert_eq!(added, ["Globex, Analyst", "Go"]);
+        assert_eq!(report.skipped.len(), 2);
+        assert_eq!(report.skipped[0].id, edited.id);
+        assert!(report.updated.is_empty());
+    }
+
+    #[test]
+    fn test_fill_blanks_never_overwrites() {
+        let mut existing = resume(vec![Experience::new("Acme", "Engineer")], vec![]);
+        existing.basics.name = "Jane Doe".into();
+        let mut imported = resume(
+            vec![Experience::new("Acme", "Engineer")
+                .with_location("Berlin")
+                .with_summary("From LinkedIn")],
+            vec![],
+        );
+        imported.basics.name = "J. Doe".into();
+        imported.basics.headline = "Engineer".into();
+        imported.sections.summary.content = "Imported summary".into();
+
+        let merged = merge_import(existing, imported, MergeStrategy::FillBlanks).unwrap();
+
+        let item = &merged.resume.sections.experience.items[0];
+        assert_eq!(item.location, "Berlin");
+        assert_eq!(item.summary, "From LinkedIn");
+        assert_eq!(merged.resume.basics.name, "Jane Doe");
+        assert_eq!(merged.resume.basics.headline, "Engineer");
+        assert_eq!(merged.resume.sections.summary.content, "Imported summary");
+        assert_eq!(merged.report.updated.len(), 1);
+        assert!(merged.report.skipped.is_empty());
+    }
+
+    #[test]
+    fn test_append_new_leaves_basics_and_summary() {
+        let existing = ResumeData::default();
+        let mut imported = ResumeData::default();
+        imported.basics.name = "Jane".into();
+        imported.sections.summary.content = "Imported".into();
+
+        let merged = merge_import(existing, imported, MergeStrategy::AppendNew).unwrap();
+        assert!(merged.resume.basics.name.is_empty());
+        assert!(merged.resume.sections.summary.content.is_empty());
+    }
+
+    #[test]
+    fn test_reimporting_same_resume_adds_nothing() {
+        let existing = resume(
+            vec![Experience::new("Acme", "Engineer")],
+            vec![Skill::new("Rust")],
+        );
+        let merged = merge_import(existing.clone(), existing, MergeStrategy::AppendNew).unwrap();
+        assert!(merged.report.added.is_empty());
+        assert_eq!(merged.report.skipped.len(), 2);
+    }
+
+    #[test]
+    fn test_conflicting_ids_and_repeats_within_import() {
+        let existing = resume(vec![Experience::new("Acme", "Engineer")], vec![]);
+        let taken = existing.sections.experience.items[0].id.clone();
+        let mut renamed = Experience::new("Globex", "Analyst");
+        renamed.id = taken.clone();
+        let imported = resume(vec![renamed, Experience::new("GLOBEX", "Analyst")], vec![]);
+
+        let merged = merge_import(existing, imported, MergeStrategy::AppendNew).unwrap();
+
+        let items = &merged.resume.sections.experience.items;
+        assert_eq!(items.len(), 2);
+        assert_ne!(items[1].id, taken);
+        assert_eq!(merged.report.added[0].id, items[1].id);
+        assert_eq!(merged.report.skipped[0].id, items[1].id);
+    }
+
+    #[test]
+    fn test_new_custom_section_is_added() {
+        let existing = ResumeData::default();
+        let mut imported = ResumeData::default();
+        let mut talks = Section::new("talks", "Talks");
+        talks.add_item(CustomItem::new("RustConf"));
+        imported.sections.custom.insert("talks".into(), talks);
+
+        let merged = merge_import(existing, imported, MergeStrategy::AppendNew).unwrap();
+
+        let talks = &merged.resume.sections.custom["talks"];
+        assert_eq!(talks.name, "Talks");
+        assert_eq!(talks.items.len(), 1);
+        assert_eq!(merged.report.added[0].section, "custom.talks");
+    }
+}
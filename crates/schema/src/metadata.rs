@@ -1,11 +1,19 @@
 //! Resume metadata - template, layout, theme, typography.
 
+use std::collections::HashMap;
+
+use rustume_utils::{replace_emoji, strip_emoji, DEFAULT_LOCALE};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
-use validator::Validate;
+use validator::{Validate, ValidationError};
+
+use crate::TypeScript;
+use crate::{Layout, NameOrder};
 
 /// Controls how skill and language proficiency levels are rendered.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default, ToSchema)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default, ToSchema, TypeScript,
+)]
 #[serde(rename_all = "kebab-case")]
 pub enum LevelDisplay {
     #[default]
@@ -17,16 +25,147 @@ pub enum LevelDisplay {
     Text,
 }
 
+/// How a section with proficiency levels is drawn, set per section in
+/// [`Metadata::section_display`].
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default, ToSchema, TypeScript,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum SectionDisplay {
+    /// The template's own item layout.
+    #[default]
+    List,
+    /// One row per item with a bar as long as its level.
+    BarChart,
+    /// One row per item with five dots, filled up to its level.
+    Dots,
+    /// Item names flowing together, larger for higher levels.
+    TagCloud,
+}
+
+/// Section keys that accept a [`SectionDisplay`].
+///
+/// **Keep in sync with:** `chart-sections` in the templates' `_common.typ`.
+pub const SECTION_DISPLAY_KEYS: &[&str] = &["skills", "languages"];
+
+/// Where a template draws the profile picture in its header.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default, ToSchema, TypeScript,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum PhotoPosition {
+    #[default]
+    TemplateDefault,
+    /// Above the name.
+    Top,
+    /// Beside the name, on the left.
+    Left,
+    /// Beside the name, on the right.
+    Right,
+    /// Not drawn, even when `basics.picture` is set.
+    Hidden,
+}
+
+/// Alignment of the name and contact details in a template's header.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default, ToSchema, TypeScript,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum HeaderAlign {
+    #[default]
+    TemplateDefault,
+    Left,
+    Center,
+    Right,
+}
+
+/// The rule drawn under section headings.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default, ToSchema, TypeScript,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum DividerStyle {
+    #[default]
+    TemplateDefault,
+    /// A thin solid line.
+    Line,
+    /// A solid line twice the template's usual weight.
+    Thick,
+    /// A dotted line.
+    Dotted,
+    /// No rule.
+    None,
+}
+
+/// Per-template presentation options, so moving the photo or centering the
+/// header doesn't take a new template. Fields left at `template-default`
+/// keep the template's own look; which options a template honors is listed
+/// in its capability descriptor, and renders warn about the rest. The name
+/// options apply to every template.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default, ToSchema, TypeScript,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct TemplateOptions {
+    /// Placement of `basics.picture`. Its size stays in `basics.picture.size`.
+    #[serde(default)]
+    pub photo: PhotoPosition,
+
+    #[serde(default)]
+    pub header_align: HeaderAlign,
+
+    #[serde(default)]
+    pub divider: DividerStyle,
+
+    /// Order of the given and family name in the header, when
+    /// `basics.nameParts` is set.
+    #[serde(default)]
+    pub name_order: NameOrder,
+
+    /// Print the family name in capitals (`Jean DUPONT`), when
+    /// `basics.nameParts` is set.
+    #[serde(default)]
+    pub family_name_caps: bool,
+}
+
+/// What rendering does with emoji and pictographic icons in headlines and
+/// rich text. Many applicant tracking systems garble them when parsing a PDF.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default, ToSchema, TypeScript,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum EmojiPolicy {
+    /// Render emoji as written.
+    #[default]
+    Preserve,
+    /// Remove emoji and icons.
+    Strip,
+    /// Swap label and bullet icons (`📧`, `✅`) for text (`Email:`, `-`) and
+    /// remove the rest.
+    Replace,
+}
+
+impl EmojiPolicy {
+    /// Apply the policy to plain text or HTML.
+    pub fn apply(self, text: &str) -> String {
+        match self {
+            Self::Preserve => text.to_string(),
+            Self::Strip => strip_emoji(text),
+            Self::Replace => replace_emoji(text),
+        }
+    }
+}
+
 /// Resume metadata.
-#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema, TypeScript)]
 #[serde(rename_all = "camelCase")]
 pub struct Metadata {
     #[serde(default = "default_template")]
     pub template: String,
 
     /// Layout: pages -> columns -> section IDs.
-    #[serde(default = "default_layout")]
-    pub layout: Vec<Vec<Vec<String>>>,
+    #[serde(default)]
+    pub layout: Layout,
 
     #[validate(nested)]
     #[serde(default)]
@@ -49,25 +188,60 @@ pub struct Metadata {
 
     #[serde(default)]
     pub level_display: LevelDisplay,
+
+    /// Chart style per section key (see [`SECTION_DISPLAY_KEYS`]). Sections
+    /// left out keep the template's layout.
+    #[validate(custom(function = "validate_section_display"))]
+    #[serde(default)]
+    pub section_display: HashMap<String, SectionDisplay>,
+
+    /// Show each position's duration ("2 yrs 3 mos") after its dates.
+    #[serde(default)]
+    pub show_durations: bool,
+
+    /// Show each language's CEFR code ("C1") with its description.
+    #[serde(default)]
+    pub show_cefr: bool,
+
+    /// Language of generated text such as durations and the end of ongoing
+    /// date ranges, as a BCP 47 tag (`de`, `pt-BR`). Unsupported languages
+    /// fall back to English.
+    #[validate(length(max = 35))]
+    #[serde(default = "default_locale")]
+    pub locale: String,
+
+    /// Emoji handling in headlines and rich text when rendering.
+    #[serde(default)]
+    pub emoji: EmojiPolicy,
+
+    /// Photo, header, divider, and name options for the selected template.
+    #[serde(default)]
+    pub template_options: TemplateOptions,
 }
 
 impl Default for Metadata {
     fn default() -> Self {
         Self {
             template: default_template(),
-            layout: default_layout(),
+            layout: Layout::default(),
             css: CustomCss::default(),
             page: PageConfig::default(),
             theme: Theme::default(),
             typography: Typography::default(),
             notes: String::new(),
             level_display: LevelDisplay::TemplateDefault,
+            section_display: HashMap::new(),
+            show_durations: false,
+            show_cefr: false,
+            locale: default_locale(),
+            emoji: EmojiPolicy::Preserve,
+            template_options: TemplateOptions::default(),
         }
     }
 }
 
 /// Custom CSS configuration.
-#[derive(Debug, Clone, Serialize, Deserialize, Validate, Default, ToSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, Default, ToSchema, TypeScript)]
 pub struct CustomCss {
     #[serde(default)]
     pub value: String,
@@ -77,7 +251,7 @@ pub struct CustomCss {
 }
 
 /// Page format.
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, ToSchema, TypeScript)]
 #[serde(rename_all = "lowercase")]
 #[derive(Default)]
 pub enum PageFormat {
@@ -87,7 +261,7 @@ pub enum PageFormat {
 }
 
 /// Page configuration.
-#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema, TypeScript)]
 #[serde(rename_all = "camelCase")]
 pub struct PageConfig {
     #[serde(default = "default_margin")]
@@ -105,6 +279,12 @@ pub struct PageConfig {
     #[validate(nested)]
     #[serde(default)]
     pub options: PageOptions,
+
+    /// Page and sidebar colors for some pages of the resume, in place of
+    /// the theme background and the template's sidebar color.
+    #[validate(nested)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub backgrounds: Vec<PageBackground>,
 }
 
 impl Default for PageConfig {
@@ -114,12 +294,99 @@ impl Default for PageConfig {
             format: PageFormat::A4,
             sidebar_ratio: None,
             options: PageOptions::default(),
+            backgrounds: Vec::new(),
         }
     }
 }
 
+/// Pages of the resume a [`PageBackground`] applies to. The first resume
+/// page is page 1; a cover letter before it and attachments after it keep
+/// the theme background.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default, ToSchema, TypeScript,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum PageSelector {
+    #[default]
+    All,
+    First,
+    /// Every page after the first.
+    Rest,
+    Last,
+    Odd,
+    Even,
+}
+
+/// Colors of the pages `pages` selects. Where several backgrounds select
+/// the same page, the later one wins for each color it sets.
+#[derive(
+    Debug, Clone, Default, PartialEq, Serialize, Deserialize, Validate, ToSchema, TypeScript,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct PageBackground {
+    #[serde(default)]
+    pub pages: PageSelector,
+
+    /// Page color; empty keeps the theme background.
+    #[validate(custom(function = "crate::validation::validate_hex_color"))]
+    #[serde(default)]
+    pub background: String,
+
+    /// Sidebar color on templates with a sidebar; empty keeps the
+    /// template's.
+    #[validate(custom(function = "crate::validation::validate_hex_color"))]
+    #[serde(default)]
+    pub sidebar: String,
+
+    /// Leave the sidebar unfilled, so the page color shows through.
+    #[serde(default)]
+    pub hide_sidebar: bool,
+}
+
+/// Where the name and contact line repeats on pages after the first.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default, ToSchema, TypeScript,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum RunningHeader {
+    Hidden,
+    #[default]
+    Header,
+    Footer,
+}
+
+/// Where a QR code of the contact details prints on the first page.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default, ToSchema, TypeScript,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum ContactQr {
+    #[default]
+    Hidden,
+    Header,
+    Footer,
+}
+
+/// How page numbers are written in the footer.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default, ToSchema, TypeScript,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum PageNumberStyle {
+    /// `2`
+    Plain,
+    /// `2 / 3`
+    #[default]
+    OfTotal,
+    /// `Page 2 of 3`
+    Labeled,
+}
+
 /// Page display options.
-#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+///
+/// Page numbers and the running header only appear when the resume runs
+/// past one page.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema, TypeScript)]
 #[serde(rename_all = "camelCase")]
 pub struct PageOptions {
     #[serde(default = "default_true")]
@@ -127,6 +394,29 @@ pub struct PageOptions {
 
     #[serde(default = "default_true")]
     pub page_numbers: bool,
+
+    #[serde(default)]
+    pub page_number_style: PageNumberStyle,
+
+    /// Repeat the name and contact details on pages 2+.
+    #[serde(default)]
+    pub running_header: RunningHeader,
+
+    /// Repeat a section's heading, marked "continued", when its items
+    /// carry over to a new page.
+    #[serde(default = "default_true")]
+    pub continued_markers: bool,
+
+    /// Print the name, headline, email, phone, location, and website as a
+    /// vCard QR code in the first page's header or footer, so a phone camera
+    /// can save them as a contact.
+    #[serde(default)]
+    pub contact_qr: ContactQr,
+
+    /// Write the title ("Name — Headline"), author, subject, keywords
+    /// (the skills), and creation date into exported PDFs.
+    #[serde(default = "default_true")]
+    pub pdf_metadata: bool,
 }
 
 impl Default for PageOptions {
@@ -134,12 +424,17 @@ impl Default for PageOptions {
         Self {
             break_line: true,
             page_numbers: true,
+            page_number_style: PageNumberStyle::default(),
+            running_header: RunningHeader::default(),
+            continued_markers: true,
+            contact_qr: ContactQr::default(),
+            pdf_metadata: true,
         }
     }
 }
 
 /// Color theme.
-#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema, TypeScript)]
 pub struct Theme {
     #[validate(custom(function = "crate::validation::validate_hex_color"))]
     #[serde(default = "default_background")]
@@ -152,6 +447,10 @@ pub struct Theme {
     #[validate(custom(function = "crate::validation::validate_hex_color"))]
     #[serde(default = "default_primary")]
     pub primary: String,
+
+    /// Id of the theme preset the colors were picked from, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub preset: Option<String>,
 }
 
 impl Default for Theme {
@@ -160,21 +459,51 @@ impl Default for Theme {
             background: "#ffffff".to_string(),
             text: "#000000".to_string(),
             primary: "#dc2626".to_string(),
+            preset: None,
         }
     }
 }
 
 /// Typography configuration.
-#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema, TypeScript)]
 #[serde(rename_all = "camelCase")]
 pub struct Typography {
     #[validate(nested)]
     #[serde(default)]
     pub font: FontConfig,
 
+    /// Line height as a multiple of the font size. In PDFs it scales each
+    /// template's line spacing, which the default of 1.5 keeps as designed.
     #[serde(default = "default_line_height")]
     pub line_height: f32,
 
+    /// Extra space between letters, in em.
+    #[validate(range(min = -0.05, max = 0.2))]
+    #[serde(default)]
+    pub letter_spacing: f32,
+
+    /// Space between paragraphs, in em; unset keeps the template's.
+    #[validate(range(min = 0.0, max = 3.0))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub paragraph_spacing: Option<f32>,
+
+    /// Size of section headings relative to the template's.
+    #[validate(range(min = 0.5, max = 2.0))]
+    #[serde(default = "default_heading_scale")]
+    pub heading_scale: f32,
+
+    /// Set section headings in small capitals.
+    #[serde(default)]
+    pub small_caps: bool,
+
+    /// Justify paragraphs to both edges; unset keeps the template's.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub justify: Option<bool>,
+
+    /// Hyphenate words at line ends; unset hyphenates justified text only.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hyphenate: Option<bool>,
+
     #[serde(default)]
     pub hide_icons: bool,
 
@@ -187,6 +516,12 @@ impl Default for Typography {
         Self {
             font: FontConfig::default(),
             line_height: 1.5,
+            letter_spacing: 0.0,
+            paragraph_spacing: None,
+            heading_scale: 1.0,
+            small_caps: false,
+            justify: None,
+            hyphenate: None,
             hide_icons: false,
             underline_links: true,
         }
@@ -194,7 +529,7 @@ impl Default for Typography {
 }
 
 /// Font configuration.
-#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema, TypeScript)]
 pub struct FontConfig {
     #[serde(default = "default_font_family")]
     pub family: String,
@@ -220,10 +555,28 @@ impl Default for FontConfig {
     }
 }
 
+fn validate_section_display(
+    display: &HashMap<String, SectionDisplay>,
+) -> Result<(), ValidationError> {
+    if display
+        .keys()
+        .all(|key| SECTION_DISPLAY_KEYS.contains(&key.as_str()))
+    {
+        return Ok(());
+    }
+    let mut error = ValidationError::new("invalid_section_display");
+    error.message = Some("Display modes apply to the skills and languages sections".into());
+    Err(error)
+}
+
 fn default_template() -> String {
     "rhyhorn".to_string()
 }
 
+fn default_locale() -> String {
+    DEFAULT_LOCALE.to_string()
+}
+
 fn default_margin() -> u32 {
     18
 }
@@ -244,6 +597,10 @@ fn default_line_height() -> f32 {
     1.5
 }
 
+fn default_heading_scale() -> f32 {
+    1.0
+}
+
 fn default_font_family() -> String {
     "IBM Plex Serif".to_string()
 }
@@ -264,31 +621,6 @@ fn default_true() -> bool {
     true
 }
 
-fn default_layout() -> Vec<Vec<Vec<String>>> {
-    vec![vec![
-        vec![
-            // Rendered as a dedicated page before the resume body; listed
-            // first because its column position does not affect placement.
-            "coverLetter".to_string(),
-            "profiles".to_string(),
-            "summary".to_string(),
-            "experience".to_string(),
-            "education".to_string(),
-            "projects".to_string(),
-            "volunteer".to_string(),
-            "references".to_string(),
-        ],
-        vec![
-            "skills".to_string(),
-            "interests".to_string(),
-            "certifications".to_string(),
-            "awards".to_string(),
-            "publications".to_string(),
-            "languages".to_string(),
-        ],
-    ]]
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -335,6 +667,92 @@ mod tests {
         assert!(too_large.validate().is_err());
     }
 
+    #[test]
+    fn page_backgrounds_round_trip_and_default_to_none() {
+        let page: PageConfig = serde_json::from_value(json!({})).unwrap();
+        assert!(page.backgrounds.is_empty());
+        let json = serde_json::to_value(&page).unwrap();
+        assert!(json.get("backgrounds").is_none());
+
+        let page: PageConfig = serde_json::from_value(json!({
+            "backgrounds": [
+                { "pages": "first", "sidebar": "#1e3a8a" },
+                { "pages": "rest", "background": "#f8fafc", "hideSidebar": true }
+            ]
+        }))
+        .unwrap();
+        assert!(page.validate().is_ok());
+        assert_eq!(page.backgrounds[0].pages, PageSelector::First);
+        assert_eq!(page.backgrounds[0].background, "");
+        assert!(page.backgrounds[1].hide_sidebar);
+
+        let json = serde_json::to_value(&page).unwrap();
+        assert_eq!(json["backgrounds"][1]["pages"], "rest");
+        assert_eq!(json["backgrounds"][1]["hideSidebar"], true);
+    }
+
+    #[test]
+    fn page_backgrounds_reject_invalid_colors() {
+        let page = PageConfig {
+            backgrounds: vec![PageBackground {
+                sidebar: "navy".to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        assert!(page.validate().is_err());
+    }
+
+    #[test]
+    fn typography_defaults_keep_template_spacing() {
+        let typography: Typography = serde_json::from_value(json!({})).unwrap();
+        assert_eq!(typography.letter_spacing, 0.0);
+        assert_eq!(typography.paragraph_spacing, None);
+        assert_eq!(typography.heading_scale, 1.0);
+        assert!(!typography.small_caps);
+
+        let json = serde_json::to_value(&typography).unwrap();
+        assert!(json.get("paragraphSpacing").is_none());
+        assert_eq!(json["headingScale"], 1.0);
+    }
+
+    #[test]
+    fn typography_justify_and_hyphenate_default_to_the_template() {
+        let typography: Typography = serde_json::from_value(json!({})).unwrap();
+        assert_eq!(typography.justify, None);
+        assert_eq!(typography.hyphenate, None);
+        let json = serde_json::to_value(&typography).unwrap();
+        assert!(json.get("justify").is_none());
+        assert!(json.get("hyphenate").is_none());
+
+        let typography: Typography =
+            serde_json::from_value(json!({ "justify": true, "hyphenate": false })).unwrap();
+        assert_eq!(typography.justify, Some(true));
+        assert_eq!(typography.hyphenate, Some(false));
+        assert!(serde_json::from_value::<Typography>(json!({ "justify": "yes" })).is_err());
+    }
+
+    #[test]
+    fn typography_rejects_spacing_outside_bounds() {
+        let valid: Typography = serde_json::from_value(json!({
+            "letterSpacing": 0.05,
+            "paragraphSpacing": 0.8,
+            "headingScale": 1.25,
+            "smallCaps": true
+        }))
+        .unwrap();
+        assert!(valid.validate().is_ok());
+
+        for invalid in [
+            json!({ "letterSpacing": 0.5 }),
+            json!({ "paragraphSpacing": -1.0 }),
+            json!({ "headingScale": 3.0 }),
+        ] {
+            let typography: Typography = serde_json::from_value(invalid.clone()).unwrap();
+            assert!(typography.validate().is_err(), "{invalid}");
+        }
+    }
+
     #[test]
     fn level_display_uses_kebab_case_round_trip() {
         let cases = [
@@ -360,4 +778,69 @@ mod tests {
         let metadata: Metadata = serde_json::from_value(json!({})).unwrap();
         assert_eq!(metadata.level_display, LevelDisplay::TemplateDefault);
     }
+
+    #[test]
+    fn section_display_round_trips_per_section() {
+        let metadata: Metadata = serde_json::from_value(json!({
+            "sectionDisplay": { "skills": "bar-chart", "languages": "tag-cloud" }
+        }))
+        .unwrap();
+        assert_eq!(metadata.section_display["skills"], SectionDisplay::BarChart);
+        assert_eq!(
+            metadata.section_display["languages"],
+            SectionDisplay::TagCloud
+        );
+        assert!(metadata.validate().is_ok());
+
+        let json = serde_json::to_value(&metadata).unwrap();
+        assert_eq!(json["sectionDisplay"]["skills"], "bar-chart");
+
+        let defaults: Metadata = serde_json::from_value(json!({})).unwrap();
+        assert!(defaults.section_display.is_empty());
+    }
+
+    #[test]
+    fn section_display_rejects_sections_without_levels() {
+        let mut metadata = Metadata::default();
+        metadata
+            .section_display
+            .insert("experience".to_string(), SectionDisplay::Dots);
+        assert!(metadata.validate().is_err());
+    }
+
+    #[test]
+    fn template_options_default_and_round_trip() {
+        let defaults: Metadata = serde_json::from_value(json!({})).unwrap();
+        assert_eq!(defaults.template_options, TemplateOptions::default());
+        assert_eq!(
+            defaults.template_options.photo,
+            PhotoPosition::TemplateDefault
+        );
+
+        let metadata: Metadata = serde_json::from_value(json!({
+            "templateOptions": { "photo": "right", "headerAlign": "center", "divider": "none" }
+        }))
+        .unwrap();
+        assert_eq!(
+            metadata.template_options,
+            TemplateOptions {
+                photo: PhotoPosition::Right,
+                header_align: HeaderAlign::Center,
+                divider: DividerStyle::None,
+                ..Default::default()
+            }
+        );
+
+        let json = serde_json::to_value(&metadata).unwrap();
+        assert_eq!(json["templateOptions"]["headerAlign"], "center");
+        assert_eq!(json["templateOptions"]["divider"], "none");
+    }
+
+    #[test]
+    fn template_options_reject_unknown_values() {
+        let result: Result<Metadata, _> = serde_json::from_value(json!({
+            "templateOptions": { "photo": "bottom" }
+        }));
+        assert!(result.is_err());
+    }
 }
@@ -0,0 +1,429 @@
+//! Personal names split into given and family parts.
+//!
+//! `basics.name` stays the name as the person writes it. The parts in
+//! `basics.nameParts` let a name sort by family name and print in the order
+//! a language expects: family name first for Chinese, Japanese, Korean, and
+//! a few others, given name first elsewhere. Importers fill the parts from
+//! structured fields where a format has them and from [`PersonName::parse`]
+//! otherwise.
+
+use rustume_utils::is_family_name_first;
+use rustume_utils::locale::primary_language;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::TypeScript;
+
+/// Which part of a name is printed first.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default, ToSchema, TypeScript,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum NameOrder {
+    /// Family name first for languages written that way, given name first
+    /// otherwise.
+    #[default]
+    Auto,
+    GivenFirst,
+    FamilyFirst,
+}
+
+impl NameOrder {
+    /// Whether the family name comes first for a resume in `locale`.
+    pub fn family_first(self, locale: &str) -> bool {
+        match self {
+            Self::Auto => is_family_name_first(locale),
+            Self::GivenFirst => false,
+            Self::FamilyFirst => true,
+        }
+    }
+}
+
+/// A personal name in parts.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default, ToSchema, TypeScript)]
+#[serde(rename_all = "camelCase")]
+pub struct PersonName {
+    /// Honorific before the name, such as `Dr.`.
+    #[serde(default)]
+    pub prefix: String,
+
+    /// Given names, including middle names.
+    #[serde(default)]
+    pub given: String,
+
+    /// Family name, including particles such as `van` or `de la`.
+    #[serde(default)]
+    pub family: String,
+
+    /// Generational or academic suffix, such as `Jr.` or `PhD`.
+    #[serde(default)]
+    pub suffix: String,
+}
+
+/// Honorifics dropped into `prefix`, compared without a trailing period.
+const PREFIXES: &[&str] = &[
+    "dr", "prof", "mr", "mrs", "ms", "mx", "miss", "sir", "dame", "rev", "hon",
+];
+
+/// Suffixes dropped into `suffix`, compared without periods.
+const SUFFIXES: &[&str] = &[
+    "jr", "sr", "ii", "iii", "iv", "phd", "md", "mba", "cpa", "esq", "dds", "msc", "bsc",
+];
+
+/// Lowercase words that belong to the family name that follows them.
+const PARTICLES: &[&str] = &[
+    "van", "von", "der", "den", "de", "del", "della", "da", "das", "dos", "du", "di", "la", "le",
+    "ter", "ten", "bin", "binti", "ibn", "al", "zu",
+];
+
+/// Chinese family names two characters long.
+const CHINESE_COMPOUND_FAMILIES: &[&str] = &[
+    "欧阳", "歐陽", "司马", "司馬", "诸葛", "諸葛", "上官", "皇甫", "司徒", "夏侯", "东方", "東方",
+    "尉迟", "尉遲", "慕容", "令狐", "公孙", "公孫", "长孙", "長孫", "端木", "独孤", "獨孤", "南宫",
+    "南宮", "宇文", "轩辕", "軒轅",
+];
+
+/// Korean family names two syllables long.
+const KOREAN_COMPOUND_FAMILIES: &[&str] = &["남궁", "황보", "제갈", "선우", "독고", "사공", "서문"];
+
+impl PersonName {
+    /// Create a name from its given and family parts.
+    pub fn new(given: impl Into<String>, family: impl Into<String>) -> Self {
+        Self {
+            given: given.into(),
+            family: family.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Whether no part is set.
+    pub fn is_empty(&self) -> bool {
+        self.prefix.is_empty()
+            && self.given.is_empty()
+            && self.family.is_empty()
+            && self.suffix.is_empty()
+    }
+
+    /// Guess the parts of a full name written as one string.
+    ///
+    /// Honorifics and suffixes are split off first, then:
+    /// - `Doe, Jane` is family name first;
+    /// - names in Chinese, Japanese, or Korean script, and names in a
+    ///   family-name-first `locale`, start with the family name. Without
+    ///   spaces, the family name is one character (two for compound family
+    ///   names), or about half the name in Japanese;
+    /// - a run of capitalized words at either end (`DUPONT Jean`) is the
+    ///   family name, as French and many CVs write it;
+    /// - otherwise the last word is the family name, with any particles
+    ///   before it (`Ludwig van Beethoven`).
+    ///
+    /// A single word is taken as a given name.
+    pub fn parse(name: &str, locale: &str) -> Self {
+        let mut rest = name.trim();
+        let mut suffixes = Vec::new();
+        while let Some((head, tail)) = rest.rsplit_once(',') {
+            if !is_suffix(tail.trim()) {
+                break;
+            }
+            suffixes.push(tail.trim());
+            rest = head.trim_end();
+        }
+
+        let mut words: Vec<&str> = rest.split_whitespace().collect();
+        while words.len() > 1 && words.last().is_some_and(|word| is_suffix(word)) {
+            suffixes.extend(words.pop());
+        }
+        let mut prefixes = Vec::new();
+        while words.len() > 1 && is_prefix(words[0]) {
+            prefixes.push(words.remove(0));
+        }
+        suffixes.reverse();
+
+        let mut parsed = Self {
+            prefix: prefixes.join(" "),
+            suffix: suffixes.join(", "),
+            ..Default::default()
+        };
+        let (given, family) = split_words(&words, locale);
+        parsed.given = given;
+        parsed.family = family;
+        parsed
+    }
+
+    /// The name in display order, with the prefix and suffix around it.
+    pub fn display(&self, order: NameOrder, locale: &str) -> String {
+        let given = self.given.trim();
+        let family = self.family.trim();
+        let name = if order.family_first(locale) {
+            // Chinese and Korean names run together; Japanese ones keep a space
+            let separator = if is_cjk_text(family)
+                && is_cjk_text(given)
+                && !primary_language(locale).eq_ignore_ascii_case("ja")
+                && !family.chars().chain(given.chars()).any(is_kana)
+            {
+                ""
+            } else {
+                " "
+            };
+            join(&[family, given], separator)
+        } else {
+            join(&[given, family], " ")
+        };
+        let name = join(&[self.prefix.trim(), &name], " ");
+        join(&[&name, self.suffix.trim()], ", ")
+    }
+
+    /// Key for sorting people alphabetically: `family, given`, lowercased.
+    pub fn sort_key(&self) -> String {
+        join(&[self.family.trim(), self.given.trim()], ", ").to_lowercase()
+    }
+
+    /// Whether the parts spell out `name`, ignoring order, case, spaces,
+    /// and punctuation. Parts that don't were set for an earlier `name`.
+    pub fn matches(&self, name: &str) -> bool {
+        let mut parts = letters(&format!(
+            "{} {} {} {}",
+            self.prefix, self.given, self.family, self.suffix
+        ));
+        let mut written = letters(name);
+        parts.sort_unstable();
+        written.sort_unstable();
+        !parts.is_empty() && parts == written
+    }
+}
+
+/// Split the words of a name, without honorifics or suffixes, into given
+/// and family name.
+fn split_words(words: &[&str], locale: &str) -> (String, String) {
+    let joined = words.join(" ");
+    if let Some((family, given)) = joined.split_once(',') {
+        let (family, given) = (family.trim(), given.trim());
+        if !family.is_empty() && !given.is_empty() {
+            return (given.to_string(), family.to_string());
+        }
+    }
+
+    match words {
+        [] => (String::new(), String::new()),
+        [word] => split_unspaced(word, locale)
+            .map(|(family, given)| (given.to_string(), family.to_string()))
+            .unwrap_or_else(|| (word.to_string(), String::new())),
+        _ => split_spaced(words, locale),
+    }
+}
+
+/// Given and family name of a name of two or more words.
+fn split_spaced(words: &[&str], locale: &str) -> (String, String) {
+    let caps: Vec<bool> = words.iter().map(|word| is_capitalized(word)).collect();
+    let leading = caps.iter().take_while(|&&caps| caps).count();
+    let trailing = caps.iter().rev().take_while(|&&caps| caps).count();
+    let mixed = leading < words.len();
+
+    // Leading capitals must be words, not initials like `JD`
+    let family_words =
+        if mixed && leading > 0 && words[..leading].iter().all(|word| word.chars().count() > 2) {
+            Some(leading)
+        } else if is_family_name_first(locale) || words.iter().any(|word| is_cjk_text(word)) {
+            Some(1)
+        } else {
+            None
+        };
+    if let Some(count) = family_words {
+        return (words[count..].join(" "), words[..count].join(" "));
+    }
+
+    let mut start = if mixed && trailing > 0 {
+        words.len() - trailing
+    } else {
+        words.len() - 1
+    };
+    while start > 1 && PARTICLES.contains(&words[start - 1]) {
+        start -= 1;
+    }
+    (words[..start].join(" "), words[start..].join(" "))
+}
+
+/// Family and given name of a name written in Chinese, Japanese, or Korean
+/// script without a space.
+fn split_unspaced<'a>(word: &'a str, locale: &str) -> Option<(&'a str, &'a str)> {
+    let length = word.chars().count();
+    if length < 2 || !is_cjk_text(word) {
+        return None;
+    }
+    let compound = |families: &[&str]| families.iter().any(|family| word.starts_with(family));
+    let family_chars = if word.chars().any(is_hangul) {
+        if compound(KOREAN_COMPOUND_FAMILIES) && length > 2 {
+            2
+        } else {
+            1
+        }
+    } else if word.chars().any(is_kana) || primary_language(locale).eq_ignore_ascii_case("ja") {
+        length.div_ceil(2).min(length - 1)
+    } else if compound(CHINESE_COMPOUND_FAMILIES) && length > 2 {
+        2
+    } else {
+        1
+    };
+    let split = word.char_indices().nth(family_chars)?.0;
+    Some(word.split_at(split))
+}
+
+fn is_prefix(word: &str) -> bool {
+    PREFIXES.contains(&word.trim_end_matches('.').to_lowercase().as_str())
+}
+
+fn is_suffix(word: &str) -> bool {
+    SUFFIXES.contains(&word.replace('.', "").to_lowercase().as_str())
+}
+
+/// Whether a word is written in capitals, such as `DUPONT`. Single letters
+/// are initials.
+fn is_capitalized(word: &str) -> bool {
+    let letters: Vec<char> = word.chars().filter(|c| c.is_alphabetic()).collect();
+    letters.len() > 1 && letters.iter().all(|c| c.is_uppercase()) && !word.ends_with('.')
+}
+
+/// Whether every letter of `text` is Chinese, Japanese, or Korean script.
+fn is_cjk_text(text: &str) -> bool {
+    let mut letters = text.chars().filter(|c| c.is_alphabetic()).peekable();
+    letters.peek().is_some() && letters.all(|c| is_han(c) || is_kana(c) || is_hangul(c))
+}
+
+fn is_han(c: char) -> bool {
+    matches!(c, '\u{3400}'..='\u{4DBF}' | '\u{4E00}'..='\u{9FFF}' | '\u{F900}'..='\u{FAFF}' | '\u{20000}'..='\u{2FFFF}' | '々')
+}
+
+fn is_kana(c: char) -> bool {
+    matches!(c, '\u{3040}'..='\u{30FF}' | '\u{31F0}'..='\u{31FF}')
+}
+
+fn is_hangul(c: char) -> bool {
+    matches!(c, '\u{1100}'..='\u{11FF}' | '\u{3130}'..='\u{318F}' | '\u{AC00}'..='\u{D7AF}')
+}
+
+/// Lowercased letters and digits of `text`.
+fn letters(text: &str) -> Vec<char> {
+    text.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Join the non-empty parts with `separator`.
+fn join(parts: &[&str], separator: &str) -> String {
+    parts
+        .iter()
+        .filter(|part| !part.is_empty())
+        .copied()
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parts(name: &str, locale: &str) -> (String, String) {
+        let parsed = PersonName::parse(name, locale);
+        (parsed.given, parsed.family)
+    }
+
+    fn pair(given: &str, family: &str) -> (String, String) {
+        (given.to_string(), family.to_string())
+    }
+
+    #[test]
+    fn test_parse_given_first() {
+        assert_eq!(parts("Ada Lovelace", "en"), pair("Ada", "Lovelace"));
+        assert_eq!(parts("Jane Q. Public", "en"), pair("Jane Q.", "Public"));
+        assert_eq!(
+            parts("Ludwig van Beethoven", "de"),
+            pair("Ludwig", "van Beethoven")
+        );
+        assert_eq!(
+            parts("Juan Carlos de la Cruz", "es"),
+            pair("Juan Carlos", "de la Cruz")
+        );
+        assert_eq!(parts("Madonna", "en"), pair("Madonna", ""));
+        assert_eq!(parts("  ", "en"), pair("", ""));
+    }
+
+    #[test]
+    fn test_parse_honorifics_and_suffixes() {
+        let parsed = PersonName::parse("Dr. Martin Luther King, Jr.", "en");
+        assert_eq!(parsed.prefix, "Dr.");
+        assert_eq!(parsed.given, "Martin Luther");
+        assert_eq!(parsed.family, "King");
+        assert_eq!(parsed.suffix, "Jr.");
+
+        let parsed = PersonName::parse("Grace Hopper PhD", "en");
+        assert_eq!(
+            (parsed.given.as_str(), parsed.suffix.as_str()),
+            ("Grace", "PhD")
+        );
+    }
+
+    #[test]
+    fn test_parse_family_first() {
+        assert_eq!(parts("Lovelace, Ada", "en"), pair("Ada", "Lovelace"));
+        assert_eq!(parts("DUPONT Jean", "fr"), pair("Jean", "DUPONT"));
+        assert_eq!(
+            parts("Jean DE LA FONTAINE", "fr"),
+            pair("Jean", "DE LA FONTAINE")
+        );
+        assert_eq!(parts("JD Salinger", "en"), pair("JD", "Salinger"));
+        assert_eq!(parts("Kovács János", "hu"), pair("János", "Kovács"));
+        assert_eq!(parts("山田 太郎", "en"), pair("太郎", "山田"));
+    }
+
+    #[test]
+    fn test_parse_unspaced_cjk() {
+        assert_eq!(parts("王小明", "zh-CN"), pair("小明", "王"));
+        assert_eq!(parts("欧阳修", "zh"), pair("修", "欧阳"));
+        assert_eq!(parts("김민준", "ko"), pair("민준", "김"));
+        assert_eq!(parts("남궁민", "ko"), pair("민", "남궁"));
+        assert_eq!(parts("山田太郎", "ja"), pair("太郎", "山田"));
+        assert_eq!(parts("佐々木一郎", "ja"), pair("一郎", "佐々木"));
+    }
+
+    #[test]
+    fn test_display_order() {
+        let name = PersonName::new("Taro", "Yamada");
+        assert_eq!(name.display(NameOrder::Auto, "en"), "Taro Yamada");
+        assert_eq!(name.display(NameOrder::Auto, "ja-JP"), "Yamada Taro");
+        assert_eq!(name.display(NameOrder::GivenFirst, "ja"), "Taro Yamada");
+
+        assert_eq!(
+            PersonName::new("小明", "王").display(NameOrder::Auto, "zh"),
+            "王小明"
+        );
+        assert_eq!(
+            PersonName::new("太郎", "山田").display(NameOrder::Auto, "ja"),
+            "山田 太郎"
+        );
+
+        let parsed = PersonName::parse("Dr. Martin Luther King, Jr.", "en");
+        assert_eq!(
+            parsed.display(NameOrder::Auto, "en"),
+            "Dr. Martin Luther King, Jr."
+        );
+    }
+
+    #[test]
+    fn test_sort_key_and_matches() {
+        let mut names = [
+            PersonName::new("Grace", "Hopper"),
+            PersonName::new("Ada", "Lovelace"),
+            PersonName::new("Charles", "Babbage"),
+        ];
+        names.sort_by_key(PersonName::sort_key);
+        assert_eq!(names[0].sort_key(), "babbage, charles");
+        assert_eq!(names[2].family, "Lovelace");
+
+        let name = PersonName::new("Jane", "Doe");
+        assert!(name.matches("Doe, Jane"));
+        assert!(name.matches("jane  DOE"));
+        assert!(!name.matches("Jane Smith"));
+        assert!(!PersonName::default().matches(""));
+    }
+}
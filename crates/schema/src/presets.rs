@@ -0,0 +1,310 @@
+//! Named section orderings for common resume styles.
+//!
+//! A preset replaces `metadata.layout` with a two-column order (main column
+//! first, then the sidebar) and shows or hides a few sections, so it works
+//! with every template. Items and custom sections are left alone: custom
+//! sections keep their relative order at the end of the main column.
+
+use std::collections::HashSet;
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::TypeScript;
+use crate::{Layout, ResumeData, Sections, CUSTOM_SECTIONS_SLOT};
+
+/// A curated section order and visibility set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema, TypeScript)]
+#[serde(rename_all = "snake_case")]
+pub enum LayoutPreset {
+    /// Work history first, newest role on top. Suits a steady career in one
+    /// field.
+    Chronological,
+    /// Skills and projects first, work history after. Suits career changers
+    /// and people returning to work.
+    Functional,
+    /// A skills summary, then work history. Suits most experienced
+    /// candidates.
+    Hybrid,
+    /// Education and publications first, with references. Suits academic
+    /// and research positions.
+    Academic,
+}
+
+/// Where a preset puts each built-in section and which it shows or hides.
+struct PresetSpec {
+    main: &'static [&'static str],
+    sidebar: &'static [&'static str],
+    show: &'static [&'static str],
+    hide: &'static [&'static str],
+}
+
+impl LayoutPreset {
+    /// All presets.
+    pub const ALL: [LayoutPreset; 4] = [
+        Self::Chronological,
+        Self::Functional,
+        Self::Hybrid,
+        Self::Academic,
+    ];
+
+    /// Wire name (`chronological`, `functional`, ...).
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Chronological => "chronological",
+            Self::Functional => "functional",
+            Self::Hybrid => "hybrid",
+            Self::Academic => "academic",
+        }
+    }
+
+    fn spec(self) -> PresetSpec {
+        match self {
+            Self::Chronological => PresetSpec {
+                main: &[
+                    "coverLetter",
+                    "profiles",
+                    "summary",
+                    "experience",
+                    "education",
+                    "projects",
+                    "volunteer",
+                    "references",
+                ],
+                sidebar: &[
+                    "skills",
+                    "certifications",
+                    "awards",
+                    "languages",
+                    "publications",
+                    "interests",
+                ],
+                show: &["experience", "education", "skills"],
+                hide: &["references"],
+            },
+            Self::Functional => PresetSpec {
+                main: &[
+                    "coverLetter",
+                    "profiles",
+                    "summary",
+                    "skills",
+                    "projects",
+                    "awards",
+                    "experience",
+                    "education",
+                    "volunteer",
+                    "references",
+                ],
+                sidebar: &["certifications", "languages", "publications", "interests"],
+                show: &["skills", "projects", "awards", "volunteer"],
+                hide: &["references"],
+            },
+            Self::Hybrid => PresetSpec {
+                main: &[
+                    "coverLetter",
+                    "profiles",
+                    "summary",
+                    "skills",
+                    "experience",
+                    "projects",
+                    "education",
+                    "volunteer",
+                    "references",
+                ],
+                sidebar: &[
+                    "certifications",
+                    "awards",
+                    "languages",
+                    "publications",
+                    "interests",
+                ],
+                show: &[
+                    "skills",
+                    "experience",
+                    "projects",
+                    "education",
+                    "certifications",
+                ],
+                hide: &["references"],
+            },
+            Self::Academic => PresetSpec {
+                main: &[
+                    "coverLetter",
+                    "profiles",
+                    "summary",
+                    "education",
+                    "publications",
+                    "experience",
+                    "awards",
+                    "projects",
+                    "volunteer",
+                    "references",
+                ],
+                sidebar: &["skills", "certifications", "languages", "interests"],
+                show: &[
+                    "education",
+                    "publications",
+                    "experience",
+                    "awards",
+                    "references",
+                ],
+                hide: &["interests"],
+            },
+        }
+    }
+}
+
+impl fmt::Display for LayoutPreset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for LayoutPreset {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|preset| preset.as_str().eq_ignore_ascii_case(s.trim()))
+            .ok_or_else(|| format!("Unknown layout preset: {s}"))
+    }
+}
+
+/// Reorder and show or hide the sections of `resume` as `preset` lays out.
+///
+/// The layout becomes one page of two columns. Custom sections stay in the
+/// order the current layout has them, after the preset's main sections;
+/// ones it doesn't place follow in ID order. Sections the preset neither
+/// shows nor hides keep their visibility.
+pub fn apply_preset(resume: &mut ResumeData, preset: LayoutPreset) {
+    let spec = preset.spec();
+    let column = |keys: &[&str]| keys.iter().map(|key| key.to_string()).collect::<Vec<_>>();
+
+    let mut main = column(spec.main);
+    main.extend(custom_keys(resume));
+    resume.metadata.layout = Layout::from(vec![vec![main, column(spec.sidebar)]]);
+
+    for key in spec.show {
+        set_visible(&mut resume.sections, key, true);
+    }
+    for key in spec.hide {
+        set_visible(&mut resume.sections, key, false);
+    }
+}
+
+/// Custom section IDs and the [`CUSTOM_SECTIONS_SLOT`] as the layout
+/// places them, then unplaced custom sections in ID order.
+fn custom_keys(resume: &ResumeData) -> Vec<String> {
+    let custom = &resume.sections.custom;
+    let mut keys: Vec<String> = resume
+        .metadata
+        .layout
+        .sections()
+        .map(|(_, key)| key)
+        .filter(|key| *key == CUSTOM_SECTIONS_SLOT || custom.contains_key(*key))
+        .map(str::to_string)
+        .collect();
+    let mut pending: HashSet<String> = keys.iter().cloned().collect();
+    keys.retain(|key| pending.remove(key));
+
+    let has_slot = keys.iter().any(|key| key == CUSTOM_SECTIONS_SLOT);
+    let mut unplaced: Vec<&String> = custom
+        .keys()
+        .filter(|id| !has_slot && !keys.contains(id))
+        .collect();
+    unplaced.sort_unstable();
+    keys.extend(unplaced.into_iter().cloned());
+    keys
+}
+
+fn set_visible(sections: &mut Sections, key: &str, visible: bool) {
+    match key {
+        "summary" => sections.summary.visible = visible,
+        "coverLetter" => sections.cover_letter.visible = visible,
+        "experience" => sections.experience.visible = visible,
+        "education" => sections.education.visible = visible,
+        "skills" => sections.skills.visible = visible,
+        "projects" => sections.projects.visible = visible,
+        "profiles" => sections.profiles.visible = visible,
+        "awards" => sections.awards.visible = visible,
+        "certifications" => sections.certifications.visible = visible,
+        "publications" => sections.publications.visible = visible,
+        "languages" => sections.languages.visible = visible,
+        "interests" => sections.interests.visible = visible,
+        "volunteer" => sections.volunteer.visible = visible,
+        "references" => sections.references.visible = visible,
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CustomItem, Section, BUILT_IN_SECTIONS};
+
+    #[test]
+    fn test_every_preset_places_each_built_in_section_once() {
+        for preset in LayoutPreset::ALL {
+            let mut resume = ResumeData::default();
+            apply_preset(&mut resume, preset);
+            let mut placed: Vec<&str> = resume
+                .metadata
+                .layout
+                .sections()
+                .map(|(_, key)| key)
+                .collect();
+            placed.sort_unstable();
+            let mut expected = BUILT_IN_SECTIONS.to_vec();
+            expected.sort_unstable();
+            assert_eq!(placed, expected, "{preset}");
+            assert!(resume.metadata.layout.check(&resume.sections).is_empty());
+        }
+    }
+
+    #[test]
+    fn test_academic_preset_orders_and_shows_sections() {
+        let mut resume = ResumeData::default();
+        apply_preset(&mut resume, LayoutPreset::Academic);
+
+        let main = &resume.metadata.layout.pages()[0][0];
+        assert_eq!(&main[3..5], ["education", "publications"]);
+        assert!(resume.sections.publications.visible);
+        assert!(resume.sections.references.visible);
+        assert!(!resume.sections.interests.visible);
+        // Not mentioned by the preset, so left as it was
+        assert!(!resume.sections.languages.visible);
+    }
+
+    #[test]
+    fn test_preset_keeps_custom_section_order() {
+        let mut resume = ResumeData::default();
+        for id in ["talks", "patents", "press"] {
+            resume
+                .sections
+                .custom
+                .insert(id.to_string(), Section::<CustomItem>::new(id, id));
+        }
+        resume.metadata.layout = Layout::from(vec![vec![vec![
+            "patents".to_string(),
+            "summary".to_string(),
+            "talks".to_string(),
+        ]]]);
+
+        apply_preset(&mut resume, LayoutPreset::Functional);
+        let main = &resume.metadata.layout.pages()[0][0];
+        assert_eq!(&main[main.len() - 3..], ["patents", "talks", "press"]);
+        assert!(resume.metadata.layout.check(&resume.sections).is_empty());
+    }
+
+    #[test]
+    fn test_preset_names_round_trip() {
+        for preset in LayoutPreset::ALL {
+            assert_eq!(preset.as_str().parse::<LayoutPreset>(), Ok(preset));
+        }
+        assert_eq!("Hybrid".parse::<LayoutPreset>(), Ok(LayoutPreset::Hybrid));
+        assert!("modern".parse::<LayoutPreset>().is_err());
+    }
+}
@@ -0,0 +1,344 @@
+//! Structural repair for resumes from imports and old files.
+//!
+//! Editors address items by ID and templates place sections by layout key,
+//! so a repeated or blank item ID or a layout entry naming no section breaks
+//! editing and rendering even when every field is fine on its own.
+//! [`ResumeData::repair`] fixes these in place, and [`repair_json`] also
+//! reads files holding option values this version doesn't know. Every change
+//! is reported; problems with no safe fix, such as reversed dates, are left
+//! for the user and listed as unresolved.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use serde_path_to_error::{Path, Segment};
+use utoipa::ToSchema;
+
+use crate::dedupe::{item_sections, section_items_mut, text};
+use crate::{LayoutError, ResumeData, TypeScript, ValidationIssue, SECTION_DISPLAY_KEYS};
+
+/// Most unknown values [`repair_json`] replaces before giving up on a file.
+const MAX_VALUE_FIXES: usize = 64;
+
+/// One change made by a repair.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema, TypeScript)]
+pub struct RepairFix {
+    /// Dotted path of the changed field.
+    #[schema(example = "sections.skills.items[3].id")]
+    pub path: String,
+
+    /// Machine-readable kind of problem: `empty_id`, `duplicate_id`,
+    /// `unknown_layout_section`, `duplicate_layout_section`,
+    /// `invalid_section_display`, or `invalid_value`.
+    #[schema(example = "duplicate_id")]
+    pub code: String,
+
+    /// What was wrong and what changed.
+    pub message: String,
+}
+
+impl RepairFix {
+    fn new(path: impl Into<String>, code: &str, message: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            code: code.to_string(),
+            message: message.into(),
+        }
+    }
+}
+
+/// Everything a repair changed, and what it couldn't.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, ToSchema, TypeScript)]
+pub struct RepairReport {
+    /// Changes made, in the order they were applied.
+    pub fixes: Vec<RepairFix>,
+
+    /// Rule errors left after repairing, which need a person to decide.
+    pub unresolved: Vec<ValidationIssue>,
+}
+
+impl RepairReport {
+    /// Whether the resume needed no changes and has no errors left.
+    pub fn is_clean(&self) -> bool {
+        self.fixes.is_empty() && self.unresolved.is_empty()
+    }
+}
+
+/// A repaired resume and its report.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, TypeScript)]
+pub struct RepairedResume {
+    pub resume: ResumeData,
+    pub report: RepairReport,
+}
+
+impl ResumeData {
+    /// Fix structural problems in place: blank or repeated item IDs get a
+    /// fresh ID (the first use of an ID keeps it), unknown and repeated
+    /// layout keys are dropped, and chart styles for sections without
+    /// charts are removed.
+    ///
+    /// Sections missing from the layout are left out, since hiding a
+    /// section that way is a choice.
+    pub fn repair(&mut self) -> RepairReport {
+        let mut fixes = Vec::new();
+        repair_item_ids(self, &mut fixes);
+        repair_layout(self, &mut fixes);
+        repair_section_display(self, &mut fixes);
+        RepairReport {
+            fixes,
+            unresolved: self.check_rules().errors,
+        }
+    }
+}
+
+/// Read resume JSON that may not deserialize as written, then
+/// [`ResumeData::repair`] it.
+///
+/// An option this version doesn't know (a `metadata.templateOptions.photo`
+/// of `"floating"`, say) is replaced with its default. Any other mismatch,
+/// such as text where a list belongs, is returned as an error: guessing at
+/// content could lose it.
+pub fn repair_json(mut value: Value) -> Result<RepairedResume, serde_json::Error> {
+    let mut fixes = Vec::new();
+    let mut resume = loop {
+        let err = match serde_path_to_error::deserialize::<_, ResumeData>(&value) {
+            Ok(resume) => break resume,
+            Err(err) => err,
+        };
+        if fixes.len() >= MAX_VALUE_FIXES || !err.inner().to_string().starts_with("unknown variant")
+        {
+            return Err(err.into_inner());
+        }
+        let path = err.path().to_string();
+        let Some(old) = remove_at(&mut value, err.path()) else {
+            return Err(err.into_inner());
+        };
+        fixes.push(RepairFix::new(
+            path,
+            "invalid_value",
+            format!("Replaced unknown value {old} with the default"),
+        ));
+    };
+
+    let mut report = resume.repair();
+    fixes.append(&mut report.fixes);
+    report.fixes = fixes;
+    Ok(RepairedResume { resume, report })
+}
+
+/// Remove the object member at `path`, so its default applies.
+fn remove_at(value: &mut Value, path: &Path) -> Option<Value> {
+    let segments: Vec<&Segment> = path.iter().collect();
+    let (last, parents) = segments.split_last()?;
+    let mut current = value;
+    for segment in parents {
+        current = match segment {
+            Segment::Seq { index } => current.get_mut(*index)?,
+            Segment::Map { key } => current.get_mut(key.as_str())?,
+            _ => return None,
+        };
+    }
+    match last {
+        Segment::Map { key } => current.as_object_mut()?.remove(key),
+        _ => None,
+    }
+}
+
+fn repair_item_ids(resume: &mut ResumeData, fixes: &mut Vec<RepairFix>) {
+    let Ok(mut sections) = serde_json::to_value(&resume.sections) else {
+        return;
+    };
+    let keys: Vec<String> = item_sections(&sections)
+        .into_iter()
+        .map(|(key, _, _)| key)
+        .collect();
+
+    let fixed = fixes.len();
+    let mut first_seen: HashMap<String, String> = HashMap::new();
+    for key in keys {
+        let Some(items) = section_items_mut(&mut sections, &key) else {
+            continue;
+        };
+        for (index, item) in items.iter_mut().enumerate() {
+            let path = format!("sections.{key}.items[{index}].id");
+            let id = text(item, "id").to_string();
+            let (code, message) = if id.trim().is_empty() {
+                ("empty_id", "Item had no ID; gave it a new one".to_string())
+            } else if let Some(first) = first_seen.get(&id) {
+                (
+                    "duplicate_id",
+                    format!("ID \"{id}\" is already used by {first}; gave this item a new one"),
+                )
+            } else {
+                first_seen.insert(id, path);
+                continue;
+            };
+
+            let new_id = cuid2::create_id();
+            item["id"] = Value::String(new_id.clone());
+            first_seen.insert(new_id, path.clone());
+            fixes.push(RepairFix::new(path, code, message));
+        }
+    }
+
+    if fixes.len() > fixed {
+        if let Ok(repaired) = serde_json::from_value(sections) {
+            resume.sections = repaired;
+        }
+    }
+}
+
+fn repair_layout(resume: &mut ResumeData, fixes: &mut Vec<RepairFix>) {
+    let errors = resume.metadata.layout.remove_invalid(&resume.sections);
+    fixes.extend(errors.into_iter().filter_map(|error| {
+        let (code, message) = match error {
+            LayoutError::UnknownSection(key) => (
+                "unknown_layout_section",
+                format!("Removed \"{key}\", which names no section"),
+            ),
+            LayoutError::DuplicateSection(key) => (
+                "duplicate_layout_section",
+                format!("Removed repeats of \"{key}\" after its first place"),
+            ),
+            LayoutError::SectionNotFound(_) => return None,
+        };
+        Some(RepairFix::new("metadata.layout", code, message))
+    }));
+}
+
+fn repair_section_display(resume: &mut ResumeData, fixes: &mut Vec<RepairFix>) {
+    let display = &mut resume.metadata.section_display;
+    let mut invalid: Vec<String> = display
+        .keys()
+        .filter(|key| !SECTION_DISPLAY_KEYS.contains(&key.as_str()))
+        .cloned()
+        .collect();
+    invalid.sort_unstable();
+    for key in invalid {
+        display.remove(&key);
+        fixes.push(RepairFix::new(
+            format!("metadata.sectionDisplay.{key}"),
+            "invalid_section_display",
+            "Removed a chart style for a section that has no charts",
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Experience, Section, SectionDisplay, Skill};
+    use serde_json::json;
+
+    fn codes(report: &RepairReport) -> Vec<&str> {
+        report.fixes.iter().map(|fix| fix.code.as_str()).collect()
+    }
+
+    #[test]
+    fn test_repair_gives_blank_and_repeated_ids_new_ones() {
+        let mut resume = ResumeData::default();
+        resume.sections.experience = Section::new("experience", "Experience");
+        let mut first = Experience::new("Acme", "Engineer");
+        first.id = "shared".to_string();
+        let mut second = Experience::new("Initech", "Manager");
+        second.id = String::new();
+        resume.sections.experience.add_item(first);
+        resume.sections.experience.add_item(second);
+        let mut skill = Skill::new("Rust");
+        skill.id = "shared".to_string();
+        resume.sections.skills.add_item(skill);
+
+        let report = resume.repair();
+        assert_eq!(codes(&report), ["empty_id", "duplicate_id"]);
+        assert_eq!(report.fixes[1].path, "sections.skills.items[0].id");
+        assert!(report.fixes[1]
+            .message
+            .contains("sections.experience.items[0].id"));
+        assert!(report.unresolved.is_empty());
+
+        let experience = &resume.sections.experience.items;
+        assert_eq!(experience[0].id, "shared");
+        assert!(!experience[1].id.is_empty());
+        assert_ne!(resume.sections.skills.items[0].id, "shared");
+        assert!(resume.repair().is_clean());
+    }
+
+    #[test]
+    fn test_repair_cleans_layout_and_section_display() {
+        let mut resume = ResumeData::default();
+        resume.metadata.layout = vec![vec![vec![
+            "summary".to_string(),
+            "hobbies".to_string(),
+            "summary".to_string(),
+        ]]]
+        .into();
+        resume
+            .metadata
+            .section_display
+            .insert("experience".to_string(), SectionDisplay::default());
+
+        let report = resume.repair();
+        assert_eq!(
+            codes(&report),
+            [
+                "unknown_layout_section",
+                "duplicate_layout_section",
+                "invalid_section_display"
+            ]
+        );
+        assert_eq!(resume.metadata.layout.pages(), [vec![vec!["summary"]]]);
+        assert!(resume.metadata.section_display.is_empty());
+        assert!(resume.check_rules().is_valid());
+    }
+
+    #[test]
+    fn test_repair_leaves_unsafe_problems_unresolved() {
+        let mut resume = ResumeData::default();
+        resume.sections.experience.add_item(
+            Experience::new("Acme", "Engineer").with_dates("2022-01", Some("2020".into())),
+        );
+
+        let report = resume.repair();
+        assert!(report.fixes.is_empty());
+        assert_eq!(report.unresolved.len(), 1);
+        assert!(!report.is_clean());
+    }
+
+    #[test]
+    fn test_repair_json_replaces_unknown_option_values() {
+        let mut value = serde_json::to_value(ResumeData::default()).unwrap();
+        value["metadata"]["templateOptions"]["photo"] = json!("floating");
+        value["metadata"]["page"]["options"]["runningHeader"] = json!("sidebar");
+
+        let repaired = repair_json(value).unwrap();
+        let paths: Vec<&str> = repaired
+            .report
+            .fixes
+            .iter()
+            .map(|fix| fix.path.as_str())
+            .collect();
+        assert_eq!(
+            paths,
+            [
+                "metadata.page.options.runningHeader",
+                "metadata.templateOptions.photo"
+            ]
+        );
+        assert_eq!(
+            repaired.report.fixes[1].message,
+            "Replaced unknown value \"floating\" with the default"
+        );
+        assert_eq!(
+            repaired.resume.metadata.template_options.photo,
+            Default::default()
+        );
+    }
+
+    #[test]
+    fn test_repair_json_rejects_content_mismatches() {
+        let mut value = serde_json::to_value(ResumeData::default()).unwrap();
+        value["sections"]["skills"]["items"] = json!("Rust, Go");
+        assert!(repair_json(value).is_err());
+    }
+}
@@ -1,14 +1,28 @@
 //! Resume sections.
 
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::borrow::Cow;
 use std::collections::HashMap;
 use utoipa::ToSchema;
 use validator::Validate;
 
+use rustume_schema_macros::SectionItem;
+use rustume_utils::{
+    display_date_range_in, extract_year, find_scholarly_id, format_citation, html_text_blocks,
+    is_empty_string, iso_date_range, parse_date_range, parse_iso_partial_date, Citation, DateRange,
+    Locale, DEFAULT_LOCALE,
+};
+
+use crate::legacy::{deserialize_items, upgrade_section_items};
 use crate::shared::Url;
+use crate::validate_optional_partial_date;
+use crate::CefrLevel;
+use crate::TypeScript;
+use crate::{FieldDeprecation, LegacyFields};
 
 /// All resume sections.
-#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema, TypeScript)]
 pub struct Sections {
     #[validate(nested)]
     #[serde(default)]
@@ -86,6 +100,34 @@ fn validate_custom_sections(
     Ok(())
 }
 
+impl LegacyFields for Sections {
+    const ITEM: &'static str = "Sections";
+    const FIELD_ALIASES: &'static [(&'static str, &'static str)] = &[];
+
+    fn upgrade_legacy_fields(value: &mut Value, deprecations: &mut Vec<FieldDeprecation>) {
+        let Some(sections) = value.as_object_mut() else {
+            return;
+        };
+        upgrade_section_items::<Experience>(sections.get_mut("experience"), deprecations);
+        upgrade_section_items::<Education>(sections.get_mut("education"), deprecations);
+        upgrade_section_items::<Skill>(sections.get_mut("skills"), deprecations);
+        upgrade_section_items::<Project>(sections.get_mut("projects"), deprecations);
+        upgrade_section_items::<Profile>(sections.get_mut("profiles"), deprecations);
+        upgrade_section_items::<Award>(sections.get_mut("awards"), deprecations);
+        upgrade_section_items::<Certification>(sections.get_mut("certifications"), deprecations);
+        upgrade_section_items::<Publication>(sections.get_mut("publications"), deprecations);
+        upgrade_section_items::<Language>(sections.get_mut("languages"), deprecations);
+        upgrade_section_items::<Interest>(sections.get_mut("interests"), deprecations);
+        upgrade_section_items::<Volunteer>(sections.get_mut("volunteer"), deprecations);
+        upgrade_section_items::<Reference>(sections.get_mut("references"), deprecations);
+        if let Some(Value::Object(custom)) = sections.get_mut("custom") {
+            for section in custom.values_mut() {
+                upgrade_section_items::<CustomItem>(Some(section), deprecations);
+            }
+        }
+    }
+}
+
 impl Default for Sections {
     fn default() -> Self {
         Self {
@@ -109,9 +151,9 @@ impl Default for Sections {
 }
 
 /// Generic section wrapper.
-#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema, TypeScript)]
 #[serde(rename_all = "camelCase")]
-#[serde(bound(deserialize = "T: serde::de::DeserializeOwned"))]
+#[serde(bound(deserialize = "T: LegacyFields"))]
 pub struct Section<T: Validate> {
     /// Section identifier.
     pub id: String,
@@ -135,7 +177,7 @@ pub struct Section<T: Validate> {
 
     /// Section items.
     #[validate(nested)]
-    #[serde(default)]
+    #[serde(default, deserialize_with = "deserialize_items")]
     pub items: Vec<T>,
 }
 
@@ -223,7 +265,7 @@ impl<T: Default + Validate> Default for Section<T> {
 }
 
 /// Summary section (special - no items, just content).
-#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema, TypeScript)]
 #[serde(rename_all = "camelCase")]
 pub struct SummarySection {
     pub id: String,
@@ -275,7 +317,7 @@ impl SummarySection {
 }
 
 /// Cover letter recipient block.
-#[derive(Debug, Clone, Serialize, Deserialize, Validate, Default, ToSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, Default, ToSchema, TypeScript)]
 #[serde(rename_all = "camelCase")]
 pub struct CoverLetterRecipient {
     #[serde(default)]
@@ -295,7 +337,7 @@ pub struct CoverLetterRecipient {
 }
 
 /// Cover letter section (special - recipient block plus rich-text body).
-#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema, TypeScript)]
 #[serde(rename_all = "camelCase", default)]
 pub struct CoverLetterSection {
     pub id: String,
@@ -327,12 +369,48 @@ impl Default for CoverLetterSection {
     }
 }
 
+/// Range of an item with both a display `date` and structured dates.
+/// Structured dates win when `start` is a valid ISO partial date.
+pub(crate) fn item_date_range(
+    date: &str,
+    start: Option<&str>,
+    end: Option<&str>,
+) -> Option<DateRange> {
+    match start.and_then(parse_iso_partial_date) {
+        Some(start) => Some(DateRange {
+            start,
+            end: end.and_then(parse_iso_partial_date),
+        }),
+        None => parse_date_range(date),
+    }
+}
+
+fn sync_item_dates(
+    date: &mut String,
+    start: &mut Option<String>,
+    end: &mut Option<String>,
+    locale: &Locale,
+) {
+    match start.as_deref().filter(|s| !s.trim().is_empty()) {
+        Some(start) if date.trim().is_empty() => {
+            *date = display_date_range_in(start, end.as_deref(), locale);
+        }
+        Some(_) => {}
+        None => {
+            if let Some((iso_start, iso_end)) = iso_date_range(date) {
+                *start = Some(iso_start);
+                *end = iso_end;
+            }
+        }
+    }
+}
+
 // ============================================================================
 // Section Item Types
 // ============================================================================
 
 /// Work experience item.
-#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema, TypeScript)]
 #[serde(rename_all = "camelCase")]
 pub struct Experience {
     pub id: String,
@@ -345,8 +423,20 @@ pub struct Experience {
     pub location: String,
     #[serde(default)]
     pub date: String,
+    /// Structured start of `date` as an ISO partial date (`2020`, `2020-03`,
+    /// `2020-03-15`).
+    #[validate(custom(function = "validate_optional_partial_date"))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<String>,
+    /// Structured end of `date`; `None` with a `start_date` means ongoing.
+    #[validate(custom(function = "validate_optional_partial_date"))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<String>,
     #[serde(default)]
     pub summary: String,
+    /// Achievements as separate bullets, printed as a list after `summary`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub highlights: Vec<String>,
     #[validate(nested)]
     #[serde(default)]
     pub url: Url,
@@ -361,7 +451,10 @@ impl Default for Experience {
             position: String::new(),
             location: String::new(),
             date: String::new(),
+            start_date: None,
+            end_date: None,
             summary: String::new(),
+            highlights: Vec::new(),
             url: Url::default(),
         }
     }
@@ -391,12 +484,56 @@ impl Experience {
         self
     }
 
+    /// Builder method to set structured start and end dates (ISO partial
+    /// dates; no end means ongoing).
+    pub fn with_dates(mut self, start: impl Into<String>, end: Option<String>) -> Self {
+        self.start_date = Some(start.into());
+        self.end_date = end;
+        self
+    }
+
+    /// Date range from the structured dates, or parsed from `date`.
+    pub fn date_range(&self) -> Option<DateRange> {
+        item_date_range(
+            &self.date,
+            self.start_date.as_deref(),
+            self.end_date.as_deref(),
+        )
+    }
+
+    /// Fill the display `date` from the structured dates, or the structured
+    /// dates from `date`, whichever is missing.
+    pub fn sync_dates(&mut self) {
+        self.sync_dates_in(Locale::for_tag(DEFAULT_LOCALE));
+    }
+
+    /// [`Self::sync_dates`], writing an ongoing end (`Present`) in `locale`.
+    pub fn sync_dates_in(&mut self, locale: &Locale) {
+        sync_item_dates(
+            &mut self.date,
+            &mut self.start_date,
+            &mut self.end_date,
+            locale,
+        );
+    }
+
     /// Builder method to set summary.
     pub fn with_summary(mut self, summary: impl Into<String>) -> Self {
         self.summary = summary.into();
         self
     }
 
+    /// Builder method to set highlights.
+    pub fn with_highlights(mut self, highlights: Vec<String>) -> Self {
+        self.highlights = highlights;
+        self
+    }
+
+    /// `summary` followed by the highlights as a bulleted list, as HTML.
+    pub fn summary_html(&self) -> Cow<'_, str> {
+        summary_with_highlights(&self.summary, &self.highlights)
+    }
+
     /// Builder method to set URL.
     pub fn with_url(mut self, url: impl Into<String>) -> Self {
         self.url = Url::new(url);
@@ -405,7 +542,7 @@ impl Experience {
 }
 
 /// Education item.
-#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema, TypeScript)]
 #[serde(rename_all = "camelCase")]
 pub struct Education {
     pub id: String,
@@ -418,6 +555,15 @@ pub struct Education {
     pub study_type: String,
     #[serde(default)]
     pub date: String,
+    /// Structured start of `date` as an ISO partial date (`2020`, `2020-03`,
+    /// `2020-03-15`).
+    #[validate(custom(function = "validate_optional_partial_date"))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<String>,
+    /// Structured end of `date`; `None` with a `start_date` means ongoing.
+    #[validate(custom(function = "validate_optional_partial_date"))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<String>,
     #[serde(default)]
     pub score: String,
     #[serde(default)]
@@ -436,6 +582,8 @@ impl Default for Education {
             area: String::new(),
             study_type: String::new(),
             date: String::new(),
+            start_date: None,
+            end_date: None,
             score: String::new(),
             summary: String::new(),
             url: Url::default(),
@@ -467,6 +615,39 @@ impl Education {
         self
     }
 
+    /// Builder method to set structured start and end dates (ISO partial
+    /// dates; no end means ongoing).
+    pub fn with_dates(mut self, start: impl Into<String>, end: Option<String>) -> Self {
+        self.start_date = Some(start.into());
+        self.end_date = end;
+        self
+    }
+
+    /// Date range from the structured dates, or parsed from `date`.
+    pub fn date_range(&self) -> Option<DateRange> {
+        item_date_range(
+            &self.date,
+            self.start_date.as_deref(),
+            self.end_date.as_deref(),
+        )
+    }
+
+    /// Fill the display `date` from the structured dates, or the structured
+    /// dates from `date`, whichever is missing.
+    pub fn sync_dates(&mut self) {
+        self.sync_dates_in(Locale::for_tag(DEFAULT_LOCALE));
+    }
+
+    /// [`Self::sync_dates`], writing an ongoing end (`Present`) in `locale`.
+    pub fn sync_dates_in(&mut self, locale: &Locale) {
+        sync_item_dates(
+            &mut self.date,
+            &mut self.start_date,
+            &mut self.end_date,
+            locale,
+        );
+    }
+
     /// Builder method to set score/GPA.
     pub fn with_score(mut self, score: impl Into<String>) -> Self {
         self.score = score.into();
@@ -481,8 +662,9 @@ impl Education {
 }
 
 /// Skill item.
-#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema, TypeScript, SectionItem)]
 #[serde(rename_all = "camelCase")]
+#[section_item(new(name))]
 pub struct Skill {
     pub id: String,
     #[serde(default = "default_true")]
@@ -498,51 +680,8 @@ pub struct Skill {
     pub keywords: Vec<String>,
 }
 
-impl Default for Skill {
-    fn default() -> Self {
-        Self {
-            id: String::new(),
-            visible: true,
-            name: String::new(),
-            description: String::new(),
-            level: 1,
-            keywords: Vec::new(),
-        }
-    }
-}
-
-impl Skill {
-    /// Create a new skill item.
-    pub fn new(name: impl Into<String>) -> Self {
-        Self {
-            id: cuid2::create_id(),
-            visible: true,
-            name: name.into(),
-            ..Default::default()
-        }
-    }
-
-    /// Builder method to set level.
-    pub fn with_level(mut self, level: u8) -> Self {
-        self.level = level.clamp(0, 5);
-        self
-    }
-
-    /// Builder method to set keywords.
-    pub fn with_keywords(mut self, keywords: Vec<String>) -> Self {
-        self.keywords = keywords;
-        self
-    }
-
-    /// Builder method to set description.
-    pub fn with_description(mut self, description: impl Into<String>) -> Self {
-        self.description = description.into();
-        self
-    }
-}
-
 /// Project item.
-#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema, TypeScript)]
 #[serde(rename_all = "camelCase")]
 pub struct Project {
     pub id: String,
@@ -553,8 +692,20 @@ pub struct Project {
     pub description: String,
     #[serde(default)]
     pub date: String,
+    /// Structured start of `date` as an ISO partial date (`2020`, `2020-03`,
+    /// `2020-03-15`).
+    #[validate(custom(function = "validate_optional_partial_date"))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<String>,
+    /// Structured end of `date`; `None` with a `start_date` means ongoing.
+    #[validate(custom(function = "validate_optional_partial_date"))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<String>,
     #[serde(default)]
     pub summary: String,
+    /// Achievements as separate bullets, printed as a list after `summary`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub highlights: Vec<String>,
     #[serde(default)]
     pub keywords: Vec<String>,
     #[validate(nested)]
@@ -570,7 +721,10 @@ impl Default for Project {
             name: String::new(),
             description: String::new(),
             date: String::new(),
+            start_date: None,
+            end_date: None,
             summary: String::new(),
+            highlights: Vec::new(),
             keywords: Vec::new(),
             url: Url::default(),
         }
@@ -600,6 +754,39 @@ impl Project {
         self
     }
 
+    /// Builder method to set structured start and end dates (ISO partial
+    /// dates; no end means ongoing).
+    pub fn with_dates(mut self, start: impl Into<String>, end: Option<String>) -> Self {
+        self.start_date = Some(start.into());
+        self.end_date = end;
+        self
+    }
+
+    /// Date range from the structured dates, or parsed from `date`.
+    pub fn date_range(&self) -> Option<DateRange> {
+        item_date_range(
+            &self.date,
+            self.start_date.as_deref(),
+            self.end_date.as_deref(),
+        )
+    }
+
+    /// Fill the display `date` from the structured dates, or the structured
+    /// dates from `date`, whichever is missing.
+    pub fn sync_dates(&mut self) {
+        self.sync_dates_in(Locale::for_tag(DEFAULT_LOCALE));
+    }
+
+    /// [`Self::sync_dates`], writing an ongoing end (`Present`) in `locale`.
+    pub fn sync_dates_in(&mut self, locale: &Locale) {
+        sync_item_dates(
+            &mut self.date,
+            &mut self.start_date,
+            &mut self.end_date,
+            locale,
+        );
+    }
+
     /// Builder method to set summary.
     pub fn with_summary(mut self, summary: impl Into<String>) -> Self {
         self.summary = summary.into();
@@ -617,10 +804,53 @@ impl Project {
         self.keywords = keywords;
         self
     }
+
+    /// Builder method to set highlights.
+    pub fn with_highlights(mut self, highlights: Vec<String>) -> Self {
+        self.highlights = highlights;
+        self
+    }
+
+    /// `summary` followed by the highlights as a bulleted list, as HTML.
+    pub fn summary_html(&self) -> Cow<'_, str> {
+        summary_with_highlights(&self.summary, &self.highlights)
+    }
+}
+
+/// Rich-text `summary` with a `<ul>` of the non-blank `highlights` after it.
+/// Highlights are plain text, so they are escaped. A summary that doesn't
+/// end in a block is wrapped in a paragraph, so the list starts on a line
+/// of its own.
+fn summary_with_highlights<'a>(summary: &'a str, highlights: &[String]) -> Cow<'a, str> {
+    let items: Vec<String> = highlights
+        .iter()
+        .map(|highlight| highlight.trim())
+        .filter(|highlight| !highlight.is_empty())
+        .map(|highlight| {
+            let escaped = highlight
+                .replace('&', "&amp;")
+                .replace('<', "&lt;")
+                .replace('>', "&gt;");
+            format!("<li>{escaped}</li>")
+        })
+        .collect();
+    if items.is_empty() {
+        return Cow::Borrowed(summary);
+    }
+    let trimmed = summary.trim_end();
+    let ends_in_block = ["</p>", "</ul>", "</ol>", "</div>", "</blockquote>"]
+        .iter()
+        .any(|tag| trimmed.ends_with(tag));
+    let list = format!("<ul>{}</ul>", items.concat());
+    if trimmed.is_empty() || ends_in_block {
+        Cow::Owned(format!("{trimmed}{list}"))
+    } else {
+        Cow::Owned(format!("<p>{trimmed}</p>{list}"))
+    }
 }
 
 /// Social/professional profile.
-#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema, TypeScript)]
 #[serde(rename_all = "camelCase")]
 pub struct Profile {
     pub id: String,
@@ -677,7 +907,7 @@ impl Profile {
 }
 
 /// Award item.
-#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema, TypeScript)]
 #[serde(rename_all = "camelCase")]
 pub struct Award {
     pub id: String,
@@ -746,7 +976,7 @@ impl Award {
 }
 
 /// Certification item.
-#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema, TypeScript)]
 #[serde(rename_all = "camelCase")]
 pub struct Certification {
     pub id: String,
@@ -810,8 +1040,9 @@ impl Certification {
 }
 
 /// Publication item.
-#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema, TypeScript, SectionItem)]
 #[serde(rename_all = "camelCase")]
+#[section_item(new(name))]
 pub struct Publication {
     pub id: String,
     #[serde(default = "default_true")]
@@ -828,59 +1059,68 @@ pub struct Publication {
     pub url: Url,
 }
 
-impl Default for Publication {
-    fn default() -> Self {
-        Self {
-            id: String::new(),
-            visible: true,
-            name: String::new(),
-            publisher: String::new(),
-            date: String::new(),
-            summary: String::new(),
-            url: Url::default(),
-        }
-    }
-}
-
 impl Publication {
-    /// Create a new publication item.
-    pub fn new(name: impl Into<String>) -> Self {
-        Self {
-            id: cuid2::create_id(),
-            visible: true,
-            name: name.into(),
-            ..Default::default()
+    /// Normalize the entry from a DOI or arXiv ID in its URL, name, or
+    /// summary, without any lookups: the URL becomes the canonical resolver
+    /// link labelled with the ID, an empty date gets the year (from the date,
+    /// arXiv ID, or summary), and an empty summary gets a formatted citation.
+    ///
+    /// Returns whether anything changed; entries without an ID are left alone.
+    pub fn enrich(&mut self) -> bool {
+        let summary_text = html_text_blocks(&self.summary).join(" ");
+        let Some(id) = [
+            self.url.href.as_str(),
+            self.name.as_str(),
+            summary_text.as_str(),
+        ]
+        .into_iter()
+        .find_map(find_scholarly_id) else {
+            return false;
+        };
+        let before = (self.url.clone(), self.date.clone(), self.summary.clone());
+
+        let links_elsewhere =
+            !self.url.href.is_empty() && find_scholarly_id(&self.url.href).as_ref() != Some(&id);
+        if !links_elsewhere {
+            self.url.href = id.url();
+            if self.url.label.is_empty() {
+                self.url.label = id.to_string();
+            }
         }
-    }
 
-    /// Builder method to set publisher.
-    pub fn with_publisher(mut self, publisher: impl Into<String>) -> Self {
-        self.publisher = publisher.into();
-        self
-    }
-
-    /// Builder method to set date.
-    pub fn with_date(mut self, date: impl Into<String>) -> Self {
-        self.date = date.into();
-        self
-    }
+        let year = parse_date_range(&self.date)
+            .map(|range| range.start.year)
+            .or_else(|| extract_year(&self.date))
+            .or_else(|| id.year())
+            .or_else(|| extract_year(&summary_text));
+        if self.date.trim().is_empty() {
+            if let Some(year) = year {
+                self.date = year.to_string();
+            }
+        }
 
-    /// Builder method to set summary.
-    pub fn with_summary(mut self, summary: impl Into<String>) -> Self {
-        self.summary = summary.into();
-        self
-    }
+        if is_empty_string(&self.summary) {
+            let citation = format_citation(&Citation {
+                title: &self.name,
+                venue: &self.publisher,
+                year,
+                id: Some(&id),
+            });
+            let escaped = citation
+                .replace('&', "&amp;")
+                .replace('<', "&lt;")
+                .replace('>', "&gt;");
+            self.summary = format!("<p>{escaped}</p>");
+        }
 
-    /// Builder method to set URL.
-    pub fn with_url(mut self, url: impl Into<String>) -> Self {
-        self.url = Url::new(url);
-        self
+        (self.url.clone(), self.date.clone(), self.summary.clone()) != before
     }
 }
 
 /// Language item.
-#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema, TypeScript, SectionItem)]
 #[serde(rename_all = "camelCase")]
+#[section_item(new(name), alias(fluency = "description"))]
 pub struct Language {
     pub id: String,
     #[serde(default = "default_true")]
@@ -891,46 +1131,22 @@ pub struct Language {
     #[validate(range(min = 0, max = 5))]
     #[serde(default = "default_level")]
     pub level: u8,
-}
-
-impl Default for Language {
-    fn default() -> Self {
-        Self {
-            id: String::new(),
-            visible: true,
-            name: String::new(),
-            description: String::new(),
-            level: 1,
-        }
-    }
+    /// CEFR level, which should agree with `level`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cefr: Option<CefrLevel>,
 }
 
 impl Language {
-    /// Create a new language item.
-    pub fn new(name: impl Into<String>) -> Self {
-        Self {
-            id: cuid2::create_id(),
-            visible: true,
-            name: name.into(),
-            ..Default::default()
-        }
-    }
-
-    /// Builder method to set level.
-    pub fn with_level(mut self, level: u8) -> Self {
-        self.level = level.clamp(0, 5);
-        self
-    }
-
-    /// Builder method to set description (e.g., "Native", "Fluent").
-    pub fn with_description(mut self, description: impl Into<String>) -> Self {
-        self.description = description.into();
+    /// Set the CEFR level and the matching 0–5 `level`.
+    pub fn with_cefr_level(mut self, cefr: CefrLevel) -> Self {
+        self.cefr = Some(cefr);
+        self.level = cefr.level();
         self
     }
 }
 
 /// Interest item.
-#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema, TypeScript)]
 #[serde(rename_all = "camelCase")]
 pub struct Interest {
     pub id: String,
@@ -971,8 +1187,9 @@ impl Interest {
 }
 
 /// Volunteer experience item.
-#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema, TypeScript, SectionItem)]
 #[serde(rename_all = "camelCase")]
+#[section_item(new(organization, position))]
 pub struct Volunteer {
     pub id: String,
     #[serde(default = "default_true")]
@@ -991,61 +1208,10 @@ pub struct Volunteer {
     pub url: Url,
 }
 
-impl Default for Volunteer {
-    fn default() -> Self {
-        Self {
-            id: String::new(),
-            visible: true,
-            organization: String::new(),
-            position: String::new(),
-            location: String::new(),
-            date: String::new(),
-            summary: String::new(),
-            url: Url::default(),
-        }
-    }
-}
-
-impl Volunteer {
-    /// Create a new volunteer item.
-    pub fn new(organization: impl Into<String>, position: impl Into<String>) -> Self {
-        Self {
-            id: cuid2::create_id(),
-            visible: true,
-            organization: organization.into(),
-            position: position.into(),
-            ..Default::default()
-        }
-    }
-
-    /// Builder method to set location.
-    pub fn with_location(mut self, location: impl Into<String>) -> Self {
-        self.location = location.into();
-        self
-    }
-
-    /// Builder method to set date range.
-    pub fn with_date(mut self, date: impl Into<String>) -> Self {
-        self.date = date.into();
-        self
-    }
-
-    /// Builder method to set summary.
-    pub fn with_summary(mut self, summary: impl Into<String>) -> Self {
-        self.summary = summary.into();
-        self
-    }
-
-    /// Builder method to set URL.
-    pub fn with_url(mut self, url: impl Into<String>) -> Self {
-        self.url = Url::new(url);
-        self
-    }
-}
-
 /// Reference item.
-#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema, TypeScript, SectionItem)]
 #[serde(rename_all = "camelCase")]
+#[section_item(new(name))]
 pub struct Reference {
     pub id: String,
     #[serde(default = "default_true")]
@@ -1060,52 +1226,10 @@ pub struct Reference {
     pub url: Url,
 }
 
-impl Default for Reference {
-    fn default() -> Self {
-        Self {
-            id: String::new(),
-            visible: true,
-            name: String::new(),
-            description: String::new(),
-            summary: String::new(),
-            url: Url::default(),
-        }
-    }
-}
-
-impl Reference {
-    /// Create a new reference item.
-    pub fn new(name: impl Into<String>) -> Self {
-        Self {
-            id: cuid2::create_id(),
-            visible: true,
-            name: name.into(),
-            ..Default::default()
-        }
-    }
-
-    /// Builder method to set description.
-    pub fn with_description(mut self, description: impl Into<String>) -> Self {
-        self.description = description.into();
-        self
-    }
-
-    /// Builder method to set summary.
-    pub fn with_summary(mut self, summary: impl Into<String>) -> Self {
-        self.summary = summary.into();
-        self
-    }
-
-    /// Builder method to set URL.
-    pub fn with_url(mut self, url: impl Into<String>) -> Self {
-        self.url = Url::new(url);
-        self
-    }
-}
-
 /// Custom section item.
-#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema, TypeScript, SectionItem)]
 #[serde(rename_all = "camelCase")]
+#[section_item(new(name))]
 pub struct CustomItem {
     pub id: String,
     #[serde(default = "default_true")]
@@ -1127,33 +1251,28 @@ pub struct CustomItem {
     pub url: Url,
 }
 
-impl Default for CustomItem {
-    fn default() -> Self {
-        Self {
-            id: String::new(),
-            visible: true,
-            name: String::new(),
-            description: String::new(),
-            date: String::new(),
-            location: String::new(),
-            summary: String::new(),
-            keywords: Vec::new(),
-            url: Url::default(),
-        }
-    }
+/// `LegacyFields` for items written without the `SectionItem` derive, none
+/// of which has renamed fields yet.
+macro_rules! no_renamed_fields {
+    ($($item:ident),*) => {
+        $(
+            impl LegacyFields for $item {
+                const ITEM: &'static str = stringify!($item);
+                const FIELD_ALIASES: &'static [(&'static str, &'static str)] = &[];
+            }
+        )*
+    };
 }
 
-impl CustomItem {
-    /// Create a new custom item.
-    pub fn new(name: impl Into<String>) -> Self {
-        Self {
-            id: cuid2::create_id(),
-            visible: true,
-            name: name.into(),
-            ..Default::default()
-        }
-    }
-}
+no_renamed_fields!(
+    Experience,
+    Education,
+    Project,
+    Profile,
+    Award,
+    Certification,
+    Interest
+);
 
 // ============================================================================
 // Helper Functions
@@ -1174,13 +1293,13 @@ fn default_level() -> u8 {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use rustume_schema_macros::SectionItem;
+    use crate::FieldDeprecation;
     use validator::Validate;
 
     // Test struct using the SectionItem derive macro
     #[derive(Debug, Clone, Serialize, Deserialize, Validate, SectionItem)]
     #[serde(rename_all = "camelCase")]
-    #[section_item(new(title))]
+    #[section_item(new(title), alias(heading = "title", blurb = "short_description"))]
     struct TestItem {
         pub id: String,
         #[serde(default = "default_true")]
@@ -1188,6 +1307,10 @@ mod tests {
         pub title: String,
         #[serde(default)]
         pub description: String,
+        #[serde(default)]
+        pub short_description: String,
+        #[serde(default = "default_level")]
+        pub rank: u8,
         #[validate(nested)]
         #[serde(default)]
         pub url: Url,
@@ -1219,6 +1342,154 @@ mod tests {
         assert!(item.id.is_empty());
         assert!(item.visible);
         assert!(item.title.is_empty());
+        // Fields with a serde default function start from it
+        assert_eq!(item.rank, 1);
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize, Validate, SectionItem)]
+    #[section_item(new(title), id = "slug")]
+    struct SluggedItem {
+        pub id: String,
+        #[serde(default = "default_true")]
+        pub visible: bool,
+        pub title: String,
+        #[validate(range(max = 10))]
+        #[serde(default)]
+        pub rank: u8,
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize, Validate, SectionItem)]
+    #[section_item(id = "none")]
+    struct UnnamedItem {
+        pub id: String,
+        #[serde(default = "default_true")]
+        pub visible: bool,
+        #[validate(range(min = 1))]
+        #[serde(default = "default_level")]
+        pub level: u8,
+    }
+
+    #[test]
+    fn test_section_item_macro_id_strategies() {
+        assert_eq!(SluggedItem::new("Rust Conf 2024").id, "rust-conf-2024");
+        assert!(UnnamedItem::new().id.is_empty());
+        assert!(UnnamedItem::new().visible);
+    }
+
+    #[test]
+    fn test_section_item_macro_clamps_validated_fields() {
+        assert_eq!(SluggedItem::new("Talk").with_rank(42).rank, 10);
+        assert_eq!(UnnamedItem::new().with_level(0).level, 1);
+
+        let skill = Skill::new("Rust");
+        assert_eq!(skill.level, 1);
+        assert_eq!(Language::new("French").with_level(9).level, 5);
+        assert!(Language::new("French").with_level(9).validate().is_ok());
+    }
+
+    #[test]
+    fn test_section_item_macro_aliases() {
+        assert_eq!(
+            TestItem::FIELD_ALIASES,
+            &[("heading", "title"), ("blurb", "shortDescription")]
+        );
+
+        let (item, deprecations) = TestItem::from_json_value(serde_json::json!({
+            "id": "a1",
+            "heading": "Old Title",
+            "blurb": "Old blurb",
+        }))
+        .unwrap();
+        assert_eq!(item.title, "Old Title");
+        assert_eq!(item.short_description, "Old blurb");
+        assert_eq!(
+            deprecations[0],
+            FieldDeprecation {
+                item: "TestItem",
+                old: "heading",
+                new: "title",
+            }
+        );
+        assert_eq!(
+            deprecations[1].to_string(),
+            "TestItem: `blurb` is deprecated, use `shortDescription`"
+        );
+
+        // The current key wins over a stale old one
+        let (item, deprecations) = TestItem::from_json_value(serde_json::json!({
+            "id": "a1",
+            "title": "New Title",
+            "heading": "Old Title",
+        }))
+        .unwrap();
+        assert_eq!(item.title, "New Title");
+        assert_eq!(deprecations.len(), 1);
+
+        let (_, deprecations) =
+            TestItem::from_json_value(serde_json::to_value(TestItem::new("Title")).unwrap())
+                .unwrap();
+        assert!(deprecations.is_empty());
+    }
+
+    #[test]
+    fn test_resume_reads_renamed_item_fields() {
+        let json = serde_json::json!({
+            "sections": {
+                "languages": {
+                    "id": "languages",
+                    "items": [{ "id": "l1", "name": "German", "fluency": "Native" }],
+                },
+                "custom": {
+                    "talks": { "id": "talks", "items": [{ "id": "t1", "name": "RustConf" }] },
+                },
+            },
+        });
+
+        let (resume, deprecations) = crate::ResumeData::from_json_value(json.clone()).unwrap();
+        assert_eq!(resume.sections.languages.items[0].description, "Native");
+        assert_eq!(
+            deprecations,
+            vec![FieldDeprecation {
+                item: "Language",
+                old: "fluency",
+                new: "description",
+            }]
+        );
+
+        // Plain deserializing takes the old key too, without the report
+        let resume: crate::ResumeData = serde_json::from_value(json).unwrap();
+        assert_eq!(resume.sections.languages.items[0].description, "Native");
+        assert_eq!(resume.sections.custom["talks"].items[0].name, "RustConf");
+    }
+
+    #[test]
+    fn test_derived_items_keep_serde_output() {
+        let item = CustomItem::new("Talk")
+            .with_description("Speaker")
+            .with_date("2024")
+            .with_location("Berlin")
+            .with_keywords(vec!["Rust".to_string()])
+            .with_url("https://example.com");
+        let json = serde_json::to_value(&item).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "id": item.id,
+                "visible": true,
+                "name": "Talk",
+                "description": "Speaker",
+                "date": "2024",
+                "location": "Berlin",
+                "summary": "",
+                "keywords": ["Rust"],
+                "url": { "label": "", "href": "https://example.com" },
+            })
+        );
+
+        let volunteer = Volunteer::new("Red Cross", "Driver").with_location("Lyon");
+        assert_eq!(volunteer.position, "Driver");
+        assert!(Reference::default().visible);
+        assert!(Publication::FIELD_ALIASES.is_empty());
     }
 
     #[test]
@@ -1246,6 +1517,34 @@ mod tests {
         assert!(exp.validate().is_ok());
     }
 
+    #[test]
+    fn test_highlights_follow_the_summary_as_a_list() {
+        let exp = Experience::new("Acme Corp", "Developer").with_summary("<p>Built tools</p>");
+        assert!(matches!(
+            exp.summary_html(),
+            Cow::Borrowed("<p>Built tools</p>")
+        ));
+        assert!(!serde_json::to_string(&exp).unwrap().contains("highlights"));
+
+        let exp = exp.with_highlights(vec!["Cut costs by 30% & more".to_string(), " ".to_string()]);
+        assert_eq!(
+            exp.summary_html(),
+            "<p>Built tools</p><ul><li>Cut costs by 30% &amp; more</li></ul>"
+        );
+
+        let exp = exp.with_summary("Built tools");
+        assert_eq!(
+            exp.summary_html(),
+            "<p>Built tools</p><ul><li>Cut costs by 30% &amp; more</li></ul>"
+        );
+
+        let project = Project::new("Engine").with_highlights(vec!["<b>Fast</b>".to_string()]);
+        assert_eq!(
+            project.summary_html(),
+            "<ul><li>&lt;b&gt;Fast&lt;/b&gt;</li></ul>"
+        );
+    }
+
     #[test]
     fn test_skill_level_validation() {
         let valid = Skill::new("Rust").with_level(5);
@@ -1265,6 +1564,51 @@ mod tests {
         assert_eq!(linkedin.icon, "linkedin");
     }
 
+    #[test]
+    fn test_publication_enrich_from_doi() {
+        let mut publication = Publication::new("Deep learning")
+            .with_publisher("Nature")
+            .with_url("https://dx.doi.org/10.1038/NATURE14539");
+        publication.date = "May 2015".to_string();
+
+        assert!(publication.enrich());
+        assert_eq!(publication.url.href, "https://doi.org/10.1038/nature14539");
+        assert_eq!(publication.url.label, "doi:10.1038/nature14539");
+        assert_eq!(publication.date, "May 2015");
+        assert_eq!(
+            publication.summary,
+            "<p>Deep learning. Nature (2015). https://doi.org/10.1038/nature14539</p>"
+        );
+    }
+
+    #[test]
+    fn test_publication_enrich_from_summary_keeps_other_links_and_summary() {
+        let mut publication = Publication::new("Q&A systems")
+            .with_url("https://example.com/talk")
+            .with_summary("<p>Preprint arXiv:2101.00001v2</p>");
+
+        assert!(publication.enrich());
+        assert_eq!(publication.url.href, "https://example.com/talk");
+        assert_eq!(publication.date, "2021");
+        assert_eq!(publication.summary, "<p>Preprint arXiv:2101.00001v2</p>");
+
+        let mut linked =
+            Publication::new("Q&A <systems>").with_url("https://arxiv.org/pdf/2101.00001");
+        assert!(linked.enrich());
+        assert_eq!(
+            linked.summary,
+            "<p>Q&amp;A &lt;systems&gt;. (2021). https://arxiv.org/abs/2101.00001</p>"
+        );
+    }
+
+    #[test]
+    fn test_publication_enrich_without_id_is_a_no_op() {
+        let mut publication = Publication::new("Field notes").with_url("https://example.com");
+        assert!(!publication.enrich());
+        assert!(publication.summary.is_empty());
+        assert!(publication.date.is_empty());
+    }
+
     #[test]
     fn test_summary_is_empty() {
         let empty = SummarySection::default();
@@ -1,13 +1,19 @@
 //! Shared types used across the schema.
 
+use rustume_utils::is_plausible_phone;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
-use validator::Validate;
+use validator::{Validate, ValidationError};
 
-use crate::validation::validate_optional_url;
+use crate::validation::{
+    validate_optional_email, validate_optional_partial_date, validate_optional_url,
+};
+use crate::TypeScript;
 
 /// URL with label.
-#[derive(Debug, Clone, Serialize, Deserialize, Validate, Default, ToSchema)]
+#[derive(
+    Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Validate, Default, ToSchema, TypeScript,
+)]
 pub struct Url {
     /// Display label for the URL.
     #[serde(default)]
@@ -42,14 +48,36 @@ impl Url {
     }
 }
 
+/// What a custom field's value is, which decides how it is validated and
+/// rendered.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema, TypeScript,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum CustomFieldKind {
+    /// Plain text, linked only when it is a web address.
+    #[default]
+    Text,
+    /// An HTTP(S) URL, shown under the field's name.
+    Link,
+    /// An email address, linked with `mailto:`.
+    Email,
+    /// A phone number, linked with `tel:`.
+    Phone,
+    /// An ISO 8601 partial date (`YYYY`, `YYYY-MM`, or `YYYY-MM-DD`).
+    Date,
+}
+
 /// Custom field for basics section.
-#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema, TypeScript)]
+#[validate(schema(function = "validate_custom_field"))]
 pub struct CustomField {
     /// CUID2 format identifier.
     #[serde(default = "cuid2::create_id")]
     pub id: String,
 
-    /// Icon identifier (e.g., from a icon library).
+    /// Icon identifier (e.g., from a icon library). Templates draw it when it
+    /// is an emoji or pictographic glyph.
     #[serde(default)]
     pub icon: String,
 
@@ -60,6 +88,10 @@ pub struct CustomField {
     /// Field value.
     #[serde(default)]
     pub value: String,
+
+    /// What the value is.
+    #[serde(default)]
+    pub kind: CustomFieldKind,
 }
 
 impl CustomField {
@@ -70,6 +102,7 @@ impl CustomField {
             icon: String::new(),
             name: name.into(),
             value: value.into(),
+            kind: CustomFieldKind::Text,
         }
     }
 
@@ -80,10 +113,77 @@ impl CustomField {
         value: impl Into<String>,
     ) -> Self {
         Self {
-            id: cuid2::create_id(),
             icon: icon.into(),
-            name: name.into(),
-            value: value.into(),
+            ..Self::new(name, value)
+        }
+    }
+
+    /// Create a link field, e.g. `CustomField::link("GitHub", "https://github.com/ada")`.
+    pub fn link(name: impl Into<String>, url: impl Into<String>) -> Self {
+        Self::new(name, url).with_kind(CustomFieldKind::Link)
+    }
+
+    /// Create an email field.
+    pub fn email(name: impl Into<String>, email: impl Into<String>) -> Self {
+        Self::new(name, email).with_kind(CustomFieldKind::Email)
+    }
+
+    /// Create a phone field.
+    pub fn phone(name: impl Into<String>, phone: impl Into<String>) -> Self {
+        Self::new(name, phone).with_kind(CustomFieldKind::Phone)
+    }
+
+    /// Create a date field from an ISO 8601 partial date.
+    pub fn date(name: impl Into<String>, date: impl Into<String>) -> Self {
+        Self::new(name, date).with_kind(CustomFieldKind::Date)
+    }
+
+    /// Builder method to set the kind.
+    pub fn with_kind(mut self, kind: CustomFieldKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// Link target for the value: the URL itself for links and web-address
+    /// text, `mailto:` for emails, and `tel:` with the dialable characters
+    /// for phones. `None` when the value shouldn't be linked.
+    pub fn href(&self) -> Option<String> {
+        let value = self.value.trim();
+        if value.is_empty() {
+            return None;
+        }
+        match self.kind {
+            CustomFieldKind::Link => Some(value.to_string()),
+            CustomFieldKind::Text if validate_optional_url(value).is_ok() => {
+                Some(value.to_string())
+            }
+            CustomFieldKind::Email => Some(format!("mailto:{value}")),
+            CustomFieldKind::Phone => Some(format!(
+                "tel:{}",
+                value
+                    .chars()
+                    .filter(|c| c.is_ascii_digit() || *c == '+')
+                    .collect::<String>()
+            )),
+            CustomFieldKind::Text | CustomFieldKind::Date => None,
+        }
+    }
+}
+
+/// Check the value against the field's kind. Empty values are allowed.
+fn validate_custom_field(field: &CustomField) -> Result<(), ValidationError> {
+    let value = field.value.trim();
+    match field.kind {
+        CustomFieldKind::Text => Ok(()),
+        CustomFieldKind::Link => validate_optional_url(value),
+        CustomFieldKind::Email => validate_optional_email(value),
+        CustomFieldKind::Date => validate_optional_partial_date(value),
+        CustomFieldKind::Phone if value.is_empty() || is_plausible_phone(value) => Ok(()),
+        CustomFieldKind::Phone => {
+            let mut error = ValidationError::new("invalid_phone");
+            error.message =
+                Some("Must be a phone number with 7 to 15 digits and only +-()./".into());
+            Err(error)
         }
     }
 }
@@ -120,5 +220,65 @@ mod tests {
         assert!(!field.id.is_empty());
         assert_eq!(field.name, "Website");
         assert_eq!(field.value, "https://example.com");
+        assert_eq!(field.kind, CustomFieldKind::Text);
+    }
+
+    #[test]
+    fn test_custom_field_kind_defaults_to_text() {
+        let field: CustomField =
+            serde_json::from_str(r#"{"name": "Pronouns", "value": "they/them"}"#).unwrap();
+        assert_eq!(field.kind, CustomFieldKind::Text);
+
+        let field: CustomField =
+            serde_json::from_str(r#"{"name": "Mail", "value": "a@b.io", "kind": "email"}"#)
+                .unwrap();
+        assert_eq!(field.kind, CustomFieldKind::Email);
+    }
+
+    #[test]
+    fn test_custom_field_validation_follows_kind() {
+        assert!(CustomField::link("GitHub", "https://github.com/ada")
+            .validate()
+            .is_ok());
+        assert!(CustomField::link("GitHub", "github.com/ada")
+            .validate()
+            .is_err());
+        assert!(CustomField::email("Work", "ada@example.com")
+            .validate()
+            .is_ok());
+        assert!(CustomField::email("Work", "ada at example")
+            .validate()
+            .is_err());
+        assert!(CustomField::phone("Mobile", "+44 20 7946 0958")
+            .validate()
+            .is_ok());
+        assert!(CustomField::phone("Mobile", "call me").validate().is_err());
+        assert!(CustomField::date("Available", "2026-03").validate().is_ok());
+        assert!(CustomField::date("Available", "March").validate().is_err());
+        assert!(CustomField::new("Anything", "goes here").validate().is_ok());
+        assert!(CustomField::email("Empty", "").validate().is_ok());
+    }
+
+    #[test]
+    fn test_custom_field_href() {
+        assert_eq!(
+            CustomField::link("GitHub", "https://github.com/ada").href(),
+            Some("https://github.com/ada".to_string())
+        );
+        assert_eq!(
+            CustomField::email("Work", "ada@example.com").href(),
+            Some("mailto:ada@example.com".to_string())
+        );
+        assert_eq!(
+            CustomField::phone("Mobile", "+44 (20) 7946-0958").href(),
+            Some("tel:+442079460958".to_string())
+        );
+        assert_eq!(
+            CustomField::new("Site", "https://ada.dev").href(),
+            Some("https://ada.dev".to_string())
+        );
+        assert_eq!(CustomField::new("Pronouns", "they/them").href(), None);
+        assert_eq!(CustomField::date("Available", "2026-03").href(), None);
+        assert_eq!(CustomField::link("Empty", " ").href(), None);
     }
 }
@@ -0,0 +1,141 @@
+//! Experience statistics derived from item dates.
+//!
+//! Durations come from [`Experience::date_range`], so structured dates are
+//! used when present and display dates are parsed otherwise. Ongoing roles
+//! run to the day the stats are computed.
+
+use rustume_utils::{format_duration_in, total_months, DateRange, Locale, PartialDate};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::TypeScript;
+use crate::{employment_gaps, EmploymentGap, Experience, GapOptions, ResumeData};
+
+/// How long one position lasted.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema, TypeScript)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionDuration {
+    /// ID of the experience item.
+    pub id: String,
+
+    #[schema(example = "Acme")]
+    pub company: String,
+
+    #[schema(example = "Senior Engineer")]
+    pub position: String,
+
+    /// Whole months, counted inclusively.
+    #[schema(example = 27)]
+    pub months: u32,
+
+    /// The same duration for display.
+    #[schema(example = "2 yrs 3 mos")]
+    pub duration: String,
+}
+
+/// Aggregate statistics for a resume.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, ToSchema, TypeScript)]
+#[serde(rename_all = "camelCase")]
+pub struct ResumeStats {
+    /// Visible positions whose dates could be read, in resume order.
+    pub positions: Vec<PositionDuration>,
+
+    /// Months covered by at least one position; overlapping roles count once.
+    #[schema(example = 74)]
+    pub total_experience_months: u32,
+
+    /// Total experience in years, to one decimal place.
+    #[schema(example = 6.2)]
+    pub total_experience_years: f64,
+
+    /// Total experience for display.
+    #[schema(example = "6 yrs 2 mos")]
+    pub total_experience: String,
+
+    /// Gaps of at least [`DEFAULT_GAP_MONTHS`] between entries, oldest first.
+    #[serde(default)]
+    pub gaps: Vec<EmploymentGap>,
+}
+
+/// Compute [`ResumeStats`] as of `today`.
+///
+/// Hidden experience items and items without a readable date are left out.
+/// Gaps come from [`employment_gaps`], which also counts education and
+/// volunteering.
+pub fn resume_stats(resume: &ResumeData, today: &PartialDate) -> ResumeStats {
+    let locale = Locale::for_tag(&resume.metadata.locale);
+    let dated: Vec<(&Experience, DateRange)> = resume
+        .sections
+        .experience
+        .items
+        .iter()
+        .filter(|item| item.visible)
+        .filter_map(|item| Some((item, item.date_range()?)))
+        .collect();
+
+    let positions = dated
+        .iter()
+        .map(|(item, range)| {
+            let months = range.months(today);
+            PositionDuration {
+                id: item.id.clone(),
+                company: item.company.clone(),
+                position: item.position.clone(),
+                months,
+                duration: format_duration_in(months, locale),
+            }
+        })
+        .collect();
+
+    let total = total_months(dated.iter().map(|(_, range)| range), today);
+    ResumeStats {
+        positions,
+        total_experience_months: total,
+        total_experience_years: (f64::from(total) / 12.0 * 10.0).round() / 10.0,
+        total_experience: format_duration_in(total, locale),
+        gaps: employment_gaps(resume, &GapOptions::default(), today),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats_count_overlaps_once() {
+        let mut resume = ResumeData::default();
+        let experience = &mut resume.sections.experience;
+        experience.add_item(Experience::new("Acme", "Engineer").with_date("Jan 2019 - Dec 2020"));
+        experience.add_item(Experience::new("Side Project", "Founder").with_dates("2020-06", None));
+        experience.add_item(Experience::new("Globex", "Intern").with_date("Summer 2017"));
+        let mut hidden = Experience::new("Initech", "Analyst").with_date("2010 - 2015");
+        hidden.visible = false;
+        experience.add_item(hidden);
+
+        let today = PartialDate {
+            year: 2021,
+            month: Some(5),
+            day: Some(10),
+        };
+        let stats = resume_stats(&resume, &today);
+
+        let durations: Vec<&str> = stats
+            .positions
+            .iter()
+            .map(|position| position.duration.as_str())
+            .collect();
+        assert_eq!(durations, ["2 yrs", "1 yr"]);
+        assert_eq!(stats.total_experience_months, 29);
+        assert_eq!(stats.total_experience, "2 yrs 5 mos");
+        assert!((stats.total_experience_years - 2.4).abs() < f64::EPSILON);
+        assert!(stats.gaps.is_empty());
+    }
+
+    #[test]
+    fn test_stats_for_empty_resume() {
+        let stats = ResumeData::default().stats();
+        assert!(stats.positions.is_empty());
+        assert_eq!(stats.total_experience_months, 0);
+        assert_eq!(stats.total_experience, "");
+    }
+}
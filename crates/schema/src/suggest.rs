@@ -0,0 +1,429 @@
+//! Rule-based content suggestions for experience bullet points.
+//!
+//! Everything here runs offline from fixed word lists and heuristics: passive
+//! voice, weak opening verbs, missing numbers, and bullet length. Suggestions
+//! are hints for the writer, not validation failures.
+
+use std::fmt;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use rustume_utils::html_text_blocks;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::ResumeData;
+use crate::TypeScript;
+
+/// Bullets shorter than this many words are flagged as too terse.
+pub const MIN_BULLET_WORDS: usize = 5;
+
+/// Bullets longer than this many words are flagged as too long to scan.
+pub const MAX_BULLET_WORDS: usize = 35;
+
+/// Curated action verbs that make strong bullet openers.
+pub const ACTION_VERBS: &[&str] = &[
+    "accelerated",
+    "achieved",
+    "adopted",
+    "analyzed",
+    "applied",
+    "architected",
+    "automated",
+    "boosted",
+    "built",
+    "championed",
+    "coached",
+    "collaborated",
+    "completed",
+    "consolidated",
+    "contributed",
+    "coordinated",
+    "created",
+    "cut",
+    "debugged",
+    "decreased",
+    "defined",
+    "delivered",
+    "deployed",
+    "designed",
+    "developed",
+    "directed",
+    "doubled",
+    "drove",
+    "earned",
+    "eliminated",
+    "enabled",
+    "engineered",
+    "established",
+    "executed",
+    "expanded",
+    "generated",
+    "grew",
+    "hired",
+    "implemented",
+    "improved",
+    "increased",
+    "introduced",
+    "launched",
+    "led",
+    "managed",
+    "mentored",
+    "migrated",
+    "modernized",
+    "negotiated",
+    "optimized",
+    "orchestrated",
+    "overhauled",
+    "owned",
+    "piloted",
+    "pioneered",
+    "planned",
+    "presented",
+    "processed",
+    "produced",
+    "prototyped",
+    "published",
+    "rebuilt",
+    "redesigned",
+    "reduced",
+    "refactored",
+    "resolved",
+    "restructured",
+    "saved",
+    "scaled",
+    "secured",
+    "shipped",
+    "simplified",
+    "spearheaded",
+    "standardized",
+    "streamlined",
+    "supported",
+    "taught",
+    "tested",
+    "trained",
+    "transformed",
+    "tripled",
+    "won",
+    "wrote",
+];
+
+/// Weak bullet openers and stronger verbs to use instead.
+const WEAK_VERBS: &[(&str, &[&str])] = &[
+    ("assisted", &["Supported", "Coordinated", "Enabled"]),
+    ("did", &["Executed", "Completed", "Delivered"]),
+    ("got", &["Achieved", "Earned", "Secured"]),
+    ("handled", &["Managed", "Resolved", "Processed"]),
+    ("helped", &["Enabled", "Supported", "Accelerated"]),
+    ("involved", &["Contributed", "Collaborated", "Drove"]),
+    ("made", &["Created", "Built", "Produced"]),
+    ("participated", &["Contributed", "Collaborated", "Drove"]),
+    ("responsible", &["Led", "Owned", "Managed"]),
+    ("tasked", &["Led", "Executed", "Delivered"]),
+    ("tried", &["Piloted", "Tested", "Prototyped"]),
+    ("used", &["Applied", "Implemented", "Adopted"]),
+    ("worked", &["Built", "Delivered", "Collaborated"]),
+];
+
+/// Leading words skipped before looking for the opening verb ("I led", "Was responsible").
+const SKIPPED_OPENERS: &[&str] = &["i", "was", "were"];
+
+/// A form of "to be"/"to get" followed by a past participle, optionally split by an adverb.
+static PASSIVE_VOICE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(concat!(
+        r"(?i)\b(?:am|is|are|was|were|be|been|being|got|gets)\s+(?:\w+ly\s+)?",
+        r"(\w+ed|begun|built|chosen|done|driven|given|grown|held|kept|known|led|made|paid|run",
+        r"|seen|sent|set|shown|sold|taken|taught|told|won|written)\b",
+    ))
+    .expect("Invalid passive voice regex")
+});
+
+/// Digits, currency, percentages, or spelled-out magnitudes.
+static METRIC: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(concat!(
+        r"(?i)[0-9%$€£¥]",
+        r"|\b(?:dozens?|hundreds?|thousands?|millions?|billions?|doubled|tripled|halved)\b",
+    ))
+    .expect("Invalid metric regex")
+});
+
+/// A rule-based improvement hint for one bullet point.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema, TypeScript)]
+pub struct ContentSuggestion {
+    /// Dotted path of the field containing the bullet.
+    #[schema(example = "sections.experience.items[0].summary")]
+    pub path: String,
+
+    /// Machine-readable rule code.
+    #[schema(example = "weak_verb")]
+    pub code: String,
+
+    /// Human-readable explanation of the hint.
+    pub message: String,
+
+    /// Plain text of the bullet the hint applies to.
+    #[schema(example = "Responsible for the billing service")]
+    pub excerpt: String,
+
+    /// Suggested replacement wording, when the rule has one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub suggestion: Option<String>,
+}
+
+impl fmt::Display for ContentSuggestion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)?;
+        if let Some(suggestion) = &self.suggestion {
+            write!(f, " (suggested: {})", suggestion)?;
+        }
+        Ok(())
+    }
+}
+
+/// Analyze each bullet or paragraph of a rich text field.
+pub fn suggest_text(path: &str, html: &str) -> Vec<ContentSuggestion> {
+    html_text_blocks(html)
+        .iter()
+        .flat_map(|bullet| suggest_bullet(path, bullet))
+        .collect()
+}
+
+/// Analyze the summaries and highlights of all visible experience items.
+pub fn content_suggestions(resume: &ResumeData) -> Vec<ContentSuggestion> {
+    resume
+        .sections
+        .experience
+        .items
+        .iter()
+        .enumerate()
+        .filter(|(_, item)| item.visible)
+        .flat_map(|(i, item)| {
+            let path = format!("sections.experience.items[{i}].summary");
+            let highlights = item
+                .highlights
+                .iter()
+                .enumerate()
+                .filter(|(_, bullet)| !bullet.trim().is_empty())
+                .flat_map(move |(j, bullet)| {
+                    let path = format!("sections.experience.items[{i}].highlights[{j}]");
+                    suggest_bullet(&path, bullet.trim())
+                });
+            suggest_text(&path, &item.summary)
+                .into_iter()
+                .chain(highlights)
+        })
+        .collect()
+}
+
+fn suggest_bullet(path: &str, bullet: &str) -> Vec<ContentSuggestion> {
+    let hint = |code: &str, message: String, suggestion: Option<String>| ContentSuggestion {
+        path: path.to_string(),
+        code: code.to_string(),
+        message,
+        excerpt: bullet.to_string(),
+        suggestion,
+    };
+
+    let words: Vec<String> = bullet
+        .split_whitespace()
+        .map(|word| {
+            word.trim_matches(|c: char| !c.is_alphanumeric())
+                .to_lowercase()
+        })
+        .filter(|word| !word.is_empty())
+        .collect();
+    let mut hints = Vec::new();
+
+    if let Some(opener) = words
+        .iter()
+        .find(|word| !SKIPPED_OPENERS.contains(&word.as_str()))
+    {
+        if let Some((_, alternatives)) = WEAK_VERBS.iter().find(|(weak, _)| weak == opener) {
+            hints.push(hint(
+                "weak_verb",
+                format!("Starts with the weak verb \"{opener}\"; lead with an action verb"),
+                Some(alternatives.join(", ")),
+            ));
+        }
+    }
+
+    if let Some(captures) = PASSIVE_VOICE.captures(bullet) {
+        hints.push(hint(
+            "passive_voice",
+            format!(
+                "Passive voice (\"{}\"); say who did it and what changed",
+                &captures[0]
+            ),
+            None,
+        ));
+    }
+
+    if words.len() < MIN_BULLET_WORDS {
+        hints.push(hint(
+            "bullet_too_short",
+            format!(
+                "Only {} words; add the outcome or scope \
+                 (aim for {MIN_BULLET_WORDS}–{MAX_BULLET_WORDS})",
+                words.len()
+            ),
+            None,
+        ));
+    } else {
+        if words.len() > MAX_BULLET_WORDS {
+            hints.push(hint(
+                "bullet_too_long",
+                format!(
+                    "{} words; split it or trim to at most {MAX_BULLET_WORDS}",
+                    words.len()
+                ),
+                None,
+            ));
+        }
+        if !METRIC.is_match(bullet) {
+            hints.push(hint(
+                "missing_metric",
+                "No numbers; quantify the impact (%, $, users, time saved)".to_string(),
+                None,
+            ));
+        }
+    }
+
+    hints
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Experience, Section};
+
+    fn codes(text: &str) -> Vec<String> {
+        suggest_text("summary", text)
+            .into_iter()
+            .map(|s| s.code)
+            .collect()
+    }
+
+    #[test]
+    fn test_strong_bullet_has_no_suggestions() {
+        assert!(codes("Cut p95 checkout latency by 40% by rebuilding the cart service").is_empty());
+    }
+
+    #[test]
+    fn test_weak_verb_with_alternatives() {
+        let hints = suggest_text(
+            "summary",
+            "Responsible for the billing service and its 3 APIs",
+        );
+        assert_eq!(hints.len(), 1);
+        assert_eq!(hints[0].code, "weak_verb");
+        assert_eq!(hints[0].suggestion.as_deref(), Some("Led, Owned, Managed"));
+    }
+
+    #[test]
+    fn test_weak_verb_after_pronoun() {
+        assert!(codes("I helped the team ship 4 releases on time").contains(&"weak_verb".into()));
+    }
+
+    #[test]
+    fn test_passive_voice() {
+        let hints = suggest_text(
+            "summary",
+            "The new pipeline was designed by me in 2021 for 5 teams",
+        );
+        assert_eq!(hints.len(), 1);
+        assert_eq!(hints[0].code, "passive_voice");
+        assert!(hints[0].message.contains("was designed"));
+    }
+
+    #[test]
+    fn test_missing_metric() {
+        assert_eq!(
+            codes("Built the internal deployment dashboard for platform engineers"),
+            vec!["missing_metric"]
+        );
+        assert!(
+            codes("Grew revenue to two million dollars across hundreds of accounts").is_empty()
+        );
+    }
+
+    #[test]
+    fn test_bullet_length() {
+        assert_eq!(codes("Shipped search"), vec!["bullet_too_short"]);
+
+        let long = format!("Delivered 3 {}", "very ".repeat(MAX_BULLET_WORDS));
+        assert!(codes(&long).contains(&"bullet_too_long".into()));
+    }
+
+    #[test]
+    fn test_html_bullets_are_analyzed_separately() {
+        let hints = suggest_text(
+            "summary",
+            "<ul><li><p>Helped with onboarding</p></li>\
+             <li>Reduced build times by 30% with caching</li></ul>",
+        );
+        assert!(hints.iter().all(|h| h.excerpt == "Helped with onboarding"));
+        assert!(!hints.is_empty());
+    }
+
+    #[test]
+    fn test_content_suggestions_paths_skip_hidden_items() {
+        let mut resume = ResumeData::default();
+        resume.sections.experience = Section::new("experience", "Experience");
+        resume
+            .sections
+            .experience
+            .add_item(Experience::new("Acme", "Engineer").with_summary("Worked on things"));
+        let mut hidden = Experience::new("Hidden", "Engineer").with_summary("Worked on things");
+        hidden.visible = false;
+        resume.sections.experience.add_item(hidden);
+
+        let hints = content_suggestions(&resume);
+        assert!(!hints.is_empty());
+        assert!(hints
+            .iter()
+            .all(|h| h.path == "sections.experience.items[0].summary"));
+    }
+
+    #[test]
+    fn test_content_suggestions_check_each_highlight() {
+        let mut resume = ResumeData::default();
+        resume.sections.experience = Section::new("experience", "Experience");
+        resume
+            .sections
+            .experience
+            .add_item(Experience::new("Acme", "Engineer").with_highlights(vec![
+                "Reduced build times by 30% with caching".to_string(),
+                "Helped with onboarding".to_string(),
+            ]));
+
+        let hints = content_suggestions(&resume);
+        assert!(!hints.is_empty());
+        assert!(hints
+            .iter()
+            .all(|h| h.path == "sections.experience.items[0].highlights[1]"));
+    }
+
+    #[test]
+    fn test_weak_verb_alternatives_are_action_verbs() {
+        for (weak, alternatives) in WEAK_VERBS {
+            assert!(!ACTION_VERBS.contains(weak), "{weak} is listed as both");
+            for verb in *alternatives {
+                assert!(
+                    ACTION_VERBS.contains(&verb.to_lowercase().as_str()),
+                    "{verb} missing from ACTION_VERBS"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_display() {
+        let hint =
+            suggest_text("summary", "Helped the team ship 2 releases each quarter").remove(0);
+        assert_eq!(
+            hint.to_string(),
+            "summary: Starts with the weak verb \"helped\"; lead with an action verb \
+             (suggested: Enabled, Supported, Accelerated)"
+        );
+    }
+}
@@ -0,0 +1,173 @@
+//! TypeScript declarations for the schema, shipped with the WASM package.
+//!
+//! Each type derives [`TypeScript`](rustume_schema_macros::TypeScript), which
+//! reads the same serde attributes that decide its JSON. [`typescript_definitions`]
+//! joins them into `bindings/wasm/schema.d.ts`, so the web app's types follow
+//! the Rust schema instead of drifting from it. After changing a schema type,
+//! regenerate the file and commit it with the change:
+//!
+//! ```bash
+//! RUSTUME_UPDATE_TS=1 cargo test -p rustume-schema typescript
+//! ```
+
+pub use rustume_schema_macros::TypeScript;
+
+use crate::*;
+
+/// A type's TypeScript declaration, as it serializes to JSON.
+pub trait TypeScript {
+    /// The `export interface` or `export type` declaration, with JSDoc from
+    /// the doc comments.
+    const DECLARATION: &'static str;
+}
+
+/// Header of the generated file.
+const HEADER: &str = "\
+// Generated from the Rust schema by rustume-schema; do not edit.
+// Regenerate with: RUSTUME_UPDATE_TS=1 cargo test -p rustume-schema typescript
+";
+
+/// Declarations of every schema type, resume data first.
+pub fn typescript_definitions() -> String {
+    let declarations = [
+        // Resume data
+        ResumeData::DECLARATION,
+        Basics::DECLARATION,
+        PersonName::DECLARATION,
+        Address::DECLARATION,
+        Url::DECLARATION,
+        CustomFieldKind::DECLARATION,
+        CustomField::DECLARATION,
+        Picture::DECLARATION,
+        PictureEffects::DECLARATION,
+        Sections::DECLARATION,
+        Section::<CustomItem>::DECLARATION,
+        SummarySection::DECLARATION,
+        CoverLetterRecipient::DECLARATION,
+        CoverLetterSection::DECLARATION,
+        Experience::DECLARATION,
+        Education::DECLARATION,
+        Skill::DECLARATION,
+        Project::DECLARATION,
+        Profile::DECLARATION,
+        Award::DECLARATION,
+        Certification::DECLARATION,
+        Publication::DECLARATION,
+        Language::DECLARATION,
+        CefrLevel::DECLARATION,
+        Interest::DECLARATION,
+        Volunteer::DECLARATION,
+        Reference::DECLARATION,
+        CustomItem::DECLARATION,
+        Metadata::DECLARATION,
+        Layout::DECLARATION,
+        LayoutPreset::DECLARATION,
+        ContentPreset::DECLARATION,
+        CustomCss::DECLARATION,
+        PageConfig::DECLARATION,
+        PageFormat::DECLARATION,
+        PageOptions::DECLARATION,
+        PageNumberStyle::DECLARATION,
+        RunningHeader::DECLARATION,
+        ContactQr::DECLARATION,
+        PageSelector::DECLARATION,
+        PageBackground::DECLARATION,
+        Theme::DECLARATION,
+        Typography::DECLARATION,
+        FontConfig::DECLARATION,
+        LevelDisplay::DECLARATION,
+        SectionDisplay::DECLARATION,
+        EmojiPolicy::DECLARATION,
+        TemplateOptions::DECLARATION,
+        PhotoPosition::DECLARATION,
+        HeaderAlign::DECLARATION,
+        DividerStyle::DECLARATION,
+        NameOrder::DECLARATION,
+        Attachment::DECLARATION,
+        AttachmentMode::DECLARATION,
+        // Checks and reports
+        Severity::DECLARATION,
+        ValidationIssue::DECLARATION,
+        RuleReport::DECLARATION,
+        ContentSuggestion::DECLARATION,
+        DuplicateCandidate::DECLARATION,
+        MergeStrategy::DECLARATION,
+        MergeEntry::DECLARATION,
+        MergeReport::DECLARATION,
+        MergedImport::DECLARATION,
+        RepairFix::DECLARATION,
+        RepairReport::DECLARATION,
+        RepairedResume::DECLARATION,
+        FitStepKind::DECLARATION,
+        FitStep::DECLARATION,
+        FitReport::DECLARATION,
+        FittedResume::DECLARATION,
+        KeywordOptions::DECLARATION,
+        KeywordCount::DECLARATION,
+        TargetCoverage::DECLARATION,
+        SectionKeywords::DECLARATION,
+        KeywordReport::DECLARATION,
+        PositionDuration::DECLARATION,
+        ResumeStats::DECLARATION,
+        GapOptions::DECLARATION,
+        EmploymentGap::DECLARATION,
+        // Job applications
+        JobApplication::DECLARATION,
+        ApplicationStatus::DECLARATION,
+        // Errors
+        ErrorCode::DECLARATION,
+        RustumeError::DECLARATION,
+    ];
+
+    let mut out = HEADER.to_string();
+    for declaration in declarations {
+        out.push('\n');
+        out.push_str(declaration);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    /// Set to rewrite `bindings/wasm/schema.d.ts` from the current schema.
+    const UPDATE_ENV: &str = "RUSTUME_UPDATE_TS";
+
+    #[test]
+    fn test_declarations_follow_serde() {
+        assert!(Experience::DECLARATION.contains("export interface Experience {"));
+        // `skip_serializing_if` fields are optional, without `null`
+        assert!(Experience::DECLARATION.contains("  startDate?: string;\n"));
+        assert!(Basics::DECLARATION.contains("  nameParts?: PersonName;\n"));
+        assert!(Sections::DECLARATION.contains("  coverLetter: CoverLetterSection;\n"));
+        assert!(Sections::DECLARATION.contains("  custom: Record<string, Section<CustomItem>>;\n"));
+        assert!(Section::<Skill>::DECLARATION.contains("export interface Section<T> {"));
+        assert!(Section::<Skill>::DECLARATION.contains("  items: T[];\n"));
+        assert!(Layout::DECLARATION.contains("export type Layout = string[][][];"));
+        assert!(MergeStrategy::DECLARATION.contains("\"append_new\""));
+        assert!(AttachmentMode::DECLARATION.contains("\"append\" | \"qr-code\""));
+        assert!(PageFormat::DECLARATION.contains("\"a4\" | \"letter\""));
+        assert!(CustomFieldKind::DECLARATION.starts_with("/**\n * What a custom field's"));
+    }
+
+    #[test]
+    fn test_wasm_declarations_in_sync() {
+        let path =
+            PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../bindings/wasm/schema.d.ts");
+        let generated = typescript_definitions();
+        if std::env::var_os(UPDATE_ENV).is_some() {
+            std::fs::write(&path, &generated).unwrap();
+            return;
+        }
+
+        let committed = std::fs::read_to_string(&path)
+            .unwrap_or_else(|e| panic!("Failed to read {}: {e}", path.display()));
+        assert!(
+            committed == generated,
+            "bindings/wasm/schema.d.ts is out of date; regenerate it with \
+             {UPDATE_ENV}=1 cargo test -p rustume-schema typescript"
+        );
+    }
+}
@@ -1,8 +1,28 @@
 //! Custom validation functions for resume data.
+//!
+//! Field validators (`validate_optional_*`) check single values and run via
+//! `#[validate]`. Semantic rules check the resume as a whole and report
+//! [`ValidationIssue`]s through a [`RuleSet`].
+
+use std::collections::HashMap;
+use std::fmt;
 
 use once_cell::sync::Lazy;
 use regex::Regex;
-use validator::ValidationError;
+use rustume_utils::{
+    contrast_ratio, is_plausible_phone, parse_iso_partial_date, split_phone_extension,
+    suggest_compliant_color, suggest_email, DateRange, PartialDate, WCAG_AA_LARGE_TEXT,
+    WCAG_AA_NORMAL_TEXT,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use utoipa::ToSchema;
+use validator::{ValidationError, ValidationErrors, ValidationErrorsKind};
+
+use crate::dedupe::{item_sections, text};
+use crate::sections::item_date_range;
+use crate::TypeScript;
+use crate::{employment_gap_warnings, AttachmentMode, Basics, LayoutError, ResumeData, Theme};
 
 static URL_REGEX: Lazy<Regex> =
     Lazy::new(|| Regex::new(r"^https?://[^\s]+$").expect("Invalid URL regex"));
@@ -41,6 +61,59 @@ pub fn validate_optional_email(email: &str) -> Result<(), ValidationError> {
     }
 }
 
+/// Validate that a string is either empty or a calendar date (`YYYY-MM-DD`).
+pub fn validate_optional_date(date: &str) -> Result<(), ValidationError> {
+    if date.is_empty() {
+        return Ok(());
+    }
+
+    let mut parts = date.splitn(3, '-');
+    let valid = match (parts.next(), parts.next(), parts.next()) {
+        (Some(y), Some(m), Some(d))
+            if y.len() == 4
+                && m.len() == 2
+                && d.len() == 2
+                && [y, m, d]
+                    .iter()
+                    .all(|p| p.bytes().all(|b| b.is_ascii_digit())) =>
+        {
+            let year: u32 = y.parse().unwrap_or(0);
+            let month: u32 = m.parse().unwrap_or(0);
+            let day: u32 = d.parse().unwrap_or(0);
+            let leap = (year % 4 == 0 && year % 100 != 0) || year % 400 == 0;
+            let days_in_month = match month {
+                1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+                4 | 6 | 9 | 11 => 30,
+                2 if leap => 29,
+                2 => 28,
+                _ => 0,
+            };
+            (1..=days_in_month).contains(&day)
+        }
+        _ => false,
+    };
+
+    if valid {
+        Ok(())
+    } else {
+        let mut error = ValidationError::new("invalid_date");
+        error.message = Some("Must be a date in YYYY-MM-DD format".into());
+        Err(error)
+    }
+}
+
+/// Validate that a string is either empty or an ISO 8601 partial date
+/// (`YYYY`, `YYYY-MM`, or `YYYY-MM-DD`).
+pub fn validate_optional_partial_date(date: &str) -> Result<(), ValidationError> {
+    if date.is_empty() || parse_iso_partial_date(date).is_some() {
+        return Ok(());
+    }
+
+    let mut error = ValidationError::new("invalid_date");
+    error.message = Some("Must be a date in YYYY, YYYY-MM, or YYYY-MM-DD format".into());
+    Err(error)
+}
+
 /// Validate that a hex color is valid (#RRGGBB format).
 pub fn validate_hex_color(color: &str) -> Result<(), ValidationError> {
     if color.is_empty() {
@@ -83,6 +156,642 @@ pub fn validate_hex_color_with_optional_alpha(color: &str) -> Result<(), Validat
     }
 }
 
+/// Flatten validation errors into `path: message` strings.
+///
+/// Nested struct and list errors are included, with paths such as
+/// `sections.experience.items[0].url.href`.
+pub fn validation_messages(errors: &ValidationErrors) -> Vec<String> {
+    validation_issues(errors)
+        .iter()
+        .map(ToString::to_string)
+        .collect()
+}
+
+/// Flatten validation errors into [`Severity::Error`] issues, one per failed
+/// check, with the same paths as [`validation_messages`] and the
+/// validator's code (`invalid_url`, `range`).
+pub fn validation_issues(errors: &ValidationErrors) -> Vec<ValidationIssue> {
+    fn collect_errors(errors: &ValidationErrors, prefix: &str, result: &mut Vec<ValidationIssue>) {
+        // Collect field errors
+        for (field, errs) in errors.field_errors() {
+            // Struct-level (`schema`) errors belong to the struct itself
+            let field_path = if field == "__all__" {
+                prefix.to_string()
+            } else if prefix.is_empty() {
+                field.to_string()
+            } else {
+                format!("{}.{}", prefix, field)
+            };
+            for e in errs {
+                let message = e
+                    .message
+                    .as_ref()
+                    .map(|m| m.to_string())
+                    .unwrap_or_else(|| e.code.to_string());
+                result.push(ValidationIssue::new(
+                    Severity::Error,
+                    field_path.clone(),
+                    &e.code,
+                    message,
+                ));
+            }
+        }
+
+        // Recursively collect nested struct and list errors
+        for (field, nested) in errors.errors() {
+            let field_path = if prefix.is_empty() {
+                field.to_string()
+            } else {
+                format!("{}.{}", prefix, field)
+            };
+            match nested {
+                ValidationErrorsKind::Struct(nested_errors) => {
+                    collect_errors(nested_errors.as_ref(), &field_path, result);
+                }
+                ValidationErrorsKind::List(list_errors) => {
+                    for (idx, nested_errors) in list_errors.iter() {
+                        let indexed_path = format!("{}[{}]", field_path, idx);
+                        collect_errors(nested_errors.as_ref(), &indexed_path, result);
+                    }
+                }
+                ValidationErrorsKind::Field(_) => {
+                    // Already handled by field_errors() above
+                }
+            }
+        }
+    }
+
+    let mut result = Vec::new();
+    collect_errors(errors, "", &mut result);
+    result
+}
+
+/// How serious a [`ValidationIssue`] is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema, TypeScript)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    /// The data contradicts itself; the resume is invalid.
+    Error,
+    /// Valid, but likely to render poorly or read as a mistake.
+    Warning,
+}
+
+/// A problem found by a semantic [`ValidationRule`].
+///
+/// Unlike the field validators above, rules look across fields and items
+/// (date ranges, duplicate IDs, theme contrast). Only [`Severity::Error`]
+/// issues make a resume invalid.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema, TypeScript)]
+pub struct ValidationIssue {
+    /// Dotted path of the field the issue applies to.
+    #[schema(example = "metadata.theme.text")]
+    pub path: String,
+
+    /// Machine-readable issue code.
+    #[schema(example = "low_contrast")]
+    pub code: String,
+
+    /// Whether the issue is an error or a warning.
+    pub severity: Severity,
+
+    /// Human-readable description of the problem.
+    pub message: String,
+
+    /// Suggested replacement value, when one can be computed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub suggestion: Option<String>,
+}
+
+impl ValidationIssue {
+    /// An issue with no suggestion.
+    pub fn new(
+        severity: Severity,
+        path: impl Into<String>,
+        code: &str,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            path: path.into(),
+            code: code.to_string(),
+            severity,
+            message: message.into(),
+            suggestion: None,
+        }
+    }
+}
+
+impl fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)?;
+        if let Some(suggestion) = &self.suggestion {
+            write!(f, " (suggested: {})", suggestion)?;
+        }
+        Ok(())
+    }
+}
+
+/// A semantic check over a whole resume.
+///
+/// Any `Fn(&ResumeData) -> Vec<ValidationIssue>` is a rule, so callers can
+/// add their own checks to a [`RuleSet`].
+pub trait ValidationRule: Send + Sync {
+    fn check(&self, resume: &ResumeData) -> Vec<ValidationIssue>;
+}
+
+impl<F> ValidationRule for F
+where
+    F: Fn(&ResumeData) -> Vec<ValidationIssue> + Send + Sync,
+{
+    fn check(&self, resume: &ResumeData) -> Vec<ValidationIssue> {
+        self(resume)
+    }
+}
+
+/// Issues found by a [`RuleSet`], split by severity.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, ToSchema, TypeScript)]
+pub struct RuleReport {
+    pub errors: Vec<ValidationIssue>,
+    pub warnings: Vec<ValidationIssue>,
+}
+
+impl RuleReport {
+    /// Whether no rule reported an error.
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+/// An ordered list of [`ValidationRule`]s.
+///
+/// The default set holds every built-in rule: [`date_order_errors`],
+/// [`duplicate_id_errors`], [`layout_errors`],
+/// [`overlapping_position_warnings`], [`employment_gap_warnings`],
+/// [`contact_warnings`], [`insecure_url_warnings`], [`attachment_warnings`],
+/// [`language_level_warnings`], and theme contrast.
+pub struct RuleSet {
+    rules: Vec<Box<dyn ValidationRule>>,
+}
+
+impl Default for RuleSet {
+    fn default() -> Self {
+        Self::empty()
+            .with(date_order_errors)
+            .with(duplicate_id_errors)
+            .with(layout_errors)
+            .with(overlapping_position_warnings)
+            .with(employment_gap_warnings)
+            .with(|resume: &ResumeData| contact_warnings(&resume.basics))
+            .with(insecure_url_warnings)
+            .with(attachment_warnings)
+            .with(language_level_warnings)
+            .with(|resume: &ResumeData| theme_contrast_warnings(&resume.metadata.theme))
+    }
+}
+
+impl RuleSet {
+    /// A set with no rules.
+    pub fn empty() -> Self {
+        Self { rules: Vec::new() }
+    }
+
+    /// Add a rule, run after the existing ones.
+    pub fn with(mut self, rule: impl ValidationRule + 'static) -> Self {
+        self.rules.push(Box::new(rule));
+        self
+    }
+
+    /// Run every rule and split the issues by severity, in rule order.
+    pub fn check(&self, resume: &ResumeData) -> RuleReport {
+        let mut report = RuleReport::default();
+        for issue in self.rules.iter().flat_map(|rule| rule.check(resume)) {
+            match issue.severity {
+                Severity::Error => report.errors.push(issue),
+                Severity::Warning => report.warnings.push(issue),
+            }
+        }
+        report
+    }
+}
+
+/// Check theme colors against WCAG AA contrast minimums.
+///
+/// Body text must reach 4.5:1 against the background. The primary color is
+/// used for headings, icons, and rules, so it is held to the 3:1 minimum for
+/// large text and graphical elements. Colors that fail to parse are skipped;
+/// the hex color validators already report those as errors.
+pub fn theme_contrast_warnings(theme: &Theme) -> Vec<ValidationIssue> {
+    let checks = [
+        ("metadata.theme.text", &theme.text, WCAG_AA_NORMAL_TEXT),
+        ("metadata.theme.primary", &theme.primary, WCAG_AA_LARGE_TEXT),
+    ];
+
+    checks
+        .into_iter()
+        .filter_map(|(path, color, minimum)| {
+            let ratio = contrast_ratio(color, &theme.background)?;
+            if ratio >= minimum {
+                return None;
+            }
+            Some(ValidationIssue {
+                suggestion: suggest_compliant_color(color, &theme.background, minimum),
+                ..ValidationIssue::new(
+                    Severity::Warning,
+                    path,
+                    "low_contrast",
+                    format!(
+                        "Contrast ratio {:.2}:1 against background {} is below the WCAG AA minimum of {}:1",
+                        ratio, theme.background, minimum
+                    ),
+                )
+            })
+        })
+        .collect()
+}
+
+/// Report item date ranges that end before they start, in every section.
+///
+/// Structured `startDate`/`endDate` take precedence over the display `date`.
+/// Display dates the range parser does not recognize are skipped; they are
+/// free text.
+pub fn date_order_errors(resume: &ResumeData) -> Vec<ValidationIssue> {
+    let Ok(sections) = serde_json::to_value(&resume.sections) else {
+        return Vec::new();
+    };
+
+    let mut issues = Vec::new();
+    for (section, items, _) in item_sections(&sections) {
+        for (index, item) in items.iter().enumerate() {
+            let date = text(item, "date");
+            let start_date = item.get("startDate").and_then(Value::as_str);
+            let end_date = item.get("endDate").and_then(Value::as_str);
+            let Some(DateRange {
+                start,
+                end: Some(end),
+            }) = item_date_range(date, start_date, end_date)
+            else {
+                continue;
+            };
+            if !end.is_before(&start) {
+                continue;
+            }
+            let (field, shown) = match start_date.and_then(parse_iso_partial_date) {
+                Some(_) => ("endDate", format!("{start} - {end}")),
+                None => ("date", date.trim().to_string()),
+            };
+            issues.push(ValidationIssue::new(
+                Severity::Error,
+                format!("sections.{section}.items[{index}].{field}"),
+                "date_order",
+                format!("\"{shown}\" ends before it starts"),
+            ));
+        }
+    }
+    issues
+}
+
+/// Report visible experience entries whose date ranges overlap.
+///
+/// Ranges are compared at the precision both give, so "2018 - 2020" and
+/// "2020 - 2022" do not overlap but "Jan 2019 - Mar 2020" and "Feb 2020 -
+/// Present" do. Concurrent roles are legitimate, hence a warning.
+pub fn overlapping_position_warnings(resume: &ResumeData) -> Vec<ValidationIssue> {
+    let today = PartialDate::today();
+    let ranges: Vec<(usize, &str, PartialDate, PartialDate)> = resume
+        .sections
+        .experience
+        .items
+        .iter()
+        .enumerate()
+        .filter(|(_, item)| item.visible)
+        .filter_map(|(index, item)| {
+            let range = item.date_range()?;
+            Some((
+                index,
+                item.company.as_str(),
+                range.start,
+                range.end.unwrap_or(today),
+            ))
+        })
+        .collect();
+
+    let mut issues = Vec::new();
+    for (i, &(index, _, start, end)) in ranges.iter().enumerate() {
+        for &(other, other_company, other_start, other_end) in &ranges[..i] {
+            if start.is_before(&other_end) && other_start.is_before(&end) {
+                issues.push(ValidationIssue::new(
+                    Severity::Warning,
+                    format!("sections.experience.items[{index}].date"),
+                    "overlapping_positions",
+                    format!(
+                        "Overlaps with {} (sections.experience.items[{other}])",
+                        if other_company.is_empty() {
+                            "another position"
+                        } else {
+                            other_company
+                        }
+                    ),
+                ));
+            }
+        }
+    }
+    issues
+}
+
+/// Email ccTLDs and the calling code of their country. Generic-use ccTLDs
+/// such as `.io`, `.co`, and `.me` are left out.
+const CCTLD_CALLING_CODES: &[(&str, &str)] = &[
+    ("at", "43"),
+    ("au", "61"),
+    ("be", "32"),
+    ("br", "55"),
+    ("ca", "1"),
+    ("ch", "41"),
+    ("cn", "86"),
+    ("cz", "420"),
+    ("de", "49"),
+    ("dk", "45"),
+    ("es", "34"),
+    ("fi", "358"),
+    ("fr", "33"),
+    ("gr", "30"),
+    ("hk", "852"),
+    ("ie", "353"),
+    ("il", "972"),
+    ("in", "91"),
+    ("it", "39"),
+    ("jp", "81"),
+    ("kr", "82"),
+    ("mx", "52"),
+    ("nl", "31"),
+    ("no", "47"),
+    ("nz", "64"),
+    ("pl", "48"),
+    ("pt", "351"),
+    ("ru", "7"),
+    ("se", "46"),
+    ("sg", "65"),
+    ("uk", "44"),
+    ("za", "27"),
+];
+
+/// Check the email for a mistyped provider domain, and that the phone number
+/// looks dialable and agrees with the email's country.
+///
+/// Typos such as `gamil.com` are reported with the corrected address as the
+/// suggestion. A phone is plausible as described for [`is_plausible_phone`].
+/// When the phone is international (`+...`) and the email uses a
+/// country-code domain, the calling codes should match.
+pub fn contact_warnings(basics: &Basics) -> Vec<ValidationIssue> {
+    let mut issues = Vec::new();
+    if let Some(suggestion) = suggest_email(&basics.email) {
+        issues.push(ValidationIssue {
+            suggestion: Some(suggestion.clone()),
+            ..ValidationIssue::new(
+                Severity::Warning,
+                "basics.email",
+                "email_typo",
+                format!("Email domain looks mistyped; did you mean {suggestion}?"),
+            )
+        });
+    }
+    issues.extend(phone_warnings(basics));
+    issues
+}
+
+fn phone_warnings(basics: &Basics) -> Vec<ValidationIssue> {
+    let phone = basics.phone.trim();
+    if phone.is_empty() {
+        return Vec::new();
+    }
+
+    let (number, _) = split_phone_extension(phone);
+    let digits: String = number.chars().filter(char::is_ascii_digit).collect();
+    if !is_plausible_phone(phone) {
+        return vec![ValidationIssue::new(
+            Severity::Warning,
+            "basics.phone",
+            "invalid_phone",
+            "Phone number should have 7 to 15 digits and only digits, spaces, and +-()./",
+        )];
+    }
+
+    let tld = basics
+        .email
+        .rsplit('.')
+        .next()
+        .unwrap_or_default()
+        .to_lowercase();
+    let expected = CCTLD_CALLING_CODES
+        .iter()
+        .find(|(domain, _)| *domain == tld)
+        .map(|(_, code)| *code);
+    match expected {
+        Some(code) if number.starts_with('+') && !digits.starts_with(code) => {
+            vec![ValidationIssue::new(
+                Severity::Warning,
+                "basics.phone",
+                "contact_country_mismatch",
+                format!(
+                    "Phone number does not use the +{code} calling code expected for a .{tld} email address"
+                ),
+            )]
+        }
+        _ => Vec::new(),
+    }
+}
+
+/// Report `http://` links anywhere in the resume, suggesting the `https://`
+/// form.
+pub fn insecure_url_warnings(resume: &ResumeData) -> Vec<ValidationIssue> {
+    fn walk(value: &Value, path: &str, issues: &mut Vec<ValidationIssue>) {
+        match value {
+            Value::Object(map) => {
+                for (key, value) in map {
+                    walk(value, &format!("{path}.{key}"), issues);
+                }
+            }
+            Value::Array(items) => {
+                for (index, value) in items.iter().enumerate() {
+                    walk(value, &format!("{path}[{index}]"), issues);
+                }
+            }
+            Value::String(url) if path.ends_with(".href") || path.ends_with(".url") => {
+                let Some(rest) = url
+                    .get(..7)
+                    .filter(|scheme| scheme.eq_ignore_ascii_case("http://"))
+                    .map(|_| &url[7..])
+                else {
+                    return;
+                };
+                issues.push(ValidationIssue {
+                    suggestion: Some(format!("https://{rest}")),
+                    ..ValidationIssue::new(
+                        Severity::Warning,
+                        path,
+                        "insecure_url",
+                        "Link does not use HTTPS",
+                    )
+                });
+            }
+            _ => {}
+        }
+    }
+
+    let mut issues = Vec::new();
+    for (name, value) in [
+        ("basics", serde_json::to_value(&resume.basics)),
+        ("sections", serde_json::to_value(&resume.sections)),
+        ("attachments", serde_json::to_value(&resume.attachments)),
+    ] {
+        if let Ok(value) = value {
+            walk(&value, name, &mut issues);
+        }
+    }
+    issues
+}
+
+/// Report visible attachments that lack what their mode needs: a file to
+/// append or a link to encode. The renderer skips these.
+pub fn attachment_warnings(resume: &ResumeData) -> Vec<ValidationIssue> {
+    resume
+        .attachments
+        .iter()
+        .enumerate()
+        .filter(|(_, attachment)| attachment.visible && !attachment.is_presentable())
+        .map(|(index, attachment)| match attachment.mode {
+            AttachmentMode::Append => ValidationIssue::new(
+                Severity::Warning,
+                format!("attachments[{index}].file"),
+                "missing_attachment_file",
+                format!("\"{}\" has no file to append", attachment.name),
+            ),
+            AttachmentMode::QrCode => ValidationIssue::new(
+                Severity::Warning,
+                format!("attachments[{index}].url"),
+                "missing_attachment_url",
+                format!("\"{}\" has no link for a QR code", attachment.name),
+            ),
+        })
+        .collect()
+}
+
+/// Report languages whose 0–5 `level` is more than a step from their CEFR
+/// level's, suggesting the level that matches the CEFR code (see
+/// [`CefrLevel::level`]). A step either way is allowed, since imports grade
+/// on their own scales: LinkedIn's "Full professional proficiency" is C1
+/// at level 5.
+///
+/// [`CefrLevel::level`]: crate::CefrLevel::level
+pub fn language_level_warnings(resume: &ResumeData) -> Vec<ValidationIssue> {
+    resume
+        .sections
+        .languages
+        .items
+        .iter()
+        .enumerate()
+        .filter_map(|(index, language)| {
+            let cefr = language.cefr?;
+            (cefr.level().abs_diff(language.level) > 1).then(|| ValidationIssue {
+                suggestion: Some(cefr.level().to_string()),
+                ..ValidationIssue::new(
+                    Severity::Warning,
+                    format!("sections.languages.items[{index}].level"),
+                    "cefr_level_mismatch",
+                    format!(
+                        "\"{}\" is {cefr} but has level {} of 5; {cefr} is level {}",
+                        language.name,
+                        language.level,
+                        cefr.level()
+                    ),
+                )
+            })
+        })
+        .collect()
+}
+
+/// Report section and item IDs used more than once.
+///
+/// Section IDs (including custom sections) must be unique among sections,
+/// and item IDs across the whole resume, since editors address items by ID.
+/// Empty IDs are ignored.
+pub fn duplicate_id_errors(resume: &ResumeData) -> Vec<ValidationIssue> {
+    let Ok(sections) = serde_json::to_value(&resume.sections) else {
+        return Vec::new();
+    };
+
+    let mut section_ids = Vec::new();
+    if let Some(map) = sections.as_object() {
+        for (name, section) in map {
+            if name == "custom" {
+                for (key, section) in section.as_object().into_iter().flatten() {
+                    section_ids.push((format!("sections.custom.{key}.id"), text(section, "id")));
+                }
+            } else {
+                section_ids.push((format!("sections.{name}.id"), text(section, "id")));
+            }
+        }
+    }
+
+    let item_ids = item_sections(&sections)
+        .into_iter()
+        .flat_map(|(section, items, _)| {
+            items.iter().enumerate().map(move |(index, item)| {
+                (
+                    format!("sections.{section}.items[{index}].id"),
+                    text(item, "id"),
+                )
+            })
+        })
+        .collect();
+
+    let mut issues = repeated_ids(section_ids);
+    issues.extend(repeated_ids(item_ids));
+    issues
+}
+
+/// Report layout entries that name no section or repeat one.
+///
+/// See [`Layout::check`](crate::Layout::check). Sections missing from the layout are not an error;
+/// they are simply not rendered.
+pub fn layout_errors(resume: &ResumeData) -> Vec<ValidationIssue> {
+    resume
+        .metadata
+        .layout
+        .check(&resume.sections)
+        .into_iter()
+        .map(|error| {
+            let code = match error {
+                LayoutError::UnknownSection(_) => "unknown_layout_section",
+                LayoutError::DuplicateSection(_) => "duplicate_layout_section",
+                LayoutError::SectionNotFound(_) => "missing_layout_section",
+            };
+            ValidationIssue::new(Severity::Error, "metadata.layout", code, error.to_string())
+        })
+        .collect()
+}
+
+fn repeated_ids(ids: Vec<(String, &str)>) -> Vec<ValidationIssue> {
+    let mut first_seen: HashMap<&str, String> = HashMap::new();
+    let mut issues = Vec::new();
+    for (path, id) in ids {
+        if id.is_empty() {
+            continue;
+        }
+        match first_seen.get(id) {
+            Some(first) => issues.push(ValidationIssue::new(
+                Severity::Error,
+                path,
+                "duplicate_id",
+                format!("ID \"{id}\" is already used by {first}"),
+            )),
+            None => {
+                first_seen.insert(id, path);
+            }
+        }
+    }
+    issues
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -118,6 +827,22 @@ mod tests {
         assert!(validate_optional_email("user@").is_err());
     }
 
+    #[test]
+    fn test_validate_optional_date() {
+        // Empty is valid
+        assert!(validate_optional_date("").is_ok());
+
+        // Valid dates
+        assert!(validate_optional_date("2024-01-31").is_ok());
+        assert!(validate_optional_date("2024-02-29").is_ok()); // Leap year
+
+        // Invalid dates
+        assert!(validate_optional_date("2023-02-29").is_err());
+        assert!(validate_optional_date("2024-13-01").is_err());
+        assert!(validate_optional_date("2024-1-01").is_err());
+        assert!(validate_optional_date("Jan 2024").is_err());
+    }
+
     #[test]
     fn test_validate_hex_color() {
         // Empty is valid
@@ -148,4 +873,292 @@ mod tests {
         assert!(validate_hex_color_with_optional_alpha("#000000gg").is_err());
         assert!(validate_hex_color_with_optional_alpha("##00000040").is_err());
     }
+
+    fn theme(background: &str, text: &str, primary: &str) -> Theme {
+        Theme {
+            background: background.to_string(),
+            text: text.to_string(),
+            primary: primary.to_string(),
+            preset: None,
+        }
+    }
+
+    #[test]
+    fn test_theme_contrast_default_theme_passes() {
+        assert!(theme_contrast_warnings(&Theme::default()).is_empty());
+    }
+
+    #[test]
+    fn test_theme_contrast_flags_low_contrast_text() {
+        let warnings = theme_contrast_warnings(&theme("#ffffff", "#aaaaaa", "#000000"));
+        assert_eq!(warnings.len(), 1);
+
+        let warning = &warnings[0];
+        assert_eq!(warning.path, "metadata.theme.text");
+        assert_eq!(warning.code, "low_contrast");
+        let suggestion = warning.suggestion.as_deref().expect("suggestion");
+        assert!(contrast_ratio(suggestion, "#ffffff").unwrap() >= WCAG_AA_NORMAL_TEXT);
+    }
+
+    #[test]
+    fn test_theme_contrast_primary_uses_large_text_minimum() {
+        // ~3.3:1 on white: fails body text, passes the 3:1 large-text minimum.
+        assert!(theme_contrast_warnings(&theme("#ffffff", "#000000", "#8a8a8a")).is_empty());
+
+        let warnings = theme_contrast_warnings(&theme("#ffffff", "#000000", "#fde047"));
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].path, "metadata.theme.primary");
+    }
+
+    #[test]
+    fn test_theme_contrast_skips_unparseable_colors() {
+        assert!(theme_contrast_warnings(&theme("", "#ffffff", "#ffffff")).is_empty());
+        assert!(theme_contrast_warnings(&theme("#ffffff", "white", "")).is_empty());
+    }
+
+    #[test]
+    fn test_validation_messages_include_nested_paths() {
+        use validator::Validate;
+
+        let mut resume = crate::ResumeData::default();
+        resume.basics.email = "nope".to_string();
+        resume
+            .sections
+            .experience
+            .add_item(crate::Experience::new("Acme", "Engineer").with_url("not-a-url"));
+
+        let messages = validation_messages(&resume.validate().unwrap_err());
+        assert!(messages.contains(&"basics.email: Must be a valid email address".to_string()));
+        assert!(messages
+            .iter()
+            .any(|m| m.starts_with("sections.experience.items[0].url.href: ")));
+    }
+
+    #[test]
+    fn test_validation_issue_display() {
+        let warning = ValidationIssue {
+            suggestion: Some("#595959".to_string()),
+            ..ValidationIssue::new(
+                Severity::Warning,
+                "metadata.theme.text",
+                "low_contrast",
+                "too faint",
+            )
+        };
+        assert_eq!(
+            warning.to_string(),
+            "metadata.theme.text: too faint (suggested: #595959)"
+        );
+    }
+
+    fn codes(issues: &[ValidationIssue]) -> Vec<&str> {
+        issues.iter().map(|issue| issue.code.as_str()).collect()
+    }
+
+    fn with_experience(dates: &[&str]) -> ResumeData {
+        let mut resume = ResumeData::default();
+        for (i, date) in dates.iter().enumerate() {
+            resume.sections.experience.add_item(
+                crate::Experience::new(format!("Company {i}"), "Engineer").with_date(*date),
+            );
+        }
+        resume
+    }
+
+    #[test]
+    fn test_date_order_flags_reversed_ranges() {
+        let mut resume = with_experience(&["2022 - 2019", "Jan 2020 - Present", "Spring term"]);
+        resume
+            .sections
+            .education
+            .add_item(crate::Education::new("MIT", "CS").with_date("2018-09 to 2018-06"));
+
+        resume.sections.projects.add_item(
+            crate::Project::new("Rustume")
+                .with_date("2019 - 2020")
+                .with_dates("2021-04", Some("2021-01".to_string())),
+        );
+
+        let issues = date_order_errors(&resume);
+        assert_eq!(issues.len(), 3);
+        assert_eq!(issues[0].path, "sections.experience.items[0].date");
+        assert_eq!(issues[0].severity, Severity::Error);
+        assert_eq!(issues[1].path, "sections.education.items[0].date");
+        assert_eq!(issues[2].path, "sections.projects.items[0].endDate");
+        assert_eq!(
+            issues[2].message,
+            "\"2021-04 - 2021-01\" ends before it starts"
+        );
+    }
+
+    #[test]
+    fn test_overlapping_positions() {
+        let resume = with_experience(&["2018 - 2020", "2020 - 2022", "Mar 2021 - Present"]);
+        let issues = overlapping_position_warnings(&resume);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].path, "sections.experience.items[2].date");
+        assert!(issues[0].message.contains("Company 1"));
+
+        let mut resume = resume;
+        resume.sections.experience.items[2].visible = false;
+        assert!(overlapping_position_warnings(&resume).is_empty());
+    }
+
+    #[test]
+    fn test_contact_warnings() {
+        let mut basics = Basics {
+            email: "jane@example.de".to_string(),
+            phone: "+49 30 1234567 ext. 12".to_string(),
+            ..Basics::default()
+        };
+        assert!(contact_warnings(&basics).is_empty());
+
+        basics.phone = "+44 20 7946 0958".to_string();
+        assert_eq!(
+            codes(&contact_warnings(&basics)),
+            ["contact_country_mismatch"]
+        );
+
+        // National numbers and generic domains carry no country to compare.
+        basics.phone = "030 1234567".to_string();
+        assert!(contact_warnings(&basics).is_empty());
+        basics.email = "jane@example.io".to_string();
+        basics.phone = "+1 555 010 9999".to_string();
+        assert!(contact_warnings(&basics).is_empty());
+
+        basics.email = "jane@gamil.com".to_string();
+        basics.phone = "+1 555 010 9999".to_string();
+        let issues = contact_warnings(&basics);
+        assert_eq!(codes(&issues), ["email_typo"]);
+        assert_eq!(issues[0].suggestion.as_deref(), Some("jane@gmail.com"));
+
+        basics.email = "jane@example.io".to_string();
+        for phone in ["12345", "call me", "555-0100 +1"] {
+            basics.phone = phone.to_string();
+            assert_eq!(
+                codes(&contact_warnings(&basics)),
+                ["invalid_phone"],
+                "{phone}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_insecure_urls_suggest_https() {
+        let mut resume = ResumeData::default();
+        resume.basics.url.href = "http://jane.dev".to_string();
+        resume
+            .sections
+            .experience
+            .add_item(crate::Experience::new("Acme", "Engineer").with_url("https://acme.example"));
+
+        let issues = insecure_url_warnings(&resume);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].path, "basics.url.href");
+        assert_eq!(issues[0].suggestion.as_deref(), Some("https://jane.dev"));
+    }
+
+    #[test]
+    fn test_attachments_missing_their_source() {
+        let mut resume = ResumeData {
+            attachments: vec![
+                crate::Attachment::new("Letter", "data:application/pdf;base64,JVBERi0="),
+                crate::Attachment::new("Certificate", ""),
+                crate::Attachment::link("Portfolio", ""),
+                crate::Attachment::link("Transcript", "http://example.com/transcript"),
+            ],
+            ..ResumeData::default()
+        };
+        resume.attachments[1].visible = false;
+
+        let issues = attachment_warnings(&resume);
+        assert_eq!(codes(&issues), ["missing_attachment_url"]);
+        assert_eq!(issues[0].path, "attachments[2].url");
+
+        resume.attachments[1].visible = true;
+        assert_eq!(
+            codes(&attachment_warnings(&resume)),
+            ["missing_attachment_file", "missing_attachment_url"]
+        );
+        assert_eq!(insecure_url_warnings(&resume)[0].path, "attachments[3].url");
+    }
+
+    #[test]
+    fn test_language_levels_agree_with_cefr() {
+        let mut resume = ResumeData::default();
+        resume.sections.languages.items = vec![
+            crate::Language::new("German").with_cefr_level(crate::CefrLevel::C1),
+            crate::Language::new("French").with_level(4),
+            crate::Language::new("Polish")
+                .with_cefr_level(crate::CefrLevel::C1)
+                .with_level(5),
+            crate::Language::new("Dutch")
+                .with_cefr_level(crate::CefrLevel::A1)
+                .with_level(3),
+        ];
+
+        let issues = language_level_warnings(&resume);
+        assert_eq!(codes(&issues), ["cefr_level_mismatch"]);
+        assert_eq!(issues[0].path, "sections.languages.items[3].level");
+        assert_eq!(issues[0].suggestion.as_deref(), Some("1"));
+        assert_eq!(
+            issues[0].message,
+            "\"Dutch\" is A1 but has level 3 of 5; A1 is level 1"
+        );
+    }
+
+    #[test]
+    fn test_duplicate_ids() {
+        let mut resume = ResumeData::default();
+        let first = crate::Skill::new("Rust");
+        let mut second = crate::Skill::new("Go");
+        second.id.clone_from(&first.id);
+        resume.sections.skills.add_item(first);
+        resume.sections.skills.add_item(second);
+        resume
+            .sections
+            .custom
+            .insert("talks".to_string(), crate::Section::new("skills", "Talks"));
+
+        let issues = duplicate_id_errors(&resume);
+        let paths: Vec<&str> = issues.iter().map(|issue| issue.path.as_str()).collect();
+        assert_eq!(paths, ["sections.skills.id", "sections.skills.items[1].id"]);
+        assert!(issues[1].message.contains("sections.skills.items[0].id"));
+    }
+
+    #[test]
+    fn test_layout_errors() {
+        let mut resume = ResumeData::default();
+        assert!(layout_errors(&resume).is_empty());
+
+        resume.metadata.layout = vec![vec![vec![
+            "summary".to_string(),
+            "hobbies".to_string(),
+            "summary".to_string(),
+        ]]]
+        .into();
+        let issues = layout_errors(&resume);
+        assert_eq!(
+            codes(&issues),
+            ["unknown_layout_section", "duplicate_layout_section"]
+        );
+        assert_eq!(issues[0].path, "metadata.layout");
+        assert!(issues[0].message.contains("hobbies"));
+    }
+
+    #[test]
+    fn test_rule_set_splits_by_severity() {
+        let mut resume = with_experience(&["2022 - 2019"]);
+        resume.basics.url.href = "http://jane.dev".to_string();
+
+        let report = RuleSet::default().check(&resume);
+        assert_eq!(codes(&report.errors), ["date_order"]);
+        assert_eq!(codes(&report.warnings), ["insecure_url"]);
+        assert!(!report.is_valid());
+
+        let custom = RuleSet::empty()
+            .with(|resume: &ResumeData| theme_contrast_warnings(&resume.metadata.theme));
+        assert!(custom.check(&resume).is_valid());
+        assert!(RuleSet::default().check(&ResumeData::default()).is_valid());
+    }
 }
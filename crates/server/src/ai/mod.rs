@@ -0,0 +1,417 @@
+//! Optional AI rewrite suggestions (`ai` feature).
+//!
+//! A [`CompletionProvider`] turns a prompt into text. Two providers ship with
+//! the server: any OpenAI-compatible chat completions API, and a local Ollama
+//! instance. Each request costs a model call, so callers must present one of
+//! the API keys in `RUSTUME_API_KEYS` (see [`crate::audit::api`]), which also
+//! records the call in the API audit log. Every key is rate limited
+//! independently of cloud-mode limits, and without configured keys AI
+//! requests are refused.
+
+mod ollama;
+mod openai;
+
+pub use ollama::OllamaProvider;
+pub use openai::OpenAiCompatibleProvider;
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use axum::http::HeaderMap;
+use governor::{
+    clock::{Clock, DefaultClock},
+    RateLimiter,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::audit::api::{ApiKeys, API_KEY_HEADER};
+use crate::config::RateLimitConfig;
+use crate::error::ApiError;
+use crate::middleware::rate_limit::RateLimitExceeded;
+
+type KeyedRateLimiter =
+    RateLimiter<String, dashmap::DashMap<String, governor::state::InMemoryState>, DefaultClock>;
+
+/// Maximum characters accepted for a single rewrite.
+pub const MAX_REWRITE_TEXT_LEN: usize = 4_000;
+
+/// Default AI requests per minute for each API key.
+const DEFAULT_AI_RATE_LIMIT_PER_MIN: u32 = 10;
+
+/// Upper bound on a single provider call.
+const AI_HTTP_TIMEOUT_SECS: u64 = 60;
+
+/// Request sent to a completion provider.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompletionRequest {
+    /// Instructions the model must follow.
+    pub system: String,
+    /// The user message.
+    pub prompt: String,
+    /// Upper bound on generated tokens.
+    pub max_tokens: u32,
+    /// Sampling temperature.
+    pub temperature: f32,
+}
+
+/// Errors returned by completion providers.
+#[derive(Debug, thiserror::Error)]
+pub enum AiError {
+    #[error("AI provider request failed: {0}")]
+    Transport(String),
+    #[error("AI provider error ({status}): {body}")]
+    Api { status: u16, body: String },
+    #[error("AI provider returned no text")]
+    EmptyResponse,
+}
+
+/// A text completion backend.
+#[async_trait]
+pub trait CompletionProvider: Send + Sync {
+    /// Provider name reported to clients (e.g. `openai`, `ollama`).
+    fn name(&self) -> &'static str;
+
+    /// Model identifier used for completions.
+    fn model(&self) -> &str;
+
+    /// Generate a completion for `request`.
+    async fn complete(&self, request: &CompletionRequest) -> Result<String, AiError>;
+}
+
+/// Tone for rewritten text.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum RewriteTone {
+    #[default]
+    Professional,
+    Confident,
+    Concise,
+    Friendly,
+}
+
+/// Target length relative to the original text.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum RewriteLength {
+    Shorter,
+    #[default]
+    Same,
+    Longer,
+}
+
+/// Build the completion request for rewriting resume text.
+pub fn rewrite_prompt(
+    text: &str,
+    tone: RewriteTone,
+    length: RewriteLength,
+    context: Option<&str>,
+) -> CompletionRequest {
+    let tone = match tone {
+        RewriteTone::Professional => "professional",
+        RewriteTone::Confident => "confident",
+        RewriteTone::Concise => "concise",
+        RewriteTone::Friendly => "warm but professional",
+    };
+    let length = match length {
+        RewriteLength::Shorter => "noticeably shorter than the original",
+        RewriteLength::Same => "about the same length as the original",
+        RewriteLength::Longer => "somewhat longer than the original, adding clarity not claims",
+    };
+
+    let mut prompt = format!("Rewrite the resume text below in a {tone} tone, {length}.\n");
+    if let Some(context) = context.map(str::trim).filter(|c| !c.is_empty()) {
+        prompt.push_str(&format!("Context: {context}\n"));
+    }
+    prompt.push_str("\nText:\n");
+    prompt.push_str(text);
+
+    CompletionRequest {
+        system: "You edit resume content. Reply with only the rewritten text: no preamble, \
+                 quotes, or commentary. Keep every fact, number, name, and date unchanged and \
+                 never invent achievements. Keep one line per bullet point."
+            .to_string(),
+        prompt,
+        max_tokens: 512,
+        temperature: 0.4,
+    }
+}
+
+/// Provider backend selected by `RUSTUME_AI_PROVIDER`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProviderKind {
+    OpenAi,
+    Ollama,
+}
+
+/// AI settings loaded from the environment.
+#[derive(Clone)]
+pub struct AiConfig {
+    pub provider: ProviderKind,
+    pub base_url: String,
+    pub model: String,
+    pub api_key: Option<String>,
+    pub rate_limit_per_min: u32,
+}
+
+impl std::fmt::Debug for AiConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AiConfig")
+            .field("provider", &self.provider)
+            .field("base_url", &self.base_url)
+            .field("model", &self.model)
+            .field("api_key", &self.api_key.as_ref().map(|_| "<redacted>"))
+            .field("rate_limit_per_min", &self.rate_limit_per_min)
+            .finish()
+    }
+}
+
+impl AiConfig {
+    /// Load settings, or `None` when `RUSTUME_AI_PROVIDER` is unset.
+    ///
+    /// | Variable | Default |
+    /// | --- | --- |
+    /// | `RUSTUME_AI_PROVIDER` | unset (AI disabled); `openai` or `ollama` |
+    /// | `RUSTUME_AI_BASE_URL` | `https://api.openai.com/v1` / `http://localhost:11434` |
+    /// | `RUSTUME_AI_MODEL` | `gpt-4o-mini` / `llama3.1` |
+    /// | `RUSTUME_AI_API_KEY` | unset |
+    /// | `RATE_LIMIT_AI_PER_MIN` | `10` |
+    pub fn from_env() -> Option<Self> {
+        let provider = match env_non_empty("RUSTUME_AI_PROVIDER")?
+            .to_lowercase()
+            .as_str()
+        {
+            "openai" => ProviderKind::OpenAi,
+            "ollama" => ProviderKind::Ollama,
+            other => {
+                tracing::warn!("RUSTUME_AI_PROVIDER={other:?} is not supported; AI disabled");
+                return None;
+            }
+        };
+        let (default_base_url, default_model) = match provider {
+            ProviderKind::OpenAi => ("https://api.openai.com/v1", "gpt-4o-mini"),
+            ProviderKind::Ollama => ("http://localhost:11434", "llama3.1"),
+        };
+
+        let rate_limit_per_min = env_non_empty("RATE_LIMIT_AI_PER_MIN")
+            .and_then(|value| value.parse().ok())
+            .unwrap_or(DEFAULT_AI_RATE_LIMIT_PER_MIN);
+
+        Some(Self {
+            provider,
+            base_url: env_non_empty("RUSTUME_AI_BASE_URL")
+                .unwrap_or_else(|| default_base_url.to_string()),
+            model: env_non_empty("RUSTUME_AI_MODEL").unwrap_or_else(|| default_model.to_string()),
+            api_key: env_non_empty("RUSTUME_AI_API_KEY"),
+            rate_limit_per_min,
+        })
+    }
+
+    /// Build the configured provider.
+    pub fn build_provider(&self) -> Arc<dyn CompletionProvider> {
+        let http = reqwest::Client::builder()
+            .timeout(Duration::from_secs(AI_HTTP_TIMEOUT_SECS))
+            .build()
+            .expect("reqwest client");
+        match self.provider {
+            ProviderKind::OpenAi => Arc::new(OpenAiCompatibleProvider::new(
+                http,
+                &self.base_url,
+                &self.model,
+                self.api_key.clone(),
+            )),
+            ProviderKind::Ollama => {
+                Arc::new(OllamaProvider::new(http, &self.base_url, &self.model))
+            }
+        }
+    }
+}
+
+fn env_non_empty(key: &str) -> Option<String> {
+    std::env::var(key)
+        .ok()
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+/// Provider and per-key rate limiter shared by AI routes.
+pub struct AiState {
+    provider: Arc<dyn CompletionProvider>,
+    limiter: KeyedRateLimiter,
+}
+
+impl AiState {
+    /// Build state from environment configuration.
+    pub fn new(config: &AiConfig) -> Self {
+        Self::with_provider(config.build_provider(), config.rate_limit_per_min)
+    }
+
+    /// Build state around an explicit provider.
+    pub fn with_provider(provider: Arc<dyn CompletionProvider>, rate_limit_per_min: u32) -> Self {
+        Self {
+            provider,
+            limiter: RateLimiter::dashmap(RateLimitConfig::quota_per_minute(rate_limit_per_min)),
+        }
+    }
+
+    /// The configured completion provider.
+    pub fn provider(&self) -> &dyn CompletionProvider {
+        self.provider.as_ref()
+    }
+
+    /// Check the caller's API key against `keys` and its rate limit.
+    ///
+    /// `keys` is the audited key registry; nobody gets in when it is missing
+    /// or empty.
+    pub fn authorize(
+        &self,
+        keys: Option<&ApiKeys>,
+        headers: &HeaderMap,
+    ) -> Result<(), AiAccessError> {
+        let keys = keys
+            .filter(|keys| !keys.is_empty())
+            .ok_or(AiAccessError::NoKeys)?;
+        let name = headers
+            .get(API_KEY_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|presented| keys.identify(presented))
+            .ok_or(AiAccessError::Unauthorized)?;
+
+        let rate_key = format!("ai-key:{name}");
+        self.limiter.check_key(&rate_key).map_err(|not_until| {
+            let wait = not_until.wait_time_from(DefaultClock::default().now());
+            AiAccessError::RateLimited(RateLimitExceeded::new(wait))
+        })
+    }
+}
+
+/// Rejection from [`AiState::authorize`].
+#[derive(Debug)]
+pub enum AiAccessError {
+    /// No API key is configured, so no caller can be admitted.
+    NoKeys,
+    Unauthorized,
+    RateLimited(RateLimitExceeded),
+}
+
+impl axum::response::IntoResponse for AiAccessError {
+    fn into_response(self) -> axum::response::Response {
+        match self {
+            Self::NoKeys => ApiError::forbidden(
+                "AI rewriting requires an API key, and this server has none configured",
+            )
+            .into_response(),
+            Self::Unauthorized => {
+                ApiError::unauthorized("A valid X-Rustume-Api-Key header is required")
+                    .into_response()
+            }
+            Self::RateLimited(exceeded) => exceeded.into_response(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::HeaderValue;
+
+    struct EchoProvider;
+
+    #[async_trait]
+    impl CompletionProvider for EchoProvider {
+        fn name(&self) -> &'static str {
+            "echo"
+        }
+
+        fn model(&self) -> &str {
+            "echo-1"
+        }
+
+        async fn complete(&self, request: &CompletionRequest) -> Result<String, AiError> {
+            Ok(request.prompt.clone())
+        }
+    }
+
+    fn state(per_min: u32) -> AiState {
+        AiState::with_provider(Arc::new(EchoProvider), per_min)
+    }
+
+    fn keys(raw: &str) -> ApiKeys {
+        ApiKeys::parse(raw).unwrap()
+    }
+
+    fn headers_with_key(key: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(API_KEY_HEADER, HeaderValue::from_str(key).unwrap());
+        headers
+    }
+
+    #[test]
+    fn test_rewrite_prompt_includes_constraints() {
+        let request = rewrite_prompt(
+            "Helped ship the app",
+            RewriteTone::Confident,
+            RewriteLength::Shorter,
+            Some("Senior Engineer at Acme"),
+        );
+        assert!(request.prompt.contains("confident tone"));
+        assert!(request.prompt.contains("shorter"));
+        assert!(request.prompt.contains("Context: Senior Engineer at Acme"));
+        assert!(request.prompt.ends_with("Text:\nHelped ship the app"));
+        assert!(request.system.contains("never invent"));
+    }
+
+    #[test]
+    fn test_rewrite_prompt_skips_blank_context() {
+        let request = rewrite_prompt(
+            "x",
+            RewriteTone::default(),
+            RewriteLength::default(),
+            Some(" "),
+        );
+        assert!(!request.prompt.contains("Context:"));
+    }
+
+    #[test]
+    fn test_authorize_requires_configured_key() {
+        let state = state(10);
+        let keys = keys("acme:secret-1,globex:secret-2");
+        assert!(matches!(
+            state.authorize(Some(&keys), &HeaderMap::new()),
+            Err(AiAccessError::Unauthorized)
+        ));
+        assert!(matches!(
+            state.authorize(Some(&keys), &headers_with_key("wrong")),
+            Err(AiAccessError::Unauthorized)
+        ));
+        assert!(state
+            .authorize(Some(&keys), &headers_with_key("secret-2"))
+            .is_ok());
+    }
+
+    #[test]
+    fn test_authorize_rate_limits_per_key() {
+        let state = state(1);
+        let keys = keys("acme:a,globex:b");
+        assert!(state.authorize(Some(&keys), &headers_with_key("a")).is_ok());
+        assert!(matches!(
+            state.authorize(Some(&keys), &headers_with_key("a")),
+            Err(AiAccessError::RateLimited(_))
+        ));
+        assert!(state.authorize(Some(&keys), &headers_with_key("b")).is_ok());
+    }
+
+    #[test]
+    fn test_authorize_refuses_everyone_without_keys() {
+        let state = state(10);
+        assert!(matches!(
+            state.authorize(None, &headers_with_key("anything")),
+            Err(AiAccessError::NoKeys)
+        ));
+        assert!(matches!(
+            state.authorize(Some(&keys("")), &headers_with_key("anything")),
+            Err(AiAccessError::NoKeys)
+        ));
+    }
+}
@@ -0,0 +1,142 @@
+//! Local Ollama provider (`POST {base}/api/chat`).
+
+use async_trait::async_trait;
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+
+use super::{AiError, CompletionProvider, CompletionRequest};
+
+/// Provider for a local or self-hosted Ollama server.
+pub struct OllamaProvider {
+    http: Client,
+    endpoint: String,
+    model: String,
+}
+
+impl OllamaProvider {
+    /// Create a provider for `base_url` (e.g. `http://localhost:11434`).
+    pub fn new(http: Client, base_url: &str, model: &str) -> Self {
+        Self {
+            http,
+            endpoint: format!("{}/api/chat", base_url.trim_end_matches('/')),
+            model: model.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct OllamaChatRequest<'a> {
+    model: &'a str,
+    messages: [OllamaMessage<'a>; 2],
+    stream: bool,
+    options: OllamaOptions,
+}
+
+#[derive(Debug, Serialize)]
+struct OllamaMessage<'a> {
+    role: &'static str,
+    content: &'a str,
+}
+
+#[derive(Debug, Serialize)]
+struct OllamaOptions {
+    temperature: f32,
+    num_predict: u32,
+}
+
+#[derive(Debug, Deserialize)]
+struct OllamaChatResponse {
+    message: Option<OllamaResponseMessage>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OllamaResponseMessage {
+    #[serde(default)]
+    content: String,
+}
+
+fn completion_text(response: OllamaChatResponse) -> Result<String, AiError> {
+    response
+        .message
+        .map(|message| message.content.trim().to_string())
+        .filter(|text| !text.is_empty())
+        .ok_or(AiError::EmptyResponse)
+}
+
+#[async_trait]
+impl CompletionProvider for OllamaProvider {
+    fn name(&self) -> &'static str {
+        "ollama"
+    }
+
+    fn model(&self) -> &str {
+        &self.model
+    }
+
+    async fn complete(&self, request: &CompletionRequest) -> Result<String, AiError> {
+        let payload = OllamaChatRequest {
+            model: &self.model,
+            messages: [
+                OllamaMessage {
+                    role: "system",
+                    content: &request.system,
+                },
+                OllamaMessage {
+                    role: "user",
+                    content: &request.prompt,
+                },
+            ],
+            stream: false,
+            options: OllamaOptions {
+                temperature: request.temperature,
+                num_predict: request.max_tokens,
+            },
+        };
+
+        let response = self
+            .http
+            .post(&self.endpoint)
+            .json(&payload)
+            .send()
+            .await
+            .map_err(|err| AiError::Transport(err.to_string()))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(AiError::Api {
+                status: status.as_u16(),
+                body: body.chars().take(500).collect(),
+            });
+        }
+
+        let body: OllamaChatResponse = response
+            .json()
+            .await
+            .map_err(|err| AiError::Transport(err.to_string()))?;
+        completion_text(body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_completion_text() {
+        let response: OllamaChatResponse = serde_json::from_str(
+            r#"{"model":"llama3.1","message":{"role":"assistant","content":"Shipped v2"},"done":true}"#,
+        )
+        .unwrap();
+        assert_eq!(completion_text(response).unwrap(), "Shipped v2");
+    }
+
+    #[test]
+    fn test_completion_text_missing_message() {
+        let response: OllamaChatResponse = serde_json::from_str(r#"{"done":true}"#).unwrap();
+        assert!(matches!(
+            completion_text(response),
+            Err(AiError::EmptyResponse)
+        ));
+    }
+}
@@ -0,0 +1,159 @@
+//! OpenAI-compatible chat completions provider.
+//!
+//! Works with OpenAI and any server exposing `POST {base}/chat/completions`
+//! (Azure OpenAI proxies, vLLM, LM Studio, OpenRouter, ...).
+
+use async_trait::async_trait;
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+
+use super::{AiError, CompletionProvider, CompletionRequest};
+
+/// Provider for OpenAI-style `/chat/completions` endpoints.
+pub struct OpenAiCompatibleProvider {
+    http: Client,
+    endpoint: String,
+    model: String,
+    api_key: Option<String>,
+}
+
+impl OpenAiCompatibleProvider {
+    /// Create a provider for `base_url` (e.g. `https://api.openai.com/v1`).
+    pub fn new(http: Client, base_url: &str, model: &str, api_key: Option<String>) -> Self {
+        Self {
+            http,
+            endpoint: format!("{}/chat/completions", base_url.trim_end_matches('/')),
+            model: model.to_string(),
+            api_key,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct ChatRequest<'a> {
+    model: &'a str,
+    messages: [ChatMessage<'a>; 2],
+    max_tokens: u32,
+    temperature: f32,
+}
+
+#[derive(Debug, Serialize)]
+struct ChatMessage<'a> {
+    role: &'static str,
+    content: &'a str,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatResponse {
+    #[serde(default)]
+    choices: Vec<ChatChoice>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatChoice {
+    message: ChatResponseMessage,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatResponseMessage {
+    #[serde(default)]
+    content: Option<String>,
+}
+
+/// Extract the first choice's text from a chat completions response.
+fn completion_text(response: ChatResponse) -> Result<String, AiError> {
+    response
+        .choices
+        .into_iter()
+        .next()
+        .and_then(|choice| choice.message.content)
+        .map(|text| text.trim().to_string())
+        .filter(|text| !text.is_empty())
+        .ok_or(AiError::EmptyResponse)
+}
+
+#[async_trait]
+impl CompletionProvider for OpenAiCompatibleProvider {
+    fn name(&self) -> &'static str {
+        "openai"
+    }
+
+    fn model(&self) -> &str {
+        &self.model
+    }
+
+    async fn complete(&self, request: &CompletionRequest) -> Result<String, AiError> {
+        let payload = ChatRequest {
+            model: &self.model,
+            messages: [
+                ChatMessage {
+                    role: "system",
+                    content: &request.system,
+                },
+                ChatMessage {
+                    role: "user",
+                    content: &request.prompt,
+                },
+            ],
+            max_tokens: request.max_tokens,
+            temperature: request.temperature,
+        };
+
+        let mut builder = self.http.post(&self.endpoint).json(&payload);
+        if let Some(api_key) = &self.api_key {
+            builder = builder.bearer_auth(api_key);
+        }
+        let response = builder
+            .send()
+            .await
+            .map_err(|err| AiError::Transport(err.to_string()))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(AiError::Api {
+                status: status.as_u16(),
+                body: body.chars().take(500).collect(),
+            });
+        }
+
+        let body: ChatResponse = response
+            .json()
+            .await
+            .map_err(|err| AiError::Transport(err.to_string()))?;
+        completion_text(body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_endpoint_trims_trailing_slash() {
+        let provider =
+            OpenAiCompatibleProvider::new(Client::new(), "http://localhost:8000/v1/", "m", None);
+        assert_eq!(
+            provider.endpoint,
+            "http://localhost:8000/v1/chat/completions"
+        );
+    }
+
+    #[test]
+    fn test_completion_text() {
+        let response: ChatResponse = serde_json::from_str(
+            r#"{"choices":[{"index":0,"message":{"role":"assistant","content":"  Led the team \n"}}]}"#,
+        )
+        .unwrap();
+        assert_eq!(completion_text(response).unwrap(), "Led the team");
+    }
+
+    #[test]
+    fn test_completion_text_empty() {
+        let response: ChatResponse = serde_json::from_str(r#"{"choices":[]}"#).unwrap();
+        assert!(matches!(
+            completion_text(response),
+            Err(AiError::EmptyResponse)
+        ));
+    }
+}
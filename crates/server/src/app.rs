@@ -13,25 +13,32 @@ use tower_http::{
     limit::RequestBodyLimitLayer,
     trace::TraceLayer,
 };
-use utoipa::OpenApi;
-use utoipa_swagger_ui::SwaggerUi;
+use utoipa_swagger_ui::{Config, SwaggerUi};
 
 use crate::config::MAX_BODY_SIZE;
+#[cfg(feature = "ai")]
+use crate::middleware::ai::require_ai_access;
+use crate::middleware::api_audit::audit_api_calls;
 use crate::middleware::auth::require_auth_when_enabled;
 use crate::middleware::rate_limit::{
     rate_limit_account_delete, rate_limit_auth, rate_limit_billable, rate_limit_health,
     rate_limit_import, rate_limit_metrics, rate_limit_pdf, rate_limit_preview,
-    rate_limit_resume_crud,
+    rate_limit_resume_crud, rate_limit_unauthenticated,
 };
+use crate::middleware::request_id::request_id;
 use crate::middleware::security::security_headers;
 use crate::middleware::subscription::require_subscription_render;
 use crate::observability::apply_sentry_layers;
-use crate::openapi::ApiDoc;
 use crate::routes::{
-    callback, create_resume, delete_account, delete_resume, export_resumes_json,
-    export_resumes_pdf, get_resume, health, import_resumes, list_resumes, list_templates, login,
-    logout, me, metrics, parse, render_pdf, render_preview, security_txt, spa_fallback, static_dir,
-    template_thumbnail, update_resume, update_sharing, validate,
+    analyze, apply_layout_preset, callback, create_application, create_resume, dedupe,
+    dedupe_merge, delete_account, delete_application, delete_resume, diff_report,
+    export_resumes_json, export_resumes_pdf, export_section, fit_pages, get_application,
+    get_content_preset, get_resume, health, import_resumes, list_api_calls, list_applications,
+    list_content_presets, list_resumes, list_templates, list_trashed_resumes, login, logout, me,
+    merge, metrics, openapi_json, parse, parse_csv, parse_linkedin_upload, parse_with_report,
+    public_resume_json, render_pdf, render_pdf_stream, render_preview, render_svg, render_timeline,
+    restore_resume, resume_history, security_txt, spa_fallback, static_dir, stats, suggest,
+    template_thumbnail, update_application, update_resume, update_sharing, validate, OPENAPI_PATH,
 };
 use crate::state::AppState;
 
@@ -53,9 +60,24 @@ pub fn create_router_with_state(state: AppState) -> Router {
 
     let mut billable_core = Router::new()
         .route("/api/templates", get(list_templates))
+        .route("/api/presets", get(list_content_presets))
+        .route("/api/presets/{name}", get(get_content_preset))
         .route("/api/templates/{id}/thumbnail", get(template_thumbnail))
         .route("/api/parse", post(parse))
+        .route("/api/parse/report", post(parse_with_report))
+        .route("/api/parse/csv", post(parse_csv))
+        .route("/api/parse/linkedin", post(parse_linkedin_upload))
+        .route("/api/export/section", post(export_section))
         .route("/api/validate", post(validate))
+        .route("/api/suggest", post(suggest))
+        .route("/api/dedupe", post(dedupe))
+        .route("/api/dedupe/merge", post(dedupe_merge))
+        .route("/api/merge", post(merge))
+        .route("/api/diff/report", post(diff_report))
+        .route("/api/stats", post(stats))
+        .route("/api/analyze", post(analyze))
+        .route("/api/presets/{name}/apply", post(apply_layout_preset))
+        .route("/api/render/timeline", post(render_timeline))
         .route_layer(middleware::from_fn_with_state(
             state.clone(),
             require_auth_when_enabled,
@@ -69,6 +91,8 @@ pub fn create_router_with_state(state: AppState) -> Router {
 
     let mut preview_routes = Router::new()
         .route("/api/render/preview", post(render_preview))
+        .route("/api/render/svg", post(render_svg))
+        .route("/api/fit", post(fit_pages))
         .route_layer(middleware::from_fn_with_state(
             state.clone(),
             require_auth_when_enabled,
@@ -88,6 +112,7 @@ pub fn create_router_with_state(state: AppState) -> Router {
 
     let mut pdf_routes = Router::new()
         .route("/api/render/pdf", post(render_pdf))
+        .route("/api/render/pdf/stream", post(render_pdf_stream))
         .route_layer(middleware::from_fn_with_state(
             state.clone(),
             require_auth_when_enabled,
@@ -122,14 +147,31 @@ pub fn create_router_with_state(state: AppState) -> Router {
     }
 
     let mut router = Router::new()
-        .merge(SwaggerUi::new("/swagger-ui").url("/api-docs/openapi.json", ApiDoc::openapi()))
+        .merge(SwaggerUi::new("/swagger-ui").config(Config::from(OPENAPI_PATH)))
+        .route(OPENAPI_PATH, get(openapi_json))
         .route("/.well-known/security.txt", get(security_txt))
+        .route("/api/admin/api-calls", get(list_api_calls))
         .merge(health_routes)
         .merge(metrics_routes)
         .merge(billable_core)
         .merge(preview_routes)
         .merge(pdf_routes);
 
+    #[cfg(feature = "ai")]
+    {
+        let ai_routes = Router::new()
+            .route("/api/ai/rewrite", post(crate::routes::rewrite))
+            .route_layer(middleware::from_fn_with_state(
+                state.clone(),
+                require_auth_when_enabled,
+            ))
+            .route_layer(middleware::from_fn_with_state(
+                state.clone(),
+                require_ai_access,
+            ));
+        router = router.merge(ai_routes);
+    }
+
     if state.cloud.is_some() {
         let auth_routes = Router::new()
             .route("/auth/login", get(login))
@@ -148,6 +190,9 @@ pub fn create_router_with_state(state: AppState) -> Router {
                 get(get_resume).put(update_resume).delete(delete_resume),
             )
             .route("/api/resumes/{id}/sharing", put(update_sharing))
+            .route("/api/resumes/{id}/history", get(resume_history))
+            .route("/api/resumes/{id}/restore", post(restore_resume))
+            .route("/api/resumes/trash", get(list_trashed_resumes))
             .route_layer(middleware::from_fn_with_state(
                 state.clone(),
                 require_auth_when_enabled,
@@ -159,6 +204,58 @@ pub fn create_router_with_state(state: AppState) -> Router {
             ));
         }
 
+        let mut public_routes =
+            Router::new().route("/u/{slug}/resume.json", get(public_resume_json));
+        if cloud_rate_limits {
+            public_routes = public_routes.route_layer(middleware::from_fn_with_state(
+                state_for_layers.clone(),
+                rate_limit_unauthenticated,
+            ));
+        }
+
+        let mut application_routes = Router::new()
+            .route(
+                "/api/applications",
+                get(list_applications).post(create_application),
+            )
+            .route(
+                "/api/applications/{id}",
+                get(get_application)
+                    .put(update_application)
+                    .delete(delete_application),
+            )
+            .route_layer(middleware::from_fn_with_state(
+                state.clone(),
+                require_auth_when_enabled,
+            ));
+        if cloud_rate_limits {
+            application_routes = application_routes.route_layer(middleware::from_fn_with_state(
+                state_for_layers.clone(),
+                rate_limit_resume_crud,
+            ));
+        }
+
+        let mut webhook_routes = Router::new()
+            .route("/api/webhooks", get(list_webhooks).post(create_webhook))
+            .route(
+                "/api/webhooks/{id}",
+                put(update_webhook).delete(delete_webhook),
+            )
+            .route(
+                "/api/webhooks/{id}/deliveries",
+                get(list_webhook_deliveries),
+            )
+            .route_layer(middleware::from_fn_with_state(
+                state.clone(),
+                require_auth_when_enabled,
+            ));
+        if cloud_rate_limits {
+            webhook_routes = webhook_routes.route_layer(middleware::from_fn_with_state(
+                state_for_layers.clone(),
+                rate_limit_resume_crud,
+            ));
+        }
+
         let mut import_routes = Router::new()
             .route("/api/resumes/import", post(import_resumes))
             .route_layer(middleware::from_fn_with_state(
@@ -213,6 +310,9 @@ pub fn create_router_with_state(state: AppState) -> Router {
         router = router
             .merge(auth_routes)
             .merge(resume_routes)
+            .merge(public_routes)
+            .merge(application_routes)
+            .merge(webhook_routes)
             .merge(import_routes)
             .merge(export_json_routes)
             .merge(export_pdf_routes)
@@ -220,6 +320,11 @@ pub fn create_router_with_state(state: AppState) -> Router {
     }
 
     let router = router
+        // After every route is added, so the audit log sees the matched path
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            audit_api_calls,
+        ))
         .fallback(spa_fallback)
         .with_state(state)
         .layer(middleware::from_fn(security_headers))
@@ -227,7 +332,9 @@ pub fn create_router_with_state(state: AppState) -> Router {
         .layer(cors)
         .layer(TraceLayer::new_for_http())
         .layer(DefaultBodyLimit::disable())
-        .layer(RequestBodyLimitLayer::new(MAX_BODY_SIZE));
+        .layer(RequestBodyLimitLayer::new(MAX_BODY_SIZE))
+        // Outermost, so every log line and error below it carries the ID
+        .layer(middleware::from_fn(request_id));
 
     apply_sentry_layers(router)
 }
@@ -250,6 +357,7 @@ fn build_cors_layer_for_origin(origin: Option<String>) -> CorsLayer {
             header::ACCEPT,
             header::COOKIE,
             header::AUTHORIZATION,
+            header::HeaderName::from_static(crate::audit::api::API_KEY_HEADER),
         ])
         .expose_headers([
             "X-Total-Pages".parse::<header::HeaderName>().unwrap(),
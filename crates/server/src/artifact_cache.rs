@@ -0,0 +1,367 @@
+//! Disk cache for rendered artifacts (PDFs, thumbnails).
+//!
+//! Entries are content-addressed: the file name is a SHA-256 of everything
+//! that affects the output, so an unchanged resume exported twice hits the
+//! same file, and a changed one can never be served a stale render. The cache
+//! survives restarts and is trimmed, least recently used first, to a byte
+//! budget.
+//!
+//! All methods do blocking file I/O; call them from `spawn_blocking`.
+
+use rustume_render::{Branding, RenderEngine};
+use rustume_schema::{canonicalize, ResumeData};
+use sha2::{Digest, Sha256};
+use std::fs::{self, File};
+use std::io;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::SystemTime;
+use tracing::warn;
+
+use crate::config::ArtifactCacheConfig;
+
+/// Extension of finished cache entries; anything else in the directory is ignored.
+const ENTRY_EXTENSION: &str = "bin";
+
+/// Content-addressed key for one rendered artifact.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ArtifactKey(String);
+
+impl ArtifactKey {
+    /// Hash `kind` (e.g. `pdf`) and the render inputs into a key.
+    ///
+    /// The server version is part of every key, so template or renderer
+    /// changes in an upgrade never serve renders made by an older build.
+    pub fn new(kind: &str, inputs: &[&[u8]]) -> Self {
+        let mut hasher = Sha256::new();
+        for part in [env!("CARGO_PKG_VERSION").as_bytes(), kind.as_bytes()]
+            .into_iter()
+            .chain(inputs.iter().copied())
+        {
+            // Length-prefix each part so ("ab", "c") and ("a", "bc") differ
+            hasher.update((part.len() as u64).to_le_bytes());
+            hasher.update(part);
+        }
+        let digest = hasher.finalize();
+        Self(digest.iter().map(|b| format!("{b:02x}")).collect())
+    }
+
+    /// Key for a PDF of `resume` rendered by `engine` with the given
+    /// exclusions and the deployment's `branding`.
+    pub fn pdf(
+        resume: &ResumeData,
+        engine: RenderEngine,
+        exclude_sections: &[String],
+        exclude_item_ids: &[String],
+        branding: &Branding,
+    ) -> Self {
+        // Canonical JSON sorts object keys, so custom sections (a HashMap)
+        // hash the same regardless of iteration order
+        let resume = canonicalize(resume);
+        let exclude_sections = exclude_sections.join("\n");
+        let exclude_item_ids = exclude_item_ids.join("\n");
+        let mut inputs = vec![
+            resume.as_bytes(),
+            engine.as_str().as_bytes(),
+            exclude_sections.as_bytes(),
+            exclude_item_ids.as_bytes(),
+        ];
+        inputs.extend(branding_inputs(branding));
+        Self::new("pdf", &inputs)
+    }
+
+    /// Key for the gallery thumbnail of `template` with the deployment's
+    /// `branding`.
+    pub fn thumbnail(template: &str, branding: &Branding) -> Self {
+        let mut inputs = vec![template.as_bytes()];
+        inputs.extend(branding_inputs(branding));
+        Self::new("thumbnail", &inputs)
+    }
+
+    /// Hex digest, used as the file name.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// The parts of `branding` that change a render. The cache outlives the
+/// process, so a restart with new branding must not serve the old look.
+fn branding_inputs(branding: &Branding) -> [&[u8]; 5] {
+    let text = |value: &Option<String>| value.as_deref().unwrap_or_default().as_bytes();
+    [
+        text(&branding.background),
+        text(&branding.text),
+        text(&branding.primary),
+        text(&branding.footer),
+        branding.logo.as_ref().map_or(&[][..], |logo| logo.bytes()),
+    ]
+}
+
+/// Size-bounded, content-addressed artifact store in one directory.
+#[derive(Debug)]
+pub struct ArtifactCache {
+    dir: PathBuf,
+    max_bytes: u64,
+    /// Running total of entry sizes; recomputed on every eviction pass.
+    total_bytes: AtomicU64,
+    /// Serializes writes and eviction.
+    write_lock: Mutex<()>,
+}
+
+impl ArtifactCache {
+    /// Open (creating if needed) a cache in `dir` that holds at most `max_bytes`.
+    pub fn open(dir: impl Into<PathBuf>, max_bytes: u64) -> io::Result<Self> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+        let cache = Self {
+            dir,
+            max_bytes,
+            total_bytes: AtomicU64::new(0),
+            write_lock: Mutex::new(()),
+        };
+        let total = cache.entries()?.iter().map(|e| e.size).sum();
+        cache.total_bytes.store(total, Ordering::Relaxed);
+        Ok(cache)
+    }
+
+    /// Open the cache configured by `ARTIFACT_CACHE_DIR`, or `None` when it
+    /// is unset or the directory can't be created.
+    pub fn from_config(config: &ArtifactCacheConfig) -> Option<Self> {
+        let dir = config.dir.as_ref()?;
+        match Self::open(dir, config.max_bytes) {
+            Ok(cache) => Some(cache),
+            Err(err) => {
+                warn!(dir = %dir.display(), error = %err, "Artifact cache disabled");
+                None
+            }
+        }
+    }
+
+    /// Cached bytes for `key`, marking the entry as recently used.
+    pub fn get(&self, key: &ArtifactKey) -> Option<Vec<u8>> {
+        let path = self.path(key);
+        let bytes = fs::read(&path).ok()?;
+        // Eviction goes by modification time, so a hit counts as a use
+        if let Ok(file) = File::options().write(true).open(&path) {
+            let _ = file.set_modified(SystemTime::now());
+        }
+        Some(bytes)
+    }
+
+    /// Store `bytes` under `key`, then evict old entries if over budget.
+    /// Failures are logged: a cache that can't write just stops helping.
+    pub fn put(&self, key: &ArtifactKey, bytes: &[u8]) {
+        if bytes.len() as u64 > self.max_bytes {
+            return;
+        }
+        let _guard = self.write_lock.lock().unwrap_or_else(|e| e.into_inner());
+        let path = self.path(key);
+        if path.exists() {
+            return;
+        }
+
+        // Write then rename, so readers never see a partial file
+        let tmp = path.with_extension("tmp");
+        if let Err(err) = fs::write(&tmp, bytes).and_then(|()| fs::rename(&tmp, &path)) {
+            warn!(path = %path.display(), error = %err, "Failed to write artifact cache entry");
+            let _ = fs::remove_file(&tmp);
+            return;
+        }
+
+        let total = self
+            .total_bytes
+            .fetch_add(bytes.len() as u64, Ordering::Relaxed)
+            + bytes.len() as u64;
+        if total > self.max_bytes {
+            if let Err(err) = self.evict() {
+                warn!(error = %err, "Artifact cache eviction failed");
+            }
+        }
+    }
+
+    /// Total size of cached entries in bytes.
+    pub fn size(&self) -> u64 {
+        self.total_bytes.load(Ordering::Relaxed)
+    }
+
+    fn path(&self, key: &ArtifactKey) -> PathBuf {
+        self.dir.join(key.as_str()).with_extension(ENTRY_EXTENSION)
+    }
+
+    /// Delete least recently used entries until the cache fits its budget.
+    fn evict(&self) -> io::Result<()> {
+        let mut entries = self.entries()?;
+        entries.sort_by_key(|e| e.used);
+        let mut total: u64 = entries.iter().map(|e| e.size).sum();
+        for entry in entries {
+            if total <= self.max_bytes {
+                break;
+            }
+            if fs::remove_file(&entry.path).is_ok() {
+                total -= entry.size;
+            }
+        }
+        self.total_bytes.store(total, Ordering::Relaxed);
+        Ok(())
+    }
+
+    fn entries(&self) -> io::Result<Vec<Entry>> {
+        let mut entries = Vec::new();
+        for dir_entry in fs::read_dir(&self.dir)? {
+            let path = dir_entry?.path();
+            if path.extension().and_then(|e| e.to_str()) != Some(ENTRY_EXTENSION) {
+                continue;
+            }
+            let Ok(metadata) = fs::metadata(&path) else {
+                continue;
+            };
+            entries.push(Entry {
+                used: metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH),
+                size: metadata.len(),
+                path,
+            });
+        }
+        Ok(entries)
+    }
+}
+
+/// Serve `key` from `cache`, or call `render` and store its output. Renders
+/// without caching when there is no cache or key.
+pub fn cached_render<E>(
+    cache: Option<&ArtifactCache>,
+    key: Option<&ArtifactKey>,
+    render: impl FnOnce() -> Result<Vec<u8>, E>,
+) -> Result<Vec<u8>, E> {
+    let (Some(cache), Some(key)) = (cache, key) else {
+        return render();
+    };
+    if let Some(bytes) = cache.get(key) {
+        return Ok(bytes);
+    }
+    let bytes = render()?;
+    cache.put(key, &bytes);
+    Ok(bytes)
+}
+
+struct Entry {
+    path: PathBuf,
+    size: u64,
+    used: SystemTime,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_key_depends_on_every_input() {
+        let key = ArtifactKey::new("pdf", &[b"resume", b"rhyhorn"]);
+        assert_eq!(key, ArtifactKey::new("pdf", &[b"resume", b"rhyhorn"]));
+        assert_ne!(key, ArtifactKey::new("thumbnail", &[b"resume", b"rhyhorn"]));
+        assert_ne!(key, ArtifactKey::new("pdf", &[b"resum", b"erhyhorn"]));
+        assert_eq!(key.as_str().len(), 64);
+    }
+
+    #[test]
+    fn test_put_get_roundtrip_survives_reopen() {
+        let dir = tempfile::tempdir().unwrap();
+        let key = ArtifactKey::new("pdf", &[b"resume"]);
+        {
+            let cache = ArtifactCache::open(dir.path(), 1024).unwrap();
+            assert!(cache.get(&key).is_none());
+            cache.put(&key, b"%PDF-1.7");
+        }
+
+        let cache = ArtifactCache::open(dir.path(), 1024).unwrap();
+        assert_eq!(cache.get(&key).as_deref(), Some(&b"%PDF-1.7"[..]));
+        assert_eq!(cache.size(), 8);
+    }
+
+    #[test]
+    fn test_evicts_least_recently_used_over_budget() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = ArtifactCache::open(dir.path(), 250).unwrap();
+        let keys: Vec<_> = (0..3u8).map(|i| ArtifactKey::new("pdf", &[&[i]])).collect();
+
+        cache.put(&keys[0], &[0; 100]);
+        cache.put(&keys[1], &[1; 100]);
+        // Age entry 1 so it is the least recently used when the third write lands
+        let earlier = SystemTime::now() - Duration::from_secs(60);
+        File::options()
+            .write(true)
+            .open(cache.path(&keys[1]))
+            .unwrap()
+            .set_modified(earlier)
+            .unwrap();
+        assert!(cache.get(&keys[0]).is_some());
+        cache.put(&keys[2], &[2; 100]);
+
+        assert!(cache.get(&keys[1]).is_none());
+        assert!(cache.get(&keys[0]).is_some());
+        assert!(cache.get(&keys[2]).is_some());
+        assert_eq!(cache.size(), 200);
+    }
+
+    #[test]
+    fn test_cached_render_renders_once() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = ArtifactCache::open(dir.path(), 1024).unwrap();
+        let key = ArtifactKey::pdf(
+            &ResumeData::default(),
+            RenderEngine::Typst,
+            &[],
+            &[],
+            &Branding::default(),
+        );
+        let mut renders = 0;
+        for _ in 0..2 {
+            let bytes = cached_render(Some(&cache), Some(&key), || {
+                renders += 1;
+                Ok::<_, ()>(b"%PDF".to_vec())
+            })
+            .unwrap();
+            assert_eq!(bytes, b"%PDF");
+        }
+        assert_eq!(renders, 1);
+        let resume = ResumeData::default();
+        let branding = Branding::default();
+        assert_ne!(
+            key,
+            ArtifactKey::pdf(
+                &resume,
+                RenderEngine::Typst,
+                &["skills".into()],
+                &[],
+                &branding
+            )
+        );
+        assert_ne!(
+            key,
+            ArtifactKey::pdf(&resume, RenderEngine::Chromium, &[], &[], &branding)
+        );
+        let branding = Branding {
+            footer: Some("Prepared by Globex".to_string()),
+            ..Branding::default()
+        };
+        assert_ne!(
+            key,
+            ArtifactKey::pdf(&resume, RenderEngine::Typst, &[], &[], &branding)
+        );
+        assert_ne!(
+            ArtifactKey::thumbnail("onyx", &Branding::default()),
+            ArtifactKey::thumbnail("onyx", &branding)
+        );
+    }
+
+    #[test]
+    fn test_skips_artifacts_larger_than_budget() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = ArtifactCache::open(dir.path(), 10).unwrap();
+        let key = ArtifactKey::new("pdf", &[b"big"]);
+        cache.put(&key, &[0; 11]);
+        assert!(cache.get(&key).is_none());
+        assert_eq!(cache.size(), 0);
+    }
+}
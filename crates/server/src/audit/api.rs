@@ -0,0 +1,186 @@
+//! Audit log of API calls made with an API key.
+//!
+//! Organizations embedding the API give each integration a named key in
+//! `RUSTUME_API_KEYS`. A request that sends one in `X-Rustume-Api-Key` is
+//! recorded by [`audit_api_calls`](crate::middleware::api_audit::audit_api_calls)
+//! (key name, endpoint, resume hash, status, and duration) in an
+//! [`ApiAuditLog`] from the storage crate: a SQLite file at
+//! `RUSTUME_API_AUDIT_DB`, or memory when unset. Calls older than
+//! `RUSTUME_API_AUDIT_RETENTION_DAYS` (default 90) are purged hourly.
+
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::Context;
+use rustume_storage::{ApiAuditLog, MemoryStorage, SqliteStorage, API_AUDIT_RETENTION_DAYS};
+use subtle::ConstantTimeEq;
+use tracing::{error, info, warn};
+
+/// Header carrying an API key.
+pub const API_KEY_HEADER: &str = "x-rustume-api-key";
+
+/// How often expired calls are purged.
+const PURGE_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// An audit log shared across request handlers.
+pub type SharedApiAuditLog = Arc<dyn ApiAuditLog + Send + Sync>;
+
+/// Named API keys.
+#[derive(Clone, Default)]
+pub struct ApiKeys(Vec<(String, String)>);
+
+impl ApiKeys {
+    /// Parse comma-separated `name:key` pairs, such as
+    /// `acme:k3y,globex:s3cret`. Names must be unique; keys may contain `:`.
+    pub fn parse(raw: &str) -> anyhow::Result<Self> {
+        let mut keys: Vec<(String, String)> = Vec::new();
+        for entry in raw
+            .split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+        {
+            let (name, key) = entry
+                .split_once(':')
+                .map(|(name, key)| (name.trim(), key.trim()))
+                .filter(|(name, key)| !name.is_empty() && !key.is_empty())
+                .with_context(|| format!("API key `{entry}` is not in the form name:key"))?;
+            anyhow::ensure!(
+                keys.iter().all(|(existing, _)| existing != name),
+                "API key name `{name}` is used twice"
+            );
+            keys.push((name.to_string(), key.to_string()));
+        }
+        Ok(Self(keys))
+    }
+
+    /// Whether no key is configured.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Name of the key `presented` matches.
+    pub fn identify(&self, presented: &str) -> Option<&str> {
+        let presented = presented.trim();
+        self.0
+            .iter()
+            .find(|(_, key)| bool::from(key.as_bytes().ct_eq(presented.as_bytes())))
+            .map(|(name, _)| name.as_str())
+    }
+}
+
+impl std::fmt::Debug for ApiKeys {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list()
+            .entries(self.0.iter().map(|(name, _)| name))
+            .finish()
+    }
+}
+
+/// API audit settings from the environment.
+#[derive(Debug, Clone)]
+pub struct ApiAuditConfig {
+    pub keys: ApiKeys,
+    /// SQLite file for the log; kept in memory when unset.
+    pub db_path: Option<PathBuf>,
+    pub retention_days: u32,
+}
+
+impl ApiAuditConfig {
+    /// Load `RUSTUME_API_KEYS`, `RUSTUME_API_AUDIT_DB`, and
+    /// `RUSTUME_API_AUDIT_RETENTION_DAYS`.
+    pub fn from_env() -> anyhow::Result<Self> {
+        let var = |key| {
+            std::env::var(key)
+                .ok()
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty())
+        };
+        let retention_days = match var("RUSTUME_API_AUDIT_RETENTION_DAYS") {
+            Some(days) => days
+                .parse()
+                .context("RUSTUME_API_AUDIT_RETENTION_DAYS must be a number of days")?,
+            None => API_AUDIT_RETENTION_DAYS,
+        };
+        Ok(Self {
+            keys: ApiKeys::parse(var("RUSTUME_API_KEYS").as_deref().unwrap_or(""))
+                .context("Invalid RUSTUME_API_KEYS")?,
+            db_path: var("RUSTUME_API_AUDIT_DB").map(PathBuf::from),
+            retention_days,
+        })
+    }
+}
+
+/// API keys and the log their calls are recorded in.
+pub struct ApiAudit {
+    pub keys: ApiKeys,
+    pub log: SharedApiAuditLog,
+    pub retention_days: u32,
+}
+
+impl ApiAudit {
+    /// Audit calls made with `keys` to `log`, kept for the default retention.
+    pub fn new(keys: ApiKeys, log: SharedApiAuditLog) -> Self {
+        Self {
+            keys,
+            log,
+            retention_days: API_AUDIT_RETENTION_DAYS,
+        }
+    }
+
+    /// Open the configured log, or `None` when no API key is configured.
+    pub async fn open(config: ApiAuditConfig) -> anyhow::Result<Option<Self>> {
+        if config.keys.is_empty() {
+            return Ok(None);
+        }
+        let log: SharedApiAuditLog = match &config.db_path {
+            Some(path) => Arc::new(
+                SqliteStorage::open(path)
+                    .await
+                    .with_context(|| format!("Opening API audit log {}", path.display()))?,
+            ),
+            None => {
+                warn!("RUSTUME_API_AUDIT_DB is not set; the API audit log is lost on restart");
+                Arc::new(MemoryStorage::new())
+            }
+        };
+        Ok(Some(Self {
+            retention_days: config.retention_days,
+            ..Self::new(config.keys, log)
+        }))
+    }
+}
+
+/// Start the background task that purges calls past the retention window.
+pub fn spawn_purger(audit: Arc<ApiAudit>) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(PURGE_INTERVAL);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+        loop {
+            interval.tick().await;
+            match audit.log.purge_api_calls(audit.retention_days).await {
+                Ok(0) => {}
+                Ok(purged) => info!("purged {purged} expired API audit entries"),
+                Err(err) => error!("API audit purge failed: {err}"),
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_api_keys_identify_by_name() {
+        let keys = ApiKeys::parse(" acme:k3y , globex:a:b ").unwrap();
+        assert_eq!(keys.identify("k3y"), Some("acme"));
+        assert_eq!(keys.identify("a:b"), Some("globex"));
+        assert_eq!(keys.identify("nope"), None);
+        assert_eq!(format!("{keys:?}"), r#"["acme", "globex"]"#);
+
+        assert!(ApiKeys::parse("").unwrap().is_empty());
+        assert!(ApiKeys::parse("no-name").is_err());
+        assert!(ApiKeys::parse("acme:1,acme:2").is_err());
+    }
+}
@@ -1,5 +1,7 @@
 //! Append-only audit logging for security-sensitive events.
 
+pub mod api;
+
 use serde_json::Value;
 use sqlx::{Executor, PgPool, Postgres};
 use tracing::error;
@@ -32,6 +32,8 @@ pub struct CloudConfig {
     pub resend_api_key: Option<String>,
     /// Sender address when transactional email is enabled (`EMAIL_FROM`).
     pub email_from: Option<String>,
+    /// Days a deleted resume stays in the trash (`TRASH_RETENTION_DAYS`, default 30).
+    pub trash_retention_days: u32,
 }
 
 impl CloudConfig {
@@ -54,6 +56,7 @@ impl CloudConfig {
             db_acquire_timeout_secs: optional_env_u64("DB_ACQUIRE_TIMEOUT_SECS", 5)?,
             resend_api_key: optional_non_empty_env("RESEND_API_KEY")?,
             email_from: optional_non_empty_env("EMAIL_FROM")?,
+            trash_retention_days: optional_env_u32("TRASH_RETENTION_DAYS", 30)?,
         })
     }
 }
@@ -73,6 +76,7 @@ impl std::fmt::Debug for CloudConfig {
                 &self.resend_api_key.as_ref().map(|_| "<redacted>"),
             )
             .field("email_from", &self.email_from)
+            .field("trash_retention_days", &self.trash_retention_days)
             .finish()
     }
 }
@@ -198,6 +202,8 @@ pub struct CloudState {
     pub workos_redirect_uri: String,
     /// Transactional email delivery for account lifecycle events.
     pub email: Option<EmailService>,
+    /// Days a deleted resume stays in the trash before it is purged.
+    pub trash_retention_days: u32,
 }
 
 /// Connect to PostgreSQL, run migrations, and wire cloud auth services.
@@ -237,6 +243,7 @@ pub async fn init_cloud(config: CloudConfig) -> anyhow::Result<Arc<CloudState>>
         sessions,
         workos_redirect_uri,
         email,
+        trash_retention_days: config.trash_retention_days,
     }))
 }
 
@@ -265,6 +272,7 @@ mod tests {
             db_acquire_timeout_secs: 5,
             resend_api_key: Some("re_test".to_string()),
             email_from: None,
+            trash_retention_days: 30,
         };
 
         let err = email_service_from_config(&config).expect_err("partial config");
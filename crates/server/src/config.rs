@@ -1,8 +1,13 @@
 //! Server configuration constants.
 
 use std::num::NonZeroU32;
+use std::path::PathBuf;
 
+use anyhow::Context;
 use governor::Quota;
+use rustume_render::{
+    Branding, Logo, RenderEngine, RenderLimits, RendererRegistry, SandboxLimits, SharedRenderer,
+};
 
 /// Maximum request body size (10 MB)
 pub const MAX_BODY_SIZE: usize = 10 * 1024 * 1024;
@@ -33,6 +38,180 @@ pub const DEFAULT_PORT: u16 = 3000;
 /// Default location for the production web bundle in the container image.
 pub const DEFAULT_STATIC_DIR: &str = "/app/web";
 
+/// Startup pre-rendering of template gallery thumbnails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ThumbnailWarmupConfig {
+    /// Render every template thumbnail into the cache at startup.
+    pub enabled: bool,
+    /// Maximum thumbnails rendered at once.
+    pub concurrency: usize,
+}
+
+impl Default for ThumbnailWarmupConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            concurrency: 4,
+        }
+    }
+}
+
+impl ThumbnailWarmupConfig {
+    /// Load warm-up settings from `THUMBNAIL_WARMUP` and
+    /// `THUMBNAIL_WARMUP_CONCURRENCY`.
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+        Self {
+            enabled: !matches!(
+                std::env::var("THUMBNAIL_WARMUP").as_deref(),
+                Ok("false" | "0")
+            ),
+            concurrency: env_u32("THUMBNAIL_WARMUP_CONCURRENCY", defaults.concurrency as u32).max(1)
+                as usize,
+        }
+    }
+}
+
+/// Default artifact cache budget in megabytes.
+const DEFAULT_ARTIFACT_CACHE_MAX_MB: u32 = 512;
+
+/// On-disk cache for rendered PDFs and thumbnails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArtifactCacheConfig {
+    /// Cache directory; the cache is off when unset.
+    pub dir: Option<PathBuf>,
+    /// Size budget; least recently used entries are evicted past it.
+    pub max_bytes: u64,
+}
+
+impl ArtifactCacheConfig {
+    /// Load cache settings from `ARTIFACT_CACHE_DIR` and `ARTIFACT_CACHE_MAX_MB`.
+    pub fn from_env() -> Self {
+        Self {
+            dir: std::env::var("ARTIFACT_CACHE_DIR")
+                .ok()
+                .filter(|dir| !dir.trim().is_empty())
+                .map(PathBuf::from),
+            max_bytes: u64::from(env_u32(
+                "ARTIFACT_CACHE_MAX_MB",
+                DEFAULT_ARTIFACT_CACHE_MAX_MB,
+            )) * 1024
+                * 1024,
+        }
+    }
+}
+
+/// Default ceiling on resume data handed to Typst, in kilobytes. A long
+/// resume is tens of kilobytes; a 50,000-word summary is well past this.
+const DEFAULT_RENDER_MAX_DATA_KB: u32 = 256;
+
+/// Default ceiling on pictures and attachment files in one render, in megabytes.
+const DEFAULT_RENDER_MAX_ASSETS_MB: u32 = 16;
+
+/// Load render size ceilings from `RENDER_MAX_DATA_KB` and
+/// `RENDER_MAX_ASSETS_MB`. `0` turns a limit off.
+pub fn render_limits_from_env() -> RenderLimits {
+    let limit = |key, default, unit: usize| {
+        Some(env_u32(key, default) as usize * unit).filter(|bytes| *bytes > 0)
+    };
+    RenderLimits {
+        max_data_bytes: limit("RENDER_MAX_DATA_KB", DEFAULT_RENDER_MAX_DATA_KB, 1024),
+        max_asset_bytes: limit(
+            "RENDER_MAX_ASSETS_MB",
+            DEFAULT_RENDER_MAX_ASSETS_MB,
+            1024 * 1024,
+        ),
+    }
+}
+
+/// Default longest Typst compilation, in seconds. Resumes compile in well
+/// under a second; only a runaway template gets near this.
+const DEFAULT_RENDER_TIMEOUT_SECS: u32 = 60;
+
+/// Default most Typst compilations alive at once, timed-out ones included.
+const DEFAULT_RENDER_MAX_COMPILATIONS: u32 = 32;
+
+/// Load Typst compilation limits from `RENDER_TIMEOUT_SECS`,
+/// `RENDER_MAX_COMPILATIONS`, and `RENDER_MAX_MEMORY_MB`. `0` turns the
+/// memory ceiling off, which is the default.
+pub fn sandbox_limits_from_env() -> SandboxLimits {
+    let timeout = env_u32("RENDER_TIMEOUT_SECS", DEFAULT_RENDER_TIMEOUT_SECS).max(1);
+    let compilations = env_u32("RENDER_MAX_COMPILATIONS", DEFAULT_RENDER_MAX_COMPILATIONS).max(1);
+    let memory_mb = env_u32("RENDER_MAX_MEMORY_MB", 0);
+    SandboxLimits {
+        max_compile_time: std::time::Duration::from_secs(u64::from(timeout)),
+        max_compilations: compilations as usize,
+        max_resident_bytes: Some(u64::from(memory_mb) * 1024 * 1024).filter(|bytes| *bytes > 0),
+        ..SandboxLimits::default()
+    }
+}
+
+/// Load white-label branding from `RUSTUME_BRAND_BACKGROUND`,
+/// `RUSTUME_BRAND_TEXT`, `RUSTUME_BRAND_PRIMARY` (hex colors),
+/// `RUSTUME_BRAND_FOOTER` (footer text), and `RUSTUME_BRAND_LOGO` (path to a
+/// PNG, JPEG, GIF, WebP, or SVG file). Unset or blank variables add nothing.
+pub fn branding_from_env() -> anyhow::Result<Branding> {
+    branding_from(|key| std::env::var(key).ok())
+}
+
+fn branding_from(lookup: impl Fn(&str) -> Option<String>) -> anyhow::Result<Branding> {
+    let var = |key| {
+        lookup(key)
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+    };
+    let logo = match var("RUSTUME_BRAND_LOGO") {
+        Some(path) => {
+            let bytes = std::fs::read(&path).with_context(|| format!("Reading logo {path}"))?;
+            Some(Logo::new(bytes).with_context(|| format!("Logo {path}"))?)
+        }
+        None => None,
+    };
+    let branding = Branding {
+        background: var("RUSTUME_BRAND_BACKGROUND"),
+        text: var("RUSTUME_BRAND_TEXT"),
+        primary: var("RUSTUME_BRAND_PRIMARY"),
+        footer: var("RUSTUME_BRAND_FOOTER"),
+        logo,
+    };
+    branding.validate()?;
+    Ok(branding)
+}
+
+/// Register the PDF backends this build has and pick the default from
+/// `RUSTUME_RENDER_ENGINE` (`typst` unless set). With the `chromium` feature,
+/// a browser found by [`rustume_render::ChromiumRenderer::discover`] is
+/// registered too. An unknown or unavailable engine falls back to Typst.
+pub fn render_engines_from_env(typst: SharedRenderer) -> RendererRegistry {
+    let registry = RendererRegistry::new(typst);
+    #[cfg(feature = "chromium")]
+    let registry = match rustume_render::ChromiumRenderer::discover() {
+        Some(chromium) => registry.register(RenderEngine::Chromium, std::sync::Arc::new(chromium)),
+        None => {
+            tracing::info!("No Chromium binary found; the chromium render engine is off");
+            registry
+        }
+    };
+
+    let Ok(value) = std::env::var("RUSTUME_RENDER_ENGINE") else {
+        return registry;
+    };
+    let value = value.trim();
+    if value.is_empty() {
+        return registry;
+    }
+    match value
+        .parse::<RenderEngine>()
+        .and_then(|engine| registry.clone().with_default(engine))
+    {
+        Ok(configured) => configured,
+        Err(err) => {
+            tracing::warn!("RUSTUME_RENDER_ENGINE={value:?}: {err}; using typst");
+            registry
+        }
+    }
+}
+
 /// Per-route-group rate limits for Rustume Cloud (requests per minute).
 #[derive(Debug, Clone, Copy)]
 pub struct RateLimitConfig {
@@ -184,9 +363,7 @@ fn env_u32(key: &str, default: u32) -> u32 {
             match trimmed.parse::<u32>() {
                 Ok(parsed) => parsed,
                 Err(_) => {
-                    tracing::warn!(
-                        "{key}={trimmed:?} is invalid; using default rate limit {default}"
-                    );
+                    tracing::warn!("{key}={trimmed:?} is invalid; using default {default}");
                     default
                 }
             }
@@ -203,6 +380,36 @@ fn trusted_proxy_from_env() -> bool {
 mod tests {
     use super::*;
 
+    #[test]
+    fn branding_reads_colors_footer_and_logo() {
+        let dir = tempfile::tempdir().unwrap();
+        let logo = dir.path().join("logo.svg");
+        std::fs::write(&logo, r#"<svg xmlns="http://www.w3.org/2000/svg"/>"#).unwrap();
+        let vars = |key: &str| match key {
+            "RUSTUME_BRAND_PRIMARY" => Some("#1d4ed8".to_string()),
+            "RUSTUME_BRAND_TEXT" => Some("  ".to_string()),
+            "RUSTUME_BRAND_FOOTER" => Some(" Prepared by Globex ".to_string()),
+            "RUSTUME_BRAND_LOGO" => Some(logo.display().to_string()),
+            _ => None,
+        };
+
+        let branding = branding_from(vars).unwrap();
+        assert_eq!(branding.primary.as_deref(), Some("#1d4ed8"));
+        assert_eq!(branding.text, None);
+        assert_eq!(branding.footer.as_deref(), Some("Prepared by Globex"));
+        assert!(branding.logo.is_some());
+
+        assert!(branding_from(|_| None).unwrap().is_empty());
+        assert!(
+            branding_from(|key| (key == "RUSTUME_BRAND_BACKGROUND").then(|| "white".into()))
+                .is_err()
+        );
+        assert!(branding_from(
+            |key| (key == "RUSTUME_BRAND_LOGO").then(|| "/nonexistent/logo.png".into())
+        )
+        .is_err());
+    }
+
     #[test]
     fn policy_versions_match_web_constants() {
         // Keep in sync with apps/web/src/lib/policies.ts
@@ -210,6 +417,13 @@ mod tests {
         assert_eq!(PRIVACY_VERSION, "2026-07-10");
     }
 
+    #[test]
+    fn thumbnail_warmup_is_on_by_default() {
+        let config = ThumbnailWarmupConfig::default();
+        assert!(config.enabled);
+        assert_eq!(config.concurrency, 4);
+    }
+
     #[test]
     fn default_limits_match_issue() {
         let config = RateLimitConfig::default();
@@ -0,0 +1,19 @@
+-- Job application tracker: where each resume was sent and how it is going.
+-- The full application (company, role, dates, notes) lives in `data`; status
+-- and the linked resume are columns so lists can be filtered in SQL.
+
+CREATE TABLE job_applications (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+    resume_id UUID REFERENCES resumes (id) ON DELETE SET NULL,
+    status TEXT NOT NULL DEFAULT 'saved' CHECK (
+        status IN ('saved', 'applied', 'interviewing', 'offer', 'accepted', 'rejected', 'withdrawn')
+    ),
+    data JSONB NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE INDEX job_applications_user_id_updated_at_idx ON job_applications (user_id, updated_at DESC);
+
+CREATE INDEX job_applications_resume_id_idx ON job_applications (resume_id);
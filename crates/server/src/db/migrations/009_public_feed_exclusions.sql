@@ -0,0 +1,8 @@
+-- What a shared resume's public JSON feed (`GET /u/{slug}/resume.json`)
+-- leaves out. Uses the same keys and item IDs as render exclusions, so an
+-- owner can hide a phone number or an old job from the feed without hiding
+-- it from their own exports.
+
+ALTER TABLE resumes
+    ADD COLUMN public_exclude_sections TEXT[] NOT NULL DEFAULT '{}',
+    ADD COLUMN public_exclude_item_ids TEXT[] NOT NULL DEFAULT '{}';
@@ -0,0 +1,15 @@
+-- Resume history: an append-only log of changes to each resume, with a
+-- one-line summary of what changed. Rows are kept after the resume is
+-- deleted, so its history ends with the deletion; they go with the account.
+
+CREATE TABLE resume_history (
+    id BIGSERIAL PRIMARY KEY,
+    resume_id UUID NOT NULL,
+    user_id UUID NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+    author TEXT,
+    action TEXT NOT NULL CHECK (action IN ('created', 'updated', 'deleted')),
+    summary TEXT NOT NULL,
+    at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE INDEX resume_history_user_id_resume_id_idx ON resume_history (user_id, resume_id, id);
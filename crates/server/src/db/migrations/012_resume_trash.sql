@@ -0,0 +1,11 @@
+-- Trash: deleting a resume stamps `trashed_at` instead of removing the row.
+-- Trashed resumes are hidden from every route but the trash and restore
+-- routes, and the purge task erases them once the retention window passes.
+
+ALTER TABLE resumes ADD COLUMN trashed_at TIMESTAMPTZ;
+
+CREATE INDEX resumes_trashed_at_idx ON resumes (trashed_at) WHERE trashed_at IS NOT NULL;
+
+ALTER TABLE resume_history DROP CONSTRAINT resume_history_action_check;
+ALTER TABLE resume_history ADD CONSTRAINT resume_history_action_check
+    CHECK (action IN ('created', 'updated', 'deleted', 'restored'));
@@ -155,6 +155,13 @@ pub struct CreateResumeRequest {
 #[derive(Debug, Deserialize, ToSchema)]
 pub struct UpdateSharingRequest {
     pub is_public: bool,
+    /// Sections or contact fields to leave out of the public feed, as in
+    /// render exclusions. Omit to keep the current list.
+    #[serde(default)]
+    pub exclude_sections: Option<Vec<String>>,
+    /// Item IDs to leave out of the public feed. Omit to keep the current list.
+    #[serde(default)]
+    pub exclude_item_ids: Option<Vec<String>>,
 }
 
 /// Response body for `PUT /api/resumes/{id}/sharing`.
@@ -163,6 +170,10 @@ pub struct SharingResponse {
     pub is_public: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub public_slug: Option<String>,
+    /// Sections left out of the public feed.
+    pub exclude_sections: Vec<String>,
+    /// Item IDs left out of the public feed.
+    pub exclude_item_ids: Vec<String>,
 }
 
 /// Request body for `PUT /api/resumes/{id}`.
@@ -191,6 +202,140 @@ pub struct ImportResumesRequest {
     pub resumes: Vec<ImportResumeItem>,
 }
 
+/// Job application row as stored in PostgreSQL.
+#[derive(Debug, Clone, FromRow, Serialize, ToSchema)]
+pub struct ApplicationRow {
+    #[schema(value_type = String, format = "uuid")]
+    pub id: Uuid,
+    #[schema(value_type = String, format = "uuid")]
+    pub user_id: Uuid,
+    #[schema(value_type = Option<String>, format = "uuid")]
+    pub resume_id: Option<Uuid>,
+    pub status: String,
+    #[schema(value_type = rustume_schema::JobApplication)]
+    pub data: serde_json::Value,
+    #[schema(value_type = String, format = "date-time")]
+    pub created_at: DateTime<Utc>,
+    #[schema(value_type = String, format = "date-time")]
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Filters for `GET /api/applications`.
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct ApplicationListQuery {
+    /// Only applications in this status (`saved`, `applied`, `interviewing`, ...).
+    pub status: Option<String>,
+    /// Only applications linked to this resume ID.
+    pub resume_id: Option<String>,
+}
+
+/// Webhook endpoint as returned by the API. The signing secret is only
+/// returned once, when the endpoint is created.
+#[derive(Debug, Clone, FromRow, Serialize, ToSchema)]
+pub struct WebhookRow {
+    #[schema(value_type = String, format = "uuid")]
+    pub id: Uuid,
+    pub url: String,
+    /// Subscribed events (`resume.created`, `resume.updated`, ...).
+    pub events: Vec<String>,
+    /// Inactive endpoints receive nothing; pending deliveries wait.
+    pub active: bool,
+    #[schema(value_type = String, format = "date-time")]
+    pub created_at: DateTime<Utc>,
+    #[schema(value_type = String, format = "date-time")]
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Response body for `POST /api/webhooks`.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CreatedWebhook {
+    #[serde(flatten)]
+    pub webhook: WebhookRow,
+    /// Key for verifying the `Rustume-Signature` header. Store it now; it
+    /// is not shown again.
+    pub secret: String,
+}
+
+/// Request body for `POST /api/webhooks`.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateWebhookRequest {
+    /// Public HTTPS URL that receives event POSTs.
+    pub url: String,
+    /// Events to subscribe to; at least one.
+    pub events: Vec<String>,
+}
+
+/// Request body for `PUT /api/webhooks/{id}`. Omitted fields are unchanged.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateWebhookRequest {
+    pub url: Option<String>,
+    pub events: Option<Vec<String>>,
+    pub active: Option<bool>,
+}
+
+/// One event sent (or being sent) to a webhook endpoint.
+#[derive(Debug, Clone, FromRow, Serialize, ToSchema)]
+pub struct WebhookDeliveryRow {
+    #[schema(value_type = String, format = "uuid")]
+    pub id: Uuid,
+    /// Shared by the deliveries of one event to several endpoints.
+    #[schema(value_type = String, format = "uuid")]
+    pub event_id: Uuid,
+    pub event_type: String,
+    /// The body sent to the endpoint.
+    #[schema(value_type = Object)]
+    pub payload: serde_json::Value,
+    /// `pending`, `succeeded`, or `failed` (retries exhausted).
+    pub status: String,
+    pub attempts: i32,
+    /// HTTP status of the latest attempt, if the endpoint responded.
+    pub last_status_code: Option<i32>,
+    pub last_error: Option<String>,
+    /// When a pending delivery is next attempted.
+    #[schema(value_type = String, format = "date-time")]
+    pub next_attempt_at: DateTime<Utc>,
+    #[schema(value_type = Option<String>, format = "date-time")]
+    pub delivered_at: Option<DateTime<Utc>>,
+    #[schema(value_type = String, format = "date-time")]
+    pub created_at: DateTime<Utc>,
+}
+
+/// Query parameters for `DELETE /api/resumes/{id}`.
+#[derive(Debug, Default, Deserialize, IntoParams)]
+pub struct DeleteResumeQuery {
+    /// Delete immediately instead of moving the resume to the trash.
+    #[serde(default)]
+    pub permanent: bool,
+}
+
+/// Deleted resume awaiting purge, from `GET /api/resumes/trash`.
+#[derive(Debug, Clone, FromRow, Serialize, ToSchema)]
+pub struct TrashedResumeSummary {
+    #[schema(value_type = String, format = "uuid")]
+    pub id: Uuid,
+    pub title: String,
+    #[schema(value_type = String, format = "date-time")]
+    pub trashed_at: DateTime<Utc>,
+    /// When the resume will be deleted for good unless restored.
+    #[schema(value_type = String, format = "date-time")]
+    pub purge_at: DateTime<Utc>,
+}
+
+/// One change to a cloud resume, from `GET /api/resumes/{id}/history`.
+#[derive(Debug, Clone, FromRow, Serialize, ToSchema)]
+pub struct ResumeHistoryRow {
+    #[schema(value_type = String, format = "uuid")]
+    pub resume_id: Uuid,
+    /// Email of the user who saved the change, or their ID when unknown.
+    pub author: Option<String>,
+    /// `created`, `updated`, `deleted`, or `restored`.
+    pub action: String,
+    /// What changed, such as `Edited basics (email); added 1 skills item`.
+    pub summary: String,
+    #[schema(value_type = String, format = "date-time")]
+    pub at: DateTime<Utc>,
+}
+
 /// Subscription summary returned by `GET /auth/me` for linked instances.
 #[derive(Debug, Clone, Serialize, ToSchema)]
 pub struct SubscriptionInfo {
@@ -363,6 +508,8 @@ mod tests {
         let response = SharingResponse {
             is_public: true,
             public_slug: Some("clxyz123".to_string()),
+            exclude_sections: Vec::new(),
+            exclude_item_ids: Vec::new(),
         };
         let json = serde_json::to_value(&response).unwrap();
 
@@ -375,6 +522,8 @@ mod tests {
         let response = SharingResponse {
             is_public: false,
             public_slug: None,
+            exclude_sections: Vec::new(),
+            exclude_item_ids: Vec::new(),
         };
         let json = serde_json::to_value(&response).unwrap();
 
@@ -387,6 +536,8 @@ mod tests {
         let response = SharingResponse {
             is_public: false,
             public_slug: Some("clxyz123".to_string()),
+            exclude_sections: Vec::new(),
+            exclude_item_ids: Vec::new(),
         };
         let json = serde_json::to_value(&response).unwrap();
 
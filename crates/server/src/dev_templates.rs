@@ -0,0 +1,66 @@
+//! `--dev-templates <dir>`: render with Typst templates from disk (`dev` feature).
+//!
+//! Every render reads the templates in the directory, so an edit shows up
+//! in the next preview without rebuilding the server. A watcher thread drops
+//! the cached template thumbnails whenever a template changes.
+
+use std::path::PathBuf;
+
+use anyhow::Context;
+use rustume_render::{set_dev_templates_dir, TemplateWatcher};
+use tracing::{info, warn};
+
+use crate::routes::static_files::path_arg;
+use crate::routes::templates::clear_thumbnail_cache;
+
+/// Command-line flag naming the template directory.
+pub const DEV_TEMPLATES_FLAG: &str = "--dev-templates";
+
+/// The directory passed as `--dev-templates <dir>` or `--dev-templates=<dir>`.
+pub fn dev_templates_arg(args: impl IntoIterator<Item = String>) -> Option<PathBuf> {
+    path_arg(args, DEV_TEMPLATES_FLAG)
+}
+
+/// Render with the templates in `dir` and watch it for edits.
+pub fn use_dev_templates(dir: PathBuf) -> anyhow::Result<()> {
+    set_dev_templates_dir(Some(dir.clone()))?;
+    let mut watcher =
+        TemplateWatcher::new(&dir).with_context(|| format!("Failed to watch {}", dir.display()))?;
+    info!("Rendering with templates from {}", dir.display());
+
+    std::thread::spawn(move || loop {
+        match watcher.wait() {
+            Ok(changed) => {
+                info!("Templates changed: {}", changed.join(", "));
+                clear_thumbnail_cache();
+            }
+            Err(err) => {
+                warn!("Stopped watching {}: {err}", dir.display());
+                return;
+            }
+        }
+    });
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dev_templates_arg() {
+        let args = |list: &[&str]| list.iter().map(|arg| arg.to_string()).collect::<Vec<_>>();
+        assert_eq!(
+            dev_templates_arg(args(&["rustume-server", "--dev-templates", "tpl"])),
+            Some(PathBuf::from("tpl"))
+        );
+        assert_eq!(
+            dev_templates_arg(args(&["rustume-server", "--dev-templates=./tpl"])),
+            Some(PathBuf::from("./tpl"))
+        );
+        assert_eq!(
+            dev_templates_arg(args(&["rustume-server", "--serve-web", "web"])),
+            None
+        );
+    }
+}
@@ -1,28 +1,58 @@
-use rustume_parser::ResumeFormat;
+use rustume_parser::{CsvSection, IdStrategy, ImportOptions, ImportReport, ResumeFormat};
+use rustume_render::{RenderEngine, TEMPLATES};
+use rustume_schema::{
+    ContentPreset, ContentSuggestion, DiffReportFormat, DuplicateCandidate, EmploymentGap,
+    GapOptions, KeywordOptions, KeywordReport, LayoutPreset, MergeStrategy, PhotoPosition,
+    ResumeData,
+};
+use rustume_storage::{ApiCall, ApiCallQuery};
 use serde::{Deserialize, Serialize};
-use utoipa::ToSchema;
+use utoipa::openapi::schema::{ObjectBuilder, Schema, Type};
+use utoipa::openapi::RefOr;
+use utoipa::{IntoParams, PartialSchema, ToSchema};
+use uuid::Uuid;
+
+#[cfg(feature = "ai")]
+use crate::ai::{RewriteLength, RewriteTone};
 
 /// Input format for parsing
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "kebab-case")]
 pub enum ParseFormat {
+    /// Detected from the data: magic bytes, markup, or JSON shape. Plain text
+    /// is never detected and has to be named.
+    Auto,
     /// JSON Resume standard format (https://jsonresume.org)
     JsonResume,
     /// LinkedIn data export ZIP file
     LinkedIn,
     /// Reactive Resume v3 format
     Rrv3,
+    /// Stack Overflow user data (API JSON or data-dump XML)
+    StackOverflow,
+    /// HTML page with hResume or schema.org Person markup
+    Html,
+    /// vCard contact (.vcf), imported into basics
+    Vcard,
+    /// Unstructured plain text, such as a resume copied out of a PDF
+    Text,
     /// Native Rustume format
     Rustume,
 }
 
-impl From<ParseFormat> for ResumeFormat {
-    fn from(format: ParseFormat) -> Self {
-        match format {
-            ParseFormat::JsonResume => Self::JsonResume,
-            ParseFormat::LinkedIn => Self::LinkedIn,
-            ParseFormat::Rrv3 => Self::Rrv3,
-            ParseFormat::Rustume => Self::Rustume,
+impl ParseFormat {
+    /// The parser format this names, or `None` for [`ParseFormat::Auto`].
+    pub fn resume_format(&self) -> Option<ResumeFormat> {
+        match self {
+            Self::Auto => None,
+            Self::JsonResume => Some(ResumeFormat::JsonResume),
+            Self::LinkedIn => Some(ResumeFormat::LinkedIn),
+            Self::Rrv3 => Some(ResumeFormat::Rrv3),
+            Self::StackOverflow => Some(ResumeFormat::StackOverflow),
+            Self::Html => Some(ResumeFormat::Html),
+            Self::Vcard => Some(ResumeFormat::Vcard),
+            Self::Text => Some(ResumeFormat::Text),
+            Self::Rustume => Some(ResumeFormat::Rustume),
         }
     }
 }
@@ -30,7 +60,7 @@ impl From<ParseFormat> for ResumeFormat {
 /// Parse request body
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct ParseRequest {
-    /// Input format to parse
+    /// Input format to parse, or `auto` to detect it from the data
     #[schema(example = "json-resume")]
     pub format: ParseFormat,
     /// Resume data as string (JSON) or base64-encoded (for binary formats like LinkedIn ZIP)
@@ -40,6 +70,164 @@ pub struct ParseRequest {
     #[serde(default)]
     #[schema(example = false)]
     pub base64: bool,
+    /// Language of the resume as a BCP 47 tag, stored as `metadata.locale`
+    /// and used to write normalized dates
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(example = "de")]
+    pub locale: Option<String>,
+    /// How imported items get their IDs: `random`, or `stable` to derive
+    /// them from each item's content so re-imports keep them
+    #[serde(default)]
+    #[schema(value_type = String, example = "random")]
+    pub ids: IdStrategy,
+    /// Keep a resume that fails validation and report its errors, instead
+    /// of rejecting the import with `422`
+    #[serde(default)]
+    #[schema(example = false)]
+    pub lenient: bool,
+}
+
+impl ParseRequest {
+    /// Pipeline options for parsing this request's data as `format`.
+    pub fn import_options(&self, format: ResumeFormat) -> ImportOptions {
+        ImportOptions {
+            format: Some(format),
+            locale: self.locale.clone(),
+            ids: self.ids,
+            lenient: self.lenient,
+        }
+    }
+}
+
+/// A parsed resume with what the import changed and found
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ImportResponse {
+    pub resume: ResumeData,
+    /// `format` and detection `confidence`, the `changes` made after parsing,
+    /// near-`duplicates` left to merge, warning or (lenient) error
+    /// `issues`, and the old keys of renamed fields as `deprecations`
+    #[schema(value_type = Object)]
+    pub report: ImportReport,
+}
+
+/// Section a CSV import appends to
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum CsvImportSection {
+    Skills,
+    Languages,
+    Certifications,
+}
+
+impl From<CsvImportSection> for CsvSection {
+    fn from(section: CsvImportSection) -> Self {
+        match section {
+            CsvImportSection::Skills => Self::Skills,
+            CsvImportSection::Languages => Self::Languages,
+            CsvImportSection::Certifications => Self::Certifications,
+        }
+    }
+}
+
+/// CSV section import request body
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CsvImportRequest {
+    /// Section to append the rows to
+    #[schema(example = "skills")]
+    pub section: CsvImportSection,
+    /// CSV text with a header row; comma, semicolon, and tab separators work
+    #[schema(example = "Skill,Level,Keywords\nRust,4,\"async, tokio\"")]
+    pub data: String,
+    /// Resume in Rustume format to append to (defaults to an empty resume)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resume: Option<serde_json::Value>,
+}
+
+/// CSV section import response
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CsvImportResponse {
+    /// The resume with the imported items appended
+    pub resume: ResumeData,
+    /// Number of items added; blank and already-present names are skipped
+    #[schema(example = 12)]
+    pub imported: usize,
+}
+
+/// Spreadsheet format for a section export
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum SectionExportFormat {
+    /// Comma-separated values
+    Csv,
+    /// Excel workbook
+    Xlsx,
+}
+
+/// Section export request body
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct SectionExportRequest {
+    /// Resume in Rustume format
+    pub resume: serde_json::Value,
+    /// Section key: `experience`, `skills`, ... or `custom.<id>`
+    #[schema(example = "experience")]
+    pub section: String,
+    /// Output format
+    #[schema(example = "csv")]
+    pub format: SectionExportFormat,
+}
+
+/// Backend that renders a PDF
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum PdfEngine {
+    /// Typst templates (default)
+    Typst,
+    /// The HTML page printed by headless Chromium (servers built with the `chromium` feature)
+    Chromium,
+}
+
+impl From<PdfEngine> for RenderEngine {
+    fn from(engine: PdfEngine) -> Self {
+        match engine {
+            PdfEngine::Typst => Self::Typst,
+            PdfEngine::Chromium => Self::Chromium,
+        }
+    }
+}
+
+/// Raw bytes of a file response (PDF, PNG, ZIP, or spreadsheet)
+#[derive(ToSchema)]
+#[schema(value_type = String, format = Binary)]
+pub struct BinaryFile(pub Vec<u8>);
+
+/// Name of a built-in template, as listed by `GET /api/templates`
+///
+/// Documented as a string enum so generated clients get a template type;
+/// the wire format is a plain string.
+pub struct TemplateId;
+
+impl PartialSchema for TemplateId {
+    fn schema() -> RefOr<Schema> {
+        ObjectBuilder::new()
+            .schema_type(Type::String)
+            .description(Some(
+                "Name of a built-in template, as listed by `GET /api/templates`",
+            ))
+            .enum_values(Some(TEMPLATES.iter().copied()))
+            .examples(["rhyhorn"])
+            .into()
+    }
+}
+
+impl ToSchema for TemplateId {}
+
+/// Template ID path parameter
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Path)]
+pub struct TemplatePath {
+    /// Template ID
+    #[param(value_type = TemplateId)]
+    pub id: String,
 }
 
 /// Render PDF request body
@@ -49,8 +237,34 @@ pub struct RenderPdfRequest {
     pub resume: serde_json::Value,
     /// Template name (optional, uses resume metadata or 'rhyhorn' default)
     #[serde(default)]
-    #[schema(example = "rhyhorn")]
+    #[schema(value_type = Option<TemplateId>)]
     pub template: Option<String>,
+    /// Sections (`references`, a custom section ID) or contact fields (`basics.phone`) to leave
+    /// out of the PDF. The submitted resume is not modified.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[schema(example = json!(["basics.phone", "references"]))]
+    pub exclude_sections: Vec<String>,
+    /// IDs of section items or basics custom fields to leave out of the PDF
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub exclude_item_ids: Vec<String>,
+    /// Rendering backend (optional, uses the server's `RUSTUME_RENDER_ENGINE`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub engine: Option<PdfEngine>,
+    /// Stored resume this render is for (cloud mode). When the signed-in user owns it,
+    /// their webhooks receive a `resume.rendered` event.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<String>, format = "uuid")]
+    pub resume_id: Option<Uuid>,
+}
+
+/// Final `complete` event of a streamed PDF render
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct RenderStreamComplete {
+    /// Base64-encoded PDF document
+    pub pdf: String,
+    /// PDF size in bytes (before base64 encoding)
+    #[schema(example = 48213)]
+    pub size: usize,
 }
 
 /// Render preview request body
@@ -60,7 +274,7 @@ pub struct RenderPreviewRequest {
     pub resume: serde_json::Value,
     /// Template name (optional)
     #[serde(default)]
-    #[schema(example = "rhyhorn")]
+    #[schema(value_type = Option<TemplateId>)]
     pub template: Option<String>,
     /// Page number to preview (0-indexed)
     #[serde(default)]
@@ -68,17 +282,74 @@ pub struct RenderPreviewRequest {
     pub page: usize,
 }
 
+/// Fit to pages request body
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct FitRequest {
+    /// Resume data in Rustume format
+    pub resume: serde_json::Value,
+    /// Page budget
+    #[schema(example = 1, minimum = 1)]
+    pub pages: u32,
+    /// Template name (optional)
+    #[serde(default)]
+    #[schema(value_type = Option<TemplateId>)]
+    pub template: Option<String>,
+    /// Items with any of these keywords are never hidden (case-insensitive)
+    #[serde(default)]
+    #[schema(example = json!(["core"]))]
+    pub keep: Vec<String>,
+}
+
+/// Content preset information
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ContentPresetInfo {
+    /// Preset name, as passed to `GET /api/presets/{name}`
+    pub name: ContentPreset,
+    /// Display name
+    #[schema(example = "Software Engineer")]
+    pub title: String,
+    /// What the skeleton contains
+    pub description: String,
+    /// Layout preset the skeleton is arranged with
+    pub layout: LayoutPreset,
+}
+
+impl From<ContentPreset> for ContentPresetInfo {
+    fn from(preset: ContentPreset) -> Self {
+        Self {
+            name: preset,
+            title: preset.title().to_string(),
+            description: preset.description().to_string(),
+            layout: preset.layout(),
+        }
+    }
+}
+
 /// Template information
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct TemplateInfo {
     /// Template identifier (slug)
-    #[schema(example = "rhyhorn")]
+    #[schema(value_type = TemplateId)]
     pub id: String,
     /// Display name
     #[schema(example = "Rhyhorn")]
     pub name: String,
     /// Theme colors for this template
     pub theme: ThemeInfo,
+    /// `metadata.templateOptions` the template honors
+    pub options: TemplateOptionsInfo,
+}
+
+/// Template options a template honors; others are ignored with a warning
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TemplateOptionsInfo {
+    /// Photo positions besides `template-default`
+    pub photo: Vec<PhotoPosition>,
+    /// Whether `headerAlign` applies
+    pub header_align: bool,
+    /// Whether `divider` applies
+    pub divider: bool,
 }
 
 /// Theme colors for a template
@@ -105,4 +376,185 @@ pub struct ValidationResponse {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[schema(example = json!(["basics.email: invalid email format"]))]
     pub errors: Option<Vec<String>>,
+    /// Non-fatal warnings, such as low theme contrast (only present if any)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(example = json!([
+        "metadata.theme.text: Contrast ratio 1.61:1 against background #ffffff is below the WCAG AA minimum of 4.5:1 (suggested: #767676)"
+    ]))]
+    pub warnings: Option<Vec<String>>,
+}
+
+/// Content suggestions response
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct SuggestResponse {
+    /// Rule-based hints, in resume order (empty when nothing to improve)
+    pub suggestions: Vec<ContentSuggestion>,
+}
+
+/// Analyze request body
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct AnalyzeRequest {
+    /// Resume data in Rustume format
+    pub resume: serde_json::Value,
+    /// Target role keywords, buzzwords, and the overuse limit (all optional)
+    #[serde(default)]
+    pub keywords: KeywordOptions,
+    /// Shortest employment gap to report (optional)
+    #[serde(default)]
+    pub gaps: GapOptions,
+}
+
+/// Resume analysis response
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct AnalyzeResponse {
+    /// Keyword frequency, overused terms, buzzwords, and target coverage
+    pub keywords: KeywordReport,
+    /// Gaps between experience, education, and volunteer entries, oldest first
+    pub gaps: Vec<EmploymentGap>,
+}
+
+/// Duplicate detection response
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct DedupeResponse {
+    /// Likely duplicate item pairs, in section and item order (empty when none found)
+    pub candidates: Vec<DuplicateCandidate>,
+}
+
+/// A confirmed merge of one duplicate item into another
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DedupeMerge {
+    /// Section key from the candidate (`experience`, `custom.<id>`, ...)
+    #[schema(example = "experience")]
+    pub section: String,
+    /// ID of the item to keep
+    pub keep_id: String,
+    /// ID of the item folded into it and removed
+    pub duplicate_id: String,
+}
+
+/// Apply duplicate merges request body
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct DedupeMergeRequest {
+    /// Resume data in Rustume format
+    pub resume: serde_json::Value,
+    /// Merges to apply, in order
+    pub merges: Vec<DedupeMerge>,
+}
+
+/// Merge import request body
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct MergeImportRequest {
+    /// Current resume in Rustume format; its items, IDs, and metadata are kept
+    pub existing: serde_json::Value,
+    /// Newly imported resume in Rustume format (e.g. from `POST /api/parse`)
+    pub imported: serde_json::Value,
+    /// How to combine the two (defaults to `append_new`)
+    #[serde(default)]
+    pub strategy: MergeStrategy,
+}
+
+/// Change report request body
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct DiffReportRequest {
+    /// Old version of the resume in Rustume format
+    pub before: serde_json::Value,
+    /// New version of the resume in Rustume format
+    pub after: serde_json::Value,
+    /// Report format: `md` (default) or `html`
+    #[serde(default)]
+    #[schema(example = "html")]
+    pub format: DiffReportFormat,
+}
+
+/// AI rewrite request
+#[cfg(feature = "ai")]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct RewriteRequest {
+    /// Text to rewrite (plain text or rich text HTML)
+    #[schema(example = "Responsible for the billing service")]
+    pub text: String,
+    /// Desired tone
+    #[serde(default)]
+    pub tone: RewriteTone,
+    /// Target length relative to the original
+    #[serde(default)]
+    pub length: RewriteLength,
+    /// Optional context such as the role and company
+    #[serde(default)]
+    #[schema(example = "Senior Engineer at Acme")]
+    pub context: Option<String>,
+}
+
+/// AI rewrite response
+#[cfg(feature = "ai")]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct RewriteResponse {
+    /// Rewritten plain text, one line per bullet point
+    pub text: String,
+    /// Provider that produced the rewrite
+    #[schema(example = "openai")]
+    pub provider: String,
+    /// Model that produced the rewrite
+    #[schema(example = "gpt-4o-mini")]
+    pub model: String,
+}
+
+/// Filters for `GET /api/admin/api-calls`.
+#[derive(Debug, Default, Deserialize, IntoParams)]
+pub struct ApiCallListQuery {
+    /// Only calls made with the API key of this name.
+    pub api_key: Option<String>,
+    /// Only calls at or after this RFC 3339 timestamp or date.
+    #[param(example = "2026-10-01")]
+    pub since: Option<String>,
+    /// Only calls before this RFC 3339 timestamp or date.
+    pub until: Option<String>,
+    /// At most this many calls (default 100, at most 1000).
+    pub limit: Option<usize>,
+}
+
+impl From<ApiCallListQuery> for ApiCallQuery {
+    fn from(query: ApiCallListQuery) -> Self {
+        Self {
+            api_key: query.api_key,
+            since: query.since,
+            until: query.until,
+            limit: query.limit,
+        }
+    }
+}
+
+/// One audited call made with an API key.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ApiCallRecord {
+    /// When the call finished (RFC 3339, UTC)
+    #[schema(format = "date-time")]
+    pub at: String,
+    /// Name of the API key
+    #[schema(example = "acme")]
+    pub api_key: String,
+    /// Method and route
+    #[schema(example = "POST /api/render/pdf")]
+    pub endpoint: String,
+    /// SHA-256 of the canonical JSON of the resume in the request, when it carried one
+    pub resume_hash: Option<String>,
+    /// HTTP status of the response
+    #[schema(example = 200)]
+    pub status: u16,
+    /// Milliseconds until the response headers were sent
+    pub duration_ms: u64,
+}
+
+impl From<ApiCall> for ApiCallRecord {
+    fn from(call: ApiCall) -> Self {
+        Self {
+            at: call.at,
+            api_key: call.api_key,
+            endpoint: call.endpoint,
+            resume_hash: call.resume_hash,
+            status: call.status,
+            duration_ms: call.duration_ms,
+        }
+    }
 }
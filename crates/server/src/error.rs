@@ -3,9 +3,12 @@ use axum::{
     response::{IntoResponse, Response},
     Json,
 };
+use rustume_schema::{ErrorCode, RustumeError};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
+use crate::middleware::request_id::current_request_id;
+
 /// Error kind for mapping to HTTP status codes.
 #[derive(Debug, Clone, Copy, Default)]
 pub enum ApiErrorKind {
@@ -26,6 +29,10 @@ pub enum ApiErrorKind {
     Conflict,
     /// Payload too large (413) - request exceeds size or count limits
     PayloadTooLarge,
+    /// Unsupported media type (415) - body is not JSON
+    UnsupportedMediaType,
+    /// Bad gateway (502) - an upstream service failed
+    BadGateway,
 }
 
 impl ApiErrorKind {
@@ -39,6 +46,21 @@ impl ApiErrorKind {
             ApiErrorKind::Forbidden => StatusCode::FORBIDDEN,
             ApiErrorKind::Conflict => StatusCode::CONFLICT,
             ApiErrorKind::PayloadTooLarge => StatusCode::PAYLOAD_TOO_LARGE,
+            ApiErrorKind::UnsupportedMediaType => StatusCode::UNSUPPORTED_MEDIA_TYPE,
+            ApiErrorKind::BadGateway => StatusCode::BAD_GATEWAY,
+        }
+    }
+
+    /// Code sent when the handler doesn't set a more specific one. Statuses
+    /// such as 401 or 404 say all a client needs, so they carry none.
+    fn default_code(self) -> Option<ErrorCode> {
+        match self {
+            ApiErrorKind::BadRequest | ApiErrorKind::UnsupportedMediaType => {
+                Some(ErrorCode::InvalidInput)
+            }
+            ApiErrorKind::UnprocessableEntity => Some(ErrorCode::ValidationFailed),
+            ApiErrorKind::InternalError => Some(ErrorCode::Internal),
+            _ => None,
         }
     }
 }
@@ -49,13 +71,31 @@ pub struct ApiError {
     /// Error message
     #[schema(example = "Failed to parse JSON Resume")]
     pub error: String,
+    /// Stable, machine-readable error code; branch on this rather than on
+    /// `error`, whose wording may change.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(example = "PARSE_LINKEDIN_BAD_ZIP")]
+    pub code: Option<ErrorCode>,
     /// Detailed error messages (e.g., validation errors)
     #[serde(skip_serializing_if = "Option::is_none")]
     #[schema(example = json!(["basics.email: invalid email format"]))]
     pub details: Option<Vec<String>>,
+    /// JSON pointer (RFC 6901) to the request body field that doesn't fit
+    /// the expected shape; `""` is the whole body.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(example = "/format")]
+    pub pointer: Option<String>,
+    /// What the field at `pointer` should hold, when known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(example = "one of `json-resume`, `linked-in`, `rrv3`")]
+    pub expected: Option<String>,
     /// Current resource version returned on optimistic concurrency conflicts.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub current_version: Option<i32>,
+    /// ID of the request that failed, for matching a report to server logs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(example = "9b2c6e1a-5f3d-4c8e-a7b0-2d1f4e6c8a90")]
+    pub request_id: Option<String>,
     /// Error kind for HTTP status mapping (not serialized)
     #[serde(skip)]
     pub kind: ApiErrorKind,
@@ -65,8 +105,12 @@ impl ApiError {
     fn with_kind(kind: ApiErrorKind, error: impl Into<String>) -> Self {
         Self {
             error: error.into(),
+            code: kind.default_code(),
             details: None,
+            pointer: None,
+            expected: None,
             current_version: None,
+            request_id: current_request_id(),
             kind,
         }
     }
@@ -80,12 +124,21 @@ impl ApiError {
     pub fn with_details(error: impl Into<String>, details: Vec<String>) -> Self {
         Self {
             error: error.into(),
+            code: ApiErrorKind::UnprocessableEntity.default_code(),
             details: Some(details),
+            pointer: None,
+            expected: None,
             current_version: None,
+            request_id: current_request_id(),
             kind: ApiErrorKind::UnprocessableEntity,
         }
     }
 
+    /// Create a 422 Unprocessable Entity error.
+    pub fn unprocessable(error: impl Into<String>) -> Self {
+        Self::with_kind(ApiErrorKind::UnprocessableEntity, error)
+    }
+
     /// Create a 404 Not Found error.
     pub fn not_found(error: impl Into<String>) -> Self {
         Self::with_kind(ApiErrorKind::NotFound, error)
@@ -115,8 +168,12 @@ impl ApiError {
     pub fn version_conflict(error: impl Into<String>, current_version: i32) -> Self {
         Self {
             error: error.into(),
+            code: None,
             details: None,
+            pointer: None,
+            expected: None,
             current_version: Some(current_version),
+            request_id: current_request_id(),
             kind: ApiErrorKind::Conflict,
         }
     }
@@ -125,6 +182,36 @@ impl ApiError {
     pub fn payload_too_large(error: impl Into<String>) -> Self {
         Self::with_kind(ApiErrorKind::PayloadTooLarge, error)
     }
+
+    /// Create a 415 Unsupported Media Type error.
+    pub fn unsupported_media_type(error: impl Into<String>) -> Self {
+        Self::with_kind(ApiErrorKind::UnsupportedMediaType, error)
+    }
+
+    /// Create a 502 Bad Gateway error.
+    pub fn bad_gateway(error: impl Into<String>) -> Self {
+        Self::with_kind(ApiErrorKind::BadGateway, error)
+    }
+
+    /// Point at the request body field that caused the error.
+    pub fn with_pointer(mut self, pointer: impl Into<String>, expected: Option<String>) -> Self {
+        self.pointer = Some(pointer.into());
+        self.expected = expected;
+        self
+    }
+
+    /// Replace the error code.
+    pub fn with_code(mut self, code: ErrorCode) -> Self {
+        self.code = Some(code);
+        self
+    }
+}
+
+/// A core error as a 400, keeping its message and code.
+impl From<RustumeError> for ApiError {
+    fn from(err: RustumeError) -> Self {
+        Self::new(err.message).with_code(err.code)
+    }
 }
 
 impl IntoResponse for ApiError {
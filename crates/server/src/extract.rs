@@ -0,0 +1,177 @@
+//! Request extractors that reject with [`ApiError`].
+
+use axum::{
+    body::Bytes,
+    extract::{FromRequest, Request},
+    http::{header, HeaderMap, StatusCode},
+};
+use rustume_schema::ErrorCode;
+use serde::de::DeserializeOwned;
+use serde_json::error::Category;
+use serde_path_to_error::{Path, Segment};
+
+use crate::error::ApiError;
+
+/// JSON request body, like [`axum::Json`] but rejected with an [`ApiError`].
+///
+/// Malformed JSON is a 400. JSON that doesn't fit `T` is a 422 with the
+/// JSON pointer of the offending field in `pointer` and what belongs there in
+/// `expected`, so clients can point at the field instead of showing axum's
+/// plain-text message.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ApiJson<T>(pub T);
+
+impl<T, S> FromRequest<S> for ApiJson<T>
+where
+    T: DeserializeOwned,
+    S: Send + Sync,
+{
+    type Rejection = ApiError;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        if !has_json_content_type(req.headers()) {
+            return Err(ApiError::unsupported_media_type(
+                "Expected a request body with `Content-Type: application/json`",
+            ));
+        }
+        let bytes = Bytes::from_request(req, state).await.map_err(|rejection| {
+            match rejection.status() {
+                StatusCode::PAYLOAD_TOO_LARGE => ApiError::payload_too_large(rejection.body_text()),
+                _ => ApiError::new(rejection.body_text()),
+            }
+        })?;
+
+        let deserializer = &mut serde_json::Deserializer::from_slice(&bytes);
+        serde_path_to_error::deserialize(deserializer)
+            .map(ApiJson)
+            .map_err(body_error)
+    }
+}
+
+fn has_json_content_type(headers: &HeaderMap) -> bool {
+    let Some(mime) = headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.split(';').next())
+    else {
+        return false;
+    };
+    let mime = mime.trim().to_ascii_lowercase();
+    mime == "application/json" || (mime.starts_with("application/") && mime.ends_with("+json"))
+}
+
+fn body_error(err: serde_path_to_error::Error<serde_json::Error>) -> ApiError {
+    let mut pointer = json_pointer(err.path());
+    let inner = err.into_inner();
+    // serde_json appends the position, which is noise next to a pointer
+    let message = inner.to_string();
+    let message = message
+        .strip_suffix(&format!(
+            " at line {} column {}",
+            inner.line(),
+            inner.column()
+        ))
+        .unwrap_or(&message)
+        .to_string();
+
+    if inner.classify() != Category::Data {
+        return ApiError::new(format!("Request body is not valid JSON: {message}"));
+    }
+
+    if let Some(field) = backticked(&message, "missing field ") {
+        pointer.push('/');
+        pointer.push_str(&escape(field));
+    }
+    let expected = message
+        .split_once(", expected ")
+        .map(|(_, expected)| expected.to_string());
+    let location = if pointer.is_empty() {
+        "the request body".to_string()
+    } else {
+        format!("`{pointer}`")
+    };
+
+    ApiError::unprocessable(format!("Invalid value at {location}: {message}"))
+        .with_code(ErrorCode::InvalidInput)
+        .with_pointer(pointer, expected)
+}
+
+/// The RFC 6901 pointer for a deserializer path, `""` for the whole body.
+fn json_pointer(path: &Path) -> String {
+    path.iter()
+        .filter_map(|segment| match segment {
+            Segment::Seq { index } => Some(index.to_string()),
+            Segment::Map { key } => Some(escape(key)),
+            Segment::Enum { variant } => Some(escape(variant)),
+            Segment::Unknown => None,
+        })
+        .map(|token| format!("/{token}"))
+        .collect()
+}
+
+fn escape(token: &str) -> String {
+    token.replace('~', "~0").replace('/', "~1")
+}
+
+/// The text between backticks right after `prefix` in `message`.
+fn backticked<'a>(message: &'a str, prefix: &str) -> Option<&'a str> {
+    message
+        .strip_prefix(prefix)?
+        .strip_prefix('`')?
+        .split('`')
+        .next()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, Deserialize)]
+    #[allow(dead_code)]
+    struct Body {
+        items: Vec<Item>,
+    }
+
+    #[derive(Debug, Deserialize)]
+    #[allow(dead_code)]
+    struct Item {
+        name: String,
+        #[serde(rename = "a/b")]
+        slash: Option<u8>,
+    }
+
+    fn error_for(json: &str) -> ApiError {
+        let deserializer = &mut serde_json::Deserializer::from_str(json);
+        body_error(serde_path_to_error::deserialize::<_, Body>(deserializer).unwrap_err())
+    }
+
+    #[test]
+    fn test_type_mismatch_points_at_field() {
+        let err = error_for(r#"{"items": [{"name": "x"}, {"name": 5}]}"#);
+        assert_eq!(err.pointer.as_deref(), Some("/items/1/name"));
+        assert_eq!(err.expected.as_deref(), Some("a string"));
+        assert_eq!(err.code, Some(ErrorCode::InvalidInput));
+        assert_eq!(
+            err.error,
+            "Invalid value at `/items/1/name`: invalid type: integer `5`, expected a string"
+        );
+    }
+
+    #[test]
+    fn test_missing_field_and_escaped_keys() {
+        let err = error_for(r#"{"items": [{}]}"#);
+        assert_eq!(err.pointer.as_deref(), Some("/items/0/name"));
+        assert_eq!(err.expected, None);
+
+        let err = error_for(r#"{"items": [{"name": "x", "a/b": 900}]}"#);
+        assert_eq!(err.pointer.as_deref(), Some("/items/0/a~1b"));
+    }
+
+    #[test]
+    fn test_malformed_json_has_no_pointer() {
+        let err = error_for(r#"{"items": ["#);
+        assert_eq!(err.pointer, None);
+        assert!(err.error.starts_with("Request body is not valid JSON"));
+    }
+}
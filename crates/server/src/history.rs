@@ -0,0 +1,136 @@
+//! Append-only change log of cloud resumes.
+//!
+//! Creates, edits, imports, deletions, and restores from the trash each add a `resume_history` row
+//! with a one-line summary from [`rustume_schema::diff_resumes`]. Writing a
+//! resume without changing it adds nothing.
+
+use rustume_schema::{diff_resumes, ResumeData};
+use sqlx::PgPool;
+use tracing::error;
+use uuid::Uuid;
+
+use crate::db::{ResumeRow, User};
+
+/// One change about to be logged.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResumeChange {
+    /// `created`, `updated`, `deleted`, or `restored`.
+    pub action: &'static str,
+    pub summary: String,
+}
+
+impl ResumeChange {
+    /// Change from writing `row` over `previous`, or `None` when neither the
+    /// title nor the content changed.
+    pub fn saved(previous: Option<&ResumeRow>, row: &ResumeRow) -> Option<Self> {
+        let Some(previous) = previous else {
+            return Some(Self {
+                action: "created",
+                summary: "Created".to_string(),
+            });
+        };
+
+        let mut parts = Vec::new();
+        if previous.title != row.title {
+            parts.push(format!("Renamed to \"{}\"", row.title));
+        }
+        if previous.data != row.data {
+            let before = serde_json::from_value::<ResumeData>(previous.data.clone());
+            let after = serde_json::from_value::<ResumeData>(row.data.clone());
+            match (before, after) {
+                (Ok(before), Ok(after)) => {
+                    let diff = diff_resumes(&before, &after);
+                    if !diff.is_empty() {
+                        parts.push(diff.summary());
+                    }
+                }
+                _ => parts.push("Edited resume".to_string()),
+            }
+        }
+
+        (!parts.is_empty()).then(|| Self {
+            action: "updated",
+            summary: parts.join("; "),
+        })
+    }
+
+    /// Change for deleting a resume.
+    pub fn deleted() -> Self {
+        Self {
+            action: "deleted",
+            summary: "Deleted".to_string(),
+        }
+    }
+
+    /// Change for restoring a resume from the trash.
+    pub fn restored() -> Self {
+        Self {
+            action: "restored",
+            summary: "Restored from trash".to_string(),
+        }
+    }
+}
+
+/// Append `change` to the history of `resume_id` without failing the request
+/// on persistence errors.
+pub async fn record(pool: &PgPool, user: &User, resume_id: Uuid, change: ResumeChange) {
+    let author = user.email.clone().unwrap_or_else(|| user.id.to_string());
+    let result = sqlx::query(
+        r#"
+        INSERT INTO resume_history (resume_id, user_id, author, action, summary)
+        VALUES ($1, $2, $3, $4, $5)
+        "#,
+    )
+    .bind(resume_id)
+    .bind(user.id)
+    .bind(author)
+    .bind(change.action)
+    .bind(change.summary)
+    .execute(pool)
+    .await;
+
+    if let Err(err) = result {
+        error!("resume history insert failed: {err}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn row(title: &str, resume: &ResumeData) -> ResumeRow {
+        ResumeRow {
+            id: Uuid::nil(),
+            user_id: Uuid::nil(),
+            title: title.to_string(),
+            data: serde_json::to_value(resume).unwrap(),
+            is_public: false,
+            public_slug: None,
+            password_hash: None,
+            version: 1,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn saved_changes_are_summarized() {
+        let resume = ResumeData::with_basics("Ada Lovelace", "ada@example.com");
+        let original = row("Resume", &resume);
+        assert_eq!(
+            ResumeChange::saved(None, &original).unwrap().action,
+            "created"
+        );
+        assert_eq!(ResumeChange::saved(Some(&original), &original), None);
+
+        let mut edited = resume.clone();
+        edited.basics.email = "ada@analytical.engine".to_string();
+        let change = ResumeChange::saved(Some(&original), &row("Engine CV", &edited)).unwrap();
+        assert_eq!(change.action, "updated");
+        assert_eq!(
+            change.summary,
+            "Renamed to \"Engine CV\"; Edited basics (email)"
+        );
+    }
+}
@@ -7,9 +7,28 @@
 //! - `GET /health` - Health check
 //! - `GET /api/templates` - List available templates
 //! - `POST /api/parse` - Parse resume from various formats
+//! - `POST /api/parse/report` - Parse resume and report what the import changed and found
+//! - `POST /api/parse/csv` - Append skills, languages, or certifications from CSV
+//! - `POST /api/parse/linkedin` - Parse a LinkedIn export ZIP uploaded as the raw body
+//! - `POST /api/export/section` - Export one section's items as CSV or XLSX
 //! - `POST /api/render/pdf` - Render resume to PDF
+//! - `POST /api/render/pdf/stream` - Render resume to PDF with SSE progress events
 //! - `POST /api/render/preview` - Render resume to PNG preview
+//! - `POST /api/render/svg` - Render one resume page to SVG
+//! - `POST /api/render/timeline` - Render the career timeline to SVG
+//! - `POST /api/fit` - Fit a resume to a page budget
 //! - `POST /api/validate` - Validate resume data
+//! - `POST /api/suggest` - Rule-based suggestions for experience bullet points
+//! - `POST /api/dedupe` - Find likely duplicate section items
+//! - `POST /api/dedupe/merge` - Merge confirmed duplicate items
+//! - `POST /api/merge` - Merge a new import into an existing resume
+//! - `POST /api/diff/report` - Report the changes between two resumes as Markdown or HTML
+//! - `POST /api/stats` - Position durations, total years of experience, and gaps
+//! - `POST /api/analyze` - Keyword density, buzzwords, target role coverage, and employment gaps
+//! - `GET /api/presets` - List content presets (starting resumes by profession)
+//! - `GET /api/presets/{name}` - Pre-filled resume for a content preset
+//! - `POST /api/presets/{name}/apply` - Reorder sections with a layout preset
+//! - `POST /api/ai/rewrite` - AI rewrite of resume text (`ai` feature + `RUSTUME_AI_PROVIDER`)
 //! - `GET /swagger-ui` - Swagger UI documentation
 //!
 //! # Cloud endpoints (when `RUSTUME_CLOUD=true`)
@@ -19,33 +38,55 @@
 //! - `POST /auth/logout` - Clear session
 //! - `GET /auth/me` - Current user profile
 //! - `GET/POST /api/resumes` - List and create resumes
-//! - `GET/PUT/DELETE /api/resumes/{id}` - Resume CRUD
+//! - `GET/PUT/DELETE /api/resumes/{id}` - Resume CRUD (delete moves to the trash)
+//! - `PUT /api/resumes/{id}/sharing` - Publish a resume and set public feed exclusions
+//! - `GET /api/resumes/{id}/history` - Change log of a resume
+//! - `GET /api/resumes/trash` - Deleted resumes awaiting purge
+//! - `POST /api/resumes/{id}/restore` - Restore a resume from the trash
+//! - `GET /u/{slug}/resume.json` - Public JSON Resume feed of a published resume
 //! - `POST /api/resumes/import` - Bulk import from local storage
 //! - `GET /api/resumes/export` - Bulk JSON export
 //! - `GET /api/resumes/export/pdf` - Bulk PDF export (ZIP)
+//! - `GET/POST /api/applications` - List and create job applications
+//! - `GET/PUT/DELETE /api/applications/{id}` - Job application CRUD
+//! - `GET/POST /api/webhooks` - List and register webhook endpoints
+//! - `PUT/DELETE /api/webhooks/{id}` - Update or delete a webhook endpoint
+//! - `GET /api/webhooks/{id}/deliveries` - Recent webhook deliveries
 //! - `DELETE /api/account` - Permanently delete account and all data
 //! - `GET /metrics` - Prometheus metrics
+//! - `GET /api/admin/api-calls` - Audit log of calls made with an API key (admin token)
 
+#[cfg(feature = "ai")]
+pub mod ai;
 pub mod app;
+pub mod artifact_cache;
 pub mod audit;
 pub mod auth;
 pub mod cloud;
 pub mod config;
 pub mod db;
+#[cfg(feature = "dev")]
+pub mod dev_templates;
 pub mod dto;
 pub mod email;
 pub mod error;
+pub mod extract;
+pub mod history;
 pub mod middleware;
 pub mod net;
 pub mod observability;
 pub mod openapi;
+pub mod picture;
 pub mod policy;
+pub mod precompressed;
 pub mod routes;
 pub mod run;
 pub mod shutdown;
 pub mod state;
 pub mod subscription;
+pub mod trash;
 pub mod validation;
+pub mod webhooks;
 
 pub use app::{create_router, create_router_with_state, create_router_with_static_dir};
 pub use run::run;
@@ -55,15 +96,15 @@ mod tests {
     use super::*;
     use axum::{
         body::Body,
-        http::{Request, StatusCode},
+        http::{header, Request, StatusCode},
     };
     use dto::{
         ParseFormat, ParseRequest, RenderPdfRequest, RenderPreviewRequest, TemplateInfo,
         ValidationResponse,
     };
     use error::ApiError;
-    use routes::sanitize_static_path;
-    use rustume_schema::ResumeData;
+    use routes::{sanitize_static_path, serve_web_arg};
+    use rustume_schema::{ErrorCode, ResumeData};
     use tower::ServiceExt;
 
     #[tokio::test]
@@ -85,6 +126,8 @@ mod tests {
 
     #[tokio::test]
     async fn test_templates() {
+        use rustume_schema::PhotoPosition;
+
         let app = create_router();
 
         let response = app
@@ -106,6 +149,14 @@ mod tests {
 
         assert!(!templates.is_empty());
         assert!(templates.iter().any(|t| t.id == "rhyhorn"));
+
+        let pikachu = templates.iter().find(|t| t.id == "pikachu").unwrap();
+        assert_eq!(
+            pikachu.options.photo,
+            [PhotoPosition::Top, PhotoPosition::Hidden]
+        );
+        assert!(pikachu.options.header_align);
+        assert!(!pikachu.options.divider);
     }
 
     #[tokio::test]
@@ -134,11 +185,14 @@ mod tests {
 
         assert!(result.valid);
         assert!(result.errors.is_none());
+        assert!(result.warnings.is_none());
     }
 
     #[tokio::test]
-    async fn test_validate_unknown_shape() {
+    async fn test_validate_low_contrast_theme_warns() {
         let app = create_router();
+        let mut resume = ResumeData::default();
+        resume.metadata.theme.primary = "#fef08a".to_string();
 
         let response = app
             .oneshot(
@@ -146,7 +200,7 @@ mod tests {
                     .method("POST")
                     .uri("/api/validate")
                     .header("content-type", "application/json")
-                    .body(Body::from(r#"{"foo":1}"#))
+                    .body(Body::from(serde_json::to_string(&resume).unwrap()))
                     .unwrap(),
             )
             .await
@@ -160,15 +214,26 @@ mod tests {
         let result: ValidationResponse = serde_json::from_slice(&body).unwrap();
 
         assert!(
-            !result.valid,
-            "Unknown JSON shape must not return valid:true"
+            result.valid,
+            "contrast warnings must not invalidate the resume"
         );
-        assert!(result.errors.is_some());
+        let warnings = result.warnings.expect("expected contrast warnings");
+        assert!(warnings
+            .iter()
+            .any(|w| w.starts_with("metadata.theme.primary:")));
     }
 
     #[tokio::test]
-    async fn test_validate_malformed_body_returns_bad_request() {
+    async fn test_validate_rule_errors_and_warnings_are_distinct() {
+        use rustume_schema::Experience;
+
         let app = create_router();
+        let mut resume = ResumeData::default();
+        resume
+            .sections
+            .experience
+            .add_item(Experience::new("Acme", "Engineer").with_date("2022 - 2019"));
+        resume.basics.url.href = "http://jane.dev".to_string();
 
         let response = app
             .oneshot(
@@ -176,28 +241,51 @@ mod tests {
                     .method("POST")
                     .uri("/api/validate")
                     .header("content-type", "application/json")
-                    .body(Body::from(r#"{"basics":"not-an-object"}"#))
+                    .body(Body::from(serde_json::to_string(&resume).unwrap()))
                     .unwrap(),
             )
             .await
             .unwrap();
 
-        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let result: ValidationResponse = serde_json::from_slice(&body).unwrap();
+
+        assert!(!result.valid);
+        let errors = result.errors.expect("expected rule errors");
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].starts_with("sections.experience.items[0].date:"));
+        let warnings = result.warnings.expect("expected warnings");
+        assert_eq!(
+            warnings,
+            ["basics.url.href: Link does not use HTTPS (suggested: https://jane.dev)"]
+        );
     }
 
     #[tokio::test]
-    async fn test_validate_partial_resume_still_valid() {
+    async fn test_stats_totals_experience() {
+        use rustume_schema::{Experience, ResumeStats};
+
         let app = create_router();
+        let mut resume = ResumeData::default();
+        resume
+            .sections
+            .experience
+            .add_item(Experience::new("Acme", "Engineer").with_date("Jan 2015 - Mar 2017"));
+        resume.sections.experience.add_item(
+            Experience::new("Globex", "Lead").with_dates("2017-04", Some("2018-03".into())),
+        );
 
         let response = app
             .oneshot(
                 Request::builder()
                     .method("POST")
-                    .uri("/api/validate")
+                    .uri("/api/stats")
                     .header("content-type", "application/json")
-                    .body(Body::from(
-                        r#"{"basics":{"name":"Ada Lovelace","email":"ada@example.com"}}"#,
-                    ))
+                    .body(Body::from(serde_json::to_string(&resume).unwrap()))
                     .unwrap(),
             )
             .await
@@ -208,37 +296,77 @@ mod tests {
         let body = axum::body::to_bytes(response.into_body(), usize::MAX)
             .await
             .unwrap();
-        let result: ValidationResponse = serde_json::from_slice(&body).unwrap();
-
-        assert!(result.valid);
-        assert!(result.errors.is_none());
+        let stats: ResumeStats = serde_json::from_slice(&body).unwrap();
+        assert_eq!(stats.positions.len(), 2);
+        assert_eq!(stats.positions[0].duration, "2 yrs 3 mos");
+        assert_eq!(stats.total_experience_months, 39);
+        assert_eq!(stats.total_experience, "3 yrs 3 mos");
+        assert!(stats.gaps.is_empty());
     }
 
     #[tokio::test]
-    async fn test_parse_json_resume() {
+    async fn test_analyze_reports_keywords() {
+        use rustume_schema::Experience;
+
         let app = create_router();
+        let mut resume = ResumeData::default();
+        resume.basics.headline = "Passionate Rust engineer".to_string();
+        resume.sections.experience.add_item(
+            Experience::new("Acme", "Developer")
+                .with_dates("2016-01", Some("2018-12".into()))
+                .with_summary("Built Rust services on Kubernetes"),
+        );
+        resume
+            .sections
+            .experience
+            .add_item(Experience::new("Globex", "Lead").with_dates("2019-04", None));
+        let request = serde_json::json!({
+            "resume": resume,
+            "keywords": { "target": ["rust", "terraform"] },
+            "gaps": { "minMonths": 3 },
+        });
 
-        let json_resume = r#"{
-            "basics": {
-                "name": "Test User",
-                "label": "Developer",
-                "email": "test@example.com"
-            }
-        }"#;
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/analyze")
+                    .header("content-type", "application/json")
+                    .body(Body::from(request.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
 
-        let request = ParseRequest {
-            format: ParseFormat::JsonResume,
-            data: json_resume.to_string(),
-            base64: false,
-        };
+        assert_eq!(response.status(), StatusCode::OK);
 
-        let response = app
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let analysis: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        let keywords = &analysis["keywords"];
+        assert_eq!(keywords["keywords"][0]["term"], "rust");
+        assert_eq!(keywords["buzzwords"][0]["term"], "passionate");
+        assert_eq!(
+            keywords["target"]["missing"],
+            serde_json::json!(["terraform"])
+        );
+        assert_eq!(keywords["sections"][1]["section"], "experience");
+        assert_eq!(analysis["gaps"][0]["start"], "2019-01");
+        assert_eq!(analysis["gaps"][0]["months"], 3);
+    }
+
+    #[tokio::test]
+    async fn test_apply_layout_preset() {
+        let response = create_router()
             .oneshot(
                 Request::builder()
                     .method("POST")
-                    .uri("/api/parse")
+                    .uri("/api/presets/academic/apply")
                     .header("content-type", "application/json")
-                    .body(Body::from(serde_json::to_string(&request).unwrap()))
+                    .body(Body::from(
+                        serde_json::to_string(&ResumeData::default()).unwrap(),
+                    ))
                     .unwrap(),
             )
             .await
@@ -250,27 +378,89 @@ mod tests {
             .await
             .unwrap();
         let resume: ResumeData = serde_json::from_slice(&body).unwrap();
+        assert_eq!(resume.metadata.layout.pages()[0][0][3], "education");
+        assert!(resume.sections.publications.visible);
 
-        assert_eq!(resume.basics.name, "Test User");
-        assert_eq!(resume.basics.headline, "Developer");
+        let response = create_router()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/presets/modern/apply")
+                    .header("content-type", "application/json")
+                    .body(Body::from(
+                        serde_json::to_string(&ResumeData::default()).unwrap(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
     }
 
     #[tokio::test]
-    async fn test_render_pdf() {
-        let app = create_router();
+    async fn test_content_presets() {
+        let response = create_router()
+            .oneshot(
+                Request::builder()
+                    .uri("/api/presets")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let presets: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(presets[0]["name"], "software-engineer");
+        assert_eq!(presets[0]["layout"], "hybrid");
 
-        let request = RenderPdfRequest {
-            resume: serde_json::to_value(ResumeData::default()).unwrap(),
-            template: None,
-        };
+        let response = create_router()
+            .oneshot(
+                Request::builder()
+                    .uri("/api/presets/nurse")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let resume: ResumeData = serde_json::from_slice(&body).unwrap();
+        assert_eq!(resume.basics.headline, "Registered Nurse");
+
+        let response = create_router()
+            .oneshot(
+                Request::builder()
+                    .uri("/api/presets/astronaut")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_render_timeline_returns_svg() {
+        use rustume_schema::Experience;
+
+        let app = create_router();
+        let mut resume = ResumeData::default();
+        resume.sections.experience.add_item(
+            Experience::new("Acme", "Engineer").with_dates("2017-04", Some("2020-03".into())),
+        );
 
         let response = app
             .oneshot(
                 Request::builder()
                     .method("POST")
-                    .uri("/api/render/pdf")
+                    .uri("/api/render/timeline")
                     .header("content-type", "application/json")
-                    .body(Body::from(serde_json::to_string(&request).unwrap()))
+                    .body(Body::from(serde_json::to_string(&resume).unwrap()))
                     .unwrap(),
             )
             .await
@@ -279,228 +469,1555 @@ mod tests {
         assert_eq!(response.status(), StatusCode::OK);
         assert_eq!(
             response.headers().get("content-type").unwrap(),
-            "application/pdf"
+            "image/svg+xml"
         );
-
         let body = axum::body::to_bytes(response.into_body(), usize::MAX)
             .await
             .unwrap();
-
-        // Check PDF magic bytes
-        assert!(body.starts_with(b"%PDF"));
+        let svg = String::from_utf8(body.to_vec()).unwrap();
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.contains(">Acme</text>"));
     }
 
     #[tokio::test]
-    async fn test_render_preview() {
-        let app = create_router();
+    async fn test_suggest_flags_weak_bullets() {
+        use rustume_schema::{Experience, Section};
 
-        let request = RenderPreviewRequest {
-            resume: serde_json::to_value(ResumeData::default()).unwrap(),
-            template: None,
-            page: 0,
-        };
+        let app = create_router();
+        let mut resume = ResumeData::default();
+        resume.sections.experience = Section::new("experience", "Experience");
+        resume.sections.experience.add_item(
+            Experience::new("Acme", "Engineer")
+                .with_summary("<ul><li>Responsible for the deployment pipeline</li></ul>"),
+        );
 
         let response = app
             .oneshot(
                 Request::builder()
                     .method("POST")
-                    .uri("/api/render/preview")
+                    .uri("/api/suggest")
                     .header("content-type", "application/json")
-                    .body(Body::from(serde_json::to_string(&request).unwrap()))
+                    .body(Body::from(serde_json::to_string(&resume).unwrap()))
                     .unwrap(),
             )
             .await
             .unwrap();
 
         assert_eq!(response.status(), StatusCode::OK);
-        assert_eq!(response.headers().get("content-type").unwrap(), "image/png");
-        assert!(response.headers().contains_key("x-total-pages"));
 
         let body = axum::body::to_bytes(response.into_body(), usize::MAX)
             .await
             .unwrap();
-
-        // Check PNG magic bytes
-        assert!(body.starts_with(&[0x89, 0x50, 0x4E, 0x47]));
+        let result: dto::SuggestResponse = serde_json::from_slice(&body).unwrap();
+        let codes: Vec<&str> = result.suggestions.iter().map(|s| s.code.as_str()).collect();
+        assert_eq!(codes, ["weak_verb", "missing_metric"]);
+        assert_eq!(
+            result.suggestions[0].path,
+            "sections.experience.items[0].summary"
+        );
     }
 
     #[tokio::test]
-    async fn test_swagger_ui() {
-        let app = create_router();
+    async fn test_dedupe_finds_and_merges_duplicates() {
+        use rustume_schema::{Experience, Section};
 
-        let response = app
+        let mut resume = ResumeData::default();
+        resume.sections.experience = Section::new("experience", "Experience");
+        resume
+            .sections
+            .experience
+            .add_item(Experience::new("Acme Inc.", "Sr. Engineer"));
+        resume
+            .sections
+            .experience
+            .add_item(Experience::new("ACME", "Senior Engineer").with_location("Berlin"));
+
+        let response = create_router()
             .oneshot(
                 Request::builder()
-                    .uri("/swagger-ui/")
-                    .body(Body::empty())
+                    .method("POST")
+                    .uri("/api/dedupe")
+                    .header("content-type", "application/json")
+                    .body(Body::from(serde_json::to_string(&resume).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let result: dto::DedupeResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(result.candidates.len(), 1);
+        let candidate = &result.candidates[0];
+
+        let request = serde_json::json!({
+            "resume": resume,
+            "merges": [{
+                "section": candidate.section,
+                "keepId": candidate.keep_id,
+                "duplicateId": candidate.duplicate_id,
+            }],
+        });
+        let response = create_router()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/dedupe/merge")
+                    .header("content-type", "application/json")
+                    .body(Body::from(request.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let merged: ResumeData = serde_json::from_slice(&body).unwrap();
+        let items = &merged.sections.experience.items;
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].id, candidate.keep_id);
+        assert_eq!(items[0].location, "Berlin");
+    }
+
+    #[tokio::test]
+    async fn test_dedupe_merge_unknown_item_is_bad_request() {
+        let request = serde_json::json!({
+            "resume": ResumeData::default(),
+            "merges": [{"section": "skills", "keepId": "a", "duplicateId": "b"}],
+        });
+        let response = create_router()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/dedupe/merge")
+                    .header("content-type", "application/json")
+                    .body(Body::from(request.to_string()))
                     .unwrap(),
             )
             .await
             .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_merge_appends_new_items_and_reports() {
+        use rustume_schema::{MergedImport, Skill};
 
+        let mut existing = ResumeData::default();
+        existing.sections.skills.add_item(Skill::new("Rust"));
+        let mut imported = ResumeData::default();
+        imported.sections.skills.add_item(Skill::new("rust"));
+        imported.sections.skills.add_item(Skill::new("Go"));
+
+        let request = serde_json::json!({ "existing": existing, "imported": imported });
+        let response = create_router()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/merge")
+                    .header("content-type", "application/json")
+                    .body(Body::from(request.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
         assert_eq!(response.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let merged: MergedImport = serde_json::from_slice(&body).unwrap();
+        let names: Vec<&str> = merged
+            .resume
+            .sections
+            .skills
+            .items
+            .iter()
+            .map(|skill| skill.name.as_str())
+            .collect();
+        assert_eq!(names, ["Rust", "Go"]);
+        assert_eq!(merged.report.added.len(), 1);
+        assert_eq!(merged.report.skipped.len(), 1);
     }
 
     #[tokio::test]
-    async fn test_unknown_api_route_returns_not_found() {
+    async fn test_diff_report_marks_edited_words() {
+        let before = ResumeData::with_basics("Ada Lovelace", "ada@example.com");
+        let mut after = before.clone();
+        after.basics.headline = "Analyst and writer".to_string();
+
+        for (format, content_type, expected) in [
+            (
+                "md",
+                "text/markdown; charset=utf-8",
+                "- Headline: **Analyst and writer**",
+            ),
+            (
+                "html",
+                "text/html; charset=utf-8",
+                "<li>Headline: <ins>Analyst and writer</ins></li>",
+            ),
+        ] {
+            let request = serde_json::json!({ "before": before, "after": after, "format": format });
+            let response = create_router()
+                .oneshot(
+                    Request::builder()
+                        .method("POST")
+                        .uri("/api/diff/report")
+                        .header("content-type", "application/json")
+                        .body(Body::from(request.to_string()))
+                        .unwrap(),
+                )
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+            assert_eq!(response.headers()["content-type"], content_type);
+
+            let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            let report = String::from_utf8(body.to_vec()).unwrap();
+            assert!(report.contains(expected), "{format}: {report}");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_validate_unknown_shape() {
         let app = create_router();
 
         let response = app
             .oneshot(
                 Request::builder()
-                    .uri("/api/missing")
-                    .body(Body::empty())
+                    .method("POST")
+                    .uri("/api/validate")
+                    .header("content-type", "application/json")
+                    .body(Body::from(r#"{"foo":1}"#))
                     .unwrap(),
             )
             .await
             .unwrap();
-
-        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let result: ValidationResponse = serde_json::from_slice(&body).unwrap();
+
+        assert!(
+            !result.valid,
+            "Unknown JSON shape must not return valid:true"
+        );
+        assert!(result.errors.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_validate_malformed_body_returns_bad_request() {
+        let app = create_router();
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/validate")
+                    .header("content-type", "application/json")
+                    .body(Body::from(r#"{"basics":"not-an-object"}"#))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_validate_partial_resume_still_valid() {
+        let app = create_router();
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/validate")
+                    .header("content-type", "application/json")
+                    .body(Body::from(
+                        r#"{"basics":{"name":"Ada Lovelace","email":"ada@example.com"}}"#,
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let result: ValidationResponse = serde_json::from_slice(&body).unwrap();
+
+        assert!(result.valid);
+        assert!(result.errors.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_parse_json_resume() {
+        let app = create_router();
+
+        let json_resume = r#"{
+            "basics": {
+                "name": "Test User",
+                "label": "Developer",
+                "email": "test@example.com"
+            }
+        }"#;
+
+        let request = ParseRequest {
+            format: ParseFormat::JsonResume,
+            data: json_resume.to_string(),
+            base64: false,
+            locale: None,
+            ids: Default::default(),
+            lenient: false,
+        };
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/parse")
+                    .header("content-type", "application/json")
+                    .body(Body::from(serde_json::to_string(&request).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let resume: ResumeData = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(resume.basics.name, "Test User");
+        assert_eq!(resume.basics.headline, "Developer");
+    }
+
+    #[tokio::test]
+    async fn test_parse_auto_detects_format() {
+        let app = create_router();
+
+        let request = ParseRequest {
+            format: ParseFormat::Auto,
+            data: "BEGIN:VCARD\r\nVERSION:4.0\r\nFN:Test User\r\nEND:VCARD\r\n".to_string(),
+            base64: false,
+            locale: None,
+            ids: Default::default(),
+            lenient: false,
+        };
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/parse")
+                    .header("content-type", "application/json")
+                    .body(Body::from(serde_json::to_string(&request).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let resume: ResumeData = serde_json::from_slice(&body).unwrap();
+        assert_eq!(resume.basics.name, "Test User");
+    }
+
+    #[tokio::test]
+    async fn test_parse_auto_rejects_undetectable_input() {
+        let app = create_router();
+
+        let request = ParseRequest {
+            format: ParseFormat::Auto,
+            data: "Test User\nDeveloper".to_string(),
+            base64: false,
+            locale: None,
+            ids: Default::default(),
+            lenient: false,
+        };
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/parse")
+                    .header("content-type", "application/json")
+                    .body(Body::from(serde_json::to_string(&request).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let error: ApiError = serde_json::from_slice(&body).unwrap();
+        assert!(error.error.contains("`format`"));
+        assert_eq!(error.code, Some(ErrorCode::ParseInvalidFormat));
+    }
+
+    #[tokio::test]
+    async fn test_parse_report_lists_pipeline_changes() {
+        let data = r#"{"basics":{"name":"Test User","label":"Dev"},
+            "skills":[{"name":"Rust"},{"name":"Rust"}],
+            "work":[{"name":"Acme","position":"Dev","startDate":"2022-01","endDate":"2019-01"}]}"#;
+        let post = |lenient: bool| {
+            let request = serde_json::json!({
+                "format": "json-resume",
+                "data": data,
+                "lenient": lenient,
+            });
+            create_router().oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/parse/report")
+                    .header("content-type", "application/json")
+                    .body(Body::from(request.to_string()))
+                    .unwrap(),
+            )
+        };
+
+        let response = post(false).await.unwrap();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let error: ApiError = serde_json::from_slice(&body).unwrap();
+        assert!(error.error.contains("`lenient`"));
+        assert_eq!(error.code, Some(ErrorCode::ValidationFailed));
+
+        let response = post(true).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let imported: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        let report = &imported["report"];
+        assert_eq!(report["format"], "json-resume");
+        assert_eq!(
+            imported["resume"]["sections"]["skills"]["items"]
+                .as_array()
+                .unwrap()
+                .len(),
+            1
+        );
+        assert!(report["changes"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|change| change["code"] == "duplicate_item"));
+        assert!(report["issues"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|issue| issue["severity"] == "error"));
+    }
+
+    #[tokio::test]
+    async fn test_render_pdf() {
+        let app = create_router();
+
+        let request = RenderPdfRequest {
+            resume: serde_json::to_value(ResumeData::default()).unwrap(),
+            template: None,
+            exclude_sections: Vec::new(),
+            exclude_item_ids: Vec::new(),
+            engine: None,
+            resume_id: None,
+        };
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/render/pdf")
+                    .header("content-type", "application/json")
+                    .body(Body::from(serde_json::to_string(&request).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers().get("content-type").unwrap(),
+            "application/pdf"
+        );
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+
+        // Check PDF magic bytes
+        assert!(body.starts_with(b"%PDF"));
+    }
+
+    #[tokio::test]
+    async fn test_render_pdf_sets_content_length() {
+        let app = create_router();
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/render/pdf")
+                    .header("content-type", "application/json")
+                    .body(Body::from(
+                        serde_json::to_string(&sample_render_pdf_request()).unwrap(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let content_length: usize = response.headers()["content-length"]
+            .to_str()
+            .unwrap()
+            .parse()
+            .unwrap();
+        // The body arrives in chunks of at most 64 KiB
+        let mut body = Vec::new();
+        let mut chunks = response.into_body().into_data_stream();
+        while let Some(chunk) = tokio_stream::StreamExt::next(&mut chunks).await {
+            let chunk = chunk.unwrap();
+            assert!(chunk.len() <= 64 * 1024);
+            body.extend_from_slice(&chunk);
+        }
+        assert_eq!(body.len(), content_length);
+        assert!(body.starts_with(b"%PDF"));
+    }
+
+    #[tokio::test]
+    async fn test_render_pdf_with_exclusions() {
+        let mut resume = ResumeData::default();
+        resume.basics.phone = "+1 555 0100".to_string();
+        let request = RenderPdfRequest {
+            resume: serde_json::to_value(resume).unwrap(),
+            template: None,
+            exclude_sections: vec!["basics.phone".to_string(), "references".to_string()],
+            exclude_item_ids: vec!["unknown-id".to_string()],
+            engine: None,
+            resume_id: None,
+        };
+
+        let response = create_router()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/render/pdf")
+                    .header("content-type", "application/json")
+                    .body(Body::from(serde_json::to_string(&request).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let request = RenderPdfRequest {
+            exclude_sections: vec!["basics.fax".to_string()],
+            ..sample_render_pdf_request()
+        };
+        let response = create_router()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/render/pdf")
+                    .header("content-type", "application/json")
+                    .body(Body::from(serde_json::to_string(&request).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_render_pdf_rejects_unavailable_engine() {
+        let request = RenderPdfRequest {
+            engine: Some(dto::PdfEngine::Chromium),
+            ..sample_render_pdf_request()
+        };
+        // Test state registers Typst only, whatever features are built in
+        let state = state::AppState::with_require_auth(
+            std::sync::Arc::new(routes::static_dir()),
+            None,
+            false,
+        );
+        for uri in ["/api/render/pdf", "/api/render/pdf/stream"] {
+            let response = create_router_with_state(state.clone())
+                .oneshot(
+                    Request::builder()
+                        .method("POST")
+                        .uri(uri)
+                        .header("content-type", "application/json")
+                        .body(Body::from(serde_json::to_string(&request).unwrap()))
+                        .unwrap(),
+                )
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+            let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            let error: serde_json::Value = serde_json::from_slice(&body).unwrap();
+            assert_eq!(error["error"], "Render engine `chromium` is not available");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_render_pdf_rejects_resume_over_render_limits() {
+        let mut state = state::AppState::with_require_auth(
+            std::sync::Arc::new(routes::static_dir()),
+            None,
+            false,
+        );
+        state.renderer = std::sync::Arc::new(rustume_render::TypstRenderer::new().with_limits(
+            rustume_render::RenderLimits {
+                max_data_bytes: Some(4 * 1024),
+                max_asset_bytes: None,
+            },
+        ));
+        let app = create_router_with_state(state);
+
+        let mut resume = ResumeData::default();
+        resume.sections.summary.content = "word ".repeat(2_000);
+        let request = RenderPdfRequest {
+            resume: serde_json::to_value(resume).unwrap(),
+            template: None,
+            exclude_sections: Vec::new(),
+            exclude_item_ids: Vec::new(),
+            engine: None,
+            resume_id: None,
+        };
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/render/pdf")
+                    .header("content-type", "application/json")
+                    .body(Body::from(serde_json::to_string(&request).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let error: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert!(error["error"]
+            .as_str()
+            .unwrap()
+            .contains("too large to render"));
+    }
+
+    #[tokio::test]
+    async fn test_render_pdf_served_from_artifact_cache() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = artifact_cache::ArtifactCache::open(dir.path(), 64 * 1024 * 1024).unwrap();
+        let state = state::AppState::with_require_auth(
+            std::sync::Arc::new(routes::static_dir()),
+            None,
+            false,
+        )
+        .with_artifact_cache(cache);
+        let app = create_router_with_state(state);
+        let body = serde_json::to_string(&sample_render_pdf_request()).unwrap();
+
+        let mut pdfs = Vec::new();
+        for _ in 0..2 {
+            let response = app
+                .clone()
+                .oneshot(
+                    Request::builder()
+                        .method("POST")
+                        .uri("/api/render/pdf")
+                        .header("content-type", "application/json")
+                        .body(Body::from(body.clone()))
+                        .unwrap(),
+                )
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+            let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            pdfs.push(bytes);
+        }
+
+        assert_eq!(pdfs[0], pdfs[1]);
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_render_pdf_stream_emits_progress_then_pdf() {
+        use base64::Engine;
+
+        let app = create_router();
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/render/pdf/stream")
+                    .header("content-type", "application/json")
+                    .body(Body::from(
+                        serde_json::to_string(&sample_render_pdf_request()).unwrap(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers().get("content-type").unwrap(),
+            "text/event-stream"
+        );
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body = String::from_utf8(body.to_vec()).unwrap();
+
+        let events: Vec<(&str, serde_json::Value)> = body
+            .split("\n\n")
+            .filter_map(|frame| {
+                let name = frame.lines().find_map(|l| l.strip_prefix("event: "))?;
+                let data = frame.lines().find_map(|l| l.strip_prefix("data: "))?;
+                Some((name, serde_json::from_str(data).unwrap()))
+            })
+            .collect();
+
+        let stages: Vec<&str> = events
+            .iter()
+            .filter(|(name, _)| *name == "progress")
+            .map(|(_, data)| data["stage"].as_str().unwrap())
+            .collect();
+        let total_pages = events
+            .iter()
+            .find(|(_, data)| data["stage"] == "compiled")
+            .map(|(_, data)| data["total_pages"].as_u64().unwrap() as usize)
+            .unwrap();
+        assert!(total_pages > 0);
+        assert_eq!(stages[..2], ["source_generated", "compiled"]);
+        assert_eq!(
+            stages[2..2 + total_pages],
+            vec!["page_rendered"; total_pages]
+        );
+        assert_eq!(stages[2 + total_pages..], ["exporting", "done"]);
+
+        let (name, complete) = events.last().unwrap();
+        assert_eq!(*name, "complete");
+        let pdf = base64::engine::general_purpose::STANDARD
+            .decode(complete["pdf"].as_str().unwrap())
+            .unwrap();
+        assert!(pdf.starts_with(b"%PDF"));
+        assert_eq!(complete["size"].as_u64().unwrap() as usize, pdf.len());
+    }
+
+    #[tokio::test]
+    async fn test_render_pdf_stream_rejects_invalid_resume_before_streaming() {
+        let app = create_router();
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/render/pdf/stream")
+                    .header("content-type", "application/json")
+                    .body(Body::from(r#"{"resume":{"basics":"not an object"}}"#))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_render_preview() {
+        let app = create_router();
+
+        let request = RenderPreviewRequest {
+            resume: serde_json::to_value(ResumeData::default()).unwrap(),
+            template: None,
+            page: 0,
+        };
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/render/preview")
+                    .header("content-type", "application/json")
+                    .body(Body::from(serde_json::to_string(&request).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers().get("content-type").unwrap(), "image/png");
+        assert!(response.headers().contains_key("x-total-pages"));
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+
+        // Check PNG magic bytes
+        assert!(body.starts_with(&[0x89, 0x50, 0x4E, 0x47]));
+    }
+
+    #[tokio::test]
+    async fn test_render_svg() {
+        let app = create_router();
+
+        let request = RenderPreviewRequest {
+            resume: serde_json::to_value(ResumeData::default()).unwrap(),
+            template: None,
+            page: 0,
+        };
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/render/svg")
+                    .header("content-type", "application/json")
+                    .body(Body::from(serde_json::to_string(&request).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers().get("content-type").unwrap(),
+            "image/svg+xml"
+        );
+        assert_eq!(response.headers().get("x-total-pages").unwrap(), "1");
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        assert!(String::from_utf8(body.to_vec())
+            .unwrap()
+            .starts_with("<svg"));
+    }
+
+    #[tokio::test]
+    async fn test_api_calls_are_audited_by_key() {
+        use audit::api::{ApiAudit, ApiKeys, API_KEY_HEADER};
+        use rustume_storage::{ApiCallQuery, MemoryStorage};
+
+        let audit = std::sync::Arc::new(ApiAudit::new(
+            ApiKeys::parse("acme:k3y").unwrap(),
+            std::sync::Arc::new(MemoryStorage::new()),
+        ));
+        let state = state::AppState::with_require_auth(
+            std::sync::Arc::new(routes::static_dir()),
+            None,
+            false,
+        )
+        .with_api_audit(audit.clone());
+        let resume = serde_json::to_string(&ResumeData::default()).unwrap();
+        let validate = |key: Option<&str>| {
+            let mut request = Request::builder()
+                .method("POST")
+                .uri("/api/validate")
+                .header("content-type", "application/json");
+            if let Some(key) = key {
+                request = request.header(API_KEY_HEADER, key);
+            }
+            request.body(Body::from(resume.clone())).unwrap()
+        };
+
+        for key in [Some("k3y"), None, Some("wrong")] {
+            let response = create_router_with_state(state.clone())
+                .oneshot(validate(key))
+                .await
+                .unwrap();
+            let expected = if key == Some("wrong") {
+                StatusCode::UNAUTHORIZED
+            } else {
+                StatusCode::OK
+            };
+            assert_eq!(response.status(), expected);
+        }
+
+        let calls = audit.log.api_calls(&ApiCallQuery::default()).await.unwrap();
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].api_key, "acme");
+        assert_eq!(calls[0].endpoint, "POST /api/validate");
+        assert_eq!(calls[0].status, 200);
+        assert_eq!(calls[0].resume_hash.as_ref().map(String::len), Some(64));
+
+        let response = create_router_with_state(state)
+            .oneshot(
+                Request::builder()
+                    .uri("/api/admin/api-calls")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn test_fit_pages() {
+        use rustume_schema::FittedResume;
+
+        let app = create_router();
+        let resume = serde_json::to_value(ResumeData::default()).unwrap();
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/fit")
+                    .header("content-type", "application/json")
+                    .body(Body::from(
+                        serde_json::json!({ "resume": resume, "pages": 1 }).to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let fitted: FittedResume = serde_json::from_slice(&body).unwrap();
+        assert!(fitted.report.fits);
+        assert_eq!(fitted.report.pages_before, 1);
+        assert!(fitted.report.steps.is_empty());
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/fit")
+                    .header("content-type", "application/json")
+                    .body(Body::from(
+                        serde_json::json!({ "resume": resume, "pages": 0 }).to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_swagger_ui() {
+        let app = create_router();
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/swagger-ui/")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_unknown_api_route_returns_not_found() {
+        let app = create_router();
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/api/missing")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_asset_miss_returns_404() {
+        let tmp = tempfile::tempdir().unwrap();
+        std::fs::write(tmp.path().join("index.html"), "<html></html>").unwrap();
+        let app = create_router_with_static_dir(tmp.path().to_path_buf());
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/assets/missing.js")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_spa_routes_and_assets_cache_headers() {
+        let tmp = tempfile::tempdir().unwrap();
+        std::fs::write(tmp.path().join("index.html"), "<html></html>").unwrap();
+        std::fs::create_dir(tmp.path().join("assets")).unwrap();
+        std::fs::write(tmp.path().join("assets/app-3f2a.js"), "export {}").unwrap();
+        let app = create_router_with_static_dir(tmp.path().to_path_buf());
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .uri("/resumes/123/edit")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let headers = response.headers();
+        assert_eq!(headers["content-type"], "text/html; charset=utf-8");
+        assert_eq!(headers["cache-control"], "no-cache");
+        assert!(headers.contains_key("content-security-policy"));
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/assets/app-3f2a.js")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers()["cache-control"],
+            "public, max-age=31536000, immutable"
+        );
+    }
+
+    #[test]
+    fn test_serve_web_arg() {
+        let args = |list: &[&str]| list.iter().map(|arg| arg.to_string()).collect::<Vec<_>>();
+        assert_eq!(
+            serve_web_arg(args(&["rustume-server", "--serve-web", "dist"])),
+            Some("dist".into())
+        );
+        assert_eq!(
+            serve_web_arg(args(&["rustume-server", "--serve-web=./web"])),
+            Some("./web".into())
+        );
+        assert_eq!(serve_web_arg(args(&["rustume-server", "--health"])), None);
+        assert_eq!(
+            serve_web_arg(args(&["rustume-server", "--serve-web"])),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn test_post_to_unmatched_route_returns_method_not_allowed() {
+        let app = create_router();
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/some-unmatched-path")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::METHOD_NOT_ALLOWED);
+    }
+
+    #[test]
+    fn test_static_path_rejects_traversal() {
+        assert!(sanitize_static_path("/assets/app.js").is_some());
+        assert!(sanitize_static_path("/../Cargo.toml").is_none());
+        assert!(sanitize_static_path("/assets/../Cargo.toml").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_openapi_spec() {
+        let app = create_router();
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/api-docs/openapi.json")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let spec: serde_json::Value = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(spec["info"]["title"], "Rustume API");
+        assert_eq!(spec["info"]["version"], env!("CARGO_PKG_VERSION"));
+        assert!(spec["paths"].as_object().unwrap().contains_key("/health"));
+        assert!(spec["paths"]
+            .as_object()
+            .unwrap()
+            .contains_key("/api/templates"));
+        assert!(spec["paths"].as_object().unwrap().contains_key("/auth/me"));
+        assert!(spec["paths"]
+            .as_object()
+            .unwrap()
+            .contains_key("/api/resumes"));
+        assert!(spec["paths"]
+            .as_object()
+            .unwrap()
+            .contains_key("/api/applications/{id}"));
+        assert!(spec["components"]["schemas"]["JobApplication"].is_object());
+        assert!(spec["components"]["securitySchemes"]["cookieAuth"].is_object());
+    }
+
+    #[tokio::test]
+    async fn test_openapi_binary_and_error_responses() {
+        let response = create_router()
+            .oneshot(
+                Request::builder()
+                    .uri("/api-docs/openapi.json")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let spec: serde_json::Value = serde_json::from_slice(&body).unwrap();
+
+        let pdf = &spec["paths"]["/api/render/pdf"]["post"]["responses"];
+        assert_eq!(
+            pdf["200"]["content"]["application/pdf"]["schema"]["format"],
+            "binary"
+        );
+        assert_eq!(
+            pdf["400"]["content"]["application/json"]["example"]["code"],
+            "INVALID_INPUT"
+        );
+        assert_eq!(
+            pdf["422"]["content"]["application/json"]["example"]["pointer"],
+            "/template"
+        );
+
+        // Every operation documents a 500 and a rate limit body
+        let health = &spec["paths"]["/health"]["get"]["responses"];
+        assert_eq!(
+            health["500"]["content"]["application/json"]["example"]["code"],
+            "INTERNAL"
+        );
+        assert_eq!(
+            health["429"]["content"]["application/json"]["schema"]["$ref"],
+            "#/components/schemas/RateLimitErrorBody"
+        );
+
+        let thumbnail = &spec["paths"]["/api/templates/{id}/thumbnail"]["get"];
+        assert_eq!(
+            thumbnail["parameters"][0]["schema"]["$ref"],
+            "#/components/schemas/TemplateId"
+        );
+        let templates = spec["components"]["schemas"]["TemplateId"]["enum"]
+            .as_array()
+            .unwrap();
+        assert_eq!(templates.len(), rustume_render::TEMPLATES.len());
+    }
+
+    #[tokio::test]
+    async fn test_parse_rustume_format() {
+        let app = create_router();
+        let resume = ResumeData::default();
+
+        let request = ParseRequest {
+            format: ParseFormat::Rustume,
+            data: serde_json::to_string(&resume).unwrap(),
+            base64: false,
+            locale: None,
+            ids: Default::default(),
+            lenient: false,
+        };
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/parse")
+                    .header("content-type", "application/json")
+                    .body(Body::from(serde_json::to_string(&request).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let parsed: ResumeData = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(parsed.basics.name, resume.basics.name);
+    }
+
+    #[tokio::test]
+    async fn test_parse_linkedin_upload_reads_raw_zip() {
+        use std::io::Write;
+
+        let mut archive = Vec::new();
+        {
+            let mut zip = zip::ZipWriter::new(std::io::Cursor::new(&mut archive));
+            zip.start_file("Profile.csv", zip::write::SimpleFileOptions::default())
+                .unwrap();
+            zip.write_all(b"First Name,Last Name,Headline\nAda,Lovelace,Analyst\n")
+                .unwrap();
+            zip.finish().unwrap();
+        }
+
+        let upload = |body: Vec<u8>| {
+            create_router().oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/parse/linkedin")
+                    .header("content-type", "application/zip")
+                    .body(Body::from(body))
+                    .unwrap(),
+            )
+        };
+
+        let response = upload(archive).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let parsed: ResumeData = serde_json::from_slice(&body).unwrap();
+        assert_eq!(parsed.basics.name, "Ada Lovelace");
+
+        let response = upload(b"not a zip".to_vec()).await.unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_parse_invalid_json() {
+        let app = create_router();
+
+        let request = ParseRequest {
+            format: ParseFormat::JsonResume,
+            data: "{ invalid json }".to_string(),
+            base64: false,
+            locale: None,
+            ids: Default::default(),
+            lenient: false,
+        };
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/parse")
+                    .header("content-type", "application/json")
+                    .body(Body::from(serde_json::to_string(&request).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let error: ApiError = serde_json::from_slice(&body).unwrap();
+
+        assert!(error.error.contains("Failed to parse"));
+        assert_eq!(error.code, Some(ErrorCode::ParseReadFailed));
+    }
+
+    #[tokio::test]
+    async fn test_parse_request_schema_error_has_pointer() {
+        let app = create_router();
+        let request = serde_json::json!({ "format": "pdf", "data": "{}" });
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/parse")
+                    .header("content-type", "application/json")
+                    .body(Body::from(request.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let error: ApiError = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(error.code, Some(ErrorCode::InvalidInput));
+        assert_eq!(error.pointer.as_deref(), Some("/format"));
+        assert!(error.expected.unwrap().contains("`json-resume`"));
+    }
+
+    #[tokio::test]
+    async fn test_render_pdf_request_missing_field_and_content_type() {
+        let response = create_router()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/render/pdf")
+                    .header("content-type", "application/json")
+                    .body(Body::from(r#"{"template": "rhyhorn"}"#))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let error: ApiError = serde_json::from_slice(&body).unwrap();
+        assert_eq!(error.pointer.as_deref(), Some("/resume"));
+
+        let response = create_router()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/render/pdf")
+                    .header("content-type", "text/plain")
+                    .body(Body::from("{}"))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+    }
+
+    #[tokio::test]
+    async fn test_parse_csv_appends_to_resume() {
+        let app = create_router();
+
+        let mut resume = ResumeData::default();
+        resume
+            .sections
+            .skills
+            .items
+            .push(rustume_schema::Skill::new("Rust"));
+        let request = serde_json::json!({
+            "section": "skills",
+            "data": "Skill,Level\nrust,5\nTypeScript,4\n",
+            "resume": resume,
+        });
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/parse/csv")
+                    .header("content-type", "application/json")
+                    .body(Body::from(request.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let imported: dto::CsvImportResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(imported.imported, 1);
+        let skills = &imported.resume.sections.skills.items;
+        assert_eq!(skills.len(), 2);
+        assert_eq!(skills[1].name, "TypeScript");
+        assert_eq!(skills[1].level, 4);
     }
 
     #[tokio::test]
-    async fn test_asset_miss_returns_404() {
-        let tmp = tempfile::tempdir().unwrap();
-        std::fs::write(tmp.path().join("index.html"), "<html></html>").unwrap();
-        let app = create_router_with_static_dir(tmp.path().to_path_buf());
+    async fn test_export_section_csv() {
+        let app = create_router();
+
+        let mut resume = ResumeData::default();
+        resume
+            .sections
+            .skills
+            .items
+            .push(rustume_schema::Skill::new("Rust").with_level(4));
+        let request = serde_json::json!({
+            "resume": resume,
+            "section": "skills",
+            "format": "csv",
+        });
 
         let response = app
             .oneshot(
                 Request::builder()
-                    .uri("/assets/missing.js")
-                    .body(Body::empty())
+                    .method("POST")
+                    .uri("/api/export/section")
+                    .header("content-type", "application/json")
+                    .body(Body::from(request.to_string()))
                     .unwrap(),
             )
             .await
             .unwrap();
 
-        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers()["content-disposition"],
+            "attachment; filename=\"skills.csv\""
+        );
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let csv = String::from_utf8(body.to_vec()).unwrap();
+        assert!(csv.starts_with("name,level,keywords,description,visible,id\nRust,4,"));
     }
 
     #[tokio::test]
-    async fn test_post_to_unmatched_route_returns_method_not_allowed() {
+    async fn test_export_section_rejects_unknown_section() {
         let app = create_router();
 
+        let request = serde_json::json!({
+            "resume": ResumeData::default(),
+            "section": "custom.missing",
+            "format": "xlsx",
+        });
         let response = app
             .oneshot(
                 Request::builder()
                     .method("POST")
-                    .uri("/some-unmatched-path")
-                    .body(Body::empty())
+                    .uri("/api/export/section")
+                    .header("content-type", "application/json")
+                    .body(Body::from(request.to_string()))
                     .unwrap(),
             )
             .await
             .unwrap();
 
-        assert_eq!(response.status(), StatusCode::METHOD_NOT_ALLOWED);
-    }
-
-    #[test]
-    fn test_static_path_rejects_traversal() {
-        assert!(sanitize_static_path("/assets/app.js").is_some());
-        assert!(sanitize_static_path("/../Cargo.toml").is_none());
-        assert!(sanitize_static_path("/assets/../Cargo.toml").is_none());
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
     }
 
     #[tokio::test]
-    async fn test_openapi_spec() {
+    async fn test_parse_csv_requires_name_column() {
         let app = create_router();
 
+        let request = serde_json::json!({ "section": "languages", "data": "level\n5\n" });
         let response = app
             .oneshot(
                 Request::builder()
-                    .uri("/api-docs/openapi.json")
-                    .body(Body::empty())
+                    .method("POST")
+                    .uri("/api/parse/csv")
+                    .header("content-type", "application/json")
+                    .body(Body::from(request.to_string()))
                     .unwrap(),
             )
             .await
             .unwrap();
 
-        assert_eq!(response.status(), StatusCode::OK);
-
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
         let body = axum::body::to_bytes(response.into_body(), usize::MAX)
             .await
             .unwrap();
-        let spec: serde_json::Value = serde_json::from_slice(&body).unwrap();
-
-        assert_eq!(spec["info"]["title"], "Rustume API");
-        assert_eq!(spec["info"]["version"], env!("CARGO_PKG_VERSION"));
-        assert!(spec["paths"].as_object().unwrap().contains_key("/health"));
-        assert!(spec["paths"]
-            .as_object()
-            .unwrap()
-            .contains_key("/api/templates"));
-        assert!(spec["paths"].as_object().unwrap().contains_key("/auth/me"));
-        assert!(spec["paths"]
-            .as_object()
-            .unwrap()
-            .contains_key("/api/resumes"));
-        assert!(spec["components"]["securitySchemes"]["cookieAuth"].is_object());
+        let error: ApiError = serde_json::from_slice(&body).unwrap();
+        assert!(error.error.contains("no name column"));
+        assert_eq!(error.code, Some(ErrorCode::ParseInvalidFormat));
     }
 
     #[tokio::test]
-    async fn test_parse_rustume_format() {
+    async fn test_request_id_assigned_and_echoed() {
         let app = create_router();
-        let resume = ResumeData::default();
-
-        let request = ParseRequest {
-            format: ParseFormat::Rustume,
-            data: serde_json::to_string(&resume).unwrap(),
-            base64: false,
-        };
 
         let response = app
+            .clone()
             .oneshot(
                 Request::builder()
-                    .method("POST")
-                    .uri("/api/parse")
-                    .header("content-type", "application/json")
-                    .body(Body::from(serde_json::to_string(&request).unwrap()))
+                    .uri("/health")
+                    .body(Body::empty())
                     .unwrap(),
             )
             .await
             .unwrap();
+        let generated = response.headers()["x-request-id"].to_str().unwrap();
+        assert!(uuid::Uuid::parse_str(generated).is_ok());
 
-        assert_eq!(response.status(), StatusCode::OK);
-
-        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/health")
+                    .header("x-request-id", "edge-1234")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
             .await
             .unwrap();
-        let parsed: ResumeData = serde_json::from_slice(&body).unwrap();
-
-        assert_eq!(parsed.basics.name, resume.basics.name);
+        assert_eq!(response.headers()["x-request-id"], "edge-1234");
     }
 
     #[tokio::test]
-    async fn test_parse_invalid_json() {
+    async fn test_error_response_includes_request_id() {
         let app = create_router();
 
-        let request = ParseRequest {
-            format: ParseFormat::JsonResume,
-            data: "{ invalid json }".to_string(),
-            base64: false,
-        };
-
         let response = app
             .oneshot(
                 Request::builder()
                     .method("POST")
-                    .uri("/api/parse")
+                    .uri("/api/render/pdf")
                     .header("content-type", "application/json")
-                    .body(Body::from(serde_json::to_string(&request).unwrap()))
+                    .header("x-request-id", "render-42")
+                    .body(Body::from(r#"{"resume":{"basics":"not-an-object"}}"#))
                     .unwrap(),
             )
             .await
             .unwrap();
 
         assert_eq!(response.status(), StatusCode::BAD_REQUEST);
-
         let body = axum::body::to_bytes(response.into_body(), usize::MAX)
             .await
             .unwrap();
         let error: ApiError = serde_json::from_slice(&body).unwrap();
-
-        assert!(error.error.contains("Failed to parse"));
+        assert_eq!(error.request_id.as_deref(), Some("render-42"));
     }
 
     #[tokio::test]
@@ -602,6 +2119,112 @@ mod tests {
         assert!(templates.iter().any(|t| t.id == "onyx"));
     }
 
+    #[tokio::test]
+    async fn test_template_policy_limits_list_thumbnails_and_renders() {
+        let policy = rustume_render::TemplatePolicy::parse(Some("onyx,kakuna"), None).unwrap();
+        let state = state::AppState::with_require_auth(
+            std::sync::Arc::new(routes::static_dir()),
+            None,
+            false,
+        )
+        .with_template_policy(policy);
+        let app = create_router_with_state(state);
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .uri("/api/templates")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let templates: Vec<TemplateInfo> = serde_json::from_slice(&body).unwrap();
+        let ids: Vec<&str> = templates.iter().map(|t| t.id.as_str()).collect();
+        assert_eq!(ids, ["kakuna", "onyx"]);
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .uri("/api/templates/rhyhorn/thumbnail")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        let request = RenderPdfRequest {
+            template: Some("rhyhorn".to_string()),
+            ..sample_render_pdf_request()
+        };
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/render/pdf")
+                    .header("content-type", "application/json")
+                    .body(Body::from(serde_json::to_string(&request).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let error: ApiError = serde_json::from_slice(&body).unwrap();
+        assert_eq!(error.code, Some(ErrorCode::RenderTemplateNotAllowed));
+        assert!(error.error.contains("rhyhorn"));
+    }
+
+    #[tokio::test]
+    async fn test_templates_revalidate_with_etag() {
+        let request = |headers: &[(header::HeaderName, &str)]| {
+            let mut builder = Request::builder().uri("/api/templates");
+            for (name, value) in headers {
+                builder = builder.header(name, *value);
+            }
+            builder.body(Body::empty()).unwrap()
+        };
+
+        let response = create_router()
+            .oneshot(request(&[(header::ACCEPT_ENCODING, "gzip, br")]))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[header::CONTENT_ENCODING], "br");
+        let etag = response.headers()[header::ETAG]
+            .to_str()
+            .unwrap()
+            .to_string();
+
+        let response = create_router()
+            .oneshot(request(&[
+                (header::ACCEPT_ENCODING, "gzip, br"),
+                (header::IF_NONE_MATCH, etag.as_str()),
+            ]))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        assert!(body.is_empty());
+
+        let response = create_router()
+            .oneshot(request(&[(header::IF_NONE_MATCH, etag.as_str())]))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(response.headers().get(header::CONTENT_ENCODING).is_none());
+    }
+
     #[tokio::test]
     async fn test_health_returns_json_compatible() {
         let app = create_router();
@@ -657,6 +2280,13 @@ mod tests {
         assert_eq!(missing.status(), StatusCode::NOT_FOUND);
     }
 
+    #[tokio::test]
+    async fn test_warm_thumbnail_cache_renders_every_template() {
+        let renderer = std::sync::Arc::new(rustume_render::TypstRenderer::new());
+        let cached = crate::routes::warm_thumbnail_cache(renderer, None, 3).await;
+        assert_eq!(cached, rustume_render::TEMPLATES.len());
+    }
+
     #[tokio::test]
     async fn test_security_txt_endpoint() {
         let app = create_router();
@@ -758,6 +2388,7 @@ mod tests {
                 "re_test_key".to_string(),
                 "noreply@rustume.com".to_string(),
             )),
+            trash_retention_days: 30,
         })
     }
 
@@ -765,6 +2396,10 @@ mod tests {
         RenderPdfRequest {
             resume: serde_json::to_value(ResumeData::default()).unwrap(),
             template: None,
+            exclude_sections: Vec::new(),
+            exclude_item_ids: Vec::new(),
+            engine: None,
+            resume_id: None,
         }
     }
 
@@ -864,6 +2499,28 @@ mod tests {
         assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
     }
 
+    #[tokio::test]
+    async fn test_applications_anonymous_401_when_require_auth_enabled() {
+        let state = state::AppState::with_require_auth(
+            std::sync::Arc::new(routes::static_dir()),
+            Some(test_cloud_state()),
+            true,
+        );
+        let app = create_router_with_state(state);
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/api/applications")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
     #[tokio::test]
     async fn test_auth_me_includes_require_auth_when_signed_out() {
         let state = state::AppState::with_require_auth(
@@ -1042,4 +2699,171 @@ mod tests {
             assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
         }
     }
+
+    #[cfg(feature = "ai")]
+    mod ai {
+        use super::*;
+        use crate::ai::{AiError, AiState, CompletionProvider, CompletionRequest};
+        use crate::audit::api::{ApiAudit, ApiKeys, API_KEY_HEADER};
+        use async_trait::async_trait;
+        use rustume_storage::{ApiCallQuery, MemoryStorage};
+        use std::sync::Arc;
+
+        /// Echoes the text portion of the prompt, or fails when `fail` is set.
+        struct StubProvider {
+            fail: bool,
+        }
+
+        #[async_trait]
+        impl CompletionProvider for StubProvider {
+            fn name(&self) -> &'static str {
+                "stub"
+            }
+
+            fn model(&self) -> &str {
+                "stub-1"
+            }
+
+            async fn complete(&self, request: &CompletionRequest) -> Result<String, AiError> {
+                if self.fail {
+                    return Err(AiError::Api {
+                        status: 500,
+                        body: "boom".into(),
+                    });
+                }
+                let text = request.prompt.rsplit("Text:\n").next().unwrap_or_default();
+                Ok(format!("  {}\n", text.to_uppercase()))
+            }
+        }
+
+        fn ai_state(fail: bool) -> state::AppState {
+            let ai = AiState::with_provider(Arc::new(StubProvider { fail }), 10);
+            state::AppState::with_require_auth(Arc::new(routes::static_dir()), None, false)
+                .with_ai(ai)
+        }
+
+        /// App with AI enabled and `team-key` registered as API key `team`.
+        fn ai_app(fail: bool) -> (axum::Router, Arc<ApiAudit>) {
+            let audit = Arc::new(ApiAudit::new(
+                ApiKeys::parse("team:team-key").unwrap(),
+                Arc::new(MemoryStorage::new()),
+            ));
+            let state = ai_state(fail).with_api_audit(audit.clone());
+            (create_router_with_state(state), audit)
+        }
+
+        fn rewrite_request(text: &str, key: Option<&str>) -> Request<Body> {
+            let mut builder = Request::builder()
+                .method("POST")
+                .uri("/api/ai/rewrite")
+                .header("content-type", "application/json");
+            if let Some(key) = key {
+                builder = builder.header(API_KEY_HEADER, key);
+            }
+            builder
+                .body(Body::from(
+                    serde_json::json!({ "text": text, "tone": "concise" }).to_string(),
+                ))
+                .unwrap()
+        }
+
+        #[tokio::test]
+        async fn test_rewrite_not_found_without_provider() {
+            let state =
+                state::AppState::with_require_auth(Arc::new(routes::static_dir()), None, false);
+            let response = create_router_with_state(state)
+                .oneshot(rewrite_request("Helped ship v2", None))
+                .await
+                .unwrap();
+
+            assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        }
+
+        #[tokio::test]
+        async fn test_rewrite_flattens_html_and_returns_provider() {
+            let (app, _) = ai_app(false);
+            let response = app
+                .oneshot(rewrite_request(
+                    "<ul><li>Helped ship v2</li><li>Fixed bugs</li></ul>",
+                    Some("team-key"),
+                ))
+                .await
+                .unwrap();
+
+            assert_eq!(response.status(), StatusCode::OK);
+            let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            let result: dto::RewriteResponse = serde_json::from_slice(&body).unwrap();
+            assert_eq!(result.text, "HELPED SHIP V2\nFIXED BUGS");
+            assert_eq!(result.provider, "stub");
+            assert_eq!(result.model, "stub-1");
+        }
+
+        #[tokio::test]
+        async fn test_rewrite_requires_api_key_and_is_audited() {
+            let (app, audit) = ai_app(false);
+
+            for key in [None, Some("wrong")] {
+                let response = app
+                    .clone()
+                    .oneshot(rewrite_request("Helped ship v2", key))
+                    .await
+                    .unwrap();
+                assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+            }
+
+            let response = app
+                .oneshot(rewrite_request("Helped ship v2", Some("team-key")))
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+
+            let calls = audit.log.api_calls(&ApiCallQuery::default()).await.unwrap();
+            assert_eq!(calls.len(), 1);
+            assert_eq!(calls[0].api_key, "team");
+            assert_eq!(calls[0].endpoint, "POST /api/ai/rewrite");
+            assert_eq!(calls[0].status, 200);
+        }
+
+        #[tokio::test]
+        async fn test_rewrite_forbidden_without_api_keys() {
+            let response = create_router_with_state(ai_state(false))
+                .oneshot(rewrite_request("Helped ship v2", Some("team-key")))
+                .await
+                .unwrap();
+
+            assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        }
+
+        #[tokio::test]
+        async fn test_rewrite_rejects_empty_and_oversized_text() {
+            let (app, _) = ai_app(false);
+
+            let response = app
+                .clone()
+                .oneshot(rewrite_request("<p> </p>", Some("team-key")))
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+            let long = "a".repeat(crate::ai::MAX_REWRITE_TEXT_LEN + 1);
+            let response = app
+                .oneshot(rewrite_request(&long, Some("team-key")))
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+        }
+
+        #[tokio::test]
+        async fn test_rewrite_provider_failure_is_bad_gateway() {
+            let (app, _) = ai_app(true);
+            let response = app
+                .oneshot(rewrite_request("Helped ship v2", Some("team-key")))
+                .await
+                .unwrap();
+
+            assert_eq!(response.status(), StatusCode::BAD_GATEWAY);
+        }
+    }
 }
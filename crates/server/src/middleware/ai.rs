@@ -0,0 +1,33 @@
+//! API key and rate limit checks for AI routes.
+//!
+//! Keys come from the API audit registry, so every admitted AI call is also
+//! recorded by [`crate::middleware::api_audit`].
+
+use axum::{
+    extract::{Request, State},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+
+use crate::state::AppState;
+
+/// Reject AI requests without a valid API key, or over the AI rate limit.
+///
+/// Returns 404 when no AI provider is configured, and 403 when no API keys are.
+pub async fn require_ai_access(
+    State(state): State<AppState>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let ai = match state.ai() {
+        Ok(ai) => ai,
+        Err(err) => return err.into_response(),
+    };
+
+    let keys = state.api_audit.as_ref().map(|audit| &audit.keys);
+
+    match ai.authorize(keys, request.headers()) {
+        Ok(()) => next.run(request).await,
+        Err(err) => err.into_response(),
+    }
+}
@@ -0,0 +1,151 @@
+//! Audit log entries for calls made with an API key.
+
+use std::time::Instant;
+
+use axum::{
+    body::Body,
+    extract::{MatchedPath, Request, State},
+    http::{header::CONTENT_TYPE, HeaderMap},
+    middleware::Next,
+    response::Response,
+};
+use rustume_schema::{canonicalize, ResumeData};
+use rustume_storage::ApiCall;
+use sha2::{Digest, Sha256};
+use tracing::error;
+
+use crate::audit::api::API_KEY_HEADER;
+use crate::config::MAX_BODY_SIZE;
+use crate::error::ApiError;
+use crate::state::AppState;
+
+/// Routes whose JSON body carries a resume, by matched path prefix.
+const RESUME_ROUTES: &[&str] = &[
+    "/api/render",
+    "/api/resumes",
+    "/api/validate",
+    "/api/suggest",
+    "/api/analyze",
+    "/api/stats",
+    "/api/fit",
+    "/api/export/section",
+    "/api/presets/{name}/apply",
+    "/api/ai/rewrite",
+];
+
+/// Record calls that present an API key; reject unknown keys.
+///
+/// Requests without a key pass through unrecorded. Only JSON bodies sent to
+/// [`RESUME_ROUTES`] are read to hash their resume; every other body streams
+/// to its handler untouched and the call is recorded without a hash. A
+/// failure to write the log is logged and does not fail the call.
+pub async fn audit_api_calls(
+    State(state): State<AppState>,
+    request: Request,
+    next: Next,
+) -> Result<Response, ApiError> {
+    let Some(audit) = state.api_audit.clone() else {
+        return Ok(next.run(request).await);
+    };
+    let Some(presented) = request.headers().get(API_KEY_HEADER) else {
+        return Ok(next.run(request).await);
+    };
+    let api_key = presented
+        .to_str()
+        .ok()
+        .and_then(|key| audit.keys.identify(key))
+        .ok_or_else(|| ApiError::unauthorized("Invalid X-Rustume-Api-Key header"))?
+        .to_string();
+
+    let started = Instant::now();
+    let path = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map_or_else(|| request.uri().path(), MatchedPath::as_str);
+    let endpoint = format!("{} {path}", request.method());
+    let (request, resume_hash) = if carries_resume(path, request.headers()) {
+        let (parts, body) = request.into_parts();
+        let body = axum::body::to_bytes(body, MAX_BODY_SIZE)
+            .await
+            .map_err(|_| ApiError::payload_too_large("Request body is too large"))?;
+        let hash = resume_hash(&body);
+        (Request::from_parts(parts, Body::from(body)), hash)
+    } else {
+        (request, None)
+    };
+
+    let response = next.run(request).await;
+
+    let call = ApiCall::new(
+        &api_key,
+        &endpoint,
+        resume_hash,
+        response.status().as_u16(),
+        started.elapsed(),
+    );
+    if let Err(err) = audit.log.record_api_call(&call).await {
+        error!("API audit log insert failed: {err}");
+    }
+    Ok(response)
+}
+
+/// Whether a request to the route at `path` has a JSON body that may carry
+/// a resume.
+fn carries_resume(path: &str, headers: &HeaderMap) -> bool {
+    let json = headers
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.split(';').next())
+        .is_some_and(|mime| mime.trim().eq_ignore_ascii_case("application/json"));
+    json && RESUME_ROUTES.iter().any(|route| path.starts_with(route))
+}
+
+/// SHA-256 of the canonical JSON of the resume in a request body: its
+/// `resume` field, or the body itself when it is a resume.
+fn resume_hash(body: &[u8]) -> Option<String> {
+    let mut value: serde_json::Value = serde_json::from_slice(body).ok()?;
+    let resume = match value.get_mut("resume") {
+        Some(resume) => resume.take(),
+        None if value.get("basics").is_some() => value,
+        None => return None,
+    };
+    let resume: ResumeData = serde_json::from_value(resume).ok()?;
+    let digest = Sha256::digest(canonicalize(&resume).as_bytes());
+    Some(digest.iter().map(|b| format!("{b:02x}")).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resume_hash_ignores_key_order_and_other_fields() {
+        let resume =
+            serde_json::to_value(ResumeData::with_basics("Ada", "ada@example.com")).unwrap();
+        let wrapped = serde_json::json!({ "resume": resume, "template": "onyx" });
+        let hash = resume_hash(wrapped.to_string().as_bytes()).unwrap();
+        assert_eq!(hash.len(), 64);
+        assert_eq!(resume_hash(resume.to_string().as_bytes()), Some(hash));
+
+        assert_eq!(resume_hash(br#"{"format":"json-resume"}"#), None);
+        assert_eq!(resume_hash(b"PK\x03\x04"), None);
+    }
+
+    #[test]
+    fn test_only_json_bodies_on_resume_routes_are_hashed() {
+        let mut json = HeaderMap::new();
+        json.insert(
+            CONTENT_TYPE,
+            "application/json; charset=utf-8".parse().unwrap(),
+        );
+        let mut zip = HeaderMap::new();
+        zip.insert(CONTENT_TYPE, "application/zip".parse().unwrap());
+
+        assert!(carries_resume("/api/render/pdf", &json));
+        assert!(carries_resume("/api/resumes/{id}", &json));
+        assert!(!carries_resume("/api/render/pdf", &zip));
+        assert!(!carries_resume("/api/render/pdf", &HeaderMap::new()));
+        assert!(!carries_resume("/api/parse/linkedin", &json));
+        assert!(!carries_resume("/api/parse/linkedin", &zip));
+    }
+}
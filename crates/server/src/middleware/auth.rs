@@ -1,7 +1,7 @@
 //! Session cookie authentication extractor for cloud routes.
 
 use axum::{
-    extract::{FromRequestParts, Request, State},
+    extract::{FromRequestParts, OptionalFromRequestParts, Request, State},
     http::request::Parts,
     middleware::Next,
     response::Response,
@@ -11,7 +11,7 @@ use tracing::error;
 
 use crate::auth::session::SESSION_COOKIE;
 use crate::db::User;
-use crate::error::ApiError;
+use crate::error::{ApiError, ApiErrorKind};
 use crate::state::AppState;
 
 /// Authenticated user extracted from a valid `rustume_session` cookie.
@@ -48,6 +48,26 @@ impl FromRequestParts<AppState> for AuthUser {
     }
 }
 
+/// `Option<AuthUser>` is `None` for anonymous requests and outside cloud
+/// mode, for routes that work either way.
+impl OptionalFromRequestParts<AppState> for AuthUser {
+    type Rejection = ApiError;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Option<Self>, Self::Rejection> {
+        if state.cloud.is_none() {
+            return Ok(None);
+        }
+        match <Self as FromRequestParts<AppState>>::from_request_parts(parts, state).await {
+            Ok(user) => Ok(Some(user)),
+            Err(err) if matches!(err.kind, ApiErrorKind::Unauthorized) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+}
+
 fn unauthorized(message: &str) -> ApiError {
     ApiError::unauthorized(message)
 }
@@ -1,6 +1,10 @@
 //! Axum middleware for Rustume Cloud.
 
+#[cfg(feature = "ai")]
+pub mod ai;
+pub mod api_audit;
 pub mod auth;
 pub mod rate_limit;
+pub mod request_id;
 pub mod security;
 pub mod subscription;
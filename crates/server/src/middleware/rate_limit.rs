@@ -17,6 +17,7 @@ use std::net::SocketAddr;
 use std::sync::Arc;
 use std::time::{Duration, UNIX_EPOCH};
 use tracing::warn;
+use utoipa::ToSchema;
 
 use crate::auth::session::SESSION_COOKIE;
 use crate::config::RateLimitConfig;
@@ -153,9 +154,13 @@ impl RateLimitState {
 }
 
 /// JSON body returned when a client exceeds a rate limit.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct RateLimitErrorBody {
+    /// Error message
+    #[schema(example = "Too many requests. Please try again shortly.")]
     pub error: String,
+    /// Seconds to wait before retrying, also sent as `Retry-After`
+    #[schema(example = 30)]
     pub retry_after: u64,
 }
 
@@ -166,7 +171,7 @@ pub struct RateLimitExceeded {
 }
 
 impl RateLimitExceeded {
-    fn new(retry_after: Duration) -> Self {
+    pub(crate) fn new(retry_after: Duration) -> Self {
         Self { retry_after }
     }
 
@@ -210,7 +215,7 @@ fn set_rate_limit_headers(headers: &mut HeaderMap, retry_after: u64, reset_times
     }
 }
 
-fn ip_rate_limit_key(
+pub(crate) fn ip_rate_limit_key(
     headers: &HeaderMap,
     remote_addr: Option<SocketAddr>,
     trusted_proxy: bool,
@@ -0,0 +1,108 @@
+//! Per-request IDs for log correlation.
+//!
+//! Every request gets an ID: the caller's `X-Request-ID` when it is a sane
+//! token, otherwise a fresh UUID. The ID is recorded on a `request` span that
+//! wraps the rest of the stack, echoed in the response header, and copied into
+//! any [`ApiError`](crate::error::ApiError) built while the request runs.
+
+use axum::extract::Request;
+use axum::http::header::{HeaderName, HeaderValue};
+use axum::middleware::Next;
+use axum::response::Response;
+use tokio::task::JoinHandle;
+use tracing::{Instrument, Span};
+
+/// Header carrying the request ID in both directions.
+pub const REQUEST_ID_HEADER: HeaderName = HeaderName::from_static("x-request-id");
+
+/// Longest caller-supplied ID that is reused rather than replaced.
+const MAX_REQUEST_ID_LEN: usize = 128;
+
+tokio::task_local! {
+    static REQUEST_ID: String;
+}
+
+/// The request ID of the request being handled on this task, if any.
+pub fn current_request_id() -> Option<String> {
+    REQUEST_ID.try_with(Clone::clone).ok()
+}
+
+/// Like [`tokio::task::spawn_blocking`], but the closure runs inside the
+/// caller's span and sees its request ID, so renders log against the request.
+pub fn spawn_blocking_in_request<F, R>(f: F) -> JoinHandle<R>
+where
+    F: FnOnce() -> R + Send + 'static,
+    R: Send + 'static,
+{
+    let span = Span::current();
+    let request_id = current_request_id();
+    tokio::task::spawn_blocking(move || {
+        let _entered = span.enter();
+        match request_id {
+            Some(id) => REQUEST_ID.sync_scope(id, f),
+            None => f(),
+        }
+    })
+}
+
+/// Reuse the caller's ID only if it is short and header-safe; anything else
+/// could be used to inject misleading text into logs.
+fn is_valid_request_id(id: &str) -> bool {
+    !id.is_empty()
+        && id.len() <= MAX_REQUEST_ID_LEN
+        && id
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'-' | b'_' | b'.' | b':'))
+}
+
+/// Middleware that assigns a request ID and runs the request inside its span.
+pub async fn request_id(req: Request, next: Next) -> Response {
+    let id = req
+        .headers()
+        .get(&REQUEST_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .filter(|id| is_valid_request_id(id))
+        .map_or_else(|| uuid::Uuid::new_v4().to_string(), str::to_string);
+
+    let span = tracing::info_span!(
+        "request",
+        request_id = %id,
+        method = %req.method(),
+        path = %req.uri().path(),
+    );
+    let mut response = REQUEST_ID
+        .scope(id.clone(), next.run(req))
+        .instrument(span)
+        .await;
+
+    if let Ok(value) = HeaderValue::from_str(&id) {
+        response.headers_mut().insert(REQUEST_ID_HEADER, value);
+    }
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_request_id_validation() {
+        assert!(is_valid_request_id("3f2a9c1e-web-42"));
+        assert!(is_valid_request_id("trace:abc.def_1"));
+        assert!(!is_valid_request_id(""));
+        assert!(!is_valid_request_id("has space"));
+        assert!(!is_valid_request_id("line\nbreak"));
+        assert!(!is_valid_request_id(&"a".repeat(MAX_REQUEST_ID_LEN + 1)));
+    }
+
+    #[tokio::test]
+    async fn test_blocking_tasks_inherit_request_id() {
+        assert_eq!(current_request_id(), None);
+        let id = REQUEST_ID
+            .scope("req-1".to_string(), async {
+                spawn_blocking_in_request(current_request_id).await.unwrap()
+            })
+            .await;
+        assert_eq!(id.as_deref(), Some("req-1"));
+    }
+}
@@ -1,4 +1,6 @@
-//! Network helpers for client IP extraction.
+//! Network helpers: client IP extraction and checks for outbound requests.
+
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
 
 use axum::http::HeaderMap;
 
@@ -37,6 +39,86 @@ pub fn trusted_client_ip(headers: &HeaderMap, trusted_proxy: bool) -> Option<Str
         .map(str::to_string)
 }
 
+/// Whether a URL host names this machine or a private network, by IP literal
+/// or well-known internal name. Outbound requests to user-supplied URLs must
+/// not reach these.
+pub fn is_internal_host(host: &str) -> bool {
+    match host.trim_matches(['[', ']']).parse::<IpAddr>() {
+        Ok(ip) => is_internal_ip(ip),
+        Err(_) => {
+            let domain = host.trim_end_matches('.').to_ascii_lowercase();
+            domain.is_empty()
+                || domain == "localhost"
+                || [".localhost", ".local", ".internal"]
+                    .iter()
+                    .any(|suffix| domain.ends_with(suffix))
+        }
+    }
+}
+
+/// Whether an address is loopback, private, link-local, or otherwise not a
+/// public host.
+pub fn is_internal_ip(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => is_internal_ipv4(ip),
+        IpAddr::V6(ip) => {
+            if let Some(mapped) = ip.to_ipv4_mapped() {
+                return is_internal_ipv4(mapped);
+            }
+            let first = ip.segments()[0];
+            ip.is_loopback()
+                || ip.is_unspecified()
+                || ip.is_multicast()
+                // Unique local (fc00::/7) and link-local (fe80::/10)
+                || (first & 0xfe00) == 0xfc00
+                || (first & 0xffc0) == 0xfe80
+        }
+    }
+}
+
+/// Why [`resolve_public`] refused a host.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResolveError {
+    /// The name didn't resolve to any address.
+    Unresolved,
+    /// At least one address is internal.
+    Internal,
+}
+
+/// Resolve `domain` for an outbound request, requiring every address to be
+/// public. Connect only to the returned addresses (with
+/// `reqwest::ClientBuilder::resolve_to_addrs`), so a second DNS answer can't
+/// steer the request into a private network.
+pub async fn resolve_public(domain: &str, port: u16) -> Result<Vec<SocketAddr>, ResolveError> {
+    let addrs: Vec<SocketAddr> = tokio::net::lookup_host((domain, port))
+        .await
+        .map_err(|_| ResolveError::Unresolved)?
+        .collect();
+    if addrs.is_empty() {
+        return Err(ResolveError::Unresolved);
+    }
+    if !all_public(addrs.iter().map(SocketAddr::ip)) {
+        return Err(ResolveError::Internal);
+    }
+    Ok(addrs)
+}
+
+fn all_public(mut ips: impl Iterator<Item = IpAddr>) -> bool {
+    ips.all(|ip| !is_internal_ip(ip))
+}
+
+fn is_internal_ipv4(ip: Ipv4Addr) -> bool {
+    let [a, b, ..] = ip.octets();
+    ip.is_loopback()
+        || ip.is_private()
+        || ip.is_link_local()
+        || ip.is_unspecified()
+        || ip.is_broadcast()
+        || ip.is_multicast()
+        // Carrier-grade NAT (100.64.0.0/10)
+        || (a == 100 && (b & 0xc0) == 64)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -70,4 +152,43 @@ mod tests {
             Some("198.51.100.2")
         );
     }
+
+    #[test]
+    fn internal_hosts_are_recognized() {
+        for host in [
+            "localhost",
+            "api.localhost",
+            "printer.local",
+            "db.internal",
+            "127.0.0.1",
+            "10.0.0.5",
+            "169.254.169.254",
+            "100.64.0.1",
+            "[::1]",
+            "[fd00::1]",
+            "[::ffff:192.168.1.1]",
+            "",
+        ] {
+            assert!(is_internal_host(host), "{host} should be internal");
+        }
+        assert!(!is_internal_host("example.com"));
+        assert!(!is_internal_host("203.0.113.10"));
+    }
+
+    #[test]
+    fn resolved_addresses_must_all_be_public() {
+        let public: IpAddr = "203.0.113.10".parse().unwrap();
+        let private: IpAddr = "192.168.1.1".parse().unwrap();
+        assert!(all_public([public].into_iter()));
+        assert!(!all_public([public, private].into_iter()));
+    }
+
+    #[tokio::test]
+    async fn names_resolving_to_loopback_are_internal() {
+        // Only the resolved addresses are checked, not the name
+        assert_eq!(
+            resolve_public("localhost", 443).await,
+            Err(ResolveError::Internal)
+        );
+    }
 }
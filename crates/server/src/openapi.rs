@@ -1,21 +1,29 @@
 //! OpenAPI specification for the Rustume HTTP API.
 
 use utoipa::openapi::security::{ApiKey, ApiKeyValue, SecurityScheme};
+use utoipa::openapi::{Content, Ref, RefOr, Response};
 use utoipa::Modify;
 use utoipa::OpenApi;
 
 use crate::db::{
-    AuthMeUnauthorizedResponse, AuthUserResponse, CreateResumeRequest, DeleteAccountRequest,
-    DeleteAccountResponse, ImportFailure, ImportResumeItem, ImportResumesRequest,
-    ImportResumesResponse, PaginatedResumeSummaries, ResumeBulkExport, ResumeExportItem,
+    ApplicationRow, AuthMeUnauthorizedResponse, AuthUserResponse, CreateResumeRequest,
+    CreateWebhookRequest, CreatedWebhook, DeleteAccountRequest, DeleteAccountResponse,
+    ImportFailure, ImportResumeItem, ImportResumesRequest, ImportResumesResponse,
+    PaginatedResumeSummaries, ResumeBulkExport, ResumeExportItem, ResumeHistoryRow,
     ResumeListQuery, ResumeRow, ResumeSummary, SharingResponse, SubscriptionInfo,
-    UpdateResumeRequest, UpdateSharingRequest,
+    TrashedResumeSummary, UpdateResumeRequest, UpdateSharingRequest, UpdateWebhookRequest,
+    WebhookDeliveryRow, WebhookRow,
 };
 use crate::dto::{
-    ParseFormat, ParseRequest, RenderPdfRequest, RenderPreviewRequest, TemplateInfo, ThemeInfo,
-    ValidationResponse,
+    AnalyzeRequest, AnalyzeResponse, ApiCallRecord, ContentPresetInfo, CsvImportRequest,
+    CsvImportResponse, CsvImportSection, DedupeMerge, DedupeMergeRequest, DedupeResponse,
+    DiffReportRequest, FitRequest, ImportResponse, MergeImportRequest, ParseFormat, ParseRequest,
+    PdfEngine, RenderPdfRequest, RenderPreviewRequest, RenderStreamComplete, SectionExportFormat,
+    SectionExportRequest, SuggestResponse, TemplateId, TemplateInfo, TemplateOptionsInfo,
+    ThemeInfo, ValidationResponse,
 };
 use crate::error::ApiError;
+use crate::middleware::rate_limit::RateLimitErrorBody;
 
 struct CookieAuthAddon;
 
@@ -30,12 +38,96 @@ impl Modify for CookieAuthAddon {
     }
 }
 
+/// Error responses every operation shares, so generated clients get typed
+/// errors without each route listing them: a `500` and a `429` (rate limits
+/// apply in cloud mode and to AI rewrites) on every operation, a body for
+/// `429`s, and an example on each [`ApiError`] response.
+struct ErrorResponsesAddon;
+
+impl Modify for ErrorResponsesAddon {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let api_error = Ref::from_schema_name("ApiError");
+        let rate_limited = Ref::from_schema_name("RateLimitErrorBody");
+
+        for item in openapi.paths.paths.values_mut() {
+            let operations = [
+                &mut item.get,
+                &mut item.put,
+                &mut item.post,
+                &mut item.delete,
+                &mut item.patch,
+            ];
+            for operation in operations.into_iter().flatten() {
+                let responses = &mut operation.responses.responses;
+                responses
+                    .entry("429".to_string())
+                    .or_insert_with(|| Response::new("Rate limit exceeded").into());
+                responses.entry("500".to_string()).or_insert_with(|| {
+                    let mut response = Response::new("Internal server error");
+                    response.content.insert(
+                        "application/json".to_string(),
+                        Content::new(Some(api_error.clone())),
+                    );
+                    response.into()
+                });
+
+                for (status, response) in responses.iter_mut() {
+                    let RefOr::T(response) = response else {
+                        continue;
+                    };
+                    if status == "429" && response.content.is_empty() {
+                        let mut content = Content::new(Some(rate_limited.clone()));
+                        content.example = serde_json::to_value(RateLimitErrorBody {
+                            error: "Too many requests. Please try again shortly.".to_string(),
+                            retry_after: 30,
+                        })
+                        .ok();
+                        response
+                            .content
+                            .insert("application/json".to_string(), content);
+                        continue;
+                    }
+                    let example = error_example(status, &response.description);
+                    for content in response.content.values_mut() {
+                        let is_api_error = matches!(
+                            &content.schema,
+                            Some(RefOr::Ref(schema)) if *schema == api_error
+                        );
+                        if is_api_error && content.example.is_none() {
+                            content.example = example.clone();
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// The body the server sends for an error `status`, with `message` as the
+/// error text; `None` for statuses [`ApiError`] has no constructor for.
+fn error_example(status: &str, message: &str) -> Option<serde_json::Value> {
+    let error = match status {
+        "400" => ApiError::new(message),
+        "401" => ApiError::unauthorized(message),
+        "403" => ApiError::forbidden(message),
+        "404" => ApiError::not_found(message),
+        "409" => ApiError::conflict(message),
+        "413" => ApiError::payload_too_large(message),
+        "415" => ApiError::unsupported_media_type(message),
+        "422" => ApiError::unprocessable(message),
+        "500" => ApiError::internal(message),
+        "502" => ApiError::bad_gateway(message),
+        _ => return None,
+    };
+    serde_json::to_value(error).ok()
+}
+
 #[derive(OpenApi)]
 #[openapi(
     info(
         title = "Rustume API",
         version = env!("CARGO_PKG_VERSION"),
-        description = "REST API for resume parsing, rendering, validation, and Rustume Cloud storage.\n\n## Features\n\n- **Parse**: Import resumes from JSON Resume, LinkedIn exports, or Reactive Resume v3\n- **Render**: Generate PDF or PNG previews of resumes\n- **Validate**: Check resume data against the schema\n- **Templates**: List available resume templates with theme colors\n- **Cloud** (when enabled): WorkOS auth and authenticated resume CRUD",
+        description = "REST API for resume parsing, rendering, validation, and Rustume Cloud storage.\n\n## Features\n\n- **Parse**: Import resumes from JSON Resume, LinkedIn exports, or Reactive Resume v3\n- **Render**: Generate PDFs or PNG and SVG page previews of resumes, or an SVG career timeline, and fit a resume to a page budget\n- **Validate**: Check resume data against the schema\n- **Suggest**: Rule-based hints for experience bullet points\n- **Dedupe**: Find and merge duplicate items from multi-source imports\n- **Merge**: Add a fresh import to an existing resume without losing edits\n- **Stats**: Per-position durations, total years of experience, and employment gaps\n- **Presets**: Pre-filled starting resumes for common professions, and chronological, functional, hybrid, and academic section orderings\n- **Analyze**: Keyword frequency, overused terms, buzzwords, coverage of a target role's keywords, and employment gaps\n- **Templates**: List available resume templates with theme colors\n- **Cloud** (when enabled): WorkOS auth authenticated resume CRUD, and job application tracking",
         license(name = "AGPL-3.0-only", url = "https://www.gnu.org/licenses/agpl-3.0.en.html"),
         contact(name = "Rustume", url = "https://github.com/lgtm-hq/Rustume")
     ),
@@ -48,9 +140,27 @@ impl Modify for CookieAuthAddon {
         crate::routes::templates::list_templates,
         crate::routes::templates::template_thumbnail,
         crate::routes::parse::parse,
+        crate::routes::parse::parse_with_report,
+        crate::routes::parse::parse_csv,
+        crate::routes::parse::parse_linkedin_upload,
+        crate::routes::sections::export_section,
         crate::routes::render::render_pdf,
+        crate::routes::render::render_pdf_stream,
         crate::routes::render::render_preview,
+        crate::routes::render::render_svg,
+        crate::routes::render::fit_pages,
+        crate::routes::render::render_timeline,
         crate::routes::validate::validate,
+        crate::routes::suggest::suggest,
+        crate::routes::dedupe::dedupe,
+        crate::routes::dedupe::dedupe_merge,
+        crate::routes::merge::merge,
+        crate::routes::diff::diff_report,
+        crate::routes::stats::stats,
+        crate::routes::analyze::analyze,
+        crate::routes::presets::list_content_presets,
+        crate::routes::presets::get_content_preset,
+        crate::routes::presets::apply_layout_preset,
         crate::routes::auth::me,
         crate::routes::resumes::list_resumes,
         crate::routes::resumes::get_resume,
@@ -58,21 +168,57 @@ impl Modify for CookieAuthAddon {
         crate::routes::resumes::update_resume,
         crate::routes::resumes::update_sharing,
         crate::routes::resumes::delete_resume,
+        crate::routes::resumes::list_trashed_resumes,
+        crate::routes::resumes::restore_resume,
+        crate::routes::resumes::resume_history,
         crate::routes::resumes::import_resumes,
+        crate::routes::public::public_resume_json,
+        crate::routes::applications::list_applications,
+        crate::routes::applications::get_application,
+        crate::routes::applications::create_application,
+        crate::routes::applications::update_application,
+        crate::routes::applications::delete_application,
+        crate::routes::webhooks::list_webhooks,
+        crate::routes::webhooks::create_webhook,
+        crate::routes::webhooks::update_webhook,
+        crate::routes::webhooks::delete_webhook,
+        crate::routes::webhooks::list_webhook_deliveries,
         crate::routes::export::export_resumes_json,
         crate::routes::export::export_resumes_pdf,
         crate::routes::account::delete_account,
+        crate::routes::admin::list_api_calls,
     ),
     components(
         schemas(
             ApiError,
+            RateLimitErrorBody,
+            TemplateId,
             ParseFormat,
             ParseRequest,
+            ImportResponse,
+            CsvImportSection,
+            CsvImportRequest,
+            CsvImportResponse,
+            SectionExportFormat,
+            SectionExportRequest,
+            PdfEngine,
             RenderPdfRequest,
+            RenderStreamComplete,
             RenderPreviewRequest,
+            FitRequest,
             TemplateInfo,
+            TemplateOptionsInfo,
             ThemeInfo,
             ValidationResponse,
+            SuggestResponse,
+            DedupeResponse,
+            DedupeMerge,
+            DedupeMergeRequest,
+            MergeImportRequest,
+            DiffReportRequest,
+            rustume_schema::DiffReportFormat,
+            AnalyzeRequest,
+            AnalyzeResponse,
             AuthUserResponse,
             AuthMeUnauthorizedResponse,
             SubscriptionInfo,
@@ -82,6 +228,8 @@ impl Modify for CookieAuthAddon {
             PaginatedResumeSummaries,
             ResumeListQuery,
             ResumeRow,
+            ResumeHistoryRow,
+            TrashedResumeSummary,
             CreateResumeRequest,
             UpdateResumeRequest,
             UpdateSharingRequest,
@@ -90,21 +238,91 @@ impl Modify for CookieAuthAddon {
             ImportResumesResponse,
             ImportFailure,
             ImportResumeItem,
+            ApplicationRow,
+            WebhookRow,
+            CreatedWebhook,
+            CreateWebhookRequest,
+            UpdateWebhookRequest,
+            WebhookDeliveryRow,
             DeleteAccountRequest,
             DeleteAccountResponse,
-            rustume_schema::ResumeData
+            ApiCallRecord,
+            rustume_schema::ResumeData,
+            rustume_schema::ContentSuggestion,
+            rustume_schema::DuplicateCandidate,
+            rustume_schema::MergeStrategy,
+            rustume_schema::MergeEntry,
+            rustume_schema::MergeReport,
+            rustume_schema::MergedImport,
+            rustume_schema::FitStepKind,
+            rustume_schema::FitStep,
+            rustume_schema::FitReport,
+            rustume_schema::FittedResume,
+            rustume_schema::ResumeStats,
+            rustume_schema::PositionDuration,
+            rustume_schema::GapOptions,
+            rustume_schema::EmploymentGap,
+            rustume_schema::KeywordOptions,
+            rustume_schema::KeywordCount,
+            rustume_schema::TargetCoverage,
+            rustume_schema::SectionKeywords,
+            rustume_schema::KeywordReport,
+            rustume_schema::LayoutPreset,
+            rustume_schema::ContentPreset,
+            ContentPresetInfo,
+            rustume_schema::JobApplication,
+            rustume_schema::ApplicationStatus,
+            rustume_schema::ErrorCode
         )
     ),
     tags(
         (name = "Health", description = "Health check endpoints"),
         (name = "Templates", description = "Template management"),
         (name = "Parse", description = "Resume parsing from various formats"),
-        (name = "Render", description = "Resume rendering to PDF/PNG"),
+        (name = "Export", description = "Section data as CSV or XLSX spreadsheets"),
+        (name = "Render", description = "Resume rendering to PDF/PNG and SVG timelines, and fitting to a page budget"),
         (name = "Validate", description = "Resume validation"),
+        (name = "Suggest", description = "Rule-based writing suggestions"),
+        (name = "Dedupe", description = "Duplicate item detection and merging"),
+        (name = "Merge", description = "Merging a new import into an existing resume"),
+        (name = "Diff", description = "Change reports between two versions of a resume"),
+        (name = "Stats", description = "Experience durations, totals, and gaps"),
+        (name = "Analyze", description = "Keyword density, wording, and employment gap analysis"),
+        (name = "Presets", description = "Starting resumes for common professions and curated section orderings"),
         (name = "Auth", description = "Rustume Cloud authentication (cloud mode only)"),
         (name = "Resumes", description = "Authenticated resume storage (cloud mode only)"),
-        (name = "Account", description = "Account lifecycle (cloud mode only)")
+        (name = "Public", description = "Shared resumes, no authentication (cloud mode only)"),
+        (name = "Applications", description = "Job application tracker (cloud mode only)"),
+        (name = "Webhooks", description = "Resume event webhooks (cloud mode only)"),
+        (name = "Account", description = "Account lifecycle (cloud mode only)"),
+        (name = "Admin", description = "Deployment administration (admin token only)")
     )
 )]
 /// Generated OpenAPI document served at `/api-docs/openapi.json`.
 pub struct ApiDoc;
+
+/// AI rewrite routes, merged into [`ApiDoc`] when the `ai` feature is enabled.
+#[cfg(feature = "ai")]
+#[derive(OpenApi)]
+#[openapi(
+    paths(crate::routes::ai::rewrite),
+    components(schemas(
+        crate::dto::RewriteRequest,
+        crate::dto::RewriteResponse,
+        crate::ai::RewriteTone,
+        crate::ai::RewriteLength
+    )),
+    tags((name = "AI", description = "AI rewrite suggestions (optional `ai` feature)"))
+)]
+struct AiApiDoc;
+
+/// Build the OpenAPI document, including feature-gated routes.
+pub fn api_doc() -> utoipa::openapi::OpenApi {
+    #[cfg(feature = "ai")]
+    let mut doc = ApiDoc::openapi().merge_from(AiApiDoc::openapi());
+    #[cfg(not(feature = "ai"))]
+    let mut doc = ApiDoc::openapi();
+    // After the merge, so feature-gated routes get the shared errors too
+    ErrorResponsesAddon.modify(&mut doc);
+    doc
+}
@@ -0,0 +1,318 @@
+//! Responses serialized and compressed once, for payloads that only change
+//! between builds: the OpenAPI document and the template list.
+//!
+//! Each payload keeps identity, gzip, and brotli bodies with a strong `ETag`
+//! per encoding. A request gets the smallest encoding its `Accept-Encoding`
+//! allows, and a matching `If-None-Match` gets `304 Not Modified` without a
+//! body, so clients polling these endpoints cost a header comparison.
+
+use std::io::Write;
+
+use axum::{
+    body::Bytes,
+    http::{header, HeaderMap, HeaderValue, StatusCode},
+    response::{IntoResponse, Response},
+};
+use flate2::{write::GzEncoder, Compression};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+/// Clients may reuse a copy but must revalidate it first.
+const CACHE_CONTROL: &str = "public, no-cache";
+
+/// Brotli quality, window size, and buffer size: the slowest, smallest
+/// setting, which only runs once per payload.
+const BROTLI_QUALITY: u32 = 11;
+const BROTLI_WINDOW: u32 = 22;
+const BROTLI_BUFFER: usize = 4096;
+
+/// Content codings a payload is stored in, best first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Encoding {
+    Brotli,
+    Gzip,
+    Identity,
+}
+
+impl Encoding {
+    const PREFERENCE: [Self; 3] = [Self::Brotli, Self::Gzip, Self::Identity];
+
+    fn token(self) -> &'static str {
+        match self {
+            Self::Brotli => "br",
+            Self::Gzip => "gzip",
+            Self::Identity => "identity",
+        }
+    }
+
+    /// The encoding to send for an `Accept-Encoding` header (RFC 9110
+    /// §12.5.3): the highest weighted coding, brotli first among equals.
+    /// Identity is acceptable unless excluded with `q=0`, and a missing
+    /// header accepts only identity.
+    pub fn negotiate(accept_encoding: Option<&str>) -> Self {
+        let Some(accept) = accept_encoding else {
+            return Self::Identity;
+        };
+        let weights: Vec<(String, f32)> = accept
+            .split(',')
+            .filter_map(|entry| {
+                let mut params = entry.split(';');
+                let coding = params.next()?.trim().to_ascii_lowercase();
+                if coding.is_empty() {
+                    return None;
+                }
+                let weight = params
+                    .filter_map(|param| param.trim().strip_prefix("q="))
+                    .find_map(|q| q.trim().parse::<f32>().ok())
+                    .unwrap_or(1.0);
+                Some((coding, weight))
+            })
+            .collect();
+        let weight = |encoding: Self| {
+            let named = weights
+                .iter()
+                .find(|(coding, _)| coding == encoding.token())
+                .or_else(|| weights.iter().find(|(coding, _)| coding == "*"));
+            match (named, encoding) {
+                (Some((_, weight)), _) => *weight,
+                (None, Self::Identity) => 0.001,
+                (None, _) => 0.0,
+            }
+        };
+
+        Self::PREFERENCE
+            .into_iter()
+            .filter(|&encoding| weight(encoding) > 0.0)
+            // `max_by` returns the last of equal maxima, so go worst first
+            .rev()
+            .max_by(|&a, &b| weight(a).total_cmp(&weight(b)))
+            .unwrap_or(Self::Identity)
+    }
+}
+
+/// One body in one encoding.
+#[derive(Debug, Clone)]
+struct Variant {
+    body: Bytes,
+    etag: HeaderValue,
+}
+
+/// A response body stored in every [`Encoding`].
+#[derive(Debug, Clone)]
+pub struct Precompressed {
+    content_type: HeaderValue,
+    identity: Variant,
+    gzip: Variant,
+    brotli: Variant,
+}
+
+impl Precompressed {
+    /// Compress `body` and tag each encoding with a hash of the content.
+    pub fn new(content_type: &'static str, body: impl Into<Bytes>) -> Self {
+        let body: Bytes = body.into();
+        let digest = Sha256::digest(&body);
+        let hash: String = digest[..16].iter().map(|b| format!("{b:02x}")).collect();
+        let variant = |body: Bytes, suffix: &str| Variant {
+            body,
+            etag: HeaderValue::from_str(&format!("\"{hash}{suffix}\""))
+                .expect("hex ETags are valid header values"),
+        };
+
+        Self {
+            content_type: HeaderValue::from_static(content_type),
+            gzip: variant(compress_gzip(&body).into(), "-gzip"),
+            brotli: variant(compress_brotli(&body).into(), "-br"),
+            identity: variant(body, ""),
+        }
+    }
+
+    /// Serialize `value` as JSON and compress it.
+    pub fn json<T: Serialize + ?Sized>(value: &T) -> Self {
+        let body = serde_json::to_vec(value).expect("response payloads serialize to JSON");
+        Self::new("application/json", body)
+    }
+
+    fn variant(&self, encoding: Encoding) -> &Variant {
+        match encoding {
+            Encoding::Brotli => &self.brotli,
+            Encoding::Gzip => &self.gzip,
+            Encoding::Identity => &self.identity,
+        }
+    }
+
+    /// The body in the encoding `headers` ask for, or `304 Not Modified`
+    /// when their `If-None-Match` lists its `ETag`.
+    pub fn respond(&self, headers: &HeaderMap) -> Response {
+        let accept = headers
+            .get(header::ACCEPT_ENCODING)
+            .and_then(|value| value.to_str().ok());
+        let encoding = Encoding::negotiate(accept);
+        let variant = self.variant(encoding);
+        let cache_headers = [
+            (header::ETAG, variant.etag.clone()),
+            (
+                header::CACHE_CONTROL,
+                HeaderValue::from_static(CACHE_CONTROL),
+            ),
+            (header::VARY, HeaderValue::from_static("accept-encoding")),
+        ];
+
+        if is_not_modified(headers, &variant.etag) {
+            return (StatusCode::NOT_MODIFIED, cache_headers).into_response();
+        }
+
+        let mut response = (
+            StatusCode::OK,
+            cache_headers,
+            [(header::CONTENT_TYPE, self.content_type.clone())],
+            variant.body.clone(),
+        )
+            .into_response();
+        if encoding != Encoding::Identity {
+            response.headers_mut().insert(
+                header::CONTENT_ENCODING,
+                HeaderValue::from_static(encoding.token()),
+            );
+        }
+        response
+    }
+}
+
+/// Whether `If-None-Match` lists `etag` or `*`, compared weakly as the
+/// header requires (RFC 9110 §13.1.2).
+fn is_not_modified(headers: &HeaderMap, etag: &HeaderValue) -> bool {
+    let Some(if_none_match) = headers
+        .get(header::IF_NONE_MATCH)
+        .and_then(|value| value.to_str().ok())
+    else {
+        return false;
+    };
+    let etag = etag.to_str().unwrap_or_default();
+    if_none_match.split(',').map(str::trim).any(|candidate| {
+        candidate == "*" || candidate.strip_prefix("W/").unwrap_or(candidate) == etag
+    })
+}
+
+fn compress_gzip(body: &[u8]) -> Vec<u8> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::best());
+    encoder
+        .write_all(body)
+        .expect("writing to a Vec cannot fail");
+    encoder.finish().expect("writing to a Vec cannot fail")
+}
+
+fn compress_brotli(body: &[u8]) -> Vec<u8> {
+    let mut compressed = Vec::new();
+    {
+        let mut writer = brotli::CompressorWriter::new(
+            &mut compressed,
+            BROTLI_BUFFER,
+            BROTLI_QUALITY,
+            BROTLI_WINDOW,
+        );
+        writer
+            .write_all(body)
+            .expect("writing to a Vec cannot fail");
+    }
+    compressed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Read;
+
+    fn request(pairs: &[(header::HeaderName, &str)]) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        for (name, value) in pairs {
+            headers.insert(name.clone(), HeaderValue::from_str(value).unwrap());
+        }
+        headers
+    }
+
+    async fn body(response: Response) -> Vec<u8> {
+        axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap()
+            .to_vec()
+    }
+
+    #[test]
+    fn negotiate_prefers_brotli_and_honors_weights() {
+        assert_eq!(Encoding::negotiate(None), Encoding::Identity);
+        assert_eq!(
+            Encoding::negotiate(Some("gzip, deflate, br, zstd")),
+            Encoding::Brotli
+        );
+        assert_eq!(Encoding::negotiate(Some("gzip")), Encoding::Gzip);
+        assert_eq!(
+            Encoding::negotiate(Some("br;q=0.5, GZIP;q=0.8")),
+            Encoding::Gzip
+        );
+        assert_eq!(Encoding::negotiate(Some("br;q=0, *")), Encoding::Gzip);
+        assert_eq!(Encoding::negotiate(Some("deflate")), Encoding::Identity);
+        assert_eq!(Encoding::negotiate(Some("")), Encoding::Identity);
+    }
+
+    #[tokio::test]
+    async fn respond_serves_each_encoding() {
+        let payload = Precompressed::json(&(0..200).map(|_| "rhyhorn").collect::<Vec<_>>());
+        let identity = body(payload.respond(&HeaderMap::new())).await;
+        assert!(identity.starts_with(b"[\"rhyhorn\""));
+
+        let response = payload.respond(&request(&[(header::ACCEPT_ENCODING, "gzip")]));
+        assert_eq!(response.headers()[header::CONTENT_ENCODING], "gzip");
+        assert_eq!(response.headers()[header::VARY], "accept-encoding");
+        let mut decoded = Vec::new();
+        flate2::read::GzDecoder::new(&body(response).await[..])
+            .read_to_end(&mut decoded)
+            .unwrap();
+        assert_eq!(decoded, identity);
+
+        let response = payload.respond(&request(&[(header::ACCEPT_ENCODING, "br")]));
+        assert_eq!(response.headers()[header::CONTENT_ENCODING], "br");
+        let compressed = body(response).await;
+        assert!(compressed.len() < identity.len());
+        let mut decoded = Vec::new();
+        brotli::Decompressor::new(&compressed[..], BROTLI_BUFFER)
+            .read_to_end(&mut decoded)
+            .unwrap();
+        assert_eq!(decoded, identity);
+    }
+
+    #[tokio::test]
+    async fn respond_revalidates_per_encoding() {
+        let payload = Precompressed::json(&["rhyhorn", "pikachu"]);
+        let gzip_etag = payload
+            .respond(&request(&[(header::ACCEPT_ENCODING, "gzip")]))
+            .headers()[header::ETAG]
+            .to_str()
+            .unwrap()
+            .to_string();
+        let identity_etag = payload.respond(&HeaderMap::new()).headers()[header::ETAG]
+            .to_str()
+            .unwrap()
+            .to_string();
+        assert_ne!(gzip_etag, identity_etag);
+
+        let response = payload.respond(&request(&[
+            (header::ACCEPT_ENCODING, "gzip"),
+            (
+                header::IF_NONE_MATCH,
+                format!("\"stale\", W/{gzip_etag}").as_str(),
+            ),
+        ]));
+        assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+        assert_eq!(response.headers()[header::ETAG], gzip_etag.as_str());
+        assert!(body(response).await.is_empty());
+
+        // A copy in another encoding is a different representation
+        let response = payload.respond(&request(&[(header::IF_NONE_MATCH, gzip_etag.as_str())]));
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let changed = Precompressed::json(&["rhyhorn"]);
+        let response =
+            changed.respond(&request(&[(header::IF_NONE_MATCH, identity_etag.as_str())]));
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+}
@@ -0,0 +1,46 @@
+//! Deployment administration endpoints.
+
+use axum::extract::{Query, State};
+use axum::http::HeaderMap;
+use axum::Json;
+
+use crate::dto::{ApiCallListQuery, ApiCallRecord};
+use crate::error::ApiError;
+use crate::routes::metrics::bearer_authorized;
+use crate::state::AppState;
+
+/// List audited API calls
+///
+/// Calls made with an API key (`X-Rustume-Api-Key`), newest first. Requires
+/// `Authorization: Bearer` with the `RUSTUME_ADMIN_TOKEN` of the deployment.
+/// Returns 404 when no API key is configured.
+#[utoipa::path(
+    get,
+    path = "/api/admin/api-calls",
+    tag = "Admin",
+    params(ApiCallListQuery),
+    responses(
+        (status = 200, description = "Audited API calls, newest first", body = [ApiCallRecord]),
+        (status = 401, description = "Missing or invalid admin token", body = ApiError),
+        (status = 404, description = "API call auditing is not enabled", body = ApiError)
+    )
+)]
+pub async fn list_api_calls(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Query(query): Query<ApiCallListQuery>,
+) -> Result<Json<Vec<ApiCallRecord>>, ApiError> {
+    if !bearer_authorized(&headers, "RUSTUME_ADMIN_TOKEN") {
+        return Err(ApiError::unauthorized("Unauthorized"));
+    }
+    let audit = state
+        .api_audit
+        .as_ref()
+        .ok_or_else(|| ApiError::not_found("API call auditing is not enabled on this server"))?;
+    let calls = audit.log.api_calls(&query.into()).await.map_err(|err| {
+        tracing::error!("API audit log query failed: {err}");
+        ApiError::internal("Failed to read the API audit log")
+    })?;
+
+    Ok(Json(calls.into_iter().map(ApiCallRecord::from).collect()))
+}
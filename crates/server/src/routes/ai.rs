@@ -0,0 +1,68 @@
+use axum::{extract::State, Json};
+use rustume_utils::html_text_blocks;
+use tracing::warn;
+
+use crate::ai::{rewrite_prompt, MAX_REWRITE_TEXT_LEN};
+use crate::dto::{RewriteRequest, RewriteResponse};
+use crate::error::ApiError;
+use crate::state::AppState;
+
+/// Rewrite resume text with the configured AI provider
+///
+/// Rich text is flattened to one line per paragraph or bullet before it is
+/// sent to the model. Requires the `ai` server feature and
+/// `RUSTUME_AI_PROVIDER`. Callers must send one of the `RUSTUME_API_KEYS` in
+/// `X-Rustume-Api-Key`, and each call is recorded in the API audit log.
+#[utoipa::path(
+    post,
+    path = "/api/ai/rewrite",
+    tag = "AI",
+    request_body = RewriteRequest,
+    params(
+        ("X-Rustume-Api-Key" = String, Header, description = "API key")
+    ),
+    responses(
+        (status = 200, description = "Rewritten text", body = RewriteResponse),
+        (status = 400, description = "Empty text", body = ApiError),
+        (status = 401, description = "Missing or invalid API key", body = ApiError),
+        (status = 403, description = "No API keys are configured", body = ApiError),
+        (status = 404, description = "AI rewriting is not enabled", body = ApiError),
+        (status = 413, description = "Text too long", body = ApiError),
+        (status = 429, description = "AI rate limit exceeded"),
+        (status = 502, description = "AI provider failed", body = ApiError)
+    )
+)]
+pub async fn rewrite(
+    State(state): State<AppState>,
+    Json(request): Json<RewriteRequest>,
+) -> Result<Json<RewriteResponse>, ApiError> {
+    let ai = state.ai()?;
+
+    let text = html_text_blocks(&request.text).join("\n");
+    if text.is_empty() {
+        return Err(ApiError::new("Text to rewrite is empty"));
+    }
+    if text.chars().count() > MAX_REWRITE_TEXT_LEN {
+        return Err(ApiError::payload_too_large(format!(
+            "Text exceeds {MAX_REWRITE_TEXT_LEN} characters"
+        )));
+    }
+
+    let prompt = rewrite_prompt(
+        &text,
+        request.tone,
+        request.length,
+        request.context.as_deref(),
+    );
+    let provider = ai.provider();
+    let rewritten = provider.complete(&prompt).await.map_err(|err| {
+        warn!(provider = provider.name(), "AI rewrite failed: {err}");
+        ApiError::bad_gateway("AI provider request failed")
+    })?;
+
+    Ok(Json(RewriteResponse {
+        text: rewritten.trim().to_string(),
+        provider: provider.name().to_string(),
+        model: provider.model().to_string(),
+    }))
+}
@@ -0,0 +1,39 @@
+use axum::Json;
+use rustume_schema::{employment_gaps, keyword_report, ResumeData};
+use rustume_utils::PartialDate;
+
+use crate::dto::{AnalyzeRequest, AnalyzeResponse};
+use crate::error::ApiError;
+use crate::validation::validate_resume_json;
+
+/// Analyze resume wording
+///
+/// Counts keywords across the visible text of the resume, overall and per
+/// section, with stop words left out. Flags terms used more than
+/// `keywords.maxRepeats` times and any of the `keywords.buzzwords` (a built-in
+/// list when omitted), and, given `keywords.target`, reports which of the
+/// target role's keywords the resume uses and which it misses.
+///
+/// Also lists employment gaps of at least `gaps.minMonths` months (6 by
+/// default) between experience, education, and volunteer entries, each with
+/// a suggestion for an entry that would account for it.
+#[utoipa::path(
+    post,
+    path = "/api/analyze",
+    tag = "Analyze",
+    request_body = AnalyzeRequest,
+    responses(
+        (status = 200, description = "Resume analysis", body = AnalyzeResponse),
+        (status = 400, description = "Invalid resume data", body = ApiError)
+    )
+)]
+pub async fn analyze(Json(req): Json<AnalyzeRequest>) -> Result<Json<AnalyzeResponse>, ApiError> {
+    validate_resume_json(&req.resume)?;
+    let resume: ResumeData = serde_json::from_value(req.resume)
+        .map_err(|_| ApiError::new("Invalid resume data format"))?;
+
+    Ok(Json(AnalyzeResponse {
+        keywords: keyword_report(&resume, &req.keywords),
+        gaps: employment_gaps(&resume, &req.gaps, &PartialDate::today()),
+    }))
+}
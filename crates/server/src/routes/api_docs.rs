@@ -0,0 +1,22 @@
+//! The OpenAPI document behind Swagger UI.
+
+use std::sync::OnceLock;
+
+use axum::{http::HeaderMap, response::Response};
+
+use crate::openapi::api_doc;
+use crate::precompressed::Precompressed;
+
+/// Path Swagger UI loads the document from.
+pub const OPENAPI_PATH: &str = "/api-docs/openapi.json";
+
+/// The OpenAPI document, serialized and compressed on first use.
+pub fn openapi_payload() -> &'static Precompressed {
+    static PAYLOAD: OnceLock<Precompressed> = OnceLock::new();
+    PAYLOAD.get_or_init(|| Precompressed::json(&api_doc()))
+}
+
+/// Serve the OpenAPI document, with `304` for a current cached copy.
+pub async fn openapi_json(headers: HeaderMap) -> Response {
+    openapi_payload().respond(&headers)
+}
@@ -0,0 +1,395 @@
+//! Authenticated job application tracker routes for Rustume Cloud.
+
+use axum::{
+    extract::{Path, Query, State},
+    http::{HeaderMap, StatusCode},
+    Json,
+};
+use rustume_schema::{validation_messages, ApplicationStatus, JobApplication};
+use serde_json::Value;
+use tracing::error;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::audit::{record_event, AuditEvent};
+use crate::db::{ApplicationListQuery, ApplicationRow};
+use crate::error::ApiError;
+use crate::middleware::auth::AuthUser;
+use crate::net::{self, trusted_client_ip};
+use crate::state::AppState;
+use crate::subscription;
+
+/// List job applications for the authenticated user, most recently updated first.
+#[utoipa::path(
+    get,
+    path = "/api/applications",
+    tag = "Applications",
+    params(ApplicationListQuery),
+    responses(
+        (status = 200, description = "Job applications", body = [ApplicationRow]),
+        (status = 400, description = "Invalid filter", body = ApiError),
+        (status = 401, description = "Not authenticated", body = ApiError),
+    ),
+    security(("cookieAuth" = []))
+)]
+pub async fn list_applications(
+    AuthUser(user): AuthUser,
+    State(state): State<AppState>,
+    Query(query): Query<ApplicationListQuery>,
+) -> Result<Json<Vec<ApplicationRow>>, ApiError> {
+    let status = query
+        .status
+        .as_deref()
+        .map(str::parse::<ApplicationStatus>)
+        .transpose()
+        .map_err(ApiError::new)?;
+    let resume_id = query
+        .resume_id
+        .as_deref()
+        .map(parse_resume_id)
+        .transpose()?;
+
+    let cloud = state.cloud()?;
+    let access = subscription::load_access(&cloud.db, user.id).await?;
+    access.ensure_read()?;
+
+    let rows = sqlx::query_as::<_, ApplicationRow>(
+        r#"
+        SELECT id, user_id, resume_id, status, data, created_at, updated_at
+        FROM job_applications
+        WHERE user_id = $1
+          AND ($2::text IS NULL OR status = $2)
+          AND ($3::uuid IS NULL OR resume_id = $3)
+        ORDER BY updated_at DESC
+        "#,
+    )
+    .bind(user.id)
+    .bind(status.map(ApplicationStatus::as_str))
+    .bind(resume_id)
+    .fetch_all(&cloud.db)
+    .await
+    .map_err(internal_db_error)?;
+
+    Ok(Json(rows))
+}
+
+/// Fetch a job application owned by the authenticated user.
+#[utoipa::path(
+    get,
+    path = "/api/applications/{id}",
+    tag = "Applications",
+    params(("id" = String, Path, description = "Application ID")),
+    responses(
+        (status = 200, description = "Job application", body = ApplicationRow),
+        (status = 401, description = "Not authenticated", body = ApiError),
+        (status = 404, description = "Application not found", body = ApiError),
+    ),
+    security(("cookieAuth" = []))
+)]
+pub async fn get_application(
+    AuthUser(user): AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApplicationRow>, ApiError> {
+    let cloud = state.cloud()?;
+    let access = subscription::load_access(&cloud.db, user.id).await?;
+    access.ensure_read()?;
+
+    sqlx::query_as::<_, ApplicationRow>(
+        r#"
+        SELECT id, user_id, resume_id, status, data, created_at, updated_at
+        FROM job_applications
+        WHERE id = $1 AND user_id = $2
+        "#,
+    )
+    .bind(id)
+    .bind(user.id)
+    .fetch_optional(&cloud.db)
+    .await
+    .map_err(internal_db_error)?
+    .map(Json)
+    .ok_or_else(|| ApiError::not_found("Application not found"))
+}
+
+/// Create a job application for the authenticated user.
+///
+/// The body is a `JobApplication`; `id` is optional and must be a UUID when
+/// given. `resumeId`, when set, must reference one of the user's resumes.
+#[utoipa::path(
+    post,
+    path = "/api/applications",
+    tag = "Applications",
+    request_body = JobApplication,
+    responses(
+        (status = 201, description = "Application created", body = ApplicationRow),
+        (status = 400, description = "Invalid application", body = ApiError),
+        (status = 401, description = "Not authenticated", body = ApiError),
+        (status = 404, description = "Linked resume not found", body = ApiError),
+        (status = 409, description = "Application ID already exists", body = ApiError),
+        (status = 422, description = "Validation failed", body = ApiError),
+    ),
+    security(("cookieAuth" = []))
+)]
+pub async fn create_application(
+    AuthUser(user): AuthUser,
+    State(state): State<AppState>,
+    Json(body): Json<Value>,
+) -> Result<(StatusCode, Json<ApplicationRow>), ApiError> {
+    let id = match body.get("id").and_then(Value::as_str) {
+        Some(id) if !id.is_empty() => {
+            Uuid::parse_str(id).map_err(|_| ApiError::new("Application id must be a UUID"))?
+        }
+        _ => Uuid::new_v4(),
+    };
+    let application = parse_application(body, id)?;
+    let resume_id = application
+        .resume_id
+        .as_deref()
+        .map(parse_resume_id)
+        .transpose()?;
+
+    let cloud = state.cloud()?;
+    let access = subscription::load_access(&cloud.db, user.id).await?;
+    access.ensure_write()?;
+    if let Some(resume_id) = resume_id {
+        ensure_owned_resume(&cloud.db, user.id, resume_id).await?;
+    }
+
+    let row = sqlx::query_as::<_, ApplicationRow>(
+        r#"
+        INSERT INTO job_applications (id, user_id, resume_id, status, data)
+        VALUES ($1, $2, $3, $4, $5)
+        RETURNING id, user_id, resume_id, status, data, created_at, updated_at
+        "#,
+    )
+    .bind(id)
+    .bind(user.id)
+    .bind(resume_id)
+    .bind(application.status.as_str())
+    .bind(to_json_value(&application)?)
+    .fetch_one(&cloud.db)
+    .await
+    .map_err(map_application_db_error)?;
+
+    Ok((StatusCode::CREATED, Json(row)))
+}
+
+/// Replace a job application owned by the authenticated user.
+#[utoipa::path(
+    put,
+    path = "/api/applications/{id}",
+    tag = "Applications",
+    params(("id" = String, Path, description = "Application ID")),
+    request_body = JobApplication,
+    responses(
+        (status = 200, description = "Application updated", body = ApplicationRow),
+        (status = 400, description = "Invalid application", body = ApiError),
+        (status = 401, description = "Not authenticated", body = ApiError),
+        (status = 404, description = "Application or linked resume not found", body = ApiError),
+        (status = 422, description = "Validation failed", body = ApiError),
+    ),
+    security(("cookieAuth" = []))
+)]
+pub async fn update_application(
+    AuthUser(user): AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Json(body): Json<Value>,
+) -> Result<Json<ApplicationRow>, ApiError> {
+    let application = parse_application(body, id)?;
+    let resume_id = application
+        .resume_id
+        .as_deref()
+        .map(parse_resume_id)
+        .transpose()?;
+
+    let cloud = state.cloud()?;
+    let access = subscription::load_access(&cloud.db, user.id).await?;
+    access.ensure_write()?;
+    if let Some(resume_id) = resume_id {
+        ensure_owned_resume(&cloud.db, user.id, resume_id).await?;
+    }
+
+    sqlx::query_as::<_, ApplicationRow>(
+        r#"
+        UPDATE job_applications
+        SET resume_id = $1,
+            status = $2,
+            data = $3,
+            updated_at = now()
+        WHERE id = $4 AND user_id = $5
+        RETURNING id, user_id, resume_id, status, data, created_at, updated_at
+        "#,
+    )
+    .bind(resume_id)
+    .bind(application.status.as_str())
+    .bind(to_json_value(&application)?)
+    .bind(id)
+    .bind(user.id)
+    .fetch_optional(&cloud.db)
+    .await
+    .map_err(internal_db_error)?
+    .map(Json)
+    .ok_or_else(|| ApiError::not_found("Application not found"))
+}
+
+/// Delete a job application owned by the authenticated user.
+#[utoipa::path(
+    delete,
+    path = "/api/applications/{id}",
+    tag = "Applications",
+    params(("id" = String, Path, description = "Application ID")),
+    responses(
+        (status = 204, description = "Application deleted"),
+        (status = 401, description = "Not authenticated", body = ApiError),
+        (status = 404, description = "Application not found", body = ApiError),
+    ),
+    security(("cookieAuth" = []))
+)]
+pub async fn delete_application(
+    AuthUser(user): AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    headers: HeaderMap,
+) -> Result<StatusCode, ApiError> {
+    let cloud = state.cloud()?;
+    let access = subscription::load_access(&cloud.db, user.id).await?;
+    access.ensure_delete()?;
+    let result = sqlx::query("DELETE FROM job_applications WHERE id = $1 AND user_id = $2")
+        .bind(id)
+        .bind(user.id)
+        .execute(&cloud.db)
+        .await
+        .map_err(internal_db_error)?;
+
+    if result.rows_affected() == 0 {
+        return Err(ApiError::not_found("Application not found"));
+    }
+
+    record_event(
+        &cloud.db,
+        AuditEvent {
+            event_type: "application.delete",
+            actor_user_id: Some(user.id),
+            resource_type: Some("application"),
+            resource_id: Some(id),
+            metadata: serde_json::json!({}),
+            ip_address: trusted_client_ip(&headers, net::trusted_proxy_enabled()).as_deref(),
+        },
+    )
+    .await;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Parse a request body into a validated [`JobApplication`] stored under `id`.
+fn parse_application(mut body: Value, id: Uuid) -> Result<JobApplication, ApiError> {
+    let Some(fields) = body.as_object_mut() else {
+        return Err(ApiError::new("Job application must be a JSON object"));
+    };
+    fields.insert("id".to_string(), Value::String(id.to_string()));
+
+    let application: JobApplication = serde_json::from_value(body)
+        .map_err(|err| ApiError::new(format!("Invalid job application: {err}")))?;
+    application
+        .validate()
+        .map_err(|e| ApiError::with_details("Validation failed", validation_messages(&e)))?;
+    Ok(application)
+}
+
+fn parse_resume_id(value: &str) -> Result<Uuid, ApiError> {
+    Uuid::parse_str(value).map_err(|_| ApiError::new("resumeId must be a resume UUID"))
+}
+
+fn to_json_value(application: &JobApplication) -> Result<Value, ApiError> {
+    serde_json::to_value(application).map_err(|err| {
+        error!("failed to serialize job application: {err}");
+        ApiError::internal("internal server error")
+    })
+}
+
+async fn ensure_owned_resume(
+    db: &sqlx::PgPool,
+    user_id: Uuid,
+    resume_id: Uuid,
+) -> Result<(), ApiError> {
+    let exists = sqlx::query_scalar::<_, bool>(
+        "SELECT EXISTS (SELECT 1 FROM resumes WHERE id = $1 AND user_id = $2 AND trashed_at IS NULL)",
+    )
+    .bind(resume_id)
+    .bind(user_id)
+    .fetch_one(db)
+    .await
+    .map_err(internal_db_error)?;
+
+    if exists {
+        Ok(())
+    } else {
+        Err(ApiError::not_found("Resume not found"))
+    }
+}
+
+fn internal_db_error(err: impl std::fmt::Display) -> ApiError {
+    error!("database error: {err}");
+    ApiError::internal("internal server error")
+}
+
+fn map_application_db_error(err: sqlx::Error) -> ApiError {
+    if let sqlx::Error::Database(db_err) = &err {
+        if db_err.code().as_deref() == Some("23505") {
+            return ApiError::conflict("An application with this ID already exists");
+        }
+    }
+    internal_db_error(err)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ApiErrorKind;
+    use serde_json::json;
+
+    #[test]
+    fn parse_application_assigns_id_and_defaults() {
+        let id = Uuid::new_v4();
+        let application = parse_application(
+            json!({"id": "ignored", "company": "Acme", "role": "Engineer"}),
+            id,
+        )
+        .expect("valid application");
+
+        assert_eq!(application.id, id.to_string());
+        assert_eq!(application.status, ApplicationStatus::Saved);
+    }
+
+    #[test]
+    fn parse_application_rejects_non_objects_and_bad_status() {
+        let id = Uuid::new_v4();
+        assert!(parse_application(json!(["Acme"]), id).is_err());
+
+        let err = parse_application(
+            json!({"company": "Acme", "role": "Engineer", "status": "ghosted"}),
+            id,
+        )
+        .expect_err("unknown status");
+        assert!(err.error.starts_with("Invalid job application"));
+    }
+
+    #[test]
+    fn parse_application_reports_validation_details() {
+        let err = parse_application(
+            json!({"company": "", "role": "Engineer", "appliedDate": "soon"}),
+            Uuid::new_v4(),
+        )
+        .expect_err("invalid application");
+
+        assert!(matches!(err.kind, ApiErrorKind::UnprocessableEntity));
+        assert!(err.details.is_some_and(|details| details.len() == 2));
+    }
+
+    #[test]
+    fn parse_resume_id_requires_uuid() {
+        assert!(parse_resume_id(&Uuid::new_v4().to_string()).is_ok());
+        assert!(parse_resume_id("resume-1").is_err());
+    }
+}
@@ -0,0 +1,68 @@
+use axum::Json;
+use rustume_schema::{merge_items, ResumeData, RustumeError};
+use serde_json::Value;
+
+use crate::dto::{DedupeMergeRequest, DedupeResponse};
+use crate::error::ApiError;
+use crate::validation::validate_resume_json;
+
+/// Find likely duplicate items
+///
+/// Compares items within each section on their identifying fields (company
+/// and position, institution and area, skill name, ...) after normalizing
+/// case, punctuation, legal suffixes, and common abbreviations. Returns
+/// candidate pairs only; nothing is changed until the merges are applied.
+#[utoipa::path(
+    post,
+    path = "/api/dedupe",
+    tag = "Dedupe",
+    request_body = ResumeData,
+    responses(
+        (status = 200, description = "Duplicate candidates", body = DedupeResponse),
+        (status = 400, description = "Invalid resume data", body = ApiError)
+    )
+)]
+pub async fn dedupe(Json(value): Json<Value>) -> Result<Json<DedupeResponse>, ApiError> {
+    validate_resume_json(&value)?;
+    let resume: ResumeData =
+        serde_json::from_value(value).map_err(|_| ApiError::new("Invalid resume data format"))?;
+
+    Ok(Json(DedupeResponse {
+        candidates: resume.duplicates(),
+    }))
+}
+
+/// Merge duplicate items
+///
+/// Applies the confirmed merges in order and returns the updated resume.
+/// Each kept item keeps its ID and filled-in fields; blank fields are taken
+/// from the duplicate and keyword lists are joined.
+#[utoipa::path(
+    post,
+    path = "/api/dedupe/merge",
+    tag = "Dedupe",
+    request_body = DedupeMergeRequest,
+    responses(
+        (status = 200, description = "Resume with duplicates merged", body = ResumeData),
+        (status = 400, description = "Invalid resume data or unknown item", body = ApiError)
+    )
+)]
+pub async fn dedupe_merge(
+    Json(req): Json<DedupeMergeRequest>,
+) -> Result<Json<ResumeData>, ApiError> {
+    validate_resume_json(&req.resume)?;
+    let mut resume: ResumeData = serde_json::from_value(req.resume)
+        .map_err(|_| ApiError::new("Invalid resume data format"))?;
+
+    for merge in &req.merges {
+        merge_items(
+            &mut resume,
+            &merge.section,
+            &merge.keep_id,
+            &merge.duplicate_id,
+        )
+        .map_err(|e| ApiError::from(RustumeError::from(e)))?;
+    }
+
+    Ok(Json(resume))
+}
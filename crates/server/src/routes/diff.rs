@@ -0,0 +1,52 @@
+use axum::{
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use rustume_schema::{DiffReportFormat, ResumeData};
+
+use crate::dto::DiffReportRequest;
+use crate::error::ApiError;
+use crate::validation::validate_resume_json;
+
+/// Report the changes between two resumes
+///
+/// Compares two versions of a resume, such as a resume before and after
+/// AI-suggested edits, and writes what changed for a person to review:
+/// added and removed items named by their identifying fields, and edited
+/// fields compared word by word. Markdown strikes removed words through and
+/// puts added words in bold; the HTML page marks them with `<del>` and `<ins>`.
+#[utoipa::path(
+    post,
+    path = "/api/diff/report",
+    tag = "Diff",
+    request_body = DiffReportRequest,
+    responses(
+        (status = 200, description = "Change report, per `format`", content(
+            (String = "text/markdown"),
+            (String = "text/html")
+        )),
+        (status = 400, description = "Invalid resume data", body = ApiError)
+    )
+)]
+pub async fn diff_report(Json(req): Json<DiffReportRequest>) -> Result<Response, ApiError> {
+    let before = parse_resume(req.before)?;
+    let after = parse_resume(req.after)?;
+
+    let content_type = match req.format {
+        DiffReportFormat::Markdown => "text/markdown; charset=utf-8",
+        DiffReportFormat::Html => "text/html; charset=utf-8",
+    };
+    let report = rustume_schema::diff_report(&before, &after).render(req.format);
+    Ok((
+        StatusCode::OK,
+        [(header::CONTENT_TYPE, content_type)],
+        report,
+    )
+        .into_response())
+}
+
+fn parse_resume(value: serde_json::Value) -> Result<ResumeData, ApiError> {
+    validate_resume_json(&value)?;
+    serde_json::from_value(value).map_err(|_| ApiError::new("Invalid resume data format"))
+}
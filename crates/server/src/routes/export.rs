@@ -7,16 +7,21 @@ use axum::{
     Json,
 };
 use chrono::Utc;
-use rustume_render::Renderer;
+use rustume_render::{RenderEngine, Renderer};
 use rustume_schema::ResumeData;
 use tracing::error;
 use uuid::Uuid;
 use zip::write::SimpleFileOptions;
 use zip::ZipWriter;
 
+use crate::artifact_cache::{cached_render, ArtifactKey};
 use crate::db::{ResumeBulkExport, ResumeExportItem};
+use crate::dto::BinaryFile;
 use crate::error::ApiError;
 use crate::middleware::auth::AuthUser;
+use crate::middleware::request_id::spawn_blocking_in_request;
+use crate::picture::embed_remote_picture;
+use crate::routes::render::render_error;
 use crate::state::AppState;
 use crate::subscription;
 
@@ -80,7 +85,7 @@ pub async fn export_resumes_json(
     path = "/api/resumes/export/pdf",
     tag = "Resumes",
     responses(
-        (status = 200, description = "ZIP archive of PDF resumes", content_type = "application/zip"),
+        (status = 200, description = "ZIP archive of PDF resumes", content_type = "application/zip", body = inline(BinaryFile)),
         (status = 401, description = "Not authenticated", body = ApiError),
         (status = 403, description = "Subscription expired", body = ApiError),
         (status = 413, description = "Too many resumes to export", body = ApiError),
@@ -97,27 +102,36 @@ pub async fn export_resumes_pdf(
 
     let rows = fetch_all_resumes(&cloud.db, user.id).await?;
     let renderer = state.renderer.clone();
+    let cache = state.artifact_cache.clone();
     let mut archive = ZipWriter::new(std::io::Cursor::new(Vec::new()));
     let options = SimpleFileOptions::default().compression_method(zip::CompressionMethod::Deflated);
 
     for row in rows {
-        let resume: ResumeData = serde_json::from_value(row.data)
+        let mut resume: ResumeData = serde_json::from_value(row.data)
             .map_err(|_| ApiError::new("Invalid resume data format"))?;
+        embed_remote_picture(&mut resume).await?;
         let file_name = export_pdf_filename(&row.id, &row.title);
-        let pdf = tokio::task::spawn_blocking({
+        let pdf = spawn_blocking_in_request({
             let renderer = renderer.clone();
+            let cache = cache.clone();
             let resume = resume.clone();
             let resume_id = row.id;
             let resume_title = row.title.clone();
             move || {
-                renderer.render_pdf(&resume).map_err(|err| {
-                    format!("Failed to render PDF for resume '{resume_title}' ({resume_id}): {err}")
+                let key =
+                    ArtifactKey::pdf(&resume, RenderEngine::Typst, &[], &[], renderer.branding());
+                cached_render(cache.as_deref(), Some(&key), || {
+                    renderer.render_pdf(&resume).map_err(|err| {
+                        let context = format!(
+                            "Failed to render PDF for resume '{resume_title}' ({resume_id})"
+                        );
+                        render_error("pdf", &resume, &err, &context)
+                    })
                 })
             }
         })
         .await
-        .map_err(|err| ApiError::internal(format!("Render task failed: {err}")))?
-        .map_err(ApiError::internal)?;
+        .map_err(|err| ApiError::internal(format!("Render task failed: {err}")))??;
 
         archive
             .start_file(file_name, options)
@@ -341,6 +355,7 @@ mod tests {
                     "re_test".into(),
                     "noreply@rustume.com".into(),
                 )),
+                trash_retention_days: 30,
             })),
             false,
         )
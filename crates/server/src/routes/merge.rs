@@ -0,0 +1,35 @@
+use axum::Json;
+use rustume_schema::{merge_import, MergedImport, ResumeData};
+
+use crate::dto::MergeImportRequest;
+use crate::error::ApiError;
+use crate::validation::validate_resume_json;
+
+/// Merge an import into an existing resume
+///
+/// Appends imported items that have no near-duplicate in the existing resume
+/// and reports which were added, skipped, or (with `fill_blanks`) used to
+/// fill blank fields. Existing items keep their IDs, visibility, and edits.
+#[utoipa::path(
+    post,
+    path = "/api/merge",
+    tag = "Merge",
+    request_body = MergeImportRequest,
+    responses(
+        (status = 200, description = "Merged resume and report", body = MergedImport),
+        (status = 400, description = "Invalid resume data", body = ApiError)
+    )
+)]
+pub async fn merge(Json(req): Json<MergeImportRequest>) -> Result<Json<MergedImport>, ApiError> {
+    let existing = parse_resume(req.existing)?;
+    let imported = parse_resume(req.imported)?;
+
+    merge_import(existing, imported, req.strategy)
+        .map(Json)
+        .map_err(|e| ApiError::new(e.to_string()))
+}
+
+fn parse_resume(value: serde_json::Value) -> Result<ResumeData, ApiError> {
+    validate_resume_json(&value)?;
+    serde_json::from_value(value).map_err(|_| ApiError::new("Invalid resume data format"))
+}
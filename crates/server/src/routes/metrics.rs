@@ -4,11 +4,13 @@ use axum::{
     http::{header, HeaderMap, StatusCode},
     response::{IntoResponse, Response},
 };
+use metrics::{counter, histogram};
+use rustume_render::RenderStats;
 use std::sync::OnceLock;
 use subtle::ConstantTimeEq;
 use tracing::warn;
 
-use metrics_exporter_prometheus::PrometheusHandle;
+use metrics_exporter_prometheus::{Matcher, PrometheusBuilder, PrometheusHandle};
 
 use crate::error::ApiError;
 
@@ -20,7 +22,7 @@ pub fn init_metrics() {
         return;
     }
 
-    match metrics_exporter_prometheus::PrometheusBuilder::new().install_recorder() {
+    match prometheus_builder().install_recorder() {
         Ok(handle) => {
             let _ = PROMETHEUS.set(handle);
         }
@@ -28,9 +30,89 @@ pub fn init_metrics() {
     }
 }
 
+/// Upper bounds of the render compile time buckets, in seconds. Warm
+/// compiles of a typical resume take tens of milliseconds.
+const COMPILE_SECONDS_BUCKETS: &[f64] = &[0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0];
+
+/// Upper bounds of the page count buckets.
+const PAGE_BUCKETS: &[f64] = &[1.0, 2.0, 3.0, 4.0, 6.0, 10.0];
+
+/// Upper bounds of the output size buckets, in bytes.
+const OUTPUT_BYTES_BUCKETS: &[f64] = &[
+    25_000.0,
+    50_000.0,
+    100_000.0,
+    250_000.0,
+    500_000.0,
+    1_000_000.0,
+    5_000_000.0,
+];
+
+/// Prometheus exporter with histogram buckets for the render metrics, so
+/// they aggregate across instances.
+fn prometheus_builder() -> PrometheusBuilder {
+    [
+        ("rustume_render_compile_seconds", COMPILE_SECONDS_BUCKETS),
+        ("rustume_render_pages", PAGE_BUCKETS),
+        ("rustume_render_output_bytes", OUTPUT_BYTES_BUCKETS),
+    ]
+    .into_iter()
+    .fold(PrometheusBuilder::new(), |builder, (name, buckets)| {
+        builder
+            .set_buckets_for_metric(Matcher::Full(name.to_string()), buckets)
+            .expect("buckets are not empty")
+    })
+}
+
+/// Record a finished Typst render, labeled by template and output kind:
+///
+/// - `rustume_renders_total`, with `status` `ok` or the error code
+/// - `rustume_render_compile_seconds`
+/// - `rustume_render_pages` and `rustume_render_output_bytes`, for
+///   successful renders
+/// - `rustume_render_font_fallbacks_total`, by fallback font `family`
+pub fn record_render(stats: &RenderStats) {
+    let template = stats.template.clone();
+    let kind = stats.kind.as_str();
+    let status = stats.error.map_or("ok", |code| code.as_str());
+
+    counter!(
+        "rustume_renders_total",
+        "template" => template.clone(),
+        "kind" => kind,
+        "status" => status
+    )
+    .increment(1);
+    histogram!(
+        "rustume_render_compile_seconds",
+        "template" => template.clone(),
+        "kind" => kind
+    )
+    .record(stats.compile_time.as_secs_f64());
+
+    if stats.error.is_none() {
+        histogram!("rustume_render_pages", "template" => template.clone(), "kind" => kind)
+            .record(stats.pages as f64);
+        histogram!(
+            "rustume_render_output_bytes",
+            "template" => template.clone(),
+            "kind" => kind
+        )
+        .record(stats.output_bytes as f64);
+    }
+    for family in &stats.font_fallbacks {
+        counter!(
+            "rustume_render_font_fallbacks_total",
+            "template" => template.clone(),
+            "family" => *family
+        )
+        .increment(1);
+    }
+}
+
 /// Render all recorded metrics in Prometheus text format.
 pub async fn metrics(headers: HeaderMap) -> Result<Response, ApiError> {
-    if !metrics_authorized(&headers) {
+    if !bearer_authorized(&headers, "METRICS_TOKEN") {
         return Err(ApiError::unauthorized("Unauthorized"));
     }
 
@@ -42,8 +124,10 @@ pub async fn metrics(headers: HeaderMap) -> Result<Response, ApiError> {
     Ok((StatusCode::OK, body).into_response())
 }
 
-fn metrics_authorized(headers: &HeaderMap) -> bool {
-    let expected = match std::env::var("METRICS_TOKEN") {
+/// Whether `headers` carry `Authorization: Bearer` with the token in the
+/// environment variable `token_var`. Always false when it is unset or empty.
+pub(crate) fn bearer_authorized(headers: &HeaderMap, token_var: &str) -> bool {
+    let expected = match std::env::var(token_var) {
         Ok(token) if !token.is_empty() => token,
         _ => return false,
     };
@@ -65,3 +149,52 @@ fn metrics_authorized(headers: &HeaderMap) -> bool {
 fn constant_time_eq(left: &str, right: &str) -> bool {
     left.as_bytes().ct_eq(right.as_bytes()).into()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rustume_render::RenderKind;
+    use rustume_schema::ErrorCode;
+    use std::time::Duration;
+
+    #[test]
+    fn test_record_render_labels_by_template() {
+        let recorder = prometheus_builder().build_recorder();
+        let handle = recorder.handle();
+        let ok = RenderStats {
+            template: "onyx".to_string(),
+            kind: RenderKind::Pdf,
+            pages: 2,
+            compile_time: Duration::from_millis(80),
+            output_bytes: 42_000,
+            font_fallbacks: vec!["Noto Sans CJK JP"],
+            error: None,
+        };
+        let failed = RenderStats {
+            kind: RenderKind::Preview,
+            pages: 0,
+            output_bytes: 0,
+            font_fallbacks: Vec::new(),
+            error: Some(ErrorCode::RenderFailed),
+            ..ok.clone()
+        };
+        metrics::with_local_recorder(&recorder, || {
+            record_render(&ok);
+            record_render(&failed);
+        });
+
+        let body = handle.render();
+        assert!(body.contains(r#"rustume_renders_total{template="onyx",kind="pdf",status="ok"} 1"#));
+        assert!(body.contains(
+            r#"rustume_renders_total{template="onyx",kind="preview",status="RENDER_FAILED"} 1"#
+        ));
+        assert!(body.contains(
+            r#"rustume_render_compile_seconds_bucket{template="onyx",kind="pdf",le="0.1"} 1"#
+        ));
+        assert!(body.contains(r#"rustume_render_pages_sum{template="onyx",kind="pdf"} 2"#));
+        assert!(!body.contains(r#"rustume_render_pages_sum{template="onyx",kind="preview"}"#));
+        assert!(body.contains(
+            r#"rustume_render_font_fallbacks_total{template="onyx",family="Noto Sans CJK JP"} 1"#
+        ));
+    }
+}
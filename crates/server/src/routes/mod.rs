@@ -1,30 +1,66 @@
 //! HTTP route handlers for the Rustume API.
 
 pub mod account;
+pub mod admin;
+#[cfg(feature = "ai")]
+pub mod ai;
+pub mod analyze;
+pub mod api_docs;
+pub mod applications;
 pub mod auth;
+pub mod dedupe;
+pub mod diff;
 pub mod export;
 pub mod health;
+pub mod merge;
 pub mod metrics;
 pub mod parse;
+pub mod presets;
+pub mod public;
 pub mod render;
 pub mod resumes;
+pub mod sections;
 pub mod security_txt;
 pub mod static_files;
+pub mod stats;
+pub mod suggest;
 pub mod templates;
 pub mod validate;
+pub mod webhooks;
 
 pub use account::delete_account;
+pub use admin::list_api_calls;
+#[cfg(feature = "ai")]
+pub use ai::rewrite;
+pub use analyze::analyze;
+pub use api_docs::{openapi_json, openapi_payload, OPENAPI_PATH};
+pub use applications::{
+    create_application, delete_application, get_application, list_applications, update_application,
+};
 pub use auth::{callback, login, logout, me};
+pub use dedupe::{dedupe, dedupe_merge};
+pub use diff::diff_report;
 pub use export::{export_resumes_json, export_resumes_pdf};
 pub use health::health;
-pub use metrics::{init_metrics, metrics};
-pub use parse::parse;
-pub use render::{render_pdf, render_preview};
+pub use merge::merge;
+pub use metrics::{init_metrics, metrics, record_render};
+pub use parse::{parse, parse_csv, parse_linkedin_upload, parse_with_report};
+pub use presets::{apply_layout_preset, get_content_preset, list_content_presets};
+pub use public::public_resume_json;
+pub use render::{
+    fit_pages, render_pdf, render_pdf_stream, render_preview, render_svg, render_timeline,
+};
 pub use resumes::{
-    create_resume, delete_resume, get_resume, import_resumes, list_resumes, update_resume,
-    update_sharing,
+    create_resume, delete_resume, get_resume, import_resumes, list_resumes, list_trashed_resumes,
+    restore_resume, resume_history, update_resume, update_sharing,
 };
+pub use sections::export_section;
 pub use security_txt::security_txt;
-pub use static_files::{sanitize_static_path, spa_fallback, static_dir};
-pub use templates::{list_templates, template_thumbnail};
+pub use static_files::{sanitize_static_path, serve_web_arg, spa_fallback, static_dir};
+pub use stats::stats;
+pub use suggest::suggest;
+pub use templates::{list_templates, template_thumbnail, warm_thumbnail_cache};
 pub use validate::validate;
+pub use webhooks::{
+    create_webhook, delete_webhook, list_webhook_deliveries, list_webhooks, update_webhook,
+};
@@ -1,17 +1,41 @@
+use std::io;
+
+use axum::body::Body;
 use axum::Json;
-use rustume_parser::{parse_resume, ResumeFormat};
+use rustume_parser::{
+    detect_resume_format, import_section_csv, AsyncParser, CsvSection, ImportPipeline, Imported,
+    LinkedInParser, ParseError, Parser, ResumeFormat,
+};
 use rustume_schema::ResumeData;
+use tokio_stream::StreamExt;
+use tokio_util::io::StreamReader;
 use tracing::error;
 
-use crate::dto::ParseRequest;
+use crate::dto::{BinaryFile, CsvImportRequest, CsvImportResponse, ImportResponse, ParseRequest};
 use crate::error::ApiError;
+use crate::extract::ApiJson;
+use crate::middleware::request_id::spawn_blocking_in_request;
+use crate::validation::validate_resume_json;
 
 /// Parse resume from various formats
 ///
 /// Converts resumes from JSON Resume, LinkedIn export, Reactive Resume v3,
-/// or native Rustume format into the unified Rustume schema.
+/// Stack Overflow user data, an HTML page with hResume or schema.org Person
+/// markup, a vCard, plain text, or native Rustume format into the unified
+/// Rustume schema. Plain text is sectionized heuristically, so the result is
+/// a draft to review.
+///
+/// With `format: "auto"`, the format is detected from the data; input that
+/// can't be placed, such as plain text or a PDF, is rejected with `400`.
+///
+/// Every import goes through the same pipeline as the CLI and the WASM
+/// bindings: dates are normalized, verbatim duplicate items dropped, IDs
+/// assigned, and the resume validated. An invalid resume is rejected with
+/// `422` unless `lenient` is set. `POST /api/parse/report` returns what the
+/// pipeline changed and found along with the resume.
 ///
 /// For LinkedIn exports, the data must be base64 encoded since it's a ZIP file.
+/// Archives that exceed the decompression limits are rejected with `413`.
 #[utoipa::path(
     post,
     path = "/api/parse",
@@ -19,10 +43,48 @@ use crate::error::ApiError;
     request_body = ParseRequest,
     responses(
         (status = 200, description = "Successfully parsed resume", body = ResumeData),
-        (status = 400, description = "Failed to parse resume", body = ApiError)
+        (status = 400, description = "Failed to parse resume", body = ApiError),
+        (status = 413, description = "Input exceeds import limits", body = ApiError),
+        (status = 422, description = "Request body doesn't match `ParseRequest`; `pointer` names the field. Also sent for a parsed resume that fails validation when `lenient` is off", body = ApiError, example = json!({
+            "error": "Invalid value at `/base64`: invalid type: string \"yes\", expected a boolean",
+            "code": "INVALID_INPUT",
+            "pointer": "/base64",
+            "expected": "a boolean"
+        }))
+    )
+)]
+pub async fn parse(ApiJson(req): ApiJson<ParseRequest>) -> Result<Json<ResumeData>, ApiError> {
+    Ok(Json(import(req)?.resume))
+}
+
+/// Parse resume and report on the import
+///
+/// Takes the same request as `POST /api/parse` and returns the resume with
+/// the import report: the detected format and how sure the detection was,
+/// dates rewritten and duplicates dropped, near-duplicates left to merge,
+/// and warnings such as dates that couldn't be read. With `lenient` set,
+/// the report also lists the validation errors the resume still has.
+#[utoipa::path(
+    post,
+    path = "/api/parse/report",
+    tag = "Parse",
+    request_body = ParseRequest,
+    responses(
+        (status = 200, description = "Parsed resume and import report", body = ImportResponse),
+        (status = 400, description = "Failed to parse resume", body = ApiError),
+        (status = 413, description = "Input exceeds import limits", body = ApiError),
+        (status = 422, description = "Invalid request body, or a resume that fails validation when `lenient` is off", body = ApiError)
     )
 )]
-pub async fn parse(Json(req): Json<ParseRequest>) -> Result<Json<ResumeData>, ApiError> {
+pub async fn parse_with_report(
+    ApiJson(req): ApiJson<ParseRequest>,
+) -> Result<Json<ImportResponse>, ApiError> {
+    let Imported { resume, report } = import(req)?;
+    Ok(Json(ImportResponse { resume, report }))
+}
+
+/// Decode a parse request and run it through the import pipeline.
+fn import(mut req: ParseRequest) -> Result<Imported, ApiError> {
     // Decode data
     let data = if req.base64 {
         use base64::Engine;
@@ -33,29 +95,154 @@ pub async fn parse(Json(req): Json<ParseRequest>) -> Result<Json<ResumeData>, Ap
                 ApiError::new("Invalid base64 input")
             })?
     } else {
-        req.data.into_bytes()
+        std::mem::take(&mut req.data).into_bytes()
     };
 
-    // Parse based on format
-    let format = ResumeFormat::from(req.format);
-    let resume = parse_resume(format, &data).map_err(|err| match format {
-        ResumeFormat::JsonResume => {
+    let format = match req.format.resume_format() {
+        Some(format) => format,
+        None => detect_resume_format(&data).map_err(|err| {
+            ApiError::new(format!("{err}; set `format` to choose a parser")).with_code(err.code())
+        })?,
+    };
+    ImportPipeline::new(req.import_options(format))
+        .run(&data)
+        .map_err(|err| parse_failure(&err, format).with_code(err.code()))
+}
+
+/// Parse a LinkedIn export upload
+///
+/// Takes the LinkedIn data export ZIP as the raw request body, so clients
+/// can upload the file as is instead of base64 in JSON. Entries are unpacked
+/// one at a time as the body arrives, and the upload is refused with `413` as
+/// soon as it passes the 10 MB request limit or an entry passes the
+/// decompression limits of `POST /api/parse`. Archives that store entry sizes
+/// after the data can't be read this way and get `400`; send those to
+/// `POST /api/parse`.
+#[utoipa::path(
+    post,
+    path = "/api/parse/linkedin",
+    tag = "Parse",
+    request_body(content = inline(BinaryFile), content_type = "application/zip", description = "LinkedIn data export ZIP"),
+    responses(
+        (status = 200, description = "Successfully parsed resume", body = ResumeData),
+        (status = 400, description = "Not a readable LinkedIn export", body = ApiError),
+        (status = 413, description = "Body over the 10 MB request limit, or archive over the import limits", body = ApiError)
+    )
+)]
+pub async fn parse_linkedin_upload(body: Body) -> Result<Json<ResumeData>, ApiError> {
+    let parser = LinkedInParser::default();
+    let chunks = body
+        .into_data_stream()
+        .map(|chunk| chunk.map_err(io::Error::other));
+    let failure =
+        |err: ParseError| parse_failure(&err, ResumeFormat::LinkedIn).with_code(err.code());
+
+    let data = parser
+        .read_async(StreamReader::new(chunks))
+        .await
+        .map_err(failure)?;
+    let resume = spawn_blocking_in_request(move || parser.convert(data))
+        .await
+        .map_err(|err| ApiError::internal(format!("Parse task failed: {err}")))?
+        .map_err(failure)?;
+    let imported = ImportPipeline::default()
+        .finish(ResumeFormat::LinkedIn, resume)
+        .map_err(failure)?;
+
+    Ok(Json(imported.resume))
+}
+
+/// Response for a failed import, logging the underlying error.
+fn parse_failure(err: &ParseError, format: ResumeFormat) -> ApiError {
+    match (err, format) {
+        (ParseError::InvalidResume(reason), _) => ApiError::with_details(
+            "Imported resume failed validation; set `lenient` to keep it anyway",
+            vec![reason.clone()],
+        ),
+        (ParseError::LimitExceeded(reason), _) => {
+            error!("{} input exceeded parser limits: {err}", format.label());
+            ApiError::payload_too_large(format!("Input exceeds import limits: {reason}"))
+        }
+        (_, ResumeFormat::JsonResume) => {
             error!("JSON Resume parse failed: {err}");
             ApiError::new("Failed to parse JSON Resume input")
         }
-        ResumeFormat::LinkedIn => {
+        (_, ResumeFormat::LinkedIn) => {
             error!("LinkedIn export parse failed: {err}");
             ApiError::new("Failed to parse LinkedIn export")
         }
-        ResumeFormat::Rrv3 => {
+        (_, ResumeFormat::Rrv3) => {
             error!("Reactive Resume v3 parse failed: {err}");
             ApiError::new("Failed to parse Reactive Resume v3 input")
         }
-        ResumeFormat::Rustume => {
+        (_, ResumeFormat::StackOverflow) => {
+            error!("Stack Overflow export parse failed: {err}");
+            ApiError::new("Failed to parse Stack Overflow export")
+        }
+        (_, ResumeFormat::Html) => {
+            error!("HTML page parse failed: {err}");
+            ApiError::new("Failed to parse HTML page: no hResume or schema.org Person markup")
+        }
+        (_, ResumeFormat::Vcard) => {
+            error!("vCard parse failed: {err}");
+            ApiError::new("Failed to parse vCard")
+        }
+        (_, ResumeFormat::Text) => {
+            error!("Plain text parse failed: {err}");
+            ApiError::new("Failed to parse plain text resume")
+        }
+        (_, ResumeFormat::Rustume) => {
             error!("Rustume JSON parse failed: {err}");
             ApiError::new("Failed to parse Rustume JSON input")
         }
-    })?;
+    }
+}
+
+/// Import a section from CSV
+///
+/// Appends the rows of a spreadsheet export to the skills, languages, or
+/// certifications section. Columns are matched by header name (`Skill`,
+/// `Level`, `Issued By`, ...), so their order doesn't matter; rows without a
+/// name, or with a name the section already has, are skipped.
+#[utoipa::path(
+    post,
+    path = "/api/parse/csv",
+    tag = "Parse",
+    request_body = CsvImportRequest,
+    responses(
+        (status = 200, description = "Resume with the imported items", body = CsvImportResponse),
+        (status = 400, description = "Invalid CSV or resume data", body = ApiError),
+        (status = 413, description = "CSV exceeds the row limit", body = ApiError)
+    )
+)]
+pub async fn parse_csv(
+    Json(req): Json<CsvImportRequest>,
+) -> Result<Json<CsvImportResponse>, ApiError> {
+    let mut resume = match req.resume {
+        Some(value) => {
+            validate_resume_json(&value)?;
+            serde_json::from_value(value)
+                .map_err(|_| ApiError::new("Invalid resume data format"))?
+        }
+        None => ResumeData::default(),
+    };
+
+    let section: CsvSection = req.section.into();
+    let imported =
+        import_section_csv(&mut resume, section, req.data.as_bytes()).map_err(|err| {
+            let code = err.code();
+            match err {
+                ParseError::LimitExceeded(reason) => {
+                    ApiError::payload_too_large(format!("Input exceeds import limits: {reason}"))
+                }
+                ParseError::ValidationError(reason) => ApiError::new(reason),
+                err => {
+                    error!("{section} CSV import failed: {err}");
+                    ApiError::new(format!("Failed to parse {section} CSV"))
+                }
+            }
+            .with_code(code)
+        })?;
 
-    Ok(Json(resume))
+    Ok(Json(CsvImportResponse { resume, imported }))
 }
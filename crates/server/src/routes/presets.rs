@@ -0,0 +1,79 @@
+use axum::{extract::Path, Json};
+use rustume_schema::{apply_preset, ContentPreset, LayoutPreset, ResumeData};
+use serde_json::Value;
+
+use crate::dto::ContentPresetInfo;
+use crate::error::ApiError;
+use crate::validation::validate_resume_json;
+
+/// List content presets
+///
+/// Pre-filled starting resumes for common professions, distinct from the
+/// visual templates. Fetch one with `GET /api/presets/{name}`.
+#[utoipa::path(
+    get,
+    path = "/api/presets",
+    tag = "Presets",
+    responses(
+        (status = 200, description = "Available content presets", body = Vec<ContentPresetInfo>)
+    )
+)]
+pub async fn list_content_presets() -> Json<Vec<ContentPresetInfo>> {
+    Json(ContentPreset::ALL.into_iter().map(Into::into).collect())
+}
+
+/// Get a content preset
+///
+/// A new resume pre-filled for a profession: a headline, a summary, and
+/// placeholder items in the sections it relies on, arranged with a fitting
+/// layout preset. Item IDs are fresh on every request.
+#[utoipa::path(
+    get,
+    path = "/api/presets/{name}",
+    tag = "Presets",
+    params(
+        ("name" = ContentPreset, Path, description = "Content preset name")
+    ),
+    responses(
+        (status = 200, description = "Pre-filled resume", body = ResumeData),
+        (status = 404, description = "Preset not found", body = ApiError)
+    )
+)]
+pub async fn get_content_preset(Path(name): Path<String>) -> Result<Json<ResumeData>, ApiError> {
+    let preset: ContentPreset = name.parse().map_err(ApiError::not_found)?;
+    Ok(Json(preset.resume()))
+}
+
+/// Apply a layout preset
+///
+/// Reorders the resume's sections and shows or hides a few of them in a curated style:
+/// `chronological` (work history first), `functional` (skills and projects first), `hybrid` (a
+/// skills summary, then work history), or `academic` (education and publications first). The
+/// layout becomes one page of a main column and a sidebar, which every template reads; custom
+/// sections keep their order at the end of the main column. Items are not changed.
+#[utoipa::path(
+    post,
+    path = "/api/presets/{name}/apply",
+    tag = "Presets",
+    params(
+        ("name" = LayoutPreset, Path, description = "Preset name")
+    ),
+    request_body = ResumeData,
+    responses(
+        (status = 200, description = "Resume with the preset applied", body = ResumeData),
+        (status = 400, description = "Invalid resume data", body = ApiError),
+        (status = 404, description = "Preset not found", body = ApiError)
+    )
+)]
+pub async fn apply_layout_preset(
+    Path(name): Path<String>,
+    Json(value): Json<Value>,
+) -> Result<Json<ResumeData>, ApiError> {
+    let preset: LayoutPreset = name.parse().map_err(ApiError::not_found)?;
+    validate_resume_json(&value)?;
+    let mut resume: ResumeData =
+        serde_json::from_value(value).map_err(|_| ApiError::new("Invalid resume data format"))?;
+
+    apply_preset(&mut resume, preset);
+    Ok(Json(resume))
+}
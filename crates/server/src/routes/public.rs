@@ -0,0 +1,217 @@
+//! Public, unauthenticated views of shared resumes.
+
+use axum::{
+    extract::{Path, State},
+    http::{header, HeaderMap, HeaderValue, StatusCode},
+    response::{IntoResponse, Response},
+};
+use chrono::{DateTime, Utc};
+use rustume_parser::to_json_resume;
+use rustume_render::Redaction;
+use rustume_schema::ResumeData;
+use tracing::error;
+
+use crate::error::ApiError;
+use crate::state::AppState;
+
+/// Bumped when the feed's shape changes, so cached copies are revalidated
+/// even if the resume itself didn't change.
+const FEED_FORMAT: u32 = 1;
+
+/// Shared resume fields the public feed needs.
+#[derive(Debug, sqlx::FromRow)]
+struct PublicResumeRow {
+    data: serde_json::Value,
+    version: i32,
+    updated_at: DateTime<Utc>,
+    public_exclude_sections: Vec<String>,
+    public_exclude_item_ids: Vec<String>,
+}
+
+/// Serve a shared resume as a JSON Resume document.
+///
+/// Only visible content is included, minus what the owner excluded from the
+/// public feed in the sharing settings. Responses carry an `ETag` and
+/// `Last-Modified`, and conditional requests get `304 Not Modified`.
+#[utoipa::path(
+    get,
+    path = "/u/{slug}/resume.json",
+    tag = "Public",
+    params(("slug" = String, Path, description = "Public share token of the resume")),
+    responses(
+        (status = 200, description = "JSON Resume document", content_type = "application/json"),
+        (status = 304, description = "Not modified since the cached copy"),
+        (status = 404, description = "Resume not found or not shared", body = ApiError),
+        (status = 429, description = "Rate limit exceeded"),
+    )
+)]
+pub async fn public_resume_json(
+    State(state): State<AppState>,
+    Path(slug): Path<String>,
+    headers: HeaderMap,
+) -> Result<Response, ApiError> {
+    let cloud = state.cloud()?;
+
+    // Password-protected shares have no way to pass the password here
+    let row = sqlx::query_as::<_, PublicResumeRow>(
+        r#"
+        SELECT data, version, updated_at, public_exclude_sections, public_exclude_item_ids
+        FROM resumes
+        WHERE public_slug = $1 AND is_public = true AND password_hash IS NULL
+          AND trashed_at IS NULL
+        "#,
+    )
+    .bind(&slug)
+    .fetch_optional(&cloud.db)
+    .await
+    .map_err(|err| {
+        error!("database error: {err}");
+        ApiError::internal("internal server error")
+    })?
+    .ok_or_else(|| ApiError::not_found("Resume not found"))?;
+
+    let etag = feed_etag(row.version, row.updated_at);
+    let last_modified = row
+        .updated_at
+        .format("%a, %d %b %Y %H:%M:%S GMT")
+        .to_string();
+    let cache_headers = [
+        (header::ETAG, header_value(&etag)),
+        (header::LAST_MODIFIED, header_value(&last_modified)),
+        (
+            header::CACHE_CONTROL,
+            HeaderValue::from_static("public, no-cache"),
+        ),
+        (
+            header::ACCESS_CONTROL_ALLOW_ORIGIN,
+            HeaderValue::from_static("*"),
+        ),
+    ];
+
+    if is_not_modified(&headers, &etag, row.updated_at) {
+        return Ok((StatusCode::NOT_MODIFIED, cache_headers).into_response());
+    }
+
+    let mut resume: ResumeData = serde_json::from_value(row.data)
+        .map_err(|_| ApiError::internal("Invalid resume data format"))?;
+    Redaction {
+        exclude_sections: row.public_exclude_sections,
+        exclude_item_ids: row.public_exclude_item_ids,
+    }
+    .apply(&mut resume);
+
+    let mut feed = to_json_resume(&resume);
+    feed["meta"]["version"] = format!("v{}", row.version).into();
+    feed["meta"]["lastModified"] = row.updated_at.to_rfc3339().into();
+
+    Ok((
+        StatusCode::OK,
+        cache_headers,
+        [(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("application/json"),
+        )],
+        feed.to_string(),
+    )
+        .into_response())
+}
+
+/// Strong validator for the feed. Every save bumps `version` and
+/// `updated_at`; sharing changes only touch the latter.
+fn feed_etag(version: i32, updated_at: DateTime<Utc>) -> String {
+    format!(
+        "\"{FEED_FORMAT}-{version}-{}\"",
+        updated_at.timestamp_millis()
+    )
+}
+
+fn header_value(value: &str) -> HeaderValue {
+    HeaderValue::from_str(value).expect("ETag and HTTP dates are ASCII")
+}
+
+/// Whether the client's cached copy is current (RFC 9110 §13.1).
+/// `If-None-Match` takes precedence; `If-Modified-Since` is compared at the
+/// second precision of HTTP dates.
+fn is_not_modified(headers: &HeaderMap, etag: &str, updated_at: DateTime<Utc>) -> bool {
+    if let Some(if_none_match) = headers.get(header::IF_NONE_MATCH) {
+        let Ok(if_none_match) = if_none_match.to_str() else {
+            return false;
+        };
+        return if_none_match.split(',').map(str::trim).any(|candidate| {
+            candidate == "*" || candidate.strip_prefix("W/").unwrap_or(candidate) == etag
+        });
+    }
+
+    headers
+        .get(header::IF_MODIFIED_SINCE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| DateTime::parse_from_rfc2822(value).ok())
+        .is_some_and(|since| updated_at.timestamp() <= since.timestamp())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn updated_at() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 3, 14, 9, 26, 53).unwrap() + chrono::Duration::milliseconds(589)
+    }
+
+    fn request_with(name: header::HeaderName, value: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(name, HeaderValue::from_str(value).unwrap());
+        headers
+    }
+
+    #[test]
+    fn etag_changes_with_version_and_update_time() {
+        let etag = feed_etag(3, updated_at());
+        assert!(etag.starts_with('"') && etag.ends_with('"'));
+        assert_ne!(etag, feed_etag(4, updated_at()));
+        assert_ne!(
+            etag,
+            feed_etag(3, updated_at() + chrono::Duration::milliseconds(1))
+        );
+    }
+
+    #[test]
+    fn if_none_match_accepts_lists_weak_tags_and_wildcard() {
+        let etag = feed_etag(3, updated_at());
+
+        let headers = request_with(header::IF_NONE_MATCH, &format!("\"stale\", W/{etag}"));
+        assert!(is_not_modified(&headers, &etag, updated_at()));
+
+        let headers = request_with(header::IF_NONE_MATCH, "*");
+        assert!(is_not_modified(&headers, &etag, updated_at()));
+
+        let headers = request_with(header::IF_NONE_MATCH, &feed_etag(2, updated_at()));
+        assert!(!is_not_modified(&headers, &etag, updated_at()));
+    }
+
+    #[test]
+    fn if_modified_since_compares_whole_seconds() {
+        let etag = feed_etag(3, updated_at());
+
+        let headers = request_with(header::IF_MODIFIED_SINCE, "Sat, 14 Mar 2026 09:26:53 GMT");
+        assert!(is_not_modified(&headers, &etag, updated_at()));
+
+        let headers = request_with(header::IF_MODIFIED_SINCE, "Sat, 14 Mar 2026 09:26:52 GMT");
+        assert!(!is_not_modified(&headers, &etag, updated_at()));
+
+        let headers = request_with(header::IF_MODIFIED_SINCE, "not a date");
+        assert!(!is_not_modified(&headers, &etag, updated_at()));
+        assert!(!is_not_modified(&HeaderMap::new(), &etag, updated_at()));
+    }
+
+    #[test]
+    fn if_none_match_takes_precedence_over_if_modified_since() {
+        let etag = feed_etag(3, updated_at());
+        let mut headers = request_with(header::IF_NONE_MATCH, "\"stale\"");
+        headers.insert(
+            header::IF_MODIFIED_SINCE,
+            HeaderValue::from_static("Sat, 14 Mar 2026 09:26:53 GMT"),
+        );
+        assert!(!is_not_modified(&headers, &etag, updated_at()));
+    }
+}
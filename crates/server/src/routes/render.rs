@@ -1,21 +1,49 @@
+use std::convert::Infallible;
+use std::sync::Arc;
+
 use axum::{
+    body::{Body, Bytes},
     extract::State,
     http::{header, HeaderValue, StatusCode},
-    response::{IntoResponse, Response},
+    response::{
+        sse::{Event, KeepAlive, Sse},
+        IntoResponse, Response,
+    },
     Json,
 };
-use rustume_render::Renderer;
-use rustume_schema::ResumeData;
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use rustume_render::{
+    layout_warnings, page_option_warnings, template_option_warnings, Redaction, RenderEngine,
+    RenderError, Renderer, SharedRenderer, TypstRenderer,
+};
+use rustume_schema::{FittedResume, ResumeData};
+use serde::Serialize;
+use sqlx::PgPool;
+use tokio::sync::mpsc;
+use tokio_stream::{wrappers::ReceiverStream, Stream, StreamExt};
+use tracing::error;
+use uuid::Uuid;
 use validator::Validate;
 
-use crate::dto::{RenderPdfRequest, RenderPreviewRequest};
+use crate::artifact_cache::{cached_render, ArtifactKey};
+use crate::dto::{
+    BinaryFile, FitRequest, RenderPdfRequest, RenderPreviewRequest, RenderStreamComplete,
+};
 use crate::error::ApiError;
+use crate::extract::ApiJson;
+use crate::middleware::auth::AuthUser;
+use crate::middleware::request_id::spawn_blocking_in_request;
+use crate::picture::embed_remote_picture;
 use crate::routes::validate::validation_errors;
 use crate::state::AppState;
 use crate::validation::validate_resume_json;
+use crate::webhooks::{self, ResumeEvent, WebhookEvent};
 
-/// Deserialize resume JSON, apply an optional template override, and validate.
-fn prepare_resume(
+/// Deserialize resume JSON, apply an optional template override, validate,
+/// and embed a remote profile picture. A template the deployment doesn't offer
+/// is a 403, whichever engine renders it.
+async fn prepare_resume(
+    state: &AppState,
     resume: serde_json::Value,
     template: Option<String>,
 ) -> Result<ResumeData, ApiError> {
@@ -26,51 +54,307 @@ fn prepare_resume(
     if let Some(template) = template {
         resume.metadata.template = template;
     }
+    state
+        .template_policy()
+        .check(&resume.metadata.template)
+        .map_err(|err| ApiError::forbidden(err.to_string()).with_code(err.code()))?;
 
     resume
         .validate()
         .map_err(|e| ApiError::with_details("Validation failed", validation_errors(&e)))?;
+    embed_remote_picture(&mut resume).await?;
 
     Ok(resume)
 }
 
+/// The `resume.rendered` webhook event for a render of one of the signed-in
+/// user's stored resumes, with the pool to queue it on.
+fn rendered_event(
+    state: &AppState,
+    user: Option<AuthUser>,
+    resume_id: Option<Uuid>,
+    resume: &ResumeData,
+) -> Option<(PgPool, ResumeEvent)> {
+    let (AuthUser(user), resume_id, cloud) = (user?, resume_id?, state.cloud.as_deref()?);
+    Some((
+        cloud.db.clone(),
+        ResumeEvent {
+            event: WebhookEvent::ResumeRendered,
+            user_id: user.id,
+            resume_id,
+            data: serde_json::json!({
+                "format": "pdf",
+                "template": resume.metadata.template,
+            }),
+        },
+    ))
+}
+
+/// The backend for `engine`, set up to apply the request's exclusions. Typst
+/// gets a copy of the shared renderer with the exclusions; other engines get
+/// `resume` with the exclusions already applied. An engine this server doesn't
+/// have is a 400.
+fn pdf_renderer(
+    state: &AppState,
+    engine: RenderEngine,
+    resume: &mut ResumeData,
+    exclude_sections: Vec<String>,
+    exclude_item_ids: Vec<String>,
+) -> Result<SharedRenderer, ApiError> {
+    let redaction = Redaction {
+        exclude_sections,
+        exclude_item_ids,
+    };
+    let unknown = redaction.unknown_sections(resume);
+    if !unknown.is_empty() {
+        return Err(ApiError::new(format!(
+            "Unknown sections to exclude: {}",
+            unknown.join(", ")
+        )));
+    }
+
+    if engine == RenderEngine::Typst {
+        if redaction.is_empty() {
+            return Ok(state.renderer.clone());
+        }
+        return Ok(Arc::new(
+            TypstRenderer::clone(&state.renderer).with_redaction(redaction),
+        ));
+    }
+    let renderer = state
+        .renderers
+        .get(Some(engine))
+        .map_err(|err| ApiError::new(err.to_string()).with_code(err.code()))?;
+    redaction.apply(resume);
+    Ok(renderer)
+}
+
+/// Log a failed render with the template and resume size, which is usually
+/// enough to reproduce it. Runs inside the request span, so the entry also
+/// carries the request ID.
+pub(crate) fn log_render_failure(artifact: &str, resume: &ResumeData, err: &dyn std::fmt::Display) {
+    let resume_bytes = serde_json::to_vec(resume).map_or(0, |json| json.len());
+    error!(
+        artifact,
+        template = %resume.metadata.template,
+        resume_bytes,
+        error = %err,
+        "Render failed"
+    );
+}
+
+/// Turn a render failure into a response. A resume over the render limits
+/// gets a 413 the client can act on; anything else is logged and reported as
+/// a server error after `context`.
+pub(crate) fn render_error(
+    artifact: &str,
+    resume: &ResumeData,
+    err: &RenderError,
+    context: &str,
+) -> ApiError {
+    match err {
+        RenderError::TooLarge { .. } | RenderError::LimitExceeded(_) => {
+            return ApiError::payload_too_large(err.to_string()).with_code(err.code())
+        }
+        RenderError::TemplateNotAllowed(_) => {
+            return ApiError::forbidden(err.to_string()).with_code(err.code())
+        }
+        _ => {}
+    }
+    log_render_failure(artifact, resume, err);
+    ApiError::internal(format!("{context}: {err}")).with_code(err.code())
+}
+
+/// Size of each body chunk when streaming a rendered PDF.
+const PDF_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Progress events buffered before the render task waits on a slow client.
+const PROGRESS_CHANNEL_CAPACITY: usize = 16;
+
+/// Stream PDF bytes in fixed-size chunks instead of one large frame.
+fn chunked_pdf_body(pdf: Vec<u8>) -> Body {
+    let pdf = Bytes::from(pdf);
+    let chunks = (0..pdf.len()).step_by(PDF_CHUNK_SIZE).map(move |start| {
+        let end = (start + PDF_CHUNK_SIZE).min(pdf.len());
+        Ok::<_, Infallible>(pdf.slice(start..end))
+    });
+    Body::from_stream(tokio_stream::iter(chunks))
+}
+
+/// Build a named SSE event with a JSON payload.
+fn json_event(name: &str, data: &impl Serialize) -> Event {
+    Event::default()
+        .event(name)
+        .json_data(data)
+        .unwrap_or_else(|err| {
+            let message = format!("Failed to encode {name} event: {err}");
+            Event::default()
+                .event("error")
+                .data(serde_json::json!({ "error": message }).to_string())
+        })
+}
+
 /// Render resume to PDF
 ///
-/// Generates a PDF document from the provided resume data using the specified template.
+/// Generates a PDF document from the provided resume data using the specified template. The
+/// optional `engine` picks the backend; an engine the server doesn't have is rejected with 400.
 #[utoipa::path(
     post,
     path = "/api/render/pdf",
     tag = "Render",
     request_body = RenderPdfRequest,
     responses(
-        (status = 200, description = "PDF document", content_type = "application/pdf"),
-        (status = 400, description = "Failed to render PDF", body = ApiError)
+        (status = 200, description = "PDF document", content_type = "application/pdf", body = inline(BinaryFile)),
+        (status = 400, description = "Failed to render PDF or engine unavailable", body = ApiError),
+        (status = 403, description = "Template not offered on this deployment", body = ApiError),
+        (status = 413, description = "Resume or picture too large to render", body = ApiError),
+        (status = 422, description = "Request body doesn't match `RenderPdfRequest`; `pointer` names the field", body = ApiError, example = json!({
+            "error": "Invalid value at `/template`: invalid type: integer `5`, expected a string",
+            "code": "INVALID_INPUT",
+            "pointer": "/template",
+            "expected": "a string"
+        })),
+        (status = 502, description = "Remote picture could not be downloaded", body = ApiError)
     )
 )]
 pub async fn render_pdf(
     State(state): State<AppState>,
-    Json(req): Json<RenderPdfRequest>,
+    user: Option<AuthUser>,
+    ApiJson(req): ApiJson<RenderPdfRequest>,
 ) -> Result<Response, ApiError> {
-    let resume = prepare_resume(req.resume, req.template)?;
-    let renderer = state.renderer.clone();
+    let mut resume = prepare_resume(&state, req.resume, req.template).await?;
+    let rendered = rendered_event(&state, user, req.resume_id, &resume);
+    let engine = state.renderers.resolve(req.engine.map(Into::into));
+    let key = ArtifactKey::pdf(
+        &resume,
+        engine,
+        &req.exclude_sections,
+        &req.exclude_item_ids,
+        state.renderer.branding(),
+    );
+    let renderer = pdf_renderer(
+        &state,
+        engine,
+        &mut resume,
+        req.exclude_sections,
+        req.exclude_item_ids,
+    )?;
+    let cache = state.artifact_cache.clone();
 
-    let pdf = tokio::task::spawn_blocking(move || {
-        renderer
-            .render_pdf(&resume)
-            .map_err(|err| format!("Failed to render PDF: {err}"))
+    let pdf = spawn_blocking_in_request(move || {
+        cached_render(cache.as_deref(), Some(&key), || {
+            renderer
+                .render_pdf(&resume)
+                .map_err(|err| render_error("pdf", &resume, &err, "Failed to render PDF"))
+        })
     })
     .await
-    .map_err(|err| ApiError::internal(format!("Render task failed: {err}")))?
-    .map_err(ApiError::internal)?;
+    .map_err(|err| ApiError::internal(format!("Render task failed: {err}")))??;
 
+    if let Some((db, event)) = rendered {
+        webhooks::enqueue(&db, event).await;
+    }
+
+    let content_length = pdf.len().to_string();
     Ok((
         StatusCode::OK,
-        [(header::CONTENT_TYPE, "application/pdf")],
-        pdf,
+        [
+            (header::CONTENT_TYPE, "application/pdf".to_string()),
+            (header::CONTENT_LENGTH, content_length),
+        ],
+        chunked_pdf_body(pdf),
     )
         .into_response())
 }
 
+/// Render resume to PDF with progress events
+///
+/// Sends Server-Sent Events while the PDF renders. It opens with a `warning` event for each
+/// part of the layout the template won't render as written. `progress` events carry a `stage`:
+/// `source_generated`, `compiled` with `total_pages`, `page_rendered` with `page` and
+/// `total_pages` for each page, `exporting`, and `done` with the PDF's `size`. The stream ends
+/// with a `complete` event holding the base64-encoded PDF, or an `error` event if rendering fails.
+#[utoipa::path(
+    post,
+    path = "/api/render/pdf/stream",
+    tag = "Render",
+    request_body = RenderPdfRequest,
+    responses(
+        (
+            status = 200,
+            description = "Progress events followed by the PDF",
+            content_type = "text/event-stream",
+            body = RenderStreamComplete
+        ),
+        (status = 400, description = "Invalid resume data or engine unavailable", body = ApiError),
+        (status = 403, description = "Template not offered on this deployment", body = ApiError),
+        (status = 422, description = "Request body doesn't match `RenderPdfRequest`; `pointer` names the field", body = ApiError, example = json!({
+            "error": "Invalid value at `/template`: invalid type: integer `5`, expected a string",
+            "code": "INVALID_INPUT",
+            "pointer": "/template",
+            "expected": "a string"
+        })),
+        (status = 502, description = "Remote picture could not be downloaded", body = ApiError)
+    )
+)]
+pub async fn render_pdf_stream(
+    State(state): State<AppState>,
+    user: Option<AuthUser>,
+    ApiJson(req): ApiJson<RenderPdfRequest>,
+) -> Result<Sse<impl Stream<Item = Result<Event, Infallible>>>, ApiError> {
+    let mut resume = prepare_resume(&state, req.resume, req.template).await?;
+    let rendered = rendered_event(&state, user, req.resume_id, &resume);
+    let engine = state.renderers.resolve(req.engine.map(Into::into));
+    let renderer = pdf_renderer(
+        &state,
+        engine,
+        &mut resume,
+        req.exclude_sections,
+        req.exclude_item_ids,
+    )?;
+    let (tx, rx) = mpsc::channel(PROGRESS_CHANNEL_CAPACITY);
+    let runtime = tokio::runtime::Handle::current();
+
+    spawn_blocking_in_request(move || {
+        // Send failures mean the client disconnected; the render still runs to completion.
+        let metadata = &resume.metadata;
+        let issues = layout_warnings(&metadata.template, &metadata.layout)
+            .into_iter()
+            .chain(template_option_warnings(
+                &metadata.template,
+                &metadata.template_options,
+            ))
+            .chain(page_option_warnings(&metadata.template, &metadata.page));
+        for issue in issues {
+            let _ = tx.blocking_send(json_event("warning", &issue));
+        }
+        let result = renderer.render_pdf_with_progress(&resume, &mut |progress| {
+            let _ = tx.blocking_send(json_event("progress", &progress));
+        });
+        let last = match result {
+            Ok(pdf) => {
+                if let Some((db, event)) = rendered {
+                    runtime.spawn(async move { webhooks::enqueue(&db, event).await });
+                }
+                json_event(
+                    "complete",
+                    &RenderStreamComplete {
+                        size: pdf.len(),
+                        pdf: BASE64.encode(&pdf),
+                    },
+                )
+            }
+            Err(err) => json_event(
+                "error",
+                &render_error("pdf", &resume, &err, "Failed to render PDF"),
+            ),
+        };
+        let _ = tx.blocking_send(last);
+    });
+
+    Ok(Sse::new(ReceiverStream::new(rx).map(Ok::<_, Infallible>)).keep_alive(KeepAlive::default()))
+}
+
 /// Render resume to PNG preview
 ///
 /// Generates a PNG image preview of a specific page from the resume.
@@ -80,28 +364,76 @@ pub async fn render_pdf(
     tag = "Render",
     request_body = RenderPreviewRequest,
     responses(
-        (status = 200, description = "PNG image preview", content_type = "image/png"),
-        (status = 400, description = "Failed to render preview", body = ApiError)
+        (status = 200, description = "PNG image preview", content_type = "image/png", body = inline(BinaryFile)),
+        (status = 400, description = "Failed to render preview", body = ApiError),
+        (status = 403, description = "Template not offered on this deployment", body = ApiError),
+        (status = 413, description = "Resume or picture too large to render", body = ApiError),
+        (status = 502, description = "Remote picture could not be downloaded", body = ApiError)
     )
 )]
 pub async fn render_preview(
     State(state): State<AppState>,
     Json(req): Json<RenderPreviewRequest>,
 ) -> Result<Response, ApiError> {
-    let resume = prepare_resume(req.resume, req.template)?;
+    let resume = prepare_resume(&state, req.resume, req.template).await?;
     let page = req.page;
     let renderer = state.renderer.clone();
 
-    let (png, total_pages) = tokio::task::spawn_blocking(move || {
+    let (png, total_pages) = spawn_blocking_in_request(move || {
         renderer
             .render_preview(&resume, page)
-            .map_err(|err| format!("Failed to render preview: {err}"))
+            .map_err(|err| render_error("preview", &resume, &err, "Failed to render preview"))
+    })
+    .await
+    .map_err(|err| ApiError::internal(format!("Render task failed: {err}")))??;
+
+    page_response("image/png", png, total_pages)
+}
+
+/// Render one resume page to SVG
+///
+/// Generates an SVG image of a specific page from the resume. Unlike the PNG preview it stays
+/// sharp at any zoom and on high-DPI screens.
+#[utoipa::path(
+    post,
+    path = "/api/render/svg",
+    tag = "Render",
+    request_body = RenderPreviewRequest,
+    responses(
+        (status = 200, description = "SVG image of the page", content_type = "image/svg+xml", body = String),
+        (status = 400, description = "Failed to render page", body = ApiError),
+        (status = 403, description = "Template not offered on this deployment", body = ApiError),
+        (status = 413, description = "Resume or picture too large to render", body = ApiError),
+        (status = 502, description = "Remote picture could not be downloaded", body = ApiError)
+    )
+)]
+pub async fn render_svg(
+    State(state): State<AppState>,
+    Json(req): Json<RenderPreviewRequest>,
+) -> Result<Response, ApiError> {
+    let resume = prepare_resume(&state, req.resume, req.template).await?;
+    let page = req.page;
+    let renderer = state.renderer.clone();
+
+    let (svg, total_pages) = spawn_blocking_in_request(move || {
+        renderer
+            .render_svg(&resume, page)
+            .map_err(|err| render_error("svg", &resume, &err, "Failed to render page"))
     })
     .await
-    .map_err(|err| ApiError::internal(format!("Render task failed: {err}")))?
-    .map_err(ApiError::internal)?;
+    .map_err(|err| ApiError::internal(format!("Render task failed: {err}")))??;
 
-    let mut response = (StatusCode::OK, [(header::CONTENT_TYPE, "image/png")], png).into_response();
+    page_response("image/svg+xml", svg, total_pages)
+}
+
+/// A rendered page with its `X-Total-Pages` header.
+fn page_response(
+    content_type: &'static str,
+    body: impl IntoResponse,
+    total_pages: usize,
+) -> Result<Response, ApiError> {
+    let mut response =
+        (StatusCode::OK, [(header::CONTENT_TYPE, content_type)], body).into_response();
     let total_pages_header = HeaderValue::from_str(&total_pages.to_string())
         .map_err(|err| ApiError::internal(format!("invalid X-Total-Pages header: {err}")))?;
     response
@@ -109,3 +441,73 @@ pub async fn render_preview(
         .insert("X-Total-Pages", total_pages_header);
     Ok(response)
 }
+
+/// Fit resume to a page budget
+///
+/// Finds the fewest changes that bring the resume down to `pages` pages: narrower margins, then a
+/// smaller font (neither below 10pt), then hiding items, oldest first, starting with interests and
+/// references and ending with experience. Each section keeps its newest item, and items tagged
+/// with a `keep` keyword are never hidden. Returns the fitted resume and the steps taken; when
+/// every cut together still runs long, all are applied and `report.fits` is false.
+#[utoipa::path(
+    post,
+    path = "/api/fit",
+    tag = "Render",
+    request_body = FitRequest,
+    responses(
+        (status = 200, description = "Fitted resume and the changes made", body = FittedResume),
+        (status = 400, description = "Invalid resume data or page budget", body = ApiError),
+        (status = 403, description = "Template not offered on this deployment", body = ApiError),
+        (status = 413, description = "Resume or picture too large to render", body = ApiError),
+        (status = 502, description = "Remote picture could not be downloaded", body = ApiError)
+    )
+)]
+pub async fn fit_pages(
+    State(state): State<AppState>,
+    Json(req): Json<FitRequest>,
+) -> Result<Json<FittedResume>, ApiError> {
+    if req.pages == 0 {
+        return Err(ApiError::new("The page budget must be at least one page"));
+    }
+    let resume = prepare_resume(&state, req.resume, req.template).await?;
+    let renderer = state.renderer.clone();
+
+    let fitted = spawn_blocking_in_request(move || {
+        renderer
+            .fit_to_pages(&resume, req.pages, &req.keep)
+            .map_err(|err| render_error("fit", &resume, &err, "Failed to fit resume"))
+    })
+    .await
+    .map_err(|err| ApiError::internal(format!("Render task failed: {err}")))??;
+
+    Ok(Json(fitted))
+}
+
+/// Render career timeline to SVG
+///
+/// Draws each visible position as a bar labeled with its company, with education shaded behind,
+/// on a shared year axis in the resume's theme colors. Ongoing positions run to today. Items
+/// whose dates cannot be read are left out.
+#[utoipa::path(
+    post,
+    path = "/api/render/timeline",
+    tag = "Render",
+    request_body = ResumeData,
+    responses(
+        (status = 200, description = "SVG timeline", content_type = "image/svg+xml", body = String),
+        (status = 400, description = "Invalid resume data", body = ApiError)
+    )
+)]
+pub async fn render_timeline(Json(value): Json<serde_json::Value>) -> Result<Response, ApiError> {
+    validate_resume_json(&value)?;
+    let resume: ResumeData =
+        serde_json::from_value(value).map_err(|_| ApiError::new("Invalid resume data format"))?;
+
+    let svg = rustume_parser::render_timeline(&resume);
+    Ok((
+        StatusCode::OK,
+        [(header::CONTENT_TYPE, "image/svg+xml")],
+        svg,
+    )
+        .into_response())
+}
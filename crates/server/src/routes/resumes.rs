@@ -5,21 +5,26 @@ use axum::{
     http::{HeaderMap, StatusCode},
     Json,
 };
+use rustume_render::Redaction;
+use rustume_schema::ResumeData;
 use tracing::error;
 use uuid::Uuid;
 
 use crate::audit::{record_event, record_event_required, AuditEvent};
 use crate::db::{
-    CreateResumeRequest, ImportFailure, ImportResumeItem, ImportResumesRequest,
-    ImportResumesResponse, PaginatedResumeSummaries, ResumeListQuery, ResumeRow, ResumeSummary,
-    SharingResponse, UpdateResumeRequest, UpdateSharingRequest,
+    CreateResumeRequest, DeleteResumeQuery, ImportFailure, ImportResumeItem, ImportResumesRequest,
+    ImportResumesResponse, PaginatedResumeSummaries, ResumeHistoryRow, ResumeListQuery, ResumeRow,
+    ResumeSummary, SharingResponse, TrashedResumeSummary, UpdateResumeRequest,
+    UpdateSharingRequest,
 };
 use crate::error::ApiError;
+use crate::history::{self, ResumeChange};
 use crate::middleware::auth::AuthUser;
 use crate::net::{self, trusted_client_ip};
 use crate::state::AppState;
 use crate::subscription;
 use crate::validation::{validate_resume_json, validate_title};
+use crate::webhooks::{self, ResumeEvent, WebhookEvent};
 
 /// List resumes for the authenticated user.
 #[utoipa::path(
@@ -47,7 +52,7 @@ pub async fn list_resumes(
         r#"
         SELECT COUNT(*)
         FROM resumes
-        WHERE user_id = $1
+        WHERE user_id = $1 AND trashed_at IS NULL
         "#,
     )
     .bind(user.id)
@@ -59,7 +64,7 @@ pub async fn list_resumes(
         r#"
         SELECT id, title, updated_at
         FROM resumes
-        WHERE user_id = $1
+        WHERE user_id = $1 AND trashed_at IS NULL
         ORDER BY updated_at DESC
         LIMIT $2 OFFSET $3
         "#,
@@ -143,6 +148,11 @@ pub async fn create_resume(
     .await
     .map_err(map_resume_db_error)?;
 
+    if let Some(change) = ResumeChange::saved(None, &row) {
+        history::record(&cloud.db, &user, row.id, change).await;
+    }
+    webhooks::enqueue(&cloud.db, saved_event(WebhookEvent::ResumeCreated, &row)).await;
+
     Ok((StatusCode::CREATED, Json(row)))
 }
 
@@ -175,7 +185,7 @@ pub async fn update_resume(
     let access = subscription::load_access(&cloud.db, user.id).await?;
     access.ensure_write()?;
     let existing = fetch_owned_resume(&state, user.id, id).await?;
-    let title = body.title.unwrap_or(existing.title);
+    let title = body.title.unwrap_or_else(|| existing.title.clone());
     validate_title(title.as_str())?;
     if let Some(data) = &body.data {
         validate_resume_json(data)?;
@@ -183,6 +193,11 @@ pub async fn update_resume(
 
     let row = apply_resume_update(&cloud.db, user.id, id, &title, body.data, body.version).await?;
 
+    if let Some(change) = ResumeChange::saved(Some(&existing), &row) {
+        history::record(&cloud.db, &user, id, change).await;
+    }
+    webhooks::enqueue(&cloud.db, saved_event(WebhookEvent::ResumeUpdated, &row)).await;
+
     Ok(Json(row))
 }
 
@@ -211,6 +226,23 @@ pub async fn update_sharing(
     let access = subscription::load_access(&cloud.db, user.id).await?;
     access.ensure_write()?;
 
+    if let Some(exclude_sections) = body.exclude_sections.as_ref().filter(|s| !s.is_empty()) {
+        let row = fetch_owned_resume(&state, user.id, id).await?;
+        let resume: ResumeData = serde_json::from_value(row.data)
+            .map_err(|_| ApiError::new("Invalid resume data format"))?;
+        let redaction = Redaction {
+            exclude_sections: exclude_sections.clone(),
+            exclude_item_ids: Vec::new(),
+        };
+        let unknown = redaction.unknown_sections(&resume);
+        if !unknown.is_empty() {
+            return Err(ApiError::new(format!(
+                "Unknown sections to exclude: {}",
+                unknown.join(", ")
+            )));
+        }
+    }
+
     let new_slug = if body.is_public {
         Some(generate_public_slug())
     } else {
@@ -218,8 +250,7 @@ pub async fn update_sharing(
     };
     let mut tx = cloud.db.begin().await.map_err(internal_db_error)?;
 
-    let sharing =
-        apply_sharing_update(&mut tx, user.id, id, body.is_public, new_slug.as_deref()).await?;
+    let sharing = apply_sharing_update(&mut tx, user.id, id, &body, new_slug.as_deref()).await?;
 
     let event_type = if body.is_public {
         "resume.publish"
@@ -236,6 +267,8 @@ pub async fn update_sharing(
             metadata: serde_json::json!({
                 "is_public": sharing.is_public,
                 "public_slug": sharing.public_slug,
+                "exclude_sections": sharing.exclude_sections,
+                "exclude_item_ids": sharing.exclude_item_ids,
             }),
             ip_address: trusted_client_ip(&headers, net::trusted_proxy_enabled()).as_deref(),
         },
@@ -245,17 +278,34 @@ pub async fn update_sharing(
 
     tx.commit().await.map_err(internal_db_error)?;
 
+    webhooks::enqueue(
+        &cloud.db,
+        ResumeEvent {
+            event: WebhookEvent::ResumeShared,
+            user_id: user.id,
+            resume_id: id,
+            data: serde_json::json!({
+                "is_public": sharing.is_public,
+                "public_slug": sharing.public_slug,
+            }),
+        },
+    )
+    .await;
+
     Ok(Json(sharing))
 }
 
 /// Delete a resume owned by the authenticated user.
+///
+/// The resume moves to the trash, where it can be restored until the purge
+/// task removes it. `?permanent=true` deletes it immediately, trashed or not.
 #[utoipa::path(
     delete,
     path = "/api/resumes/{id}",
     tag = "Resumes",
-    params(("id" = String, Path, description = "Resume ID")),
+    params(("id" = String, Path, description = "Resume ID"), DeleteResumeQuery),
     responses(
-        (status = 204, description = "Resume deleted"),
+        (status = 204, description = "Resume moved to the trash or deleted"),
         (status = 401, description = "Not authenticated", body = ApiError),
         (status = 404, description = "Resume not found", body = ApiError),
     ),
@@ -265,22 +315,34 @@ pub async fn delete_resume(
     AuthUser(user): AuthUser,
     State(state): State<AppState>,
     Path(id): Path<Uuid>,
+    Query(query): Query<DeleteResumeQuery>,
     headers: HeaderMap,
 ) -> Result<StatusCode, ApiError> {
     let cloud = state.cloud()?;
     let access = subscription::load_access(&cloud.db, user.id).await?;
     access.ensure_delete()?;
-    let result = sqlx::query("DELETE FROM resumes WHERE id = $1 AND user_id = $2")
+    let sql = if query.permanent {
+        "DELETE FROM resumes WHERE id = $1 AND user_id = $2 RETURNING trashed_at IS NOT NULL"
+    } else {
+        r#"
+        UPDATE resumes
+        SET trashed_at = now()
+        WHERE id = $1 AND user_id = $2 AND trashed_at IS NULL
+        RETURNING false
+        "#
+    };
+    let was_trashed = sqlx::query_scalar::<_, bool>(sql)
         .bind(id)
         .bind(user.id)
-        .execute(&cloud.db)
+        .fetch_optional(&cloud.db)
         .await
-        .map_err(internal_db_error)?;
+        .map_err(internal_db_error)?
+        .ok_or_else(|| ApiError::not_found("Resume not found"))?;
 
-    if result.rows_affected() == 0 {
-        return Err(ApiError::not_found("Resume not found"));
+    // Purging from the trash already logged the deletion
+    if !was_trashed {
+        history::record(&cloud.db, &user, id, ResumeChange::deleted()).await;
     }
-
     record_event(
         &cloud.db,
         AuditEvent {
@@ -288,7 +350,7 @@ pub async fn delete_resume(
             actor_user_id: Some(user.id),
             resource_type: Some("resume"),
             resource_id: Some(id),
-            metadata: serde_json::json!({}),
+            metadata: serde_json::json!({ "permanent": query.permanent }),
             ip_address: trusted_client_ip(&headers, net::trusted_proxy_enabled()).as_deref(),
         },
     )
@@ -297,6 +359,141 @@ pub async fn delete_resume(
     Ok(StatusCode::NO_CONTENT)
 }
 
+/// List the authenticated user's trashed resumes, most recently deleted first.
+#[utoipa::path(
+    get,
+    path = "/api/resumes/trash",
+    tag = "Resumes",
+    responses(
+        (status = 200, description = "Trashed resumes with their purge time", body = [TrashedResumeSummary]),
+        (status = 401, description = "Not authenticated", body = ApiError),
+    ),
+    security(("cookieAuth" = []))
+)]
+pub async fn list_trashed_resumes(
+    AuthUser(user): AuthUser,
+    State(state): State<AppState>,
+) -> Result<Json<Vec<TrashedResumeSummary>>, ApiError> {
+    let cloud = state.cloud()?;
+    let access = subscription::load_access(&cloud.db, user.id).await?;
+    access.ensure_read()?;
+
+    let rows = sqlx::query_as::<_, TrashedResumeSummary>(
+        r#"
+        SELECT id, title, trashed_at, trashed_at + make_interval(days => $2) AS purge_at
+        FROM resumes
+        WHERE user_id = $1 AND trashed_at IS NOT NULL
+        ORDER BY trashed_at DESC, id
+        "#,
+    )
+    .bind(user.id)
+    .bind(i32::try_from(cloud.trash_retention_days).unwrap_or(i32::MAX))
+    .fetch_all(&cloud.db)
+    .await
+    .map_err(internal_db_error)?;
+
+    Ok(Json(rows))
+}
+
+/// Restore a trashed resume owned by the authenticated user.
+#[utoipa::path(
+    post,
+    path = "/api/resumes/{id}/restore",
+    tag = "Resumes",
+    params(("id" = String, Path, description = "Resume ID")),
+    responses(
+        (status = 200, description = "Resume restored", body = ResumeRow),
+        (status = 401, description = "Not authenticated", body = ApiError),
+        (status = 404, description = "Resume not in the trash", body = ApiError),
+    ),
+    security(("cookieAuth" = []))
+)]
+pub async fn restore_resume(
+    AuthUser(user): AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    headers: HeaderMap,
+) -> Result<Json<ResumeRow>, ApiError> {
+    let cloud = state.cloud()?;
+    let access = subscription::load_access(&cloud.db, user.id).await?;
+    access.ensure_write()?;
+
+    let row = sqlx::query_as::<_, ResumeRow>(
+        r#"
+        UPDATE resumes
+        SET trashed_at = NULL
+        WHERE id = $1 AND user_id = $2 AND trashed_at IS NOT NULL
+        RETURNING id, user_id, title, data, is_public, public_slug, password_hash, version, created_at, updated_at
+        "#,
+    )
+    .bind(id)
+    .bind(user.id)
+    .fetch_optional(&cloud.db)
+    .await
+    .map_err(internal_db_error)?
+    .ok_or_else(|| ApiError::not_found("Resume not in the trash"))?;
+
+    history::record(&cloud.db, &user, id, ResumeChange::restored()).await;
+    record_event(
+        &cloud.db,
+        AuditEvent {
+            event_type: "resume.restore",
+            actor_user_id: Some(user.id),
+            resource_type: Some("resume"),
+            resource_id: Some(id),
+            metadata: serde_json::json!({}),
+            ip_address: trusted_client_ip(&headers, net::trusted_proxy_enabled()).as_deref(),
+        },
+    )
+    .await;
+
+    Ok(Json(row))
+}
+
+/// Change log of a resume owned by the authenticated user, oldest first.
+#[utoipa::path(
+    get,
+    path = "/api/resumes/{id}/history",
+    tag = "Resumes",
+    params(("id" = String, Path, description = "Resume ID")),
+    responses(
+        (status = 200, description = "History entries, oldest first", body = [ResumeHistoryRow]),
+        (status = 401, description = "Not authenticated", body = ApiError),
+        (status = 404, description = "Resume not found", body = ApiError),
+    ),
+    security(("cookieAuth" = []))
+)]
+pub async fn resume_history(
+    AuthUser(user): AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<Vec<ResumeHistoryRow>>, ApiError> {
+    let cloud = state.cloud()?;
+    let access = subscription::load_access(&cloud.db, user.id).await?;
+    access.ensure_read()?;
+
+    let rows = sqlx::query_as::<_, ResumeHistoryRow>(
+        r#"
+        SELECT resume_id, author, action, summary, at
+        FROM resume_history
+        WHERE user_id = $1 AND resume_id = $2
+        ORDER BY id
+        "#,
+    )
+    .bind(user.id)
+    .bind(id)
+    .fetch_all(&cloud.db)
+    .await
+    .map_err(internal_db_error)?;
+
+    // Resumes saved before history was kept have none; anything else is 404
+    if rows.is_empty() {
+        fetch_owned_resume(&state, user.id, id).await?;
+    }
+
+    Ok(Json(rows))
+}
+
 const MAX_IMPORT_BATCH: usize = 100;
 
 /// Import local resumes into the authenticated user's cloud account.
@@ -346,8 +543,33 @@ pub async fn import_resumes(
             });
             continue;
         }
+        let previous = match resume_id {
+            Some(id) => match find_owned_resume(&cloud.db, user.id, id).await {
+                Ok(previous) => previous,
+                Err(err) => {
+                    failed.push(ImportFailure {
+                        id: resume_id,
+                        error: err.error,
+                    });
+                    continue;
+                }
+            },
+            None => None,
+        };
         match import_single_resume(&cloud.db, user.id, item).await {
-            Ok(row) => imported.push(ResumeSummary::from(row)),
+            Ok(row) => {
+                if let Some(change) = ResumeChange::saved(previous.as_ref(), &row) {
+                    history::record(&cloud.db, &user, row.id, change).await;
+                }
+                // Imports upsert; a row whose timestamps differ already existed
+                let event = if row.created_at == row.updated_at {
+                    WebhookEvent::ResumeCreated
+                } else {
+                    WebhookEvent::ResumeUpdated
+                };
+                webhooks::enqueue(&cloud.db, saved_event(event, &row)).await;
+                imported.push(ResumeSummary::from(row));
+            }
             Err(err) => failed.push(ImportFailure {
                 id: resume_id,
                 error: err.error,
@@ -390,6 +612,7 @@ async fn import_single_resume(
         ON CONFLICT (id) DO UPDATE SET
             title = EXCLUDED.title,
             data = EXCLUDED.data,
+            trashed_at = NULL,
             updated_at = now()
         WHERE resumes.user_id = EXCLUDED.user_id
         RETURNING id, user_id, title, data, is_public, public_slug, password_hash, version, created_at, updated_at
@@ -404,6 +627,16 @@ async fn import_single_resume(
     .map_err(|err| map_import_db_error(err, resume_id))
 }
 
+/// Webhook event for a resume that was just written.
+fn saved_event(event: WebhookEvent, row: &ResumeRow) -> ResumeEvent {
+    ResumeEvent {
+        event,
+        user_id: row.user_id,
+        resume_id: row.id,
+        data: serde_json::json!({ "title": row.title, "version": row.version }),
+    }
+}
+
 fn internal_db_error(err: impl std::fmt::Display + Send + Sync + 'static) -> ApiError {
     error!("database error: {err}");
     ApiError::internal("internal server error")
@@ -447,7 +680,7 @@ async fn apply_resume_update(
                     data = $2,
                     version = version + 1,
                     updated_at = now()
-                WHERE id = $3 AND user_id = $4 AND version = $5
+                WHERE id = $3 AND user_id = $4 AND version = $5 AND trashed_at IS NULL
                 RETURNING id, user_id, title, data, is_public, public_slug, password_hash, version, created_at, updated_at
                 "#,
             )
@@ -467,7 +700,7 @@ async fn apply_resume_update(
                     data = $2,
                     version = version + 1,
                     updated_at = now()
-                WHERE id = $3 AND user_id = $4
+                WHERE id = $3 AND user_id = $4 AND trashed_at IS NULL
                 RETURNING id, user_id, title, data, is_public, public_slug, password_hash, version, created_at, updated_at
                 "#,
             )
@@ -485,7 +718,7 @@ async fn apply_resume_update(
                 SET title = $1,
                     version = version + 1,
                     updated_at = now()
-                WHERE id = $2 AND user_id = $3 AND version = $4
+                WHERE id = $2 AND user_id = $3 AND version = $4 AND trashed_at IS NULL
                 RETURNING id, user_id, title, data, is_public, public_slug, password_hash, version, created_at, updated_at
                 "#,
             )
@@ -503,7 +736,7 @@ async fn apply_resume_update(
                 SET title = $1,
                     version = version + 1,
                     updated_at = now()
-                WHERE id = $2 AND user_id = $3
+                WHERE id = $2 AND user_id = $3 AND trashed_at IS NULL
                 RETURNING id, user_id, title, data, is_public, public_slug, password_hash, version, created_at, updated_at
                 "#,
             )
@@ -532,7 +765,7 @@ async fn map_update_miss(
         r#"
         SELECT version
         FROM resumes
-        WHERE id = $1 AND user_id = $2
+        WHERE id = $1 AND user_id = $2 AND trashed_at IS NULL
         "#,
     )
     .bind(resume_id)
@@ -554,6 +787,8 @@ async fn map_update_miss(
 struct SharingRow {
     is_public: bool,
     public_slug: Option<String>,
+    public_exclude_sections: Vec<String>,
+    public_exclude_item_ids: Vec<String>,
 }
 
 fn generate_public_slug() -> String {
@@ -571,9 +806,10 @@ async fn apply_sharing_update(
     db: &mut sqlx::PgConnection,
     user_id: Uuid,
     resume_id: Uuid,
-    is_public: bool,
+    update: &UpdateSharingRequest,
     new_slug: Option<&str>,
 ) -> Result<SharingResponse, ApiError> {
+    let is_public = update.is_public;
     let mut slug = new_slug.map(str::to_string);
 
     for attempt in 0..2 {
@@ -585,15 +821,19 @@ async fn apply_sharing_update(
                     WHEN $3 = true AND public_slug IS NULL THEN $4
                     ELSE public_slug
                 END,
+                public_exclude_sections = COALESCE($5, public_exclude_sections),
+                public_exclude_item_ids = COALESCE($6, public_exclude_item_ids),
                 updated_at = now()
-            WHERE id = $1 AND user_id = $2
-            RETURNING is_public, public_slug
+            WHERE id = $1 AND user_id = $2 AND trashed_at IS NULL
+            RETURNING is_public, public_slug, public_exclude_sections, public_exclude_item_ids
             "#,
         )
         .bind(resume_id)
         .bind(user_id)
         .bind(is_public)
         .bind(slug.as_deref())
+        .bind(update.exclude_sections.as_deref())
+        .bind(update.exclude_item_ids.as_deref())
         .fetch_optional(&mut *db)
         .await;
 
@@ -602,6 +842,8 @@ async fn apply_sharing_update(
                 return Ok(SharingResponse {
                     is_public: row.is_public,
                     public_slug: row.public_slug,
+                    exclude_sections: row.public_exclude_sections,
+                    exclude_item_ids: row.public_exclude_item_ids,
                 });
             }
             Ok(None) => return Err(ApiError::not_found("Resume not found")),
@@ -621,19 +863,28 @@ async fn fetch_owned_resume(
     resume_id: Uuid,
 ) -> Result<ResumeRow, ApiError> {
     let cloud = state.cloud()?;
+    find_owned_resume(&cloud.db, user_id, resume_id)
+        .await?
+        .ok_or_else(|| ApiError::not_found("Resume not found"))
+}
+
+async fn find_owned_resume(
+    db: &sqlx::PgPool,
+    user_id: Uuid,
+    resume_id: Uuid,
+) -> Result<Option<ResumeRow>, ApiError> {
     sqlx::query_as::<_, ResumeRow>(
         r#"
         SELECT id, user_id, title, data, is_public, public_slug, password_hash, version, created_at, updated_at
         FROM resumes
-        WHERE id = $1 AND user_id = $2
+        WHERE id = $1 AND user_id = $2 AND trashed_at IS NULL
         "#,
     )
     .bind(resume_id)
     .bind(user_id)
-    .fetch_optional(&cloud.db)
+    .fetch_optional(db)
     .await
-    .map_err(internal_db_error)?
-    .ok_or_else(|| ApiError::not_found("Resume not found"))
+    .map_err(internal_db_error)
 }
 
 #[cfg(test)]
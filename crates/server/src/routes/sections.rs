@@ -0,0 +1,94 @@
+use axum::{
+    http::{header, HeaderValue, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use rustume_parser::{section_table, ParseError};
+use rustume_schema::ResumeData;
+use tracing::error;
+
+use crate::dto::{BinaryFile, SectionExportFormat, SectionExportRequest};
+use crate::error::ApiError;
+use crate::validation::validate_resume_json;
+
+/// Export a section as a spreadsheet
+///
+/// Writes the items of one section (`experience`, `skills`, ... or `custom.<id>`) as CSV or as an
+/// XLSX workbook, one row per item and one column per field. Links are written as their address
+/// and rich-text summaries as plain text. Skills, languages, and certifications exported as CSV
+/// can be edited and imported again with `POST /api/parse/csv`.
+#[utoipa::path(
+    post,
+    path = "/api/export/section",
+    tag = "Export",
+    request_body = SectionExportRequest,
+    responses(
+        (status = 200, description = "CSV file or XLSX workbook, per `format`", content(
+            (inline(BinaryFile) = "text/csv"),
+            (inline(BinaryFile) = "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet")
+        )),
+        (status = 400, description = "Invalid resume data or unknown section", body = ApiError)
+    )
+)]
+pub async fn export_section(Json(req): Json<SectionExportRequest>) -> Result<Response, ApiError> {
+    validate_resume_json(&req.resume)?;
+    let resume: ResumeData = serde_json::from_value(req.resume)
+        .map_err(|_| ApiError::new("Invalid resume data format"))?;
+
+    let table = section_table(&resume, &req.section).map_err(|err| {
+        let code = err.code();
+        match err {
+            ParseError::ValidationError(reason) => ApiError::new(reason),
+            err => {
+                error!("section table for {} failed: {err}", req.section);
+                ApiError::internal("Failed to export section")
+            }
+        }
+        .with_code(code)
+    })?;
+    let (content_type, extension, bytes) = match req.format {
+        SectionExportFormat::Csv => (
+            "text/csv; charset=utf-8",
+            "csv",
+            table.to_csv().map(String::into_bytes),
+        ),
+        SectionExportFormat::Xlsx => (
+            "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+            "xlsx",
+            table.to_xlsx(),
+        ),
+    };
+    let bytes = bytes.map_err(|err| {
+        error!("{extension} export of {} failed: {err}", req.section);
+        ApiError::internal("Failed to export section")
+    })?;
+
+    let content_disposition = HeaderValue::from_str(&format!(
+        "attachment; filename=\"{}.{extension}\"",
+        file_stem(&req.section)
+    ))
+    .map_err(|err| ApiError::internal(format!("invalid Content-Disposition header: {err}")))?;
+    Ok((
+        StatusCode::OK,
+        [
+            (header::CONTENT_TYPE, HeaderValue::from_static(content_type)),
+            (header::CONTENT_DISPOSITION, content_disposition),
+        ],
+        bytes,
+    )
+        .into_response())
+}
+
+/// File name for a section key, keeping only characters safe in a header.
+fn file_stem(section: &str) -> String {
+    section
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '-'
+            }
+        })
+        .collect()
+}
@@ -9,10 +9,38 @@ use crate::config::DEFAULT_STATIC_DIR;
 use crate::error::ApiError;
 use crate::state::AppState;
 
+/// Command-line flag naming the built web app directory.
+pub const SERVE_WEB_FLAG: &str = "--serve-web";
+
+/// Directory the web app is served from: `--serve-web <dir>`, then
+/// `RUSTUME_STATIC_DIR`, then `SERVE_STATIC_DIR`, then [`DEFAULT_STATIC_DIR`].
 pub fn static_dir() -> PathBuf {
-    std::env::var("RUSTUME_STATIC_DIR")
-        .map(PathBuf::from)
-        .unwrap_or_else(|_| PathBuf::from(DEFAULT_STATIC_DIR))
+    serve_web_arg(std::env::args())
+        .or_else(|| std::env::var_os("RUSTUME_STATIC_DIR").map(PathBuf::from))
+        .or_else(|| std::env::var_os("SERVE_STATIC_DIR").map(PathBuf::from))
+        .unwrap_or_else(|| PathBuf::from(DEFAULT_STATIC_DIR))
+}
+
+/// The directory passed as `--serve-web <dir>` or `--serve-web=<dir>`.
+pub fn serve_web_arg(args: impl IntoIterator<Item = String>) -> Option<PathBuf> {
+    path_arg(args, SERVE_WEB_FLAG)
+}
+
+/// The path passed as `<flag> <path>` or `<flag>=<path>`.
+pub(crate) fn path_arg(args: impl IntoIterator<Item = String>, flag: &str) -> Option<PathBuf> {
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        if arg == flag {
+            return args.next().map(PathBuf::from);
+        }
+        if let Some(path) = arg
+            .strip_prefix(flag)
+            .and_then(|rest| rest.strip_prefix('='))
+        {
+            return Some(PathBuf::from(path));
+        }
+    }
+    None
 }
 
 fn is_reserved_server_path(path: &str) -> bool {
@@ -51,17 +79,25 @@ fn content_type(path: &FsPath) -> &'static str {
     match path.extension().and_then(|extension| extension.to_str()) {
         Some("css") => "text/css; charset=utf-8",
         Some("html") => "text/html; charset=utf-8",
-        Some("js") => "text/javascript; charset=utf-8",
-        Some("json") => "application/json; charset=utf-8",
+        Some("ico") => "image/x-icon",
+        Some("jpg" | "jpeg") => "image/jpeg",
+        Some("js" | "mjs") => "text/javascript; charset=utf-8",
+        Some("json" | "map") => "application/json; charset=utf-8",
         Some("png") => "image/png",
         Some("svg") => "image/svg+xml",
+        Some("txt") => "text/plain; charset=utf-8",
         Some("wasm") => "application/wasm",
         Some("webmanifest") => "application/manifest+json; charset=utf-8",
+        Some("webp") => "image/webp",
+        Some("woff") => "font/woff",
         Some("woff2") => "font/woff2",
+        Some("xml") => "application/xml; charset=utf-8",
         _ => "application/octet-stream",
     }
 }
 
+/// Vite fingerprints everything under `assets/`, so those never change under
+/// the same name; anything else, `index.html` above all, is revalidated.
 fn cache_control(path: &FsPath) -> &'static str {
     if path.starts_with("assets") {
         "public, max-age=31536000, immutable"
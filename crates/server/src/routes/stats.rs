@@ -0,0 +1,31 @@
+use axum::Json;
+use rustume_schema::{ResumeData, ResumeStats};
+use serde_json::Value;
+
+use crate::error::ApiError;
+use crate::validation::validate_resume_json;
+
+/// Compute resume statistics
+///
+/// Returns the duration of each visible position and the total years of
+/// experience, with overlapping positions counted once. Ongoing positions
+/// run to today. Positions whose dates cannot be read are left out. Gaps of
+/// six months or more between experience, education, and volunteer entries
+/// are listed too; use `/api/analyze` for a different threshold.
+#[utoipa::path(
+    post,
+    path = "/api/stats",
+    tag = "Stats",
+    request_body = ResumeData,
+    responses(
+        (status = 200, description = "Resume statistics", body = ResumeStats),
+        (status = 400, description = "Invalid resume data", body = ApiError)
+    )
+)]
+pub async fn stats(Json(value): Json<Value>) -> Result<Json<ResumeStats>, ApiError> {
+    validate_resume_json(&value)?;
+    let resume: ResumeData =
+        serde_json::from_value(value).map_err(|_| ApiError::new("Invalid resume data format"))?;
+
+    Ok(Json(resume.stats()))
+}
@@ -0,0 +1,32 @@
+use axum::Json;
+use rustume_schema::ResumeData;
+use serde_json::Value;
+
+use crate::dto::SuggestResponse;
+use crate::error::ApiError;
+use crate::validation::validate_resume_json;
+
+/// Suggest improvements to experience bullet points
+///
+/// Runs offline, rule-based checks over each experience summary: weak opening
+/// verbs, passive voice, missing numbers, and bullet length. Suggestions are
+/// hints only and do not depend on whether the resume validates.
+#[utoipa::path(
+    post,
+    path = "/api/suggest",
+    tag = "Suggest",
+    request_body = ResumeData,
+    responses(
+        (status = 200, description = "Content suggestions", body = SuggestResponse),
+        (status = 400, description = "Invalid resume data", body = ApiError)
+    )
+)]
+pub async fn suggest(Json(value): Json<Value>) -> Result<Json<SuggestResponse>, ApiError> {
+    validate_resume_json(&value)?;
+    let resume: ResumeData =
+        serde_json::from_value(value).map_err(|_| ApiError::new("Invalid resume data format"))?;
+
+    Ok(Json(SuggestResponse {
+        suggestions: resume.suggestions(),
+    }))
+}
@@ -1,23 +1,38 @@
 use axum::{
     extract::{Path, State},
-    http::{header, StatusCode},
+    http::{header, HeaderMap, StatusCode},
     response::{IntoResponse, Response},
-    Json,
 };
 use lru::LruCache;
-use rustume_render::{get_template_theme, Renderer, TEMPLATES};
+use rustume_render::{
+    get_template_theme, template_options, Renderer, TemplatePolicy, TypstRenderer,
+};
 use rustume_schema::ResumeData;
 use std::num::NonZeroUsize;
-use std::sync::OnceLock;
-use tokio::sync::Mutex as AsyncMutex;
+use std::sync::{Arc, OnceLock};
+use std::time::Instant;
+use tokio::sync::{Mutex as AsyncMutex, Semaphore};
+use tokio::task::JoinSet;
+use tracing::{info, warn};
 
-use crate::dto::{TemplateInfo, ThemeInfo};
+use crate::artifact_cache::{cached_render, ArtifactCache, ArtifactKey};
+use crate::dto::{BinaryFile, TemplateInfo, TemplateOptionsInfo, TemplatePath, ThemeInfo};
 use crate::error::ApiError;
+use crate::middleware::request_id::spawn_blocking_in_request;
+use crate::routes::render::log_render_failure;
 use crate::state::AppState;
 
 /// Maximum number of template thumbnails to cache
 const THUMBNAIL_CACHE_CAPACITY: usize = 32;
 
+/// Drop every cached thumbnail, so the next requests render them again.
+///
+/// Blocks on the cache lock; call it outside the async runtime.
+#[cfg(feature = "dev")]
+pub(crate) fn clear_thumbnail_cache() {
+    thumbnail_cache().blocking_lock().clear();
+}
+
 /// Cache for rendered template thumbnails (keyed by template name, bounded LRU)
 fn thumbnail_cache() -> &'static AsyncMutex<LruCache<String, Vec<u8>>> {
     static CACHE: OnceLock<AsyncMutex<LruCache<String, Vec<u8>>>> = OnceLock::new();
@@ -102,20 +117,31 @@ fn create_sample_resume() -> ResumeData {
 
 /// List available templates
 ///
-/// Returns a list of all available resume templates with their theme colors.
+/// Returns the resume templates this deployment offers with their theme
+/// colors and the template options each one honors. `RUSTUME_TEMPLATES_ALLOW`
+/// and `RUSTUME_TEMPLATES_DENY` narrow the list. It is built once and served
+/// with an `ETag`, so polling clients can revalidate for a `304`.
 #[utoipa::path(
     get,
     path = "/api/templates",
     tag = "Templates",
     responses(
-        (status = 200, description = "List of available templates", body = Vec<TemplateInfo>)
+        (status = 200, description = "List of available templates", body = Vec<TemplateInfo>),
+        (status = 304, description = "Not modified since the cached copy")
     )
 )]
-pub async fn list_templates() -> Json<Vec<TemplateInfo>> {
-    let templates: Vec<TemplateInfo> = TEMPLATES
-        .iter()
+pub async fn list_templates(State(state): State<AppState>, headers: HeaderMap) -> Response {
+    state.templates_payload().respond(&headers)
+}
+
+/// Details of the templates `policy` offers.
+pub fn template_list(policy: &TemplatePolicy) -> Vec<TemplateInfo> {
+    policy
+        .templates()
+        .into_iter()
         .map(|name| {
             let theme = get_template_theme(name);
+            let options = template_options(name);
             // Capitalize first letter for display name
             let display_name = {
                 let mut chars = name.chars();
@@ -132,11 +158,14 @@ pub async fn list_templates() -> Json<Vec<TemplateInfo>> {
                     text: theme.text,
                     primary: theme.primary,
                 },
+                options: TemplateOptionsInfo {
+                    photo: options.photo.to_vec(),
+                    header_align: options.header_align,
+                    divider: options.divider,
+                },
             }
         })
-        .collect();
-
-    Json(templates)
+        .collect()
 }
 
 /// Get template thumbnail
@@ -146,20 +175,18 @@ pub async fn list_templates() -> Json<Vec<TemplateInfo>> {
     get,
     path = "/api/templates/{id}/thumbnail",
     tag = "Templates",
-    params(
-        ("id" = String, Path, description = "Template ID")
-    ),
+    params(TemplatePath),
     responses(
-        (status = 200, description = "PNG thumbnail image", content_type = "image/png"),
-        (status = 404, description = "Template not found", body = ApiError)
+        (status = 200, description = "PNG thumbnail image", content_type = "image/png", body = inline(BinaryFile)),
+        (status = 404, description = "Template not found or not offered", body = ApiError)
     )
 )]
 pub async fn template_thumbnail(
     State(state): State<AppState>,
-    Path(id): Path<String>,
+    Path(TemplatePath { id }): Path<TemplatePath>,
 ) -> Result<Response, ApiError> {
-    // Verify template exists
-    if !TEMPLATES.contains(&id.as_str()) {
+    // Verify the template exists and this deployment offers it
+    if !state.template_policy().allows(&id) {
         return Err(ApiError::not_found(format!("Template '{}' not found", id)));
     }
 
@@ -180,23 +207,7 @@ pub async fn template_thumbnail(
             .into_response());
     }
 
-    // Render thumbnail with sample data
-    let mut resume = create_sample_resume();
-    resume.metadata.template = id.clone();
-    let theme = get_template_theme(&id);
-    resume.metadata.theme.primary = theme.primary.clone();
-    resume.metadata.theme.text = theme.text.clone();
-    resume.metadata.theme.background = theme.background.clone();
-
-    let renderer = state.renderer.clone();
-    let (png, _total_pages) = tokio::task::spawn_blocking(move || {
-        renderer
-            .render_preview(&resume, 0)
-            .map_err(|e| format!("Failed to render thumbnail: {e}"))
-    })
-    .await
-    .map_err(|err| ApiError::internal(format!("Render task failed: {err}")))?
-    .map_err(ApiError::internal)?;
+    let png = render_thumbnail(state.renderer.clone(), state.artifact_cache.clone(), &id).await?;
 
     // Cache the result
     {
@@ -214,3 +225,80 @@ pub async fn template_thumbnail(
     )
         .into_response())
 }
+
+/// Render the thumbnail for template `id` with sample data, or load it from
+/// the disk cache.
+async fn render_thumbnail(
+    renderer: Arc<TypstRenderer>,
+    disk_cache: Option<Arc<ArtifactCache>>,
+    id: &str,
+) -> Result<Vec<u8>, ApiError> {
+    let mut resume = create_sample_resume();
+    resume.metadata.template = id.to_string();
+    let theme = get_template_theme(id);
+    resume.metadata.theme.primary = theme.primary;
+    resume.metadata.theme.text = theme.text;
+    resume.metadata.theme.background = theme.background;
+
+    let key = ArtifactKey::thumbnail(id, renderer.branding());
+    spawn_blocking_in_request(move || {
+        cached_render(disk_cache.as_deref(), Some(&key), || {
+            renderer
+                .render_preview(&resume, 0)
+                .map(|(png, _total_pages)| png)
+                .map_err(|e| {
+                    log_render_failure("thumbnail", &resume, &e);
+                    format!("Failed to render thumbnail: {e}")
+                })
+        })
+    })
+    .await
+    .map_err(|err| ApiError::internal(format!("Render task failed: {err}")))?
+    .map_err(ApiError::internal)
+}
+
+/// Render every offered template's thumbnail into the in-memory cache, at most
+/// `concurrency` at a time, so the first visit to the template gallery
+/// doesn't pay for a dozen cold compiles. Thumbnails already in the disk cache
+/// are loaded instead of rendered. Returns how many thumbnails were cached;
+/// failures are logged and left for the request path to retry.
+pub async fn warm_thumbnail_cache(
+    renderer: Arc<TypstRenderer>,
+    disk_cache: Option<Arc<ArtifactCache>>,
+    concurrency: usize,
+) -> usize {
+    let started = Instant::now();
+    let permits = Arc::new(Semaphore::new(concurrency.max(1)));
+    let mut tasks = JoinSet::new();
+    for id in renderer.template_policy().templates() {
+        let renderer = renderer.clone();
+        let disk_cache = disk_cache.clone();
+        let permits = permits.clone();
+        tasks.spawn(async move {
+            let _permit = permits.acquire_owned().await;
+            let result = render_thumbnail(renderer, disk_cache, id).await;
+            (id, result)
+        });
+    }
+
+    let mut cached = 0;
+    while let Some(joined) = tasks.join_next().await {
+        match joined {
+            Ok((id, Ok(png))) => {
+                thumbnail_cache().lock().await.put(id.to_string(), png);
+                cached += 1;
+            }
+            Ok((id, Err(err))) => {
+                warn!(template = id, error = %err.error, "Thumbnail warm-up failed");
+            }
+            Err(err) => warn!(error = %err, "Thumbnail warm-up task failed"),
+        }
+    }
+
+    info!(
+        cached,
+        elapsed_ms = started.elapsed().as_millis() as u64,
+        "Template thumbnails warmed"
+    );
+    cached
+}
@@ -1,5 +1,6 @@
 use axum::Json;
-use rustume_schema::ResumeData;
+use rustume_render::template_rules;
+use rustume_schema::{validation_messages, ResumeData, ValidationIssue};
 use serde_json::Value;
 use validator::Validate;
 
@@ -22,8 +23,13 @@ pub fn has_recognized_resume_shape(value: &Value) -> bool {
 
 /// Validate resume data
 ///
-/// Checks if the provided resume data conforms to the Rustume schema.
-/// Returns validation errors if the data is invalid.
+/// Checks if the provided resume data conforms to the Rustume schema and
+/// passes the semantic rules (date ranges in order, unique IDs). Failures of
+/// either are returned as `errors`. Non-fatal findings such as overlapping
+/// positions, `http://` links, or theme colors below WCAG AA contrast are
+/// returned separately as `warnings` and do not affect `valid`, as are
+/// layouts the chosen template would render differently (sections on later
+/// layout pages or past a two-column template's second column).
 #[utoipa::path(
     post,
     path = "/api/validate",
@@ -40,77 +46,36 @@ pub async fn validate(Json(value): Json<Value>) -> Result<Json<ValidationRespons
             errors: Some(vec![
                 "No recognized resume fields found in request body".to_string()
             ]),
+            warnings: None,
         }));
     }
 
     let resume: ResumeData =
         serde_json::from_value(value).map_err(|_| ApiError::new("Invalid resume data format"))?;
 
-    match resume.validate() {
-        Ok(_) => Ok(Json(ValidationResponse {
-            valid: true,
-            errors: None,
-        })),
-        Err(e) => Ok(Json(ValidationResponse {
-            valid: false,
-            errors: Some(validation_errors(&e)),
-        })),
-    }
+    let report = template_rules().check(&resume);
+    let mut errors = match resume.validate() {
+        Ok(_) => Vec::new(),
+        Err(e) => validation_errors(&e),
+    };
+    errors.extend(report.errors.iter().map(ToString::to_string));
+
+    Ok(Json(ValidationResponse {
+        valid: errors.is_empty(),
+        errors: (!errors.is_empty()).then_some(errors),
+        warnings: issue_messages(&report.warnings),
+    }))
+}
+
+/// Format rule issues, or `None` when there are none.
+fn issue_messages(issues: &[ValidationIssue]) -> Option<Vec<String>> {
+    let messages: Vec<String> = issues.iter().map(ToString::to_string).collect();
+    (!messages.is_empty()).then_some(messages)
 }
 
 /// Extract validation errors as strings (including nested struct and list errors)
 pub fn validation_errors(errors: &validator::ValidationErrors) -> Vec<String> {
-    fn collect_errors(
-        errors: &validator::ValidationErrors,
-        prefix: &str,
-        result: &mut Vec<String>,
-    ) {
-        // Collect field errors
-        for (field, errs) in errors.field_errors() {
-            let field_path = if prefix.is_empty() {
-                field.to_string()
-            } else {
-                format!("{}.{}", prefix, field)
-            };
-            for e in errs {
-                result.push(format!(
-                    "{}: {}",
-                    field_path,
-                    e.message
-                        .as_ref()
-                        .map(|m| m.to_string())
-                        .unwrap_or_else(|| e.code.to_string())
-                ));
-            }
-        }
-
-        // Recursively collect nested struct and list errors
-        for (field, nested) in errors.errors() {
-            let field_path = if prefix.is_empty() {
-                field.to_string()
-            } else {
-                format!("{}.{}", prefix, field)
-            };
-            match nested {
-                validator::ValidationErrorsKind::Struct(nested_errors) => {
-                    collect_errors(nested_errors.as_ref(), &field_path, result);
-                }
-                validator::ValidationErrorsKind::List(list_errors) => {
-                    for (idx, nested_errors) in list_errors.iter() {
-                        let indexed_path = format!("{}[{}]", field_path, idx);
-                        collect_errors(nested_errors.as_ref(), &indexed_path, result);
-                    }
-                }
-                validator::ValidationErrorsKind::Field(_) => {
-                    // Already handled by field_errors() above
-                }
-            }
-        }
-    }
-
-    let mut result = Vec::new();
-    collect_errors(errors, "", &mut result);
-    result
+    validation_messages(errors)
 }
 
 #[cfg(test)]
@@ -135,4 +100,38 @@ mod tests {
         assert!(!has_recognized_resume_shape(&json!([])));
         assert!(!has_recognized_resume_shape(&json!("resume")));
     }
+
+    #[test]
+    fn validation_warnings_omitted_for_default_theme() {
+        assert!(issue_messages(&ResumeData::default().warnings()).is_none());
+    }
+
+    #[test]
+    fn validation_warnings_report_low_contrast_theme() {
+        let mut resume = ResumeData::default();
+        resume.metadata.theme.text = "#cccccc".to_string();
+
+        let warnings = issue_messages(&resume.warnings()).expect("expected warnings");
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].starts_with("metadata.theme.text:"));
+        assert!(warnings[0].contains("WCAG AA"));
+    }
+
+    #[test]
+    fn validation_warnings_report_layout_the_template_drops() {
+        let mut resume = ResumeData::default();
+        resume.metadata.template = "pikachu".to_string();
+        resume.metadata.layout = vec![vec![
+            vec!["summary".to_string()],
+            vec!["skills".to_string()],
+            vec!["awards".to_string()],
+        ]]
+        .into();
+
+        let report = template_rules().check(&resume);
+        let warnings = issue_messages(&report.warnings).expect("expected warnings");
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("column 3 is not rendered (awards)"));
+        assert!(report.is_valid());
+    }
 }
@@ -0,0 +1,336 @@
+//! Authenticated webhook endpoint management for Rustume Cloud.
+
+use axum::{
+    extract::{Path, State},
+    http::{HeaderMap, StatusCode},
+    Json,
+};
+use tracing::error;
+use uuid::Uuid;
+
+use crate::audit::{record_event, AuditEvent};
+use crate::db::{
+    CreateWebhookRequest, CreatedWebhook, UpdateWebhookRequest, WebhookDeliveryRow, WebhookRow,
+};
+use crate::error::ApiError;
+use crate::middleware::auth::AuthUser;
+use crate::net::{self, trusted_client_ip};
+use crate::state::AppState;
+use crate::subscription;
+use crate::webhooks::{
+    generate_secret, validate_endpoint_url, WebhookEvent, MAX_ENDPOINTS_PER_USER,
+};
+
+/// Deliveries returned by the log endpoint, newest first.
+const DELIVERY_LOG_LIMIT: i64 = 50;
+
+/// List webhook endpoints for the authenticated user.
+#[utoipa::path(
+    get,
+    path = "/api/webhooks",
+    tag = "Webhooks",
+    responses(
+        (status = 200, description = "Webhook endpoints", body = [WebhookRow]),
+        (status = 401, description = "Not authenticated", body = ApiError),
+    ),
+    security(("cookieAuth" = []))
+)]
+pub async fn list_webhooks(
+    AuthUser(user): AuthUser,
+    State(state): State<AppState>,
+) -> Result<Json<Vec<WebhookRow>>, ApiError> {
+    let cloud = state.cloud()?;
+    let access = subscription::load_access(&cloud.db, user.id).await?;
+    access.ensure_read()?;
+
+    let rows = sqlx::query_as::<_, WebhookRow>(
+        r#"
+        SELECT id, url, events, active, created_at, updated_at
+        FROM webhook_endpoints
+        WHERE user_id = $1
+        ORDER BY created_at
+        "#,
+    )
+    .bind(user.id)
+    .fetch_all(&cloud.db)
+    .await
+    .map_err(internal_db_error)?;
+
+    Ok(Json(rows))
+}
+
+/// Register a webhook endpoint for the authenticated user.
+///
+/// The response includes the signing secret, which is not returned again.
+#[utoipa::path(
+    post,
+    path = "/api/webhooks",
+    tag = "Webhooks",
+    request_body = CreateWebhookRequest,
+    responses(
+        (status = 201, description = "Webhook created", body = CreatedWebhook),
+        (status = 400, description = "Invalid URL or events, or endpoint limit reached", body = ApiError),
+        (status = 401, description = "Not authenticated", body = ApiError),
+    ),
+    security(("cookieAuth" = []))
+)]
+pub async fn create_webhook(
+    AuthUser(user): AuthUser,
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(body): Json<CreateWebhookRequest>,
+) -> Result<(StatusCode, Json<CreatedWebhook>), ApiError> {
+    validate_endpoint_url(&body.url).map_err(ApiError::new)?;
+    let events = parse_events(&body.events)?;
+
+    let cloud = state.cloud()?;
+    let access = subscription::load_access(&cloud.db, user.id).await?;
+    access.ensure_write()?;
+
+    let count =
+        sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM webhook_endpoints WHERE user_id = $1")
+            .bind(user.id)
+            .fetch_one(&cloud.db)
+            .await
+            .map_err(internal_db_error)?;
+    if count >= MAX_ENDPOINTS_PER_USER {
+        return Err(ApiError::new(format!(
+            "A maximum of {MAX_ENDPOINTS_PER_USER} webhooks is allowed"
+        )));
+    }
+
+    let secret = generate_secret();
+    let webhook = sqlx::query_as::<_, WebhookRow>(
+        r#"
+        INSERT INTO webhook_endpoints (user_id, url, secret, events)
+        VALUES ($1, $2, $3, $4)
+        RETURNING id, url, events, active, created_at, updated_at
+        "#,
+    )
+    .bind(user.id)
+    .bind(&body.url)
+    .bind(&secret)
+    .bind(&events)
+    .fetch_one(&cloud.db)
+    .await
+    .map_err(internal_db_error)?;
+
+    record_event(
+        &cloud.db,
+        AuditEvent {
+            event_type: "webhook.create",
+            actor_user_id: Some(user.id),
+            resource_type: Some("webhook"),
+            resource_id: Some(webhook.id),
+            metadata: serde_json::json!({ "url": webhook.url, "events": webhook.events }),
+            ip_address: trusted_client_ip(&headers, net::trusted_proxy_enabled()).as_deref(),
+        },
+    )
+    .await;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(CreatedWebhook { webhook, secret }),
+    ))
+}
+
+/// Change a webhook's URL, events, or active state.
+#[utoipa::path(
+    put,
+    path = "/api/webhooks/{id}",
+    tag = "Webhooks",
+    params(("id" = String, Path, description = "Webhook ID")),
+    request_body = UpdateWebhookRequest,
+    responses(
+        (status = 200, description = "Webhook updated", body = WebhookRow),
+        (status = 400, description = "Invalid URL or events", body = ApiError),
+        (status = 401, description = "Not authenticated", body = ApiError),
+        (status = 404, description = "Webhook not found", body = ApiError),
+    ),
+    security(("cookieAuth" = []))
+)]
+pub async fn update_webhook(
+    AuthUser(user): AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Json(body): Json<UpdateWebhookRequest>,
+) -> Result<Json<WebhookRow>, ApiError> {
+    if let Some(url) = &body.url {
+        validate_endpoint_url(url).map_err(ApiError::new)?;
+    }
+    let events = body.events.as_deref().map(parse_events).transpose()?;
+
+    let cloud = state.cloud()?;
+    let access = subscription::load_access(&cloud.db, user.id).await?;
+    access.ensure_write()?;
+
+    sqlx::query_as::<_, WebhookRow>(
+        r#"
+        UPDATE webhook_endpoints
+        SET url = COALESCE($3, url),
+            events = COALESCE($4, events),
+            active = COALESCE($5, active),
+            updated_at = now()
+        WHERE id = $1 AND user_id = $2
+        RETURNING id, url, events, active, created_at, updated_at
+        "#,
+    )
+    .bind(id)
+    .bind(user.id)
+    .bind(body.url)
+    .bind(events)
+    .bind(body.active)
+    .fetch_optional(&cloud.db)
+    .await
+    .map_err(internal_db_error)?
+    .map(Json)
+    .ok_or_else(|| ApiError::not_found("Webhook not found"))
+}
+
+/// Delete a webhook and its delivery log.
+#[utoipa::path(
+    delete,
+    path = "/api/webhooks/{id}",
+    tag = "Webhooks",
+    params(("id" = String, Path, description = "Webhook ID")),
+    responses(
+        (status = 204, description = "Webhook deleted"),
+        (status = 401, description = "Not authenticated", body = ApiError),
+        (status = 404, description = "Webhook not found", body = ApiError),
+    ),
+    security(("cookieAuth" = []))
+)]
+pub async fn delete_webhook(
+    AuthUser(user): AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    headers: HeaderMap,
+) -> Result<StatusCode, ApiError> {
+    let cloud = state.cloud()?;
+    let access = subscription::load_access(&cloud.db, user.id).await?;
+    access.ensure_delete()?;
+    let result = sqlx::query("DELETE FROM webhook_endpoints WHERE id = $1 AND user_id = $2")
+        .bind(id)
+        .bind(user.id)
+        .execute(&cloud.db)
+        .await
+        .map_err(internal_db_error)?;
+
+    if result.rows_affected() == 0 {
+        return Err(ApiError::not_found("Webhook not found"));
+    }
+
+    record_event(
+        &cloud.db,
+        AuditEvent {
+            event_type: "webhook.delete",
+            actor_user_id: Some(user.id),
+            resource_type: Some("webhook"),
+            resource_id: Some(id),
+            metadata: serde_json::json!({}),
+            ip_address: trusted_client_ip(&headers, net::trusted_proxy_enabled()).as_deref(),
+        },
+    )
+    .await;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Recent deliveries to a webhook, newest first.
+#[utoipa::path(
+    get,
+    path = "/api/webhooks/{id}/deliveries",
+    tag = "Webhooks",
+    params(("id" = String, Path, description = "Webhook ID")),
+    responses(
+        (status = 200, description = "Latest 50 deliveries", body = [WebhookDeliveryRow]),
+        (status = 401, description = "Not authenticated", body = ApiError),
+        (status = 404, description = "Webhook not found", body = ApiError),
+    ),
+    security(("cookieAuth" = []))
+)]
+pub async fn list_webhook_deliveries(
+    AuthUser(user): AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<Vec<WebhookDeliveryRow>>, ApiError> {
+    let cloud = state.cloud()?;
+    let access = subscription::load_access(&cloud.db, user.id).await?;
+    access.ensure_read()?;
+
+    let owned = sqlx::query_scalar::<_, bool>(
+        "SELECT EXISTS (SELECT 1 FROM webhook_endpoints WHERE id = $1 AND user_id = $2)",
+    )
+    .bind(id)
+    .bind(user.id)
+    .fetch_one(&cloud.db)
+    .await
+    .map_err(internal_db_error)?;
+    if !owned {
+        return Err(ApiError::not_found("Webhook not found"));
+    }
+
+    let rows = sqlx::query_as::<_, WebhookDeliveryRow>(
+        r#"
+        SELECT id, event_id, event_type, payload, status, attempts, last_status_code,
+               last_error, next_attempt_at, delivered_at, created_at
+        FROM webhook_deliveries
+        WHERE endpoint_id = $1
+        ORDER BY created_at DESC
+        LIMIT $2
+        "#,
+    )
+    .bind(id)
+    .bind(DELIVERY_LOG_LIMIT)
+    .fetch_all(&cloud.db)
+    .await
+    .map_err(internal_db_error)?;
+
+    Ok(Json(rows))
+}
+
+/// Validate event names, dropping duplicates.
+fn parse_events(events: &[String]) -> Result<Vec<String>, ApiError> {
+    if events.is_empty() {
+        return Err(ApiError::new("Subscribe to at least one event"));
+    }
+    let mut parsed: Vec<String> = Vec::with_capacity(events.len());
+    for event in events {
+        let name = event
+            .parse::<WebhookEvent>()
+            .map_err(ApiError::new)?
+            .as_str()
+            .to_string();
+        if !parsed.contains(&name) {
+            parsed.push(name);
+        }
+    }
+    Ok(parsed)
+}
+
+fn internal_db_error(err: impl std::fmt::Display) -> ApiError {
+    error!("database error: {err}");
+    ApiError::internal("internal server error")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_events_rejects_unknown_and_empty_lists() {
+        assert!(parse_events(&[]).is_err());
+        assert!(parse_events(&["resume.created".into(), "resume.deleted".into()]).is_err());
+    }
+
+    #[test]
+    fn parse_events_drops_duplicates() {
+        let events = parse_events(&[
+            "resume.updated".into(),
+            " resume.updated".into(),
+            "resume.shared".into(),
+        ])
+        .unwrap();
+        assert_eq!(events, vec!["resume.updated", "resume.shared"]);
+    }
+}
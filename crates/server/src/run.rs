@@ -1,16 +1,25 @@
 use anyhow::Context;
+use rustume_render::TemplatePolicy;
 use std::net::SocketAddr;
 use std::sync::Arc;
-use tracing::info;
+use tracing::{info, warn};
 
 use crate::app::create_router_with_state;
+use crate::audit::api::{self as api_keys, ApiAudit, ApiAuditConfig};
 use crate::cloud::{cloud_enabled, init_cloud, CloudConfig};
-use crate::config::DEFAULT_PORT;
+use crate::config::{branding_from_env, ThumbnailWarmupConfig, DEFAULT_PORT};
+#[cfg(feature = "dev")]
+use crate::dev_templates::{dev_templates_arg, use_dev_templates, DEV_TEMPLATES_FLAG};
 use crate::middleware::rate_limit::RateLimitState;
 use crate::observability::init_sentry;
-use crate::routes::{init_metrics, static_dir};
+use crate::routes::static_files::SERVE_WEB_FLAG;
+use crate::routes::{
+    init_metrics, openapi_payload, serve_web_arg, static_dir, warm_thumbnail_cache,
+};
 use crate::shutdown::{health_probe, shutdown_signal};
 use crate::state::AppState;
+use crate::trash;
+use crate::webhooks;
 
 /// Start the HTTP server, optionally initializing Rustume Cloud when configured.
 pub async fn run() -> anyhow::Result<()> {
@@ -28,8 +37,41 @@ pub async fn run() -> anyhow::Result<()> {
         .init();
 
     init_metrics();
+    // Checked before state is built, which would offer every template and
+    // skip branding instead
+    let templates = TemplatePolicy::from_env().context("Invalid template allow or deny list")?;
+    if templates.is_restricted() {
+        info!("Offering templates: {}", templates.templates().join(", "));
+    }
+    if !branding_from_env()
+        .context("Invalid RUSTUME_BRAND_* setting")?
+        .is_empty()
+    {
+        info!("Adding deployment branding to every render");
+    }
+    #[cfg(feature = "dev")]
+    let dev_templates = dev_templates_arg(std::env::args());
+    #[cfg(feature = "dev")]
+    if let Some(dir) = dev_templates.clone() {
+        use_dev_templates(dir)?;
+    }
+    // Serialize and compress the responses that never change before serving
+    openapi_payload();
 
     let static_root = Arc::new(static_dir());
+    if !static_root.join("index.html").is_file() {
+        // A directory asked for on the command line is a mistake to stop on;
+        // the default one is simply absent when only the API is wanted
+        anyhow::ensure!(
+            serve_web_arg(std::env::args()).is_none(),
+            "{SERVE_WEB_FLAG} {}: no index.html in that directory",
+            static_root.display()
+        );
+        warn!(
+            "No index.html in {}; non-API routes will return 404",
+            static_root.display()
+        );
+    }
     let cloud = if cloud_enabled() {
         let config = CloudConfig::from_env()?;
         info!("Rustume Cloud mode enabled");
@@ -39,10 +81,43 @@ pub async fn run() -> anyhow::Result<()> {
         None
     };
 
-    let app_state = AppState::new(static_root.clone(), cloud);
+    if let Some(cloud) = &cloud {
+        webhooks::spawn_dispatcher(cloud.db.clone());
+        trash::spawn_purger(cloud.db.clone(), cloud.trash_retention_days);
+    }
+
+    let api_audit = ApiAudit::open(ApiAuditConfig::from_env()?).await?;
+
+    let mut app_state = AppState::new(static_root.clone(), cloud);
+    if let Some(audit) = api_audit.map(Arc::new) {
+        info!(
+            "Auditing API calls for keys {:?}, kept {} days",
+            audit.keys, audit.retention_days
+        );
+        api_keys::spawn_purger(audit.clone());
+        app_state = app_state.with_api_audit(audit);
+    }
+    #[cfg(feature = "ai")]
+    if app_state.ai.is_some() && app_state.api_audit.is_none() {
+        warn!("AI rewriting is enabled but RUSTUME_API_KEYS is unset; AI requests will be refused");
+    }
+    #[cfg(feature = "dev")]
+    if dev_templates.is_some() && app_state.artifact_cache.take().is_some() {
+        // Cache keys don't cover template contents
+        warn!("Disk artifact cache disabled while {DEV_TEMPLATES_FLAG} is set");
+    }
+    app_state.templates_payload();
     if let Some(rate_limits) = app_state.rate_limits.clone() {
         RateLimitState::spawn_eviction_task(rate_limits);
     }
+    let warmup = ThumbnailWarmupConfig::from_env();
+    if warmup.enabled {
+        tokio::spawn(warm_thumbnail_cache(
+            app_state.renderer.clone(),
+            app_state.artifact_cache.clone(),
+            warmup.concurrency,
+        ));
+    }
     let app = create_router_with_state(app_state);
 
     let port: u16 = std::env::var("PORT")
@@ -58,7 +133,7 @@ pub async fn run() -> anyhow::Result<()> {
         port
     );
     info!(
-        "Serving web UI assets from {} (set RUSTUME_STATIC_DIR to override)",
+        "Serving web UI assets from {} (set {SERVE_WEB_FLAG} or RUSTUME_STATIC_DIR to override)",
         static_root.as_path().display()
     );
     info!(
@@ -1,36 +1,79 @@
 use std::path::PathBuf;
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
 
-use rustume_render::TypstRenderer;
+use rustume_render::{RendererRegistry, TemplatePolicy, TypstRenderer};
 
+#[cfg(feature = "ai")]
+use crate::ai::{AiConfig, AiState};
+use crate::artifact_cache::ArtifactCache;
+use crate::audit::api::ApiAudit;
 use crate::cloud::CloudState;
-use crate::config::RateLimitConfig;
+use crate::config::{
+    branding_from_env, render_engines_from_env, render_limits_from_env, sandbox_limits_from_env,
+    ArtifactCacheConfig, RateLimitConfig,
+};
 use crate::middleware::rate_limit::RateLimitState;
+use crate::precompressed::Precompressed;
+use crate::routes::record_render;
+use crate::routes::templates::template_list;
 
 /// Shared router state for all handlers.
 #[derive(Clone)]
 pub struct AppState {
     pub static_dir: Arc<PathBuf>,
     pub cloud: Option<Arc<CloudState>>,
+    /// Typst renderer, limited to the templates of the deployment's
+    /// [`TemplatePolicy`], recording every render in the Prometheus metrics.
     pub renderer: Arc<TypstRenderer>,
+    /// PDF backends by engine; the Typst entry is [`Self::renderer`] and is
+    /// used through that field so per-request redaction can copy it.
+    pub renderers: RendererRegistry,
+    /// Disk cache for rendered PDFs and thumbnails (when `ARTIFACT_CACHE_DIR` is set).
+    pub artifact_cache: Option<Arc<ArtifactCache>>,
     /// When true, billable API routes require a valid session (hosted Rustume Cloud).
     pub require_auth: bool,
     /// In-memory rate limiters (cloud mode only).
     pub rate_limits: Option<Arc<RateLimitState>>,
+    /// AI rewrite provider (`ai` feature, when `RUSTUME_AI_PROVIDER` is set).
+    #[cfg(feature = "ai")]
+    pub ai: Option<Arc<AiState>>,
+    /// Audit log of calls made with an API key (when `RUSTUME_API_KEYS` is set).
+    pub api_audit: Option<Arc<ApiAudit>>,
+    /// `GET /api/templates` body, serialized and compressed on first use.
+    templates: Arc<OnceLock<Precompressed>>,
 }
 
 impl AppState {
     /// Build application state with a shared Typst renderer instance.
+    ///
+    /// An invalid `RUSTUME_TEMPLATES_ALLOW` or `RUSTUME_TEMPLATES_DENY` offers
+    /// every template here, and invalid `RUSTUME_BRAND_*` settings add no
+    /// branding; `run` checks both before building state.
     pub fn new(static_dir: Arc<PathBuf>, cloud: Option<Arc<CloudState>>) -> Self {
         let rate_limits = cloud
             .as_ref()
             .map(|_| Arc::new(RateLimitState::new(RateLimitConfig::from_env())));
+        let renderer = Arc::new(
+            TypstRenderer::new()
+                .with_limits(render_limits_from_env())
+                .with_sandbox_limits(sandbox_limits_from_env())
+                .with_template_policy(TemplatePolicy::from_env().unwrap_or_default())
+                .with_branding(branding_from_env().unwrap_or_default())
+                .with_observer(Arc::new(record_render)),
+        );
         Self {
             static_dir,
             cloud,
-            renderer: Arc::new(TypstRenderer::new()),
+            renderers: render_engines_from_env(renderer.clone()),
+            renderer,
+            artifact_cache: ArtifactCache::from_config(&ArtifactCacheConfig::from_env())
+                .map(Arc::new),
             require_auth: crate::cloud::require_auth_enabled(),
             rate_limits,
+            #[cfg(feature = "ai")]
+            ai: AiConfig::from_env().map(|config| Arc::new(AiState::new(&config))),
+            api_audit: None,
+            templates: Arc::default(),
         }
     }
 
@@ -55,19 +98,75 @@ impl AppState {
         let rate_limits = cloud
             .as_ref()
             .map(|_| Arc::new(RateLimitState::new(rate_limit_config)));
+        let renderer = Arc::new(TypstRenderer::new());
         Self {
             static_dir,
             cloud,
-            renderer: Arc::new(TypstRenderer::new()),
+            renderers: RendererRegistry::new(renderer.clone()),
+            renderer,
+            artifact_cache: None,
             require_auth,
             rate_limits,
+            #[cfg(feature = "ai")]
+            ai: None,
+            api_audit: None,
+            templates: Arc::default(),
         }
     }
 
+    /// Offer only the templates `policy` allows (tests).
+    #[cfg(test)]
+    pub fn with_template_policy(mut self, policy: TemplatePolicy) -> Self {
+        let renderer = Arc::new(TypstRenderer::new().with_template_policy(policy));
+        self.renderers = RendererRegistry::new(renderer.clone());
+        self.renderer = renderer;
+        self.templates = Arc::default();
+        self
+    }
+
+    /// Attach a disk artifact cache (tests).
+    #[cfg(test)]
+    pub fn with_artifact_cache(mut self, cache: ArtifactCache) -> Self {
+        self.artifact_cache = Some(Arc::new(cache));
+        self
+    }
+
+    /// Attach an AI provider (tests).
+    #[cfg(all(test, feature = "ai"))]
+    pub fn with_ai(mut self, ai: AiState) -> Self {
+        self.ai = Some(Arc::new(ai));
+        self
+    }
+
+    /// Record calls made with an API key in `audit`.
+    pub fn with_api_audit(mut self, audit: Arc<ApiAudit>) -> Self {
+        self.api_audit = Some(audit);
+        self
+    }
+
+    /// The templates this deployment offers.
+    pub fn template_policy(&self) -> &TemplatePolicy {
+        self.renderer.template_policy()
+    }
+
+    /// The `GET /api/templates` body for the offered templates.
+    pub fn templates_payload(&self) -> &Precompressed {
+        self.templates
+            .get_or_init(|| Precompressed::json(&template_list(self.template_policy())))
+    }
+
     /// Return cloud services or a 404 when cloud mode is disabled.
     pub fn cloud(&self) -> Result<&CloudState, crate::error::ApiError> {
         self.cloud.as_deref().ok_or_else(|| {
             crate::error::ApiError::not_found("Cloud features are not enabled on this server")
         })
     }
+
+    /// Return AI services or a 404 when no provider is configured.
+    #[cfg(feature = "ai")]
+    pub fn ai(&self) -> Result<&AiState, crate::error::ApiError> {
+        self.ai.as_deref().ok_or_else(|| {
+            crate::error::ApiError::not_found("AI rewriting is not enabled on this server")
+        })
+    }
 }
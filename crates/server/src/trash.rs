@@ -0,0 +1,44 @@
+//! Purging of trashed cloud resumes.
+//!
+//! `DELETE /api/resumes/{id}` only stamps `trashed_at`; the task started by
+//! [`spawn_purger`] erases resumes that have sat in the trash longer than the
+//! retention window (`TRASH_RETENTION_DAYS`).
+
+use std::time::Duration;
+
+use sqlx::PgPool;
+use tracing::{error, info};
+
+/// How often trashed resumes are checked for expiry.
+const PURGE_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// Start the background task that purges expired trash.
+pub fn spawn_purger(db: PgPool, retention_days: u32) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(PURGE_INTERVAL);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+        loop {
+            interval.tick().await;
+            match purge_expired(&db, retention_days).await {
+                Ok(0) => {}
+                Ok(purged) => info!("purged {purged} expired trashed resumes"),
+                Err(err) => error!("trash purge failed: {err}"),
+            }
+        }
+    });
+}
+
+/// Permanently delete resumes trashed more than `retention_days` ago.
+pub async fn purge_expired(db: &PgPool, retention_days: u32) -> Result<u64, sqlx::Error> {
+    let result = sqlx::query(
+        r#"
+        DELETE FROM resumes
+        WHERE trashed_at IS NOT NULL
+          AND trashed_at <= now() - make_interval(days => $1)
+        "#,
+    )
+    .bind(i32::try_from(retention_days).unwrap_or(i32::MAX))
+    .execute(db)
+    .await?;
+    Ok(result.rows_affected())
+}
@@ -0,0 +1,107 @@
+//! Append-only log of calls made to the server API with an API key.
+//!
+//! Each [`ApiCall`] names the key, the endpoint, a hash of the resume the
+//! call carried, the response status, and how long it took. The log never
+//! holds resume content, and entries are never rewritten; they are only
+//! erased once older than the retention window.
+
+use std::time::Duration;
+
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+use crate::trash::timestamp;
+
+/// Days an [`ApiCall`] is kept before
+/// [`ApiAuditLog::purge_api_calls`](crate::ApiAuditLog::purge_api_calls)
+/// erases it, unless the caller picks another window.
+pub const API_AUDIT_RETENTION_DAYS: u32 = 90;
+
+/// Entries returned by one query when it sets no limit.
+pub const DEFAULT_API_CALL_LIMIT: usize = 100;
+
+/// Most entries one query returns.
+pub const MAX_API_CALL_LIMIT: usize = 1000;
+
+/// One call to the server API.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiCall {
+    /// When the call finished, as RFC 3339 in UTC.
+    pub at: String,
+    /// Name of the API key the call was made with.
+    pub api_key: String,
+    /// Method and route, such as `POST /api/render/pdf`.
+    pub endpoint: String,
+    /// SHA-256 of the canonical JSON of the resume in the request, when it
+    /// carried one.
+    pub resume_hash: Option<String>,
+    /// HTTP status of the response.
+    pub status: u16,
+    /// Time from receiving the request to sending the response headers.
+    pub duration_ms: u64,
+}
+
+impl ApiCall {
+    /// A call that finished now.
+    pub fn new(
+        api_key: &str,
+        endpoint: &str,
+        resume_hash: Option<String>,
+        status: u16,
+        duration: Duration,
+    ) -> Self {
+        Self {
+            at: timestamp(Utc::now()),
+            api_key: api_key.to_string(),
+            endpoint: endpoint.to_string(),
+            resume_hash,
+            status,
+            duration_ms: u64::try_from(duration.as_millis()).unwrap_or(u64::MAX),
+        }
+    }
+
+    /// Whether the response was a success (2xx or 3xx).
+    pub fn succeeded(&self) -> bool {
+        self.status < 400
+    }
+}
+
+/// Which [`ApiCall`]s to return.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiCallQuery {
+    /// Only calls made with this key.
+    pub api_key: Option<String>,
+    /// Only calls at or after this RFC 3339 timestamp or date.
+    pub since: Option<String>,
+    /// Only calls before this RFC 3339 timestamp or date.
+    pub until: Option<String>,
+    /// At most this many calls, capped at [`MAX_API_CALL_LIMIT`];
+    /// [`DEFAULT_API_CALL_LIMIT`] when unset.
+    pub limit: Option<usize>,
+}
+
+impl ApiCallQuery {
+    /// Whether `call` passes the key and time filters.
+    pub fn matches(&self, call: &ApiCall) -> bool {
+        self.api_key
+            .as_ref()
+            .map_or(true, |key| *key == call.api_key)
+            && self
+                .since
+                .as_ref()
+                .map_or(true, |since| call.at.as_str() >= since.as_str())
+            && self
+                .until
+                .as_ref()
+                .map_or(true, |until| call.at.as_str() < until.as_str())
+    }
+
+    /// The number of calls to return.
+    pub fn limit(&self) -> usize {
+        self.limit
+            .unwrap_or(DEFAULT_API_CALL_LIMIT)
+            .min(MAX_API_CALL_LIMIT)
+    }
+}
@@ -0,0 +1,110 @@
+//! Append-only change log of stored resumes.
+//!
+//! Every backend records a [`HistoryEntry`] when a resume is created,
+//! changed, deleted, or restored from the trash, with a one-line summary of what changed from
+//! [`diff_resumes`]. Saving a resume unchanged records nothing. Entries are
+//! never rewritten, and a deleted resume's history stays readable.
+
+use chrono::Utc;
+use rustume_schema::{diff_resumes, ResumeData};
+use serde::{Deserialize, Serialize};
+
+use crate::trash::timestamp;
+
+/// What a [`HistoryEntry`] records.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HistoryAction {
+    Created,
+    Updated,
+    Deleted,
+    Restored,
+}
+
+impl HistoryAction {
+    /// Lowercase name, as stored and serialized.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Created => "created",
+            Self::Updated => "updated",
+            Self::Deleted => "deleted",
+            Self::Restored => "restored",
+        }
+    }
+
+    /// Parse a name written by [`HistoryAction::as_str`].
+    pub fn parse(name: &str) -> Option<Self> {
+        match name {
+            "created" => Some(Self::Created),
+            "updated" => Some(Self::Updated),
+            "deleted" => Some(Self::Deleted),
+            "restored" => Some(Self::Restored),
+            _ => None,
+        }
+    }
+}
+
+/// One change to a stored resume.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoryEntry {
+    pub resume_id: String,
+    /// When the change was saved, as RFC 3339 in UTC.
+    pub at: String,
+    /// Who saved it, when the backend knows. Local backends have a single
+    /// user and leave it empty.
+    pub author: Option<String>,
+    pub action: HistoryAction,
+    /// What changed, such as `Edited basics (email); added 1 skills item`.
+    pub summary: String,
+}
+
+impl HistoryEntry {
+    /// Entry for saving `current` over `previous`, or `None` when nothing
+    /// changed.
+    pub fn for_save(
+        resume_id: &str,
+        previous: Option<&ResumeData>,
+        current: &ResumeData,
+    ) -> Option<Self> {
+        let Some(previous) = previous else {
+            return Some(Self::now(
+                resume_id,
+                HistoryAction::Created,
+                "Created".to_string(),
+            ));
+        };
+        let diff = diff_resumes(previous, current);
+        (!diff.is_empty()).then(|| Self::now(resume_id, HistoryAction::Updated, diff.summary()))
+    }
+
+    /// Entry for deleting a resume.
+    pub fn for_delete(resume_id: &str) -> Self {
+        Self::now(resume_id, HistoryAction::Deleted, "Deleted".to_string())
+    }
+
+    /// Entry for restoring a resume from the trash.
+    pub fn for_restore(resume_id: &str) -> Self {
+        Self::now(
+            resume_id,
+            HistoryAction::Restored,
+            "Restored from trash".to_string(),
+        )
+    }
+
+    /// Set who made the change.
+    pub fn with_author(mut self, author: impl Into<String>) -> Self {
+        self.author = Some(author.into());
+        self
+    }
+
+    fn now(resume_id: &str, action: HistoryAction, summary: String) -> Self {
+        Self {
+            resume_id: resume_id.to_string(),
+            at: timestamp(Utc::now()),
+            author: None,
+            action,
+            summary,
+        }
+    }
+}
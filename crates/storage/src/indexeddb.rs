@@ -1,20 +1,51 @@
 //! IndexedDB storage backend for WASM.
 //!
-//! This module provides persistent storage for resumes in the browser
-//! using the IndexedDB API.
-
-use crate::traits::{StorageBackend, StorageError};
+//! This module provides persistent storage for resumes and job applications
+//! in the browser using the IndexedDB API.
+//!
+//! Resume search uses a manual inverted index: `search_terms` maps each term
+//! to its postings and `search_fields` holds the indexed fields of each
+//! resume, both as JSON strings.
+//!
+//! The change log of each resume is a JSON array of entries in the `history`
+//! store, keyed by resume ID. Deleted resumes move to the `trash` store.
+
+use crate::history::HistoryEntry;
+use crate::search::{
+    index_fields, postings, query_terms, rank, Posting, SearchField, SearchResult,
+};
+use crate::traits::{
+    ApplicationStore, ResumeHistory, ResumeSearch, ResumeTrash, StorageBackend, StorageError,
+};
+use crate::trash::{purge_cutoff, TrashedResume};
 use async_trait::async_trait;
 use js_sys::Array;
-use rustume_schema::ResumeData;
+use rustume_schema::{JobApplication, ResumeData};
 use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
 use std::rc::Rc;
 use wasm_bindgen::prelude::*;
 use wasm_bindgen_futures::JsFuture;
 use web_sys::{console, IdbDatabase, IdbObjectStore, IdbRequest};
 
-const DB_VERSION: u32 = 1;
+const DB_VERSION: u32 = 5;
 const STORE_NAME: &str = "resumes";
+const APPLICATIONS_STORE_NAME: &str = "applications";
+const SEARCH_TERMS_STORE_NAME: &str = "search_terms";
+const SEARCH_FIELDS_STORE_NAME: &str = "search_fields";
+const HISTORY_STORE_NAME: &str = "history";
+const TRASH_STORE_NAME: &str = "trash";
+/// Object stores created on upgrade; version 2 added `applications`,
+/// version 3 the search index stores, version 4 `history`, and version 5
+/// `trash`.
+const STORE_NAMES: [&str; 6] = [
+    STORE_NAME,
+    APPLICATIONS_STORE_NAME,
+    SEARCH_TERMS_STORE_NAME,
+    SEARCH_FIELDS_STORE_NAME,
+    HISTORY_STORE_NAME,
+    TRASH_STORE_NAME,
+];
 
 /// IndexedDB storage backend.
 pub struct IndexedDbStorage {
@@ -55,7 +86,6 @@ impl IndexedDbStorage {
         let upgrade_closure_clone = upgrade_closure.clone();
 
         // Set up database upgrade handler
-        let store_name = STORE_NAME;
         let onupgradeneeded = Closure::once(move |event: web_sys::IdbVersionChangeEvent| {
             // Self-clear the closure to prevent memory leak (consistent with idb_request_to_promise)
             upgrade_closure_clone.borrow_mut().take();
@@ -73,10 +103,12 @@ impl IndexedDbStorage {
                 }
             };
 
-            // Create object store if it doesn't exist
-            if !db.object_store_names().contains(store_name) {
-                if let Err(e) = db.create_object_store(store_name) {
-                    console::error_1(&format!("Failed to create object store: {:?}", e).into());
+            // Create any object stores that don't exist yet
+            for store_name in STORE_NAMES {
+                if !db.object_store_names().contains(store_name) {
+                    if let Err(e) = db.create_object_store(store_name) {
+                        console::error_1(&format!("Failed to create object store: {:?}", e).into());
+                    }
                 }
             }
         });
@@ -97,7 +129,12 @@ impl IndexedDbStorage {
     }
 
     /// Get an object store for read/write operations.
-    fn get_store(&self, db: &IdbDatabase, readonly: bool) -> Result<IdbObjectStore, StorageError> {
+    fn get_store(
+        &self,
+        db: &IdbDatabase,
+        store_name: &str,
+        readonly: bool,
+    ) -> Result<IdbObjectStore, StorageError> {
         let mode = if readonly {
             web_sys::IdbTransactionMode::Readonly
         } else {
@@ -105,46 +142,18 @@ impl IndexedDbStorage {
         };
 
         let transaction = db
-            .transaction_with_str_and_mode(STORE_NAME, mode)
+            .transaction_with_str_and_mode(store_name, mode)
             .map_err(|e| StorageError::Internal(format!("Transaction failed: {:?}", e)))?;
 
         transaction
-            .object_store(STORE_NAME)
+            .object_store(store_name)
             .map_err(|e| StorageError::Internal(format!("Failed to get object store: {:?}", e)))
     }
-}
-
-#[async_trait(?Send)]
-impl StorageBackend for IndexedDbStorage {
-    async fn list(&self) -> Result<Vec<String>, StorageError> {
-        let db = self.open_db().await?;
-        let store = self.get_store(&db, true)?;
-
-        let request = store
-            .get_all_keys()
-            .map_err(|e| StorageError::Internal(format!("Failed to get keys: {:?}", e)))?;
-
-        let result = JsFuture::from(idb_request_to_promise(&request)?)
-            .await
-            .map_err(|e| StorageError::Internal(format!("Get keys failed: {:?}", e)))?;
-
-        let array: Array = result
-            .dyn_into()
-            .map_err(|e| StorageError::Internal(format!("Invalid keys array: {:?}", e)))?;
-
-        let mut keys = Vec::new();
-        for i in 0..array.length() {
-            if let Some(key) = array.get(i).as_string() {
-                keys.push(key);
-            }
-        }
-
-        Ok(keys)
-    }
 
-    async fn get(&self, id: &str) -> Result<ResumeData, StorageError> {
+    /// Read the JSON string stored under `id`, if any.
+    async fn read_json(&self, store_name: &str, id: &str) -> Result<Option<String>, StorageError> {
         let db = self.open_db().await?;
-        let store = self.get_store(&db, true)?;
+        let store = self.get_store(&db, store_name, true)?;
 
         let request = store
             .get(&JsValue::from_str(id))
@@ -155,28 +164,23 @@ impl StorageBackend for IndexedDbStorage {
             .map_err(|e| StorageError::Internal(format!("Get failed: {:?}", e)))?;
 
         if result.is_undefined() || result.is_null() {
-            return Err(StorageError::NotFound(id.to_string()));
+            return Ok(None);
         }
 
         // The stored value is a JSON string
-        let json_str = result
+        result
             .as_string()
-            .ok_or_else(|| StorageError::Internal("Stored value is not a string".to_string()))?;
-
-        serde_json::from_str(&json_str)
-            .map_err(|e| StorageError::Internal(format!("Deserialization failed: {}", e)))
+            .map(Some)
+            .ok_or_else(|| StorageError::Internal("Stored value is not a string".to_string()))
     }
 
-    async fn save(&self, id: &str, data: &ResumeData) -> Result<(), StorageError> {
+    /// Store a JSON string under `id` (upsert).
+    async fn write_json(&self, store_name: &str, id: &str, json: &str) -> Result<(), StorageError> {
         let db = self.open_db().await?;
-        let store = self.get_store(&db, false)?;
-
-        // Serialize to JSON string for storage
-        let json_str = serde_json::to_string(data)
-            .map_err(|e| StorageError::Internal(format!("Serialization failed: {}", e)))?;
+        let store = self.get_store(&db, store_name, false)?;
 
         let request = store
-            .put_with_key(&JsValue::from_str(&json_str), &JsValue::from_str(id))
+            .put_with_key(&JsValue::from_str(json), &JsValue::from_str(id))
             .map_err(|e| StorageError::Internal(format!("Failed to put: {:?}", e)))?;
 
         JsFuture::from(idb_request_to_promise(&request)?)
@@ -186,11 +190,12 @@ impl StorageBackend for IndexedDbStorage {
         Ok(())
     }
 
-    async fn delete(&self, id: &str) -> Result<(), StorageError> {
+    /// Delete `id`, returning `NotFound` when it did not exist.
+    async fn delete_key(&self, store_name: &str, id: &str) -> Result<(), StorageError> {
         let db = self.open_db().await?;
 
         // Use a single readwrite transaction for atomic check-and-delete
-        let store = self.get_store(&db, false)?;
+        let store = self.get_store(&db, store_name, false)?;
 
         // Issue both requests before awaiting to keep transaction active
         let get_request = store
@@ -222,9 +227,242 @@ impl StorageBackend for IndexedDbStorage {
         Ok(())
     }
 
+    /// List all keys in a store.
+    async fn list_keys(&self, store_name: &str) -> Result<Vec<String>, StorageError> {
+        let db = self.open_db().await?;
+        let store = self.get_store(&db, store_name, true)?;
+
+        let request = store
+            .get_all_keys()
+            .map_err(|e| StorageError::Internal(format!("Failed to get keys: {:?}", e)))?;
+
+        let result = JsFuture::from(idb_request_to_promise(&request)?)
+            .await
+            .map_err(|e| StorageError::Internal(format!("Get keys failed: {:?}", e)))?;
+
+        let array: Array = result
+            .dyn_into()
+            .map_err(|e| StorageError::Internal(format!("Invalid keys array: {:?}", e)))?;
+
+        let mut keys = Vec::new();
+        for i in 0..array.length() {
+            if let Some(key) = array.get(i).as_string() {
+                keys.push(key);
+            }
+        }
+
+        Ok(keys)
+    }
+
+    /// Read the JSON strings stored under several keys in one transaction.
+    async fn read_json_many(
+        &self,
+        store_name: &str,
+        keys: &[String],
+    ) -> Result<Vec<Option<String>>, StorageError> {
+        if keys.is_empty() {
+            return Ok(Vec::new());
+        }
+        let db = self.open_db().await?;
+        let store = self.get_store(&db, store_name, true)?;
+
+        // Issue every request before awaiting so the transaction stays active
+        let mut futures = Vec::with_capacity(keys.len());
+        for key in keys {
+            let request = store
+                .get(&JsValue::from_str(key))
+                .map_err(|e| StorageError::Internal(format!("Failed to get: {:?}", e)))?;
+            futures.push(JsFuture::from(idb_request_to_promise(&request)?));
+        }
+
+        let mut values = Vec::with_capacity(futures.len());
+        for future in futures {
+            let result = future
+                .await
+                .map_err(|e| StorageError::Internal(format!("Get failed: {:?}", e)))?;
+            if result.is_undefined() || result.is_null() {
+                values.push(None);
+            } else {
+                values.push(Some(result.as_string().ok_or_else(|| {
+                    StorageError::Internal("Stored value is not a string".to_string())
+                })?));
+            }
+        }
+
+        Ok(values)
+    }
+
+    /// Put (`Some`) or delete (`None`) several keys in one transaction.
+    async fn write_json_many(
+        &self,
+        store_name: &str,
+        entries: &[(String, Option<String>)],
+    ) -> Result<(), StorageError> {
+        if entries.is_empty() {
+            return Ok(());
+        }
+        let db = self.open_db().await?;
+        let store = self.get_store(&db, store_name, false)?;
+
+        let mut futures = Vec::with_capacity(entries.len());
+        for (key, json) in entries {
+            let key = JsValue::from_str(key);
+            let request = match json {
+                Some(json) => store.put_with_key(&JsValue::from_str(json), &key),
+                None => store.delete(&key),
+            }
+            .map_err(|e| StorageError::Internal(format!("Failed to write: {:?}", e)))?;
+            futures.push(JsFuture::from(idb_request_to_promise(&request)?));
+        }
+
+        for future in futures {
+            future
+                .await
+                .map_err(|e| StorageError::Internal(format!("Write failed: {:?}", e)))?;
+        }
+
+        Ok(())
+    }
+
+    /// Replace the indexed text of a resume, or drop it when `resume` is `None`.
+    async fn update_search_index(
+        &self,
+        id: &str,
+        resume: Option<&ResumeData>,
+    ) -> Result<(), StorageError> {
+        let old_fields: Vec<SearchField> =
+            match self.read_json(SEARCH_FIELDS_STORE_NAME, id).await? {
+                Some(json) => decode(&json)?,
+                None => Vec::new(),
+            };
+        let new_fields = resume.map(index_fields).unwrap_or_default();
+        let mut new_postings = postings(id, &new_fields);
+
+        let mut terms: Vec<String> = postings(id, &old_fields)
+            .into_keys()
+            .chain(new_postings.keys().cloned())
+            .collect();
+        terms.sort();
+        terms.dedup();
+
+        let stored = self.read_json_many(SEARCH_TERMS_STORE_NAME, &terms).await?;
+        let mut writes = Vec::with_capacity(terms.len());
+        for (term, json) in terms.into_iter().zip(stored) {
+            let mut list: Vec<Posting> = match json {
+                Some(json) => decode(&json)?,
+                None => Vec::new(),
+            };
+            list.retain(|posting| posting.resume_id != id);
+            list.extend(new_postings.remove(&term).unwrap_or_default());
+            let json = if list.is_empty() {
+                None
+            } else {
+                Some(encode(&list)?)
+            };
+            writes.push((term, json));
+        }
+        self.write_json_many(SEARCH_TERMS_STORE_NAME, &writes)
+            .await?;
+
+        let fields_json = match resume {
+            Some(_) => Some(encode(&new_fields)?),
+            None => None,
+        };
+        self.write_json_many(SEARCH_FIELDS_STORE_NAME, &[(id.to_string(), fields_json)])
+            .await
+    }
+
+    /// Index resumes stored before search existed. Returns the number of resumes.
+    async fn index_missing_resumes(&self) -> Result<usize, StorageError> {
+        let resume_ids = self.list_keys(STORE_NAME).await?;
+        let indexed: HashSet<String> = self
+            .list_keys(SEARCH_FIELDS_STORE_NAME)
+            .await?
+            .into_iter()
+            .collect();
+
+        for id in resume_ids.iter().filter(|id| !indexed.contains(*id)) {
+            let resume = self.get(id).await?;
+            self.update_search_index(id, Some(&resume)).await?;
+        }
+
+        Ok(resume_ids.len())
+    }
+
+    /// Append `entry` to the change log of its resume.
+    async fn record_history(&self, entry: Option<HistoryEntry>) -> Result<(), StorageError> {
+        let Some(entry) = entry else {
+            return Ok(());
+        };
+        let mut history = self.history(&entry.resume_id).await?;
+        let id = entry.resume_id.clone();
+        history.push(entry);
+        self.write_json(HISTORY_STORE_NAME, &id, &encode(&history)?)
+            .await
+    }
+}
+
+fn encode<T: serde::Serialize>(value: &T) -> Result<String, StorageError> {
+    serde_json::to_string(value)
+        .map_err(|e| StorageError::Internal(format!("Serialization failed: {}", e)))
+}
+
+fn decode<T: serde::de::DeserializeOwned>(json: &str) -> Result<T, StorageError> {
+    serde_json::from_str(json)
+        .map_err(|e| StorageError::Internal(format!("Deserialization failed: {}", e)))
+}
+
+#[async_trait(?Send)]
+impl StorageBackend for IndexedDbStorage {
+    async fn list(&self) -> Result<Vec<String>, StorageError> {
+        self.list_keys(STORE_NAME).await
+    }
+
+    async fn get(&self, id: &str) -> Result<ResumeData, StorageError> {
+        let json_str = self
+            .read_json(STORE_NAME, id)
+            .await?
+            .ok_or_else(|| StorageError::NotFound(id.to_string()))?;
+
+        serde_json::from_str(&json_str)
+            .map_err(|e| StorageError::Internal(format!("Deserialization failed: {}", e)))
+    }
+
+    async fn save(&self, id: &str, data: &ResumeData) -> Result<(), StorageError> {
+        let previous: Option<ResumeData> = match self.read_json(STORE_NAME, id).await? {
+            Some(json) => Some(decode(&json)?),
+            None => None,
+        };
+
+        // Serialize to JSON string for storage
+        let json_str = serde_json::to_string(data)
+            .map_err(|e| StorageError::Internal(format!("Serialization failed: {}", e)))?;
+
+        self.write_json(STORE_NAME, id, &json_str).await?;
+        self.write_json_many(TRASH_STORE_NAME, &[(id.to_string(), None)])
+            .await?;
+        self.update_search_index(id, Some(data)).await?;
+        self.record_history(HistoryEntry::for_save(id, previous.as_ref(), data))
+            .await
+    }
+
+    async fn delete(&self, id: &str) -> Result<(), StorageError> {
+        let json = self
+            .read_json(STORE_NAME, id)
+            .await?
+            .ok_or_else(|| StorageError::NotFound(id.to_string()))?;
+        let trashed = TrashedResume::new(id, decode(&json)?);
+        self.write_json(TRASH_STORE_NAME, id, &encode(&trashed)?)
+            .await?;
+        self.delete_key(STORE_NAME, id).await?;
+        self.update_search_index(id, None).await?;
+        self.record_history(Some(HistoryEntry::for_delete(id)))
+            .await
+    }
+
     async fn exists(&self, id: &str) -> Result<bool, StorageError> {
         let db = self.open_db().await?;
-        let store = self.get_store(&db, true)?;
+        let store = self.get_store(&db, STORE_NAME, true)?;
 
         let request = store
             .get(&JsValue::from_str(id))
@@ -238,6 +476,110 @@ impl StorageBackend for IndexedDbStorage {
     }
 }
 
+#[async_trait(?Send)]
+impl ApplicationStore for IndexedDbStorage {
+    async fn list_applications(&self) -> Result<Vec<JobApplication>, StorageError> {
+        let db = self.open_db().await?;
+        let store = self.get_store(&db, APPLICATIONS_STORE_NAME, true)?;
+
+        let request = store
+            .get_all()
+            .map_err(|e| StorageError::Internal(format!("Failed to get values: {:?}", e)))?;
+
+        let result = JsFuture::from(idb_request_to_promise(&request)?)
+            .await
+            .map_err(|e| StorageError::Internal(format!("Get values failed: {:?}", e)))?;
+
+        let array: Array = result
+            .dyn_into()
+            .map_err(|e| StorageError::Internal(format!("Invalid values array: {:?}", e)))?;
+
+        let mut applications = Vec::with_capacity(array.length() as usize);
+        for i in 0..array.length() {
+            let json_str = array.get(i).as_string().ok_or_else(|| {
+                StorageError::Internal("Stored value is not a string".to_string())
+            })?;
+            applications.push(
+                serde_json::from_str(&json_str).map_err(|e| {
+                    StorageError::Internal(format!("Deserialization failed: {}", e))
+                })?,
+            );
+        }
+
+        Ok(applications)
+    }
+
+    async fn get_application(&self, id: &str) -> Result<JobApplication, StorageError> {
+        let json_str = self
+            .read_json(APPLICATIONS_STORE_NAME, id)
+            .await?
+            .ok_or_else(|| StorageError::NotFound(id.to_string()))?;
+
+        serde_json::from_str(&json_str)
+            .map_err(|e| StorageError::Internal(format!("Deserialization failed: {}", e)))
+    }
+
+    async fn save_application(&self, application: &JobApplication) -> Result<(), StorageError> {
+        let json_str = serde_json::to_string(application)
+            .map_err(|e| StorageError::Internal(format!("Serialization failed: {}", e)))?;
+
+        self.write_json(APPLICATIONS_STORE_NAME, &application.id, &json_str)
+            .await
+    }
+
+    async fn delete_application(&self, id: &str) -> Result<(), StorageError> {
+        self.delete_key(APPLICATIONS_STORE_NAME, id).await
+    }
+}
+
+#[async_trait(?Send)]
+impl ResumeSearch for IndexedDbStorage {
+    async fn search(&self, query: &str) -> Result<Vec<SearchResult>, StorageError> {
+        let terms = query_terms(query);
+        if terms.is_empty() {
+            return Ok(Vec::new());
+        }
+        let total_resumes = self.index_missing_resumes().await?;
+
+        let mut term_postings: HashMap<String, Vec<Posting>> = HashMap::new();
+        let stored = self.read_json_many(SEARCH_TERMS_STORE_NAME, &terms).await?;
+        for (term, json) in terms.iter().zip(stored) {
+            if let Some(json) = json {
+                term_postings.insert(term.clone(), decode(&json)?);
+            }
+        }
+
+        // Only resumes containing every term need their fields loaded
+        let candidates: Vec<String> = terms
+            .iter()
+            .map(|term| {
+                term_postings
+                    .get(term)
+                    .into_iter()
+                    .flatten()
+                    .map(|posting| posting.resume_id.as_str())
+                    .collect::<HashSet<_>>()
+            })
+            .reduce(|a, b| a.intersection(&b).copied().collect())
+            .unwrap_or_default()
+            .into_iter()
+            .map(str::to_string)
+            .collect();
+
+        let stored = self
+            .read_json_many(SEARCH_FIELDS_STORE_NAME, &candidates)
+            .await?;
+        let mut fields: HashMap<String, Vec<SearchField>> = HashMap::new();
+        for (id, json) in candidates.into_iter().zip(stored) {
+            if let Some(json) = json {
+                fields.insert(id, decode(&json)?);
+            }
+        }
+
+        Ok(rank(&terms, &term_postings, &fields, total_resumes))
+    }
+}
+
 /// Convert an IdbRequest to a Promise.
 ///
 /// Uses Rc<RefCell<Option<Closure>>> pattern to manage closure lifetimes
@@ -298,3 +640,65 @@ fn idb_request_to_promise(request: &IdbRequest) -> Result<js_sys::Promise, Stora
 
     Ok(promise)
 }
+
+#[async_trait(?Send)]
+impl ResumeHistory for IndexedDbStorage {
+    async fn history(&self, id: &str) -> Result<Vec<HistoryEntry>, StorageError> {
+        match self.read_json(HISTORY_STORE_NAME, id).await? {
+            Some(json) => decode(&json),
+            None => Ok(Vec::new()),
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl ResumeTrash for IndexedDbStorage {
+    async fn list_trashed(&self) -> Result<Vec<TrashedResume>, StorageError> {
+        let ids = self.list_keys(TRASH_STORE_NAME).await?;
+        let mut trashed = Vec::with_capacity(ids.len());
+        for json in self
+            .read_json_many(TRASH_STORE_NAME, &ids)
+            .await?
+            .into_iter()
+            .flatten()
+        {
+            trashed.push(decode::<TrashedResume>(&json)?);
+        }
+        trashed.sort_by(|a, b| b.trashed_at.cmp(&a.trashed_at).then(a.id.cmp(&b.id)));
+        Ok(trashed)
+    }
+
+    async fn restore(&self, id: &str) -> Result<(), StorageError> {
+        let json = self
+            .read_json(TRASH_STORE_NAME, id)
+            .await?
+            .ok_or_else(|| StorageError::NotFound(id.to_string()))?;
+        if self.exists(id).await? {
+            return Err(StorageError::AlreadyExists(id.to_string()));
+        }
+        let trashed: TrashedResume = decode(&json)?;
+        self.write_json(STORE_NAME, id, &encode(&trashed.resume)?)
+            .await?;
+        self.delete_key(TRASH_STORE_NAME, id).await?;
+        self.update_search_index(id, Some(&trashed.resume)).await?;
+        self.record_history(Some(HistoryEntry::for_restore(id)))
+            .await
+    }
+
+    async fn purge(&self, id: &str) -> Result<(), StorageError> {
+        self.delete_key(TRASH_STORE_NAME, id).await
+    }
+
+    async fn purge_expired(&self, retention_days: u32) -> Result<usize, StorageError> {
+        let cutoff = purge_cutoff(retention_days);
+        let expired: Vec<(String, Option<String>)> = self
+            .list_trashed()
+            .await?
+            .into_iter()
+            .filter(|trashed| trashed.trashed_at <= cutoff)
+            .map(|trashed| (trashed.id, None))
+            .collect();
+        self.write_json_many(TRASH_STORE_NAME, &expired).await?;
+        Ok(expired.len())
+    }
+}
@@ -2,14 +2,39 @@
 //!
 //! Provides a unified interface for storing resumes across platforms:
 //! - IndexedDB (Web/WASM)
-//! - SQLite (Mobile/Desktop)
+//! - SQLite (Mobile/Desktop, `sqlite` feature)
 //! - In-memory (Testing)
+//!
+//! Each backend stores resumes ([`StorageBackend`]) and job applications
+//! ([`ApplicationStore`]), indexes resume text for [`ResumeSearch`], logs
+//! every change to a resume for [`ResumeHistory`], and keeps deleted resumes
+//! in a [`ResumeTrash`] until they are restored or purged.
+//!
+//! Outside the browser, [`UsageLog`] keeps the opt-in local usage counts
+//! shown by `rustume stats --usage`. The in-memory and SQLite backends also
+//! keep the server's [`ApiAuditLog`] of calls made with an API key.
 
+mod api_audit;
+mod history;
 mod memory;
+mod search;
 mod traits;
+mod trash;
 
+pub use api_audit::{
+    ApiCall, ApiCallQuery, API_AUDIT_RETENTION_DAYS, DEFAULT_API_CALL_LIMIT, MAX_API_CALL_LIMIT,
+};
+pub use history::{HistoryAction, HistoryEntry};
 pub use memory::MemoryStorage;
+pub use search::{SearchHit, SearchResult};
 pub use traits::*;
+pub use trash::{TrashedResume, TRASH_RETENTION_DAYS};
+
+#[cfg(not(target_arch = "wasm32"))]
+mod usage;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use usage::{data_dir, UsageLog, UsageStats, DATA_DIR_ENV, USAGE_LOG_FILE};
 
 #[cfg(target_arch = "wasm32")]
 mod indexeddb;
@@ -17,6 +42,12 @@ mod indexeddb;
 #[cfg(target_arch = "wasm32")]
 pub use indexeddb::IndexedDbStorage;
 
+#[cfg(all(feature = "sqlite", not(target_arch = "wasm32")))]
+mod sqlite;
+
+#[cfg(all(feature = "sqlite", not(target_arch = "wasm32")))]
+pub use sqlite::SqliteStorage;
+
 /// Storage configuration.
 #[derive(Debug, Clone)]
 pub struct StorageConfig {
@@ -35,8 +66,6 @@ pub enum StorageBackendType {
     Memory,
     /// IndexedDB (Web/WASM).
     IndexedDb,
-    /// SQLite (Mobile/Desktop).
-    ///
-    /// **Note:** Not yet implemented. Reserved for future mobile/desktop support.
+    /// SQLite (Mobile/Desktop, requires the `sqlite` feature).
     Sqlite,
 }
@@ -1,24 +1,53 @@
 //! In-memory storage backend for testing.
 
-use crate::traits::{StorageBackend, StorageError};
+use crate::api_audit::{ApiCall, ApiCallQuery};
+use crate::history::HistoryEntry;
+use crate::search::{SearchIndex, SearchResult};
+use crate::traits::{
+    ApiAuditLog, ApplicationStore, ResumeHistory, ResumeSearch, ResumeTrash, StorageBackend,
+    StorageError,
+};
+use crate::trash::{purge_cutoff, TrashedResume};
 use async_trait::async_trait;
-use rustume_schema::ResumeData;
+use rustume_schema::{JobApplication, ResumeData};
 use std::collections::HashMap;
 use std::sync::RwLock;
 
 /// In-memory storage backend.
 pub struct MemoryStorage {
     data: RwLock<HashMap<String, ResumeData>>,
+    applications: RwLock<HashMap<String, JobApplication>>,
+    index: RwLock<SearchIndex>,
+    history: RwLock<Vec<HistoryEntry>>,
+    trash: RwLock<HashMap<String, TrashedResume>>,
+    api_calls: RwLock<Vec<ApiCall>>,
 }
 
 impl MemoryStorage {
     pub fn new() -> Self {
         Self {
             data: RwLock::new(HashMap::new()),
+            applications: RwLock::new(HashMap::new()),
+            index: RwLock::new(SearchIndex::default()),
+            history: RwLock::new(Vec::new()),
+            trash: RwLock::new(HashMap::new()),
+            api_calls: RwLock::new(Vec::new()),
         }
     }
 }
 
+impl MemoryStorage {
+    fn record(&self, entry: Option<HistoryEntry>) -> Result<(), StorageError> {
+        if let Some(entry) = entry {
+            self.history
+                .write()
+                .map_err(|e| StorageError::Internal(e.to_string()))?
+                .push(entry);
+        }
+        Ok(())
+    }
+}
+
 impl Default for MemoryStorage {
     fn default() -> Self {
         Self::new()
@@ -50,8 +79,17 @@ impl StorageBackend for MemoryStorage {
             .data
             .write()
             .map_err(|e| StorageError::Internal(e.to_string()))?;
+        let entry = HistoryEntry::for_save(id, data.get(id), resume);
         data.insert(id.to_string(), resume.clone());
-        Ok(())
+        self.trash
+            .write()
+            .map_err(|e| StorageError::Internal(e.to_string()))?
+            .remove(id);
+        self.index
+            .write()
+            .map_err(|e| StorageError::Internal(e.to_string()))?
+            .insert(id, resume);
+        self.record(entry)
     }
 
     async fn delete(&self, id: &str) -> Result<(), StorageError> {
@@ -59,9 +97,18 @@ impl StorageBackend for MemoryStorage {
             .data
             .write()
             .map_err(|e| StorageError::Internal(e.to_string()))?;
-        data.remove(id)
+        let resume = data
+            .remove(id)
             .ok_or_else(|| StorageError::NotFound(id.to_string()))?;
-        Ok(())
+        self.trash
+            .write()
+            .map_err(|e| StorageError::Internal(e.to_string()))?
+            .insert(id.to_string(), TrashedResume::new(id, resume));
+        self.index
+            .write()
+            .map_err(|e| StorageError::Internal(e.to_string()))?
+            .remove(id);
+        self.record(Some(HistoryEntry::for_delete(id)))
     }
 
     async fn exists(&self, id: &str) -> Result<bool, StorageError> {
@@ -72,3 +119,163 @@ impl StorageBackend for MemoryStorage {
         Ok(data.contains_key(id))
     }
 }
+
+#[async_trait(?Send)]
+impl ApplicationStore for MemoryStorage {
+    async fn list_applications(&self) -> Result<Vec<JobApplication>, StorageError> {
+        let applications = self
+            .applications
+            .read()
+            .map_err(|e| StorageError::Internal(e.to_string()))?;
+        Ok(applications.values().cloned().collect())
+    }
+
+    async fn get_application(&self, id: &str) -> Result<JobApplication, StorageError> {
+        let applications = self
+            .applications
+            .read()
+            .map_err(|e| StorageError::Internal(e.to_string()))?;
+        applications
+            .get(id)
+            .cloned()
+            .ok_or_else(|| StorageError::NotFound(id.to_string()))
+    }
+
+    async fn save_application(&self, application: &JobApplication) -> Result<(), StorageError> {
+        let mut applications = self
+            .applications
+            .write()
+            .map_err(|e| StorageError::Internal(e.to_string()))?;
+        applications.insert(application.id.clone(), application.clone());
+        Ok(())
+    }
+
+    async fn delete_application(&self, id: &str) -> Result<(), StorageError> {
+        let mut applications = self
+            .applications
+            .write()
+            .map_err(|e| StorageError::Internal(e.to_string()))?;
+        applications
+            .remove(id)
+            .ok_or_else(|| StorageError::NotFound(id.to_string()))?;
+        Ok(())
+    }
+}
+
+#[async_trait(?Send)]
+impl ResumeSearch for MemoryStorage {
+    async fn search(&self, query: &str) -> Result<Vec<SearchResult>, StorageError> {
+        let index = self
+            .index
+            .read()
+            .map_err(|e| StorageError::Internal(e.to_string()))?;
+        Ok(index.search(query))
+    }
+}
+
+#[async_trait(?Send)]
+impl ResumeHistory for MemoryStorage {
+    async fn history(&self, id: &str) -> Result<Vec<HistoryEntry>, StorageError> {
+        let history = self
+            .history
+            .read()
+            .map_err(|e| StorageError::Internal(e.to_string()))?;
+        Ok(history
+            .iter()
+            .filter(|entry| entry.resume_id == id)
+            .cloned()
+            .collect())
+    }
+}
+
+#[async_trait(?Send)]
+impl ResumeTrash for MemoryStorage {
+    async fn list_trashed(&self) -> Result<Vec<TrashedResume>, StorageError> {
+        let trash = self
+            .trash
+            .read()
+            .map_err(|e| StorageError::Internal(e.to_string()))?;
+        let mut trashed: Vec<TrashedResume> = trash.values().cloned().collect();
+        trashed.sort_by(|a, b| b.trashed_at.cmp(&a.trashed_at).then(a.id.cmp(&b.id)));
+        Ok(trashed)
+    }
+
+    async fn restore(&self, id: &str) -> Result<(), StorageError> {
+        let mut data = self
+            .data
+            .write()
+            .map_err(|e| StorageError::Internal(e.to_string()))?;
+        let mut trash = self
+            .trash
+            .write()
+            .map_err(|e| StorageError::Internal(e.to_string()))?;
+        if data.contains_key(id) && trash.contains_key(id) {
+            return Err(StorageError::AlreadyExists(id.to_string()));
+        }
+        let trashed = trash
+            .remove(id)
+            .ok_or_else(|| StorageError::NotFound(id.to_string()))?;
+        self.index
+            .write()
+            .map_err(|e| StorageError::Internal(e.to_string()))?
+            .insert(id, &trashed.resume);
+        data.insert(id.to_string(), trashed.resume);
+        self.record(Some(HistoryEntry::for_restore(id)))
+    }
+
+    async fn purge(&self, id: &str) -> Result<(), StorageError> {
+        self.trash
+            .write()
+            .map_err(|e| StorageError::Internal(e.to_string()))?
+            .remove(id)
+            .map(|_| ())
+            .ok_or_else(|| StorageError::NotFound(id.to_string()))
+    }
+
+    async fn purge_expired(&self, retention_days: u32) -> Result<usize, StorageError> {
+        let cutoff = purge_cutoff(retention_days);
+        let mut trash = self
+            .trash
+            .write()
+            .map_err(|e| StorageError::Internal(e.to_string()))?;
+        let before = trash.len();
+        trash.retain(|_, trashed| trashed.trashed_at > cutoff);
+        Ok(before - trash.len())
+    }
+}
+
+#[async_trait]
+impl ApiAuditLog for MemoryStorage {
+    async fn record_api_call(&self, call: &ApiCall) -> Result<(), StorageError> {
+        self.api_calls
+            .write()
+            .map_err(|e| StorageError::Internal(e.to_string()))?
+            .push(call.clone());
+        Ok(())
+    }
+
+    async fn api_calls(&self, query: &ApiCallQuery) -> Result<Vec<ApiCall>, StorageError> {
+        let calls = self
+            .api_calls
+            .read()
+            .map_err(|e| StorageError::Internal(e.to_string()))?;
+        Ok(calls
+            .iter()
+            .rev()
+            .filter(|call| query.matches(call))
+            .take(query.limit())
+            .cloned()
+            .collect())
+    }
+
+    async fn purge_api_calls(&self, retention_days: u32) -> Result<usize, StorageError> {
+        let cutoff = purge_cutoff(retention_days);
+        let mut calls = self
+            .api_calls
+            .write()
+            .map_err(|e| StorageError::Internal(e.to_string()))?;
+        let before = calls.len();
+        calls.retain(|call| call.at > cutoff);
+        Ok(before - calls.len())
+    }
+}
@@ -0,0 +1,436 @@
+//! Full-text search over stored resumes.
+//!
+//! Every backend indexes the same fields: the string fields of `basics`, the
+//! summary and cover letter content, and each string (or string list) field of
+//! every section item, with rich text reduced to plain text. A query matches a
+//! resume when every query term appears somewhere in it; hits report the path
+//! of each matching field so callers can point at the section.
+
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use rustume_schema::ResumeData;
+use rustume_utils::html_text_blocks;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Maximum snippet length in characters.
+const SNIPPET_CHARS: usize = 120;
+
+/// Item fields holding identifiers or presentation data rather than content.
+const SKIPPED_FIELDS: &[&str] = &["id", "icon"];
+
+/// A field of a resume that matched a search.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchHit {
+    /// Section key (`basics`, `experience`, ...) or custom section ID.
+    pub section: String,
+    /// Dotted path of the field, e.g. `sections.experience.items[0].company`.
+    pub path: String,
+    /// Plain text of the field, shortened around the first match.
+    pub snippet: String,
+}
+
+/// A resume that matched a search.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchResult {
+    pub resume_id: String,
+    /// Relevance score (higher is better); only comparable within one search.
+    pub score: f64,
+    /// Matching fields in document order.
+    pub hits: Vec<SearchHit>,
+}
+
+/// One indexed field of a resume.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct SearchField {
+    pub section: String,
+    pub path: String,
+    pub text: String,
+}
+
+impl SearchField {
+    pub(crate) fn hit(&self, terms: &[String]) -> SearchHit {
+        SearchHit {
+            section: self.section.clone(),
+            path: self.path.clone(),
+            snippet: snippet(&self.text, terms),
+        }
+    }
+}
+
+/// Occurrences of a term in one field of one resume.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct Posting {
+    pub resume_id: String,
+    /// Index into the resume's [`SearchField`] list.
+    pub field: usize,
+    pub count: u32,
+}
+
+/// Split text into lowercase alphanumeric terms.
+pub(crate) fn tokenize(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|token| !token.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// Distinct terms of a query, in order.
+pub(crate) fn query_terms(query: &str) -> Vec<String> {
+    let mut seen = HashSet::new();
+    tokenize(query)
+        .into_iter()
+        .filter(|term| seen.insert(term.clone()))
+        .collect()
+}
+
+/// Extract the searchable fields of a resume.
+pub(crate) fn index_fields(resume: &ResumeData) -> Vec<SearchField> {
+    let Ok(value) = serde_json::to_value(resume) else {
+        return Vec::new();
+    };
+    let mut fields = Vec::new();
+
+    if let Some(basics) = value.get("basics").and_then(Value::as_object) {
+        for (key, field) in basics {
+            if let Some(text) = field.as_str() {
+                push_field(&mut fields, "basics", format!("basics.{key}"), text);
+            }
+        }
+    }
+
+    if let Some(sections) = value.get("sections").and_then(Value::as_object) {
+        for (key, section) in sections {
+            if key == "custom" {
+                for (id, custom) in section.as_object().into_iter().flatten() {
+                    collect_section(&mut fields, id, &format!("sections.custom.{id}"), custom);
+                }
+            } else {
+                collect_section(&mut fields, key, &format!("sections.{key}"), section);
+            }
+        }
+    }
+
+    fields
+}
+
+fn collect_section(fields: &mut Vec<SearchField>, section: &str, path: &str, value: &Value) {
+    if let Some(content) = value.get("content").and_then(Value::as_str) {
+        push_field(fields, section, format!("{path}.content"), content);
+    }
+
+    let items = value.get("items").and_then(Value::as_array);
+    for (i, item) in items.into_iter().flatten().enumerate() {
+        for (key, field) in item.as_object().into_iter().flatten() {
+            if SKIPPED_FIELDS.contains(&key.as_str()) {
+                continue;
+            }
+            let text = match field {
+                Value::String(text) => text.clone(),
+                Value::Array(values) => values
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                _ => continue,
+            };
+            push_field(fields, section, format!("{path}.items[{i}].{key}"), &text);
+        }
+    }
+}
+
+fn push_field(fields: &mut Vec<SearchField>, section: &str, path: String, raw: &str) {
+    let text = html_text_blocks(raw).join(" ");
+    if !text.is_empty() {
+        fields.push(SearchField {
+            section: section.to_string(),
+            path,
+            text,
+        });
+    }
+}
+
+/// Build the posting lists for a resume's fields, keyed by term.
+pub(crate) fn postings(resume_id: &str, fields: &[SearchField]) -> HashMap<String, Vec<Posting>> {
+    let mut postings: HashMap<String, Vec<Posting>> = HashMap::new();
+    for (index, field) in fields.iter().enumerate() {
+        let mut counts: HashMap<String, u32> = HashMap::new();
+        for term in tokenize(&field.text) {
+            *counts.entry(term).or_default() += 1;
+        }
+        for (term, count) in counts {
+            postings.entry(term).or_default().push(Posting {
+                resume_id: resume_id.to_string(),
+                field: index,
+                count,
+            });
+        }
+    }
+    postings
+}
+
+/// Rank the resumes that contain every term.
+///
+/// `postings` must hold the posting list of each term (missing terms match
+/// nothing) and `fields` the indexed fields of each matching resume. Each term
+/// contributes `idf * (1 + ln(tf))` to a resume's score.
+pub(crate) fn rank(
+    terms: &[String],
+    postings: &HashMap<String, Vec<Posting>>,
+    fields: &HashMap<String, Vec<SearchField>>,
+    total_resumes: usize,
+) -> Vec<SearchResult> {
+    let mut candidates: Option<HashSet<&str>> = None;
+    let mut scores: HashMap<&str, f64> = HashMap::new();
+    let mut matched: HashMap<&str, BTreeSet<usize>> = HashMap::new();
+
+    for term in terms {
+        let mut frequencies: HashMap<&str, u32> = HashMap::new();
+        for posting in postings.get(term).into_iter().flatten() {
+            *frequencies.entry(&posting.resume_id).or_default() += posting.count;
+            matched
+                .entry(&posting.resume_id)
+                .or_default()
+                .insert(posting.field);
+        }
+
+        let idf = (1.0 + total_resumes as f64 / frequencies.len().max(1) as f64).ln();
+        for (&resume_id, &count) in &frequencies {
+            *scores.entry(resume_id).or_default() += idf * (1.0 + f64::from(count).ln());
+        }
+
+        let ids: HashSet<&str> = frequencies.into_keys().collect();
+        candidates = Some(match candidates {
+            Some(previous) => previous.intersection(&ids).copied().collect(),
+            None => ids,
+        });
+    }
+
+    let mut results: Vec<SearchResult> = candidates
+        .unwrap_or_default()
+        .into_iter()
+        .map(|resume_id| {
+            let resume_fields = fields.get(resume_id).map(Vec::as_slice).unwrap_or_default();
+            let hits = matched
+                .get(resume_id)
+                .into_iter()
+                .flatten()
+                .filter_map(|&index| resume_fields.get(index))
+                .map(|field| field.hit(terms))
+                .collect();
+            SearchResult {
+                resume_id: resume_id.to_string(),
+                score: scores.get(resume_id).copied().unwrap_or_default(),
+                hits,
+            }
+        })
+        .collect();
+    sort_results(&mut results);
+    results
+}
+
+/// Order results by descending score, then by resume ID.
+pub(crate) fn sort_results(results: &mut [SearchResult]) {
+    results.sort_by(|a, b| {
+        b.score
+            .total_cmp(&a.score)
+            .then_with(|| a.resume_id.cmp(&b.resume_id))
+    });
+}
+
+/// Shorten `text` to a window around the first occurrence of any term.
+pub(crate) fn snippet(text: &str, terms: &[String]) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    if chars.len() <= SNIPPET_CHARS {
+        return text.to_string();
+    }
+
+    let position = first_match(&chars, terms).unwrap_or(0);
+    let start = position
+        .saturating_sub(SNIPPET_CHARS / 3)
+        .min(chars.len() - SNIPPET_CHARS);
+    let end = start + SNIPPET_CHARS;
+
+    let mut excerpt: String = chars[start..end].iter().collect();
+    excerpt = excerpt.trim().to_string();
+    if start > 0 {
+        excerpt.insert(0, '…');
+    }
+    if end < chars.len() {
+        excerpt.push('…');
+    }
+    excerpt
+}
+
+/// Character offset of the first token that is one of `terms`.
+fn first_match(chars: &[char], terms: &[String]) -> Option<usize> {
+    let mut start = None;
+    let mut token = String::new();
+    for (i, &c) in chars.iter().chain(std::iter::once(&' ')).enumerate() {
+        if c.is_alphanumeric() {
+            start.get_or_insert(i);
+            token.push(c);
+        } else if let Some(token_start) = start.take() {
+            if terms.contains(&token.to_lowercase()) {
+                return Some(token_start);
+            }
+            token.clear();
+        }
+    }
+    None
+}
+
+/// In-memory inverted index, used by [`MemoryStorage`](crate::MemoryStorage).
+#[derive(Debug, Default)]
+pub(crate) struct SearchIndex {
+    fields: HashMap<String, Vec<SearchField>>,
+    postings: HashMap<String, Vec<Posting>>,
+}
+
+impl SearchIndex {
+    /// Index (or re-index) a resume.
+    pub(crate) fn insert(&mut self, resume_id: &str, resume: &ResumeData) {
+        self.remove(resume_id);
+        let fields = index_fields(resume);
+        for (term, list) in postings(resume_id, &fields) {
+            self.postings.entry(term).or_default().extend(list);
+        }
+        self.fields.insert(resume_id.to_string(), fields);
+    }
+
+    /// Drop a resume from the index.
+    pub(crate) fn remove(&mut self, resume_id: &str) {
+        let Some(fields) = self.fields.remove(resume_id) else {
+            return;
+        };
+        for term in postings(resume_id, &fields).into_keys() {
+            if let Some(list) = self.postings.get_mut(&term) {
+                list.retain(|posting| posting.resume_id != resume_id);
+                if list.is_empty() {
+                    self.postings.remove(&term);
+                }
+            }
+        }
+    }
+
+    pub(crate) fn search(&self, query: &str) -> Vec<SearchResult> {
+        rank(
+            &query_terms(query),
+            &self.postings,
+            &self.fields,
+            self.fields.len(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rustume_schema::{Basics, Experience, Project, Section, Skill};
+
+    fn resume(name: &str, company: &str, project: &str) -> ResumeData {
+        let mut resume = ResumeData {
+            basics: Basics::new(name),
+            ..ResumeData::default()
+        };
+        resume.sections.experience = Section::new("experience", "Experience");
+        resume.sections.experience.add_item(
+            Experience::new(company, "Engineer")
+                .with_summary("<ul><li>Built the <strong>billing</strong> pipeline</li></ul>"),
+        );
+        resume.sections.projects = Section::new("projects", "Projects");
+        resume
+            .sections
+            .projects
+            .add_item(Project::new(project).with_description("Open source CLI"));
+        resume.sections.skills = Section::new("skills", "Skills");
+        resume
+            .sections
+            .skills
+            .add_item(Skill::new("Languages").with_keywords(vec!["Rust".into(), "Go".into()]));
+        resume
+    }
+
+    #[test]
+    fn test_index_fields_paths_and_plain_text() {
+        let fields = index_fields(&resume("Jane Doe", "Acme", "Rustume"));
+        let field = |path: &str| fields.iter().find(|f| f.path == path).map(|f| &f.text);
+
+        assert_eq!(field("basics.name").unwrap(), "Jane Doe");
+        assert_eq!(
+            field("sections.experience.items[0].company").unwrap(),
+            "Acme"
+        );
+        assert_eq!(
+            field("sections.experience.items[0].summary").unwrap(),
+            "Built the billing pipeline"
+        );
+        assert_eq!(
+            field("sections.skills.items[0].keywords").unwrap(),
+            "Rust, Go"
+        );
+        assert!(fields.iter().all(|f| !f.path.ends_with(".id")));
+    }
+
+    #[test]
+    fn test_search_requires_every_term_and_reports_hits() {
+        let mut index = SearchIndex::default();
+        index.insert("a", &resume("Jane", "Acme", "Rustume"));
+        index.insert("b", &resume("John", "Globex", "Orbit"));
+
+        let results = index.search("billing ACME");
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].resume_id, "a");
+        let sections: Vec<&str> = results[0].hits.iter().map(|h| h.section.as_str()).collect();
+        assert_eq!(sections, vec!["experience", "experience"]);
+
+        assert_eq!(index.search("billing").len(), 2);
+        assert!(index.search("billing kubernetes").is_empty());
+        assert!(index.search("  ").is_empty());
+    }
+
+    #[test]
+    fn test_search_ranks_rarer_and_repeated_terms_higher() {
+        let mut index = SearchIndex::default();
+        index.insert("once", &resume("Jane", "Acme", "Orbit"));
+        index.insert("twice", &resume("John", "Orbit", "Orbit"));
+        index.insert("none", &resume("Jim", "Globex", "Atlas"));
+
+        let ids: Vec<String> = index
+            .search("orbit")
+            .into_iter()
+            .map(|r| r.resume_id)
+            .collect();
+        assert_eq!(ids, vec!["twice", "once"]);
+    }
+
+    #[test]
+    fn test_reindex_and_remove() {
+        let mut index = SearchIndex::default();
+        index.insert("a", &resume("Jane", "Acme", "Rustume"));
+        index.insert("a", &resume("Jane", "Initech", "Rustume"));
+        assert!(index.search("acme").is_empty());
+        assert_eq!(index.search("initech").len(), 1);
+
+        index.remove("a");
+        assert!(index.search("rustume").is_empty());
+        assert!(index.postings.is_empty());
+    }
+
+    #[test]
+    fn test_snippet_centers_on_first_match() {
+        let text = format!(
+            "{} Kubernetes migration {}",
+            "lorem ".repeat(40),
+            "ipsum ".repeat(40)
+        );
+        let snippet = snippet(&text, &["kubernetes".to_string()]);
+
+        assert!(snippet.starts_with('…') && snippet.ends_with('…'));
+        assert!(snippet.contains("Kubernetes migration"));
+        assert!(snippet.chars().count() <= SNIPPET_CHARS + 2);
+        assert_eq!(super::snippet("short text", &[]), "short text");
+    }
+}
@@ -0,0 +1,597 @@
+//! SQLite storage backend for desktop and mobile.
+//!
+//! Resumes and job applications are stored as JSON text, one table each.
+//! Applications also keep their status and linked resume in columns so they
+//! can be filtered without parsing every row. Resume text is indexed in an
+//! FTS5 table, one row per searchable field. Every change to a resume
+//! appends a row to `resume_history` in the same transaction. Deleting a
+//! resume moves its row to `resume_trash`. Server API calls go to
+//! `api_audit`, which a trigger keeps append-only.
+
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use crate::api_audit::{ApiCall, ApiCallQuery};
+use crate::history::{HistoryAction, HistoryEntry};
+use crate::search::{index_fields, query_terms, sort_results, tokenize, SearchField, SearchResult};
+use crate::traits::{
+    ApiAuditLog, ApplicationStore, ResumeHistory, ResumeSearch, ResumeTrash, StorageBackend,
+    StorageError,
+};
+use crate::trash::{purge_cutoff, timestamp, TrashedResume};
+use async_trait::async_trait;
+use chrono::Utc;
+use rustume_schema::{JobApplication, ResumeData};
+use sqlx::sqlite::{SqliteConnectOptions, SqliteConnection, SqlitePool, SqlitePoolOptions};
+
+const SCHEMA: &str = r#"
+CREATE TABLE IF NOT EXISTS resumes (
+    id TEXT PRIMARY KEY NOT NULL,
+    data TEXT NOT NULL,
+    updated_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE TABLE IF NOT EXISTS applications (
+    id TEXT PRIMARY KEY NOT NULL,
+    resume_id TEXT,
+    status TEXT NOT NULL,
+    data TEXT NOT NULL,
+    updated_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS applications_resume_id_idx ON applications (resume_id);
+
+CREATE VIRTUAL TABLE IF NOT EXISTS resume_search USING fts5(
+    resume_id UNINDEXED,
+    section UNINDEXED,
+    path UNINDEXED,
+    content,
+    tokenize = 'unicode61 remove_diacritics 0'
+);
+
+CREATE TABLE IF NOT EXISTS resume_history (
+    seq INTEGER PRIMARY KEY AUTOINCREMENT,
+    resume_id TEXT NOT NULL,
+    at TEXT NOT NULL,
+    author TEXT,
+    action TEXT NOT NULL,
+    summary TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS resume_history_resume_id_idx ON resume_history (resume_id, seq);
+
+CREATE TABLE IF NOT EXISTS resume_trash (
+    id TEXT PRIMARY KEY NOT NULL,
+    data TEXT NOT NULL,
+    trashed_at TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS api_audit (
+    seq INTEGER PRIMARY KEY AUTOINCREMENT,
+    at TEXT NOT NULL,
+    api_key TEXT NOT NULL,
+    endpoint TEXT NOT NULL,
+    resume_hash TEXT,
+    status INTEGER NOT NULL,
+    duration_ms INTEGER NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS api_audit_at_idx ON api_audit (at);
+
+CREATE TRIGGER IF NOT EXISTS api_audit_append_only BEFORE UPDATE ON api_audit
+BEGIN
+    SELECT RAISE(ABORT, 'api_audit is append-only');
+END;
+"#;
+
+/// `PRAGMA user_version` once `resume_search` covers every stored resume.
+/// Databases below it are re-indexed when opened.
+const SEARCH_INDEX_VERSION: i64 = 1;
+
+/// SQLite storage backend.
+pub struct SqliteStorage {
+    pool: SqlitePool,
+}
+
+impl SqliteStorage {
+    /// Open the database file at `path`, creating it if needed.
+    pub async fn open(path: impl AsRef<Path>) -> Result<Self, StorageError> {
+        let options = SqliteConnectOptions::new()
+            .filename(path)
+            .create_if_missing(true);
+        let pool = SqlitePoolOptions::new()
+            .connect_with(options)
+            .await
+            .map_err(db_error)?;
+        Self::with_pool(pool).await
+    }
+
+    /// Open a private in-memory database (testing).
+    pub async fn in_memory() -> Result<Self, StorageError> {
+        // Each in-memory connection is its own database, so keep exactly one alive.
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .idle_timeout(None)
+            .max_lifetime(None)
+            .connect_with(SqliteConnectOptions::new().in_memory(true))
+            .await
+            .map_err(db_error)?;
+        Self::with_pool(pool).await
+    }
+
+    /// Use an existing pool, creating tables if they do not exist.
+    pub async fn with_pool(pool: SqlitePool) -> Result<Self, StorageError> {
+        sqlx::raw_sql(SCHEMA)
+            .execute(&pool)
+            .await
+            .map_err(db_error)?;
+
+        let version: i64 = sqlx::query_scalar("PRAGMA user_version")
+            .fetch_one(&pool)
+            .await
+            .map_err(db_error)?;
+        if version < SEARCH_INDEX_VERSION {
+            rebuild_search_index(&pool).await?;
+        }
+        Ok(Self { pool })
+    }
+}
+
+/// Re-index every stored resume and record the index version.
+async fn rebuild_search_index(pool: &SqlitePool) -> Result<(), StorageError> {
+    let mut tx = pool.begin().await.map_err(db_error)?;
+    let rows: Vec<(String, String)> = sqlx::query_as("SELECT id, data FROM resumes")
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(db_error)?;
+    for (id, data) in rows {
+        // Rows that no longer deserialize cannot be loaded either; leave them unindexed.
+        if let Ok(resume) = decode::<ResumeData>(&data) {
+            index_resume(&mut tx, &id, &resume).await?;
+        }
+    }
+    sqlx::raw_sql(sqlx::AssertSqlSafe(format!(
+        "PRAGMA user_version = {SEARCH_INDEX_VERSION}"
+    )))
+    .execute(&mut *tx)
+    .await
+    .map_err(db_error)?;
+    tx.commit().await.map_err(db_error)
+}
+
+/// Replace the search rows of one resume.
+async fn index_resume(
+    conn: &mut SqliteConnection,
+    id: &str,
+    resume: &ResumeData,
+) -> Result<(), StorageError> {
+    sqlx::query("DELETE FROM resume_search WHERE resume_id = ?")
+        .bind(id)
+        .execute(&mut *conn)
+        .await
+        .map_err(db_error)?;
+    for field in index_fields(resume) {
+        sqlx::query(
+            "INSERT INTO resume_search (resume_id, section, path, content) VALUES (?, ?, ?, ?)",
+        )
+        .bind(id)
+        .bind(field.section)
+        .bind(field.path)
+        .bind(field.text)
+        .execute(&mut *conn)
+        .await
+        .map_err(db_error)?;
+    }
+    Ok(())
+}
+
+/// Append `entry` to the change log.
+async fn record_history(
+    conn: &mut SqliteConnection,
+    entry: &HistoryEntry,
+) -> Result<(), StorageError> {
+    sqlx::query(
+        "INSERT INTO resume_history (resume_id, at, author, action, summary) VALUES (?, ?, ?, ?, ?)",
+    )
+    .bind(&entry.resume_id)
+    .bind(&entry.at)
+    .bind(&entry.author)
+    .bind(entry.action.as_str())
+    .bind(&entry.summary)
+    .execute(&mut *conn)
+    .await
+    .map_err(db_error)?;
+    Ok(())
+}
+
+fn db_error(err: impl std::fmt::Display) -> StorageError {
+    StorageError::Internal(err.to_string())
+}
+
+fn decode<T: serde::de::DeserializeOwned>(json: &str) -> Result<T, StorageError> {
+    serde_json::from_str(json)
+        .map_err(|e| StorageError::Internal(format!("Deserialization failed: {}", e)))
+}
+
+fn encode<T: serde::Serialize>(value: &T) -> Result<String, StorageError> {
+    serde_json::to_string(value)
+        .map_err(|e| StorageError::Internal(format!("Serialization failed: {}", e)))
+}
+
+#[async_trait(?Send)]
+impl StorageBackend for SqliteStorage {
+    async fn list(&self) -> Result<Vec<String>, StorageError> {
+        sqlx::query_scalar("SELECT id FROM resumes ORDER BY id")
+            .fetch_all(&self.pool)
+            .await
+            .map_err(db_error)
+    }
+
+    async fn get(&self, id: &str) -> Result<ResumeData, StorageError> {
+        let data: String = sqlx::query_scalar("SELECT data FROM resumes WHERE id = ?")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(db_error)?
+            .ok_or_else(|| StorageError::NotFound(id.to_string()))?;
+        decode(&data)
+    }
+
+    async fn save(&self, id: &str, data: &ResumeData) -> Result<(), StorageError> {
+        let mut tx = self.pool.begin().await.map_err(db_error)?;
+        let previous: Option<String> = sqlx::query_scalar("SELECT data FROM resumes WHERE id = ?")
+            .bind(id)
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(db_error)?;
+        let previous: Option<ResumeData> = previous.as_deref().map(decode).transpose()?;
+        sqlx::query(
+            r#"
+            INSERT INTO resumes (id, data) VALUES (?, ?)
+            ON CONFLICT (id) DO UPDATE SET data = excluded.data, updated_at = CURRENT_TIMESTAMP
+            "#,
+        )
+        .bind(id)
+        .bind(encode(data)?)
+        .execute(&mut *tx)
+        .await
+        .map_err(db_error)?;
+        index_resume(&mut tx, id, data).await?;
+        sqlx::query("DELETE FROM resume_trash WHERE id = ?")
+            .bind(id)
+            .execute(&mut *tx)
+            .await
+            .map_err(db_error)?;
+        if let Some(entry) = HistoryEntry::for_save(id, previous.as_ref(), data) {
+            record_history(&mut tx, &entry).await?;
+        }
+        tx.commit().await.map_err(db_error)
+    }
+
+    async fn delete(&self, id: &str) -> Result<(), StorageError> {
+        let mut tx = self.pool.begin().await.map_err(db_error)?;
+        let result = sqlx::query(
+            r#"
+            INSERT OR REPLACE INTO resume_trash (id, data, trashed_at)
+            SELECT id, data, ? FROM resumes WHERE id = ?
+            "#,
+        )
+        .bind(timestamp(Utc::now()))
+        .bind(id)
+        .execute(&mut *tx)
+        .await
+        .map_err(db_error)?;
+        if result.rows_affected() == 0 {
+            return Err(StorageError::NotFound(id.to_string()));
+        }
+        sqlx::query("DELETE FROM resumes WHERE id = ?")
+            .bind(id)
+            .execute(&mut *tx)
+            .await
+            .map_err(db_error)?;
+        sqlx::query("DELETE FROM resume_search WHERE resume_id = ?")
+            .bind(id)
+            .execute(&mut *tx)
+            .await
+            .map_err(db_error)?;
+        record_history(&mut tx, &HistoryEntry::for_delete(id)).await?;
+        tx.commit().await.map_err(db_error)
+    }
+
+    async fn exists(&self, id: &str) -> Result<bool, StorageError> {
+        sqlx::query_scalar("SELECT EXISTS (SELECT 1 FROM resumes WHERE id = ?)")
+            .bind(id)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(db_error)
+    }
+}
+
+#[async_trait(?Send)]
+impl ApplicationStore for SqliteStorage {
+    async fn list_applications(&self) -> Result<Vec<JobApplication>, StorageError> {
+        let rows: Vec<String> =
+            sqlx::query_scalar("SELECT data FROM applications ORDER BY updated_at DESC, id")
+                .fetch_all(&self.pool)
+                .await
+                .map_err(db_error)?;
+        rows.iter().map(|data| decode(data)).collect()
+    }
+
+    async fn get_application(&self, id: &str) -> Result<JobApplication, StorageError> {
+        let data: String = sqlx::query_scalar("SELECT data FROM applications WHERE id = ?")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(db_error)?
+            .ok_or_else(|| StorageError::NotFound(id.to_string()))?;
+        decode(&data)
+    }
+
+    async fn save_application(&self, application: &JobApplication) -> Result<(), StorageError> {
+        sqlx::query(
+            r#"
+            INSERT INTO applications (id, resume_id, status, data) VALUES (?, ?, ?, ?)
+            ON CONFLICT (id) DO UPDATE SET
+                resume_id = excluded.resume_id,
+                status = excluded.status,
+                data = excluded.data,
+                updated_at = CURRENT_TIMESTAMP
+            "#,
+        )
+        .bind(&application.id)
+        .bind(application.resume_id.as_deref())
+        .bind(application.status.as_str())
+        .bind(encode(application)?)
+        .execute(&self.pool)
+        .await
+        .map_err(db_error)?;
+        Ok(())
+    }
+
+    async fn delete_application(&self, id: &str) -> Result<(), StorageError> {
+        let result = sqlx::query("DELETE FROM applications WHERE id = ?")
+            .bind(id)
+            .execute(&self.pool)
+            .await
+            .map_err(db_error)?;
+        if result.rows_affected() == 0 {
+            return Err(StorageError::NotFound(id.to_string()));
+        }
+        Ok(())
+    }
+
+    async fn applications_for_resume(
+        &self,
+        resume_id: &str,
+    ) -> Result<Vec<JobApplication>, StorageError> {
+        let rows: Vec<String> = sqlx::query_scalar(
+            "SELECT data FROM applications WHERE resume_id = ? ORDER BY updated_at DESC, id",
+        )
+        .bind(resume_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(db_error)?;
+        rows.iter().map(|data| decode(data)).collect()
+    }
+}
+
+#[async_trait(?Send)]
+impl ResumeSearch for SqliteStorage {
+    async fn search(&self, query: &str) -> Result<Vec<SearchResult>, StorageError> {
+        let terms = query_terms(query);
+        if terms.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        // Match fields containing any term; resumes missing a term are dropped below.
+        let fts_query = terms
+            .iter()
+            .map(|term| format!("\"{term}\""))
+            .collect::<Vec<_>>()
+            .join(" OR ");
+        let rows: Vec<(String, String, String, String, f64)> = sqlx::query_as(
+            r#"
+            SELECT resume_id, section, path, content, bm25(resume_search)
+            FROM resume_search
+            WHERE resume_search MATCH ?
+            ORDER BY rowid
+            "#,
+        )
+        .bind(fts_query)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        let mut results: HashMap<String, (SearchResult, HashSet<String>)> = HashMap::new();
+        for (resume_id, section, path, text, bm25) in rows {
+            let (result, found) = results.entry(resume_id.clone()).or_insert_with(|| {
+                let result = SearchResult {
+                    resume_id,
+                    score: 0.0,
+                    hits: Vec::new(),
+                };
+                (result, HashSet::new())
+            });
+            found.extend(
+                tokenize(&text)
+                    .into_iter()
+                    .filter(|token| terms.contains(token)),
+            );
+            // bm25() is negative, with better matches further below zero.
+            result.score -= bm25;
+            result.hits.push(
+                SearchField {
+                    section,
+                    path,
+                    text,
+                }
+                .hit(&terms),
+            );
+        }
+
+        let mut results: Vec<SearchResult> = results
+            .into_values()
+            .filter(|(_, found)| found.len() == terms.len())
+            .map(|(result, _)| result)
+            .collect();
+        sort_results(&mut results);
+        Ok(results)
+    }
+}
+
+#[async_trait(?Send)]
+impl ResumeHistory for SqliteStorage {
+    async fn history(&self, id: &str) -> Result<Vec<HistoryEntry>, StorageError> {
+        let rows: Vec<(String, Option<String>, String, String)> = sqlx::query_as(
+            "SELECT at, author, action, summary FROM resume_history WHERE resume_id = ? ORDER BY seq",
+        )
+        .bind(id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(db_error)?;
+        rows.into_iter()
+            .map(|(at, author, action, summary)| {
+                let action = HistoryAction::parse(&action).ok_or_else(|| {
+                    StorageError::Internal(format!("Unknown history action: {action}"))
+                })?;
+                Ok(HistoryEntry {
+                    resume_id: id.to_string(),
+                    at,
+                    author,
+                    action,
+                    summary,
+                })
+            })
+            .collect()
+    }
+}
+
+#[async_trait(?Send)]
+impl ResumeTrash for SqliteStorage {
+    async fn list_trashed(&self) -> Result<Vec<TrashedResume>, StorageError> {
+        let rows: Vec<(String, String, String)> = sqlx::query_as(
+            "SELECT id, data, trashed_at FROM resume_trash ORDER BY trashed_at DESC, id",
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(db_error)?;
+        rows.into_iter()
+            .map(|(id, data, trashed_at)| {
+                Ok(TrashedResume {
+                    id,
+                    trashed_at,
+                    resume: decode(&data)?,
+                })
+            })
+            .collect()
+    }
+
+    async fn restore(&self, id: &str) -> Result<(), StorageError> {
+        let mut tx = self.pool.begin().await.map_err(db_error)?;
+        let data: String = sqlx::query_scalar("SELECT data FROM resume_trash WHERE id = ?")
+            .bind(id)
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(db_error)?
+            .ok_or_else(|| StorageError::NotFound(id.to_string()))?;
+        let resume: ResumeData = decode(&data)?;
+        let result =
+            sqlx::query("INSERT INTO resumes (id, data) VALUES (?, ?) ON CONFLICT DO NOTHING")
+                .bind(id)
+                .bind(&data)
+                .execute(&mut *tx)
+                .await
+                .map_err(db_error)?;
+        if result.rows_affected() == 0 {
+            return Err(StorageError::AlreadyExists(id.to_string()));
+        }
+        sqlx::query("DELETE FROM resume_trash WHERE id = ?")
+            .bind(id)
+            .execute(&mut *tx)
+            .await
+            .map_err(db_error)?;
+        index_resume(&mut tx, id, &resume).await?;
+        record_history(&mut tx, &HistoryEntry::for_restore(id)).await?;
+        tx.commit().await.map_err(db_error)
+    }
+
+    async fn purge(&self, id: &str) -> Result<(), StorageError> {
+        let result = sqlx::query("DELETE FROM resume_trash WHERE id = ?")
+            .bind(id)
+            .execute(&self.pool)
+            .await
+            .map_err(db_error)?;
+        if result.rows_affected() == 0 {
+            return Err(StorageError::NotFound(id.to_string()));
+        }
+        Ok(())
+    }
+
+    async fn purge_expired(&self, retention_days: u32) -> Result<usize, StorageError> {
+        let result = sqlx::query("DELETE FROM resume_trash WHERE trashed_at <= ?")
+            .bind(purge_cutoff(retention_days))
+            .execute(&self.pool)
+            .await
+            .map_err(db_error)?;
+        Ok(result.rows_affected() as usize)
+    }
+}
+
+#[async_trait]
+impl ApiAuditLog for SqliteStorage {
+    async fn record_api_call(&self, call: &ApiCall) -> Result<(), StorageError> {
+        sqlx::query(
+            "INSERT INTO api_audit (at, api_key, endpoint, resume_hash, status, duration_ms) \
+             VALUES (?, ?, ?, ?, ?, ?)",
+        )
+        .bind(&call.at)
+        .bind(&call.api_key)
+        .bind(&call.endpoint)
+        .bind(&call.resume_hash)
+        .bind(i64::from(call.status))
+        .bind(i64::try_from(call.duration_ms).unwrap_or(i64::MAX))
+        .execute(&self.pool)
+        .await
+        .map_err(db_error)?;
+        Ok(())
+    }
+
+    async fn api_calls(&self, query: &ApiCallQuery) -> Result<Vec<ApiCall>, StorageError> {
+        let rows: Vec<(String, String, String, Option<String>, i64, i64)> = sqlx::query_as(
+            "SELECT at, api_key, endpoint, resume_hash, status, duration_ms FROM api_audit \
+             WHERE (?1 IS NULL OR api_key = ?1) AND (?2 IS NULL OR at >= ?2) \
+             AND (?3 IS NULL OR at < ?3) \
+             ORDER BY seq DESC LIMIT ?4",
+        )
+        .bind(&query.api_key)
+        .bind(&query.since)
+        .bind(&query.until)
+        .bind(i64::try_from(query.limit()).unwrap_or(i64::MAX))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(db_error)?;
+        Ok(rows
+            .into_iter()
+            .map(
+                |(at, api_key, endpoint, resume_hash, status, duration_ms)| ApiCall {
+                    at,
+                    api_key,
+                    endpoint,
+                    resume_hash,
+                    status: u16::try_from(status).unwrap_or_default(),
+                    duration_ms: u64::try_from(duration_ms).unwrap_or_default(),
+                },
+            )
+            .collect())
+    }
+
+    async fn purge_api_calls(&self, retention_days: u32) -> Result<usize, StorageError> {
+        let result = sqlx::query("DELETE FROM api_audit WHERE at <= ?")
+            .bind(purge_cutoff(retention_days))
+            .execute(&self.pool)
+            .await
+            .map_err(db_error)?;
+        Ok(result.rows_affected() as usize)
+    }
+}
@@ -1,9 +1,14 @@
 //! Storage trait definitions.
 
 use async_trait::async_trait;
-use rustume_schema::ResumeData;
+use rustume_schema::{ErrorCode, JobApplication, ResumeData, RustumeError};
 use thiserror::Error;
 
+use crate::api_audit::{ApiCall, ApiCallQuery};
+use crate::history::HistoryEntry;
+use crate::search::SearchResult;
+use crate::trash::TrashedResume;
+
 /// Storage error types.
 #[derive(Error, Debug)]
 pub enum StorageError {
@@ -11,9 +16,9 @@ pub enum StorageError {
     #[error("Not found: {0}")]
     NotFound(String),
 
-    /// A resource with the same identifier already exists.
-    ///
-    /// **Note:** Reserved for future `create` method. Current `save` uses upsert semantics.
+    /// A resource with the same identifier already exists, such as when
+    /// restoring a trashed resume whose ID is in use again. `save` upserts
+    /// and never returns it.
     #[error("Already exists: {0}")]
     AlreadyExists(String),
 
@@ -22,6 +27,23 @@ pub enum StorageError {
     Internal(String),
 }
 
+impl StorageError {
+    /// Stable code for this error.
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Self::NotFound(_) => ErrorCode::StorageNotFound,
+            Self::AlreadyExists(_) => ErrorCode::StorageAlreadyExists,
+            Self::Internal(_) => ErrorCode::StorageInternal,
+        }
+    }
+}
+
+impl From<StorageError> for RustumeError {
+    fn from(err: StorageError) -> Self {
+        Self::new(err.code(), err.to_string())
+    }
+}
+
 /// Storage backend trait.
 #[async_trait(?Send)]
 pub trait StorageBackend {
@@ -31,12 +53,102 @@ pub trait StorageBackend {
     /// Get resume by ID.
     async fn get(&self, id: &str) -> Result<ResumeData, StorageError>;
 
-    /// Save resume (upsert).
+    /// Save resume (upsert). Saving under the ID of a trashed resume
+    /// replaces the trashed copy.
     async fn save(&self, id: &str, data: &ResumeData) -> Result<(), StorageError>;
 
-    /// Delete resume.
+    /// Delete resume, moving it to the [`ResumeTrash`].
     async fn delete(&self, id: &str) -> Result<(), StorageError>;
 
     /// Check if resume exists.
     async fn exists(&self, id: &str) -> Result<bool, StorageError>;
 }
+
+/// Job application storage, kept alongside resumes in the same backend.
+#[async_trait(?Send)]
+pub trait ApplicationStore {
+    /// List all job applications.
+    async fn list_applications(&self) -> Result<Vec<JobApplication>, StorageError>;
+
+    /// Get a job application by ID.
+    async fn get_application(&self, id: &str) -> Result<JobApplication, StorageError>;
+
+    /// Save a job application under its `id` (upsert).
+    async fn save_application(&self, application: &JobApplication) -> Result<(), StorageError>;
+
+    /// Delete a job application.
+    async fn delete_application(&self, id: &str) -> Result<(), StorageError>;
+
+    /// List applications sent with the given resume.
+    async fn applications_for_resume(
+        &self,
+        resume_id: &str,
+    ) -> Result<Vec<JobApplication>, StorageError> {
+        Ok(self
+            .list_applications()
+            .await?
+            .into_iter()
+            .filter(|application| application.resume_id.as_deref() == Some(resume_id))
+            .collect())
+    }
+}
+
+/// Full-text search across stored resumes.
+///
+/// Backends keep their index current as resumes are saved and deleted.
+#[async_trait(?Send)]
+pub trait ResumeSearch {
+    /// Find resumes containing every term of `query`, best match first.
+    ///
+    /// Terms are case-insensitive whole words; an empty query matches nothing.
+    async fn search(&self, query: &str) -> Result<Vec<SearchResult>, StorageError>;
+}
+
+/// Change log of stored resumes.
+///
+/// Backends append a [`HistoryEntry`] as resumes are created, changed, and
+/// deleted.
+#[async_trait(?Send)]
+pub trait ResumeHistory {
+    /// Changes to resume `id`, oldest first. The log outlives the resume, so
+    /// a deleted resume's history ends with its deletion.
+    async fn history(&self, id: &str) -> Result<Vec<HistoryEntry>, StorageError>;
+}
+
+/// Deleted resumes, kept until restored or purged.
+///
+/// Trashed resumes are left out of [`StorageBackend::list`], `get`, `exists`,
+/// and search.
+#[async_trait(?Send)]
+pub trait ResumeTrash {
+    /// Trashed resumes, most recently deleted first.
+    async fn list_trashed(&self) -> Result<Vec<TrashedResume>, StorageError>;
+
+    /// Move a trashed resume back. Fails with `AlreadyExists` if a resume
+    /// with its ID has been saved since.
+    async fn restore(&self, id: &str) -> Result<(), StorageError>;
+
+    /// Erase a trashed resume for good. Its history is kept.
+    async fn purge(&self, id: &str) -> Result<(), StorageError>;
+
+    /// Erase resumes deleted `retention_days` or more days ago, returning
+    /// how many were erased.
+    async fn purge_expired(&self, retention_days: u32) -> Result<usize, StorageError>;
+}
+
+/// Append-only log of server API calls made with an API key.
+///
+/// Unlike the other traits its futures are `Send`, so a multi-threaded
+/// server can record calls from any request.
+#[async_trait]
+pub trait ApiAuditLog {
+    /// Append `call` to the log.
+    async fn record_api_call(&self, call: &ApiCall) -> Result<(), StorageError>;
+
+    /// Calls matching `query`, newest first.
+    async fn api_calls(&self, query: &ApiCallQuery) -> Result<Vec<ApiCall>, StorageError>;
+
+    /// Erase calls made `retention_days` or more days ago, returning how
+    /// many were erased.
+    async fn purge_api_calls(&self, retention_days: u32) -> Result<usize, StorageError>;
+}
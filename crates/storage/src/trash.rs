@@ -0,0 +1,47 @@
+//! Trash for deleted resumes.
+//!
+//! [`StorageBackend::delete`](crate::StorageBackend::delete) moves a resume
+//! into the trash instead of erasing it. A trashed resume is left out of
+//! listing, lookups, and search until it is restored, and is erased for good
+//! when purged, either on request or once it has been in the trash longer
+//! than the retention window.
+
+use chrono::{DateTime, Duration, SecondsFormat, Utc};
+use rustume_schema::ResumeData;
+use serde::{Deserialize, Serialize};
+
+/// Days a resume stays in the trash before
+/// [`ResumeTrash::purge_expired`](crate::ResumeTrash::purge_expired) erases
+/// it, unless the caller picks another window.
+pub const TRASH_RETENTION_DAYS: u32 = 30;
+
+/// A resume in the trash.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrashedResume {
+    pub id: String,
+    /// When the resume was deleted, as RFC 3339 in UTC.
+    pub trashed_at: String,
+    pub resume: ResumeData,
+}
+
+impl TrashedResume {
+    pub(crate) fn new(id: &str, resume: ResumeData) -> Self {
+        Self {
+            id: id.to_string(),
+            trashed_at: timestamp(Utc::now()),
+            resume,
+        }
+    }
+}
+
+/// `at` as RFC 3339 with milliseconds, the format every backend stores.
+/// Timestamps in this format sort as strings in time order.
+pub(crate) fn timestamp(at: DateTime<Utc>) -> String {
+    at.to_rfc3339_opts(SecondsFormat::Millis, true)
+}
+
+/// Resumes trashed at or before this timestamp are past `retention_days`.
+pub(crate) fn purge_cutoff(retention_days: u32) -> String {
+    timestamp(Utc::now() - Duration::days(i64::from(retention_days)))
+}
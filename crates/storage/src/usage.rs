@@ -0,0 +1,228 @@
+//! Local usage statistics: how often this machine rendered, which templates
+//! it used, and where imports came from.
+//!
+//! The log is opt-in and never leaves the machine. Nothing is recorded until
+//! [`UsageLog::enable`] creates the log file in the user's data directory,
+//! and [`UsageLog::disable`] deletes it again. Recording is best effort: a
+//! log that can't be read or written is skipped, never an error for the
+//! command being counted.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::StorageError;
+
+/// Name of the log file in the data directory.
+pub const USAGE_LOG_FILE: &str = "usage.json";
+
+/// Environment variable overriding the data directory.
+pub const DATA_DIR_ENV: &str = "RUSTUME_DATA_DIR";
+
+/// Counts kept in the usage log.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct UsageStats {
+    /// Date the log was enabled, as `YYYY-MM-DD`.
+    pub since: Option<String>,
+    /// Documents rendered (PDFs, previews, sites, comparison sheets).
+    pub renders: u64,
+    /// Renders per template.
+    pub templates: BTreeMap<String, u64>,
+    /// Imports per source format.
+    pub imports: BTreeMap<String, u64>,
+}
+
+impl UsageStats {
+    /// Count a render with `template`.
+    pub fn record_render(&mut self, template: &str) {
+        self.renders += 1;
+        *self.templates.entry(template.to_string()).or_default() += 1;
+    }
+
+    /// Count an import from `source`, such as `linkedin` or `csv`.
+    pub fn record_import(&mut self, source: &str) {
+        *self.imports.entry(source.to_string()).or_default() += 1;
+    }
+
+    /// Total imports over every source.
+    pub fn total_imports(&self) -> u64 {
+        self.imports.values().sum()
+    }
+}
+
+/// The usage log file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UsageLog {
+    path: PathBuf,
+}
+
+impl UsageLog {
+    /// The log in `dir`.
+    pub fn new(dir: impl AsRef<Path>) -> Self {
+        Self {
+            path: dir.as_ref().join(USAGE_LOG_FILE),
+        }
+    }
+
+    /// The log in the user's data directory, or `None` when there is no home
+    /// directory to put it in.
+    pub fn open_default() -> Option<Self> {
+        data_dir().map(Self::new)
+    }
+
+    /// Path of the log file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Whether the user has opted in.
+    pub fn is_enabled(&self) -> bool {
+        self.path.is_file()
+    }
+
+    /// Opt in, starting an empty log dated `since`; an enabled log keeps its
+    /// counts.
+    pub fn enable(&self, since: &str) -> Result<(), StorageError> {
+        if self.is_enabled() {
+            return Ok(());
+        }
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir).map_err(|err| io_error(dir, &err))?;
+        }
+        self.save(&UsageStats {
+            since: Some(since.to_string()),
+            ..UsageStats::default()
+        })
+    }
+
+    /// Opt out, deleting the log and its counts.
+    pub fn disable(&self) -> Result<(), StorageError> {
+        match fs::remove_file(&self.path) {
+            Err(err) if err.kind() != io::ErrorKind::NotFound => Err(io_error(&self.path, &err)),
+            _ => Ok(()),
+        }
+    }
+
+    /// Read the counts; [`StorageError::NotFound`] when not enabled.
+    pub fn load(&self) -> Result<UsageStats, StorageError> {
+        let data = fs::read(&self.path).map_err(|err| match err.kind() {
+            io::ErrorKind::NotFound => {
+                StorageError::NotFound(format!("usage log {}", self.path.display()))
+            }
+            _ => io_error(&self.path, &err),
+        })?;
+        serde_json::from_slice(&data).map_err(|err| {
+            StorageError::Internal(format!("Corrupt usage log {}: {err}", self.path.display()))
+        })
+    }
+
+    /// Apply `update` to the counts and save them, if enabled.
+    ///
+    /// Failures are ignored so that counting never breaks a command.
+    pub fn record(&self, update: impl FnOnce(&mut UsageStats)) {
+        if let Ok(mut stats) = self.load() {
+            update(&mut stats);
+            let _ = self.save(&stats);
+        }
+    }
+
+    /// Write the counts through a temporary file, so an interrupted write
+    /// leaves the old log in place.
+    fn save(&self, stats: &UsageStats) -> Result<(), StorageError> {
+        let json = serde_json::to_vec_pretty(stats)
+            .map_err(|err| StorageError::Internal(err.to_string()))?;
+        let tmp = self.path.with_extension("json.tmp");
+        fs::write(&tmp, json).map_err(|err| io_error(&tmp, &err))?;
+        fs::rename(&tmp, &self.path).map_err(|err| io_error(&self.path, &err))
+    }
+}
+
+fn io_error(path: &Path, err: &io::Error) -> StorageError {
+    StorageError::Internal(format!("{}: {err}", path.display()))
+}
+
+/// The Rustume data directory: `$RUSTUME_DATA_DIR`, else the platform's
+/// per-user data directory.
+pub fn data_dir() -> Option<PathBuf> {
+    if let Some(dir) = env::var_os(DATA_DIR_ENV).filter(|dir| !dir.is_empty()) {
+        return Some(PathBuf::from(dir));
+    }
+    platform_data_dir().map(|dir| dir.join("rustume"))
+}
+
+#[cfg(windows)]
+fn platform_data_dir() -> Option<PathBuf> {
+    env::var_os("APPDATA")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+}
+
+#[cfg(target_os = "macos")]
+fn platform_data_dir() -> Option<PathBuf> {
+    home_dir().map(|home| home.join("Library/Application Support"))
+}
+
+#[cfg(not(any(windows, target_os = "macos")))]
+fn platform_data_dir() -> Option<PathBuf> {
+    env::var_os("XDG_DATA_HOME")
+        .map(PathBuf::from)
+        // The XDG spec says relative paths are invalid and must be ignored
+        .filter(|dir| dir.is_absolute())
+        .or_else(|| home_dir().map(|home| home.join(".local/share")))
+}
+
+#[cfg(not(windows))]
+fn home_dir() -> Option<PathBuf> {
+    env::var_os("HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_log_records_only_once_enabled() {
+        let dir = tempfile::tempdir().unwrap();
+        let log = UsageLog::new(dir.path().join("rustume"));
+
+        log.record(|stats| stats.record_render("rhyhorn"));
+        assert!(!log.is_enabled());
+        assert!(matches!(log.load(), Err(StorageError::NotFound(_))));
+
+        log.enable("2026-10-16").unwrap();
+        log.record(|stats| stats.record_render("rhyhorn"));
+        log.record(|stats| stats.record_render("onyx"));
+        log.record(|stats| stats.record_render("rhyhorn"));
+        log.record(|stats| stats.record_import("linkedin"));
+
+        // Enabling again keeps the counts
+        log.enable("2026-10-17").unwrap();
+        let stats = log.load().unwrap();
+        assert_eq!(stats.since.as_deref(), Some("2026-10-16"));
+        assert_eq!(stats.renders, 3);
+        assert_eq!(stats.templates["rhyhorn"], 2);
+        assert_eq!(stats.templates["onyx"], 1);
+        assert_eq!(stats.total_imports(), 1);
+
+        log.disable().unwrap();
+        assert!(!log.path().exists());
+        log.disable().unwrap();
+    }
+
+    #[test]
+    fn test_corrupt_log_is_an_error_but_not_for_recording() {
+        let dir = tempfile::tempdir().unwrap();
+        let log = UsageLog::new(dir.path());
+        fs::write(log.path(), "not json").unwrap();
+
+        assert!(matches!(log.load(), Err(StorageError::Internal(_))));
+        log.record(|stats| stats.record_import("csv"));
+        assert_eq!(fs::read_to_string(log.path()).unwrap(), "not json");
+    }
+}
@@ -2,8 +2,16 @@
 //!
 //! These tests verify the storage backend implementations work correctly.
 
-use rustume_schema::{Basics, Experience, ResumeData, Section};
-use rustume_storage::{MemoryStorage, StorageBackend, StorageError};
+use rustume_schema::{
+    ApplicationStatus, Basics, Experience, JobApplication, Project, ResumeData, Section,
+};
+use std::time::Duration;
+
+use rustume_storage::{
+    ApiAuditLog, ApiCall, ApiCallQuery, ApplicationStore, HistoryAction, MemoryStorage,
+    ResumeHistory, ResumeSearch, ResumeTrash, StorageBackend, StorageError,
+    API_AUDIT_RETENTION_DAYS, TRASH_RETENTION_DAYS,
+};
 
 /// Create a sample resume for testing.
 #[allow(clippy::field_reassign_with_default)]
@@ -327,3 +335,431 @@ async fn test_interleaved_save_delete() {
     let list = storage.list().await.unwrap();
     assert_eq!(list.len(), 26);
 }
+
+// ============================================================================
+// ApplicationStore Tests
+// ============================================================================
+
+/// Exercise application CRUD against any backend.
+async fn check_application_store(storage: &impl ApplicationStore) {
+    assert!(storage.list_applications().await.unwrap().is_empty());
+
+    let acme = JobApplication::new("Acme", "Backend Engineer")
+        .with_resume("resume-backend")
+        .with_status(ApplicationStatus::Applied)
+        .with_applied_date("2024-05-01");
+    let globex = JobApplication::new("Globex", "Platform Engineer").with_resume("resume-platform");
+    storage.save_application(&acme).await.unwrap();
+    storage.save_application(&globex).await.unwrap();
+
+    assert_eq!(storage.get_application(&acme.id).await.unwrap(), acme);
+    assert_eq!(storage.list_applications().await.unwrap().len(), 2);
+
+    // Upsert moves the application through the pipeline
+    let interviewing = acme.clone().with_status(ApplicationStatus::Interviewing);
+    storage.save_application(&interviewing).await.unwrap();
+    assert_eq!(
+        storage.get_application(&acme.id).await.unwrap().status,
+        ApplicationStatus::Interviewing
+    );
+
+    let for_backend = storage
+        .applications_for_resume("resume-backend")
+        .await
+        .unwrap();
+    assert_eq!(for_backend.len(), 1);
+    assert_eq!(for_backend[0].company, "Acme");
+
+    storage.delete_application(&globex.id).await.unwrap();
+    assert!(matches!(
+        storage.get_application(&globex.id).await,
+        Err(StorageError::NotFound(_))
+    ));
+    assert!(matches!(
+        storage.delete_application(&globex.id).await,
+        Err(StorageError::NotFound(_))
+    ));
+    assert_eq!(storage.list_applications().await.unwrap().len(), 1);
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_memory_application_store() {
+    check_application_store(&MemoryStorage::new()).await;
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_memory_applications_separate_from_resumes() {
+    let storage = MemoryStorage::new();
+    let application = JobApplication::new("Acme", "Engineer");
+    storage.save_application(&application).await.unwrap();
+
+    assert!(storage.list().await.unwrap().is_empty());
+    assert!(!storage.exists(&application.id).await.unwrap());
+}
+
+// ============================================================================
+// SqliteStorage Tests
+// ============================================================================
+
+// ============================================================================
+// Search Tests
+// ============================================================================
+
+/// Exercise search ranking, hit locations, and index maintenance.
+async fn check_resume_search(storage: &(impl StorageBackend + ResumeSearch)) {
+    let mut tailored = sample_resume("Jane Doe");
+    tailored.sections.projects = Section::new("projects", "Projects");
+    tailored
+        .sections
+        .projects
+        .add_item(Project::new("Orbit").with_description("<p>Satellite telemetry dashboard</p>"));
+    storage.save("tailored", &tailored).await.unwrap();
+    storage
+        .save("generic", &sample_resume("Jane Doe"))
+        .await
+        .unwrap();
+
+    let results = storage.search("orbit telemetry").await.unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].resume_id, "tailored");
+    assert!(results[0].score > 0.0);
+    let paths: Vec<&str> = results[0].hits.iter().map(|h| h.path.as_str()).collect();
+    assert!(paths.contains(&"sections.projects.items[0].name"));
+    assert!(paths.contains(&"sections.projects.items[0].description"));
+    assert!(results[0].hits.iter().all(|h| h.section == "projects"));
+    assert_eq!(
+        results[0]
+            .hits
+            .iter()
+            .find(|h| h.path.ends_with("description"))
+            .unwrap()
+            .snippet,
+        "Satellite telemetry dashboard"
+    );
+
+    // Terms are case-insensitive and must all appear somewhere in the resume.
+    assert_eq!(storage.search("TEST company").await.unwrap().len(), 2);
+    assert!(storage.search("orbit kubernetes").await.unwrap().is_empty());
+    assert!(storage.search("").await.unwrap().is_empty());
+
+    // Saving replaces the indexed text; deleting removes it.
+    storage
+        .save("tailored", &sample_resume("Jane Doe"))
+        .await
+        .unwrap();
+    assert!(storage.search("orbit").await.unwrap().is_empty());
+    storage.delete("generic").await.unwrap();
+    let remaining: Vec<String> = storage
+        .search("software")
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|r| r.resume_id)
+        .collect();
+    assert_eq!(remaining, vec!["tailored"]);
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_memory_resume_search() {
+    check_resume_search(&MemoryStorage::new()).await;
+}
+
+// ============================================================================
+// History Tests
+// ============================================================================
+
+/// Exercise the change log through creation, edits, and deletion.
+async fn check_resume_history(storage: &(impl StorageBackend + ResumeHistory)) {
+    let mut resume = sample_resume("Jane Doe");
+    storage.save("resume-1", &resume).await.unwrap();
+    // Saving unchanged content records nothing.
+    storage.save("resume-1", &resume).await.unwrap();
+    resume.basics.headline = "Staff Engineer".to_string();
+    storage.save("resume-1", &resume).await.unwrap();
+    storage
+        .save("resume-2", &sample_resume("John Doe"))
+        .await
+        .unwrap();
+    storage.delete("resume-1").await.unwrap();
+
+    // History outlives the resume, oldest entry first.
+    let history = storage.history("resume-1").await.unwrap();
+    let entries: Vec<(HistoryAction, &str)> = history
+        .iter()
+        .map(|entry| (entry.action, entry.summary.as_str()))
+        .collect();
+    assert_eq!(
+        entries,
+        vec![
+            (HistoryAction::Created, "Created"),
+            (HistoryAction::Updated, "Edited basics (headline)"),
+            (HistoryAction::Deleted, "Deleted"),
+        ]
+    );
+    assert!(history.iter().all(|entry| entry.resume_id == "resume-1"));
+    assert!(history.iter().all(|entry| entry.author.is_none()));
+    assert!(history.windows(2).all(|pair| pair[0].at <= pair[1].at));
+
+    assert_eq!(storage.history("resume-2").await.unwrap().len(), 1);
+    assert!(storage.history("missing").await.unwrap().is_empty());
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_memory_resume_history() {
+    check_resume_history(&MemoryStorage::new()).await;
+}
+
+// ============================================================================
+// Trash Tests
+// ============================================================================
+
+/// Exercise soft deletion, restoring, and purging.
+async fn check_resume_trash(
+    storage: &(impl StorageBackend + ResumeSearch + ResumeHistory + ResumeTrash),
+) {
+    storage
+        .save("resume-1", &sample_resume("Jane Doe"))
+        .await
+        .unwrap();
+    storage
+        .save("resume-2", &sample_resume("John Doe"))
+        .await
+        .unwrap();
+
+    // Deleted resumes leave listing, lookups, and search for the trash.
+    storage.delete("resume-1").await.unwrap();
+    assert_eq!(storage.list().await.unwrap(), vec!["resume-2"]);
+    assert!(!storage.exists("resume-1").await.unwrap());
+    assert!(storage.search("jane").await.unwrap().is_empty());
+    assert!(matches!(
+        storage.delete("resume-1").await,
+        Err(StorageError::NotFound(_))
+    ));
+    let trashed = storage.list_trashed().await.unwrap();
+    assert_eq!(trashed.len(), 1);
+    assert_eq!(trashed[0].id, "resume-1");
+    assert_eq!(trashed[0].resume.basics.name, "Jane Doe");
+
+    // Restoring brings back the resume and its search entries.
+    storage.restore("resume-1").await.unwrap();
+    assert_eq!(
+        storage.get("resume-1").await.unwrap().basics.name,
+        "Jane Doe"
+    );
+    assert_eq!(storage.search("jane").await.unwrap().len(), 1);
+    assert!(storage.list_trashed().await.unwrap().is_empty());
+    assert!(matches!(
+        storage.restore("resume-1").await,
+        Err(StorageError::NotFound(_))
+    ));
+    let actions: Vec<HistoryAction> = storage
+        .history("resume-1")
+        .await
+        .unwrap()
+        .iter()
+        .map(|entry| entry.action)
+        .collect();
+    assert_eq!(
+        actions,
+        vec![
+            HistoryAction::Created,
+            HistoryAction::Deleted,
+            HistoryAction::Restored
+        ]
+    );
+
+    // Saving under a trashed ID replaces the trashed copy.
+    storage.delete("resume-1").await.unwrap();
+    storage
+        .save("resume-1", &sample_resume("Ada Lovelace"))
+        .await
+        .unwrap();
+    assert!(storage.list_trashed().await.unwrap().is_empty());
+
+    // Purging erases a trashed resume for good.
+    storage.delete("resume-2").await.unwrap();
+    storage.purge("resume-2").await.unwrap();
+    assert!(storage.list_trashed().await.unwrap().is_empty());
+    assert!(matches!(
+        storage.purge("resume-2").await,
+        Err(StorageError::NotFound(_))
+    ));
+    assert!(matches!(
+        storage.restore("resume-2").await,
+        Err(StorageError::NotFound(_))
+    ));
+
+    // Only resumes past the retention window expire.
+    storage.delete("resume-1").await.unwrap();
+    assert_eq!(
+        storage.purge_expired(TRASH_RETENTION_DAYS).await.unwrap(),
+        0
+    );
+    assert_eq!(storage.list_trashed().await.unwrap().len(), 1);
+    assert_eq!(storage.purge_expired(0).await.unwrap(), 1);
+    assert!(storage.list_trashed().await.unwrap().is_empty());
+    assert!(storage.list().await.unwrap().is_empty());
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_memory_resume_trash() {
+    check_resume_trash(&MemoryStorage::new()).await;
+}
+
+async fn check_api_audit_log(storage: &impl ApiAuditLog) {
+    let render = ApiCall::new(
+        "acme",
+        "POST /api/render/pdf",
+        Some("ab12".to_string()),
+        200,
+        Duration::from_millis(350),
+    );
+    let failed = ApiCall::new("acme", "POST /api/validate", None, 400, Duration::ZERO);
+    let other = ApiCall::new("globex", "GET /api/templates", None, 200, Duration::ZERO);
+    for call in [&render, &failed, &other] {
+        storage.record_api_call(call).await.unwrap();
+    }
+
+    // Newest first
+    let all = storage.api_calls(&ApiCallQuery::default()).await.unwrap();
+    assert_eq!(all, [other.clone(), failed.clone(), render.clone()]);
+    assert!(!failed.succeeded());
+
+    let acme = ApiCallQuery {
+        api_key: Some("acme".to_string()),
+        ..Default::default()
+    };
+    assert_eq!(
+        storage.api_calls(&acme).await.unwrap(),
+        [failed.clone(), render.clone()]
+    );
+    let latest = ApiCallQuery {
+        limit: Some(1),
+        ..acme
+    };
+    assert_eq!(storage.api_calls(&latest).await.unwrap(), [failed]);
+    let future = ApiCallQuery {
+        since: Some("2999-01-01".to_string()),
+        ..Default::default()
+    };
+    assert!(storage.api_calls(&future).await.unwrap().is_empty());
+    let past = ApiCallQuery {
+        until: Some("2000-01-01".to_string()),
+        ..Default::default()
+    };
+    assert!(storage.api_calls(&past).await.unwrap().is_empty());
+
+    // Only calls past the retention window expire.
+    assert_eq!(
+        storage
+            .purge_api_calls(API_AUDIT_RETENTION_DAYS)
+            .await
+            .unwrap(),
+        0
+    );
+    assert_eq!(storage.purge_api_calls(0).await.unwrap(), 3);
+    assert!(storage
+        .api_calls(&ApiCallQuery::default())
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_memory_api_audit_log() {
+    check_api_audit_log(&MemoryStorage::new()).await;
+}
+
+#[cfg(feature = "sqlite")]
+mod sqlite {
+    use super::*;
+    use rustume_storage::SqliteStorage;
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_sqlite_resume_crud() {
+        let storage = SqliteStorage::in_memory().await.unwrap();
+        assert!(storage.list().await.unwrap().is_empty());
+
+        storage
+            .save("id-2", &sample_resume("Jane Doe"))
+            .await
+            .unwrap();
+        storage
+            .save("id-1", &sample_resume("John Doe"))
+            .await
+            .unwrap();
+        assert_eq!(storage.list().await.unwrap(), vec!["id-1", "id-2"]);
+        assert!(storage.exists("id-1").await.unwrap());
+
+        storage
+            .save("id-1", &sample_resume("Johnny Doe"))
+            .await
+            .unwrap();
+        let loaded = storage.get("id-1").await.unwrap();
+        assert_eq!(loaded.basics.name, "Johnny Doe");
+        assert_eq!(loaded.sections.experience.items.len(), 1);
+
+        storage.delete("id-1").await.unwrap();
+        assert!(!storage.exists("id-1").await.unwrap());
+        assert!(matches!(
+            storage.get("id-1").await,
+            Err(StorageError::NotFound(_))
+        ));
+        assert!(matches!(
+            storage.delete("id-1").await,
+            Err(StorageError::NotFound(_))
+        ));
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_sqlite_application_store() {
+        check_application_store(&SqliteStorage::in_memory().await.unwrap()).await;
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_sqlite_resume_search() {
+        check_resume_search(&SqliteStorage::in_memory().await.unwrap()).await;
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_sqlite_resume_history() {
+        check_resume_history(&SqliteStorage::in_memory().await.unwrap()).await;
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_sqlite_resume_trash() {
+        check_resume_trash(&SqliteStorage::in_memory().await.unwrap()).await;
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_sqlite_api_audit_log() {
+        check_api_audit_log(&SqliteStorage::in_memory().await.unwrap()).await;
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_sqlite_persists_to_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("rustume.db");
+        let application = JobApplication::new("Acme", "Engineer");
+
+        {
+            let storage = SqliteStorage::open(&path).await.unwrap();
+            storage
+                .save("resume-1", &sample_resume("John Doe"))
+                .await
+                .unwrap();
+            storage.save_application(&application).await.unwrap();
+        }
+
+        let reopened = SqliteStorage::open(&path).await.unwrap();
+        assert_eq!(
+            reopened.get("resume-1").await.unwrap().basics.name,
+            "John Doe"
+        );
+        assert_eq!(
+            reopened.get_application(&application.id).await.unwrap(),
+            application
+        );
+        assert_eq!(reopened.search("john").await.unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,71 @@
+//! Invariants that must hold for any valid resume.
+//!
+//! Each check returns a [`TestCaseError`] instead of panicking so it can be
+//! used with `?` inside `proptest!` bodies and shrink properly.
+
+use proptest::prelude::*;
+use proptest::test_runner::TestCaseError;
+use rustume_schema::ResumeData;
+use serde_json::Value;
+use validator::Validate;
+
+/// Serialize to a JSON value, failing the test case on error.
+fn to_value(resume: &ResumeData) -> Result<Value, TestCaseError> {
+    serde_json::to_value(resume).map_err(|e| TestCaseError::fail(format!("serialize: {e}")))
+}
+
+/// JSON serialization round-trips without losing or altering any field.
+///
+/// Compares the serialized form before and after a string round-trip, which
+/// covers every field without requiring `PartialEq` on the schema types.
+pub fn check_json_roundtrip(resume: &ResumeData) -> Result<(), TestCaseError> {
+    let json = resume
+        .to_json()
+        .map_err(|e| TestCaseError::fail(format!("to_json: {e}")))?;
+    let parsed =
+        ResumeData::from_json(&json).map_err(|e| TestCaseError::fail(format!("from_json: {e}")))?;
+    prop_assert_eq!(to_value(resume)?, to_value(&parsed)?);
+
+    let bytes = resume
+        .to_json_bytes()
+        .map_err(|e| TestCaseError::fail(format!("to_json_bytes: {e}")))?;
+    let parsed = ResumeData::from_json_bytes(&bytes)
+        .map_err(|e| TestCaseError::fail(format!("from_json_bytes: {e}")))?;
+    prop_assert_eq!(to_value(resume)?, to_value(&parsed)?);
+    Ok(())
+}
+
+/// Validation is deterministic and unaffected by a JSON round-trip.
+///
+/// Also checks that warnings are stable and never turn a valid resume invalid.
+pub fn check_validation_consistent(resume: &ResumeData) -> Result<(), TestCaseError> {
+    let first = resume.validate().is_ok();
+    prop_assert_eq!(
+        first,
+        resume.validate().is_ok(),
+        "validate() is not deterministic"
+    );
+
+    let json = resume
+        .to_json()
+        .map_err(|e| TestCaseError::fail(format!("to_json: {e}")))?;
+    let parsed =
+        ResumeData::from_json(&json).map_err(|e| TestCaseError::fail(format!("from_json: {e}")))?;
+    prop_assert_eq!(
+        first,
+        parsed.validate().is_ok(),
+        "validation changed after JSON round-trip"
+    );
+    prop_assert_eq!(resume.warnings(), parsed.warnings());
+    Ok(())
+}
+
+/// Generated data must satisfy every schema validator.
+pub fn check_valid(resume: &ResumeData) -> Result<(), TestCaseError> {
+    if let Err(errors) = resume.validate() {
+        return Err(TestCaseError::fail(format!(
+            "generated resume failed validation: {errors}"
+        )));
+    }
+    Ok(())
+}
@@ -0,0 +1,26 @@
+//! Property-test support for Rustume.
+//!
+//! This crate is only used as a dev-dependency. It provides:
+//! - `proptest` strategies that generate valid [`ResumeData`](rustume_schema::ResumeData)
+//!   covering every section type
+//! - invariant checks shared by the property suites in this and other crates
+//!
+//! # Example
+//!
+//! ```ignore
+//! use proptest::prelude::*;
+//! use rustume_test_support::{arb_resume_data, check_json_roundtrip};
+//!
+//! proptest! {
+//!     #[test]
+//!     fn roundtrips(resume in arb_resume_data()) {
+//!         check_json_roundtrip(&resume)?;
+//!     }
+//! }
+//! ```
+
+mod invariants;
+mod strategies;
+
+pub use invariants::*;
+pub use strategies::*;
@@ -0,0 +1,1066 @@
+//! `proptest` strategies for resume data.
+//!
+//! Every strategy produces data that passes `Validate`. Strings are drawn from
+//! printable Unicode so serialization and rendering see non-ASCII input, while
+//! URLs, emails, and colors stick to the formats the validators accept.
+
+use std::collections::HashMap;
+
+use proptest::collection::{hash_map, vec};
+use proptest::option;
+use proptest::prelude::*;
+use proptest::sample::select;
+use rustume_schema::{
+    Address, Award, Basics, CefrLevel, Certification, ContactQr, CoverLetterRecipient,
+    CoverLetterSection, CustomCss, CustomField, CustomFieldKind, CustomItem, DividerStyle,
+    Education, EmojiPolicy, Experience, FontConfig, HeaderAlign, Interest, Language, Layout,
+    LevelDisplay, Metadata, NameOrder, PageBackground, PageConfig, PageFormat, PageNumberStyle,
+    PageOptions, PageSelector, PersonName, PhotoPosition, Picture, PictureEffects, Profile,
+    Project, Publication, Reference, ResumeData, RunningHeader, Section, SectionDisplay, Sections,
+    Skill, SummarySection, TemplateOptions, Theme, Typography, Url, Volunteer, BUILT_IN_SECTIONS,
+    SECTION_DISPLAY_KEYS,
+};
+use rustume_utils::escape_html;
+use validator::Validate;
+
+/// Maximum number of items generated per section.
+const MAX_ITEMS: usize = 3;
+
+// ============================================================================
+// Primitives
+// ============================================================================
+
+/// Short single-line text (names, titles, locations).
+pub fn arb_text() -> impl Strategy<Value = String> {
+    "\\PC{0,24}"
+}
+
+/// Rich-text content in the HTML subset produced by the editor.
+pub fn arb_rich_text() -> impl Strategy<Value = String> {
+    let words = "[A-Za-z0-9 ,.&<>'\"-]{0,40}";
+    prop_oneof![
+        Just(String::new()),
+        arb_text(),
+        words.prop_map(|text| format!("<p>{}</p>", escape_html(&text))),
+        (words, words).prop_map(|(a, b)| format!(
+            "<p><strong>{}</strong> <em>{}</em></p>",
+            escape_html(&a),
+            escape_html(&b)
+        )),
+        vec(words, 1..4).prop_map(|items| {
+            let items: String = items
+                .iter()
+                .map(|item| format!("<li>{}</li>", escape_html(item)))
+                .collect();
+            format!("<ul>{}</ul>", items)
+        }),
+    ]
+}
+
+/// A cuid2-shaped identifier.
+pub fn arb_id() -> impl Strategy<Value = String> {
+    "[a-z][a-z0-9]{23}"
+}
+
+/// An empty string or an HTTP(S) URL accepted by `validate_optional_url`.
+pub fn arb_href() -> impl Strategy<Value = String> {
+    prop_oneof![
+        Just(String::new()),
+        "https?://[a-z]{1,12}\\.(com|org|dev)(/[a-z0-9-]{1,10}){0,2}",
+    ]
+}
+
+/// An empty string or an email accepted by `validate_optional_email`.
+pub fn arb_email() -> impl Strategy<Value = String> {
+    prop_oneof![
+        Just(String::new()),
+        "[a-z][a-z0-9._]{0,10}@[a-z]{1,10}\\.(com|org|dev)",
+    ]
+}
+
+/// A `#rrggbb` color.
+pub fn arb_hex_color() -> impl Strategy<Value = String> {
+    "#[0-9a-f]{6}"
+}
+
+/// An empty string, `#rrggbb`, or `#rrggbbaa` color.
+pub fn arb_hex_color_with_optional_alpha() -> impl Strategy<Value = String> {
+    prop_oneof![Just(String::new()), "#[0-9a-f]{6}", "#[0-9a-f]{8}"]
+}
+
+/// Optional ISO partial start and end dates (`YYYY`, `YYYY-MM`, or
+/// `YYYY-MM-DD`), as accepted by `validate_optional_partial_date`.
+pub fn arb_structured_dates() -> impl Strategy<Value = (Option<String>, Option<String>)> {
+    let date = prop_oneof!["(19|20)[0-9]{2}", "(19|20)[0-9]{2}-(0[1-9]|1[0-2])"];
+    (option::of(date.clone()), option::of(date))
+}
+
+/// A labelled link.
+pub fn arb_url() -> impl Strategy<Value = Url> {
+    (arb_text(), arb_href()).prop_map(|(label, href)| Url { label, href })
+}
+
+fn arb_keywords() -> impl Strategy<Value = Vec<String>> {
+    vec(arb_text(), 0..4)
+}
+
+// ============================================================================
+// Basics
+// ============================================================================
+
+/// A custom contact field.
+pub fn arb_custom_field() -> impl Strategy<Value = CustomField> {
+    (arb_id(), arb_text(), arb_text(), arb_text()).prop_map(|(id, icon, name, value)| CustomField {
+        id,
+        icon,
+        name,
+        value,
+        kind: CustomFieldKind::Text,
+    })
+}
+
+/// Picture effects within their validated ranges.
+pub fn arb_picture_effects() -> impl Strategy<Value = PictureEffects> {
+    (
+        any::<bool>(),
+        any::<bool>(),
+        any::<bool>(),
+        0.0f32..=360.0,
+        arb_hex_color_with_optional_alpha(),
+        0u32..=10,
+        arb_hex_color_with_optional_alpha(),
+        0u32..=20,
+    )
+        .prop_map(
+            |(
+                hidden,
+                border,
+                grayscale,
+                rotation,
+                border_color,
+                border_width,
+                shadow_color,
+                shadow_size,
+            )| PictureEffects {
+                hidden,
+                border,
+                grayscale,
+                rotation,
+                border_color,
+                border_width,
+                shadow_color,
+                shadow_size,
+            },
+        )
+}
+
+/// Picture settings without an image source, so renders never touch the network.
+pub fn arb_picture() -> impl Strategy<Value = Picture> {
+    (1u32..=256, 0.5f32..=2.0, 0u32..=100, arb_picture_effects()).prop_map(
+        |(size, aspect_ratio, border_radius, effects)| Picture {
+            url: String::new(),
+            size,
+            aspect_ratio,
+            border_radius,
+            effects,
+        },
+    )
+}
+
+/// Name parts, often unset as in resumes saved before they existed.
+pub fn arb_person_name() -> impl Strategy<Value = PersonName> {
+    prop_oneof![
+        Just(PersonName::default()),
+        (arb_text(), arb_text(), arb_text(), arb_text()).prop_map(
+            |(prefix, given, family, suffix)| PersonName {
+                prefix,
+                given,
+                family,
+                suffix,
+            }
+        ),
+    ]
+}
+
+/// Postal address parts, often unset as in resumes saved before they existed.
+pub fn arb_address() -> impl Strategy<Value = Address> {
+    prop_oneof![
+        Just(Address::default()),
+        (
+            arb_text(),
+            arb_text(),
+            arb_text(),
+            "[0-9A-Z -]{0,10}",
+            "[A-Z]{2}"
+        )
+            .prop_map(|(street, city, region, postal_code, country)| Address {
+                street,
+                city,
+                region,
+                postal_code,
+                country,
+            }),
+    ]
+}
+
+/// Basic personal information.
+pub fn arb_basics() -> impl Strategy<Value = Basics> {
+    (
+        arb_text(),
+        arb_person_name(),
+        arb_text(),
+        arb_email(),
+        "[0-9 +()-]{0,16}",
+        arb_text(),
+        arb_address(),
+        arb_url(),
+        vec(arb_custom_field(), 0..3),
+        arb_picture(),
+    )
+        .prop_map(
+            |(
+                name,
+                name_parts,
+                headline,
+                email,
+                phone,
+                location,
+                address,
+                url,
+                custom_fields,
+                picture,
+            )| {
+                Basics {
+                    name,
+                    name_parts,
+                    headline,
+                    email,
+                    phone,
+                    location,
+                    address,
+                    url,
+                    custom_fields,
+                    picture,
+                }
+            },
+        )
+}
+
+// ============================================================================
+// Section items
+// ============================================================================
+
+/// Work experience entry.
+pub fn arb_experience() -> impl Strategy<Value = Experience> {
+    (
+        arb_id(),
+        any::<bool>(),
+        arb_text(),
+        arb_text(),
+        arb_text(),
+        arb_text(),
+        arb_structured_dates(),
+        arb_rich_text(),
+        arb_keywords(),
+        arb_url(),
+    )
+        .prop_map(
+            |(
+                id,
+                visible,
+                company,
+                position,
+                location,
+                date,
+                (start_date, end_date),
+                summary,
+                highlights,
+                url,
+            )| {
+                Experience {
+                    id,
+                    visible,
+                    company,
+                    position,
+                    location,
+                    date,
+                    start_date,
+                    end_date,
+                    summary,
+                    highlights,
+                    url,
+                }
+            },
+        )
+}
+
+/// Education entry.
+pub fn arb_education() -> impl Strategy<Value = Education> {
+    (
+        arb_id(),
+        any::<bool>(),
+        arb_text(),
+        arb_text(),
+        arb_text(),
+        arb_text(),
+        arb_structured_dates(),
+        arb_text(),
+        arb_rich_text(),
+        arb_url(),
+    )
+        .prop_map(
+            |(
+                id,
+                visible,
+                institution,
+                area,
+                study_type,
+                date,
+                (start_date, end_date),
+                score,
+                summary,
+                url,
+            )| Education {
+                id,
+                visible,
+                institution,
+                area,
+                study_type,
+                date,
+                start_date,
+                end_date,
+                score,
+                summary,
+                url,
+            },
+        )
+}
+
+/// Skill entry with a level in `0..=5`.
+pub fn arb_skill() -> impl Strategy<Value = Skill> {
+    (
+        arb_id(),
+        any::<bool>(),
+        arb_text(),
+        arb_text(),
+        0u8..=5,
+        arb_keywords(),
+    )
+        .prop_map(|(id, visible, name, description, level, keywords)| Skill {
+            id,
+            visible,
+            name,
+            description,
+            level,
+            keywords,
+        })
+}
+
+/// Project entry.
+pub fn arb_project() -> impl Strategy<Value = Project> {
+    (
+        arb_id(),
+        any::<bool>(),
+        arb_text(),
+        arb_text(),
+        arb_text(),
+        arb_structured_dates(),
+        arb_rich_text(),
+        arb_keywords(),
+        arb_keywords(),
+        arb_url(),
+    )
+        .prop_map(
+            |(
+                id,
+                visible,
+                name,
+                description,
+                date,
+                (start_date, end_date),
+                summary,
+                highlights,
+                keywords,
+                url,
+            )| {
+                Project {
+                    id,
+                    visible,
+                    name,
+                    description,
+                    date,
+                    start_date,
+                    end_date,
+                    summary,
+                    highlights,
+                    keywords,
+                    url,
+                }
+            },
+        )
+}
+
+/// Social profile entry.
+pub fn arb_profile() -> impl Strategy<Value = Profile> {
+    (
+        arb_id(),
+        any::<bool>(),
+        arb_text(),
+        arb_text(),
+        "[a-z-]{0,12}",
+        arb_url(),
+    )
+        .prop_map(|(id, visible, network, username, icon, url)| Profile {
+            id,
+            visible,
+            network,
+            username,
+            icon,
+            url,
+        })
+}
+
+/// Award entry.
+pub fn arb_award() -> impl Strategy<Value = Award> {
+    (
+        arb_id(),
+        any::<bool>(),
+        arb_text(),
+        arb_text(),
+        arb_text(),
+        arb_rich_text(),
+        arb_url(),
+    )
+        .prop_map(|(id, visible, title, awarder, date, summary, url)| Award {
+            id,
+            visible,
+            title,
+            awarder,
+            date,
+            summary,
+            url,
+        })
+}
+
+/// Certification entry.
+pub fn arb_certification() -> impl Strategy<Value = Certification> {
+    (
+        arb_id(),
+        any::<bool>(),
+        arb_text(),
+        arb_text(),
+        arb_text(),
+        arb_rich_text(),
+        arb_url(),
+    )
+        .prop_map(
+            |(id, visible, name, issuer, date, summary, url)| Certification {
+                id,
+                visible,
+                name,
+                issuer,
+                date,
+                summary,
+                url,
+            },
+        )
+}
+
+/// Publication entry.
+pub fn arb_publication() -> impl Strategy<Value = Publication> {
+    (
+        arb_id(),
+        any::<bool>(),
+        arb_text(),
+        arb_text(),
+        arb_text(),
+        arb_rich_text(),
+        arb_url(),
+    )
+        .prop_map(
+            |(id, visible, name, publisher, date, summary, url)| Publication {
+                id,
+                visible,
+                name,
+                publisher,
+                date,
+                summary,
+                url,
+            },
+        )
+}
+
+/// Language entry with a level in `0..=5`, sometimes with the CEFR level
+/// that matches it.
+pub fn arb_language() -> impl Strategy<Value = Language> {
+    (
+        arb_id(),
+        any::<bool>(),
+        arb_text(),
+        arb_text(),
+        0u8..=5,
+        any::<bool>(),
+    )
+        .prop_map(
+            |(id, visible, name, description, level, with_cefr)| Language {
+                id,
+                visible,
+                name,
+                description,
+                level,
+                cefr: CefrLevel::from_level(level).filter(|_| with_cefr),
+            },
+        )
+}
+
+/// Interest entry.
+pub fn arb_interest() -> impl Strategy<Value = Interest> {
+    (arb_id(), any::<bool>(), arb_text(), arb_keywords()).prop_map(
+        |(id, visible, name, keywords)| Interest {
+            id,
+            visible,
+            name,
+            keywords,
+        },
+    )
+}
+
+/// Volunteer entry.
+pub fn arb_volunteer() -> impl Strategy<Value = Volunteer> {
+    (
+        arb_id(),
+        any::<bool>(),
+        arb_text(),
+        arb_text(),
+        arb_text(),
+        arb_text(),
+        arb_rich_text(),
+        arb_url(),
+    )
+        .prop_map(
+            |(id, visible, organization, position, location, date, summary, url)| Volunteer {
+                id,
+                visible,
+                organization,
+                position,
+                location,
+                date,
+                summary,
+                url,
+            },
+        )
+}
+
+/// Reference entry.
+pub fn arb_reference() -> impl Strategy<Value = Reference> {
+    (
+        arb_id(),
+        any::<bool>(),
+        arb_text(),
+        arb_text(),
+        arb_rich_text(),
+        arb_url(),
+    )
+        .prop_map(|(id, visible, name, description, summary, url)| Reference {
+            id,
+            visible,
+            name,
+            description,
+            summary,
+            url,
+        })
+}
+
+/// Custom section entry.
+pub fn arb_custom_item() -> impl Strategy<Value = CustomItem> {
+    (
+        arb_id(),
+        any::<bool>(),
+        arb_text(),
+        arb_text(),
+        arb_text(),
+        arb_text(),
+        arb_rich_text(),
+        arb_keywords(),
+        arb_url(),
+    )
+        .prop_map(
+            |(id, visible, name, description, date, location, summary, keywords, url)| CustomItem {
+                id,
+                visible,
+                name,
+                description,
+                date,
+                location,
+                summary,
+                keywords,
+                url,
+            },
+        )
+}
+
+// ============================================================================
+// Sections
+// ============================================================================
+
+/// A section with the given id and up to [`MAX_ITEMS`] generated items.
+pub fn arb_section<T, S>(id: &'static str, item: S) -> impl Strategy<Value = Section<T>>
+where
+    T: Validate + std::fmt::Debug + Clone,
+    S: Strategy<Value = T>,
+{
+    (
+        arb_text(),
+        1u8..=5,
+        any::<bool>(),
+        any::<bool>(),
+        vec(item, 0..=MAX_ITEMS),
+    )
+        .prop_map(
+            move |(name, columns, separate_links, visible, items)| Section {
+                id: id.to_string(),
+                name,
+                columns,
+                separate_links,
+                visible,
+                items,
+            },
+        )
+}
+
+/// Summary section.
+pub fn arb_summary_section() -> impl Strategy<Value = SummarySection> {
+    (
+        arb_text(),
+        1u8..=5,
+        any::<bool>(),
+        any::<bool>(),
+        arb_rich_text(),
+    )
+        .prop_map(
+            |(name, columns, separate_links, visible, content)| SummarySection {
+                id: "summary".to_string(),
+                name,
+                columns,
+                separate_links,
+                visible,
+                content,
+            },
+        )
+}
+
+/// Cover letter section.
+pub fn arb_cover_letter_section() -> impl Strategy<Value = CoverLetterSection> {
+    let recipient = (arb_text(), arb_text(), arb_text(), arb_text(), arb_email()).prop_map(
+        |(name, title, company, address, email)| CoverLetterRecipient {
+            name,
+            title,
+            company,
+            address,
+            email,
+        },
+    );
+
+    (arb_text(), any::<bool>(), recipient, arb_rich_text()).prop_map(
+        |(name, visible, recipient, content)| CoverLetterSection {
+            id: "coverLetter".to_string(),
+            name,
+            visible,
+            recipient,
+            content,
+        },
+    )
+}
+
+/// User-defined sections keyed by their id.
+pub fn arb_custom_sections() -> impl Strategy<Value = HashMap<String, Section<CustomItem>>> {
+    hash_map(
+        "[a-z]{4,10}",
+        arb_section("custom", arb_custom_item()),
+        0..3,
+    )
+    .prop_map(|sections| {
+        sections
+            .into_iter()
+            .map(|(key, mut section)| {
+                section.id = key.clone();
+                (key, section)
+            })
+            .collect()
+    })
+}
+
+/// All resume sections, including custom ones.
+pub fn arb_sections() -> impl Strategy<Value = Sections> {
+    let main = (
+        arb_summary_section(),
+        arb_cover_letter_section(),
+        arb_section("experience", arb_experience()),
+        arb_section("education", arb_education()),
+        arb_section("skills", arb_skill()),
+        arb_section("projects", arb_project()),
+        arb_section("profiles", arb_profile()),
+    );
+    let extra = (
+        arb_section("awards", arb_award()),
+        arb_section("certifications", arb_certification()),
+        arb_section("publications", arb_publication()),
+        arb_section("languages", arb_language()),
+        arb_section("interests", arb_interest()),
+        arb_section("volunteer", arb_volunteer()),
+        arb_section("references", arb_reference()),
+        arb_custom_sections(),
+    );
+
+    (main, extra).prop_map(
+        |(
+            (summary, cover_letter, experience, education, skills, projects, profiles),
+            (
+                awards,
+                certifications,
+                publications,
+                languages,
+                interests,
+                volunteer,
+                references,
+                custom,
+            ),
+        )| Sections {
+            summary,
+            cover_letter,
+            experience,
+            education,
+            skills,
+            projects,
+            profiles,
+            awards,
+            certifications,
+            publications,
+            languages,
+            interests,
+            volunteer,
+            references,
+            custom,
+        },
+    )
+}
+
+// ============================================================================
+// Metadata
+// ============================================================================
+
+/// A single-page layout with the built-in sections shuffled across main and sidebar.
+pub fn arb_layout() -> impl Strategy<Value = Layout> {
+    let keys: Vec<String> = BUILT_IN_SECTIONS
+        .iter()
+        .map(|key| key.to_string())
+        .collect();
+    let len = keys.len();
+    (Just(keys).prop_shuffle(), 0..=len).prop_map(|(mut main, split)| {
+        let sidebar = main.split_off(split);
+        Layout::from(vec![vec![main, sidebar]])
+    })
+}
+
+/// Page settings within the ranges the renderer accepts.
+pub fn arb_page_config() -> impl Strategy<Value = PageConfig> {
+    (
+        0u32..=60,
+        prop_oneof![Just(PageFormat::A4), Just(PageFormat::Letter)],
+        option::of(0.1f32..=0.5),
+        any::<bool>(),
+        any::<bool>(),
+        prop_oneof![
+            Just(PageNumberStyle::Plain),
+            Just(PageNumberStyle::OfTotal),
+            Just(PageNumberStyle::Labeled),
+        ],
+        prop_oneof![
+            Just(RunningHeader::Hidden),
+            Just(RunningHeader::Header),
+            Just(RunningHeader::Footer),
+        ],
+        any::<bool>(),
+        prop_oneof![
+            Just(ContactQr::Hidden),
+            Just(ContactQr::Header),
+            Just(ContactQr::Footer),
+        ],
+        any::<bool>(),
+        vec(arb_page_background(), 0..3),
+    )
+        .prop_map(
+            |(
+                margin,
+                format,
+                sidebar_ratio,
+                break_line,
+                page_numbers,
+                page_number_style,
+                running_header,
+                continued_markers,
+                contact_qr,
+                pdf_metadata,
+                backgrounds,
+            )| PageConfig {
+                margin,
+                format,
+                sidebar_ratio,
+                options: PageOptions {
+                    break_line,
+                    page_numbers,
+                    page_number_style,
+                    running_header,
+                    continued_markers,
+                    contact_qr,
+                    pdf_metadata,
+                },
+                backgrounds,
+            },
+        )
+}
+
+/// Colors for some pages: each color either kept or set.
+pub fn arb_page_background() -> impl Strategy<Value = PageBackground> {
+    (
+        prop_oneof![
+            Just(PageSelector::All),
+            Just(PageSelector::First),
+            Just(PageSelector::Rest),
+            Just(PageSelector::Last),
+            Just(PageSelector::Odd),
+            Just(PageSelector::Even),
+        ],
+        prop_oneof![Just(String::new()), arb_hex_color()],
+        prop_oneof![Just(String::new()), arb_hex_color()],
+        any::<bool>(),
+    )
+        .prop_map(
+            |(pages, background, sidebar, hide_sidebar)| PageBackground {
+                pages,
+                background,
+                sidebar,
+                hide_sidebar,
+            },
+        )
+}
+
+/// Theme colors.
+pub fn arb_theme() -> impl Strategy<Value = Theme> {
+    (arb_hex_color(), arb_hex_color(), arb_hex_color()).prop_map(|(background, text, primary)| {
+        Theme {
+            background,
+            text,
+            primary,
+            preset: None,
+        }
+    })
+}
+
+/// Typography settings with a font size the renderer accepts.
+pub fn arb_typography() -> impl Strategy<Value = Typography> {
+    let font = (
+        select(vec!["IBM Plex Serif", "IBM Plex Sans", "Merriweather"]),
+        8u32..=20,
+    )
+        .prop_map(|(family, size)| FontConfig {
+            family: family.to_string(),
+            size,
+            ..FontConfig::default()
+        });
+
+    (
+        font,
+        1.0f32..=2.0,
+        -0.05f32..=0.2,
+        option::of(0.0f32..=3.0),
+        0.5f32..=2.0,
+        any::<bool>(),
+        option::of(any::<bool>()),
+        option::of(any::<bool>()),
+        any::<bool>(),
+        any::<bool>(),
+    )
+        .prop_map(
+            |(
+                font,
+                line_height,
+                letter_spacing,
+                paragraph_spacing,
+                heading_scale,
+                small_caps,
+                justify,
+                hyphenate,
+                hide_icons,
+                underline_links,
+            )| Typography {
+                font,
+                line_height,
+                letter_spacing,
+                paragraph_spacing,
+                heading_scale,
+                small_caps,
+                justify,
+                hyphenate,
+                hide_icons,
+                underline_links,
+            },
+        )
+}
+
+/// Skill and language level display modes.
+pub fn arb_level_display() -> impl Strategy<Value = LevelDisplay> {
+    prop_oneof![
+        Just(LevelDisplay::TemplateDefault),
+        Just(LevelDisplay::Hidden),
+        Just(LevelDisplay::Circle),
+        Just(LevelDisplay::Square),
+        Just(LevelDisplay::ProgressBar),
+        Just(LevelDisplay::Text),
+    ]
+}
+
+/// Chart styles for the sections that have levels.
+pub fn arb_section_display() -> impl Strategy<Value = HashMap<String, SectionDisplay>> {
+    hash_map(
+        select(SECTION_DISPLAY_KEYS).prop_map(str::to_string),
+        prop_oneof![
+            Just(SectionDisplay::List),
+            Just(SectionDisplay::BarChart),
+            Just(SectionDisplay::Dots),
+            Just(SectionDisplay::TagCloud),
+        ],
+        0..=SECTION_DISPLAY_KEYS.len(),
+    )
+}
+
+/// Emoji handling policies.
+pub fn arb_emoji_policy() -> impl Strategy<Value = EmojiPolicy> {
+    prop_oneof![
+        Just(EmojiPolicy::Preserve),
+        Just(EmojiPolicy::Strip),
+        Just(EmojiPolicy::Replace),
+    ]
+}
+
+/// Photo, header, divider, and name options, including ones a template
+/// ignores.
+pub fn arb_template_options() -> impl Strategy<Value = TemplateOptions> {
+    (
+        prop_oneof![
+            Just(PhotoPosition::TemplateDefault),
+            Just(PhotoPosition::Top),
+            Just(PhotoPosition::Left),
+            Just(PhotoPosition::Right),
+            Just(PhotoPosition::Hidden),
+        ],
+        prop_oneof![
+            Just(HeaderAlign::TemplateDefault),
+            Just(HeaderAlign::Left),
+            Just(HeaderAlign::Center),
+            Just(HeaderAlign::Right),
+        ],
+        prop_oneof![
+            Just(DividerStyle::TemplateDefault),
+            Just(DividerStyle::Line),
+            Just(DividerStyle::Thick),
+            Just(DividerStyle::Dotted),
+            Just(DividerStyle::None),
+        ],
+        prop_oneof![
+            Just(NameOrder::Auto),
+            Just(NameOrder::GivenFirst),
+            Just(NameOrder::FamilyFirst),
+        ],
+        any::<bool>(),
+    )
+        .prop_map(
+            |(photo, header_align, divider, name_order, family_name_caps)| TemplateOptions {
+                photo,
+                header_align,
+                divider,
+                name_order,
+                family_name_caps,
+            },
+        )
+}
+
+/// Metadata drawn from the given templates.
+pub fn arb_metadata(templates: &'static [&'static str]) -> impl Strategy<Value = Metadata> {
+    (
+        select(templates),
+        arb_layout(),
+        any::<bool>(),
+        arb_page_config(),
+        arb_theme(),
+        arb_typography(),
+        arb_text(),
+        arb_level_display(),
+        arb_section_display(),
+        (any::<bool>(), any::<bool>()),
+        arb_emoji_policy(),
+        arb_template_options(),
+    )
+        .prop_map(
+            |(
+                template,
+                layout,
+                css_visible,
+                page,
+                theme,
+                typography,
+                notes,
+                level_display,
+                section_display,
+                (show_durations, show_cefr),
+                emoji,
+                template_options,
+            )| {
+                Metadata {
+                    template: template.to_string(),
+                    layout,
+                    css: CustomCss {
+                        value: String::new(),
+                        visible: css_visible,
+                    },
+                    page,
+                    theme,
+                    typography,
+                    notes,
+                    level_display,
+                    section_display,
+                    show_durations,
+                    show_cefr,
+                    locale: "en".to_string(),
+                    emoji,
+                    template_options,
+                }
+            },
+        )
+}
+
+// ============================================================================
+// Resume
+// ============================================================================
+
+/// A complete, valid resume using the default template.
+pub fn arb_resume_data() -> impl Strategy<Value = ResumeData> {
+    arb_resume_data_with_templates(&["rhyhorn"])
+}
+
+/// A complete, valid resume whose template is drawn from `templates`.
+///
+/// Renderer suites pass `rustume_render::TEMPLATES` so every template is exercised
+/// without this crate depending on the renderer.
+pub fn arb_resume_data_with_templates(
+    templates: &'static [&'static str],
+) -> impl Strategy<Value = ResumeData> {
+    (arb_basics(), arb_sections(), arb_metadata(templates)).prop_map(
+        |(basics, sections, metadata)| ResumeData {
+            basics,
+            sections,
+            metadata,
+            attachments: Vec::new(),
+        },
+    )
+}
@@ -0,0 +1,48 @@
+//! Property tests for schema invariants over generated resumes.
+
+use proptest::prelude::*;
+use rustume_test_support::{
+    arb_resume_data, check_json_roundtrip, check_valid, check_validation_consistent,
+};
+use validator::Validate;
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn generated_resumes_are_valid(resume in arb_resume_data()) {
+        check_valid(&resume)?;
+    }
+
+    #[test]
+    fn json_roundtrip_preserves_resume(resume in arb_resume_data()) {
+        check_json_roundtrip(&resume)?;
+    }
+
+    #[test]
+    fn validation_is_consistent(resume in arb_resume_data()) {
+        check_validation_consistent(&resume)?;
+    }
+
+    #[test]
+    fn invalid_email_is_always_rejected(
+        mut resume in arb_resume_data(),
+        local in "[a-z]{1,12}",
+    ) {
+        resume.basics.email = local;
+        let errors = resume.validate().expect_err("email without '@' must be rejected");
+        prop_assert!(errors.to_string().contains("email"), "unexpected errors: {errors}");
+        check_validation_consistent(&resume)?;
+    }
+
+    #[test]
+    fn out_of_range_skill_level_is_always_rejected(
+        mut resume in arb_resume_data(),
+        level in 6u8..,
+    ) {
+        let mut skill = rustume_schema::Skill::new("Rust");
+        skill.level = level;
+        resume.sections.skills.add_item(skill);
+        prop_assert!(resume.validate().is_err());
+    }
+}
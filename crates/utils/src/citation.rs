@@ -0,0 +1,230 @@
+//! Citations for publications identified by DOI or arXiv ID.
+//!
+//! Everything works offline: identifiers are recognized by their patterns in
+//! URLs or prose, and the citation is formatted from the fields a resume
+//! already has (title, venue, year) in a compact APA-like style, without
+//! looking anything up.
+
+use std::fmt;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+/// DOIs: the `10.` directory prefix, a registrant code, and a suffix that
+/// runs to the next whitespace or quote.
+static DOI: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"\b10\.\d{4,9}/[^\s"'<>]+"#).expect("Invalid DOI regex"));
+
+/// arXiv IDs after `arXiv:` or an arxiv.org abstract/PDF URL, in the current
+/// (`2101.00001`) or pre-2007 (`hep-th/9901001`) scheme.
+static ARXIV: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?i)arxiv(?:\.org/(?:abs|pdf)/|:\s*)(\d{4}\.\d{4,5}|[a-z-]+(?:\.[a-z]{2})?/\d{7})(v\d+)?",
+    )
+    .expect("Invalid arXiv regex")
+});
+
+/// Standalone years from 1900 to 2099.
+static YEAR: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\b(19|20)\d{2}\b").expect("Invalid year regex"));
+
+/// A persistent identifier for a scholarly work.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ScholarlyId {
+    /// A DOI such as `10.1145/3290605`, lowercased (DOIs ignore case).
+    Doi(String),
+    /// An arXiv ID such as `2101.00001` or `hep-th/9901001`, without version.
+    Arxiv(String),
+}
+
+impl ScholarlyId {
+    /// Canonical resolver URL.
+    pub fn url(&self) -> String {
+        match self {
+            Self::Doi(doi) => format!("https://doi.org/{doi}"),
+            Self::Arxiv(id) => format!("https://arxiv.org/abs/{id}"),
+        }
+    }
+
+    /// Year of submission, which current arXiv IDs encode as `YYMM`. DOIs
+    /// carry no date.
+    pub fn year(&self) -> Option<i32> {
+        match self {
+            Self::Arxiv(id) => {
+                let yymm = id.split_once('.').map_or(id.as_str(), |(yymm, _)| yymm);
+                let digits = yymm.rsplit('/').next().unwrap_or(yymm);
+                let yy: i32 = digits.get(..2)?.parse().ok()?;
+                // The old scheme started in 1991; the new one in 2007
+                Some(if yy >= 91 { 1900 + yy } else { 2000 + yy })
+            }
+            Self::Doi(_) => None,
+        }
+    }
+}
+
+impl fmt::Display for ScholarlyId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Doi(doi) => write!(f, "doi:{doi}"),
+            Self::Arxiv(id) => write!(f, "arXiv:{id}"),
+        }
+    }
+}
+
+/// Find the first DOI or arXiv ID in `text`, which may be a URL
+/// (`https://doi.org/10.1000/xyz`, `https://arxiv.org/pdf/2101.00001v2`) or
+/// prose (`doi:10.1000/xyz`, `arXiv:2101.00001`). DOIs win over arXiv IDs,
+/// since arXiv's own DOIs name the same paper.
+pub fn find_scholarly_id(text: &str) -> Option<ScholarlyId> {
+    if let Some(doi) = DOI.find(text) {
+        // Sentence punctuation and closing brackets aren't part of the DOI
+        let doi = doi
+            .as_str()
+            .trim_end_matches(['.', ',', ';', ':', ')', ']', '>']);
+        let doi = doi.strip_suffix(".pdf").unwrap_or(doi);
+        return Some(ScholarlyId::Doi(doi.to_lowercase()));
+    }
+    let caps = ARXIV.captures(text)?;
+    Some(ScholarlyId::Arxiv(caps[1].to_lowercase()))
+}
+
+/// First standalone year (1900–2099) in `text`.
+pub fn extract_year(text: &str) -> Option<i32> {
+    YEAR.find(text)?.as_str().parse().ok()
+}
+
+/// The parts of a publication a citation is built from. Empty strings and
+/// `None` are left out.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Citation<'a> {
+    /// Title of the work.
+    pub title: &'a str,
+    /// Journal, conference, or publisher.
+    pub venue: &'a str,
+    pub year: Option<i32>,
+    pub id: Option<&'a ScholarlyId>,
+}
+
+/// Format a citation as `Title. Venue (Year). https://doi.org/...`.
+pub fn format_citation(citation: &Citation) -> String {
+    let mut parts = Vec::new();
+    let title = citation.title.trim();
+    if !title.is_empty() {
+        parts.push(with_period(title));
+    }
+    let venue = citation.venue.trim();
+    match (venue.is_empty(), citation.year) {
+        (false, Some(year)) => parts.push(format!("{venue} ({year}).")),
+        (false, None) => parts.push(with_period(venue)),
+        (true, Some(year)) => parts.push(format!("({year}).")),
+        (true, None) => {}
+    }
+    if let Some(id) = citation.id {
+        parts.push(id.url());
+    }
+    parts.join(" ")
+}
+
+/// End a sentence with a period unless it already has closing punctuation.
+fn with_period(text: &str) -> String {
+    if text.ends_with(['.', '?', '!']) {
+        text.to_string()
+    } else {
+        format!("{text}.")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("https://doi.org/10.1145/3290605.3300233", "10.1145/3290605.3300233")]
+    #[case("https://dx.doi.org/10.1000/XYZ123", "10.1000/xyz123")]
+    #[case("Published as doi:10.1038/nature14539.", "10.1038/nature14539")]
+    #[case("(DOI 10.1016/j.cell.2020.01.001)", "10.1016/j.cell.2020.01.001")]
+    #[case("https://example.org/papers/10.5555/12345678.pdf", "10.5555/12345678")]
+    fn test_finds_dois(#[case] text: &str, #[case] doi: &str) {
+        assert_eq!(
+            find_scholarly_id(text),
+            Some(ScholarlyId::Doi(doi.to_string()))
+        );
+    }
+
+    #[rstest]
+    #[case("https://arxiv.org/abs/1706.03762", "1706.03762")]
+    #[case("https://arxiv.org/pdf/2101.00001v2", "2101.00001")]
+    #[case("Preprint: arXiv:2310.12345", "2310.12345")]
+    #[case("arXiv: hep-th/9901001v1", "hep-th/9901001")]
+    fn test_finds_arxiv_ids(#[case] text: &str, #[case] id: &str) {
+        assert_eq!(
+            find_scholarly_id(text),
+            Some(ScholarlyId::Arxiv(id.to_string()))
+        );
+    }
+
+    #[test]
+    fn test_prefers_doi_and_ignores_plain_numbers() {
+        assert_eq!(
+            find_scholarly_id("arXiv:2101.00001, doi:10.48550/arXiv.2101.00001"),
+            Some(ScholarlyId::Doi("10.48550/arxiv.2101.00001".to_string()))
+        );
+        assert_eq!(find_scholarly_id("Version 1706.03762 of the notes"), None);
+        assert_eq!(find_scholarly_id("https://example.com/paper"), None);
+    }
+
+    #[test]
+    fn test_id_urls_and_years() {
+        let doi = ScholarlyId::Doi("10.1000/xyz".to_string());
+        assert_eq!(doi.url(), "https://doi.org/10.1000/xyz");
+        assert_eq!(doi.to_string(), "doi:10.1000/xyz");
+        assert_eq!(doi.year(), None);
+
+        let arxiv = ScholarlyId::Arxiv("1706.03762".to_string());
+        assert_eq!(arxiv.url(), "https://arxiv.org/abs/1706.03762");
+        assert_eq!(arxiv.to_string(), "arXiv:1706.03762");
+        assert_eq!(arxiv.year(), Some(2017));
+        assert_eq!(
+            ScholarlyId::Arxiv("hep-th/9901001".to_string()).year(),
+            Some(1999)
+        );
+    }
+
+    #[test]
+    fn test_extract_year() {
+        assert_eq!(extract_year("Presented in June 2019, Vienna"), Some(2019));
+        assert_eq!(extract_year("Vol. 12, pp. 20190-20195"), None);
+        assert_eq!(extract_year("no date"), None);
+    }
+
+    #[test]
+    fn test_format_citation() {
+        let id = ScholarlyId::Arxiv("1706.03762".to_string());
+        assert_eq!(
+            format_citation(&Citation {
+                title: "Attention Is All You Need",
+                venue: "NeurIPS",
+                year: Some(2017),
+                id: Some(&id),
+            }),
+            "Attention Is All You Need. NeurIPS (2017). https://arxiv.org/abs/1706.03762"
+        );
+        assert_eq!(
+            format_citation(&Citation {
+                title: "Why?",
+                year: Some(2020),
+                ..Citation::default()
+            }),
+            "Why? (2020)."
+        );
+        assert_eq!(
+            format_citation(&Citation {
+                title: "Notes",
+                venue: "Self-published",
+                ..Citation::default()
+            }),
+            "Notes. Self-published."
+        );
+    }
+}
@@ -39,6 +39,26 @@ pub fn hex_to_rgb(hex: &str) -> Option<(u8, u8, u8)> {
     Some((r, g, b))
 }
 
+/// Convert a 6- or 8-digit hex color to an RGBA array.
+///
+/// Six digits are opaque; the last two of eight are the alpha channel, as in
+/// the picture border and shadow colors (e.g., `#00000040`).
+pub fn hex_to_rgba(hex: &str) -> Option<[u8; 4]> {
+    let hex = hex.trim();
+    let hex = hex.strip_prefix('#').unwrap_or(hex);
+    if hex.starts_with('#') {
+        return None;
+    }
+    if hex.len() == 8 && hex.is_ascii() {
+        let (r, g, b) = hex_to_rgb(&hex[..6])?;
+        let a = u8::from_str_radix(&hex[6..], 16).ok()?;
+        Some([r, g, b, a])
+    } else {
+        let (r, g, b) = hex_to_rgb(hex)?;
+        Some([r, g, b, 255])
+    }
+}
+
 /// Convert hex color to RGB(A) string.
 ///
 /// Converts valid 6-digit hex colors to CSS `rgb()` or `rgba()` format.
@@ -90,6 +110,83 @@ pub fn linear_transform(value: f64, in_min: f64, in_max: f64, out_min: f64, out_
     ((value - in_min) * (out_max - out_min)) / denom + out_min
 }
 
+/// Minimum WCAG AA contrast ratio for normal body text.
+pub const WCAG_AA_NORMAL_TEXT: f64 = 4.5;
+
+/// Minimum WCAG AA contrast ratio for large text and graphical UI components.
+pub const WCAG_AA_LARGE_TEXT: f64 = 3.0;
+
+/// Convert an RGB tuple to a lowercase `#rrggbb` hex string.
+pub fn rgb_to_hex((r, g, b): (u8, u8, u8)) -> String {
+    format!("#{:02x}{:02x}{:02x}", r, g, b)
+}
+
+/// Compute the WCAG 2.x relative luminance of an sRGB color.
+///
+/// # Returns
+/// A value in `0.0..=1.0`, where `0.0` is black and `1.0` is white.
+pub fn relative_luminance((r, g, b): (u8, u8, u8)) -> f64 {
+    fn channel(value: u8) -> f64 {
+        let c = f64::from(value) / 255.0;
+        if c <= 0.03928 {
+            c / 12.92
+        } else {
+            ((c + 0.055) / 1.055).powf(2.4)
+        }
+    }
+
+    0.2126 * channel(r) + 0.7152 * channel(g) + 0.0722 * channel(b)
+}
+
+/// Compute the WCAG contrast ratio between two hex colors.
+///
+/// The ratio is symmetric, so argument order does not matter.
+///
+/// # Returns
+/// * `Some(ratio)` in `1.0..=21.0` when both colors are valid 6-digit hex
+/// * `None` if either color cannot be parsed
+pub fn contrast_ratio(a: &str, b: &str) -> Option<f64> {
+    let la = relative_luminance(hex_to_rgb(a)?);
+    let lb = relative_luminance(hex_to_rgb(b)?);
+    let (lighter, darker) = if la >= lb { (la, lb) } else { (lb, la) };
+    Some((lighter + 0.05) / (darker + 0.05))
+}
+
+/// Suggest the nearest color to `foreground` that meets `min_ratio` against `background`.
+///
+/// The foreground is mixed towards black and towards white in small steps; the
+/// candidate needing the least mixing wins, so the hue stays as close to the
+/// original as possible.
+///
+/// # Returns
+/// * `Some(hex)` with the adjusted color (the original, normalized, if it already complies)
+/// * `None` if either color is invalid or no mix reaches the requested ratio
+pub fn suggest_compliant_color(
+    foreground: &str,
+    background: &str,
+    min_ratio: f64,
+) -> Option<String> {
+    const STEPS: u16 = 256;
+
+    let fg = hex_to_rgb(foreground)?;
+    let bg = rgb_to_hex(hex_to_rgb(background)?);
+
+    let mix = |target: u8, t: f64| -> (u8, u8, u8) {
+        let blend = |c: u8| (f64::from(c) + (f64::from(target) - f64::from(c)) * t).round() as u8;
+        (blend(fg.0), blend(fg.1), blend(fg.2))
+    };
+
+    (0..=STEPS)
+        .map(|step| f64::from(step) / f64::from(STEPS))
+        .find_map(|t| {
+            [mix(0, t), mix(255, t)].into_iter().find_map(|candidate| {
+                let hex = rgb_to_hex(candidate);
+                let ratio = contrast_ratio(&hex, &bg)?;
+                (ratio >= min_ratio).then_some(hex)
+            })
+        })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -102,6 +199,16 @@ mod tests {
         assert_eq!(hex_to_rgb("invalid"), None);
     }
 
+    #[test]
+    fn test_hex_to_rgba() {
+        assert_eq!(hex_to_rgba("#dc2626"), Some([220, 38, 38, 255]));
+        assert_eq!(hex_to_rgba("#00000040"), Some([0, 0, 0, 64]));
+        assert_eq!(hex_to_rgba("ffffff80"), Some([255, 255, 255, 128]));
+        assert_eq!(hex_to_rgba("##000000"), None);
+        assert_eq!(hex_to_rgba("#0000004g"), None);
+        assert_eq!(hex_to_rgba("#fff"), None);
+    }
+
     #[test]
     fn test_hex_to_rgb_without_hash() {
         assert_eq!(hex_to_rgb("ffffff"), Some((255, 255, 255)));
@@ -167,4 +274,70 @@ mod tests {
         // Normal case with large values should work
         assert!((linear_transform(5e10, 0.0, 10e10, 0.0, 100.0) - 50.0).abs() < 1e-10);
     }
+
+    #[test]
+    fn test_rgb_to_hex() {
+        assert_eq!(rgb_to_hex((255, 255, 255)), "#ffffff");
+        assert_eq!(rgb_to_hex((220, 38, 38)), "#dc2626");
+        assert_eq!(rgb_to_hex((0, 10, 171)), "#000aab");
+    }
+
+    #[test]
+    fn test_relative_luminance_extremes() {
+        assert!(relative_luminance((0, 0, 0)).abs() < f64::EPSILON);
+        assert!((relative_luminance((255, 255, 255)) - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_contrast_ratio() {
+        let max = contrast_ratio("#000000", "#ffffff").unwrap();
+        assert!((max - 21.0).abs() < 1e-9);
+        assert!((contrast_ratio("#ffffff", "#000000").unwrap() - max).abs() < f64::EPSILON);
+        assert!((contrast_ratio("#777777", "#777777").unwrap() - 1.0).abs() < f64::EPSILON);
+        assert!(contrast_ratio("red", "#ffffff").is_none());
+    }
+
+    #[test]
+    fn test_contrast_ratio_known_pairs() {
+        // #767676 is the classic lightest gray passing AA on white.
+        assert!(contrast_ratio("#767676", "#ffffff").unwrap() >= WCAG_AA_NORMAL_TEXT);
+        assert!(contrast_ratio("#777777", "#ffffff").unwrap() < WCAG_AA_NORMAL_TEXT);
+    }
+
+    #[test]
+    fn test_suggest_compliant_color_keeps_passing_color() {
+        assert_eq!(
+            suggest_compliant_color("#000000", "#ffffff", WCAG_AA_NORMAL_TEXT),
+            Some("#000000".to_string())
+        );
+    }
+
+    #[test]
+    fn test_suggest_compliant_color_darkens_on_light_background() {
+        let suggestion =
+            suggest_compliant_color("#aaaaaa", "#ffffff", WCAG_AA_NORMAL_TEXT).unwrap();
+        assert!(contrast_ratio(&suggestion, "#ffffff").unwrap() >= WCAG_AA_NORMAL_TEXT);
+        let (r, _, _) = hex_to_rgb(&suggestion).unwrap();
+        assert!(r < 0xaa, "expected a darker gray, got {suggestion}");
+    }
+
+    #[test]
+    fn test_suggest_compliant_color_lightens_on_dark_background() {
+        let suggestion =
+            suggest_compliant_color("#333333", "#000000", WCAG_AA_NORMAL_TEXT).unwrap();
+        assert!(contrast_ratio(&suggestion, "#000000").unwrap() >= WCAG_AA_NORMAL_TEXT);
+        let (r, _, _) = hex_to_rgb(&suggestion).unwrap();
+        assert!(r > 0x33, "expected a lighter gray, got {suggestion}");
+    }
+
+    #[test]
+    fn test_suggest_compliant_color_invalid_input() {
+        assert!(suggest_compliant_color("nope", "#ffffff", WCAG_AA_NORMAL_TEXT).is_none());
+        assert!(suggest_compliant_color("#000000", "", WCAG_AA_NORMAL_TEXT).is_none());
+    }
+
+    #[test]
+    fn test_suggest_compliant_color_unreachable_ratio() {
+        assert!(suggest_compliant_color("#777777", "#777777", 22.0).is_none());
+    }
 }
@@ -0,0 +1,426 @@
+//! Contact detail extraction and normalization.
+//!
+//! Importers find emails, phone numbers, and links in free text with the
+//! `extract_*` functions, then tidy them for storage: [`format_phone`],
+//! [`normalize_email`], and [`canonicalize_url`]. [`suggest_email`] catches
+//! mistyped provider domains such as `gamil.com`.
+//!
+//! With the `phone` feature, phone numbers are parsed and formatted with
+//! libphonenumber's metadata, which knows each country's numbering plan.
+//! Without it (the WASM bundles, where the metadata would dominate the
+//! download), [`format_phone`] only checks that a number is plausible and
+//! tidies its spacing.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::is_valid_email;
+
+static EMAIL: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)\b[a-z0-9._%+-]+@[a-z0-9-]+(?:\.[a-z0-9-]+)*\.[a-z]{2,}\b")
+        .expect("Invalid email regex")
+});
+
+static PHONE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?:\+\d{1,3}[ .-]?)?(?:\(\d{1,4}\)[ .-]?)?\d[\d .-]{5,}\d")
+        .expect("Invalid phone regex")
+});
+
+/// Links with a scheme or `www.`, and bare links to well-known profile hosts.
+static LINK: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r#"(?i)\b(?:https?://|www\.)[^\s|,;<>()"]+|\b(?:[a-z0-9-]+\.)?(?:linkedin\.com|github\.com|gitlab\.com|stackoverflow\.com)/[^\s|,;<>()"]+"#,
+    )
+    .expect("Invalid link regex")
+});
+
+static PHONE_EXTENSION: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)\s*(?:ext\.?|x|#)\s*\d{1,6}$").expect("Invalid phone extension regex")
+});
+
+/// A year range such as `2015 - 2019`, which [`PHONE`] would otherwise take
+/// for a number.
+static YEAR_RANGE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^(?:19|20)\d{2}\s*[-.]?\s*(?:(?:19|20)\d{2})?$").expect("Invalid year range regex")
+});
+
+/// Common email providers, for [`suggest_email`].
+const EMAIL_DOMAINS: &[&str] = &[
+    "gmail.com",
+    "googlemail.com",
+    "yahoo.com",
+    "hotmail.com",
+    "outlook.com",
+    "live.com",
+    "msn.com",
+    "icloud.com",
+    "me.com",
+    "aol.com",
+    "proton.me",
+    "protonmail.com",
+    "gmx.de",
+    "gmx.net",
+    "web.de",
+    "yandex.ru",
+    "mail.com",
+    "zoho.com",
+];
+
+/// Misspelled top-level domains and their likely intent.
+const TLD_TYPOS: &[(&str, &str)] = &[
+    ("con", "com"),
+    ("cmo", "com"),
+    ("ocm", "com"),
+    ("vom", "com"),
+    ("xom", "com"),
+    ("comm", "com"),
+    ("coom", "com"),
+    ("nte", "net"),
+    ("nett", "net"),
+    ("ogr", "org"),
+    ("orgg", "org"),
+];
+
+/// Query parameters that only track where a click came from.
+const TRACKING_PARAMS: &[&str] = &["fbclid", "gclid", "msclkid", "mc_cid", "mc_eid", "trk"];
+
+// ============================================================================
+// Extraction
+// ============================================================================
+
+/// Email addresses in `text`, in order.
+pub fn extract_emails(text: &str) -> Vec<String> {
+    EMAIL
+        .find_iter(text)
+        .map(|m| m.as_str().to_string())
+        .collect()
+}
+
+/// Phone numbers in `text`, in order: runs of 7 to 15 digits with the usual
+/// separators, skipping year ranges.
+pub fn extract_phones(text: &str) -> Vec<String> {
+    PHONE
+        .find_iter(text)
+        .map(|m| m.as_str().trim())
+        .filter(|candidate| {
+            let digits = candidate.chars().filter(char::is_ascii_digit).count();
+            (7..=15).contains(&digits) && !YEAR_RANGE.is_match(candidate)
+        })
+        .map(str::to_string)
+        .collect()
+}
+
+/// Links in `text`, in order, as written. Bare `www.` and profile host links
+/// are included; pass them through [`canonicalize_url`] to add the scheme.
+pub fn extract_urls(text: &str) -> Vec<String> {
+    LINK.find_iter(text)
+        .map(|m| {
+            m.as_str()
+                .trim_end_matches(['.', ',', ')', ':'])
+                .to_string()
+        })
+        .collect()
+}
+
+// ============================================================================
+// Phone Numbers
+// ============================================================================
+
+/// Split a trailing extension (`ext. 12`, `x12`, `#12`) off `phone`.
+pub fn split_phone_extension(phone: &str) -> (&str, Option<&str>) {
+    let phone = phone.trim();
+    match PHONE_EXTENSION.find(phone) {
+        Some(extension) => {
+            let digits = extension
+                .as_str()
+                .trim_start_matches(|c: char| !c.is_ascii_digit());
+            (&phone[..extension.start()], Some(digits))
+        }
+        None => (phone, None),
+    }
+}
+
+/// Whether `phone` looks dialable: 7 to 15 digits (the E.164 maximum) and
+/// only digits, spaces, and `+-()./`, ignoring a trailing extension.
+pub fn is_plausible_phone(phone: &str) -> bool {
+    let (number, _) = split_phone_extension(phone);
+    let digits = number.chars().filter(char::is_ascii_digit).count();
+    // `+` may only lead the number.
+    let allowed = number
+        .trim_start_matches('+')
+        .chars()
+        .all(|c| c.is_ascii_digit() || " -()./".contains(c));
+    allowed && (7..=15).contains(&digits)
+}
+
+/// Format `phone` in international form, `+1 757-555-0142`.
+///
+/// `region` is the ISO 3166 country code (`US`, `de`) a national number is
+/// read in; numbers starting with `+` don't need one. Returns `None` when the
+/// number isn't valid there.
+#[cfg(feature = "phone")]
+pub fn format_phone(phone: &str, region: Option<&str>) -> Option<String> {
+    use phonenumber::country::Id;
+
+    let region = match region {
+        Some(region) => Some(region.trim().to_uppercase().parse::<Id>().ok()?),
+        None => None,
+    };
+    let number = phonenumber::parse(region, phone.trim()).ok()?;
+    phonenumber::is_valid(&number).then(|| {
+        number
+            .format()
+            .mode(phonenumber::Mode::International)
+            .to_string()
+    })
+}
+
+/// Format `phone` in international form, `+1 757-555-0142`.
+///
+/// Built without the `phone` feature, this can't check a number against its
+/// country's plan: it returns plausible numbers (see [`is_plausible_phone`])
+/// with their spacing tidied, and ignores `region`.
+#[cfg(not(feature = "phone"))]
+pub fn format_phone(phone: &str, _region: Option<&str>) -> Option<String> {
+    is_plausible_phone(phone).then(|| phone.split_whitespace().collect::<Vec<_>>().join(" "))
+}
+
+// ============================================================================
+// Email
+// ============================================================================
+
+/// `email` trimmed, without a `mailto:` prefix, and with its domain
+/// lowercased. `None` if it isn't a valid address.
+pub fn normalize_email(email: &str) -> Option<String> {
+    let email = email.trim();
+    let email = email
+        .get(..7)
+        .filter(|prefix| prefix.eq_ignore_ascii_case("mailto:"))
+        .map_or(email, |_| &email[7..]);
+    if !is_valid_email(email) {
+        return None;
+    }
+    let (local, domain) = email.rsplit_once('@')?;
+    Some(format!("{local}@{}", domain.to_lowercase()))
+}
+
+/// A corrected `email` when its domain looks like a typo of a common
+/// provider (`gamil.com`, `hotmial.com`) or of `.com`, `.net`, or `.org`
+/// (`example.con`). `None` if the address looks right or isn't valid.
+pub fn suggest_email(email: &str) -> Option<String> {
+    let email = normalize_email(email)?;
+    let (local, domain) = email.rsplit_once('@')?;
+    if EMAIL_DOMAINS.contains(&domain) {
+        return None;
+    }
+
+    // Allow one edit in short domains, two in longer ones
+    let max_distance = if domain.len() < 8 { 1 } else { 2 };
+    let provider = EMAIL_DOMAINS
+        .iter()
+        .map(|candidate| (strsim::damerau_levenshtein(domain, candidate), *candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate.to_string());
+
+    let suggestion = provider.or_else(|| {
+        let (name, tld) = domain.rsplit_once('.')?;
+        TLD_TYPOS
+            .iter()
+            .find(|(typo, _)| *typo == tld)
+            .map(|(_, fixed)| format!("{name}.{fixed}"))
+    })?;
+    Some(format!("{local}@{suggestion}"))
+}
+
+// ============================================================================
+// URLs
+// ============================================================================
+
+/// `url` in a canonical form, so the same link written two ways compares
+/// equal:
+///
+/// - `https://` is added when there is no scheme; only HTTP(S) links are
+///   accepted
+/// - the scheme and host are lowercased, and default ports dropped
+/// - tracking parameters (`utm_*`, `fbclid`, ...) are removed
+/// - a trailing `/` is removed
+///
+/// Returns `None` if there is no plausible host.
+pub fn canonicalize_url(url: &str) -> Option<String> {
+    let url = url
+        .trim()
+        .trim_start_matches('<')
+        .trim_end_matches(['>', '.', ',', ';', ')']);
+    let (scheme, rest) = match url.split_once("://") {
+        Some((scheme, rest)) => (scheme.to_lowercase(), rest),
+        None => ("https".to_string(), url),
+    };
+    if scheme != "http" && scheme != "https" {
+        return None;
+    }
+
+    let end = rest.find(['/', '?', '#']).unwrap_or(rest.len());
+    let (authority, rest) = rest.split_at(end);
+    let mut host = authority.to_lowercase();
+    let default_port = if scheme == "https" { ":443" } else { ":80" };
+    if let Some(stripped) = host.strip_suffix(default_port) {
+        host = stripped.to_string();
+    }
+    let hostname = host.split(':').next().unwrap_or_default();
+    let plausible = hostname.contains('.')
+        && !hostname.starts_with('.')
+        && !hostname.ends_with('.')
+        && hostname
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '-' || c == '.');
+    if !plausible {
+        return None;
+    }
+
+    let (rest, fragment) = match rest.split_once('#') {
+        Some((rest, fragment)) if !fragment.is_empty() => (rest, Some(fragment)),
+        Some((rest, _)) => (rest, None),
+        None => (rest, None),
+    };
+    let (path, query) = rest.split_once('?').unwrap_or((rest, ""));
+    let query: Vec<&str> = query
+        .split('&')
+        .filter(|param| {
+            let key = param.split('=').next().unwrap_or_default().to_lowercase();
+            !key.is_empty() && !key.starts_with("utm_") && !TRACKING_PARAMS.contains(&key.as_str())
+        })
+        .collect();
+
+    let mut canonical = format!("{scheme}://{host}{}", path.trim_end_matches('/'));
+    if !query.is_empty() {
+        canonical.push('?');
+        canonical.push_str(&query.join("&"));
+    }
+    if let Some(fragment) = fragment {
+        canonical.push('#');
+        canonical.push_str(fragment);
+    }
+    Some(canonical)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEXT: &str = "Jane Doe | jane@Example.org | +1 (757) 555-0142\n\
+                        2015 - 2019 · github.com/jane · https://jane.dev/?utm_source=cv.";
+
+    #[test]
+    fn test_extracts_contact_details() {
+        assert_eq!(extract_emails(TEXT), ["jane@Example.org"]);
+        assert_eq!(extract_phones(TEXT), ["+1 (757) 555-0142"]);
+        assert_eq!(
+            extract_urls(TEXT),
+            ["github.com/jane", "https://jane.dev/?utm_source=cv"]
+        );
+    }
+
+    #[test]
+    fn test_split_phone_extension() {
+        assert_eq!(
+            split_phone_extension("+49 30 1234567 ext. 12"),
+            ("+49 30 1234567", Some("12"))
+        );
+        assert_eq!(
+            split_phone_extension("555-0142 x9"),
+            ("555-0142", Some("9"))
+        );
+        assert_eq!(split_phone_extension(" 555-0142 "), ("555-0142", None));
+    }
+
+    #[test]
+    fn test_plausible_phones() {
+        assert!(is_plausible_phone("+49 30 1234567 ext. 12"));
+        assert!(is_plausible_phone("(757) 555-0142"));
+        assert!(!is_plausible_phone("12345"));
+        assert!(!is_plausible_phone("555-0100 +1"));
+        assert!(!is_plausible_phone("call me"));
+    }
+
+    #[test]
+    #[cfg(feature = "phone")]
+    fn test_format_phone() {
+        assert_eq!(
+            format_phone("(650) 253-0000", Some("us")).as_deref(),
+            Some("+1 650-253-0000")
+        );
+        assert_eq!(
+            format_phone("030 303986300", Some("DE")).as_deref(),
+            Some("+49 30 303986300")
+        );
+        assert_eq!(
+            format_phone("+442070313000", None).as_deref(),
+            Some("+44 20 7031 3000")
+        );
+        assert_eq!(format_phone("030 303986300", None), None);
+        assert_eq!(format_phone("12345", Some("US")), None);
+        assert_eq!(format_phone("555-0142", Some("XX")), None);
+    }
+
+    #[test]
+    #[cfg(not(feature = "phone"))]
+    fn test_format_phone() {
+        assert_eq!(
+            format_phone(" +1  757 555-0142 ", Some("US")).as_deref(),
+            Some("+1 757 555-0142")
+        );
+        assert_eq!(format_phone("12345", None), None);
+    }
+
+    #[test]
+    fn test_normalize_email() {
+        assert_eq!(
+            normalize_email(" mailto:Jane.Doe@Example.ORG ").as_deref(),
+            Some("Jane.Doe@example.org")
+        );
+        assert_eq!(normalize_email("not-an-email"), None);
+    }
+
+    #[test]
+    fn test_suggest_email() {
+        for (typo, fixed) in [
+            ("jane@gamil.com", "jane@gmail.com"),
+            ("jane@gmail.co", "jane@gmail.com"),
+            ("jane@hotmial.com", "jane@hotmail.com"),
+            ("jane@yahooo.com", "jane@yahoo.com"),
+            ("jane@example.con", "jane@example.com"),
+        ] {
+            assert_eq!(suggest_email(typo).as_deref(), Some(fixed), "{typo}");
+        }
+        for fine in ["jane@gmail.com", "jane@example.org", "jane@acme.io", "nope"] {
+            assert_eq!(suggest_email(fine), None, "{fine}");
+        }
+    }
+
+    #[test]
+    fn test_canonicalize_url() {
+        for (url, canonical) in [
+            ("github.com/jane/", "https://github.com/jane"),
+            ("HTTPS://Jane.DEV:443/Blog/", "https://jane.dev/Blog"),
+            (
+                "https://jane.dev/?utm_source=cv&page=2&fbclid=x#work",
+                "https://jane.dev?page=2#work",
+            ),
+            (
+                "<http://www.example.com:8080/a>.",
+                "http://www.example.com:8080/a",
+            ),
+        ] {
+            assert_eq!(canonicalize_url(url).as_deref(), Some(canonical), "{url}");
+        }
+        for url in [
+            "mailto:jane@example.org",
+            "localhost",
+            "https://",
+            "ftp://x.org",
+        ] {
+            assert_eq!(canonicalize_url(url), None, "{url}");
+        }
+    }
+}
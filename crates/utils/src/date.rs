@@ -1,14 +1,293 @@
 //! Date handling utilities.
+//!
+//! Resume dates are free-form strings ("Jan 2020 - Present", "2018-2021").
+//! [`parse_date_range`] recovers the bounds from the common spellings so
+//! they can be compared; anything it does not recognize yields `None`.
+
+use std::fmt;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::locale::{is_localized_present, Locale, EN};
+
+/// Separators between the two ends of a range: spaced hyphen, en/em dash, or "to".
+static RANGE_SEPARATOR: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)\s+(?:-|to|until)\s+|\s*[–—]\s*").expect("Invalid range separator regex")
+});
+
+/// Unspaced year ranges such as "2018-2021" or "2018-Present".
+static COMPACT_YEAR_RANGE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^(\d{4})\s*-\s*(\d{4}|[A-Za-z]+)$").expect("Invalid compact range regex")
+});
+
+static ISO_DATE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^(\d{4})(?:[-/](\d{1,2})(?:[-/](\d{1,2}))?)?$").expect("Invalid ISO date regex")
+});
+
+static MONTH_YEAR: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^(?:(\d{1,2})/(\d{4})|([A-Za-z]{3,})\.?,?\s+(\d{4}))$")
+        .expect("Invalid month-year regex")
+});
+
+/// Words that mark a range as ongoing.
+const ONGOING: &[&str] = &["present", "current", "now", "today", "ongoing"];
+
+const MONTHS: [&str; 12] = [
+    "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+];
+
+/// A calendar date known to year, month, or day precision.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PartialDate {
+    pub year: i32,
+    pub month: Option<u32>,
+    pub day: Option<u32>,
+}
+
+impl PartialDate {
+    /// Today's date (UTC), to day precision.
+    pub fn today() -> Self {
+        use chrono::Datelike;
+
+        let today = chrono::Utc::now().date_naive();
+        Self {
+            year: today.year(),
+            month: Some(today.month()),
+            day: Some(today.day()),
+        }
+    }
+
+    /// Whether `self` is strictly earlier than `other` at the precision both
+    /// share: "2020" is neither before nor after "2020-06".
+    pub fn is_before(&self, other: &PartialDate) -> bool {
+        if self.year != other.year {
+            return self.year < other.year;
+        }
+        match (self.month, other.month) {
+            (Some(a), Some(b)) if a != b => a < b,
+            (Some(_), Some(_)) => matches!((self.day, other.day), (Some(a), Some(b)) if a < b),
+            _ => false,
+        }
+    }
+}
+
+/// Formats as an ISO 8601 partial date: `2020`, `2020-03`, or `2020-03-15`.
+impl fmt::Display for PartialDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}", self.year)?;
+        if let Some(month) = self.month {
+            write!(f, "-{:02}", month)?;
+            if let Some(day) = self.day {
+                write!(f, "-{:02}", day)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A parsed date range. `end` is `None` when the range is ongoing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DateRange {
+    pub start: PartialDate,
+    pub end: Option<PartialDate>,
+}
+
+impl DateRange {
+    /// Length of the range in whole months; ongoing ranges run to `today`.
+    ///
+    /// Months are counted inclusively (Jan–Mar is 3 months), matching how
+    /// LinkedIn shows tenure. A year-only end counts up to that year, so
+    /// "2018 - 2020" is 24 months.
+    pub fn months(&self, today: &PartialDate) -> u32 {
+        let (start, end) = self.month_span(today);
+        u32::try_from(end - start).unwrap_or(0)
+    }
+
+    /// Half-open span of month indexes (`year * 12 + month - 1`); ongoing
+    /// ranges run to `today`.
+    pub fn month_span(&self, today: &PartialDate) -> (i64, i64) {
+        let end = self.end.unwrap_or(*today);
+        let start = i64::from(self.start.year) * 12 + i64::from(self.start.month.unwrap_or(1)) - 1;
+        let end = i64::from(end.year) * 12 + i64::from(end.month.unwrap_or(0));
+        (start, end)
+    }
+}
+
+/// Months covered by at least one of `ranges`, so concurrent roles are not
+/// counted twice.
+pub fn total_months<'a>(
+    ranges: impl IntoIterator<Item = &'a DateRange>,
+    today: &PartialDate,
+) -> u32 {
+    let mut spans: Vec<(i64, i64)> = ranges
+        .into_iter()
+        .map(|range| range.month_span(today))
+        .filter(|(start, end)| start < end)
+        .collect();
+    spans.sort_unstable();
+
+    let mut total = 0;
+    let mut covered_until = i64::MIN;
+    for (start, end) in spans {
+        let start = start.max(covered_until);
+        if end > start {
+            total += end - start;
+            covered_until = end;
+        }
+    }
+    u32::try_from(total).unwrap_or(u32::MAX)
+}
+
+/// Format a month count the way LinkedIn does: `2 yrs 3 mos`, `1 yr`,
+/// `5 mos`. Zero months formats as an empty string.
+pub fn format_duration(months: u32) -> String {
+    format_duration_in(months, &EN)
+}
+
+/// [`format_duration`] with the units of `locale` (`2 Jahre 3 Monate`).
+pub fn format_duration_in(months: u32, locale: &Locale) -> String {
+    let (years, months) = (months / 12, months % 12);
+    let years = match years {
+        0 => None,
+        1 => Some(format!("1 {}", locale.year)),
+        n => Some(format!("{n} {}", locale.years)),
+    };
+    let months = match months {
+        0 => None,
+        1 => Some(format!("1 {}", locale.month)),
+        n => Some(format!("{n} {}", locale.months)),
+    };
+    years
+        .into_iter()
+        .chain(months)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Parse a single date: `2020`, `2020-03`, `2020-03-15`, `03/2020`, `Mar 2020`,
+/// or `March 2020`.
+pub fn parse_partial_date(input: &str) -> Option<PartialDate> {
+    let input = input.trim();
+
+    if let Some(caps) = ISO_DATE.captures(input) {
+        let year = caps[1].parse().ok()?;
+        let month = caps.get(2).map(|m| m.as_str().parse()).transpose().ok()?;
+        let day = caps.get(3).map(|d| d.as_str().parse()).transpose().ok()?;
+        return checked_date(year, month, day);
+    }
+
+    let caps = MONTH_YEAR.captures(input)?;
+    if let (Some(month), Some(year)) = (caps.get(1), caps.get(2)) {
+        return checked_date(
+            year.as_str().parse().ok()?,
+            month.as_str().parse().ok(),
+            None,
+        );
+    }
+    let name = caps.get(3)?.as_str().to_lowercase();
+    let month = MONTHS.iter().position(|m| name.starts_with(m))? as u32 + 1;
+    checked_date(caps[4].parse().ok()?, Some(month), None)
+}
+
+/// Parse a date range such as `Jan 2020 - Present`, `2018-2021`, or
+/// `2019-04 to 2020-01`. A single date is a range that starts and ends on it.
+pub fn parse_date_range(input: &str) -> Option<DateRange> {
+    let input = input.trim();
+    let (start, end) = match RANGE_SEPARATOR.splitn(input, 2).collect::<Vec<_>>()[..] {
+        [start, end] => (start, end),
+        _ => match COMPACT_YEAR_RANGE.captures(input) {
+            Some(caps) => (caps.get(1)?.as_str(), caps.get(2)?.as_str()),
+            None => (input, input),
+        },
+    };
+
+    let start = parse_partial_date(start)?;
+    let end = end.trim();
+    let end = if ONGOING.contains(&end.to_lowercase().as_str()) || is_localized_present(end) {
+        None
+    } else {
+        Some(parse_partial_date(end)?)
+    };
+    Some(DateRange { start, end })
+}
+
+fn checked_date(year: i32, month: Option<u32>, day: Option<u32>) -> Option<PartialDate> {
+    match (month, day) {
+        (Some(month), Some(day)) => {
+            chrono::NaiveDate::from_ymd_opt(year, month, day)?;
+        }
+        (Some(month), None) if !(1..=12).contains(&month) => return None,
+        (None, Some(_)) => return None,
+        _ => {}
+    }
+    Some(PartialDate { year, month, day })
+}
+
+/// Parse a strict ISO 8601 partial date (`YYYY`, `YYYY-MM`, or `YYYY-MM-DD`),
+/// the form used by structured `startDate`/`endDate` fields.
+pub fn parse_iso_partial_date(input: &str) -> Option<PartialDate> {
+    parse_partial_date(input).filter(|date| date.to_string() == input)
+}
+
+/// Structured ISO start and end dates for a display date string.
+///
+/// The end is `None` for ongoing ranges; a single date starts and ends on
+/// itself. Returns `None` when the string is not a recognizable date.
+pub fn iso_date_range(date: &str) -> Option<(String, Option<String>)> {
+    let range = parse_date_range(date)?;
+    Some((
+        range.start.to_string(),
+        range.end.map(|end| end.to_string()),
+    ))
+}
+
+/// Display string for structured ISO dates: `Mar 2020 - Present`,
+/// `2016 - 2020`, or a single `Jun 2019` when both ends are the same.
+///
+/// Days are dropped, as resume dates rarely show them. Values that are not
+/// ISO partial dates are shown as given.
+pub fn display_date_range(start: &str, end: Option<&str>) -> String {
+    display_date_range_in(start, end, &EN)
+}
+
+/// [`display_date_range`] with the ongoing end written in `locale`.
+pub fn display_date_range_in(start: &str, end: Option<&str>, locale: &Locale) -> String {
+    let display = |date: &str| match parse_iso_partial_date(date.trim()) {
+        Some(PartialDate {
+            year,
+            month: Some(month),
+            ..
+        }) => {
+            let name = MONTHS[month as usize - 1];
+            format!("{}{} {}", name[..1].to_uppercase(), &name[1..], year)
+        }
+        Some(PartialDate { year, .. }) => year.to_string(),
+        None => date.trim().to_string(),
+    };
+
+    let start = display(start);
+    match end.map(display) {
+        Some(end) if end == start => start,
+        end => format_date_range_in(Some(&start), end.as_deref(), locale),
+    }
+}
 
 /// Format a date range string.
 /// Normalizes empty/whitespace strings and handles end-only ranges cleanly.
 pub fn format_date_range(start: Option<&str>, end: Option<&str>) -> String {
+    format_date_range_in(start, end, &EN)
+}
+
+/// [`format_date_range`] with the ongoing end written in `locale`
+/// (`2020 - Heute`).
+pub fn format_date_range_in(start: Option<&str>, end: Option<&str>, locale: &Locale) -> String {
     let start = start.map(str::trim).filter(|s| !s.is_empty());
     let end = end.map(str::trim).filter(|e| !e.is_empty());
 
     match (start, end) {
         (Some(s), Some(e)) => format!("{} - {}", s, e),
-        (Some(s), None) => format!("{} - Present", s),
+        (Some(s), None) => format!("{} - {}", s, locale.present),
         (None, Some(e)) => e.to_string(),
         _ => String::new(),
     }
@@ -33,4 +312,179 @@ mod tests {
             "2020 - Present"
         );
     }
+
+    #[test]
+    fn test_iso_partial_dates_round_trip() {
+        assert_eq!(
+            parse_iso_partial_date("2020-03").unwrap().to_string(),
+            "2020-03"
+        );
+        assert_eq!(
+            parse_iso_partial_date("2020-03-05").unwrap().to_string(),
+            "2020-03-05"
+        );
+        assert!(parse_iso_partial_date("2020-3").is_none());
+        assert!(parse_iso_partial_date("Mar 2020").is_none());
+        assert!(parse_iso_partial_date(" 2020").is_none());
+    }
+
+    #[test]
+    fn test_iso_date_range() {
+        assert_eq!(
+            iso_date_range("Jan 2020 - Present"),
+            Some(("2020-01".to_string(), None))
+        );
+        assert_eq!(
+            iso_date_range("2016 - 2020"),
+            Some(("2016".to_string(), Some("2020".to_string())))
+        );
+        assert_eq!(
+            iso_date_range("06/2019"),
+            Some(("2019-06".to_string(), Some("2019-06".to_string())))
+        );
+        assert_eq!(iso_date_range("Summer"), None);
+    }
+
+    #[test]
+    fn test_display_date_range() {
+        assert_eq!(display_date_range("2020-01", None), "Jan 2020 - Present");
+        assert_eq!(display_date_range("2016", Some("2020")), "2016 - 2020");
+        assert_eq!(
+            display_date_range("2019-06-15", Some("2019-06")),
+            "Jun 2019"
+        );
+        assert_eq!(
+            display_date_range("2019-06", Some("")),
+            "Jun 2019 - Present"
+        );
+        assert_eq!(
+            display_date_range("Fall 2019", Some("2020")),
+            "Fall 2019 - 2020"
+        );
+
+        // Display strings parse back to the same structured dates.
+        let display = display_date_range("2018-09", Some("2021-05"));
+        assert_eq!(
+            iso_date_range(&display),
+            Some(("2018-09".to_string(), Some("2021-05".to_string())))
+        );
+    }
+
+    #[test]
+    fn test_range_months() {
+        let today = date(2024, Some(6), Some(15));
+        let months = |range: &str| parse_date_range(range).unwrap().months(&today);
+
+        assert_eq!(months("Jan 2020 - Mar 2020"), 3);
+        assert_eq!(months("2018 - 2020"), 24);
+        assert_eq!(months("2022-01 - Present"), 30);
+        assert_eq!(months("2020 - 2020"), 0);
+        assert_eq!(months("2021 - 2019"), 0);
+    }
+
+    #[test]
+    fn test_total_months_merges_overlaps() {
+        let today = date(2024, Some(6), None);
+        let ranges: Vec<DateRange> = [
+            "Jan 2019 - Dec 2020",
+            "Jun 2020 - Jun 2021",
+            "2023-01 - 2023-06",
+        ]
+        .iter()
+        .map(|range| parse_date_range(range).unwrap())
+        .collect();
+
+        // 2019-01..2021-06 is 30 months, plus 6 in 2023.
+        assert_eq!(total_months(&ranges, &today), 36);
+        assert_eq!(total_months(&[], &today), 0);
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(27), "2 yrs 3 mos");
+        assert_eq!(format_duration(12), "1 yr");
+        assert_eq!(format_duration(13), "1 yr 1 mo");
+        assert_eq!(format_duration(5), "5 mos");
+        assert_eq!(format_duration(0), "");
+    }
+
+    #[test]
+    fn test_localized_formatting() {
+        let de = Locale::for_tag("de-DE");
+        assert_eq!(format_duration_in(27, de), "2 Jahre 3 Monate");
+        assert_eq!(format_duration_in(13, de), "1 Jahr 1 Monat");
+        assert_eq!(format_date_range_in(Some("2020"), None, de), "2020 - Heute");
+        assert_eq!(
+            display_date_range_in("2020-01", None, Locale::for_tag("es")),
+            "Jan 2020 - Actualidad"
+        );
+        // Localized ongoing ends still parse, so durations work in any locale
+        assert!(parse_date_range("Jan 2020 - Heute").unwrap().end.is_none());
+    }
+
+    fn date(year: i32, month: Option<u32>, day: Option<u32>) -> PartialDate {
+        PartialDate { year, month, day }
+    }
+
+    #[test]
+    fn test_parse_partial_date() {
+        assert_eq!(parse_partial_date("2020"), Some(date(2020, None, None)));
+        assert_eq!(
+            parse_partial_date("2020-03"),
+            Some(date(2020, Some(3), None))
+        );
+        assert_eq!(
+            parse_partial_date("2020-03-15"),
+            Some(date(2020, Some(3), Some(15)))
+        );
+        assert_eq!(
+            parse_partial_date("03/2020"),
+            Some(date(2020, Some(3), None))
+        );
+        assert_eq!(
+            parse_partial_date("Sept. 2020"),
+            Some(date(2020, Some(9), None))
+        );
+        assert_eq!(
+            parse_partial_date(" march 2020 "),
+            Some(date(2020, Some(3), None))
+        );
+
+        assert_eq!(parse_partial_date("2020-13"), None);
+        assert_eq!(parse_partial_date("2021-02-29"), None);
+        assert_eq!(parse_partial_date("Spring 2020"), None);
+        assert_eq!(parse_partial_date(""), None);
+    }
+
+    #[test]
+    fn test_parse_date_range() {
+        let range = parse_date_range("Jan 2020 - Present").unwrap();
+        assert_eq!(range.start, date(2020, Some(1), None));
+        assert_eq!(range.end, None);
+
+        let range = parse_date_range("2018-2021").unwrap();
+        assert_eq!(range.start.year, 2018);
+        assert_eq!(range.end.unwrap().year, 2021);
+
+        let range = parse_date_range("2019-04 to 2020-01").unwrap();
+        assert_eq!(range.end, Some(date(2020, Some(1), None)));
+
+        let range = parse_date_range("2019–current").unwrap();
+        assert_eq!(range.end, None);
+
+        let range = parse_date_range("2020-03").unwrap();
+        assert_eq!(range.start, range.end.unwrap());
+
+        assert!(parse_date_range("Summer internship").is_none());
+        assert!(parse_date_range("2020 - someday").is_none());
+    }
+
+    #[test]
+    fn test_is_before_uses_shared_precision() {
+        assert!(date(2019, None, None).is_before(&date(2020, Some(1), None)));
+        assert!(date(2020, Some(2), None).is_before(&date(2020, Some(3), Some(1))));
+        assert!(!date(2020, None, None).is_before(&date(2020, Some(6), None)));
+        assert!(!date(2020, Some(6), None).is_before(&date(2020, None, None)));
+        assert!(!date(2020, Some(6), Some(2)).is_before(&date(2020, Some(6), Some(2))));
+    }
 }
@@ -0,0 +1,135 @@
+//! Extract plain-text blocks from rich text HTML.
+//!
+//! Used for analyzing editor content (bullet points, paragraphs) as prose
+//! rather than markup.
+
+use scraper::{Html, Node};
+
+/// Elements that start a new text block.
+const BLOCK_TAGS: &[&str] = &[
+    "p",
+    "li",
+    "div",
+    "blockquote",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "br",
+];
+
+/// Leading markers stripped from plain-text bullet lines.
+const BULLET_MARKERS: &[char] = &['-', '*', '•', '–', '—', '·'];
+
+/// Split rich text into plain-text blocks, one per paragraph or list item.
+///
+/// HTML input yields one block per `<p>`, `<li>`, heading, or `<br>`-separated
+/// line, with entities decoded and whitespace collapsed. Plain text input is
+/// split on newlines, and leading bullet markers (`-`, `*`, `•`) are removed.
+/// Empty blocks are dropped.
+pub fn html_text_blocks(html: &str) -> Vec<String> {
+    let trimmed = html.trim();
+    if trimmed.is_empty() {
+        return Vec::new();
+    }
+
+    if !trimmed.contains('<') {
+        return trimmed
+            .lines()
+            .map(|line| collapse_whitespace(line.trim_start().trim_start_matches(BULLET_MARKERS)))
+            .filter(|line| !line.is_empty())
+            .collect();
+    }
+
+    let document = Html::parse_fragment(trimmed);
+    let mut blocks = Vec::new();
+    let mut current = String::new();
+    for child in document.root_element().children() {
+        collect_blocks(&child, &mut current, &mut blocks);
+    }
+    flush(&mut current, &mut blocks);
+    blocks
+}
+
+fn collect_blocks(
+    node: &ego_tree::NodeRef<'_, Node>,
+    current: &mut String,
+    blocks: &mut Vec<String>,
+) {
+    match node.value() {
+        Node::Text(text) => current.push_str(&text.text),
+        Node::Element(el) if BLOCK_TAGS.contains(&el.name.local.as_ref()) => {
+            flush(current, blocks);
+            for child in node.children() {
+                collect_blocks(&child, current, blocks);
+            }
+            flush(current, blocks);
+        }
+        Node::Element(_) => {
+            for child in node.children() {
+                collect_blocks(&child, current, blocks);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn flush(current: &mut String, blocks: &mut Vec<String>) {
+    let block = collapse_whitespace(current);
+    if !block.is_empty() {
+        blocks.push(block);
+    }
+    current.clear();
+}
+
+fn collapse_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_input() {
+        assert!(html_text_blocks("").is_empty());
+        assert!(html_text_blocks("<p></p>").is_empty());
+        assert!(html_text_blocks("<p><br></p>").is_empty());
+    }
+
+    #[test]
+    fn plain_text_lines() {
+        assert_eq!(
+            html_text_blocks("- Led a team\n•  Shipped v2\n\nPlain line"),
+            vec!["Led a team", "Shipped v2", "Plain line"]
+        );
+    }
+
+    #[test]
+    fn list_items_and_paragraphs() {
+        let html = "<p>Intro text</p><ul><li><p>Built <strong>API</strong> gateway</p></li>\
+                    <li>Cut costs by 20%</li></ul>";
+        assert_eq!(
+            html_text_blocks(html),
+            vec!["Intro text", "Built API gateway", "Cut costs by 20%"]
+        );
+    }
+
+    #[test]
+    fn line_breaks_split_blocks() {
+        assert_eq!(
+            html_text_blocks("<p>First<br>Second</p>"),
+            vec!["First", "Second"]
+        );
+    }
+
+    #[test]
+    fn decodes_entities_and_collapses_whitespace() {
+        assert_eq!(
+            html_text_blocks("<p>R&amp;D   \n  lead</p>"),
+            vec!["R&D lead"]
+        );
+    }
+}
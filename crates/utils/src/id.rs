@@ -10,6 +10,16 @@ pub fn is_valid_id(id: &str) -> bool {
     cuid2::is_cuid2(id)
 }
 
+/// Lowercase `text` with runs of anything but letters and digits turned
+/// into single hyphens: `Rust Conf 2024!` becomes `rust-conf-2024`.
+pub fn slugify(text: &str) -> String {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -20,4 +30,11 @@ mod tests {
         assert!(!id.is_empty());
         assert!(is_valid_id(&id));
     }
+
+    #[test]
+    fn test_slugify() {
+        assert_eq!(slugify("Rust Conf 2024!"), "rust-conf-2024");
+        assert_eq!(slugify("  Café -- Crème "), "café-crème");
+        assert_eq!(slugify("***"), "");
+    }
 }
@@ -4,22 +4,42 @@
 //! - ID generation (CUID2)
 //! - String manipulation
 //! - Date handling
+//! - Publication citations from DOI and arXiv identifiers
+//! - Contact detail extraction and normalization (phone numbers formatted
+//!   per country with the `phone` feature)
+//! - Localized generated strings
 //! - Color conversion
 //! - Layout utilities
-//! - HTML sanitization
+//! - HTML sanitization and escaping
+//! - Plain-text extraction from rich text
+//! - Profile picture effects (`picture` feature)
 
+mod citation;
 mod color;
+mod contact;
 mod date;
+mod html_text;
 mod html_to_typst;
 mod id;
 mod layout;
+pub mod locale;
+#[cfg(feature = "picture")]
+mod picture;
 mod sanitize;
 mod string;
 
+pub use citation::*;
 pub use color::*;
+pub use contact::*;
 pub use date::*;
+pub use html_text::*;
 pub use html_to_typst::*;
 pub use id::*;
 pub use layout::*;
+pub use locale::{
+    is_family_name_first, is_rtl_char, is_rtl_language, is_rtl_text, Locale, DEFAULT_LOCALE,
+};
+#[cfg(feature = "picture")]
+pub use picture::*;
 pub use sanitize::*;
 pub use string::*;
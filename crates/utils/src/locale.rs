@@ -0,0 +1,524 @@
+//! Localized strings that Rustume generates itself.
+//!
+//! User content is never translated. What is covered here is the text that
+//! importers and the renderer write on the user's behalf: the end of an
+//! ongoing date range, duration units, the "Courses:" label, skill group
+//! labels, section titles, and template labels such as page numbers. A
+//! resume's `metadata.locale` picks the table and, for right-to-left
+//! languages, the page direction.
+
+/// Titles for the built-in sections, keyed like the section IDs.
+#[derive(Debug, PartialEq, Eq)]
+pub struct SectionTitles {
+    pub summary: &'static str,
+    pub profiles: &'static str,
+    pub experience: &'static str,
+    pub education: &'static str,
+    pub skills: &'static str,
+    pub languages: &'static str,
+    pub awards: &'static str,
+    pub certifications: &'static str,
+    pub interests: &'static str,
+    pub projects: &'static str,
+    pub publications: &'static str,
+    pub volunteer: &'static str,
+    pub references: &'static str,
+}
+
+/// Generated strings for one language.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Locale {
+    /// Primary language subtag (`en`, `de`, ...).
+    pub language: &'static str,
+    /// End of an ongoing date range, as in `Jan 2020 - Present`.
+    pub present: &'static str,
+    /// Label before a list of courses, as in `Courses: Algorithms`.
+    pub courses: &'static str,
+    /// Duration units: one year, several years, one month, several months.
+    pub year: &'static str,
+    pub years: &'static str,
+    pub month: &'static str,
+    pub months: &'static str,
+    /// Labels for the first, second, and remaining groups of imported skills.
+    pub skill_groups: [&'static str; 3],
+    pub sections: SectionTitles,
+    /// Labeled page numbers, with `{current}` and `{total}` placeholders.
+    pub page_label: &'static str,
+    /// Text for skill and language levels 1 through 5.
+    pub level_labels: [&'static str; 5],
+    /// Heading for contact details in templates that show one.
+    pub contact: &'static str,
+    /// Heading over the QR codes linking attached files.
+    pub attachments: &'static str,
+}
+
+/// Tag used when a resume does not set one.
+pub const DEFAULT_LOCALE: &str = "en";
+
+pub static EN: Locale = Locale {
+    language: "en",
+    present: "Present",
+    courses: "Courses",
+    year: "yr",
+    years: "yrs",
+    month: "mo",
+    months: "mos",
+    skill_groups: ["Skills", "Additional Skills", "More Skills"],
+    sections: SectionTitles {
+        summary: "Summary",
+        profiles: "Profiles",
+        experience: "Experience",
+        education: "Education",
+        skills: "Skills",
+        languages: "Languages",
+        awards: "Awards",
+        certifications: "Certifications",
+        interests: "Interests",
+        projects: "Projects",
+        publications: "Publications",
+        volunteer: "Volunteer",
+        references: "References",
+    },
+    page_label: "Page {current} of {total}",
+    level_labels: ["Novice", "Beginner", "Intermediate", "Advanced", "Expert"],
+    contact: "Contact",
+    attachments: "Attachments",
+};
+
+pub static DE: Locale = Locale {
+    language: "de",
+    present: "Heute",
+    courses: "Kurse",
+    year: "Jahr",
+    years: "Jahre",
+    month: "Monat",
+    months: "Monate",
+    skill_groups: ["Kenntnisse", "Weitere Kenntnisse", "Sonstige Kenntnisse"],
+    sections: SectionTitles {
+        summary: "Zusammenfassung",
+        profiles: "Profile",
+        experience: "Berufserfahrung",
+        education: "Ausbildung",
+        skills: "Kenntnisse",
+        languages: "Sprachen",
+        awards: "Auszeichnungen",
+        certifications: "Zertifikate",
+        interests: "Interessen",
+        projects: "Projekte",
+        publications: "Publikationen",
+        volunteer: "Ehrenamt",
+        references: "Referenzen",
+    },
+    page_label: "Seite {current} von {total}",
+    level_labels: [
+        "Anfänger",
+        "Grundkenntnisse",
+        "Fortgeschritten",
+        "Sehr gut",
+        "Experte",
+    ],
+    contact: "Kontakt",
+    attachments: "Anlagen",
+};
+
+pub static ES: Locale = Locale {
+    language: "es",
+    present: "Actualidad",
+    courses: "Cursos",
+    year: "año",
+    years: "años",
+    month: "mes",
+    months: "meses",
+    skill_groups: [
+        "Habilidades",
+        "Habilidades adicionales",
+        "Otras habilidades",
+    ],
+    sections: SectionTitles {
+        summary: "Resumen",
+        profiles: "Perfiles",
+        experience: "Experiencia",
+        education: "Educación",
+        skills: "Habilidades",
+        languages: "Idiomas",
+        awards: "Premios",
+        certifications: "Certificaciones",
+        interests: "Intereses",
+        projects: "Proyectos",
+        publications: "Publicaciones",
+        volunteer: "Voluntariado",
+        references: "Referencias",
+    },
+    page_label: "Página {current} de {total}",
+    level_labels: [
+        "Novato",
+        "Principiante",
+        "Intermedio",
+        "Avanzado",
+        "Experto",
+    ],
+    contact: "Contacto",
+    attachments: "Anexos",
+};
+
+pub static FR: Locale = Locale {
+    language: "fr",
+    present: "Aujourd'hui",
+    courses: "Cours",
+    year: "an",
+    years: "ans",
+    month: "mois",
+    months: "mois",
+    skill_groups: [
+        "Compétences",
+        "Compétences complémentaires",
+        "Autres compétences",
+    ],
+    sections: SectionTitles {
+        summary: "Profil",
+        profiles: "Réseaux",
+        experience: "Expérience",
+        education: "Formation",
+        skills: "Compétences",
+        languages: "Langues",
+        awards: "Distinctions",
+        certifications: "Certifications",
+        interests: "Centres d'intérêt",
+        projects: "Projets",
+        publications: "Publications",
+        volunteer: "Bénévolat",
+        references: "Références",
+    },
+    page_label: "Page {current} sur {total}",
+    level_labels: ["Novice", "Débutant", "Intermédiaire", "Avancé", "Expert"],
+    contact: "Contact",
+    attachments: "Pièces jointes",
+};
+
+pub static IT: Locale = Locale {
+    language: "it",
+    present: "Presente",
+    courses: "Corsi",
+    year: "anno",
+    years: "anni",
+    month: "mese",
+    months: "mesi",
+    skill_groups: ["Competenze", "Competenze aggiuntive", "Altre competenze"],
+    sections: SectionTitles {
+        summary: "Profilo",
+        profiles: "Profili",
+        experience: "Esperienza",
+        education: "Istruzione",
+        skills: "Competenze",
+        languages: "Lingue",
+        awards: "Premi",
+        certifications: "Certificazioni",
+        interests: "Interessi",
+        projects: "Progetti",
+        publications: "Pubblicazioni",
+        volunteer: "Volontariato",
+        references: "Referenze",
+    },
+    page_label: "Pagina {current} di {total}",
+    level_labels: [
+        "Novizio",
+        "Principiante",
+        "Intermedio",
+        "Avanzato",
+        "Esperto",
+    ],
+    contact: "Contatti",
+    attachments: "Allegati",
+};
+
+pub static NL: Locale = Locale {
+    language: "nl",
+    present: "Heden",
+    courses: "Cursussen",
+    year: "jaar",
+    years: "jaar",
+    month: "maand",
+    months: "maanden",
+    skill_groups: [
+        "Vaardigheden",
+        "Aanvullende vaardigheden",
+        "Overige vaardigheden",
+    ],
+    sections: SectionTitles {
+        summary: "Profiel",
+        profiles: "Profielen",
+        experience: "Werkervaring",
+        education: "Opleiding",
+        skills: "Vaardigheden",
+        languages: "Talen",
+        awards: "Prijzen",
+        certifications: "Certificeringen",
+        interests: "Interesses",
+        projects: "Projecten",
+        publications: "Publicaties",
+        volunteer: "Vrijwilligerswerk",
+        references: "Referenties",
+    },
+    page_label: "Pagina {current} van {total}",
+    level_labels: ["Starter", "Beginner", "Gemiddeld", "Gevorderd", "Expert"],
+    contact: "Contact",
+    attachments: "Bijlagen",
+};
+
+pub static PT: Locale = Locale {
+    language: "pt",
+    present: "Atual",
+    courses: "Cursos",
+    year: "ano",
+    years: "anos",
+    month: "mês",
+    months: "meses",
+    skill_groups: [
+        "Competências",
+        "Competências adicionais",
+        "Outras competências",
+    ],
+    sections: SectionTitles {
+        summary: "Resumo",
+        profiles: "Perfis",
+        experience: "Experiência",
+        education: "Formação",
+        skills: "Competências",
+        languages: "Idiomas",
+        awards: "Prémios",
+        certifications: "Certificações",
+        interests: "Interesses",
+        projects: "Projetos",
+        publications: "Publicações",
+        volunteer: "Voluntariado",
+        references: "Referências",
+    },
+    page_label: "Página {current} de {total}",
+    level_labels: [
+        "Novato",
+        "Iniciante",
+        "Intermediário",
+        "Avançado",
+        "Especialista",
+    ],
+    contact: "Contato",
+    attachments: "Anexos",
+};
+
+pub static AR: Locale = Locale {
+    language: "ar",
+    present: "حتى الآن",
+    courses: "الدورات",
+    year: "سنة",
+    years: "سنوات",
+    month: "شهر",
+    months: "أشهر",
+    skill_groups: ["المهارات", "مهارات إضافية", "مهارات أخرى"],
+    sections: SectionTitles {
+        summary: "الملخص",
+        profiles: "الملفات الشخصية",
+        experience: "الخبرة العملية",
+        education: "التعليم",
+        skills: "المهارات",
+        languages: "اللغات",
+        awards: "الجوائز",
+        certifications: "الشهادات",
+        interests: "الاهتمامات",
+        projects: "المشاريع",
+        publications: "المنشورات",
+        volunteer: "العمل التطوعي",
+        references: "المراجع",
+    },
+    page_label: "صفحة {current} من {total}",
+    level_labels: ["مبتدئ", "أساسي", "متوسط", "متقدم", "خبير"],
+    contact: "التواصل",
+    attachments: "المرفقات",
+};
+
+pub static HE: Locale = Locale {
+    language: "he",
+    present: "היום",
+    courses: "קורסים",
+    year: "שנה",
+    years: "שנים",
+    month: "חודש",
+    months: "חודשים",
+    skill_groups: ["כישורים", "כישורים נוספים", "כישורים אחרים"],
+    sections: SectionTitles {
+        summary: "תקציר",
+        profiles: "פרופילים",
+        experience: "ניסיון תעסוקתי",
+        education: "השכלה",
+        skills: "כישורים",
+        languages: "שפות",
+        awards: "פרסים",
+        certifications: "הסמכות",
+        interests: "תחומי עניין",
+        projects: "פרויקטים",
+        publications: "פרסומים",
+        volunteer: "התנדבות",
+        references: "ממליצים",
+    },
+    page_label: "עמוד {current} מתוך {total}",
+    level_labels: ["מתחיל", "בסיסי", "בינוני", "מתקדם", "מומחה"],
+    contact: "פרטי קשר",
+    attachments: "קבצים מצורפים",
+};
+
+/// Every supported locale, English first.
+pub static LOCALES: [&Locale; 9] = [&EN, &DE, &ES, &FR, &IT, &NL, &PT, &AR, &HE];
+
+/// Primary subtags of languages written right to left, whether or not they
+/// have a table above.
+const RTL_LANGUAGES: &[&str] = &[
+    "ar", "ckb", "dv", "fa", "he", "iw", "ps", "sd", "ug", "ur", "yi",
+];
+
+/// Primary subtags of languages that put the family name before the given
+/// name: Chinese, Japanese, Korean, Hungarian, Vietnamese, and Mongolian.
+const FAMILY_FIRST_LANGUAGES: &[&str] = &["zh", "ja", "ko", "hu", "vi", "mn"];
+
+/// Primary language subtag of a BCP 47 tag, as written.
+pub fn primary_language(tag: &str) -> &str {
+    tag.trim().split(['-', '_']).next().unwrap_or_default()
+}
+
+/// Region subtag of a BCP 47 tag, uppercased: `BR` for `pt-BR`, `TW` for
+/// `zh-Hant-TW`. `None` when the tag names only a language.
+pub fn region_subtag(tag: &str) -> Option<String> {
+    tag.trim()
+        .split(['-', '_'])
+        .skip(1)
+        .find(|subtag| subtag.len() == 2 && subtag.chars().all(|c| c.is_ascii_alphabetic()))
+        .map(str::to_ascii_uppercase)
+}
+
+impl Locale {
+    /// Locale for a BCP 47 tag such as `de`, `pt-BR`, or `en_US`, matched on
+    /// the primary language. Unknown or empty tags get English.
+    pub fn for_tag(tag: &str) -> &'static Locale {
+        // `iw` is the deprecated code for Hebrew, still sent by older systems
+        let language = match primary_language(tag) {
+            l if l.eq_ignore_ascii_case("iw") => "he",
+            l => l,
+        };
+        LOCALES
+            .iter()
+            .copied()
+            .find(|locale| locale.language.eq_ignore_ascii_case(language))
+            .unwrap_or(&EN)
+    }
+
+    /// Label for the `index`-th group of imported skills.
+    pub fn skill_group(&self, index: usize) -> &'static str {
+        self.skill_groups[index.min(self.skill_groups.len() - 1)]
+    }
+}
+
+/// Whether `tag` names a right-to-left language such as Arabic or Hebrew.
+pub fn is_rtl_language(tag: &str) -> bool {
+    let language = primary_language(tag);
+    RTL_LANGUAGES
+        .iter()
+        .any(|rtl| rtl.eq_ignore_ascii_case(language))
+}
+
+/// Whether names are written family name first in `tag`'s language, as in
+/// `山田 太郎` or `Kovács János`.
+pub fn is_family_name_first(tag: &str) -> bool {
+    let language = primary_language(tag);
+    FAMILY_FIRST_LANGUAGES
+        .iter()
+        .any(|first| first.eq_ignore_ascii_case(language))
+}
+
+/// Whether `c` belongs to a right-to-left script (Hebrew, Arabic, Syriac,
+/// Thaana, N'Ko, and their presentation forms).
+pub fn is_rtl_char(c: char) -> bool {
+    matches!(c, '\u{0590}'..='\u{08FF}' | '\u{FB1D}'..='\u{FDFF}' | '\u{FE70}'..='\u{FEFF}')
+}
+
+/// Whether most letters in `text` are from right-to-left scripts. Digits,
+/// punctuation, and spaces don't count either way.
+pub fn is_rtl_text(text: &str) -> bool {
+    let (rtl, ltr) =
+        text.chars()
+            .filter(|c| c.is_alphabetic())
+            .fold((0usize, 0usize), |(rtl, ltr), c| {
+                if is_rtl_char(c) {
+                    (rtl + 1, ltr)
+                } else {
+                    (rtl, ltr + 1)
+                }
+            });
+    rtl > ltr
+}
+
+/// Whether `word` is how some supported locale ends an ongoing range.
+pub(crate) fn is_localized_present(word: &str) -> bool {
+    LOCALES
+        .iter()
+        .any(|locale| locale.present.to_lowercase() == word.to_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_for_tag_matches_primary_language() {
+        assert_eq!(Locale::for_tag("de").language, "de");
+        assert_eq!(Locale::for_tag("pt-BR").language, "pt");
+        assert_eq!(Locale::for_tag("FR_ca").language, "fr");
+        assert_eq!(Locale::for_tag("ja-JP"), &EN);
+        assert_eq!(Locale::for_tag(""), &EN);
+        assert_eq!(Locale::for_tag("iw-IL"), &HE);
+    }
+
+    #[test]
+    fn test_rtl_detection() {
+        assert!(is_rtl_language("ar-EG"));
+        assert!(is_rtl_language("HE"));
+        assert!(is_rtl_language("fa"));
+        assert!(!is_rtl_language("en"));
+        assert!(!is_rtl_language(""));
+
+        assert!(is_rtl_text("مهندسة برمجيات في Acme"));
+        assert!(is_rtl_text("שלום 2024"));
+        assert!(!is_rtl_text("Software engineer, תל אביב"));
+        assert!(!is_rtl_text("2020 - 2024"));
+    }
+
+    #[test]
+    fn test_family_name_first() {
+        assert!(is_family_name_first("ja"));
+        assert!(is_family_name_first("zh-Hant-TW"));
+        assert!(is_family_name_first("KO_kr"));
+        assert!(is_family_name_first("hu"));
+        assert!(!is_family_name_first("en"));
+        assert!(!is_family_name_first(""));
+    }
+
+    #[test]
+    fn test_region_subtag() {
+        assert_eq!(region_subtag("pt-BR").as_deref(), Some("BR"));
+        assert_eq!(region_subtag("zh-Hant-TW").as_deref(), Some("TW"));
+        assert_eq!(region_subtag("en_gb").as_deref(), Some("GB"));
+        assert_eq!(region_subtag("de"), None);
+        assert_eq!(region_subtag(""), None);
+    }
+
+    #[test]
+    fn test_skill_group_clamps_to_last_label() {
+        assert_eq!(DE.skill_group(0), "Kenntnisse");
+        assert_eq!(DE.skill_group(1), "Weitere Kenntnisse");
+        assert_eq!(DE.skill_group(7), "Sonstige Kenntnisse");
+    }
+
+    #[test]
+    fn test_localized_present() {
+        assert!(is_localized_present("heute"));
+        assert!(is_localized_present("AUJOURD'HUI"));
+        assert!(!is_localized_present("2020"));
+    }
+}
@@ -0,0 +1,282 @@
+//! Profile picture effects applied to the image itself.
+//!
+//! The crop, grayscale, rounded corners, and border are baked into a PNG, so
+//! the picture looks the same in every output without template-side
+//! clipping and strokes.
+
+use std::io::Cursor;
+
+use image::imageops::FilterType;
+use image::{DynamicImage, GenericImageView, ImageError, ImageFormat, Rgba, RgbaImage};
+
+/// Longest side of a processed picture in pixels; larger photos are scaled
+/// down, which is still sharp at any picture size templates use.
+pub const MAX_PICTURE_SIDE: u32 = 1024;
+
+/// Effects to bake into a profile picture.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PictureStyle {
+    /// Width over height to center-crop to; `None` keeps the image's shape.
+    pub aspect_ratio: Option<f32>,
+    /// Convert to grayscale, keeping transparency.
+    pub grayscale: bool,
+    /// Corner radius as a fraction of the cropped width, at most half the
+    /// shorter side (a circle or pill).
+    pub corner_radius: f32,
+    /// Border drawn inside the edge, following the corners.
+    pub border: Option<PictureBorder>,
+}
+
+/// A border around a processed picture.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PictureBorder {
+    /// Width as a fraction of the cropped width.
+    pub width: f32,
+    /// Color as RGBA.
+    pub color: [u8; 4],
+}
+
+/// A processed picture, encoded as PNG.
+#[derive(Debug, Clone)]
+pub struct ProcessedPicture {
+    pub png: Vec<u8>,
+    pub width: u32,
+    pub height: u32,
+}
+
+/// Decode a PNG, JPEG, GIF, or WebP picture and apply `style`.
+pub fn process_picture(bytes: &[u8], style: &PictureStyle) -> Result<ProcessedPicture, ImageError> {
+    let image = crop_to_aspect(image::load_from_memory(bytes)?, style.aspect_ratio);
+    let image = if image.width().max(image.height()) > MAX_PICTURE_SIDE {
+        image.resize(MAX_PICTURE_SIDE, MAX_PICTURE_SIDE, FilterType::Lanczos3)
+    } else {
+        image
+    };
+    let mut pixels = if style.grayscale {
+        image.grayscale().to_rgba8()
+    } else {
+        image.to_rgba8()
+    };
+    shape(&mut pixels, style);
+
+    let (width, height) = pixels.dimensions();
+    let mut png = Cursor::new(Vec::new());
+    DynamicImage::ImageRgba8(pixels).write_to(&mut png, ImageFormat::Png)?;
+    Ok(ProcessedPicture {
+        png: png.into_inner(),
+        width,
+        height,
+    })
+}
+
+/// Center-crop to `aspect_ratio` (width over height).
+fn crop_to_aspect(image: DynamicImage, aspect_ratio: Option<f32>) -> DynamicImage {
+    let Some(aspect) = aspect_ratio.filter(|ratio| ratio.is_finite() && *ratio > 0.0) else {
+        return image;
+    };
+    let (width, height) = image.dimensions();
+    let (crop_width, crop_height) = if width as f32 / height as f32 > aspect {
+        (
+            ((height as f32 * aspect).round() as u32).clamp(1, width),
+            height,
+        )
+    } else {
+        (
+            width,
+            ((width as f32 / aspect).round() as u32).clamp(1, height),
+        )
+    };
+    image.crop_imm(
+        (width - crop_width) / 2,
+        (height - crop_height) / 2,
+        crop_width,
+        crop_height,
+    )
+}
+
+/// Round the corners and draw the border, anti-aliased over one pixel.
+fn shape(pixels: &mut RgbaImage, style: &PictureStyle) {
+    let (width, height) = (pixels.width() as f32, pixels.height() as f32);
+    let radius = (style.corner_radius.max(0.0) * width).min(width.min(height) / 2.0);
+    let border = style
+        .border
+        .map(|border| (border.width.max(0.0) * width, border.color))
+        .filter(|(border_width, _)| *border_width > 0.0);
+    if radius <= 0.0 && border.is_none() {
+        return;
+    }
+
+    for (x, y, pixel) in pixels.enumerate_pixels_mut() {
+        let depth = inside_depth(x as f32 + 0.5, y as f32 + 0.5, width, height, radius);
+        if let Some((border_width, color)) = border {
+            let share = (border_width - depth + 0.5).clamp(0.0, 1.0);
+            *pixel = blend(*pixel, color, share);
+        }
+        let coverage = (depth + 0.5).clamp(0.0, 1.0);
+        pixel[3] = (f32::from(pixel[3]) * coverage).round() as u8;
+    }
+}
+
+/// How far a point lies inside the rounded rectangle's edge, negative outside.
+fn inside_depth(x: f32, y: f32, width: f32, height: f32, radius: f32) -> f32 {
+    let qx = (x - width / 2.0).abs() - (width / 2.0 - radius);
+    let qy = (y - height / 2.0).abs() - (height / 2.0 - radius);
+    let outside = qx.max(0.0).hypot(qy.max(0.0));
+    let inside = qx.max(qy).min(0.0);
+    radius - outside - inside
+}
+
+/// Paint `color` over `pixel`, covering `share` of it.
+fn blend(pixel: Rgba<u8>, color: [u8; 4], share: f32) -> Rgba<u8> {
+    let over = f32::from(color[3]) / 255.0 * share;
+    if over <= 0.0 {
+        return pixel;
+    }
+    let under = f32::from(pixel[3]) / 255.0 * (1.0 - over);
+    let alpha = over + under;
+    let channel = |i: usize| {
+        ((f32::from(color[i]) * over + f32::from(pixel[i]) * under) / alpha).round() as u8
+    };
+    Rgba([
+        channel(0),
+        channel(1),
+        channel(2),
+        (alpha * 255.0).round() as u8,
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A PNG filled with `color`.
+    fn sample(width: u32, height: u32, color: [u8; 4]) -> Vec<u8> {
+        let mut png = Cursor::new(Vec::new());
+        DynamicImage::ImageRgba8(RgbaImage::from_pixel(width, height, Rgba(color)))
+            .write_to(&mut png, ImageFormat::Png)
+            .unwrap();
+        png.into_inner()
+    }
+
+    fn decode(picture: &ProcessedPicture) -> RgbaImage {
+        image::load_from_memory(&picture.png).unwrap().to_rgba8()
+    }
+
+    #[test]
+    fn unstyled_pictures_are_reencoded_unchanged() {
+        let picture = process_picture(
+            &sample(40, 30, [200, 40, 40, 255]),
+            &PictureStyle::default(),
+        )
+        .unwrap();
+        assert_eq!((picture.width, picture.height), (40, 30));
+        assert_eq!(decode(&picture).get_pixel(0, 0), &Rgba([200, 40, 40, 255]));
+    }
+
+    #[test]
+    fn pictures_are_center_cropped_to_the_aspect_ratio() {
+        let style = PictureStyle {
+            aspect_ratio: Some(1.0),
+            ..PictureStyle::default()
+        };
+        let wide = process_picture(&sample(200, 100, [0, 0, 0, 255]), &style).unwrap();
+        assert_eq!((wide.width, wide.height), (100, 100));
+
+        let portrait = PictureStyle {
+            aspect_ratio: Some(0.75),
+            ..PictureStyle::default()
+        };
+        let tall = process_picture(&sample(100, 200, [0, 0, 0, 255]), &portrait).unwrap();
+        assert_eq!((tall.width, tall.height), (100, 133));
+    }
+
+    #[test]
+    fn crop_keeps_the_middle_of_the_image() {
+        // Left half red, right half blue; a square crop of the middle keeps both
+        let mut image = RgbaImage::from_pixel(300, 100, Rgba([255, 0, 0, 255]));
+        for x in 150..300 {
+            for y in 0..100 {
+                image.put_pixel(x, y, Rgba([0, 0, 255, 255]));
+            }
+        }
+        let cropped = crop_to_aspect(DynamicImage::ImageRgba8(image), Some(1.0)).to_rgba8();
+        assert_eq!(cropped.dimensions(), (100, 100));
+        assert_eq!(cropped.get_pixel(0, 50), &Rgba([255, 0, 0, 255]));
+        assert_eq!(cropped.get_pixel(99, 50), &Rgba([0, 0, 255, 255]));
+    }
+
+    #[test]
+    fn grayscale_keeps_transparency() {
+        let style = PictureStyle {
+            grayscale: true,
+            ..PictureStyle::default()
+        };
+        let picture = process_picture(&sample(10, 10, [220, 30, 90, 128]), &style).unwrap();
+        let pixel = decode(&picture).get_pixel(5, 5).0;
+        assert_eq!(pixel[0], pixel[1]);
+        assert_eq!(pixel[1], pixel[2]);
+        assert_eq!(pixel[3], 128);
+    }
+
+    #[test]
+    fn full_corner_radius_makes_a_circle() {
+        let style = PictureStyle {
+            corner_radius: 0.5,
+            ..PictureStyle::default()
+        };
+        let picture = decode(&process_picture(&sample(64, 64, [0, 128, 0, 255]), &style).unwrap());
+        assert_eq!(picture.get_pixel(0, 0)[3], 0);
+        assert_eq!(picture.get_pixel(63, 63)[3], 0);
+        assert_eq!(picture.get_pixel(32, 32), &Rgba([0, 128, 0, 255]));
+        assert!(picture.get_pixel(32, 0)[3] > 250);
+    }
+
+    #[test]
+    fn oversized_radius_is_capped_at_the_shorter_side() {
+        let style = PictureStyle {
+            corner_radius: 5.0,
+            ..PictureStyle::default()
+        };
+        let picture = decode(&process_picture(&sample(80, 40, [0, 0, 0, 255]), &style).unwrap());
+        // A pill: straight top edge in the middle, rounded ends
+        assert_eq!(picture.get_pixel(40, 0)[3], 255);
+        assert_eq!(picture.get_pixel(0, 0)[3], 0);
+    }
+
+    #[test]
+    fn border_is_drawn_inside_the_edge() {
+        let style = PictureStyle {
+            border: Some(PictureBorder {
+                width: 0.1,
+                color: [255, 255, 0, 255],
+            }),
+            ..PictureStyle::default()
+        };
+        let picture = decode(&process_picture(&sample(50, 50, [0, 0, 255, 255]), &style).unwrap());
+        assert_eq!(picture.get_pixel(0, 25), &Rgba([255, 255, 0, 255]));
+        assert_eq!(picture.get_pixel(3, 25), &Rgba([255, 255, 0, 255]));
+        assert_eq!(picture.get_pixel(25, 25), &Rgba([0, 0, 255, 255]));
+    }
+
+    #[test]
+    fn translucent_border_blends_with_the_photo() {
+        let blended = blend(Rgba([0, 0, 0, 255]), [255, 255, 255, 128], 1.0);
+        assert_eq!(blended[3], 255);
+        assert!((120..=136).contains(&blended[0]));
+    }
+
+    #[test]
+    fn large_pictures_are_scaled_down() {
+        let picture = process_picture(
+            &sample(3000, 1500, [10, 10, 10, 255]),
+            &PictureStyle::default(),
+        )
+        .unwrap();
+        assert_eq!((picture.width, picture.height), (MAX_PICTURE_SIDE, 512));
+    }
+
+    #[test]
+    fn undecodable_bytes_are_an_error() {
+        assert!(process_picture(b"not an image", &PictureStyle::default()).is_err());
+    }
+}
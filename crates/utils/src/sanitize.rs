@@ -1,4 +1,8 @@
 //! HTML sanitization utilities.
+//!
+//! Besides HTML cleaning, this covers emoji and pictographic icons: many
+//! applicant tracking systems garble them when parsing a PDF, so they can be
+//! stripped or swapped for plain-text equivalents before rendering.
 
 use ammonia::Builder;
 use once_cell::sync::Lazy;
@@ -131,9 +135,138 @@ pub fn sanitize_html(html: &str) -> String {
     SANITIZER.clean(html).to_string()
 }
 
+/// Escape plain text for HTML or XML, in element content and quoted
+/// attribute values alike.
+pub fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Whether `c` is an emoji or pictographic icon (dingbats, weather and
+/// office symbols, geometric bullets, stars).
+fn is_emoji(c: char) -> bool {
+    matches!(
+        c,
+        '\u{1F000}'..='\u{1FAFF}'
+            | '\u{2600}'..='\u{27BF}'
+            | '\u{2B00}'..='\u{2BFF}'
+            | '\u{25A0}'..='\u{25FF}'
+            | '\u{231A}'..='\u{231B}'
+            | '\u{2328}'
+            | '\u{23CF}'
+            | '\u{23E9}'..='\u{23FA}'
+            | '\u{3030}'
+            | '\u{303D}'
+            | '\u{3297}'
+            | '\u{3299}'
+    )
+}
+
+/// Invisible characters that only modify a preceding emoji: variation
+/// selectors, the keycap mark, skin tones, and flag tags.
+fn is_emoji_component(c: char) -> bool {
+    matches!(
+        c,
+        '\u{FE0E}' | '\u{FE0F}' | '\u{20E3}' | '\u{1F3FB}'..='\u{1F3FF}' | '\u{E0020}'..='\u{E007F}'
+    )
+}
+
+/// Plain-text stand-in for icons commonly used as labels or bullets.
+fn text_equivalent(c: char) -> Option<&'static str> {
+    match c {
+        '📧' | '✉' | '📨' | '📩' | '📮' => Some("Email:"),
+        '📞' | '☎' | '📱' | '📲' => Some("Phone:"),
+        '📍' | '📌' | '🏠' | '🏡' => Some("Location:"),
+        '🔗' | '🌐' => Some("Web:"),
+        '★' | '☆' | '⭐' | '🌟' => Some("*"),
+        '✓' | '✔' | '✅' | '☑' | '▶' | '►' | '▪' | '■' | '●' | '◆' | '➔' | '➜' | '➤' | '👉' => {
+            Some("-")
+        }
+        _ => None,
+    }
+}
+
+/// Whether `text` contains any emoji or pictographic icon.
+pub fn contains_emoji(text: &str) -> bool {
+    text.chars().any(is_emoji)
+}
+
+/// Remove emoji and icons from `text`, which may be plain text or HTML,
+/// along with the space that separated them from the surrounding words.
+pub fn strip_emoji(text: &str) -> String {
+    rewrite_emoji(text, |_| None)
+}
+
+/// Replace emoji and icons that stand for a label or bullet (`📧`, `✅`, `⭐`)
+/// with a plain-text equivalent (`Email:`, `-`, `*`) and remove the rest.
+pub fn replace_emoji(text: &str) -> String {
+    rewrite_emoji(text, text_equivalent)
+}
+
+fn rewrite_emoji(text: &str, replacement: impl Fn(char) -> Option<&'static str>) -> String {
+    if !contains_emoji(text) {
+        return text.to_string();
+    }
+
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if is_emoji_component(c) {
+            // A keycap's selector and mark after a plain digit
+            continue;
+        }
+        if !is_emoji(c) {
+            out.push(c);
+            continue;
+        }
+
+        // Swallow the rest of the sequence: modifiers and ZWJ-joined emoji
+        // (👩‍💻 is three code points plus a joiner)
+        loop {
+            match chars.peek() {
+                Some(&next) if is_emoji_component(next) => {
+                    chars.next();
+                }
+                Some('\u{200D}') => {
+                    chars.next();
+                    chars.next_if(|&next| is_emoji(next));
+                }
+                _ => break,
+            }
+        }
+
+        if let Some(text) = replacement(c) {
+            out.push_str(text);
+        } else if out.ends_with(' ')
+            && chars
+                .peek()
+                .map_or(true, |&next| next.is_whitespace() || next == '<')
+        {
+            // Trailing icon: drop the space before it too
+            out.pop();
+        } else if out.is_empty() || out.ends_with(|prev: char| prev.is_whitespace() || prev == '>')
+        {
+            // Leading icon: drop the space after it
+            while chars.next_if_eq(&' ').is_some() {}
+        }
+    }
+    out
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rstest::rstest;
 
     #[test]
     fn test_sanitize_html_allows_safe_tags() {
@@ -142,6 +275,15 @@ mod tests {
         assert_eq!(output, "<p>Hello <strong>world</strong></p>");
     }
 
+    #[test]
+    fn test_escape_html() {
+        assert_eq!(
+            escape_html(r#"<a href="x">Tom & Jerry's</a>"#),
+            "&lt;a href=&quot;x&quot;&gt;Tom &amp; Jerry&#39;s&lt;/a&gt;"
+        );
+        assert_eq!(escape_html("plain"), "plain");
+    }
+
     #[test]
     fn test_sanitize_html_removes_script() {
         let input = "<p>Hello</p><script>alert('xss')</script>";
@@ -201,4 +343,31 @@ mod tests {
         assert!(output.contains("rowspan"));
         assert!(output.contains("scope"));
     }
+
+    #[rstest]
+    #[case("Software Engineer 🚀", "Software Engineer")]
+    #[case("🚀 Shipped v2 ✨ on time", "Shipped v2 on time")]
+    #[case("<p>✅ Cut costs 👩‍💻</p>", "<p>Cut costs</p>")]
+    #[case("Team of 5 👍🏽 1️⃣", "Team of 5 1")]
+    #[case("Go → Rust, 日本語, naïve", "Go → Rust, 日本語, naïve")]
+    fn test_strip_emoji(#[case] input: &str, #[case] expected: &str) {
+        assert_eq!(strip_emoji(input), expected);
+    }
+
+    #[test]
+    fn test_replace_emoji() {
+        assert_eq!(
+            replace_emoji("📧 jane@example.com"),
+            "Email: jane@example.com"
+        );
+        assert_eq!(replace_emoji("✅ Led hiring ⭐️"), "- Led hiring *");
+        assert_eq!(replace_emoji("Rust 🦀 fan"), "Rust fan");
+    }
+
+    #[test]
+    fn test_contains_emoji() {
+        assert!(contains_emoji("Hi 👋"));
+        assert!(contains_emoji("☕"));
+        assert!(!contains_emoji("Café – 2024 • Tokyo → Berlin"));
+    }
 }